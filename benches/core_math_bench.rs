@@ -2,14 +2,17 @@ use chart_rs::api::{
     AxisLabelLocale, ChartEngine, ChartEngineConfig, CrosshairLabelBoxHorizontalAnchor,
     CrosshairLabelBoxOverflowPolicy, CrosshairLabelBoxVerticalAnchor,
     CrosshairLabelBoxVisibilityPriority, CrosshairLabelBoxWidthMode, CrosshairLabelBoxZOrderPolicy,
-    LastPriceLabelBoxWidthMode, LastPriceSourceMode, PriceAxisDisplayMode, PriceAxisLabelConfig,
-    PriceAxisLabelPolicy, PriceScaleTransformedBaseBehavior, PriceScaleTransformedBaseSource,
-    RenderStyle, TimeAxisLabelConfig, TimeAxisLabelPolicy, TimeAxisSessionConfig, TimeAxisTimeZone,
+    GridLineOptions, GridOptions, LastPriceLabelBoxWidthMode, LastPriceSourceMode,
+    PriceAxisDisplayMode, PriceAxisLabelConfig, PriceAxisLabelPolicy, PriceAxisSecondaryLabel,
+    PriceScaleTransformedBaseBehavior, PriceScaleTransformedBaseSource, RenderStyle,
+    TimeAxisLabelConfig, TimeAxisLabelPolicy, TimeAxisSessionConfig, TimeAxisTimeUnit,
+    TimeAxisTimeZone,
 };
 use chart_rs::core::{
-    DataPoint, LinearScale, OhlcBar, PriceScale, PriceScaleMode, TimeScale, Viewport,
-    points_in_time_window, project_area_geometry, project_bars, project_baseline_geometry,
-    project_candles, project_histogram_bars, project_line_segments,
+    DataPoint, LinearScale, OhlcBar, PARALLEL_PROJECTION_MIN_LEN, PriceScale, PriceScaleMode,
+    TimeScale, Viewport, points_in_sorted_time_window, points_in_time_window,
+    project_area_geometry, project_bars, project_baseline_geometry, project_candles,
+    project_histogram_bars, project_line_segments,
 };
 use chart_rs::extensions::{
     ChartPlugin, MarkerPlacementConfig, MarkerPosition, PluginContext, PluginEvent, SeriesMarker,
@@ -17,7 +20,7 @@ use chart_rs::extensions::{
 };
 use chart_rs::interaction::{CrosshairMode, KineticPanConfig};
 use chart_rs::render::{Color, LineStrokeStyle, NullRenderer, TextHAlign};
-use criterion::{Criterion, criterion_group, criterion_main};
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
 use std::hint::black_box;
 use std::sync::Arc;
 
@@ -66,6 +69,43 @@ fn bench_candle_projection_10k(c: &mut Criterion) {
     });
 }
 
+/// Candle projection on both sides of `PARALLEL_PROJECTION_MIN_LEN`; run
+/// with `--features parallel-projection` to compare against the sequential
+/// timings.
+fn bench_candle_projection_parallel_crossover(c: &mut Criterion) {
+    let viewport = Viewport::new(1920, 1080);
+    let mut group = c.benchmark_group("candle_projection_parallel_crossover");
+    for len in [
+        PARALLEL_PROJECTION_MIN_LEN / 4,
+        PARALLEL_PROJECTION_MIN_LEN,
+        PARALLEL_PROJECTION_MIN_LEN * 16,
+    ] {
+        let time_scale = TimeScale::new(0.0, len as f64 + 1.0).expect("valid time scale");
+        let price_scale = PriceScale::new(0.0, 2_500.0).expect("valid price scale");
+        let bars: Vec<OhlcBar> = (0..len)
+            .map(|i| {
+                let t = i as f64;
+                let base = 100.0 + (t * 0.01).sin() * 50.0;
+                OhlcBar::new(t, base, base + 1.5, base - 1.5, base + 0.5)
+                    .expect("valid generated bar")
+            })
+            .collect();
+        group.bench_with_input(BenchmarkId::from_parameter(len), &bars, |b, bars| {
+            b.iter(|| {
+                project_candles(
+                    black_box(bars),
+                    black_box(time_scale),
+                    black_box(price_scale),
+                    black_box(viewport),
+                    black_box(7.0),
+                )
+                .expect("projection should succeed")
+            })
+        });
+    }
+    group.finish();
+}
+
 fn bench_bar_projection_10k(c: &mut Criterion) {
     let viewport = Viewport::new(1920, 1080);
     let time_scale = TimeScale::new(0.0, 10_001.0).expect("valid time scale");
@@ -219,6 +259,16 @@ fn bench_visible_window_points_100k(c: &mut Criterion) {
                 points_in_time_window(black_box(&points), black_box(45_000.0), black_box(55_000.0));
         })
     });
+
+    c.bench_function("visible_window_points_sorted_slice_100k", |b| {
+        b.iter(|| {
+            let _ = points_in_sorted_time_window(
+                black_box(&points),
+                black_box(45_000.0),
+                black_box(55_000.0),
+            );
+        })
+    });
 }
 
 fn bench_marker_placement_5k(c: &mut Criterion) {
@@ -473,6 +523,51 @@ fn bench_crosshair_modes_pointer_move(c: &mut Criterion) {
     });
 }
 
+/// Magnet pointer moves over a fixed 1k-bar view while the series grows 100x;
+/// the snap search is windowed binary search, so timings should stay flat.
+fn bench_crosshair_snap_pointer_move_scaling(c: &mut Criterion) {
+    /// Samples appended one by one at the live edge; `set_data` drops
+    /// non-finite values, so whitespace only enters through appends.
+    const APPENDED_TAIL: u32 = 1_000;
+    /// Whitespace run under the pointer, inside the snap skip limit.
+    const WHITESPACE_RUN: u32 = 48;
+
+    let mut group = c.benchmark_group("crosshair_snap_pointer_move");
+    for len in [10_000_u32, 100_000, 1_000_000] {
+        let value = |t: f64| 1_000.0 + (t * 0.01).sin() * 100.0;
+        let tail_start = len - APPENDED_TAIL;
+        let run_start = len - APPENDED_TAIL / 2 - WHITESPACE_RUN / 2;
+        let points: Vec<DataPoint> = (0..tail_start)
+            .map(|i| DataPoint::new(f64::from(i), value(f64::from(i))))
+            .collect();
+        let end = f64::from(len);
+        let mut engine = ChartEngine::new(
+            NullRenderer::default(),
+            ChartEngineConfig::new(Viewport::new(1600, 900), end - 1_000.0, end)
+                .with_price_domain(0.0, 2_000.0),
+        )
+        .expect("engine init");
+        engine.set_data(points);
+        for i in tail_start..len {
+            let t = f64::from(i);
+            let whitespace = i % 97 == 0 || (run_start..run_start + WHITESPACE_RUN).contains(&i);
+            let y = if whitespace { f64::NAN } else { value(t) };
+            engine.append_point(DataPoint::new(t, y)).expect("append");
+        }
+        engine.set_crosshair_mode(CrosshairMode::Magnet);
+        let pointer_x = engine
+            .map_x_to_pixel(f64::from(run_start + WHITESPACE_RUN / 2))
+            .expect("pointer x");
+
+        group.bench_with_input(BenchmarkId::from_parameter(len), &len, |b, _| {
+            b.iter(|| {
+                engine.pointer_move(black_box(pointer_x), black_box(300.0));
+            })
+        });
+    }
+    group.finish();
+}
+
 fn bench_crosshair_render_lines(c: &mut Criterion) {
     let points: Vec<DataPoint> = (0..5_000)
         .map(|i| {
@@ -508,6 +603,36 @@ fn bench_crosshair_render_lines(c: &mut Criterion) {
     });
 }
 
+fn bench_render_frame_buffer_recycling(c: &mut Criterion) {
+    let points: Vec<DataPoint> = (0..5_000)
+        .map(|i| {
+            let t = i as f64;
+            DataPoint::new(t, 1_000.0 + (t * 0.01).sin() * 100.0)
+        })
+        .collect();
+
+    let mut engine = ChartEngine::new(
+        NullRenderer::default(),
+        ChartEngineConfig::new(Viewport::new(1600, 900), 0.0, 5_000.0)
+            .with_price_domain(0.0, 2_000.0),
+    )
+    .expect("engine init");
+    engine.set_data(points);
+    engine.pointer_move(800.0, 320.0);
+
+    c.bench_function("render_frame_rebuild_dropped", |b| {
+        b.iter(|| {
+            let _ = engine.build_render_frame().expect("build render frame");
+        })
+    });
+    c.bench_function("render_frame_rebuild_recycled", |b| {
+        b.iter(|| {
+            let frame = engine.build_render_frame().expect("build render frame");
+            engine.recycle_render_frame(black_box(frame));
+        })
+    });
+}
+
 fn bench_crosshair_render_line_style_per_axis(c: &mut Criterion) {
     let points: Vec<DataPoint> = (0..5_000)
         .map(|i| {
@@ -764,12 +889,12 @@ fn bench_crosshair_axis_label_text_transform_per_axis_render(c: &mut Criterion)
             show_price_axis_labels: false,
             show_crosshair_time_label_box: false,
             show_crosshair_price_label_box: false,
-            crosshair_label_prefix: "S:",
-            crosshair_label_suffix: ":S",
-            crosshair_time_label_prefix: Some("T:"),
-            crosshair_time_label_suffix: Some(":T"),
-            crosshair_price_label_prefix: Some("P:"),
-            crosshair_price_label_suffix: Some(":P"),
+            crosshair_label_prefix: "S:".into(),
+            crosshair_label_suffix: ":S".into(),
+            crosshair_time_label_prefix: Some("T:".into()),
+            crosshair_time_label_suffix: Some(":T".into()),
+            crosshair_price_label_prefix: Some("P:".into()),
+            crosshair_price_label_suffix: Some(":P".into()),
             ..engine.render_style()
         })
         .expect("set style");
@@ -2076,6 +2201,8 @@ fn bench_time_axis_session_timezone_formatter(c: &mut Criterion) {
                 end_hour: 16,
                 end_minute: 0,
             }),
+            time_unit: TimeAxisTimeUnit::Seconds,
+            anchor_ticks_to_nice_steps: false,
         })
         .expect("set session+timezone policy");
 
@@ -2106,11 +2233,19 @@ fn bench_render_major_time_tick_styling(c: &mut Criterion) {
                 end_hour: 16,
                 end_minute: 0,
             }),
+            time_unit: TimeAxisTimeUnit::Seconds,
+            anchor_ticks_to_nice_steps: false,
         })
         .expect("set session+timezone policy");
     engine
         .set_render_style(RenderStyle {
-            major_grid_line_width: 2.0,
+            grid: GridOptions {
+                major_vert_lines: GridLineOptions {
+                    width: 2.0,
+                    ..engine.render_style().grid.major_vert_lines
+                },
+                ..engine.render_style().grid
+            },
             major_time_label_font_size_px: 14.0,
             ..engine.render_style()
         })
@@ -2143,12 +2278,20 @@ fn bench_major_time_grid_lines_hidden_render(c: &mut Criterion) {
                 end_hour: 16,
                 end_minute: 0,
             }),
+            time_unit: TimeAxisTimeUnit::Seconds,
+            anchor_ticks_to_nice_steps: false,
         })
         .expect("set session+timezone policy");
     engine
         .set_render_style(RenderStyle {
-            show_major_time_grid_lines: false,
-            major_grid_line_width: 2.0,
+            grid: GridOptions {
+                major_vert_lines: GridLineOptions {
+                    visible: false,
+                    width: 2.0,
+                    ..engine.render_style().grid.major_vert_lines
+                },
+                ..engine.render_style().grid
+            },
             major_time_label_font_size_px: 14.0,
             ..engine.render_style()
         })
@@ -2181,6 +2324,8 @@ fn bench_major_time_label_color_render(c: &mut Criterion) {
                 end_hour: 16,
                 end_minute: 0,
             }),
+            time_unit: TimeAxisTimeUnit::Seconds,
+            anchor_ticks_to_nice_steps: false,
         })
         .expect("set session+timezone policy");
     engine
@@ -2218,6 +2363,8 @@ fn bench_major_time_label_offset_render(c: &mut Criterion) {
                 end_hour: 16,
                 end_minute: 0,
             }),
+            time_unit: TimeAxisTimeUnit::Seconds,
+            anchor_ticks_to_nice_steps: false,
         })
         .expect("set session+timezone policy");
     engine
@@ -2256,6 +2403,8 @@ fn bench_major_time_tick_mark_style_render(c: &mut Criterion) {
                 end_hour: 16,
                 end_minute: 0,
             }),
+            time_unit: TimeAxisTimeUnit::Seconds,
+            anchor_ticks_to_nice_steps: false,
         })
         .expect("set session+timezone policy");
     engine
@@ -2297,6 +2446,8 @@ fn bench_major_time_tick_marks_hidden_render(c: &mut Criterion) {
                 end_hour: 16,
                 end_minute: 0,
             }),
+            time_unit: TimeAxisTimeUnit::Seconds,
+            anchor_ticks_to_nice_steps: false,
         })
         .expect("set session+timezone policy");
     engine
@@ -2355,6 +2506,9 @@ fn bench_price_axis_percentage_display(c: &mut Criterion) {
             display_mode: PriceAxisDisplayMode::Percentage {
                 base_price: Some(100.0),
             },
+            secondary_label: PriceAxisSecondaryLabel::Hidden,
+            align_ticks_to_min_move: false,
+            anchor_ticks_to_nice_steps: false,
         })
         .expect("set percentage display");
 
@@ -2474,6 +2628,9 @@ fn make_price_axis_fallback_bench_engine(
             locale,
             policy: PriceAxisLabelPolicy::FixedDecimals { precision: 2 },
             display_mode,
+            secondary_label: PriceAxisSecondaryLabel::Hidden,
+            align_ticks_to_min_move: false,
+            anchor_ticks_to_nice_steps: false,
         })
         .expect("set fallback display mode");
     engine
@@ -3007,7 +3164,13 @@ fn bench_price_axis_grid_lines_hidden_render(c: &mut Criterion) {
         .set_render_style(RenderStyle {
             show_last_price_line: true,
             show_last_price_label: true,
-            show_price_axis_grid_lines: false,
+            grid: GridOptions {
+                horz_lines: GridLineOptions {
+                    visible: false,
+                    ..engine.render_style().grid.horz_lines
+                },
+                ..engine.render_style().grid
+            },
             ..engine.render_style()
         })
         .expect("set style");
@@ -3069,9 +3232,15 @@ fn bench_price_axis_grid_line_style_render(c: &mut Criterion) {
         .set_render_style(RenderStyle {
             show_last_price_line: true,
             show_last_price_label: true,
-            show_price_axis_grid_lines: true,
-            price_axis_grid_line_color: Color::rgb(0.22, 0.64, 0.89),
-            price_axis_grid_line_width: 1.75,
+            grid: GridOptions {
+                horz_lines: GridLineOptions {
+                    visible: true,
+                    color: Color::rgb(0.22, 0.64, 0.89),
+                    width: 1.75,
+                    ..engine.render_style().grid.horz_lines
+                },
+                ..engine.render_style().grid
+            },
             ..engine.render_style()
         })
         .expect("set style");
@@ -3177,6 +3346,8 @@ fn bench_major_time_axis_labels_hidden_render(c: &mut Criterion) {
                 end_hour: 16,
                 end_minute: 0,
             }),
+            time_unit: TimeAxisTimeUnit::Seconds,
+            anchor_ticks_to_nice_steps: false,
         })
         .expect("set session/time-axis label config");
     engine
@@ -3345,15 +3516,18 @@ criterion_group!(
     benches,
     bench_linear_scale_round_trip,
     bench_candle_projection_10k,
+    bench_candle_projection_parallel_crossover,
     bench_bar_projection_10k,
     bench_line_projection_20k,
     bench_area_projection_20k,
     bench_baseline_projection_20k,
     bench_histogram_projection_20k,
     bench_visible_window_points_100k,
+    bench_render_frame_buffer_recycling,
     bench_marker_placement_5k,
     bench_plugin_dispatch_pointer_move,
     bench_crosshair_modes_pointer_move,
+    bench_crosshair_snap_pointer_move_scaling,
     bench_crosshair_render_lines,
     bench_crosshair_render_line_style_per_axis,
     bench_crosshair_render_line_color_per_axis,
@@ -9,7 +9,8 @@ fn main() {
     use gtk4 as gtk;
     use gtk4::prelude::*;
 
-    use gtk_binance_support::{build_engine_with_binance_candles, install_default_interaction};
+    use chart_rs::platform_gtk::InteractionOptions;
+    use gtk_binance_support::build_engine_with_binance_candles;
 
     let app = gtk::Application::builder()
         .application_id("rs.chart.examples.binance.axis-interactions")
@@ -24,37 +25,13 @@ fn main() {
             }
         };
         let adapter = Rc::new(chart_rs::platform_gtk::GtkChartAdapter::new(engine));
-        install_default_interaction(Rc::clone(&adapter));
+        adapter.enable_default_interactions(InteractionOptions::default());
 
         let info = gtk::Label::new(Some(
             "Drag right axis: scale price | Drag bottom axis: scale time | Double click axis: reset",
         ));
         info.set_xalign(0.0);
 
-        let click = gtk::GestureClick::new();
-        {
-            let adapter = Rc::clone(&adapter);
-            click.connect_pressed(move |_, n_press, x, y| {
-                if n_press != 2 {
-                    return;
-                }
-                let _ = adapter.update_engine(|engine| {
-                    let style = engine.render_style();
-                    let viewport = engine.viewport();
-                    let plot_right = viewport.width as f64 - style.price_axis_width_px;
-                    let plot_bottom = viewport.height as f64 - style.time_axis_height_px;
-
-                    if x >= plot_right {
-                        let _ = engine.axis_double_click_reset_price_scale()?;
-                    } else if y >= plot_bottom {
-                        let _ = engine.axis_double_click_reset_time_scale()?;
-                    }
-                    Ok(())
-                });
-            });
-        }
-        adapter.drawing_area().add_controller(click);
-
         let layout = gtk::Box::new(gtk::Orientation::Vertical, 6);
         layout.append(adapter.drawing_area());
         layout.append(&info);
@@ -10,7 +10,8 @@ fn main() {
     use gtk4::gdk;
     use gtk4::prelude::*;
 
-    use gtk_binance_support::{build_engine_with_binance_candles, install_default_interaction};
+    use chart_rs::platform_gtk::InteractionOptions;
+    use gtk_binance_support::build_engine_with_binance_candles;
 
     let app = gtk::Application::builder()
         .application_id("rs.chart.examples.binance.crosshair")
@@ -26,7 +27,7 @@ fn main() {
         };
 
         let adapter = Rc::new(chart_rs::platform_gtk::GtkChartAdapter::new(engine));
-        install_default_interaction(Rc::clone(&adapter));
+        adapter.enable_default_interactions(InteractionOptions::default());
 
         let info = gtk::Label::new(Some(
             "Crosshair mode: Magnet (keys: M=Magnet, N=Normal, H=Hidden)",
@@ -10,9 +10,9 @@ fn main() {
     use gtk4::glib;
     use gtk4::prelude::*;
 
+    use chart_rs::platform_gtk::InteractionOptions;
     use gtk_binance_support::{
-        build_engine_with_binance_candles, fetch_binance_klines, install_default_interaction,
-        klines_to_ohlc,
+        build_engine_with_binance_candles, fetch_binance_klines, klines_to_ohlc,
     };
 
     let app = gtk::Application::builder()
@@ -29,7 +29,7 @@ fn main() {
         };
 
         let adapter = Rc::new(chart_rs::platform_gtk::GtkChartAdapter::new(engine));
-        install_default_interaction(Rc::clone(&adapter));
+        adapter.enable_default_interactions(InteractionOptions::default());
 
         let status = gtk::Label::new(Some("Polling Binance every 3s..."));
         status.set_xalign(0.0);
@@ -9,7 +9,8 @@ fn main() {
     use gtk4 as gtk;
     use gtk4::prelude::*;
 
-    use gtk_binance_support::{build_engine_with_binance_candles, install_default_interaction};
+    use chart_rs::platform_gtk::InteractionOptions;
+    use gtk_binance_support::build_engine_with_binance_candles;
 
     let app = gtk::Application::builder()
         .application_id("rs.chart.examples.binance.logical-inspector")
@@ -25,7 +26,7 @@ fn main() {
         };
 
         let adapter = Rc::new(chart_rs::platform_gtk::GtkChartAdapter::new(engine));
-        install_default_interaction(Rc::clone(&adapter));
+        adapter.enable_default_interactions(InteractionOptions::default());
 
         let info = gtk::Label::new(Some("move pointer to inspect nearest filled logical slot"));
         info.set_xalign(0.0);
@@ -9,9 +9,8 @@ fn main() {
     use gtk4 as gtk;
     use gtk4::prelude::*;
 
-    use gtk_binance_support::{
-        fetch_binance_klines, install_default_interaction, klines_to_ohlc, klines_to_volume_points,
-    };
+    use chart_rs::platform_gtk::InteractionOptions;
+    use gtk_binance_support::{fetch_binance_klines, klines_to_ohlc, klines_to_volume_points};
 
     let app = gtk::Application::builder()
         .application_id("rs.chart.examples.binance.multi-pane")
@@ -76,7 +75,7 @@ fn main() {
         let _ = engine.fit_time_to_data(chart_rs::core::TimeScaleTuning::default());
 
         let adapter = Rc::new(chart_rs::platform_gtk::GtkChartAdapter::new(engine));
-        install_default_interaction(Rc::clone(&adapter));
+        adapter.enable_default_interactions(InteractionOptions::default());
 
         let window = gtk::ApplicationWindow::builder()
             .application(app)
@@ -9,9 +9,9 @@ fn main() {
     use gtk4 as gtk;
     use gtk4::prelude::*;
 
+    use chart_rs::platform_gtk::InteractionOptions;
     use gtk_binance_support::{
-        build_engine_with_binance_candles, fetch_binance_klines, install_default_interaction,
-        klines_to_ohlc,
+        build_engine_with_binance_candles, fetch_binance_klines, klines_to_ohlc,
     };
 
     let app = gtk::Application::builder()
@@ -27,7 +27,7 @@ fn main() {
             }
         };
         let adapter = Rc::new(chart_rs::platform_gtk::GtkChartAdapter::new(engine));
-        install_default_interaction(Rc::clone(&adapter));
+        adapter.enable_default_interactions(InteractionOptions::default());
 
         let combo = gtk::DropDown::from_strings(&["BTCUSDT", "ETHUSDT", "BNBUSDT", "SOLUSDT"]);
         combo.set_selected(0);
@@ -1,15 +1,10 @@
 #![allow(dead_code)]
 
-use std::cell::RefCell;
-use std::rc::Rc;
 use std::time::Duration;
 
 use chart_rs::api::{ChartEngine, ChartEngineConfig};
 use chart_rs::core::{DataPoint, OhlcBar, TimeScaleTuning, Viewport};
-use chart_rs::platform_gtk::GtkChartAdapter;
 use chart_rs::render::CairoRenderer;
-use gtk4 as gtk;
-use gtk4::prelude::*;
 
 #[derive(Debug, Clone)]
 pub struct BinanceKline {
@@ -135,138 +130,3 @@ pub fn build_engine_with_binance_candles(
         .map_err(|e| format!("fit time: {e}"))?;
     Ok(engine)
 }
-
-pub fn install_default_interaction(adapter: Rc<GtkChartAdapter<CairoRenderer>>) {
-    let area = adapter.drawing_area().clone();
-
-    let motion = gtk::EventControllerMotion::new();
-    {
-        let adapter = Rc::clone(&adapter);
-        motion.connect_motion(move |_, x, y| {
-            let _ = adapter.update_engine(|engine| {
-                engine.pointer_move(x, y);
-                Ok(())
-            });
-        });
-    }
-    {
-        let adapter = Rc::clone(&adapter);
-        motion.connect_leave(move |_| {
-            let _ = adapter.update_engine(|engine| {
-                engine.pointer_leave();
-                Ok(())
-            });
-        });
-    }
-    area.add_controller(motion);
-
-    #[derive(Clone, Copy, Debug)]
-    enum DragRegion {
-        Plot,
-        PriceAxis,
-        TimeAxis,
-    }
-
-    #[derive(Clone, Copy, Debug)]
-    struct DragState {
-        region: DragRegion,
-        last_dx: f64,
-        last_dy: f64,
-    }
-
-    let drag_state = Rc::new(RefCell::new(DragState {
-        region: DragRegion::Plot,
-        last_dx: 0.0,
-        last_dy: 0.0,
-    }));
-
-    let drag = gtk::GestureDrag::new();
-    {
-        let adapter = Rc::clone(&adapter);
-        let drag_state = Rc::clone(&drag_state);
-        drag.connect_drag_begin(move |_, start_x, start_y| {
-            let region = {
-                let engine_rc = adapter.engine();
-                let engine = engine_rc.borrow();
-                let viewport = engine.viewport();
-                let style = engine.render_style();
-                let plot_right = viewport.width as f64 - style.price_axis_width_px;
-                let plot_bottom = viewport.height as f64 - style.time_axis_height_px;
-                if start_x >= plot_right {
-                    DragRegion::PriceAxis
-                } else if start_y >= plot_bottom {
-                    DragRegion::TimeAxis
-                } else {
-                    DragRegion::Plot
-                }
-            };
-            *drag_state.borrow_mut() = DragState {
-                region,
-                last_dx: 0.0,
-                last_dy: 0.0,
-            };
-            let _ = adapter.update_engine(|engine| {
-                engine.pan_start();
-                Ok(())
-            });
-        });
-    }
-    {
-        let adapter = Rc::clone(&adapter);
-        let drag_state = Rc::clone(&drag_state);
-        drag.connect_drag_update(move |_, offset_x, offset_y| {
-            let mut state = drag_state.borrow_mut();
-            let delta_x = offset_x - state.last_dx;
-            let delta_y = offset_y - state.last_dy;
-            state.last_dx = offset_x;
-            state.last_dy = offset_y;
-
-            let _ = adapter.update_engine(|engine| match state.region {
-                DragRegion::Plot => engine.pan_time_visible_by_pixels(delta_x),
-                DragRegion::PriceAxis => {
-                    let anchor_y = engine.viewport().height as f64 * 0.5;
-                    let _ = engine.axis_drag_scale_price(delta_y, anchor_y, 0.16, 0.000_000_1)?;
-                    Ok(())
-                }
-                DragRegion::TimeAxis => {
-                    let anchor_x = engine.viewport().width as f64 * 0.5;
-                    let _ = engine.axis_drag_scale_time(delta_x, anchor_x, 0.16, 1.0)?;
-                    Ok(())
-                }
-            });
-        });
-    }
-    {
-        let adapter = Rc::clone(&adapter);
-        drag.connect_drag_end(move |_, _, _| {
-            let _ = adapter.update_engine(|engine| {
-                engine.pan_end();
-                Ok(())
-            });
-        });
-    }
-    area.add_controller(drag);
-
-    let scroll = gtk::EventControllerScroll::new(
-        gtk::EventControllerScrollFlags::VERTICAL | gtk::EventControllerScrollFlags::HORIZONTAL,
-    );
-    {
-        let adapter = Rc::clone(&adapter);
-        scroll.connect_scroll(move |_, dx, dy| {
-            let _ = adapter.update_engine(|engine| {
-                if dy != 0.0 {
-                    let wheel_delta = dy * 120.0;
-                    let anchor_px = engine.viewport().width as f64 * 0.5;
-                    let _ = engine.wheel_zoom_time_visible(wheel_delta, anchor_px, 0.12, 1.0)?;
-                }
-                if dx != 0.0 {
-                    let wheel_delta = dx * 120.0;
-                    let _ = engine.wheel_pan_time_visible(wheel_delta, 0.16)?;
-                }
-                Ok(())
-            });
-            gtk::glib::Propagation::Stop
-        });
-    }
-    area.add_controller(scroll);
-}
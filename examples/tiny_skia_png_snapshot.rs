@@ -0,0 +1,34 @@
+#[cfg(feature = "tiny-skia-backend")]
+fn main() {
+    use chart_rs::api::{ChartEngine, ChartEngineConfig};
+    use chart_rs::core::Viewport;
+    use chart_rs::data::sim::{RandomWalkConfig, simulate_candles};
+    use chart_rs::render::TinySkiaRenderer;
+
+    let output = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "tiny_skia_snapshot.png".to_owned());
+    let run = || -> chart_rs::ChartResult<()> {
+        let bars = simulate_candles(
+            RandomWalkConfig::default().with_volatility(0.002),
+            60.0,
+            200,
+        )?;
+        let (first, last) = (bars[0].time, bars[bars.len() - 1].time);
+        let config = ChartEngineConfig::new(Viewport::new(960, 540), first, last);
+        let mut engine = ChartEngine::new(TinySkiaRenderer::new(960, 540)?, config)?;
+        engine.set_candles(bars);
+        engine.autoscale_price_from_candles()?;
+        engine.render()?;
+        engine.renderer().write_png(&output)
+    };
+    match run() {
+        Ok(()) => println!("wrote {output}"),
+        Err(err) => eprintln!("tiny-skia snapshot failed: {err}"),
+    }
+}
+
+#[cfg(not(feature = "tiny-skia-backend"))]
+fn main() {
+    println!("run with: cargo run --features tiny-skia-backend --example tiny_skia_png_snapshot");
+}
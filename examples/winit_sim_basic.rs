@@ -0,0 +1,34 @@
+#[cfg(feature = "winit-adapter")]
+fn main() {
+    use chart_rs::api::{ChartEngine, ChartEngineConfig, ViewportResizePolicy};
+    use chart_rs::core::Viewport;
+    use chart_rs::data::sim::{RandomWalkConfig, simulate_candles};
+    use chart_rs::platform_winit::{WinitChartApp, WinitChartOptions};
+    use chart_rs::render::TinySkiaRenderer;
+
+    let run = || -> chart_rs::ChartResult<()> {
+        let bars = simulate_candles(
+            RandomWalkConfig::default().with_volatility(0.002),
+            60.0,
+            400,
+        )?;
+        let (first, last) = (bars[0].time, bars[bars.len() - 1].time);
+        let config = ChartEngineConfig::new(Viewport::new(960, 540), first, last);
+        let mut engine = ChartEngine::new(TinySkiaRenderer::new(960, 540)?, config)?;
+        engine.set_candles(bars);
+        engine.autoscale_price_from_candles()?;
+
+        let options = WinitChartOptions::default()
+            .with_title("chart-rs winit (simulated candles)")
+            .with_resize_policy(ViewportResizePolicy::PreserveRightOffset);
+        WinitChartApp::new(engine, options).run().map(|_| ())
+    };
+    if let Err(err) = run() {
+        eprintln!("winit example failed: {err}");
+    }
+}
+
+#[cfg(not(feature = "winit-adapter"))]
+fn main() {
+    println!("run with: cargo run --features winit-adapter --example winit_sim_basic");
+}
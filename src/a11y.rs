@@ -0,0 +1,140 @@
+//! Screen-reader text for the current chart view.
+//!
+//! `summarize_view` collects the values a sighted user reads off the chart
+//! (visible range, last price, change, extremes, hovered bar) and
+//! `describe_view` renders them as one plain-text paragraph using the
+//! engine's own time/price label formatting, so custom formatters apply.
+
+use crate::api::{ChartEngine, nearest_by_time, percent_change};
+use crate::core::{DataPoint, OhlcBar};
+use crate::render::Renderer;
+
+/// Sample under the crosshair.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HoveredSample {
+    Candle(OhlcBar),
+    Point(DataPoint),
+}
+
+impl HoveredSample {
+    #[must_use]
+    pub fn time(self) -> f64 {
+        match self {
+            Self::Candle(bar) => bar.time,
+            Self::Point(point) => point.x,
+        }
+    }
+}
+
+/// Values behind an accessible description. Candles take precedence over
+/// points when both series are loaded, matching the last-price label.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ViewSummary {
+    pub visible_start: f64,
+    pub visible_end: f64,
+    /// Latest close/value in the series, visible or not.
+    pub last_price: Option<f64>,
+    /// Change from the first visible open/value to the last visible
+    /// close/value, in percent.
+    pub change_percent: Option<f64>,
+    pub visible_low: Option<f64>,
+    pub visible_high: Option<f64>,
+    pub hovered: Option<HoveredSample>,
+}
+
+#[must_use]
+pub fn summarize_view<R: Renderer>(engine: &ChartEngine<R>) -> ViewSummary {
+    let (visible_start, visible_end) = engine.time_visible_range();
+    let mut summary = ViewSummary {
+        visible_start,
+        visible_end,
+        last_price: None,
+        change_percent: None,
+        visible_low: None,
+        visible_high: None,
+        hovered: None,
+    };
+
+    let hovered_time = hovered_time(engine);
+    if !engine.candles().is_empty() {
+        let visible = engine.visible_candles_slice();
+        summary.last_price = engine.candles().last().map(|bar| bar.close);
+        if let (Some(first), Some(last)) = (visible.first(), visible.last()) {
+            summary.change_percent = percent_change(first.open, last.close);
+            summary.visible_low = visible.iter().map(|bar| bar.low).reduce(f64::min);
+            summary.visible_high = visible.iter().map(|bar| bar.high).reduce(f64::max);
+        }
+        summary.hovered = hovered_time
+            .and_then(|time| nearest_by_time(engine.candles(), time, |bar| bar.time))
+            .copied()
+            .map(HoveredSample::Candle);
+    } else if !engine.points().is_empty() {
+        let visible = engine.visible_points_slice();
+        summary.last_price = engine.points().last().map(|point| point.y);
+        if let (Some(first), Some(last)) = (visible.first(), visible.last()) {
+            summary.change_percent = percent_change(first.y, last.y);
+            summary.visible_low = visible.iter().map(|point| point.y).reduce(f64::min);
+            summary.visible_high = visible.iter().map(|point| point.y).reduce(f64::max);
+        }
+        summary.hovered = hovered_time
+            .and_then(|time| nearest_by_time(engine.points(), time, |point| point.x))
+            .copied()
+            .map(HoveredSample::Point);
+    }
+    summary
+}
+
+/// One-paragraph description of the current view, e.g. for a widget's
+/// accessible description.
+#[must_use]
+pub fn describe_view<R: Renderer>(engine: &ChartEngine<R>) -> String {
+    describe_summary(engine, &summarize_view(engine))
+}
+
+/// Renders `summary` with `engine`'s label formatting.
+#[must_use]
+pub fn describe_summary<R: Renderer>(engine: &ChartEngine<R>, summary: &ViewSummary) -> String {
+    let time = |value: f64| engine.format_time_for_description(value);
+    let price = |value: f64| engine.format_price_for_description(value);
+
+    let mut sentences = vec![format!(
+        "Showing {} to {}.",
+        time(summary.visible_start),
+        time(summary.visible_end)
+    )];
+    match summary.last_price {
+        Some(last) => sentences.push(format!("Last price {}.", price(last))),
+        None => sentences.push("No data.".to_owned()),
+    }
+    if let Some(change) = summary.change_percent {
+        sentences.push(format!("Change {change:+.2}% over the visible range."));
+    }
+    if let (Some(low), Some(high)) = (summary.visible_low, summary.visible_high) {
+        sentences.push(format!("Visible low {}, high {}.", price(low), price(high)));
+    }
+    match summary.hovered {
+        Some(HoveredSample::Candle(bar)) => sentences.push(format!(
+            "At {}: open {}, high {}, low {}, close {}.",
+            time(bar.time),
+            price(bar.open),
+            price(bar.high),
+            price(bar.low),
+            price(bar.close)
+        )),
+        Some(HoveredSample::Point(point)) => {
+            sentences.push(format!("At {}: value {}.", time(point.x), price(point.y)))
+        }
+        None => {}
+    }
+    sentences.join(" ")
+}
+
+fn hovered_time<R: Renderer>(engine: &ChartEngine<R>) -> Option<f64> {
+    let crosshair = engine.crosshair_state();
+    if !crosshair.visible {
+        return None;
+    }
+    crosshair
+        .snapped_time
+        .or_else(|| engine.map_pixel_to_x(crosshair.x).ok())
+}
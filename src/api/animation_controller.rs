@@ -0,0 +1,230 @@
+use crate::error::{ChartError, ChartResult};
+use crate::extensions::VisibleRangeChangeCause;
+use crate::render::Renderer;
+
+use super::ChartEngine;
+
+/// Duration of one crosshair snap-marker pulse.
+const CROSSHAIR_SNAP_MARKER_PULSE_SECONDS: f64 = 0.8;
+
+/// Eased visible-range transition advanced by `ChartEngine::step_animations`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(super) struct TimeRangeAnimation {
+    from: (f64, f64),
+    to: (f64, f64),
+    duration_seconds: f64,
+    elapsed_seconds: f64,
+    /// Return from an elastic overscroll; its frames lie past a fixed edge
+    /// and so bypass the edge clamp.
+    spring_back: bool,
+}
+
+impl TimeRangeAnimation {
+    /// Range at the current elapsed time (ease-out cubic) and whether the
+    /// animation finished.
+    fn sample(self) -> ((f64, f64), bool) {
+        let progress = (self.elapsed_seconds / self.duration_seconds).min(1.0);
+        if progress >= 1.0 {
+            return (self.to, true);
+        }
+        let eased = ease_out_cubic(progress);
+        let lerp = |from: f64, to: f64| from + (to - from) * eased;
+        (
+            (lerp(self.from.0, self.to.0), lerp(self.from.1, self.to.1)),
+            false,
+        )
+    }
+}
+
+pub(super) fn ease_out_cubic(progress: f64) -> f64 {
+    1.0 - (1.0 - progress).powi(3)
+}
+
+impl<R: Renderer> ChartEngine<R> {
+    /// Animates the visible time range to `[start, end]` over
+    /// `duration_seconds`, replacing a running range animation or kinetic
+    /// pan. A zero duration applies the range immediately.
+    ///
+    /// The transition only moves when the host advances it through
+    /// `step_animations`; starting a drag pan cancels it.
+    pub fn animate_time_visible_range(
+        &mut self,
+        start: f64,
+        end: f64,
+        duration_seconds: f64,
+    ) -> ChartResult<()> {
+        if !start.is_finite() || !end.is_finite() || start >= end {
+            return Err(ChartError::InvalidData(
+                "animated time range must be finite with start < end".to_owned(),
+            ));
+        }
+        if !duration_seconds.is_finite() || duration_seconds < 0.0 {
+            return Err(ChartError::InvalidData(
+                "animation duration must be finite and >= 0".to_owned(),
+            ));
+        }
+        self.stop_kinetic_pan();
+        self.core.runtime.time_range_animation = None;
+        if duration_seconds == 0.0 {
+            return self.set_time_visible_range(start, end);
+        }
+        self.core.runtime.time_range_animation = Some(TimeRangeAnimation {
+            from: self.core.model.time_scale.visible_range(),
+            to: (start, end),
+            duration_seconds,
+            elapsed_seconds: 0.0,
+            spring_back: false,
+        });
+        Ok(())
+    }
+
+    /// Eases an overscrolled range back to `target` after a drag pan ends.
+    pub(super) fn start_spring_back_animation(
+        &mut self,
+        target: (f64, f64),
+        duration_seconds: f64,
+    ) {
+        self.stop_kinetic_pan();
+        self.core.runtime.time_range_animation = Some(TimeRangeAnimation {
+            from: self.core.model.time_scale.visible_range(),
+            to: target,
+            duration_seconds,
+            elapsed_seconds: 0.0,
+            spring_back: true,
+        });
+    }
+
+    /// Jumps an interrupted spring-back to its edge target, so cancelling it
+    /// never strands the range past a fixed edge.
+    pub(super) fn finish_spring_back_animation(&mut self) {
+        if let Some(animation) = self
+            .core
+            .runtime
+            .time_range_animation
+            .take_if(|animation| animation.spring_back)
+        {
+            self.set_overscrolled_time_visible_range(animation.to);
+        }
+    }
+
+    pub(super) fn spring_back_animation_active(&self) -> bool {
+        self.core
+            .runtime
+            .time_range_animation
+            .is_some_and(|animation| animation.spring_back)
+    }
+
+    /// Moves the visible range without edge clamps, for overscroll frames.
+    pub(super) fn set_overscrolled_time_visible_range(&mut self, (start, end): (f64, f64)) {
+        // Overscroll ranges derive from a valid visible range, so they are
+        // finite and ordered.
+        if self
+            .core
+            .model
+            .time_scale
+            .set_visible_range(start, end)
+            .is_ok()
+        {
+            self.set_lwc_time_scale_invalidation_intent(
+                super::chart_runtime::LwcTimeScaleInvalidationIntent::ApplyRange,
+            );
+            self.emit_visible_range_changed();
+        }
+    }
+
+    #[must_use]
+    pub fn time_range_animation_active(&self) -> bool {
+        self.core.runtime.time_range_animation.is_some()
+    }
+
+    pub fn stop_time_range_animation(&mut self) {
+        self.core.runtime.time_range_animation = None;
+    }
+
+    #[must_use]
+    pub fn crosshair_snap_marker_pulse_active(&self) -> bool {
+        self.core
+            .runtime
+            .crosshair_snap_marker_pulse_elapsed
+            .is_some()
+    }
+
+    /// Pulse progress in `[0, 1)` while a snap-marker pulse runs.
+    pub(super) fn crosshair_snap_marker_pulse_progress(&self) -> Option<f64> {
+        self.core
+            .runtime
+            .crosshair_snap_marker_pulse_elapsed
+            .map(|elapsed| elapsed / CROSSHAIR_SNAP_MARKER_PULSE_SECONDS)
+    }
+
+    /// Restarts the snap-marker pulse after a realtime update when the style
+    /// asks for it and a snapped crosshair is showing.
+    pub(super) fn restart_crosshair_snap_marker_pulse(&mut self) {
+        let pulse = self
+            .core
+            .presentation
+            .render_style
+            .crosshair_snap_marker
+            .is_some_and(|marker| marker.pulse);
+        let crosshair = self.core.model.interaction.crosshair();
+        if pulse && crosshair.visible && crosshair.snapped_x.is_some() {
+            self.core.runtime.crosshair_snap_marker_pulse_elapsed = Some(0.0);
+            self.invalidate_cursor();
+        }
+    }
+
+    /// Whether kinetic pan, a range animation, a snap-marker pulse, a
+    /// last-value transition, replay playback or a debounced visible-range
+    /// notification still needs `step_animations` calls; frame-clock drivers
+    /// stop ticking once this turns `false`.
+    #[must_use]
+    pub fn has_active_animation(&self) -> bool {
+        self.kinetic_pan_state().active
+            || self.time_range_animation_active()
+            || self.crosshair_snap_marker_pulse_active()
+            || self.last_value_animation_active()
+            || self.replay_playing()
+            || self.visible_range_change_debounce_active()
+    }
+
+    /// Advances every running animation and replay playback by
+    /// `delta_seconds`.
+    ///
+    /// Returns `true` when the visible range moved or replay revealed bars; a
+    /// snap-marker pulse or last-value transition only invalidates drawing.
+    pub fn step_animations(&mut self, delta_seconds: f64) -> ChartResult<bool> {
+        if !delta_seconds.is_finite() || delta_seconds <= 0.0 {
+            return Err(ChartError::InvalidData(
+                "animation delta seconds must be finite and > 0".to_owned(),
+            ));
+        }
+        let mut moved = false;
+        if self.kinetic_pan_state().active {
+            moved |= self.step_kinetic_pan(delta_seconds)?;
+        }
+        if let Some(mut animation) = self.core.runtime.time_range_animation {
+            animation.elapsed_seconds += delta_seconds;
+            let ((start, end), finished) = animation.sample();
+            self.core.runtime.time_range_animation = (!finished).then_some(animation);
+            self.with_visible_range_change_cause(VisibleRangeChangeCause::Animation, |engine| {
+                if animation.spring_back {
+                    engine.set_overscrolled_time_visible_range((start, end));
+                    Ok(())
+                } else {
+                    engine.set_time_visible_range(start, end)
+                }
+            })?;
+            moved = true;
+        }
+        if let Some(elapsed) = self.core.runtime.crosshair_snap_marker_pulse_elapsed {
+            let elapsed = elapsed + delta_seconds;
+            self.core.runtime.crosshair_snap_marker_pulse_elapsed =
+                (elapsed < CROSSHAIR_SNAP_MARKER_PULSE_SECONDS).then_some(elapsed);
+            self.invalidate_cursor();
+        }
+        self.step_last_value_animations(delta_seconds);
+        self.step_visible_range_change_debounce(delta_seconds);
+        moved |= self.step_replay_playback(delta_seconds)?;
+        Ok(moved)
+    }
+}
@@ -0,0 +1,221 @@
+use std::io::Write;
+
+use crate::error::{ChartError, ChartResult};
+use crate::render::{Renderer, TinySkiaRenderer};
+
+use super::{ChartEngine, InteractionTrace, apply_interaction_action};
+
+/// Timing of an animated PNG written by `AnimatedPngRecorder`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AnimatedPngOptions {
+    /// Display time of every frame.
+    pub frame_delay_ms: u16,
+    /// Number of times the animation plays; `0` loops forever.
+    pub loop_count: u32,
+    /// Frames the recorder buffers at most; each one holds
+    /// `width * height * 4` bytes until encoding.
+    pub max_frames: usize,
+}
+
+impl Default for AnimatedPngOptions {
+    fn default() -> Self {
+        Self {
+            frame_delay_ms: 100,
+            loop_count: 0,
+            max_frames: 600,
+        }
+    }
+}
+
+/// Collects engine frames and encodes them as an animated PNG (APNG).
+///
+/// Frames are rasterized with an internal `TinySkiaRenderer`, so any engine
+/// can be captured regardless of its own renderer. All frames must share
+/// the viewport size of the first one, and at most
+/// `AnimatedPngOptions::max_frames` are kept.
+#[derive(Debug, Clone)]
+pub struct AnimatedPngRecorder {
+    options: AnimatedPngOptions,
+    renderer: TinySkiaRenderer,
+    size: Option<(u32, u32)>,
+    /// Straight-alpha RGBA rows of every captured frame.
+    frames: Vec<Vec<u8>>,
+}
+
+impl AnimatedPngRecorder {
+    pub fn new(options: AnimatedPngOptions) -> ChartResult<Self> {
+        if options.max_frames == 0 {
+            return Err(ChartError::InvalidData(
+                "animated png recorder must allow at least one frame".to_owned(),
+            ));
+        }
+        Ok(Self {
+            options,
+            renderer: TinySkiaRenderer::new(1, 1)?,
+            size: None,
+            frames: Vec::new(),
+        })
+    }
+
+    #[must_use]
+    pub fn options(&self) -> AnimatedPngOptions {
+        self.options
+    }
+
+    /// Rasterizer used for captures, for fonts and clear color; load a fixed
+    /// font for output that matches across machines.
+    pub fn renderer_mut(&mut self) -> &mut TinySkiaRenderer {
+        &mut self.renderer
+    }
+
+    #[must_use]
+    pub fn frame_count(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Frames that can still be captured before `max_frames` is reached.
+    #[must_use]
+    pub fn remaining_capacity(&self) -> usize {
+        self.options.max_frames.saturating_sub(self.frames.len())
+    }
+
+    /// Renders the engine's current frame and appends it; fails once
+    /// `max_frames` frames are buffered.
+    pub fn capture<R: Renderer>(&mut self, engine: &ChartEngine<R>) -> ChartResult<()> {
+        if self.remaining_capacity() == 0 {
+            return Err(ChartError::InvalidData(format!(
+                "animated png recorder is full ({} frames)",
+                self.options.max_frames
+            )));
+        }
+        let frame = engine.build_render_frame()?;
+        let size = (frame.viewport.width, frame.viewport.height);
+        if let Some(expected) = self.size
+            && expected != size
+        {
+            return Err(ChartError::InvalidData(format!(
+                "animation frame is {}x{}, expected {}x{}",
+                size.0, size.1, expected.0, expected.1
+            )));
+        }
+        let rendered = self.renderer.render(&frame);
+        engine.recycle_render_frame(frame);
+        rendered?;
+        let rgba = self
+            .renderer
+            .pixmap()
+            .pixels()
+            .iter()
+            .flat_map(|pixel| {
+                let color = pixel.demultiply();
+                [color.red(), color.green(), color.blue(), color.alpha()]
+            })
+            .collect();
+        self.size = Some(size);
+        self.frames.push(rgba);
+        Ok(())
+    }
+
+    /// Captures the current replay position, then steps the replay forward
+    /// by `bars_per_frame` and captures again until the hidden data runs out,
+    /// `max_frames` frames were taken, or the recorder is full. Returns the
+    /// number of captures.
+    pub fn capture_replay<R: Renderer>(
+        &mut self,
+        engine: &mut ChartEngine<R>,
+        bars_per_frame: usize,
+        max_frames: usize,
+    ) -> ChartResult<usize> {
+        if bars_per_frame == 0 {
+            return Err(ChartError::InvalidData(
+                "replay capture must advance at least one bar per frame".to_owned(),
+            ));
+        }
+        if engine.replay_cursor().is_none() {
+            return Err(ChartError::InvalidData(
+                "replay capture requires an active replay".to_owned(),
+            ));
+        }
+        let max_frames = max_frames.min(self.remaining_capacity());
+        let mut captured = 0;
+        while captured < max_frames {
+            if captured > 0 && engine.replay_step_forward(bars_per_frame)? == 0 {
+                break;
+            }
+            self.capture(engine)?;
+            captured += 1;
+        }
+        Ok(captured)
+    }
+
+    /// Replays scenario `scenario_index` of `trace` on a fresh engine from
+    /// `InteractionTrace::build_engine`, capturing the seeded state and the
+    /// state after every step. Returns the number of captures; a scenario
+    /// that does not fit the remaining capacity is rejected up front.
+    pub fn capture_trace_scenario(
+        &mut self,
+        trace: &InteractionTrace,
+        scenario_index: usize,
+    ) -> ChartResult<usize> {
+        let scenario = trace.scenarios.get(scenario_index).ok_or_else(|| {
+            ChartError::InvalidData(format!(
+                "interaction trace has no scenario at index {scenario_index}"
+            ))
+        })?;
+        if scenario.steps.len() + 1 > self.remaining_capacity() {
+            return Err(ChartError::InvalidData(format!(
+                "scenario needs {} frames, recorder has room for {}",
+                scenario.steps.len() + 1,
+                self.remaining_capacity()
+            )));
+        }
+        let mut engine = trace.build_engine()?;
+        if !scenario.points.is_empty() {
+            engine.set_data(scenario.data_points());
+        }
+        self.capture(&engine)?;
+        for step in &scenario.steps {
+            apply_interaction_action(&mut engine, step.action)?;
+            self.capture(&engine)?;
+        }
+        Ok(scenario.steps.len() + 1)
+    }
+
+    /// Encodes the captured frames as an APNG file in memory.
+    pub fn encode(&self) -> ChartResult<Vec<u8>> {
+        let mut bytes = Vec::new();
+        self.write_to(&mut bytes)?;
+        Ok(bytes)
+    }
+
+    /// Streams the APNG encoding of the captured frames into `out`; where
+    /// the bytes go (file, socket, buffer) is up to the caller.
+    pub fn write_to(&self, out: impl Write) -> ChartResult<()> {
+        let Some((width, height)) = self.size else {
+            return Err(ChartError::InvalidData(
+                "animated png needs at least one captured frame".to_owned(),
+            ));
+        };
+        let frame_count = u32::try_from(self.frames.len()).map_err(|_| {
+            ChartError::InvalidData("too many frames for an animated png".to_owned())
+        })?;
+        let mut encoder = png::Encoder::new(out, width, height);
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+        encoder
+            .set_animated(frame_count, self.options.loop_count)
+            .map_err(map_encoding_error)?;
+        encoder
+            .set_frame_delay(self.options.frame_delay_ms, 1000)
+            .map_err(map_encoding_error)?;
+        let mut writer = encoder.write_header().map_err(map_encoding_error)?;
+        for frame in &self.frames {
+            writer.write_image_data(frame).map_err(map_encoding_error)?;
+        }
+        writer.finish().map_err(map_encoding_error)
+    }
+}
+
+fn map_encoding_error(err: png::EncodingError) -> ChartError {
+    ChartError::InvalidData(format!("failed to encode animated png: {err}"))
+}
@@ -2,6 +2,7 @@ use crate::error::ChartResult;
 use crate::render::Renderer;
 
 use super::axis_layout_pass_resolver::resolve_axis_layout_pass;
+use super::axis_price_axis_auto_width_resolver::resolve_price_axis_auto_width;
 use super::axis_price_axis_relayout_pass_resolver::resolve_price_axis_relayout_pass;
 use super::axis_price_axis_relayout_resolver::should_relayout_price_axis_for_adaptive_width;
 use super::axis_requested_section_sizes_resolver::resolve_requested_axis_section_sizes;
@@ -11,13 +12,15 @@ use super::{ChartEngine, RenderStyle};
 impl<R: Renderer> ChartEngine<R> {
     pub(super) fn resolve_adaptive_axis_layout(
         &self,
-        style: RenderStyle,
+        style: &RenderStyle,
         viewport_width: f64,
         viewport_height: f64,
         visible_start: f64,
         visible_end: f64,
     ) -> ChartResult<AxisLayout> {
-        let requested_sections = resolve_requested_axis_section_sizes(style);
+        let previous_price_axis_width = self.core.runtime.resolved_price_axis_width_px.get();
+        let requested_sections =
+            resolve_requested_axis_section_sizes(style, previous_price_axis_width);
         let requested_price_axis_width = requested_sections.requested_price_axis_width;
         let requested_time_axis_height = requested_sections.requested_time_axis_height;
 
@@ -28,10 +31,14 @@ impl<R: Renderer> ChartEngine<R> {
             requested_time_axis_height,
         );
 
-        let adaptive_price_axis_width =
-            self.resolve_adaptive_price_axis_width(style, axis_layout, visible_start, visible_end)?;
+        let adaptive_price_axis_width = resolve_price_axis_auto_width(
+            style.price_axis_width_mode,
+            self.resolve_adaptive_price_axis_width(style, axis_layout, visible_start, visible_end)?,
+            previous_price_axis_width,
+        );
 
         if should_relayout_price_axis_for_adaptive_width(
+            style.price_axis_width_mode,
             adaptive_price_axis_width,
             requested_price_axis_width,
         ) {
@@ -43,6 +50,10 @@ impl<R: Renderer> ChartEngine<R> {
             );
         }
 
+        self.core
+            .runtime
+            .resolved_price_axis_width_px
+            .set(Some(axis_layout.price_axis_width));
         Ok(axis_layout)
     }
 }
@@ -7,7 +7,7 @@ use super::{ChartEngine, RenderStyle};
 impl<R: Renderer> ChartEngine<R> {
     pub(super) fn resolve_adaptive_price_axis_width(
         &self,
-        style: RenderStyle,
+        style: &RenderStyle,
         axis_layout: AxisLayout,
         visible_start: f64,
         visible_end: f64,
@@ -0,0 +1,81 @@
+use crate::render::{CanvasLayerKind, LinePrimitive, RectPrimitive};
+
+use super::RenderStyle;
+use super::axis_render_frame_builder::AxisPrimitiveSink;
+
+#[derive(Debug, Clone, Copy)]
+pub(super) struct AxisBackgroundContext<'a> {
+    pub plot_right: f64,
+    pub plot_bottom: f64,
+    pub viewport_width: f64,
+    pub viewport_height: f64,
+    pub style: &'a RenderStyle,
+}
+
+/// Emits axis strip fills and their shadow lines first in the axis layer, so
+/// borders, ticks and labels draw on top while plot overflow stays beneath.
+pub(super) fn append_axis_background_primitives(
+    sink: &mut AxisPrimitiveSink<'_>,
+    ctx: AxisBackgroundContext,
+) {
+    let style = ctx.style;
+    let price_strip_width = (ctx.viewport_width - ctx.plot_right).max(0.0);
+    let time_strip_height = (ctx.viewport_height - ctx.plot_bottom).max(0.0);
+
+    if let Some(color) = style.price_axis_background_color
+        && price_strip_width > 0.0
+    {
+        sink.push_rect(
+            CanvasLayerKind::Axis,
+            RectPrimitive::new(
+                ctx.plot_right,
+                0.0,
+                price_strip_width,
+                ctx.viewport_height,
+                color,
+            ),
+        );
+    }
+    if let Some(color) = style.time_axis_background_color
+        && time_strip_height > 0.0
+    {
+        sink.push_rect(
+            CanvasLayerKind::Axis,
+            RectPrimitive::new(
+                0.0,
+                ctx.plot_bottom,
+                ctx.plot_right,
+                time_strip_height,
+                color,
+            ),
+        );
+    }
+
+    let Some(shadow) = style.axis_background_shadow else {
+        return;
+    };
+    // Offset by half the width so the shadow sits inside the strip, next to
+    // the axis border line.
+    let offset = shadow.width_px * 0.5;
+    if style.price_axis_background_color.is_some() && price_strip_width > 0.0 {
+        let x = ctx.plot_right + offset;
+        sink.push_line(
+            CanvasLayerKind::Axis,
+            LinePrimitive::new(
+                x,
+                0.0,
+                x,
+                ctx.viewport_height,
+                shadow.width_px,
+                shadow.color,
+            ),
+        );
+    }
+    if style.time_axis_background_color.is_some() && time_strip_height > 0.0 {
+        let y = ctx.plot_bottom + offset;
+        sink.push_line(
+            CanvasLayerKind::Axis,
+            LinePrimitive::new(0.0, y, ctx.plot_right, y, shadow.width_px, shadow.color),
+        );
+    }
+}
@@ -16,6 +16,8 @@ pub enum TimeAxisLabelPolicy {
     LogicalDecimal { precision: u8 },
     /// Interpret logical values as unix timestamps and format in UTC.
     UtcDateTime { show_seconds: bool },
+    /// Interpret logical values as unix timestamps and format dates only.
+    UtcDate,
     /// Select UTC format detail based on current visible span (zoom level).
     #[default]
     UtcAdaptive,
@@ -48,6 +50,33 @@ impl TimeAxisTimeZone {
     }
 }
 
+/// Unit of logical time values interpreted by UTC-based time-axis policies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub enum TimeAxisTimeUnit {
+    #[default]
+    Seconds,
+    /// Millisecond timestamps; enables sub-second adaptive label patterns.
+    Milliseconds,
+}
+
+impl TimeAxisTimeUnit {
+    #[must_use]
+    pub(super) fn to_seconds(self, value: f64) -> f64 {
+        match self {
+            Self::Seconds => value,
+            Self::Milliseconds => value / 1_000.0,
+        }
+    }
+
+    #[must_use]
+    pub(super) fn seconds_to_unit(self, seconds: f64) -> f64 {
+        match self {
+            Self::Seconds => seconds,
+            Self::Milliseconds => seconds * 1_000.0,
+        }
+    }
+}
+
 /// Optional trading-session envelope used by time-axis labels.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct TimeAxisSessionConfig {
@@ -96,6 +125,11 @@ pub struct TimeAxisLabelConfig {
     pub policy: TimeAxisLabelPolicy,
     pub timezone: TimeAxisTimeZone,
     pub session: Option<TimeAxisSessionConfig>,
+    pub time_unit: TimeAxisTimeUnit,
+    /// Places ticks on multiples of a nice step (calendar steps in the active
+    /// timezone for UTC policies) instead of evenly dividing the visible
+    /// range, so labels stay on the same times while panning.
+    pub anchor_ticks_to_nice_steps: bool,
 }
 
 /// Built-in policy used for price-axis labels.
@@ -110,6 +144,16 @@ pub enum PriceAxisLabelPolicy {
     },
     /// Select precision from current visible price-step density.
     Adaptive,
+    /// Abbreviate thousands, millions, billions and trillions with
+    /// `K`/`M`/`B`/`T` (`1.2K`, `3.4M`, `12.5B`), rounded to
+    /// `significant_digits` with trailing zeros dropped.
+    Compact { significant_digits: u8 },
+    /// Round to `significant_digits` and write values in `notation`, for
+    /// prices many orders of magnitude below one.
+    Scientific {
+        significant_digits: u8,
+        notation: ScientificNotation,
+    },
 }
 
 impl Default for PriceAxisLabelPolicy {
@@ -118,6 +162,17 @@ impl Default for PriceAxisLabelPolicy {
     }
 }
 
+/// Notation of `PriceAxisLabelPolicy::Scientific` labels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub enum ScientificNotation {
+    /// Mantissa and power of ten for every non-zero value (`1.23e-6`).
+    #[default]
+    Exponent,
+    /// Plain decimals, with runs of four or more zeros after the decimal
+    /// separator collapsed into a subscript count (`0.0₅123`).
+    SubscriptZeros,
+}
+
 /// Display transform used for price-axis labels.
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default)]
 pub enum PriceAxisDisplayMode {
@@ -131,6 +186,17 @@ pub enum PriceAxisDisplayMode {
     },
 }
 
+/// Smaller second line drawn under each price-axis tick label.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default)]
+pub enum PriceAxisSecondaryLabel {
+    #[default]
+    Hidden,
+    /// Signed percent distance of the tick from the last price.
+    PercentFromLastPrice,
+    /// Signed percent distance of the tick from a fixed price.
+    PercentFromBase { base_price: f64 },
+}
+
 /// Runtime formatter configuration for the price axis.
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default)]
 #[serde(default)]
@@ -138,4 +204,13 @@ pub struct PriceAxisLabelConfig {
     pub locale: AxisLabelLocale,
     pub policy: PriceAxisLabelPolicy,
     pub display_mode: PriceAxisDisplayMode,
+    pub secondary_label: PriceAxisSecondaryLabel,
+    /// With a `MinMove` policy on a linear scale in `Normal` display, places
+    /// ticks only on multiples of `min_move` and snaps the free crosshair
+    /// price to that grid.
+    pub align_ticks_to_min_move: bool,
+    /// On non-log scales, places ticks on multiples of a nice 1/2/5 step
+    /// instead of evenly dividing the visible range, so gridlines stay on the
+    /// same prices while panning. `align_ticks_to_min_move` wins when it applies.
+    pub anchor_ticks_to_nice_steps: bool,
 }
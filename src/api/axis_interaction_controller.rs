@@ -0,0 +1,94 @@
+use crate::error::ChartResult;
+use crate::render::Renderer;
+
+use super::validation::validate_axis_interaction_config;
+use super::{AxisInteractionConfig, ChartAxis, ChartEngine};
+
+impl<R: Renderer> ChartEngine<R> {
+    #[must_use]
+    pub fn axis_interaction_config(&self) -> AxisInteractionConfig {
+        self.core.behavior.axis_interaction_config
+    }
+
+    pub fn set_axis_interaction_config(
+        &mut self,
+        config: AxisInteractionConfig,
+    ) -> ChartResult<()> {
+        validate_axis_interaction_config(config)?;
+        self.core.behavior.axis_interaction_config = config;
+        Ok(())
+    }
+
+    /// Scales `axis` by a drag of `delta_px` around `anchor_px` using the
+    /// configured sensitivity and minimum span.
+    ///
+    /// `delta_px` follows `axis_drag_scale_price` (vertical) or
+    /// `axis_drag_scale_time` (horizontal) unless `invert_direction` is set.
+    /// Returns the applied zoom factor (`1.0` while dragging is disabled).
+    pub fn handle_axis_drag(
+        &mut self,
+        axis: ChartAxis,
+        delta_px: f64,
+        anchor_px: f64,
+    ) -> ChartResult<f64> {
+        let options = self.core.behavior.axis_interaction_config.axis(axis);
+        if !options.drag_enabled {
+            return Ok(1.0);
+        }
+        let delta_px = if options.invert_direction {
+            -delta_px
+        } else {
+            delta_px
+        };
+        match axis {
+            ChartAxis::Price => self.axis_drag_scale_price(
+                delta_px,
+                anchor_px,
+                options.drag_sensitivity,
+                options.min_span,
+            ),
+            ChartAxis::Time => self.axis_drag_scale_time(
+                delta_px,
+                anchor_px,
+                options.drag_sensitivity,
+                options.min_span,
+            ),
+        }
+    }
+
+    /// Zooms `axis` by `wheel_delta` (`120` per notch, positive zooms out)
+    /// around `anchor_px` using the configured wheel sensitivity.
+    ///
+    /// Returns the applied zoom factor (`1.0` while wheel scaling is
+    /// disabled).
+    pub fn handle_axis_wheel(
+        &mut self,
+        axis: ChartAxis,
+        wheel_delta: f64,
+        anchor_px: f64,
+    ) -> ChartResult<f64> {
+        let options = self.core.behavior.axis_interaction_config.axis(axis);
+        if !options.wheel_enabled {
+            return Ok(1.0);
+        }
+        let wheel_delta = if options.invert_direction {
+            -wheel_delta
+        } else {
+            wheel_delta
+        };
+        match axis {
+            ChartAxis::Price => self.axis_drag_scale_price(
+                wheel_delta,
+                anchor_px,
+                options.wheel_sensitivity,
+                options.min_span,
+            ),
+            ChartAxis::Time => self.wheel_zoom_time_visible(
+                wheel_delta,
+                anchor_px,
+                options.wheel_sensitivity,
+                options.min_span,
+            ),
+        }
+    }
+}
@@ -1,9 +1,15 @@
+use chrono::format::{Fixed, Item, Numeric, StrftimeItems};
 use chrono::{DateTime, FixedOffset, Timelike, Utc};
 
+/// Visible spans at or below this many seconds use millisecond labels when the
+/// time unit is milliseconds.
+const SUB_SECOND_LABEL_SPAN_SECONDS: f64 = 10.0;
+
 use super::label_cache::TimeLabelPattern;
 use super::{
     AxisLabelLocale, PriceAxisDisplayMode, PriceAxisLabelConfig, PriceAxisLabelPolicy,
-    TimeAxisLabelConfig, TimeAxisLabelPolicy, TimeAxisSessionConfig,
+    ScientificNotation, TimeAxisLabelConfig, TimeAxisLabelPolicy, TimeAxisSessionConfig,
+    TimeAxisTimeUnit,
 };
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -13,10 +19,10 @@ pub(super) enum ResolvedTimeLabelPattern {
 }
 
 pub(super) fn resolve_time_label_pattern(
-    policy: TimeAxisLabelPolicy,
+    config: TimeAxisLabelConfig,
     visible_span_abs: f64,
 ) -> ResolvedTimeLabelPattern {
-    match policy {
+    match config.policy {
         TimeAxisLabelPolicy::LogicalDecimal { precision } => {
             ResolvedTimeLabelPattern::LogicalDecimal { precision }
         }
@@ -28,10 +34,18 @@ pub(super) fn resolve_time_label_pattern(
             };
             ResolvedTimeLabelPattern::Utc { pattern }
         }
+        TimeAxisLabelPolicy::UtcDate => ResolvedTimeLabelPattern::Utc {
+            pattern: TimeLabelPattern::Date,
+        },
         TimeAxisLabelPolicy::UtcAdaptive => {
-            let pattern = if visible_span_abs <= 600.0 {
+            let visible_span_seconds = config.time_unit.to_seconds(visible_span_abs);
+            let pattern = if config.time_unit == TimeAxisTimeUnit::Milliseconds
+                && visible_span_seconds <= SUB_SECOND_LABEL_SPAN_SECONDS
+            {
+                TimeLabelPattern::DateMillisecond
+            } else if visible_span_seconds <= 600.0 {
                 TimeLabelPattern::DateSecond
-            } else if visible_span_abs <= 172_800.0 {
+            } else if visible_span_seconds <= 172_800.0 {
                 TimeLabelPattern::DateMinute
             } else {
                 TimeLabelPattern::Date
@@ -78,31 +92,12 @@ pub(super) fn format_time_axis_label(
         return "nan".to_owned();
     }
 
-    match resolve_time_label_pattern(config.policy, visible_span_abs) {
+    match resolve_time_label_pattern(config, visible_span_abs) {
         ResolvedTimeLabelPattern::LogicalDecimal { precision } => {
             format_axis_decimal(logical_time, usize::from(precision), config.locale)
         }
         ResolvedTimeLabelPattern::Utc { pattern } => {
-            let seconds = logical_time.round() as i64;
-            let Some(dt) = DateTime::<Utc>::from_timestamp(seconds, 0) else {
-                return format_axis_decimal(logical_time, 2, config.locale);
-            };
-            let local_dt = dt.with_timezone(&config.timezone.fixed_offset());
-            let pattern = resolve_session_time_label_pattern(pattern, config.session, local_dt);
-
-            let pattern = match (config.locale, pattern) {
-                (AxisLabelLocale::EnUs, TimeLabelPattern::Date) => "%Y-%m-%d",
-                (AxisLabelLocale::EnUs, TimeLabelPattern::DateMinute) => "%Y-%m-%d %H:%M",
-                (AxisLabelLocale::EnUs, TimeLabelPattern::DateSecond) => "%Y-%m-%d %H:%M:%S",
-                (AxisLabelLocale::EnUs, TimeLabelPattern::TimeMinute) => "%H:%M",
-                (AxisLabelLocale::EnUs, TimeLabelPattern::TimeSecond) => "%H:%M:%S",
-                (AxisLabelLocale::EsEs, TimeLabelPattern::Date) => "%d/%m/%Y",
-                (AxisLabelLocale::EsEs, TimeLabelPattern::DateMinute) => "%d/%m/%Y %H:%M",
-                (AxisLabelLocale::EsEs, TimeLabelPattern::DateSecond) => "%d/%m/%Y %H:%M:%S",
-                (AxisLabelLocale::EsEs, TimeLabelPattern::TimeMinute) => "%H:%M",
-                (AxisLabelLocale::EsEs, TimeLabelPattern::TimeSecond) => "%H:%M:%S",
-            };
-            local_dt.format(pattern).to_string()
+            format_utc_time_label(logical_time, config, pattern)
         }
     }
 }
@@ -118,12 +113,7 @@ pub(super) fn format_time_axis_tick_label(
         return "nan".to_owned();
     }
 
-    match resolve_time_axis_tick_pattern(
-        config.policy,
-        visible_span_abs,
-        tick_step_abs,
-        is_major_tick,
-    ) {
+    match resolve_time_axis_tick_pattern(config, visible_span_abs, tick_step_abs, is_major_tick) {
         ResolvedTimeLabelPattern::LogicalDecimal { precision } => {
             format_axis_decimal(logical_time, usize::from(precision), config.locale)
         }
@@ -134,12 +124,12 @@ pub(super) fn format_time_axis_tick_label(
 }
 
 pub(super) fn resolve_time_axis_tick_pattern(
-    policy: TimeAxisLabelPolicy,
+    config: TimeAxisLabelConfig,
     visible_span_abs: f64,
     tick_step_abs: f64,
     is_major_tick: bool,
 ) -> ResolvedTimeLabelPattern {
-    match policy {
+    match config.policy {
         TimeAxisLabelPolicy::LogicalDecimal { precision } => {
             ResolvedTimeLabelPattern::LogicalDecimal { precision }
         }
@@ -151,9 +141,16 @@ pub(super) fn resolve_time_axis_tick_pattern(
             };
             ResolvedTimeLabelPattern::Utc { pattern }
         }
+        TimeAxisLabelPolicy::UtcDate => ResolvedTimeLabelPattern::Utc {
+            pattern: TimeLabelPattern::Date,
+        },
         TimeAxisLabelPolicy::UtcAdaptive => {
-            let pattern =
-                resolve_adaptive_tick_pattern(visible_span_abs, tick_step_abs.abs(), is_major_tick);
+            let pattern = resolve_adaptive_tick_pattern(
+                config.time_unit.to_seconds(visible_span_abs),
+                config.time_unit.to_seconds(tick_step_abs.abs()),
+                is_major_tick,
+                config.time_unit == TimeAxisTimeUnit::Milliseconds,
+            );
             ResolvedTimeLabelPattern::Utc { pattern }
         }
     }
@@ -171,6 +168,23 @@ pub(super) fn format_time_axis_label_with_precision(
     format_time_axis_label(logical_time, config, visible_span_abs)
 }
 
+/// Formats a date-only label; `None` for logical (non-timestamp) time axes.
+pub(super) fn format_time_axis_date_label(
+    logical_time: f64,
+    config: TimeAxisLabelConfig,
+) -> Option<String> {
+    if !logical_time.is_finite()
+        || matches!(config.policy, TimeAxisLabelPolicy::LogicalDecimal { .. })
+    {
+        return None;
+    }
+    Some(format_utc_time_label(
+        logical_time,
+        config,
+        TimeLabelPattern::Date,
+    ))
+}
+
 fn resolve_session_time_label_pattern(
     pattern: TimeLabelPattern,
     session: Option<TimeAxisSessionConfig>,
@@ -193,14 +207,18 @@ fn resolve_session_time_label_pattern(
     match pattern {
         TimeLabelPattern::DateMinute => TimeLabelPattern::TimeMinute,
         TimeLabelPattern::DateSecond => TimeLabelPattern::TimeSecond,
+        TimeLabelPattern::DateMillisecond => TimeLabelPattern::TimeMillisecond,
         other => other,
     }
 }
 
+/// Spans and steps are in seconds; `allow_sub_second` enables millisecond
+/// patterns for sub-second tick steps.
 fn resolve_adaptive_tick_pattern(
     visible_span_abs: f64,
     tick_step_abs: f64,
     is_major_tick: bool,
+    allow_sub_second: bool,
 ) -> TimeLabelPattern {
     let base = if visible_span_abs <= 600.0 {
         TimeLabelPattern::DateSecond
@@ -215,6 +233,13 @@ fn resolve_adaptive_tick_pattern(
         f64::INFINITY
     };
 
+    if allow_sub_second && step < 1.0 {
+        return if is_major_tick {
+            TimeLabelPattern::DateMillisecond
+        } else {
+            TimeLabelPattern::TimeMillisecond
+        };
+    }
     if is_major_tick {
         return if step < 60.0 {
             TimeLabelPattern::DateSecond
@@ -242,7 +267,10 @@ fn resolve_adaptive_tick_pattern(
                 TimeLabelPattern::Date
             }
         }
-        TimeLabelPattern::TimeMinute | TimeLabelPattern::TimeSecond => base,
+        TimeLabelPattern::DateMillisecond
+        | TimeLabelPattern::TimeMinute
+        | TimeLabelPattern::TimeSecond
+        | TimeLabelPattern::TimeMillisecond => base,
     }
 }
 
@@ -251,28 +279,75 @@ fn format_utc_time_label(
     config: TimeAxisLabelConfig,
     base_pattern: TimeLabelPattern,
 ) -> String {
-    let seconds = logical_time.round() as i64;
-    let Some(dt) = DateTime::<Utc>::from_timestamp(seconds, 0) else {
+    let Some(local_dt) = resolve_local_datetime(logical_time, config, base_pattern.is_sub_second())
+    else {
         return format_axis_decimal(logical_time, 2, config.locale);
     };
-    let local_dt = dt.with_timezone(&config.timezone.fixed_offset());
     let pattern = resolve_session_time_label_pattern(base_pattern, config.session, local_dt);
 
     let pattern = match (config.locale, pattern) {
         (AxisLabelLocale::EnUs, TimeLabelPattern::Date) => "%Y-%m-%d",
         (AxisLabelLocale::EnUs, TimeLabelPattern::DateMinute) => "%Y-%m-%d %H:%M",
         (AxisLabelLocale::EnUs, TimeLabelPattern::DateSecond) => "%Y-%m-%d %H:%M:%S",
+        (AxisLabelLocale::EnUs, TimeLabelPattern::DateMillisecond) => "%Y-%m-%d %H:%M:%S%.3f",
         (AxisLabelLocale::EnUs, TimeLabelPattern::TimeMinute) => "%H:%M",
         (AxisLabelLocale::EnUs, TimeLabelPattern::TimeSecond) => "%H:%M:%S",
+        (AxisLabelLocale::EnUs, TimeLabelPattern::TimeMillisecond) => "%H:%M:%S%.3f",
         (AxisLabelLocale::EsEs, TimeLabelPattern::Date) => "%d/%m/%Y",
         (AxisLabelLocale::EsEs, TimeLabelPattern::DateMinute) => "%d/%m/%Y %H:%M",
         (AxisLabelLocale::EsEs, TimeLabelPattern::DateSecond) => "%d/%m/%Y %H:%M:%S",
+        (AxisLabelLocale::EsEs, TimeLabelPattern::DateMillisecond) => "%d/%m/%Y %H:%M:%S%.3f",
         (AxisLabelLocale::EsEs, TimeLabelPattern::TimeMinute) => "%H:%M",
         (AxisLabelLocale::EsEs, TimeLabelPattern::TimeSecond) => "%H:%M:%S",
+        (AxisLabelLocale::EsEs, TimeLabelPattern::TimeMillisecond) => "%H:%M:%S%.3f",
     };
     local_dt.format(pattern).to_string()
 }
 
+/// Formats `logical_time` with a strftime `pattern` in the configured
+/// timezone; the pattern must already be validated.
+pub(super) fn format_time_with_pattern(
+    logical_time: f64,
+    config: TimeAxisLabelConfig,
+    pattern: &str,
+) -> String {
+    let millisecond_precision = StrftimeItems::new(pattern).any(|item| {
+        matches!(
+            item,
+            Item::Numeric(Numeric::Nanosecond, _)
+                | Item::Fixed(
+                    Fixed::Nanosecond
+                        | Fixed::Nanosecond3
+                        | Fixed::Nanosecond6
+                        | Fixed::Nanosecond9
+                        | Fixed::Internal(_)
+                )
+        )
+    });
+    match resolve_local_datetime(logical_time, config, millisecond_precision) {
+        Some(local_dt) => local_dt.format(pattern).to_string(),
+        None => format_axis_decimal(logical_time, 2, config.locale),
+    }
+}
+
+/// Converts a logical time into the configured timezone.
+///
+/// Whole-second labels keep rounding to the nearest second; millisecond
+/// precision is only used when the label actually shows milliseconds.
+fn resolve_local_datetime(
+    logical_time: f64,
+    config: TimeAxisLabelConfig,
+    millisecond_precision: bool,
+) -> Option<DateTime<FixedOffset>> {
+    let seconds = config.time_unit.to_seconds(logical_time);
+    let dt = if millisecond_precision {
+        DateTime::<Utc>::from_timestamp_millis((seconds * 1_000.0).round() as i64)?
+    } else {
+        DateTime::<Utc>::from_timestamp(seconds.round() as i64, 0)?
+    };
+    Some(dt.with_timezone(&config.timezone.fixed_offset()))
+}
+
 pub(super) fn is_major_time_tick(logical_time: f64, config: TimeAxisLabelConfig) -> bool {
     if !logical_time.is_finite() {
         return false;
@@ -281,11 +356,13 @@ pub(super) fn is_major_time_tick(logical_time: f64, config: TimeAxisLabelConfig)
         return false;
     }
 
-    let seconds = logical_time.round() as i64;
-    let Some(dt) = DateTime::<Utc>::from_timestamp(seconds, 0) else {
+    let millisecond_precision = config.time_unit == TimeAxisTimeUnit::Milliseconds;
+    let Some(local_dt) = resolve_local_datetime(logical_time, config, millisecond_precision) else {
         return false;
     };
-    let local_dt = dt.with_timezone(&config.timezone.fixed_offset());
+    if local_dt.nanosecond() != 0 {
+        return false;
+    }
     let minute_of_day = (local_dt.hour() * 60 + local_dt.minute()) as u16;
 
     if let Some(session) = config.session {
@@ -394,6 +471,13 @@ pub(super) fn format_price_axis_label(
             let precision = precision_from_step(nice_step);
             format_axis_decimal(value, precision, config.locale)
         }
+        PriceAxisLabelPolicy::Compact { significant_digits } => {
+            format_compact_price(value, significant_digits, config.locale)
+        }
+        PriceAxisLabelPolicy::Scientific {
+            significant_digits,
+            notation,
+        } => format_scientific_price(value, significant_digits, notation, config.locale),
     }
 }
 
@@ -412,6 +496,179 @@ pub(super) fn format_price_axis_label_with_precision(
     format_price_axis_label(value, config, tick_step_abs)
 }
 
+const RELATIVE_TIME_UNITS: [(f64, &str); 4] =
+    [(86_400.0, "d"), (3_600.0, "h"), (60.0, "m"), (1.0, "s")];
+
+/// Humanized `offset_seconds` (reference minus labeled time) in its largest
+/// non-zero unit plus the next smaller one when non-zero: `3h 24m ago`,
+/// `in 2d`, or `now` under a second.
+pub(super) fn format_relative_time_offset(offset_seconds: f64) -> String {
+    let total = offset_seconds.abs().round();
+    if !total.is_finite() || total < 1.0 {
+        return "now".to_owned();
+    }
+    let largest = RELATIVE_TIME_UNITS
+        .iter()
+        .position(|&(unit_seconds, _)| total >= unit_seconds)
+        .unwrap_or(RELATIVE_TIME_UNITS.len() - 1);
+    let (unit_seconds, unit) = RELATIVE_TIME_UNITS[largest];
+    let mut text = format!("{}{unit}", (total / unit_seconds).floor());
+    if let Some(&(next_seconds, next_unit)) = RELATIVE_TIME_UNITS.get(largest + 1) {
+        let next = ((total % unit_seconds) / next_seconds).floor();
+        if next > 0.0 {
+            text.push_str(&format!(" {next}{next_unit}"));
+        }
+    }
+    if offset_seconds > 0.0 {
+        format!("{text} ago")
+    } else {
+        format!("in {text}")
+    }
+}
+
+/// Clock-style time left in a bar: `MM:SS`, `H:MM:SS` from an hour and
+/// `Dd HH:MM:SS` from a day. Partial seconds round up so `00:00` shows only
+/// once the bar has closed.
+pub(super) fn format_bar_countdown(remaining_seconds: f64) -> String {
+    let total = if remaining_seconds.is_finite() {
+        remaining_seconds.max(0.0).ceil() as u64
+    } else {
+        0
+    };
+    let (days, hours) = (total / 86_400, total / 3_600 % 24);
+    let (minutes, seconds) = (total / 60 % 60, total % 60);
+    if days > 0 {
+        format!("{days}d {hours:02}:{minutes:02}:{seconds:02}")
+    } else if hours > 0 {
+        format!("{hours}:{minutes:02}:{seconds:02}")
+    } else {
+        format!("{minutes:02}:{seconds:02}")
+    }
+}
+
+/// Signed percent change with two decimals, e.g. `+1.25%` or `-0.50%`.
+pub(super) fn format_signed_percent(percent: f64, locale: AxisLabelLocale) -> String {
+    let text = format_axis_decimal(percent.abs(), 2, locale);
+    if text.bytes().all(|byte| matches!(byte, b'0' | b'.' | b',')) {
+        format!("{text}%")
+    } else if percent < 0.0 {
+        format!("-{text}%")
+    } else {
+        format!("+{text}%")
+    }
+}
+
+const COMPACT_SUFFIXES: [&str; 5] = ["", "K", "M", "B", "T"];
+
+/// Shortest run of zeros after the decimal separator that
+/// `ScientificNotation::SubscriptZeros` collapses.
+const SUBSCRIPT_MIN_ZEROS: i32 = 4;
+
+/// Significant digits (without sign or separator) and decimal exponent of
+/// `value` rounded to `significant_digits`, so `0.00000123` gives
+/// `("123", -6)`.
+fn significant_digits_and_exponent(value: f64, significant_digits: u8) -> (String, i32) {
+    let precision = usize::from(significant_digits.max(1) - 1);
+    let text = format!("{:.precision$e}", value.abs());
+    let (mantissa, exponent) = text.split_once('e').unwrap_or((&text, "0"));
+    (mantissa.replace('.', ""), exponent.parse().unwrap_or(0))
+}
+
+/// `digits` with the decimal separator after `integer_digits` of them,
+/// padding with zeros on either side and dropping trailing fraction zeros.
+fn place_decimal_separator(digits: &str, integer_digits: i32, locale: AxisLabelLocale) -> String {
+    let text = if integer_digits <= 0 {
+        format!(
+            "0.{}{digits}",
+            "0".repeat(integer_digits.unsigned_abs() as usize)
+        )
+    } else {
+        let split = integer_digits as usize;
+        if split >= digits.len() {
+            format!("{digits}{}", "0".repeat(split - digits.len()))
+        } else {
+            format!("{}.{}", &digits[..split], &digits[split..])
+        }
+    };
+    let text = match locale {
+        AxisLabelLocale::EnUs => text,
+        AxisLabelLocale::EsEs => text.replace('.', ","),
+    };
+    trim_axis_decimal(text, locale)
+}
+
+fn signed_label(value: f64, magnitude: String) -> String {
+    if value < 0.0
+        && magnitude
+            .bytes()
+            .any(|byte| byte.is_ascii_digit() && byte != b'0')
+    {
+        format!("-{magnitude}")
+    } else {
+        magnitude
+    }
+}
+
+fn format_compact_price(value: f64, significant_digits: u8, locale: AxisLabelLocale) -> String {
+    if value == 0.0 {
+        return "0".to_owned();
+    }
+    let (digits, exponent) = significant_digits_and_exponent(value, significant_digits);
+    let tier = exponent
+        .div_euclid(3)
+        .clamp(0, COMPACT_SUFFIXES.len() as i32 - 1);
+    let magnitude = place_decimal_separator(&digits, exponent - 3 * tier + 1, locale);
+    signed_label(
+        value,
+        format!("{magnitude}{}", COMPACT_SUFFIXES[tier as usize]),
+    )
+}
+
+fn format_scientific_price(
+    value: f64,
+    significant_digits: u8,
+    notation: ScientificNotation,
+    locale: AxisLabelLocale,
+) -> String {
+    if value == 0.0 {
+        return "0".to_owned();
+    }
+    let (digits, exponent) = significant_digits_and_exponent(value, significant_digits);
+    let magnitude = match notation {
+        ScientificNotation::Exponent => {
+            format!("{}e{exponent}", place_decimal_separator(&digits, 1, locale))
+        }
+        ScientificNotation::SubscriptZeros => {
+            let leading_zeros = -exponent - 1;
+            if leading_zeros >= SUBSCRIPT_MIN_ZEROS {
+                let fraction = digits.trim_end_matches('0');
+                let separator = match locale {
+                    AxisLabelLocale::EnUs => '.',
+                    AxisLabelLocale::EsEs => ',',
+                };
+                format!(
+                    "0{separator}0{}{fraction}",
+                    subscript_number(leading_zeros.unsigned_abs())
+                )
+            } else if exponent + 1 >= i32::from(significant_digits) {
+                // Never round away integer digits.
+                format_axis_decimal(value.abs(), 0, locale)
+            } else {
+                place_decimal_separator(&digits, exponent + 1, locale)
+            }
+        }
+    };
+    signed_label(value, magnitude)
+}
+
+fn subscript_number(value: u32) -> String {
+    value
+        .to_string()
+        .chars()
+        .map(|digit| char::from_u32(0x2080 + digit.to_digit(10).unwrap_or(0)).unwrap_or(digit))
+        .collect()
+}
+
 fn normalize_step_for_precision(step_abs: f64) -> f64 {
     if !step_abs.is_finite() || step_abs <= 0.0 {
         return 0.01;
@@ -481,3 +738,93 @@ fn format_axis_decimal(value: f64, precision: usize, locale: AxisLabelLocale) ->
         AxisLabelLocale::EsEs => text.replace('.', ","),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        AxisLabelLocale, format_bar_countdown, format_compact_price, format_relative_time_offset,
+        format_scientific_price, format_signed_percent,
+    };
+    use crate::api::ScientificNotation;
+
+    #[test]
+    fn compact_prices_use_thousand_tiers() {
+        let en = AxisLabelLocale::EnUs;
+        assert_eq!(format_compact_price(1_234.0, 2, en), "1.2K");
+        assert_eq!(format_compact_price(3_400_000.0, 3, en), "3.4M");
+        assert_eq!(format_compact_price(12_500_000_000.0, 3, en), "12.5B");
+        assert_eq!(format_compact_price(-2.5e12, 3, en), "-2.5T");
+        assert_eq!(format_compact_price(950.0, 3, en), "950");
+        assert_eq!(format_compact_price(0.126, 2, en), "0.13");
+        // Rounding carries into the next tier.
+        assert_eq!(format_compact_price(999_950.0, 3, en), "1M");
+        assert_eq!(
+            format_compact_price(1_234.0, 3, AxisLabelLocale::EsEs),
+            "1,23K"
+        );
+    }
+
+    #[test]
+    fn scientific_prices_use_exponent_or_subscript_zeros() {
+        let en = AxisLabelLocale::EnUs;
+        let exponent = ScientificNotation::Exponent;
+        let subscript = ScientificNotation::SubscriptZeros;
+        assert_eq!(
+            format_scientific_price(0.00000123, 3, exponent, en),
+            "1.23e-6"
+        );
+        assert_eq!(format_scientific_price(45_000.0, 3, exponent, en), "4.5e4");
+        assert_eq!(format_scientific_price(0.0, 3, exponent, en), "0");
+        assert_eq!(
+            format_scientific_price(0.00000123, 3, subscript, en),
+            "0.0₅123"
+        );
+        assert_eq!(
+            format_scientific_price(-0.000000000001234, 2, subscript, en),
+            "-0.0₁₁12"
+        );
+        assert_eq!(
+            format_scientific_price(0.000123, 3, subscript, en),
+            "0.000123"
+        );
+        assert_eq!(format_scientific_price(12_345.6, 3, subscript, en), "12346");
+        assert_eq!(
+            format_scientific_price(0.00000123, 3, subscript, AxisLabelLocale::EsEs),
+            "0,0₅123"
+        );
+    }
+
+    #[test]
+    fn relative_offsets_keep_the_two_largest_units() {
+        assert_eq!(
+            format_relative_time_offset(3.0 * 3_600.0 + 24.0 * 60.0 + 5.0),
+            "3h 24m ago"
+        );
+        assert_eq!(format_relative_time_offset(2.0 * 86_400.0 + 60.0), "2d ago");
+        assert_eq!(format_relative_time_offset(-45.4), "in 45s");
+        assert_eq!(format_relative_time_offset(0.4), "now");
+    }
+
+    #[test]
+    fn bar_countdown_uses_clock_notation() {
+        assert_eq!(format_bar_countdown(42.0), "00:42");
+        assert_eq!(format_bar_countdown(41.2), "00:42");
+        assert_eq!(format_bar_countdown(-3.0), "00:00");
+        assert_eq!(format_bar_countdown(59.0 * 60.0 + 59.0), "59:59");
+        assert_eq!(format_bar_countdown(3_600.0 + 5.0), "1:00:05");
+        assert_eq!(format_bar_countdown(86_400.0 + 61.0), "1d 00:01:01");
+    }
+
+    #[test]
+    fn signed_percent_marks_direction_except_at_zero() {
+        assert_eq!(
+            format_signed_percent(1.254, AxisLabelLocale::EnUs),
+            "+1.25%"
+        );
+        assert_eq!(format_signed_percent(-0.5, AxisLabelLocale::EsEs), "-0,50%");
+        assert_eq!(
+            format_signed_percent(-0.001, AxisLabelLocale::EnUs),
+            "0.00%"
+        );
+    }
+}
@@ -0,0 +1,89 @@
+/// Vertical extent of one axis label (box or bare text) in pixels.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(super) struct AxisLabelSpan {
+    pub top: f64,
+    pub height: f64,
+}
+
+impl AxisLabelSpan {
+    /// Returns `true` when both spans share a strictly positive vertical extent.
+    pub(super) fn overlaps(self, other: Self) -> bool {
+        self.top < other.top + other.height && other.top < self.top + self.height
+    }
+}
+
+/// Resolves per-label vertical offsets that remove overlaps between axis labels.
+///
+/// Labels keep their relative order by requested top; colliding labels are
+/// pushed down, then pulled back up when the stack would cross `max_y`. When
+/// the stack is taller than `[min_y, max_y]` the topmost labels stay pinned to
+/// `min_y` and overlap is unavoidable.
+pub(super) fn resolve_axis_label_stack_offsets(
+    spans: &[AxisLabelSpan],
+    min_y: f64,
+    max_y: f64,
+) -> Vec<f64> {
+    let mut order = (0..spans.len()).collect::<Vec<_>>();
+    order.sort_by(|left, right| spans[*left].top.total_cmp(&spans[*right].top));
+
+    let mut tops = order.iter().map(|idx| spans[*idx].top).collect::<Vec<_>>();
+    let heights = order
+        .iter()
+        .map(|idx| spans[*idx].height.max(0.0))
+        .collect::<Vec<_>>();
+
+    for i in 1..tops.len() {
+        tops[i] = tops[i].max(tops[i - 1] + heights[i - 1]);
+    }
+    if let Some(last) = tops.len().checked_sub(1) {
+        tops[last] = tops[last].min(max_y - heights[last]);
+        for i in (0..last).rev() {
+            tops[i] = tops[i].min(tops[i + 1] - heights[i]);
+        }
+    }
+    for top in &mut tops {
+        *top = top.max(min_y);
+    }
+
+    let mut offsets = vec![0.0; spans.len()];
+    for (sorted_idx, original_idx) in order.into_iter().enumerate() {
+        offsets[original_idx] = tops[sorted_idx] - spans[original_idx].top;
+    }
+    offsets
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AxisLabelSpan, resolve_axis_label_stack_offsets};
+
+    fn span(top: f64, height: f64) -> AxisLabelSpan {
+        AxisLabelSpan { top, height }
+    }
+
+    #[test]
+    fn separated_labels_are_left_in_place() {
+        let offsets =
+            resolve_axis_label_stack_offsets(&[span(10.0, 10.0), span(40.0, 10.0)], 0.0, 100.0);
+        assert_eq!(offsets, vec![0.0, 0.0]);
+    }
+
+    #[test]
+    fn overlapping_labels_are_pushed_apart_in_requested_order() {
+        let offsets =
+            resolve_axis_label_stack_offsets(&[span(25.0, 10.0), span(20.0, 10.0)], 0.0, 100.0);
+        assert_eq!(offsets, vec![5.0, 0.0]);
+    }
+
+    #[test]
+    fn stack_is_pulled_up_from_bottom_edge() {
+        let offsets =
+            resolve_axis_label_stack_offsets(&[span(85.0, 10.0), span(88.0, 10.0)], 0.0, 100.0);
+        assert_eq!(offsets, vec![-5.0, 2.0]);
+    }
+
+    #[test]
+    fn spans_overlap_only_with_shared_extent() {
+        assert!(span(10.0, 10.0).overlaps(span(15.0, 10.0)));
+        assert!(!span(10.0, 10.0).overlaps(span(20.0, 10.0)));
+    }
+}
@@ -1,39 +1,78 @@
 use crate::render::Renderer;
 
 use super::axis_label_format::map_price_to_display_value;
-use super::layout_helpers::estimate_label_text_width_px;
-use super::{ChartEngine, RenderStyle};
+use super::price_resolver::PriceSampleSource;
+use super::{ChartEngine, RenderStyle, SeriesRef};
 
 impl<R: Renderer> ChartEngine<R> {
     pub(super) fn estimate_last_price_axis_label_required_width(
         &self,
-        style: RenderStyle,
+        style: &RenderStyle,
         visible_start: f64,
         visible_end: f64,
         fallback_display_base_price: f64,
         display_tick_step_abs: f64,
         display_suffix: &str,
     ) -> f64 {
-        if !style.show_last_price_label {
-            return 0.0;
+        let mut label_prices = Vec::with_capacity(3);
+        if style.show_last_price_label {
+            label_prices.extend(
+                self.resolve_latest_and_previous_price_values(
+                    style.last_price_source_mode,
+                    visible_start,
+                    visible_end,
+                )
+                .map(|(last_price, _)| (last_price, self.price_axis_label_policy())),
+            );
+        }
+        for (source, series, series_style) in [
+            (
+                PriceSampleSource::Points,
+                SeriesRef::Points,
+                style.point_series_last_value,
+            ),
+            (
+                PriceSampleSource::Candles,
+                SeriesRef::Candles,
+                style.candle_series_last_value,
+            ),
+        ] {
+            if !series_style.is_some_and(|series_style| series_style.show_label) {
+                continue;
+            }
+            label_prices.extend(
+                self.resolve_source_latest_and_previous_price_values(
+                    source,
+                    style.last_price_source_mode,
+                    visible_start,
+                    visible_end,
+                )
+                .map(|(last_price, _)| (last_price, self.series_price_label_policy(series))),
+            );
         }
 
-        let Some((last_price, _previous_price)) = self.resolve_latest_and_previous_price_values(
-            style.last_price_source_mode,
-            visible_start,
-            visible_end,
-        ) else {
+        let text_width = label_prices
+            .into_iter()
+            .map(|(last_price, policy)| {
+                let display_price = map_price_to_display_value(
+                    last_price,
+                    self.resolve_price_axis_display_mode(),
+                    fallback_display_base_price,
+                );
+                let text = self.format_price_label_with_policy(
+                    display_price,
+                    display_tick_step_abs,
+                    display_suffix,
+                    policy,
+                );
+                style
+                    .label_text_metrics
+                    .text_width_px(&text, style.last_price_label_font_size_px)
+            })
+            .reduce(f64::max);
+        let Some(text_width) = text_width else {
             return 0.0;
         };
-
-        let display_price = map_price_to_display_value(
-            last_price,
-            self.core.behavior.price_axis_label_config.display_mode,
-            fallback_display_base_price,
-        );
-        let text =
-            self.format_price_axis_label(display_price, display_tick_step_abs, display_suffix);
-        let text_width = estimate_label_text_width_px(&text, style.last_price_label_font_size_px);
         let padding_right = if style.show_last_price_label_box {
             (2.0 * style.last_price_label_box_padding_x_px)
                 .max(style.last_price_label_padding_right_px)
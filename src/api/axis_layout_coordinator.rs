@@ -2,7 +2,7 @@ use crate::error::ChartResult;
 use crate::render::Renderer;
 
 use super::layout_helpers::AxisLayout;
-use super::{ChartEngine, RenderStyle};
+use super::{ChartEngine, PriceAxisWidthMode, RenderStyle};
 
 #[derive(Debug, Clone, Copy)]
 pub(super) struct ResolvedRenderAxisLayout {
@@ -13,9 +13,35 @@ pub(super) struct ResolvedRenderAxisLayout {
 }
 
 impl<R: Renderer> ChartEngine<R> {
+    /// Price-axis width used by the most recently built frame, including
+    /// adaptive widening and `PriceAxisWidthMode::Auto` sizing. Before the
+    /// first frame this is the styled width (or the auto minimum).
+    #[must_use]
+    pub fn resolved_price_axis_width_px(&self) -> f64 {
+        let style = &self.core.presentation.render_style;
+        self.core
+            .runtime
+            .resolved_price_axis_width_px
+            .get()
+            .unwrap_or(match style.price_axis_width_mode {
+                PriceAxisWidthMode::Fixed => style.price_axis_width_px,
+                PriceAxisWidthMode::Auto { min_width_px, .. } => min_width_px,
+            })
+    }
+
+    /// Price-axis width assumed by interaction hit-testing and pane layout
+    /// outside the render pass: the styled width in `Fixed` mode, the last
+    /// resolved width in `Auto` mode.
+    pub(super) fn layout_price_axis_width_px(&self) -> f64 {
+        match self.core.presentation.render_style.price_axis_width_mode {
+            PriceAxisWidthMode::Fixed => self.core.presentation.render_style.price_axis_width_px,
+            PriceAxisWidthMode::Auto { .. } => self.resolved_price_axis_width_px(),
+        }
+    }
+
     pub(super) fn resolve_render_axis_layout(
         &self,
-        style: RenderStyle,
+        style: &RenderStyle,
         visible_start: f64,
         visible_end: f64,
     ) -> ChartResult<ResolvedRenderAxisLayout> {
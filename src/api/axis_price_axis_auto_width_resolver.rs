@@ -0,0 +1,64 @@
+use super::PriceAxisWidthMode;
+
+/// Width for `PriceAxisWidthMode::Auto` given the widest label requirement and
+/// the width used by the previous frame.
+///
+/// Growth is immediate so labels never clip; shrinking waits until the
+/// requirement falls more than `hysteresis_px` below the previous width.
+/// `Fixed` mode returns `required_width` unchanged.
+pub(super) fn resolve_price_axis_auto_width(
+    mode: PriceAxisWidthMode,
+    required_width: f64,
+    previous_width: Option<f64>,
+) -> f64 {
+    let PriceAxisWidthMode::Auto {
+        min_width_px,
+        max_width_px,
+        hysteresis_px,
+    } = mode
+    else {
+        return required_width;
+    };
+
+    let target = required_width.ceil().clamp(min_width_px, max_width_px);
+    match previous_width {
+        Some(previous) if target <= previous && previous - target <= hysteresis_px => {
+            previous.clamp(min_width_px, max_width_px)
+        }
+        _ => target,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::resolve_price_axis_auto_width;
+    use crate::api::PriceAxisWidthMode;
+
+    const AUTO: PriceAxisWidthMode = PriceAxisWidthMode::Auto {
+        min_width_px: 40.0,
+        max_width_px: 120.0,
+        hysteresis_px: 8.0,
+    };
+
+    #[test]
+    fn auto_width_clamps_required_width_to_bounds() {
+        assert_eq!(resolve_price_axis_auto_width(AUTO, 12.0, None), 40.0);
+        assert_eq!(resolve_price_axis_auto_width(AUTO, 55.2, None), 56.0);
+        assert_eq!(resolve_price_axis_auto_width(AUTO, 300.0, None), 120.0);
+    }
+
+    #[test]
+    fn auto_width_grows_immediately_and_shrinks_past_hysteresis() {
+        assert_eq!(resolve_price_axis_auto_width(AUTO, 70.0, Some(60.0)), 70.0);
+        assert_eq!(resolve_price_axis_auto_width(AUTO, 64.0, Some(70.0)), 70.0);
+        assert_eq!(resolve_price_axis_auto_width(AUTO, 61.0, Some(70.0)), 61.0);
+    }
+
+    #[test]
+    fn fixed_mode_passes_required_width_through() {
+        assert_eq!(
+            resolve_price_axis_auto_width(PriceAxisWidthMode::Fixed, 55.2, Some(90.0)),
+            55.2
+        );
+    }
+}
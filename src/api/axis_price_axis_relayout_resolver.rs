@@ -1,6 +1,12 @@
+use super::PriceAxisWidthMode;
+
 pub(super) fn should_relayout_price_axis_for_adaptive_width(
+    mode: PriceAxisWidthMode,
     adaptive_price_axis_width: f64,
     requested_price_axis_width: f64,
 ) -> bool {
-    adaptive_price_axis_width > requested_price_axis_width
+    match mode {
+        PriceAxisWidthMode::Fixed => adaptive_price_axis_width > requested_price_axis_width,
+        PriceAxisWidthMode::Auto { .. } => adaptive_price_axis_width != requested_price_axis_width,
+    }
 }
@@ -10,7 +10,7 @@ use super::{ChartEngine, RenderStyle};
 impl<R: Renderer> ChartEngine<R> {
     pub(super) fn estimate_required_price_axis_width(
         &self,
-        style: RenderStyle,
+        style: &RenderStyle,
         plot_bottom: f64,
         visible_start: f64,
         visible_end: f64,
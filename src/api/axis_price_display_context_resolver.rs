@@ -10,11 +10,10 @@ impl<R: Renderer> ChartEngine<R> {
         raw_tick_step_abs: f64,
     ) -> AxisPriceDisplayContext {
         let fallback_display_base_price = self.resolve_price_display_base_price();
-        let display_suffix =
-            price_display_mode_suffix(self.core.behavior.price_axis_label_config.display_mode);
+        let display_suffix = price_display_mode_suffix(self.resolve_price_axis_display_mode());
         let display_tick_step_abs = map_price_step_to_display_value(
             raw_tick_step_abs,
-            self.core.behavior.price_axis_label_config.display_mode,
+            self.resolve_price_axis_display_mode(),
             fallback_display_base_price,
         )
         .abs();
@@ -8,10 +8,10 @@ pub(super) struct AxisPriceSceneLayout {
 }
 
 #[derive(Debug, Clone, Copy)]
-pub(super) struct AxisPriceSceneLayoutContext {
+pub(super) struct AxisPriceSceneLayoutContext<'a> {
     pub plot_right: f64,
     pub viewport_width: f64,
-    pub style: RenderStyle,
+    pub style: &'a RenderStyle,
 }
 
 pub(super) fn build_axis_price_scene_layout(
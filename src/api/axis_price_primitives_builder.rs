@@ -1,11 +1,17 @@
+use crate::core::PriceScaleMode;
+use crate::error::ChartResult;
 use crate::render::{CanvasLayerKind, Renderer, TextHAlign, TextPrimitive};
 
 use super::axis_label_format::map_price_to_display_value;
 use super::axis_render_frame_builder::AxisPrimitiveSink;
-use super::{ChartEngine, RenderStyle};
+use super::axis_ticks::{local_tick_steps, subdivided_minor_positions};
+use super::{ChartEngine, PriceAxisDisplayMode, RenderStyle};
+
+/// Font size of secondary tick labels relative to the primary label.
+pub(super) const PRICE_AXIS_SECONDARY_LABEL_FONT_SCALE: f64 = 0.85;
 
 #[derive(Debug, Clone, Copy)]
-pub(super) struct AxisPricePrimitivesContext {
+pub(super) struct AxisPricePrimitivesContext<'a> {
     pub plot_right: f64,
     pub plot_bottom: f64,
     pub price_axis_label_anchor_x: f64,
@@ -13,7 +19,7 @@ pub(super) struct AxisPricePrimitivesContext {
     pub fallback_display_base_price: f64,
     pub display_tick_step_abs: f64,
     pub display_suffix: &'static str,
-    pub style: RenderStyle,
+    pub style: &'a RenderStyle,
 }
 
 impl<R: Renderer> ChartEngine<R> {
@@ -32,16 +38,32 @@ impl<R: Renderer> ChartEngine<R> {
         let display_suffix = ctx.display_suffix;
         let style = ctx.style;
         let price_label_color = style.axis_label_color;
+        let secondary_base_price = self.resolve_price_axis_secondary_base_price();
+        let secondary_font_size =
+            style.price_axis_label_font_size_px * PRICE_AXIS_SECONDARY_LABEL_FONT_SCALE;
+
+        let local_steps = (self.core.model.price_scale.mode() == PriceScaleMode::Log
+            && self.resolve_price_axis_display_mode() == PriceAxisDisplayMode::Normal)
+            .then(|| local_tick_steps(&ticks.iter().map(|tick| tick.0).collect::<Vec<_>>()));
 
-        for (price, py) in ticks {
+        for (index, (price, py)) in ticks.into_iter().enumerate() {
             let display_price = map_price_to_display_value(
                 price,
-                self.core.behavior.price_axis_label_config.display_mode,
+                self.resolve_price_axis_display_mode(),
                 fallback_display_base_price,
             );
-            let text =
-                self.format_price_axis_label(display_price, display_tick_step_abs, display_suffix);
-            if style.show_price_axis_labels {
+            let tick_step_abs = local_steps
+                .as_ref()
+                .map_or(display_tick_step_abs, |steps| steps[index]);
+            let text = self.format_price_axis_label(display_price, tick_step_abs, display_suffix);
+            if style.show_price_axis_labels
+                && let Some(text) = style.label_overflow_policy.fit_shared_text(
+                    &text,
+                    price_axis_label_anchor_x - plot_right,
+                    style.price_axis_label_font_size_px,
+                    style.label_text_metrics,
+                )
+            {
                 let price_label_y = (py - style.price_axis_label_offset_y_px).clamp(
                     0.0,
                     (plot_bottom - style.price_axis_label_font_size_px).max(0.0),
@@ -57,8 +79,33 @@ impl<R: Renderer> ChartEngine<R> {
                         TextHAlign::Right,
                     ),
                 );
+                if let Some(secondary) = secondary_base_price
+                    .map(|base_price| self.format_price_axis_secondary_label(price, base_price))
+                    .and_then(|secondary| {
+                        style.label_overflow_policy.fit_text(
+                            &secondary,
+                            price_axis_label_anchor_x - plot_right,
+                            secondary_font_size,
+                            style.label_text_metrics,
+                        )
+                    })
+                {
+                    sink.push_text(
+                        CanvasLayerKind::Axis,
+                        TextPrimitive::new(
+                            secondary,
+                            price_axis_label_anchor_x,
+                            (price_label_y + style.price_axis_label_font_size_px)
+                                .min((plot_bottom - secondary_font_size).max(0.0)),
+                            secondary_font_size,
+                            price_label_color,
+                            TextHAlign::Right,
+                        ),
+                    );
+                }
             }
-            if style.show_price_axis_grid_lines {
+            let horz_lines = style.grid.horz_lines;
+            if horz_lines.visible {
                 sink.push_line(
                     CanvasLayerKind::Grid,
                     crate::render::LinePrimitive::new(
@@ -66,9 +113,10 @@ impl<R: Renderer> ChartEngine<R> {
                         py,
                         plot_right,
                         py,
-                        style.price_axis_grid_line_width,
-                        style.price_axis_grid_line_color,
-                    ),
+                        horz_lines.width,
+                        horz_lines.color,
+                    )
+                    .with_stroke_style(horz_lines.style),
                 );
             }
             if style.show_price_axis_tick_marks {
@@ -86,4 +134,55 @@ impl<R: Renderer> ChartEngine<R> {
             }
         }
     }
+
+    /// Minor horizontal grid lines between price ticks: the log ladder's
+    /// minor ticks on log scales, equal subdivisions of `grid_tick_pixels`
+    /// otherwise.
+    pub(super) fn append_price_axis_minor_grid_primitives(
+        &self,
+        sink: &mut AxisPrimitiveSink<'_>,
+        plot_right: f64,
+        plot_bottom: f64,
+        price_tick_count: usize,
+        grid_tick_pixels: &[f64],
+        style: &RenderStyle,
+    ) -> ChartResult<()> {
+        let minor_lines = style.grid.minor_horz_lines;
+        if !minor_lines.visible {
+            return Ok(());
+        }
+        let price_scale = self.core.model.price_scale;
+        let minor_rows = if price_scale.mode() == PriceScaleMode::Log {
+            price_scale
+                .minor_ticks(price_tick_count)?
+                .into_iter()
+                .map(|price| price_scale.price_to_pixel(price, self.core.model.viewport))
+                .collect::<ChartResult<Vec<_>>>()?
+        } else {
+            subdivided_minor_positions(
+                grid_tick_pixels,
+                style.grid.minor_horz_subdivisions,
+                0.0,
+                plot_bottom,
+            )
+        };
+        for py in minor_rows {
+            if !(0.0..=plot_bottom).contains(&py) {
+                continue;
+            }
+            sink.push_line(
+                CanvasLayerKind::Grid,
+                crate::render::LinePrimitive::new(
+                    0.0,
+                    py,
+                    plot_right,
+                    py,
+                    minor_lines.width,
+                    minor_lines.color,
+                )
+                .with_stroke_style(minor_lines.style),
+            );
+        }
+        Ok(())
+    }
 }
@@ -1,23 +1,26 @@
+use tracing::trace_span;
+
 use crate::error::ChartResult;
 use crate::render::Renderer;
+use crate::telemetry::{FRAME_LABELS_SPAN, FRAME_TICKS_SPAN};
 
 use super::axis_price_layout_builder::{
     AxisPriceSceneLayoutContext, build_axis_price_scene_layout,
 };
 use super::axis_price_primitives_builder::AxisPricePrimitivesContext;
-use super::axis_render_frame_builder::{AxisPriceDisplayContext, AxisPrimitiveSink};
-use super::last_price_axis_scene_builder::LastPriceAxisSceneContext;
+use super::axis_render_frame_builder::{AxisPriceSceneOutput, AxisPrimitiveSink};
+use super::last_price_axis_scene_builder::{LastPriceAxisSceneContext, LastPriceMarker};
 use super::{ChartEngine, RenderStyle};
 
 #[derive(Debug, Clone, Copy)]
-pub(super) struct AxisPriceSceneContext {
+pub(super) struct AxisPriceSceneContext<'a> {
     pub plot_right: f64,
     pub plot_bottom: f64,
     pub viewport_width: f64,
     pub visible_start: f64,
     pub visible_end: f64,
     pub price_tick_count: usize,
-    pub style: RenderStyle,
+    pub style: &'a RenderStyle,
 }
 
 impl<R: Renderer> ChartEngine<R> {
@@ -25,7 +28,7 @@ impl<R: Renderer> ChartEngine<R> {
         &self,
         sink: &mut AxisPrimitiveSink<'_>,
         ctx: AxisPriceSceneContext,
-    ) -> ChartResult<AxisPriceDisplayContext> {
+    ) -> ChartResult<AxisPriceSceneOutput> {
         let plot_right = ctx.plot_right;
         let plot_bottom = ctx.plot_bottom;
         let viewport_width = ctx.viewport_width;
@@ -40,20 +43,31 @@ impl<R: Renderer> ChartEngine<R> {
             style,
         });
 
-        let latest_price_marker = self.resolve_last_price_marker_for_axis(
-            style,
-            visible_start,
-            visible_end,
-            plot_bottom,
-        )?;
+        let last_price_markers =
+            self.resolve_price_axis_markers(style, visible_start, visible_end, plot_bottom)?;
+        let ticks_span = trace_span!(FRAME_TICKS_SPAN, axis = "price", ticks = 0_usize).entered();
         let tick_selection = self.select_price_axis_ticks(
             price_tick_count,
             plot_bottom,
             style,
-            latest_price_marker,
+            &last_price_markers,
         )?;
+        ticks_span.record("ticks", tick_selection.ticks.len());
+        ticks_span.exit();
         let display_ctx = self.resolve_price_axis_display_context(tick_selection.tick_step_abs);
 
+        self.append_price_axis_minor_grid_primitives(
+            sink,
+            plot_right,
+            plot_bottom,
+            price_tick_count,
+            &tick_selection.grid_tick_pixels,
+            style,
+        )?;
+
+        let labels_span =
+            trace_span!(FRAME_LABELS_SPAN, axis = "price", labels = 0_usize).entered();
+        let texts_before = sink.text_count();
         self.append_price_axis_tick_primitives(
             sink,
             tick_selection.ticks,
@@ -69,9 +83,17 @@ impl<R: Renderer> ChartEngine<R> {
             },
         );
 
-        self.append_last_price_axis_primitives(
+        self.append_high_low_marker_primitives(
+            sink,
+            plot_right,
+            plot_bottom,
+            (visible_end - visible_start).abs(),
+            display_ctx,
+        )?;
+
+        let last_value_labels = self.append_last_price_axis_primitives(
             sink,
-            latest_price_marker,
+            &last_price_markers,
             LastPriceAxisSceneContext {
                 plot_right,
                 plot_bottom,
@@ -83,7 +105,36 @@ impl<R: Renderer> ChartEngine<R> {
                 style,
             },
         );
+        labels_span.record("labels", sink.text_count() - texts_before);
+
+        Ok(AxisPriceSceneOutput {
+            display: display_ctx,
+            last_value_labels,
+        })
+    }
 
-        Ok(display_ctx)
+    /// Last-price, last-value and high/low markers stacked on the price axis.
+    pub(super) fn resolve_price_axis_markers(
+        &self,
+        style: &RenderStyle,
+        visible_start: f64,
+        visible_end: f64,
+        plot_bottom: f64,
+    ) -> ChartResult<Vec<LastPriceMarker>> {
+        let latest_price_marker = self.resolve_last_price_marker_for_axis(
+            style,
+            visible_start,
+            visible_end,
+            plot_bottom,
+        )?;
+        let mut markers = latest_price_marker.into_iter().collect::<Vec<_>>();
+        markers.extend(self.resolve_series_last_value_markers_for_axis(
+            style,
+            visible_start,
+            visible_end,
+            plot_bottom,
+        )?);
+        markers.extend(self.resolve_high_low_markers_for_axis(plot_bottom)?);
+        Ok(markers)
     }
 }
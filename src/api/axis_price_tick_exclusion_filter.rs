@@ -1,31 +1,40 @@
 use super::RenderStyle;
 use super::last_price_axis_scene_builder::LastPriceMarker;
 
+/// Drops regular price ticks within the exclusion distance of any visible
+/// last-value label, keeping at least the tick farthest from the labels.
 pub(super) fn filter_price_ticks_for_last_price_label(
     selected_price_ticks: &[(f64, f64)],
-    style: RenderStyle,
-    latest_price_marker: Option<LastPriceMarker>,
+    style: &RenderStyle,
+    last_price_markers: &[LastPriceMarker],
 ) -> Vec<(f64, f64)> {
     let mut ticks = selected_price_ticks.to_vec();
 
-    if style.show_last_price_label
+    let label_pys = last_price_markers
+        .iter()
+        .filter(|marker| marker.show_label)
+        .map(|marker| marker.py)
+        .collect::<Vec<_>>();
+    if !label_pys.is_empty()
         && style.last_price_label_exclusion_px.is_finite()
         && style.last_price_label_exclusion_px > 0.0
     {
-        if let Some(marker) = latest_price_marker {
-            ticks.retain(|(_, py)| (*py - marker.py).abs() >= style.last_price_label_exclusion_px);
-            if ticks.is_empty() && !selected_price_ticks.is_empty() {
-                let fallback_tick = selected_price_ticks
-                    .iter()
-                    .copied()
-                    .max_by(|left, right| {
-                        (left.1 - marker.py)
-                            .abs()
-                            .total_cmp(&(right.1 - marker.py).abs())
-                    })
-                    .expect("selected price ticks not empty");
-                ticks.push(fallback_tick);
-            }
+        let nearest_label_distance = |py: f64| {
+            label_pys
+                .iter()
+                .map(|label_py| (py - label_py).abs())
+                .fold(f64::INFINITY, f64::min)
+        };
+        ticks.retain(|(_, py)| nearest_label_distance(*py) >= style.last_price_label_exclusion_px);
+        if ticks.is_empty() && !selected_price_ticks.is_empty() {
+            let fallback_tick = selected_price_ticks
+                .iter()
+                .copied()
+                .max_by(|left, right| {
+                    nearest_label_distance(left.1).total_cmp(&nearest_label_distance(right.1))
+                })
+                .expect("selected price ticks not empty");
+            ticks.push(fallback_tick);
         }
     }
 
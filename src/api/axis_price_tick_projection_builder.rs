@@ -1,8 +1,10 @@
 use crate::error::ChartResult;
 use crate::render::Renderer;
 
-use super::ChartEngine;
-use super::axis_ticks::tick_step_hint_from_values;
+use crate::core::PriceScaleMode;
+
+use super::axis_ticks::{min_move_aligned_ticks, tick_step_hint_from_values};
+use super::{ChartEngine, PriceAxisDisplayMode, PriceAxisLabelPolicy};
 
 #[derive(Debug, Clone)]
 pub(super) struct ProjectedPriceTicks {
@@ -11,12 +13,46 @@ pub(super) struct ProjectedPriceTicks {
 }
 
 impl<R: Renderer> ChartEngine<R> {
+    /// Min-move grid price ticks and the free crosshair price align to, when
+    /// `align_ticks_to_min_move` applies to the active label policy and scale.
+    pub(super) fn price_tick_min_move_grid(&self) -> Option<f64> {
+        let config = self.core.behavior.price_axis_label_config;
+        if !config.align_ticks_to_min_move
+            || self.core.model.price_scale.mode() != PriceScaleMode::Linear
+            || self.resolve_price_axis_display_mode() != PriceAxisDisplayMode::Normal
+        {
+            return None;
+        }
+        match self.price_axis_label_policy() {
+            PriceAxisLabelPolicy::MinMove { min_move, .. } => Some(min_move),
+            _ => None,
+        }
+    }
+
     pub(super) fn build_projected_price_ticks(
         &self,
         price_tick_count: usize,
         plot_bottom: f64,
     ) -> ChartResult<ProjectedPriceTicks> {
-        let raw_price_ticks = self.core.model.price_scale.ticks(price_tick_count)?;
+        let raw_price_ticks = match self.price_tick_min_move_grid() {
+            Some(min_move) => min_move_aligned_ticks(
+                self.core.model.price_scale.domain(),
+                price_tick_count,
+                min_move,
+            ),
+            None if self
+                .core
+                .behavior
+                .price_axis_label_config
+                .anchor_ticks_to_nice_steps =>
+            {
+                self.core
+                    .model
+                    .price_scale
+                    .anchored_ticks(price_tick_count)?
+            }
+            None => self.core.model.price_scale.ticks(price_tick_count)?,
+        };
         let tick_step_abs = tick_step_hint_from_values(&raw_price_ticks);
 
         let mut ticks = Vec::with_capacity(raw_price_ticks.len());
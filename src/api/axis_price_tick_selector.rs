@@ -3,13 +3,17 @@ use crate::render::Renderer;
 
 use super::axis_price_tick_exclusion_filter::filter_price_ticks_for_last_price_label;
 use super::axis_price_tick_spacing_selector::select_price_ticks_with_min_spacing;
+use super::frame_budget::FrameReduction;
 use super::last_price_axis_scene_builder::LastPriceMarker;
-use super::{ChartEngine, RenderStyle};
+use super::{ChartEngine, ChartWarning, RenderStyle, WarningAxis};
 
 #[derive(Debug, Clone)]
 pub(super) struct PriceAxisTickSelection {
     pub ticks: Vec<(f64, f64)>,
     pub tick_step_abs: f64,
+    /// Pixel rows of the spacing-selected ticks before last-price exclusion,
+    /// so minor grid lines keep a regular cadence around the last-price label.
+    pub grid_tick_pixels: Vec<f64>,
 }
 
 impl<R: Renderer> ChartEngine<R> {
@@ -17,23 +21,72 @@ impl<R: Renderer> ChartEngine<R> {
         &self,
         price_tick_count: usize,
         plot_bottom: f64,
-        style: RenderStyle,
-        latest_price_marker: Option<LastPriceMarker>,
+        style: &RenderStyle,
+        last_price_markers: &[LastPriceMarker],
     ) -> ChartResult<PriceAxisTickSelection> {
+        let (selection, candidates) =
+            self.plan_price_axis_ticks(price_tick_count, plot_bottom, style, last_price_markers)?;
+        let kept = selection.grid_tick_pixels.len();
+        if candidates >= 2 && kept < 2 {
+            self.emit_warning(ChartWarning::TicksDropped {
+                axis: WarningAxis::Price,
+                candidates,
+                kept,
+            });
+        }
+        Ok(selection)
+    }
+
+    /// Prices of the horizontal gridlines the next frame draws, resolved
+    /// without building it (crosshair gridline snapping).
+    pub(super) fn resolve_price_gridline_prices(&self) -> ChartResult<Vec<f64>> {
+        let style = &self.core.presentation.render_style;
+        let (visible_start, visible_end) = self.core.model.time_scale.visible_range();
+        let plot_bottom = self
+            .resolve_render_axis_layout(style, visible_start, visible_end)?
+            .axis_layout
+            .plot_bottom;
+        let price_tick_count =
+            self.resolve_price_tick_count(plot_bottom, FrameReduction::default())?;
+        let markers =
+            self.resolve_price_axis_markers(style, visible_start, visible_end, plot_bottom)?;
+        let (selection, _) =
+            self.plan_price_axis_ticks(price_tick_count, plot_bottom, style, &markers)?;
+        Ok(selection
+            .ticks
+            .into_iter()
+            .map(|(price, _)| price)
+            .collect())
+    }
+
+    /// Tick selection plus the candidate count, without emitting warnings.
+    fn plan_price_axis_ticks(
+        &self,
+        price_tick_count: usize,
+        plot_bottom: f64,
+        style: &RenderStyle,
+        last_price_markers: &[LastPriceMarker],
+    ) -> ChartResult<(PriceAxisTickSelection, usize)> {
         let projected_ticks = self.build_projected_price_ticks(price_tick_count, plot_bottom)?;
         let tick_step_abs = projected_ticks.tick_step_abs;
         let price_ticks = projected_ticks.ticks;
 
+        let candidates = price_ticks.len();
         let selected_price_ticks = select_price_ticks_with_min_spacing(price_ticks);
+        let grid_tick_pixels = selected_price_ticks.iter().map(|tick| tick.1).collect();
         let ticks = filter_price_ticks_for_last_price_label(
             &selected_price_ticks,
             style,
-            latest_price_marker,
+            last_price_markers,
         );
 
-        Ok(PriceAxisTickSelection {
-            ticks,
-            tick_step_abs,
-        })
+        Ok((
+            PriceAxisTickSelection {
+                ticks,
+                tick_step_abs,
+                grid_tick_pixels,
+            },
+            candidates,
+        ))
     }
 }
@@ -1,7 +1,12 @@
-use super::RenderStyle;
+use super::{PriceAxisWidthMode, RenderStyle};
 
-pub(super) fn initialize_required_price_axis_width(style: RenderStyle) -> f64 {
-    style.price_axis_width_px
+/// Lower bound for the required width: the styled width in `Fixed` mode, none
+/// in `Auto` mode where the configured bounds are applied afterwards.
+pub(super) fn initialize_required_price_axis_width(style: &RenderStyle) -> f64 {
+    match style.price_axis_width_mode {
+        PriceAxisWidthMode::Fixed => style.price_axis_width_px,
+        PriceAxisWidthMode::Auto { .. } => 0.0,
+    }
 }
 
 pub(super) fn finalize_required_price_axis_width(required_width: f64) -> f64 {
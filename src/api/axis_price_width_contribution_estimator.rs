@@ -9,7 +9,7 @@ use super::{ChartEngine, RenderStyle};
 impl<R: Renderer> ChartEngine<R> {
     pub(super) fn estimate_price_axis_width_contribution(
         &self,
-        style: RenderStyle,
+        style: &RenderStyle,
         visible_start: f64,
         visible_end: f64,
         width_tick_context: &PriceAxisWidthTickContext,
@@ -6,7 +6,7 @@ use super::{ChartEngine, RenderStyle};
 impl<R: Renderer> ChartEngine<R> {
     pub(super) fn resolve_price_axis_width_contribution_from_pipeline(
         &self,
-        style: RenderStyle,
+        style: &RenderStyle,
         plot_bottom: f64,
         visible_start: f64,
         visible_end: f64,
@@ -1,20 +1,26 @@
 use crate::core::PaneId;
 use crate::error::ChartResult;
 use crate::render::{
-    CanvasLayerKind, LayeredRenderFrame, LinePrimitive, RectPrimitive, RenderFrame, Renderer,
-    TextPrimitive,
+    ArrowPrimitive, CanvasLayerKind, ClipRect, LayeredRenderFrame, LinePrimitive, RectPrimitive,
+    RenderFrame, Renderer, TextPrimitive,
 };
 
+use super::axis_background_primitives_builder::{
+    AxisBackgroundContext, append_axis_background_primitives,
+};
 use super::axis_price_scene_builder::AxisPriceSceneContext;
 use super::axis_ticks::{
     AXIS_PRICE_MIN_SPACING_PX, AXIS_PRICE_TARGET_SPACING_PX, AXIS_TIME_MIN_SPACING_PX,
     AXIS_TIME_TARGET_SPACING_PX, axis_tick_target_count_with_density,
 };
 use super::axis_time_scene_builder::AxisTimeSceneContext;
+use super::frame_budget::FrameReduction;
+use super::last_price_axis_label_primitives_builder::LastPriceAxisLabelPrimitives;
+use super::render_clip_regions_resolver::RenderClipRegions;
 use super::{ChartEngine, RenderStyle};
 
 #[derive(Debug, Clone, Copy)]
-pub(super) struct AxisRenderContext {
+pub(super) struct AxisRenderContext<'a> {
     pub main_pane_id: PaneId,
     pub plot_right: f64,
     pub plot_bottom: f64,
@@ -23,7 +29,9 @@ pub(super) struct AxisRenderContext {
     pub visible_start: f64,
     pub visible_end: f64,
     pub visible_span_abs: f64,
-    pub style: RenderStyle,
+    pub clip_regions: RenderClipRegions,
+    pub style: &'a RenderStyle,
+    pub reduction: FrameReduction,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -33,10 +41,20 @@ pub(super) struct AxisPriceDisplayContext {
     pub display_suffix: &'static str,
 }
 
+/// Price-axis state handed from the axis stage to the crosshair stage.
+#[derive(Debug, Clone)]
+pub(super) struct AxisPriceSceneOutput {
+    pub display: AxisPriceDisplayContext,
+    /// Stacked last-price/last-value labels not yet pushed to the frame.
+    pub last_value_labels: Vec<LastPriceAxisLabelPrimitives>,
+}
+
 pub(super) struct AxisPrimitiveSink<'a> {
     frame: &'a mut RenderFrame,
     layered: &'a mut LayeredRenderFrame,
     pane_id: PaneId,
+    clip_regions: RenderClipRegions,
+    axis_panel_clip: Option<ClipRect>,
 }
 
 impl<'a> AxisPrimitiveSink<'a> {
@@ -44,34 +62,62 @@ impl<'a> AxisPrimitiveSink<'a> {
         frame: &'a mut RenderFrame,
         layered: &'a mut LayeredRenderFrame,
         pane_id: PaneId,
+        clip_regions: RenderClipRegions,
     ) -> Self {
         Self {
             frame,
             layered,
             pane_id,
+            clip_regions,
+            axis_panel_clip: None,
+        }
+    }
+
+    /// Sets the clip used for `CanvasLayerKind::Axis` primitives pushed next.
+    pub(super) fn set_axis_panel_clip(&mut self, axis_panel_clip: Option<ClipRect>) {
+        self.axis_panel_clip = axis_panel_clip;
+    }
+
+    /// Texts pushed into the flat frame so far, for span counters.
+    pub(super) fn text_count(&self) -> usize {
+        self.frame.texts.len()
+    }
+
+    fn clip_for_layer(&self, layer: CanvasLayerKind) -> Option<ClipRect> {
+        match layer {
+            CanvasLayerKind::Axis => self.axis_panel_clip,
+            _ => self.clip_regions.plot_clip_for_layer(layer),
         }
     }
 
-    pub(super) fn push_line(&mut self, layer: CanvasLayerKind, line: LinePrimitive) {
+    pub(super) fn push_line(&mut self, layer: CanvasLayerKind, mut line: LinePrimitive) {
+        line.clip_rect = line.clip_rect.or(self.clip_for_layer(layer));
         self.frame.lines.push(line);
         let idx = self.frame.lines.len() - 1;
         self.layered
             .push_line(self.pane_id, layer, self.frame.lines[idx]);
     }
 
-    pub(super) fn push_rect(&mut self, layer: CanvasLayerKind, rect: RectPrimitive) {
+    pub(super) fn push_rect(&mut self, layer: CanvasLayerKind, mut rect: RectPrimitive) {
+        rect.clip_rect = rect.clip_rect.or(self.clip_for_layer(layer));
         self.frame.rects.push(rect);
-        let idx = self.frame.rects.len() - 1;
-        self.layered
-            .push_rect(self.pane_id, layer, self.frame.rects[idx]);
+        self.layered.push_rect(self.pane_id, layer, rect);
     }
 
-    pub(super) fn push_text(&mut self, layer: CanvasLayerKind, text: TextPrimitive) {
+    pub(super) fn push_text(&mut self, layer: CanvasLayerKind, mut text: TextPrimitive) {
+        text.clip_rect = text.clip_rect.or(self.clip_for_layer(layer));
         self.frame.texts.push(text);
         let idx = self.frame.texts.len() - 1;
         self.layered
             .push_text(self.pane_id, layer, self.frame.texts[idx].clone());
     }
+
+    pub(super) fn push_arrow(&mut self, layer: CanvasLayerKind, arrow: ArrowPrimitive) {
+        let mut path = arrow.to_path();
+        path.clip_rect = path.clip_rect.or(self.clip_for_layer(layer));
+        self.layered.push_path(self.pane_id, layer, path.clone());
+        self.frame.paths.push(path);
+    }
 }
 
 impl<R: Renderer> ChartEngine<R> {
@@ -80,7 +126,7 @@ impl<R: Renderer> ChartEngine<R> {
         frame: &mut RenderFrame,
         layered: &mut LayeredRenderFrame,
         ctx: AxisRenderContext,
-    ) -> ChartResult<AxisPriceDisplayContext> {
+    ) -> ChartResult<AxisPriceSceneOutput> {
         let main_pane_id = ctx.main_pane_id;
         let plot_right = ctx.plot_right;
         let plot_bottom = ctx.plot_bottom;
@@ -92,26 +138,31 @@ impl<R: Renderer> ChartEngine<R> {
         let style = ctx.style;
 
         let time_density_scale = self.resolve_time_axis_density_scale();
-        let price_density_scale = self.resolve_price_axis_density_scale();
-        let price_axis_span_px = self.resolve_price_axis_span_px(plot_bottom)?;
-        let time_tick_count = axis_tick_target_count_with_density(
-            plot_right,
-            AXIS_TIME_TARGET_SPACING_PX,
-            AXIS_TIME_MIN_SPACING_PX,
-            2,
-            12,
-            time_density_scale,
-        );
-        let price_tick_count = axis_tick_target_count_with_density(
-            price_axis_span_px,
-            AXIS_PRICE_TARGET_SPACING_PX,
-            AXIS_PRICE_MIN_SPACING_PX,
+        let time_tick_count = ctx.reduction.thin_tick_count(
+            axis_tick_target_count_with_density(
+                plot_right,
+                AXIS_TIME_TARGET_SPACING_PX,
+                AXIS_TIME_MIN_SPACING_PX,
+                2,
+                12,
+                time_density_scale,
+            ),
             2,
-            16,
-            price_density_scale,
         );
+        let price_tick_count = self.resolve_price_tick_count(plot_bottom, ctx.reduction)?;
 
-        let mut sink = AxisPrimitiveSink::new(frame, layered, main_pane_id);
+        let mut sink = AxisPrimitiveSink::new(frame, layered, main_pane_id, ctx.clip_regions);
+
+        append_axis_background_primitives(
+            &mut sink,
+            AxisBackgroundContext {
+                plot_right,
+                plot_bottom,
+                viewport_width,
+                viewport_height,
+                style,
+            },
+        );
 
         // Axis borders remain explicit frame primitives, keeping visual output
         // deterministic across all renderer backends.
@@ -142,6 +193,7 @@ impl<R: Renderer> ChartEngine<R> {
             );
         }
 
+        sink.set_axis_panel_clip(Some(ctx.clip_regions.time_axis));
         self.append_time_axis_scene(
             &mut sink,
             AxisTimeSceneContext {
@@ -154,6 +206,7 @@ impl<R: Renderer> ChartEngine<R> {
             },
         )?;
 
+        sink.set_axis_panel_clip(Some(ctx.clip_regions.price_axis));
         self.append_price_axis_scene(
             &mut sink,
             AxisPriceSceneContext {
@@ -167,4 +220,24 @@ impl<R: Renderer> ChartEngine<R> {
             },
         )
     }
+
+    /// Price tick budget for a plot ending at `plot_bottom`.
+    pub(super) fn resolve_price_tick_count(
+        &self,
+        plot_bottom: f64,
+        reduction: FrameReduction,
+    ) -> ChartResult<usize> {
+        let price_axis_span_px = self.resolve_price_axis_span_px(plot_bottom)?;
+        Ok(reduction.thin_tick_count(
+            axis_tick_target_count_with_density(
+                price_axis_span_px,
+                AXIS_PRICE_TARGET_SPACING_PX,
+                AXIS_PRICE_MIN_SPACING_PX,
+                2,
+                16,
+                self.resolve_price_axis_density_scale(),
+            ),
+            2,
+        ))
+    }
 }
@@ -1,5 +1,5 @@
-use super::RenderStyle;
 use super::axis_time_axis_height_estimator::estimate_required_time_axis_height;
+use super::{PriceAxisWidthMode, RenderStyle};
 
 #[derive(Debug, Clone, Copy)]
 pub(super) struct RequestedAxisSectionSizes {
@@ -7,10 +7,22 @@ pub(super) struct RequestedAxisSectionSizes {
     pub requested_time_axis_height: f64,
 }
 
+/// `previous_price_axis_width` seeds the first layout pass in auto-width mode
+/// so an unchanged label set does not trigger a relayout.
 pub(super) fn resolve_requested_axis_section_sizes(
-    style: RenderStyle,
+    style: &RenderStyle,
+    previous_price_axis_width: Option<f64>,
 ) -> RequestedAxisSectionSizes {
-    let requested_price_axis_width = style.price_axis_width_px;
+    let requested_price_axis_width = match style.price_axis_width_mode {
+        PriceAxisWidthMode::Fixed => style.price_axis_width_px,
+        PriceAxisWidthMode::Auto {
+            min_width_px,
+            max_width_px,
+            ..
+        } => previous_price_axis_width
+            .unwrap_or(min_width_px)
+            .clamp(min_width_px, max_width_px),
+    };
     let requested_time_axis_height = style
         .time_axis_height_px
         .max(estimate_required_time_axis_height(style));
@@ -1,3 +1,5 @@
+use super::{TimeAxisLabelConfig, TimeAxisLabelPolicy, TimeAxisTimeUnit};
+
 pub(super) const AXIS_TIME_TARGET_SPACING_PX: f64 = 72.0;
 pub(super) const AXIS_TIME_MIN_SPACING_PX: f64 = 56.0;
 pub(super) const AXIS_PRICE_TARGET_SPACING_PX: f64 = 26.0;
@@ -225,6 +227,121 @@ pub(super) fn axis_ticks(range: (f64, f64), tick_count: usize) -> Vec<f64> {
         .collect()
 }
 
+/// Calendar-friendly UTC tick steps in seconds; longer steps use whole-day
+/// 1/2/5 multiples.
+const TIME_TICK_STEPS_SECONDS: [f64; 17] = [
+    1.0, 2.0, 5.0, 10.0, 15.0, 30.0, 60.0, 120.0, 300.0, 600.0, 900.0, 1_800.0, 3_600.0, 7_200.0,
+    10_800.0, 21_600.0, 43_200.0,
+];
+const SECONDS_PER_DAY: f64 = 86_400.0;
+
+/// Smallest 1/2/5 step not below `raw_step`.
+fn nice_step_at_least(raw_step: f64) -> f64 {
+    let magnitude = 10.0_f64.powf(raw_step.log10().floor());
+    let normalized = raw_step / magnitude;
+    let nice = if normalized <= 1.0 {
+        1.0
+    } else if normalized <= 2.0 {
+        2.0
+    } else if normalized <= 5.0 {
+        5.0
+    } else {
+        10.0
+    };
+    nice * magnitude
+}
+
+/// Time ticks on multiples of a nice step, so they stay on the same times
+/// while the range pans and only reflow when its span changes.
+///
+/// UTC policies pick calendar steps aligned to the configured timezone
+/// (whole days land on local midnight); `LogicalDecimal` uses 1/2/5 steps.
+pub(super) fn anchored_time_ticks(
+    range: (f64, f64),
+    tick_count: usize,
+    config: TimeAxisLabelConfig,
+) -> Vec<f64> {
+    let (start, end) = if range.0 <= range.1 {
+        range
+    } else {
+        (range.1, range.0)
+    };
+    let raw_step = (end - start) / (tick_count.max(2) - 1) as f64;
+    if tick_count < 2 || !raw_step.is_finite() || raw_step <= 0.0 {
+        return axis_ticks(range, tick_count);
+    }
+
+    let (step, offset) = match config.policy {
+        TimeAxisLabelPolicy::LogicalDecimal { .. } => (nice_step_at_least(raw_step), 0.0),
+        _ => {
+            let unit = match config.time_unit {
+                TimeAxisTimeUnit::Seconds => 1.0,
+                TimeAxisTimeUnit::Milliseconds => 1_000.0,
+            };
+            let raw_seconds = raw_step / unit;
+            let step_seconds = if raw_seconds < 1.0 {
+                nice_step_at_least(raw_seconds)
+            } else {
+                TIME_TICK_STEPS_SECONDS
+                    .into_iter()
+                    .find(|step| *step >= raw_seconds)
+                    .unwrap_or_else(|| {
+                        SECONDS_PER_DAY * nice_step_at_least(raw_seconds / SECONDS_PER_DAY)
+                    })
+            };
+            let offset_seconds = f64::from(config.timezone.offset_minutes()) * 60.0;
+            (step_seconds * unit, offset_seconds * unit)
+        }
+    };
+    let first_index = ((start + offset) / step - 1e-9).ceil() as i64;
+    let last_index = ((end + offset) / step + 1e-9).floor() as i64;
+    (first_index..=last_index)
+        .map(|index| index as f64 * step - offset)
+        .collect()
+}
+
+/// Ticks on multiples of a nice 1/2/5 step rounded up to a whole number of
+/// `min_move`s, so about `tick_count` ticks fall inside `range` and every
+/// tick is a representable price.
+pub(super) fn min_move_aligned_ticks(
+    range: (f64, f64),
+    tick_count: usize,
+    min_move: f64,
+) -> Vec<f64> {
+    let (start, end) = if range.0 <= range.1 {
+        range
+    } else {
+        (range.1, range.0)
+    };
+    if tick_count == 0 || !min_move.is_finite() || min_move <= 0.0 {
+        return Vec::new();
+    }
+    let raw_step = (end - start) / (tick_count.max(2) - 1) as f64;
+    let nice_step = if raw_step.is_finite() && raw_step > 0.0 {
+        let magnitude = 10.0_f64.powf(raw_step.log10().floor());
+        let normalized = raw_step / magnitude;
+        let nice = if normalized < 1.5 {
+            1.0
+        } else if normalized < 3.0 {
+            2.0
+        } else if normalized < 7.0 {
+            5.0
+        } else {
+            10.0
+        };
+        nice * magnitude
+    } else {
+        min_move
+    };
+    let step = min_move * (nice_step / min_move - 1e-9).ceil().max(1.0);
+    let first_index = (start / step).ceil() as i64;
+    let last_index = (end / step).floor() as i64;
+    (first_index..=last_index)
+        // Re-round on the min-move grid to drop float drift from the step.
+        .map(|index| ((index as f64 * step) / min_move).round() * min_move)
+        .collect()
+}
+
 pub(super) fn tick_step_hint_from_values(values: &[f64]) -> f64 {
     if values.len() <= 1 {
         return 0.0;
@@ -241,9 +358,88 @@ pub(super) fn tick_step_hint_from_values(values: &[f64]) -> f64 {
     if best.is_finite() { best } else { 0.0 }
 }
 
+/// Per-tick step to the nearest neighbour, so labels on uneven (log) ladders
+/// get precision from their local density instead of the densest region.
+pub(super) fn local_tick_steps(values: &[f64]) -> Vec<f64> {
+    (0..values.len())
+        .map(|index| {
+            let previous = index
+                .checked_sub(1)
+                .map(|previous| (values[index] - values[previous]).abs());
+            let next = values
+                .get(index + 1)
+                .map(|next| (next - values[index]).abs());
+            match (previous, next) {
+                (Some(previous), Some(next)) => previous.min(next),
+                (Some(step), None) | (None, Some(step)) => step,
+                (None, None) => 0.0,
+            }
+        })
+        .collect()
+}
+
+/// Minor positions splitting every gap between consecutive `major_positions`
+/// into `subdivisions` equal parts.
+///
+/// The outer gaps repeat the nearest major gap so minor lines continue up to
+/// `[min, max]`; gaps whose minor step would be under one pixel are skipped.
+pub(super) fn subdivided_minor_positions(
+    major_positions: &[f64],
+    subdivisions: u32,
+    min: f64,
+    max: f64,
+) -> Vec<f64> {
+    let mut majors: Vec<f64> = major_positions
+        .iter()
+        .copied()
+        .filter(|position| position.is_finite())
+        .collect();
+    majors.sort_by(f64::total_cmp);
+    majors.dedup_by(|right, left| (*right - *left).abs() < 1.0);
+    if subdivisions < 2 || majors.len() < 2 {
+        return Vec::new();
+    }
+
+    let parts = f64::from(subdivisions);
+    let mut minors = Vec::new();
+    let mut push_steps = |anchor: f64, step: f64, count: Option<u32>| {
+        if step.abs() < 1.0 {
+            return;
+        }
+        for index in 1.. {
+            if count.is_some_and(|count| index >= count) {
+                break;
+            }
+            let position = anchor + step * f64::from(index);
+            if position < min || position > max {
+                break;
+            }
+            if index % subdivisions != 0 {
+                minors.push(position);
+            }
+        }
+    };
+    for pair in majors.windows(2) {
+        push_steps(pair[0], (pair[1] - pair[0]) / parts, Some(subdivisions));
+    }
+    let last = majors.len() - 1;
+    push_steps(majors[0], -(majors[1] - majors[0]) / parts, None);
+    push_steps(
+        majors[last],
+        (majors[last] - majors[last - 1]) / parts,
+        None,
+    );
+    minors.sort_by(f64::total_cmp);
+    minors
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{density_scale_from_zoom_ratio, select_positions_with_min_spacing_prioritized};
+    use super::{TimeAxisLabelConfig, anchored_time_ticks};
+    use super::{
+        density_scale_from_zoom_ratio, min_move_aligned_ticks,
+        select_positions_with_min_spacing_prioritized, subdivided_minor_positions,
+    };
 
     #[test]
     fn density_scale_is_one_inside_neutral_band() {
@@ -299,4 +495,46 @@ mod tests {
         let ids: Vec<u8> = selected.iter().map(|(id, _, _)| *id).collect();
         assert_eq!(ids, vec![1, 2, 4]);
     }
+
+    #[test]
+    fn min_move_aligned_ticks_land_on_the_min_move_grid() {
+        let ticks = min_move_aligned_ticks((4001.1, 4004.9), 9, 0.25);
+        assert_eq!(
+            ticks,
+            vec![4001.5, 4002.0, 4002.5, 4003.0, 4003.5, 4004.0, 4004.5]
+        );
+
+        // Nice steps that are not min-move multiples round up to one.
+        let odd = min_move_aligned_ticks((0.0, 3.0), 6, 0.3);
+        assert_eq!(odd.len(), 6);
+        assert!((odd[1] - 0.6).abs() < 1e-12);
+
+        // Dense requests never go below one min move per step.
+        let dense = min_move_aligned_ticks((10.0, 11.0), 50, 0.25);
+        assert_eq!(dense, vec![10.0, 10.25, 10.5, 10.75, 11.0]);
+    }
+
+    #[test]
+    fn subdivided_minor_positions_split_gaps_and_extend_to_edges() {
+        let minors = subdivided_minor_positions(&[40.0, 80.0, 120.0], 4, 0.0, 140.0);
+        assert_eq!(
+            minors,
+            vec![
+                10.0, 20.0, 30.0, 50.0, 60.0, 70.0, 90.0, 100.0, 110.0, 130.0, 140.0
+            ]
+        );
+        assert!(subdivided_minor_positions(&[40.0, 80.0], 1, 0.0, 140.0).is_empty());
+        assert!(subdivided_minor_positions(&[40.0], 4, 0.0, 140.0).is_empty());
+    }
+
+    #[test]
+    fn anchored_time_ticks_keep_their_values_across_a_pan() {
+        let config = TimeAxisLabelConfig::default();
+        let start = 1_700_000_123.0;
+        let ticks = anchored_time_ticks((start, start + 36_000.0), 8, config);
+        let panned = anchored_time_ticks((start + 1_234.0, start + 37_234.0), 8, config);
+        assert!(ticks.iter().all(|tick| tick.rem_euclid(7_200.0) == 0.0));
+        assert!(panned.iter().all(|tick| tick.rem_euclid(7_200.0) == 0.0));
+        assert!(ticks.len() >= 4);
+    }
 }
@@ -1,6 +1,6 @@
 use super::RenderStyle;
 
-pub(super) fn estimate_required_time_axis_height(style: RenderStyle) -> f64 {
+pub(super) fn estimate_required_time_axis_height(style: &RenderStyle) -> f64 {
     let mut required: f64 = 0.0;
     if style.show_time_axis_tick_marks {
         required = required.max(style.time_axis_tick_mark_length_px);
@@ -1,23 +1,28 @@
+use tracing::trace_span;
+
 use crate::error::ChartResult;
-use crate::render::{CanvasLayerKind, Renderer, TextHAlign, TextPrimitive};
+use crate::render::{CanvasLayerKind, Renderer, TextHAlign, TextPrimitive, TextVAlign};
+use crate::telemetry::{FRAME_LABELS_SPAN, FRAME_TICKS_SPAN};
 
 use super::axis_label_format::is_major_time_tick;
 use super::axis_render_frame_builder::AxisPrimitiveSink;
 use super::axis_ticks::{
-    AXIS_TIME_MIN_SPACING_PX, axis_ticks, select_positions_with_min_spacing_prioritized,
+    AXIS_TIME_MIN_SPACING_PX, anchored_time_ticks, axis_ticks,
+    select_positions_with_min_spacing_prioritized, subdivided_minor_positions,
     tick_step_hint_from_values,
 };
-use super::layout_helpers::estimate_label_text_width_px;
-use super::{ChartEngine, RenderStyle};
+use super::{ChartEngine, ChartWarning, RenderStyle, WarningAxis};
+
+const TIME_AXIS_STICKY_LABEL_INSET_PX: f64 = 4.0;
 
 #[derive(Debug, Clone, Copy)]
-pub(super) struct AxisTimeSceneContext {
+pub(super) struct AxisTimeSceneContext<'a> {
     pub plot_right: f64,
     pub plot_bottom: f64,
     pub viewport_height: f64,
     pub visible_span_abs: f64,
     pub time_tick_count: usize,
-    pub style: RenderStyle,
+    pub style: &'a RenderStyle,
 }
 
 impl<R: Renderer> ChartEngine<R> {
@@ -33,12 +38,19 @@ impl<R: Renderer> ChartEngine<R> {
         let time_tick_count = ctx.time_tick_count;
         let style = ctx.style;
 
-        let raw_time_ticks =
-            axis_ticks(self.core.model.time_scale.visible_range(), time_tick_count);
+        let ticks_span = trace_span!(FRAME_TICKS_SPAN, axis = "time", ticks = 0_usize).entered();
+        let time_axis_label_config = self.core.behavior.time_axis_label_config;
+        let visible_range = self.core.model.time_scale.visible_range();
+        let raw_time_ticks = if time_axis_label_config.anchor_ticks_to_nice_steps {
+            anchored_time_ticks(visible_range, time_tick_count, time_axis_label_config)
+        } else {
+            axis_ticks(visible_range, time_tick_count)
+        };
         let time_tick_step_abs = tick_step_hint_from_values(&raw_time_ticks).abs();
         let mut time_label_min_spacing_px = AXIS_TIME_MIN_SPACING_PX;
+        let mut max_label_width_px: f64 = 0.0;
+        let mut max_label_font_size_px: f64 = 0.0;
         if style.show_time_axis_labels {
-            let mut max_label_width_px: f64 = 0.0;
             for time in raw_time_ticks.iter().copied() {
                 let is_major_tick =
                     is_major_time_tick(time, self.core.behavior.time_axis_label_config);
@@ -53,10 +65,13 @@ impl<R: Renderer> ChartEngine<R> {
                     time_tick_step_abs,
                     is_major_tick,
                 );
-                let measured_width = estimate_label_text_width_px(&text, label_font_size_px);
+                let measured_width = style
+                    .label_text_metrics
+                    .text_width_px(&text, label_font_size_px);
                 let capped_width =
                     measured_width.min(Self::lwc_time_label_width_budget_px(label_font_size_px));
                 max_label_width_px = max_label_width_px.max(capped_width);
+                max_label_font_size_px = max_label_font_size_px.max(label_font_size_px);
             }
             if max_label_width_px.is_finite() && max_label_width_px > 0.0 {
                 time_label_min_spacing_px = time_label_min_spacing_px
@@ -71,18 +86,57 @@ impl<R: Renderer> ChartEngine<R> {
                 .model
                 .time_scale
                 .time_to_pixel(time, self.core.model.viewport)?;
+            // Anchored ticks past the plot edge belong to other times; clamping
+            // them would draw a gridline at the wrong value.
+            if time_axis_label_config.anchor_ticks_to_nice_steps
+                && !(0.0..=plot_right).contains(&px)
+            {
+                continue;
+            }
             let clamped_px = px.clamp(0.0, plot_right);
-            let is_major_tick = is_major_time_tick(time, self.core.behavior.time_axis_label_config);
+            let is_major_tick = is_major_time_tick(time, time_axis_label_config);
             time_ticks.push((time, clamped_px, is_major_tick));
         }
+        // Rotate only when label width (not the base cadence) would drop
+        // ticks; the rotated bounding box is narrower, so more ticks keep labels.
+        let mut label_rotation_radians = 0.0;
+        if style.show_time_axis_labels
+            && style.time_axis_label_rotation_degrees > 0.0
+            && time_label_min_spacing_px > AXIS_TIME_MIN_SPACING_PX
+            && select_positions_with_min_spacing_prioritized(
+                time_ticks.clone(),
+                time_label_min_spacing_px,
+            )
+            .len()
+                < select_positions_with_min_spacing_prioritized(
+                    time_ticks.clone(),
+                    AXIS_TIME_MIN_SPACING_PX,
+                )
+                .len()
+        {
+            label_rotation_radians = style.time_axis_label_rotation_degrees.to_radians();
+            let footprint_px = rotated_label_footprint_px(
+                max_label_width_px,
+                max_label_font_size_px,
+                label_rotation_radians,
+            );
+            time_label_min_spacing_px =
+                time_label_min_spacing_px.min((footprint_px + 4.0).max(AXIS_TIME_MIN_SPACING_PX));
+        }
+        ticks_span.record("ticks", time_ticks.len());
+        ticks_span.exit();
 
+        let labels_span = trace_span!(FRAME_LABELS_SPAN, axis = "time", labels = 0_usize).entered();
+        let texts_before = sink.text_count();
+        let tick_candidates = time_ticks.len();
         let mut time_label_candidates: Vec<(TextPrimitive, bool)> = Vec::new();
+        let mut grid_tick_pixels = Vec::new();
         for (time, px, is_major_tick) in
             select_positions_with_min_spacing_prioritized(time_ticks, time_label_min_spacing_px)
         {
+            grid_tick_pixels.push(px);
             let (
-                grid_color,
-                grid_line_width,
+                grid_lines,
                 label_font_size_px,
                 label_offset_y_px,
                 label_color,
@@ -91,8 +145,7 @@ impl<R: Renderer> ChartEngine<R> {
                 tick_mark_length_px,
             ) = if is_major_tick {
                 (
-                    style.major_grid_line_color,
-                    style.major_grid_line_width,
+                    style.grid.major_vert_lines,
                     style.major_time_label_font_size_px,
                     style.major_time_label_offset_y_px,
                     style.major_time_label_color,
@@ -102,8 +155,7 @@ impl<R: Renderer> ChartEngine<R> {
                 )
             } else {
                 (
-                    style.grid_line_color,
-                    style.grid_line_width,
+                    style.grid.vert_lines,
                     style.time_axis_label_font_size_px,
                     style.time_axis_label_offset_y_px,
                     style.time_axis_label_color,
@@ -121,8 +173,31 @@ impl<R: Renderer> ChartEngine<R> {
                 is_major_tick,
             );
             if style.show_time_axis_labels && (!is_major_tick || style.show_major_time_labels) {
-                let estimated_width = estimate_label_text_width_px(&text, label_font_size_px);
-                if estimated_width <= (plot_right - 2.0).max(0.0) {
+                let estimated_width = style
+                    .label_text_metrics
+                    .text_width_px(&text, label_font_size_px);
+                if label_rotation_radians > 0.0 {
+                    // Anchored at the text end, so the label hangs down-left of its tick.
+                    let footprint_px = rotated_label_footprint_px(
+                        estimated_width,
+                        label_font_size_px,
+                        label_rotation_radians,
+                    )
+                    .min(plot_right);
+                    time_label_candidates.push((
+                        TextPrimitive::new(
+                            text,
+                            px.clamp(footprint_px, plot_right.max(footprint_px)),
+                            time_label_y,
+                            label_font_size_px,
+                            label_color,
+                            TextHAlign::Right,
+                        )
+                        .with_v_align(TextVAlign::Top)
+                        .with_rotation(-label_rotation_radians),
+                        is_major_tick,
+                    ));
+                } else if estimated_width <= (plot_right - 2.0).max(0.0) {
                     let half_width = (estimated_width * 0.5).clamp(0.0, plot_right * 0.5);
                     let time_label_x =
                         px.clamp(half_width, (plot_right - half_width).max(half_width));
@@ -139,7 +214,7 @@ impl<R: Renderer> ChartEngine<R> {
                     ));
                 }
             }
-            if !is_major_tick || style.show_major_time_grid_lines {
+            if style.grid.vert_lines.visible && grid_lines.visible {
                 sink.push_line(
                     CanvasLayerKind::Grid,
                     crate::render::LinePrimitive::new(
@@ -147,9 +222,10 @@ impl<R: Renderer> ChartEngine<R> {
                         0.0,
                         px,
                         plot_bottom,
-                        grid_line_width,
-                        grid_color,
-                    ),
+                        grid_lines.width,
+                        grid_lines.color,
+                    )
+                    .with_stroke_style(grid_lines.style),
                 );
             }
             if style.show_time_axis_tick_marks
@@ -169,6 +245,43 @@ impl<R: Renderer> ChartEngine<R> {
             }
         }
 
+        let minor_lines = style.grid.minor_vert_lines;
+        if minor_lines.visible {
+            for px in subdivided_minor_positions(
+                &grid_tick_pixels,
+                style.grid.minor_vert_subdivisions,
+                0.0,
+                plot_right,
+            ) {
+                sink.push_line(
+                    CanvasLayerKind::Grid,
+                    crate::render::LinePrimitive::new(
+                        px,
+                        0.0,
+                        px,
+                        plot_bottom,
+                        minor_lines.width,
+                        minor_lines.color,
+                    )
+                    .with_stroke_style(minor_lines.style),
+                );
+            }
+        }
+
+        let sticky_date_label =
+            if style.show_time_axis_labels && style.show_time_axis_sticky_date_label {
+                self.build_time_axis_sticky_date_label(plot_bottom, viewport_height, style)
+            } else {
+                None
+            };
+        let sticky_date_label_right = sticky_date_label.as_ref().map(|label| {
+            label.x
+                + style
+                    .label_text_metrics
+                    .text_width_px(&label.text, label.font_size_px)
+                + TIME_AXIS_STICKY_LABEL_INSET_PX
+        });
+
         if !time_label_candidates.is_empty() {
             let index_candidates: Vec<(usize, f64, bool)> = time_label_candidates
                 .iter()
@@ -200,12 +313,69 @@ impl<R: Renderer> ChartEngine<R> {
                     selected_labels.pop();
                 }
             }
+            if tick_candidates >= 2 && selected_labels.len() < 2 {
+                self.emit_warning(ChartWarning::TicksDropped {
+                    axis: WarningAxis::Time,
+                    candidates: tick_candidates,
+                    kept: selected_labels.len(),
+                });
+            }
+
+            if let Some(sticky_right) = sticky_date_label_right {
+                selected_labels.retain(|(label, _)| {
+                    let width = style
+                        .label_text_metrics
+                        .text_width_px(&label.text, label.font_size_px);
+                    let left_extent = if label.is_rotated() {
+                        rotated_label_footprint_px(
+                            width,
+                            label.font_size_px,
+                            label.rotation_radians,
+                        )
+                    } else {
+                        width * 0.5
+                    };
+                    label.x - left_extent >= sticky_right
+                });
+            }
 
             for (label, _) in selected_labels {
                 sink.push_text(CanvasLayerKind::Axis, label);
             }
         }
+        if let Some(label) = sticky_date_label {
+            sink.push_text(CanvasLayerKind::Axis, label);
+        }
+        labels_span.record("labels", sink.text_count() - texts_before);
 
         Ok(())
     }
+
+    /// Left-aligned date of the left-most visible bar, styled like a major
+    /// time label so it reads as context rather than a regular tick.
+    fn build_time_axis_sticky_date_label(
+        &self,
+        plot_bottom: f64,
+        viewport_height: f64,
+        style: &RenderStyle,
+    ) -> Option<TextPrimitive> {
+        let time = self.resolve_left_edge_bar_time()?;
+        let text = self.format_time_axis_date_label(time)?;
+        let font_size_px = style.major_time_label_font_size_px;
+        let y = (plot_bottom + style.major_time_label_offset_y_px)
+            .min((viewport_height - font_size_px).max(0.0));
+        Some(TextPrimitive::new(
+            text,
+            TIME_AXIS_STICKY_LABEL_INSET_PX,
+            y,
+            font_size_px,
+            style.major_time_label_color,
+            TextHAlign::Left,
+        ))
+    }
+}
+
+/// Horizontal extent of a `width_px` x `height_px` label rotated by `radians`.
+fn rotated_label_footprint_px(width_px: f64, height_px: f64, radians: f64) -> f64 {
+    width_px * radians.cos().abs() + height_px * radians.sin().abs()
 }
@@ -0,0 +1,262 @@
+use crate::core::PaneId;
+use crate::error::ChartResult;
+use crate::render::{
+    CanvasLayerKind, ClipRect, LayeredRenderFrame, RectPrimitive, RenderFrame, Renderer,
+};
+
+use super::{
+    BackgroundBandMode, ChartEngine, RenderStyle, TimeAxisLabelConfig, TimeAxisLabelPolicy,
+    TimeAxisSessionConfig,
+};
+
+const SECONDS_PER_DAY: f64 = 86_400.0;
+/// Upper bound on visible local days before band shading is skipped as too dense.
+const MAX_BACKGROUND_BAND_DAYS: f64 = 4_096.0;
+
+#[derive(Debug, Clone, Copy)]
+pub(super) struct BackgroundRenderContext<'a> {
+    pub main_pane_id: PaneId,
+    pub viewport_width: f64,
+    pub viewport_height: f64,
+    pub visible_start: f64,
+    pub visible_end: f64,
+    pub plot_clip: ClipRect,
+    pub style: &'a RenderStyle,
+}
+
+impl<R: Renderer> ChartEngine<R> {
+    /// Emits viewport/plot fills and shaded bands into the background layer.
+    pub(super) fn append_background_primitives(
+        &self,
+        frame: &mut RenderFrame,
+        layered: &mut LayeredRenderFrame,
+        ctx: BackgroundRenderContext,
+    ) -> ChartResult<()> {
+        let mut push_rect = |rect: RectPrimitive| {
+            frame.rects.push(rect);
+            layered.push_rect(ctx.main_pane_id, CanvasLayerKind::Background, rect);
+        };
+        let style = ctx.style;
+        if let Some(color) = style.background_color {
+            if ctx.viewport_width > 0.0 && ctx.viewport_height > 0.0 {
+                push_rect(RectPrimitive::new(
+                    0.0,
+                    0.0,
+                    ctx.viewport_width,
+                    ctx.viewport_height,
+                    color,
+                ));
+            }
+        }
+        let plot = ctx.plot_clip;
+        if plot.is_empty() {
+            return Ok(());
+        }
+        if let Some(color) = style.plot_background_color {
+            push_rect(RectPrimitive::new(
+                plot.x,
+                plot.y,
+                plot.width,
+                plot.height,
+                color,
+            ));
+        }
+
+        let intervals = resolve_background_band_intervals(
+            style.background_band_mode,
+            self.core.behavior.time_axis_label_config,
+            ctx.visible_start,
+            ctx.visible_end,
+        );
+        for (start, end) in intervals {
+            let x1 = self
+                .core
+                .model
+                .time_scale
+                .time_to_pixel(start, self.core.model.viewport)?
+                .clamp(plot.x, plot.right());
+            let x2 = self
+                .core
+                .model
+                .time_scale
+                .time_to_pixel(end, self.core.model.viewport)?
+                .clamp(plot.x, plot.right());
+            let width = (x2 - x1).abs();
+            if width <= 0.0 {
+                continue;
+            }
+            push_rect(
+                RectPrimitive::new(
+                    x1.min(x2),
+                    plot.y,
+                    width,
+                    plot.height,
+                    style.background_band_color,
+                )
+                .with_clip_rect(plot),
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Resolves shaded `[start, end]` time intervals intersecting the visible range.
+///
+/// Intervals are merged when adjacent so each contiguous band yields one rect.
+pub(super) fn resolve_background_band_intervals(
+    mode: BackgroundBandMode,
+    config: TimeAxisLabelConfig,
+    visible_start: f64,
+    visible_end: f64,
+) -> Vec<(f64, f64)> {
+    if mode == BackgroundBandMode::None
+        || matches!(config.policy, TimeAxisLabelPolicy::LogicalDecimal { .. })
+        || !visible_start.is_finite()
+        || !visible_end.is_finite()
+    {
+        return Vec::new();
+    }
+    let unit = config.time_unit;
+    let (visible_start, visible_end) = (
+        unit.to_seconds(visible_start.min(visible_end)),
+        unit.to_seconds(visible_start.max(visible_end)),
+    );
+    let offset = f64::from(config.timezone.offset_minutes()) * 60.0;
+    let first_day = ((visible_start + offset) / SECONDS_PER_DAY).floor();
+    let last_day = ((visible_end + offset) / SECONDS_PER_DAY).floor();
+    if last_day - first_day > MAX_BACKGROUND_BAND_DAYS {
+        return Vec::new();
+    }
+
+    let mut intervals: Vec<(f64, f64)> = Vec::new();
+    let mut push_local = |local_start: f64, local_end: f64| {
+        let start = (local_start - offset).max(visible_start);
+        let end = (local_end - offset).min(visible_end);
+        if end <= start {
+            return;
+        }
+        match intervals.last_mut() {
+            Some(last) if last.1 >= start => last.1 = last.1.max(end),
+            _ => intervals.push((start, end)),
+        }
+    };
+
+    let mut day = first_day;
+    while day <= last_day {
+        let day_start = day * SECONDS_PER_DAY;
+        let day_end = day_start + SECONDS_PER_DAY;
+        match mode {
+            BackgroundBandMode::None => {}
+            BackgroundBandMode::AlternatingDays => {
+                if day.rem_euclid(2.0) == 1.0 {
+                    push_local(day_start, day_end);
+                }
+            }
+            BackgroundBandMode::Weekends => {
+                // 1970-01-01 was a Thursday; 0 = Sunday, 6 = Saturday.
+                let weekday = (day + 4.0).rem_euclid(7.0);
+                if weekday == 0.0 || weekday == 6.0 {
+                    push_local(day_start, day_end);
+                }
+            }
+            BackgroundBandMode::OutsideSession => {
+                let Some(session) = config.session else {
+                    return Vec::new();
+                };
+                for (start, end) in outside_session_local_ranges(session) {
+                    push_local(day_start + start, day_start + end);
+                }
+            }
+        }
+        day += 1.0;
+    }
+    intervals
+        .into_iter()
+        .map(|(start, end)| (unit.seconds_to_unit(start), unit.seconds_to_unit(end)))
+        .collect()
+}
+
+/// Returns the out-of-session ranges of one local day in seconds from midnight.
+fn outside_session_local_ranges(session: TimeAxisSessionConfig) -> Vec<(f64, f64)> {
+    let start = f64::from(session.start_minute_of_day()) * 60.0;
+    let end = f64::from(session.end_minute_of_day()) * 60.0;
+    if start < end {
+        vec![(0.0, start), (end, SECONDS_PER_DAY)]
+    } else {
+        vec![(end, start)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::resolve_background_band_intervals;
+    use crate::api::{
+        BackgroundBandMode, TimeAxisLabelConfig, TimeAxisLabelPolicy, TimeAxisSessionConfig,
+        TimeAxisTimeZone,
+    };
+
+    const DAY: f64 = 86_400.0;
+
+    #[test]
+    fn alternating_days_shade_odd_local_days() {
+        let intervals = resolve_background_band_intervals(
+            BackgroundBandMode::AlternatingDays,
+            TimeAxisLabelConfig::default(),
+            0.0,
+            4.0 * DAY,
+        );
+        assert_eq!(intervals, vec![(DAY, 2.0 * DAY), (3.0 * DAY, 4.0 * DAY)]);
+    }
+
+    #[test]
+    fn weekends_merge_saturday_and_sunday_in_local_timezone() {
+        let config = TimeAxisLabelConfig {
+            timezone: TimeAxisTimeZone::FixedOffsetMinutes { minutes: 60 },
+            ..TimeAxisLabelConfig::default()
+        };
+        // 1970-01-03 (Saturday) through 1970-01-05 (Monday).
+        let intervals =
+            resolve_background_band_intervals(BackgroundBandMode::Weekends, config, 0.0, 7.0 * DAY);
+        assert_eq!(intervals, vec![(2.0 * DAY - 3_600.0, 4.0 * DAY - 3_600.0)]);
+    }
+
+    #[test]
+    fn outside_session_requires_session_and_shades_complement() {
+        let session = TimeAxisSessionConfig {
+            start_hour: 9,
+            start_minute: 30,
+            end_hour: 16,
+            end_minute: 0,
+        };
+        let config = TimeAxisLabelConfig {
+            session: Some(session),
+            ..TimeAxisLabelConfig::default()
+        };
+        let intervals =
+            resolve_background_band_intervals(BackgroundBandMode::OutsideSession, config, 0.0, DAY);
+        assert_eq!(intervals, vec![(0.0, 34_200.0), (57_600.0, DAY)]);
+
+        let without_session = resolve_background_band_intervals(
+            BackgroundBandMode::OutsideSession,
+            TimeAxisLabelConfig::default(),
+            0.0,
+            DAY,
+        );
+        assert!(without_session.is_empty());
+    }
+
+    #[test]
+    fn logical_time_policy_disables_bands() {
+        let config = TimeAxisLabelConfig {
+            policy: TimeAxisLabelPolicy::LogicalDecimal { precision: 2 },
+            ..TimeAxisLabelConfig::default()
+        };
+        let intervals = resolve_background_band_intervals(
+            BackgroundBandMode::AlternatingDays,
+            config,
+            0.0,
+            4.0 * DAY,
+        );
+        assert!(intervals.is_empty());
+    }
+}
@@ -0,0 +1,169 @@
+use crate::core::PaneId;
+use crate::error::{ChartError, ChartResult};
+use crate::render::{
+    CanvasLayerKind, ClipRect, LayeredRenderFrame, RectPrimitive, RenderFrame, Renderer,
+    TextHAlign, TextPrimitive, TextVAlign,
+};
+
+use super::axis_label_format::format_bar_countdown;
+use super::validation::validate_bar_countdown_behavior;
+use super::{BarCountdownBehavior, ChartEngine, CrosshairTimeLabelMode, RelativeTimeAnchor};
+
+impl<R: Renderer> ChartEngine<R> {
+    #[must_use]
+    pub fn bar_countdown_behavior(&self) -> BarCountdownBehavior {
+        self.core.behavior.bar_countdown_behavior
+    }
+
+    pub fn set_bar_countdown_behavior(
+        &mut self,
+        behavior: BarCountdownBehavior,
+    ) -> ChartResult<()> {
+        validate_bar_countdown_behavior(behavior)?;
+        if self.core.behavior.bar_countdown_behavior != behavior {
+            self.core.behavior.bar_countdown_behavior = behavior;
+            self.invalidate_full();
+        }
+        Ok(())
+    }
+
+    /// Advances the clock behind the bar-close countdown to `now`, in the
+    /// same time units as the data.
+    ///
+    /// Hosts call this from a timer (once a second is enough); the axis is
+    /// only invalidated when the visible countdown text changes. The same
+    /// clock anchors `RelativeTimeAnchor::Now`, so a visible crosshair with
+    /// that anchor is repainted on every tick that moves the clock.
+    pub fn bar_clock_tick(&mut self, now: f64) -> ChartResult<()> {
+        if !now.is_finite() {
+            return Err(ChartError::InvalidData(
+                "bar clock time must be finite".to_owned(),
+            ));
+        }
+        let before = self.bar_countdown_text();
+        let previous = self.core.runtime.bar_clock_time.replace(now);
+        if self.core.behavior.bar_countdown_behavior.visible && self.bar_countdown_text() != before
+        {
+            self.invalidate_axis();
+        }
+        let anchored_on_clock = matches!(
+            self.core
+                .presentation
+                .render_style
+                .crosshair_time_label_mode,
+            CrosshairTimeLabelMode::Relative(RelativeTimeAnchor::Now)
+                | CrosshairTimeLabelMode::AbsoluteWithRelative(RelativeTimeAnchor::Now)
+        );
+        if anchored_on_clock
+            && previous != Some(now)
+            && self.core.model.interaction.crosshair().visible
+        {
+            self.invalidate_cursor();
+        }
+        Ok(())
+    }
+
+    /// Time last passed to `bar_clock_tick`.
+    #[must_use]
+    pub fn bar_clock_time(&self) -> Option<f64> {
+        self.core.runtime.bar_clock_time
+    }
+
+    /// Time units left until the latest bar closes, clamped to
+    /// `0..=interval`.
+    ///
+    /// `None` before the first clock tick or without a bar interval.
+    #[must_use]
+    pub fn bar_close_countdown(&self) -> Option<f64> {
+        let now = self.core.runtime.bar_clock_time?;
+        let (open, interval) = self.latest_bar_open_and_interval()?;
+        Some((open + interval - now).clamp(0.0, interval))
+    }
+
+    /// Countdown label text, e.g. `00:42`.
+    #[must_use]
+    pub fn bar_countdown_text(&self) -> Option<String> {
+        let remaining = self.bar_close_countdown()?;
+        let time_unit = self.effective_time_axis_label_config().time_unit;
+        Some(format_bar_countdown(time_unit.to_seconds(remaining)))
+    }
+
+    /// Draws the countdown box at the top of the time-axis panel, centered
+    /// under the latest bar and kept inside the plot width.
+    pub(super) fn append_bar_countdown_primitives(
+        &self,
+        frame: &mut RenderFrame,
+        layered: &mut LayeredRenderFrame,
+        main_pane_id: PaneId,
+        plot_right: f64,
+        time_axis_clip: ClipRect,
+    ) -> ChartResult<()> {
+        let behavior = self.core.behavior.bar_countdown_behavior;
+        if !behavior.visible {
+            return Ok(());
+        }
+        let (Some(text), Some((open, _))) = (
+            self.bar_countdown_text(),
+            self.latest_bar_open_and_interval(),
+        ) else {
+            return Ok(());
+        };
+        let bar_x = self.map_x_to_pixel(open)?;
+        if !(0.0..=plot_right).contains(&bar_x) {
+            return Ok(());
+        }
+        let metrics = self.core.presentation.render_style.label_text_metrics;
+        let width = metrics.text_width_px(&text, behavior.font_size_px) + 2.0 * behavior.padding_px;
+        let height = behavior.font_size_px + 2.0 * behavior.padding_px;
+        let left = (bar_x - width * 0.5).clamp(0.0, (plot_right - width).max(0.0));
+        let top = time_axis_clip.y;
+
+        let background = RectPrimitive::new(left, top, width, height, behavior.background_color)
+            .with_clip_rect(time_axis_clip);
+        frame.rects.push(background);
+        layered.push_rect(main_pane_id, CanvasLayerKind::Axis, background);
+
+        let label = TextPrimitive::new(
+            text,
+            left + width * 0.5,
+            top + height * 0.5,
+            behavior.font_size_px,
+            behavior.text_color,
+            TextHAlign::Center,
+        )
+        .with_v_align(TextVAlign::Middle)
+        .with_clip_rect(time_axis_clip);
+        frame.texts.push(label.clone());
+        layered.push_text(main_pane_id, CanvasLayerKind::Axis, label);
+        Ok(())
+    }
+
+    /// Open time of the latest candle (or point without candles) and the bar
+    /// interval, configured or taken from the last two bars.
+    fn latest_bar_open_and_interval(&self) -> Option<(f64, f64)> {
+        let model = &self.core.model;
+        let times: Vec<f64> = if model.candles.is_empty() {
+            model
+                .points
+                .iter()
+                .rev()
+                .take(2)
+                .map(|point| point.x)
+                .collect()
+        } else {
+            model
+                .candles
+                .iter()
+                .rev()
+                .take(2)
+                .map(|bar| bar.time)
+                .collect()
+        };
+        let open = *times.first()?;
+        let interval = match self.core.behavior.bar_countdown_behavior.bar_interval {
+            Some(interval) => interval,
+            None => open - *times.get(1)?,
+        };
+        (interval.is_finite() && interval > 0.0).then_some((open, interval))
+    }
+}
@@ -4,7 +4,7 @@ use crate::core::OhlcBar;
 use crate::error::ChartResult;
 use crate::render::{Color, LineStrokeStyle};
 
-use super::{CandlestickBodyMode, LastPriceSourceMode, RenderStyle};
+use super::{CandlestickBodyMode, LastPriceOffscreenPolicy, LastPriceSourceMode, RenderStyle};
 
 fn default_true() -> bool {
     true
@@ -17,6 +17,49 @@ pub struct TimeScaleEdgeBehavior {
     pub fix_right_edge: bool,
 }
 
+/// Rubber-band overscroll for drag pans against an edge fixed by
+/// `TimeScaleEdgeBehavior`.
+///
+/// While enabled, a drag may pull the visible range past a fixed edge with
+/// growing resistance, and `pan_end` springs it back through
+/// `ChartEngine::step_animations`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ElasticOverscrollBehavior {
+    pub enabled: bool,
+    /// Displacement past the edge that the pull approaches but never
+    /// reaches.
+    pub max_overscroll_px: f64,
+    /// Duration of the eased return to the edge; `0` snaps back at once.
+    pub spring_back_seconds: f64,
+}
+
+impl Default for ElasticOverscrollBehavior {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_overscroll_px: 80.0,
+            spring_back_seconds: 0.3,
+        }
+    }
+}
+
+impl TimeScaleEdgeBehavior {
+    /// Both edges fixed: pans and zooms keep the visible range inside the
+    /// data bounds.
+    #[must_use]
+    pub const fn locked_to_data_bounds() -> Self {
+        Self {
+            fix_left_edge: true,
+            fix_right_edge: true,
+        }
+    }
+
+    #[must_use]
+    pub const fn is_locked_to_data_bounds(self) -> bool {
+        self.fix_left_edge && self.fix_right_edge
+    }
+}
+
 /// Host-configurable interaction input gates aligned with Lightweight Charts
 /// `handleScroll` / `handleScale` behavior families.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -150,6 +193,10 @@ pub struct TimeScaleZoomLimitBehavior {
     pub min_bar_spacing_px: f64,
     /// Optional maximum allowed spacing between bars in pixels (zoom-in limit).
     pub max_bar_spacing_px: Option<f64>,
+    /// Optional cap on the visible span as a multiple of the full data span
+    /// (zoom-out limit); the bar-spacing limits win when the two conflict.
+    #[serde(default)]
+    pub max_visible_span_ratio: Option<f64>,
 }
 
 impl Default for TimeScaleZoomLimitBehavior {
@@ -157,6 +204,7 @@ impl Default for TimeScaleZoomLimitBehavior {
         Self {
             min_bar_spacing_px: 0.5,
             max_bar_spacing_px: None,
+            max_visible_span_ratio: None,
         }
     }
 }
@@ -282,6 +330,446 @@ impl Default for PriceScaleMarginBehavior {
     }
 }
 
+/// Pane separator drag behavior.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PaneSeparatorBehavior {
+    /// Whether pointer drags on a separator resize the adjacent panes.
+    pub draggable: bool,
+    /// Half-height of the hit zone centered on each separator, in pixels.
+    pub hit_zone_half_height_px: f64,
+    /// Height a separator drag never shrinks an adjacent pane below.
+    pub min_pane_height_px: f64,
+}
+
+impl Default for PaneSeparatorBehavior {
+    fn default() -> Self {
+        Self {
+            draggable: true,
+            hit_zone_half_height_px: 4.0,
+            min_pane_height_px: 30.0,
+        }
+    }
+}
+
+/// Where the OHLC info line readout ends up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum OhlcInfoLineMode {
+    #[default]
+    Hidden,
+    /// Drawn in the top-left corner of the main pane.
+    Rendered,
+    /// Exposed through `ChartEngine::ohlc_info_line` only; the host draws it.
+    DataOnly,
+}
+
+/// TradingView-style O/H/L/C + change readout for the hovered or latest bar.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct OhlcInfoLineBehavior {
+    pub mode: OhlcInfoLineMode,
+    pub font_size_px: f64,
+    /// Offset of the row from the plot's top-left corner.
+    pub padding_px: f64,
+    /// Horizontal gap between fields.
+    pub field_gap_px: f64,
+    /// Color of the field labels (`O`, `H`, `L`, `C`).
+    pub label_color: Color,
+    /// Value color when the bar or change is flat.
+    pub neutral_color: Color,
+}
+
+impl Default for OhlcInfoLineBehavior {
+    fn default() -> Self {
+        Self {
+            mode: OhlcInfoLineMode::Hidden,
+            font_size_px: 12.0,
+            padding_px: 8.0,
+            field_gap_px: 8.0,
+            label_color: Color::rgb(0.47, 0.49, 0.53),
+            neutral_color: Color::rgb(0.2, 0.22, 0.25),
+        }
+    }
+}
+
+/// Badge geometry for time-scale marks along the bottom of the time axis.
+///
+/// Marks share the time-axis panel with tick labels; raise
+/// `RenderStyle::time_axis_height_px` to keep the two rows apart.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct TimeScaleMarkBehavior {
+    pub visible: bool,
+    /// Diameter of each round badge.
+    pub size_px: f64,
+    pub font_size_px: f64,
+    /// Gap between the badges and the bottom edge of the time axis.
+    pub bottom_padding_px: f64,
+}
+
+impl Default for TimeScaleMarkBehavior {
+    fn default() -> Self {
+        Self {
+            visible: true,
+            size_px: 14.0,
+            font_size_px: 9.0,
+            bottom_padding_px: 2.0,
+        }
+    }
+}
+
+/// Scale axis addressed by the axis interaction entry points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ChartAxis {
+    Price,
+    Time,
+}
+
+/// Drag/wheel tuning of one axis for `ChartEngine::handle_axis_drag` and
+/// `ChartEngine::handle_axis_wheel`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct AxisInteractionOptions {
+    pub drag_enabled: bool,
+    pub wheel_enabled: bool,
+    /// Zoom step ratio per `120` pixels of drag.
+    pub drag_sensitivity: f64,
+    /// Zoom step ratio per wheel notch (`120` delta units).
+    pub wheel_sensitivity: f64,
+    /// Smallest span scaling may reach, in axis units.
+    pub min_span: f64,
+    /// Flips the zoom direction of drags and wheel steps.
+    pub invert_direction: bool,
+}
+
+impl AxisInteractionOptions {
+    #[must_use]
+    fn with_min_span(min_span: f64) -> Self {
+        Self {
+            drag_enabled: true,
+            wheel_enabled: true,
+            drag_sensitivity: 0.16,
+            wheel_sensitivity: 0.12,
+            min_span,
+            invert_direction: false,
+        }
+    }
+}
+
+/// Per-axis interaction tuning held by the engine, so hosts configure step
+/// ratios and minimum spans once instead of on every call.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct AxisInteractionConfig {
+    pub price: AxisInteractionOptions,
+    pub time: AxisInteractionOptions,
+}
+
+impl Default for AxisInteractionConfig {
+    fn default() -> Self {
+        Self {
+            price: AxisInteractionOptions::with_min_span(1e-7),
+            time: AxisInteractionOptions::with_min_span(1.0),
+        }
+    }
+}
+
+impl AxisInteractionConfig {
+    #[must_use]
+    pub fn axis(self, axis: ChartAxis) -> AxisInteractionOptions {
+        match axis {
+            ChartAxis::Price => self.price,
+            ChartAxis::Time => self.time,
+        }
+    }
+}
+
+/// Scroll direction of a wheel delta.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WheelAxis {
+    Vertical,
+    Horizontal,
+}
+
+/// Mapping of wheel deltas onto time-scale zoom and pan for
+/// `ChartEngine::handle_wheel`.
+///
+/// The default matches the usual host wiring: vertical wheel zooms around
+/// the pointer, horizontal wheel pans.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct WheelBehaviorConfig {
+    /// Wheel axis zooming the time scale; `None` disables wheel zoom.
+    pub zoom_axis: Option<WheelAxis>,
+    /// Wheel axis panning the time scale; `None` disables wheel pan.
+    pub pan_axis: Option<WheelAxis>,
+    /// Zooms only while control is held; otherwise the zoom axis falls
+    /// back to panning when it is also `pan_axis`.
+    pub zoom_requires_control: bool,
+    /// Flips zoom and pan direction (natural scrolling).
+    pub invert_direction: bool,
+    /// Multiplier applied to vertical wheel deltas.
+    pub vertical_sensitivity: f64,
+    /// Multiplier applied to horizontal wheel deltas.
+    pub horizontal_sensitivity: f64,
+    /// Zoom step ratio per wheel notch.
+    pub zoom_step_ratio: f64,
+    /// Pan step ratio per wheel notch.
+    pub pan_step_ratio: f64,
+}
+
+impl Default for WheelBehaviorConfig {
+    fn default() -> Self {
+        Self {
+            zoom_axis: Some(WheelAxis::Vertical),
+            pan_axis: Some(WheelAxis::Horizontal),
+            zoom_requires_control: false,
+            invert_direction: false,
+            vertical_sensitivity: 1.0,
+            horizontal_sensitivity: 1.0,
+            zoom_step_ratio: 0.12,
+            pan_step_ratio: 0.16,
+        }
+    }
+}
+
+/// Rubber-band zoom selection (`ChartEngine::begin_zoom_selection`), e.g.
+/// shift+drag through `PointerInputBridge`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ZoomSelectionBehavior {
+    /// Also fits the price domain to the selection's vertical extent.
+    pub zoom_price: bool,
+    /// Selections narrower than this (or, with `zoom_price`, shorter) are
+    /// cancelled on release instead of zooming.
+    pub min_size_px: f64,
+    pub fill_color: Color,
+    pub border_color: Color,
+    pub border_width_px: f64,
+}
+
+impl Default for ZoomSelectionBehavior {
+    fn default() -> Self {
+        Self {
+            zoom_price: false,
+            min_size_px: 4.0,
+            fill_color: Color::rgba(0.16, 0.38, 1.0, 0.12),
+            border_color: Color::rgba(0.16, 0.38, 1.0, 0.8),
+            border_width_px: 1.0,
+        }
+    }
+}
+
+/// Plot corner the debug overlay is anchored to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum DebugOverlayCorner {
+    TopLeft,
+    #[default]
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+/// Opt-in in-chart panel with frame diagnostics (see
+/// `ChartEngine::debug_overlay_stats`).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct DebugOverlayBehavior {
+    pub enabled: bool,
+    pub corner: DebugOverlayCorner,
+    pub font_size_px: f64,
+    /// Gap between the panel and the plot edges, and between the panel
+    /// border and its text.
+    pub padding_px: f64,
+    pub text_color: Color,
+    pub background_color: Color,
+}
+
+impl Default for DebugOverlayBehavior {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            corner: DebugOverlayCorner::TopRight,
+            font_size_px: 11.0,
+            padding_px: 6.0,
+            text_color: Color::rgb(0.92, 0.94, 0.96),
+            background_color: Color::rgba(0.08, 0.09, 0.11, 0.78),
+        }
+    }
+}
+
+/// Frame-rate cap applied by `ChartEngine::redraw_due` to frame-clock
+/// driven redraws.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct RedrawThrottleBehavior {
+    /// Maximum redraws per second; `None` redraws on every tick with pending
+    /// invalidation.
+    pub max_fps: Option<f64>,
+}
+
+/// Power-saving mode for always-on charts, driven by `ChartEngine::power_tick`.
+///
+/// After `idle_after_seconds` without interaction or data update the engine
+/// goes idle: `redraw_due` grants at most one redraw per
+/// `batch_interval_seconds` and data commands from an `EngineCommandQueue`
+/// are held and applied in one batch per interval. Any interaction resumes
+/// immediately.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct IdlePowerBehavior {
+    /// `None` disables idle detection.
+    pub idle_after_seconds: Option<f64>,
+    pub batch_interval_seconds: f64,
+}
+
+impl Default for IdlePowerBehavior {
+    fn default() -> Self {
+        Self {
+            idle_after_seconds: None,
+            batch_interval_seconds: 1.0,
+        }
+    }
+}
+
+/// Missing-bar detection behind `ChartEngine::detected_gaps`.
+///
+/// Spacing wider than `expected_interval * tolerance` is a gap unless every
+/// missing bar would fall outside the time-axis session (or on a weekend
+/// with `skip_weekends`), i.e. the market was closed rather than the feed
+/// dropping data.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct DataGapBehavior {
+    /// Bar interval in time units; `None` uses the median bar spacing.
+    pub expected_interval: Option<f64>,
+    /// Multiple of the interval a spacing must exceed to count as a gap.
+    pub tolerance: f64,
+    pub skip_weekends: bool,
+    /// Draws a thin marker along the top of the time axis over each gap.
+    pub show_markers: bool,
+    pub marker_color: Color,
+    pub marker_height_px: f64,
+}
+
+impl Default for DataGapBehavior {
+    fn default() -> Self {
+        Self {
+            expected_interval: None,
+            tolerance: 1.5,
+            skip_weekends: false,
+            show_markers: false,
+            marker_color: Color::rgba(0.937, 0.325, 0.314, 0.6),
+            marker_height_px: 2.0,
+        }
+    }
+}
+
+/// Live countdown to the current bar's close, drawn on the time axis under
+/// the latest bar.
+///
+/// The engine reads no wall clock; hosts feed the current time through
+/// `ChartEngine::bar_clock_tick`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct BarCountdownBehavior {
+    pub visible: bool,
+    /// Bar duration in time units; `None` uses the spacing of the last two
+    /// bars.
+    pub bar_interval: Option<f64>,
+    pub font_size_px: f64,
+    pub padding_px: f64,
+    pub text_color: Color,
+    pub background_color: Color,
+}
+
+impl Default for BarCountdownBehavior {
+    fn default() -> Self {
+        Self {
+            visible: false,
+            bar_interval: None,
+            font_size_px: 11.0,
+            padding_px: 3.0,
+            text_color: Color::rgb(1.0, 1.0, 1.0),
+            background_color: Color::rgb(0.2, 0.22, 0.25),
+        }
+    }
+}
+
+/// Labels on the highest high and lowest low inside the visible time range.
+///
+/// Extremes come from visible candles (or points without candles) and are
+/// resolved every frame, so they follow range changes and data updates.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct HighLowMarkerBehavior {
+    pub visible: bool,
+    /// Also pins both prices on the price axis; regular ticks keep clear of
+    /// them like they do of the last-price label.
+    pub show_axis_labels: bool,
+    pub font_size_px: f64,
+    /// Gap between the extreme and its label.
+    pub offset_px: f64,
+    pub high_color: Color,
+    pub low_color: Color,
+}
+
+impl Default for HighLowMarkerBehavior {
+    fn default() -> Self {
+        Self {
+            visible: false,
+            show_axis_labels: true,
+            font_size_px: 10.0,
+            offset_px: 4.0,
+            high_color: Color::rgb(0.149, 0.651, 0.604),
+            low_color: Color::rgb(0.937, 0.325, 0.314),
+        }
+    }
+}
+
+/// Eased transition of the newest point value or candle close after a
+/// realtime append/update, advanced by `ChartEngine::step_animations`.
+///
+/// Only series geometry animates; axis labels, markers and autoscale use the
+/// new value right away.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct LastValueAnimationBehavior {
+    pub enabled: bool,
+    pub duration_seconds: f64,
+}
+
+impl Default for LastValueAnimationBehavior {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            duration_seconds: 0.25,
+        }
+    }
+}
+
+/// How visible-range change notifications (plugin events and host
+/// subscriptions) are delivered. Internal reactions such as autoscale always
+/// run on every change.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default)]
+pub enum VisibleRangeChangeDelivery {
+    /// One notification per change.
+    #[default]
+    Immediate,
+    /// One notification per burst, sent once no change arrived for
+    /// `quiet_seconds` of `step_animations` time.
+    Debounced { quiet_seconds: f64 },
+    /// At most one notification per rendered frame.
+    PerFrame,
+}
+
+/// Visible-range change notification behavior.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default)]
+pub struct VisibleRangeChangeBehavior {
+    pub delivery: VisibleRangeChangeDelivery,
+}
+
+/// Bounded undo/redo history of the navigated view, checkpointed on discrete
+/// gestures (drag end, zoom bursts, fit/reset, price scale mode switches).
+///
+/// `max_entries` bounds the undo stack; `0` disables the history.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NavigationHistoryBehavior {
+    pub max_entries: usize,
+}
+
+impl Default for NavigationHistoryBehavior {
+    fn default() -> Self {
+        Self { max_entries: 50 }
+    }
+}
+
 /// Crosshair guide-line visibility behavior (`shared && axis`).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct CrosshairGuideLineBehavior {
@@ -428,6 +916,8 @@ pub struct LastPriceBehavior {
     pub show_label: bool,
     pub use_trend_color: bool,
     pub source_mode: LastPriceSourceMode,
+    #[serde(default)]
+    pub offscreen_policy: LastPriceOffscreenPolicy,
 }
 
 impl Default for LastPriceBehavior {
@@ -437,6 +927,7 @@ impl Default for LastPriceBehavior {
             show_label: true,
             use_trend_color: false,
             source_mode: LastPriceSourceMode::LatestData,
+            offscreen_policy: LastPriceOffscreenPolicy::Draw,
         }
     }
 }
@@ -1,8 +1,8 @@
 use crate::render::Renderer;
 
-use super::ChartEngine;
 use super::axis_label_format::{ResolvedTimeLabelPattern, resolve_time_label_pattern};
 use super::label_cache::{PriceLabelCacheProfile, TimeLabelCacheProfile, price_policy_profile};
+use super::{ChartEngine, PriceAxisLabelPolicy};
 
 impl<R: Renderer> ChartEngine<R> {
     pub(super) fn resolve_time_label_cache_profile(
@@ -17,26 +17,28 @@ impl<R: Renderer> ChartEngine<R> {
             };
         }
 
-        match resolve_time_label_pattern(
-            self.core.behavior.time_axis_label_config.policy,
-            visible_span_abs,
-        ) {
+        let config = self.effective_time_axis_label_config();
+        match resolve_time_label_pattern(config, visible_span_abs) {
             ResolvedTimeLabelPattern::LogicalDecimal { precision } => {
                 TimeLabelCacheProfile::LogicalDecimal {
                     precision,
-                    locale: self.core.behavior.time_axis_label_config.locale,
+                    locale: config.locale,
                 }
             }
             ResolvedTimeLabelPattern::Utc { pattern } => TimeLabelCacheProfile::Utc {
-                locale: self.core.behavior.time_axis_label_config.locale,
+                locale: config.locale,
                 pattern,
-                timezone: self.core.behavior.time_axis_label_config.timezone,
-                session: self.core.behavior.time_axis_label_config.session,
+                timezone: config.timezone,
+                session: config.session,
+                time_unit: config.time_unit,
             },
         }
     }
 
-    pub(super) fn resolve_price_label_cache_profile(&self) -> PriceLabelCacheProfile {
+    pub(super) fn resolve_price_label_cache_profile(
+        &self,
+        policy: PriceAxisLabelPolicy,
+    ) -> PriceLabelCacheProfile {
         if self.core.presentation.price_label_formatter.is_some() {
             return PriceLabelCacheProfile::Custom {
                 formatter_generation: self.core.presentation.price_label_formatter_generation,
@@ -47,7 +49,7 @@ impl<R: Renderer> ChartEngine<R> {
 
         PriceLabelCacheProfile::BuiltIn {
             locale: self.core.behavior.price_axis_label_config.locale,
-            policy: price_policy_profile(self.core.behavior.price_axis_label_config.policy),
+            policy: price_policy_profile(policy),
         }
     }
 }
@@ -1,37 +1,86 @@
-use crate::core::{OhlcBar, PaneId, PriceScale, project_candles};
+use tracing::trace_span;
+
+use crate::core::candlestick::project_single_candle;
+use crate::core::{OhlcBar, PaneId, PriceScale, candles_time_window_range, project_candles};
 use crate::error::ChartResult;
 use crate::render::{
-    CanvasLayerKind, Color, LayeredRenderFrame, LinePrimitive, RectPrimitive, RenderFrame, Renderer,
+    CanvasLayerKind, ClipRect, Color, LayeredRenderFrame, LinePrimitive, RectPrimitive,
+    RenderFrame, Renderer,
 };
+use crate::telemetry::{FRAME_PROJECTION_SPAN, FRAME_WINDOWING_SPAN};
+
+use super::frame_budget::merge_candle_buckets;
+use super::last_value_animation_controller::with_animated_close;
+use super::{CandlestickBodyMode, CandlestickDetailTiers, ChartEngine, RenderStyle};
+
+#[derive(Debug, Clone, Copy)]
+pub(super) struct CandlestickSeriesRenderContext<'a> {
+    pub pane_id: PaneId,
+    pub price_scale: PriceScale,
+    pub visible_start: f64,
+    pub visible_end: f64,
+    pub plot_clip: ClipRect,
+    pub style: &'a RenderStyle,
+    /// Source candles merged into each drawn bar (1 = full detail).
+    pub lod_bucket_len: usize,
+}
 
-use super::{CandlestickBodyMode, ChartEngine, RenderStyle};
+/// Candlestick detail drawn at the current bar spacing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CandlestickDetail {
+    FilledRect,
+    NoBorder,
+    Full,
+}
+
+fn resolve_candlestick_detail(
+    tiers: Option<CandlestickDetailTiers>,
+    bar_spacing_px: f64,
+) -> CandlestickDetail {
+    match tiers {
+        Some(tiers) if bar_spacing_px < tiers.filled_rect_below_px => CandlestickDetail::FilledRect,
+        Some(tiers) if bar_spacing_px < tiers.no_border_below_px => CandlestickDetail::NoBorder,
+        _ => CandlestickDetail::Full,
+    }
+}
 
 impl<R: Renderer> ChartEngine<R> {
     pub(super) fn append_candlestick_series_primitives(
         &self,
         frame: &mut RenderFrame,
         layered: &mut LayeredRenderFrame,
-        pane_and_scale: (PaneId, PriceScale),
-        visible_range: (f64, f64),
-        plot_right: f64,
-        style: RenderStyle,
+        ctx: CandlestickSeriesRenderContext,
     ) -> ChartResult<()> {
-        let (candles_pane_id, candles_scale) = pane_and_scale;
-        let (visible_start, visible_end) = visible_range;
-        let visible_candle_indices =
-            self.visible_candle_indices_in_time_window(visible_start, visible_end);
-        if visible_candle_indices.is_empty() {
+        let plot_clip = ctx.plot_clip;
+        let style = ctx.style;
+        let plot_right = plot_clip.right();
+        let candles_pane_id = ctx.pane_id;
+        let candles_scale = ctx.price_scale;
+        let windowing_span =
+            trace_span!(FRAME_WINDOWING_SPAN, series = "candles", samples = 0_usize).entered();
+        let visible_range =
+            candles_time_window_range(&self.core.model.candles, ctx.visible_start, ctx.visible_end);
+        if visible_range.is_empty() {
             return Ok(());
         }
 
-        let visible_candles: Vec<OhlcBar> = visible_candle_indices
-            .iter()
-            .map(|&idx| self.core.model.candles[idx])
-            .collect();
-        let candle_bar_spacing =
-            self.resolve_candlestick_bar_spacing_px(&visible_candles, plot_right);
-        let candle_body_width =
-            self.resolve_candlestick_body_width_px(&visible_candles, plot_right);
+        let window = &self.core.model.candles[visible_range.clone()];
+        let animated_close = self.animated_last_candle_close(window);
+        let merged = (ctx.lod_bucket_len > 1).then(|| {
+            let (mut bars, indices) =
+                merge_candle_buckets(window, visible_range.start, ctx.lod_bucket_len);
+            if let (Some(close), Some(bar)) = (animated_close, bars.last_mut()) {
+                *bar = with_animated_close(*bar, close);
+            }
+            (bars, indices)
+        });
+        let visible_candles = merged.as_ref().map_or(window, |(bars, _)| bars.as_slice());
+        windowing_span.record("samples", visible_candles.len());
+        windowing_span.exit();
+        let candle_bar_spacing = self
+            .resolve_candlestick_bar_spacing_px(visible_candles, plot_right)
+            * ctx.lod_bucket_len as f64;
+        let candle_body_width = Self::resolve_candlestick_body_width_px(candle_bar_spacing);
         let wick_width = Self::resolve_effective_candlestick_wick_width_px(
             style.candlestick_wick_width_px,
             candle_bar_spacing,
@@ -43,22 +92,44 @@ impl<R: Renderer> ChartEngine<R> {
             candle_body_width,
             1.0,
         );
-        let render_border_only_body = style.show_candlestick_borders
-            && border_width > 0.0
-            && candle_body_width <= 2.0 * border_width;
-        let candle_geometries = project_candles(
-            &visible_candles,
-            self.core.model.time_scale,
-            candles_scale,
-            self.core.model.viewport,
-            candle_body_width,
-        )?;
+        let detail = resolve_candlestick_detail(style.candlestick_detail_tiers, candle_bar_spacing);
+        let show_borders = style.show_candlestick_borders && detail == CandlestickDetail::Full;
+        let render_border_only_body =
+            show_borders && border_width > 0.0 && candle_body_width <= 2.0 * border_width;
+        let mut candle_geometries = trace_span!(
+            FRAME_PROJECTION_SPAN,
+            series = "candles",
+            samples = visible_candles.len()
+        )
+        .in_scope(|| {
+            project_candles(
+                visible_candles,
+                self.core.model.time_scale,
+                candles_scale,
+                self.core.model.viewport,
+                candle_body_width,
+            )
+        })?;
+        // Merged buckets already carry the animated close; at full detail
+        // only the newest bar is re-projected.
+        if merged.is_none()
+            && let (Some(close), Some(bar), Some(geometry)) =
+                (animated_close, window.last(), candle_geometries.last_mut())
+        {
+            *geometry = project_single_candle(
+                with_animated_close(*bar, close),
+                self.core.model.time_scale,
+                candles_scale,
+                self.core.model.viewport,
+                candle_body_width,
+            )?;
+        }
         let mut prev_wick_edge: Option<i64> = None;
         let mut prev_border_edge: Option<i64> = None;
-        for (candle, source_index) in candle_geometries
-            .into_iter()
-            .zip(visible_candle_indices.into_iter())
-        {
+        for (offset, candle) in candle_geometries.into_iter().enumerate() {
+            let source_index = merged
+                .as_ref()
+                .map_or(visible_range.start + offset, |(_, indices)| indices[offset]);
             let style_override = self
                 .core
                 .model
@@ -74,6 +145,24 @@ impl<R: Renderer> ChartEngine<R> {
             let body_color = style_override
                 .and_then(|entry| entry.color)
                 .unwrap_or(fallback_body_color);
+            if detail == CandlestickDetail::FilledRect {
+                let (left_px, _right_px, draw_width) = Self::resolve_lwc_horizontal_draw_bounds(
+                    candle.center_x,
+                    candle_body_width,
+                    None,
+                );
+                let rect = RectPrimitive::new(
+                    left_px as f64,
+                    candle.wick_top.min(candle.wick_bottom),
+                    draw_width as f64,
+                    (candle.wick_bottom - candle.wick_top).abs().max(1.0),
+                    body_color,
+                )
+                .with_clip_rect(plot_clip);
+                frame.rects.push(rect);
+                layered.push_rect(candles_pane_id, CanvasLayerKind::Series, rect);
+                continue;
+            }
             let fallback_wick_color = if candle.is_bullish {
                 style.candlestick_wick_up_color
             } else {
@@ -113,7 +202,8 @@ impl<R: Renderer> ChartEngine<R> {
                     candle.wick_bottom,
                     wick_draw_width as f64,
                     wick_color,
-                );
+                )
+                .with_clip_rect(plot_clip);
                 frame.lines.push(line);
                 layered.push_line(candles_pane_id, CanvasLayerKind::Series, line);
                 prev_wick_edge = Some(wick_right_px);
@@ -124,8 +214,7 @@ impl<R: Renderer> ChartEngine<R> {
             } else {
                 body_fill_color
             };
-            let (body_left_px, _body_right_px, body_draw_width) = if style.show_candlestick_borders
-            {
+            let (body_left_px, _body_right_px, body_draw_width) = if show_borders {
                 let bounds = Self::resolve_lwc_horizontal_draw_bounds(
                     candle.center_x,
                     candle_body_width,
@@ -143,9 +232,10 @@ impl<R: Renderer> ChartEngine<R> {
                 (candle.body_bottom - candle.body_top).abs().max(1.0),
                 rect_fill_color,
             );
-            if !render_border_only_body && style.show_candlestick_borders && border_width > 0.0 {
+            if !render_border_only_body && show_borders && border_width > 0.0 {
                 body = body.with_border(border_width, border_color);
             }
+            body = body.with_clip_rect(plot_clip);
             frame.rects.push(body);
             layered.push_rect(candles_pane_id, CanvasLayerKind::Series, body);
         }
@@ -153,13 +243,7 @@ impl<R: Renderer> ChartEngine<R> {
         Ok(())
     }
 
-    fn resolve_candlestick_body_width_px(
-        &self,
-        visible_candles: &[OhlcBar],
-        plot_width_px: f64,
-    ) -> f64 {
-        let bar_spacing_px =
-            self.resolve_candlestick_bar_spacing_px(visible_candles, plot_width_px);
+    fn resolve_candlestick_body_width_px(bar_spacing_px: f64) -> f64 {
         let mut body_width = Self::lwc_optimal_candlestick_width_px(bar_spacing_px, 1.0);
         if body_width >= 2.0 {
             // Lightweight keeps candlestick body parity aligned with 1px wick/grid
@@ -281,22 +365,6 @@ impl<R: Renderer> ChartEngine<R> {
         None
     }
 
-    fn visible_candle_indices_in_time_window(&self, start: f64, end: f64) -> Vec<usize> {
-        self.core
-            .model
-            .candles
-            .iter()
-            .enumerate()
-            .filter_map(|(idx, candle)| {
-                if candle.time >= start && candle.time <= end {
-                    Some(idx)
-                } else {
-                    None
-                }
-            })
-            .collect()
-    }
-
     fn lwc_optimal_candlestick_width_px(bar_spacing_px: f64, pixel_ratio: f64) -> f64 {
         let min_width = pixel_ratio.floor().max(1.0);
         if !bar_spacing_px.is_finite() || bar_spacing_px <= 0.0 || !pixel_ratio.is_finite() {
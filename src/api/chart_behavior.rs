@@ -1,14 +1,22 @@
+use crate::extensions::{TradingOverlayStyle, VolumeProfileConfig};
+
 use super::{
-    InteractionInputBehavior, PriceAxisLabelConfig, PriceScaleRealtimeBehavior,
-    PriceScaleTransformedBaseBehavior, TimeAxisLabelConfig, TimeScaleEdgeBehavior,
-    TimeScaleNavigationBehavior, TimeScaleRealtimeAppendBehavior, TimeScaleResizeBehavior,
-    TimeScaleScrollZoomBehavior, TimeScaleZoomLimitBehavior,
+    AxisInteractionConfig, BarCountdownBehavior, DataGapBehavior, DebugOverlayBehavior,
+    ElasticOverscrollBehavior, FrameBudget, HighLowMarkerBehavior, IdlePowerBehavior,
+    InteractionInputBehavior, LastValueAnimationBehavior, LayoutInsets, NavigationHistoryBehavior,
+    OhlcInfoLineBehavior, PaneSeparatorBehavior, PriceAxisLabelConfig, PriceAxisLabelPolicy,
+    PriceScaleRealtimeBehavior, PriceScaleTransformedBaseBehavior, RedrawThrottleBehavior,
+    TimeAxisLabelConfig, TimeScaleEdgeBehavior, TimeScaleMarkBehavior, TimeScaleNavigationBehavior,
+    TimeScaleRealtimeAppendBehavior, TimeScaleResizeBehavior, TimeScaleScrollZoomBehavior,
+    TimeScaleZoomLimitBehavior, VisibleRangeChangeBehavior, WheelBehaviorConfig,
+    ZoomSelectionBehavior,
 };
 
 /// Runtime behavior/configuration state grouped separately from core chart data.
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
 pub(super) struct ChartBehaviorState {
     pub(super) time_scale_edge_behavior: TimeScaleEdgeBehavior,
+    pub(super) elastic_overscroll_behavior: ElasticOverscrollBehavior,
     pub(super) time_scale_navigation_behavior: TimeScaleNavigationBehavior,
     pub(super) time_scale_zoom_limit_behavior: TimeScaleZoomLimitBehavior,
     pub(super) time_scale_right_offset_px: Option<f64>,
@@ -20,4 +28,26 @@ pub(super) struct ChartBehaviorState {
     pub(super) price_scale_transformed_base_behavior: PriceScaleTransformedBaseBehavior,
     pub(super) time_axis_label_config: TimeAxisLabelConfig,
     pub(super) price_axis_label_config: PriceAxisLabelConfig,
+    pub(super) points_price_format: Option<PriceAxisLabelPolicy>,
+    pub(super) candles_price_format: Option<PriceAxisLabelPolicy>,
+    pub(super) frame_budget: Option<FrameBudget>,
+    pub(super) layout_insets: LayoutInsets,
+    pub(super) pane_separator_behavior: PaneSeparatorBehavior,
+    pub(super) ohlc_info_line_behavior: OhlcInfoLineBehavior,
+    pub(super) volume_profile_config: Option<VolumeProfileConfig>,
+    pub(super) compare_mode_enabled: bool,
+    pub(super) time_scale_mark_behavior: TimeScaleMarkBehavior,
+    pub(super) debug_overlay_behavior: DebugOverlayBehavior,
+    pub(super) redraw_throttle_behavior: RedrawThrottleBehavior,
+    pub(super) idle_power_behavior: IdlePowerBehavior,
+    pub(super) data_gap_behavior: DataGapBehavior,
+    pub(super) bar_countdown_behavior: BarCountdownBehavior,
+    pub(super) high_low_marker_behavior: HighLowMarkerBehavior,
+    pub(super) last_value_animation_behavior: LastValueAnimationBehavior,
+    pub(super) visible_range_change_behavior: VisibleRangeChangeBehavior,
+    pub(super) navigation_history_behavior: NavigationHistoryBehavior,
+    pub(super) trading_overlay_style: TradingOverlayStyle,
+    pub(super) axis_interaction_config: AxisInteractionConfig,
+    pub(super) wheel_behavior_config: WheelBehaviorConfig,
+    pub(super) zoom_selection_behavior: ZoomSelectionBehavior,
 }
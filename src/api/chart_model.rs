@@ -1,11 +1,14 @@
 use indexmap::IndexMap;
 
 use crate::core::{
-    DataPoint, OhlcBar, PaneCollection, PaneId, PriceScale, PriceScaleMode, TimeScale, Viewport,
+    ChartTimeKind, DataPoint, OhlcBar, PaneCollection, PaneId, PriceScale, PriceScaleMode,
+    TimeScale, Viewport,
 };
+use crate::extensions::{OpenPosition, PriceAlert, VolumeSample, WorkingOrder};
 use crate::interaction::InteractionState;
 
-use super::CandlestickBarStyleOverride;
+use super::custom_series_controller::CustomSeriesEntry;
+use super::{CandlestickBarStyleOverride, CompareSeries, TimeScaleMark, ViewBookmark};
 
 /// Core chart domain state modeled after Lightweight Charts `ChartModel`.
 ///
@@ -21,6 +24,22 @@ pub struct ChartModel {
     pub(super) points: Vec<DataPoint>,
     pub(super) candles: Vec<OhlcBar>,
     pub(super) candle_style_overrides: Vec<Option<CandlestickBarStyleOverride>>,
+    /// Per-candle volumes feeding the volume profile, sorted by time.
+    pub(super) volume_samples: Vec<VolumeSample>,
+    /// Secondary series rebased onto the primary one in compare mode.
+    pub(super) compare_series: Vec<CompareSeries>,
+    /// Time-axis badges, sorted by time.
+    pub(super) time_scale_marks: Vec<TimeScaleMark>,
+    /// Trading overlay orders and positions, in draw order.
+    pub(super) working_orders: Vec<WorkingOrder>,
+    pub(super) open_positions: Vec<OpenPosition>,
+    pub(super) price_alerts: Vec<PriceAlert>,
+    /// Named views in save order.
+    pub(super) view_bookmarks: Vec<ViewBookmark>,
+    /// User-defined series in registration (draw) order.
+    pub(super) custom_series: Vec<CustomSeriesEntry>,
+    /// Original time representation of the last `ChartTime`-based data set.
+    pub(super) time_kind: Option<ChartTimeKind>,
     pub(super) points_pane_id: PaneId,
     pub(super) candles_pane_id: PaneId,
     pub(super) series_metadata: IndexMap<String, String>,
@@ -50,6 +69,15 @@ impl ChartModel {
             points: Vec::new(),
             candles: Vec::new(),
             candle_style_overrides: Vec::new(),
+            volume_samples: Vec::new(),
+            compare_series: Vec::new(),
+            time_scale_marks: Vec::new(),
+            working_orders: Vec::new(),
+            open_positions: Vec::new(),
+            price_alerts: Vec::new(),
+            view_bookmarks: Vec::new(),
+            custom_series: Vec::new(),
+            time_kind: None,
             points_pane_id: bootstrap.points_pane_id,
             candles_pane_id: bootstrap.candles_pane_id,
             series_metadata: IndexMap::new(),
@@ -4,8 +4,8 @@ use super::label_cache::{
     PriceLabelCache, PriceLabelFormatterFn, TimeLabelCache, TimeLabelFormatterFn,
 };
 use super::{
-    CrosshairPriceLabelFormatterWithContextFn, CrosshairTimeLabelFormatterWithContextFn,
-    RenderStyle,
+    CrosshairLabelTemplate, CrosshairPriceLabelFormatterWithContextFn,
+    CrosshairTimeLabelFormatterWithContextFn, RenderStyle,
 };
 
 /// Runtime presentation state grouped separately from core chart model/behavior.
@@ -26,6 +26,8 @@ pub(super) struct ChartPresentationState {
     pub(super) price_label_cache: RefCell<PriceLabelCache>,
     pub(super) crosshair_time_label_cache: RefCell<TimeLabelCache>,
     pub(super) crosshair_price_label_cache: RefCell<PriceLabelCache>,
+    pub(super) crosshair_time_label_template: Option<CrosshairLabelTemplate>,
+    pub(super) crosshair_price_label_template: Option<CrosshairLabelTemplate>,
     pub(super) render_style: RenderStyle,
 }
 
@@ -46,6 +48,8 @@ impl Default for ChartPresentationState {
             price_label_cache: RefCell::new(PriceLabelCache::default()),
             crosshair_time_label_cache: RefCell::new(TimeLabelCache::default()),
             crosshair_price_label_cache: RefCell::new(PriceLabelCache::default()),
+            crosshair_time_label_template: None,
+            crosshair_price_label_template: None,
             render_style: RenderStyle::default(),
         }
     }
@@ -1,6 +1,27 @@
-use crate::extensions::ChartPlugin;
+use std::cell::{Cell, RefCell};
+use std::collections::VecDeque;
+use std::time::Duration;
 
-use super::InvalidationTopics;
+use crate::extensions::{ChartPlugin, HitRegion, VisibleRangeChangeCause};
+
+use super::animation_controller::TimeRangeAnimation;
+use super::data_gap_controller::DataGapCache;
+use super::data_selection_controller::{DataSelection, DataSelectionDrag};
+use super::elastic_overscroll_controller::ElasticOverscroll;
+use super::frame_budget::FrameReduction;
+use super::idle_power_controller::IdlePowerState;
+use super::interaction_recording_controller::InteractionRecorder;
+use super::last_value_animation_controller::LastValueAnimation;
+use super::navigation_history_controller::NavigationHistory;
+use super::render_frame_pool::RenderFramePool;
+use super::replay_controller::ReplayState;
+use super::trading_overlay_controller::OrderDrag;
+use super::visible_range_change_controller::{
+    PendingVisibleRangeChange, VisibleRangeChangeCallback,
+};
+use super::warning_controller::WarningSink;
+use super::zoom_selection_controller::ZoomSelection;
+use super::{FramePrimitiveStats, IngestionStats, InvalidationTopics, PrimitiveHit, StagedCandles};
 
 /// Legacy topic accumulator kept while migrating fully to LWC invalidation.
 pub(super) struct PendingInvalidationTopics {
@@ -50,6 +71,56 @@ pub(super) struct ChartRuntimeState {
     pub(super) pending_invalidation_topics: PendingInvalidationTopics,
     pub(super) pending_lwc_time_scale_invalidation_intent: Option<LwcTimeScaleInvalidationIntent>,
     pub(super) last_lwc_time_scale_state: Option<LwcTimeScaleStateSnapshot>,
+    pub(super) staged_candles: Option<StagedCandles>,
+    pub(super) ingestion_stats: IngestionStats,
+    pub(super) interaction_recorder: Option<InteractionRecorder>,
+    /// Interior-mutable so render paths (`&self`) can report warnings.
+    pub(super) warning_sink: RefCell<Option<WarningSink>>,
+    pub(super) last_frame_stats: Cell<Option<FramePrimitiveStats>>,
+    /// Budget reduction that fit the last frame; the next build starts here.
+    pub(super) frame_reduction: Cell<FrameReduction>,
+    /// Price-axis width of the last resolved layout; also the hysteresis
+    /// reference for `PriceAxisWidthMode::Auto`.
+    pub(super) resolved_price_axis_width_px: Cell<Option<f64>>,
+    /// `(series id, region)` pairs reported by custom series in the last
+    /// built frame.
+    pub(super) custom_series_hit_regions: RefCell<Vec<(String, HitRegion)>>,
+    pub(super) hovered_primitive: Option<PrimitiveHit>,
+    /// Frame-clock times of the most recent renders, for the fps estimate.
+    pub(super) render_instants: VecDeque<Duration>,
+    /// Host frame clock from `frame_clock_tick` / `redraw_due`.
+    pub(super) frame_clock_time: Option<Duration>,
+    /// Frame-clock time of the last redraw granted by `redraw_due`.
+    pub(super) last_redraw_frame_time: Option<Duration>,
+    pub(super) time_range_animation: Option<TimeRangeAnimation>,
+    pub(super) elastic_overscroll: ElasticOverscroll,
+    /// Elapsed seconds of the running crosshair snap-marker pulse.
+    pub(super) crosshair_snap_marker_pulse_elapsed: Option<f64>,
+    pub(super) last_point_animation: Option<LastValueAnimation>,
+    pub(super) last_candle_animation: Option<LastValueAnimation>,
+    pub(super) idle_power: IdlePowerState,
+    pub(super) order_drag: Option<OrderDrag>,
+    pub(super) zoom_selection: Option<ZoomSelection>,
+    pub(super) data_selection_drag: Option<DataSelectionDrag>,
+    pub(super) data_selection: Option<DataSelection>,
+    /// Last price seen by the alert crossing check.
+    pub(super) alert_reference_price: Option<f64>,
+    pub(super) next_price_alert_id: u64,
+    /// Interior-mutable so `detected_gaps` (`&self`) can fill it.
+    pub(super) data_gap_cache: RefCell<Option<DataGapCache>>,
+    /// Host clock from `bar_clock_tick`, in time units.
+    pub(super) bar_clock_time: Option<f64>,
+    pub(super) replay: Option<ReplayState>,
+    /// Cause attributed to visible-range changes made by the running call.
+    pub(super) visible_range_change_cause: VisibleRangeChangeCause,
+    /// Range of the last visible-range notification; the `previous` range of
+    /// the next one.
+    pub(super) reported_visible_range: Option<(f64, f64)>,
+    pub(super) pending_visible_range_change: Option<PendingVisibleRangeChange>,
+    pub(super) visible_range_subscriptions: Vec<(u64, VisibleRangeChangeCallback)>,
+    pub(super) next_visible_range_subscription_id: u64,
+    pub(super) navigation_history: NavigationHistory,
+    pub(super) render_frame_pool: RefCell<RenderFramePool>,
 }
 
 impl ChartRuntimeState {
@@ -60,6 +131,40 @@ impl ChartRuntimeState {
             pending_invalidation_topics: PendingInvalidationTopics::with_all_topics(),
             pending_lwc_time_scale_invalidation_intent: None,
             last_lwc_time_scale_state: None,
+            staged_candles: None,
+            ingestion_stats: IngestionStats::default(),
+            interaction_recorder: None,
+            warning_sink: RefCell::new(None),
+            last_frame_stats: Cell::new(None),
+            frame_reduction: Cell::new(FrameReduction::default()),
+            resolved_price_axis_width_px: Cell::new(None),
+            custom_series_hit_regions: RefCell::new(Vec::new()),
+            hovered_primitive: None,
+            render_instants: VecDeque::new(),
+            frame_clock_time: None,
+            last_redraw_frame_time: None,
+            time_range_animation: None,
+            elastic_overscroll: ElasticOverscroll::default(),
+            crosshair_snap_marker_pulse_elapsed: None,
+            last_point_animation: None,
+            last_candle_animation: None,
+            idle_power: IdlePowerState::default(),
+            order_drag: None,
+            zoom_selection: None,
+            data_selection_drag: None,
+            data_selection: None,
+            alert_reference_price: None,
+            next_price_alert_id: 1,
+            data_gap_cache: RefCell::new(None),
+            bar_clock_time: None,
+            replay: None,
+            visible_range_change_cause: VisibleRangeChangeCause::Api,
+            reported_visible_range: None,
+            pending_visible_range_change: None,
+            visible_range_subscriptions: Vec::new(),
+            next_visible_range_subscription_id: 1,
+            navigation_history: NavigationHistory::default(),
+            render_frame_pool: RefCell::new(RenderFramePool::default()),
         }
     }
 }
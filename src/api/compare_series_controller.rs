@@ -0,0 +1,244 @@
+use serde::{Deserialize, Serialize};
+
+use crate::core::{DataPoint, PaneId, PriceScale, points_in_sorted_time_window};
+use crate::error::{ChartError, ChartResult};
+use crate::render::{ClipRect, Color, LayeredRenderFrame, RenderFrame, Renderer};
+
+use super::line_series_render_frame_builder::LineSeriesRenderContext;
+use super::validation::validate_price_axis_label_policy;
+use super::{ChartEngine, PriceAxisDisplayMode, PriceAxisLabelPolicy};
+
+/// Secondary line series drawn against the primary series in compare mode.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CompareSeries {
+    pub id: String,
+    pub points: Vec<DataPoint>,
+    pub color: Color,
+    /// Label format for this series' values; `None` follows the price axis.
+    #[serde(default)]
+    pub price_format: Option<PriceAxisLabelPolicy>,
+}
+
+impl CompareSeries {
+    #[must_use]
+    pub fn new(id: impl Into<String>, points: Vec<DataPoint>, color: Color) -> Self {
+        Self {
+            id: id.into(),
+            points,
+            color,
+            price_format: None,
+        }
+    }
+
+    #[must_use]
+    pub fn with_price_format(mut self, price_format: PriceAxisLabelPolicy) -> Self {
+        self.price_format = Some(price_format);
+        self
+    }
+}
+
+impl<R: Renderer> ChartEngine<R> {
+    #[must_use]
+    pub fn compare_series(&self) -> &[CompareSeries] {
+        &self.core.model.compare_series
+    }
+
+    /// Adds a secondary series; points are sorted and deduplicated by time.
+    pub fn add_compare_series(&mut self, mut series: CompareSeries) -> ChartResult<()> {
+        if series.id.is_empty() {
+            return Err(ChartError::InvalidData(
+                "compare series id must not be empty".to_owned(),
+            ));
+        }
+        if self
+            .core
+            .model
+            .compare_series
+            .iter()
+            .any(|existing| existing.id == series.id)
+        {
+            return Err(ChartError::InvalidData(format!(
+                "compare series `{}` already exists",
+                series.id
+            )));
+        }
+        series.color.validate()?;
+        if let Some(price_format) = series.price_format {
+            validate_price_axis_label_policy(price_format)?;
+        }
+        series.points = super::data_controller::canonicalize_points(series.points);
+        self.core.model.compare_series.push(series);
+        self.invalidate_full();
+        Ok(())
+    }
+
+    /// Removes the compare series with `id`, returning whether it existed.
+    pub fn remove_compare_series(&mut self, id: &str) -> bool {
+        let before = self.core.model.compare_series.len();
+        self.core
+            .model
+            .compare_series
+            .retain(|series| series.id != id);
+        let removed = self.core.model.compare_series.len() != before;
+        if removed {
+            self.invalidate_full();
+        }
+        removed
+    }
+
+    #[must_use]
+    pub fn compare_mode_enabled(&self) -> bool {
+        self.core.behavior.compare_mode_enabled
+    }
+
+    /// Toggles compare mode.
+    ///
+    /// While enabled, compare series are rebased onto the primary series at
+    /// the first visible bar and the price axis reads as percentage change
+    /// (or stays indexed to 100 when that display mode is configured).
+    pub fn set_compare_mode_enabled(&mut self, enabled: bool) {
+        if self.core.behavior.compare_mode_enabled != enabled {
+            self.core.behavior.compare_mode_enabled = enabled;
+            self.invalidate_full();
+        }
+    }
+
+    /// Price axis display mode after compare mode is taken into account.
+    ///
+    /// Compare mode drops explicit base prices so every label is measured
+    /// from the first visible primary value.
+    #[must_use]
+    pub fn resolve_price_axis_display_mode(&self) -> PriceAxisDisplayMode {
+        let configured = self.core.behavior.price_axis_label_config.display_mode;
+        if !self.core.behavior.compare_mode_enabled {
+            return configured;
+        }
+        match configured {
+            PriceAxisDisplayMode::IndexedTo100 { .. } => {
+                PriceAxisDisplayMode::IndexedTo100 { base_price: None }
+            }
+            PriceAxisDisplayMode::Normal | PriceAxisDisplayMode::Percentage { .. } => {
+                PriceAxisDisplayMode::Percentage { base_price: None }
+            }
+        }
+    }
+
+    /// First visible primary value: the first visible candle close, else the
+    /// first visible point.
+    #[must_use]
+    pub fn compare_primary_base_price(&self) -> Option<f64> {
+        self.visible_candles_slice()
+            .first()
+            .map(|bar| bar.close)
+            .or_else(|| self.visible_points_slice().first().map(|point| point.y))
+            .filter(|price| price.is_finite() && *price != 0.0)
+    }
+
+    /// First visible value of the compare series with `id`, the base its
+    /// percentage change is measured from.
+    #[must_use]
+    pub fn compare_series_base_price(&self, id: &str) -> Option<f64> {
+        let series = self.find_compare_series(id)?;
+        self.compare_series_base(series)
+    }
+
+    /// Visible points of the compare series with `id`, rebased into the
+    /// primary price space so both share the first visible value.
+    ///
+    /// Returns `None` outside compare mode or when either base is missing.
+    #[must_use]
+    pub fn rebased_compare_points(&self, id: &str) -> Option<Vec<DataPoint>> {
+        if !self.core.behavior.compare_mode_enabled {
+            return None;
+        }
+        let series = self.find_compare_series(id)?;
+        self.rebase_compare_series(series)
+    }
+
+    /// Rebased visible values of every compare series, merged for autoscale.
+    pub(super) fn rebased_compare_values(&self) -> Vec<DataPoint> {
+        if !self.core.behavior.compare_mode_enabled {
+            return Vec::new();
+        }
+        self.core
+            .model
+            .compare_series
+            .iter()
+            .filter_map(|series| self.rebase_compare_series(series))
+            .flatten()
+            .collect()
+    }
+
+    /// Draws rebased compare lines into the primary series pane.
+    pub(super) fn append_compare_series_primitives(
+        &self,
+        frame: &mut RenderFrame,
+        layered: &mut LayeredRenderFrame,
+        pane_id: PaneId,
+        price_scale: PriceScale,
+        plot_clip: ClipRect,
+    ) -> ChartResult<()> {
+        if !self.core.behavior.compare_mode_enabled {
+            return Ok(());
+        }
+        let (visible_start, visible_end) = self.core.model.time_scale.visible_range();
+        for series in &self.core.model.compare_series {
+            let Some(points) = self.rebase_compare_series(series) else {
+                continue;
+            };
+            self.push_line_series_segments(
+                frame,
+                layered,
+                &points,
+                None,
+                LineSeriesRenderContext {
+                    pane_id,
+                    price_scale,
+                    visible_start,
+                    visible_end,
+                    line_color: series.color,
+                    clip_rect: plot_clip,
+                    lod_bucket_len: 1,
+                },
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Pane of the primary series, which compare lines share.
+    pub(super) fn compare_pane_id(&self) -> PaneId {
+        if self.core.model.candles.is_empty() {
+            self.core.model.points_pane_id
+        } else {
+            self.core.model.candles_pane_id
+        }
+    }
+
+    pub(super) fn find_compare_series(&self, id: &str) -> Option<&CompareSeries> {
+        self.core
+            .model
+            .compare_series
+            .iter()
+            .find(|series| series.id == id)
+    }
+
+    fn compare_series_base(&self, series: &CompareSeries) -> Option<f64> {
+        let (start, end) = self.core.model.time_scale.visible_range();
+        points_in_sorted_time_window(&series.points, start, end)
+            .first()
+            .map(|point| point.y)
+            .filter(|value| *value != 0.0)
+    }
+
+    fn rebase_compare_series(&self, series: &CompareSeries) -> Option<Vec<DataPoint>> {
+        let primary_base = self.compare_primary_base_price()?;
+        let series_base = self.compare_series_base(series)?;
+        let (start, end) = self.core.model.time_scale.visible_range();
+        Some(
+            points_in_sorted_time_window(&series.points, start, end)
+                .iter()
+                .map(|point| DataPoint::new(point.x, primary_base * point.y / series_base))
+                .collect(),
+        )
+    }
+}
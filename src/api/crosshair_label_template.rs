@@ -0,0 +1,177 @@
+use chrono::format::{Item, StrftimeItems};
+use serde::{Deserialize, Serialize};
+
+use crate::error::{ChartError, ChartResult};
+
+/// Literal run or placeholder of a `CrosshairLabelTemplate`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CrosshairLabelTemplatePart {
+    Text(String),
+    /// Crosshair price as the crosshair price label shows it.
+    Price,
+    /// Crosshair time; `None` reuses the crosshair time label, otherwise a
+    /// strftime pattern rendered in the time-axis timezone.
+    Time(Option<String>),
+    /// Signed percent change of the crosshair price from the first visible
+    /// value (the percentage display mode base).
+    Change,
+    /// Value at the crosshair time of the main series, or of the compare
+    /// series with the given id.
+    SeriesValue(Option<String>),
+}
+
+/// Crosshair label content built from placeholders instead of a formatter
+/// closure, so each part keeps using the cached label formatters.
+///
+/// Parse from a string such as `"{price} • {time:%H:%M}"` (placeholders:
+/// `{price}`, `{time}`, `{time:PATTERN}`, `{change}`, `{value}`,
+/// `{value:SERIES_ID}`; `{{` and `}}` escape braces) or chain the builder
+/// methods.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct CrosshairLabelTemplate {
+    parts: Vec<CrosshairLabelTemplatePart>,
+}
+
+impl CrosshairLabelTemplate {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn parse(template: &str) -> ChartResult<Self> {
+        let mut parsed = Self::new();
+        let mut chars = template.chars().peekable();
+        while let Some(ch) = chars.next() {
+            match ch {
+                '{' if chars.peek() == Some(&'{') => {
+                    chars.next();
+                    parsed = parsed.text("{");
+                }
+                '}' if chars.peek() == Some(&'}') => {
+                    chars.next();
+                    parsed = parsed.text("}");
+                }
+                '{' => {
+                    let mut placeholder = String::new();
+                    loop {
+                        match chars.next() {
+                            Some('}') => break,
+                            Some(ch) => placeholder.push(ch),
+                            None => {
+                                return Err(ChartError::InvalidData(format!(
+                                    "unclosed crosshair label placeholder `{{{placeholder}`"
+                                )));
+                            }
+                        }
+                    }
+                    parsed.parts.push(parse_placeholder(&placeholder)?);
+                }
+                '}' => {
+                    return Err(ChartError::InvalidData(
+                        "unmatched `}` in crosshair label template".to_owned(),
+                    ));
+                }
+                ch => {
+                    let mut buffer = [0; 4];
+                    parsed = parsed.text(ch.encode_utf8(&mut buffer));
+                }
+            }
+        }
+        parsed.validate()?;
+        Ok(parsed)
+    }
+
+    /// Appends literal text, merging it with a preceding literal.
+    #[must_use]
+    pub fn text(mut self, text: &str) -> Self {
+        if let Some(CrosshairLabelTemplatePart::Text(last)) = self.parts.last_mut() {
+            last.push_str(text);
+        } else if !text.is_empty() {
+            self.parts
+                .push(CrosshairLabelTemplatePart::Text(text.to_owned()));
+        }
+        self
+    }
+
+    #[must_use]
+    pub fn price(mut self) -> Self {
+        self.parts.push(CrosshairLabelTemplatePart::Price);
+        self
+    }
+
+    #[must_use]
+    pub fn time(mut self) -> Self {
+        self.parts.push(CrosshairLabelTemplatePart::Time(None));
+        self
+    }
+
+    #[must_use]
+    pub fn time_with_pattern(mut self, pattern: &str) -> Self {
+        self.parts
+            .push(CrosshairLabelTemplatePart::Time(Some(pattern.to_owned())));
+        self
+    }
+
+    #[must_use]
+    pub fn change(mut self) -> Self {
+        self.parts.push(CrosshairLabelTemplatePart::Change);
+        self
+    }
+
+    #[must_use]
+    pub fn series_value(mut self) -> Self {
+        self.parts
+            .push(CrosshairLabelTemplatePart::SeriesValue(None));
+        self
+    }
+
+    #[must_use]
+    pub fn compare_series_value(mut self, series_id: &str) -> Self {
+        self.parts
+            .push(CrosshairLabelTemplatePart::SeriesValue(Some(
+                series_id.to_owned(),
+            )));
+        self
+    }
+
+    #[must_use]
+    pub fn parts(&self) -> &[CrosshairLabelTemplatePart] {
+        &self.parts
+    }
+
+    /// Rejects malformed time patterns, which chrono would only report while
+    /// rendering.
+    pub fn validate(&self) -> ChartResult<()> {
+        for part in &self.parts {
+            if let CrosshairLabelTemplatePart::Time(Some(pattern)) = part
+                && StrftimeItems::new(pattern).any(|item| matches!(item, Item::Error))
+            {
+                return Err(ChartError::InvalidData(format!(
+                    "invalid crosshair label time pattern `{pattern}`"
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+fn parse_placeholder(placeholder: &str) -> ChartResult<CrosshairLabelTemplatePart> {
+    let (name, argument) = match placeholder.split_once(':') {
+        Some((name, argument)) => (name.trim(), Some(argument)),
+        None => (placeholder.trim(), None),
+    };
+    let part = match (name, argument) {
+        ("price", None) => CrosshairLabelTemplatePart::Price,
+        ("time", pattern) => CrosshairLabelTemplatePart::Time(pattern.map(str::to_owned)),
+        ("change", None) => CrosshairLabelTemplatePart::Change,
+        ("value", series_id) => {
+            CrosshairLabelTemplatePart::SeriesValue(series_id.map(|id| id.trim().to_owned()))
+        }
+        _ => {
+            return Err(ChartError::InvalidData(format!(
+                "unknown crosshair label placeholder `{{{placeholder}}}`"
+            )));
+        }
+    };
+    Ok(part)
+}
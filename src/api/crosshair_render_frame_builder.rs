@@ -1,23 +1,28 @@
 use crate::core::PaneId;
 use crate::error::ChartResult;
 use crate::render::{
-    CanvasLayerKind, LayeredRenderFrame, LinePrimitive, RectPrimitive, RenderFrame, Renderer,
-    TextHAlign, TextPrimitive,
+    CanvasLayerKind, ClipRect, LayeredRenderFrame, LinePrimitive, RectPrimitive, RenderFrame,
+    Renderer, TextHAlign, TextPrimitive,
 };
 
 use super::axis_label_format::map_price_to_display_value;
+use super::axis_label_stacking_resolver::AxisLabelSpan;
+use super::axis_render_frame_builder::AxisPriceDisplayContext;
+use super::crosshair_snap_marker_primitives_builder::crosshair_snap_marker_rects;
+use super::label_text_formatter::CrosshairLabelTemplateValues;
+use super::last_price_axis_label_primitives_builder::LastPriceAxisLabelPrimitives;
 use super::layout_helpers::{
-    estimate_label_text_width_px, rects_overlap, resolve_crosshair_box_vertical_layout,
-    stabilize_position,
+    rects_overlap, resolve_crosshair_box_vertical_layout, stabilize_position,
 };
+use super::render_clip_regions_resolver::RenderClipRegions;
 use super::{
-    ChartEngine, CrosshairLabelBoxHorizontalAnchor, CrosshairLabelBoxOverflowPolicy,
+    ChartEngine, ChartWarning, CrosshairLabelBoxHorizontalAnchor, CrosshairLabelBoxOverflowPolicy,
     CrosshairLabelBoxVisibilityPriority, CrosshairLabelBoxWidthMode, CrosshairLabelBoxZOrderPolicy,
-    CrosshairLabelSourceMode, RenderStyle,
+    CrosshairLabelSourceMode, PriceAxisLabelCollisionPriority, RenderStyle, WarningLabel,
 };
 
 #[derive(Debug, Clone, Copy)]
-pub(super) struct CrosshairRenderContext {
+pub(super) struct CrosshairRenderContext<'a> {
     pub main_pane_id: PaneId,
     pub plot_right: f64,
     pub plot_bottom: f64,
@@ -27,7 +32,8 @@ pub(super) struct CrosshairRenderContext {
     pub fallback_display_base_price: f64,
     pub display_tick_step_abs: f64,
     pub display_suffix: &'static str,
-    pub style: RenderStyle,
+    pub clip_regions: RenderClipRegions,
+    pub style: &'a RenderStyle,
 }
 
 impl<R: Renderer> ChartEngine<R> {
@@ -36,6 +42,7 @@ impl<R: Renderer> ChartEngine<R> {
         frame: &mut RenderFrame,
         layered: &mut LayeredRenderFrame,
         ctx: CrosshairRenderContext,
+        mut last_value_labels: Vec<LastPriceAxisLabelPrimitives>,
     ) -> ChartResult<()> {
         let main_pane_id = ctx.main_pane_id;
         let plot_right = ctx.plot_right;
@@ -48,9 +55,12 @@ impl<R: Renderer> ChartEngine<R> {
         let display_suffix = ctx.display_suffix;
         let style = ctx.style;
 
+        let clip_regions = ctx.clip_regions;
         macro_rules! push_line {
             ($layer:expr, $line:expr) => {{
-                frame.lines.push($line);
+                let mut line: LinePrimitive = $line;
+                line.clip_rect = line.clip_rect.or(clip_regions.plot_clip_for_layer($layer));
+                frame.lines.push(line);
                 let idx = frame.lines.len() - 1;
                 layered.push_line(main_pane_id, $layer, frame.lines[idx]);
             }};
@@ -69,6 +79,24 @@ impl<R: Renderer> ChartEngine<R> {
                 layered.push_text(main_pane_id, $layer, frame.texts[idx].clone());
             }};
         }
+        // Last-value labels go right below the crosshair label boxes so the
+        // collision policy below decides which of them remain visible.
+        macro_rules! push_last_value_labels {
+            () => {{
+                for label in last_value_labels.drain(..) {
+                    if let Some(rect) = label.box_rect {
+                        push_rect!(
+                            CanvasLayerKind::Axis,
+                            rect.with_clip_rect(clip_regions.price_axis)
+                        );
+                    }
+                    push_text!(
+                        CanvasLayerKind::Axis,
+                        label.text.with_clip_rect(clip_regions.price_axis)
+                    );
+                }
+            }};
+        }
         let crosshair = self.core.model.interaction.crosshair();
         if crosshair.visible {
             let crosshair_x = crosshair
@@ -79,10 +107,37 @@ impl<R: Renderer> ChartEngine<R> {
                 .snapped_y
                 .unwrap_or(crosshair.y)
                 .clamp(0.0, plot_bottom);
+            // Free crosshair prices snap to the min-move tick grid when enabled.
+            let grid_price = match self.price_tick_min_move_grid() {
+                Some(min_move) if crosshair.snapped_price.is_none() => {
+                    let price = self
+                        .core
+                        .model
+                        .price_scale
+                        .pixel_to_price(crosshair_y, self.core.model.viewport)?;
+                    Some((price / min_move).round() * min_move)
+                }
+                _ => None,
+            };
+            let crosshair_y = match grid_price {
+                Some(price) => self
+                    .core
+                    .model
+                    .price_scale
+                    .price_to_pixel(price, self.core.model.viewport)?
+                    .clamp(0.0, plot_bottom),
+                None => crosshair_y,
+            };
             let mut time_box_rect: Option<RectPrimitive> = None;
             let mut time_box_text: Option<TextPrimitive> = None;
             let mut price_box_rect: Option<RectPrimitive> = None;
             let mut price_box_text: Option<TextPrimitive> = None;
+            let (vertical_line_bottom, horizontal_line_right) =
+                if style.crosshair_lines_extend_into_axis_panels {
+                    (viewport_height, viewport_width)
+                } else {
+                    (plot_bottom, plot_right)
+                };
             if style.show_crosshair_lines && style.show_crosshair_vertical_line {
                 let vertical_line_color = style
                     .crosshair_vertical_line_color
@@ -96,10 +151,11 @@ impl<R: Renderer> ChartEngine<R> {
                         crosshair_x,
                         0.0,
                         crosshair_x,
-                        plot_bottom,
+                        vertical_line_bottom,
                         vertical_line_width,
                         vertical_line_color,
                     )
+                    .with_clip_rect(ClipRect::new(0.0, 0.0, plot_right, vertical_line_bottom))
                     .with_stroke_style(
                         style
                             .crosshair_vertical_line_style
@@ -119,11 +175,12 @@ impl<R: Renderer> ChartEngine<R> {
                     LinePrimitive::new(
                         0.0,
                         crosshair_y,
-                        plot_right,
+                        horizontal_line_right,
                         crosshair_y,
                         horizontal_line_width,
                         horizontal_line_color,
                     )
+                    .with_clip_rect(ClipRect::new(0.0, 0.0, horizontal_line_right, plot_bottom))
                     .with_stroke_style(
                         style
                             .crosshair_horizontal_line_style
@@ -131,6 +188,34 @@ impl<R: Renderer> ChartEngine<R> {
                     )
                 );
             }
+            if let Some(marker) = style.crosshair_snap_marker
+                && crosshair.snapped_x.is_some()
+                && crosshair.snapped_y.is_some()
+            {
+                let plot_clip = clip_regions.plot_clip_for_layer(CanvasLayerKind::Crosshair);
+                for rect in crosshair_snap_marker_rects(
+                    crosshair_x,
+                    crosshair_y,
+                    marker,
+                    self.crosshair_snap_marker_pulse_progress(),
+                ) {
+                    push_rect!(
+                        CanvasLayerKind::Crosshair,
+                        RectPrimitive {
+                            clip_rect: plot_clip,
+                            ..rect
+                        }
+                    );
+                }
+            }
+            let presentation = &self.core.presentation;
+            let template_values = if presentation.crosshair_time_label_template.is_some()
+                || presentation.crosshair_price_label_template.is_some()
+            {
+                Some(self.resolve_crosshair_label_template_values(crosshair_x, crosshair_y, ctx)?)
+            } else {
+                None
+            };
             if style.show_crosshair_time_label {
                 let time_box_fill_color = style
                     .crosshair_time_label_box_color
@@ -160,6 +245,7 @@ impl<R: Renderer> ChartEngine<R> {
                         (plot_right - time_label_padding_x).max(time_label_padding_x),
                     );
                 let mut time_text_x = crosshair_time_label_x;
+                let mut time_label_hidden = false;
                 let mut time_text_h_align = TextHAlign::Center;
                 let time_label_precision = style
                     .crosshair_time_label_numeric_precision
@@ -169,19 +255,30 @@ impl<R: Renderer> ChartEngine<R> {
                 } else {
                     CrosshairLabelSourceMode::PointerProjected
                 };
-                let text = Self::apply_crosshair_label_text_transform(
-                    self.format_crosshair_time_axis_label(
+                let content = match (&presentation.crosshair_time_label_template, template_values) {
+                    (Some(template), Some(values)) => {
+                        self.render_crosshair_label_template(template, values)
+                    }
+                    _ => self.apply_crosshair_time_label_mode(
+                        self.format_crosshair_time_axis_label(
+                            crosshair_time,
+                            visible_span_abs,
+                            time_label_precision,
+                            time_source_mode,
+                        ),
                         crosshair_time,
-                        visible_span_abs,
-                        time_label_precision,
-                        time_source_mode,
                     ),
+                };
+                let mut text = Self::apply_crosshair_label_text_transform(
+                    content,
                     style
                         .crosshair_time_label_prefix
-                        .unwrap_or(style.crosshair_label_prefix),
+                        .as_deref()
+                        .unwrap_or(&style.crosshair_label_prefix),
                     style
                         .crosshair_time_label_suffix
-                        .unwrap_or(style.crosshair_label_suffix),
+                        .as_deref()
+                        .unwrap_or(&style.crosshair_label_suffix),
                 );
                 let time_label_anchor_y = (plot_bottom + style.crosshair_time_label_offset_y_px)
                     .min((viewport_height - style.crosshair_time_label_font_size_px).max(0.0));
@@ -201,10 +298,9 @@ impl<R: Renderer> ChartEngine<R> {
                         .crosshair_time_label_box_text_h_align
                         .or(style.crosshair_label_box_text_h_align)
                         .unwrap_or(TextHAlign::Center);
-                    let estimated_text_width = estimate_label_text_width_px(
-                        &text,
-                        style.crosshair_time_label_font_size_px,
-                    );
+                    let estimated_text_width = style
+                        .label_text_metrics
+                        .text_width_px(&text, style.crosshair_time_label_font_size_px);
                     let time_box_width_mode = style
                         .crosshair_time_label_box_width_mode
                         .unwrap_or(style.crosshair_label_box_width_mode);
@@ -265,6 +361,22 @@ impl<R: Renderer> ChartEngine<R> {
                     let box_width = requested_box_width
                         .max(time_box_min_width)
                         .clamp(0.0, time_max_box_width);
+                    match style.label_overflow_policy.fit_text(
+                        &text,
+                        box_width - 2.0 * style.crosshair_time_label_box_padding_x_px,
+                        style.crosshair_time_label_font_size_px,
+                        style.label_text_metrics,
+                    ) {
+                        Some(fitted) => text = fitted,
+                        None => time_label_hidden = true,
+                    }
+                    if box_width < requested_box_width.max(time_box_min_width) {
+                        self.emit_warning(ChartWarning::LabelBoxClipped {
+                            label: WarningLabel::CrosshairTime,
+                            requested_width_px: requested_box_width.max(time_box_min_width),
+                            width_px: box_width,
+                        });
+                    }
                     let time_box_horizontal_anchor = style
                         .crosshair_time_label_box_horizontal_anchor
                         .or(style.crosshair_label_box_horizontal_anchor)
@@ -298,7 +410,7 @@ impl<R: Renderer> ChartEngine<R> {
                         );
                     time_label_y = resolved_time_label_y;
                     let box_height = (box_bottom - box_top).max(0.0);
-                    if box_width > 0.0 && box_height > 0.0 {
+                    if box_width > 0.0 && box_height > 0.0 && !time_label_hidden {
                         time_text_x = match time_text_h_align {
                             TextHAlign::Left => (box_left
                                 + style.crosshair_time_label_box_padding_x_px)
@@ -344,20 +456,22 @@ impl<R: Renderer> ChartEngine<R> {
                         time_box_rect = Some(rect);
                     }
                 }
-                time_box_text = Some(TextPrimitive::new(
-                    text,
-                    time_text_x,
-                    time_label_y,
-                    style.crosshair_time_label_font_size_px,
-                    time_label_text_color,
-                    time_text_h_align,
-                ));
+                if !time_label_hidden {
+                    time_box_text = Some(TextPrimitive::new(
+                        text,
+                        time_text_x,
+                        time_label_y,
+                        style.crosshair_time_label_font_size_px,
+                        time_label_text_color,
+                        time_text_h_align,
+                    ));
+                }
             }
             if style.show_crosshair_price_label {
                 let price_box_fill_color = style
                     .crosshair_price_label_box_color
                     .unwrap_or(style.crosshair_label_box_color);
-                let crosshair_price = crosshair.snapped_price.unwrap_or(
+                let crosshair_price = crosshair.snapped_price.or(grid_price).unwrap_or(
                     self.core
                         .model
                         .price_scale
@@ -365,7 +479,7 @@ impl<R: Renderer> ChartEngine<R> {
                 );
                 let display_price = map_price_to_display_value(
                     crosshair_price,
-                    self.core.behavior.price_axis_label_config.display_mode,
+                    self.resolve_price_axis_display_mode(),
                     fallback_display_base_price,
                 );
                 let price_label_precision = style
@@ -376,8 +490,14 @@ impl<R: Renderer> ChartEngine<R> {
                 } else {
                     CrosshairLabelSourceMode::PointerProjected
                 };
-                let text = Self::apply_crosshair_label_text_transform(
-                    self.format_crosshair_price_axis_label(
+                let content = match (
+                    &presentation.crosshair_price_label_template,
+                    template_values,
+                ) {
+                    (Some(template), Some(values)) => {
+                        self.render_crosshair_label_template(template, values)
+                    }
+                    _ => self.format_crosshair_price_axis_label(
                         display_price,
                         display_tick_step_abs,
                         display_suffix,
@@ -385,12 +505,17 @@ impl<R: Renderer> ChartEngine<R> {
                         visible_span_abs,
                         price_source_mode,
                     ),
+                };
+                let mut text = Self::apply_crosshair_label_text_transform(
+                    content,
                     style
                         .crosshair_price_label_prefix
-                        .unwrap_or(style.crosshair_label_prefix),
+                        .as_deref()
+                        .unwrap_or(&style.crosshair_label_prefix),
                     style
                         .crosshair_price_label_suffix
-                        .unwrap_or(style.crosshair_label_suffix),
+                        .as_deref()
+                        .unwrap_or(&style.crosshair_label_suffix),
                 );
                 let price_label_anchor_y = (crosshair_y - style.crosshair_price_label_offset_y_px)
                     .clamp(
@@ -423,6 +548,7 @@ impl<R: Renderer> ChartEngine<R> {
                     - style.crosshair_price_label_padding_right_px)
                     .clamp(plot_right, viewport_width);
                 let mut text_x = crosshair_price_label_anchor_x;
+                let mut price_label_hidden = false;
                 let mut price_text_h_align = TextHAlign::Right;
                 if style.show_crosshair_price_label_box {
                     price_text_h_align = style
@@ -431,10 +557,9 @@ impl<R: Renderer> ChartEngine<R> {
                         .unwrap_or(TextHAlign::Right);
                     let axis_panel_left = plot_right;
                     let axis_panel_width = (viewport_width - axis_panel_left).max(0.0);
-                    let estimated_text_width = estimate_label_text_width_px(
-                        &text,
-                        style.crosshair_price_label_font_size_px,
-                    );
+                    let estimated_text_width = style
+                        .label_text_metrics
+                        .text_width_px(&text, style.crosshair_price_label_font_size_px);
                     let price_box_width_mode = style
                         .crosshair_price_label_box_width_mode
                         .unwrap_or(style.crosshair_label_box_width_mode);
@@ -496,6 +621,22 @@ impl<R: Renderer> ChartEngine<R> {
                     let box_width = requested_box_width
                         .max(price_box_min_width)
                         .clamp(0.0, price_max_box_width);
+                    match style.label_overflow_policy.fit_text(
+                        &text,
+                        box_width - 2.0 * style.crosshair_price_label_box_padding_x_px,
+                        style.crosshair_price_label_font_size_px,
+                        style.label_text_metrics,
+                    ) {
+                        Some(fitted) => text = fitted,
+                        None => price_label_hidden = true,
+                    }
+                    if box_width < requested_box_width.max(price_box_min_width) {
+                        self.emit_warning(ChartWarning::LabelBoxClipped {
+                            label: WarningLabel::CrosshairPrice,
+                            requested_width_px: requested_box_width.max(price_box_min_width),
+                            width_px: box_width,
+                        });
+                    }
                     let price_box_horizontal_anchor = style
                         .crosshair_price_label_box_horizontal_anchor
                         .or(style.crosshair_label_box_horizontal_anchor)
@@ -538,7 +679,7 @@ impl<R: Renderer> ChartEngine<R> {
                             - style.crosshair_price_label_box_padding_x_px)
                             .clamp(box_left, box_left + box_width),
                     };
-                    if box_width > 0.0 && box_height > 0.0 {
+                    if box_width > 0.0 && box_height > 0.0 && !price_label_hidden {
                         let mut rect = RectPrimitive::new(
                             box_left,
                             box_top,
@@ -575,14 +716,16 @@ impl<R: Renderer> ChartEngine<R> {
                         price_box_rect = Some(rect);
                     }
                 }
-                price_box_text = Some(TextPrimitive::new(
-                    text,
-                    text_x,
-                    text_y,
-                    style.crosshair_price_label_font_size_px,
-                    price_label_text_color,
-                    price_text_h_align,
-                ));
+                if !price_label_hidden {
+                    price_box_text = Some(TextPrimitive::new(
+                        text,
+                        text_x,
+                        text_y,
+                        style.crosshair_price_label_font_size_px,
+                        price_label_text_color,
+                        price_text_h_align,
+                    ));
+                }
             }
 
             if let (Some(time_rect), Some(price_rect)) = (time_box_rect, price_box_rect) {
@@ -610,6 +753,46 @@ impl<R: Renderer> ChartEngine<R> {
                     }
                 }
             }
+            let crosshair_price_span = match (price_box_rect, price_box_text.as_ref()) {
+                (Some(rect), _) => Some(AxisLabelSpan {
+                    top: rect.y,
+                    height: rect.height,
+                }),
+                (None, Some(text)) => Some(AxisLabelSpan {
+                    top: text.y,
+                    height: style.crosshair_price_label_font_size_px,
+                }),
+                (None, None) => None,
+            };
+            if let Some(crosshair_span) = crosshair_price_span {
+                match style.price_axis_label_collision_priority {
+                    PriceAxisLabelCollisionPriority::PreferCrosshair => {
+                        last_value_labels
+                            .retain(|label| !label.span(style).overlaps(crosshair_span));
+                    }
+                    PriceAxisLabelCollisionPriority::PreferLastValue => {
+                        if last_value_labels
+                            .iter()
+                            .any(|label| label.span(style).overlaps(crosshair_span))
+                        {
+                            price_box_rect = None;
+                            price_box_text = None;
+                        }
+                    }
+                }
+            }
+            push_last_value_labels!();
+
+            // Label boxes stay inside their axis panels regardless of the
+            // overflow policy, which only controls the inner margin.
+            let time_box_rect =
+                time_box_rect.map(|rect| rect.with_clip_rect(clip_regions.time_axis));
+            let time_box_text =
+                time_box_text.map(|text| text.with_clip_rect(clip_regions.time_axis));
+            let price_box_rect =
+                price_box_rect.map(|rect| rect.with_clip_rect(clip_regions.price_axis));
+            let price_box_text =
+                price_box_text.map(|text| text.with_clip_rect(clip_regions.price_axis));
             let mut z_order_policy = style.crosshair_label_box_z_order_policy;
             if let Some(time_policy) = style.crosshair_time_label_box_z_order_policy {
                 z_order_policy = time_policy;
@@ -648,6 +831,55 @@ impl<R: Renderer> ChartEngine<R> {
                 }
             }
         }
+        push_last_value_labels!();
         Ok(())
     }
+
+    /// Crosshair time and price (snapped when available) plus the label
+    /// settings shared by both crosshair label templates.
+    fn resolve_crosshair_label_template_values(
+        &self,
+        crosshair_x: f64,
+        crosshair_y: f64,
+        ctx: CrosshairRenderContext,
+    ) -> ChartResult<CrosshairLabelTemplateValues> {
+        let crosshair = self.core.model.interaction.crosshair();
+        let model = &self.core.model;
+        let source_mode = |snapped: bool| {
+            if snapped {
+                CrosshairLabelSourceMode::SnappedData
+            } else {
+                CrosshairLabelSourceMode::PointerProjected
+            }
+        };
+        let style = ctx.style;
+        Ok(CrosshairLabelTemplateValues {
+            time: match crosshair.snapped_time {
+                Some(time) => time,
+                None => model
+                    .time_scale
+                    .pixel_to_time(crosshair_x, model.viewport)?,
+            },
+            time_precision: style
+                .crosshair_time_label_numeric_precision
+                .or(style.crosshair_label_numeric_precision),
+            time_source_mode: source_mode(crosshair.snapped_time.is_some()),
+            price: match crosshair.snapped_price {
+                Some(price) => price,
+                None => model
+                    .price_scale
+                    .pixel_to_price(crosshair_y, model.viewport)?,
+            },
+            price_precision: style
+                .crosshair_price_label_numeric_precision
+                .or(style.crosshair_label_numeric_precision),
+            price_source_mode: source_mode(crosshair.snapped_price.is_some()),
+            visible_span_abs: ctx.visible_span_abs,
+            display: AxisPriceDisplayContext {
+                fallback_display_base_price: ctx.fallback_display_base_price,
+                display_tick_step_abs: ctx.display_tick_step_abs,
+                display_suffix: ctx.display_suffix,
+            },
+        })
+    }
 }
@@ -0,0 +1,38 @@
+use crate::render::{Color, RectPrimitive};
+
+use super::CrosshairSnapMarkerStyle;
+
+/// Halo radius at the end of a pulse, as a multiple of the marker radius.
+const PULSE_MAX_RADIUS_SCALE: f64 = 2.5;
+/// Halo opacity at the start of a pulse, relative to the fill opacity.
+const PULSE_START_OPACITY: f64 = 0.4;
+
+/// Circles for the snap marker centered on `(x, y)`: the pulse halo while
+/// `pulse_progress` is set, then the bordered marker itself.
+pub(super) fn crosshair_snap_marker_rects(
+    x: f64,
+    y: f64,
+    marker: CrosshairSnapMarkerStyle,
+    pulse_progress: Option<f64>,
+) -> Vec<RectPrimitive> {
+    let circle = |radius: f64, color: Color| {
+        RectPrimitive::new(x - radius, y - radius, radius * 2.0, radius * 2.0, color)
+            .with_corner_radius(radius)
+    };
+    let mut rects = Vec::with_capacity(2);
+    if let Some(progress) = pulse_progress.map(|progress| progress.clamp(0.0, 1.0)) {
+        let radius = marker.radius_px * (1.0 + (PULSE_MAX_RADIUS_SCALE - 1.0) * progress);
+        let fill = marker.fill_color;
+        let alpha = fill.alpha * PULSE_START_OPACITY * (1.0 - progress);
+        rects.push(circle(
+            radius,
+            Color::rgba(fill.red, fill.green, fill.blue, alpha),
+        ));
+    }
+    let mut marker_rect = circle(marker.radius_px, marker.fill_color);
+    if marker.border_width_px > 0.0 {
+        marker_rect = marker_rect.with_border(marker.border_width_px, marker.border_color);
+    }
+    rects.push(marker_rect);
+    rects
+}
@@ -0,0 +1,303 @@
+use std::any::Any;
+
+use crate::core::{DataPoint, PaneId};
+use crate::error::{ChartError, ChartResult};
+use crate::extensions::{CustomSeries, CustomSeriesPrimitives, CustomSeriesRenderContext};
+use crate::render::{CanvasLayerKind, ClipRect, LayeredRenderFrame, RenderFrame, Renderer};
+
+use super::ChartEngine;
+
+/// Object-safe view of a `CustomSeries` together with its rows.
+trait ErasedCustomSeries {
+    fn autoscale_range(&self, window: Option<(f64, f64)>) -> Option<(f64, f64)>;
+    fn render(
+        &self,
+        ctx: &CustomSeriesRenderContext,
+        out: &mut CustomSeriesPrimitives,
+    ) -> ChartResult<()>;
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+struct CustomSeriesSlot<S: CustomSeries> {
+    series: S,
+    rows: Vec<S::Row>,
+}
+
+impl<S: CustomSeries> CustomSeriesSlot<S> {
+    /// Sorts `rows` by time, rejecting non-finite times.
+    fn canonical_rows(series: &S, mut rows: Vec<S::Row>) -> ChartResult<Vec<S::Row>> {
+        if rows.iter().any(|row| !series.row_time(row).is_finite()) {
+            return Err(ChartError::InvalidData(
+                "custom series rows must have finite times".to_owned(),
+            ));
+        }
+        rows.sort_by(|a, b| series.row_time(a).total_cmp(&series.row_time(b)));
+        Ok(rows)
+    }
+
+    fn rows_in_window(&self, window: Option<(f64, f64)>) -> &[S::Row] {
+        let Some((start, end)) = window else {
+            return &self.rows;
+        };
+        let (min_t, max_t) = (start.min(end), start.max(end));
+        let first = self
+            .rows
+            .partition_point(|row| self.series.row_time(row) < min_t);
+        let last = self
+            .rows
+            .partition_point(|row| self.series.row_time(row) <= max_t);
+        &self.rows[first..last.max(first)]
+    }
+}
+
+impl<S: CustomSeries + 'static> ErasedCustomSeries for CustomSeriesSlot<S> {
+    fn autoscale_range(&self, window: Option<(f64, f64)>) -> Option<(f64, f64)> {
+        let rows = self.rows_in_window(window);
+        if rows.is_empty() {
+            return None;
+        }
+        self.series
+            .autoscale_range(rows)
+            .filter(|(low, high)| low.is_finite() && high.is_finite())
+            .map(|(low, high)| (low.min(high), low.max(high)))
+    }
+
+    fn render(
+        &self,
+        ctx: &CustomSeriesRenderContext,
+        out: &mut CustomSeriesPrimitives,
+    ) -> ChartResult<()> {
+        self.series
+            .render(self.rows_in_window(Some(ctx.visible_range)), ctx, out)
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+/// Registered custom series and the pane it draws into.
+pub(super) struct CustomSeriesEntry {
+    id: String,
+    pane_id: PaneId,
+    slot: Box<dyn ErasedCustomSeries>,
+}
+
+impl<R: Renderer> ChartEngine<R> {
+    /// Registers a custom series drawn into `pane_id`.
+    ///
+    /// Like the built-in series it joins the pane's autoscale and falls back
+    /// to the main pane if its pane is removed.
+    pub fn add_custom_series<S: CustomSeries + 'static>(
+        &mut self,
+        id: impl Into<String>,
+        pane_id: PaneId,
+        series: S,
+        rows: Vec<S::Row>,
+    ) -> ChartResult<()> {
+        let id = id.into();
+        if id.is_empty() {
+            return Err(ChartError::InvalidData(
+                "custom series id must not be empty".to_owned(),
+            ));
+        }
+        if self.has_custom_series(&id) {
+            return Err(ChartError::InvalidData(format!(
+                "custom series `{id}` already exists"
+            )));
+        }
+        if !self.core.model.pane_collection.contains(pane_id) {
+            return Err(ChartError::InvalidData(
+                "custom series pane does not exist".to_owned(),
+            ));
+        }
+        let rows = CustomSeriesSlot::canonical_rows(&series, rows)?;
+        self.core.model.custom_series.push(CustomSeriesEntry {
+            id,
+            pane_id,
+            slot: Box::new(CustomSeriesSlot { series, rows }),
+        });
+        self.invalidate_pane_content(pane_id);
+        Ok(())
+    }
+
+    /// Replaces the rows of custom series `id`, which must have been
+    /// registered with the same series type `S`.
+    pub fn set_custom_series_rows<S: CustomSeries + 'static>(
+        &mut self,
+        id: &str,
+        rows: Vec<S::Row>,
+    ) -> ChartResult<()> {
+        let Some(entry) = self
+            .core
+            .model
+            .custom_series
+            .iter_mut()
+            .find(|entry| entry.id == id)
+        else {
+            return Err(ChartError::InvalidData(format!(
+                "custom series `{id}` does not exist"
+            )));
+        };
+        let Some(slot) = entry
+            .slot
+            .as_any_mut()
+            .downcast_mut::<CustomSeriesSlot<S>>()
+        else {
+            return Err(ChartError::InvalidData(format!(
+                "custom series `{id}` was registered with a different series type"
+            )));
+        };
+        slot.rows = CustomSeriesSlot::canonical_rows(&slot.series, rows)?;
+        let pane_id = entry.pane_id;
+        self.invalidate_pane_content(pane_id);
+        Ok(())
+    }
+
+    /// Removes custom series `id`, returning whether it existed.
+    pub fn remove_custom_series(&mut self, id: &str) -> bool {
+        let Some(index) = self
+            .core
+            .model
+            .custom_series
+            .iter()
+            .position(|entry| entry.id == id)
+        else {
+            return false;
+        };
+        let entry = self.core.model.custom_series.remove(index);
+        self.invalidate_pane_content(entry.pane_id);
+        true
+    }
+
+    #[must_use]
+    pub fn has_custom_series(&self, id: &str) -> bool {
+        self.core
+            .model
+            .custom_series
+            .iter()
+            .any(|entry| entry.id == id)
+    }
+
+    /// Registered custom series ids in draw order.
+    #[must_use]
+    pub fn custom_series_ids(&self) -> Vec<&str> {
+        self.core
+            .model
+            .custom_series
+            .iter()
+            .map(|entry| entry.id.as_str())
+            .collect()
+    }
+
+    /// Autoscale range custom series `id` reports for its visible rows.
+    #[must_use]
+    pub fn custom_series_autoscale_range(&self, id: &str) -> Option<(f64, f64)> {
+        self.custom_series_range_in_window(id, self.core.model.time_scale.visible_range())
+    }
+
+    pub(super) fn custom_series_pane_id(&self, id: &str) -> Option<PaneId> {
+        self.core
+            .model
+            .custom_series
+            .iter()
+            .find(|entry| entry.id == id)
+            .map(|entry| entry.pane_id)
+    }
+
+    /// Autoscale range custom series `id` reports for rows in `window`.
+    pub(super) fn custom_series_range_in_window(
+        &self,
+        id: &str,
+        window: (f64, f64),
+    ) -> Option<(f64, f64)> {
+        self.core
+            .model
+            .custom_series
+            .iter()
+            .find(|entry| entry.id == id)?
+            .slot
+            .autoscale_range(Some(window))
+    }
+
+    /// Merged autoscale range of the custom series assigned to `pane_id`.
+    pub(super) fn custom_series_price_extents(
+        &self,
+        pane_id: PaneId,
+        window: Option<(f64, f64)>,
+    ) -> Option<(f64, f64)> {
+        self.core
+            .model
+            .custom_series
+            .iter()
+            .filter(|entry| entry.pane_id == pane_id)
+            .filter_map(|entry| entry.slot.autoscale_range(window))
+            .reduce(|(low, high), (next_low, next_high)| (low.min(next_low), high.max(next_high)))
+    }
+
+    /// Visible custom-series range of the main pane as two flat samples at
+    /// the window start, for the main price-scale autoscale paths.
+    pub(super) fn main_pane_custom_series_autoscale_points(&self) -> Vec<DataPoint> {
+        let (start, end) = self.core.model.time_scale.visible_range();
+        self.custom_series_price_extents(self.main_pane_id(), Some((start, end)))
+            .map(|(low, high)| vec![DataPoint::new(start, low), DataPoint::new(start, high)])
+            .unwrap_or_default()
+    }
+
+    pub(super) fn append_custom_series_primitives(
+        &self,
+        frame: &mut RenderFrame,
+        layered: &mut LayeredRenderFrame,
+        main_pane_id: PaneId,
+        visible_range: (f64, f64),
+        plot_clip: ClipRect,
+    ) -> ChartResult<()> {
+        let mut hit_regions = Vec::new();
+        for entry in &self.core.model.custom_series {
+            let target = self.resolve_pane_series_render_target(
+                entry.pane_id,
+                main_pane_id,
+                visible_range.0,
+                visible_range.1,
+            );
+            let ctx = CustomSeriesRenderContext {
+                time_scale: self.core.model.time_scale,
+                price_scale: target.price_scale,
+                viewport: self.core.model.viewport,
+                visible_range,
+                plot_clip,
+            };
+            let mut out = CustomSeriesPrimitives::default();
+            entry.slot.render(&ctx, &mut out)?;
+
+            let pane_id = target.pane_id;
+            for mut line in out.lines {
+                line.clip_rect = line.clip_rect.or(Some(plot_clip));
+                frame.lines.push(line);
+                layered.push_line(pane_id, CanvasLayerKind::Series, line);
+            }
+            for mut rect in out.rects {
+                rect.clip_rect = rect.clip_rect.or(Some(plot_clip));
+                frame.rects.push(rect);
+                layered.push_rect(pane_id, CanvasLayerKind::Series, rect);
+            }
+            for mut path in out.paths {
+                path.clip_rect = path.clip_rect.or(Some(plot_clip));
+                frame.paths.push(path.clone());
+                layered.push_path(pane_id, CanvasLayerKind::Series, path);
+            }
+            for mut text in out.texts {
+                text.clip_rect = text.clip_rect.or(Some(plot_clip));
+                frame.texts.push(text.clone());
+                layered.push_text(pane_id, CanvasLayerKind::Series, text);
+            }
+            hit_regions.extend(
+                out.hit_regions
+                    .into_iter()
+                    .map(|region| (entry.id.clone(), region)),
+            );
+        }
+        self.record_custom_series_hit_regions(hit_regions);
+        Ok(())
+    }
+}
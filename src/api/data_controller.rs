@@ -2,14 +2,20 @@ use std::cmp::Ordering;
 
 use tracing::{debug, trace, warn};
 
+use crate::core::{ChartTime, ChartTimeKind, DataPoint, OhlcBar, resolve_chart_time_kind};
 use crate::error::{ChartError, ChartResult};
 use crate::render::Renderer;
 
-use super::{CandlestickBarStyleOverride, ChartEngine, PluginEvent, StyledOhlcBar};
+use super::{
+    CandlestickBarStyleOverride, ChartEngine, PluginEvent, StyledOhlcBar, TimeAxisTimeUnit,
+};
 
 impl<R: Renderer> ChartEngine<R> {
     /// Replaces line/point data series.
+    ///
+    /// Raw times carry no representation, so `chart_time_kind` resets.
     pub fn set_data(&mut self, points: Vec<crate::core::DataPoint>) {
+        self.core.model.time_kind = None;
         let original_count = points.len();
         let points = canonicalize_points(points);
         debug!(
@@ -17,12 +23,25 @@ impl<R: Renderer> ChartEngine<R> {
             canonical_count = points.len(),
             "set data points"
         );
+        self.install_canonical_points(points, "set_data");
+    }
+
+    /// Swaps already-canonical points into the model (hiding samples past an
+    /// active replay cursor) and runs the shared post-replace refresh.
+    pub(super) fn install_canonical_points(
+        &mut self,
+        mut points: Vec<DataPoint>,
+        source: &'static str,
+    ) {
+        self.mask_replay_points(&mut points);
         self.core.model.points = points;
+        self.core.runtime.last_point_animation = None;
         self.maybe_autoscale_price_after_data_set_points();
         if let Err(err) = self.refresh_price_scale_transformed_base() {
             warn!(
                 error = %err,
-                "skipping transformed-base refresh after set_data"
+                source,
+                "skipping transformed-base refresh after point replace"
             );
         }
         self.emit_plugin_event(PluginEvent::DataUpdated {
@@ -30,8 +49,46 @@ impl<R: Renderer> ChartEngine<R> {
         });
     }
 
+    /// Replaces line/point data from `ChartTime` samples.
+    ///
+    /// Times are normalized to unix seconds; the original representation is
+    /// kept so time labels respect it (business days render as dates only).
+    pub fn set_timed_data(&mut self, samples: Vec<(ChartTime, f64)>) -> ChartResult<()> {
+        let time_kind = resolve_chart_time_kind(samples.iter().map(|(time, _)| *time))?;
+        let points = samples
+            .into_iter()
+            .map(|(time, value)| Ok(DataPoint::new(self.normalize_chart_time(time)?, value)))
+            .collect::<ChartResult<Vec<_>>>()?;
+        self.set_data(points);
+        self.core.model.time_kind = time_kind;
+        Ok(())
+    }
+
     /// Appends a single line/point sample.
-    pub fn append_point(&mut self, point: crate::core::DataPoint) {
+    ///
+    /// Series stay sorted by time: an equal time appends a duplicate, an
+    /// older one is rejected with `NonMonotonicTime` (see `update_point`).
+    pub fn append_point(&mut self, point: crate::core::DataPoint) -> ChartResult<()> {
+        if !point.x.is_finite() {
+            return Err(ChartError::InvalidData(
+                "point time must be finite".to_owned(),
+            ));
+        }
+        if self.queue_replay_point(point, false)? {
+            return Ok(());
+        }
+        if self
+            .core
+            .model
+            .points
+            .last()
+            .is_some_and(|last| point.x < last.x)
+        {
+            return Err(ChartError::NonMonotonicTime {
+                index: self.core.model.points.len(),
+            });
+        }
+        let previous_value = self.displayed_last_point_value();
         self.core.model.points.push(point);
         trace!(count = self.core.model.points.len(), "append data point");
         let visible_range_changed = self.handle_realtime_time_append(point.x);
@@ -42,7 +99,9 @@ impl<R: Renderer> ChartEngine<R> {
                 "skipping transformed-base refresh after append_point"
             );
         }
+        self.start_last_point_animation(previous_value);
         self.emit_point_data_updated(visible_range_changed);
+        Ok(())
     }
 
     /// Updates point series using realtime-update semantics:
@@ -55,6 +114,10 @@ impl<R: Renderer> ChartEngine<R> {
                 "point time must be finite".to_owned(),
             ));
         }
+        if self.queue_replay_point(point, true)? {
+            return Ok(());
+        }
+        let previous_value = self.displayed_last_point_value();
 
         let mut visible_range_changed = false;
         match self
@@ -65,9 +128,9 @@ impl<R: Renderer> ChartEngine<R> {
             .map_or(Ordering::Greater, |last| point.x.total_cmp(&last.x))
         {
             Ordering::Less => {
-                return Err(ChartError::InvalidData(
-                    "point update time must be >= latest point time".to_owned(),
-                ));
+                return Err(ChartError::NonMonotonicTime {
+                    index: self.core.model.points.len(),
+                });
             }
             Ordering::Equal => {
                 if let Some(last) = self.core.model.points.last_mut() {
@@ -91,12 +154,14 @@ impl<R: Renderer> ChartEngine<R> {
                 "skipping transformed-base refresh after update_point"
             );
         }
+        self.start_last_point_animation(previous_value);
         self.emit_point_data_updated(visible_range_changed);
         Ok(())
     }
 
-    /// Replaces candlestick series.
+    /// Replaces candlestick series; like `set_data`, resets `chart_time_kind`.
     pub fn set_candles(&mut self, candles: Vec<crate::core::OhlcBar>) {
+        self.core.model.time_kind = None;
         let original_count = candles.len();
         let candles = canonicalize_candles(candles);
         debug!(
@@ -104,65 +169,123 @@ impl<R: Renderer> ChartEngine<R> {
             canonical_count = candles.len(),
             "set candles"
         );
-        self.core.model.candles = candles;
-        self.core.model.candle_style_overrides = vec![None; self.core.model.candles.len()];
-        self.maybe_autoscale_price_after_data_set_candles();
-        if let Err(err) = self.refresh_price_scale_transformed_base() {
-            warn!(
-                error = %err,
-                "skipping transformed-base refresh after set_candles"
-            );
+        let style_overrides = vec![None; candles.len()];
+        self.install_canonical_candles(candles, style_overrides, "set_candles");
+    }
+
+    /// Replaces candlestick series from `ChartTime` bars given as
+    /// `(time, [open, high, low, close])`.
+    ///
+    /// See [`Self::set_timed_data`] for time normalization rules.
+    pub fn set_timed_candles(&mut self, bars: Vec<(ChartTime, [f64; 4])>) -> ChartResult<()> {
+        let time_kind = resolve_chart_time_kind(bars.iter().map(|(time, _)| *time))?;
+        let candles = bars
+            .into_iter()
+            .map(|(time, [open, high, low, close])| {
+                OhlcBar::new(self.normalize_chart_time(time)?, open, high, low, close)
+            })
+            .collect::<ChartResult<Vec<_>>>()?;
+        self.set_candles(candles);
+        self.core.model.time_kind = time_kind;
+        Ok(())
+    }
+
+    /// Converts a `ChartTime` into the configured logical time unit.
+    fn normalize_chart_time(&self, time: ChartTime) -> ChartResult<f64> {
+        let unit = self.core.behavior.time_axis_label_config.time_unit;
+        match time {
+            // Avoids a lossy seconds round-trip for millisecond input.
+            ChartTime::UnixMillis(millis)
+                if unit == TimeAxisTimeUnit::Milliseconds && millis.is_finite() =>
+            {
+                Ok(millis)
+            }
+            _ => Ok(unit.seconds_to_unit(time.to_unix_seconds()?)),
         }
-        self.emit_plugin_event(PluginEvent::CandlesUpdated {
-            candles_len: self.core.model.candles.len(),
-        });
+    }
+
+    /// Original time representation recorded by the last `ChartTime`-based
+    /// data setter; `None` when only raw `f64` times were supplied.
+    #[must_use]
+    pub fn chart_time_kind(&self) -> Option<ChartTimeKind> {
+        self.core.model.time_kind
     }
 
     /// Replaces candlestick series with optional per-bar style overrides.
     pub fn set_styled_candles(&mut self, candles: Vec<StyledOhlcBar>) -> ChartResult<()> {
         let original_count = candles.len();
         let (candles, style_overrides) = canonicalize_styled_candles(candles)?;
+        self.core.model.time_kind = None;
         debug!(
             original_count,
             canonical_count = candles.len(),
             "set styled candles"
         );
+        self.install_canonical_candles(candles, style_overrides, "set_styled_candles");
+        Ok(())
+    }
+
+    /// Swaps already-canonical candles into the model (hiding bars past an
+    /// active replay cursor) and runs the shared post-replace refresh
+    /// (autoscale, transformed base, plugin event).
+    pub(super) fn install_canonical_candles(
+        &mut self,
+        mut candles: Vec<OhlcBar>,
+        mut style_overrides: Vec<Option<CandlestickBarStyleOverride>>,
+        source: &'static str,
+    ) {
+        self.mask_replay_candles(&mut candles, &mut style_overrides);
         self.core.model.candles = candles;
+        self.core.runtime.last_candle_animation = None;
         self.core.model.candle_style_overrides = style_overrides;
         self.maybe_autoscale_price_after_data_set_candles();
         if let Err(err) = self.refresh_price_scale_transformed_base() {
             warn!(
                 error = %err,
-                "skipping transformed-base refresh after set_styled_candles"
+                source,
+                "skipping transformed-base refresh after candle replace"
             );
         }
         self.emit_plugin_event(PluginEvent::CandlesUpdated {
             candles_len: self.core.model.candles.len(),
         });
-        Ok(())
     }
 
     /// Appends a single OHLC bar.
-    pub fn append_candle(&mut self, candle: crate::core::OhlcBar) {
-        self.core.model.candles.push(candle);
-        self.core.model.candle_style_overrides.push(None);
-        trace!(count = self.core.model.candles.len(), "append candle");
-        let visible_range_changed = self.handle_realtime_time_append(candle.time);
-        self.maybe_autoscale_price_after_realtime_data_update();
-        if let Err(err) = self.refresh_price_scale_transformed_base() {
-            warn!(
-                error = %err,
-                "skipping transformed-base refresh after append_candle"
-            );
-        }
-        self.emit_candle_data_updated(visible_range_changed);
+    ///
+    /// Like `append_point`, an older time than the latest bar is rejected
+    /// with `NonMonotonicTime`.
+    pub fn append_candle(&mut self, candle: crate::core::OhlcBar) -> ChartResult<()> {
+        self.append_styled_candle(StyledOhlcBar::new(candle))
     }
 
     /// Appends a single OHLC bar with optional per-bar style override.
+    ///
+    /// An older time than the latest bar is rejected with `NonMonotonicTime`.
     pub fn append_styled_candle(&mut self, candle: StyledOhlcBar) -> ChartResult<()> {
+        if !candle.ohlc.time.is_finite() {
+            return Err(ChartError::InvalidData(
+                "candle time must be finite".to_owned(),
+            ));
+        }
         if let Some(style_override) = candle.style_override {
             style_override.validate()?;
         }
+        if self.queue_replay_candle(candle, false)? {
+            return Ok(());
+        }
+        if self
+            .core
+            .model
+            .candles
+            .last()
+            .is_some_and(|last| candle.ohlc.time < last.time)
+        {
+            return Err(ChartError::NonMonotonicTime {
+                index: self.core.model.candles.len(),
+            });
+        }
+        let previous_close = self.displayed_last_candle_close();
         self.core.model.candles.push(candle.ohlc);
         self.core
             .model
@@ -180,6 +303,7 @@ impl<R: Renderer> ChartEngine<R> {
                 "skipping transformed-base refresh after append_styled_candle"
             );
         }
+        self.start_last_candle_animation(previous_close);
         self.emit_candle_data_updated(visible_range_changed);
         Ok(())
     }
@@ -194,6 +318,10 @@ impl<R: Renderer> ChartEngine<R> {
                 "candle time must be finite".to_owned(),
             ));
         }
+        if self.queue_replay_candle(StyledOhlcBar::new(candle), true)? {
+            return Ok(());
+        }
+        let previous_close = self.displayed_last_candle_close();
 
         let mut visible_range_changed = false;
         match self
@@ -204,9 +332,9 @@ impl<R: Renderer> ChartEngine<R> {
             .map_or(Ordering::Greater, |last| candle.time.total_cmp(&last.time))
         {
             Ordering::Less => {
-                return Err(ChartError::InvalidData(
-                    "candle update time must be >= latest candle time".to_owned(),
-                ));
+                return Err(ChartError::NonMonotonicTime {
+                    index: self.core.model.candles.len(),
+                });
             }
             Ordering::Equal => {
                 if let Some(last) = self.core.model.candles.last_mut() {
@@ -235,6 +363,7 @@ impl<R: Renderer> ChartEngine<R> {
                 "skipping transformed-base refresh after update_candle"
             );
         }
+        self.start_last_candle_animation(previous_close);
         self.emit_candle_data_updated(visible_range_changed);
         Ok(())
     }
@@ -250,6 +379,10 @@ impl<R: Renderer> ChartEngine<R> {
         if let Some(style_override) = candle.style_override {
             style_override.validate()?;
         }
+        if self.queue_replay_candle(candle, true)? {
+            return Ok(());
+        }
+        let previous_close = self.displayed_last_candle_close();
 
         let mut visible_range_changed = false;
         match self
@@ -261,9 +394,9 @@ impl<R: Renderer> ChartEngine<R> {
                 candle.ohlc.time.total_cmp(&last.time)
             }) {
             Ordering::Less => {
-                return Err(ChartError::InvalidData(
-                    "candle update time must be >= latest candle time".to_owned(),
-                ));
+                return Err(ChartError::NonMonotonicTime {
+                    index: self.core.model.candles.len(),
+                });
             }
             Ordering::Equal => {
                 if let Some(last) = self.core.model.candles.last_mut() {
@@ -301,6 +434,7 @@ impl<R: Renderer> ChartEngine<R> {
                 "skipping transformed-base refresh after update_styled_candle"
             );
         }
+        self.start_last_candle_animation(previous_close);
         self.emit_candle_data_updated(visible_range_changed);
         Ok(())
     }
@@ -368,6 +502,7 @@ impl<R: Renderer> ChartEngine<R> {
     }
 
     fn emit_point_data_updated(&mut self, visible_range_changed: bool) {
+        self.restart_crosshair_snap_marker_pulse();
         self.emit_plugin_event(PluginEvent::DataUpdated {
             points_len: self.core.model.points.len(),
         });
@@ -377,6 +512,7 @@ impl<R: Renderer> ChartEngine<R> {
     }
 
     fn emit_candle_data_updated(&mut self, visible_range_changed: bool) {
+        self.restart_crosshair_snap_marker_pulse();
         self.emit_plugin_event(PluginEvent::CandlesUpdated {
             candles_len: self.core.model.candles.len(),
         });
@@ -386,7 +522,9 @@ impl<R: Renderer> ChartEngine<R> {
     }
 }
 
-fn canonicalize_points(mut points: Vec<crate::core::DataPoint>) -> Vec<crate::core::DataPoint> {
+pub(super) fn canonicalize_points(
+    mut points: Vec<crate::core::DataPoint>,
+) -> Vec<crate::core::DataPoint> {
     let original_len = points.len();
     points.retain(|point| point.x.is_finite() && point.y.is_finite());
     points.sort_by(|a, b| a.x.total_cmp(&b.x));
@@ -416,7 +554,9 @@ fn canonicalize_points(mut points: Vec<crate::core::DataPoint>) -> Vec<crate::co
     deduped
 }
 
-fn canonicalize_candles(mut candles: Vec<crate::core::OhlcBar>) -> Vec<crate::core::OhlcBar> {
+pub(super) fn canonicalize_candles(
+    mut candles: Vec<crate::core::OhlcBar>,
+) -> Vec<crate::core::OhlcBar> {
     let original_len = candles.len();
     candles.retain(is_valid_candle);
     candles.sort_by(|a, b| a.time.total_cmp(&b.time));
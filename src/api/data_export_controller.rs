@@ -0,0 +1,107 @@
+use std::fmt::Write as _;
+
+use serde::Serialize;
+
+use crate::core::{
+    DataPoint, OhlcBar, candles_in_sorted_time_window, points_in_sorted_time_window,
+};
+use crate::error::{ChartError, ChartResult};
+use crate::render::Renderer;
+
+use super::{ChartEngine, SeriesRef};
+
+/// Text format produced by `ChartEngine::export_visible`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DataExportFormat {
+    /// Header row plus one comma-separated row per sample.
+    Csv,
+    /// Array of objects keyed like the CSV header.
+    Json,
+}
+
+#[derive(Serialize)]
+struct PointRow {
+    time: f64,
+    value: f64,
+}
+
+impl<R: Renderer> ChartEngine<R> {
+    /// Serializes the samples of `series` inside the visible time window.
+    ///
+    /// Candles export `time,open,high,low,close`; points and compare series
+    /// (raw, not rebased) export `time,value`. Times stay in the engine's
+    /// logical unit. Custom series have no tabular rows and unknown compare
+    /// ids are rejected.
+    pub fn export_visible(
+        &self,
+        series: SeriesRef<'_>,
+        format: DataExportFormat,
+    ) -> ChartResult<String> {
+        let (start, end) = self.core.model.time_scale.visible_range();
+        match series {
+            SeriesRef::Points => export_points(
+                points_in_sorted_time_window(&self.core.model.points, start, end),
+                format,
+            ),
+            SeriesRef::Candles => export_candles(
+                candles_in_sorted_time_window(&self.core.model.candles, start, end),
+                format,
+            ),
+            SeriesRef::Compare(id) => {
+                let compare = self.find_compare_series(id).ok_or_else(|| {
+                    ChartError::InvalidData(format!("unknown compare series `{id}`"))
+                })?;
+                export_points(
+                    points_in_sorted_time_window(&compare.points, start, end),
+                    format,
+                )
+            }
+            SeriesRef::Custom(id) => Err(ChartError::InvalidData(format!(
+                "custom series `{id}` cannot be exported"
+            ))),
+        }
+    }
+}
+
+fn export_points(points: &[DataPoint], format: DataExportFormat) -> ChartResult<String> {
+    match format {
+        DataExportFormat::Csv => {
+            let mut csv = String::from("time,value\n");
+            for point in points {
+                let _ = writeln!(csv, "{},{}", point.x, point.y);
+            }
+            Ok(csv)
+        }
+        DataExportFormat::Json => to_json(
+            &points
+                .iter()
+                .map(|point| PointRow {
+                    time: point.x,
+                    value: point.y,
+                })
+                .collect::<Vec<_>>(),
+        ),
+    }
+}
+
+fn export_candles(candles: &[OhlcBar], format: DataExportFormat) -> ChartResult<String> {
+    match format {
+        DataExportFormat::Csv => {
+            let mut csv = String::from("time,open,high,low,close\n");
+            for bar in candles {
+                let _ = writeln!(
+                    csv,
+                    "{},{},{},{},{}",
+                    bar.time, bar.open, bar.high, bar.low, bar.close
+                );
+            }
+            Ok(csv)
+        }
+        DataExportFormat::Json => to_json(candles),
+    }
+}
+
+fn to_json<T: Serialize + ?Sized>(rows: &T) -> ChartResult<String> {
+    serde_json::to_string(rows)
+        .map_err(|e| ChartError::InvalidData(format!("failed to serialize visible data: {e}")))
+}
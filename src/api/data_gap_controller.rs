@@ -0,0 +1,195 @@
+use serde::{Deserialize, Serialize};
+
+use crate::core::PaneId;
+use crate::error::ChartResult;
+use crate::render::{
+    CanvasLayerKind, ClipRect, LayeredRenderFrame, RectPrimitive, RenderFrame, Renderer,
+};
+
+use super::validation::validate_data_gap_behavior;
+use super::{ChartEngine, DataGapBehavior, TimeAxisLabelConfig, TimeAxisLabelPolicy};
+
+const SECONDS_PER_DAY: f64 = 86_400.0;
+/// Missing-bar slots checked one by one against the session and weekends;
+/// longer gaps count every slot as missing.
+const MAX_GAP_SCAN_SLOTS: usize = 100_000;
+
+/// Span between two consecutive bars where the feed dropped data.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct DataGap {
+    /// Time of the last bar before the gap.
+    pub start: f64,
+    /// Time of the first bar after the gap.
+    pub end: f64,
+    /// Expected bars missing in between, excluding closed-market slots.
+    pub missing_bars: usize,
+}
+
+/// Gaps detected for one behavior and time-axis config; cleared on data
+/// updates.
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct DataGapCache {
+    behavior: DataGapBehavior,
+    time_axis_label_config: TimeAxisLabelConfig,
+    gaps: Vec<DataGap>,
+}
+
+impl<R: Renderer> ChartEngine<R> {
+    #[must_use]
+    pub fn data_gap_behavior(&self) -> DataGapBehavior {
+        self.core.behavior.data_gap_behavior
+    }
+
+    pub fn set_data_gap_behavior(&mut self, behavior: DataGapBehavior) -> ChartResult<()> {
+        validate_data_gap_behavior(behavior)?;
+        if self.core.behavior.data_gap_behavior != behavior {
+            self.core.behavior.data_gap_behavior = behavior;
+            self.invalidate_full();
+        }
+        Ok(())
+    }
+
+    /// Missing-bar gaps in the candle series (or the point series without
+    /// candles), in time order.
+    ///
+    /// Spacing that only covers time outside the time-axis session (and
+    /// weekends, with `skip_weekends`) is treated as a market closure and
+    /// not reported.
+    #[must_use]
+    pub fn detected_gaps(&self) -> Vec<DataGap> {
+        let behavior = self.core.behavior.data_gap_behavior;
+        let time_axis_label_config = self.core.behavior.time_axis_label_config;
+        if let Some(cache) = self.core.runtime.data_gap_cache.borrow().as_ref()
+            && cache.behavior == behavior
+            && cache.time_axis_label_config == time_axis_label_config
+        {
+            return cache.gaps.clone();
+        }
+        let times: Vec<f64> = if self.core.model.candles.is_empty() {
+            self.core.model.points.iter().map(|point| point.x).collect()
+        } else {
+            self.core.model.candles.iter().map(|bar| bar.time).collect()
+        };
+        let gaps = detect_data_gaps(&times, behavior, time_axis_label_config);
+        *self.core.runtime.data_gap_cache.borrow_mut() = Some(DataGapCache {
+            behavior,
+            time_axis_label_config,
+            gaps: gaps.clone(),
+        });
+        gaps
+    }
+
+    pub(super) fn clear_data_gap_cache(&mut self) {
+        self.core.runtime.data_gap_cache.get_mut().take();
+    }
+
+    /// Draws gap markers along the top edge of the time-axis panel in the
+    /// main pane's axis layer.
+    pub(super) fn append_data_gap_primitives(
+        &self,
+        frame: &mut RenderFrame,
+        layered: &mut LayeredRenderFrame,
+        main_pane_id: PaneId,
+        plot_right: f64,
+        time_axis_clip: ClipRect,
+    ) -> ChartResult<()> {
+        let behavior = self.core.behavior.data_gap_behavior;
+        if !behavior.show_markers {
+            return Ok(());
+        }
+        let (visible_start, visible_end) = self.core.model.time_scale.visible_range();
+        for gap in self.detected_gaps() {
+            if gap.end < visible_start.min(visible_end)
+                || gap.start > visible_start.max(visible_end)
+            {
+                continue;
+            }
+            let start_x = self.map_x_to_pixel(gap.start)?.clamp(0.0, plot_right);
+            let end_x = self.map_x_to_pixel(gap.end)?.clamp(0.0, plot_right);
+            let (left, right) = (start_x.min(end_x), start_x.max(end_x));
+            if right <= left {
+                continue;
+            }
+            let marker = RectPrimitive::new(
+                left,
+                time_axis_clip.y,
+                right - left,
+                behavior.marker_height_px,
+                behavior.marker_color,
+            )
+            .with_clip_rect(time_axis_clip);
+            frame.rects.push(marker);
+            layered.push_rect(main_pane_id, CanvasLayerKind::Axis, marker);
+        }
+        Ok(())
+    }
+}
+
+/// Gaps between consecutive sorted `times`.
+fn detect_data_gaps(
+    times: &[f64],
+    behavior: DataGapBehavior,
+    config: TimeAxisLabelConfig,
+) -> Vec<DataGap> {
+    let Some(interval) = behavior.expected_interval.or_else(|| median_spacing(times)) else {
+        return Vec::new();
+    };
+    // Logical time carries no calendar, so every slot counts as open.
+    let calendar = !matches!(config.policy, TimeAxisLabelPolicy::LogicalDecimal { .. })
+        && (config.session.is_some() || behavior.skip_weekends);
+    times
+        .windows(2)
+        .filter_map(|pair| {
+            let (start, end) = (pair[0], pair[1]);
+            if end - start <= interval * behavior.tolerance {
+                return None;
+            }
+            let slots = ((end - start) / interval).round() as usize;
+            let candidates = slots.saturating_sub(1);
+            let missing_bars = if calendar && candidates <= MAX_GAP_SCAN_SLOTS {
+                (1..slots)
+                    .filter(|&slot| slot_is_open(start + slot as f64 * interval, behavior, config))
+                    .count()
+            } else {
+                candidates
+            };
+            (missing_bars > 0).then_some(DataGap {
+                start,
+                end,
+                missing_bars,
+            })
+        })
+        .collect()
+}
+
+fn median_spacing(times: &[f64]) -> Option<f64> {
+    let mut spacings: Vec<f64> = times
+        .windows(2)
+        .map(|pair| pair[1] - pair[0])
+        .filter(|spacing| *spacing > 0.0)
+        .collect();
+    if spacings.is_empty() {
+        return None;
+    }
+    let middle = spacings.len() / 2;
+    let (_, median, _) = spacings.select_nth_unstable_by(middle, f64::total_cmp);
+    Some(*median)
+}
+
+/// Whether a bar is expected at `time`: inside the session and, with
+/// `skip_weekends`, on a weekday (both in the time axis' timezone).
+fn slot_is_open(time: f64, behavior: DataGapBehavior, config: TimeAxisLabelConfig) -> bool {
+    let local =
+        config.time_unit.to_seconds(time) + f64::from(config.timezone.offset_minutes()) * 60.0;
+    if behavior.skip_weekends {
+        // 1970-01-01 was a Thursday; 0 = Sunday, 6 = Saturday.
+        let weekday = ((local / SECONDS_PER_DAY).floor() + 4.0).rem_euclid(7.0);
+        if weekday == 0.0 || weekday == 6.0 {
+            return false;
+        }
+    }
+    config.session.is_none_or(|session| {
+        let minute_of_day = (local.rem_euclid(SECONDS_PER_DAY) / 60.0).floor() as u16;
+        session.contains_local_minute(minute_of_day)
+    })
+}
@@ -0,0 +1,234 @@
+use serde::{Deserialize, Serialize};
+
+use crate::core::{DataPoint, OhlcBar, PaneId};
+use crate::error::ChartResult;
+use crate::render::{
+    CanvasLayerKind, ClipRect, LayeredRenderFrame, RectPrimitive, RenderFrame, Renderer,
+};
+
+use super::data_window::samples_in_time_window;
+use super::{ChartEngine, PluginEvent, SeriesPixelSpace, SeriesRef, ZoomSelection};
+
+/// Series a box data selection collects samples from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum DataSelectionSeries {
+    Points,
+    #[default]
+    Candles,
+}
+
+impl DataSelectionSeries {
+    fn series_ref(self) -> SeriesRef<'static> {
+        match self {
+            Self::Points => SeriesRef::Points,
+            Self::Candles => SeriesRef::Candles,
+        }
+    }
+}
+
+/// Samples inside a released box selection, captured at release time.
+///
+/// Points are inside when their value lies in `price_range`; candles when
+/// their low/high range overlaps it. Only the selected series' list is
+/// filled.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DataSelection {
+    pub series: DataSelectionSeries,
+    pub time_range: (f64, f64),
+    pub price_range: (f64, f64),
+    pub points: Vec<DataPoint>,
+    pub candles: Vec<OhlcBar>,
+}
+
+impl DataSelection {
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.points.len() + self.candles.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Box being dragged by `begin_data_selection`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(super) struct DataSelectionDrag {
+    series: DataSelectionSeries,
+    rect: ZoomSelection,
+}
+
+impl<R: Renderer> ChartEngine<R> {
+    /// Selection kept from the last released box, if any.
+    #[must_use]
+    pub fn current_selection(&self) -> Option<&DataSelection> {
+        self.core.runtime.data_selection.as_ref()
+    }
+
+    /// Box of the data selection being dragged, in content pixels.
+    #[must_use]
+    pub fn data_selection_box(&self) -> Option<ZoomSelection> {
+        self.core.runtime.data_selection_drag.map(|drag| drag.rect)
+    }
+
+    /// Starts a box selection over `series` at content position `(x, y)`;
+    /// the current selection stays until the box is released.
+    pub fn begin_data_selection(&mut self, series: DataSelectionSeries, x: f64, y: f64) {
+        let (x, y) = self.clamp_to_plot(x, y);
+        self.core.runtime.data_selection_drag = Some(DataSelectionDrag {
+            series,
+            rect: ZoomSelection {
+                start_x: x,
+                start_y: y,
+                end_x: x,
+                end_y: y,
+            },
+        });
+        self.invalidate_cursor();
+    }
+
+    /// Moves the free corner of the box being dragged to `(x, y)`.
+    pub fn update_data_selection(&mut self, x: f64, y: f64) {
+        let (x, y) = self.clamp_to_plot(x, y);
+        let Some(drag) = self.core.runtime.data_selection_drag.as_mut() else {
+            return;
+        };
+        drag.rect.end_x = x;
+        drag.rect.end_y = y;
+        self.invalidate_cursor();
+    }
+
+    /// Releases the box and replaces the current selection with the samples
+    /// inside it, emitting `PluginEvent::SelectionChanged`.
+    ///
+    /// A box below `ZoomSelectionBehavior::min_size_px` in either direction
+    /// acts like a click and clears the selection.
+    pub fn end_data_selection(&mut self) -> ChartResult<Option<&DataSelection>> {
+        let Some(drag) = self.core.runtime.data_selection_drag.take() else {
+            return Ok(self.core.runtime.data_selection.as_ref());
+        };
+        self.invalidate_cursor();
+        let (left, top, width, height) = drag.rect.rect();
+        let min_size = self
+            .core
+            .behavior
+            .zoom_selection_behavior
+            .min_size_px
+            .max(f64::EPSILON);
+        if width < min_size || height < min_size {
+            self.clear_selection();
+            return Ok(None);
+        }
+
+        let series = drag.series.series_ref();
+        let left_time = self.map_pixel_to_x(left)?;
+        let right_time = self.map_pixel_to_x(left + width)?;
+        let top_price = self.series_pixel_to_price(series, top, SeriesPixelSpace::Content)?;
+        let bottom_price =
+            self.series_pixel_to_price(series, top + height, SeriesPixelSpace::Content)?;
+        let (start, end) = (left_time.min(right_time), left_time.max(right_time));
+        let (low, high) = (top_price.min(bottom_price), top_price.max(bottom_price));
+
+        let mut selection = DataSelection {
+            series: drag.series,
+            time_range: (start, end),
+            price_range: (low, high),
+            points: Vec::new(),
+            candles: Vec::new(),
+        };
+        match drag.series {
+            DataSelectionSeries::Points => {
+                selection.points =
+                    samples_in_time_window(&self.core.model.points, start, end, |point| point.x)
+                        .iter()
+                        .filter(|point| (low..=high).contains(&point.y))
+                        .copied()
+                        .collect();
+            }
+            DataSelectionSeries::Candles => {
+                selection.candles =
+                    samples_in_time_window(&self.core.model.candles, start, end, |bar| bar.time)
+                        .iter()
+                        .filter(|bar| bar.low <= high && bar.high >= low)
+                        .copied()
+                        .collect();
+            }
+        }
+        let event = PluginEvent::SelectionChanged {
+            samples: selection.len(),
+            time_range: Some(selection.time_range),
+            price_range: Some(selection.price_range),
+        };
+        self.core.runtime.data_selection = Some(selection);
+        self.emit_plugin_event(event);
+        Ok(self.core.runtime.data_selection.as_ref())
+    }
+
+    /// Drops the box being dragged, keeping the current selection.
+    pub fn cancel_data_selection(&mut self) {
+        if self.core.runtime.data_selection_drag.take().is_some() {
+            self.invalidate_cursor();
+        }
+    }
+
+    /// Clears the current selection, emitting `PluginEvent::SelectionChanged`
+    /// when there was one.
+    pub fn clear_selection(&mut self) {
+        if self.core.runtime.data_selection.take().is_some() {
+            self.emit_plugin_event(PluginEvent::SelectionChanged {
+                samples: 0,
+                time_range: None,
+                price_range: None,
+            });
+        }
+    }
+
+    /// Draws the box being dragged, or else the current selection projected
+    /// into the current view, with the `ZoomSelectionBehavior` colors.
+    pub(super) fn append_data_selection_primitives(
+        &self,
+        frame: &mut RenderFrame,
+        layered: &mut LayeredRenderFrame,
+        main_pane_id: PaneId,
+        plot_clip: ClipRect,
+    ) {
+        let rect = match (
+            self.core.runtime.data_selection_drag,
+            &self.core.runtime.data_selection,
+        ) {
+            (Some(drag), _) => Some(drag.rect.rect()),
+            (None, Some(selection)) => self.project_data_selection(selection),
+            (None, None) => None,
+        };
+        let Some((left, top, width, height)) = rect else {
+            return;
+        };
+        if width <= 0.0 || height <= 0.0 {
+            return;
+        }
+        let behavior = self.core.behavior.zoom_selection_behavior;
+        let mut rect = RectPrimitive::new(left, top, width, height, behavior.fill_color)
+            .with_clip_rect(plot_clip);
+        if behavior.border_width_px > 0.0 {
+            rect = rect.with_border(behavior.border_width_px, behavior.border_color);
+        }
+        frame.rects.push(rect);
+        layered.push_rect(main_pane_id, CanvasLayerKind::Crosshair, rect);
+    }
+
+    fn project_data_selection(&self, selection: &DataSelection) -> Option<(f64, f64, f64, f64)> {
+        let series = selection.series.series_ref();
+        let (start, end) = selection.time_range;
+        let (low, high) = selection.price_range;
+        let x0 = self.map_x_to_pixel(start).ok()?;
+        let x1 = self.map_x_to_pixel(end).ok()?;
+        let y0 = self
+            .series_price_to_pixel(series, high, SeriesPixelSpace::Content)
+            .ok()?;
+        let y1 = self
+            .series_price_to_pixel(series, low, SeriesPixelSpace::Content)
+            .ok()?;
+        Some((x0.min(x1), y0.min(y1), (x1 - x0).abs(), (y1 - y0).abs()))
+    }
+}
@@ -30,3 +30,44 @@ pub(super) fn markers_in_time_window(
         .cloned()
         .collect()
 }
+
+/// Percent change from `from` to `to`; `None` for a zero or non-finite base.
+pub(crate) fn percent_change(from: f64, to: f64) -> Option<f64> {
+    let change = (to - from) / from * 100.0;
+    (from != 0.0 && change.is_finite()).then_some(change)
+}
+
+/// Nearest sample by time in a time-sorted series.
+pub(crate) fn nearest_by_time<T>(
+    samples: &[T],
+    time: f64,
+    time_of: impl Fn(&T) -> f64,
+) -> Option<&T> {
+    let index = samples.partition_point(|sample| time_of(sample) < time);
+    let after = samples.get(index);
+    let before = index.checked_sub(1).and_then(|index| samples.get(index));
+    match (before, after) {
+        (Some(before), Some(after)) => {
+            if time - time_of(before) <= time_of(after) - time {
+                Some(before)
+            } else {
+                Some(after)
+            }
+        }
+        (Some(sample), None) | (None, Some(sample)) => Some(sample),
+        (None, None) => None,
+    }
+}
+
+/// Samples of a time-sorted series with time inside `[start, end]`.
+pub(super) fn samples_in_time_window<T>(
+    samples: &[T],
+    start: f64,
+    end: f64,
+    time_of: impl Fn(&T) -> f64,
+) -> &[T] {
+    let (min_t, max_t) = (start.min(end), start.max(end));
+    let first = samples.partition_point(|sample| time_of(sample) < min_t);
+    let last = samples.partition_point(|sample| time_of(sample) <= max_t);
+    &samples[first..last.max(first)]
+}
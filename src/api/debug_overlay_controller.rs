@@ -0,0 +1,222 @@
+use crate::core::PaneId;
+use crate::error::ChartResult;
+use crate::interaction::InteractionMode;
+use crate::render::{
+    CanvasLayerKind, ClipRect, LayeredRenderFrame, RectPrimitive, RenderFrame, Renderer,
+    TextHAlign, TextPrimitive,
+};
+
+use super::validation::validate_debug_overlay_behavior;
+use super::{ChartEngine, DebugOverlayBehavior, DebugOverlayCorner, FramePrimitiveStats};
+
+/// Completed renders the fps estimate averages over.
+const DEBUG_OVERLAY_FPS_WINDOW: usize = 30;
+
+/// Diagnostics shown by the debug overlay.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DebugOverlayStats {
+    /// Renders per second over the recent render window; `None` until two
+    /// renders completed.
+    pub fps: Option<f64>,
+    pub visible_points: usize,
+    pub total_points: usize,
+    pub visible_candles: usize,
+    pub total_candles: usize,
+    /// Primitive counts of the last built frame.
+    pub last_frame: Option<FramePrimitiveStats>,
+    /// Hit ratio (`0..=1`) of the time-axis label cache; `None` before the
+    /// first lookup.
+    pub time_label_cache_hit_rate: Option<f64>,
+    pub price_label_cache_hit_rate: Option<f64>,
+    pub interaction_mode: InteractionMode,
+}
+
+impl DebugOverlayStats {
+    /// Overlay text, one entry per row.
+    #[must_use]
+    pub fn lines(&self) -> Vec<String> {
+        let percent = |rate: Option<f64>| {
+            rate.map_or_else(|| "--".to_owned(), |rate| format!("{:.0}%", rate * 100.0))
+        };
+        let mut lines = vec![
+            match self.fps {
+                Some(fps) => format!("fps {fps:.1}"),
+                None => "fps --".to_owned(),
+            },
+            format!("points {}/{}", self.visible_points, self.total_points),
+            format!("candles {}/{}", self.visible_candles, self.total_candles),
+        ];
+        if let Some(stats) = self.last_frame {
+            lines.push(format!(
+                "prims L{} R{} P{} T{}",
+                stats.lines, stats.rects, stats.paths, stats.texts
+            ));
+        }
+        lines.push(format!(
+            "cache time {} price {}",
+            percent(self.time_label_cache_hit_rate),
+            percent(self.price_label_cache_hit_rate)
+        ));
+        lines.push(
+            match self.interaction_mode {
+                InteractionMode::Idle => "mode idle",
+                InteractionMode::Panning => "mode panning",
+            }
+            .to_owned(),
+        );
+        lines
+    }
+}
+
+fn hit_rate(hits: u64, misses: u64) -> Option<f64> {
+    let total = hits.saturating_add(misses);
+    (total > 0).then(|| hits as f64 / total as f64)
+}
+
+impl<R: Renderer> ChartEngine<R> {
+    #[must_use]
+    pub fn debug_overlay_behavior(&self) -> DebugOverlayBehavior {
+        self.core.behavior.debug_overlay_behavior
+    }
+
+    pub fn set_debug_overlay_behavior(
+        &mut self,
+        behavior: DebugOverlayBehavior,
+    ) -> ChartResult<()> {
+        validate_debug_overlay_behavior(behavior)?;
+        if self.core.behavior.debug_overlay_behavior != behavior {
+            self.core.behavior.debug_overlay_behavior = behavior;
+            self.invalidate_full();
+        }
+        Ok(())
+    }
+
+    /// Runtime toggle keeping the rest of the overlay behavior.
+    pub fn set_debug_overlay_enabled(&mut self, enabled: bool) {
+        if self.core.behavior.debug_overlay_behavior.enabled != enabled {
+            self.core.behavior.debug_overlay_behavior.enabled = enabled;
+            self.invalidate_full();
+        }
+    }
+
+    /// Current diagnostics, available whether or not the overlay is drawn.
+    #[must_use]
+    pub fn debug_overlay_stats(&self) -> DebugOverlayStats {
+        let (start, end) = self.core.model.time_scale.visible_range();
+        let (min_t, max_t) = (start.min(end), start.max(end));
+        let points = &self.core.model.points;
+        let candles = &self.core.model.candles;
+        let visible_points = points.partition_point(|point| point.x <= max_t)
+            - points.partition_point(|point| point.x < min_t);
+        let visible_candles = candles.partition_point(|bar| bar.time <= max_t)
+            - candles.partition_point(|bar| bar.time < min_t);
+
+        let renders = &self.core.runtime.render_instants;
+        let fps = match (renders.front(), renders.back()) {
+            (Some(first), Some(last)) if renders.len() > 1 => {
+                let elapsed = last.saturating_sub(*first).as_secs_f64();
+                (elapsed > 0.0).then(|| (renders.len() - 1) as f64 / elapsed)
+            }
+            _ => None,
+        };
+
+        let time_cache = self.time_label_cache_stats();
+        let price_cache = self.price_label_cache_stats();
+        DebugOverlayStats {
+            fps,
+            visible_points,
+            total_points: points.len(),
+            visible_candles,
+            total_candles: candles.len(),
+            last_frame: self.last_frame_stats(),
+            time_label_cache_hit_rate: hit_rate(time_cache.hits, time_cache.misses),
+            price_label_cache_hit_rate: hit_rate(price_cache.hits, price_cache.misses),
+            interaction_mode: self.core.model.interaction.mode(),
+        }
+    }
+
+    /// Stamps a completed render with the host frame clock.
+    ///
+    /// Only while the overlay is enabled, and never from the system clock
+    /// (unavailable on `wasm32-unknown-unknown`).
+    pub(super) fn record_render_instant(&mut self) {
+        if !self.core.behavior.debug_overlay_behavior.enabled {
+            return;
+        }
+        let Some(at) = self.core.runtime.frame_clock_time else {
+            return;
+        };
+        let renders = &mut self.core.runtime.render_instants;
+        // A clock reset (time going backwards) restarts the window.
+        if renders.back().is_some_and(|last| at < *last) {
+            renders.clear();
+        }
+        if renders.len() == DEBUG_OVERLAY_FPS_WINDOW {
+            renders.pop_front();
+        }
+        renders.push_back(at);
+    }
+
+    /// Draws the diagnostics panel into the main pane's crosshair layer so
+    /// cursor-only repaints (e.g. pan start/end) refresh it.
+    pub(super) fn append_debug_overlay_primitives(
+        &self,
+        frame: &mut RenderFrame,
+        layered: &mut LayeredRenderFrame,
+        main_pane_id: PaneId,
+        plot_right: f64,
+        plot_bottom: f64,
+        plot_clip: ClipRect,
+    ) {
+        let behavior = self.core.behavior.debug_overlay_behavior;
+        if !behavior.enabled {
+            return;
+        }
+        let lines = self.debug_overlay_stats().lines();
+        let font_size = behavior.font_size_px;
+        let padding = behavior.padding_px;
+        let line_height = font_size * 1.3;
+        let width = lines
+            .iter()
+            .map(|line| {
+                self.core
+                    .presentation
+                    .render_style
+                    .label_text_metrics
+                    .text_width_px(line, font_size)
+            })
+            .fold(0.0, f64::max)
+            + 2.0 * padding;
+        let height = line_height * lines.len() as f64 + 2.0 * padding;
+        let x = match behavior.corner {
+            DebugOverlayCorner::TopLeft | DebugOverlayCorner::BottomLeft => padding,
+            DebugOverlayCorner::TopRight | DebugOverlayCorner::BottomRight => {
+                plot_right - padding - width
+            }
+        };
+        let y = match behavior.corner {
+            DebugOverlayCorner::TopLeft | DebugOverlayCorner::TopRight => padding,
+            DebugOverlayCorner::BottomLeft | DebugOverlayCorner::BottomRight => {
+                plot_bottom - padding - height
+            }
+        };
+
+        let panel = RectPrimitive::new(x, y, width, height, behavior.background_color)
+            .with_clip_rect(plot_clip);
+        frame.rects.push(panel);
+        layered.push_rect(main_pane_id, CanvasLayerKind::Crosshair, panel);
+        for (row, line) in lines.iter().enumerate() {
+            let text = TextPrimitive::new(
+                line.as_str(),
+                x + padding,
+                y + padding + line_height * row as f64,
+                font_size,
+                behavior.text_color,
+                TextHAlign::Left,
+            )
+            .with_clip_rect(plot_clip);
+            frame.texts.push(text.clone());
+            layered.push_text(main_pane_id, CanvasLayerKind::Crosshair, text);
+        }
+    }
+}
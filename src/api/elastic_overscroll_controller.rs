@@ -0,0 +1,108 @@
+use crate::error::ChartResult;
+use crate::interaction::InteractionMode;
+use crate::render::Renderer;
+
+use super::validation::validate_elastic_overscroll_behavior;
+use super::{ChartEngine, ElasticOverscrollBehavior};
+
+/// iOS-style rubber-band resistance: the first pixels past an edge follow the
+/// pointer at this rate and later ones ever more slowly.
+const RUBBER_BAND_COEFFICIENT: f64 = 0.55;
+
+/// Drag displacement past a fixed time-scale edge, in time units.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub(super) struct ElasticOverscroll {
+    /// Unresisted pointer travel past the edge.
+    pull_time: f64,
+    /// Resisted shift of the visible range off the clamped range.
+    offset_time: f64,
+}
+
+impl<R: Renderer> ChartEngine<R> {
+    #[must_use]
+    pub fn elastic_overscroll_behavior(&self) -> ElasticOverscrollBehavior {
+        self.core.behavior.elastic_overscroll_behavior
+    }
+
+    pub fn set_elastic_overscroll_behavior(
+        &mut self,
+        behavior: ElasticOverscrollBehavior,
+    ) -> ChartResult<()> {
+        validate_elastic_overscroll_behavior(behavior)?;
+        self.core.behavior.elastic_overscroll_behavior = behavior;
+        if !behavior.enabled {
+            self.release_elastic_overscroll();
+        }
+        Ok(())
+    }
+
+    /// Whether a drag currently holds the visible range past a fixed edge.
+    #[must_use]
+    pub fn elastic_overscroll_active(&self) -> bool {
+        self.core.runtime.elastic_overscroll.offset_time != 0.0
+    }
+
+    /// Edge clamp for drag pans: with elastic overscroll enabled during a
+    /// drag, the range may trail past a fixed edge with rubber-band
+    /// resistance instead of stopping at it.
+    pub(super) fn apply_pan_edge_behavior(&mut self) -> ChartResult<()> {
+        let behavior = self.core.behavior.elastic_overscroll_behavior;
+        if !behavior.enabled || self.interaction_mode() != InteractionMode::Panning {
+            self.core.runtime.elastic_overscroll = ElasticOverscroll::default();
+            let _ = self.apply_time_scale_edge_behavior()?;
+            return Ok(());
+        }
+
+        let overscroll = self.core.runtime.elastic_overscroll;
+        let shift = overscroll.pull_time - overscroll.offset_time;
+        let (start, end) = self.core.model.time_scale.visible_range();
+        let pulled = (start + shift, end + shift);
+        self.core
+            .model
+            .time_scale
+            .set_visible_range(pulled.0, pulled.1)?;
+        let _ = self.apply_time_scale_edge_behavior()?;
+        let (clamped_start, clamped_end) = self.core.model.time_scale.visible_range();
+        let pull_time = (pulled.0 - clamped_start + pulled.1 - clamped_end) / 2.0;
+
+        let viewport_width = f64::from(self.core.model.viewport.width);
+        let time_per_px = (clamped_end - clamped_start) / viewport_width;
+        let offset_time = if pull_time == 0.0 || !time_per_px.is_finite() || time_per_px <= 0.0 {
+            0.0
+        } else {
+            let max_px = behavior.max_overscroll_px;
+            let pull_px = pull_time.abs() / time_per_px;
+            let offset_px =
+                (1.0 - 1.0 / (pull_px * RUBBER_BAND_COEFFICIENT / max_px + 1.0)) * max_px;
+            pull_time.signum() * offset_px * time_per_px
+        };
+        self.core.runtime.elastic_overscroll = ElasticOverscroll {
+            pull_time,
+            offset_time,
+        };
+        if offset_time != 0.0 {
+            self.core
+                .model
+                .time_scale
+                .set_visible_range(clamped_start + offset_time, clamped_end + offset_time)?;
+        }
+        Ok(())
+    }
+
+    /// Ends a drag overscroll, springing the range back to the edge over
+    /// `spring_back_seconds` (at once when zero).
+    pub(super) fn release_elastic_overscroll(&mut self) {
+        let overscroll = std::mem::take(&mut self.core.runtime.elastic_overscroll);
+        if overscroll.offset_time == 0.0 {
+            return;
+        }
+        let (start, end) = self.core.model.time_scale.visible_range();
+        let target = (start - overscroll.offset_time, end - overscroll.offset_time);
+        let behavior = self.core.behavior.elastic_overscroll_behavior;
+        if behavior.enabled && behavior.spring_back_seconds > 0.0 {
+            self.start_spring_back_animation(target, behavior.spring_back_seconds);
+        } else {
+            self.set_overscrolled_time_visible_range(target);
+        }
+    }
+}
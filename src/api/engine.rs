@@ -26,17 +26,32 @@ pub struct ChartEngine<R: Renderer> {
 impl<R: Renderer> ChartEngine<R> {
     #[must_use]
     pub fn render_style(&self) -> RenderStyle {
-        self.core.presentation.render_style
+        self.core.presentation.render_style.clone()
     }
 
     pub fn set_render_style(&mut self, style: RenderStyle) -> ChartResult<()> {
         if self.core.presentation.render_style == style {
             return Ok(());
         }
-        validate_render_style(style)?;
-        let previous = self.core.presentation.render_style;
-        self.core.presentation.render_style = style;
-        match resolve_render_style_invalidation(previous, style) {
+        validate_render_style(&style)?;
+        let previous = std::mem::replace(&mut self.core.presentation.render_style, style);
+        let next = &self.core.presentation.render_style;
+        if previous.price_label_prefix != next.price_label_prefix
+            || previous.price_label_suffix != next.price_label_suffix
+        {
+            // Cached price labels already carry the old affixes.
+            self.core
+                .presentation
+                .price_label_cache
+                .borrow_mut()
+                .clear();
+            self.core
+                .presentation
+                .crosshair_price_label_cache
+                .borrow_mut()
+                .clear();
+        }
+        match resolve_render_style_invalidation(&previous, &self.core.presentation.render_style) {
             RenderStyleInvalidationDecision::None => {}
             RenderStyleInvalidationDecision::Full => {
                 self.invalidate_full();
@@ -64,6 +79,41 @@ impl<R: Renderer> ChartEngine<R> {
         RenderCoordinator::render_on_cairo_context(self, context)
     }
 
+    /// Writes the current frame to a single-page vector PDF at `path`,
+    /// fitted into `page_size`; see [`crate::render::write_frame_pdf`].
+    ///
+    /// Works with any renderer: the frame is drawn by a dedicated cairo
+    /// PDF surface and the engine's own renderer is left untouched.
+    #[cfg(feature = "pdf-export")]
+    pub fn render_to_pdf(
+        &self,
+        path: impl AsRef<std::path::Path>,
+        page_size: crate::render::PdfPageSize,
+    ) -> ChartResult<()> {
+        let frame = self.build_render_frame()?;
+        let viewport = self.core.model.viewport;
+        crate::render::write_frame_pdf(
+            &frame,
+            f64::from(viewport.width),
+            f64::from(viewport.height),
+            path,
+            page_size,
+        )
+    }
+
+    /// Renderer owned by the engine, for backends exposing their output
+    /// (for example a software renderer's pixel buffer).
+    #[must_use]
+    pub fn renderer(&self) -> &R {
+        &self.renderer
+    }
+
+    /// Mutable access for backend settings such as clear color or pixel
+    /// ratio; frame content still comes from the engine.
+    pub fn renderer_mut(&mut self) -> &mut R {
+        &mut self.renderer
+    }
+
     #[must_use]
     pub fn into_renderer(self) -> R {
         self.renderer
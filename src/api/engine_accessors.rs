@@ -1,7 +1,7 @@
 use indexmap::IndexMap;
 
 use crate::core::{DataPoint, OhlcBar, Viewport};
-use crate::error::{ChartError, ChartResult};
+use crate::error::ChartResult;
 use crate::render::Renderer;
 
 use super::ChartEngine;
@@ -32,19 +32,29 @@ impl<R: Renderer> ChartEngine<R> {
         &self.core.model.candles
     }
 
+    /// Widget viewport, including the layout insets.
     #[must_use]
     pub fn viewport(&self) -> Viewport {
-        self.core.model.viewport
+        self.core
+            .behavior
+            .layout_insets
+            .outer_viewport(self.core.model.viewport)
     }
 
     /// Updates viewport dimensions used by scale mapping and render layout.
+    ///
+    /// `viewport` is the widget size; the chart is laid out in it minus the
+    /// layout insets (see `content_viewport`).
     pub fn set_viewport(&mut self, viewport: Viewport) -> ChartResult<()> {
-        if !viewport.is_valid() {
-            return Err(ChartError::InvalidViewport {
-                width: viewport.width,
-                height: viewport.height,
-            });
-        }
+        let content = self
+            .core
+            .behavior
+            .layout_insets
+            .resolve_content_viewport(viewport)?;
+        self.apply_content_viewport(content)
+    }
+
+    pub(super) fn apply_content_viewport(&mut self, viewport: Viewport) -> ChartResult<()> {
         let previous_width = self.core.model.viewport.width;
         self.core.model.viewport = viewport;
         self.invalidate_full();
@@ -0,0 +1,190 @@
+use std::sync::mpsc;
+
+use tracing::debug;
+
+use crate::core::{DataPoint, OhlcBar};
+use crate::error::{ChartError, ChartResult};
+use crate::render::Renderer;
+
+use super::{ChartEngine, RenderStyle, StagedCandles};
+
+/// Engine mutation that can be produced on any thread.
+#[derive(Debug, Clone, PartialEq)]
+pub enum EngineCommand {
+    SetData(Vec<DataPoint>),
+    SetCandles(Vec<OhlcBar>),
+    AppendPoint(DataPoint),
+    AppendCandle(OhlcBar),
+    /// Realtime point update (`ChartEngine::update_point` semantics).
+    UpdatePoint(DataPoint),
+    /// Realtime candle update (`ChartEngine::update_candle` semantics).
+    UpdateCandle(OhlcBar),
+    SetVisibleRange {
+        start: f64,
+        end: f64,
+    },
+    ResetVisibleRange,
+    /// Stages and immediately commits a batch prepared off the engine thread.
+    CommitStagedCandles(StagedCandles),
+    /// Boxed to keep the command small while it sits in the queue.
+    SetStyle(Box<RenderStyle>),
+}
+
+impl EngineCommand {
+    /// Whether the command changes series data (held while power idle).
+    #[must_use]
+    pub fn is_data_update(&self) -> bool {
+        matches!(
+            self,
+            Self::SetData(_)
+                | Self::SetCandles(_)
+                | Self::AppendPoint(_)
+                | Self::AppendCandle(_)
+                | Self::UpdatePoint(_)
+                | Self::UpdateCandle(_)
+                | Self::CommitStagedCandles(_)
+        )
+    }
+}
+
+/// Thread-safe producer side of an engine command queue.
+///
+/// `ChartEngine` is single-threaded; background threads hold a cloned
+/// `EngineHandle` and the thread owning the engine drains the matching
+/// `EngineCommandQueue`, so no engine borrow ever crosses threads.
+#[derive(Debug, Clone)]
+pub struct EngineHandle {
+    sender: mpsc::Sender<EngineCommand>,
+}
+
+impl EngineHandle {
+    /// Enqueues `command`; fails once the queue has been dropped.
+    pub fn send(&self, command: EngineCommand) -> ChartResult<()> {
+        self.sender
+            .send(command)
+            .map_err(|_| ChartError::InvalidData("engine command queue is closed".to_owned()))
+    }
+
+    pub fn set_data(&self, points: Vec<DataPoint>) -> ChartResult<()> {
+        self.send(EngineCommand::SetData(points))
+    }
+
+    pub fn set_candles(&self, candles: Vec<OhlcBar>) -> ChartResult<()> {
+        self.send(EngineCommand::SetCandles(candles))
+    }
+
+    pub fn append_candle(&self, candle: OhlcBar) -> ChartResult<()> {
+        self.send(EngineCommand::AppendCandle(candle))
+    }
+
+    pub fn update_candle(&self, candle: OhlcBar) -> ChartResult<()> {
+        self.send(EngineCommand::UpdateCandle(candle))
+    }
+
+    /// Hands over candles prepared with `StagedCandles::prepare`.
+    pub fn commit_staged_candles(&self, staged: StagedCandles) -> ChartResult<()> {
+        self.send(EngineCommand::CommitStagedCandles(staged))
+    }
+
+    pub fn set_visible_range(&self, start: f64, end: f64) -> ChartResult<()> {
+        self.send(EngineCommand::SetVisibleRange { start, end })
+    }
+
+    pub fn set_style(&self, style: RenderStyle) -> ChartResult<()> {
+        self.send(EngineCommand::SetStyle(Box::new(style)))
+    }
+}
+
+/// Outcome of one `EngineCommandQueue::apply_pending` call.
+#[derive(Debug, Default)]
+pub struct EngineCommandReport {
+    pub applied: usize,
+    /// Data commands held for the next batch because the engine is idle
+    /// (see `IdlePowerBehavior`).
+    pub deferred: usize,
+    /// Errors from rejected commands, in queue order.
+    pub errors: Vec<ChartError>,
+}
+
+/// Consumer side of an engine command queue; lives on the engine's thread.
+#[derive(Debug)]
+pub struct EngineCommandQueue {
+    receiver: mpsc::Receiver<EngineCommand>,
+}
+
+impl EngineCommandQueue {
+    /// Applies every queued command in order without blocking.
+    ///
+    /// A rejected command does not stop the drain; its error is collected in
+    /// the report and later commands still apply. While the engine is power
+    /// idle, data commands are held for `ChartEngine::power_tick` instead.
+    pub fn apply_pending<R: Renderer>(
+        &mut self,
+        engine: &mut ChartEngine<R>,
+    ) -> EngineCommandReport {
+        let mut report = EngineCommandReport::default();
+        while let Ok(command) = self.receiver.try_recv() {
+            if engine.is_power_idle() && command.is_data_update() {
+                engine.defer_command(command);
+                report.deferred += 1;
+                continue;
+            }
+            match engine.apply_command(command) {
+                Ok(()) => report.applied += 1,
+                Err(err) => report.errors.push(err),
+            }
+        }
+        if report.applied > 0 || report.deferred > 0 || !report.errors.is_empty() {
+            debug!(
+                applied = report.applied,
+                deferred = report.deferred,
+                rejected = report.errors.len(),
+                "applied pending engine commands"
+            );
+        }
+        report
+    }
+}
+
+/// Creates a connected handle/queue pair.
+#[must_use]
+pub fn engine_command_channel() -> (EngineHandle, EngineCommandQueue) {
+    let (sender, receiver) = mpsc::channel();
+    (EngineHandle { sender }, EngineCommandQueue { receiver })
+}
+
+impl<R: Renderer> ChartEngine<R> {
+    /// Applies one command immediately.
+    pub fn apply_command(&mut self, command: EngineCommand) -> ChartResult<()> {
+        if command.is_data_update() {
+            self.note_data_activity();
+        }
+        match command {
+            EngineCommand::SetData(points) => {
+                self.set_data(points);
+                Ok(())
+            }
+            EngineCommand::SetCandles(candles) => {
+                self.set_candles(candles);
+                Ok(())
+            }
+            EngineCommand::AppendPoint(point) => self.append_point(point),
+            EngineCommand::AppendCandle(candle) => self.append_candle(candle),
+            EngineCommand::UpdatePoint(point) => self.update_point(point),
+            EngineCommand::UpdateCandle(candle) => self.update_candle(candle),
+            EngineCommand::SetVisibleRange { start, end } => {
+                self.set_time_visible_range(start, end)
+            }
+            EngineCommand::ResetVisibleRange => {
+                self.reset_time_visible_range();
+                Ok(())
+            }
+            EngineCommand::CommitStagedCandles(staged) => {
+                self.stage_prepared_candles(staged);
+                self.commit_staged();
+                Ok(())
+            }
+            EngineCommand::SetStyle(style) => self.set_render_style(*style),
+        }
+    }
+}
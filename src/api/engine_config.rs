@@ -2,16 +2,22 @@ use serde::{Deserialize, Serialize};
 
 use crate::core::{PriceScaleMode, Viewport};
 use crate::error::{ChartError, ChartResult};
-use crate::interaction::CrosshairMode;
+use crate::interaction::{CrosshairMode, CrosshairSnapTarget};
 
 use super::{
-    CandlestickStyleBehavior, CrosshairAxisLabelBoxStyleBehavior, CrosshairAxisLabelStyleBehavior,
+    AxisInteractionConfig, BarCountdownBehavior, CandlestickStyleBehavior,
+    CrosshairAxisLabelBoxStyleBehavior, CrosshairAxisLabelStyleBehavior,
     CrosshairAxisLabelVisibilityBehavior, CrosshairGuideLineBehavior,
-    CrosshairGuideLineStyleBehavior, InteractionInputBehavior, LastPriceBehavior,
-    LastPriceSourceMode, PriceAxisLabelConfig, PriceScaleMarginBehavior,
-    PriceScaleRealtimeBehavior, PriceScaleTransformedBaseBehavior, TimeAxisLabelConfig,
-    TimeScaleEdgeBehavior, TimeScaleNavigationBehavior, TimeScaleRealtimeAppendBehavior,
-    TimeScaleResizeBehavior, TimeScaleScrollZoomBehavior, TimeScaleZoomLimitBehavior,
+    CrosshairGuideLineStyleBehavior, DataGapBehavior, DebugOverlayBehavior,
+    ElasticOverscrollBehavior, FrameBudget, HighLowMarkerBehavior, IdlePowerBehavior,
+    InteractionInputBehavior, LastPriceBehavior, LastPriceSourceMode, LastValueAnimationBehavior,
+    LayoutInsets, NavigationHistoryBehavior, OhlcInfoLineBehavior, PaneSeparatorBehavior,
+    PriceAxisLabelConfig, PriceScaleMarginBehavior, PriceScaleRealtimeBehavior,
+    PriceScaleTransformedBaseBehavior, RedrawThrottleBehavior, TimeAxisLabelConfig,
+    TimeScaleEdgeBehavior, TimeScaleMarkBehavior, TimeScaleNavigationBehavior,
+    TimeScaleRealtimeAppendBehavior, TimeScaleResizeBehavior, TimeScaleScrollZoomBehavior,
+    TimeScaleZoomLimitBehavior, VisibleRangeChangeBehavior, WheelBehaviorConfig,
+    ZoomSelectionBehavior,
 };
 
 /// Public engine bootstrap configuration.
@@ -27,10 +33,16 @@ pub struct ChartEngineConfig {
     pub price_max: f64,
     #[serde(default = "default_crosshair_mode")]
     pub crosshair_mode: CrosshairMode,
+    #[serde(default)]
+    pub crosshair_snap_target: CrosshairSnapTarget,
+    #[serde(default)]
+    pub crosshair_snap_radius_px: Option<f64>,
     #[serde(default = "default_price_scale_mode")]
     pub price_scale_mode: PriceScaleMode,
     #[serde(default)]
     pub price_scale_inverted: bool,
+    #[serde(default)]
+    pub time_scale_inverted: bool,
     #[serde(default = "default_price_scale_margins")]
     pub price_scale_margins: PriceScaleMarginBehavior,
     #[serde(default = "default_price_scale_transformed_base_behavior")]
@@ -73,6 +85,42 @@ pub struct ChartEngineConfig {
     pub time_axis_label_config: TimeAxisLabelConfig,
     #[serde(default = "default_price_axis_label_config")]
     pub price_axis_label_config: PriceAxisLabelConfig,
+    #[serde(default)]
+    pub frame_budget: Option<FrameBudget>,
+    #[serde(default)]
+    pub layout_insets: LayoutInsets,
+    #[serde(default)]
+    pub pane_separator_behavior: PaneSeparatorBehavior,
+    #[serde(default)]
+    pub ohlc_info_line_behavior: OhlcInfoLineBehavior,
+    #[serde(default)]
+    pub time_scale_mark_behavior: TimeScaleMarkBehavior,
+    #[serde(default)]
+    pub debug_overlay_behavior: DebugOverlayBehavior,
+    #[serde(default)]
+    pub redraw_throttle_behavior: RedrawThrottleBehavior,
+    #[serde(default)]
+    pub idle_power_behavior: IdlePowerBehavior,
+    #[serde(default)]
+    pub data_gap_behavior: DataGapBehavior,
+    #[serde(default)]
+    pub bar_countdown_behavior: BarCountdownBehavior,
+    #[serde(default)]
+    pub high_low_marker_behavior: HighLowMarkerBehavior,
+    #[serde(default)]
+    pub last_value_animation_behavior: LastValueAnimationBehavior,
+    #[serde(default)]
+    pub visible_range_change_behavior: VisibleRangeChangeBehavior,
+    #[serde(default)]
+    pub navigation_history_behavior: NavigationHistoryBehavior,
+    #[serde(default)]
+    pub axis_interaction_config: AxisInteractionConfig,
+    #[serde(default)]
+    pub wheel_behavior_config: WheelBehaviorConfig,
+    #[serde(default)]
+    pub zoom_selection_behavior: ZoomSelectionBehavior,
+    #[serde(default)]
+    pub elastic_overscroll_behavior: ElasticOverscrollBehavior,
 }
 
 impl ChartEngineConfig {
@@ -86,8 +134,11 @@ impl ChartEngineConfig {
             price_min: 0.0,
             price_max: 1.0,
             crosshair_mode: default_crosshair_mode(),
+            crosshair_snap_target: CrosshairSnapTarget::default(),
+            crosshair_snap_radius_px: None,
             price_scale_mode: default_price_scale_mode(),
             price_scale_inverted: false,
+            time_scale_inverted: false,
             price_scale_margins: default_price_scale_margins(),
             price_scale_transformed_base_behavior: default_price_scale_transformed_base_behavior(),
             interaction_input_behavior: default_interaction_input_behavior(),
@@ -110,6 +161,24 @@ impl ChartEngineConfig {
             crosshair_axis_label_box_style_behavior: None,
             time_axis_label_config: default_time_axis_label_config(),
             price_axis_label_config: default_price_axis_label_config(),
+            frame_budget: None,
+            layout_insets: LayoutInsets::default(),
+            pane_separator_behavior: PaneSeparatorBehavior::default(),
+            ohlc_info_line_behavior: OhlcInfoLineBehavior::default(),
+            time_scale_mark_behavior: TimeScaleMarkBehavior::default(),
+            debug_overlay_behavior: DebugOverlayBehavior::default(),
+            redraw_throttle_behavior: RedrawThrottleBehavior::default(),
+            idle_power_behavior: IdlePowerBehavior::default(),
+            data_gap_behavior: DataGapBehavior::default(),
+            bar_countdown_behavior: BarCountdownBehavior::default(),
+            high_low_marker_behavior: HighLowMarkerBehavior::default(),
+            last_value_animation_behavior: LastValueAnimationBehavior::default(),
+            visible_range_change_behavior: VisibleRangeChangeBehavior::default(),
+            navigation_history_behavior: NavigationHistoryBehavior::default(),
+            axis_interaction_config: AxisInteractionConfig::default(),
+            wheel_behavior_config: WheelBehaviorConfig::default(),
+            zoom_selection_behavior: ZoomSelectionBehavior::default(),
+            elastic_overscroll_behavior: ElasticOverscrollBehavior::default(),
         }
     }
 
@@ -128,6 +197,20 @@ impl ChartEngineConfig {
         self
     }
 
+    /// Sets initial magnet-mode snap target.
+    #[must_use]
+    pub fn with_crosshair_snap_target(mut self, target: CrosshairSnapTarget) -> Self {
+        self.crosshair_snap_target = target;
+        self
+    }
+
+    /// Sets initial magnet-mode snap radius in pixels.
+    #[must_use]
+    pub fn with_crosshair_snap_radius_px(mut self, radius_px: Option<f64>) -> Self {
+        self.crosshair_snap_radius_px = radius_px;
+        self
+    }
+
     /// Sets initial price scale mode.
     #[must_use]
     pub fn with_price_scale_mode(mut self, mode: PriceScaleMode) -> Self {
@@ -142,6 +225,14 @@ impl ChartEngineConfig {
         self
     }
 
+    /// Sets initial inverted state of time scale mapping (newest time on the
+    /// left).
+    #[must_use]
+    pub fn with_time_scale_inverted(mut self, inverted: bool) -> Self {
+        self.time_scale_inverted = inverted;
+        self
+    }
+
     /// Sets initial top/bottom price-scale margins.
     #[must_use]
     pub fn with_price_scale_margins(
@@ -329,6 +420,138 @@ impl ChartEngineConfig {
         self
     }
 
+    /// Sets per-frame primitive caps (see `FrameBudget`).
+    #[must_use]
+    pub fn with_frame_budget(mut self, budget: FrameBudget) -> Self {
+        self.frame_budget = Some(budget);
+        self
+    }
+
+    /// Sets outer chart padding; `viewport` stays the full widget size.
+    #[must_use]
+    pub fn with_layout_insets(mut self, insets: LayoutInsets) -> Self {
+        self.layout_insets = insets;
+        self
+    }
+
+    /// Sets initial pane separator drag behavior.
+    #[must_use]
+    pub fn with_pane_separator_behavior(mut self, behavior: PaneSeparatorBehavior) -> Self {
+        self.pane_separator_behavior = behavior;
+        self
+    }
+
+    /// Sets initial OHLC info line behavior.
+    #[must_use]
+    pub fn with_ohlc_info_line_behavior(mut self, behavior: OhlcInfoLineBehavior) -> Self {
+        self.ohlc_info_line_behavior = behavior;
+        self
+    }
+
+    /// Sets initial time-scale mark badge behavior.
+    #[must_use]
+    pub fn with_time_scale_mark_behavior(mut self, behavior: TimeScaleMarkBehavior) -> Self {
+        self.time_scale_mark_behavior = behavior;
+        self
+    }
+
+    /// Sets initial debug overlay behavior.
+    #[must_use]
+    pub fn with_debug_overlay_behavior(mut self, behavior: DebugOverlayBehavior) -> Self {
+        self.debug_overlay_behavior = behavior;
+        self
+    }
+
+    /// Sets initial frame-rate cap for frame-clock driven redraws.
+    #[must_use]
+    pub fn with_redraw_throttle_behavior(mut self, behavior: RedrawThrottleBehavior) -> Self {
+        self.redraw_throttle_behavior = behavior;
+        self
+    }
+
+    /// Sets initial idle power-saving behavior.
+    #[must_use]
+    pub fn with_idle_power_behavior(mut self, behavior: IdlePowerBehavior) -> Self {
+        self.idle_power_behavior = behavior;
+        self
+    }
+
+    /// Sets initial missing-bar detection behavior.
+    #[must_use]
+    pub fn with_data_gap_behavior(mut self, behavior: DataGapBehavior) -> Self {
+        self.data_gap_behavior = behavior;
+        self
+    }
+
+    /// Sets initial bar-close countdown behavior.
+    #[must_use]
+    pub fn with_bar_countdown_behavior(mut self, behavior: BarCountdownBehavior) -> Self {
+        self.bar_countdown_behavior = behavior;
+        self
+    }
+
+    /// Sets initial visible-range high/low marker behavior.
+    #[must_use]
+    pub fn with_high_low_marker_behavior(mut self, behavior: HighLowMarkerBehavior) -> Self {
+        self.high_low_marker_behavior = behavior;
+        self
+    }
+
+    /// Sets initial realtime last-value animation behavior.
+    #[must_use]
+    pub fn with_last_value_animation_behavior(
+        mut self,
+        behavior: LastValueAnimationBehavior,
+    ) -> Self {
+        self.last_value_animation_behavior = behavior;
+        self
+    }
+
+    /// Sets initial visible-range change notification behavior.
+    #[must_use]
+    pub fn with_visible_range_change_behavior(
+        mut self,
+        behavior: VisibleRangeChangeBehavior,
+    ) -> Self {
+        self.visible_range_change_behavior = behavior;
+        self
+    }
+
+    /// Sets initial undo/redo navigation history behavior.
+    #[must_use]
+    pub fn with_navigation_history_behavior(mut self, behavior: NavigationHistoryBehavior) -> Self {
+        self.navigation_history_behavior = behavior;
+        self
+    }
+
+    /// Sets initial per-axis drag/wheel tuning.
+    #[must_use]
+    pub fn with_axis_interaction_config(mut self, config: AxisInteractionConfig) -> Self {
+        self.axis_interaction_config = config;
+        self
+    }
+
+    /// Sets initial wheel zoom/pan mapping for `ChartEngine::handle_wheel`.
+    #[must_use]
+    pub fn with_wheel_behavior_config(mut self, config: WheelBehaviorConfig) -> Self {
+        self.wheel_behavior_config = config;
+        self
+    }
+
+    /// Sets initial rubber-band zoom selection behavior.
+    #[must_use]
+    pub fn with_zoom_selection_behavior(mut self, behavior: ZoomSelectionBehavior) -> Self {
+        self.zoom_selection_behavior = behavior;
+        self
+    }
+
+    /// Sets initial rubber-band overscroll for drag pans past fixed edges.
+    #[must_use]
+    pub fn with_elastic_overscroll_behavior(mut self, behavior: ElasticOverscrollBehavior) -> Self {
+        self.elastic_overscroll_behavior = behavior;
+        self
+    }
+
     /// Serializes config to pretty JSON for debug/config files.
     pub fn to_json_pretty(self) -> ChartResult<String> {
         serde_json::to_string_pretty(&self)
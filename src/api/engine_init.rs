@@ -4,10 +4,14 @@ use crate::interaction::InteractionState;
 use crate::render::Renderer;
 
 use super::{
-    ChartEngine, ChartEngineConfig, ChartModel, ChartModelBootstrap,
-    CrosshairAxisLabelVisibilityBehavior, CrosshairGuideLineBehavior, LastPriceSourceMode,
-    TimeScaleEdgeBehavior, TimeScaleNavigationBehavior, TimeScaleRealtimeAppendBehavior,
+    AxisInteractionConfig, BarCountdownBehavior, ChartEngine, ChartEngineConfig, ChartModel,
+    ChartModelBootstrap, CrosshairAxisLabelVisibilityBehavior, CrosshairGuideLineBehavior,
+    DataGapBehavior, DebugOverlayBehavior, ElasticOverscrollBehavior, HighLowMarkerBehavior,
+    IdlePowerBehavior, LastPriceSourceMode, LastValueAnimationBehavior, NavigationHistoryBehavior,
+    OhlcInfoLineBehavior, PaneSeparatorBehavior, RedrawThrottleBehavior, TimeScaleEdgeBehavior,
+    TimeScaleMarkBehavior, TimeScaleNavigationBehavior, TimeScaleRealtimeAppendBehavior,
     TimeScaleResizeBehavior, TimeScaleScrollZoomBehavior, TimeScaleZoomLimitBehavior,
+    VisibleRangeChangeBehavior, WheelBehaviorConfig, ZoomSelectionBehavior,
     chart_behavior::ChartBehaviorState, chart_presentation::ChartPresentationState,
     chart_runtime::ChartRuntimeState, engine_core::EngineCore,
 };
@@ -15,12 +19,9 @@ use super::{
 impl<R: Renderer> ChartEngine<R> {
     /// Creates a fully initialized engine with explicit domains.
     pub fn new(renderer: R, config: ChartEngineConfig) -> ChartResult<Self> {
-        if !config.viewport.is_valid() {
-            return Err(ChartError::InvalidViewport {
-                width: config.viewport.width,
-                height: config.viewport.height,
-            });
-        }
+        let viewport = config
+            .layout_insets
+            .resolve_content_viewport(config.viewport)?;
         if let Some(explicit_base) = config
             .price_scale_transformed_base_behavior
             .explicit_base_price
@@ -32,7 +33,8 @@ impl<R: Renderer> ChartEngine<R> {
             }
         }
 
-        let time_scale = TimeScale::new(config.time_start, config.time_end)?;
+        let time_scale = TimeScale::new(config.time_start, config.time_end)?
+            .with_inverted(config.time_scale_inverted);
         let price_scale = PriceScale::new_with_mode_and_base(
             config.price_min,
             config.price_max,
@@ -48,10 +50,11 @@ impl<R: Renderer> ChartEngine<R> {
         )?;
         let mut interaction = InteractionState::default();
         interaction.set_crosshair_mode(config.crosshair_mode);
+        interaction.set_crosshair_snap_target(config.crosshair_snap_target);
         let pane_collection = PaneCollection::default();
         let main_pane_id = pane_collection.main_pane_id();
         let model = ChartModel::new(ChartModelBootstrap {
-            viewport: config.viewport,
+            viewport,
             time_scale,
             price_scale,
             price_scale_mode: config.price_scale_mode,
@@ -65,24 +68,28 @@ impl<R: Renderer> ChartEngine<R> {
             renderer,
             core: EngineCore {
                 model,
-                lwc_model: crate::lwc::model::ChartModel::with_default_pane(
-                    config.viewport.width as f64,
-                ),
+                lwc_model: crate::lwc::model::ChartModel::with_default_pane(viewport.width as f64),
                 behavior: ChartBehaviorState {
                     price_scale_realtime_behavior: config.price_scale_realtime_behavior,
                     interaction_input_behavior: config.interaction_input_behavior,
                     price_scale_transformed_base_behavior: config
                         .price_scale_transformed_base_behavior,
+                    layout_insets: config.layout_insets,
                     ..ChartBehaviorState::default()
                 },
                 presentation: ChartPresentationState::default(),
                 runtime: ChartRuntimeState::with_full_invalidation(),
             },
         };
+        engine.core.runtime.reported_visible_range =
+            Some(engine.core.model.time_scale.visible_range());
 
         if config.time_scale_navigation_behavior != TimeScaleNavigationBehavior::default() {
             engine.set_time_scale_navigation_behavior(config.time_scale_navigation_behavior)?;
         }
+        if config.crosshair_snap_radius_px.is_some() {
+            engine.set_crosshair_snap_radius_px(config.crosshair_snap_radius_px)?;
+        }
         if config.time_scale_right_offset_px.is_some() {
             engine.set_time_scale_right_offset_px(config.time_scale_right_offset_px)?;
         }
@@ -136,7 +143,59 @@ impl<R: Renderer> ChartEngine<R> {
         }
         engine.set_time_axis_label_config(config.time_axis_label_config)?;
         engine.set_price_axis_label_config(config.price_axis_label_config)?;
+        if config.pane_separator_behavior != PaneSeparatorBehavior::default() {
+            engine.set_pane_separator_behavior(config.pane_separator_behavior)?;
+        }
+        if config.ohlc_info_line_behavior != OhlcInfoLineBehavior::default() {
+            engine.set_ohlc_info_line_behavior(config.ohlc_info_line_behavior)?;
+        }
+        if config.time_scale_mark_behavior != TimeScaleMarkBehavior::default() {
+            engine.set_time_scale_mark_behavior(config.time_scale_mark_behavior)?;
+        }
+        if config.debug_overlay_behavior != DebugOverlayBehavior::default() {
+            engine.set_debug_overlay_behavior(config.debug_overlay_behavior)?;
+        }
+        if config.redraw_throttle_behavior != RedrawThrottleBehavior::default() {
+            engine.set_redraw_throttle_behavior(config.redraw_throttle_behavior)?;
+        }
+        if config.idle_power_behavior != IdlePowerBehavior::default() {
+            engine.set_idle_power_behavior(config.idle_power_behavior)?;
+        }
+        if config.data_gap_behavior != DataGapBehavior::default() {
+            engine.set_data_gap_behavior(config.data_gap_behavior)?;
+        }
+        if config.bar_countdown_behavior != BarCountdownBehavior::default() {
+            engine.set_bar_countdown_behavior(config.bar_countdown_behavior)?;
+        }
+        if config.high_low_marker_behavior != HighLowMarkerBehavior::default() {
+            engine.set_high_low_marker_behavior(config.high_low_marker_behavior)?;
+        }
+        if config.last_value_animation_behavior != LastValueAnimationBehavior::default() {
+            engine.set_last_value_animation_behavior(config.last_value_animation_behavior)?;
+        }
+        if config.visible_range_change_behavior != VisibleRangeChangeBehavior::default() {
+            engine.set_visible_range_change_behavior(config.visible_range_change_behavior)?;
+        }
+        if config.navigation_history_behavior != NavigationHistoryBehavior::default() {
+            engine.set_navigation_history_behavior(config.navigation_history_behavior);
+        }
+        if config.axis_interaction_config != AxisInteractionConfig::default() {
+            engine.set_axis_interaction_config(config.axis_interaction_config)?;
+        }
+        if config.wheel_behavior_config != WheelBehaviorConfig::default() {
+            engine.set_wheel_behavior_config(config.wheel_behavior_config)?;
+        }
+        if config.zoom_selection_behavior != ZoomSelectionBehavior::default() {
+            engine.set_zoom_selection_behavior(config.zoom_selection_behavior)?;
+        }
+        if config.elastic_overscroll_behavior != ElasticOverscrollBehavior::default() {
+            engine.set_elastic_overscroll_behavior(config.elastic_overscroll_behavior)?;
+        }
+        if config.frame_budget.is_some() {
+            engine.set_frame_budget(config.frame_budget)?;
+        }
         engine.sync_lwc_model_from_core()?;
+        engine.clear_view_history();
 
         Ok(engine)
     }
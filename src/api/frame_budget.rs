@@ -0,0 +1,356 @@
+use serde::{Deserialize, Serialize};
+
+use crate::core::{DataPoint, OhlcBar};
+use crate::error::{ChartError, ChartResult};
+use crate::render::{RenderFrame, Renderer};
+
+use super::ChartEngine;
+
+/// Highest level tried per reduction before a frame is kept over budget.
+///
+/// Each tick level halves axis tick counts and each LOD level doubles the
+/// series bucket, so level 6 draws one series sample per 64 source samples.
+pub(super) const MAX_FRAME_REDUCTION_LEVEL: u8 = 6;
+
+/// Primitive counts of one built `RenderFrame`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct FramePrimitiveStats {
+    pub lines: usize,
+    pub rects: usize,
+    pub paths: usize,
+    pub texts: usize,
+}
+
+impl FramePrimitiveStats {
+    #[must_use]
+    pub fn from_frame(frame: &RenderFrame) -> Self {
+        Self {
+            lines: frame.lines.len(),
+            rects: frame.rects.len(),
+            paths: frame.paths.len(),
+            texts: frame.texts.len(),
+        }
+    }
+
+    #[must_use]
+    pub fn total(self) -> usize {
+        self.lines + self.rects + self.paths + self.texts
+    }
+}
+
+/// Optional per-frame primitive caps.
+///
+/// When a built frame exceeds any cap, the engine rebuilds it with thinned
+/// axis ticks and coarser series level-of-detail until it fits (or the
+/// strongest reduction is reached) and reports
+/// `ChartWarning::FrameBudgetExceeded`. The reduction that fit carries over
+/// to later frames, which relax it one level at a time once there is room,
+/// so sustained load neither rebuilds nor warns on every frame. `None`
+/// leaves a kind uncapped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct FrameBudget {
+    #[serde(default)]
+    pub max_lines: Option<usize>,
+    #[serde(default)]
+    pub max_texts: Option<usize>,
+    #[serde(default)]
+    pub max_rects: Option<usize>,
+}
+
+impl FrameBudget {
+    #[must_use]
+    pub fn with_max_lines(mut self, max_lines: usize) -> Self {
+        self.max_lines = Some(max_lines);
+        self
+    }
+
+    #[must_use]
+    pub fn with_max_texts(mut self, max_texts: usize) -> Self {
+        self.max_texts = Some(max_texts);
+        self
+    }
+
+    #[must_use]
+    pub fn with_max_rects(mut self, max_rects: usize) -> Self {
+        self.max_rects = Some(max_rects);
+        self
+    }
+
+    /// Returns `true` when `stats` fits every configured cap.
+    #[must_use]
+    pub fn allows(self, stats: FramePrimitiveStats) -> bool {
+        let fits = |cap: Option<usize>, count: usize| cap.is_none_or(|cap| count <= cap);
+        fits(self.max_lines, stats.lines)
+            && fits(self.max_texts, stats.texts)
+            && fits(self.max_rects, stats.rects)
+    }
+
+    pub(super) fn validate(self) -> ChartResult<()> {
+        for (name, cap) in [
+            ("max_lines", self.max_lines),
+            ("max_texts", self.max_texts),
+            ("max_rects", self.max_rects),
+        ] {
+            if cap == Some(0) {
+                return Err(ChartError::InvalidData(format!(
+                    "frame budget `{name}` must be > 0"
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Automatic reduction applied while building an over-budget frame.
+///
+/// Tick thinning answers text pressure, series LOD answers rect pressure,
+/// and both answer line pressure (grid lines and wicks/segments).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(super) struct FrameReduction {
+    pub tick_level: u8,
+    pub lod_level: u8,
+}
+
+impl FrameReduction {
+    /// Next reduction for a frame with `stats` over `budget`, or `None` when
+    /// every relevant reduction is already at `MAX_FRAME_REDUCTION_LEVEL`.
+    #[must_use]
+    pub(super) fn escalate(self, budget: FrameBudget, stats: FramePrimitiveStats) -> Option<Self> {
+        let over = |cap: Option<usize>, count: usize| cap.is_some_and(|cap| count > cap);
+        let lines_over = over(budget.max_lines, stats.lines);
+        let thin_ticks = lines_over || over(budget.max_texts, stats.texts);
+        let coarsen_series = lines_over || over(budget.max_rects, stats.rects);
+
+        let mut next = self;
+        if thin_ticks && next.tick_level < MAX_FRAME_REDUCTION_LEVEL {
+            next.tick_level += 1;
+        }
+        if coarsen_series && next.lod_level < MAX_FRAME_REDUCTION_LEVEL {
+            next.lod_level += 1;
+        }
+        (next != self).then_some(next)
+    }
+
+    /// Next-lighter reduction when `stats` would still fit `budget` with
+    /// every count doubled, i.e. one level less of thinning and LOD.
+    #[must_use]
+    pub(super) fn relax(self, budget: FrameBudget, stats: FramePrimitiveStats) -> Option<Self> {
+        let doubled = FramePrimitiveStats {
+            lines: stats.lines.saturating_mul(2),
+            rects: stats.rects.saturating_mul(2),
+            paths: stats.paths,
+            texts: stats.texts.saturating_mul(2),
+        };
+        (self != Self::default() && budget.allows(doubled)).then(|| Self {
+            tick_level: self.tick_level.saturating_sub(1),
+            lod_level: self.lod_level.saturating_sub(1),
+        })
+    }
+
+    /// Highest of the tick and LOD levels, as reported in warnings.
+    #[must_use]
+    pub(super) fn level(self) -> u8 {
+        self.tick_level.max(self.lod_level)
+    }
+
+    /// Halves `count` per tick level without going below `min_ticks`.
+    #[must_use]
+    pub(super) fn thin_tick_count(self, count: usize, min_ticks: usize) -> usize {
+        (count >> self.tick_level).max(min_ticks).min(count)
+    }
+
+    /// Number of consecutive series samples folded into one drawn sample.
+    #[must_use]
+    pub(super) fn lod_bucket_len(self) -> usize {
+        1 << self.lod_level
+    }
+}
+
+/// Keeps the lowest and highest sample of every `bucket_len` points, in time
+/// order, so spikes survive decimation. `newest` stands in for the final
+/// point (its animated value) so the window itself is never copied.
+pub(super) fn decimate_points(
+    points: &[DataPoint],
+    newest: Option<DataPoint>,
+    bucket_len: usize,
+) -> Vec<DataPoint> {
+    let sample = |index: usize| match newest {
+        Some(newest) if index + 1 == points.len() => newest,
+        _ => points[index],
+    };
+    if bucket_len <= 1 || points.len() <= 2 {
+        return (0..points.len()).map(sample).collect();
+    }
+    let mut reduced = Vec::with_capacity(points.len() / bucket_len * 2 + 2);
+    for start in (0..points.len()).step_by(bucket_len) {
+        let bucket = start..(start + bucket_len).min(points.len());
+        let min = bucket
+            .clone()
+            .min_by(|&left, &right| sample(left).y.total_cmp(&sample(right).y))
+            .unwrap_or(start);
+        let max = bucket
+            .max_by(|&left, &right| sample(left).y.total_cmp(&sample(right).y))
+            .unwrap_or(start);
+        reduced.push(sample(min.min(max)));
+        if min != max {
+            reduced.push(sample(min.max(max)));
+        }
+    }
+    reduced
+}
+
+/// Merges every `bucket_len` candles into one bar (first open and time, last
+/// close, extreme high/low). Each merged bar keeps the source index of its
+/// last candle, counted from `first_index`, so per-bar style overrides still
+/// resolve.
+pub(super) fn merge_candle_buckets(
+    candles: &[OhlcBar],
+    first_index: usize,
+    bucket_len: usize,
+) -> (Vec<OhlcBar>, Vec<usize>) {
+    let bucket_len = bucket_len.max(1);
+    candles
+        .chunks(bucket_len)
+        .enumerate()
+        .map(|(bucket_index, bucket)| {
+            let first = bucket[0];
+            let last = bucket[bucket.len() - 1];
+            let merged = OhlcBar {
+                time: first.time,
+                open: first.open,
+                high: bucket
+                    .iter()
+                    .map(|bar| bar.high)
+                    .fold(f64::NEG_INFINITY, f64::max),
+                low: bucket
+                    .iter()
+                    .map(|bar| bar.low)
+                    .fold(f64::INFINITY, f64::min),
+                close: last.close,
+            };
+            let source_index = first_index + bucket_index * bucket_len + bucket.len() - 1;
+            (merged, source_index)
+        })
+        .unzip()
+}
+
+impl<R: Renderer> ChartEngine<R> {
+    #[must_use]
+    pub fn frame_budget(&self) -> Option<FrameBudget> {
+        self.core.behavior.frame_budget
+    }
+
+    /// Caps primitive counts of built frames; `None` disables the guardrail.
+    pub fn set_frame_budget(&mut self, budget: Option<FrameBudget>) -> ChartResult<()> {
+        if let Some(budget) = budget {
+            budget.validate()?;
+        }
+        self.core.behavior.frame_budget = budget;
+        self.core
+            .runtime
+            .frame_reduction
+            .set(FrameReduction::default());
+        self.invalidate_full();
+        Ok(())
+    }
+
+    /// Primitive counts of the most recently built frame, after any budget
+    /// reduction.
+    #[must_use]
+    pub fn last_frame_stats(&self) -> Option<FramePrimitiveStats> {
+        self.core.runtime.last_frame_stats.get()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        FrameBudget, FramePrimitiveStats, FrameReduction, MAX_FRAME_REDUCTION_LEVEL,
+        decimate_points, merge_candle_buckets,
+    };
+    use crate::core::{DataPoint, OhlcBar};
+
+    #[test]
+    fn budget_checks_only_configured_caps() {
+        let stats = FramePrimitiveStats {
+            lines: 10,
+            rects: 5,
+            paths: 100,
+            texts: 3,
+        };
+        assert!(FrameBudget::default().allows(stats));
+        assert!(FrameBudget::default().with_max_lines(10).allows(stats));
+        assert!(!FrameBudget::default().with_max_rects(4).allows(stats));
+        assert!(FrameBudget::default().with_max_rects(0).validate().is_err());
+    }
+
+    #[test]
+    fn reduction_escalates_only_reductions_for_exceeded_kinds() {
+        let stats = FramePrimitiveStats {
+            lines: 10,
+            rects: 5,
+            paths: 0,
+            texts: 3,
+        };
+        let texts_only = FrameBudget::default().with_max_texts(2);
+        let next = FrameReduction::default()
+            .escalate(texts_only, stats)
+            .expect("escalate");
+        assert_eq!((next.tick_level, next.lod_level), (1, 0));
+
+        let lines = FrameBudget::default().with_max_lines(2);
+        let next = next.escalate(lines, stats).expect("escalate");
+        assert_eq!((next.tick_level, next.lod_level), (2, 1));
+
+        let maxed = FrameReduction {
+            tick_level: MAX_FRAME_REDUCTION_LEVEL,
+            lod_level: 0,
+        };
+        assert_eq!(maxed.escalate(texts_only, stats), None);
+    }
+
+    #[test]
+    fn reduction_thins_ticks_down_to_minimum() {
+        let reduction = FrameReduction {
+            tick_level: 2,
+            lod_level: 2,
+        };
+        assert_eq!(reduction.thin_tick_count(12, 2), 3);
+        assert_eq!(reduction.thin_tick_count(4, 2), 2);
+        assert_eq!(FrameReduction::default().thin_tick_count(1, 2), 1);
+        assert_eq!(reduction.lod_bucket_len(), 4);
+    }
+
+    #[test]
+    fn decimation_keeps_bucket_extremes_in_time_order() {
+        let points: Vec<DataPoint> = [3.0, 9.0, 1.0, 5.0, 2.0]
+            .iter()
+            .enumerate()
+            .map(|(x, &y)| DataPoint::new(x as f64, y))
+            .collect();
+        let reduced = decimate_points(&points, None, 4);
+        let xs: Vec<f64> = reduced.iter().map(|point| point.x).collect();
+        assert_eq!(xs, vec![1.0, 2.0, 4.0]);
+
+        // The animated newest value decides its bucket's extremes.
+        let reduced = decimate_points(&points[..4], Some(DataPoint::new(3.0, 0.5)), 2);
+        let ys: Vec<f64> = reduced.iter().map(|point| point.y).collect();
+        assert_eq!(ys, vec![3.0, 9.0, 1.0, 0.5]);
+    }
+
+    #[test]
+    fn candle_buckets_merge_ohlc_and_keep_last_source_index() {
+        let candles = [
+            OhlcBar::new(0.0, 10.0, 12.0, 9.0, 11.0).expect("bar"),
+            OhlcBar::new(1.0, 11.0, 15.0, 10.0, 14.0).expect("bar"),
+            OhlcBar::new(2.0, 14.0, 14.5, 7.0, 8.0).expect("bar"),
+        ];
+        let (merged, indices) = merge_candle_buckets(&candles, 4, 2);
+        assert_eq!(indices, vec![5, 6]);
+        assert_eq!(
+            merged[0],
+            OhlcBar::new(0.0, 10.0, 15.0, 9.0, 14.0).expect("bar")
+        );
+        assert_eq!(merged[1], candles[2]);
+    }
+}
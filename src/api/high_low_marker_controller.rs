@@ -0,0 +1,166 @@
+use crate::error::ChartResult;
+use crate::render::{CanvasLayerKind, Renderer, TextHAlign, TextPrimitive, TextVAlign};
+
+use super::axis_label_format::map_price_to_display_value;
+use super::axis_render_frame_builder::{AxisPriceDisplayContext, AxisPrimitiveSink};
+use super::last_price_axis_scene_builder::LastPriceMarker;
+use super::validation::validate_high_low_marker_behavior;
+use super::{ChartEngine, HighLowMarkerBehavior};
+
+/// Highest high and lowest low inside the visible time range.
+///
+/// Ties resolve to the earliest bar.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VisibleHighLow {
+    pub high: f64,
+    pub high_time: f64,
+    pub low: f64,
+    pub low_time: f64,
+}
+
+impl<R: Renderer> ChartEngine<R> {
+    #[must_use]
+    pub fn high_low_marker_behavior(&self) -> HighLowMarkerBehavior {
+        self.core.behavior.high_low_marker_behavior
+    }
+
+    pub fn set_high_low_marker_behavior(
+        &mut self,
+        behavior: HighLowMarkerBehavior,
+    ) -> ChartResult<()> {
+        validate_high_low_marker_behavior(behavior)?;
+        if self.core.behavior.high_low_marker_behavior != behavior {
+            self.core.behavior.high_low_marker_behavior = behavior;
+            self.invalidate_full();
+        }
+        Ok(())
+    }
+
+    /// Extremes of the visible candles, or of the visible points when the
+    /// chart has no candles.
+    #[must_use]
+    pub fn visible_high_low(&self) -> Option<VisibleHighLow> {
+        let samples: Vec<(f64, f64, f64)> = if self.core.model.candles.is_empty() {
+            self.visible_points_slice()
+                .iter()
+                .map(|point| (point.x, point.y, point.y))
+                .collect()
+        } else {
+            self.visible_candles_slice()
+                .iter()
+                .map(|bar| (bar.time, bar.high, bar.low))
+                .collect()
+        };
+        let (&(first_time, first_high, first_low), rest) = samples.split_first()?;
+        let mut extremes = VisibleHighLow {
+            high: first_high,
+            high_time: first_time,
+            low: first_low,
+            low_time: first_time,
+        };
+        for &(time, high, low) in rest {
+            if high > extremes.high {
+                extremes.high = high;
+                extremes.high_time = time;
+            }
+            if low < extremes.low {
+                extremes.low = low;
+                extremes.low_time = time;
+            }
+        }
+        Some(extremes)
+    }
+
+    /// Axis markers for the visible extremes, stacked with the last-value
+    /// labels so ticks keep clear of them.
+    pub(super) fn resolve_high_low_markers_for_axis(
+        &self,
+        plot_bottom: f64,
+    ) -> ChartResult<Vec<LastPriceMarker>> {
+        let behavior = self.core.behavior.high_low_marker_behavior;
+        if !behavior.visible || !behavior.show_axis_labels {
+            return Ok(Vec::new());
+        }
+        let Some(extremes) = self.visible_high_low() else {
+            return Ok(Vec::new());
+        };
+        [
+            (extremes.high, behavior.high_color),
+            (extremes.low, behavior.low_color),
+        ]
+        .into_iter()
+        .map(|(price, color)| {
+            Ok(LastPriceMarker {
+                last_price: price,
+                py: self.map_price_to_pixel(price)?.clamp(0.0, plot_bottom),
+                marker_line_color: color,
+                marker_label_color: color,
+                show_line: false,
+                show_label: true,
+                edge_arrow: None,
+                price_format: None,
+            })
+        })
+        .collect()
+    }
+
+    /// Draws `price (time)` labels above the high bar and below the low bar,
+    /// kept inside the plot area.
+    pub(super) fn append_high_low_marker_primitives(
+        &self,
+        sink: &mut AxisPrimitiveSink<'_>,
+        plot_right: f64,
+        plot_bottom: f64,
+        visible_span_abs: f64,
+        display: AxisPriceDisplayContext,
+    ) -> ChartResult<()> {
+        let behavior = self.core.behavior.high_low_marker_behavior;
+        if !behavior.visible {
+            return Ok(());
+        }
+        let Some(extremes) = self.visible_high_low() else {
+            return Ok(());
+        };
+        let metrics = self.core.presentation.render_style.label_text_metrics;
+        let font_size = behavior.font_size_px;
+        for (price, time, color, is_high) in [
+            (extremes.high, extremes.high_time, behavior.high_color, true),
+            (extremes.low, extremes.low_time, behavior.low_color, false),
+        ] {
+            let display_price = map_price_to_display_value(
+                price,
+                self.resolve_price_axis_display_mode(),
+                display.fallback_display_base_price,
+            );
+            let price_text = self.format_price_axis_label(
+                display_price,
+                display.display_tick_step_abs,
+                display.display_suffix,
+            );
+            let time_text = self.format_time_axis_label(time, visible_span_abs);
+            let text = format!("{price_text} ({time_text})");
+
+            let x = self.map_x_to_pixel(time)?;
+            let py = self.map_price_to_pixel(price)?;
+            let width = metrics.text_width_px(&text, font_size);
+            let left = (x - width * 0.5).clamp(0.0, (plot_right - width).max(0.0));
+            let (y, v_align) = if is_high {
+                (
+                    (py - behavior.offset_px).clamp(font_size.min(plot_bottom), plot_bottom),
+                    TextVAlign::Bottom,
+                )
+            } else {
+                (
+                    (py + behavior.offset_px).clamp(0.0, (plot_bottom - font_size).max(0.0)),
+                    TextVAlign::Top,
+                )
+            };
+            sink.push_text(
+                CanvasLayerKind::Overlay,
+                TextPrimitive::new(text, left, y, font_size, color, TextHAlign::Left)
+                    .with_v_align(v_align),
+            );
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,145 @@
+use std::time::Duration;
+
+use tracing::{debug, warn};
+
+use crate::error::ChartResult;
+use crate::render::Renderer;
+
+use super::validation::validate_idle_power_behavior;
+use super::{ChartEngine, EngineCommand, EngineCommandReport, IdlePowerBehavior};
+
+/// Idle tracking behind `IdlePowerBehavior`.
+#[derive(Debug, Default)]
+pub(super) struct IdlePowerState {
+    /// Time of the last interaction or data update, in `power_tick` clock
+    /// time.
+    last_activity: Option<Duration>,
+    /// Activity seen since the last tick; stamped with the next tick's time
+    /// because interaction entry points carry no clock.
+    activity_pending: bool,
+    idle: bool,
+    last_batch: Option<Duration>,
+    deferred_commands: Vec<EngineCommand>,
+}
+
+impl<R: Renderer> ChartEngine<R> {
+    #[must_use]
+    pub fn idle_power_behavior(&self) -> IdlePowerBehavior {
+        self.core.behavior.idle_power_behavior
+    }
+
+    /// Disabling idle detection while idle resumes and applies held
+    /// commands.
+    pub fn set_idle_power_behavior(&mut self, behavior: IdlePowerBehavior) -> ChartResult<()> {
+        validate_idle_power_behavior(behavior)?;
+        self.core.behavior.idle_power_behavior = behavior;
+        if behavior.idle_after_seconds.is_none() && self.is_power_idle() {
+            self.resume_from_power_idle();
+        }
+        Ok(())
+    }
+
+    /// Whether the engine is in the idle power-saving mode.
+    #[must_use]
+    pub fn is_power_idle(&self) -> bool {
+        self.core.runtime.idle_power.idle
+    }
+
+    /// Data commands held while idle, waiting for the next batch.
+    #[must_use]
+    pub fn deferred_command_count(&self) -> usize {
+        self.core.runtime.idle_power.deferred_commands.len()
+    }
+
+    /// Records user interaction, resuming immediately when idle.
+    ///
+    /// `pointer_move`, `pan_start` and `PointerInputBridge::handle` call this
+    /// already; hosts driving other input paths call it themselves.
+    pub fn note_user_activity(&mut self) {
+        self.core.runtime.idle_power.activity_pending = true;
+        if self.is_power_idle() {
+            self.resume_from_power_idle();
+        }
+    }
+
+    /// Advances idle detection to monotonic clock time `now`.
+    ///
+    /// Hosts call this periodically (e.g. from their frame clock, with the
+    /// same clock passed to `redraw_due`). It enters the idle mode once
+    /// `idle_after_seconds` passed without activity and, while idle, applies
+    /// held data commands at most once per `batch_interval_seconds`. The
+    /// report covers the commands applied by this tick.
+    pub fn power_tick(&mut self, now: Duration) -> EngineCommandReport {
+        let behavior = self.core.behavior.idle_power_behavior;
+        let state = &mut self.core.runtime.idle_power;
+        // A clock reset (time going backwards) restarts the idle timer.
+        if state.activity_pending || state.last_activity.is_none_or(|last| now < last) {
+            state.last_activity = Some(now);
+            state.activity_pending = false;
+        }
+        let Some(idle_after) = behavior.idle_after_seconds else {
+            return EngineCommandReport::default();
+        };
+        if !state.idle {
+            let quiet = state
+                .last_activity
+                .map_or(0.0, |last| (now - last).as_secs_f64());
+            if quiet >= idle_after {
+                state.idle = true;
+                state.last_batch = Some(now);
+                debug!(quiet_seconds = quiet, "entered idle power mode");
+            }
+            return EngineCommandReport::default();
+        }
+        let batch_due = state.last_batch.is_none_or(|last| {
+            now < last || (now - last).as_secs_f64() >= behavior.batch_interval_seconds
+        });
+        if !batch_due || state.deferred_commands.is_empty() {
+            return EngineCommandReport::default();
+        }
+        state.last_batch = Some(now);
+        self.apply_deferred_commands()
+    }
+
+    /// Holds a data command until the next idle batch.
+    pub(super) fn defer_command(&mut self, command: EngineCommand) {
+        self.core.runtime.idle_power.deferred_commands.push(command);
+    }
+
+    /// Marks a data update for idle detection without leaving the idle mode.
+    pub(super) fn note_data_activity(&mut self) {
+        self.core.runtime.idle_power.activity_pending = true;
+    }
+
+    /// Minimum seconds between granted redraws while idle.
+    pub(super) fn idle_redraw_interval_seconds(&self) -> Option<f64> {
+        self.is_power_idle().then_some(
+            self.core
+                .behavior
+                .idle_power_behavior
+                .batch_interval_seconds,
+        )
+    }
+
+    fn resume_from_power_idle(&mut self) {
+        self.core.runtime.idle_power.idle = false;
+        self.core.runtime.idle_power.activity_pending = true;
+        let report = self.apply_deferred_commands();
+        debug!(applied = report.applied, "resumed from idle power mode");
+        for err in report.errors {
+            warn!(error = %err, "held command rejected on idle resume");
+        }
+    }
+
+    fn apply_deferred_commands(&mut self) -> EngineCommandReport {
+        let commands = std::mem::take(&mut self.core.runtime.idle_power.deferred_commands);
+        let mut report = EngineCommandReport::default();
+        for command in commands {
+            match self.apply_command(command) {
+                Ok(()) => report.applied += 1,
+                Err(err) => report.errors.push(err),
+            }
+        }
+        report
+    }
+}
@@ -1,12 +1,16 @@
-use crate::error::ChartResult;
+use crate::error::{ChartError, ChartResult};
 use crate::interaction::{
-    CrosshairMode, CrosshairState, InteractionMode, KineticPanConfig, KineticPanState,
+    CrosshairMode, CrosshairSnapTarget, CrosshairState, InteractionMode, InteractionState,
+    KineticPanConfig, KineticPanState,
 };
 use crate::render::Renderer;
 
-use super::interaction_validation::validate_kinetic_pan_config;
+use super::interaction_validation::{
+    validate_crosshair_snap_radius_px, validate_kinetic_pan_config,
+};
 use super::{
-    ChartEngine, InteractionInputBehavior, interaction_coordinator::InteractionCoordinator,
+    ChartEngine, InteractionAction, InteractionInputBehavior,
+    interaction_coordinator::InteractionCoordinator,
 };
 
 impl<R: Renderer> ChartEngine<R> {
@@ -31,6 +35,32 @@ impl<R: Renderer> ChartEngine<R> {
 
     pub fn set_crosshair_mode(&mut self, mode: CrosshairMode) {
         InteractionCoordinator::set_crosshair_mode(self, mode);
+        self.record_interaction(InteractionAction::SetCrosshairMode { mode: mode.into() });
+    }
+
+    /// Returns the sample field magnet-mode snapping targets.
+    #[must_use]
+    pub fn crosshair_snap_target(&self) -> CrosshairSnapTarget {
+        self.core.model.interaction.crosshair_snap_target()
+    }
+
+    /// Sets the magnet snap target and re-resolves an active snapped crosshair.
+    pub fn set_crosshair_snap_target(&mut self, target: CrosshairSnapTarget) {
+        InteractionCoordinator::set_crosshair_snap_target(self, target);
+    }
+
+    /// Returns the magnet snap radius in pixels (`None` snaps at any distance).
+    #[must_use]
+    pub fn crosshair_snap_radius_px(&self) -> Option<f64> {
+        self.core.model.interaction.crosshair_snap_radius_px()
+    }
+
+    /// Limits magnet snapping to samples within `radius_px` horizontal pixels of
+    /// the pointer; farther away the crosshair behaves like `Normal` mode.
+    pub fn set_crosshair_snap_radius_px(&mut self, radius_px: Option<f64>) -> ChartResult<()> {
+        validate_crosshair_snap_radius_px(radius_px)?;
+        InteractionCoordinator::set_crosshair_snap_radius_px(self, radius_px);
+        Ok(())
     }
 
     #[must_use]
@@ -51,7 +81,11 @@ impl<R: Renderer> ChartEngine<R> {
 
     /// Starts kinetic pan with signed velocity in time-units per second.
     pub fn start_kinetic_pan(&mut self, velocity_time_per_sec: f64) -> ChartResult<()> {
-        InteractionCoordinator::start_kinetic_pan(self, velocity_time_per_sec)
+        InteractionCoordinator::start_kinetic_pan(self, velocity_time_per_sec)?;
+        self.record_interaction(InteractionAction::StartKineticPan {
+            velocity_time_per_sec,
+        });
+        Ok(())
     }
 
     pub fn stop_kinetic_pan(&mut self) {
@@ -63,21 +97,97 @@ impl<R: Renderer> ChartEngine<R> {
         self.core.model.interaction.crosshair()
     }
 
+    /// Full interaction state for session persistence; pair with
+    /// `InteractionState::without_pointer_position` to drop the hover location.
+    #[must_use]
+    pub fn interaction_state(&self) -> InteractionState {
+        self.core.model.interaction
+    }
+
+    /// Restores crosshair mode, snapping, kinetic config and the crosshair
+    /// from a saved state. An in-progress pan or kinetic coast is not
+    /// restored. `restore_pointer_position = false` keeps the current pointer
+    /// location and hides the saved crosshair instead.
+    pub fn set_interaction_state(
+        &mut self,
+        state: InteractionState,
+        restore_pointer_position: bool,
+    ) -> ChartResult<()> {
+        validate_crosshair_snap_radius_px(state.crosshair_snap_radius_px())?;
+        validate_kinetic_pan_config(state.kinetic_pan_config())?;
+        let crosshair = state.crosshair();
+        let (cursor_x, cursor_y) = state.cursor();
+        let finite = [
+            crosshair.x,
+            crosshair.y,
+            cursor_x,
+            cursor_y,
+            state.kinetic_pan_state().velocity_time_per_sec,
+        ]
+        .into_iter()
+        .chain(crosshair.snapped_x)
+        .chain(crosshair.snapped_y)
+        .chain(crosshair.snapped_time)
+        .chain(crosshair.snapped_price)
+        .all(f64::is_finite);
+        if !finite {
+            return Err(ChartError::InvalidData(
+                "interaction state positions must be finite".to_owned(),
+            ));
+        }
+        let mut restored = state;
+        restored.on_pan_end();
+        restored.stop_kinetic_pan();
+        if !restore_pointer_position {
+            let (x, y) = self.core.model.interaction.cursor();
+            restored = restored.without_pointer_position();
+            restored.set_cursor(x, y);
+        }
+        self.core.model.interaction = restored;
+        self.invalidate_cursor();
+        Ok(())
+    }
+
+    /// Data time under the visible crosshair (snapped in magnet mode).
+    #[must_use]
+    pub fn crosshair_time(&self) -> Option<f64> {
+        self.hovered_time()
+    }
+
+    /// Shows the crosshair at `time` as if driven by another chart; `None`
+    /// hides it. Plugins receive no pointer events for it.
+    pub fn set_crosshair_time(&mut self, time: Option<f64>) -> ChartResult<()> {
+        if let Some(time) = time
+            && !time.is_finite()
+        {
+            return Err(ChartError::InvalidData(
+                "crosshair time must be finite".to_owned(),
+            ));
+        }
+        InteractionCoordinator::set_crosshair_time(self, time)
+    }
+
     /// Handles pointer movement and updates crosshair snapping in one step.
     pub fn pointer_move(&mut self, x: f64, y: f64) {
+        self.note_user_activity();
         InteractionCoordinator::pointer_move(self, x, y);
+        self.record_interaction(InteractionAction::PointerMove { x, y });
     }
 
     /// Marks pointer as outside chart bounds.
     pub fn pointer_leave(&mut self) {
         InteractionCoordinator::pointer_leave(self);
+        self.record_interaction(InteractionAction::PointerLeave);
     }
 
     pub fn pan_start(&mut self) {
+        self.note_user_activity();
         InteractionCoordinator::pan_start(self);
+        self.record_interaction(InteractionAction::PanStart);
     }
 
     pub fn pan_end(&mut self) {
         InteractionCoordinator::pan_end(self);
+        self.record_interaction(InteractionAction::PanEnd);
     }
 }
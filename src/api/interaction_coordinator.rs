@@ -1,7 +1,9 @@
 use crate::error::{ChartError, ChartResult};
-use crate::interaction::CrosshairMode;
+use crate::interaction::{CrosshairMode, CrosshairSnapTarget};
 use crate::render::Renderer;
 
+use super::elastic_overscroll_controller::ElasticOverscroll;
+use super::navigation_history_controller::ViewCheckpointKind;
 use super::{ChartEngine, PluginEvent};
 
 pub(super) struct InteractionCoordinator;
@@ -19,6 +21,41 @@ impl InteractionCoordinator {
         engine.invalidate_cursor();
     }
 
+    pub(super) fn set_crosshair_snap_target<R: Renderer>(
+        engine: &mut ChartEngine<R>,
+        target: CrosshairSnapTarget,
+    ) {
+        engine
+            .core
+            .model
+            .interaction
+            .set_crosshair_snap_target(target);
+        Self::refresh_magnet_snap(engine);
+    }
+
+    pub(super) fn set_crosshair_snap_radius_px<R: Renderer>(
+        engine: &mut ChartEngine<R>,
+        radius_px: Option<f64>,
+    ) {
+        engine
+            .core
+            .model
+            .interaction
+            .set_crosshair_snap_radius_px(radius_px);
+        Self::refresh_magnet_snap(engine);
+    }
+
+    fn refresh_magnet_snap<R: Renderer>(engine: &mut ChartEngine<R>) {
+        let interaction = engine.core.model.interaction;
+        if interaction.crosshair_mode() == CrosshairMode::Magnet && interaction.crosshair().visible
+        {
+            let crosshair = interaction.crosshair();
+            let snap = engine.snap_at(crosshair.x, crosshair.y);
+            engine.core.model.interaction.set_crosshair_snap(snap);
+        }
+        engine.invalidate_cursor();
+    }
+
     pub(super) fn start_kinetic_pan<R: Renderer>(
         engine: &mut ChartEngine<R>,
         velocity_time_per_sec: f64,
@@ -32,6 +69,11 @@ impl InteractionCoordinator {
             Self::stop_kinetic_pan(engine);
             return Ok(());
         }
+        // A released overscroll returns to the edge instead of coasting.
+        if engine.spring_back_animation_active() {
+            return Ok(());
+        }
+        engine.core.runtime.time_range_animation = None;
         engine
             .core
             .model
@@ -45,6 +87,7 @@ impl InteractionCoordinator {
         if engine.core.model.interaction.kinetic_pan_state().active {
             engine.core.model.interaction.stop_kinetic_pan();
             engine.emit_plugin_event(PluginEvent::PanEnded);
+            engine.checkpoint_navigation_view(ViewCheckpointKind::Kinetic);
         }
     }
 
@@ -72,6 +115,7 @@ impl InteractionCoordinator {
 
         if was_active && !engine.core.model.interaction.kinetic_pan_state().active {
             engine.emit_plugin_event(PluginEvent::PanEnded);
+            engine.checkpoint_navigation_view(ViewCheckpointKind::Kinetic);
         }
         Ok(true)
     }
@@ -81,18 +125,77 @@ impl InteractionCoordinator {
         let crosshair_mode = engine.core.model.interaction.crosshair_mode();
         match crosshair_mode {
             CrosshairMode::Magnet => {
-                let snap = engine.snap_at_x(x);
+                let snap = engine.snap_at(x, y);
                 engine.core.model.interaction.set_crosshair_snap(snap);
             }
             CrosshairMode::Normal => engine.core.model.interaction.set_crosshair_snap(None),
             CrosshairMode::Hidden => engine.core.model.interaction.on_pointer_leave(),
+            CrosshairMode::PriceGrid => Self::snap_to_price_gridline(engine, y),
         }
         engine.emit_plugin_event(PluginEvent::PointerMoved { x, y });
+        engine.update_hovered_primitive(Some((x, y)));
+    }
+
+    /// Snaps the crosshair price to the gridline nearest pixel row `y`;
+    /// without gridlines the crosshair stays free.
+    fn snap_to_price_gridline<R: Renderer>(engine: &mut ChartEngine<R>, y: f64) {
+        let viewport = engine.core.model.viewport;
+        let price_scale = engine.core.model.price_scale;
+        let nearest = engine
+            .resolve_price_gridline_prices()
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|price| {
+                let py = price_scale.price_to_pixel(price, viewport).ok()?;
+                Some((price, py))
+            })
+            .min_by(|left, right| (left.1 - y).abs().total_cmp(&(right.1 - y).abs()));
+        match nearest {
+            Some((price, py)) => engine
+                .core
+                .model
+                .interaction
+                .set_crosshair_gridline_snap(py, price),
+            None => engine.core.model.interaction.set_crosshair_snap(None),
+        }
     }
 
     pub(super) fn pointer_leave<R: Renderer>(engine: &mut ChartEngine<R>) {
         engine.core.model.interaction.on_pointer_leave();
         engine.emit_plugin_event(PluginEvent::PointerLeft);
+        engine.update_hovered_primitive(None);
+    }
+
+    /// Places the crosshair at `time` without a pointer event, keeping the
+    /// current cursor row; `None` hides it.
+    pub(super) fn set_crosshair_time<R: Renderer>(
+        engine: &mut ChartEngine<R>,
+        time: Option<f64>,
+    ) -> ChartResult<()> {
+        let Some(time) = time else {
+            engine.core.model.interaction.on_pointer_leave();
+            engine.invalidate_cursor();
+            return Ok(());
+        };
+        let crosshair_mode = engine.core.model.interaction.crosshair_mode();
+        if crosshair_mode == CrosshairMode::Hidden {
+            return Ok(());
+        }
+        let x = engine.map_x_to_pixel(time)?;
+        let (_, y) = engine.core.model.interaction.cursor();
+        engine.core.model.interaction.on_pointer_move(x, y);
+        match crosshair_mode {
+            CrosshairMode::Magnet => {
+                let snap = engine.snap_at(x, y);
+                engine.core.model.interaction.set_crosshair_snap(snap);
+            }
+            CrosshairMode::PriceGrid => Self::snap_to_price_gridline(engine, y),
+            CrosshairMode::Normal | CrosshairMode::Hidden => {
+                engine.core.model.interaction.set_crosshair_snap(None);
+            }
+        }
+        engine.invalidate_cursor();
+        Ok(())
     }
 
     pub(super) fn pan_start<R: Renderer>(engine: &mut ChartEngine<R>) {
@@ -104,6 +207,9 @@ impl InteractionCoordinator {
         {
             return;
         }
+        engine.finish_spring_back_animation();
+        engine.core.runtime.time_range_animation = None;
+        engine.core.runtime.elastic_overscroll = ElasticOverscroll::default();
         engine.core.model.interaction.on_pan_start();
         engine.emit_plugin_event(PluginEvent::PanStarted);
     }
@@ -119,5 +225,7 @@ impl InteractionCoordinator {
         }
         engine.core.model.interaction.on_pan_end();
         engine.emit_plugin_event(PluginEvent::PanEnded);
+        engine.checkpoint_navigation_view(ViewCheckpointKind::Pan);
+        engine.release_elastic_overscroll();
     }
 }
@@ -0,0 +1,177 @@
+use std::time::Duration;
+
+use crate::render::Renderer;
+
+use super::{
+    ChartEngine, InteractionAction, InteractionScenario, InteractionStep, InteractionTrace,
+    TracePoint, TracePriceRange, TraceTimeRange, TraceViewport, observe_interaction_state,
+};
+
+/// Options for `ChartEngine::start_interaction_recording`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InteractionRecordingOptions {
+    pub trace_name: String,
+    pub source_notes: String,
+    /// Stores the observed state after every step so a replay can flag the
+    /// first divergence instead of only re-running the gestures.
+    pub record_expectations: bool,
+    pub tolerance: f64,
+}
+
+impl Default for InteractionRecordingOptions {
+    fn default() -> Self {
+        Self {
+            trace_name: "recorded-session".to_owned(),
+            source_notes: String::new(),
+            record_expectations: true,
+            tolerance: 1e-9,
+        }
+    }
+}
+
+impl InteractionRecordingOptions {
+    #[must_use]
+    pub fn with_trace_name(mut self, trace_name: impl Into<String>) -> Self {
+        self.trace_name = trace_name.into();
+        self
+    }
+
+    #[must_use]
+    pub fn with_source_notes(mut self, source_notes: impl Into<String>) -> Self {
+        self.source_notes = source_notes.into();
+        self
+    }
+
+    #[must_use]
+    pub fn with_record_expectations(mut self, enabled: bool) -> Self {
+        self.record_expectations = enabled;
+        self
+    }
+
+    #[must_use]
+    pub fn with_tolerance(mut self, tolerance: f64) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+}
+
+/// Active recording held in runtime state.
+pub(super) struct InteractionRecorder {
+    /// Frame-clock time of the first stamped step.
+    started: Option<Duration>,
+    record_expectations: bool,
+    trace: InteractionTrace,
+}
+
+impl<R: Renderer> ChartEngine<R> {
+    /// Starts capturing interaction API calls into an `InteractionTrace`.
+    ///
+    /// The trace header and scenario points come from the content viewport,
+    /// ranges, and point series; the current crosshair mode and visible range
+    /// are recorded as the first steps. Every successful interaction call
+    /// after this is appended with its elapsed host frame-clock time
+    /// (`frame_clock_tick` / `redraw_due`; `None` until the host drives that
+    /// clock). Behaviors, style, and
+    /// candles are not captured; pair the trace with `export_scene` and
+    /// replay it with `replay_interaction_scenario` on the restored engine when
+    /// those matter. Restarting discards the previous recording.
+    pub fn start_interaction_recording(&mut self, options: InteractionRecordingOptions) {
+        let viewport = self.content_viewport();
+        let (full_start, full_end) = self.time_full_range();
+        let (price_min, price_max) = self.price_domain();
+        let points = self
+            .core
+            .model
+            .points
+            .iter()
+            .map(|point| TracePoint {
+                time: point.x,
+                value: point.y,
+            })
+            .collect();
+        let trace = InteractionTrace {
+            trace_name: options.trace_name,
+            source: "chart-rs-recording".to_owned(),
+            source_notes: options.source_notes,
+            viewport: TraceViewport {
+                width: viewport.width,
+                height: viewport.height,
+            },
+            time_range: TraceTimeRange {
+                start: full_start,
+                end: full_end,
+            },
+            price_range: TracePriceRange {
+                min: price_min,
+                max: price_max,
+            },
+            tolerance: options.tolerance,
+            scenarios: vec![InteractionScenario {
+                id: "recording".to_owned(),
+                points,
+                steps: Vec::new(),
+            }],
+        };
+        self.core.runtime.interaction_recorder = Some(InteractionRecorder {
+            started: self.core.runtime.frame_clock_time,
+            record_expectations: options.record_expectations,
+            trace,
+        });
+
+        let (visible_start, visible_end) = self.time_visible_range();
+        self.record_interaction(InteractionAction::SetCrosshairMode {
+            mode: self.crosshair_mode().into(),
+        });
+        self.record_interaction(InteractionAction::SetTimeVisibleRange {
+            start: visible_start,
+            end: visible_end,
+        });
+    }
+
+    #[must_use]
+    pub fn is_recording_interactions(&self) -> bool {
+        self.core.runtime.interaction_recorder.is_some()
+    }
+
+    /// Trace recorded so far, without stopping the recording.
+    #[must_use]
+    pub fn recorded_interaction_trace(&self) -> Option<&InteractionTrace> {
+        self.core
+            .runtime
+            .interaction_recorder
+            .as_ref()
+            .map(|recorder| &recorder.trace)
+    }
+
+    /// Stops recording and returns the captured trace.
+    pub fn stop_interaction_recording(&mut self) -> Option<InteractionTrace> {
+        self.core
+            .runtime
+            .interaction_recorder
+            .take()
+            .map(|recorder| recorder.trace)
+    }
+
+    pub(super) fn record_interaction(&mut self, action: InteractionAction) {
+        let Some(recorder) = self.core.runtime.interaction_recorder.as_ref() else {
+            return;
+        };
+        let expect = recorder
+            .record_expectations
+            .then(|| observe_interaction_state(self));
+        let now = self.core.runtime.frame_clock_time;
+        let Some(recorder) = self.core.runtime.interaction_recorder.as_mut() else {
+            return;
+        };
+        let elapsed_ms = now.map(|now| {
+            let started = *recorder.started.get_or_insert(now);
+            now.saturating_sub(started).as_secs_f64() * 1000.0
+        });
+        let step = InteractionStep {
+            action,
+            expect,
+            elapsed_ms,
+        };
+        recorder.trace.scenarios[0].steps.push(step);
+    }
+}
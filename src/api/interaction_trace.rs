@@ -0,0 +1,553 @@
+//! Interaction traces: a JSON schema for user sessions plus a deterministic
+//! executor.
+//!
+//! A trace is a list of scenarios. Each one seeds a headless engine with a
+//! point series and then applies interaction steps (pans, zooms, kinetic
+//! steps, crosshair moves). A step may carry an expectation of the observable
+//! state after it ran, which `replay_interaction_scenario` compares within
+//! the trace tolerance. This is the same format used by
+//! `differential_trace_tool` and the Lightweight parity fixtures; interaction
+//! recording produces it, and `testing::trace` re-exports it for test suites.
+
+use serde::{Deserialize, Serialize};
+
+use super::{ChartEngine, ChartEngineConfig, TimeScaleNavigationBehavior};
+use crate::core::{DataPoint, Viewport};
+use crate::error::{ChartError, ChartResult};
+use crate::interaction::CrosshairMode;
+use crate::render::{NullRenderer, Renderer};
+
+/// A complete interaction trace file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InteractionTrace {
+    pub trace_name: String,
+    pub source: String,
+    pub source_notes: String,
+    pub viewport: TraceViewport,
+    pub time_range: TraceTimeRange,
+    pub price_range: TracePriceRange,
+    /// Absolute tolerance applied to every numeric expectation.
+    pub tolerance: f64,
+    pub scenarios: Vec<InteractionScenario>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct TraceViewport {
+    pub width: u32,
+    pub height: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct TraceTimeRange {
+    pub start: f64,
+    pub end: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct TracePriceRange {
+    pub min: f64,
+    pub max: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct TracePoint {
+    pub time: f64,
+    pub value: f64,
+}
+
+/// One independent session, replayed on a fresh engine.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InteractionScenario {
+    pub id: String,
+    /// Point series installed before the first step; empty keeps the engine
+    /// without data.
+    pub points: Vec<TracePoint>,
+    pub steps: Vec<InteractionStep>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InteractionStep {
+    pub action: InteractionAction,
+    #[serde(default)]
+    pub expect: Option<InteractionExpectation>,
+    /// Milliseconds since the recording started. Informational only: replay
+    /// is driven by the actions (kinetic steps carry their own `dt`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub elapsed_ms: Option<f64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TraceCrosshairMode {
+    Magnet,
+    Normal,
+    Hidden,
+    PriceGrid,
+}
+
+impl From<TraceCrosshairMode> for CrosshairMode {
+    fn from(value: TraceCrosshairMode) -> Self {
+        match value {
+            TraceCrosshairMode::Magnet => Self::Magnet,
+            TraceCrosshairMode::Normal => Self::Normal,
+            TraceCrosshairMode::Hidden => Self::Hidden,
+            TraceCrosshairMode::PriceGrid => Self::PriceGrid,
+        }
+    }
+}
+
+impl From<CrosshairMode> for TraceCrosshairMode {
+    fn from(value: CrosshairMode) -> Self {
+        match value {
+            CrosshairMode::Magnet => Self::Magnet,
+            CrosshairMode::Normal => Self::Normal,
+            CrosshairMode::Hidden => Self::Hidden,
+            CrosshairMode::PriceGrid => Self::PriceGrid,
+        }
+    }
+}
+
+/// One interaction API call; each variant maps to exactly one engine method.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum InteractionAction {
+    /// `set_time_scale_navigation_behavior`
+    SetNavigation {
+        right_offset_bars: f64,
+        bar_spacing_px: Option<f64>,
+    },
+    /// `wheel_pan_time_visible`
+    WheelPan {
+        wheel_delta_x: f64,
+        pan_step_ratio: f64,
+    },
+    /// `touch_drag_pan_time_visible`
+    TouchDragPan { delta_x_px: f64, delta_y_px: f64 },
+    /// `wheel_zoom_time_visible`
+    WheelZoom {
+        wheel_delta_y: f64,
+        anchor_px: f64,
+        zoom_step_ratio: f64,
+        min_span_absolute: f64,
+    },
+    /// `pinch_zoom_time_visible`
+    PinchZoom {
+        factor: f64,
+        anchor_px: f64,
+        min_span_absolute: f64,
+    },
+    /// `start_kinetic_pan`
+    StartKineticPan { velocity_time_per_sec: f64 },
+    /// `step_kinetic_pan`
+    StepKineticPan { delta_seconds: f64 },
+    /// `set_crosshair_mode`
+    SetCrosshairMode { mode: TraceCrosshairMode },
+    /// `pointer_move`
+    PointerMove { x: f64, y: f64 },
+    /// `pointer_leave`
+    PointerLeave,
+    /// `set_time_visible_range`
+    SetTimeVisibleRange { start: f64, end: f64 },
+    /// `pan_start`
+    PanStart,
+    /// `pan_end`
+    PanEnd,
+    /// `pan_time_visible_by_pixels`
+    PanByPixels { delta_px: f64 },
+    /// `axis_drag_pan_price`
+    AxisDragPanPrice {
+        drag_delta_y_px: f64,
+        anchor_y_px: f64,
+    },
+    /// `axis_drag_scale_price`
+    AxisDragScalePrice {
+        drag_delta_y_px: f64,
+        anchor_y_px: f64,
+        zoom_step_ratio: f64,
+        min_span_absolute: f64,
+    },
+    /// `axis_drag_scale_time`
+    AxisDragScaleTime {
+        drag_delta_x_px: f64,
+        anchor_x_px: f64,
+        zoom_step_ratio: f64,
+        min_span_absolute: f64,
+    },
+    /// `axis_double_click_reset_price_scale`
+    AxisDoubleClickResetPrice,
+    /// `axis_double_click_reset_time_scale`
+    AxisDoubleClickResetTime,
+}
+
+/// Observable engine state after a step. `None` fields are not checked.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default)]
+pub struct InteractionExpectation {
+    #[serde(default)]
+    pub visible_start: Option<f64>,
+    #[serde(default)]
+    pub visible_end: Option<f64>,
+    #[serde(default)]
+    pub visible_span: Option<f64>,
+    #[serde(default)]
+    pub scroll_position_bars: Option<f64>,
+    #[serde(default)]
+    pub kinetic_active: Option<bool>,
+    #[serde(default)]
+    pub kinetic_velocity_time_per_sec: Option<f64>,
+    #[serde(default)]
+    pub crosshair_visible: Option<bool>,
+    #[serde(default)]
+    pub crosshair_x: Option<f64>,
+    #[serde(default)]
+    pub crosshair_y: Option<f64>,
+    #[serde(default)]
+    pub crosshair_snapped_x: Option<f64>,
+    #[serde(default)]
+    pub crosshair_snapped_y: Option<f64>,
+    #[serde(default)]
+    pub crosshair_snapped_time: Option<f64>,
+    #[serde(default)]
+    pub crosshair_snapped_price: Option<f64>,
+}
+
+/// One expectation field that replay could not reproduce.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TraceMismatch {
+    pub scenario_id: String,
+    pub step_index: usize,
+    pub field: &'static str,
+    pub expected: String,
+    pub actual: String,
+}
+
+/// Outcome of replaying a trace or scenario.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ReplayReport {
+    pub scenarios: usize,
+    pub steps: usize,
+    pub mismatches: Vec<TraceMismatch>,
+}
+
+impl ReplayReport {
+    #[must_use]
+    pub fn is_match(&self) -> bool {
+        self.mismatches.is_empty()
+    }
+}
+
+impl InteractionTrace {
+    pub fn from_json_str(input: &str) -> ChartResult<Self> {
+        let trace: Self = serde_json::from_str(input).map_err(|e| {
+            ChartError::InvalidData(format!("failed to parse interaction trace: {e}"))
+        })?;
+        if !trace.tolerance.is_finite() || trace.tolerance < 0.0 {
+            return Err(ChartError::InvalidData(
+                "interaction trace tolerance must be finite and >= 0".to_owned(),
+            ));
+        }
+        Ok(trace)
+    }
+
+    pub fn to_json_pretty(&self) -> ChartResult<String> {
+        serde_json::to_string_pretty(self).map_err(|e| {
+            ChartError::InvalidData(format!("failed to serialize interaction trace: {e}"))
+        })
+    }
+
+    /// Builds the headless engine every scenario of this trace starts from.
+    pub fn build_engine(&self) -> ChartResult<ChartEngine<NullRenderer>> {
+        let config = ChartEngineConfig::new(
+            Viewport::new(self.viewport.width, self.viewport.height),
+            self.time_range.start,
+            self.time_range.end,
+        )
+        .with_price_domain(self.price_range.min, self.price_range.max);
+        ChartEngine::new(NullRenderer::default(), config)
+    }
+
+    /// Replays every scenario on a fresh engine from `build_engine`.
+    pub fn replay(&self) -> ChartResult<ReplayReport> {
+        let mut report = ReplayReport::default();
+        for scenario in &self.scenarios {
+            let mut engine = self.build_engine()?;
+            let scenario_report =
+                replay_interaction_scenario(&mut engine, scenario, self.tolerance)?;
+            report.scenarios += 1;
+            report.steps += scenario_report.steps;
+            report.mismatches.extend(scenario_report.mismatches);
+        }
+        Ok(report)
+    }
+
+    /// Replaces every step expectation with the state this engine produces.
+    ///
+    /// Turns an action-only trace (for example a hand-written reproduction)
+    /// into a regression fixture.
+    pub fn record_expectations(&mut self) -> ChartResult<()> {
+        for index in 0..self.scenarios.len() {
+            let mut engine = self.build_engine()?;
+            self.scenarios[index].install_points(&mut engine);
+            for step in &mut self.scenarios[index].steps {
+                apply_interaction_action(&mut engine, step.action)?;
+                step.expect = Some(observe_interaction_state(&engine));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl InteractionScenario {
+    #[must_use]
+    pub fn data_points(&self) -> Vec<DataPoint> {
+        self.points
+            .iter()
+            .map(|point| DataPoint::new(point.time, point.value))
+            .collect()
+    }
+
+    fn install_points<R: Renderer>(&self, engine: &mut ChartEngine<R>) {
+        if !self.points.is_empty() {
+            engine.set_data(self.data_points());
+        }
+    }
+}
+
+/// Applies one traced interaction to `engine`.
+pub fn apply_interaction_action<R: Renderer>(
+    engine: &mut ChartEngine<R>,
+    action: InteractionAction,
+) -> ChartResult<()> {
+    match action {
+        InteractionAction::SetNavigation {
+            right_offset_bars,
+            bar_spacing_px,
+        } => engine.set_time_scale_navigation_behavior(TimeScaleNavigationBehavior {
+            right_offset_bars,
+            bar_spacing_px,
+        })?,
+        InteractionAction::WheelPan {
+            wheel_delta_x,
+            pan_step_ratio,
+        } => {
+            engine.wheel_pan_time_visible(wheel_delta_x, pan_step_ratio)?;
+        }
+        InteractionAction::TouchDragPan {
+            delta_x_px,
+            delta_y_px,
+        } => {
+            engine.touch_drag_pan_time_visible(delta_x_px, delta_y_px)?;
+        }
+        InteractionAction::WheelZoom {
+            wheel_delta_y,
+            anchor_px,
+            zoom_step_ratio,
+            min_span_absolute,
+        } => {
+            engine.wheel_zoom_time_visible(
+                wheel_delta_y,
+                anchor_px,
+                zoom_step_ratio,
+                min_span_absolute,
+            )?;
+        }
+        InteractionAction::PinchZoom {
+            factor,
+            anchor_px,
+            min_span_absolute,
+        } => {
+            engine.pinch_zoom_time_visible(factor, anchor_px, min_span_absolute)?;
+        }
+        InteractionAction::StartKineticPan {
+            velocity_time_per_sec,
+        } => engine.start_kinetic_pan(velocity_time_per_sec)?,
+        InteractionAction::StepKineticPan { delta_seconds } => {
+            engine.step_kinetic_pan(delta_seconds)?;
+        }
+        InteractionAction::SetCrosshairMode { mode } => engine.set_crosshair_mode(mode.into()),
+        InteractionAction::PointerMove { x, y } => engine.pointer_move(x, y),
+        InteractionAction::PointerLeave => engine.pointer_leave(),
+        InteractionAction::SetTimeVisibleRange { start, end } => {
+            engine.set_time_visible_range(start, end)?;
+        }
+        InteractionAction::PanStart => engine.pan_start(),
+        InteractionAction::PanEnd => engine.pan_end(),
+        InteractionAction::PanByPixels { delta_px } => {
+            engine.pan_time_visible_by_pixels(delta_px)?
+        }
+        InteractionAction::AxisDragPanPrice {
+            drag_delta_y_px,
+            anchor_y_px,
+        } => {
+            engine.axis_drag_pan_price(drag_delta_y_px, anchor_y_px)?;
+        }
+        InteractionAction::AxisDragScalePrice {
+            drag_delta_y_px,
+            anchor_y_px,
+            zoom_step_ratio,
+            min_span_absolute,
+        } => {
+            engine.axis_drag_scale_price(
+                drag_delta_y_px,
+                anchor_y_px,
+                zoom_step_ratio,
+                min_span_absolute,
+            )?;
+        }
+        InteractionAction::AxisDragScaleTime {
+            drag_delta_x_px,
+            anchor_x_px,
+            zoom_step_ratio,
+            min_span_absolute,
+        } => {
+            engine.axis_drag_scale_time(
+                drag_delta_x_px,
+                anchor_x_px,
+                zoom_step_ratio,
+                min_span_absolute,
+            )?;
+        }
+        InteractionAction::AxisDoubleClickResetPrice => {
+            engine.axis_double_click_reset_price_scale()?;
+        }
+        InteractionAction::AxisDoubleClickResetTime => {
+            engine.axis_double_click_reset_time_scale()?;
+        }
+    }
+    Ok(())
+}
+
+/// Captures every expectation field from the current engine state.
+#[must_use]
+pub fn observe_interaction_state<R: Renderer>(engine: &ChartEngine<R>) -> InteractionExpectation {
+    let (visible_start, visible_end) = engine.time_visible_range();
+    let kinetic = engine.kinetic_pan_state();
+    let crosshair = engine.crosshair_state();
+    InteractionExpectation {
+        visible_start: Some(visible_start),
+        visible_end: Some(visible_end),
+        visible_span: Some(visible_end - visible_start),
+        scroll_position_bars: engine.time_scroll_position_bars(),
+        kinetic_active: Some(kinetic.active),
+        kinetic_velocity_time_per_sec: Some(kinetic.velocity_time_per_sec),
+        crosshair_visible: Some(crosshair.visible),
+        crosshair_x: Some(crosshair.x),
+        crosshair_y: Some(crosshair.y),
+        crosshair_snapped_x: crosshair.snapped_x,
+        crosshair_snapped_y: crosshair.snapped_y,
+        crosshair_snapped_time: crosshair.snapped_time,
+        crosshair_snapped_price: crosshair.snapped_price,
+    }
+}
+
+/// Replays `scenario` on a caller-provided engine.
+///
+/// The scenario's points (if any) replace the engine's point series first.
+/// Action errors abort the replay; expectation differences are collected in
+/// the report instead.
+pub fn replay_interaction_scenario<R: Renderer>(
+    engine: &mut ChartEngine<R>,
+    scenario: &InteractionScenario,
+    tolerance: f64,
+) -> ChartResult<ReplayReport> {
+    scenario.install_points(engine);
+    let mut report = ReplayReport {
+        scenarios: 1,
+        ..ReplayReport::default()
+    };
+    for (step_index, step) in scenario.steps.iter().enumerate() {
+        apply_interaction_action(engine, step.action).map_err(|err| {
+            ChartError::InvalidData(format!(
+                "scenario `{}` step {step_index} failed: {err}",
+                scenario.id
+            ))
+        })?;
+        report.steps += 1;
+        if let Some(expected) = step.expect {
+            let actual = observe_interaction_state(engine);
+            compare_expectation(&expected, &actual, tolerance, |field, expected, actual| {
+                report.mismatches.push(TraceMismatch {
+                    scenario_id: scenario.id.clone(),
+                    step_index,
+                    field,
+                    expected,
+                    actual,
+                });
+            });
+        }
+    }
+    Ok(report)
+}
+
+fn compare_expectation(
+    expected: &InteractionExpectation,
+    actual: &InteractionExpectation,
+    tolerance: f64,
+    mut mismatch: impl FnMut(&'static str, String, String),
+) {
+    let mut number = |field: &'static str, expected: Option<f64>, actual: Option<f64>| {
+        let Some(expected) = expected else {
+            return;
+        };
+        match actual {
+            Some(actual) if (actual - expected).abs() <= tolerance => {}
+            _ => mismatch(field, format!("{expected}"), format!("{actual:?}")),
+        }
+    };
+    number(
+        "visible_start",
+        expected.visible_start,
+        actual.visible_start,
+    );
+    number("visible_end", expected.visible_end, actual.visible_end);
+    number("visible_span", expected.visible_span, actual.visible_span);
+    number(
+        "scroll_position_bars",
+        expected.scroll_position_bars,
+        actual.scroll_position_bars,
+    );
+    number(
+        "kinetic_velocity_time_per_sec",
+        expected.kinetic_velocity_time_per_sec,
+        actual.kinetic_velocity_time_per_sec,
+    );
+    number("crosshair_x", expected.crosshair_x, actual.crosshair_x);
+    number("crosshair_y", expected.crosshair_y, actual.crosshair_y);
+    number(
+        "crosshair_snapped_x",
+        expected.crosshair_snapped_x,
+        actual.crosshair_snapped_x,
+    );
+    number(
+        "crosshair_snapped_y",
+        expected.crosshair_snapped_y,
+        actual.crosshair_snapped_y,
+    );
+    number(
+        "crosshair_snapped_time",
+        expected.crosshair_snapped_time,
+        actual.crosshair_snapped_time,
+    );
+    number(
+        "crosshair_snapped_price",
+        expected.crosshair_snapped_price,
+        actual.crosshair_snapped_price,
+    );
+
+    let mut flag = |field: &'static str, expected: Option<bool>, actual: Option<bool>| {
+        if let Some(expected) = expected {
+            if actual != Some(expected) {
+                mismatch(field, format!("{expected}"), format!("{actual:?}"));
+            }
+        }
+    };
+    flag(
+        "kinetic_active",
+        expected.kinetic_active,
+        actual.kinetic_active,
+    );
+    flag(
+        "crosshair_visible",
+        expected.crosshair_visible,
+        actual.crosshair_visible,
+    );
+}
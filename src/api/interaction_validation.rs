@@ -1,6 +1,8 @@
 use crate::error::{ChartError, ChartResult};
 use crate::interaction::KineticPanConfig;
 
+use super::PaneSeparatorBehavior;
+
 pub(super) fn validate_kinetic_pan_config(
     config: KineticPanConfig,
 ) -> ChartResult<KineticPanConfig> {
@@ -19,3 +21,28 @@ pub(super) fn validate_kinetic_pan_config(
     }
     Ok(config)
 }
+
+pub(super) fn validate_crosshair_snap_radius_px(radius_px: Option<f64>) -> ChartResult<()> {
+    if let Some(radius) = radius_px {
+        if !radius.is_finite() || radius < 0.0 {
+            return Err(ChartError::InvalidData(
+                "crosshair snap radius px must be finite and >= 0".to_owned(),
+            ));
+        }
+    }
+    Ok(())
+}
+
+pub(super) fn validate_pane_separator_behavior(behavior: PaneSeparatorBehavior) -> ChartResult<()> {
+    if !behavior.hit_zone_half_height_px.is_finite() || behavior.hit_zone_half_height_px < 0.0 {
+        return Err(ChartError::InvalidData(
+            "pane separator hit zone half height px must be finite and >= 0".to_owned(),
+        ));
+    }
+    if !behavior.min_pane_height_px.is_finite() || behavior.min_pane_height_px < 0.0 {
+        return Err(ChartError::InvalidData(
+            "pane separator min pane height px must be finite and >= 0".to_owned(),
+        ));
+    }
+    Ok(())
+}
@@ -39,10 +39,11 @@ impl EngineSnapshot {
             ChartError::InvalidData(format!("failed to parse snapshot json payload: {e}"))
         })?;
         if payload.schema_version != ENGINE_SNAPSHOT_JSON_SCHEMA_V1 {
-            return Err(ChartError::InvalidData(format!(
-                "unsupported snapshot schema version: {}",
-                payload.schema_version
-            )));
+            return Err(ChartError::SchemaVersionMismatch {
+                schema: "snapshot",
+                expected: ENGINE_SNAPSHOT_JSON_SCHEMA_V1,
+                found: payload.schema_version,
+            });
         }
         Ok(payload.snapshot)
     }
@@ -76,10 +77,11 @@ impl CrosshairFormatterDiagnostics {
                 ChartError::InvalidData(format!("failed to parse diagnostics json payload: {e}"))
             })?;
         if payload.schema_version != CROSSHAIR_DIAGNOSTICS_JSON_SCHEMA_V1 {
-            return Err(ChartError::InvalidData(format!(
-                "unsupported crosshair diagnostics schema version: {}",
-                payload.schema_version
-            )));
+            return Err(ChartError::SchemaVersionMismatch {
+                schema: "crosshair diagnostics",
+                expected: CROSSHAIR_DIAGNOSTICS_JSON_SCHEMA_V1,
+                found: payload.schema_version,
+            });
         }
         Ok(payload.diagnostics)
     }
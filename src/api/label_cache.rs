@@ -1,9 +1,13 @@
 use std::collections::HashMap;
+use std::hash::Hash;
 use std::sync::Arc;
 
 use serde::{Deserialize, Serialize};
 
-use super::{AxisLabelLocale, PriceAxisLabelPolicy, TimeAxisSessionConfig, TimeAxisTimeZone};
+use super::{
+    AxisLabelLocale, PriceAxisLabelPolicy, ScientificNotation, TimeAxisSessionConfig,
+    TimeAxisTimeUnit, TimeAxisTimeZone,
+};
 
 pub type TimeLabelFormatterFn = Arc<dyn Fn(f64) -> String + Send + Sync + 'static>;
 pub type PriceLabelFormatterFn = Arc<dyn Fn(f64) -> String + Send + Sync + 'static>;
@@ -13,6 +17,11 @@ pub struct TimeLabelCacheStats {
     pub hits: u64,
     pub misses: u64,
     pub size: usize,
+    /// Least recently used labels dropped to stay within `capacity`.
+    #[serde(default)]
+    pub evictions: u64,
+    #[serde(default)]
+    pub capacity: usize,
 }
 
 /// Runtime metrics exposed by the in-engine price-label cache.
@@ -21,6 +30,11 @@ pub struct PriceLabelCacheStats {
     pub hits: u64,
     pub misses: u64,
     pub size: usize,
+    /// Least recently used labels dropped to stay within `capacity`.
+    #[serde(default)]
+    pub evictions: u64,
+    #[serde(default)]
+    pub capacity: usize,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -28,8 +42,17 @@ pub(super) enum TimeLabelPattern {
     Date,
     DateMinute,
     DateSecond,
+    DateMillisecond,
     TimeMinute,
     TimeSecond,
+    TimeMillisecond,
+}
+
+impl TimeLabelPattern {
+    #[must_use]
+    pub(super) fn is_sub_second(self) -> bool {
+        matches!(self, Self::DateMillisecond | Self::TimeMillisecond)
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -42,6 +65,13 @@ pub(super) enum PriceLabelCachePolicy {
         trim_trailing_zeros: bool,
     },
     Adaptive,
+    Compact {
+        significant_digits: u8,
+    },
+    Scientific {
+        significant_digits: u8,
+        notation: ScientificNotation,
+    },
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -55,6 +85,7 @@ pub(super) enum TimeLabelCacheProfile {
         pattern: TimeLabelPattern,
         timezone: TimeAxisTimeZone,
         session: Option<TimeAxisSessionConfig>,
+        time_unit: TimeAxisTimeUnit,
     },
     Custom {
         formatter_generation: u64,
@@ -76,6 +107,22 @@ pub(super) enum PriceLabelCacheProfile {
     },
 }
 
+impl PriceLabelCacheProfile {
+    /// Cache key of a display price. Significant-digit policies label values
+    /// far below a nano or past the `i64` nano range, so they key on the exact
+    /// bits instead of the nano quantization.
+    pub(super) fn display_price_key(self, display_price: f64) -> i64 {
+        match self {
+            Self::BuiltIn {
+                policy:
+                    PriceLabelCachePolicy::Compact { .. } | PriceLabelCachePolicy::Scientific { .. },
+                ..
+            } => display_price.to_bits() as i64,
+            Self::BuiltIn { .. } | Self::Custom { .. } => quantize_price_label_value(display_price),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub(super) struct TimeLabelCacheKey {
     pub(super) profile: TimeLabelCacheProfile,
@@ -85,41 +132,179 @@ pub(super) struct TimeLabelCacheKey {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub(super) struct PriceLabelCacheKey {
     pub(super) profile: PriceLabelCacheProfile,
-    pub(super) display_price_nanos: i64,
+    pub(super) display_price_key: i64,
     pub(super) tick_step_nanos: i64,
     pub(super) has_percent_suffix: bool,
 }
 
+/// Entries each label cache keeps before evicting its least recently used
+/// label.
+pub const DEFAULT_LABEL_CACHE_CAPACITY: usize = 8192;
+
+const NO_SLOT: usize = usize::MAX;
+
+#[derive(Debug)]
+struct LruSlot<K> {
+    key: K,
+    value: Arc<str>,
+    prev: usize,
+    next: usize,
+}
+
+/// Fixed-capacity LRU map over a slot vector. A full cache evicts one entry
+/// per insert and reuses its slot, so eviction cost stays flat instead of
+/// spiking on a clear-all.
+#[derive(Debug)]
+struct LabelLru<K> {
+    index: HashMap<K, usize>,
+    slots: Vec<LruSlot<K>>,
+    /// Most recently used slot.
+    head: usize,
+    /// Least recently used slot, evicted first.
+    tail: usize,
+    capacity: usize,
+    evictions: u64,
+}
+
+impl<K> Default for LabelLru<K> {
+    fn default() -> Self {
+        Self {
+            index: HashMap::new(),
+            slots: Vec::new(),
+            head: NO_SLOT,
+            tail: NO_SLOT,
+            capacity: DEFAULT_LABEL_CACHE_CAPACITY,
+            evictions: 0,
+        }
+    }
+}
+
+impl<K: Copy + Eq + Hash> LabelLru<K> {
+    fn get(&mut self, key: K) -> Option<Arc<str>> {
+        let slot = *self.index.get(&key)?;
+        self.touch(slot);
+        Some(Arc::clone(&self.slots[slot].value))
+    }
+
+    fn insert(&mut self, key: K, value: Arc<str>) {
+        if let Some(&slot) = self.index.get(&key) {
+            self.slots[slot].value = value;
+            self.touch(slot);
+            return;
+        }
+        let slot = if self.slots.len() < self.capacity {
+            self.slots.push(LruSlot {
+                key,
+                value,
+                prev: NO_SLOT,
+                next: NO_SLOT,
+            });
+            self.slots.len() - 1
+        } else {
+            let slot = self.tail;
+            self.unlink(slot);
+            self.index.remove(&self.slots[slot].key);
+            self.slots[slot].key = key;
+            self.slots[slot].value = value;
+            self.evictions = self.evictions.saturating_add(1);
+            slot
+        };
+        self.index.insert(key, slot);
+        self.push_front(slot);
+    }
+
+    fn len(&self) -> usize {
+        self.index.len()
+    }
+
+    fn clear(&mut self) {
+        self.index.clear();
+        self.slots.clear();
+        self.head = NO_SLOT;
+        self.tail = NO_SLOT;
+    }
+
+    /// Keeps the `capacity` most recently used entries.
+    fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity;
+        if self.slots.len() <= capacity {
+            return;
+        }
+        let mut recent = Vec::with_capacity(capacity);
+        let mut slot = self.head;
+        while slot != NO_SLOT && recent.len() < capacity {
+            recent.push((self.slots[slot].key, Arc::clone(&self.slots[slot].value)));
+            slot = self.slots[slot].next;
+        }
+        self.evictions = self
+            .evictions
+            .saturating_add((self.slots.len() - recent.len()) as u64);
+        self.clear();
+        for (key, value) in recent.into_iter().rev() {
+            self.insert(key, value);
+        }
+    }
+
+    fn touch(&mut self, slot: usize) {
+        if self.head != slot {
+            self.unlink(slot);
+            self.push_front(slot);
+        }
+    }
+
+    fn unlink(&mut self, slot: usize) {
+        let LruSlot { prev, next, .. } = self.slots[slot];
+        if prev == NO_SLOT {
+            self.head = next;
+        } else {
+            self.slots[prev].next = next;
+        }
+        if next == NO_SLOT {
+            self.tail = prev;
+        } else {
+            self.slots[next].prev = prev;
+        }
+    }
+
+    fn push_front(&mut self, slot: usize) {
+        self.slots[slot].prev = NO_SLOT;
+        self.slots[slot].next = self.head;
+        if self.head == NO_SLOT {
+            self.tail = slot;
+        } else {
+            self.slots[self.head].prev = slot;
+        }
+        self.head = slot;
+    }
+}
+
+/// Labels are `Arc<str>` so a cache hit hands the same allocation to every
+/// frame's `TextPrimitive` instead of cloning the string.
 #[derive(Debug, Default)]
 pub(super) struct TimeLabelCache {
-    entries: HashMap<TimeLabelCacheKey, String>,
+    entries: LabelLru<TimeLabelCacheKey>,
     hits: u64,
     misses: u64,
 }
 
 #[derive(Debug, Default)]
 pub(super) struct PriceLabelCache {
-    entries: HashMap<PriceLabelCacheKey, String>,
+    entries: LabelLru<PriceLabelCacheKey>,
     hits: u64,
     misses: u64,
 }
 
 impl TimeLabelCache {
-    const MAX_ENTRIES: usize = 8192;
-
-    pub(super) fn get(&mut self, key: TimeLabelCacheKey) -> Option<String> {
-        let value = self.entries.get(&key).cloned();
+    pub(super) fn get(&mut self, key: TimeLabelCacheKey) -> Option<Arc<str>> {
+        let value = self.entries.get(key);
         if value.is_some() {
             self.hits = self.hits.saturating_add(1);
         }
         value
     }
 
-    pub(super) fn insert(&mut self, key: TimeLabelCacheKey, value: String) {
+    pub(super) fn insert(&mut self, key: TimeLabelCacheKey, value: Arc<str>) {
         self.misses = self.misses.saturating_add(1);
-        if self.entries.len() >= Self::MAX_ENTRIES {
-            self.entries.clear();
-        }
         self.entries.insert(key, value);
     }
 
@@ -127,31 +312,32 @@ impl TimeLabelCache {
         self.entries.clear();
     }
 
+    pub(super) fn set_capacity(&mut self, capacity: usize) {
+        self.entries.set_capacity(capacity);
+    }
+
     pub(super) fn stats(&self) -> TimeLabelCacheStats {
         TimeLabelCacheStats {
             hits: self.hits,
             misses: self.misses,
             size: self.entries.len(),
+            evictions: self.entries.evictions,
+            capacity: self.entries.capacity,
         }
     }
 }
 
 impl PriceLabelCache {
-    const MAX_ENTRIES: usize = 8192;
-
-    pub(super) fn get(&mut self, key: PriceLabelCacheKey) -> Option<String> {
-        let value = self.entries.get(&key).cloned();
+    pub(super) fn get(&mut self, key: PriceLabelCacheKey) -> Option<Arc<str>> {
+        let value = self.entries.get(key);
         if value.is_some() {
             self.hits = self.hits.saturating_add(1);
         }
         value
     }
 
-    pub(super) fn insert(&mut self, key: PriceLabelCacheKey, value: String) {
+    pub(super) fn insert(&mut self, key: PriceLabelCacheKey, value: Arc<str>) {
         self.misses = self.misses.saturating_add(1);
-        if self.entries.len() >= Self::MAX_ENTRIES {
-            self.entries.clear();
-        }
         self.entries.insert(key, value);
     }
 
@@ -159,11 +345,17 @@ impl PriceLabelCache {
         self.entries.clear();
     }
 
+    pub(super) fn set_capacity(&mut self, capacity: usize) {
+        self.entries.set_capacity(capacity);
+    }
+
     pub(super) fn stats(&self) -> PriceLabelCacheStats {
         PriceLabelCacheStats {
             hits: self.hits,
             misses: self.misses,
             size: self.entries.len(),
+            evictions: self.entries.evictions,
+            capacity: self.entries.capacity,
         }
     }
 }
@@ -181,6 +373,16 @@ pub(super) fn price_policy_profile(policy: PriceAxisLabelPolicy) -> PriceLabelCa
             trim_trailing_zeros,
         },
         PriceAxisLabelPolicy::Adaptive => PriceLabelCachePolicy::Adaptive,
+        PriceAxisLabelPolicy::Compact { significant_digits } => {
+            PriceLabelCachePolicy::Compact { significant_digits }
+        }
+        PriceAxisLabelPolicy::Scientific {
+            significant_digits,
+            notation,
+        } => PriceLabelCachePolicy::Scientific {
+            significant_digits,
+            notation,
+        },
     }
 }
 
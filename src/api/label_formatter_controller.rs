@@ -1,9 +1,11 @@
+use crate::error::{ChartError, ChartResult};
 use crate::render::Renderer;
 
 use super::{
     ChartEngine, CrosshairFormatterDiagnostics, CrosshairFormatterOverrideMode,
-    CrosshairPriceLabelFormatterWithContextFn, CrosshairTimeLabelFormatterWithContextFn,
-    PriceLabelCacheStats, PriceLabelFormatterFn, TimeLabelCacheStats, TimeLabelFormatterFn,
+    CrosshairLabelTemplate, CrosshairPriceLabelFormatterWithContextFn,
+    CrosshairTimeLabelFormatterWithContextFn, PriceLabelCacheStats, PriceLabelFormatterFn,
+    TimeLabelCacheStats, TimeLabelFormatterFn,
 };
 
 impl<R: Renderer> ChartEngine<R> {
@@ -361,6 +363,53 @@ impl<R: Renderer> ChartEngine<R> {
             .clear();
     }
 
+    /// Sets the content template for the crosshair time-axis label, or
+    /// clears it with `None`.
+    ///
+    /// The template replaces the whole label text; its `{time}` and
+    /// `{price}` parts still go through the crosshair formatters and caches.
+    pub fn set_crosshair_time_label_template(
+        &mut self,
+        template: Option<CrosshairLabelTemplate>,
+    ) -> ChartResult<()> {
+        if let Some(template) = &template {
+            template.validate()?;
+        }
+        self.core.presentation.crosshair_time_label_template = template;
+        self.invalidate_cursor();
+        Ok(())
+    }
+
+    #[must_use]
+    pub fn crosshair_time_label_template(&self) -> Option<&CrosshairLabelTemplate> {
+        self.core
+            .presentation
+            .crosshair_time_label_template
+            .as_ref()
+    }
+
+    /// Sets the content template for the crosshair price-axis label, or
+    /// clears it with `None`.
+    pub fn set_crosshair_price_label_template(
+        &mut self,
+        template: Option<CrosshairLabelTemplate>,
+    ) -> ChartResult<()> {
+        if let Some(template) = &template {
+            template.validate()?;
+        }
+        self.core.presentation.crosshair_price_label_template = template;
+        self.invalidate_cursor();
+        Ok(())
+    }
+
+    #[must_use]
+    pub fn crosshair_price_label_template(&self) -> Option<&CrosshairLabelTemplate> {
+        self.core
+            .presentation
+            .crosshair_price_label_template
+            .as_ref()
+    }
+
     #[must_use]
     pub fn time_label_cache_stats(&self) -> TimeLabelCacheStats {
         self.core.presentation.time_label_cache.borrow().stats()
@@ -384,4 +433,40 @@ impl<R: Renderer> ChartEngine<R> {
             .borrow_mut()
             .clear();
     }
+
+    /// Entries each axis and crosshair label cache keeps.
+    #[must_use]
+    pub fn label_cache_capacity(&self) -> usize {
+        self.time_label_cache_stats().capacity
+    }
+
+    /// Sets how many labels each axis and crosshair label cache keeps before
+    /// evicting the least recently used one. Shrinking keeps the most
+    /// recently used labels; `evictions` in the cache stats counts the rest.
+    pub fn set_label_cache_capacity(&mut self, capacity: usize) -> ChartResult<()> {
+        if capacity == 0 {
+            return Err(ChartError::InvalidCapacity {
+                field: "label cache",
+                capacity,
+            });
+        }
+        let presentation = &self.core.presentation;
+        presentation
+            .time_label_cache
+            .borrow_mut()
+            .set_capacity(capacity);
+        presentation
+            .price_label_cache
+            .borrow_mut()
+            .set_capacity(capacity);
+        presentation
+            .crosshair_time_label_cache
+            .borrow_mut()
+            .set_capacity(capacity);
+        presentation
+            .crosshair_price_label_cache
+            .borrow_mut()
+            .set_capacity(capacity);
+        Ok(())
+    }
 }
@@ -1,17 +1,56 @@
+use std::sync::Arc;
+
+use crate::core::ChartTimeKind;
+use crate::interaction::CrosshairSnapSeries;
 use crate::render::Renderer;
 
 use super::axis_label_format::{
     ResolvedTimeLabelPattern, format_price_axis_label, format_price_axis_label_with_precision,
+    format_relative_time_offset, format_signed_percent, format_time_axis_date_label,
     format_time_axis_label, format_time_axis_label_with_precision, format_time_axis_tick_label,
-    quantize_logical_time_millis, quantize_price_label_value, resolve_time_axis_tick_pattern,
+    format_time_with_pattern, map_price_to_display_value, quantize_logical_time_millis,
+    quantize_price_label_value, resolve_time_axis_tick_pattern,
 };
+use super::axis_render_frame_builder::AxisPriceDisplayContext;
 use super::label_cache::{PriceLabelCacheKey, TimeLabelCacheKey, TimeLabelCacheProfile};
 use super::{
-    ChartEngine, CrosshairLabelSourceMode, CrosshairPriceLabelFormatterContext,
-    CrosshairTimeLabelFormatterContext,
+    ChartEngine, CrosshairLabelSourceMode, CrosshairLabelTemplate, CrosshairLabelTemplatePart,
+    CrosshairPriceLabelFormatterContext, CrosshairTimeLabelFormatterContext,
+    CrosshairTimeLabelMode, PriceAxisLabelConfig, PriceAxisLabelPolicy, PriceAxisSecondaryLabel,
+    RelativeTimeAnchor, SeriesRef, SeriesValueInterpolation, TimeAxisLabelConfig,
+    TimeAxisLabelPolicy,
 };
 
+/// Crosshair position and label settings a `CrosshairLabelTemplate` draws
+/// its placeholders from.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct CrosshairLabelTemplateValues {
+    pub time: f64,
+    pub time_precision: Option<u8>,
+    pub time_source_mode: CrosshairLabelSourceMode,
+    pub price: f64,
+    pub price_precision: Option<u8>,
+    pub price_source_mode: CrosshairLabelSourceMode,
+    pub visible_span_abs: f64,
+    pub display: AxisPriceDisplayContext,
+}
+
 impl<R: Renderer> ChartEngine<R> {
+    /// Time-axis label config adjusted to the original data time type:
+    /// business-day data never shows intraday clock times.
+    pub(super) fn effective_time_axis_label_config(&self) -> TimeAxisLabelConfig {
+        let mut config = self.core.behavior.time_axis_label_config;
+        if self.core.model.time_kind == Some(ChartTimeKind::BusinessDay)
+            && matches!(
+                config.policy,
+                TimeAxisLabelPolicy::UtcDateTime { .. } | TimeAxisLabelPolicy::UtcAdaptive
+            )
+        {
+            config.policy = TimeAxisLabelPolicy::UtcDate;
+        }
+        config
+    }
+
     fn crosshair_source_mode_tag(source_mode: CrosshairLabelSourceMode) -> u8 {
         match source_mode {
             CrosshairLabelSourceMode::SnappedData => 1,
@@ -48,7 +87,11 @@ impl<R: Renderer> ChartEngine<R> {
         transformed
     }
 
-    fn format_time_axis_label(&self, logical_time: f64, visible_span_abs: f64) -> String {
+    pub(super) fn format_time_axis_label(
+        &self,
+        logical_time: f64,
+        visible_span_abs: f64,
+    ) -> Arc<str> {
         let profile = self.resolve_time_label_cache_profile(visible_span_abs);
         let key = TimeLabelCacheKey {
             profile,
@@ -70,15 +113,16 @@ impl<R: Renderer> ChartEngine<R> {
         } else {
             format_time_axis_label(
                 logical_time,
-                self.core.behavior.time_axis_label_config,
+                self.effective_time_axis_label_config(),
                 visible_span_abs,
             )
         };
+        let value: Arc<str> = value.into();
         self.core
             .presentation
             .time_label_cache
             .borrow_mut()
-            .insert(key, value.clone());
+            .insert(key, Arc::clone(&value));
         value
     }
 
@@ -88,7 +132,7 @@ impl<R: Renderer> ChartEngine<R> {
         visible_span_abs: f64,
         tick_step_abs: f64,
         is_major_tick: bool,
-    ) -> String {
+    ) -> Arc<str> {
         let profile = if self.core.presentation.time_label_formatter.is_some() {
             TimeLabelCacheProfile::Custom {
                 formatter_generation: self.core.presentation.time_label_formatter_generation,
@@ -96,8 +140,9 @@ impl<R: Renderer> ChartEngine<R> {
                 visible_span_millis: 0,
             }
         } else {
+            let config = self.effective_time_axis_label_config();
             match resolve_time_axis_tick_pattern(
-                self.core.behavior.time_axis_label_config.policy,
+                config,
                 visible_span_abs,
                 tick_step_abs,
                 is_major_tick,
@@ -105,14 +150,15 @@ impl<R: Renderer> ChartEngine<R> {
                 ResolvedTimeLabelPattern::LogicalDecimal { precision } => {
                     TimeLabelCacheProfile::LogicalDecimal {
                         precision,
-                        locale: self.core.behavior.time_axis_label_config.locale,
+                        locale: config.locale,
                     }
                 }
                 ResolvedTimeLabelPattern::Utc { pattern } => TimeLabelCacheProfile::Utc {
-                    locale: self.core.behavior.time_axis_label_config.locale,
+                    locale: config.locale,
                     pattern,
-                    timezone: self.core.behavior.time_axis_label_config.timezone,
-                    session: self.core.behavior.time_axis_label_config.session,
+                    timezone: config.timezone,
+                    session: config.session,
+                    time_unit: config.time_unit,
                 },
             }
         };
@@ -135,30 +181,97 @@ impl<R: Renderer> ChartEngine<R> {
         } else {
             format_time_axis_tick_label(
                 logical_time,
-                self.core.behavior.time_axis_label_config,
+                self.effective_time_axis_label_config(),
                 visible_span_abs,
                 tick_step_abs,
                 is_major_tick,
             )
         };
+        let value: Arc<str> = value.into();
         self.core
             .presentation
             .time_label_cache
             .borrow_mut()
-            .insert(key, value.clone());
+            .insert(key, Arc::clone(&value));
         value
     }
 
+    /// Date label for the sticky left-edge time-axis label; custom time
+    /// formatters take precedence over the built-in date pattern.
+    pub(super) fn format_time_axis_date_label(&self, logical_time: f64) -> Option<String> {
+        if let Some(formatter) = &self.core.presentation.time_label_formatter {
+            return Some(formatter(logical_time));
+        }
+        format_time_axis_date_label(logical_time, self.effective_time_axis_label_config())
+    }
+
+    /// Style prefix and suffix wrapped around every price-axis, last-price
+    /// and crosshair price label.
+    fn price_label_affixes(&self) -> (&str, &str) {
+        let style = &self.core.presentation.render_style;
+        (&style.price_label_prefix, &style.price_label_suffix)
+    }
+
+    fn decorate_price_label(&self, mut text: String, mode_suffix: &str) -> String {
+        text.push_str(mode_suffix);
+        let (prefix, suffix) = self.price_label_affixes();
+        Self::apply_crosshair_label_text_transform(text, prefix, suffix)
+    }
+
+    /// Price the secondary tick labels measure from, or `None` when they are
+    /// hidden or the last price is unknown or zero.
+    pub(super) fn resolve_price_axis_secondary_base_price(&self) -> Option<f64> {
+        let base_price = match self.core.behavior.price_axis_label_config.secondary_label {
+            PriceAxisSecondaryLabel::Hidden => return None,
+            PriceAxisSecondaryLabel::PercentFromBase { base_price } => base_price,
+            PriceAxisSecondaryLabel::PercentFromLastPrice => {
+                let (visible_start, visible_end) = self.core.model.time_scale.visible_range();
+                self.resolve_latest_and_previous_price_values(
+                    self.core.presentation.render_style.last_price_source_mode,
+                    visible_start,
+                    visible_end,
+                )?
+                .0
+            }
+        };
+        (base_price.is_finite() && base_price != 0.0).then_some(base_price)
+    }
+
+    /// Secondary tick label, e.g. `+1.25%` for a tick 1.25% above the base.
+    pub(super) fn format_price_axis_secondary_label(&self, price: f64, base_price: f64) -> String {
+        format_signed_percent(
+            (price - base_price) / base_price.abs() * 100.0,
+            self.core.behavior.price_axis_label_config.locale,
+        )
+    }
+
     pub(super) fn format_price_axis_label(
         &self,
         display_price: f64,
         tick_step_abs: f64,
         mode_suffix: &str,
-    ) -> String {
-        let profile = self.resolve_price_label_cache_profile();
+    ) -> Arc<str> {
+        self.format_price_label_with_policy(
+            display_price,
+            tick_step_abs,
+            mode_suffix,
+            self.price_axis_label_policy(),
+        )
+    }
+
+    /// Price label text with `policy` in place of the axis policy, sharing
+    /// the price-label cache (the policy is part of the cache key).
+    pub(super) fn format_price_label_with_policy(
+        &self,
+        display_price: f64,
+        tick_step_abs: f64,
+        mode_suffix: &str,
+        policy: PriceAxisLabelPolicy,
+    ) -> Arc<str> {
+        let profile = self.resolve_price_label_cache_profile(policy);
         let key = PriceLabelCacheKey {
             profile,
-            display_price_nanos: quantize_price_label_value(display_price),
+            display_price_key: profile.display_price_key(display_price),
             tick_step_nanos: quantize_price_label_value(tick_step_abs),
             has_percent_suffix: !mode_suffix.is_empty(),
         };
@@ -173,26 +286,57 @@ impl<R: Renderer> ChartEngine<R> {
             return cached;
         }
 
-        let mut text = if let Some(formatter) = &self.core.presentation.price_label_formatter {
+        let text = if let Some(formatter) = &self.core.presentation.price_label_formatter {
             formatter(display_price)
         } else {
             format_price_axis_label(
                 display_price,
-                self.core.behavior.price_axis_label_config,
+                PriceAxisLabelConfig {
+                    policy,
+                    ..self.core.behavior.price_axis_label_config
+                },
                 tick_step_abs,
             )
         };
-        if !mode_suffix.is_empty() {
-            text.push_str(mode_suffix);
-        }
+        let text: Arc<str> = self.decorate_price_label(text, mode_suffix).into();
         self.core
             .presentation
             .price_label_cache
             .borrow_mut()
-            .insert(key, text.clone());
+            .insert(key, Arc::clone(&text));
         text
     }
 
+    /// Time text for non-visual consumers such as `a11y`: formatted like
+    /// the crosshair time label, without prefix/suffix.
+    pub(crate) fn format_time_for_description(&self, logical_time: f64) -> String {
+        let (start, end) = self.core.model.time_scale.visible_range();
+        self.format_crosshair_time_axis_label(
+            logical_time,
+            (end - start).abs(),
+            None,
+            CrosshairLabelSourceMode::SnappedData,
+        )
+    }
+
+    /// Raw price text for non-visual consumers such as `a11y`. Uses the
+    /// custom price formatter or axis label config, ignoring percentage and
+    /// indexed display modes.
+    pub(crate) fn format_price_for_description(&self, price: f64) -> String {
+        if let Some(formatter) = &self.core.presentation.price_label_formatter {
+            return formatter(price);
+        }
+        let (domain_start, domain_end) = self.core.model.price_scale.domain();
+        format_price_axis_label(
+            price,
+            PriceAxisLabelConfig {
+                policy: self.price_axis_label_policy(),
+                ..self.core.behavior.price_axis_label_config
+            },
+            (domain_end - domain_start).abs() / 10.0,
+        )
+    }
+
     pub(super) fn format_crosshair_time_axis_label(
         &self,
         logical_time: f64,
@@ -223,7 +367,7 @@ impl<R: Renderer> ChartEngine<R> {
                 .borrow_mut()
                 .get(key)
             {
-                return cached;
+                return cached.to_string();
             }
             let value = formatter(
                 logical_time,
@@ -236,7 +380,7 @@ impl<R: Renderer> ChartEngine<R> {
                 .presentation
                 .crosshair_time_label_cache
                 .borrow_mut()
-                .insert(key, value.clone());
+                .insert(key, value.as_str().into());
             value
         } else if let Some(formatter) = &self.core.presentation.crosshair_time_label_formatter {
             let key = TimeLabelCacheKey {
@@ -257,25 +401,156 @@ impl<R: Renderer> ChartEngine<R> {
                 .borrow_mut()
                 .get(key)
             {
-                return cached;
+                return cached.to_string();
             }
             let value = formatter(logical_time);
             self.core
                 .presentation
                 .crosshair_time_label_cache
                 .borrow_mut()
-                .insert(key, value.clone());
+                .insert(key, value.as_str().into());
             value
         } else if let Some(precision) = precision_override {
             format_time_axis_label_with_precision(
                 logical_time,
-                self.core.behavior.time_axis_label_config,
+                self.effective_time_axis_label_config(),
                 visible_span_abs,
                 precision,
             )
         } else {
             self.format_time_axis_label(logical_time, visible_span_abs)
+                .to_string()
+        }
+    }
+
+    /// Crosshair time text for `RenderStyle::crosshair_time_label_mode`,
+    /// built around the already formatted `absolute` timestamp.
+    pub(super) fn apply_crosshair_time_label_mode(
+        &self,
+        absolute: String,
+        logical_time: f64,
+    ) -> String {
+        let (anchor, keep_absolute) = match self
+            .core
+            .presentation
+            .render_style
+            .crosshair_time_label_mode
+        {
+            CrosshairTimeLabelMode::Absolute => return absolute,
+            CrosshairTimeLabelMode::Relative(anchor) => (anchor, false),
+            CrosshairTimeLabelMode::AbsoluteWithRelative(anchor) => (anchor, true),
+        };
+        let time_unit = self.effective_time_axis_label_config().time_unit;
+        let anchor_seconds = match anchor {
+            RelativeTimeAnchor::LatestBar => {
+                let model = &self.core.model;
+                let latest = model
+                    .candles
+                    .last()
+                    .map(|bar| bar.time)
+                    .into_iter()
+                    .chain(model.points.last().map(|point| point.x))
+                    .reduce(f64::max);
+                latest.map(|time| time_unit.to_seconds(time))
+            }
+            RelativeTimeAnchor::Now => self
+                .core
+                .runtime
+                .bar_clock_time
+                .map(|now| time_unit.to_seconds(now)),
+        };
+        let Some(anchor_seconds) = anchor_seconds else {
+            return absolute;
+        };
+        let relative =
+            format_relative_time_offset(anchor_seconds - time_unit.to_seconds(logical_time));
+        if keep_absolute {
+            format!("{absolute} ({relative})")
+        } else {
+            relative
+        }
+    }
+
+    /// Renders a crosshair label template; placeholders without a value
+    /// (e.g. `{value}` before the first sample) render empty.
+    pub(super) fn render_crosshair_label_template(
+        &self,
+        template: &CrosshairLabelTemplate,
+        values: CrosshairLabelTemplateValues,
+    ) -> String {
+        let display_mode = self.resolve_price_axis_display_mode();
+        let display = values.display;
+        let mut text = String::new();
+        for part in template.parts() {
+            match part {
+                CrosshairLabelTemplatePart::Text(literal) => text.push_str(literal),
+                CrosshairLabelTemplatePart::Price => {
+                    text.push_str(&self.format_crosshair_price_axis_label(
+                        map_price_to_display_value(
+                            values.price,
+                            display_mode,
+                            display.fallback_display_base_price,
+                        ),
+                        display.display_tick_step_abs,
+                        display.display_suffix,
+                        values.price_precision,
+                        values.visible_span_abs,
+                        values.price_source_mode,
+                    ));
+                }
+                CrosshairLabelTemplatePart::Time(None) => {
+                    text.push_str(&self.apply_crosshair_time_label_mode(
+                        self.format_crosshair_time_axis_label(
+                            values.time,
+                            values.visible_span_abs,
+                            values.time_precision,
+                            values.time_source_mode,
+                        ),
+                        values.time,
+                    ));
+                }
+                CrosshairLabelTemplatePart::Time(Some(pattern)) => {
+                    text.push_str(&format_time_with_pattern(
+                        values.time,
+                        self.effective_time_axis_label_config(),
+                        pattern,
+                    ));
+                }
+                CrosshairLabelTemplatePart::Change => {
+                    let base = display.fallback_display_base_price;
+                    if base.is_finite() && base != 0.0 {
+                        text.push_str(&format_signed_percent(
+                            (values.price - base) / base.abs() * 100.0,
+                            self.core.behavior.price_axis_label_config.locale,
+                        ));
+                    }
+                }
+                CrosshairLabelTemplatePart::SeriesValue(series_id) => {
+                    let series = match series_id {
+                        Some(id) => SeriesRef::Compare(id),
+                        None if self.core.model.candles.is_empty() => SeriesRef::Points,
+                        None => SeriesRef::Candles,
+                    };
+                    if let Some(value) = self.series_value_at(
+                        series,
+                        values.time,
+                        SeriesValueInterpolation::Previous,
+                    ) {
+                        text.push_str(&self.format_price_label_with_policy(
+                            map_price_to_display_value(
+                                value,
+                                display_mode,
+                                display.fallback_display_base_price,
+                            ),
+                            display.display_tick_step_abs,
+                            display.display_suffix,
+                            self.series_price_label_policy(series),
+                        ));
+                    }
+                }
+            }
         }
+        text
     }
 
     pub(super) fn format_crosshair_price_axis_label(
@@ -301,7 +576,7 @@ impl<R: Renderer> ChartEngine<R> {
                     source_mode_tag: Self::crosshair_source_mode_tag(source_mode),
                     visible_span_millis: Self::quantize_visible_span_millis(visible_span_abs),
                 },
-                display_price_nanos: quantize_price_label_value(display_price),
+                display_price_key: quantize_price_label_value(display_price),
                 tick_step_nanos: quantize_price_label_value(tick_step_abs),
                 has_percent_suffix: !mode_suffix.is_empty(),
             };
@@ -312,23 +587,21 @@ impl<R: Renderer> ChartEngine<R> {
                 .borrow_mut()
                 .get(key)
             {
-                return cached;
+                return cached.to_string();
             }
-            let mut value = formatter(
+            let value = formatter(
                 display_price,
                 CrosshairPriceLabelFormatterContext {
                     visible_span_abs,
                     source_mode,
                 },
             );
-            if !mode_suffix.is_empty() {
-                value.push_str(mode_suffix);
-            }
+            let value = self.decorate_price_label(value, mode_suffix);
             self.core
                 .presentation
                 .crosshair_price_label_cache
                 .borrow_mut()
-                .insert(key, value.clone());
+                .insert(key, value.as_str().into());
             value
         } else if let Some(formatter) = &self.core.presentation.crosshair_price_label_formatter {
             let key = PriceLabelCacheKey {
@@ -340,7 +613,7 @@ impl<R: Renderer> ChartEngine<R> {
                     source_mode_tag: 0,
                     visible_span_millis: 0,
                 },
-                display_price_nanos: quantize_price_label_value(display_price),
+                display_price_key: quantize_price_label_value(display_price),
                 tick_step_nanos: quantize_price_label_value(tick_step_abs),
                 has_percent_suffix: !mode_suffix.is_empty(),
             };
@@ -351,31 +624,38 @@ impl<R: Renderer> ChartEngine<R> {
                 .borrow_mut()
                 .get(key)
             {
-                return cached;
-            }
-            let mut value = formatter(display_price);
-            if !mode_suffix.is_empty() {
-                value.push_str(mode_suffix);
+                return cached.to_string();
             }
+            let value = formatter(display_price);
+            let value = self.decorate_price_label(value, mode_suffix);
             self.core
                 .presentation
                 .crosshair_price_label_cache
                 .borrow_mut()
-                .insert(key, value.clone());
+                .insert(key, value.as_str().into());
             value
         } else if let Some(precision) = precision_override {
-            let mut text = format_price_axis_label_with_precision(
+            let text = format_price_axis_label_with_precision(
                 display_price,
                 self.core.behavior.price_axis_label_config,
                 tick_step_abs,
                 precision,
             );
-            if !mode_suffix.is_empty() {
-                text.push_str(mode_suffix);
-            }
-            text
+            self.decorate_price_label(text, mode_suffix)
         } else {
-            self.format_price_axis_label(display_price, tick_step_abs, mode_suffix)
+            let snapped_series = match self.core.model.interaction.crosshair().snapped_series {
+                Some(CrosshairSnapSeries::Points) => Some(SeriesRef::Points),
+                Some(CrosshairSnapSeries::Candles) => Some(SeriesRef::Candles),
+                None => None,
+            };
+            let policy = match (source_mode, snapped_series) {
+                (CrosshairLabelSourceMode::SnappedData, Some(series)) => {
+                    self.series_price_label_policy(series)
+                }
+                _ => self.price_axis_label_policy(),
+            };
+            self.format_price_label_with_policy(display_price, tick_step_abs, mode_suffix, policy)
+                .to_string()
         }
     }
 }
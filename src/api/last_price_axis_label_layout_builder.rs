@@ -1,10 +1,13 @@
+use std::sync::Arc;
+
 use crate::render::{Color, RectPrimitive};
 
-use super::layout_helpers::estimate_label_text_width_px;
 use super::{LastPriceLabelBoxWidthMode, RenderStyle};
 
 #[derive(Debug, Clone)]
 pub(super) struct LastPriceAxisLabelLayout {
+    /// Text after `label_overflow_policy`; `None` hides the label.
+    pub text: Option<Arc<str>>,
     pub text_y: f64,
     pub text_anchor_x: f64,
     pub box_rect: Option<RectPrimitive>,
@@ -13,13 +16,13 @@ pub(super) struct LastPriceAxisLabelLayout {
 #[derive(Debug, Clone, Copy)]
 pub(super) struct LastPriceAxisLabelLayoutContext<'a> {
     pub marker_py: f64,
-    pub text: &'a str,
+    pub text: &'a Arc<str>,
     pub plot_right: f64,
     pub plot_bottom: f64,
     pub viewport_width: f64,
     pub default_text_anchor_x: f64,
     pub box_fill_color: Color,
-    pub style: RenderStyle,
+    pub style: &'a RenderStyle,
 }
 
 pub(super) fn build_last_price_axis_label_layout(
@@ -42,6 +45,13 @@ pub(super) fn build_last_price_axis_label_layout(
     let axis_panel_width = (viewport_width - axis_panel_left).max(0.0);
     let mut label_text_anchor_x = default_text_anchor_x;
     let mut box_rect = None;
+    // Without a box the text only has the axis panel left of its anchor.
+    let mut fitted_text = style.label_overflow_policy.fit_shared_text(
+        text,
+        default_text_anchor_x - axis_panel_left,
+        style.last_price_label_font_size_px,
+        style.label_text_metrics,
+    );
 
     if style.show_last_price_label_box {
         let min_text_y = style.last_price_label_box_padding_y_px.max(0.0);
@@ -50,8 +60,9 @@ pub(super) fn build_last_price_axis_label_layout(
             - style.last_price_label_box_padding_y_px.max(0.0))
         .max(min_text_y);
         text_y = text_y.clamp(min_text_y, max_text_y);
-        let estimated_text_width =
-            estimate_label_text_width_px(text, style.last_price_label_font_size_px);
+        let estimated_text_width = style
+            .label_text_metrics
+            .text_width_px(text, style.last_price_label_font_size_px);
         // Keep width selection deterministic and backend-independent so snapshots
         // remain stable across null/cairo renderers and CI environments.
         let requested_box_width = match style.last_price_label_box_width_mode {
@@ -61,6 +72,12 @@ pub(super) fn build_last_price_axis_label_layout(
                 .max(style.last_price_label_box_min_width_px),
         };
         let box_width = requested_box_width.clamp(0.0, axis_panel_width);
+        fitted_text = style.label_overflow_policy.fit_shared_text(
+            text,
+            box_width - 2.0 * style.last_price_label_box_padding_x_px,
+            style.last_price_label_font_size_px,
+            style.label_text_metrics,
+        );
         let box_left = (viewport_width - box_width).max(axis_panel_left);
         let box_top = (text_y - style.last_price_label_box_padding_y_px.max(0.0)).max(0.0);
         let box_bottom = (text_y
@@ -70,7 +87,7 @@ pub(super) fn build_last_price_axis_label_layout(
         let box_height = (box_bottom - box_top).max(0.0);
         label_text_anchor_x = (viewport_width - style.last_price_label_box_padding_x_px)
             .clamp(box_left, viewport_width);
-        if box_width > 0.0 && box_height > 0.0 {
+        if box_width > 0.0 && box_height > 0.0 && fitted_text.is_some() {
             let mut rect =
                 RectPrimitive::new(box_left, box_top, box_width, box_height, box_fill_color);
             if style.last_price_label_box_border_width_px > 0.0 {
@@ -91,6 +108,7 @@ pub(super) fn build_last_price_axis_label_layout(
     }
 
     LastPriceAxisLabelLayout {
+        text: fitted_text,
         text_y,
         text_anchor_x: if style.show_last_price_label_box {
             label_text_anchor_x
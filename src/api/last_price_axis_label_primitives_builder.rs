@@ -1,20 +1,48 @@
-use crate::render::{CanvasLayerKind, Renderer, TextHAlign, TextPrimitive};
+use crate::render::{RectPrimitive, Renderer, TextHAlign, TextPrimitive};
 
-use super::ChartEngine;
 use super::axis_label_format::map_price_to_display_value;
-use super::axis_render_frame_builder::AxisPrimitiveSink;
+use super::axis_label_stacking_resolver::AxisLabelSpan;
 use super::last_price_axis_label_layout_builder::{
     LastPriceAxisLabelLayoutContext, build_last_price_axis_label_layout,
 };
 use super::last_price_axis_scene_builder::{LastPriceAxisSceneContext, LastPriceMarker};
+use super::{ChartEngine, RenderStyle};
+
+/// Text and optional box of one last-value label before stacking.
+#[derive(Debug, Clone)]
+pub(super) struct LastPriceAxisLabelPrimitives {
+    pub text: TextPrimitive,
+    pub box_rect: Option<RectPrimitive>,
+}
+
+impl LastPriceAxisLabelPrimitives {
+    pub(super) fn span(&self, style: &RenderStyle) -> AxisLabelSpan {
+        match self.box_rect {
+            Some(rect) => AxisLabelSpan {
+                top: rect.y,
+                height: rect.height,
+            },
+            None => AxisLabelSpan {
+                top: self.text.y,
+                height: style.last_price_label_font_size_px,
+            },
+        }
+    }
+
+    pub(super) fn shift_y(&mut self, offset: f64) {
+        self.text.y += offset;
+        if let Some(rect) = self.box_rect.as_mut() {
+            rect.y += offset;
+        }
+    }
+}
 
 impl<R: Renderer> ChartEngine<R> {
-    pub(super) fn append_last_price_axis_label_primitives(
+    pub(super) fn build_last_price_axis_label_primitives(
         &self,
-        sink: &mut AxisPrimitiveSink<'_>,
         marker: LastPriceMarker,
         ctx: LastPriceAxisSceneContext,
-    ) {
+    ) -> Option<LastPriceAxisLabelPrimitives> {
         let plot_right = ctx.plot_right;
         let plot_bottom = ctx.plot_bottom;
         let viewport_width = ctx.viewport_width;
@@ -24,17 +52,19 @@ impl<R: Renderer> ChartEngine<R> {
         let display_suffix = ctx.display_suffix;
         let style = ctx.style;
 
-        if !style.show_last_price_label {
-            return;
-        }
-
         let display_price = map_price_to_display_value(
             marker.last_price,
-            self.core.behavior.price_axis_label_config.display_mode,
+            self.resolve_price_axis_display_mode(),
             fallback_display_base_price,
         );
-        let text =
-            self.format_price_axis_label(display_price, display_tick_step_abs, display_suffix);
+        let text = self.format_price_label_with_policy(
+            display_price,
+            display_tick_step_abs,
+            display_suffix,
+            marker
+                .price_format
+                .unwrap_or_else(|| self.price_axis_label_policy()),
+        );
         let box_fill_color =
             self.resolve_last_price_label_box_fill_color(marker.marker_label_color);
         let label_text_color =
@@ -50,19 +80,16 @@ impl<R: Renderer> ChartEngine<R> {
             box_fill_color,
             style,
         });
-        if let Some(rect) = layout.box_rect {
-            sink.push_rect(CanvasLayerKind::Axis, rect);
-        }
-        sink.push_text(
-            CanvasLayerKind::Axis,
-            TextPrimitive::new(
-                text,
+        Some(LastPriceAxisLabelPrimitives {
+            text: TextPrimitive::new(
+                layout.text?,
                 layout.text_anchor_x,
                 layout.text_y,
                 style.last_price_label_font_size_px,
                 label_text_color,
                 TextHAlign::Right,
             ),
-        );
+            box_rect: layout.box_rect,
+        })
     }
 }
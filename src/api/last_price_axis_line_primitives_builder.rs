@@ -1,9 +1,15 @@
-use crate::render::{CanvasLayerKind, Renderer};
+use crate::render::{ArrowDirection, ArrowPrimitive, CanvasLayerKind, Renderer};
 
 use super::ChartEngine;
 use super::axis_render_frame_builder::AxisPrimitiveSink;
 use super::last_price_axis_scene_builder::{LastPriceAxisSceneContext, LastPriceMarker};
 
+/// Size of the `LastPriceOffscreenPolicy::EdgeArrow` indicator.
+const EDGE_ARROW_LENGTH_PX: f64 = 6.0;
+const EDGE_ARROW_WIDTH_PX: f64 = 10.0;
+/// Gap between the arrow and the plot's right and top/bottom edges.
+const EDGE_ARROW_INSET_PX: f64 = 4.0;
+
 impl<R: Renderer> ChartEngine<R> {
     pub(super) fn append_last_price_axis_line_primitive(
         &self,
@@ -14,7 +20,7 @@ impl<R: Renderer> ChartEngine<R> {
         let plot_right = ctx.plot_right;
         let style = ctx.style;
 
-        if style.show_last_price_line {
+        if marker.show_line {
             sink.push_line(
                 CanvasLayerKind::Overlay,
                 crate::render::LinePrimitive::new(
@@ -27,5 +33,25 @@ impl<R: Renderer> ChartEngine<R> {
                 ),
             );
         }
+
+        if let Some(direction) = marker.edge_arrow
+            && (marker.show_line || marker.show_label)
+        {
+            let tip_y = match direction {
+                ArrowDirection::Down => marker.py - EDGE_ARROW_INSET_PX,
+                _ => marker.py + EDGE_ARROW_INSET_PX,
+            };
+            sink.push_arrow(
+                CanvasLayerKind::Overlay,
+                ArrowPrimitive::new(
+                    plot_right - EDGE_ARROW_INSET_PX - EDGE_ARROW_WIDTH_PX * 0.5,
+                    tip_y,
+                    direction,
+                    EDGE_ARROW_LENGTH_PX,
+                    EDGE_ARROW_WIDTH_PX,
+                    marker.marker_line_color,
+                ),
+            );
+        }
     }
 }
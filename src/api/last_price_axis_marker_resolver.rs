@@ -1,13 +1,14 @@
 use crate::error::ChartResult;
-use crate::render::Renderer;
+use crate::render::{ArrowDirection, Renderer};
 
 use super::last_price_axis_scene_builder::LastPriceMarker;
-use super::{ChartEngine, RenderStyle};
+use super::price_resolver::PriceSampleSource;
+use super::{ChartEngine, LastPriceOffscreenPolicy, RenderStyle, SeriesRef};
 
 impl<R: Renderer> ChartEngine<R> {
     pub(super) fn resolve_last_price_marker_for_axis(
         &self,
-        style: RenderStyle,
+        style: &RenderStyle,
         visible_start: f64,
         visible_end: f64,
         plot_bottom: f64,
@@ -20,12 +21,16 @@ impl<R: Renderer> ChartEngine<R> {
             return Ok(None);
         };
 
-        let py = self
-            .core
-            .model
-            .price_scale
-            .price_to_pixel(last_price, self.core.model.viewport)?
-            .clamp(0.0, plot_bottom);
+        let Some((py, edge_arrow)) = self.resolve_last_price_marker_position(
+            PriceSampleSource::All,
+            style,
+            last_price,
+            (visible_start, visible_end),
+            plot_bottom,
+        )?
+        else {
+            return Ok(None);
+        };
         let (marker_line_color, marker_label_color) =
             self.resolve_last_price_marker_colors(last_price, previous_price);
 
@@ -34,6 +39,120 @@ impl<R: Renderer> ChartEngine<R> {
             py,
             marker_line_color,
             marker_label_color,
+            show_line: style.show_last_price_line,
+            show_label: style.show_last_price_label,
+            edge_arrow,
+            price_format: None,
         }))
     }
+
+    /// Resolves opt-in per-series last-value markers for series drawn in the main pane.
+    pub(super) fn resolve_series_last_value_markers_for_axis(
+        &self,
+        style: &RenderStyle,
+        visible_start: f64,
+        visible_end: f64,
+        plot_bottom: f64,
+    ) -> ChartResult<Vec<LastPriceMarker>> {
+        let main_pane_id = self.core.model.pane_collection.main_pane_id();
+        let series = [
+            (
+                PriceSampleSource::Points,
+                SeriesRef::Points,
+                self.core.model.points_pane_id,
+                style.point_series_last_value,
+            ),
+            (
+                PriceSampleSource::Candles,
+                SeriesRef::Candles,
+                self.core.model.candles_pane_id,
+                style.candle_series_last_value,
+            ),
+        ];
+
+        let mut markers = Vec::new();
+        for (source, series, pane_id, series_style) in series {
+            let Some(series_style) = series_style else {
+                continue;
+            };
+            if pane_id != main_pane_id && self.core.model.pane_collection.contains(pane_id) {
+                continue;
+            }
+            let Some((last_price, previous_price)) = self
+                .resolve_source_latest_and_previous_price_values(
+                    source,
+                    style.last_price_source_mode,
+                    visible_start,
+                    visible_end,
+                )
+            else {
+                continue;
+            };
+            let Some((py, edge_arrow)) = self.resolve_last_price_marker_position(
+                source,
+                style,
+                last_price,
+                (visible_start, visible_end),
+                plot_bottom,
+            )?
+            else {
+                continue;
+            };
+            let color = if series_style.use_trend_color {
+                self.resolve_trend_color(last_price, previous_price)
+            } else {
+                series_style.color
+            };
+            markers.push(LastPriceMarker {
+                last_price,
+                py,
+                marker_line_color: color,
+                marker_label_color: color,
+                show_line: series_style.show_line,
+                show_label: series_style.show_label,
+                edge_arrow,
+                price_format: self.series_price_format(series),
+            });
+        }
+        Ok(markers)
+    }
+
+    /// Marker y pinned to the plot, plus the edge-arrow direction when the
+    /// price is off-domain under `LastPriceOffscreenPolicy::EdgeArrow`.
+    ///
+    /// `None` hides the marker under `LastPriceOffscreenPolicy::Hide` while
+    /// its source bar is outside the visible time range.
+    fn resolve_last_price_marker_position(
+        &self,
+        source: PriceSampleSource,
+        style: &RenderStyle,
+        last_price: f64,
+        (visible_start, visible_end): (f64, f64),
+        plot_bottom: f64,
+    ) -> ChartResult<Option<(f64, Option<ArrowDirection>)>> {
+        if style.last_price_offscreen_policy == LastPriceOffscreenPolicy::Hide
+            && let Some((time, _)) = self.resolve_source_latest_price_sample(
+                source,
+                style.last_price_source_mode,
+                visible_start,
+                visible_end,
+            )
+            && (time < visible_start.min(visible_end) || time > visible_start.max(visible_end))
+        {
+            return Ok(None);
+        }
+        let raw_py = self
+            .core
+            .model
+            .price_scale
+            .price_to_pixel(last_price, self.core.model.viewport)?;
+        let edge_arrow = match style.last_price_offscreen_policy {
+            LastPriceOffscreenPolicy::EdgeArrow if raw_py < 0.0 => Some(ArrowDirection::Up),
+            LastPriceOffscreenPolicy::EdgeArrow if raw_py > plot_bottom => {
+                Some(ArrowDirection::Down)
+            }
+            _ => None,
+        };
+        Ok(Some((raw_py.clamp(0.0, plot_bottom), edge_arrow)))
+    }
 }
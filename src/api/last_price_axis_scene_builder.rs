@@ -1,6 +1,8 @@
+use super::axis_label_stacking_resolver::resolve_axis_label_stack_offsets;
 use super::axis_render_frame_builder::AxisPrimitiveSink;
-use super::{ChartEngine, RenderStyle};
-use crate::render::{Color, Renderer};
+use super::last_price_axis_label_primitives_builder::LastPriceAxisLabelPrimitives;
+use super::{ChartEngine, PriceAxisLabelPolicy, RenderStyle};
+use crate::render::{ArrowDirection, Color, Renderer};
 
 #[derive(Debug, Clone, Copy)]
 pub(super) struct LastPriceMarker {
@@ -8,10 +10,16 @@ pub(super) struct LastPriceMarker {
     pub py: f64,
     pub marker_line_color: Color,
     pub marker_label_color: Color,
+    pub show_line: bool,
+    pub show_label: bool,
+    /// Arrow pointing towards an off-domain price pinned to the plot edge.
+    pub edge_arrow: Option<ArrowDirection>,
+    /// Label format of the marker's series; `None` uses the axis format.
+    pub price_format: Option<PriceAxisLabelPolicy>,
 }
 
 #[derive(Debug, Clone, Copy)]
-pub(super) struct LastPriceAxisSceneContext {
+pub(super) struct LastPriceAxisSceneContext<'a> {
     pub plot_right: f64,
     pub plot_bottom: f64,
     pub viewport_width: f64,
@@ -19,21 +27,37 @@ pub(super) struct LastPriceAxisSceneContext {
     pub fallback_display_base_price: f64,
     pub display_tick_step_abs: f64,
     pub display_suffix: &'static str,
-    pub style: RenderStyle,
+    pub style: &'a RenderStyle,
 }
 
 impl<R: Renderer> ChartEngine<R> {
+    /// Emits marker lines and returns the labels stacked so boxes never overlap.
+    ///
+    /// Labels are emitted later by the crosshair stage, which resolves their
+    /// collisions with the crosshair price label.
     pub(super) fn append_last_price_axis_primitives(
         &self,
         sink: &mut AxisPrimitiveSink<'_>,
-        marker: Option<LastPriceMarker>,
+        markers: &[LastPriceMarker],
         ctx: LastPriceAxisSceneContext,
-    ) {
-        let Some(marker) = marker else {
-            return;
-        };
+    ) -> Vec<LastPriceAxisLabelPrimitives> {
+        for marker in markers {
+            self.append_last_price_axis_line_primitive(sink, *marker, ctx);
+        }
 
-        self.append_last_price_axis_line_primitive(sink, marker, ctx);
-        self.append_last_price_axis_label_primitives(sink, marker, ctx);
+        let mut labels = markers
+            .iter()
+            .filter(|marker| marker.show_label)
+            .filter_map(|marker| self.build_last_price_axis_label_primitives(*marker, ctx))
+            .collect::<Vec<_>>();
+        let spans = labels
+            .iter()
+            .map(|label| label.span(ctx.style))
+            .collect::<Vec<_>>();
+        let offsets = resolve_axis_label_stack_offsets(&spans, 0.0, ctx.plot_bottom);
+        for (label, offset) in labels.iter_mut().zip(offsets) {
+            label.shift_y(offset);
+        }
+        labels
     }
 }
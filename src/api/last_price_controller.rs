@@ -1,7 +1,9 @@
+use crate::core::DataPoint;
 use crate::error::ChartResult;
 use crate::render::Renderer;
 
-use super::{ChartEngine, LastPriceBehavior};
+use super::price_resolver::PriceSampleSource;
+use super::{ChartEngine, LastPriceBehavior, LastPriceOffscreenPolicy};
 
 impl<R: Renderer> ChartEngine<R> {
     #[must_use]
@@ -12,6 +14,7 @@ impl<R: Renderer> ChartEngine<R> {
             show_label: style.show_last_price_label,
             use_trend_color: style.last_price_use_trend_color,
             source_mode: style.last_price_source_mode,
+            offscreen_policy: style.last_price_offscreen_policy,
         }
     }
 
@@ -21,6 +24,21 @@ impl<R: Renderer> ChartEngine<R> {
         style.show_last_price_label = behavior.show_label;
         style.last_price_use_trend_color = behavior.use_trend_color;
         style.last_price_source_mode = behavior.source_mode;
+        style.last_price_offscreen_policy = behavior.offscreen_policy;
         self.set_render_style(style)
     }
+
+    /// Latest sample that `LastPriceOffscreenPolicy::ExtendDomain` keeps
+    /// inside visible-range autoscale.
+    pub(super) fn last_price_autoscale_point(&self) -> Option<DataPoint> {
+        let style = &self.core.presentation.render_style;
+        if style.last_price_offscreen_policy != LastPriceOffscreenPolicy::ExtendDomain
+            || !(style.show_last_price_line || style.show_last_price_label)
+        {
+            return None;
+        }
+        let (time, price) =
+            self.resolve_latest_price_sample_with_window(PriceSampleSource::All, None)?;
+        Some(DataPoint::new(time, price))
+    }
 }
@@ -0,0 +1,188 @@
+use crate::core::{DataPoint, OhlcBar};
+use crate::error::ChartResult;
+use crate::render::Renderer;
+
+use super::animation_controller::ease_out_cubic;
+use super::validation::validate_last_value_animation_behavior;
+use super::{ChartEngine, LastValueAnimationBehavior};
+
+/// Transition of the newest sample's value, keyed by the sample time and
+/// target value so it stops applying once the series no longer ends there.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(super) struct LastValueAnimation {
+    time: f64,
+    from: f64,
+    to: f64,
+    duration_seconds: f64,
+    elapsed_seconds: f64,
+}
+
+impl LastValueAnimation {
+    fn new(
+        behavior: LastValueAnimationBehavior,
+        from: Option<f64>,
+        time: f64,
+        to: f64,
+    ) -> Option<Self> {
+        let from = from?;
+        (behavior.enabled && from != to).then_some(Self {
+            time,
+            from,
+            to,
+            duration_seconds: behavior.duration_seconds,
+            elapsed_seconds: 0.0,
+        })
+    }
+
+    /// Animated value while `(time, value)` still describes the target sample.
+    fn value_for(self, time: f64, value: f64) -> Option<f64> {
+        if self.time != time || self.to != value {
+            return None;
+        }
+        let progress = (self.elapsed_seconds / self.duration_seconds).min(1.0);
+        Some(self.from + (self.to - self.from) * ease_out_cubic(progress))
+    }
+}
+
+impl<R: Renderer> ChartEngine<R> {
+    #[must_use]
+    pub fn last_value_animation_behavior(&self) -> LastValueAnimationBehavior {
+        self.core.behavior.last_value_animation_behavior
+    }
+
+    /// Disabling the behavior drops running transitions so the series jumps
+    /// to its latest values.
+    pub fn set_last_value_animation_behavior(
+        &mut self,
+        behavior: LastValueAnimationBehavior,
+    ) -> ChartResult<()> {
+        validate_last_value_animation_behavior(behavior)?;
+        if self.core.behavior.last_value_animation_behavior != behavior {
+            self.core.behavior.last_value_animation_behavior = behavior;
+            if !behavior.enabled {
+                self.core.runtime.last_point_animation = None;
+                self.core.runtime.last_candle_animation = None;
+            }
+            self.invalidate_full();
+        }
+        Ok(())
+    }
+
+    #[must_use]
+    pub fn last_value_animation_active(&self) -> bool {
+        self.core.runtime.last_point_animation.is_some()
+            || self.core.runtime.last_candle_animation.is_some()
+    }
+
+    /// Value the line series currently draws for its newest point.
+    pub(super) fn displayed_last_point_value(&self) -> Option<f64> {
+        let last = self.core.model.points.last()?;
+        Some(
+            self.core
+                .runtime
+                .last_point_animation
+                .and_then(|animation| animation.value_for(last.x, last.y))
+                .unwrap_or(last.y),
+        )
+    }
+
+    /// Close the candlestick series currently draws for its newest bar.
+    pub(super) fn displayed_last_candle_close(&self) -> Option<f64> {
+        let last = self.core.model.candles.last()?;
+        Some(
+            self.core
+                .runtime
+                .last_candle_animation
+                .and_then(|animation| animation.value_for(last.time, last.close))
+                .unwrap_or(last.close),
+        )
+    }
+
+    /// Starts moving the newest point from `from` (the value drawn before the
+    /// realtime update) to its new value.
+    pub(super) fn start_last_point_animation(&mut self, from: Option<f64>) {
+        let Some(last) = self.core.model.points.last().copied() else {
+            return;
+        };
+        self.core.runtime.last_point_animation = LastValueAnimation::new(
+            self.core.behavior.last_value_animation_behavior,
+            from,
+            last.x,
+            last.y,
+        );
+    }
+
+    /// Starts moving the newest close from `from` (the close drawn before the
+    /// realtime update) to its new value.
+    pub(super) fn start_last_candle_animation(&mut self, from: Option<f64>) {
+        let Some(last) = self.core.model.candles.last().copied() else {
+            return;
+        };
+        self.core.runtime.last_candle_animation = LastValueAnimation::new(
+            self.core.behavior.last_value_animation_behavior,
+            from,
+            last.time,
+            last.close,
+        );
+    }
+
+    pub(super) fn step_last_value_animations(&mut self, delta_seconds: f64) {
+        let runtime = &mut self.core.runtime;
+        let mut stepped = false;
+        for slot in [
+            &mut runtime.last_point_animation,
+            &mut runtime.last_candle_animation,
+        ] {
+            if let Some(mut animation) = *slot {
+                animation.elapsed_seconds += delta_seconds;
+                *slot =
+                    (animation.elapsed_seconds < animation.duration_seconds).then_some(animation);
+                stepped = true;
+            }
+        }
+        if stepped {
+            self.invalidate_full();
+        }
+    }
+
+    /// Newest point of a windowed slice with its animated value, when the
+    /// slice ends at the series' newest point and that value is animating.
+    pub(super) fn animated_last_point(&self, points: &[DataPoint]) -> Option<DataPoint> {
+        let point = *points.last()?;
+        let model_last = self.core.model.points.last()?;
+        if point.x != model_last.x {
+            return None;
+        }
+        let y = self
+            .core
+            .runtime
+            .last_point_animation?
+            .value_for(model_last.x, model_last.y)?;
+        Some(DataPoint { y, ..point })
+    }
+
+    /// Animated close of the newest bar, when a windowed slice ends at it and
+    /// its close is animating.
+    pub(super) fn animated_last_candle_close(&self, candles: &[OhlcBar]) -> Option<f64> {
+        let bar = candles.last()?;
+        let model_last = self.core.model.candles.last()?;
+        if bar.time != model_last.time {
+            return None;
+        }
+        self.core
+            .runtime
+            .last_candle_animation?
+            .value_for(model_last.time, model_last.close)
+    }
+}
+
+/// `bar` drawn at an animated `close`, stretching the wick range so it still
+/// contains the body.
+pub(super) fn with_animated_close(bar: OhlcBar, close: f64) -> OhlcBar {
+    OhlcBar {
+        close,
+        high: bar.high.max(close),
+        low: bar.low.min(close),
+        ..bar
+    }
+}
@@ -50,20 +50,6 @@ pub(super) fn resolve_axis_layout(
     }
 }
 
-pub(super) fn estimate_label_text_width_px(text: &str, font_size_px: f64) -> f64 {
-    // Keep this estimate deterministic and backend-independent.
-    let units = text.chars().fold(0.0, |acc, ch| {
-        acc + match ch {
-            '0'..='9' => 0.62,
-            '.' | ',' => 0.34,
-            '-' | '+' | '%' => 0.42,
-            ' ' => 0.33,
-            _ => 0.58,
-        }
-    });
-    (units * font_size_px).max(font_size_px)
-}
-
 pub(super) fn stabilize_position(value: f64, step_px: f64) -> f64 {
     if step_px > 0.0 {
         (value / step_px).round() * step_px
@@ -0,0 +1,147 @@
+use serde::{Deserialize, Serialize};
+
+use crate::core::Viewport;
+use crate::error::{ChartError, ChartResult};
+use crate::render::Renderer;
+
+use super::ChartEngine;
+
+/// Outer padding between the widget edges and the chart (plot plus axes).
+///
+/// The engine lays out and maps coordinates inside the content viewport
+/// (the widget viewport minus these insets); built frames are shifted by
+/// `(left, top)` and keep the full widget viewport, leaving the margins
+/// free for host overlays.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct LayoutInsets {
+    #[serde(default)]
+    pub left: u32,
+    #[serde(default)]
+    pub top: u32,
+    #[serde(default)]
+    pub right: u32,
+    #[serde(default)]
+    pub bottom: u32,
+}
+
+impl LayoutInsets {
+    #[must_use]
+    pub const fn new(left: u32, top: u32, right: u32, bottom: u32) -> Self {
+        Self {
+            left,
+            top,
+            right,
+            bottom,
+        }
+    }
+
+    /// Same inset on every side.
+    #[must_use]
+    pub const fn uniform(inset: u32) -> Self {
+        Self::new(inset, inset, inset, inset)
+    }
+
+    #[must_use]
+    pub fn is_zero(self) -> bool {
+        self == Self::default()
+    }
+
+    /// Area left for the chart inside `outer`, or `None` when the insets
+    /// consume a whole dimension.
+    #[must_use]
+    pub fn content_viewport(self, outer: Viewport) -> Option<Viewport> {
+        let width = outer
+            .width
+            .checked_sub(self.left.checked_add(self.right)?)?;
+        let height = outer
+            .height
+            .checked_sub(self.top.checked_add(self.bottom)?)?;
+        let content = Viewport::new(width, height);
+        content.is_valid().then_some(content)
+    }
+
+    /// Widget viewport around a `content` viewport.
+    #[must_use]
+    pub fn outer_viewport(self, content: Viewport) -> Viewport {
+        Viewport::new(
+            content
+                .width
+                .saturating_add(self.left)
+                .saturating_add(self.right),
+            content
+                .height
+                .saturating_add(self.top)
+                .saturating_add(self.bottom),
+        )
+    }
+
+    pub(super) fn resolve_content_viewport(self, outer: Viewport) -> ChartResult<Viewport> {
+        if !outer.is_valid() {
+            return Err(ChartError::InvalidViewport {
+                width: outer.width,
+                height: outer.height,
+            });
+        }
+        self.content_viewport(outer).ok_or_else(|| {
+            ChartError::InvalidData(format!(
+                "layout insets {self:?} leave no content area in a {}x{} viewport",
+                outer.width, outer.height
+            ))
+        })
+    }
+}
+
+impl<R: Renderer> ChartEngine<R> {
+    #[must_use]
+    pub fn layout_insets(&self) -> LayoutInsets {
+        self.core.behavior.layout_insets
+    }
+
+    /// Sets the outer chart padding, keeping the widget viewport unchanged.
+    ///
+    /// Fails when the insets leave no content area.
+    pub fn set_layout_insets(&mut self, insets: LayoutInsets) -> ChartResult<()> {
+        let outer = self.viewport();
+        let content = insets.resolve_content_viewport(outer)?;
+        self.core.behavior.layout_insets = insets;
+        self.apply_content_viewport(content)
+    }
+
+    /// Viewport the chart is laid out in: `viewport()` minus the layout
+    /// insets. Pixel coordinates of the interaction and mapping APIs are
+    /// relative to its top-left corner.
+    #[must_use]
+    pub fn content_viewport(&self) -> Viewport {
+        self.core.model.viewport
+    }
+
+    /// Converts widget pixel coordinates into content coordinates.
+    #[must_use]
+    pub fn widget_to_content(&self, x: f64, y: f64) -> (f64, f64) {
+        let insets = self.core.behavior.layout_insets;
+        (x - f64::from(insets.left), y - f64::from(insets.top))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LayoutInsets;
+    use crate::core::Viewport;
+
+    #[test]
+    fn content_viewport_subtracts_insets_and_rejects_empty_area() {
+        let insets = LayoutInsets::new(10, 20, 30, 40);
+        let content = insets
+            .content_viewport(Viewport::new(200, 100))
+            .expect("content");
+        assert_eq!(content, Viewport::new(160, 40));
+        assert_eq!(insets.outer_viewport(content), Viewport::new(200, 100));
+
+        assert_eq!(insets.content_viewport(Viewport::new(40, 100)), None);
+        assert_eq!(insets.content_viewport(Viewport::new(200, 60)), None);
+        assert_eq!(
+            LayoutInsets::uniform(u32::MAX).content_viewport(Viewport::new(200, 100)),
+            None
+        );
+    }
+}
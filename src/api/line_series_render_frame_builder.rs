@@ -1,10 +1,16 @@
-use crate::core::{PaneId, PriceScale, points_in_time_window, project_line_segments};
+use tracing::trace_span;
+
+use crate::core::{
+    DataPoint, PaneId, PriceScale, points_in_sorted_time_window, project_line_segments,
+};
 use crate::error::ChartResult;
 use crate::render::{
-    CanvasLayerKind, Color, LayeredRenderFrame, LinePrimitive, RenderFrame, Renderer,
+    CanvasLayerKind, ClipRect, Color, LayeredRenderFrame, LinePrimitive, RenderFrame, Renderer,
 };
+use crate::telemetry::{FRAME_PROJECTION_SPAN, FRAME_WINDOWING_SPAN};
 
 use super::ChartEngine;
+use super::frame_budget::decimate_points;
 
 #[derive(Debug, Clone, Copy)]
 pub(super) struct LineSeriesRenderContext {
@@ -13,6 +19,9 @@ pub(super) struct LineSeriesRenderContext {
     pub visible_start: f64,
     pub visible_end: f64,
     pub line_color: Color,
+    pub clip_rect: ClipRect,
+    /// Source points folded into each drawn min/max pair (1 = full detail).
+    pub lod_bucket_len: usize,
 }
 
 impl<R: Renderer> ChartEngine<R> {
@@ -22,27 +31,67 @@ impl<R: Renderer> ChartEngine<R> {
         layered: &mut LayeredRenderFrame,
         ctx: LineSeriesRenderContext,
     ) -> ChartResult<()> {
-        let pane_id = ctx.pane_id;
-        let price_scale = ctx.price_scale;
-        let visible_start = ctx.visible_start;
-        let visible_end = ctx.visible_end;
-        let line_color = ctx.line_color;
+        let windowing_span =
+            trace_span!(FRAME_WINDOWING_SPAN, series = "line", samples = 0_usize).entered();
+        let window = points_in_sorted_time_window(
+            &self.core.model.points,
+            ctx.visible_start,
+            ctx.visible_end,
+        );
+        let newest = self.animated_last_point(window);
+        if ctx.lod_bucket_len > 1 {
+            let visible_points = decimate_points(window, newest, ctx.lod_bucket_len);
+            windowing_span.record("samples", visible_points.len());
+            windowing_span.exit();
+            return self.push_line_series_segments(frame, layered, &visible_points, None, ctx);
+        }
+        windowing_span.record("samples", window.len());
+        windowing_span.exit();
+        self.push_line_series_segments(frame, layered, window, newest, ctx)
+    }
 
-        let visible_points =
-            points_in_time_window(&self.core.model.points, visible_start, visible_end);
-        let segments = project_line_segments(
-            &visible_points,
-            self.core.model.time_scale,
-            price_scale,
-            self.core.model.viewport,
-        )?;
+    /// Projects already-windowed points and pushes their segments into the
+    /// series layer of `ctx.pane_id`. `newest` replaces the final point (its
+    /// animated value) without copying `points`.
+    pub(super) fn push_line_series_segments(
+        &self,
+        frame: &mut RenderFrame,
+        layered: &mut LayeredRenderFrame,
+        points: &[DataPoint],
+        newest: Option<DataPoint>,
+        ctx: LineSeriesRenderContext,
+    ) -> ChartResult<()> {
+        let mut segments = trace_span!(
+            FRAME_PROJECTION_SPAN,
+            series = "line",
+            samples = points.len()
+        )
+        .in_scope(|| {
+            project_line_segments(
+                points,
+                self.core.model.time_scale,
+                ctx.price_scale,
+                self.core.model.viewport,
+            )
+        })?;
+        if let (Some(newest), Some(segment)) = (newest, segments.last_mut()) {
+            segment.y2 = ctx
+                .price_scale
+                .price_to_pixel(newest.y, self.core.model.viewport)?;
+        }
 
         for segment in segments {
             let line = LinePrimitive::new(
-                segment.x1, segment.y1, segment.x2, segment.y2, 1.5, line_color,
-            );
+                segment.x1,
+                segment.y1,
+                segment.x2,
+                segment.y2,
+                1.5,
+                ctx.line_color,
+            )
+            .with_clip_rect(ctx.clip_rect);
             frame.lines.push(line);
-            layered.push_line(pane_id, CanvasLayerKind::Series, line);
+            layered.push_line(ctx.pane_id, CanvasLayerKind::Series, line);
         }
 
         Ok(())
@@ -1,36 +1,49 @@
 pub(crate) use crate::extensions::PluginEvent;
-pub use crate::interaction::CrosshairMode;
+pub use crate::interaction::{
+    CrosshairMode, CrosshairSnapKind, CrosshairSnapSeries, CrosshairSnapTarget,
+};
 
 mod render_style;
 pub use render_style::{
-    CandlestickBodyMode, CrosshairLabelBoxHorizontalAnchor, CrosshairLabelBoxOverflowPolicy,
+    AxisBackgroundShadow, BackgroundBandMode, CandlestickBodyMode, CandlestickDetailTiers,
+    CrosshairLabelBoxHorizontalAnchor, CrosshairLabelBoxOverflowPolicy,
     CrosshairLabelBoxVerticalAnchor, CrosshairLabelBoxVisibilityPriority,
-    CrosshairLabelBoxWidthMode, CrosshairLabelBoxZOrderPolicy, LastPriceLabelBoxWidthMode,
-    LastPriceSourceMode, RenderStyle,
+    CrosshairLabelBoxWidthMode, CrosshairLabelBoxZOrderPolicy, CrosshairSnapMarkerStyle,
+    CrosshairTimeLabelMode, GridLineOptions, GridOptions, LabelOverflowPolicy, LabelTextMetrics,
+    LastPriceLabelBoxWidthMode, LastPriceOffscreenPolicy, LastPriceSourceMode,
+    PriceAxisLabelCollisionPriority, PriceAxisWidthMode, RelativeTimeAnchor, RenderStyle,
+    SeriesLastValueStyle,
 };
 
 mod axis_config;
 pub use axis_config::{
     AxisLabelLocale, PriceAxisDisplayMode, PriceAxisLabelConfig, PriceAxisLabelPolicy,
-    TimeAxisLabelConfig, TimeAxisLabelPolicy, TimeAxisSessionConfig, TimeAxisTimeZone,
+    PriceAxisSecondaryLabel, ScientificNotation, TimeAxisLabelConfig, TimeAxisLabelPolicy,
+    TimeAxisSessionConfig, TimeAxisTimeUnit, TimeAxisTimeZone,
 };
 
 mod behavior;
 pub use behavior::{
-    CandlestickBarStyleOverride, CandlestickStyleBehavior, CrosshairAxisLabelBoxStyleBehavior,
-    CrosshairAxisLabelStyleBehavior, CrosshairAxisLabelVisibilityBehavior,
-    CrosshairGuideLineBehavior, CrosshairGuideLineStyleBehavior, InteractionInputBehavior,
-    LastPriceBehavior, PriceScaleMarginBehavior, PriceScaleRealtimeBehavior,
-    PriceScaleTransformedBaseBehavior, PriceScaleTransformedBaseSource, StyledOhlcBar,
-    TimeCoordinateIndexPolicy, TimeFilledLogicalSlot, TimeFilledLogicalSource,
-    TimeScaleEdgeBehavior, TimeScaleNavigationBehavior, TimeScaleRealtimeAppendBehavior,
-    TimeScaleResizeAnchor, TimeScaleResizeBehavior, TimeScaleScrollZoomBehavior,
-    TimeScaleZoomLimitBehavior,
+    AxisInteractionConfig, AxisInteractionOptions, BarCountdownBehavior,
+    CandlestickBarStyleOverride, CandlestickStyleBehavior, ChartAxis,
+    CrosshairAxisLabelBoxStyleBehavior, CrosshairAxisLabelStyleBehavior,
+    CrosshairAxisLabelVisibilityBehavior, CrosshairGuideLineBehavior,
+    CrosshairGuideLineStyleBehavior, DataGapBehavior, DebugOverlayBehavior, DebugOverlayCorner,
+    ElasticOverscrollBehavior, HighLowMarkerBehavior, IdlePowerBehavior, InteractionInputBehavior,
+    LastPriceBehavior, LastValueAnimationBehavior, NavigationHistoryBehavior, OhlcInfoLineBehavior,
+    OhlcInfoLineMode, PaneSeparatorBehavior, PriceScaleMarginBehavior, PriceScaleRealtimeBehavior,
+    PriceScaleTransformedBaseBehavior, PriceScaleTransformedBaseSource, RedrawThrottleBehavior,
+    StyledOhlcBar, TimeCoordinateIndexPolicy, TimeFilledLogicalSlot, TimeFilledLogicalSource,
+    TimeScaleEdgeBehavior, TimeScaleMarkBehavior, TimeScaleNavigationBehavior,
+    TimeScaleRealtimeAppendBehavior, TimeScaleResizeAnchor, TimeScaleResizeBehavior,
+    TimeScaleScrollZoomBehavior, TimeScaleZoomLimitBehavior, VisibleRangeChangeBehavior,
+    VisibleRangeChangeDelivery, WheelAxis, WheelBehaviorConfig, ZoomSelectionBehavior,
 };
 
 mod label_cache;
 pub use label_cache::{
-    PriceLabelCacheStats, PriceLabelFormatterFn, TimeLabelCacheStats, TimeLabelFormatterFn,
+    DEFAULT_LABEL_CACHE_CAPACITY, PriceLabelCacheStats, PriceLabelFormatterFn, TimeLabelCacheStats,
+    TimeLabelFormatterFn,
 };
 
 mod label_formatter_context;
@@ -40,15 +53,33 @@ pub use label_formatter_context::{
     CrosshairTimeLabelFormatterWithContextFn,
 };
 
+mod crosshair_label_template;
+pub use crosshair_label_template::{CrosshairLabelTemplate, CrosshairLabelTemplatePart};
+
 mod json_contract;
 pub use json_contract::{
     CROSSHAIR_DIAGNOSTICS_JSON_SCHEMA_V1, CrosshairFormatterDiagnosticsJsonContractV1,
     ENGINE_SNAPSHOT_JSON_SCHEMA_V1, EngineSnapshotJsonContractV1,
 };
 
+mod render_frame_json_contract;
+pub use render_frame_json_contract::{
+    RENDER_FRAME_JSON_SCHEMA_V1, RenderFrameJsonContractV1, RenderFrameLineV1, RenderFramePathV1,
+    RenderFrameRectV1, RenderFrameTextV1,
+};
+
+mod interaction_trace;
+pub use interaction_trace::{
+    InteractionAction, InteractionExpectation, InteractionScenario, InteractionStep,
+    InteractionTrace, ReplayReport, TraceCrosshairMode, TraceMismatch, TracePoint, TracePriceRange,
+    TraceTimeRange, TraceViewport, apply_interaction_action, observe_interaction_state,
+    replay_interaction_scenario,
+};
+
 mod validation;
 
 mod axis_label_format;
+mod axis_label_stacking_resolver;
 mod axis_ticks;
 mod chart_behavior;
 mod chart_model;
@@ -59,19 +90,27 @@ mod engine_config;
 mod engine_core;
 mod engine_init;
 mod engine_snapshot;
+mod frame_budget;
 mod interaction_validation;
 mod invalidation;
 mod invalidation_render_gate;
 mod layout_helpers;
+mod layout_insets;
 mod lwc_model_sync;
 
+mod animation_controller;
+#[cfg(feature = "apng-export")]
+mod animation_export;
 mod axis_adaptive_layout_resolver;
 mod axis_adaptive_price_axis_width_resolver;
+mod axis_background_primitives_builder;
 mod axis_density_coordinator;
+mod axis_interaction_controller;
 mod axis_label_controller;
 mod axis_last_price_label_width_estimator;
 mod axis_layout_coordinator;
 mod axis_layout_pass_resolver;
+mod axis_price_axis_auto_width_resolver;
 mod axis_price_axis_relayout_pass_resolver;
 mod axis_price_axis_relayout_resolver;
 mod axis_price_axis_width_estimator;
@@ -98,19 +137,33 @@ mod axis_render_frame_builder;
 mod axis_requested_section_sizes_resolver;
 mod axis_time_axis_height_estimator;
 mod axis_time_scene_builder;
+mod background_render_frame_builder;
+mod bar_countdown_controller;
 mod cache_profile;
 mod candlestick_render_frame_builder;
 mod candlestick_style_controller;
+mod compare_series_controller;
 mod crosshair_label_box_style_controller;
 mod crosshair_label_style_controller;
 mod crosshair_label_visibility_controller;
 mod crosshair_line_controller;
 mod crosshair_line_style_controller;
 mod crosshair_render_frame_builder;
+mod crosshair_snap_marker_primitives_builder;
+mod custom_series_controller;
 mod data_controller;
+mod data_export_controller;
+mod data_gap_controller;
+mod data_selection_controller;
+mod debug_overlay_controller;
+mod elastic_overscroll_controller;
 mod engine_accessors;
+mod engine_command_controller;
+mod high_low_marker_controller;
+mod idle_power_controller;
 mod interaction_controller;
 mod interaction_coordinator;
+mod interaction_recording_controller;
 mod label_formatter_controller;
 mod label_text_formatter;
 mod last_price_axis_label_layout_builder;
@@ -119,19 +172,27 @@ mod last_price_axis_line_primitives_builder;
 mod last_price_axis_marker_resolver;
 mod last_price_axis_scene_builder;
 mod last_price_controller;
+mod last_value_animation_controller;
 mod line_series_render_frame_builder;
+mod navigation_history_controller;
+mod ohlc_info_line_controller;
 mod pane_controller;
 mod pane_price_scale_coordinator;
 #[cfg(feature = "cairo-backend")]
 mod pane_render_executor;
 mod pane_scene_coordinator;
+mod pane_separator_controller;
 mod plugin_dispatch;
 mod plugin_registry;
+mod pointer_input_controller;
+mod price_alert_controller;
 mod price_resolver;
 mod price_scale_access;
 mod price_scale_coordinator;
 mod price_scale_interaction_controller;
 mod price_scale_validation;
+mod primitive_hit_controller;
+mod redraw_governor_controller;
 #[cfg(feature = "cairo-backend")]
 mod render_cairo_coordinator;
 #[cfg(feature = "cairo-backend")]
@@ -144,9 +205,11 @@ mod render_cairo_partial_input_resolver;
 mod render_cairo_partial_pass_executor;
 #[cfg(feature = "cairo-backend")]
 mod render_cairo_partial_plan_resolver;
+mod render_clip_regions_resolver;
 mod render_coordinator;
 mod render_cycle_finalizer;
 mod render_frame_builder;
+mod render_frame_pool;
 mod render_full_pass_executor;
 #[cfg(feature = "cairo-backend")]
 mod render_partial_lwc_policy_resolver;
@@ -164,35 +227,93 @@ mod render_partial_scheduler;
 mod render_partial_task;
 #[cfg(feature = "cairo-backend")]
 mod render_partial_task_collectors;
-#[cfg(test)]
+#[cfg(all(test, feature = "cairo-backend"))]
 pub(crate) mod render_partial_test_support;
 mod render_style_invalidation_resolver;
+mod replay_controller;
 mod scale_access;
 mod scale_coordinator;
+mod scene_controller;
+mod series_coordinate_controller;
+mod series_legend_controller;
+mod series_price_format_controller;
 mod series_projection;
+mod series_query_controller;
 mod series_scene_coordinator;
 mod snap_resolver;
 mod snapshot_controller;
+mod staged_ingestion_controller;
+mod sync_event;
+mod sync_group;
+mod time_axis_edge_label_resolver;
 mod time_scale_controller;
 mod time_scale_coordinator;
 mod time_scale_input_validation;
 mod time_scale_interaction_controller;
+mod time_scale_mark_controller;
 mod time_scale_navigation_target_resolver;
 mod time_scale_pan_delta_resolver;
 mod time_scale_validation;
 mod time_scale_zoom_factor_resolver;
 mod time_scale_zoom_target_resolver;
+mod trading_overlay_controller;
+mod view_bookmark_controller;
+mod viewport_resize_controller;
+mod visible_range_change_controller;
 mod visible_window_access;
+mod volume_profile_controller;
+mod warning_controller;
+mod wheel_behavior_controller;
+mod zoom_selection_controller;
 
 mod engine;
+#[cfg(feature = "apng-export")]
+pub use animation_export::{AnimatedPngOptions, AnimatedPngRecorder};
 pub use chart_model::ChartModel;
 pub(crate) use chart_model::ChartModelBootstrap;
+pub use compare_series_controller::CompareSeries;
+pub use data_export_controller::DataExportFormat;
+pub use data_gap_controller::DataGap;
+pub use data_selection_controller::{DataSelection, DataSelectionSeries};
+pub(crate) use data_window::{nearest_by_time, percent_change};
+pub use debug_overlay_controller::DebugOverlayStats;
 pub use engine::ChartEngine;
+pub use engine_command_controller::{
+    EngineCommand, EngineCommandQueue, EngineCommandReport, EngineHandle, engine_command_channel,
+};
 pub use engine_config::ChartEngineConfig;
 pub use engine_snapshot::{
     CrosshairFormatterDiagnostics, CrosshairFormatterOverrideMode, CrosshairFormatterSnapshot,
     EngineSnapshot,
 };
+pub use frame_budget::{FrameBudget, FramePrimitiveStats};
+pub use high_low_marker_controller::VisibleHighLow;
+pub use interaction_recording_controller::InteractionRecordingOptions;
+pub use layout_insets::LayoutInsets;
+pub use navigation_history_controller::NavigationViewState;
+pub use ohlc_info_line_controller::{OhlcInfoDirection, OhlcInfoField, OhlcInfoLine};
+pub use pane_separator_controller::PaneSeparator;
+pub use pointer_input_controller::{
+    InteractionOptions, PointerCursorHint, PointerInput, PointerInputBridge, PointerModifiers,
+    PointerRegion, TouchGestureTracker,
+};
+pub use primitive_hit_controller::{PrimitiveHit, PrimitiveOwner};
+pub use scene_controller::{CHART_SCENE_JSON_SCHEMA_V2, ChartSceneBehaviors, ChartSceneV2};
+pub use series_coordinate_controller::SeriesPixelSpace;
+pub use series_legend_controller::{
+    LEGEND_CANDLES_NAME_KEY, LEGEND_POINTS_NAME_KEY, LegendSeriesKind, SeriesLegend,
+    SeriesLegendEntry,
+};
+pub use series_query_controller::{SeriesRef, SeriesValueInterpolation};
+pub use snap_resolver::CROSSHAIR_SNAP_MAX_WHITESPACE_SKIP;
+pub use staged_ingestion_controller::{IngestionStats, StagedCandles};
+pub use sync_event::{CrosshairSyncEvent, RangeSyncEvent, SYNC_EVENT_JSON_SCHEMA_V1, SyncEvent};
+pub use sync_group::{SyncGroup, SyncMemberId, SyncMemberOptions};
+pub use time_scale_mark_controller::TimeScaleMark;
+pub use view_bookmark_controller::ViewBookmark;
+pub use viewport_resize_controller::ViewportResizePolicy;
+pub use warning_controller::{AutoscaleSource, ChartWarning, WarningAxis, WarningLabel};
+pub use zoom_selection_controller::ZoomSelection;
 
 pub use invalidation::{
     InvalidationLevel, InvalidationMask, InvalidationTopic, InvalidationTopics,
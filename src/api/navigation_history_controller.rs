@@ -0,0 +1,200 @@
+use std::collections::VecDeque;
+
+use serde::{Deserialize, Serialize};
+
+use crate::core::PriceScaleMode;
+use crate::error::ChartResult;
+use crate::render::Renderer;
+
+use super::{
+    ChartEngine, NavigationHistoryBehavior, price_scale_coordinator::PriceScaleCoordinator,
+};
+
+/// Navigated view captured by the undo/redo history and view bookmarks.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct NavigationViewState {
+    pub time_visible_range: (f64, f64),
+    pub price_domain: (f64, f64),
+    pub price_scale_mode: PriceScaleMode,
+}
+
+/// Gesture that produced a checkpoint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum ViewCheckpointKind {
+    Pan,
+    Kinetic,
+    Zoom,
+    Discrete,
+}
+
+impl ViewCheckpointKind {
+    /// Wheel/pinch/axis zoom bursts have no end event, so consecutive zoom
+    /// checkpoints collapse into one step; a kinetic fling joins the drag
+    /// that launched it.
+    fn merges_into(self, previous: Self) -> bool {
+        matches!(
+            (previous, self),
+            (Self::Zoom, Self::Zoom) | (Self::Pan | Self::Kinetic, Self::Kinetic)
+        )
+    }
+}
+
+#[derive(Debug, Default)]
+pub(super) struct NavigationHistory {
+    undo: VecDeque<NavigationViewState>,
+    redo: Vec<NavigationViewState>,
+    /// View at the latest checkpoint, restored by the next undo.
+    committed: Option<NavigationViewState>,
+    last_kind: Option<ViewCheckpointKind>,
+}
+
+impl<R: Renderer> ChartEngine<R> {
+    #[must_use]
+    pub fn navigation_history_behavior(&self) -> NavigationHistoryBehavior {
+        self.core.behavior.navigation_history_behavior
+    }
+
+    /// Shrinking `max_entries` drops the oldest undo steps; `0` clears the
+    /// history.
+    pub fn set_navigation_history_behavior(&mut self, behavior: NavigationHistoryBehavior) {
+        self.core.behavior.navigation_history_behavior = behavior;
+        let history = &mut self.core.runtime.navigation_history;
+        while history.undo.len() > behavior.max_entries {
+            history.undo.pop_front();
+        }
+        if behavior.max_entries == 0 {
+            history.redo.clear();
+        }
+    }
+
+    #[must_use]
+    pub fn navigation_view_state(&self) -> NavigationViewState {
+        NavigationViewState {
+            time_visible_range: self.core.model.time_scale.visible_range(),
+            price_domain: self.core.model.price_scale.domain(),
+            price_scale_mode: self.core.model.price_scale_mode,
+        }
+    }
+
+    /// Records the current view as an undo step, e.g. after a host-driven
+    /// navigation the chart cannot see the end of.
+    pub fn checkpoint_view(&mut self) {
+        self.checkpoint_navigation_view(ViewCheckpointKind::Discrete);
+    }
+
+    #[must_use]
+    pub fn can_undo_view(&self) -> bool {
+        let history = &self.core.runtime.navigation_history;
+        !history.undo.is_empty()
+            || history
+                .committed
+                .is_some_and(|committed| committed != self.navigation_view_state())
+    }
+
+    #[must_use]
+    pub fn can_redo_view(&self) -> bool {
+        let history = &self.core.runtime.navigation_history;
+        !history.redo.is_empty()
+            && history
+                .committed
+                .is_none_or(|committed| committed == self.navigation_view_state())
+    }
+
+    /// Restores the view before the latest checkpoint. Changes made since
+    /// that checkpoint are checkpointed first, so they can be redone.
+    ///
+    /// Returns `true` when a view was restored.
+    pub fn undo_view(&mut self) -> ChartResult<bool> {
+        self.checkpoint_view();
+        let history = &mut self.core.runtime.navigation_history;
+        let Some(target) = history.undo.pop_back() else {
+            return Ok(false);
+        };
+        if let Some(current) = history.committed {
+            history.redo.push(current);
+        }
+        self.restore_navigation_view(target)?;
+        Ok(true)
+    }
+
+    /// Re-applies the view left by `undo_view`. Any navigation since the
+    /// undo discards the redo steps.
+    ///
+    /// Returns `true` when a view was restored.
+    pub fn redo_view(&mut self) -> ChartResult<bool> {
+        self.checkpoint_view();
+        let history = &mut self.core.runtime.navigation_history;
+        let Some(target) = history.redo.pop() else {
+            return Ok(false);
+        };
+        if let Some(current) = history.committed {
+            history.undo.push_back(current);
+        }
+        self.restore_navigation_view(target)?;
+        Ok(true)
+    }
+
+    /// Drops all undo/redo steps and makes the current view the baseline.
+    pub fn clear_view_history(&mut self) {
+        let current = self.navigation_view_state();
+        let history = &mut self.core.runtime.navigation_history;
+        history.undo.clear();
+        history.redo.clear();
+        history.committed = Some(current);
+        history.last_kind = None;
+    }
+
+    pub(super) fn checkpoint_navigation_view(&mut self, kind: ViewCheckpointKind) {
+        let max_entries = self.core.behavior.navigation_history_behavior.max_entries;
+        let current = self.navigation_view_state();
+        let history = &mut self.core.runtime.navigation_history;
+        let Some(committed) = history.committed else {
+            history.committed = Some(current);
+            return;
+        };
+        if committed == current || max_entries == 0 {
+            history.committed = Some(current);
+            return;
+        }
+        let merges = history
+            .last_kind
+            .is_some_and(|previous| kind.merges_into(previous))
+            && !history.undo.is_empty();
+        if !merges {
+            history.undo.push_back(committed);
+            while history.undo.len() > max_entries {
+                history.undo.pop_front();
+            }
+        }
+        history.redo.clear();
+        history.committed = Some(current);
+        history.last_kind = Some(kind);
+    }
+
+    fn restore_navigation_view(&mut self, target: NavigationViewState) -> ChartResult<()> {
+        self.apply_navigation_view(target)?;
+        let restored = self.navigation_view_state();
+        let history = &mut self.core.runtime.navigation_history;
+        history.committed = Some(restored);
+        history.last_kind = None;
+        Ok(())
+    }
+
+    /// Writes `target` into the scales without touching the history. The
+    /// price domain goes last so visible-range autoscale cannot override it.
+    pub(super) fn apply_navigation_view(&mut self, target: NavigationViewState) -> ChartResult<()> {
+        let (start, end) = target.time_visible_range;
+        self.set_time_visible_range(start, end)?;
+        if self.core.model.price_scale_mode != target.price_scale_mode
+            || self.core.model.price_scale.domain() != target.price_domain
+        {
+            self.core.model.price_scale_mode = target.price_scale_mode;
+            PriceScaleCoordinator::rebuild_price_scale_from_domain_preserving_mode(
+                self,
+                target.price_domain.0,
+                target.price_domain.1,
+            )?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,198 @@
+use crate::core::{OhlcBar, PaneId};
+use crate::error::ChartResult;
+use crate::render::{
+    CanvasLayerKind, ClipRect, Color, LayeredRenderFrame, RenderFrame, Renderer, TextHAlign,
+    TextPrimitive,
+};
+
+use super::data_window::{nearest_by_time, percent_change};
+use super::validation::validate_ohlc_info_line_behavior;
+use super::{ChartEngine, OhlcInfoLineBehavior, OhlcInfoLineMode};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OhlcInfoDirection {
+    Up,
+    Down,
+    Neutral,
+}
+
+impl OhlcInfoDirection {
+    fn of(delta: f64) -> Self {
+        if delta > 0.0 {
+            Self::Up
+        } else if delta < 0.0 {
+            Self::Down
+        } else {
+            Self::Neutral
+        }
+    }
+}
+
+/// One labeled value of the info line; the change fields have no label.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OhlcInfoField {
+    pub label: &'static str,
+    pub value: String,
+    pub direction: OhlcInfoDirection,
+    pub color: Color,
+}
+
+/// Readout for the hovered bar, or the latest bar without a crosshair.
+///
+/// `change` is measured against the previous bar's close (the bar's own
+/// open for the first bar). O/H/L/C are colored by the bar's direction and
+/// the change fields by the change's sign.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OhlcInfoLine {
+    pub bar: OhlcBar,
+    pub hovered: bool,
+    pub change: f64,
+    pub change_percent: Option<f64>,
+    /// `O`, `H`, `L`, `C`, change, and percent change, in display order.
+    pub fields: Vec<OhlcInfoField>,
+}
+
+impl<R: Renderer> ChartEngine<R> {
+    #[must_use]
+    pub fn ohlc_info_line_behavior(&self) -> OhlcInfoLineBehavior {
+        self.core.behavior.ohlc_info_line_behavior
+    }
+
+    pub fn set_ohlc_info_line_behavior(
+        &mut self,
+        behavior: OhlcInfoLineBehavior,
+    ) -> ChartResult<()> {
+        validate_ohlc_info_line_behavior(behavior)?;
+        if self.core.behavior.ohlc_info_line_behavior != behavior {
+            self.core.behavior.ohlc_info_line_behavior = behavior;
+            self.invalidate_full();
+        }
+        Ok(())
+    }
+
+    /// Info line data, or `None` while the mode is `Hidden` or no candles
+    /// are loaded.
+    #[must_use]
+    pub fn ohlc_info_line(&self) -> Option<OhlcInfoLine> {
+        let behavior = self.core.behavior.ohlc_info_line_behavior;
+        if behavior.mode == OhlcInfoLineMode::Hidden {
+            return None;
+        }
+        let candles = &self.core.model.candles;
+        let hovered = self
+            .hovered_time()
+            .and_then(|time| nearest_by_time(candles, time, |bar| bar.time));
+        let bar = *hovered.or(candles.last())?;
+        let index = candles.partition_point(|candle| candle.time < bar.time);
+        let reference = index
+            .checked_sub(1)
+            .and_then(|previous| candles.get(previous))
+            .map_or(bar.open, |previous| previous.close);
+        let change = bar.close - reference;
+        let change_percent = percent_change(reference, bar.close);
+
+        let style = &self.core.presentation.render_style;
+        let color_of = |direction| match direction {
+            OhlcInfoDirection::Up => style.candlestick_up_color,
+            OhlcInfoDirection::Down => style.candlestick_down_color,
+            OhlcInfoDirection::Neutral => behavior.neutral_color,
+        };
+        let bar_direction = OhlcInfoDirection::of(bar.close - bar.open);
+        let change_direction = OhlcInfoDirection::of(change);
+        let field = |label, value, direction| OhlcInfoField {
+            label,
+            value,
+            direction,
+            color: color_of(direction),
+        };
+
+        let mut fields = vec![
+            field(
+                "O",
+                self.format_price_for_description(bar.open),
+                bar_direction,
+            ),
+            field(
+                "H",
+                self.format_price_for_description(bar.high),
+                bar_direction,
+            ),
+            field(
+                "L",
+                self.format_price_for_description(bar.low),
+                bar_direction,
+            ),
+            field(
+                "C",
+                self.format_price_for_description(bar.close),
+                bar_direction,
+            ),
+        ];
+        let sign = if change > 0.0 { "+" } else { "" };
+        fields.push(field(
+            "",
+            format!("{sign}{}", self.format_price_for_description(change)),
+            change_direction,
+        ));
+        if let Some(percent) = change_percent {
+            fields.push(field("", format!("({percent:+.2}%)"), change_direction));
+        }
+
+        Some(OhlcInfoLine {
+            bar,
+            hovered: hovered.is_some(),
+            change,
+            change_percent,
+            fields,
+        })
+    }
+
+    /// Draws the info line in the main pane's crosshair layer so pointer
+    /// moves repaint it along with the crosshair.
+    pub(super) fn append_ohlc_info_line_primitives(
+        &self,
+        frame: &mut RenderFrame,
+        layered: &mut LayeredRenderFrame,
+        main_pane_id: PaneId,
+        plot_clip: ClipRect,
+    ) {
+        let behavior = self.core.behavior.ohlc_info_line_behavior;
+        if behavior.mode != OhlcInfoLineMode::Rendered {
+            return;
+        }
+        let Some(line) = self.ohlc_info_line() else {
+            return;
+        };
+
+        let font_size = behavior.font_size_px;
+        let label_gap = font_size * 0.25;
+        let y = behavior.padding_px;
+        let mut x = behavior.padding_px;
+        let mut push_text = |text: &str, x: f64, color: Color| {
+            let text = TextPrimitive::new(text, x, y, font_size, color, TextHAlign::Left)
+                .with_clip_rect(plot_clip);
+            frame.texts.push(text.clone());
+            layered.push_text(main_pane_id, CanvasLayerKind::Crosshair, text);
+        };
+        for field in &line.fields {
+            if !field.label.is_empty() {
+                push_text(field.label, x, behavior.label_color);
+                x += self
+                    .core
+                    .presentation
+                    .render_style
+                    .label_text_metrics
+                    .text_width_px(field.label, font_size)
+                    + label_gap;
+            }
+            push_text(&field.value, x, field.color);
+            x += self
+                .core
+                .presentation
+                .render_style
+                .label_text_metrics
+                .text_width_px(&field.value, font_size)
+                + behavior.field_gap_px;
+        }
+    }
+}
@@ -123,14 +123,19 @@ impl<R: Renderer> ChartEngine<R> {
 
     #[must_use]
     pub fn pane_plot_regions_for_current_viewport(&self) -> Vec<PaneLayoutRegion> {
+        self.pane_layout_regions(0.0, self.current_plot_bottom())
+    }
+
+    /// Bottom of the plot area (top of the time axis) for the current viewport.
+    pub(super) fn current_plot_bottom(&self) -> f64 {
         let viewport_width = f64::from(self.core.model.viewport.width);
         let viewport_height = f64::from(self.core.model.viewport.height);
-        let layout = resolve_axis_layout(
+        resolve_axis_layout(
             viewport_width,
             viewport_height,
-            self.core.presentation.render_style.price_axis_width_px,
+            self.layout_price_axis_width_px(),
             self.core.presentation.render_style.time_axis_height_px,
-        );
-        self.pane_layout_regions(0.0, layout.plot_bottom)
+        )
+        .plot_bottom
     }
 }
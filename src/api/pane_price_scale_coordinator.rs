@@ -46,6 +46,21 @@ impl<R: Renderer> ChartEngine<R> {
             }
         }
 
+        if self.compare_pane_id() == pane_id {
+            for point in self.rebased_compare_values() {
+                if !is_visible(point.x) {
+                    continue;
+                }
+                min_price = min_price.min(point.y);
+                max_price = max_price.max(point.y);
+            }
+        }
+
+        if let Some((low, high)) = self.custom_series_price_extents(pane_id, visible_range) {
+            min_price = min_price.min(low);
+            max_price = max_price.max(high);
+        }
+
         if !min_price.is_finite() || !max_price.is_finite() {
             return None;
         }
@@ -14,8 +14,9 @@ impl PaneRenderExecutor {
         layered: &LayeredRenderFrame,
         plan: &PartialCairoRenderPlan,
     ) -> Vec<PanePartialRenderTask> {
-        let viewport_width = f64::from(engine.core.model.viewport.width);
-        let mut tasks = collect_plot_tasks(layered, plan, viewport_width);
+        let content_left = f64::from(engine.core.behavior.layout_insets.left);
+        let content_width = f64::from(engine.core.model.viewport.width);
+        let mut tasks = collect_plot_tasks(layered, plan, content_left, content_width);
         if let Some(axis_task) = collect_main_axis_task(engine.main_pane_id(), layered) {
             tasks.push(axis_task);
         }
@@ -0,0 +1,109 @@
+use crate::core::PaneId;
+use crate::error::ChartResult;
+use crate::render::Renderer;
+
+use super::interaction_validation::validate_pane_separator_behavior;
+use super::{ChartEngine, PaneSeparatorBehavior};
+
+/// Boundary between two vertically adjacent panes.
+///
+/// `y` is in content coordinates (see `ChartEngine::content_viewport`).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PaneSeparator {
+    /// Position among the separators, top to bottom.
+    pub index: usize,
+    pub upper_pane_id: PaneId,
+    pub lower_pane_id: PaneId,
+    pub y: f64,
+}
+
+impl<R: Renderer> ChartEngine<R> {
+    #[must_use]
+    pub fn pane_separator_behavior(&self) -> PaneSeparatorBehavior {
+        self.core.behavior.pane_separator_behavior
+    }
+
+    pub fn set_pane_separator_behavior(
+        &mut self,
+        behavior: PaneSeparatorBehavior,
+    ) -> ChartResult<()> {
+        validate_pane_separator_behavior(behavior)?;
+        self.core.behavior.pane_separator_behavior = behavior;
+        Ok(())
+    }
+
+    /// Separators of the current pane layout, top to bottom.
+    #[must_use]
+    pub fn pane_separators(&self) -> Vec<PaneSeparator> {
+        self.pane_plot_regions_for_current_viewport()
+            .windows(2)
+            .enumerate()
+            .map(|(index, pair)| PaneSeparator {
+                index,
+                upper_pane_id: pair[0].pane_id,
+                lower_pane_id: pair[1].pane_id,
+                y: pair[0].plot_bottom,
+            })
+            .collect()
+    }
+
+    /// Draggable separator whose hit zone contains content position
+    /// `(x, y)`, spanning the plot and the price axis.
+    #[must_use]
+    pub fn pane_separator_at(&self, x: f64, y: f64) -> Option<PaneSeparator> {
+        let behavior = self.core.behavior.pane_separator_behavior;
+        let width = f64::from(self.core.model.viewport.width);
+        if !behavior.draggable || !(0.0..=width).contains(&x) {
+            return None;
+        }
+        self.pane_separators()
+            .into_iter()
+            .filter(|separator| (separator.y - y).abs() <= behavior.hit_zone_half_height_px)
+            .min_by(|left, right| (left.y - y).abs().total_cmp(&(right.y - y).abs()))
+    }
+
+    /// Moves separator `index` by `delta_y_px`, trading height between its
+    /// two panes while keeping both at least `min_pane_height_px` tall (or
+    /// half their combined height when that is smaller). Other panes keep
+    /// their height; the result is stored as normalized stretch factors.
+    ///
+    /// Returns `false` when `index` is unknown or the separator did not move.
+    pub fn drag_pane_separator(&mut self, index: usize, delta_y_px: f64) -> ChartResult<bool> {
+        if !delta_y_px.is_finite() {
+            return Ok(false);
+        }
+        let regions = self.pane_plot_regions_for_current_viewport();
+        if index + 1 >= regions.len() {
+            return Ok(false);
+        }
+        let mut heights: Vec<f64> = regions.iter().map(|region| region.height()).collect();
+        let total: f64 = heights.iter().sum();
+        if total <= 0.0 {
+            return Ok(false);
+        }
+
+        let combined = heights[index] + heights[index + 1];
+        let min_height = self
+            .core
+            .behavior
+            .pane_separator_behavior
+            .min_pane_height_px
+            .min(combined * 0.5);
+        let upper = (heights[index] + delta_y_px).clamp(min_height, combined - min_height);
+        if (upper - heights[index]).abs() <= f64::EPSILON {
+            return Ok(false);
+        }
+        heights[index] = upper;
+        heights[index + 1] = combined - upper;
+
+        let panes = &mut self.core.model.pane_collection;
+        for (region, height) in regions.iter().zip(heights) {
+            // A zero-height pane keeps a tiny positive weight so stretch
+            // factors stay valid.
+            panes.set_stretch_factor(region.pane_id, (height / total).max(f64::EPSILON))?;
+        }
+        panes.normalize_stretch_factors();
+        self.invalidate_pane_layout();
+        Ok(true)
+    }
+}
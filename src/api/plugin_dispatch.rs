@@ -2,7 +2,9 @@ use crate::extensions::{PluginContext, PluginEvent};
 use crate::render::Renderer;
 use tracing::warn;
 
-use super::{ChartEngine, InvalidationLevel, InvalidationTopic, InvalidationTopics};
+use super::{
+    ChartEngine, InvalidationLevel, InvalidationTopic, InvalidationTopics, PrimitiveOwner,
+};
 
 impl<R: Renderer> ChartEngine<R> {
     pub(super) fn plugin_context(&self) -> PluginContext {
@@ -32,15 +34,48 @@ impl<R: Renderer> ChartEngine<R> {
             PluginEvent::PointerMoved { .. } | PluginEvent::PointerLeft => {
                 self.invalidate_cursor();
             }
-            PluginEvent::PanStarted | PluginEvent::PanEnded => {
+            PluginEvent::PanStarted
+            | PluginEvent::PanEnded
+            | PluginEvent::ZoomSelectionStarted { .. }
+            | PluginEvent::ZoomSelectionCompleted { .. }
+            | PluginEvent::ZoomSelectionCancelled => {
                 self.invalidate_cursor();
             }
-            PluginEvent::Rendered => {}
+            PluginEvent::Rendered
+            | PluginEvent::PrimitiveHovered { .. }
+            | PluginEvent::PrimitiveHoverEnded { .. }
+            | PluginEvent::PrimitiveClicked { .. }
+            | PluginEvent::OrderPriceChanged { .. }
+            | PluginEvent::AlertTriggered { .. }
+            | PluginEvent::SelectionChanged { .. } => {}
         }
+        let data_updated = matches!(
+            event,
+            PluginEvent::DataUpdated { .. } | PluginEvent::CandlesUpdated { .. }
+        );
+
+        let context = self.plugin_context();
+        for plugin in &mut self.core.runtime.plugins {
+            plugin.on_event(event.clone(), context);
+        }
+        if data_updated {
+            self.clear_data_gap_cache();
+            self.check_price_alert_crossings();
+        }
+    }
 
+    /// Delivers a hit-region event to the plugin owning the region, or to
+    /// every plugin for custom-series regions.
+    pub(super) fn emit_primitive_event(&mut self, owner: &PrimitiveOwner, event: PluginEvent) {
         let context = self.plugin_context();
         for plugin in &mut self.core.runtime.plugins {
-            plugin.on_event(event, context);
+            let routed = match owner {
+                PrimitiveOwner::Plugin(plugin_id) => plugin.id() == plugin_id,
+                PrimitiveOwner::CustomSeries(_) => true,
+            };
+            if routed {
+                plugin.on_event(event.clone(), context);
+            }
         }
     }
 
@@ -85,7 +120,6 @@ impl<R: Renderer> ChartEngine<R> {
                 .with_topic(InvalidationTopic::Axis),
             None,
         );
-        let (start, end) = self.core.model.time_scale.visible_range();
-        self.emit_plugin_event(PluginEvent::VisibleRangeChanged { start, end });
+        self.notify_visible_range_changed();
     }
 }
@@ -0,0 +1,729 @@
+use crate::error::ChartResult;
+use crate::render::Renderer;
+
+use super::{ChartAxis, ChartEngine, DataSelectionSeries};
+
+/// Wheel delta passed to the engine per scroll notch.
+pub(super) const WHEEL_DELTA_PER_NOTCH: f64 = 120.0;
+/// Pointer travel between press and release still treated as a click
+/// (routed to `ChartEngine::pointer_click`).
+const CLICK_SLOP_PX: f64 = 3.0;
+
+/// Windowing-system-neutral pointer input, in widget pixel coordinates.
+///
+/// Platform adapters translate native events into these and feed them to a
+/// `PointerInputBridge`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PointerInput {
+    Moved {
+        x: f64,
+        y: f64,
+    },
+    Left,
+    /// Primary button pressed; starts a drag.
+    Pressed {
+        x: f64,
+        y: f64,
+    },
+    Released,
+    /// Scroll in notches (`1.0` per wheel click), applied through
+    /// `ChartEngine::handle_wheel`; under the default `WheelBehaviorConfig`
+    /// positive `delta_y` zooms out and positive `delta_x` pans towards
+    /// later times.
+    Scrolled {
+        delta_x: f64,
+        delta_y: f64,
+    },
+    /// Incremental pinch factor since the previous pinch event.
+    Pinched {
+        factor: f64,
+        anchor_x: f64,
+    },
+    DoubleClicked {
+        x: f64,
+        y: f64,
+    },
+    /// Keyboard modifiers held from now on; applies to later presses and
+    /// scrolls.
+    ModifiersChanged {
+        modifiers: PointerModifiers,
+    },
+    /// Aborts the active gesture (e.g. Escape): a zoom selection or order
+    /// drag is cancelled and the release is not routed as a click.
+    Cancelled,
+}
+
+/// Keyboard modifiers accompanying pointer input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PointerModifiers {
+    pub alt: bool,
+    pub shift: bool,
+    pub control: bool,
+}
+
+impl PointerModifiers {
+    pub const NONE: Self = Self {
+        alt: false,
+        shift: false,
+        control: false,
+    };
+    pub const ALT: Self = Self {
+        alt: true,
+        shift: false,
+        control: false,
+    };
+    pub const SHIFT: Self = Self {
+        alt: false,
+        shift: true,
+        control: false,
+    };
+    pub const CONTROL: Self = Self {
+        alt: false,
+        shift: false,
+        control: true,
+    };
+
+    /// Whether every modifier held in `required` is held here.
+    #[must_use]
+    pub fn contains(self, required: Self) -> bool {
+        (self.alt || !required.alt)
+            && (self.shift || !required.shift)
+            && (self.control || !required.control)
+    }
+}
+
+/// Gestures a `PointerInputBridge` reacts to.
+///
+/// Every gesture is enabled by default; the minimum span is forwarded to the
+/// matching `ChartEngine` interaction method. Scroll input always goes
+/// through `ChartEngine::handle_wheel`, so the engine's `WheelBehaviorConfig`
+/// decides what the wheel does.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct InteractionOptions {
+    /// Pointer motion/leave driving the crosshair.
+    pub pointer_motion: bool,
+    /// Dragging inside the plot panning the time scale.
+    pub drag_pan: bool,
+    /// Dragging the price axis scaling the price scale.
+    pub price_axis_drag: bool,
+    /// Dragging the time axis scaling the time scale.
+    pub time_axis_drag: bool,
+    /// Dragging a pane separator resizing the adjacent panes.
+    pub pane_separator_drag: bool,
+    /// Dragging a draggable working-order line to a new price.
+    pub order_drag: bool,
+    /// Modifiers that turn a press on the plot or price axis into
+    /// `ChartEngine::create_price_alert_at` (alt by default); `None`
+    /// disables alert creation.
+    pub alert_creation: Option<PointerModifiers>,
+    /// Modifiers that turn a plot drag into a rubber-band zoom selection
+    /// (shift by default); `None` disables it.
+    pub zoom_selection: Option<PointerModifiers>,
+    /// Modifiers that turn a plot drag into a box data selection of
+    /// `data_selection_series`; `None` (the default) disables it.
+    pub data_selection: Option<PointerModifiers>,
+    pub data_selection_series: DataSelectionSeries,
+    pub pinch_zoom: bool,
+    /// Double-click on an axis resetting that axis' scale.
+    pub double_click_reset: bool,
+    /// Minimum visible time span for pinch zoom; wheel zoom and axis drags
+    /// use the engine's `AxisInteractionConfig`.
+    pub min_time_span: f64,
+}
+
+impl Default for InteractionOptions {
+    fn default() -> Self {
+        Self {
+            pointer_motion: true,
+            drag_pan: true,
+            price_axis_drag: true,
+            time_axis_drag: true,
+            pane_separator_drag: true,
+            order_drag: true,
+            alert_creation: Some(PointerModifiers::ALT),
+            zoom_selection: Some(PointerModifiers::SHIFT),
+            data_selection: None,
+            data_selection_series: DataSelectionSeries::Candles,
+            pinch_zoom: true,
+            double_click_reset: true,
+            min_time_span: 1.0,
+        }
+    }
+}
+
+impl InteractionOptions {
+    #[must_use]
+    pub fn with_pointer_motion(mut self, enabled: bool) -> Self {
+        self.pointer_motion = enabled;
+        self
+    }
+
+    #[must_use]
+    pub fn with_drag_pan(mut self, enabled: bool) -> Self {
+        self.drag_pan = enabled;
+        self
+    }
+
+    #[must_use]
+    pub fn with_price_axis_drag(mut self, enabled: bool) -> Self {
+        self.price_axis_drag = enabled;
+        self
+    }
+
+    #[must_use]
+    pub fn with_time_axis_drag(mut self, enabled: bool) -> Self {
+        self.time_axis_drag = enabled;
+        self
+    }
+
+    #[must_use]
+    pub fn with_pane_separator_drag(mut self, enabled: bool) -> Self {
+        self.pane_separator_drag = enabled;
+        self
+    }
+
+    #[must_use]
+    pub fn with_order_drag(mut self, enabled: bool) -> Self {
+        self.order_drag = enabled;
+        self
+    }
+
+    #[must_use]
+    pub fn with_alert_creation(mut self, modifiers: Option<PointerModifiers>) -> Self {
+        self.alert_creation = modifiers;
+        self
+    }
+
+    #[must_use]
+    pub fn with_zoom_selection(mut self, modifiers: Option<PointerModifiers>) -> Self {
+        self.zoom_selection = modifiers;
+        self
+    }
+
+    #[must_use]
+    pub fn with_data_selection(
+        mut self,
+        modifiers: Option<PointerModifiers>,
+        series: DataSelectionSeries,
+    ) -> Self {
+        self.data_selection = modifiers;
+        self.data_selection_series = series;
+        self
+    }
+
+    #[must_use]
+    pub fn with_pinch_zoom(mut self, enabled: bool) -> Self {
+        self.pinch_zoom = enabled;
+        self
+    }
+
+    #[must_use]
+    pub fn with_double_click_reset(mut self, enabled: bool) -> Self {
+        self.double_click_reset = enabled;
+        self
+    }
+
+    #[must_use]
+    pub fn with_min_time_span(mut self, span: f64) -> Self {
+        self.min_time_span = span;
+        self
+    }
+}
+
+/// Chart section under a pointer position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PointerRegion {
+    Plot,
+    PriceAxis,
+    TimeAxis,
+    /// Hit zone of the pane separator at `index` (see `PaneSeparator`).
+    PaneSeparator {
+        index: usize,
+    },
+    /// Layout inset margin (or beyond the widget), outside the chart.
+    Outside,
+}
+
+/// Pointer cursor a host should show, as tracked by `PointerInputBridge`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PointerCursorHint {
+    #[default]
+    Default,
+    /// Over or dragging a pane separator or a working-order line.
+    ResizeVertical,
+}
+
+/// What an active drag drives.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DragTarget {
+    /// Pans, scales or resizes according to the pressed region.
+    Region,
+    /// Moves a working-order line instead of panning the plot.
+    OrderLine,
+    /// Draws a rubber-band zoom selection.
+    ZoomSelection,
+    /// Draws a rubber-band data selection.
+    DataSelection,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct ActiveDrag {
+    region: PointerRegion,
+    start_y: f64,
+    target: DragTarget,
+}
+
+/// Stateful translation of `PointerInput` into engine interaction calls.
+///
+/// Tracks the pointer position (scroll zoom anchors on it), the active
+/// drag, and the cursor hint, so adapters only forward events.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PointerInputBridge {
+    options: InteractionOptions,
+    pointer: Option<(f64, f64)>,
+    drag: Option<ActiveDrag>,
+    pressed_at: Option<(f64, f64)>,
+    modifiers: PointerModifiers,
+    cursor_hint: PointerCursorHint,
+    reported_cursor_hint: PointerCursorHint,
+}
+
+impl PointerInputBridge {
+    #[must_use]
+    pub fn new(options: InteractionOptions) -> Self {
+        Self {
+            options,
+            pointer: None,
+            drag: None,
+            pressed_at: None,
+            modifiers: PointerModifiers::NONE,
+            cursor_hint: PointerCursorHint::Default,
+            reported_cursor_hint: PointerCursorHint::Default,
+        }
+    }
+
+    #[must_use]
+    pub fn options(&self) -> InteractionOptions {
+        self.options
+    }
+
+    #[must_use]
+    pub fn is_dragging(&self) -> bool {
+        self.drag.is_some()
+    }
+
+    #[must_use]
+    pub fn cursor_hint(&self) -> PointerCursorHint {
+        self.cursor_hint
+    }
+
+    /// Returns the cursor hint once after it changed, so hosts only touch
+    /// their native cursor on transitions.
+    pub fn take_cursor_hint_change(&mut self) -> Option<PointerCursorHint> {
+        if self.cursor_hint == self.reported_cursor_hint {
+            return None;
+        }
+        self.reported_cursor_hint = self.cursor_hint;
+        Some(self.cursor_hint)
+    }
+
+    /// Applies one input event. Errors come from the engine call the event
+    /// mapped to; the bridge state stays consistent either way.
+    pub fn handle<R: Renderer>(
+        &mut self,
+        engine: &mut ChartEngine<R>,
+        input: PointerInput,
+    ) -> ChartResult<()> {
+        engine.note_user_activity();
+        match input {
+            PointerInput::Moved { x, y } => {
+                let previous = self.pointer.replace((x, y));
+                let region = engine.pointer_region(x, y);
+                let (content_x, content_y) = engine.widget_to_content(x, y);
+                if self.options.pointer_motion {
+                    if self.drag.is_none() && region == PointerRegion::Outside {
+                        engine.pointer_leave();
+                    } else {
+                        engine.pointer_move(content_x, content_y);
+                    }
+                }
+                let over_order_line = self.over_order_line(engine, region, content_x, content_y);
+                self.cursor_hint = self.resolve_cursor_hint(region, over_order_line);
+                match (self.drag.map(|drag| (drag, drag.target)), previous) {
+                    (Some((_, DragTarget::OrderLine)), _) => engine.drag_order_to(content_y),
+                    (Some((_, DragTarget::ZoomSelection)), _) => {
+                        engine.update_zoom_selection(content_x, content_y);
+                        Ok(())
+                    }
+                    (Some((_, DragTarget::DataSelection)), _) => {
+                        engine.update_data_selection(content_x, content_y);
+                        Ok(())
+                    }
+                    (Some((drag, DragTarget::Region)), Some((previous_x, previous_y))) => {
+                        self.drag_by(engine, drag, x - previous_x, y - previous_y)
+                    }
+                    _ => Ok(()),
+                }
+            }
+            PointerInput::Left => {
+                self.pointer = None;
+                if self.drag.is_none() {
+                    self.cursor_hint = PointerCursorHint::Default;
+                }
+                if self.options.pointer_motion {
+                    engine.pointer_leave();
+                }
+                Ok(())
+            }
+            PointerInput::Pressed { x, y } => {
+                self.pointer = Some((x, y));
+                let region = engine.pointer_region(x, y);
+                if self.creates_alert(region) {
+                    // A modifier-press only creates the alert: no drag, and
+                    // the release is not routed as a click.
+                    self.pressed_at = None;
+                    self.drag = None;
+                    let (_, y) = engine.widget_to_content(x, y);
+                    engine.create_price_alert_at(y)?;
+                    return Ok(());
+                }
+                if let Some(target) = self.selection_target(region) {
+                    // Like alert creation, the release is not a click.
+                    self.pressed_at = None;
+                    let (content_x, start_y) = engine.widget_to_content(x, y);
+                    if target == DragTarget::ZoomSelection {
+                        engine.begin_zoom_selection(content_x, start_y);
+                    } else {
+                        engine.begin_data_selection(
+                            self.options.data_selection_series,
+                            content_x,
+                            start_y,
+                        );
+                    }
+                    self.drag = Some(ActiveDrag {
+                        region,
+                        start_y,
+                        target,
+                    });
+                    return Ok(());
+                }
+                self.pressed_at = Some((x, y));
+                let enabled = match region {
+                    PointerRegion::Plot => self.options.drag_pan,
+                    PointerRegion::PriceAxis => self.options.price_axis_drag,
+                    PointerRegion::TimeAxis => self.options.time_axis_drag,
+                    PointerRegion::PaneSeparator { .. } => self.options.pane_separator_drag,
+                    PointerRegion::Outside => false,
+                };
+                let (content_x, start_y) = engine.widget_to_content(x, y);
+                let order_line = region == PointerRegion::Plot
+                    && self.options.order_drag
+                    && engine.begin_order_drag(content_x, start_y);
+                self.drag = (enabled || order_line).then_some(ActiveDrag {
+                    region,
+                    start_y,
+                    target: if order_line {
+                        DragTarget::OrderLine
+                    } else {
+                        DragTarget::Region
+                    },
+                });
+                if enabled && !order_line && region == PointerRegion::Plot {
+                    engine.pan_start();
+                }
+                Ok(())
+            }
+            PointerInput::Released => {
+                let mut result = Ok(());
+                match self.drag.take().map(|drag| (drag.region, drag.target)) {
+                    Some((_, DragTarget::OrderLine)) => {
+                        engine.end_order_drag();
+                    }
+                    Some((_, DragTarget::ZoomSelection)) => {
+                        result = engine.end_zoom_selection().map(|_| ());
+                    }
+                    Some((_, DragTarget::DataSelection)) => {
+                        result = engine.end_data_selection().map(|_| ());
+                    }
+                    Some((PointerRegion::Plot, DragTarget::Region)) => engine.pan_end(),
+                    _ => {}
+                }
+                if let (Some((press_x, press_y)), Some((x, y))) =
+                    (self.pressed_at.take(), self.pointer)
+                    && (x - press_x).hypot(y - press_y) <= CLICK_SLOP_PX
+                    && engine.pointer_region(x, y) != PointerRegion::Outside
+                {
+                    let (x, y) = engine.widget_to_content(x, y);
+                    engine.pointer_click(x, y);
+                }
+                self.cursor_hint = match self.pointer {
+                    Some((x, y)) => {
+                        let region = engine.pointer_region(x, y);
+                        let (content_x, content_y) = engine.widget_to_content(x, y);
+                        let over_order_line =
+                            self.over_order_line(engine, region, content_x, content_y);
+                        self.resolve_cursor_hint(region, over_order_line)
+                    }
+                    None => PointerCursorHint::Default,
+                };
+                result
+            }
+            PointerInput::Scrolled { delta_x, delta_y } => {
+                let pointer = self.pointer.map(|(x, y)| engine.widget_to_content(x, y));
+                engine.handle_wheel(delta_x, delta_y, self.modifiers, pointer)
+            }
+            PointerInput::Pinched { factor, anchor_x } => {
+                if self.options.pinch_zoom {
+                    let (anchor_x, _) = engine.widget_to_content(anchor_x, 0.0);
+                    engine.pinch_zoom_time_visible(factor, anchor_x, self.options.min_time_span)?;
+                }
+                Ok(())
+            }
+            PointerInput::DoubleClicked { x, y } => {
+                if !self.options.double_click_reset {
+                    return Ok(());
+                }
+                match engine.pointer_region(x, y) {
+                    PointerRegion::PriceAxis => {
+                        engine.axis_double_click_reset_price_scale()?;
+                    }
+                    PointerRegion::TimeAxis => {
+                        engine.axis_double_click_reset_time_scale()?;
+                    }
+                    PointerRegion::Plot
+                    | PointerRegion::PaneSeparator { .. }
+                    | PointerRegion::Outside => {}
+                }
+                Ok(())
+            }
+            PointerInput::ModifiersChanged { modifiers } => {
+                self.modifiers = modifiers;
+                Ok(())
+            }
+            PointerInput::Cancelled => {
+                self.pressed_at = None;
+                match self.drag.take().map(|drag| (drag.region, drag.target)) {
+                    Some((_, DragTarget::OrderLine)) => engine.cancel_order_drag(),
+                    Some((_, DragTarget::ZoomSelection)) => engine.cancel_zoom_selection(),
+                    Some((_, DragTarget::DataSelection)) => engine.cancel_data_selection(),
+                    Some((PointerRegion::Plot, DragTarget::Region)) => engine.pan_end(),
+                    _ => {}
+                }
+                self.cursor_hint = PointerCursorHint::Default;
+                Ok(())
+            }
+        }
+    }
+
+    /// Rubber-band selection a press in `region` starts; zoom selection wins
+    /// when both are bound to the held modifiers.
+    fn selection_target(&self, region: PointerRegion) -> Option<DragTarget> {
+        if region != PointerRegion::Plot {
+            return None;
+        }
+        let held = |modifiers: Option<PointerModifiers>| {
+            modifiers.is_some_and(|required| self.modifiers.contains(required))
+        };
+        if held(self.options.zoom_selection) {
+            Some(DragTarget::ZoomSelection)
+        } else if held(self.options.data_selection) {
+            Some(DragTarget::DataSelection)
+        } else {
+            None
+        }
+    }
+
+    fn creates_alert(&self, region: PointerRegion) -> bool {
+        matches!(region, PointerRegion::Plot | PointerRegion::PriceAxis)
+            && self
+                .options
+                .alert_creation
+                .is_some_and(|required| self.modifiers.contains(required))
+    }
+
+    fn over_order_line<R: Renderer>(
+        &self,
+        engine: &ChartEngine<R>,
+        region: PointerRegion,
+        content_x: f64,
+        content_y: f64,
+    ) -> bool {
+        self.options.order_drag
+            && region == PointerRegion::Plot
+            && engine.working_order_at(content_x, content_y).is_some()
+    }
+
+    fn resolve_cursor_hint(
+        &self,
+        hovered: PointerRegion,
+        over_order_line: bool,
+    ) -> PointerCursorHint {
+        let resizing = match self.drag {
+            Some(drag) => {
+                drag.target == DragTarget::OrderLine
+                    || matches!(drag.region, PointerRegion::PaneSeparator { .. })
+            }
+            None => {
+                over_order_line
+                    || (self.options.pane_separator_drag
+                        && matches!(hovered, PointerRegion::PaneSeparator { .. }))
+            }
+        };
+        if resizing {
+            PointerCursorHint::ResizeVertical
+        } else {
+            PointerCursorHint::Default
+        }
+    }
+
+    fn drag_by<R: Renderer>(
+        &self,
+        engine: &mut ChartEngine<R>,
+        drag: ActiveDrag,
+        delta_x: f64,
+        delta_y: f64,
+    ) -> ChartResult<()> {
+        if delta_x == 0.0 && delta_y == 0.0 {
+            return Ok(());
+        }
+        match drag.region {
+            PointerRegion::Plot => engine.pan_time_visible_by_pixels(delta_x),
+            PointerRegion::PriceAxis => engine
+                .handle_axis_drag(ChartAxis::Price, delta_y, drag.start_y)
+                .map(|_| ()),
+            PointerRegion::TimeAxis => {
+                let anchor_x = f64::from(engine.content_viewport().width) * 0.5;
+                engine
+                    .handle_axis_drag(ChartAxis::Time, delta_x, anchor_x)
+                    .map(|_| ())
+            }
+            PointerRegion::PaneSeparator { index } => {
+                engine.drag_pane_separator(index, delta_y).map(|_| ())
+            }
+            PointerRegion::Outside => Ok(()),
+        }
+    }
+}
+
+impl Default for PointerInputBridge {
+    fn default() -> Self {
+        Self::new(InteractionOptions::default())
+    }
+}
+
+/// Turns touch points into `PointerInput`.
+///
+/// One finger drags like the primary button; a second finger ends the drag
+/// and turns the gesture into a pinch around the midpoint of both touches.
+/// Further fingers are tracked but ignored until the count drops back.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TouchGestureTracker {
+    touches: Vec<(u64, f64, f64)>,
+    pinch_distance: Option<f64>,
+}
+
+impl TouchGestureTracker {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn active_touches(&self) -> usize {
+        self.touches.len()
+    }
+
+    pub fn touch_started(&mut self, id: u64, x: f64, y: f64) -> Vec<PointerInput> {
+        self.touches.retain(|(touch_id, ..)| *touch_id != id);
+        self.touches.push((id, x, y));
+        match self.touches.len() {
+            1 => vec![PointerInput::Moved { x, y }, PointerInput::Pressed { x, y }],
+            2 => {
+                self.pinch_distance = self.touch_distance();
+                vec![PointerInput::Released]
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    pub fn touch_moved(&mut self, id: u64, x: f64, y: f64) -> Vec<PointerInput> {
+        let Some(touch) = self
+            .touches
+            .iter_mut()
+            .find(|(touch_id, ..)| *touch_id == id)
+        else {
+            return Vec::new();
+        };
+        *touch = (id, x, y);
+        match self.touches.len() {
+            1 => vec![PointerInput::Moved { x, y }],
+            2 => self.pinch_update(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Ends (or cancels) touch `id`.
+    pub fn touch_ended(&mut self, id: u64) -> Vec<PointerInput> {
+        let before = self.touches.len();
+        self.touches.retain(|(touch_id, ..)| *touch_id != id);
+        self.pinch_distance = None;
+        if before == 1 && self.touches.is_empty() {
+            vec![PointerInput::Released, PointerInput::Left]
+        } else {
+            Vec::new()
+        }
+    }
+
+    fn pinch_update(&mut self) -> Vec<PointerInput> {
+        let (Some(previous), Some(distance)) = (self.pinch_distance, self.touch_distance()) else {
+            return Vec::new();
+        };
+        self.pinch_distance = Some(distance);
+        if previous <= 0.0 || distance <= 0.0 {
+            return Vec::new();
+        }
+        let anchor_x = (self.touches[0].1 + self.touches[1].1) * 0.5;
+        vec![PointerInput::Pinched {
+            factor: distance / previous,
+            anchor_x,
+        }]
+    }
+
+    fn touch_distance(&self) -> Option<f64> {
+        match self.touches.as_slice() {
+            [(_, x1, y1), (_, x2, y2)] => Some((x2 - x1).hypot(y2 - y1)),
+            _ => None,
+        }
+    }
+}
+
+impl<R: Renderer> ChartEngine<R> {
+    /// Chart section under widget position `(x, y)`, using the styled axis
+    /// panel sizes (the last resolved price-axis width in
+    /// `PriceAxisWidthMode::Auto`). Pane separator hit zones take precedence
+    /// over the plot and price axis; positions in the layout insets are
+    /// `PointerRegion::Outside`.
+    #[must_use]
+    pub fn pointer_region(&self, x: f64, y: f64) -> PointerRegion {
+        let (x, y) = self.widget_to_content(x, y);
+        let viewport = self.content_viewport();
+        if x < 0.0 || y < 0.0 || x > f64::from(viewport.width) || y > f64::from(viewport.height) {
+            return PointerRegion::Outside;
+        }
+        if let Some(separator) = self.pane_separator_at(x, y) {
+            return PointerRegion::PaneSeparator {
+                index: separator.index,
+            };
+        }
+        let style = self.render_style();
+        let plot_right = f64::from(viewport.width) - self.layout_price_axis_width_px();
+        let plot_bottom = f64::from(viewport.height) - style.time_axis_height_px;
+        if x >= plot_right {
+            PointerRegion::PriceAxis
+        } else if y >= plot_bottom {
+            PointerRegion::TimeAxis
+        } else {
+            PointerRegion::Plot
+        }
+    }
+}
@@ -0,0 +1,173 @@
+use crate::core::{PaneId, PriceScale};
+use crate::error::{ChartError, ChartResult};
+use crate::extensions::{AlertDirection, PriceAlert};
+use crate::render::{
+    CanvasLayerKind, ClipRect, Color, LayeredRenderFrame, LinePrimitive, LineStrokeStyle,
+    RectPrimitive, RenderFrame, Renderer, TextHAlign, TextPrimitive, TextVAlign,
+};
+
+use super::{ChartEngine, PluginEvent, SeriesPixelSpace, SeriesRef};
+
+const ALERT_LABEL_FONT_SIZE_PX: f64 = 11.0;
+const ALERT_LABEL_PADDING_PX: f64 = 4.0;
+const ALERT_LABEL_TEXT_COLOR: Color = Color::rgb(1.0, 1.0, 1.0);
+
+impl<R: Renderer> ChartEngine<R> {
+    #[must_use]
+    pub fn price_alerts(&self) -> &[PriceAlert] {
+        &self.core.model.price_alerts
+    }
+
+    /// Replaces all price alerts. Ids must be unique.
+    pub fn set_price_alerts(&mut self, alerts: Vec<PriceAlert>) -> ChartResult<()> {
+        for (index, alert) in alerts.iter().enumerate() {
+            alert.validate()?;
+            if alerts[..index].iter().any(|other| other.id == alert.id) {
+                return Err(duplicate_alert_id(&alert.id));
+            }
+        }
+        self.core.model.price_alerts = alerts;
+        self.invalidate_full();
+        Ok(())
+    }
+
+    pub fn add_price_alert(&mut self, alert: PriceAlert) -> ChartResult<()> {
+        alert.validate()?;
+        if self.price_alert(&alert.id).is_some() {
+            return Err(duplicate_alert_id(&alert.id));
+        }
+        self.core.model.price_alerts.push(alert);
+        self.invalidate_full();
+        Ok(())
+    }
+
+    /// Removes alert `id`; returns `false` for unknown ids.
+    pub fn remove_price_alert(&mut self, id: &str) -> bool {
+        let before = self.core.model.price_alerts.len();
+        self.core.model.price_alerts.retain(|alert| alert.id != id);
+        let removed = self.core.model.price_alerts.len() != before;
+        if removed {
+            self.invalidate_full();
+        }
+        removed
+    }
+
+    #[must_use]
+    pub fn price_alert(&self, id: &str) -> Option<&PriceAlert> {
+        self.core
+            .model
+            .price_alerts
+            .iter()
+            .find(|alert| alert.id == id)
+    }
+
+    /// Creates an alert at the candle-pane price of content row `y`, with a
+    /// generated `alert-N` id, and returns the id.
+    ///
+    /// `PointerInputBridge` calls this for modifier-clicks on the plot or
+    /// price axis (see `InteractionOptions::alert_creation`).
+    pub fn create_price_alert_at(&mut self, y: f64) -> ChartResult<String> {
+        let price = self.series_pixel_to_price(SeriesRef::Candles, y, SeriesPixelSpace::Content)?;
+        let id = loop {
+            let id = format!("alert-{}", self.core.runtime.next_price_alert_id);
+            self.core.runtime.next_price_alert_id += 1;
+            if self.price_alert(&id).is_none() {
+                break id;
+            }
+        };
+        self.add_price_alert(PriceAlert::new(id.clone(), price))?;
+        Ok(id)
+    }
+
+    /// Emits `PluginEvent::AlertTriggered` for every alert the latest price
+    /// crossed since the previous data update.
+    pub(super) fn check_price_alert_crossings(&mut self) {
+        let current = self.latest_close_price();
+        let previous = std::mem::replace(&mut self.core.runtime.alert_reference_price, current);
+        let (Some(previous), Some(current)) = (previous, current) else {
+            return;
+        };
+        let triggered: Vec<_> = self
+            .core
+            .model
+            .price_alerts
+            .iter()
+            .filter_map(|alert| {
+                AlertDirection::crossing(previous, current, alert.price).map(|direction| {
+                    PluginEvent::AlertTriggered {
+                        id: alert.id.clone(),
+                        price: alert.price,
+                        direction,
+                    }
+                })
+            })
+            .collect();
+        for event in triggered {
+            self.emit_plugin_event(event);
+        }
+    }
+
+    /// Draws alert levels as dashed lines with a price label at the plot's
+    /// right edge, into the candle pane's overlay layer.
+    pub(super) fn append_price_alert_primitives(
+        &self,
+        frame: &mut RenderFrame,
+        layered: &mut LayeredRenderFrame,
+        pane_id: PaneId,
+        price_scale: PriceScale,
+        plot_clip: ClipRect,
+    ) -> ChartResult<()> {
+        let plot_right = plot_clip.x + plot_clip.width;
+        let label_height = ALERT_LABEL_FONT_SIZE_PX + 2.0 * ALERT_LABEL_PADDING_PX;
+        for alert in &self.core.model.price_alerts {
+            let y = price_scale.price_to_pixel(alert.price, self.core.model.viewport)?;
+            if !y.is_finite() {
+                continue;
+            }
+            let line = LinePrimitive::new(plot_clip.x, y, plot_right, y, 1.0, alert.color)
+                .with_stroke_style(LineStrokeStyle::Dashed)
+                .with_clip_rect(plot_clip);
+            frame.lines.push(line);
+            layered.push_line(pane_id, CanvasLayerKind::Overlay, line);
+
+            let text = self.format_price_for_description(alert.price);
+            let label_width = self
+                .core
+                .presentation
+                .render_style
+                .label_text_metrics
+                .text_width_px(&text, ALERT_LABEL_FONT_SIZE_PX)
+                + 2.0 * ALERT_LABEL_PADDING_PX;
+            let label_x = plot_right - label_width;
+            let label = RectPrimitive::new(
+                label_x,
+                y - label_height * 0.5,
+                label_width,
+                label_height,
+                alert.color,
+            )
+            .with_corner_radius(2.0)
+            .with_clip_rect(plot_clip);
+            frame.rects.push(label);
+            layered.push_rect(pane_id, CanvasLayerKind::Overlay, label);
+
+            let text = TextPrimitive::new(
+                text,
+                label_x + ALERT_LABEL_PADDING_PX,
+                y,
+                ALERT_LABEL_FONT_SIZE_PX,
+                ALERT_LABEL_TEXT_COLOR,
+                TextHAlign::Left,
+            )
+            .with_v_align(TextVAlign::Middle)
+            .with_clip_rect(plot_clip);
+            frame.texts.push(text.clone());
+            layered.push_text(pane_id, CanvasLayerKind::Overlay, text);
+        }
+        Ok(())
+    }
+}
+
+fn duplicate_alert_id(id: &str) -> ChartError {
+    ChartError::InvalidData(format!("price alert id `{id}` is already in use"))
+}
@@ -2,8 +2,22 @@ use crate::render::{Color, Renderer};
 
 use super::{ChartEngine, LastPriceSourceMode};
 
+/// Series subset consulted when resolving latest/previous price samples.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum PriceSampleSource {
+    All,
+    Points,
+    Candles,
+}
+
 impl<R: Renderer> ChartEngine<R> {
     pub(super) fn resolve_price_display_base_price(&self) -> f64 {
+        if self.core.behavior.compare_mode_enabled {
+            if let Some(base_price) = self.compare_primary_base_price() {
+                return base_price;
+            }
+        }
+
         let mut candidate: Option<(f64, f64)> = None;
 
         for point in &self.core.model.points {
@@ -38,8 +52,29 @@ impl<R: Renderer> ChartEngine<R> {
         if domain.0.is_finite() { domain.0 } else { 1.0 }
     }
 
-    fn resolve_latest_price_sample_with_window(
+    /// Finite `(time, price)` samples of the selected source; points precede
+    /// candles so equal timestamps keep resolving to the point sample.
+    fn price_samples(&self, source: PriceSampleSource) -> impl Iterator<Item = (f64, f64)> + '_ {
+        let points = match source {
+            PriceSampleSource::All | PriceSampleSource::Points => self.core.model.points.as_slice(),
+            PriceSampleSource::Candles => &[],
+        };
+        let candles = match source {
+            PriceSampleSource::All | PriceSampleSource::Candles => {
+                self.core.model.candles.as_slice()
+            }
+            PriceSampleSource::Points => &[],
+        };
+        points
+            .iter()
+            .map(|point| (point.x, point.y))
+            .chain(candles.iter().map(|candle| (candle.time, candle.close)))
+            .filter(|(time, price)| time.is_finite() && price.is_finite())
+    }
+
+    pub(super) fn resolve_latest_price_sample_with_window(
         &self,
+        source: PriceSampleSource,
         window: Option<(f64, f64)>,
     ) -> Option<(f64, f64)> {
         let normalized_window = window.map(|(start, end)| {
@@ -51,37 +86,15 @@ impl<R: Renderer> ChartEngine<R> {
         });
         let mut candidate: Option<(f64, f64)> = None;
 
-        for point in &self.core.model.points {
-            if !point.x.is_finite() || !point.y.is_finite() {
-                continue;
-            }
+        for (time, price) in self.price_samples(source) {
             if let Some((window_start, window_end)) = normalized_window
-                && (point.x < window_start || point.x > window_end)
+                && (time < window_start || time > window_end)
             {
                 continue;
             }
             candidate = match candidate {
-                Some((best_time, best_price)) if best_time >= point.x => {
-                    Some((best_time, best_price))
-                }
-                _ => Some((point.x, point.y)),
-            };
-        }
-
-        for candle in &self.core.model.candles {
-            if !candle.time.is_finite() || !candle.close.is_finite() {
-                continue;
-            }
-            if let Some((window_start, window_end)) = normalized_window
-                && (candle.time < window_start || candle.time > window_end)
-            {
-                continue;
-            }
-            candidate = match candidate {
-                Some((best_time, best_price)) if best_time >= candle.time => {
-                    Some((best_time, best_price))
-                }
-                _ => Some((candle.time, candle.close)),
+                Some((best_time, best_price)) if best_time >= time => Some((best_time, best_price)),
+                _ => Some((time, price)),
             };
         }
 
@@ -90,6 +103,7 @@ impl<R: Renderer> ChartEngine<R> {
 
     fn resolve_previous_price_before_time_with_window(
         &self,
+        source: PriceSampleSource,
         latest_time: f64,
         window: Option<(f64, f64)>,
     ) -> Option<f64> {
@@ -102,38 +116,19 @@ impl<R: Renderer> ChartEngine<R> {
         });
         let mut candidate: Option<(f64, f64)> = None;
 
-        for point in &self.core.model.points {
-            if !point.x.is_finite() || !point.y.is_finite() || point.x >= latest_time {
+        for (time, price) in self.price_samples(source) {
+            if time >= latest_time {
                 continue;
             }
             if let Some((window_start, window_end)) = normalized_window
-                && (point.x < window_start || point.x > window_end)
+                && (time < window_start || time > window_end)
             {
                 continue;
             }
             // Preserve first-seen winner for equal timestamps to keep frame snapshots stable.
             candidate = match candidate {
-                Some((best_time, best_price)) if best_time >= point.x => {
-                    Some((best_time, best_price))
-                }
-                _ => Some((point.x, point.y)),
-            };
-        }
-
-        for candle in &self.core.model.candles {
-            if !candle.time.is_finite() || !candle.close.is_finite() || candle.time >= latest_time {
-                continue;
-            }
-            if let Some((window_start, window_end)) = normalized_window
-                && (candle.time < window_start || candle.time > window_end)
-            {
-                continue;
-            }
-            candidate = match candidate {
-                Some((best_time, best_price)) if best_time >= candle.time => {
-                    Some((best_time, best_price))
-                }
-                _ => Some((candle.time, candle.close)),
+                Some((best_time, best_price)) if best_time >= time => Some((best_time, best_price)),
+                _ => Some((time, price)),
             };
         }
 
@@ -146,13 +141,44 @@ impl<R: Renderer> ChartEngine<R> {
         visible_start: f64,
         visible_end: f64,
     ) -> Option<(f64, Option<f64>)> {
+        self.resolve_source_latest_and_previous_price_values(
+            PriceSampleSource::All,
+            source_mode,
+            visible_start,
+            visible_end,
+        )
+    }
+
+    /// Latest `(time, price)` sample the last-price marker tracks.
+    pub(super) fn resolve_source_latest_price_sample(
+        &self,
+        source: PriceSampleSource,
+        source_mode: LastPriceSourceMode,
+        visible_start: f64,
+        visible_end: f64,
+    ) -> Option<(f64, f64)> {
         let window = match source_mode {
             LastPriceSourceMode::LatestData => None,
             LastPriceSourceMode::LatestVisible => Some((visible_start, visible_end)),
         };
-        let (latest_time, latest_price) = self.resolve_latest_price_sample_with_window(window)?;
+        self.resolve_latest_price_sample_with_window(source, window)
+    }
+
+    pub(super) fn resolve_source_latest_and_previous_price_values(
+        &self,
+        source: PriceSampleSource,
+        source_mode: LastPriceSourceMode,
+        visible_start: f64,
+        visible_end: f64,
+    ) -> Option<(f64, Option<f64>)> {
+        let window = match source_mode {
+            LastPriceSourceMode::LatestData => None,
+            LastPriceSourceMode::LatestVisible => Some((visible_start, visible_end)),
+        };
+        let (latest_time, latest_price) =
+            self.resolve_latest_price_sample_with_window(source, window)?;
         let previous_price =
-            self.resolve_previous_price_before_time_with_window(latest_time, window);
+            self.resolve_previous_price_before_time_with_window(source, latest_time, window);
         Some((latest_price, previous_price))
     }
 
@@ -161,24 +187,33 @@ impl<R: Renderer> ChartEngine<R> {
         latest_price: f64,
         previous_price: Option<f64>,
     ) -> (Color, Color) {
-        let style = self.core.presentation.render_style;
+        let style = &self.core.presentation.render_style;
         if !style.last_price_use_trend_color {
             return (style.last_price_line_color, style.last_price_label_color);
         }
 
-        let trend_color = match previous_price {
+        let trend_color = self.resolve_trend_color(latest_price, previous_price);
+        (trend_color, trend_color)
+    }
+
+    pub(super) fn resolve_trend_color(
+        &self,
+        latest_price: f64,
+        previous_price: Option<f64>,
+    ) -> Color {
+        let style = &self.core.presentation.render_style;
+        match previous_price {
             Some(previous) if latest_price > previous => style.last_price_up_color,
             Some(previous) if latest_price < previous => style.last_price_down_color,
             _ => style.last_price_neutral_color,
-        };
-        (trend_color, trend_color)
+        }
     }
 
     pub(super) fn resolve_last_price_label_box_fill_color(
         &self,
         marker_label_color: Color,
     ) -> Color {
-        let style = self.core.presentation.render_style;
+        let style = &self.core.presentation.render_style;
         if style.last_price_label_box_use_marker_color {
             marker_label_color
         } else {
@@ -191,7 +226,7 @@ impl<R: Renderer> ChartEngine<R> {
         box_fill_color: Color,
         marker_label_color: Color,
     ) -> Color {
-        let style = self.core.presentation.render_style;
+        let style = &self.core.presentation.render_style;
         if !style.show_last_price_label_box {
             return marker_label_color;
         }
@@ -209,7 +244,7 @@ impl<R: Renderer> ChartEngine<R> {
         per_axis_text_color: Option<Color>,
         per_axis_auto_contrast: Option<bool>,
     ) -> Color {
-        let style = self.core.presentation.render_style;
+        let style = &self.core.presentation.render_style;
         let auto_contrast =
             per_axis_auto_contrast.unwrap_or(style.crosshair_label_box_auto_text_contrast);
         if !auto_contrast {
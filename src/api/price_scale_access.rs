@@ -1,11 +1,12 @@
-use crate::core::{PriceScale, PriceScaleMode, PriceScaleTuning};
+use crate::core::{OhlcBar, PriceScale, PriceScaleMode, PriceScaleTuning};
 use crate::error::ChartResult;
 use crate::render::Renderer;
 
+use super::navigation_history_controller::ViewCheckpointKind;
 use super::{
-    ChartEngine, PriceScaleMarginBehavior, PriceScaleRealtimeBehavior,
-    PriceScaleTransformedBaseBehavior, price_scale_coordinator::PriceScaleCoordinator,
-    price_scale_validation,
+    AutoscaleSource, ChartEngine, ChartWarning, PriceScaleMarginBehavior,
+    PriceScaleRealtimeBehavior, PriceScaleTransformedBaseBehavior,
+    price_scale_coordinator::PriceScaleCoordinator, price_scale_validation,
 };
 
 impl<R: Renderer> ChartEngine<R> {
@@ -120,6 +121,7 @@ impl<R: Renderer> ChartEngine<R> {
             .with_mode_and_base(mode, base_value)?;
         self.core.model.price_scale_mode = mode;
         self.invalidate_price_scale();
+        self.checkpoint_navigation_view(ViewCheckpointKind::Discrete);
         Ok(())
     }
 
@@ -130,6 +132,9 @@ impl<R: Renderer> ChartEngine<R> {
     /// Autoscales price domain from points with explicit tuning.
     pub fn autoscale_price_from_data_tuned(&mut self, tuning: PriceScaleTuning) -> ChartResult<()> {
         if self.core.model.points.is_empty() {
+            self.emit_warning(ChartWarning::AutoscaleSkippedEmptyData {
+                source: AutoscaleSource::Points,
+            });
             return Ok(());
         }
         let keep_inverted = self.core.model.price_scale.is_inverted();
@@ -160,6 +165,9 @@ impl<R: Renderer> ChartEngine<R> {
         tuning: PriceScaleTuning,
     ) -> ChartResult<()> {
         if self.core.model.candles.is_empty() {
+            self.emit_warning(ChartWarning::AutoscaleSkippedEmptyData {
+                source: AutoscaleSource::Candles,
+            });
             return Ok(());
         }
         let keep_inverted = self.core.model.price_scale.is_inverted();
@@ -191,10 +199,16 @@ impl<R: Renderer> ChartEngine<R> {
         &mut self,
         tuning: PriceScaleTuning,
     ) -> ChartResult<()> {
-        let visible = self.visible_points();
+        let mut visible = self.visible_points();
         if visible.is_empty() {
+            self.emit_warning(ChartWarning::AutoscaleSkippedEmptyData {
+                source: AutoscaleSource::VisiblePoints,
+            });
             return Ok(());
         }
+        visible.extend(self.rebased_compare_values());
+        visible.extend(self.main_pane_custom_series_autoscale_points());
+        visible.extend(self.last_price_autoscale_point());
         let keep_inverted = self.core.model.price_scale.is_inverted();
         let keep_margins = self.core.model.price_scale.margins();
         self.core.model.price_scale = PriceScale::from_data_tuned_with_mode(
@@ -224,10 +238,21 @@ impl<R: Renderer> ChartEngine<R> {
         &mut self,
         tuning: PriceScaleTuning,
     ) -> ChartResult<()> {
-        let visible = self.visible_candles();
+        let mut visible = self.visible_candles();
         if visible.is_empty() {
+            self.emit_warning(ChartWarning::AutoscaleSkippedEmptyData {
+                source: AutoscaleSource::VisibleCandles,
+            });
             return Ok(());
         }
+        // Rebased compare values and custom series ranges join as flat bars.
+        visible.extend(
+            self.rebased_compare_values()
+                .into_iter()
+                .chain(self.main_pane_custom_series_autoscale_points())
+                .chain(self.last_price_autoscale_point())
+                .filter_map(|point| OhlcBar::new(point.x, point.y, point.y, point.y, point.y).ok()),
+        );
         let keep_inverted = self.core.model.price_scale.is_inverted();
         let keep_margins = self.core.model.price_scale.margins();
         self.core.model.price_scale = PriceScale::from_ohlc_tuned_with_mode(
@@ -1,7 +1,8 @@
 use crate::error::ChartResult;
 use crate::render::Renderer;
 
-use super::{ChartEngine, scale_coordinator::ScaleCoordinator};
+use super::navigation_history_controller::ViewCheckpointKind;
+use super::{ChartEngine, InteractionAction, scale_coordinator::ScaleCoordinator};
 
 impl<R: Renderer> ChartEngine<R> {
     /// Applies vertical panning on the price axis around a pixel anchor.
@@ -16,7 +17,12 @@ impl<R: Renderer> ChartEngine<R> {
         drag_delta_y_px: f64,
         anchor_y_px: f64,
     ) -> ChartResult<bool> {
-        ScaleCoordinator::axis_drag_pan_price(self, drag_delta_y_px, anchor_y_px)
+        let changed = ScaleCoordinator::axis_drag_pan_price(self, drag_delta_y_px, anchor_y_px)?;
+        self.record_interaction(InteractionAction::AxisDragPanPrice {
+            drag_delta_y_px,
+            anchor_y_px,
+        });
+        Ok(changed)
     }
 
     /// Applies price-axis drag scaling around a pixel anchor.
@@ -34,13 +40,21 @@ impl<R: Renderer> ChartEngine<R> {
         zoom_step_ratio: f64,
         min_span_absolute: f64,
     ) -> ChartResult<f64> {
-        ScaleCoordinator::axis_drag_scale_price(
+        let factor = ScaleCoordinator::axis_drag_scale_price(
             self,
             drag_delta_y_px,
             anchor_y_px,
             zoom_step_ratio,
             min_span_absolute,
-        )
+        )?;
+        self.checkpoint_navigation_view(ViewCheckpointKind::Zoom);
+        self.record_interaction(InteractionAction::AxisDragScalePrice {
+            drag_delta_y_px,
+            anchor_y_px,
+            zoom_step_ratio,
+            min_span_absolute,
+        });
+        Ok(factor)
     }
 
     /// Resets price axis to data-driven autoscale domain.
@@ -50,6 +64,9 @@ impl<R: Renderer> ChartEngine<R> {
     ///
     /// Returns `true` when price domain changed.
     pub fn axis_double_click_reset_price_scale(&mut self) -> ChartResult<bool> {
-        ScaleCoordinator::axis_double_click_reset_price_scale(self)
+        let changed = ScaleCoordinator::axis_double_click_reset_price_scale(self)?;
+        self.checkpoint_navigation_view(ViewCheckpointKind::Discrete);
+        self.record_interaction(InteractionAction::AxisDoubleClickResetPrice);
+        Ok(changed)
     }
 }
@@ -0,0 +1,95 @@
+use serde::{Deserialize, Serialize};
+
+use crate::extensions::{HitRegion, PluginEvent};
+use crate::render::Renderer;
+
+use super::ChartEngine;
+
+/// Contributor of a hit region.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PrimitiveOwner {
+    Plugin(String),
+    CustomSeries(String),
+}
+
+/// Hit region under a pointer position together with its owner.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PrimitiveHit {
+    pub owner: PrimitiveOwner,
+    pub id: String,
+}
+
+impl<R: Renderer> ChartEngine<R> {
+    /// Top-most hit region containing content position `(x, y)`.
+    ///
+    /// Plugin regions sit above custom-series regions; the latter are the
+    /// ones reported while building the last frame.
+    #[must_use]
+    pub fn primitive_at(&self, x: f64, y: f64) -> Option<PrimitiveHit> {
+        let context = self.plugin_context();
+        for plugin in self.core.runtime.plugins.iter().rev() {
+            if let Some(region) = plugin
+                .hit_regions(context)
+                .into_iter()
+                .rev()
+                .find(|region| region.contains(x, y))
+            {
+                return Some(PrimitiveHit {
+                    owner: PrimitiveOwner::Plugin(plugin.id().to_owned()),
+                    id: region.id,
+                });
+            }
+        }
+        self.core
+            .runtime
+            .custom_series_hit_regions
+            .borrow()
+            .iter()
+            .rev()
+            .find(|(_, region)| region.contains(x, y))
+            .map(|(series_id, region)| PrimitiveHit {
+                owner: PrimitiveOwner::CustomSeries(series_id.clone()),
+                id: region.id.clone(),
+            })
+    }
+
+    /// Hit region the pointer currently rests on.
+    #[must_use]
+    pub fn hovered_primitive(&self) -> Option<&PrimitiveHit> {
+        self.core.runtime.hovered_primitive.as_ref()
+    }
+
+    /// Routes a click at content position `(x, y)` to the owner of the hit
+    /// region there as `PluginEvent::PrimitiveClicked`.
+    pub fn pointer_click(&mut self, x: f64, y: f64) -> Option<PrimitiveHit> {
+        let hit = self.primitive_at(x, y)?;
+        self.emit_primitive_event(
+            &hit.owner,
+            PluginEvent::PrimitiveClicked { id: hit.id.clone() },
+        );
+        Some(hit)
+    }
+
+    /// Re-resolves the hovered region for `position` (`None` once the
+    /// pointer left) and emits hover transitions to the owners.
+    pub(super) fn update_hovered_primitive(&mut self, position: Option<(f64, f64)>) {
+        let hit = position.and_then(|(x, y)| self.primitive_at(x, y));
+        if hit == self.core.runtime.hovered_primitive {
+            return;
+        }
+        let previous = std::mem::replace(&mut self.core.runtime.hovered_primitive, hit.clone());
+        if let Some(previous) = previous {
+            self.emit_primitive_event(
+                &previous.owner,
+                PluginEvent::PrimitiveHoverEnded { id: previous.id },
+            );
+        }
+        if let Some(hit) = hit {
+            self.emit_primitive_event(&hit.owner, PluginEvent::PrimitiveHovered { id: hit.id });
+        }
+    }
+
+    pub(super) fn record_custom_series_hit_regions(&self, regions: Vec<(String, HitRegion)>) {
+        *self.core.runtime.custom_series_hit_regions.borrow_mut() = regions;
+    }
+}
@@ -0,0 +1,92 @@
+use std::time::Duration;
+
+use crate::error::ChartResult;
+use crate::render::Renderer;
+
+use super::validation::validate_redraw_throttle_behavior;
+use super::{ChartEngine, InvalidationTopics, RedrawThrottleBehavior};
+
+/// Fraction of the frame interval tolerated as tick jitter, so a cap equal
+/// to the display refresh rate does not skip every other tick.
+const REDRAW_INTERVAL_SLACK: f64 = 0.1;
+
+impl<R: Renderer> ChartEngine<R> {
+    #[must_use]
+    pub fn redraw_throttle_behavior(&self) -> RedrawThrottleBehavior {
+        self.core.behavior.redraw_throttle_behavior
+    }
+
+    pub fn set_redraw_throttle_behavior(
+        &mut self,
+        behavior: RedrawThrottleBehavior,
+    ) -> ChartResult<()> {
+        validate_redraw_throttle_behavior(behavior)?;
+        self.core.behavior.redraw_throttle_behavior = behavior;
+        Ok(())
+    }
+
+    /// Whether state changed since the last render.
+    #[must_use]
+    pub fn needs_redraw(&self) -> bool {
+        self.has_pending_invalidation()
+    }
+
+    /// Topics dirtied since the last render (e.g. only `Cursor` after
+    /// pointer moves).
+    #[must_use]
+    pub fn redraw_reasons(&self) -> InvalidationTopics {
+        self.pending_invalidation_topics()
+    }
+
+    /// Advances the host frame clock to monotonic time `frame_time`.
+    ///
+    /// The engine never reads the system clock (it is unavailable on
+    /// `wasm32-unknown-unknown`); time-based diagnostics such as the debug
+    /// overlay's fps estimate use this clock instead. `redraw_due` advances
+    /// it too, so hosts gating redraws through it need not call this.
+    pub fn frame_clock_tick(&mut self, frame_time: Duration) {
+        self.core.runtime.frame_clock_time = Some(frame_time);
+    }
+
+    /// Time last passed to `frame_clock_tick` or `redraw_due`.
+    #[must_use]
+    pub fn frame_clock_time(&self) -> Option<Duration> {
+        self.core.runtime.frame_clock_time
+    }
+
+    /// Frame-clock gate for coalesced redraws.
+    ///
+    /// Hosts call this on every display tick with the clock's monotonic
+    /// `frame_time` and draw only when it returns `true`: a redraw is
+    /// pending and, under `RedrawThrottleBehavior::max_fps`, at least one
+    /// frame interval passed since the last granted tick. While power idle
+    /// the interval is at least `IdlePowerBehavior::batch_interval_seconds`.
+    /// Any number of invalidations between ticks collapse into one redraw.
+    pub fn redraw_due(&mut self, frame_time: Duration) -> bool {
+        self.frame_clock_tick(frame_time);
+        if !self.needs_redraw() {
+            return false;
+        }
+        let last = self.core.runtime.last_redraw_frame_time;
+        let min_interval = self
+            .core
+            .behavior
+            .redraw_throttle_behavior
+            .max_fps
+            .map(|max_fps| 1.0 / max_fps)
+            .into_iter()
+            .chain(self.idle_redraw_interval_seconds())
+            .reduce(f64::max);
+        let throttled = match (min_interval, last) {
+            // A clock reset (time going backwards) never blocks a redraw.
+            (Some(interval), Some(last)) if frame_time >= last => {
+                (frame_time - last).as_secs_f64() < (1.0 - REDRAW_INTERVAL_SLACK) * interval
+            }
+            _ => false,
+        };
+        if !throttled {
+            self.core.runtime.last_redraw_frame_time = Some(frame_time);
+        }
+        !throttled
+    }
+}
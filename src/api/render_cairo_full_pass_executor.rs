@@ -12,7 +12,9 @@ pub(super) fn render_full_on_cairo_context<R: Renderer + CairoContextRenderer>(
     context: &cairo::Context,
 ) -> ChartResult<()> {
     let frame = engine.build_render_frame()?;
-    engine.renderer.render_on_cairo_context(context, &frame)
+    let result = engine.renderer.render_on_cairo_context(context, &frame);
+    engine.recycle_render_frame(frame);
+    result
 }
 
 #[cfg(all(test, feature = "cairo-backend"))]
@@ -0,0 +1,81 @@
+use crate::render::{CanvasLayerKind, ClipRect};
+
+/// Clip regions resolved once per draw pass from the axis layout.
+///
+/// Plot-bound layers (grid, series, overlay, crosshair guides) clip to the
+/// plot area; axis labels and tick marks clip to their own axis panel.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(super) struct RenderClipRegions {
+    pub plot: ClipRect,
+    pub price_axis: ClipRect,
+    pub time_axis: ClipRect,
+}
+
+impl RenderClipRegions {
+    #[must_use]
+    pub(super) fn resolve(
+        plot_right: f64,
+        plot_bottom: f64,
+        viewport_width: f64,
+        viewport_height: f64,
+    ) -> Self {
+        let plot_right = plot_right.clamp(0.0, viewport_width.max(0.0));
+        let plot_bottom = plot_bottom.clamp(0.0, viewport_height.max(0.0));
+        Self {
+            plot: ClipRect::new(0.0, 0.0, plot_right, plot_bottom),
+            price_axis: ClipRect::new(
+                plot_right,
+                0.0,
+                (viewport_width - plot_right).max(0.0),
+                plot_bottom,
+            ),
+            time_axis: ClipRect::new(
+                0.0,
+                plot_bottom,
+                plot_right,
+                (viewport_height - plot_bottom).max(0.0),
+            ),
+        }
+    }
+
+    /// Returns the plot clip for plot-bound layers, `None` for layers that are
+    /// drawn unclipped or clipped per axis panel by their builder.
+    #[must_use]
+    pub(super) fn plot_clip_for_layer(self, layer: CanvasLayerKind) -> Option<ClipRect> {
+        match layer {
+            CanvasLayerKind::Grid
+            | CanvasLayerKind::Series
+            | CanvasLayerKind::Overlay
+            | CanvasLayerKind::Crosshair => Some(self.plot),
+            CanvasLayerKind::Background | CanvasLayerKind::Axis => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RenderClipRegions;
+    use crate::render::{CanvasLayerKind, ClipRect};
+
+    #[test]
+    fn resolve_splits_viewport_into_plot_and_axis_panels() {
+        let regions = RenderClipRegions::resolve(720.0, 450.0, 800.0, 500.0);
+        assert_eq!(regions.plot, ClipRect::new(0.0, 0.0, 720.0, 450.0));
+        assert_eq!(regions.price_axis, ClipRect::new(720.0, 0.0, 80.0, 450.0));
+        assert_eq!(regions.time_axis, ClipRect::new(0.0, 450.0, 720.0, 50.0));
+    }
+
+    #[test]
+    fn plot_clip_applies_only_to_plot_bound_layers() {
+        let regions = RenderClipRegions::resolve(720.0, 450.0, 800.0, 500.0);
+        assert_eq!(
+            regions.plot_clip_for_layer(CanvasLayerKind::Series),
+            Some(regions.plot)
+        );
+        assert_eq!(regions.plot_clip_for_layer(CanvasLayerKind::Axis), None);
+        assert_eq!(
+            regions.plot_clip_for_layer(CanvasLayerKind::Background),
+            None
+        );
+    }
+}
@@ -5,6 +5,8 @@ use super::ChartEngine;
 
 pub(super) fn finalize_render_cycle<R: Renderer>(engine: &mut ChartEngine<R>) {
     engine.clear_pending_invalidation();
+    engine.record_render_instant();
+    engine.flush_per_frame_visible_range_change();
     engine.emit_plugin_event(PluginEvent::Rendered);
 }
 
@@ -72,7 +74,7 @@ mod tests {
 
         finalize_render_cycle(&mut engine);
 
-        let last = events.borrow().last().copied().expect("rendered event");
+        let last = events.borrow().last().cloned().expect("rendered event");
         assert!(matches!(last, PluginEvent::Rendered));
     }
 }
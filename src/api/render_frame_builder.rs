@@ -1,11 +1,21 @@
+use tracing::field::Empty;
+use tracing::{debug, debug_span, trace_span, warn};
+
 use crate::core::PaneId;
 use crate::error::ChartResult;
 use crate::render::{LayeredRenderFrame, RenderFrame, Renderer};
+use crate::telemetry::{
+    FRAME_AXIS_SPAN, FRAME_CROSSHAIR_SPAN, FRAME_LAYOUT_SPAN, FRAME_OVERLAYS_SPAN,
+    FRAME_SERIES_SPAN, FRAME_SPAN, FRAME_VALIDATION_SPAN,
+};
 
-use super::ChartEngine;
 use super::axis_render_frame_builder::AxisRenderContext;
+use super::background_render_frame_builder::BackgroundRenderContext;
 use super::crosshair_render_frame_builder::CrosshairRenderContext;
+use super::frame_budget::FrameReduction;
+use super::render_clip_regions_resolver::RenderClipRegions;
 use super::series_scene_coordinator::SeriesSceneRenderContext;
+use super::{ChartEngine, ChartWarning, FramePrimitiveStats};
 
 impl<R: Renderer> ChartEngine<R> {
     /// Materializes backend-agnostic primitives for one draw pass.
@@ -13,7 +23,9 @@ impl<R: Renderer> ChartEngine<R> {
     /// This keeps geometry computation deterministic and centralized in the API
     /// layer while renderer backends only execute drawing commands.
     pub fn build_render_frame(&self) -> ChartResult<RenderFrame> {
-        self.build_render_outputs().map(|(frame, _)| frame)
+        let (frame, layered) = self.build_render_outputs()?;
+        self.recycle_layered_render_frame(layered);
+        Ok(frame)
     }
 
     /// Materializes a pane/layer aware render scene.
@@ -21,7 +33,9 @@ impl<R: Renderer> ChartEngine<R> {
     /// This is the pane-oriented equivalent of `build_render_frame` and keeps
     /// canonical layer ordering explicit for parity work.
     pub fn build_layered_render_frame(&self) -> ChartResult<LayeredRenderFrame> {
-        self.build_render_outputs().map(|(_, layered)| layered)
+        let (frame, layered) = self.build_render_outputs()?;
+        self.recycle_render_frame(frame);
+        Ok(layered)
     }
 
     /// Materializes a pane-scoped frame for selective redraw paths.
@@ -29,43 +43,156 @@ impl<R: Renderer> ChartEngine<R> {
     /// Returns `None` when `pane_id` is unknown.
     pub fn build_render_frame_for_pane(&self, pane_id: PaneId) -> ChartResult<Option<RenderFrame>> {
         let layered = self.build_layered_render_frame()?;
-        Ok(layered.flatten_pane(pane_id))
+        let frame = layered.flatten_pane(pane_id);
+        self.recycle_layered_render_frame(layered);
+        Ok(frame)
     }
 
+    /// Builds the frame at the budget reduction remembered from the last
+    /// frame, rebuilding it with stronger reduction while it exceeds the
+    /// configured `FrameBudget`, then shifts it past the layout insets.
     fn build_render_outputs(&self) -> ChartResult<(RenderFrame, LayeredRenderFrame)> {
-        let mut frame = RenderFrame::new(self.core.model.viewport);
+        let frame_span = debug_span!(
+            FRAME_SPAN,
+            lines = Empty,
+            texts = Empty,
+            rects = Empty,
+            reduction_level = Empty
+        )
+        .entered();
+        let budget = self.core.behavior.frame_budget;
+        let remembered = if budget.is_some() {
+            self.core.runtime.frame_reduction.get()
+        } else {
+            FrameReduction::default()
+        };
+        let mut reduction = remembered;
+        let mut outputs = self.build_render_outputs_with_reduction(reduction)?;
+        let requested = FramePrimitiveStats::from_frame(&outputs.0);
+        let mut rendered = requested;
+
+        if let Some(budget) = budget {
+            let mut next_frame_reduction = reduction;
+            if !budget.allows(requested) {
+                while !budget.allows(rendered) {
+                    let Some(next) = reduction.escalate(budget, rendered) else {
+                        break;
+                    };
+                    reduction = next;
+                    let (frame, layered) = outputs;
+                    self.recycle_render_frame(frame);
+                    self.recycle_layered_render_frame(layered);
+                    outputs = self.build_render_outputs_with_reduction(reduction)?;
+                    rendered = FramePrimitiveStats::from_frame(&outputs.0);
+                }
+                next_frame_reduction = reduction;
+            } else if let Some(lighter) = reduction.relax(budget, rendered) {
+                debug!(
+                    from_level = reduction.level(),
+                    to_level = lighter.level(),
+                    "frame budget reduction relaxed"
+                );
+                next_frame_reduction = lighter;
+            }
+            self.core.runtime.frame_reduction.set(next_frame_reduction);
+            if reduction != remembered {
+                warn!(
+                    requested_lines = requested.lines,
+                    requested_texts = requested.texts,
+                    requested_rects = requested.rects,
+                    rendered_lines = rendered.lines,
+                    rendered_texts = rendered.texts,
+                    rendered_rects = rendered.rects,
+                    tick_level = reduction.tick_level,
+                    lod_level = reduction.lod_level,
+                    within_budget = budget.allows(rendered),
+                    "frame budget exceeded"
+                );
+                self.emit_warning(ChartWarning::FrameBudgetExceeded {
+                    requested,
+                    rendered,
+                    reduction_level: reduction.level(),
+                });
+            }
+        }
+
+        frame_span.record("lines", rendered.lines);
+        frame_span.record("texts", rendered.texts);
+        frame_span.record("rects", rendered.rects);
+        frame_span.record("reduction_level", reduction.level());
+        self.core.runtime.last_frame_stats.set(Some(rendered));
+        let insets = self.core.behavior.layout_insets;
+        if !insets.is_zero() {
+            let (dx, dy) = (f64::from(insets.left), f64::from(insets.top));
+            let viewport = self.viewport();
+            let (frame, layered) = &mut outputs;
+            frame.translate(dx, dy);
+            frame.viewport = viewport;
+            layered.translate(dx, dy);
+            layered.viewport = viewport;
+        }
+        Ok(outputs)
+    }
+
+    fn build_render_outputs_with_reduction(
+        &self,
+        reduction: FrameReduction,
+    ) -> ChartResult<(RenderFrame, LayeredRenderFrame)> {
+        let mut frame = self.take_recycled_render_frame(self.core.model.viewport);
         let main_pane_id = self.main_pane_id();
-        let mut layered =
-            LayeredRenderFrame::from_stacks(self.core.model.viewport, self.pane_layer_stacks());
+        let mut layered = self
+            .take_recycled_layered_render_frame(self.core.model.viewport, self.pane_layer_stacks());
 
         let (visible_start, visible_end) = self.core.model.time_scale.visible_range();
 
-        let style = self.core.presentation.render_style;
+        let style = &self.core.presentation.render_style;
 
+        let layout_span = trace_span!(FRAME_LAYOUT_SPAN).entered();
         let resolved_layout = self.resolve_render_axis_layout(style, visible_start, visible_end)?;
         let viewport_width = resolved_layout.viewport_width;
         let viewport_height = resolved_layout.viewport_height;
         let visible_span_abs = resolved_layout.visible_span_abs;
         let plot_right = resolved_layout.axis_layout.plot_right;
         let plot_bottom = resolved_layout.axis_layout.plot_bottom;
+        let clip_regions =
+            RenderClipRegions::resolve(plot_right, plot_bottom, viewport_width, viewport_height);
         let pane_regions =
             self.resolve_pane_scene_regions(super::pane_scene_coordinator::PaneSceneContext {
                 plot_top: 0.0,
                 plot_bottom,
             });
         layered = self.apply_pane_scene_regions(layered, &pane_regions);
-        self.append_series_scene_primitives(
+        self.append_background_primitives(
             &mut frame,
             &mut layered,
-            SeriesSceneRenderContext {
+            BackgroundRenderContext {
                 main_pane_id,
+                viewport_width,
+                viewport_height,
                 visible_start,
                 visible_end,
-                plot_right,
+                plot_clip: clip_regions.plot,
                 style,
             },
         )?;
-        let axis_display = self.append_axis_primitives(
+        layout_span.exit();
+
+        trace_span!(FRAME_SERIES_SPAN).in_scope(|| {
+            self.append_series_scene_primitives(
+                &mut frame,
+                &mut layered,
+                SeriesSceneRenderContext {
+                    main_pane_id,
+                    visible_start,
+                    visible_end,
+                    plot_clip: clip_regions.plot,
+                    style,
+                    reduction,
+                },
+            )
+        })?;
+        let axis_span = trace_span!(FRAME_AXIS_SPAN).entered();
+        let axis_output = self.append_axis_primitives(
             &mut frame,
             &mut layered,
             AxisRenderContext {
@@ -77,30 +204,90 @@ impl<R: Renderer> ChartEngine<R> {
                 visible_start,
                 visible_end,
                 visible_span_abs,
+                clip_regions,
                 style,
+                reduction,
             },
         )?;
+        axis_span.exit();
 
-        self.append_crosshair_primitives(
+        let overlays_span = trace_span!(FRAME_OVERLAYS_SPAN).entered();
+        self.append_time_scale_mark_primitives(
             &mut frame,
             &mut layered,
-            CrosshairRenderContext {
-                main_pane_id,
-                plot_right,
-                plot_bottom,
-                viewport_width,
-                viewport_height,
-                visible_span_abs,
-                fallback_display_base_price: axis_display.fallback_display_base_price,
-                display_tick_step_abs: axis_display.display_tick_step_abs,
-                display_suffix: axis_display.display_suffix,
-                style,
-            },
+            main_pane_id,
+            plot_right,
+            clip_regions.time_axis,
+        );
+        self.append_bar_countdown_primitives(
+            &mut frame,
+            &mut layered,
+            main_pane_id,
+            plot_right,
+            clip_regions.time_axis,
+        )?;
+        self.append_data_gap_primitives(
+            &mut frame,
+            &mut layered,
+            main_pane_id,
+            plot_right,
+            clip_regions.time_axis,
         )?;
+        overlays_span.exit();
+
+        trace_span!(FRAME_CROSSHAIR_SPAN).in_scope(|| {
+            self.append_crosshair_primitives(
+                &mut frame,
+                &mut layered,
+                CrosshairRenderContext {
+                    main_pane_id,
+                    plot_right,
+                    plot_bottom,
+                    viewport_width,
+                    viewport_height,
+                    visible_span_abs,
+                    fallback_display_base_price: axis_output.display.fallback_display_base_price,
+                    display_tick_step_abs: axis_output.display.display_tick_step_abs,
+                    display_suffix: axis_output.display.display_suffix,
+                    clip_regions,
+                    style,
+                },
+                axis_output.last_value_labels,
+            )
+        })?;
+        let overlays_span = trace_span!(FRAME_OVERLAYS_SPAN).entered();
+        self.append_ohlc_info_line_primitives(
+            &mut frame,
+            &mut layered,
+            main_pane_id,
+            clip_regions.plot,
+        );
+        self.append_data_selection_primitives(
+            &mut frame,
+            &mut layered,
+            main_pane_id,
+            clip_regions.plot,
+        );
+        self.append_zoom_selection_primitives(
+            &mut frame,
+            &mut layered,
+            main_pane_id,
+            plot_bottom,
+            clip_regions.plot,
+        );
+        self.append_debug_overlay_primitives(
+            &mut frame,
+            &mut layered,
+            main_pane_id,
+            plot_right,
+            plot_bottom,
+            clip_regions.plot,
+        );
+        overlays_span.exit();
 
         self.remap_plot_layers_into_pane_regions(&mut layered, &pane_regions, 0.0, plot_bottom);
 
-        frame.validate()?;
+        trace_span!(FRAME_VALIDATION_SPAN).in_scope(|| frame.validate())?;
         Ok((frame, layered))
     }
 }
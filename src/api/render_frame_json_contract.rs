@@ -0,0 +1,268 @@
+use serde::{Deserialize, Serialize};
+
+use crate::core::{CandleGeometry, Viewport};
+use crate::error::{ChartError, ChartResult};
+use crate::render::{
+    ClipRect, Color, LineStrokeStyle, LinearGradient, PathFillRule, PathPoint, RenderFrame,
+    Renderer, TextFontWeight, TextHAlign, TextVAlign,
+};
+
+use super::ChartEngine;
+
+pub const RENDER_FRAME_JSON_SCHEMA_V1: u32 = 1;
+
+/// Pixel coordinates and sizes keep 1/1000 px.
+const PX_SCALE: f64 = 1_000.0;
+/// Color channels and rotation angles keep six decimals.
+const UNIT_SCALE: f64 = 1_000_000.0;
+
+fn quantize(value: f64, scale: f64) -> f64 {
+    // `+ 0.0` folds `-0.0` into `0.0` so rounding noise never shows up as a
+    // sign flip in diffs.
+    (value * scale).round() / scale + 0.0
+}
+
+fn px(value: f64) -> f64 {
+    quantize(value, PX_SCALE)
+}
+
+fn unit(value: f64) -> f64 {
+    quantize(value, UNIT_SCALE)
+}
+
+fn color(color: Color) -> Color {
+    Color::rgba(
+        unit(color.red),
+        unit(color.green),
+        unit(color.blue),
+        unit(color.alpha),
+    )
+}
+
+fn clip(clip_rect: Option<ClipRect>) -> Option<ClipRect> {
+    clip_rect.map(|rect| ClipRect::new(px(rect.x), px(rect.y), px(rect.width), px(rect.height)))
+}
+
+fn gradient(gradient: Option<LinearGradient>) -> Option<LinearGradient> {
+    gradient.map(|gradient| LinearGradient {
+        start_color: color(gradient.start_color),
+        end_color: color(gradient.end_color),
+        direction: gradient.direction,
+    })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct RenderFrameLineV1 {
+    pub x1: f64,
+    pub y1: f64,
+    pub x2: f64,
+    pub y2: f64,
+    pub stroke_width: f64,
+    pub color: Color,
+    pub stroke_style: LineStrokeStyle,
+    pub clip_rect: Option<ClipRect>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct RenderFrameRectV1 {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+    pub fill_color: Color,
+    pub border_width: f64,
+    pub border_color: Color,
+    pub corner_radius: f64,
+    pub fill_gradient: Option<LinearGradient>,
+    pub clip_rect: Option<ClipRect>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RenderFramePathV1 {
+    pub points: Vec<PathPoint>,
+    pub closed: bool,
+    pub fill_color: Option<Color>,
+    pub fill_gradient: Option<LinearGradient>,
+    pub fill_rule: PathFillRule,
+    pub stroke_width: f64,
+    pub stroke_color: Color,
+    pub stroke_style: LineStrokeStyle,
+    pub clip_rect: Option<ClipRect>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RenderFrameTextV1 {
+    pub text: String,
+    pub x: f64,
+    pub y: f64,
+    pub font_size_px: f64,
+    pub color: Color,
+    pub h_align: TextHAlign,
+    pub v_align: TextVAlign,
+    pub font_family: String,
+    pub font_weight: TextFontWeight,
+    pub italic: bool,
+    pub rotation_radians: f64,
+    pub clip_rect: Option<ClipRect>,
+}
+
+/// Versioned, quantized copy of a `RenderFrame` for visual diffs and for
+/// hosts that rasterize frames outside Rust.
+///
+/// Each primitive list keeps the frame's draw order, which is deterministic
+/// for a given engine state. Pixel values are rounded to 1/1000 px; color
+/// channels and text rotation to six decimals.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RenderFrameJsonContractV1 {
+    pub schema_version: u32,
+    pub viewport: Viewport,
+    /// Candle geometry projected at the requested body width, as in
+    /// `EngineSnapshot::candle_geometry`; empty for `from_render_frame`.
+    pub candle_geometry: Vec<CandleGeometry>,
+    pub lines: Vec<RenderFrameLineV1>,
+    pub rects: Vec<RenderFrameRectV1>,
+    pub paths: Vec<RenderFramePathV1>,
+    pub texts: Vec<RenderFrameTextV1>,
+}
+
+impl RenderFrameJsonContractV1 {
+    #[must_use]
+    pub fn from_render_frame(frame: &RenderFrame) -> Self {
+        Self {
+            schema_version: RENDER_FRAME_JSON_SCHEMA_V1,
+            viewport: frame.viewport,
+            candle_geometry: Vec::new(),
+            lines: frame
+                .lines
+                .iter()
+                .map(|line| RenderFrameLineV1 {
+                    x1: px(line.x1),
+                    y1: px(line.y1),
+                    x2: px(line.x2),
+                    y2: px(line.y2),
+                    stroke_width: px(line.stroke_width),
+                    color: color(line.color),
+                    stroke_style: line.stroke_style,
+                    clip_rect: clip(line.clip_rect),
+                })
+                .collect(),
+            rects: frame
+                .rects
+                .iter()
+                .map(|rect| RenderFrameRectV1 {
+                    x: px(rect.x),
+                    y: px(rect.y),
+                    width: px(rect.width),
+                    height: px(rect.height),
+                    fill_color: color(rect.fill_color),
+                    border_width: px(rect.border_width),
+                    border_color: color(rect.border_color),
+                    corner_radius: px(rect.corner_radius),
+                    fill_gradient: gradient(rect.fill_gradient),
+                    clip_rect: clip(rect.clip_rect),
+                })
+                .collect(),
+            paths: frame
+                .paths
+                .iter()
+                .map(|path| RenderFramePathV1 {
+                    points: path
+                        .points
+                        .iter()
+                        .map(|point| PathPoint::new(px(point.x), px(point.y)))
+                        .collect(),
+                    closed: path.closed,
+                    fill_color: path.fill_color.map(color),
+                    fill_gradient: gradient(path.fill_gradient),
+                    fill_rule: path.fill_rule,
+                    stroke_width: px(path.stroke_width),
+                    stroke_color: color(path.stroke_color),
+                    stroke_style: path.stroke_style,
+                    clip_rect: clip(path.clip_rect),
+                })
+                .collect(),
+            texts: frame
+                .texts
+                .iter()
+                .map(|text| RenderFrameTextV1 {
+                    text: text.text.to_string(),
+                    x: px(text.x),
+                    y: px(text.y),
+                    font_size_px: px(text.font_size_px),
+                    color: color(text.color),
+                    h_align: text.h_align,
+                    v_align: text.v_align,
+                    font_family: text.font_family.to_string(),
+                    font_weight: text.font_weight,
+                    italic: text.italic,
+                    rotation_radians: unit(text.rotation_radians),
+                    clip_rect: clip(text.clip_rect),
+                })
+                .collect(),
+        }
+    }
+
+    pub fn to_json(&self) -> ChartResult<String> {
+        serde_json::to_string(self).map_err(|e| {
+            ChartError::InvalidData(format!("failed to serialize render frame contract v1: {e}"))
+        })
+    }
+
+    pub fn to_json_pretty(&self) -> ChartResult<String> {
+        serde_json::to_string_pretty(self).map_err(|e| {
+            ChartError::InvalidData(format!("failed to serialize render frame contract v1: {e}"))
+        })
+    }
+
+    pub fn from_json_str(input: &str) -> ChartResult<Self> {
+        let payload: Self = serde_json::from_str(input).map_err(|e| {
+            ChartError::InvalidData(format!("failed to parse render frame contract json: {e}"))
+        })?;
+        if payload.schema_version != RENDER_FRAME_JSON_SCHEMA_V1 {
+            return Err(ChartError::SchemaVersionMismatch {
+                schema: "render frame",
+                expected: RENDER_FRAME_JSON_SCHEMA_V1,
+                found: payload.schema_version,
+            });
+        }
+        Ok(payload)
+    }
+}
+
+impl<R: Renderer> ChartEngine<R> {
+    /// Builds the current frame as a `RenderFrameJsonContractV1`, with candle
+    /// geometry projected at `body_width_px`.
+    pub fn render_frame_contract_v1(
+        &self,
+        body_width_px: f64,
+    ) -> ChartResult<RenderFrameJsonContractV1> {
+        let candle_geometry = self.project_candles(body_width_px)?;
+        let frame = self.build_render_frame()?;
+        let mut contract = RenderFrameJsonContractV1::from_render_frame(&frame);
+        self.recycle_render_frame(frame);
+        contract.candle_geometry = candle_geometry
+            .into_iter()
+            .map(|candle| CandleGeometry {
+                center_x: px(candle.center_x),
+                body_left: px(candle.body_left),
+                body_right: px(candle.body_right),
+                body_top: px(candle.body_top),
+                body_bottom: px(candle.body_bottom),
+                wick_top: px(candle.wick_top),
+                wick_bottom: px(candle.wick_bottom),
+                is_bullish: candle.is_bullish,
+            })
+            .collect();
+        Ok(contract)
+    }
+
+    /// Compact JSON of `render_frame_contract_v1`.
+    pub fn render_frame_json_contract_v1(&self, body_width_px: f64) -> ChartResult<String> {
+        self.render_frame_contract_v1(body_width_px)?.to_json()
+    }
+
+    pub fn render_frame_json_contract_v1_pretty(&self, body_width_px: f64) -> ChartResult<String> {
+        self.render_frame_contract_v1(body_width_px)?
+            .to_json_pretty()
+    }
+}
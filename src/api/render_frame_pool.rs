@@ -0,0 +1,64 @@
+use crate::core::Viewport;
+use crate::render::{LayeredRenderFrame, PaneLayerStack, RenderFrame, Renderer};
+
+use super::ChartEngine;
+
+/// Primitive buffers of already drawn frames, refilled by the next build so
+/// steady-state frames stop reallocating their primitive vectors.
+#[derive(Debug, Default)]
+pub(super) struct RenderFramePool {
+    frame: Option<RenderFrame>,
+    layered: Option<LayeredRenderFrame>,
+}
+
+impl<R: Renderer> ChartEngine<R> {
+    /// Hands a frame obtained from `build_render_frame` back to the engine;
+    /// the next build reuses its buffers. `render()` does this on its own.
+    pub fn recycle_render_frame(&self, frame: RenderFrame) {
+        self.core.runtime.render_frame_pool.borrow_mut().frame = Some(frame);
+    }
+
+    /// Layered counterpart of `recycle_render_frame`.
+    pub fn recycle_layered_render_frame(&self, layered: LayeredRenderFrame) {
+        self.core.runtime.render_frame_pool.borrow_mut().layered = Some(layered);
+    }
+
+    pub(super) fn take_recycled_render_frame(&self, viewport: Viewport) -> RenderFrame {
+        match self
+            .core
+            .runtime
+            .render_frame_pool
+            .borrow_mut()
+            .frame
+            .take()
+        {
+            Some(mut frame) => {
+                frame.clear();
+                frame.viewport = viewport;
+                frame
+            }
+            None => RenderFrame::new(viewport),
+        }
+    }
+
+    pub(super) fn take_recycled_layered_render_frame(
+        &self,
+        viewport: Viewport,
+        stacks: Vec<PaneLayerStack>,
+    ) -> LayeredRenderFrame {
+        match self
+            .core
+            .runtime
+            .render_frame_pool
+            .borrow_mut()
+            .layered
+            .take()
+        {
+            Some(mut layered) => {
+                layered.reset_from_stacks(viewport, stacks);
+                layered
+            }
+            None => LayeredRenderFrame::from_stacks(viewport, stacks),
+        }
+    }
+}
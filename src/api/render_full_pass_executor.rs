@@ -5,7 +5,9 @@ use super::ChartEngine;
 
 pub(super) fn render_full_pass<R: Renderer>(engine: &mut ChartEngine<R>) -> ChartResult<()> {
     let frame = engine.build_render_frame()?;
-    engine.renderer.render(&frame)
+    let result = engine.renderer.render(&frame);
+    engine.recycle_render_frame(frame);
+    result
 }
 
 #[cfg(test)]
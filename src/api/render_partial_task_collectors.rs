@@ -10,7 +10,8 @@ const AXIS_LAYERS: [CanvasLayerKind; 1] = [CanvasLayerKind::Axis];
 pub(super) fn collect_plot_tasks(
     layered: &LayeredRenderFrame,
     plan: &PartialCairoRenderPlan,
-    viewport_width: f64,
+    content_left: f64,
+    content_width: f64,
 ) -> Vec<PanePartialRenderTask> {
     let mut tasks = Vec::new();
 
@@ -28,7 +29,7 @@ pub(super) fn collect_plot_tasks(
             }
             tasks.push(PanePartialRenderTask::for_plot(
                 frame,
-                (0.0, pane.plot_top, viewport_width, pane_height),
+                (content_left, pane.plot_top, content_width, pane_height),
             ));
         }
     }
@@ -98,7 +99,7 @@ mod tests {
         );
         let plan = PartialCairoRenderPlan::build(pending, &[aux], &layered).expect("plan");
 
-        let tasks = collect_plot_tasks(&layered, &plan, 800.0);
+        let tasks = collect_plot_tasks(&layered, &plan, 0.0, 800.0);
         assert_eq!(tasks.len(), 1);
         assert!(tasks[0].clear_region);
         assert_eq!(tasks[0].clip_rect, Some((0.0, 200.0, 800.0, 300.0)));
@@ -1,5 +1,8 @@
-use crate::render::{Color, LineStrokeStyle, TextHAlign};
+use std::sync::Arc;
+
+use crate::render::{Color, DEJAVU_SANS_METRICS, LineStrokeStyle, TextHAlign};
 use serde::{Deserialize, Serialize};
+use unicode_segmentation::UnicodeSegmentation;
 
 /// Source policy used for latest-price marker selection.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
@@ -11,8 +14,59 @@ pub enum LastPriceSourceMode {
     LatestVisible,
 }
 
+/// Last-price marker handling when its source sample is off screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum LastPriceOffscreenPolicy {
+    /// Draw at the sample's price, pinned to the plot edge when off-domain.
+    #[default]
+    Draw,
+    /// Hide the line and label while the source bar is outside the visible
+    /// time range.
+    Hide,
+    /// Pin the line and label to the plot edge with an arrow pointing
+    /// towards the off-domain price.
+    EdgeArrow,
+    /// Include the last price in visible-range autoscale so the price domain
+    /// extends to it.
+    ExtendDomain,
+}
+
+/// Shading policy for alternating vertical background bands in the plot area.
+///
+/// Bands are resolved in the time-axis timezone and only apply to UTC-based
+/// time-axis policies; `LogicalDecimal` time values never produce bands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum BackgroundBandMode {
+    #[default]
+    None,
+    /// Shade every other local calendar day.
+    AlternatingDays,
+    /// Shade local Saturdays and Sundays.
+    Weekends,
+    /// Shade time outside the configured `TimeAxisSessionConfig` window.
+    OutsideSession,
+}
+
+/// Sizing policy for the price-axis panel.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum PriceAxisWidthMode {
+    /// Use `price_axis_width_px`, widening only when labels would not fit.
+    #[default]
+    Fixed,
+    /// Fit the widest visible tick or last-price label within
+    /// `[min_width_px, max_width_px]`. The panel grows as soon as a label
+    /// needs more room but only shrinks once the required width drops more
+    /// than `hysteresis_px` below the current width, so it does not jitter
+    /// while labels change length during pans and ticks.
+    Auto {
+        min_width_px: f64,
+        max_width_px: f64,
+        hysteresis_px: f64,
+    },
+}
+
 /// Width policy used for latest-price label box layout.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum LastPriceLabelBoxWidthMode {
     /// Stretch label box to the full axis panel width.
     #[default]
@@ -22,7 +76,7 @@ pub enum LastPriceLabelBoxWidthMode {
 }
 
 /// Width policy used for crosshair axis-label box layout.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum CrosshairLabelBoxWidthMode {
     /// Stretch label box to the full axis panel width.
     FullAxis,
@@ -32,7 +86,7 @@ pub enum CrosshairLabelBoxWidthMode {
 }
 
 /// Vertical anchor used for crosshair axis-label box layout around label Y.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum CrosshairLabelBoxVerticalAnchor {
     Top,
     #[default]
@@ -41,7 +95,7 @@ pub enum CrosshairLabelBoxVerticalAnchor {
 }
 
 /// Horizontal anchor used for crosshair axis-label box layout.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum CrosshairLabelBoxHorizontalAnchor {
     Left,
     #[default]
@@ -50,15 +104,38 @@ pub enum CrosshairLabelBoxHorizontalAnchor {
 }
 
 /// Overflow policy used for crosshair axis-label box layout.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum CrosshairLabelBoxOverflowPolicy {
     #[default]
     ClipToAxis,
     AllowOverflow,
 }
 
+/// Time the crosshair time label measures relative offsets from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum RelativeTimeAnchor {
+    /// Newest point or candle time across the loaded data.
+    #[default]
+    LatestBar,
+    /// Host clock passed to `ChartEngine::bar_clock_tick`; frames stay
+    /// deterministic and the label refreshes on every tick.
+    Now,
+}
+
+/// Text shown by the crosshair time label.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum CrosshairTimeLabelMode {
+    /// Formatted timestamp only.
+    #[default]
+    Absolute,
+    /// Humanized offset from the anchor only (`3h 24m ago`).
+    Relative(RelativeTimeAnchor),
+    /// Timestamp followed by the offset (`12:30 (3h 24m ago)`).
+    AbsoluteWithRelative(RelativeTimeAnchor),
+}
+
 /// Priority policy used when crosshair time/price label boxes overlap.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum CrosshairLabelBoxVisibilityPriority {
     #[default]
     KeepBoth,
@@ -66,14 +143,160 @@ pub enum CrosshairLabelBoxVisibilityPriority {
     PreferPrice,
 }
 
+/// Priority used when the crosshair price label collides with last-value labels
+/// on the price axis; the lower-priority labels are hidden for that frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum PriceAxisLabelCollisionPriority {
+    #[default]
+    PreferCrosshair,
+    PreferLastValue,
+}
+
 /// Z-order policy used when rendering crosshair time/price axis-label boxes.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum CrosshairLabelBoxZOrderPolicy {
     #[default]
     PriceAboveTime,
     TimeAbovePrice,
 }
 
+/// Visibility and stroke of one grid-line family.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct GridLineOptions {
+    pub visible: bool,
+    pub color: Color,
+    pub width: f64,
+    pub style: LineStrokeStyle,
+}
+
+impl GridLineOptions {
+    #[must_use]
+    pub fn new(color: Color, width: f64) -> Self {
+        Self {
+            visible: true,
+            color,
+            width,
+            style: LineStrokeStyle::Solid,
+        }
+    }
+
+    #[must_use]
+    pub fn with_visible(mut self, visible: bool) -> Self {
+        self.visible = visible;
+        self
+    }
+
+    #[must_use]
+    pub fn with_style(mut self, style: LineStrokeStyle) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+/// Grid configuration split per direction, mirroring Lightweight Charts
+/// `grid.vertLines` / `grid.horzLines`.
+///
+/// Major vertical lines (session/day boundaries) are only drawn when both
+/// `vert_lines` and `major_vert_lines` are visible.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GridOptions {
+    /// Vertical lines at regular time-axis ticks.
+    pub vert_lines: GridLineOptions,
+    /// Vertical lines at major time-axis ticks.
+    pub major_vert_lines: GridLineOptions,
+    /// Horizontal lines at price-axis ticks.
+    pub horz_lines: GridLineOptions,
+    /// Horizontal lines between the price-axis ticks. Hidden by default.
+    ///
+    /// Log scales place them at the log ladder's minor ticks; other scales
+    /// split each tick gap into `minor_horz_subdivisions` parts.
+    pub minor_horz_lines: GridLineOptions,
+    /// Parts each price tick gap is split into on non-log scales; `0` and `1`
+    /// draw no minor lines.
+    pub minor_horz_subdivisions: u32,
+    /// Vertical lines between time-axis ticks. Hidden by default.
+    pub minor_vert_lines: GridLineOptions,
+    /// Parts each time tick gap is split into; `0` and `1` draw no minor lines.
+    pub minor_vert_subdivisions: u32,
+}
+
+impl Default for GridOptions {
+    fn default() -> Self {
+        // Lightweight Charts v5.x default grid line color is #D6DCDE.
+        let line = GridLineOptions::new(Color::rgb(0.84, 0.86, 0.87), 1.0);
+        let minor_line =
+            GridLineOptions::new(Color::rgb(0.92, 0.93, 0.94), 1.0).with_visible(false);
+        Self {
+            vert_lines: line,
+            major_vert_lines: line,
+            horz_lines: line,
+            minor_horz_lines: minor_line,
+            minor_horz_subdivisions: 0,
+            minor_vert_lines: minor_line,
+            minor_vert_subdivisions: 0,
+        }
+    }
+}
+
+/// Opt-in last-value line/label drawn for a single series.
+///
+/// Labels share the last-price font/box settings and are stacked on the price
+/// axis together with the global last-price label so they never overlap.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SeriesLastValueStyle {
+    pub show_line: bool,
+    pub show_label: bool,
+    pub color: Color,
+    /// Uses the last-price up/down/neutral colors instead of `color`.
+    pub use_trend_color: bool,
+}
+
+impl SeriesLastValueStyle {
+    #[must_use]
+    pub fn new(color: Color) -> Self {
+        Self {
+            show_line: true,
+            show_label: true,
+            color,
+            use_trend_color: false,
+        }
+    }
+}
+
+/// Shadow line separating a filled axis strip from the plot: the left edge
+/// of the price-axis strip and the top edge of the time-axis strip.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct AxisBackgroundShadow {
+    pub color: Color,
+    pub width_px: f64,
+}
+
+/// Circular marker drawn at the magnet-snapped data point.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CrosshairSnapMarkerStyle {
+    pub radius_px: f64,
+    pub fill_color: Color,
+    pub border_color: Color,
+    pub border_width_px: f64,
+    /// Expands a fading halo around the marker after each realtime update
+    /// while the host advances `step_animations`.
+    #[serde(default)]
+    pub pulse: bool,
+}
+
+impl Default for CrosshairSnapMarkerStyle {
+    fn default() -> Self {
+        Self {
+            radius_px: 4.0,
+            fill_color: Color::rgb(0.129, 0.588, 0.953),
+            border_color: Color::rgb(1.0, 1.0, 1.0),
+            border_width_px: 2.0,
+            pulse: false,
+        }
+    }
+}
+
 /// Body fill policy for candlestick rendering.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum CandlestickBodyMode {
@@ -84,13 +307,138 @@ pub enum CandlestickBodyMode {
     HollowUp,
 }
 
+/// Bar-spacing thresholds that drop candlestick detail as candles get
+/// narrow: below `filled_rect_below_px` each candle is a single filled rect
+/// spanning its high-low range, below `no_border_below_px` bodies lose their
+/// border, and wider candles keep full detail.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CandlestickDetailTiers {
+    pub filled_rect_below_px: f64,
+    pub no_border_below_px: f64,
+}
+
+impl Default for CandlestickDetailTiers {
+    fn default() -> Self {
+        Self {
+            filled_rect_below_px: 3.0,
+            no_border_below_px: 6.0,
+        }
+    }
+}
+
+/// Text measurement used by label layout (box widths, axis sizing and
+/// overlap filtering); renderers never report back real text extents.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum LabelTextMetrics {
+    /// Character-class estimate (digits, separators, everything else), at
+    /// least one font size wide.
+    Estimated,
+    /// Embedded DejaVu Sans advance table (`DEJAVU_SANS_METRICS`), so
+    /// headless layout (`NullRenderer`, tests) matches Cairo/Pango output
+    /// with the default sans face.
+    #[default]
+    DejaVuSans,
+}
+
+impl LabelTextMetrics {
+    #[must_use]
+    pub fn text_width_px(self, text: &str, font_size_px: f64) -> f64 {
+        match self {
+            Self::Estimated => {
+                // Keep this estimate deterministic and backend-independent.
+                let units = text.chars().fold(0.0, |acc, ch| {
+                    acc + match ch {
+                        '0'..='9' => 0.62,
+                        '.' | ',' => 0.34,
+                        '-' | '+' | '%' => 0.42,
+                        ' ' => 0.33,
+                        _ => 0.58,
+                    }
+                });
+                (units * font_size_px).max(font_size_px)
+            }
+            Self::DejaVuSans => DEJAVU_SANS_METRICS.text_width_px(text, font_size_px),
+        }
+    }
+}
+
+/// What label layout does with text wider than the box or axis panel that
+/// holds it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum LabelOverflowPolicy {
+    /// Draw the full text past the box edges.
+    #[default]
+    Overflow,
+    /// Keep the leading graphemes and end with an ellipsis.
+    TruncateEnd,
+    /// Keep leading and trailing graphemes around an ellipsis.
+    TruncateMiddle,
+    /// Drop the label.
+    Hide,
+}
+
+impl LabelOverflowPolicy {
+    /// `text` as drawn in `max_width_px`, or `None` when the label is hidden.
+    ///
+    /// Truncation never splits a grapheme cluster and hides the label when
+    /// not even the ellipsis fits.
+    #[must_use]
+    pub fn fit_text(
+        self,
+        text: &str,
+        max_width_px: f64,
+        font_size_px: f64,
+        metrics: LabelTextMetrics,
+    ) -> Option<String> {
+        let fits = |candidate: &str| metrics.text_width_px(candidate, font_size_px) <= max_width_px;
+        if self == Self::Overflow || fits(text) {
+            return Some(text.to_owned());
+        }
+        let graphemes = text.graphemes(true).collect::<Vec<_>>();
+        let (head_share, tail_share) = match self {
+            Self::Overflow | Self::Hide => return None,
+            Self::TruncateEnd => (1, 0),
+            Self::TruncateMiddle => (1, 1),
+        };
+        (0..graphemes.len())
+            .rev()
+            .map(|kept| {
+                let tail = kept * tail_share / (head_share + tail_share);
+                let head = kept - tail;
+                format!(
+                    "{}{LABEL_ELLIPSIS}{}",
+                    graphemes[..head].concat(),
+                    graphemes[graphemes.len() - tail..].concat()
+                )
+            })
+            .find(|candidate| fits(candidate))
+    }
+
+    /// `fit_text` for cached labels: text that fits keeps its shared
+    /// allocation.
+    pub(super) fn fit_shared_text(
+        self,
+        text: &Arc<str>,
+        max_width_px: f64,
+        font_size_px: f64,
+        metrics: LabelTextMetrics,
+    ) -> Option<Arc<str>> {
+        if self == Self::Overflow || metrics.text_width_px(text, font_size_px) <= max_width_px {
+            return Some(Arc::clone(text));
+        }
+        self.fit_text(text, max_width_px, font_size_px, metrics)
+            .map(Arc::from)
+    }
+}
+
+const LABEL_ELLIPSIS: char = '\u{2026}';
+
 /// Style contract for the current render frame.
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RenderStyle {
     pub series_line_color: Color,
-    pub grid_line_color: Color,
-    pub price_axis_grid_line_color: Color,
-    pub major_grid_line_color: Color,
+    /// Per-direction grid line visibility and stroke.
+    pub grid: GridOptions,
     pub axis_border_color: Color,
     pub price_axis_tick_mark_color: Color,
     pub time_axis_tick_mark_color: Color,
@@ -104,17 +452,17 @@ pub struct RenderStyle {
     pub crosshair_time_label_color: Color,
     pub crosshair_price_label_color: Color,
     /// Shared prefix prepended to crosshair axis-label text when per-axis override is absent.
-    pub crosshair_label_prefix: &'static str,
+    pub crosshair_label_prefix: Arc<str>,
     /// Shared suffix appended to crosshair axis-label text when per-axis override is absent.
-    pub crosshair_label_suffix: &'static str,
+    pub crosshair_label_suffix: Arc<str>,
     /// Optional dedicated prefix for crosshair time-axis label text.
-    pub crosshair_time_label_prefix: Option<&'static str>,
+    pub crosshair_time_label_prefix: Option<Arc<str>>,
     /// Optional dedicated suffix for crosshair time-axis label text.
-    pub crosshair_time_label_suffix: Option<&'static str>,
+    pub crosshair_time_label_suffix: Option<Arc<str>>,
     /// Optional dedicated prefix for crosshair price-axis label text.
-    pub crosshair_price_label_prefix: Option<&'static str>,
+    pub crosshair_price_label_prefix: Option<Arc<str>>,
     /// Optional dedicated suffix for crosshair price-axis label text.
-    pub crosshair_price_label_suffix: Option<&'static str>,
+    pub crosshair_price_label_suffix: Option<Arc<str>>,
     /// Shared numeric precision override for crosshair axis labels when per-axis override is absent.
     pub crosshair_label_numeric_precision: Option<u8>,
     /// Optional dedicated numeric precision override for crosshair time-axis labels.
@@ -158,9 +506,6 @@ pub struct RenderStyle {
     pub candlestick_border_down_color: Color,
     /// Fill policy used by candlestick bodies.
     pub candlestick_body_mode: CandlestickBodyMode,
-    pub grid_line_width: f64,
-    pub price_axis_grid_line_width: f64,
-    pub major_grid_line_width: f64,
     pub axis_line_width: f64,
     pub price_axis_tick_mark_width: f64,
     pub time_axis_tick_mark_width: f64,
@@ -176,9 +521,16 @@ pub struct RenderStyle {
     pub show_candlestick_wicks: bool,
     /// Controls visibility of candlestick body border strokes.
     pub show_candlestick_borders: bool,
+    /// Spacing-based detail tiers; `None` keeps wicks and borders at every
+    /// spacing (only their widths shrink).
+    #[serde(default)]
+    pub candlestick_detail_tiers: Option<CandlestickDetailTiers>,
     pub crosshair_line_style: LineStrokeStyle,
     pub crosshair_horizontal_line_style: Option<LineStrokeStyle>,
     pub crosshair_vertical_line_style: Option<LineStrokeStyle>,
+    /// Marker at the snapped data point while magnet snapping is active.
+    #[serde(default)]
+    pub crosshair_snap_marker: Option<CrosshairSnapMarkerStyle>,
     pub crosshair_time_label_font_size_px: f64,
     pub crosshair_price_label_font_size_px: f64,
     pub crosshair_axis_label_font_size_px: f64,
@@ -227,6 +579,13 @@ pub struct RenderStyle {
     pub time_axis_label_font_size_px: f64,
     /// Vertical offset from the plot bottom used by time-axis label anchors.
     pub time_axis_label_offset_y_px: f64,
+    /// Angle (degrees, `0..=90`) time-axis labels rotate by when horizontal
+    /// labels would overlap; `0.0` drops overlapping labels instead.
+    ///
+    /// Rotated labels hang down-left from their tick, so raise
+    /// `time_axis_height_px` to leave room for them.
+    #[serde(default)]
+    pub time_axis_label_rotation_degrees: f64,
     /// Vertical offset from the plot bottom used by crosshair time-axis label anchors.
     pub crosshair_time_label_offset_y_px: f64,
     /// Vertical offset from the plot bottom used by major time-axis label anchors.
@@ -246,9 +605,12 @@ pub struct RenderStyle {
     /// Horizontal inset from right edge used by last-price label when box mode is disabled.
     pub last_price_label_padding_right_px: f64,
     pub price_axis_width_px: f64,
+    /// Fixed or label-driven price-axis width; see
+    /// `ChartEngine::resolved_price_axis_width_px` for the width in use.
+    #[serde(default)]
+    pub price_axis_width_mode: PriceAxisWidthMode,
     pub time_axis_height_px: f64,
     pub show_price_axis_tick_marks: bool,
-    pub show_price_axis_grid_lines: bool,
     pub show_price_axis_labels: bool,
     /// Controls visibility of the right-side price-axis border line.
     pub show_price_axis_border: bool,
@@ -256,7 +618,9 @@ pub struct RenderStyle {
     /// Controls visibility of the bottom time-axis border line.
     pub show_time_axis_border: bool,
     pub show_major_time_labels: bool,
-    pub show_major_time_grid_lines: bool,
+    /// Pins the date of the left-most visible bar to the left edge of the time
+    /// axis; regular labels underneath it are dropped.
+    pub show_time_axis_sticky_date_label: bool,
     pub show_time_axis_tick_marks: bool,
     /// Controls major time-axis tick-mark visibility independently from regular ticks.
     pub show_major_time_tick_marks: bool,
@@ -266,6 +630,9 @@ pub struct RenderStyle {
     pub show_crosshair_vertical_line: bool,
     /// Shared visibility gate for crosshair guide lines; per-axis toggles still apply.
     pub show_crosshair_lines: bool,
+    /// Extends the vertical guide through the time-axis panel and the horizontal
+    /// guide through the price-axis panel so they meet the axis label boxes.
+    pub crosshair_lines_extend_into_axis_panels: bool,
     /// Controls visibility of the crosshair label projected on the time axis panel.
     pub show_crosshair_time_label: bool,
     /// Controls visibility of the crosshair label projected on the price axis panel.
@@ -292,6 +659,9 @@ pub struct RenderStyle {
     pub last_price_use_trend_color: bool,
     /// Selects whether last-price marker tracks full-series or visible-range latest sample.
     pub last_price_source_mode: LastPriceSourceMode,
+    /// Handling of the last-price marker when its source bar is off screen.
+    #[serde(default)]
+    pub last_price_offscreen_policy: LastPriceOffscreenPolicy,
     /// Enables a filled price-box background behind last-price axis text.
     pub show_last_price_label_box: bool,
     /// Uses trend/marker color for last-price label box background when enabled.
@@ -317,16 +687,57 @@ pub struct RenderStyle {
     /// Corner radius for last-price label box.
     pub last_price_label_box_corner_radius_px: f64,
     pub last_price_label_exclusion_px: f64,
+    /// Last-value line/label for the point series; `None` disables it.
+    pub point_series_last_value: Option<SeriesLastValueStyle>,
+    /// Last-value line/label for the candlestick series; `None` disables it.
+    pub candle_series_last_value: Option<SeriesLastValueStyle>,
+    /// Which price-axis label survives when the crosshair price label overlaps
+    /// last-price or series last-value labels.
+    pub price_axis_label_collision_priority: PriceAxisLabelCollisionPriority,
+    /// Fill for the whole viewport; `None` keeps frames transparent.
+    pub background_color: Option<Color>,
+    /// Fill for the plot area, drawn above `background_color`.
+    pub plot_background_color: Option<Color>,
+    /// Alternating vertical band policy drawn in the background layer.
+    pub background_band_mode: BackgroundBandMode,
+    /// Fill color used by shaded background bands.
+    pub background_band_color: Color,
+    /// Fill for the price-axis strip (including the bottom-right corner),
+    /// drawn under axis labels and over series content that overflows the
+    /// plot. Also covers crosshair lines extended into the axis panels.
+    #[serde(default)]
+    pub price_axis_background_color: Option<Color>,
+    /// Fill for the time-axis strip; see `price_axis_background_color`.
+    #[serde(default)]
+    pub time_axis_background_color: Option<Color>,
+    /// Line along the plot-facing edge of each filled axis strip.
+    #[serde(default)]
+    pub axis_background_shadow: Option<AxisBackgroundShadow>,
+    /// How label layout measures text widths.
+    #[serde(default)]
+    pub label_text_metrics: LabelTextMetrics,
+    /// How last-price, crosshair and price-axis labels fit text that is
+    /// wider than their box or axis panel.
+    #[serde(default)]
+    pub label_overflow_policy: LabelOverflowPolicy,
+    /// Prefix on price-axis, last-price and crosshair price labels (`"$"`),
+    /// counted in axis width and label box sizing.
+    #[serde(default)]
+    pub price_label_prefix: Arc<str>,
+    /// Suffix on the same labels (`" BTC"`), after any percentage suffix.
+    #[serde(default)]
+    pub price_label_suffix: Arc<str>,
+    /// Absolute, relative or combined crosshair time label text; relative
+    /// offsets fall back to the timestamp when the anchor has no data.
+    #[serde(default)]
+    pub crosshair_time_label_mode: CrosshairTimeLabelMode,
 }
 
 impl Default for RenderStyle {
     fn default() -> Self {
         Self {
             series_line_color: Color::rgb(0.16, 0.38, 1.0),
-            // Lightweight Charts v5.x default grid line color is #D6DCDE.
-            grid_line_color: Color::rgb(0.84, 0.86, 0.87),
-            price_axis_grid_line_color: Color::rgb(0.84, 0.86, 0.87),
-            major_grid_line_color: Color::rgb(0.84, 0.86, 0.87),
+            grid: GridOptions::default(),
             axis_border_color: Color::rgb(0.17, 0.17, 0.26),
             price_axis_tick_mark_color: Color::rgb(0.17, 0.17, 0.26),
             time_axis_tick_mark_color: Color::rgb(0.17, 0.17, 0.26),
@@ -341,8 +752,8 @@ impl Default for RenderStyle {
             crosshair_vertical_line_color: None,
             crosshair_time_label_color: Color::rgb(1.0, 1.0, 1.0),
             crosshair_price_label_color: Color::rgb(1.0, 1.0, 1.0),
-            crosshair_label_prefix: "",
-            crosshair_label_suffix: "",
+            crosshair_label_prefix: Arc::from(""),
+            crosshair_label_suffix: Arc::from(""),
             crosshair_time_label_prefix: None,
             crosshair_time_label_suffix: None,
             crosshair_price_label_prefix: None,
@@ -379,9 +790,6 @@ impl Default for RenderStyle {
             candlestick_border_up_color: Color::rgb(0.149, 0.651, 0.604),
             candlestick_border_down_color: Color::rgb(0.937, 0.325, 0.314),
             candlestick_body_mode: CandlestickBodyMode::Solid,
-            grid_line_width: 1.0,
-            price_axis_grid_line_width: 1.0,
-            major_grid_line_width: 1.0,
             axis_line_width: 1.0,
             price_axis_tick_mark_width: 1.0,
             time_axis_tick_mark_width: 1.0,
@@ -393,9 +801,11 @@ impl Default for RenderStyle {
             candlestick_border_width_px: 1.0,
             show_candlestick_wicks: true,
             show_candlestick_borders: true,
+            candlestick_detail_tiers: None,
             crosshair_line_style: LineStrokeStyle::LargeDashed,
             crosshair_horizontal_line_style: None,
             crosshair_vertical_line_style: None,
+            crosshair_snap_marker: None,
             crosshair_time_label_font_size_px: 12.0,
             crosshair_price_label_font_size_px: 12.0,
             crosshair_axis_label_font_size_px: 12.0,
@@ -442,6 +852,7 @@ impl Default for RenderStyle {
             major_time_label_font_size_px: 12.0,
             time_axis_label_font_size_px: 12.0,
             time_axis_label_offset_y_px: 4.0,
+            time_axis_label_rotation_degrees: 0.0,
             crosshair_time_label_offset_y_px: 4.0,
             major_time_label_offset_y_px: 4.0,
             time_axis_tick_mark_length_px: 6.0,
@@ -453,20 +864,21 @@ impl Default for RenderStyle {
             last_price_label_offset_y_px: 8.64,
             last_price_label_padding_right_px: 6.0,
             price_axis_width_px: 72.0,
+            price_axis_width_mode: PriceAxisWidthMode::Fixed,
             time_axis_height_px: 24.0,
             show_price_axis_tick_marks: false,
-            show_price_axis_grid_lines: true,
             show_price_axis_labels: true,
             show_price_axis_border: true,
             show_time_axis_labels: true,
             show_time_axis_border: true,
             show_major_time_labels: true,
-            show_major_time_grid_lines: true,
+            show_time_axis_sticky_date_label: false,
             show_time_axis_tick_marks: false,
             show_major_time_tick_marks: false,
             show_crosshair_horizontal_line: true,
             show_crosshair_vertical_line: true,
             show_crosshair_lines: true,
+            crosshair_lines_extend_into_axis_panels: false,
             show_crosshair_time_label: true,
             show_crosshair_price_label: true,
             show_crosshair_time_label_box: true,
@@ -481,6 +893,7 @@ impl Default for RenderStyle {
             show_last_price_label: true,
             last_price_use_trend_color: false,
             last_price_source_mode: LastPriceSourceMode::LatestData,
+            last_price_offscreen_policy: LastPriceOffscreenPolicy::Draw,
             show_last_price_label_box: false,
             last_price_label_box_use_marker_color: true,
             last_price_label_box_color: Color::rgb(0.16, 0.38, 1.0),
@@ -494,6 +907,21 @@ impl Default for RenderStyle {
             last_price_label_box_border_color: Color::rgb(0.82, 0.84, 0.88),
             last_price_label_box_corner_radius_px: 0.0,
             last_price_label_exclusion_px: 22.0,
+            point_series_last_value: None,
+            candle_series_last_value: None,
+            price_axis_label_collision_priority: PriceAxisLabelCollisionPriority::PreferCrosshair,
+            background_color: None,
+            plot_background_color: None,
+            background_band_mode: BackgroundBandMode::None,
+            background_band_color: Color::rgba(0.46, 0.53, 0.59, 0.08),
+            price_axis_background_color: None,
+            time_axis_background_color: None,
+            axis_background_shadow: None,
+            label_text_metrics: LabelTextMetrics::default(),
+            label_overflow_policy: LabelOverflowPolicy::default(),
+            price_label_prefix: Arc::from(""),
+            price_label_suffix: Arc::from(""),
+            crosshair_time_label_mode: CrosshairTimeLabelMode::default(),
         }
     }
 }
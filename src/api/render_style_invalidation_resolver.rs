@@ -13,8 +13,8 @@ pub(super) enum RenderStyleInvalidationDecision {
 }
 
 pub(super) fn resolve_render_style_invalidation(
-    previous: RenderStyle,
-    next: RenderStyle,
+    previous: &RenderStyle,
+    next: &RenderStyle,
 ) -> RenderStyleInvalidationDecision {
     if previous == next {
         return RenderStyleInvalidationDecision::None;
@@ -25,9 +25,13 @@ pub(super) fn resolve_render_style_invalidation(
     RenderStyleInvalidationDecision::Light(RENDER_STYLE_LIGHT_TOPICS)
 }
 
-fn render_style_layout_changed(previous: RenderStyle, next: RenderStyle) -> bool {
+fn render_style_layout_changed(previous: &RenderStyle, next: &RenderStyle) -> bool {
     previous.price_axis_width_px != next.price_axis_width_px
+        || previous.price_axis_width_mode != next.price_axis_width_mode
         || previous.time_axis_height_px != next.time_axis_height_px
+        || previous.label_text_metrics != next.label_text_metrics
+        || previous.price_label_prefix != next.price_label_prefix
+        || previous.price_label_suffix != next.price_label_suffix
 }
 
 #[cfg(test)]
@@ -38,7 +42,7 @@ mod tests {
     #[test]
     fn resolver_returns_none_when_style_is_identical() {
         let style = RenderStyle::default();
-        let decision = resolve_render_style_invalidation(style, style);
+        let decision = resolve_render_style_invalidation(&style, &style);
         assert!(matches!(decision, RenderStyleInvalidationDecision::None));
     }
 
@@ -47,10 +51,10 @@ mod tests {
         let previous = RenderStyle::default();
         let next = RenderStyle {
             price_axis_width_px: previous.price_axis_width_px + 5.0,
-            ..previous
+            ..previous.clone()
         };
 
-        let decision = resolve_render_style_invalidation(previous, next);
+        let decision = resolve_render_style_invalidation(&previous, &next);
         assert!(matches!(decision, RenderStyleInvalidationDecision::Full));
     }
 
@@ -59,10 +63,10 @@ mod tests {
         let previous = RenderStyle::default();
         let next = RenderStyle {
             show_price_axis_labels: !previous.show_price_axis_labels,
-            ..previous
+            ..previous.clone()
         };
 
-        let decision = resolve_render_style_invalidation(previous, next);
+        let decision = resolve_render_style_invalidation(&previous, &next);
         let RenderStyleInvalidationDecision::Light(topics) = decision else {
             panic!("expected light invalidation");
         };
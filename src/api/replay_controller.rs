@@ -0,0 +1,309 @@
+use std::collections::VecDeque;
+
+use crate::core::{DataPoint, OhlcBar};
+use crate::error::{ChartError, ChartResult};
+use crate::render::Renderer;
+
+use super::{CandlestickBarStyleOverride, ChartEngine, StyledOhlcBar};
+
+/// Data hidden behind the replay cursor plus the playback clock.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub(super) struct ReplayState {
+    cursor: f64,
+    hidden_points: VecDeque<DataPoint>,
+    hidden_candles: VecDeque<StyledOhlcBar>,
+    bars_per_second: Option<f64>,
+    /// Fractional bars accumulated by playback but not yet revealed.
+    playback_carry: f64,
+}
+
+impl<R: Renderer> ChartEngine<R> {
+    /// Enters bar replay with every sample newer than `cursor` hidden.
+    ///
+    /// Hidden samples are excluded from projection, crosshair snapping,
+    /// autoscale and the last price until `replay_step_forward` or playback
+    /// reveals them. Restarting an active replay first restores its hidden
+    /// samples, so the cursor may also move backwards.
+    ///
+    /// Data written while replaying is masked the same way: replaced series
+    /// are split at the cursor, and realtime appends/updates newer than the
+    /// cursor are queued behind it.
+    pub fn start_replay(&mut self, cursor: f64) -> ChartResult<()> {
+        if !cursor.is_finite() {
+            return Err(ChartError::InvalidData(
+                "replay cursor must be finite".to_owned(),
+            ));
+        }
+        let (points, candles) = self.take_replay_hidden_data();
+        self.core.runtime.replay = Some(ReplayState {
+            cursor,
+            ..ReplayState::default()
+        });
+        if points || self.core.model.points.last().is_some_and(|p| p.x > cursor) {
+            let points = std::mem::take(&mut self.core.model.points);
+            self.install_canonical_points(points, "start_replay");
+        }
+        if candles
+            || self
+                .core
+                .model
+                .candles
+                .last()
+                .is_some_and(|bar| bar.time > cursor)
+        {
+            let candles = std::mem::take(&mut self.core.model.candles);
+            let overrides = std::mem::take(&mut self.core.model.candle_style_overrides);
+            self.install_canonical_candles(candles, overrides, "start_replay");
+        }
+        Ok(())
+    }
+
+    /// Leaves replay and shows all hidden samples again.
+    pub fn stop_replay(&mut self) {
+        let (points, candles) = self.take_replay_hidden_data();
+        self.core.runtime.replay = None;
+        if points {
+            let points = std::mem::take(&mut self.core.model.points);
+            self.install_canonical_points(points, "stop_replay");
+        }
+        if candles {
+            let candles = std::mem::take(&mut self.core.model.candles);
+            let overrides = std::mem::take(&mut self.core.model.candle_style_overrides);
+            self.install_canonical_candles(candles, overrides, "stop_replay");
+        }
+    }
+
+    /// Current replay cursor; `None` outside replay.
+    #[must_use]
+    pub fn replay_cursor(&self) -> Option<f64> {
+        self.core
+            .runtime
+            .replay
+            .as_ref()
+            .map(|replay| replay.cursor)
+    }
+
+    /// Bars still hidden behind the cursor, counting each distinct time
+    /// across the point and candle series once.
+    #[must_use]
+    pub fn replay_remaining_bars(&self) -> usize {
+        let Some(replay) = self.core.runtime.replay.as_ref() else {
+            return 0;
+        };
+        let mut points = replay.hidden_points.iter().map(|point| point.x).peekable();
+        let mut candles = replay
+            .hidden_candles
+            .iter()
+            .map(|bar| bar.ohlc.time)
+            .peekable();
+        let mut count = 0;
+        while let Some(time) = next_replay_time(points.peek().copied(), candles.peek().copied()) {
+            count += 1;
+            while points.next_if(|x| *x <= time).is_some() {}
+            while candles.next_if(|t| *t <= time).is_some() {}
+        }
+        count
+    }
+
+    /// Reveals the next `n_bars` hidden bars through the realtime append
+    /// path (auto-scroll, autoscale and data events apply as for live data)
+    /// and moves the cursor to the last revealed time.
+    ///
+    /// Returns the number of bars revealed, which is lower than `n_bars`
+    /// when the hidden data runs out and zero outside replay.
+    pub fn replay_step_forward(&mut self, n_bars: usize) -> ChartResult<usize> {
+        let mut revealed = 0;
+        while revealed < n_bars {
+            let Some(replay) = self.core.runtime.replay.as_mut() else {
+                break;
+            };
+            let Some(time) = next_replay_time(
+                replay.hidden_points.front().map(|point| point.x),
+                replay.hidden_candles.front().map(|bar| bar.ohlc.time),
+            ) else {
+                break;
+            };
+            replay.cursor = time;
+            let point_count = replay
+                .hidden_points
+                .partition_point(|point| point.x <= time);
+            let points: Vec<_> = replay.hidden_points.drain(..point_count).collect();
+            let candle_count = replay
+                .hidden_candles
+                .partition_point(|bar| bar.ohlc.time <= time);
+            let candles: Vec<_> = replay.hidden_candles.drain(..candle_count).collect();
+            for point in points {
+                self.append_point(point)?;
+            }
+            for candle in candles {
+                self.append_styled_candle(candle)?;
+            }
+            revealed += 1;
+        }
+        Ok(revealed)
+    }
+
+    /// Starts revealing `bars_per_second` bars per second of
+    /// `step_animations` time. Playback pauses itself once the hidden data
+    /// runs out.
+    pub fn replay_play(&mut self, bars_per_second: f64) -> ChartResult<()> {
+        if !bars_per_second.is_finite() || bars_per_second <= 0.0 {
+            return Err(ChartError::InvalidData(
+                "replay speed must be finite and > 0".to_owned(),
+            ));
+        }
+        let Some(replay) = self.core.runtime.replay.as_mut() else {
+            return Err(ChartError::InvalidData(
+                "replay playback requires an active replay".to_owned(),
+            ));
+        };
+        replay.bars_per_second = Some(bars_per_second);
+        Ok(())
+    }
+
+    pub fn replay_pause(&mut self) {
+        if let Some(replay) = self.core.runtime.replay.as_mut() {
+            replay.bars_per_second = None;
+            replay.playback_carry = 0.0;
+        }
+    }
+
+    #[must_use]
+    pub fn replay_playing(&self) -> bool {
+        self.core
+            .runtime
+            .replay
+            .as_ref()
+            .is_some_and(|replay| replay.bars_per_second.is_some())
+    }
+
+    /// Advances playback by `delta_seconds`; returns whether bars were
+    /// revealed. Called from `step_animations`.
+    pub(super) fn step_replay_playback(&mut self, delta_seconds: f64) -> ChartResult<bool> {
+        let Some(replay) = self.core.runtime.replay.as_mut() else {
+            return Ok(false);
+        };
+        let Some(bars_per_second) = replay.bars_per_second else {
+            return Ok(false);
+        };
+        replay.playback_carry += delta_seconds * bars_per_second;
+        let due = replay.playback_carry.floor();
+        replay.playback_carry -= due;
+        let revealed = self.replay_step_forward(due as usize)?;
+        if self.replay_remaining_bars() == 0 {
+            self.replay_pause();
+        }
+        Ok(revealed > 0)
+    }
+
+    /// Splits replaced points at the cursor, keeping the newer ones hidden.
+    pub(super) fn mask_replay_points(&mut self, points: &mut Vec<DataPoint>) {
+        if let Some(replay) = self.core.runtime.replay.as_mut() {
+            let split = points.partition_point(|point| point.x <= replay.cursor);
+            replay.hidden_points = points.drain(split..).collect();
+        }
+    }
+
+    /// Splits replaced candles at the cursor, keeping the newer ones hidden.
+    pub(super) fn mask_replay_candles(
+        &mut self,
+        candles: &mut Vec<OhlcBar>,
+        style_overrides: &mut Vec<Option<CandlestickBarStyleOverride>>,
+    ) {
+        if let Some(replay) = self.core.runtime.replay.as_mut() {
+            let split = candles.partition_point(|bar| bar.time <= replay.cursor);
+            replay.hidden_candles = candles
+                .drain(split..)
+                .zip(style_overrides.drain(split..))
+                .map(|(ohlc, style_override)| StyledOhlcBar {
+                    ohlc,
+                    style_override,
+                })
+                .collect();
+        }
+    }
+
+    /// Queues a realtime point newer than the cursor instead of showing it.
+    ///
+    /// Returns `false` when the point is not masked; `replace_equal` applies
+    /// `update_point` semantics to the hidden tail. Times older than the
+    /// hidden tail are rejected either way.
+    pub(super) fn queue_replay_point(
+        &mut self,
+        point: DataPoint,
+        replace_equal: bool,
+    ) -> ChartResult<bool> {
+        let Some(replay) = self.core.runtime.replay.as_mut() else {
+            return Ok(false);
+        };
+        if point.x <= replay.cursor {
+            return Ok(false);
+        }
+        let hidden = &mut replay.hidden_points;
+        match hidden.back_mut() {
+            Some(last) if point.x < last.x => {
+                return Err(ChartError::NonMonotonicTime {
+                    index: self.core.model.points.len() + hidden.len(),
+                });
+            }
+            Some(last) if replace_equal && point.x == last.x => *last = point,
+            _ => hidden.push_back(point),
+        }
+        Ok(true)
+    }
+
+    /// Candle counterpart of [`Self::queue_replay_point`].
+    pub(super) fn queue_replay_candle(
+        &mut self,
+        candle: StyledOhlcBar,
+        replace_equal: bool,
+    ) -> ChartResult<bool> {
+        let Some(replay) = self.core.runtime.replay.as_mut() else {
+            return Ok(false);
+        };
+        if candle.ohlc.time <= replay.cursor {
+            return Ok(false);
+        }
+        let hidden = &mut replay.hidden_candles;
+        match hidden.back_mut() {
+            Some(last) if candle.ohlc.time < last.ohlc.time => {
+                return Err(ChartError::NonMonotonicTime {
+                    index: self.core.model.candles.len() + hidden.len(),
+                });
+            }
+            Some(last) if replace_equal && candle.ohlc.time == last.ohlc.time => *last = candle,
+            _ => hidden.push_back(candle),
+        }
+        Ok(true)
+    }
+
+    /// Moves hidden samples back into the model without refreshing it and
+    /// reports which series (points, candles) received any.
+    fn take_replay_hidden_data(&mut self) -> (bool, bool) {
+        let Some(replay) = self.core.runtime.replay.as_mut() else {
+            return (false, false);
+        };
+        let points = !replay.hidden_points.is_empty();
+        let candles = !replay.hidden_candles.is_empty();
+        self.core
+            .model
+            .points
+            .extend(replay.hidden_points.drain(..));
+        for bar in replay.hidden_candles.drain(..) {
+            self.core.model.candles.push(bar.ohlc);
+            self.core
+                .model
+                .candle_style_overrides
+                .push(bar.style_override);
+        }
+        (points, candles)
+    }
+}
+
+/// Earliest of the next hidden point and candle times.
+fn next_replay_time(point: Option<f64>, candle: Option<f64>) -> Option<f64> {
+    match (point, candle) {
+        (Some(point), Some(candle)) => Some(point.min(candle)),
+        (time, None) | (None, time) => time,
+    }
+}
@@ -38,6 +38,7 @@ impl<R: Renderer> ChartEngine<R> {
         let Some((space, reference_step)) = self.resolve_time_index_coordinate_space() else {
             return Ok(None);
         };
+        let pixel = self.mirror_time_axis_px(pixel);
 
         match policy {
             TimeCoordinateIndexPolicy::AllowWhitespace => {
@@ -88,7 +89,9 @@ impl<R: Renderer> ChartEngine<R> {
         let Some((space, _reference_step)) = self.resolve_time_index_coordinate_space() else {
             return Ok(None);
         };
-        Ok(Some(space.index_to_coordinate(logical_index)?))
+        Ok(Some(self.mirror_time_axis_px(
+            space.index_to_coordinate(logical_index)?,
+        )))
     }
 
     /// Maps pixel X to discrete logical index using ceil semantics.
@@ -111,9 +114,9 @@ impl<R: Renderer> ChartEngine<R> {
         };
 
         match policy {
-            TimeCoordinateIndexPolicy::AllowWhitespace => {
-                Ok(Some(space.coordinate_to_index_ceil(pixel)?))
-            }
+            TimeCoordinateIndexPolicy::AllowWhitespace => Ok(Some(
+                space.coordinate_to_index_ceil(self.mirror_time_axis_px(pixel))?,
+            )),
             TimeCoordinateIndexPolicy::IgnoreWhitespace => {
                 let Some(logical) = self.map_pixel_to_logical_index(pixel, policy)? else {
                     return Ok(None);
@@ -141,6 +144,7 @@ impl<R: Renderer> ChartEngine<R> {
         let Some((space, reference_step)) = self.resolve_time_index_coordinate_space() else {
             return Ok(None);
         };
+        let pixel = self.mirror_time_axis_px(pixel);
 
         let mut best: Option<(f64, TimeFilledLogicalSlot)> = None;
 
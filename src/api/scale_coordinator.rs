@@ -74,7 +74,7 @@ impl ScaleCoordinator {
             let layout = resolve_axis_layout(
                 viewport_width,
                 viewport_height,
-                engine.core.presentation.render_style.price_axis_width_px,
+                engine.layout_price_axis_width_px(),
                 engine.core.presentation.render_style.time_axis_height_px,
             );
             let anchor_x = anchor_x_px.clamp(0.0, layout.plot_right);
@@ -140,7 +140,7 @@ impl ScaleCoordinator {
         let layout = resolve_axis_layout(
             viewport_width,
             viewport_height,
-            engine.core.presentation.render_style.price_axis_width_px,
+            engine.layout_price_axis_width_px(),
             engine.core.presentation.render_style.time_axis_height_px,
         );
         let plot_bottom = layout.plot_bottom;
@@ -242,9 +242,11 @@ impl ScaleCoordinator {
         let (domain_start, domain_end) = engine.core.model.price_scale.domain();
         let current_span = (domain_end - domain_start).abs();
         if !current_span.is_finite() || current_span <= 0.0 {
-            return Err(ChartError::InvalidData(
-                "price domain span must be finite and non-zero".to_owned(),
-            ));
+            return Err(ChartError::InvalidRange {
+                field: "price domain",
+                min: domain_start,
+                max: domain_end,
+            });
         }
 
         let unclamped_target_span = current_span * factor;
@@ -256,7 +258,7 @@ impl ScaleCoordinator {
         let layout = resolve_axis_layout(
             viewport_width,
             viewport_height,
-            engine.core.presentation.render_style.price_axis_width_px,
+            engine.layout_price_axis_width_px(),
             engine.core.presentation.render_style.time_axis_height_px,
         );
         let plot_bottom = layout.plot_bottom;
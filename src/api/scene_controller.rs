@@ -0,0 +1,319 @@
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+use crate::core::{
+    ChartTimeKind, DataPoint, PaneCollection, PaneId, PriceScale, TimeScale, Viewport,
+};
+use crate::error::{ChartError, ChartResult};
+use crate::extensions::{PriceAlert, VolumeProfileConfig, VolumeSample};
+use crate::interaction::CrosshairMode;
+use crate::render::Renderer;
+
+use super::chart_runtime::LwcTimeScaleInvalidationIntent;
+use super::{
+    AxisInteractionConfig, BarCountdownBehavior, ChartEngine, CompareSeries, DataGapBehavior,
+    ElasticOverscrollBehavior, HighLowMarkerBehavior, InteractionInputBehavior,
+    LastValueAnimationBehavior, LayoutInsets, NavigationHistoryBehavior, OhlcInfoLineBehavior,
+    PaneSeparatorBehavior, PriceAxisLabelConfig, PriceAxisLabelPolicy, PriceScaleRealtimeBehavior,
+    PriceScaleTransformedBaseBehavior, RenderStyle, SeriesRef, StyledOhlcBar, TimeAxisLabelConfig,
+    TimeScaleEdgeBehavior, TimeScaleMark, TimeScaleMarkBehavior, TimeScaleNavigationBehavior,
+    TimeScaleRealtimeAppendBehavior, TimeScaleResizeBehavior, TimeScaleScrollZoomBehavior,
+    TimeScaleZoomLimitBehavior, ViewBookmark, VisibleRangeChangeBehavior, WheelBehaviorConfig,
+    ZoomSelectionBehavior,
+};
+
+pub const CHART_SCENE_JSON_SCHEMA_V2: u32 = 2;
+
+/// Behavior policies captured by a scene.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ChartSceneBehaviors {
+    pub time_scale_edge: TimeScaleEdgeBehavior,
+    pub time_scale_navigation: TimeScaleNavigationBehavior,
+    pub time_scale_zoom_limit: TimeScaleZoomLimitBehavior,
+    pub time_scale_right_offset_px: Option<f64>,
+    pub time_scale_scroll_zoom: TimeScaleScrollZoomBehavior,
+    pub time_scale_resize: TimeScaleResizeBehavior,
+    pub time_scale_realtime_append: TimeScaleRealtimeAppendBehavior,
+    pub price_scale_realtime: PriceScaleRealtimeBehavior,
+    pub price_scale_transformed_base: PriceScaleTransformedBaseBehavior,
+    pub interaction_input: InteractionInputBehavior,
+    #[serde(default)]
+    pub pane_separator: PaneSeparatorBehavior,
+    #[serde(default)]
+    pub ohlc_info_line: OhlcInfoLineBehavior,
+    #[serde(default)]
+    pub volume_profile: Option<VolumeProfileConfig>,
+    #[serde(default)]
+    pub compare_mode: bool,
+    #[serde(default)]
+    pub time_scale_marks: TimeScaleMarkBehavior,
+    #[serde(default)]
+    pub axis_interaction: AxisInteractionConfig,
+    #[serde(default)]
+    pub wheel_behavior: WheelBehaviorConfig,
+    #[serde(default)]
+    pub zoom_selection: ZoomSelectionBehavior,
+    #[serde(default)]
+    pub elastic_overscroll: ElasticOverscrollBehavior,
+    #[serde(default)]
+    pub data_gaps: DataGapBehavior,
+    #[serde(default)]
+    pub bar_countdown: BarCountdownBehavior,
+    #[serde(default)]
+    pub high_low_markers: HighLowMarkerBehavior,
+    #[serde(default)]
+    pub last_value_animation: LastValueAnimationBehavior,
+    #[serde(default)]
+    pub visible_range_change: VisibleRangeChangeBehavior,
+    #[serde(default)]
+    pub navigation_history: NavigationHistoryBehavior,
+    #[serde(default)]
+    pub points_price_format: Option<PriceAxisLabelPolicy>,
+    #[serde(default)]
+    pub candles_price_format: Option<PriceAxisLabelPolicy>,
+    #[serde(default)]
+    pub layout_insets: LayoutInsets,
+}
+
+/// Complete reproducible chart state (engine state contract v2).
+///
+/// Unlike the v1 snapshot, which records derived geometry for regression
+/// checks, a scene carries every input needed to rebuild the chart: series
+/// data, style, axis configs, behaviors, panes, and the exact scale state.
+/// Formatter closures, plugins, custom series, and transient pointer state
+/// are not part of the contract and must be re-installed by the host.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChartSceneV2 {
+    pub schema_version: u32,
+    pub viewport: Viewport,
+    pub time_scale: TimeScale,
+    pub price_scale: PriceScale,
+    pub crosshair_mode: CrosshairMode,
+    pub render_style: RenderStyle,
+    pub time_axis_label_config: TimeAxisLabelConfig,
+    pub price_axis_label_config: PriceAxisLabelConfig,
+    pub behaviors: ChartSceneBehaviors,
+    pub panes: PaneCollection,
+    pub points_pane_id: PaneId,
+    pub candles_pane_id: PaneId,
+    pub points: Vec<DataPoint>,
+    pub candles: Vec<StyledOhlcBar>,
+    pub time_kind: Option<ChartTimeKind>,
+    pub series_metadata: IndexMap<String, String>,
+    #[serde(default)]
+    pub volume_samples: Vec<VolumeSample>,
+    #[serde(default)]
+    pub compare_series: Vec<CompareSeries>,
+    #[serde(default)]
+    pub time_scale_marks: Vec<TimeScaleMark>,
+    #[serde(default)]
+    pub price_alerts: Vec<PriceAlert>,
+    #[serde(default)]
+    pub view_bookmarks: Vec<ViewBookmark>,
+}
+
+impl ChartSceneV2 {
+    pub fn to_json_pretty(&self) -> ChartResult<String> {
+        serde_json::to_string_pretty(self).map_err(|e| {
+            ChartError::InvalidData(format!("failed to serialize scene contract v2: {e}"))
+        })
+    }
+
+    pub fn from_json_str(input: &str) -> ChartResult<Self> {
+        let scene: Self = serde_json::from_str(input).map_err(|e| {
+            ChartError::InvalidData(format!("failed to parse scene json payload: {e}"))
+        })?;
+        if scene.schema_version != CHART_SCENE_JSON_SCHEMA_V2 {
+            return Err(ChartError::SchemaVersionMismatch {
+                schema: "scene",
+                expected: CHART_SCENE_JSON_SCHEMA_V2,
+                found: scene.schema_version,
+            });
+        }
+        Ok(scene)
+    }
+}
+
+impl<R: Renderer> ChartEngine<R> {
+    /// Captures the full reproducible chart state.
+    #[must_use]
+    pub fn export_scene(&self) -> ChartSceneV2 {
+        let model = &self.core.model;
+        let candles = model
+            .candles
+            .iter()
+            .zip(model.candle_style_overrides.iter())
+            .map(|(ohlc, style_override)| StyledOhlcBar {
+                ohlc: *ohlc,
+                style_override: *style_override,
+            })
+            .collect();
+        ChartSceneV2 {
+            schema_version: CHART_SCENE_JSON_SCHEMA_V2,
+            viewport: self.viewport(),
+            time_scale: model.time_scale,
+            price_scale: model.price_scale,
+            crosshair_mode: self.crosshair_mode(),
+            render_style: self.render_style(),
+            time_axis_label_config: self.time_axis_label_config(),
+            price_axis_label_config: self.price_axis_label_config(),
+            behaviors: ChartSceneBehaviors {
+                time_scale_edge: self.time_scale_edge_behavior(),
+                time_scale_navigation: self.time_scale_navigation_behavior(),
+                time_scale_zoom_limit: self.time_scale_zoom_limit_behavior(),
+                time_scale_right_offset_px: self.time_scale_right_offset_px(),
+                time_scale_scroll_zoom: self.time_scale_scroll_zoom_behavior(),
+                time_scale_resize: self.time_scale_resize_behavior(),
+                time_scale_realtime_append: self.time_scale_realtime_append_behavior(),
+                price_scale_realtime: self.price_scale_realtime_behavior(),
+                price_scale_transformed_base: self.price_scale_transformed_base_behavior(),
+                interaction_input: self.interaction_input_behavior(),
+                pane_separator: self.pane_separator_behavior(),
+                ohlc_info_line: self.ohlc_info_line_behavior(),
+                volume_profile: self.volume_profile_config(),
+                compare_mode: self.compare_mode_enabled(),
+                time_scale_marks: self.time_scale_mark_behavior(),
+                axis_interaction: self.axis_interaction_config(),
+                wheel_behavior: self.wheel_behavior_config(),
+                zoom_selection: self.zoom_selection_behavior(),
+                elastic_overscroll: self.elastic_overscroll_behavior(),
+                data_gaps: self.data_gap_behavior(),
+                bar_countdown: self.bar_countdown_behavior(),
+                high_low_markers: self.high_low_marker_behavior(),
+                last_value_animation: self.last_value_animation_behavior(),
+                visible_range_change: self.visible_range_change_behavior(),
+                navigation_history: self.navigation_history_behavior(),
+                points_price_format: self.series_price_format(SeriesRef::Points),
+                candles_price_format: self.series_price_format(SeriesRef::Candles),
+                layout_insets: self.layout_insets(),
+            },
+            panes: model.pane_collection.clone(),
+            points_pane_id: model.points_pane_id,
+            candles_pane_id: model.candles_pane_id,
+            points: model.points.clone(),
+            candles,
+            time_kind: model.time_kind,
+            series_metadata: model.series_metadata.clone(),
+            volume_samples: model.volume_samples.clone(),
+            compare_series: model.compare_series.clone(),
+            time_scale_marks: model.time_scale_marks.clone(),
+            price_alerts: model.price_alerts.clone(),
+            view_bookmarks: model.view_bookmarks.clone(),
+        }
+    }
+
+    pub fn export_scene_json_pretty(&self) -> ChartResult<String> {
+        self.export_scene().to_json_pretty()
+    }
+
+    /// Replaces the chart state with `scene`.
+    ///
+    /// Scales, panes, and style are validated up front; if a later setter
+    /// still rejects the scene, the previous state is restored before the
+    /// error is returned. Scales are applied last and verbatim so that
+    /// data-driven autoscale or fitting cannot perturb them.
+    pub fn import_scene(&mut self, scene: ChartSceneV2) -> ChartResult<()> {
+        if scene.schema_version != CHART_SCENE_JSON_SCHEMA_V2 {
+            return Err(ChartError::SchemaVersionMismatch {
+                schema: "scene",
+                expected: CHART_SCENE_JSON_SCHEMA_V2,
+                found: scene.schema_version,
+            });
+        }
+        scene.time_scale.validated()?;
+        scene.price_scale.validated()?;
+        let panes = scene.panes.clone().validated()?;
+        if !panes.contains(scene.points_pane_id) || !panes.contains(scene.candles_pane_id) {
+            return Err(ChartError::InvalidData(
+                "scene series pane ids must exist in the pane collection".to_owned(),
+            ));
+        }
+        super::validation::validate_render_style(&scene.render_style)?;
+        scene
+            .behaviors
+            .layout_insets
+            .resolve_content_viewport(scene.viewport)?;
+
+        let previous = self.export_scene();
+        if let Err(err) = self.apply_scene(scene) {
+            // The previous scene came from this engine, so re-applying it
+            // cannot hit the error that rejected the new one.
+            let _ = self.apply_scene(previous);
+            return Err(err);
+        }
+        Ok(())
+    }
+
+    fn apply_scene(&mut self, scene: ChartSceneV2) -> ChartResult<()> {
+        // Viewport and insets go in together: either alone may not fit the
+        // other's current value.
+        let insets = scene.behaviors.layout_insets;
+        let content = insets.resolve_content_viewport(scene.viewport)?;
+        self.core.behavior.layout_insets = insets;
+        self.apply_content_viewport(content)?;
+        self.set_render_style(scene.render_style)?;
+        self.set_time_axis_label_config(scene.time_axis_label_config)?;
+        self.set_price_axis_label_config(scene.price_axis_label_config)?;
+        self.set_crosshair_mode(scene.crosshair_mode);
+
+        let behaviors = scene.behaviors;
+        self.set_time_scale_edge_behavior(behaviors.time_scale_edge)?;
+        self.set_time_scale_navigation_behavior(behaviors.time_scale_navigation)?;
+        self.set_time_scale_zoom_limit_behavior(behaviors.time_scale_zoom_limit)?;
+        self.set_time_scale_right_offset_px(behaviors.time_scale_right_offset_px)?;
+        self.set_time_scale_scroll_zoom_behavior(behaviors.time_scale_scroll_zoom)?;
+        self.set_time_scale_resize_behavior(behaviors.time_scale_resize)?;
+        self.set_time_scale_realtime_append_behavior(behaviors.time_scale_realtime_append)?;
+        self.set_price_scale_realtime_behavior(behaviors.price_scale_realtime);
+        self.set_price_scale_transformed_base_behavior(behaviors.price_scale_transformed_base)?;
+        self.set_interaction_input_behavior(behaviors.interaction_input);
+        self.set_pane_separator_behavior(behaviors.pane_separator)?;
+        self.set_ohlc_info_line_behavior(behaviors.ohlc_info_line)?;
+        self.set_volume_profile_config(behaviors.volume_profile)?;
+        self.set_compare_mode_enabled(behaviors.compare_mode);
+        self.set_time_scale_mark_behavior(behaviors.time_scale_marks)?;
+        self.set_axis_interaction_config(behaviors.axis_interaction)?;
+        self.set_wheel_behavior_config(behaviors.wheel_behavior)?;
+        self.set_zoom_selection_behavior(behaviors.zoom_selection)?;
+        self.set_elastic_overscroll_behavior(behaviors.elastic_overscroll)?;
+        self.set_data_gap_behavior(behaviors.data_gaps)?;
+        self.set_bar_countdown_behavior(behaviors.bar_countdown)?;
+        self.set_high_low_marker_behavior(behaviors.high_low_markers)?;
+        self.set_last_value_animation_behavior(behaviors.last_value_animation)?;
+        self.set_visible_range_change_behavior(behaviors.visible_range_change)?;
+        self.set_navigation_history_behavior(behaviors.navigation_history);
+        self.set_series_price_format(SeriesRef::Points, behaviors.points_price_format)?;
+        self.set_series_price_format(SeriesRef::Candles, behaviors.candles_price_format)?;
+
+        self.core.model.pane_collection = scene.panes;
+        self.core.model.points_pane_id = scene.points_pane_id;
+        self.core.model.candles_pane_id = scene.candles_pane_id;
+        self.core.model.series_metadata = scene.series_metadata;
+
+        // Loading the scene's data must not trigger the current alerts.
+        self.core.model.price_alerts.clear();
+        self.set_data(scene.points);
+        self.set_styled_candles(scene.candles)?;
+        self.set_volume_samples(scene.volume_samples)?;
+        self.core.model.compare_series.clear();
+        for series in scene.compare_series {
+            self.add_compare_series(series)?;
+        }
+        self.set_time_scale_marks(scene.time_scale_marks)?;
+        self.set_price_alerts(scene.price_alerts)?;
+        self.set_view_bookmarks(scene.view_bookmarks)?;
+        self.core.model.time_kind = scene.time_kind;
+
+        self.core.model.time_scale = scene.time_scale;
+        self.core.model.price_scale = scene.price_scale;
+        self.core.model.price_scale_mode = scene.price_scale.mode();
+        self.set_lwc_time_scale_invalidation_intent(LwcTimeScaleInvalidationIntent::ApplyRange);
+        self.invalidate_full();
+        self.clear_view_history();
+        Ok(())
+    }
+
+    pub fn import_scene_json_str(&mut self, input: &str) -> ChartResult<()> {
+        self.import_scene(ChartSceneV2::from_json_str(input)?)
+    }
+}
@@ -0,0 +1,141 @@
+use crate::core::{PaneId, PaneLayoutRegion, PriceScale};
+use crate::error::{ChartError, ChartResult};
+use crate::render::Renderer;
+
+use super::{ChartEngine, SeriesRef};
+
+/// Pixel space of the series-scoped coordinate conversions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SeriesPixelSpace {
+    /// Content coordinates, shared with the other mapping and pointer APIs.
+    #[default]
+    Content,
+    /// Widget coordinates: content coordinates shifted by the layout insets.
+    Widget,
+    /// Relative to the top of the series' pane plot region.
+    Pane,
+}
+
+/// Where and how a series is drawn in the current view.
+struct SeriesCoordinateTarget {
+    region: PaneLayoutRegion,
+    /// Plot height series are projected into before the pane remap.
+    plot_bottom: f64,
+    price_scale: PriceScale,
+    /// Factor from series values to plotted values (compare rebasing).
+    value_factor: f64,
+}
+
+impl<R: Renderer> ChartEngine<R> {
+    /// Pane `series` is drawn into; `None` for unknown series.
+    #[must_use]
+    pub fn series_pane_id(&self, series: SeriesRef<'_>) -> Option<PaneId> {
+        let preferred = match series {
+            SeriesRef::Points => self.core.model.points_pane_id,
+            SeriesRef::Candles => self.core.model.candles_pane_id,
+            SeriesRef::Compare(id) => {
+                self.find_compare_series(id)?;
+                self.compare_pane_id()
+            }
+            SeriesRef::Custom(id) => self.custom_series_pane_id(id)?,
+        };
+        if self.core.model.pane_collection.contains(preferred) {
+            Some(preferred)
+        } else {
+            Some(self.main_pane_id())
+        }
+    }
+
+    /// Maps a `series` value to pixel Y using the price scale and pane the
+    /// series is rendered with.
+    ///
+    /// Compare series take raw values and apply the compare-mode rebasing.
+    pub fn series_price_to_pixel(
+        &self,
+        series: SeriesRef<'_>,
+        price: f64,
+        space: SeriesPixelSpace,
+    ) -> ChartResult<f64> {
+        let target = self.resolve_series_coordinate_target(series)?;
+        let plot_y = target
+            .price_scale
+            .price_to_pixel(price * target.value_factor, self.core.model.viewport)?;
+        let content_y =
+            target.region.plot_top + plot_y / target.plot_bottom * target.region.height();
+        Ok(match space {
+            SeriesPixelSpace::Content => content_y,
+            SeriesPixelSpace::Widget => content_y + f64::from(self.core.behavior.layout_insets.top),
+            SeriesPixelSpace::Pane => content_y - target.region.plot_top,
+        })
+    }
+
+    /// Inverse of `series_price_to_pixel`.
+    pub fn series_pixel_to_price(
+        &self,
+        series: SeriesRef<'_>,
+        y: f64,
+        space: SeriesPixelSpace,
+    ) -> ChartResult<f64> {
+        let target = self.resolve_series_coordinate_target(series)?;
+        let content_y = match space {
+            SeriesPixelSpace::Content => y,
+            SeriesPixelSpace::Widget => y - f64::from(self.core.behavior.layout_insets.top),
+            SeriesPixelSpace::Pane => y + target.region.plot_top,
+        };
+        let plot_y =
+            (content_y - target.region.plot_top) / target.region.height() * target.plot_bottom;
+        let plotted = target
+            .price_scale
+            .pixel_to_price(plot_y, self.core.model.viewport)?;
+        Ok(plotted / target.value_factor)
+    }
+
+    fn resolve_series_coordinate_target(
+        &self,
+        series: SeriesRef<'_>,
+    ) -> ChartResult<SeriesCoordinateTarget> {
+        let unknown = || ChartError::InvalidData(format!("unknown series {series:?}"));
+        let pane_id = self.series_pane_id(series).ok_or_else(unknown)?;
+        let value_factor = match series {
+            SeriesRef::Compare(id) => {
+                if !self.core.behavior.compare_mode_enabled {
+                    return Err(ChartError::InvalidData(format!(
+                        "compare series `{id}` is not drawn while compare mode is disabled"
+                    )));
+                }
+                let primary_base = self.compare_primary_base_price();
+                let series_base = self.compare_series_base_price(id);
+                match (primary_base, series_base) {
+                    (Some(primary_base), Some(series_base)) => primary_base / series_base,
+                    _ => {
+                        return Err(ChartError::InvalidData(format!(
+                            "compare series `{id}` has no rebasing base in the visible range"
+                        )));
+                    }
+                }
+            }
+            _ => 1.0,
+        };
+        let plot_bottom = self.current_plot_bottom();
+        let region = self
+            .pane_layout_regions(0.0, plot_bottom)
+            .into_iter()
+            .find(|region| region.pane_id == pane_id)
+            .ok_or_else(unknown)?;
+        if plot_bottom <= 0.0 || region.height() <= 0.0 {
+            return Err(ChartError::InvalidData(
+                "series pane has no plot height".to_owned(),
+            ));
+        }
+        let (visible_start, visible_end) = self.core.model.time_scale.visible_range();
+        let price_scale = self
+            .resolve_pane_series_render_target(pane_id, pane_id, visible_start, visible_end)
+            .price_scale;
+        Ok(SeriesCoordinateTarget {
+            region,
+            plot_bottom,
+            price_scale,
+            value_factor,
+        })
+    }
+}
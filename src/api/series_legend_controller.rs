@@ -0,0 +1,162 @@
+use crate::core::PaneId;
+use crate::render::{Color, Renderer};
+
+use super::data_window::{nearest_by_time, percent_change};
+use super::{ChartEngine, SeriesRef};
+
+/// Series metadata key overriding the point series legend name.
+pub const LEGEND_POINTS_NAME_KEY: &str = "points.name";
+/// Series metadata key overriding the candle series legend name.
+pub const LEGEND_CANDLES_NAME_KEY: &str = "candles.name";
+
+const DEFAULT_POINTS_NAME: &str = "Line";
+const DEFAULT_CANDLES_NAME: &str = "Candles";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LegendSeriesKind {
+    Points,
+    Candles,
+}
+
+/// Legend row for one loaded series.
+///
+/// Candle values are closes; the visible-window change runs from the first
+/// visible open (points: value) to the last visible close.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SeriesLegendEntry {
+    pub kind: LegendSeriesKind,
+    pub pane_id: PaneId,
+    pub name: String,
+    /// Line color for points; up/down body color of the latest candle.
+    pub color: Color,
+    /// Latest value in the series, visible or not.
+    pub last_value: Option<f64>,
+    /// Value of the sample nearest the crosshair time while it is visible.
+    pub crosshair_value: Option<f64>,
+    /// `last_value` and `crosshair_value` in the series price format.
+    pub last_value_text: Option<String>,
+    pub crosshair_value_text: Option<String>,
+    pub change: Option<f64>,
+    pub change_percent: Option<f64>,
+}
+
+/// Legend readout for the current view, ordered points then candles.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SeriesLegend {
+    /// Time under the crosshair (snapped when snapping applies).
+    pub crosshair_time: Option<f64>,
+    pub entries: Vec<SeriesLegendEntry>,
+}
+
+impl<R: Renderer> ChartEngine<R> {
+    /// Builds the legend for series with data.
+    ///
+    /// Values follow the crosshair and the visible range, so hosts can
+    /// rebuild after each frame and compare against the previous legend.
+    #[must_use]
+    pub fn series_legend(&self) -> SeriesLegend {
+        let crosshair_time = self.hovered_time();
+        let style = &self.core.presentation.render_style;
+        let mut entries = Vec::new();
+
+        let points = &self.core.model.points;
+        if let Some(last) = points.last() {
+            let visible = self.visible_points_slice();
+            let (change, change_percent) = legend_change(
+                visible.first().map(|point| point.y),
+                visible.last().map(|point| point.y),
+            );
+            let crosshair_value = crosshair_time
+                .and_then(|time| nearest_by_time(points, time, |point| point.x))
+                .map(|point| point.y);
+            entries.push(SeriesLegendEntry {
+                kind: LegendSeriesKind::Points,
+                pane_id: self.legend_pane_id(self.core.model.points_pane_id),
+                name: self.legend_name(LEGEND_POINTS_NAME_KEY, DEFAULT_POINTS_NAME),
+                color: style.series_line_color,
+                last_value: Some(last.y),
+                crosshair_value,
+                last_value_text: Some(self.format_series_value(SeriesRef::Points, last.y)),
+                crosshair_value_text: crosshair_value
+                    .map(|value| self.format_series_value(SeriesRef::Points, value)),
+                change,
+                change_percent,
+            });
+        }
+
+        let candles = &self.core.model.candles;
+        if let Some(last) = candles.last() {
+            let visible = self.visible_candles_slice();
+            let (change, change_percent) = legend_change(
+                visible.first().map(|bar| bar.open),
+                visible.last().map(|bar| bar.close),
+            );
+            let color = if last.close >= last.open {
+                style.candlestick_up_color
+            } else {
+                style.candlestick_down_color
+            };
+            let crosshair_value = crosshair_time
+                .and_then(|time| nearest_by_time(candles, time, |bar| bar.time))
+                .map(|bar| bar.close);
+            entries.push(SeriesLegendEntry {
+                kind: LegendSeriesKind::Candles,
+                pane_id: self.legend_pane_id(self.core.model.candles_pane_id),
+                name: self.legend_name(LEGEND_CANDLES_NAME_KEY, DEFAULT_CANDLES_NAME),
+                color,
+                last_value: Some(last.close),
+                crosshair_value,
+                last_value_text: Some(self.format_series_value(SeriesRef::Candles, last.close)),
+                crosshair_value_text: crosshair_value
+                    .map(|value| self.format_series_value(SeriesRef::Candles, value)),
+                change,
+                change_percent,
+            });
+        }
+
+        SeriesLegend {
+            crosshair_time,
+            entries,
+        }
+    }
+
+    /// Data time under the visible crosshair, snapped when snapping applies.
+    pub(super) fn hovered_time(&self) -> Option<f64> {
+        let crosshair = self.core.model.interaction.crosshair();
+        if !crosshair.visible {
+            return None;
+        }
+        crosshair
+            .snapped_time
+            .or_else(|| self.map_pixel_to_x(crosshair.x).ok())
+    }
+
+    /// Pane the series renders into; removed panes fall back to main.
+    fn legend_pane_id(&self, pane_id: PaneId) -> PaneId {
+        let panes = &self.core.model.pane_collection;
+        if panes.contains(pane_id) {
+            pane_id
+        } else {
+            panes.main_pane_id()
+        }
+    }
+
+    fn legend_name(&self, key: &str, fallback: &str) -> String {
+        self.core
+            .model
+            .series_metadata
+            .get(key)
+            .map_or_else(|| fallback.to_owned(), Clone::clone)
+    }
+}
+
+fn legend_change(first: Option<f64>, last: Option<f64>) -> (Option<f64>, Option<f64>) {
+    let (Some(first), Some(last)) = (first, last) else {
+        return (None, None);
+    };
+    let change = last - first;
+    (
+        change.is_finite().then_some(change),
+        percent_change(first, last),
+    )
+}
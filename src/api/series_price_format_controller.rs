@@ -0,0 +1,88 @@
+use crate::error::{ChartError, ChartResult};
+use crate::render::Renderer;
+
+use super::validation::validate_price_axis_label_policy;
+use super::{ChartEngine, PriceAxisLabelPolicy, SeriesRef};
+
+impl<R: Renderer> ChartEngine<R> {
+    /// Label format of `series`; `None` means it follows the price axis.
+    #[must_use]
+    pub fn series_price_format(&self, series: SeriesRef<'_>) -> Option<PriceAxisLabelPolicy> {
+        match series {
+            SeriesRef::Points => self.core.behavior.points_price_format,
+            SeriesRef::Candles => self.core.behavior.candles_price_format,
+            SeriesRef::Compare(id) => self.find_compare_series(id)?.price_format,
+            SeriesRef::Custom(_) => None,
+        }
+    }
+
+    /// Sets the format of the series' crosshair value, last-value label and
+    /// legend values.
+    ///
+    /// The price axis itself follows its primary series (candles when
+    /// loaded, otherwise points) and falls back to
+    /// `PriceAxisLabelConfig::policy`.
+    pub fn set_series_price_format(
+        &mut self,
+        series: SeriesRef<'_>,
+        format: Option<PriceAxisLabelPolicy>,
+    ) -> ChartResult<()> {
+        if let Some(format) = format {
+            validate_price_axis_label_policy(format)?;
+        }
+        let slot = match series {
+            SeriesRef::Points => &mut self.core.behavior.points_price_format,
+            SeriesRef::Candles => &mut self.core.behavior.candles_price_format,
+            SeriesRef::Compare(id) => {
+                &mut self
+                    .core
+                    .model
+                    .compare_series
+                    .iter_mut()
+                    .find(|series| series.id == id)
+                    .ok_or_else(|| {
+                        ChartError::InvalidData(format!("unknown compare series `{id}`"))
+                    })?
+                    .price_format
+            }
+            SeriesRef::Custom(id) => {
+                return Err(ChartError::InvalidData(format!(
+                    "custom series `{id}` has no price format"
+                )));
+            }
+        };
+        if *slot != format {
+            *slot = format;
+            self.invalidate_full();
+        }
+        Ok(())
+    }
+
+    /// Formats a raw `value` of `series` the way its legend shows it.
+    #[must_use]
+    pub fn format_series_value(&self, series: SeriesRef<'_>, value: f64) -> String {
+        let (domain_start, domain_end) = self.core.model.price_scale.domain();
+        self.format_price_label_with_policy(
+            value,
+            (domain_end - domain_start).abs() / 10.0,
+            "",
+            self.series_price_label_policy(series),
+        )
+        .to_string()
+    }
+
+    /// Policy of the price axis: its primary series' format when set.
+    pub(super) fn price_axis_label_policy(&self) -> PriceAxisLabelPolicy {
+        let primary = if self.core.model.candles.is_empty() {
+            self.core.behavior.points_price_format
+        } else {
+            self.core.behavior.candles_price_format
+        };
+        primary.unwrap_or(self.core.behavior.price_axis_label_config.policy)
+    }
+
+    pub(super) fn series_price_label_policy(&self, series: SeriesRef<'_>) -> PriceAxisLabelPolicy {
+        self.series_price_format(series)
+            .unwrap_or_else(|| self.price_axis_label_policy())
+    }
+}
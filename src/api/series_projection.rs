@@ -1,7 +1,8 @@
 use crate::core::{
     AreaGeometry, BarGeometry, BaselineGeometry, CandleGeometry, HistogramBar, LineSegment,
-    candles_in_time_window, points_in_time_window, project_area_geometry, project_bars,
-    project_baseline_geometry, project_candles, project_histogram_bars, project_line_segments,
+    candles_in_sorted_time_window, points_in_sorted_time_window, project_area_geometry,
+    project_bars, project_baseline_geometry, project_candles, project_histogram_bars,
+    project_line_segments,
 };
 use crate::error::ChartResult;
 use crate::extensions::{
@@ -26,9 +27,9 @@ impl<R: Renderer> ChartEngine<R> {
     /// Projects only candles inside the active visible time window.
     pub fn project_visible_candles(&self, body_width_px: f64) -> ChartResult<Vec<CandleGeometry>> {
         let (start, end) = self.core.model.time_scale.visible_range();
-        let visible = candles_in_time_window(&self.core.model.candles, start, end);
+        let visible = candles_in_sorted_time_window(&self.core.model.candles, start, end);
         project_candles(
-            &visible,
+            visible,
             self.core.model.time_scale,
             self.core.model.price_scale,
             self.core.model.viewport,
@@ -44,9 +45,9 @@ impl<R: Renderer> ChartEngine<R> {
     ) -> ChartResult<Vec<CandleGeometry>> {
         let (start, end) =
             expand_visible_window(self.core.model.time_scale.visible_range(), ratio)?;
-        let visible = candles_in_time_window(&self.core.model.candles, start, end);
+        let visible = candles_in_sorted_time_window(&self.core.model.candles, start, end);
         project_candles(
-            &visible,
+            visible,
             self.core.model.time_scale,
             self.core.model.price_scale,
             self.core.model.viewport,
@@ -68,9 +69,9 @@ impl<R: Renderer> ChartEngine<R> {
     /// Projects only bars inside the active visible time window.
     pub fn project_visible_bars(&self, tick_width_px: f64) -> ChartResult<Vec<BarGeometry>> {
         let (start, end) = self.core.model.time_scale.visible_range();
-        let visible = candles_in_time_window(&self.core.model.candles, start, end);
+        let visible = candles_in_sorted_time_window(&self.core.model.candles, start, end);
         project_bars(
-            &visible,
+            visible,
             self.core.model.time_scale,
             self.core.model.price_scale,
             self.core.model.viewport,
@@ -86,9 +87,9 @@ impl<R: Renderer> ChartEngine<R> {
     ) -> ChartResult<Vec<BarGeometry>> {
         let (start, end) =
             expand_visible_window(self.core.model.time_scale.visible_range(), ratio)?;
-        let visible = candles_in_time_window(&self.core.model.candles, start, end);
+        let visible = candles_in_sorted_time_window(&self.core.model.candles, start, end);
         project_bars(
-            &visible,
+            visible,
             self.core.model.time_scale,
             self.core.model.price_scale,
             self.core.model.viewport,
@@ -119,11 +120,11 @@ impl<R: Renderer> ChartEngine<R> {
         config: MarkerPlacementConfig,
     ) -> ChartResult<Vec<PlacedMarker>> {
         let (start, end) = self.core.model.time_scale.visible_range();
-        let visible = candles_in_time_window(&self.core.model.candles, start, end);
+        let visible = candles_in_sorted_time_window(&self.core.model.candles, start, end);
         let visible_markers = markers_in_time_window(markers, start, end);
         place_markers_on_candles(
             &visible_markers,
-            &visible,
+            visible,
             self.core.model.time_scale,
             self.core.model.price_scale,
             self.core.model.viewport,
@@ -140,11 +141,11 @@ impl<R: Renderer> ChartEngine<R> {
     ) -> ChartResult<Vec<PlacedMarker>> {
         let (start, end) =
             expand_visible_window(self.core.model.time_scale.visible_range(), ratio)?;
-        let visible = candles_in_time_window(&self.core.model.candles, start, end);
+        let visible = candles_in_sorted_time_window(&self.core.model.candles, start, end);
         let visible_markers = markers_in_time_window(markers, start, end);
         place_markers_on_candles(
             &visible_markers,
-            &visible,
+            visible,
             self.core.model.time_scale,
             self.core.model.price_scale,
             self.core.model.viewport,
@@ -175,9 +176,9 @@ impl<R: Renderer> ChartEngine<R> {
     /// Projects only area geometry for points inside the visible time range.
     pub fn project_visible_area_geometry(&self) -> ChartResult<AreaGeometry> {
         let (start, end) = self.core.model.time_scale.visible_range();
-        let visible = points_in_time_window(&self.core.model.points, start, end);
+        let visible = points_in_sorted_time_window(&self.core.model.points, start, end);
         project_area_geometry(
-            &visible,
+            visible,
             self.core.model.time_scale,
             self.core.model.price_scale,
             self.core.model.viewport,
@@ -191,9 +192,9 @@ impl<R: Renderer> ChartEngine<R> {
     ) -> ChartResult<AreaGeometry> {
         let (start, end) =
             expand_visible_window(self.core.model.time_scale.visible_range(), ratio)?;
-        let visible = points_in_time_window(&self.core.model.points, start, end);
+        let visible = points_in_sorted_time_window(&self.core.model.points, start, end);
         project_area_geometry(
-            &visible,
+            visible,
             self.core.model.time_scale,
             self.core.model.price_scale,
             self.core.model.viewport,
@@ -217,9 +218,9 @@ impl<R: Renderer> ChartEngine<R> {
         baseline_price: f64,
     ) -> ChartResult<BaselineGeometry> {
         let (start, end) = self.core.model.time_scale.visible_range();
-        let visible = points_in_time_window(&self.core.model.points, start, end);
+        let visible = points_in_sorted_time_window(&self.core.model.points, start, end);
         project_baseline_geometry(
-            &visible,
+            visible,
             self.core.model.time_scale,
             self.core.model.price_scale,
             self.core.model.viewport,
@@ -235,9 +236,9 @@ impl<R: Renderer> ChartEngine<R> {
     ) -> ChartResult<BaselineGeometry> {
         let (start, end) =
             expand_visible_window(self.core.model.time_scale.visible_range(), ratio)?;
-        let visible = points_in_time_window(&self.core.model.points, start, end);
+        let visible = points_in_sorted_time_window(&self.core.model.points, start, end);
         project_baseline_geometry(
-            &visible,
+            visible,
             self.core.model.time_scale,
             self.core.model.price_scale,
             self.core.model.viewport,
@@ -268,9 +269,9 @@ impl<R: Renderer> ChartEngine<R> {
         baseline_price: f64,
     ) -> ChartResult<Vec<HistogramBar>> {
         let (start, end) = self.core.model.time_scale.visible_range();
-        let visible = points_in_time_window(&self.core.model.points, start, end);
+        let visible = points_in_sorted_time_window(&self.core.model.points, start, end);
         project_histogram_bars(
-            &visible,
+            visible,
             self.core.model.time_scale,
             self.core.model.price_scale,
             self.core.model.viewport,
@@ -288,9 +289,9 @@ impl<R: Renderer> ChartEngine<R> {
     ) -> ChartResult<Vec<HistogramBar>> {
         let (start, end) =
             expand_visible_window(self.core.model.time_scale.visible_range(), ratio)?;
-        let visible = points_in_time_window(&self.core.model.points, start, end);
+        let visible = points_in_sorted_time_window(&self.core.model.points, start, end);
         project_histogram_bars(
-            &visible,
+            visible,
             self.core.model.time_scale,
             self.core.model.price_scale,
             self.core.model.viewport,
@@ -0,0 +1,145 @@
+use crate::core::{DataPoint, OhlcBar};
+use crate::render::Renderer;
+
+use super::ChartEngine;
+use super::data_window::{nearest_by_time, samples_in_time_window};
+
+/// Series addressed by the data query API.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeriesRef<'a> {
+    Points,
+    Candles,
+    /// Compare series by id; queries see its raw (not rebased) values.
+    Compare(&'a str),
+    /// Custom series by id; only price ranges are available.
+    Custom(&'a str),
+}
+
+/// How `series_value_at` resolves a time between samples.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SeriesValueInterpolation {
+    /// Only a sample at exactly `time`.
+    Exact,
+    /// Latest sample at or before `time` (as-of lookup).
+    #[default]
+    Previous,
+    /// Sample closest to `time`; ties resolve to the earlier one.
+    Nearest,
+    /// Linear interpolation between the surrounding samples; `None` outside
+    /// the series.
+    Linear,
+}
+
+impl<R: Renderer> ChartEngine<R> {
+    /// Min/max value of `series` for samples inside `time_window`.
+    ///
+    /// Candles report low/high, custom series their autoscale range. Returns
+    /// `None` for unknown series, non-finite windows, or empty windows.
+    #[must_use]
+    pub fn series_price_range(
+        &self,
+        series: SeriesRef<'_>,
+        time_window: (f64, f64),
+    ) -> Option<(f64, f64)> {
+        let (start, end) = time_window;
+        if !start.is_finite() || !end.is_finite() {
+            return None;
+        }
+        match series {
+            SeriesRef::Points => points_range(&self.core.model.points, start, end),
+            SeriesRef::Candles => {
+                samples_in_time_window(&self.core.model.candles, start, end, |bar| bar.time)
+                    .iter()
+                    .map(|bar| (bar.low, bar.high))
+                    .reduce(|(low, high), (bar_low, bar_high)| {
+                        (low.min(bar_low), high.max(bar_high))
+                    })
+            }
+            SeriesRef::Compare(id) => {
+                points_range(&self.find_compare_series(id)?.points, start, end)
+            }
+            SeriesRef::Custom(id) => self.custom_series_range_in_window(id, (start, end)),
+        }
+    }
+
+    /// Value of `series` at `time`; candles resolve to their close.
+    ///
+    /// Custom series rows carry no scalar value, so they always return
+    /// `None`.
+    #[must_use]
+    pub fn series_value_at(
+        &self,
+        series: SeriesRef<'_>,
+        time: f64,
+        interpolation: SeriesValueInterpolation,
+    ) -> Option<f64> {
+        if !time.is_finite() {
+            return None;
+        }
+        match series {
+            SeriesRef::Points => point_value_at(&self.core.model.points, time, interpolation),
+            SeriesRef::Candles => value_at(
+                &self.core.model.candles,
+                time,
+                interpolation,
+                |bar: &OhlcBar| bar.time,
+                |bar| bar.close,
+            ),
+            SeriesRef::Compare(id) => {
+                point_value_at(&self.find_compare_series(id)?.points, time, interpolation)
+            }
+            SeriesRef::Custom(_) => None,
+        }
+    }
+}
+
+fn points_range(points: &[DataPoint], start: f64, end: f64) -> Option<(f64, f64)> {
+    samples_in_time_window(points, start, end, |point| point.x)
+        .iter()
+        .map(|point| (point.y, point.y))
+        .reduce(|(low, high), (value, _)| (low.min(value), high.max(value)))
+}
+
+fn point_value_at(
+    points: &[DataPoint],
+    time: f64,
+    interpolation: SeriesValueInterpolation,
+) -> Option<f64> {
+    value_at(
+        points,
+        time,
+        interpolation,
+        |point: &DataPoint| point.x,
+        |point| point.y,
+    )
+}
+
+fn value_at<T>(
+    samples: &[T],
+    time: f64,
+    interpolation: SeriesValueInterpolation,
+    time_of: impl Fn(&T) -> f64,
+    value_of: impl Fn(&T) -> f64,
+) -> Option<f64> {
+    let index = samples.partition_point(|sample| time_of(sample) < time);
+    let at = samples.get(index).filter(|sample| time_of(sample) == time);
+    match interpolation {
+        SeriesValueInterpolation::Exact => at.map(&value_of),
+        SeriesValueInterpolation::Previous => at
+            .or_else(|| index.checked_sub(1).and_then(|index| samples.get(index)))
+            .map(&value_of),
+        SeriesValueInterpolation::Nearest => {
+            nearest_by_time(samples, time, &time_of).map(&value_of)
+        }
+        SeriesValueInterpolation::Linear => {
+            if let Some(sample) = at {
+                return Some(value_of(sample));
+            }
+            let before = samples.get(index.checked_sub(1)?)?;
+            let after = samples.get(index)?;
+            let (t0, t1) = (time_of(before), time_of(after));
+            let ratio = (time - t0) / (t1 - t0);
+            Some(value_of(before) + (value_of(after) - value_of(before)) * ratio)
+        }
+    }
+}
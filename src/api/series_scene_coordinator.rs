@@ -1,7 +1,9 @@
 use crate::core::{PaneId, PriceScale};
 use crate::error::ChartResult;
-use crate::render::{LayeredRenderFrame, RenderFrame, Renderer};
+use crate::render::{ClipRect, LayeredRenderFrame, RenderFrame, Renderer};
 
+use super::candlestick_render_frame_builder::CandlestickSeriesRenderContext;
+use super::frame_budget::FrameReduction;
 use super::line_series_render_frame_builder::LineSeriesRenderContext;
 use super::{ChartEngine, RenderStyle};
 
@@ -18,12 +20,13 @@ pub(super) struct SeriesSceneTargets {
 }
 
 #[derive(Debug, Clone, Copy)]
-pub(super) struct SeriesSceneRenderContext {
+pub(super) struct SeriesSceneRenderContext<'a> {
     pub main_pane_id: PaneId,
     pub visible_start: f64,
     pub visible_end: f64,
-    pub plot_right: f64,
-    pub style: RenderStyle,
+    pub plot_clip: ClipRect,
+    pub style: &'a RenderStyle,
+    pub reduction: FrameReduction,
 }
 
 impl<R: Renderer> ChartEngine<R> {
@@ -64,22 +67,74 @@ impl<R: Renderer> ChartEngine<R> {
                 visible_start: ctx.visible_start,
                 visible_end: ctx.visible_end,
                 line_color: ctx.style.series_line_color,
+                clip_rect: ctx.plot_clip,
+                lod_bucket_len: ctx.reduction.lod_bucket_len(),
             },
         )?;
 
         self.append_candlestick_series_primitives(
             frame,
             layered,
-            (targets.candles.pane_id, targets.candles.price_scale),
+            CandlestickSeriesRenderContext {
+                pane_id: targets.candles.pane_id,
+                price_scale: targets.candles.price_scale,
+                visible_start: ctx.visible_start,
+                visible_end: ctx.visible_end,
+                plot_clip: ctx.plot_clip,
+                style: ctx.style,
+                lod_bucket_len: ctx.reduction.lod_bucket_len(),
+            },
+        )?;
+
+        let primary = if self.core.model.candles.is_empty() {
+            targets.points
+        } else {
+            targets.candles
+        };
+        self.append_compare_series_primitives(
+            frame,
+            layered,
+            primary.pane_id,
+            primary.price_scale,
+            ctx.plot_clip,
+        )?;
+
+        self.append_custom_series_primitives(
+            frame,
+            layered,
+            ctx.main_pane_id,
             (ctx.visible_start, ctx.visible_end),
-            ctx.plot_right,
-            ctx.style,
+            ctx.plot_clip,
+        )?;
+
+        self.append_volume_profile_primitives(
+            frame,
+            layered,
+            targets.candles.pane_id,
+            targets.candles.price_scale,
+            ctx.plot_clip,
+        )?;
+
+        self.append_price_alert_primitives(
+            frame,
+            layered,
+            targets.candles.pane_id,
+            targets.candles.price_scale,
+            ctx.plot_clip,
+        )?;
+
+        self.append_trading_overlay_primitives(
+            frame,
+            layered,
+            targets.candles.pane_id,
+            targets.candles.price_scale,
+            ctx.plot_clip,
         )?;
 
         Ok(())
     }
 
-    fn resolve_pane_series_render_target(
+    pub(super) fn resolve_pane_series_render_target(
         &self,
         preferred_pane_id: PaneId,
         fallback_main_pane_id: PaneId,
@@ -1,204 +1,260 @@
 use ordered_float::OrderedFloat;
 use smallvec::SmallVec;
 
-use crate::interaction::CrosshairSnap;
+use crate::core::OhlcBar;
+use crate::interaction::{CrosshairSnap, CrosshairSnapSeries, CrosshairSnapTarget};
 use crate::render::Renderer;
 
 use super::ChartEngine;
 
+/// Fraction of the visible span searched beyond each edge, so samples just
+/// outside the view still attract the crosshair near the plot edges.
+const CROSSHAIR_SNAP_OVERSCAN_RATIO: f64 = 0.02;
+
+/// Whitespace samples the magnet crosshair skips on each side of the pointer
+/// before giving up, so a long whitespace run cannot turn a pointer move into
+/// a linear scan. Behind a longer run the crosshair stays unsnapped.
+pub const CROSSHAIR_SNAP_MAX_WHITESPACE_SKIP: usize = 64;
+
 impl<R: Renderer> ChartEngine<R> {
-    pub(super) fn snap_at_x(&self, pointer_x: f64) -> Option<CrosshairSnap> {
+    /// Resolves the magnet snap candidate nearest to the pointer.
+    ///
+    /// Both series are time-sorted, so the search is a binary search limited to
+    /// samples inside the visible time window plus a small overscan, and costs
+    /// O(log n) per pointer move. Whitespace samples (non-finite values) are
+    /// skipped, up to `CROSSHAIR_SNAP_MAX_WHITESPACE_SKIP` per side.
+    /// Candidates farther than the configured snap radius are dropped so the
+    /// crosshair tracks the pointer.
+    pub(super) fn snap_at(&self, pointer_x: f64, pointer_y: f64) -> Option<CrosshairSnap> {
+        let target = self.core.model.interaction.crosshair_snap_target();
+        let viewport = self.core.model.viewport;
+        let pointer_time = self
+            .core
+            .model
+            .time_scale
+            .pixel_to_time(pointer_x, viewport)
+            .ok()?;
+        let (visible_start, visible_end) = self.core.model.time_scale.visible_range();
+        let overscan = (visible_end - visible_start).abs() * CROSSHAIR_SNAP_OVERSCAN_RATIO;
+        let (window_start, window_end) = (
+            visible_start.min(visible_end) - overscan,
+            visible_start.max(visible_end) + overscan,
+        );
+
         let mut candidates: SmallVec<[(OrderedFloat<f64>, CrosshairSnap); 2]> = SmallVec::new();
-        if let Some(snap) = self.nearest_data_snap(pointer_x) {
-            candidates.push(snap);
+        let points = &self.core.model.points;
+        if let Some(point) = nearest_in_window(
+            points,
+            |p| p.x,
+            |p| !p.y.is_finite(),
+            pointer_time,
+            window_start,
+            window_end,
+        ) {
+            if let Some(snap) =
+                self.project_snap(point.x, point.y, CrosshairSnapSeries::Points, pointer_x)
+            {
+                candidates.push(snap);
+            }
         }
-        if let Some(snap) = self.nearest_candle_snap(pointer_x) {
-            candidates.push(snap);
+        if target != CrosshairSnapTarget::SeriesValue {
+            let candles = &self.core.model.candles;
+            if let Some(candle) = nearest_in_window(
+                candles,
+                |c| c.time,
+                is_whitespace_candle,
+                pointer_time,
+                window_start,
+                window_end,
+            ) {
+                let price = self.resolve_candle_snap_price(candle, target, pointer_y);
+                if let Some(snap) =
+                    self.project_snap(candle.time, price, CrosshairSnapSeries::Candles, pointer_x)
+                {
+                    candidates.push(snap);
+                }
+            }
         }
 
+        let radius_px = self.core.model.interaction.crosshair_snap_radius_px();
         candidates
             .into_iter()
             .min_by_key(|item| item.0)
+            .filter(|(dist, _)| radius_px.is_none_or(|radius| dist.0 <= radius))
             .map(|(_, snap)| snap)
     }
 
-    fn nearest_data_snap(&self, pointer_x: f64) -> Option<(OrderedFloat<f64>, CrosshairSnap)> {
-        match (
-            self.nearest_data_snap_sparse(pointer_x),
-            self.nearest_data_snap_bruteforce(pointer_x),
-        ) {
-            (Some(left), Some(right)) => Some(if left.0 <= right.0 { left } else { right }),
-            (Some(left), None) => Some(left),
-            (None, Some(right)) => Some(right),
-            (None, None) => None,
+    fn resolve_candle_snap_price(
+        &self,
+        candle: &OhlcBar,
+        target: CrosshairSnapTarget,
+        pointer_y: f64,
+    ) -> f64 {
+        match target {
+            CrosshairSnapTarget::Open => candle.open,
+            CrosshairSnapTarget::High => candle.high,
+            CrosshairSnapTarget::Low => candle.low,
+            CrosshairSnapTarget::Close | CrosshairSnapTarget::SeriesValue => candle.close,
+            CrosshairSnapTarget::NearestOhlc => {
+                let viewport = self.core.model.viewport;
+                [candle.open, candle.high, candle.low, candle.close]
+                    .into_iter()
+                    .min_by_key(|price| {
+                        let y = self
+                            .core
+                            .model
+                            .price_scale
+                            .price_to_pixel(*price, viewport)
+                            .unwrap_or(f64::INFINITY);
+                        OrderedFloat((y - pointer_y).abs())
+                    })
+                    .unwrap_or(candle.close)
+            }
         }
     }
 
-    fn nearest_data_snap_sparse(
+    fn project_snap(
         &self,
+        time: f64,
+        price: f64,
+        series: CrosshairSnapSeries,
         pointer_x: f64,
     ) -> Option<(OrderedFloat<f64>, CrosshairSnap)> {
-        let (space, reference_step) = self.resolve_time_index_coordinate_space()?;
-        let slot = space
-            .coordinate_to_nearest_filled_slot(pointer_x, self.core.model.points.len(), |idx| {
-                self.core.model.points[idx].x / reference_step
-            })
-            .ok()??;
-        let point = self.core.model.points.get(slot)?;
-        let x_px = self
+        let viewport = self.core.model.viewport;
+        let x = self
             .core
             .model
             .time_scale
-            .time_to_pixel(point.x, self.core.model.viewport)
+            .time_to_pixel(time, viewport)
             .ok()?;
-        let y_px = self
+        let y = self
             .core
             .model
             .price_scale
-            .price_to_pixel(point.y, self.core.model.viewport)
+            .price_to_pixel(price, viewport)
             .ok()?;
-        let dist = OrderedFloat((x_px - pointer_x).abs());
         Some((
-            dist,
+            OrderedFloat((x - pointer_x).abs()),
             CrosshairSnap {
-                x: x_px,
-                y: y_px,
-                time: point.x,
-                price: point.y,
+                x,
+                y,
+                time,
+                price,
+                series,
             },
         ))
     }
+}
 
-    fn nearest_data_snap_bruteforce(
-        &self,
-        pointer_x: f64,
-    ) -> Option<(OrderedFloat<f64>, CrosshairSnap)> {
-        let mut best: Option<(OrderedFloat<f64>, CrosshairSnap)> = None;
-        for point in &self.core.model.points {
-            let x_px = match self
-                .core
-                .model
-                .time_scale
-                .time_to_pixel(point.x, self.core.model.viewport)
-            {
-                Ok(v) => v,
-                Err(_) => continue,
-            };
-            let y_px = match self
-                .core
-                .model
-                .price_scale
-                .price_to_pixel(point.y, self.core.model.viewport)
-            {
-                Ok(v) => v,
-                Err(_) => continue,
-            };
-            let dist = OrderedFloat((x_px - pointer_x).abs());
-            match best {
-                Some((current, _)) if current <= dist => {}
-                _ => {
-                    best = Some((
-                        dist,
-                        CrosshairSnap {
-                            x: x_px,
-                            y: y_px,
-                            time: point.x,
-                            price: point.y,
-                        },
-                    ))
-                }
+fn is_whitespace_candle(candle: &OhlcBar) -> bool {
+    ![candle.open, candle.high, candle.low, candle.close]
+        .into_iter()
+        .all(f64::is_finite)
+}
+
+/// Binary-searches a time-sorted slice for the sample nearest to `time`,
+/// considering only non-whitespace samples within `[window_start,
+/// window_end]`; ties resolve to the later sample. At most
+/// `CROSSHAIR_SNAP_MAX_WHITESPACE_SKIP` samples are inspected on each side.
+fn nearest_in_window<T>(
+    samples: &[T],
+    time_of: impl Fn(&T) -> f64,
+    is_whitespace: impl Fn(&T) -> bool,
+    time: f64,
+    window_start: f64,
+    window_end: f64,
+) -> Option<&T> {
+    let lo = samples.partition_point(|sample| time_of(sample) < window_start);
+    let hi = samples.partition_point(|sample| time_of(sample) <= window_end);
+    let window = samples.get(lo..hi)?;
+    let idx = window.partition_point(|sample| time_of(sample) < time);
+    let before = window[..idx]
+        .iter()
+        .rev()
+        .take(CROSSHAIR_SNAP_MAX_WHITESPACE_SKIP + 1)
+        .find(|sample| !is_whitespace(sample));
+    let after = window[idx..]
+        .iter()
+        .take(CROSSHAIR_SNAP_MAX_WHITESPACE_SKIP + 1)
+        .find(|sample| !is_whitespace(sample));
+    match (before, after) {
+        (Some(before), Some(after)) => {
+            if (time - time_of(before)).abs() < (time_of(after) - time).abs() {
+                Some(before)
+            } else {
+                Some(after)
             }
         }
-        best
+        (Some(sample), None) | (None, Some(sample)) => Some(sample),
+        (None, None) => None,
     }
+}
 
-    fn nearest_candle_snap(&self, pointer_x: f64) -> Option<(OrderedFloat<f64>, CrosshairSnap)> {
-        match (
-            self.nearest_candle_snap_sparse(pointer_x),
-            self.nearest_candle_snap_bruteforce(pointer_x),
-        ) {
-            (Some(left), Some(right)) => Some(if left.0 <= right.0 { left } else { right }),
-            (Some(left), None) => Some(left),
-            (None, Some(right)) => Some(right),
-            (None, None) => None,
-        }
+#[cfg(test)]
+mod tests {
+    use super::{CROSSHAIR_SNAP_MAX_WHITESPACE_SKIP, nearest_in_window};
+
+    #[test]
+    fn nearest_in_window_picks_closest_neighbor() {
+        let times = [1.0, 2.0, 4.0, 8.0];
+        let pick =
+            |time: f64| nearest_in_window(&times, |t| *t, |_| false, time, 0.0, 10.0).copied();
+        assert_eq!(pick(0.0), Some(1.0));
+        assert_eq!(pick(2.9), Some(2.0));
+        assert_eq!(pick(3.1), Some(4.0));
+        assert_eq!(pick(3.0), Some(4.0));
+        assert_eq!(pick(100.0), Some(8.0));
     }
 
-    fn nearest_candle_snap_sparse(
-        &self,
-        pointer_x: f64,
-    ) -> Option<(OrderedFloat<f64>, CrosshairSnap)> {
-        let (space, reference_step) = self.resolve_time_index_coordinate_space()?;
-        let slot = space
-            .coordinate_to_nearest_filled_slot(pointer_x, self.core.model.candles.len(), |idx| {
-                self.core.model.candles[idx].time / reference_step
-            })
-            .ok()??;
-        let candle = self.core.model.candles.get(slot)?;
-        let x_px = self
-            .core
-            .model
-            .time_scale
-            .time_to_pixel(candle.time, self.core.model.viewport)
-            .ok()?;
-        let y_px = self
-            .core
-            .model
-            .price_scale
-            .price_to_pixel(candle.close, self.core.model.viewport)
-            .ok()?;
-        let dist = OrderedFloat((x_px - pointer_x).abs());
-        Some((
-            dist,
-            CrosshairSnap {
-                x: x_px,
-                y: y_px,
-                time: candle.time,
-                price: candle.close,
-            },
-        ))
+    #[test]
+    fn nearest_in_window_ignores_samples_outside_window() {
+        let times = [1.0, 2.0, 4.0, 8.0];
+        assert_eq!(
+            nearest_in_window(&times, |t| *t, |_| false, 7.0, 1.5, 5.0).copied(),
+            Some(4.0)
+        );
+        assert_eq!(
+            nearest_in_window(&times, |t| *t, |_| false, 7.0, 5.0, 7.5),
+            None
+        );
     }
 
-    fn nearest_candle_snap_bruteforce(
-        &self,
-        pointer_x: f64,
-    ) -> Option<(OrderedFloat<f64>, CrosshairSnap)> {
-        let mut best: Option<(OrderedFloat<f64>, CrosshairSnap)> = None;
-        for candle in &self.core.model.candles {
-            let x_px = match self
-                .core
-                .model
-                .time_scale
-                .time_to_pixel(candle.time, self.core.model.viewport)
-            {
-                Ok(v) => v,
-                Err(_) => continue,
-            };
-            let y_px = match self
-                .core
-                .model
-                .price_scale
-                .price_to_pixel(candle.close, self.core.model.viewport)
-            {
-                Ok(v) => v,
-                Err(_) => continue,
-            };
-            let dist = OrderedFloat((x_px - pointer_x).abs());
-            match best {
-                Some((current, _)) if current <= dist => {}
-                _ => {
-                    best = Some((
-                        dist,
-                        CrosshairSnap {
-                            x: x_px,
-                            y: y_px,
-                            time: candle.time,
-                            price: candle.close,
-                        },
-                    ))
-                }
-            }
-        }
-        best
+    #[test]
+    fn nearest_in_window_skips_whitespace_samples() {
+        let samples = [(1.0, 1.0), (2.0, f64::NAN), (3.0, f64::NAN), (6.0, 6.0)];
+        let pick = |time: f64| {
+            nearest_in_window(&samples, |s| s.0, |s| !s.1.is_finite(), time, 0.0, 10.0).map(|s| s.0)
+        };
+        assert_eq!(pick(2.1), Some(1.0));
+        assert_eq!(pick(3.0), Some(1.0));
+        assert_eq!(pick(4.0), Some(6.0));
+        assert_eq!(
+            nearest_in_window(
+                &samples[1..3],
+                |s| s.0,
+                |s| !s.1.is_finite(),
+                2.5,
+                0.0,
+                10.0
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn nearest_in_window_bounds_the_whitespace_skip() {
+        let samples = |run: usize| -> Vec<(f64, f64)> {
+            std::iter::once((0.0, 0.0))
+                .chain((1..=run).map(|i| (i as f64, f64::NAN)))
+                .collect()
+        };
+        let pick = |samples: &[(f64, f64)], time: f64| {
+            nearest_in_window(samples, |s| s.0, |s| !s.1.is_finite(), time, 0.0, 1e9).map(|s| s.0)
+        };
+        let gap = CROSSHAIR_SNAP_MAX_WHITESPACE_SKIP;
+        // A run of exactly the limit is still crossed; a longer one stops the
+        // scan instead of walking the whole run.
+        assert_eq!(pick(&samples(gap), gap as f64 + 0.5), Some(0.0));
+        assert_eq!(pick(&samples(gap + 1), gap as f64 + 1.5), None);
     }
 }
@@ -0,0 +1,151 @@
+use std::time::Duration;
+
+use tracing::debug;
+
+use crate::core::OhlcBar;
+use crate::render::Renderer;
+
+use super::data_controller::canonicalize_candles;
+use super::{CandlestickBarStyleOverride, ChartEngine};
+
+/// Candles already sorted, validated, and deduplicated for a replace.
+///
+/// Preparing is the expensive part of a large `set_candles`; it does not touch
+/// the engine, so it can run on a worker thread (or between frames) and the
+/// result can be committed later without re-doing any of that work.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StagedCandles {
+    candles: Vec<OhlcBar>,
+    style_overrides: Vec<Option<CandlestickBarStyleOverride>>,
+    original_len: usize,
+    stage_duration: Duration,
+}
+
+impl StagedCandles {
+    /// Canonicalizes `candles` exactly like `ChartEngine::set_candles`.
+    ///
+    /// The engine never reads the system clock (it is unavailable on
+    /// `wasm32-unknown-unknown`), so the batch reports a zero
+    /// `stage_duration`; use `prepare_timed` to measure it.
+    #[must_use]
+    pub fn prepare(candles: Vec<OhlcBar>) -> Self {
+        let original_len = candles.len();
+        let candles = canonicalize_candles(candles);
+        let style_overrides = vec![None; candles.len()];
+        Self {
+            candles,
+            style_overrides,
+            original_len,
+            stage_duration: Duration::ZERO,
+        }
+    }
+
+    /// Like `prepare`, timing the work with a host-supplied monotonic clock
+    /// (e.g. `Instant::elapsed` natively, `performance.now()` on the web).
+    #[must_use]
+    pub fn prepare_timed(candles: Vec<OhlcBar>, mut clock: impl FnMut() -> Duration) -> Self {
+        let started = clock();
+        let mut staged = Self::prepare(candles);
+        staged.stage_duration = clock().saturating_sub(started);
+        staged
+    }
+
+    /// Canonical candle count that a commit will install.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.candles.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.candles.is_empty()
+    }
+
+    /// Candle count before invalid/duplicate bars were dropped.
+    #[must_use]
+    pub fn original_len(&self) -> usize {
+        self.original_len
+    }
+
+    /// Time spent preparing this batch; zero unless built by `prepare_timed`.
+    #[must_use]
+    pub fn stage_duration(&self) -> Duration {
+        self.stage_duration
+    }
+}
+
+/// Counters for staged candle ingestion.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct IngestionStats {
+    pub staged_batches: u64,
+    pub committed_batches: u64,
+    /// Staged batches replaced by a newer stage before being committed.
+    pub discarded_batches: u64,
+    pub last_stage_duration: Duration,
+    pub max_stage_duration: Duration,
+    pub total_stage_duration: Duration,
+    pub last_committed_len: usize,
+}
+
+impl<R: Renderer> ChartEngine<R> {
+    /// Prepares `candles` for a later `commit_staged` without touching the
+    /// visible series. A previously staged, uncommitted batch is discarded.
+    pub fn stage_candles(&mut self, candles: Vec<OhlcBar>) {
+        self.stage_prepared_candles(StagedCandles::prepare(candles));
+    }
+
+    /// Stages a batch prepared elsewhere (typically on a worker thread).
+    pub fn stage_prepared_candles(&mut self, staged: StagedCandles) {
+        let stats = &mut self.core.runtime.ingestion_stats;
+        stats.staged_batches += 1;
+        stats.last_stage_duration = staged.stage_duration;
+        stats.max_stage_duration = stats.max_stage_duration.max(staged.stage_duration);
+        stats.total_stage_duration += staged.stage_duration;
+        debug!(
+            original_count = staged.original_len,
+            canonical_count = staged.candles.len(),
+            stage_micros = staged.stage_duration.as_micros() as u64,
+            "staged candles"
+        );
+        if self.core.runtime.staged_candles.replace(staged).is_some() {
+            self.core.runtime.ingestion_stats.discarded_batches += 1;
+        }
+    }
+
+    #[must_use]
+    pub fn has_staged_candles(&self) -> bool {
+        self.core.runtime.staged_candles.is_some()
+    }
+
+    /// Swaps the staged batch in as the candle series.
+    ///
+    /// The buffers are moved, not copied or re-sorted; only the regular
+    /// post-replace refresh (autoscale, transformed base) still runs. Like
+    /// `set_candles`, committing raw bars resets `chart_time_kind`.
+    /// Returns `false` when nothing was staged.
+    pub fn commit_staged(&mut self) -> bool {
+        let Some(staged) = self.core.runtime.staged_candles.take() else {
+            return false;
+        };
+        self.core.model.time_kind = None;
+        let stats = &mut self.core.runtime.ingestion_stats;
+        stats.committed_batches += 1;
+        stats.last_committed_len = staged.candles.len();
+        self.install_canonical_candles(staged.candles, staged.style_overrides, "commit_staged");
+        true
+    }
+
+    /// Drops the staged batch, if any, without committing it.
+    pub fn discard_staged(&mut self) -> bool {
+        let discarded = self.core.runtime.staged_candles.take().is_some();
+        if discarded {
+            self.core.runtime.ingestion_stats.discarded_batches += 1;
+        }
+        discarded
+    }
+
+    #[must_use]
+    pub fn ingestion_stats(&self) -> IngestionStats {
+        self.core.runtime.ingestion_stats
+    }
+}
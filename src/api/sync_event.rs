@@ -0,0 +1,137 @@
+use serde::{Deserialize, Serialize};
+
+use crate::error::{ChartError, ChartResult};
+use crate::render::Renderer;
+
+use super::ChartEngine;
+
+pub const SYNC_EVENT_JSON_SCHEMA_V1: u32 = 1;
+
+/// Crosshair time shared with a remote chart; `None` hides the crosshair.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CrosshairSyncEvent {
+    #[serde(rename = "v")]
+    pub schema_version: u32,
+    #[serde(rename = "t")]
+    pub time: Option<f64>,
+}
+
+impl CrosshairSyncEvent {
+    #[must_use]
+    pub fn new(time: Option<f64>) -> Self {
+        Self {
+            schema_version: SYNC_EVENT_JSON_SCHEMA_V1,
+            time,
+        }
+    }
+}
+
+/// Visible time range shared with a remote chart.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct RangeSyncEvent {
+    #[serde(rename = "v")]
+    pub schema_version: u32,
+    #[serde(rename = "s")]
+    pub start: f64,
+    #[serde(rename = "e")]
+    pub end: f64,
+}
+
+impl RangeSyncEvent {
+    #[must_use]
+    pub fn new(start: f64, end: f64) -> Self {
+        Self {
+            schema_version: SYNC_EVENT_JSON_SCHEMA_V1,
+            start,
+            end,
+        }
+    }
+}
+
+/// Transport-agnostic sync message; serializes to compact JSON such as
+/// `{"k":"range","v":1,"s":10.0,"e":60.0}`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "k", rename_all = "snake_case")]
+pub enum SyncEvent {
+    Crosshair(CrosshairSyncEvent),
+    Range(RangeSyncEvent),
+}
+
+impl SyncEvent {
+    #[must_use]
+    pub fn schema_version(self) -> u32 {
+        match self {
+            Self::Crosshair(event) => event.schema_version,
+            Self::Range(event) => event.schema_version,
+        }
+    }
+
+    pub fn to_json(self) -> ChartResult<String> {
+        serde_json::to_string(&self)
+            .map_err(|e| ChartError::InvalidData(format!("failed to serialize sync event: {e}")))
+    }
+
+    pub fn from_json_str(input: &str) -> ChartResult<Self> {
+        let event: Self = serde_json::from_str(input)
+            .map_err(|e| ChartError::InvalidData(format!("failed to parse sync event: {e}")))?;
+        event.check_schema_version()?;
+        Ok(event)
+    }
+
+    fn check_schema_version(self) -> ChartResult<()> {
+        let found = self.schema_version();
+        if found != SYNC_EVENT_JSON_SCHEMA_V1 {
+            return Err(ChartError::SchemaVersionMismatch {
+                schema: "sync event",
+                expected: SYNC_EVENT_JSON_SCHEMA_V1,
+                found,
+            });
+        }
+        Ok(())
+    }
+}
+
+impl From<CrosshairSyncEvent> for SyncEvent {
+    fn from(event: CrosshairSyncEvent) -> Self {
+        Self::Crosshair(event)
+    }
+}
+
+impl From<RangeSyncEvent> for SyncEvent {
+    fn from(event: RangeSyncEvent) -> Self {
+        Self::Range(event)
+    }
+}
+
+impl<R: Renderer> ChartEngine<R> {
+    /// Current crosshair time as a sync message.
+    #[must_use]
+    pub fn crosshair_sync_event(&self) -> CrosshairSyncEvent {
+        CrosshairSyncEvent::new(self.crosshair_time())
+    }
+
+    /// Current visible range as a sync message.
+    #[must_use]
+    pub fn range_sync_event(&self) -> RangeSyncEvent {
+        let (start, end) = self.time_visible_range();
+        RangeSyncEvent::new(start, end)
+    }
+
+    /// Mirrors a chart's state received from another process.
+    ///
+    /// Range events stop local kinetic pan and range animations so the
+    /// remote chart stays in control. Applying an event changes local state
+    /// like any other call; hosts that also publish local changes should
+    /// skip publishing the state they just applied to avoid echo loops.
+    pub fn apply_sync_event(&mut self, event: SyncEvent) -> ChartResult<()> {
+        event.check_schema_version()?;
+        match event {
+            SyncEvent::Crosshair(event) => self.set_crosshair_time(event.time),
+            SyncEvent::Range(event) => {
+                self.stop_kinetic_pan();
+                self.stop_time_range_animation();
+                self.set_time_visible_range(event.start, event.end)
+            }
+        }
+    }
+}
@@ -0,0 +1,249 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use tracing::trace;
+
+use crate::error::{ChartError, ChartResult};
+use crate::interaction::InteractionMode;
+use crate::render::Renderer;
+
+use super::{ChartEngine, CrosshairSyncEvent, RangeSyncEvent};
+
+/// What a `SyncGroup` member shares with the rest of the group.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SyncMemberOptions {
+    /// Broadcast and follow the visible time range.
+    pub sync_range: bool,
+    /// Broadcast and follow the crosshair time.
+    pub sync_crosshair: bool,
+}
+
+impl Default for SyncMemberOptions {
+    fn default() -> Self {
+        Self {
+            sync_range: true,
+            sync_crosshair: true,
+        }
+    }
+}
+
+/// Identifies a chart inside its `SyncGroup`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SyncMemberId(u64);
+
+struct SyncMember<R: Renderer> {
+    id: SyncMemberId,
+    engine: Rc<RefCell<ChartEngine<R>>>,
+    options: SyncMemberOptions,
+    /// Values as of the last `synchronize`, to tell which member moved.
+    last_range: (f64, f64),
+    last_crosshair_time: Option<f64>,
+}
+
+/// Keeps the visible time range and crosshair time of several charts
+/// aligned.
+///
+/// Engines stay owned by their hosts (e.g. `GtkChartAdapter::engine`); the
+/// group holds shared handles and compares each member against what it saw
+/// on the previous `synchronize`. The member that changed becomes the source
+/// and the others follow it. Hosts call `synchronize` after input and on
+/// every animation tick so followers track kinetic pan and range
+/// animations frame by frame.
+pub struct SyncGroup<R: Renderer> {
+    members: Vec<SyncMember<R>>,
+    next_id: u64,
+}
+
+impl<R: Renderer> Default for SyncGroup<R> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<R: Renderer> SyncGroup<R> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            members: Vec::new(),
+            next_id: 0,
+        }
+    }
+
+    /// Adds a chart; with range sync on it adopts the group's current
+    /// visible range immediately.
+    pub fn add(
+        &mut self,
+        engine: Rc<RefCell<ChartEngine<R>>>,
+        options: SyncMemberOptions,
+    ) -> ChartResult<SyncMemberId> {
+        let group_range = match self.members.iter().find(|m| m.options.sync_range) {
+            Some(leader) if options.sync_range => {
+                Some(borrow_member(&leader.engine)?.time_visible_range())
+            }
+            _ => None,
+        };
+        let (last_range, last_crosshair_time) = {
+            let mut engine = borrow_member_mut(&engine)?;
+            if let Some((start, end)) = group_range {
+                engine.set_time_visible_range(start, end)?;
+            }
+            (engine.time_visible_range(), engine.crosshair_time())
+        };
+        let id = SyncMemberId(self.next_id);
+        self.next_id += 1;
+        self.members.push(SyncMember {
+            id,
+            engine,
+            options,
+            last_range,
+            last_crosshair_time,
+        });
+        Ok(id)
+    }
+
+    /// Removes a chart; returns `false` for unknown ids.
+    pub fn remove(&mut self, id: SyncMemberId) -> bool {
+        let before = self.members.len();
+        self.members.retain(|member| member.id != id);
+        self.members.len() != before
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.members.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.members.is_empty()
+    }
+
+    #[must_use]
+    pub fn member_options(&self, id: SyncMemberId) -> Option<SyncMemberOptions> {
+        self.member(id).map(|member| member.options)
+    }
+
+    pub fn set_member_options(
+        &mut self,
+        id: SyncMemberId,
+        options: SyncMemberOptions,
+    ) -> ChartResult<()> {
+        let member = self
+            .members
+            .iter_mut()
+            .find(|member| member.id == id)
+            .ok_or_else(|| ChartError::InvalidData("unknown sync group member".to_owned()))?;
+        member.options = options;
+        Ok(())
+    }
+
+    /// Propagates the range and crosshair of whichever member changed since
+    /// the last call.
+    ///
+    /// When several members moved, one that is panning or animating wins,
+    /// otherwise the earliest added. Followers drop their own kinetic pan or
+    /// range animation so they do not fight the source. Returns the members
+    /// that were updated, so hosts know which charts to redraw.
+    pub fn synchronize(&mut self) -> ChartResult<Vec<SyncMemberId>> {
+        let mut updated = Vec::new();
+        self.synchronize_ranges(&mut updated)?;
+        self.synchronize_crosshairs(&mut updated)?;
+        Ok(updated)
+    }
+
+    fn member(&self, id: SyncMemberId) -> Option<&SyncMember<R>> {
+        self.members.iter().find(|member| member.id == id)
+    }
+
+    fn synchronize_ranges(&mut self, updated: &mut Vec<SyncMemberId>) -> ChartResult<()> {
+        let mut source = None;
+        for (index, member) in self.members.iter().enumerate() {
+            if !member.options.sync_range {
+                continue;
+            }
+            let engine = borrow_member(&member.engine)?;
+            if engine.time_visible_range() == member.last_range {
+                continue;
+            }
+            let driving = engine.has_active_animation()
+                || engine.interaction_mode() == InteractionMode::Panning;
+            match source {
+                None => source = Some((index, driving)),
+                Some((_, false)) if driving => source = Some((index, driving)),
+                Some(_) => {}
+            }
+        }
+        let Some((source_index, _)) = source else {
+            return Ok(());
+        };
+
+        let (start, end) = borrow_member(&self.members[source_index].engine)?.time_visible_range();
+        trace!(start, end, "sync group broadcasting visible range");
+        self.members[source_index].last_range = (start, end);
+        for (index, member) in self.members.iter_mut().enumerate() {
+            if index == source_index || !member.options.sync_range {
+                continue;
+            }
+            let mut engine = borrow_member_mut(&member.engine)?;
+            if engine.time_visible_range() != (start, end) {
+                engine.apply_sync_event(RangeSyncEvent::new(start, end).into())?;
+                push_unique(updated, member.id);
+            }
+            // Edge and zoom limits may adjust the applied range; remember
+            // what the follower actually shows so it is not taken for a
+            // user change next time.
+            member.last_range = engine.time_visible_range();
+        }
+        Ok(())
+    }
+
+    fn synchronize_crosshairs(&mut self, updated: &mut Vec<SyncMemberId>) -> ChartResult<()> {
+        let mut source = None;
+        for (index, member) in self.members.iter().enumerate() {
+            if member.options.sync_crosshair
+                && borrow_member(&member.engine)?.crosshair_time() != member.last_crosshair_time
+            {
+                source = Some(index);
+                break;
+            }
+        }
+        let Some(source_index) = source else {
+            return Ok(());
+        };
+
+        let time = borrow_member(&self.members[source_index].engine)?.crosshair_time();
+        self.members[source_index].last_crosshair_time = time;
+        for (index, member) in self.members.iter_mut().enumerate() {
+            if index == source_index || !member.options.sync_crosshair {
+                continue;
+            }
+            let mut engine = borrow_member_mut(&member.engine)?;
+            engine.apply_sync_event(CrosshairSyncEvent::new(time).into())?;
+            member.last_crosshair_time = engine.crosshair_time();
+            push_unique(updated, member.id);
+        }
+        Ok(())
+    }
+}
+
+fn push_unique(ids: &mut Vec<SyncMemberId>, id: SyncMemberId) {
+    if !ids.contains(&id) {
+        ids.push(id);
+    }
+}
+
+fn borrow_member<R: Renderer>(
+    engine: &Rc<RefCell<ChartEngine<R>>>,
+) -> ChartResult<std::cell::Ref<'_, ChartEngine<R>>> {
+    engine
+        .try_borrow()
+        .map_err(|_| ChartError::InvalidData("sync group member is already borrowed".to_owned()))
+}
+
+fn borrow_member_mut<R: Renderer>(
+    engine: &Rc<RefCell<ChartEngine<R>>>,
+) -> ChartResult<std::cell::RefMut<'_, ChartEngine<R>>> {
+    engine
+        .try_borrow_mut()
+        .map_err(|_| ChartError::InvalidData("sync group member is already borrowed".to_owned()))
+}
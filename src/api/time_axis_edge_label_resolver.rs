@@ -0,0 +1,29 @@
+use crate::render::Renderer;
+
+use super::ChartEngine;
+
+impl<R: Renderer> ChartEngine<R> {
+    /// Resolves the time of the left-most bar inside the visible range across
+    /// point and candle series.
+    pub(super) fn resolve_left_edge_bar_time(&self) -> Option<f64> {
+        let (visible_start, visible_end) = self.core.model.time_scale.visible_range();
+        let (window_start, window_end) = (
+            visible_start.min(visible_end),
+            visible_start.max(visible_end),
+        );
+
+        let points = &self.core.model.points;
+        let candles = &self.core.model.candles;
+        let point_time = points
+            .get(points.partition_point(|point| point.x < window_start))
+            .map(|point| point.x);
+        let candle_time = candles
+            .get(candles.partition_point(|candle| candle.time < window_start))
+            .map(|candle| candle.time);
+        [point_time, candle_time]
+            .into_iter()
+            .flatten()
+            .filter(|time| *time <= window_end)
+            .min_by(f64::total_cmp)
+    }
+}
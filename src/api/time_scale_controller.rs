@@ -1,9 +1,11 @@
 use crate::core::TimeScaleTuning;
 use crate::error::{ChartError, ChartResult};
+use crate::extensions::VisibleRangeChangeCause;
 use crate::render::Renderer;
 
+use super::navigation_history_controller::ViewCheckpointKind;
 use super::{
-    ChartEngine, TimeScaleEdgeBehavior, TimeScaleNavigationBehavior,
+    ChartEngine, InteractionAction, TimeScaleEdgeBehavior, TimeScaleNavigationBehavior,
     TimeScaleRealtimeAppendBehavior, TimeScaleResizeBehavior, TimeScaleScrollZoomBehavior,
     TimeScaleZoomLimitBehavior, interaction_coordinator::InteractionCoordinator,
     time_scale_coordinator::TimeScaleCoordinator, time_scale_validation,
@@ -123,6 +125,18 @@ impl<R: Renderer> ChartEngine<R> {
         Ok(())
     }
 
+    /// Returns whether time-axis pixel mapping is inverted (newest on the left).
+    #[must_use]
+    pub fn time_scale_inverted(&self) -> bool {
+        self.core.model.time_scale.is_inverted()
+    }
+
+    /// Enables/disables inverted time-axis mapping.
+    pub fn set_time_scale_inverted(&mut self, inverted: bool) {
+        self.core.model.time_scale = self.core.model.time_scale.with_inverted(inverted);
+        self.invalidate_full();
+    }
+
     /// Overrides visible time range (zoom/pan style behavior).
     pub fn set_time_visible_range(&mut self, start: f64, end: f64) -> ChartResult<()> {
         self.core.model.time_scale.set_visible_range(start, end)?;
@@ -145,6 +159,7 @@ impl<R: Renderer> ChartEngine<R> {
             super::chart_runtime::LwcTimeScaleInvalidationIntent::Reset,
         );
         self.emit_visible_range_changed();
+        self.checkpoint_navigation_view(ViewCheckpointKind::Discrete);
     }
 
     /// Scrolls the visible range to the realtime edge.
@@ -191,7 +206,11 @@ impl<R: Renderer> ChartEngine<R> {
     /// Positive `delta_px` moves the range to earlier times, matching common
     /// drag-to-scroll chart behavior.
     pub fn pan_time_visible_by_pixels(&mut self, delta_px: f64) -> ChartResult<()> {
-        TimeScaleCoordinator::pan_time_visible_by_pixels(self, delta_px)
+        self.with_visible_range_change_cause(VisibleRangeChangeCause::Drag, |engine| {
+            TimeScaleCoordinator::pan_time_visible_by_pixels(engine, delta_px)
+        })?;
+        self.record_interaction(InteractionAction::PanByPixels { delta_px });
+        Ok(())
     }
 
     /// Applies touch-drag driven pan using horizontal and/or vertical movement.
@@ -207,7 +226,15 @@ impl<R: Renderer> ChartEngine<R> {
         delta_x_px: f64,
         delta_y_px: f64,
     ) -> ChartResult<f64> {
-        TimeScaleCoordinator::touch_drag_pan_time_visible(self, delta_x_px, delta_y_px)
+        let applied =
+            self.with_visible_range_change_cause(VisibleRangeChangeCause::Drag, |engine| {
+                TimeScaleCoordinator::touch_drag_pan_time_visible(engine, delta_x_px, delta_y_px)
+            })?;
+        self.record_interaction(InteractionAction::TouchDragPan {
+            delta_x_px,
+            delta_y_px,
+        });
+        Ok(applied)
     }
 
     /// Applies wheel-driven horizontal pan.
@@ -222,7 +249,15 @@ impl<R: Renderer> ChartEngine<R> {
         wheel_delta_x: f64,
         pan_step_ratio: f64,
     ) -> ChartResult<f64> {
-        TimeScaleCoordinator::wheel_pan_time_visible(self, wheel_delta_x, pan_step_ratio)
+        let applied =
+            self.with_visible_range_change_cause(VisibleRangeChangeCause::Wheel, |engine| {
+                TimeScaleCoordinator::wheel_pan_time_visible(engine, wheel_delta_x, pan_step_ratio)
+            })?;
+        self.record_interaction(InteractionAction::WheelPan {
+            wheel_delta_x,
+            pan_step_ratio,
+        });
+        Ok(applied)
     }
 
     /// Zooms visible range around a logical time anchor.
@@ -270,13 +305,24 @@ impl<R: Renderer> ChartEngine<R> {
         zoom_step_ratio: f64,
         min_span_absolute: f64,
     ) -> ChartResult<f64> {
-        TimeScaleCoordinator::wheel_zoom_time_visible(
-            self,
+        let factor =
+            self.with_visible_range_change_cause(VisibleRangeChangeCause::Wheel, |engine| {
+                TimeScaleCoordinator::wheel_zoom_time_visible(
+                    engine,
+                    wheel_delta_y,
+                    anchor_px,
+                    zoom_step_ratio,
+                    min_span_absolute,
+                )
+            })?;
+        self.checkpoint_navigation_view(ViewCheckpointKind::Zoom);
+        self.record_interaction(InteractionAction::WheelZoom {
             wheel_delta_y,
             anchor_px,
             zoom_step_ratio,
             min_span_absolute,
-        )
+        });
+        Ok(factor)
     }
 
     /// Applies pinch-driven zoom around a pixel anchor.
@@ -289,19 +335,41 @@ impl<R: Renderer> ChartEngine<R> {
         anchor_px: f64,
         min_span_absolute: f64,
     ) -> ChartResult<f64> {
-        TimeScaleCoordinator::pinch_zoom_time_visible(self, factor, anchor_px, min_span_absolute)
+        let applied =
+            self.with_visible_range_change_cause(VisibleRangeChangeCause::Drag, |engine| {
+                TimeScaleCoordinator::pinch_zoom_time_visible(
+                    engine,
+                    factor,
+                    anchor_px,
+                    min_span_absolute,
+                )
+            })?;
+        self.checkpoint_navigation_view(ViewCheckpointKind::Zoom);
+        self.record_interaction(InteractionAction::PinchZoom {
+            factor,
+            anchor_px,
+            min_span_absolute,
+        });
+        Ok(applied)
     }
 
     /// Advances kinetic pan by a deterministic simulation step.
     ///
     /// Returns `true` when a displacement was applied.
     pub fn step_kinetic_pan(&mut self, delta_seconds: f64) -> ChartResult<bool> {
-        InteractionCoordinator::step_kinetic_pan(self, delta_seconds)
+        let moved = self
+            .with_visible_range_change_cause(VisibleRangeChangeCause::Kinetic, |engine| {
+                InteractionCoordinator::step_kinetic_pan(engine, delta_seconds)
+            })?;
+        self.record_interaction(InteractionAction::StepKineticPan { delta_seconds });
+        Ok(moved)
     }
 
     /// Fits time scale against available point/candle data.
     pub fn fit_time_to_data(&mut self, tuning: TimeScaleTuning) -> ChartResult<()> {
-        TimeScaleCoordinator::fit_time_to_data(self, tuning)
+        TimeScaleCoordinator::fit_time_to_data(self, tuning)?;
+        self.checkpoint_navigation_view(ViewCheckpointKind::Discrete);
+        Ok(())
     }
 
     pub(crate) fn apply_time_scale_constraints(&mut self) -> ChartResult<bool> {
@@ -331,6 +399,14 @@ impl<R: Renderer> ChartEngine<R> {
         TimeScaleCoordinator::resolve_right_margin_zoom_anchor_px(self)
     }
 
+    /// Maps screen X to the left-to-right space of the logical-index math.
+    pub(crate) fn mirror_time_axis_px(&self, pixel: f64) -> f64 {
+        self.core
+            .model
+            .time_scale
+            .mirror_pixel(pixel, self.core.model.viewport)
+    }
+
     pub(crate) fn resolve_time_index_coordinate_space(
         &self,
     ) -> Option<(crate::core::TimeIndexCoordinateSpace, f64)> {
@@ -70,7 +70,7 @@ impl TimeScaleCoordinator {
             .time_scale
             .pan_visible_by_delta(delta_time)?;
         let _ = Self::apply_time_scale_zoom_limit_behavior(engine)?;
-        let _ = Self::apply_time_scale_edge_behavior(engine)?;
+        engine.apply_pan_edge_behavior()?;
         Self::mark_pan_invalidation_intent(engine, visible_before);
         engine.emit_visible_range_changed();
         Ok(())
@@ -273,7 +273,7 @@ impl TimeScaleCoordinator {
                     full_end,
                     visible_start: start,
                     visible_end: end,
-                    anchor_px,
+                    anchor_px: engine.mirror_time_axis_px(anchor_px),
                     viewport_width,
                     factor,
                     min_span_absolute,
@@ -336,6 +336,11 @@ impl TimeScaleCoordinator {
         }
 
         time_scale_input_validation::validate_pan_pixel_delta(delta_px)?;
+        let delta_px = if engine.core.model.time_scale.is_inverted() {
+            -delta_px
+        } else {
+            delta_px
+        };
 
         if let Some((space, reference_step)) = engine.resolve_time_index_coordinate_space() {
             let visible_before = engine.core.model.time_scale.visible_range();
@@ -351,7 +356,7 @@ impl TimeScaleCoordinator {
                     space.width_px,
                 )?;
             let _ = engine.apply_time_scale_zoom_limit_behavior()?;
-            let _ = engine.apply_time_scale_edge_behavior()?;
+            engine.apply_pan_edge_behavior()?;
             Self::mark_pan_invalidation_intent(engine, visible_before);
             engine.emit_visible_range_changed();
             return Ok(());
@@ -380,6 +385,11 @@ impl TimeScaleCoordinator {
         }
 
         time_scale_input_validation::validate_touch_drag_deltas(behavior, delta_x_px, delta_y_px)?;
+        let delta_x_px = if engine.core.model.time_scale.is_inverted() {
+            -delta_x_px
+        } else {
+            delta_x_px
+        };
 
         let (start, end) = engine.core.model.time_scale.visible_range();
         let span = end - start;
@@ -528,14 +538,21 @@ impl TimeScaleCoordinator {
             return Ok(false);
         }
 
-        let max_span =
-            (reference_step * (viewport_width / behavior.min_bar_spacing_px).max(1.0)).max(1e-9);
         let min_span = match behavior.max_bar_spacing_px {
             Some(max_spacing_px) => {
                 (reference_step * (viewport_width / max_spacing_px).max(1.0)).max(1e-9)
             }
             None => 1e-9,
         };
+        let mut max_span =
+            (reference_step * (viewport_width / behavior.min_bar_spacing_px).max(1.0)).max(1e-9);
+        if let Some(ratio) = behavior.max_visible_span_ratio {
+            let (full_start, full_end) = engine.core.model.time_scale.full_range();
+            let full_span = (full_end - full_start).abs();
+            if full_span.is_finite() && full_span > 0.0 {
+                max_span = max_span.min(full_span * ratio).max(min_span);
+            }
+        }
 
         let (visible_start, visible_end) = engine.core.model.time_scale.visible_range();
         let current_span = (visible_end - visible_start).max(1e-9);
@@ -815,7 +832,7 @@ impl TimeScaleCoordinator {
         if !viewport_width.is_finite() || viewport_width <= 0.0 {
             return None;
         }
-        Some((viewport_width - offset_px).clamp(0.0, viewport_width))
+        Some(engine.mirror_time_axis_px((viewport_width - offset_px).clamp(0.0, viewport_width)))
     }
 
     pub(super) fn resolve_time_index_coordinate_space<R: Renderer>(
@@ -1,7 +1,9 @@
 use crate::error::ChartResult;
+use crate::extensions::VisibleRangeChangeCause;
 use crate::render::Renderer;
 
-use super::{ChartEngine, scale_coordinator::ScaleCoordinator};
+use super::navigation_history_controller::ViewCheckpointKind;
+use super::{ChartEngine, InteractionAction, scale_coordinator::ScaleCoordinator};
 
 impl<R: Renderer> ChartEngine<R> {
     /// Applies time-axis drag scaling around a pixel anchor.
@@ -19,13 +21,24 @@ impl<R: Renderer> ChartEngine<R> {
         zoom_step_ratio: f64,
         min_span_absolute: f64,
     ) -> ChartResult<f64> {
-        ScaleCoordinator::axis_drag_scale_time(
-            self,
+        let factor =
+            self.with_visible_range_change_cause(VisibleRangeChangeCause::Drag, |engine| {
+                ScaleCoordinator::axis_drag_scale_time(
+                    engine,
+                    drag_delta_x_px,
+                    anchor_x_px,
+                    zoom_step_ratio,
+                    min_span_absolute,
+                )
+            })?;
+        self.checkpoint_navigation_view(ViewCheckpointKind::Zoom);
+        self.record_interaction(InteractionAction::AxisDragScaleTime {
             drag_delta_x_px,
             anchor_x_px,
             zoom_step_ratio,
             min_span_absolute,
-        )
+        });
+        Ok(factor)
     }
 
     /// Resets time axis to full-range visible domain.
@@ -34,6 +47,9 @@ impl<R: Renderer> ChartEngine<R> {
     ///
     /// Returns `true` when visible range changed.
     pub fn axis_double_click_reset_time_scale(&mut self) -> ChartResult<bool> {
-        ScaleCoordinator::axis_double_click_reset_time_scale(self)
+        let changed = ScaleCoordinator::axis_double_click_reset_time_scale(self)?;
+        self.checkpoint_navigation_view(ViewCheckpointKind::Discrete);
+        self.record_interaction(InteractionAction::AxisDoubleClickResetTime);
+        Ok(changed)
     }
 }
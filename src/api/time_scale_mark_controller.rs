@@ -0,0 +1,203 @@
+use serde::{Deserialize, Serialize};
+
+use crate::core::PaneId;
+use crate::error::{ChartError, ChartResult};
+use crate::render::{
+    CanvasLayerKind, ClipRect, Color, LayeredRenderFrame, RectPrimitive, RenderFrame, Renderer,
+    TextHAlign, TextPrimitive, TextVAlign,
+};
+
+use super::{ChartEngine, TimeScaleMarkBehavior, validation};
+
+/// Badge pinned to a time on the time axis, independent of series markers
+/// (earnings, dividends, splits, ...).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TimeScaleMark {
+    pub id: String,
+    pub time: f64,
+    /// Short glyph drawn inside the badge, e.g. `E` for earnings.
+    pub text: String,
+    /// Text a host shows when the pointer rests on the badge.
+    pub tooltip: Option<String>,
+    pub color: Color,
+    pub text_color: Color,
+}
+
+impl TimeScaleMark {
+    #[must_use]
+    pub fn new(id: impl Into<String>, time: f64, text: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            time,
+            text: text.into(),
+            tooltip: None,
+            color: Color::rgb(0.16, 0.38, 1.0),
+            text_color: Color::rgb(1.0, 1.0, 1.0),
+        }
+    }
+
+    #[must_use]
+    pub fn with_tooltip(mut self, tooltip: impl Into<String>) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
+    #[must_use]
+    pub fn with_colors(mut self, color: Color, text_color: Color) -> Self {
+        self.color = color;
+        self.text_color = text_color;
+        self
+    }
+
+    fn validate(&self) -> ChartResult<()> {
+        if !self.time.is_finite() {
+            return Err(ChartError::InvalidData(format!(
+                "time scale mark `{}` must have a finite time",
+                self.id
+            )));
+        }
+        self.color.validate()?;
+        self.text_color.validate()
+    }
+}
+
+impl<R: Renderer> ChartEngine<R> {
+    #[must_use]
+    pub fn time_scale_marks(&self) -> &[TimeScaleMark] {
+        &self.core.model.time_scale_marks
+    }
+
+    /// Replaces all marks; they are kept sorted by time.
+    pub fn set_time_scale_marks(&mut self, mut marks: Vec<TimeScaleMark>) -> ChartResult<()> {
+        for mark in &marks {
+            mark.validate()?;
+        }
+        marks.sort_by(|a, b| a.time.total_cmp(&b.time));
+        self.core.model.time_scale_marks = marks;
+        self.invalidate_full();
+        Ok(())
+    }
+
+    /// Adds one mark, e.g. from a separate provider feed.
+    pub fn add_time_scale_mark(&mut self, mark: TimeScaleMark) -> ChartResult<()> {
+        mark.validate()?;
+        let marks = &mut self.core.model.time_scale_marks;
+        let index = marks.partition_point(|existing| existing.time <= mark.time);
+        marks.insert(index, mark);
+        self.invalidate_full();
+        Ok(())
+    }
+
+    /// Removes every mark with `id`, returning whether any existed.
+    pub fn remove_time_scale_mark(&mut self, id: &str) -> bool {
+        let before = self.core.model.time_scale_marks.len();
+        self.core
+            .model
+            .time_scale_marks
+            .retain(|mark| mark.id != id);
+        let removed = self.core.model.time_scale_marks.len() != before;
+        if removed {
+            self.invalidate_full();
+        }
+        removed
+    }
+
+    #[must_use]
+    pub fn time_scale_mark_behavior(&self) -> TimeScaleMarkBehavior {
+        self.core.behavior.time_scale_mark_behavior
+    }
+
+    pub fn set_time_scale_mark_behavior(
+        &mut self,
+        behavior: TimeScaleMarkBehavior,
+    ) -> ChartResult<()> {
+        validation::validate_time_scale_mark_behavior(behavior)?;
+        if self.core.behavior.time_scale_mark_behavior != behavior {
+            self.core.behavior.time_scale_mark_behavior = behavior;
+            self.invalidate_full();
+        }
+        Ok(())
+    }
+
+    /// Mark whose badge contains content position `(x, y)`; the last drawn
+    /// (top-most) badge wins where badges overlap.
+    #[must_use]
+    pub fn time_scale_mark_at(&self, x: f64, y: f64) -> Option<&TimeScaleMark> {
+        let radius = self.core.behavior.time_scale_mark_behavior.size_px * 0.5;
+        let plot_right =
+            f64::from(self.core.model.viewport.width) - self.layout_price_axis_width_px();
+        self.time_scale_mark_badges(plot_right)
+            .into_iter()
+            .rev()
+            .find(|(_, center_x, center_y)| (x - center_x).hypot(y - center_y) <= radius)
+            .map(|(index, _, _)| &self.core.model.time_scale_marks[index])
+    }
+
+    /// Draws mark badges into the time-axis panel of the main pane's axis
+    /// layer.
+    pub(super) fn append_time_scale_mark_primitives(
+        &self,
+        frame: &mut RenderFrame,
+        layered: &mut LayeredRenderFrame,
+        main_pane_id: PaneId,
+        plot_right: f64,
+        time_axis_clip: ClipRect,
+    ) {
+        let behavior = self.core.behavior.time_scale_mark_behavior;
+        let radius = behavior.size_px * 0.5;
+        for (index, center_x, center_y) in self.time_scale_mark_badges(plot_right) {
+            let mark = &self.core.model.time_scale_marks[index];
+            let badge = RectPrimitive::new(
+                center_x - radius,
+                center_y - radius,
+                behavior.size_px,
+                behavior.size_px,
+                mark.color,
+            )
+            .with_corner_radius(radius)
+            .with_clip_rect(time_axis_clip);
+            frame.rects.push(badge);
+            layered.push_rect(main_pane_id, CanvasLayerKind::Axis, badge);
+
+            if mark.text.is_empty() {
+                continue;
+            }
+            let text = TextPrimitive::new(
+                mark.text.as_ref(),
+                center_x,
+                center_y,
+                behavior.font_size_px,
+                mark.text_color,
+                TextHAlign::Center,
+            )
+            .with_v_align(TextVAlign::Middle)
+            .with_clip_rect(time_axis_clip);
+            frame.texts.push(text.clone());
+            layered.push_text(main_pane_id, CanvasLayerKind::Axis, text);
+        }
+    }
+
+    /// `(mark index, center x, center y)` of every badge centered inside
+    /// `0..=plot_right`, in draw order.
+    fn time_scale_mark_badges(&self, plot_right: f64) -> Vec<(usize, f64, f64)> {
+        let behavior = self.core.behavior.time_scale_mark_behavior;
+        if !behavior.visible {
+            return Vec::new();
+        }
+        let center_y = f64::from(self.core.model.viewport.height)
+            - behavior.bottom_padding_px
+            - behavior.size_px * 0.5;
+        self.core
+            .model
+            .time_scale_marks
+            .iter()
+            .enumerate()
+            .filter_map(|(index, mark)| {
+                let center_x = self.map_x_to_pixel(mark.time).ok()?;
+                (0.0..=plot_right)
+                    .contains(&center_x)
+                    .then_some((index, center_x, center_y))
+            })
+            .collect()
+    }
+}
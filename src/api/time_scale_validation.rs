@@ -56,5 +56,13 @@ pub(super) fn validate_time_scale_zoom_limit_behavior(
         }
     }
 
+    if let Some(ratio) = behavior.max_visible_span_ratio {
+        if !ratio.is_finite() || ratio <= 0.0 {
+            return Err(ChartError::InvalidData(
+                "time scale maximum visible span ratio must be finite and > 0".to_owned(),
+            ));
+        }
+    }
+
     Ok(())
 }
@@ -0,0 +1,273 @@
+use crate::core::{PaneId, PriceScale};
+use crate::error::ChartResult;
+use crate::extensions::{OpenPosition, TradingOverlayStyle, WorkingOrder, trading_lines};
+use crate::render::{
+    CanvasLayerKind, ClipRect, LayeredRenderFrame, LinePrimitive, LineStrokeStyle, RectPrimitive,
+    RenderFrame, Renderer, TextHAlign, TextPrimitive, TextVAlign,
+};
+
+use super::{ChartEngine, PluginEvent, SeriesPixelSpace, SeriesRef};
+
+/// Order line grabbed by `begin_order_drag`.
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct OrderDrag {
+    id: String,
+    start_price: f64,
+}
+
+impl<R: Renderer> ChartEngine<R> {
+    #[must_use]
+    pub fn working_orders(&self) -> &[WorkingOrder] {
+        &self.core.model.working_orders
+    }
+
+    /// Replaces all working orders; a drag on an order that disappeared is
+    /// dropped.
+    pub fn set_working_orders(&mut self, orders: Vec<WorkingOrder>) -> ChartResult<()> {
+        for order in &orders {
+            order.validate()?;
+        }
+        self.core.model.working_orders = orders;
+        if let Some(drag) = &self.core.runtime.order_drag
+            && !self
+                .core
+                .model
+                .working_orders
+                .iter()
+                .any(|order| order.id == drag.id)
+        {
+            self.core.runtime.order_drag = None;
+        }
+        self.invalidate_full();
+        Ok(())
+    }
+
+    #[must_use]
+    pub fn open_positions(&self) -> &[OpenPosition] {
+        &self.core.model.open_positions
+    }
+
+    pub fn set_open_positions(&mut self, positions: Vec<OpenPosition>) -> ChartResult<()> {
+        for position in &positions {
+            position.validate()?;
+        }
+        self.core.model.open_positions = positions;
+        self.invalidate_full();
+        Ok(())
+    }
+
+    #[must_use]
+    pub fn trading_overlay_style(&self) -> TradingOverlayStyle {
+        self.core.behavior.trading_overlay_style
+    }
+
+    pub fn set_trading_overlay_style(&mut self, style: TradingOverlayStyle) -> ChartResult<()> {
+        let style = style.validate()?;
+        if self.core.behavior.trading_overlay_style != style {
+            self.core.behavior.trading_overlay_style = style;
+            self.invalidate_full();
+        }
+        Ok(())
+    }
+
+    /// Draggable order whose line passes within the style's hit distance of
+    /// content position `(x, y)`; the closest line wins.
+    #[must_use]
+    pub fn working_order_at(&self, x: f64, y: f64) -> Option<&WorkingOrder> {
+        let plot_right =
+            f64::from(self.core.model.viewport.width) - self.layout_price_axis_width_px();
+        if !(0.0..=plot_right).contains(&x) {
+            return None;
+        }
+        let half_height = self
+            .core
+            .behavior
+            .trading_overlay_style
+            .drag_hit_half_height_px;
+        self.core
+            .model
+            .working_orders
+            .iter()
+            .filter(|order| order.draggable)
+            .filter_map(|order| {
+                let line_y = self
+                    .series_price_to_pixel(
+                        SeriesRef::Candles,
+                        order.price,
+                        SeriesPixelSpace::Content,
+                    )
+                    .ok()?;
+                let distance = (line_y - y).abs();
+                (distance <= half_height).then_some((order, distance))
+            })
+            .min_by(|left, right| left.1.total_cmp(&right.1))
+            .map(|(order, _)| order)
+    }
+
+    /// Grabs the draggable order line at content position `(x, y)`.
+    ///
+    /// Returns `false` when no order line is there.
+    pub fn begin_order_drag(&mut self, x: f64, y: f64) -> bool {
+        let Some(order) = self.working_order_at(x, y) else {
+            return false;
+        };
+        self.core.runtime.order_drag = Some(OrderDrag {
+            id: order.id.clone(),
+            start_price: order.price,
+        });
+        true
+    }
+
+    /// Moves the grabbed order line to content row `y`.
+    pub fn drag_order_to(&mut self, y: f64) -> ChartResult<()> {
+        let Some(id) = self.core.runtime.order_drag.as_ref().map(|drag| &drag.id) else {
+            return Ok(());
+        };
+        let price = self.series_pixel_to_price(SeriesRef::Candles, y, SeriesPixelSpace::Content)?;
+        if let Some(order) = self
+            .core
+            .model
+            .working_orders
+            .iter_mut()
+            .find(|order| order.id == *id)
+        {
+            order.price = price;
+        }
+        self.invalidate_full();
+        Ok(())
+    }
+
+    /// Releases the grabbed order line, emitting
+    /// `PluginEvent::OrderPriceChanged` when its price moved.
+    ///
+    /// Returns the order's new price, or `None` when nothing moved.
+    pub fn end_order_drag(&mut self) -> Option<f64> {
+        let drag = self.core.runtime.order_drag.take()?;
+        let price = self
+            .core
+            .model
+            .working_orders
+            .iter()
+            .find(|order| order.id == drag.id)?
+            .price;
+        if price == drag.start_price {
+            return None;
+        }
+        self.emit_plugin_event(PluginEvent::OrderPriceChanged { id: drag.id, price });
+        Some(price)
+    }
+
+    /// Drops the grabbed order line back at its original price.
+    pub fn cancel_order_drag(&mut self) {
+        let Some(drag) = self.core.runtime.order_drag.take() else {
+            return;
+        };
+        if let Some(order) = self
+            .core
+            .model
+            .working_orders
+            .iter_mut()
+            .find(|order| order.id == drag.id)
+        {
+            order.price = drag.start_price;
+            self.invalidate_full();
+        }
+    }
+
+    /// Id of the order line currently being dragged.
+    #[must_use]
+    pub fn dragged_order_id(&self) -> Option<&str> {
+        self.core
+            .runtime
+            .order_drag
+            .as_ref()
+            .map(|drag| drag.id.as_str())
+    }
+
+    /// Price position PnL is marked against and alerts are checked against:
+    /// the last candle close, else the last point value.
+    pub(super) fn latest_close_price(&self) -> Option<f64> {
+        self.core
+            .model
+            .candles
+            .last()
+            .map(|bar| bar.close)
+            .or_else(|| self.core.model.points.last().map(|point| point.y))
+    }
+
+    /// Draws order, position and bracket lines with right-aligned labels
+    /// into the candle pane's overlay layer.
+    pub(super) fn append_trading_overlay_primitives(
+        &self,
+        frame: &mut RenderFrame,
+        layered: &mut LayeredRenderFrame,
+        pane_id: PaneId,
+        price_scale: PriceScale,
+        plot_clip: ClipRect,
+    ) -> ChartResult<()> {
+        let model = &self.core.model;
+        if model.working_orders.is_empty() && model.open_positions.is_empty() {
+            return Ok(());
+        }
+        let style = self.core.behavior.trading_overlay_style;
+        let lines = trading_lines(
+            &model.working_orders,
+            &model.open_positions,
+            self.latest_close_price(),
+            style,
+        );
+        let plot_right = plot_clip.x + plot_clip.width;
+        let label_height = style.font_size_px + 2.0 * style.label_padding_px;
+        for line in lines {
+            let y = price_scale.price_to_pixel(line.price, model.viewport)?;
+            if !y.is_finite() {
+                continue;
+            }
+            let stroke_style = if line.dashed {
+                LineStrokeStyle::Dashed
+            } else {
+                LineStrokeStyle::Solid
+            };
+            let stroke =
+                LinePrimitive::new(plot_clip.x, y, plot_right, y, style.line_width, line.color)
+                    .with_stroke_style(stroke_style)
+                    .with_clip_rect(plot_clip);
+            frame.lines.push(stroke);
+            layered.push_line(pane_id, CanvasLayerKind::Overlay, stroke);
+
+            let label_width = self
+                .core
+                .presentation
+                .render_style
+                .label_text_metrics
+                .text_width_px(&line.label, style.font_size_px)
+                + 2.0 * style.label_padding_px;
+            let label_x = plot_right - style.label_padding_px - label_width;
+            let label = RectPrimitive::new(
+                label_x,
+                y - label_height * 0.5,
+                label_width,
+                label_height,
+                line.color,
+            )
+            .with_corner_radius(2.0)
+            .with_clip_rect(plot_clip);
+            frame.rects.push(label);
+            layered.push_rect(pane_id, CanvasLayerKind::Overlay, label);
+
+            let text = TextPrimitive::new(
+                line.label,
+                label_x + style.label_padding_px,
+                y,
+                style.font_size_px,
+                style.label_text_color,
+                TextHAlign::Left,
+            )
+            .with_v_align(TextVAlign::Middle)
+            .with_clip_rect(plot_clip);
+            frame.texts.push(text.clone());
+            layered.push_text(pane_id, CanvasLayerKind::Overlay, text);
+        }
+        Ok(())
+    }
+}
@@ -1,8 +1,13 @@
 use crate::error::{ChartError, ChartResult};
 
 use super::{
-    PriceAxisDisplayMode, PriceAxisLabelConfig, PriceAxisLabelPolicy, RenderStyle,
-    TimeAxisLabelConfig, TimeAxisLabelPolicy, TimeAxisSessionConfig,
+    AxisInteractionConfig, BarCountdownBehavior, DataGapBehavior, DebugOverlayBehavior,
+    ElasticOverscrollBehavior, HighLowMarkerBehavior, IdlePowerBehavior,
+    LastValueAnimationBehavior, OhlcInfoLineBehavior, PriceAxisDisplayMode, PriceAxisLabelConfig,
+    PriceAxisLabelPolicy, PriceAxisSecondaryLabel, PriceAxisWidthMode, RedrawThrottleBehavior,
+    RenderStyle, TimeAxisLabelConfig, TimeAxisLabelPolicy, TimeAxisSessionConfig,
+    TimeScaleMarkBehavior, VisibleRangeChangeBehavior, VisibleRangeChangeDelivery,
+    WheelBehaviorConfig, ZoomSelectionBehavior,
 };
 
 pub(super) fn validate_time_axis_label_config(
@@ -16,7 +21,9 @@ pub(super) fn validate_time_axis_label_config(
                 ));
             }
         }
-        TimeAxisLabelPolicy::UtcDateTime { .. } | TimeAxisLabelPolicy::UtcAdaptive => {}
+        TimeAxisLabelPolicy::UtcDateTime { .. }
+        | TimeAxisLabelPolicy::UtcDate
+        | TimeAxisLabelPolicy::UtcAdaptive => {}
     }
 
     let offset_minutes = i32::from(config.timezone.offset_minutes());
@@ -36,7 +43,27 @@ pub(super) fn validate_time_axis_label_config(
 pub(super) fn validate_price_axis_label_config(
     config: PriceAxisLabelConfig,
 ) -> ChartResult<PriceAxisLabelConfig> {
-    match config.policy {
+    validate_price_axis_label_policy(config.policy)?;
+
+    match config.display_mode {
+        PriceAxisDisplayMode::Normal
+        | PriceAxisDisplayMode::Percentage { .. }
+        | PriceAxisDisplayMode::IndexedTo100 { .. } => {}
+    }
+
+    if let PriceAxisSecondaryLabel::PercentFromBase { base_price } = config.secondary_label
+        && (!base_price.is_finite() || base_price <= 0.0)
+    {
+        return Err(ChartError::InvalidData(
+            "price-axis secondary label base_price must be finite and > 0".to_owned(),
+        ));
+    }
+
+    Ok(config)
+}
+
+pub(super) fn validate_price_axis_label_policy(policy: PriceAxisLabelPolicy) -> ChartResult<()> {
+    match policy {
         PriceAxisLabelPolicy::FixedDecimals { precision } => {
             if precision > 12 {
                 return Err(ChartError::InvalidData(
@@ -52,15 +79,18 @@ pub(super) fn validate_price_axis_label_config(
             }
         }
         PriceAxisLabelPolicy::Adaptive => {}
+        PriceAxisLabelPolicy::Compact { significant_digits }
+        | PriceAxisLabelPolicy::Scientific {
+            significant_digits, ..
+        } => {
+            if !(1..=15).contains(&significant_digits) {
+                return Err(ChartError::InvalidData(
+                    "price-axis significant digits must be in 1..=15".to_owned(),
+                ));
+            }
+        }
     }
-
-    match config.display_mode {
-        PriceAxisDisplayMode::Normal
-        | PriceAxisDisplayMode::Percentage { .. }
-        | PriceAxisDisplayMode::IndexedTo100 { .. } => {}
-    }
-
-    Ok(config)
+    Ok(())
 }
 
 fn validate_time_axis_session_config(
@@ -88,11 +118,307 @@ fn validate_time_axis_session_config(
     Ok(session)
 }
 
-pub(super) fn validate_render_style(style: RenderStyle) -> ChartResult<RenderStyle> {
+pub(super) fn validate_ohlc_info_line_behavior(behavior: OhlcInfoLineBehavior) -> ChartResult<()> {
+    if !behavior.font_size_px.is_finite() || behavior.font_size_px <= 0.0 {
+        return Err(ChartError::InvalidData(
+            "ohlc info line font size px must be finite and > 0".to_owned(),
+        ));
+    }
+    for (name, value) in [
+        ("padding_px", behavior.padding_px),
+        ("field_gap_px", behavior.field_gap_px),
+    ] {
+        if !value.is_finite() || value < 0.0 {
+            return Err(ChartError::InvalidData(format!(
+                "ohlc info line {name} must be finite and >= 0"
+            )));
+        }
+    }
+    behavior.label_color.validate()?;
+    behavior.neutral_color.validate()
+}
+
+pub(super) fn validate_time_scale_mark_behavior(
+    behavior: TimeScaleMarkBehavior,
+) -> ChartResult<()> {
+    for (name, value) in [
+        ("size_px", behavior.size_px),
+        ("font_size_px", behavior.font_size_px),
+    ] {
+        if !value.is_finite() || value <= 0.0 {
+            return Err(ChartError::InvalidData(format!(
+                "time scale mark {name} must be finite and > 0"
+            )));
+        }
+    }
+    if !behavior.bottom_padding_px.is_finite() || behavior.bottom_padding_px < 0.0 {
+        return Err(ChartError::InvalidData(
+            "time scale mark bottom_padding_px must be finite and >= 0".to_owned(),
+        ));
+    }
+    Ok(())
+}
+
+pub(super) fn validate_axis_interaction_config(config: AxisInteractionConfig) -> ChartResult<()> {
+    for (axis, options) in [("price", config.price), ("time", config.time)] {
+        for (name, value) in [
+            ("drag_sensitivity", options.drag_sensitivity),
+            ("wheel_sensitivity", options.wheel_sensitivity),
+            ("min_span", options.min_span),
+        ] {
+            if !value.is_finite() || value <= 0.0 {
+                return Err(ChartError::InvalidData(format!(
+                    "{axis} axis interaction {name} must be finite and > 0"
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
+pub(super) fn validate_wheel_behavior_config(config: WheelBehaviorConfig) -> ChartResult<()> {
+    for (name, value) in [
+        ("vertical_sensitivity", config.vertical_sensitivity),
+        ("horizontal_sensitivity", config.horizontal_sensitivity),
+        ("zoom_step_ratio", config.zoom_step_ratio),
+        ("pan_step_ratio", config.pan_step_ratio),
+    ] {
+        if !value.is_finite() || value <= 0.0 {
+            return Err(ChartError::InvalidData(format!(
+                "wheel behavior {name} must be finite and > 0"
+            )));
+        }
+    }
+    Ok(())
+}
+
+pub(super) fn validate_elastic_overscroll_behavior(
+    behavior: ElasticOverscrollBehavior,
+) -> ChartResult<()> {
+    if !behavior.max_overscroll_px.is_finite() || behavior.max_overscroll_px <= 0.0 {
+        return Err(ChartError::InvalidData(
+            "elastic overscroll max_overscroll_px must be finite and > 0".to_owned(),
+        ));
+    }
+    if !behavior.spring_back_seconds.is_finite() || behavior.spring_back_seconds < 0.0 {
+        return Err(ChartError::InvalidData(
+            "elastic overscroll spring_back_seconds must be finite and >= 0".to_owned(),
+        ));
+    }
+    Ok(())
+}
+
+pub(super) fn validate_zoom_selection_behavior(behavior: ZoomSelectionBehavior) -> ChartResult<()> {
+    if !behavior.min_size_px.is_finite() || behavior.min_size_px < 0.0 {
+        return Err(ChartError::InvalidData(
+            "zoom selection min_size_px must be finite and >= 0".to_owned(),
+        ));
+    }
+    if !behavior.border_width_px.is_finite() || behavior.border_width_px < 0.0 {
+        return Err(ChartError::InvalidData(
+            "zoom selection border_width_px must be finite and >= 0".to_owned(),
+        ));
+    }
+    behavior.fill_color.validate()?;
+    behavior.border_color.validate()
+}
+
+pub(super) fn validate_debug_overlay_behavior(behavior: DebugOverlayBehavior) -> ChartResult<()> {
+    if !behavior.font_size_px.is_finite() || behavior.font_size_px <= 0.0 {
+        return Err(ChartError::InvalidData(
+            "debug overlay font_size_px must be finite and > 0".to_owned(),
+        ));
+    }
+    if !behavior.padding_px.is_finite() || behavior.padding_px < 0.0 {
+        return Err(ChartError::InvalidData(
+            "debug overlay padding_px must be finite and >= 0".to_owned(),
+        ));
+    }
+    behavior.text_color.validate()?;
+    behavior.background_color.validate()
+}
+
+pub(super) fn validate_redraw_throttle_behavior(
+    behavior: RedrawThrottleBehavior,
+) -> ChartResult<()> {
+    if let Some(max_fps) = behavior.max_fps
+        && (!max_fps.is_finite() || max_fps <= 0.0)
+    {
+        return Err(ChartError::InvalidData(
+            "redraw throttle max_fps must be finite and > 0".to_owned(),
+        ));
+    }
+    Ok(())
+}
+
+pub(super) fn validate_idle_power_behavior(behavior: IdlePowerBehavior) -> ChartResult<()> {
+    if let Some(idle_after) = behavior.idle_after_seconds
+        && (!idle_after.is_finite() || idle_after <= 0.0)
+    {
+        return Err(ChartError::InvalidData(
+            "idle power idle_after_seconds must be finite and > 0".to_owned(),
+        ));
+    }
+    if !behavior.batch_interval_seconds.is_finite() || behavior.batch_interval_seconds <= 0.0 {
+        return Err(ChartError::InvalidData(
+            "idle power batch_interval_seconds must be finite and > 0".to_owned(),
+        ));
+    }
+    Ok(())
+}
+
+pub(super) fn validate_data_gap_behavior(behavior: DataGapBehavior) -> ChartResult<()> {
+    if let Some(interval) = behavior.expected_interval
+        && (!interval.is_finite() || interval <= 0.0)
+    {
+        return Err(ChartError::InvalidData(
+            "data gap expected_interval must be finite and > 0".to_owned(),
+        ));
+    }
+    if !behavior.tolerance.is_finite() || behavior.tolerance < 1.0 {
+        return Err(ChartError::InvalidData(
+            "data gap tolerance must be finite and >= 1".to_owned(),
+        ));
+    }
+    if !behavior.marker_height_px.is_finite() || behavior.marker_height_px <= 0.0 {
+        return Err(ChartError::InvalidData(
+            "data gap marker_height_px must be finite and > 0".to_owned(),
+        ));
+    }
+    behavior.marker_color.validate()
+}
+
+pub(super) fn validate_bar_countdown_behavior(behavior: BarCountdownBehavior) -> ChartResult<()> {
+    if let Some(interval) = behavior.bar_interval
+        && (!interval.is_finite() || interval <= 0.0)
+    {
+        return Err(ChartError::InvalidData(
+            "bar countdown bar_interval must be finite and > 0".to_owned(),
+        ));
+    }
+    if !behavior.font_size_px.is_finite() || behavior.font_size_px <= 0.0 {
+        return Err(ChartError::InvalidData(
+            "bar countdown font_size_px must be finite and > 0".to_owned(),
+        ));
+    }
+    if !behavior.padding_px.is_finite() || behavior.padding_px < 0.0 {
+        return Err(ChartError::InvalidData(
+            "bar countdown padding_px must be finite and >= 0".to_owned(),
+        ));
+    }
+    behavior.text_color.validate()?;
+    behavior.background_color.validate()
+}
+
+pub(super) fn validate_high_low_marker_behavior(
+    behavior: HighLowMarkerBehavior,
+) -> ChartResult<()> {
+    if !behavior.font_size_px.is_finite() || behavior.font_size_px <= 0.0 {
+        return Err(ChartError::InvalidData(
+            "high/low marker font_size_px must be finite and > 0".to_owned(),
+        ));
+    }
+    if !behavior.offset_px.is_finite() || behavior.offset_px < 0.0 {
+        return Err(ChartError::InvalidData(
+            "high/low marker offset_px must be finite and >= 0".to_owned(),
+        ));
+    }
+    behavior.high_color.validate()?;
+    behavior.low_color.validate()
+}
+
+pub(super) fn validate_last_value_animation_behavior(
+    behavior: LastValueAnimationBehavior,
+) -> ChartResult<()> {
+    if !behavior.duration_seconds.is_finite() || behavior.duration_seconds <= 0.0 {
+        return Err(ChartError::InvalidData(
+            "last-value animation duration_seconds must be finite and > 0".to_owned(),
+        ));
+    }
+    Ok(())
+}
+
+pub(super) fn validate_visible_range_change_behavior(
+    behavior: VisibleRangeChangeBehavior,
+) -> ChartResult<()> {
+    if let VisibleRangeChangeDelivery::Debounced { quiet_seconds } = behavior.delivery
+        && (!quiet_seconds.is_finite() || quiet_seconds <= 0.0)
+    {
+        return Err(ChartError::InvalidData(
+            "visible-range change debounce quiet_seconds must be finite and > 0".to_owned(),
+        ));
+    }
+    Ok(())
+}
+
+pub(super) fn validate_render_style(style: &RenderStyle) -> ChartResult<()> {
     style.series_line_color.validate()?;
-    style.grid_line_color.validate()?;
-    style.price_axis_grid_line_color.validate()?;
-    style.major_grid_line_color.validate()?;
+    for color in [
+        style.background_color,
+        style.plot_background_color,
+        style.price_axis_background_color,
+        style.time_axis_background_color,
+    ]
+    .into_iter()
+    .flatten()
+    {
+        color.validate()?;
+    }
+    if let Some(shadow) = style.axis_background_shadow {
+        shadow.color.validate()?;
+        if !shadow.width_px.is_finite() || shadow.width_px <= 0.0 {
+            return Err(ChartError::InvalidData(
+                "axis background shadow width must be finite and > 0".to_owned(),
+            ));
+        }
+    }
+    if let Some(tiers) = style.candlestick_detail_tiers {
+        for (name, value) in [
+            ("filled_rect_below_px", tiers.filled_rect_below_px),
+            ("no_border_below_px", tiers.no_border_below_px),
+        ] {
+            if !value.is_finite() || value < 0.0 {
+                return Err(ChartError::InvalidData(format!(
+                    "candlestick detail tier {name} must be finite and >= 0"
+                )));
+            }
+        }
+        if tiers.filled_rect_below_px > tiers.no_border_below_px {
+            return Err(ChartError::InvalidData(
+                "candlestick detail tier filled_rect_below_px must be <= no_border_below_px"
+                    .to_owned(),
+            ));
+        }
+    }
+    if let Some(marker) = style.crosshair_snap_marker {
+        marker.fill_color.validate()?;
+        marker.border_color.validate()?;
+        if !marker.radius_px.is_finite() || marker.radius_px <= 0.0 {
+            return Err(ChartError::InvalidData(
+                "crosshair snap marker radius must be finite and > 0".to_owned(),
+            ));
+        }
+        if !marker.border_width_px.is_finite() || marker.border_width_px < 0.0 {
+            return Err(ChartError::InvalidData(
+                "crosshair snap marker border width must be finite and >= 0".to_owned(),
+            ));
+        }
+    }
+    style.background_band_color.validate()?;
+    for series_style in [
+        style.point_series_last_value,
+        style.candle_series_last_value,
+    ]
+    .into_iter()
+    .flatten()
+    {
+        series_style.color.validate()?;
+    }
+    style.grid.vert_lines.color.validate()?;
+    style.grid.major_vert_lines.color.validate()?;
+    style.grid.horz_lines.color.validate()?;
+    style.grid.minor_horz_lines.color.validate()?;
+    style.grid.minor_vert_lines.color.validate()?;
     style.axis_border_color.validate()?;
     style.price_axis_tick_mark_color.validate()?;
     style.time_axis_tick_mark_color.validate()?;
@@ -164,12 +490,20 @@ pub(super) fn validate_render_style(style: RenderStyle) -> ChartResult<RenderSty
     style.last_price_label_box_border_color.validate()?;
 
     for (name, value) in [
-        ("grid_line_width", style.grid_line_width),
+        ("grid.vert_lines.width", style.grid.vert_lines.width),
+        (
+            "grid.major_vert_lines.width",
+            style.grid.major_vert_lines.width,
+        ),
+        ("grid.horz_lines.width", style.grid.horz_lines.width),
+        (
+            "grid.minor_horz_lines.width",
+            style.grid.minor_horz_lines.width,
+        ),
         (
-            "price_axis_grid_line_width",
-            style.price_axis_grid_line_width,
+            "grid.minor_vert_lines.width",
+            style.grid.minor_vert_lines.width,
         ),
-        ("major_grid_line_width", style.major_grid_line_width),
         ("axis_line_width", style.axis_line_width),
         (
             "price_axis_tick_mark_width",
@@ -238,6 +572,29 @@ pub(super) fn validate_render_style(style: RenderStyle) -> ChartResult<RenderSty
             ));
         }
     }
+    if let PriceAxisWidthMode::Auto {
+        min_width_px,
+        max_width_px,
+        hysteresis_px,
+    } = style.price_axis_width_mode
+    {
+        if !min_width_px.is_finite() || min_width_px <= 0.0 {
+            return Err(ChartError::InvalidData(
+                "price-axis auto width `min_width_px` must be finite and > 0".to_owned(),
+            ));
+        }
+        if !max_width_px.is_finite() || max_width_px < min_width_px {
+            return Err(ChartError::InvalidData(
+                "price-axis auto width `max_width_px` must be finite and >= `min_width_px`"
+                    .to_owned(),
+            ));
+        }
+        if !hysteresis_px.is_finite() || hysteresis_px < 0.0 {
+            return Err(ChartError::InvalidData(
+                "price-axis auto width `hysteresis_px` must be finite and >= 0".to_owned(),
+            ));
+        }
+    }
     if !style.price_axis_label_padding_right_px.is_finite()
         || style.price_axis_label_padding_right_px < 0.0
     {
@@ -250,6 +607,11 @@ pub(super) fn validate_render_style(style: RenderStyle) -> ChartResult<RenderSty
             "render style `time_axis_label_offset_y_px` must be finite and >= 0".to_owned(),
         ));
     }
+    if !(0.0..=90.0).contains(&style.time_axis_label_rotation_degrees) {
+        return Err(ChartError::InvalidData(
+            "render style `time_axis_label_rotation_degrees` must be in [0, 90]".to_owned(),
+        ));
+    }
     if !style.crosshair_time_label_padding_x_px.is_finite()
         || style.crosshair_time_label_padding_x_px < 0.0
     {
@@ -536,5 +898,5 @@ pub(super) fn validate_render_style(style: RenderStyle) -> ChartResult<RenderSty
                 .to_owned(),
         ));
     }
-    Ok(style)
+    Ok(())
 }
@@ -0,0 +1,121 @@
+use serde::{Deserialize, Serialize};
+
+use crate::error::{ChartError, ChartResult};
+use crate::render::Renderer;
+
+use super::navigation_history_controller::ViewCheckpointKind;
+use super::{ChartEngine, NavigationViewState};
+
+/// Named view an analyst can jump back to, e.g. `earnings-week`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ViewBookmark {
+    pub name: String,
+    pub view: NavigationViewState,
+    /// Crosshair time shown when the bookmark was saved, re-pinned on restore.
+    #[serde(default)]
+    pub crosshair_time: Option<f64>,
+}
+
+impl ViewBookmark {
+    fn validate(&self) -> ChartResult<()> {
+        if self.name.trim().is_empty() {
+            return Err(ChartError::InvalidData(
+                "view bookmark name must not be empty".to_owned(),
+            ));
+        }
+        let (start, end) = self.view.time_visible_range;
+        let (price_min, price_max) = self.view.price_domain;
+        let finite = [start, end, price_min, price_max]
+            .into_iter()
+            .chain(self.crosshair_time)
+            .all(f64::is_finite);
+        if !finite || start >= end || price_min == price_max {
+            return Err(ChartError::InvalidData(format!(
+                "view bookmark `{}` must have finite, non-empty ranges",
+                self.name
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl<R: Renderer> ChartEngine<R> {
+    /// Bookmarks in save order.
+    #[must_use]
+    pub fn view_bookmarks(&self) -> &[ViewBookmark] {
+        &self.core.model.view_bookmarks
+    }
+
+    #[must_use]
+    pub fn view_bookmark(&self, name: &str) -> Option<&ViewBookmark> {
+        self.core
+            .model
+            .view_bookmarks
+            .iter()
+            .find(|bookmark| bookmark.name == name)
+    }
+
+    /// Replaces all bookmarks; names must be unique.
+    pub fn set_view_bookmarks(&mut self, bookmarks: Vec<ViewBookmark>) -> ChartResult<()> {
+        for (index, bookmark) in bookmarks.iter().enumerate() {
+            bookmark.validate()?;
+            if bookmarks[..index]
+                .iter()
+                .any(|other| other.name == bookmark.name)
+            {
+                return Err(ChartError::InvalidData(format!(
+                    "duplicate view bookmark `{}`",
+                    bookmark.name
+                )));
+            }
+        }
+        self.core.model.view_bookmarks = bookmarks;
+        Ok(())
+    }
+
+    /// Saves the current view and crosshair time under `name`, overwriting
+    /// an existing bookmark in place.
+    pub fn save_view(&mut self, name: impl Into<String>) -> ChartResult<()> {
+        let bookmark = ViewBookmark {
+            name: name.into(),
+            view: self.navigation_view_state(),
+            crosshair_time: self.crosshair_time(),
+        };
+        bookmark.validate()?;
+        let bookmarks = &mut self.core.model.view_bookmarks;
+        match bookmarks
+            .iter_mut()
+            .find(|existing| existing.name == bookmark.name)
+        {
+            Some(existing) => *existing = bookmark,
+            None => bookmarks.push(bookmark),
+        }
+        Ok(())
+    }
+
+    /// Jumps to the bookmarked view as one undoable step and re-pins its
+    /// crosshair time. A bookmark saved without a crosshair leaves the
+    /// current crosshair alone.
+    ///
+    /// Returns `false` when no bookmark is named `name`.
+    pub fn restore_view(&mut self, name: &str) -> ChartResult<bool> {
+        let Some(bookmark) = self.view_bookmark(name).cloned() else {
+            return Ok(false);
+        };
+        self.checkpoint_view();
+        self.apply_navigation_view(bookmark.view)?;
+        self.checkpoint_navigation_view(ViewCheckpointKind::Discrete);
+        if let Some(time) = bookmark.crosshair_time {
+            self.set_crosshair_time(Some(time))?;
+        }
+        Ok(true)
+    }
+
+    /// Returns whether a bookmark named `name` existed.
+    pub fn remove_view_bookmark(&mut self, name: &str) -> bool {
+        let bookmarks = &mut self.core.model.view_bookmarks;
+        let before = bookmarks.len();
+        bookmarks.retain(|bookmark| bookmark.name != name);
+        bookmarks.len() != before
+    }
+}
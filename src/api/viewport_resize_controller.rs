@@ -0,0 +1,57 @@
+use serde::{Deserialize, Serialize};
+
+use crate::core::Viewport;
+use crate::error::ChartResult;
+use crate::render::Renderer;
+
+use super::ChartEngine;
+
+/// How the visible time range follows a viewport width change in
+/// `ChartEngine::resize_viewport`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ViewportResizePolicy {
+    /// Same as `set_viewport`: the configured `TimeScaleResizeBehavior` and
+    /// navigation behavior decide.
+    #[default]
+    EngineBehavior,
+    /// Keeps the visible time range; bars stretch or shrink with the width.
+    PreserveVisibleSpan,
+    /// Keeps time per pixel with the left edge fixed, revealing or hiding
+    /// bars on the right.
+    PreserveBarSpacing,
+    /// Keeps time per pixel with the right edge fixed, so the gap between
+    /// the latest bar and the price axis stays the same.
+    PreserveRightOffset,
+}
+
+impl<R: Renderer> ChartEngine<R> {
+    /// Updates the viewport and re-derives the visible time range according
+    /// to `policy`. Height-only changes leave the time range untouched for
+    /// every policy except `EngineBehavior`.
+    pub fn resize_viewport(
+        &mut self,
+        viewport: Viewport,
+        policy: ViewportResizePolicy,
+    ) -> ChartResult<()> {
+        let previous_width = f64::from(self.core.model.viewport.width);
+        let (start, end) = self.core.model.time_scale.visible_range();
+        self.set_viewport(viewport)?;
+
+        let width = f64::from(self.core.model.viewport.width);
+        if policy == ViewportResizePolicy::EngineBehavior
+            || previous_width <= 0.0
+            || (width - previous_width).abs() <= f64::EPSILON
+        {
+            return Ok(());
+        }
+        let resized_span = (end - start) * width / previous_width;
+        let (target_start, target_end) = match policy {
+            ViewportResizePolicy::EngineBehavior | ViewportResizePolicy::PreserveVisibleSpan => {
+                (start, end)
+            }
+            ViewportResizePolicy::PreserveBarSpacing => (start, start + resized_span),
+            ViewportResizePolicy::PreserveRightOffset => (end - resized_span, end),
+        };
+        self.set_time_visible_range(target_start, target_end)
+    }
+}
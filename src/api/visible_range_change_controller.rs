@@ -0,0 +1,168 @@
+use crate::error::ChartResult;
+use crate::extensions::{PluginEvent, VisibleRangeChange, VisibleRangeChangeCause};
+use crate::render::Renderer;
+
+use super::validation::validate_visible_range_change_behavior;
+use super::{ChartEngine, VisibleRangeChangeBehavior, VisibleRangeChangeDelivery};
+
+pub(super) type VisibleRangeChangeCallback = Box<dyn FnMut(&VisibleRangeChange)>;
+
+/// Coalesced change waiting for its debounce quiet time or the next frame.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(super) struct PendingVisibleRangeChange {
+    change: VisibleRangeChange,
+    quiet_seconds: f64,
+}
+
+impl<R: Renderer> ChartEngine<R> {
+    #[must_use]
+    pub fn visible_range_change_behavior(&self) -> VisibleRangeChangeBehavior {
+        self.core.behavior.visible_range_change_behavior
+    }
+
+    /// Switching to `Immediate` delivers a pending coalesced change at once.
+    pub fn set_visible_range_change_behavior(
+        &mut self,
+        behavior: VisibleRangeChangeBehavior,
+    ) -> ChartResult<()> {
+        validate_visible_range_change_behavior(behavior)?;
+        self.core.behavior.visible_range_change_behavior = behavior;
+        if behavior.delivery == VisibleRangeChangeDelivery::Immediate {
+            self.flush_visible_range_change();
+        }
+        Ok(())
+    }
+
+    /// Registers a host callback for visible time range changes, delivered
+    /// with the same timing as `PluginEvent::VisibleRangeChanged`. Returns
+    /// the id for `unsubscribe_visible_time_range_change`.
+    pub fn subscribe_visible_time_range_change(
+        &mut self,
+        callback: impl FnMut(&VisibleRangeChange) + 'static,
+    ) -> u64 {
+        let id = self.core.runtime.next_visible_range_subscription_id;
+        self.core.runtime.next_visible_range_subscription_id += 1;
+        self.core
+            .runtime
+            .visible_range_subscriptions
+            .push((id, Box::new(callback)));
+        id
+    }
+
+    /// Returns `true` when a subscription was removed.
+    pub fn unsubscribe_visible_time_range_change(&mut self, id: u64) -> bool {
+        let subscriptions = &mut self.core.runtime.visible_range_subscriptions;
+        let before = subscriptions.len();
+        subscriptions.retain(|(subscription_id, _)| *subscription_id != id);
+        subscriptions.len() != before
+    }
+
+    #[must_use]
+    pub fn visible_range_change_pending(&self) -> bool {
+        self.core.runtime.pending_visible_range_change.is_some()
+    }
+
+    /// Delivers a coalesced change now instead of waiting. Returns `true`
+    /// when a notification was sent.
+    pub fn flush_visible_range_change(&mut self) -> bool {
+        let Some(pending) = self.core.runtime.pending_visible_range_change.take() else {
+            return false;
+        };
+        if pending.change.previous == pending.change.current {
+            return false;
+        }
+        self.deliver_visible_range_change(pending.change);
+        true
+    }
+
+    /// Runs `apply` with visible-range changes attributed to `cause`.
+    pub(super) fn with_visible_range_change_cause<T>(
+        &mut self,
+        cause: VisibleRangeChangeCause,
+        apply: impl FnOnce(&mut Self) -> T,
+    ) -> T {
+        let outer = std::mem::replace(&mut self.core.runtime.visible_range_change_cause, cause);
+        let result = apply(self);
+        self.core.runtime.visible_range_change_cause = outer;
+        result
+    }
+
+    /// Records a visible-range change and delivers or coalesces it.
+    pub(super) fn notify_visible_range_changed(&mut self) {
+        let current = self.core.model.time_scale.visible_range();
+        let previous = self
+            .core
+            .runtime
+            .reported_visible_range
+            .replace(current)
+            .unwrap_or(current);
+        let cause = self.core.runtime.visible_range_change_cause;
+        if self.core.behavior.visible_range_change_behavior.delivery
+            == VisibleRangeChangeDelivery::Immediate
+        {
+            self.deliver_visible_range_change(VisibleRangeChange {
+                previous,
+                current,
+                cause,
+            });
+            return;
+        }
+        let previous = self
+            .core
+            .runtime
+            .pending_visible_range_change
+            .map_or(previous, |pending| pending.change.previous);
+        self.core.runtime.pending_visible_range_change = Some(PendingVisibleRangeChange {
+            change: VisibleRangeChange {
+                previous,
+                current,
+                cause,
+            },
+            quiet_seconds: 0.0,
+        });
+    }
+
+    /// Whether a debounced change still needs `step_animations` time.
+    pub(super) fn visible_range_change_debounce_active(&self) -> bool {
+        matches!(
+            self.core.behavior.visible_range_change_behavior.delivery,
+            VisibleRangeChangeDelivery::Debounced { .. }
+        ) && self.visible_range_change_pending()
+    }
+
+    pub(super) fn step_visible_range_change_debounce(&mut self, delta_seconds: f64) {
+        let VisibleRangeChangeDelivery::Debounced { quiet_seconds } =
+            self.core.behavior.visible_range_change_behavior.delivery
+        else {
+            return;
+        };
+        let Some(pending) = self.core.runtime.pending_visible_range_change.as_mut() else {
+            return;
+        };
+        pending.quiet_seconds += delta_seconds;
+        if pending.quiet_seconds >= quiet_seconds {
+            self.flush_visible_range_change();
+        }
+    }
+
+    pub(super) fn flush_per_frame_visible_range_change(&mut self) {
+        if self.core.behavior.visible_range_change_behavior.delivery
+            == VisibleRangeChangeDelivery::PerFrame
+        {
+            self.flush_visible_range_change();
+        }
+    }
+
+    fn deliver_visible_range_change(&mut self, change: VisibleRangeChange) {
+        self.emit_plugin_event(PluginEvent::VisibleRangeChanged {
+            start: change.current.0,
+            end: change.current.1,
+            previous_start: change.previous.0,
+            previous_end: change.previous.1,
+            cause: change.cause,
+        });
+        for (_, callback) in &mut self.core.runtime.visible_range_subscriptions {
+            callback(&change);
+        }
+    }
+}
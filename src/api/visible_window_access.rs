@@ -1,4 +1,9 @@
-use crate::core::{DataPoint, OhlcBar, candles_in_time_window, points_in_time_window};
+use std::ops::Range;
+
+use crate::core::{
+    DataPoint, OhlcBar, candles_in_sorted_time_window, candles_time_window_range,
+    points_in_sorted_time_window, points_time_window_range,
+};
 use crate::error::ChartResult;
 use crate::render::Renderer;
 
@@ -9,28 +14,75 @@ impl<R: Renderer> ChartEngine<R> {
     /// Returns point samples currently inside the visible time window.
     #[must_use]
     pub fn visible_points(&self) -> Vec<DataPoint> {
-        let (start, end) = self.core.model.time_scale.visible_range();
-        points_in_time_window(&self.core.model.points, start, end)
+        self.visible_points_slice().to_vec()
     }
 
     /// Returns candle samples currently inside the visible time window.
     #[must_use]
     pub fn visible_candles(&self) -> Vec<OhlcBar> {
+        self.visible_candles_slice().to_vec()
+    }
+
+    /// Borrows the points inside the visible time window without copying.
+    ///
+    /// Series are kept sorted by time (set paths sort, append and update
+    /// paths reject older samples), so the window is one contiguous slice.
+    #[must_use]
+    pub fn visible_points_slice(&self) -> &[DataPoint] {
+        let (start, end) = self.core.model.time_scale.visible_range();
+        points_in_sorted_time_window(&self.core.model.points, start, end)
+    }
+
+    /// Borrows the candles inside the visible time window without copying.
+    #[must_use]
+    pub fn visible_candles_slice(&self) -> &[OhlcBar] {
+        let (start, end) = self.core.model.time_scale.visible_range();
+        candles_in_sorted_time_window(&self.core.model.candles, start, end)
+    }
+
+    /// Index range of the visible points within `points()`.
+    #[must_use]
+    pub fn visible_points_range(&self) -> Range<usize> {
         let (start, end) = self.core.model.time_scale.visible_range();
-        candles_in_time_window(&self.core.model.candles, start, end)
+        points_time_window_range(&self.core.model.points, start, end)
+    }
+
+    /// Index range of the visible candles within `candles()`.
+    #[must_use]
+    pub fn visible_candles_range(&self) -> Range<usize> {
+        let (start, end) = self.core.model.time_scale.visible_range();
+        candles_time_window_range(&self.core.model.candles, start, end)
     }
 
     /// Returns visible points with symmetric overscan around the visible window.
     pub fn visible_points_with_overscan(&self, ratio: f64) -> ChartResult<Vec<DataPoint>> {
-        let (start, end) =
-            expand_visible_window(self.core.model.time_scale.visible_range(), ratio)?;
-        Ok(points_in_time_window(&self.core.model.points, start, end))
+        Ok(self.visible_points_with_overscan_slice(ratio)?.to_vec())
     }
 
     /// Returns visible candles with symmetric overscan around the visible window.
     pub fn visible_candles_with_overscan(&self, ratio: f64) -> ChartResult<Vec<OhlcBar>> {
+        Ok(self.visible_candles_with_overscan_slice(ratio)?.to_vec())
+    }
+
+    /// Borrowing counterpart of `visible_points_with_overscan`.
+    pub fn visible_points_with_overscan_slice(&self, ratio: f64) -> ChartResult<&[DataPoint]> {
+        let (start, end) =
+            expand_visible_window(self.core.model.time_scale.visible_range(), ratio)?;
+        Ok(points_in_sorted_time_window(
+            &self.core.model.points,
+            start,
+            end,
+        ))
+    }
+
+    /// Borrowing counterpart of `visible_candles_with_overscan`.
+    pub fn visible_candles_with_overscan_slice(&self, ratio: f64) -> ChartResult<&[OhlcBar]> {
         let (start, end) =
             expand_visible_window(self.core.model.time_scale.visible_range(), ratio)?;
-        Ok(candles_in_time_window(&self.core.model.candles, start, end))
+        Ok(candles_in_sorted_time_window(
+            &self.core.model.candles,
+            start,
+            end,
+        ))
     }
 }
@@ -0,0 +1,113 @@
+use crate::core::{PaneId, PriceScale};
+use crate::error::{ChartError, ChartResult};
+use crate::extensions::{
+    VolumeProfile, VolumeProfileConfig, VolumeSample, compute_volume_profile,
+    project_volume_profile,
+};
+use crate::render::{
+    CanvasLayerKind, ClipRect, LayeredRenderFrame, RectPrimitive, RenderFrame, Renderer,
+};
+
+use super::ChartEngine;
+
+impl<R: Renderer> ChartEngine<R> {
+    #[must_use]
+    pub fn volume_profile_config(&self) -> Option<VolumeProfileConfig> {
+        self.core.behavior.volume_profile_config
+    }
+
+    /// Enables (or with `None` removes) the volume profile overlay.
+    pub fn set_volume_profile_config(
+        &mut self,
+        config: Option<VolumeProfileConfig>,
+    ) -> ChartResult<()> {
+        let config = config.map(VolumeProfileConfig::validate).transpose()?;
+        if self.core.behavior.volume_profile_config != config {
+            self.core.behavior.volume_profile_config = config;
+            self.invalidate_full();
+        }
+        Ok(())
+    }
+
+    #[must_use]
+    pub fn volume_samples(&self) -> &[VolumeSample] {
+        &self.core.model.volume_samples
+    }
+
+    /// Replaces per-candle volumes, matched to candles by exact time.
+    ///
+    /// Without samples the profile counts bars instead of volume.
+    pub fn set_volume_samples(&mut self, mut samples: Vec<VolumeSample>) -> ChartResult<()> {
+        if samples
+            .iter()
+            .any(|sample| !sample.time.is_finite() || !sample.volume.is_finite())
+        {
+            return Err(ChartError::InvalidData(
+                "volume samples must have finite time and volume".to_owned(),
+            ));
+        }
+        if samples.iter().any(|sample| sample.volume < 0.0) {
+            return Err(ChartError::InvalidData(
+                "volume samples must be >= 0".to_owned(),
+            ));
+        }
+        samples.sort_by(|a, b| a.time.total_cmp(&b.time));
+        self.core.model.volume_samples = samples;
+        if self.core.behavior.volume_profile_config.is_some() {
+            self.invalidate_full();
+        }
+        Ok(())
+    }
+
+    /// Profile of the candles in the visible time range, or `None` while the
+    /// overlay is disabled or nothing is visible.
+    pub fn volume_profile(&self) -> ChartResult<Option<VolumeProfile>> {
+        let Some(config) = self.core.behavior.volume_profile_config else {
+            return Ok(None);
+        };
+        compute_volume_profile(
+            self.visible_candles_slice(),
+            &self.core.model.volume_samples,
+            config,
+        )
+    }
+
+    /// Draws profile bars into the candle pane's overlay layer, above the
+    /// series and below the crosshair.
+    pub(super) fn append_volume_profile_primitives(
+        &self,
+        frame: &mut RenderFrame,
+        layered: &mut LayeredRenderFrame,
+        pane_id: PaneId,
+        price_scale: PriceScale,
+        plot_clip: ClipRect,
+    ) -> ChartResult<()> {
+        let Some(config) = self.core.behavior.volume_profile_config else {
+            return Ok(());
+        };
+        let Some(profile) = self.volume_profile()? else {
+            return Ok(());
+        };
+        let bars = project_volume_profile(
+            &profile,
+            price_scale,
+            self.core.model.viewport,
+            plot_clip.width,
+            config,
+        )?;
+        for bar in bars {
+            let color = if bar.bucket == profile.point_of_control {
+                config.point_of_control_color
+            } else if profile.buckets[bar.bucket].in_value_area {
+                config.value_area_color
+            } else {
+                config.outside_color
+            };
+            let rect = RectPrimitive::new(bar.x, bar.y, bar.width, bar.height, color)
+                .with_clip_rect(plot_clip);
+            frame.rects.push(rect);
+            layered.push_rect(pane_id, CanvasLayerKind::Overlay, rect);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,129 @@
+use std::fmt;
+
+use crate::render::Renderer;
+
+use super::{ChartEngine, FramePrimitiveStats};
+
+/// Which data an autoscale call tried to fit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AutoscaleSource {
+    Points,
+    Candles,
+    VisiblePoints,
+    VisibleCandles,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WarningAxis {
+    Time,
+    Price,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WarningLabel {
+    CrosshairTime,
+    CrosshairPrice,
+}
+
+/// Non-fatal condition where the engine degraded instead of failing.
+///
+/// Warnings are reported through the sink installed with
+/// `ChartEngine::set_warning_sink`. Render-time warnings fire for every frame
+/// built while the condition holds.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub enum ChartWarning {
+    /// An autoscale call found no samples and left the price scale unchanged.
+    AutoscaleSkippedEmptyData { source: AutoscaleSource },
+    /// A label box was narrowed to fit its axis panel.
+    LabelBoxClipped {
+        label: WarningLabel,
+        requested_width_px: f64,
+        width_px: f64,
+    },
+    /// Label spacing left fewer than two ticks on an axis, so it no longer
+    /// conveys a scale. Routine thinning of crowded labels is not reported.
+    TicksDropped {
+        axis: WarningAxis,
+        candidates: usize,
+        kept: usize,
+    },
+    /// A built frame exceeded the configured `FrameBudget` at the reduction
+    /// carried over from earlier frames and was rebuilt with thinned ticks
+    /// and coarser series detail; `requested` counts that first build.
+    /// Reported only when the reduction level grows. `rendered` may still be
+    /// over budget when the strongest reduction was not enough.
+    FrameBudgetExceeded {
+        requested: FramePrimitiveStats,
+        rendered: FramePrimitiveStats,
+        reduction_level: u8,
+    },
+}
+
+impl fmt::Display for ChartWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AutoscaleSkippedEmptyData { source } => {
+                write!(f, "autoscale from {source:?} skipped: no samples")
+            }
+            Self::LabelBoxClipped {
+                label,
+                requested_width_px,
+                width_px,
+            } => write!(
+                f,
+                "{label:?} label box clipped from {requested_width_px}px to {width_px}px"
+            ),
+            Self::TicksDropped {
+                axis,
+                candidates,
+                kept,
+            } => write!(
+                f,
+                "{axis:?} axis kept {kept} of {candidates} ticks after spacing"
+            ),
+            Self::FrameBudgetExceeded {
+                requested,
+                rendered,
+                reduction_level,
+            } => write!(
+                f,
+                "frame over budget ({} primitives) reduced to {} at level {reduction_level}",
+                requested.total(),
+                rendered.total()
+            ),
+        }
+    }
+}
+
+pub(super) type WarningSink = Box<dyn FnMut(&ChartWarning)>;
+
+impl<R: Renderer> ChartEngine<R> {
+    /// Installs a callback receiving every `ChartWarning`, replacing any
+    /// previous sink.
+    pub fn set_warning_sink(&mut self, sink: impl FnMut(&ChartWarning) + 'static) {
+        *self.core.runtime.warning_sink.get_mut() = Some(Box::new(sink));
+    }
+
+    pub fn clear_warning_sink(&mut self) {
+        *self.core.runtime.warning_sink.get_mut() = None;
+    }
+
+    #[must_use]
+    pub fn has_warning_sink(&self) -> bool {
+        self.core
+            .runtime
+            .warning_sink
+            .try_borrow()
+            .is_ok_and(|sink| sink.is_some())
+    }
+
+    pub(super) fn emit_warning(&self, warning: ChartWarning) {
+        let Ok(mut sink) = self.core.runtime.warning_sink.try_borrow_mut() else {
+            return;
+        };
+        if let Some(sink) = sink.as_mut() {
+            sink(&warning);
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use crate::error::ChartResult;
+use crate::render::Renderer;
+
+use super::pointer_input_controller::WHEEL_DELTA_PER_NOTCH;
+use super::validation::validate_wheel_behavior_config;
+use super::{ChartEngine, PointerModifiers, WheelAxis, WheelBehaviorConfig};
+
+impl<R: Renderer> ChartEngine<R> {
+    #[must_use]
+    pub fn wheel_behavior_config(&self) -> WheelBehaviorConfig {
+        self.core.behavior.wheel_behavior_config
+    }
+
+    pub fn set_wheel_behavior_config(&mut self, config: WheelBehaviorConfig) -> ChartResult<()> {
+        validate_wheel_behavior_config(config)?;
+        self.core.behavior.wheel_behavior_config = config;
+        Ok(())
+    }
+
+    /// Applies one scroll event through `WheelBehaviorConfig`.
+    ///
+    /// Deltas are in notches like `PointerInput::Scrolled` (positive
+    /// `delta_y` zooms out, positive `delta_x` pans towards later times
+    /// under the default mapping). `pointer` is the content-pixel position
+    /// zoom anchors on; `None` anchors on the viewport center. The minimum
+    /// zoom span comes from the time axis' `AxisInteractionConfig`.
+    pub fn handle_wheel(
+        &mut self,
+        delta_x: f64,
+        delta_y: f64,
+        modifiers: PointerModifiers,
+        pointer: Option<(f64, f64)>,
+    ) -> ChartResult<()> {
+        let config = self.core.behavior.wheel_behavior_config;
+        let direction = if config.invert_direction { -1.0 } else { 1.0 };
+        let zoom_allowed = !config.zoom_requires_control || modifiers.control;
+        for (axis, delta, sensitivity) in [
+            (WheelAxis::Vertical, delta_y, config.vertical_sensitivity),
+            (
+                WheelAxis::Horizontal,
+                delta_x,
+                config.horizontal_sensitivity,
+            ),
+        ] {
+            let wheel_delta = delta * sensitivity * direction * WHEEL_DELTA_PER_NOTCH;
+            if wheel_delta == 0.0 {
+                continue;
+            }
+            if zoom_allowed && config.zoom_axis == Some(axis) {
+                let anchor_px =
+                    pointer.map_or(f64::from(self.content_viewport().width) * 0.5, |(x, _)| x);
+                let min_span = self.core.behavior.axis_interaction_config.time.min_span;
+                self.wheel_zoom_time_visible(
+                    wheel_delta,
+                    anchor_px,
+                    config.zoom_step_ratio,
+                    min_span,
+                )?;
+            } else if config.pan_axis == Some(axis) {
+                self.wheel_pan_time_visible(wheel_delta, config.pan_step_ratio)?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,183 @@
+use crate::core::PaneId;
+use crate::error::ChartResult;
+use crate::extensions::VisibleRangeChangeCause;
+use crate::render::{
+    CanvasLayerKind, ClipRect, LayeredRenderFrame, RectPrimitive, RenderFrame, Renderer,
+};
+
+use super::navigation_history_controller::ViewCheckpointKind;
+use super::validation::validate_zoom_selection_behavior;
+use super::{ChartEngine, PluginEvent, ZoomSelectionBehavior};
+
+/// Rubber-band rectangle of a zoom or data selection drag, in content
+/// pixels clamped to the plot.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ZoomSelection {
+    pub start_x: f64,
+    pub start_y: f64,
+    pub end_x: f64,
+    pub end_y: f64,
+}
+
+impl ZoomSelection {
+    /// `(left, top, width, height)` of the selection rectangle.
+    #[must_use]
+    pub fn rect(self) -> (f64, f64, f64, f64) {
+        let left = self.start_x.min(self.end_x);
+        let top = self.start_y.min(self.end_y);
+        (
+            left,
+            top,
+            (self.end_x - self.start_x).abs(),
+            (self.end_y - self.start_y).abs(),
+        )
+    }
+}
+
+impl<R: Renderer> ChartEngine<R> {
+    #[must_use]
+    pub fn zoom_selection_behavior(&self) -> ZoomSelectionBehavior {
+        self.core.behavior.zoom_selection_behavior
+    }
+
+    pub fn set_zoom_selection_behavior(
+        &mut self,
+        behavior: ZoomSelectionBehavior,
+    ) -> ChartResult<()> {
+        validate_zoom_selection_behavior(behavior)?;
+        if self.core.behavior.zoom_selection_behavior != behavior {
+            self.core.behavior.zoom_selection_behavior = behavior;
+            self.invalidate_cursor();
+        }
+        Ok(())
+    }
+
+    #[must_use]
+    pub fn zoom_selection(&self) -> Option<ZoomSelection> {
+        self.core.runtime.zoom_selection
+    }
+
+    /// Starts a rubber-band selection at content position `(x, y)`,
+    /// replacing (and cancelling) any selection in progress.
+    pub fn begin_zoom_selection(&mut self, x: f64, y: f64) {
+        if self.core.runtime.zoom_selection.is_some() {
+            self.cancel_zoom_selection();
+        }
+        let (x, y) = self.clamp_to_plot(x, y);
+        self.core.runtime.zoom_selection = Some(ZoomSelection {
+            start_x: x,
+            start_y: y,
+            end_x: x,
+            end_y: y,
+        });
+        self.invalidate_cursor();
+        self.emit_plugin_event(PluginEvent::ZoomSelectionStarted { x, y });
+    }
+
+    /// Moves the free corner of the active selection to `(x, y)`.
+    pub fn update_zoom_selection(&mut self, x: f64, y: f64) {
+        let (x, y) = self.clamp_to_plot(x, y);
+        let Some(selection) = self.core.runtime.zoom_selection.as_mut() else {
+            return;
+        };
+        selection.end_x = x;
+        selection.end_y = y;
+        self.invalidate_cursor();
+    }
+
+    /// Applies the active selection: the visible time range (and, with
+    /// `ZoomSelectionBehavior::zoom_price`, the price domain) is set to it.
+    ///
+    /// Selections below `min_size_px` are cancelled. Returns `true` when
+    /// the view zoomed.
+    pub fn end_zoom_selection(&mut self) -> ChartResult<bool> {
+        let Some(selection) = self.core.runtime.zoom_selection.take() else {
+            return Ok(false);
+        };
+        let behavior = self.core.behavior.zoom_selection_behavior;
+        let (left, top, width, height) = selection.rect();
+        let min_size = behavior.min_size_px.max(f64::EPSILON);
+        if width < min_size || (behavior.zoom_price && height < min_size) {
+            self.invalidate_cursor();
+            self.emit_plugin_event(PluginEvent::ZoomSelectionCancelled);
+            return Ok(false);
+        }
+
+        let left_time = self.map_pixel_to_x(left)?;
+        let right_time = self.map_pixel_to_x(left + width)?;
+        let (start, end) = (left_time.min(right_time), left_time.max(right_time));
+        let price_domain = if behavior.zoom_price {
+            let top_price = self.map_pixel_to_price(top)?;
+            let bottom_price = self.map_pixel_to_price(top + height)?;
+            Some((top_price.min(bottom_price), top_price.max(bottom_price)))
+        } else {
+            None
+        };
+
+        self.with_visible_range_change_cause(VisibleRangeChangeCause::Drag, |engine| {
+            engine.set_time_visible_range(start, end)
+        })?;
+        if let Some((price_min, price_max)) = price_domain {
+            self.rebuild_price_scale_from_domain_preserving_mode(price_min, price_max)?;
+        }
+        self.checkpoint_navigation_view(ViewCheckpointKind::Discrete);
+        let (start, end) = self.time_visible_range();
+        self.emit_plugin_event(PluginEvent::ZoomSelectionCompleted {
+            start,
+            end,
+            price_domain: price_domain.map(|_| self.price_domain()),
+        });
+        Ok(true)
+    }
+
+    /// Drops the active selection without zooming.
+    pub fn cancel_zoom_selection(&mut self) {
+        if self.core.runtime.zoom_selection.take().is_some() {
+            self.invalidate_cursor();
+            self.emit_plugin_event(PluginEvent::ZoomSelectionCancelled);
+        }
+    }
+
+    pub(super) fn clamp_to_plot(&self, x: f64, y: f64) -> (f64, f64) {
+        let viewport = self.core.model.viewport;
+        let plot_right = f64::from(viewport.width) - self.layout_price_axis_width_px();
+        let plot_bottom =
+            f64::from(viewport.height) - self.core.presentation.render_style.time_axis_height_px;
+        (
+            x.clamp(0.0, plot_right.max(0.0)),
+            y.clamp(0.0, plot_bottom.max(0.0)),
+        )
+    }
+
+    /// Draws the selection into the main pane's crosshair layer so
+    /// pointer-driven updates only repaint the cursor layer. Time-only
+    /// selections are drawn as a full-height band.
+    pub(super) fn append_zoom_selection_primitives(
+        &self,
+        frame: &mut RenderFrame,
+        layered: &mut LayeredRenderFrame,
+        main_pane_id: PaneId,
+        plot_bottom: f64,
+        plot_clip: ClipRect,
+    ) {
+        let Some(selection) = self.core.runtime.zoom_selection else {
+            return;
+        };
+        let behavior = self.core.behavior.zoom_selection_behavior;
+        let (left, mut top, width, mut height) = selection.rect();
+        if !behavior.zoom_price {
+            top = 0.0;
+            height = plot_bottom;
+        }
+        if width <= 0.0 || height <= 0.0 {
+            return;
+        }
+        let mut rect = RectPrimitive::new(left, top, width, height, behavior.fill_color)
+            .with_clip_rect(plot_clip);
+        if behavior.border_width_px > 0.0 {
+            rect = rect.with_border(behavior.border_width_px, behavior.border_color);
+        }
+        frame.rects.push(rect);
+        layered.push_rect(main_pane_id, CanvasLayerKind::Crosshair, rect);
+    }
+}
@@ -0,0 +1,346 @@
+//! Grid of canned chart scenarios used as a manual QA surface.
+//!
+//! Every scenario is built through public engine APIs only, so a new
+//! rendering feature gets a tile by adding one builder to `scenarios`.
+
+#![cfg_attr(not(feature = "gtk4-adapter"), allow(dead_code))]
+
+use chart_rs::ChartResult;
+use chart_rs::api::{
+    ChartEngine, ChartEngineConfig, CompareSeries, HighLowMarkerBehavior, RenderStyle,
+    TimeScaleMark,
+};
+use chart_rs::core::{DataPoint, OhlcBar, PriceScaleMode, Viewport, project_baseline_geometry};
+use chart_rs::data::sim::{RandomWalkConfig, simulate_candles};
+use chart_rs::extensions::{CustomSeries, CustomSeriesPrimitives, CustomSeriesRenderContext};
+use chart_rs::render::{Color, PathPrimitive, Renderer};
+
+const TILE_WIDTH: u32 = 440;
+const TILE_HEIGHT: u32 = 300;
+const GRID_COLUMNS: i32 = 3;
+const BAR_INTERVAL: f64 = 3_600.0;
+const BAR_COUNT: usize = 240;
+
+struct GalleryScenario<R: Renderer> {
+    title: &'static str,
+    build: fn(R) -> ChartResult<ChartEngine<R>>,
+}
+
+fn scenarios<R: Renderer>() -> [GalleryScenario<R>; 6] {
+    [
+        GalleryScenario {
+            title: "Candles + volume pane",
+            build: build_candles_with_volume,
+        },
+        GalleryScenario {
+            title: "Log price scale",
+            build: build_log_scale,
+        },
+        GalleryScenario {
+            title: "Percentage compare",
+            build: build_percentage_compare,
+        },
+        GalleryScenario {
+            title: "Time marks + high/low markers",
+            build: build_markers,
+        },
+        GalleryScenario {
+            title: "Baseline (custom series)",
+            build: build_baseline,
+        },
+        GalleryScenario {
+            title: "Dark theme",
+            build: build_dark_theme,
+        },
+    ]
+}
+
+fn sim_candles(seed: u64, drift: f64) -> ChartResult<Vec<OhlcBar>> {
+    simulate_candles(
+        RandomWalkConfig::default()
+            .with_seed(seed)
+            .with_tick_interval(60.0)
+            .with_volatility(0.002)
+            .with_drift(drift),
+        BAR_INTERVAL,
+        BAR_COUNT,
+    )
+}
+
+fn closes(candles: &[OhlcBar]) -> Vec<DataPoint> {
+    candles
+        .iter()
+        .map(|bar| DataPoint::new(bar.time, bar.close))
+        .collect()
+}
+
+fn engine_for<R: Renderer>(renderer: R, candles: &[OhlcBar]) -> ChartResult<ChartEngine<R>> {
+    let (first, last) = match (candles.first(), candles.last()) {
+        (Some(first), Some(last)) => (first.time, last.time),
+        _ => (0.0, 1.0),
+    };
+    // Seed the price domain from the data so every scale mode starts valid.
+    let (low, high) = candles
+        .iter()
+        .fold((f64::MAX, f64::MIN), |(low, high), bar| {
+            (low.min(bar.low), high.max(bar.high))
+        });
+    let config = ChartEngineConfig::new(Viewport::new(TILE_WIDTH, TILE_HEIGHT), first, last)
+        .with_price_domain(low, high);
+    ChartEngine::new(renderer, config)
+}
+
+fn build_candles_with_volume<R: Renderer>(renderer: R) -> ChartResult<ChartEngine<R>> {
+    let candles = sim_candles(1, 0.0)?;
+    let volumes = candles
+        .iter()
+        .map(|bar| {
+            let range = (bar.high - bar.low) / bar.close;
+            DataPoint::new(bar.time, 1_000.0 + range * 250_000.0)
+        })
+        .collect();
+    let mut engine = engine_for(renderer, &candles)?;
+    let volume_pane = engine.create_pane(0.3)?;
+    engine.set_points_pane(volume_pane)?;
+    engine.set_candles(candles);
+    engine.set_data(volumes);
+    engine.autoscale_price_from_candles()?;
+    Ok(engine)
+}
+
+fn build_log_scale<R: Renderer>(renderer: R) -> ChartResult<ChartEngine<R>> {
+    let candles = sim_candles(2, 0.0002)?;
+    let mut engine = engine_for(renderer, &candles)?;
+    // Switch before loading data: the linear autoscale margin can dip below zero.
+    engine.set_price_scale_mode(PriceScaleMode::Log)?;
+    engine.set_candles(candles);
+    engine.autoscale_price_from_candles()?;
+    Ok(engine)
+}
+
+fn build_percentage_compare<R: Renderer>(renderer: R) -> ChartResult<ChartEngine<R>> {
+    let primary = sim_candles(3, 0.00005)?;
+    let mut engine = engine_for(renderer, &primary)?;
+    engine.set_data(closes(&primary));
+    engine.add_compare_series(CompareSeries::new(
+        "peer-a",
+        closes(&sim_candles(4, 0.0001)?),
+        Color::rgb(0.95, 0.55, 0.10),
+    ))?;
+    engine.add_compare_series(CompareSeries::new(
+        "peer-b",
+        closes(&sim_candles(5, -0.00005)?),
+        Color::rgb(0.55, 0.30, 0.85),
+    ))?;
+    engine.set_compare_mode_enabled(true);
+    engine.autoscale_price_from_data()?;
+    Ok(engine)
+}
+
+fn build_markers<R: Renderer>(renderer: R) -> ChartResult<ChartEngine<R>> {
+    let candles = sim_candles(6, 0.0)?;
+    let mut engine = engine_for(renderer, &candles)?;
+    for (index, label) in [(40, "E"), (120, "D"), (200, "S")] {
+        engine.add_time_scale_mark(TimeScaleMark::new(
+            format!("mark-{index}"),
+            candles[index].time,
+            label,
+        ))?;
+    }
+    engine.set_candles(candles);
+    engine.set_high_low_marker_behavior(HighLowMarkerBehavior {
+        visible: true,
+        ..HighLowMarkerBehavior::default()
+    })?;
+    engine.autoscale_price_from_candles()?;
+    Ok(engine)
+}
+
+/// Line split into green/red fills above/below a fixed price.
+struct BaselineSeries {
+    baseline_price: f64,
+}
+
+impl CustomSeries for BaselineSeries {
+    type Row = DataPoint;
+
+    fn row_time(&self, row: &DataPoint) -> f64 {
+        row.x
+    }
+
+    fn autoscale_range(&self, rows: &[DataPoint]) -> Option<(f64, f64)> {
+        rows.iter().fold(None, |range, row| {
+            let (min, max) = range.unwrap_or((self.baseline_price, self.baseline_price));
+            Some((min.min(row.y), max.max(row.y)))
+        })
+    }
+
+    fn render(
+        &self,
+        rows: &[DataPoint],
+        ctx: &CustomSeriesRenderContext,
+        out: &mut CustomSeriesPrimitives,
+    ) -> ChartResult<()> {
+        let geometry = project_baseline_geometry(
+            rows,
+            ctx.time_scale,
+            ctx.price_scale,
+            ctx.viewport,
+            self.baseline_price,
+        )?;
+        out.paths.extend(PathPrimitive::from_baseline_geometry(
+            &geometry,
+            2.0,
+            Color::rgb(0.16, 0.38, 1.0),
+            Color::rgba(0.15, 0.65, 0.60, 0.25),
+            Color::rgba(0.94, 0.33, 0.31, 0.25),
+        ));
+        Ok(())
+    }
+}
+
+fn build_baseline<R: Renderer>(renderer: R) -> ChartResult<ChartEngine<R>> {
+    let candles = sim_candles(7, 0.0)?;
+    let baseline_price = candles[0].close;
+    let mut engine = engine_for(renderer, &candles)?;
+    let main_pane = engine.main_pane_id();
+    engine.add_custom_series(
+        "baseline",
+        main_pane,
+        BaselineSeries { baseline_price },
+        closes(&candles),
+    )?;
+    Ok(engine)
+}
+
+fn build_dark_theme<R: Renderer>(renderer: R) -> ChartResult<ChartEngine<R>> {
+    let candles = sim_candles(8, 0.0)?;
+    let mut engine = engine_for(renderer, &candles)?;
+    engine.set_candles(candles);
+    engine.autoscale_price_from_candles()?;
+
+    let text = Color::rgb(0.82, 0.84, 0.88);
+    let grid = Color::rgb(0.17, 0.19, 0.24);
+    let mut style = RenderStyle {
+        background_color: Some(Color::rgb(0.08, 0.09, 0.12)),
+        axis_border_color: grid,
+        axis_label_color: text,
+        time_axis_label_color: text,
+        major_time_label_color: text,
+        price_axis_tick_mark_color: grid,
+        time_axis_tick_mark_color: grid,
+        major_time_tick_mark_color: grid,
+        ..engine.render_style()
+    };
+    style.grid.vert_lines.color = grid;
+    style.grid.major_vert_lines.color = grid;
+    style.grid.horz_lines.color = grid;
+    engine.set_render_style(style)?;
+    Ok(engine)
+}
+
+#[cfg(feature = "gtk4-adapter")]
+fn main() {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use gtk4 as gtk;
+    use gtk4::prelude::*;
+
+    use chart_rs::platform_gtk::{GtkChartAdapter, InteractionOptions};
+    use chart_rs::render::CairoRenderer;
+
+    let app = gtk::Application::builder()
+        .application_id("rs.chart.gallery")
+        .build();
+
+    app.connect_activate(|app| {
+        let grid = gtk::Grid::builder()
+            .row_spacing(12)
+            .column_spacing(12)
+            .margin_top(12)
+            .margin_bottom(12)
+            .margin_start(12)
+            .margin_end(12)
+            .build();
+        let mut adapters = Vec::new();
+
+        for (index, scenario) in scenarios::<CairoRenderer>().into_iter().enumerate() {
+            let engine =
+                CairoRenderer::new(TILE_WIDTH as i32, TILE_HEIGHT as i32).and_then(scenario.build);
+            let tile = gtk::Box::new(gtk::Orientation::Vertical, 4);
+            let title = gtk::Label::new(Some(scenario.title));
+            title.set_xalign(0.0);
+            tile.append(&title);
+            match engine {
+                Ok(engine) => {
+                    let adapter = Rc::new(GtkChartAdapter::new(engine));
+                    adapter.enable_default_interactions(InteractionOptions::default());
+                    let area = adapter.drawing_area();
+                    area.set_size_request(TILE_WIDTH as i32, TILE_HEIGHT as i32);
+                    area.set_hexpand(true);
+                    area.set_vexpand(true);
+                    tile.append(area);
+                    adapters.push(adapter);
+                }
+                Err(err) => {
+                    eprintln!("scenario `{}` failed: {err}", scenario.title);
+                    tile.append(&gtk::Label::new(Some(&format!("failed: {err}"))));
+                }
+            }
+            let index = index as i32;
+            grid.attach(&tile, index % GRID_COLUMNS, index / GRID_COLUMNS, 1, 1);
+        }
+
+        let window = gtk::ApplicationWindow::builder()
+            .application(app)
+            .title("chart-rs | scenario gallery")
+            .default_width(1400)
+            .default_height(720)
+            .build();
+        window.set_child(Some(&grid));
+        // Adapters own the interaction controllers; keep them alive with the window.
+        let adapters = RefCell::new(adapters);
+        window.connect_close_request(move |_| {
+            adapters.borrow_mut().clear();
+            gtk::glib::Propagation::Proceed
+        });
+        window.present();
+    });
+
+    let _ = app.run();
+}
+
+#[cfg(not(feature = "gtk4-adapter"))]
+fn main() {
+    println!("run with: cargo run --features desktop --bin chart_gallery");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chart_rs::render::NullRenderer;
+
+    #[test]
+    fn every_scenario_builds_a_frame() {
+        for scenario in scenarios::<NullRenderer>() {
+            let engine = (scenario.build)(NullRenderer::default())
+                .unwrap_or_else(|err| panic!("`{}` failed: {err}", scenario.title));
+            let frame = engine
+                .build_render_frame()
+                .unwrap_or_else(|err| panic!("`{}` frame failed: {err}", scenario.title));
+            assert!(
+                !frame.lines.is_empty() || !frame.paths.is_empty() || !frame.rects.is_empty(),
+                "`{}` rendered nothing",
+                scenario.title
+            );
+        }
+    }
+
+    #[test]
+    fn baseline_scenario_draws_fills_and_line() {
+        let engine = build_baseline(NullRenderer::default()).expect("baseline");
+        let frame = engine.build_render_frame().expect("frame");
+        assert!(frame.paths.len() >= 3);
+    }
+}
@@ -1,15 +1,20 @@
 use chart_rs::api::{
-    ChartEngine, ChartEngineConfig, PriceAxisLabelConfig, PriceScaleRealtimeBehavior,
-    PriceScaleTransformedBaseBehavior, TimeAxisLabelConfig, TimeScaleNavigationBehavior,
-    TimeScaleScrollZoomBehavior,
+    ChartEngine, ChartEngineConfig, CrosshairAxisLabelVisibilityBehavior, PriceAxisLabelConfig,
+    PriceScaleRealtimeBehavior, PriceScaleTransformedBaseBehavior, RenderStyle,
+    TimeAxisLabelConfig, TimeScaleNavigationBehavior, TimeScaleScrollZoomBehavior,
 };
 use chart_rs::core::{DataPoint, OhlcBar, PriceScaleMode, Viewport};
-use chart_rs::interaction::CrosshairMode;
-use chart_rs::render::NullRenderer;
+use chart_rs::render::{Color, NullRenderer, TextHAlign};
+use chart_rs::testing::trace::{
+    InteractionAction, InteractionExpectation, InteractionScenario, InteractionStep,
+    InteractionTrace, TraceCrosshairMode, TracePoint, TracePriceRange, TraceTimeRange,
+    TraceViewport,
+};
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum CommandKind {
@@ -19,15 +24,103 @@ enum CommandKind {
     ImportPrice,
     ExportInteraction,
     ImportInteraction,
+    ExportLabels,
+    ImportLabels,
     ImportLwcInteraction,
     ImportLwcVisual,
 }
 
+const USAGE: &str = "usage: differential_trace_tool <export-time|import-time|export-price|import-price|export-interaction|import-interaction|export-labels|import-labels|import-lwc-interaction|import-lwc-visual> (--input <path> --output <path> | --input-dir <dir> --output-dir <dir> [--summary <path>])";
+
+impl CommandKind {
+    const ALL: [Self; 10] = [
+        Self::ExportTime,
+        Self::ImportTime,
+        Self::ExportPrice,
+        Self::ImportPrice,
+        Self::ExportInteraction,
+        Self::ImportInteraction,
+        Self::ExportLabels,
+        Self::ImportLabels,
+        Self::ImportLwcInteraction,
+        Self::ImportLwcVisual,
+    ];
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::ExportTime => "export-time",
+            Self::ImportTime => "import-time",
+            Self::ExportPrice => "export-price",
+            Self::ImportPrice => "import-price",
+            Self::ExportInteraction => "export-interaction",
+            Self::ImportInteraction => "import-interaction",
+            Self::ExportLabels => "export-labels",
+            Self::ImportLabels => "import-labels",
+            Self::ImportLwcInteraction => "import-lwc-interaction",
+            Self::ImportLwcVisual => "import-lwc-visual",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|command| command.name() == name)
+    }
+}
+
+#[derive(Debug)]
+enum CliTarget {
+    File {
+        input: PathBuf,
+        output: PathBuf,
+    },
+    /// Batch mode: every `*.json` of `input_dir` is processed and checked
+    /// against the expectations it already carries.
+    Directory {
+        input_dir: PathBuf,
+        output_dir: PathBuf,
+        summary: Option<PathBuf>,
+    },
+}
+
 #[derive(Debug)]
 struct CliArgs {
     command: CommandKind,
-    input: PathBuf,
-    output: PathBuf,
+    target: CliTarget,
+}
+
+/// Machine-readable result of a batch run, written as `summary.json`.
+#[derive(Debug, Clone, Serialize)]
+struct BatchSummary {
+    command: String,
+    total: usize,
+    passed: usize,
+    failed: usize,
+    files: Vec<BatchFileResult>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct BatchFileResult {
+    file: String,
+    status: BatchFileStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    mismatches: Vec<BatchMismatch>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum BatchFileStatus {
+    Passed,
+    Failed,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct BatchMismatch {
+    scenario_id: String,
+    step_index: usize,
+    field: String,
+    expected: String,
+    actual: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -35,9 +128,9 @@ struct TimeTraceFile {
     trace_name: String,
     source: String,
     source_notes: String,
-    viewport: DifferentialViewport,
-    time_range: DifferentialTimeRange,
-    price_range: DifferentialPriceRange,
+    viewport: TraceViewport,
+    time_range: TraceTimeRange,
+    price_range: TracePriceRange,
     tolerance: f64,
     scenarios: Vec<TimeScenario>,
 }
@@ -47,51 +140,27 @@ struct TimeCaptureFile {
     trace_name: String,
     source: String,
     source_notes: String,
-    viewport: DifferentialViewport,
-    time_range: DifferentialTimeRange,
-    price_range: DifferentialPriceRange,
+    viewport: TraceViewport,
+    time_range: TraceTimeRange,
+    price_range: TracePriceRange,
     tolerance: f64,
     scenarios: Vec<TimeCaptureScenario>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-struct DifferentialViewport {
-    width: u32,
-    height: u32,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-struct DifferentialTimeRange {
-    start: f64,
-    end: f64,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-struct DifferentialPriceRange {
-    min: f64,
-    max: f64,
-}
-
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct TimeScenario {
     id: String,
-    points: Vec<DifferentialPoint>,
+    points: Vec<TracePoint>,
     steps: Vec<TimeStep>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct TimeCaptureScenario {
     id: String,
-    points: Vec<DifferentialPoint>,
+    points: Vec<TracePoint>,
     steps: Vec<TimeCaptureStep>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-struct DifferentialPoint {
-    time: f64,
-    value: f64,
-}
-
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct TimeStep {
     action: TimeAction,
@@ -148,9 +217,9 @@ struct PriceTraceFile {
     trace_name: String,
     source: String,
     source_notes: String,
-    viewport: DifferentialViewport,
-    time_range: DifferentialTimeRange,
-    price_range: DifferentialPriceRange,
+    viewport: TraceViewport,
+    time_range: TraceTimeRange,
+    price_range: TracePriceRange,
     tolerance: f64,
     scenarios: Vec<PriceScenario>,
 }
@@ -160,9 +229,9 @@ struct PriceCaptureFile {
     trace_name: String,
     source: String,
     source_notes: String,
-    viewport: DifferentialViewport,
-    time_range: DifferentialTimeRange,
-    price_range: DifferentialPriceRange,
+    viewport: TraceViewport,
+    time_range: TraceTimeRange,
+    price_range: TracePriceRange,
     tolerance: f64,
     scenarios: Vec<PriceCaptureScenario>,
 }
@@ -170,7 +239,7 @@ struct PriceCaptureFile {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct PriceScenario {
     id: String,
-    points: Vec<DifferentialPoint>,
+    points: Vec<TracePoint>,
     #[serde(default)]
     probe_price: Option<f64>,
     #[serde(default)]
@@ -181,7 +250,7 @@ struct PriceScenario {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct PriceCaptureScenario {
     id: String,
-    points: Vec<DifferentialPoint>,
+    points: Vec<TracePoint>,
     #[serde(default)]
     probe_price: Option<f64>,
     #[serde(default)]
@@ -288,15 +357,93 @@ struct PriceExpectation {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-struct InteractionTraceFile {
+struct LabelTraceFile {
     trace_name: String,
     source: String,
     source_notes: String,
-    viewport: DifferentialViewport,
-    time_range: DifferentialTimeRange,
-    price_range: DifferentialPriceRange,
+    viewport: TraceViewport,
+    time_range: TraceTimeRange,
+    price_range: TracePriceRange,
     tolerance: f64,
-    scenarios: Vec<InteractionScenario>,
+    scenarios: Vec<LabelScenario>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct LabelCaptureFile {
+    trace_name: String,
+    source: String,
+    source_notes: String,
+    viewport: TraceViewport,
+    time_range: TraceTimeRange,
+    price_range: TracePriceRange,
+    tolerance: f64,
+    scenarios: Vec<LabelCaptureScenario>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct LabelScenario {
+    id: String,
+    points: Vec<TracePoint>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    time_axis_label_config: Option<TimeAxisLabelConfig>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    price_axis_label_config: Option<PriceAxisLabelConfig>,
+    steps: Vec<LabelStep>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct LabelCaptureScenario {
+    id: String,
+    points: Vec<TracePoint>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    time_axis_label_config: Option<TimeAxisLabelConfig>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    price_axis_label_config: Option<PriceAxisLabelConfig>,
+    steps: Vec<LabelCaptureStep>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct LabelStep {
+    action: LabelAction,
+    #[serde(default)]
+    expect: Option<LabelExpectation>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct LabelCaptureStep {
+    action: LabelAction,
+    observed: LabelExpectation,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum LabelAction {
+    SetTimeVisibleRange { start: f64, end: f64 },
+    SetPriceScaleMode { mode: TracePriceScaleMode },
+    AutoscaleVisibleData,
+    SetCrosshairMode { mode: TraceCrosshairMode },
+    PointerMove { x: f64, y: f64 },
+    PointerLeave,
+}
+
+/// Axis label as drawn: `position_px` is the label anchor, x for time labels
+/// and y for price labels.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct TraceTickLabel {
+    position_px: f64,
+    text: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+struct LabelExpectation {
+    #[serde(default)]
+    time_ticks: Vec<TraceTickLabel>,
+    #[serde(default)]
+    price_ticks: Vec<TraceTickLabel>,
+    #[serde(default)]
+    crosshair_time_label: Option<String>,
+    #[serde(default)]
+    crosshair_price_label: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -304,9 +451,9 @@ struct InteractionCaptureFile {
     trace_name: String,
     source: String,
     source_notes: String,
-    viewport: DifferentialViewport,
-    time_range: DifferentialTimeRange,
-    price_range: DifferentialPriceRange,
+    viewport: TraceViewport,
+    time_range: TraceTimeRange,
+    price_range: TracePriceRange,
     tolerance: f64,
     scenarios: Vec<InteractionCaptureScenario>,
 }
@@ -317,13 +464,13 @@ struct LightweightInteractionCaptureFile {
     trace_name: Option<String>,
     #[serde(default)]
     source_notes: Option<String>,
-    viewport: DifferentialViewport,
-    time_range: DifferentialTimeRange,
-    price_range: DifferentialPriceRange,
+    viewport: TraceViewport,
+    time_range: TraceTimeRange,
+    price_range: TracePriceRange,
     #[serde(default)]
     tolerance: Option<f64>,
     #[serde(default)]
-    points: Vec<DifferentialPoint>,
+    points: Vec<TracePoint>,
     #[serde(default)]
     scenarios: Vec<LightweightInteractionCaptureScenario>,
     #[serde(default)]
@@ -335,128 +482,24 @@ struct LightweightInteractionCaptureScenario {
     #[serde(default)]
     id: Option<String>,
     #[serde(default)]
-    points: Vec<DifferentialPoint>,
+    points: Vec<TracePoint>,
     #[serde(default)]
     events: Vec<Value>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-struct InteractionScenario {
-    id: String,
-    points: Vec<DifferentialPoint>,
-    steps: Vec<InteractionStep>,
-}
-
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct InteractionCaptureScenario {
     id: String,
-    points: Vec<DifferentialPoint>,
+    points: Vec<TracePoint>,
     steps: Vec<InteractionCaptureStep>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-struct InteractionStep {
-    action: InteractionAction,
-    #[serde(default)]
-    expect: Option<InteractionExpectation>,
-}
-
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct InteractionCaptureStep {
     action: InteractionAction,
     observed: InteractionExpectation,
 }
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
-#[serde(rename_all = "snake_case")]
-enum TraceCrosshairMode {
-    Magnet,
-    Normal,
-    Hidden,
-}
-
-impl From<TraceCrosshairMode> for CrosshairMode {
-    fn from(value: TraceCrosshairMode) -> Self {
-        match value {
-            TraceCrosshairMode::Magnet => Self::Magnet,
-            TraceCrosshairMode::Normal => Self::Normal,
-            TraceCrosshairMode::Hidden => Self::Hidden,
-        }
-    }
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(tag = "type", rename_all = "snake_case")]
-enum InteractionAction {
-    SetNavigation {
-        right_offset_bars: f64,
-        bar_spacing_px: Option<f64>,
-    },
-    WheelPan {
-        wheel_delta_x: f64,
-        pan_step_ratio: f64,
-    },
-    TouchDragPan {
-        delta_x_px: f64,
-        delta_y_px: f64,
-    },
-    WheelZoom {
-        wheel_delta_y: f64,
-        anchor_px: f64,
-        zoom_step_ratio: f64,
-        min_span_absolute: f64,
-    },
-    PinchZoom {
-        factor: f64,
-        anchor_px: f64,
-        min_span_absolute: f64,
-    },
-    StartKineticPan {
-        velocity_time_per_sec: f64,
-    },
-    StepKineticPan {
-        delta_seconds: f64,
-    },
-    SetCrosshairMode {
-        mode: TraceCrosshairMode,
-    },
-    PointerMove {
-        x: f64,
-        y: f64,
-    },
-    PointerLeave,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
-struct InteractionExpectation {
-    #[serde(default)]
-    visible_start: Option<f64>,
-    #[serde(default)]
-    visible_end: Option<f64>,
-    #[serde(default)]
-    visible_span: Option<f64>,
-    #[serde(default)]
-    scroll_position_bars: Option<f64>,
-    #[serde(default)]
-    kinetic_active: Option<bool>,
-    #[serde(default)]
-    kinetic_velocity_time_per_sec: Option<f64>,
-    #[serde(default)]
-    crosshair_visible: Option<bool>,
-    #[serde(default)]
-    crosshair_x: Option<f64>,
-    #[serde(default)]
-    crosshair_y: Option<f64>,
-    #[serde(default)]
-    crosshair_snapped_x: Option<f64>,
-    #[serde(default)]
-    crosshair_snapped_y: Option<f64>,
-    #[serde(default)]
-    crosshair_snapped_time: Option<f64>,
-    #[serde(default)]
-    crosshair_snapped_price: Option<f64>,
-}
-
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct VisualCorpusFile {
     schema_version: u32,
@@ -544,11 +587,11 @@ struct LightweightVisualCaptureFile {
     #[serde(default)]
     source_notes: Option<String>,
     #[serde(default)]
-    viewport: Option<DifferentialViewport>,
+    viewport: Option<TraceViewport>,
     #[serde(default)]
-    time_range: Option<DifferentialTimeRange>,
+    time_range: Option<TraceTimeRange>,
     #[serde(default)]
-    price_range: Option<DifferentialPriceRange>,
+    price_range: Option<TracePriceRange>,
     #[serde(default)]
     tolerance: Option<VisualCorpusTolerance>,
     #[serde(default)]
@@ -572,11 +615,11 @@ struct LightweightVisualCaptureFixture {
     #[serde(default)]
     description: Option<String>,
     #[serde(default)]
-    viewport: Option<DifferentialViewport>,
+    viewport: Option<TraceViewport>,
     #[serde(default)]
-    time_range: Option<DifferentialTimeRange>,
+    time_range: Option<TraceTimeRange>,
     #[serde(default)]
-    price_range: Option<DifferentialPriceRange>,
+    price_range: Option<TracePriceRange>,
     #[serde(default)]
     points: Vec<DataPoint>,
     #[serde(default)]
@@ -602,75 +645,407 @@ fn main() {
 
 fn run() -> Result<(), String> {
     let args = parse_args()?;
-    match args.command {
+    match args.target {
+        CliTarget::File { input, output } => process_file(args.command, &input, &output).map(drop),
+        CliTarget::Directory {
+            input_dir,
+            output_dir,
+            summary,
+        } => {
+            let summary_path = summary.unwrap_or_else(|| output_dir.join("summary.json"));
+            let summary = run_batch(args.command, &input_dir, &output_dir)?;
+            write_json(&summary_path, &summary)?;
+            if summary.failed > 0 {
+                return Err(format!(
+                    "{} of {} trace files failed; see `{}`",
+                    summary.failed,
+                    summary.total,
+                    summary_path.display()
+                ));
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Converts `input` into `output` and returns the expectations already in
+/// `input` that replay did not reproduce within the trace tolerance. Import
+/// commands have nothing to replay and never report mismatches.
+fn process_file(
+    command: CommandKind,
+    input: &Path,
+    output: &Path,
+) -> Result<Vec<BatchMismatch>, String> {
+    match command {
         CommandKind::ExportTime => {
-            let raw = fs::read_to_string(&args.input)
-                .map_err(|err| format!("failed to read `{}`: {err}", args.input.display()))?;
-            let mut trace: TimeTraceFile =
-                serde_json::from_str(&raw).map_err(|err| format!("invalid json: {err}"))?;
+            let mut trace: TimeTraceFile = read_json(input)?;
+            let recorded = trace.clone();
             export_time_trace(&mut trace)?;
-            write_json(&args.output, &trace)
+            write_json(output, &trace)?;
+            Ok(compare_time_traces(&recorded, &trace))
         }
         CommandKind::ImportTime => {
-            let raw = fs::read_to_string(&args.input)
-                .map_err(|err| format!("failed to read `{}`: {err}", args.input.display()))?;
-            let capture: TimeCaptureFile =
-                serde_json::from_str(&raw).map_err(|err| format!("invalid json: {err}"))?;
-            let trace = import_time_capture(capture);
-            write_json(&args.output, &trace)
+            let capture: TimeCaptureFile = read_json(input)?;
+            write_json(output, &import_time_capture(capture))?;
+            Ok(Vec::new())
         }
         CommandKind::ExportPrice => {
-            let raw = fs::read_to_string(&args.input)
-                .map_err(|err| format!("failed to read `{}`: {err}", args.input.display()))?;
-            let mut trace: PriceTraceFile =
-                serde_json::from_str(&raw).map_err(|err| format!("invalid json: {err}"))?;
+            let mut trace: PriceTraceFile = read_json(input)?;
+            let recorded = trace.clone();
             export_price_trace(&mut trace)?;
-            write_json(&args.output, &trace)
+            write_json(output, &trace)?;
+            Ok(compare_price_traces(&recorded, &trace))
         }
         CommandKind::ImportPrice => {
-            let raw = fs::read_to_string(&args.input)
-                .map_err(|err| format!("failed to read `{}`: {err}", args.input.display()))?;
-            let capture: PriceCaptureFile =
-                serde_json::from_str(&raw).map_err(|err| format!("invalid json: {err}"))?;
-            let trace = import_price_capture(capture);
-            write_json(&args.output, &trace)
+            let capture: PriceCaptureFile = read_json(input)?;
+            write_json(output, &import_price_capture(capture))?;
+            Ok(Vec::new())
         }
         CommandKind::ExportInteraction => {
-            let raw = fs::read_to_string(&args.input)
-                .map_err(|err| format!("failed to read `{}`: {err}", args.input.display()))?;
-            let mut trace: InteractionTraceFile =
-                serde_json::from_str(&raw).map_err(|err| format!("invalid json: {err}"))?;
+            let mut trace: InteractionTrace = read_json(input)?;
+            let report = trace
+                .replay()
+                .map_err(|err| format!("interaction replay failed: {err}"))?;
             export_interaction_trace(&mut trace)?;
-            write_json(&args.output, &trace)
+            write_json(output, &trace)?;
+            Ok(report
+                .mismatches
+                .into_iter()
+                .map(|mismatch| BatchMismatch {
+                    scenario_id: mismatch.scenario_id,
+                    step_index: mismatch.step_index,
+                    field: mismatch.field.to_owned(),
+                    expected: mismatch.expected,
+                    actual: mismatch.actual,
+                })
+                .collect())
         }
         CommandKind::ImportInteraction => {
-            let raw = fs::read_to_string(&args.input)
-                .map_err(|err| format!("failed to read `{}`: {err}", args.input.display()))?;
-            let capture: InteractionCaptureFile =
-                serde_json::from_str(&raw).map_err(|err| format!("invalid json: {err}"))?;
-            let trace = import_interaction_capture(capture);
-            write_json(&args.output, &trace)
+            let capture: InteractionCaptureFile = read_json(input)?;
+            write_json(output, &import_interaction_capture(capture))?;
+            Ok(Vec::new())
+        }
+        CommandKind::ExportLabels => {
+            let mut trace: LabelTraceFile = read_json(input)?;
+            let recorded = trace.clone();
+            export_label_trace(&mut trace)?;
+            write_json(output, &trace)?;
+            Ok(compare_label_traces(&recorded, &trace))
+        }
+        CommandKind::ImportLabels => {
+            let capture: LabelCaptureFile = read_json(input)?;
+            write_json(output, &import_label_capture(capture))?;
+            Ok(Vec::new())
         }
         CommandKind::ImportLwcInteraction => {
-            let raw = fs::read_to_string(&args.input)
-                .map_err(|err| format!("failed to read `{}`: {err}", args.input.display()))?;
-            let capture: LightweightInteractionCaptureFile =
-                serde_json::from_str(&raw).map_err(|err| format!("invalid json: {err}"))?;
-            let trace = import_lightweight_interaction_capture(capture)?;
-            write_json(&args.output, &trace)
+            let capture: LightweightInteractionCaptureFile = read_json(input)?;
+            write_json(output, &import_lightweight_interaction_capture(capture)?)?;
+            Ok(Vec::new())
         }
         CommandKind::ImportLwcVisual => {
-            let raw = fs::read_to_string(&args.input)
-                .map_err(|err| format!("failed to read `{}`: {err}", args.input.display()))?;
-            let capture: LightweightVisualCaptureFile =
-                serde_json::from_str(&raw).map_err(|err| format!("invalid json: {err}"))?;
-            let corpus = import_lightweight_visual_capture(capture)?;
-            write_json(&args.output, &corpus)
+            let capture: LightweightVisualCaptureFile = read_json(input)?;
+            write_json(output, &import_lightweight_visual_capture(capture)?)?;
+            Ok(Vec::new())
+        }
+    }
+}
+
+/// Runs `command` over every `*.json` file of `input_dir` in file-name order,
+/// writing each output under the same name in `output_dir`.
+fn run_batch(
+    command: CommandKind,
+    input_dir: &Path,
+    output_dir: &Path,
+) -> Result<BatchSummary, String> {
+    let entries = fs::read_dir(input_dir)
+        .map_err(|err| format!("failed to read `{}`: {err}", input_dir.display()))?;
+    let mut inputs = Vec::new();
+    for entry in entries {
+        let path = entry
+            .map_err(|err| format!("failed to read `{}`: {err}", input_dir.display()))?
+            .path();
+        if path.is_file() && path.extension().is_some_and(|ext| ext == "json") {
+            inputs.push(path);
+        }
+    }
+    inputs.sort();
+    fs::create_dir_all(output_dir)
+        .map_err(|err| format!("failed to create `{}`: {err}", output_dir.display()))?;
+
+    let mut summary = BatchSummary {
+        command: command.name().to_owned(),
+        total: inputs.len(),
+        passed: 0,
+        failed: 0,
+        files: Vec::with_capacity(inputs.len()),
+    };
+    for input in inputs {
+        let file_name = input.file_name().unwrap_or_default();
+        let output = output_dir.join(file_name);
+        let result = match process_file(command, &input, &output) {
+            Ok(mismatches) if mismatches.is_empty() => BatchFileResult {
+                file: file_name.to_string_lossy().into_owned(),
+                status: BatchFileStatus::Passed,
+                error: None,
+                mismatches,
+            },
+            Ok(mismatches) => BatchFileResult {
+                file: file_name.to_string_lossy().into_owned(),
+                status: BatchFileStatus::Failed,
+                error: None,
+                mismatches,
+            },
+            Err(err) => BatchFileResult {
+                file: file_name.to_string_lossy().into_owned(),
+                status: BatchFileStatus::Failed,
+                error: Some(err),
+                mismatches: Vec::new(),
+            },
+        };
+        if result.status == BatchFileStatus::Passed {
+            summary.passed += 1;
+        } else {
+            summary.failed += 1;
+        }
+        summary.files.push(result);
+    }
+    Ok(summary)
+}
+
+fn push_f64_mismatch(
+    mismatches: &mut Vec<BatchMismatch>,
+    location: (&str, usize),
+    field: &str,
+    expected: Option<f64>,
+    actual: Option<f64>,
+    tolerance: f64,
+) {
+    let Some(expected) = expected else {
+        return;
+    };
+    if actual.is_some_and(|actual| (actual - expected).abs() <= tolerance) {
+        return;
+    }
+    mismatches.push(BatchMismatch {
+        scenario_id: location.0.to_owned(),
+        step_index: location.1,
+        field: field.to_owned(),
+        expected: expected.to_string(),
+        actual: actual.map_or_else(|| "none".to_owned(), |actual| actual.to_string()),
+    });
+}
+
+fn compare_time_traces(recorded: &TimeTraceFile, replayed: &TimeTraceFile) -> Vec<BatchMismatch> {
+    let mut mismatches = Vec::new();
+    let tolerance = recorded.tolerance;
+    for (scenario, replayed_scenario) in recorded.scenarios.iter().zip(&replayed.scenarios) {
+        for (index, (step, replayed_step)) in scenario
+            .steps
+            .iter()
+            .zip(&replayed_scenario.steps)
+            .enumerate()
+        {
+            let (Some(expected), Some(actual)) = (&step.expect, &replayed_step.expect) else {
+                continue;
+            };
+            let location = (scenario.id.as_str(), index);
+            for (field, expected, actual) in [
+                (
+                    "visible_start",
+                    expected.visible_start,
+                    actual.visible_start,
+                ),
+                ("visible_end", expected.visible_end, actual.visible_end),
+                ("visible_span", expected.visible_span, actual.visible_span),
+                (
+                    "right_margin_px",
+                    expected.right_margin_px,
+                    actual.right_margin_px,
+                ),
+                (
+                    "scroll_position_bars",
+                    expected.scroll_position_bars,
+                    actual.scroll_position_bars,
+                ),
+            ] {
+                push_f64_mismatch(
+                    &mut mismatches,
+                    location,
+                    field,
+                    expected,
+                    actual,
+                    tolerance,
+                );
+            }
+        }
+    }
+    mismatches
+}
+
+fn compare_price_traces(
+    recorded: &PriceTraceFile,
+    replayed: &PriceTraceFile,
+) -> Vec<BatchMismatch> {
+    let mut mismatches = Vec::new();
+    let tolerance = recorded.tolerance;
+    for (scenario, replayed_scenario) in recorded.scenarios.iter().zip(&replayed.scenarios) {
+        for (index, (step, replayed_step)) in scenario
+            .steps
+            .iter()
+            .zip(&replayed_scenario.steps)
+            .enumerate()
+        {
+            let (Some(expected), Some(actual)) = (&step.expect, &replayed_step.expect) else {
+                continue;
+            };
+            let location = (scenario.id.as_str(), index);
+            for (field, expected, actual) in [
+                ("base_value", expected.base_value, actual.base_value),
+                (
+                    "price_domain_start",
+                    expected.price_domain_start,
+                    actual.price_domain_start,
+                ),
+                (
+                    "price_domain_end",
+                    expected.price_domain_end,
+                    actual.price_domain_end,
+                ),
+                (
+                    "price_domain_span",
+                    expected.price_domain_span,
+                    actual.price_domain_span,
+                ),
+                (
+                    "probe_price_pixel",
+                    expected.probe_price_pixel,
+                    actual.probe_price_pixel,
+                ),
+                (
+                    "probe_pixel_price",
+                    expected.probe_pixel_price,
+                    actual.probe_pixel_price,
+                ),
+            ] {
+                push_f64_mismatch(
+                    &mut mismatches,
+                    location,
+                    field,
+                    expected,
+                    actual,
+                    tolerance,
+                );
+            }
+        }
+    }
+    mismatches
+}
+
+fn push_tick_label_mismatches(
+    mismatches: &mut Vec<BatchMismatch>,
+    location: (&str, usize),
+    field: &str,
+    expected: &[TraceTickLabel],
+    actual: &[TraceTickLabel],
+    tolerance: f64,
+) {
+    let texts = |labels: &[TraceTickLabel]| {
+        labels
+            .iter()
+            .map(|label| label.text.as_str())
+            .collect::<Vec<_>>()
+            .join("|")
+    };
+    let (expected_texts, actual_texts) = (texts(expected), texts(actual));
+    if expected_texts != actual_texts {
+        mismatches.push(BatchMismatch {
+            scenario_id: location.0.to_owned(),
+            step_index: location.1,
+            field: format!("{field}.text"),
+            expected: expected_texts,
+            actual: actual_texts,
+        });
+        return;
+    }
+    for (index, (expected, actual)) in expected.iter().zip(actual).enumerate() {
+        push_f64_mismatch(
+            mismatches,
+            location,
+            &format!("{field}[{index}].position_px"),
+            Some(expected.position_px),
+            Some(actual.position_px),
+            tolerance,
+        );
+    }
+}
+
+fn compare_label_traces(
+    recorded: &LabelTraceFile,
+    replayed: &LabelTraceFile,
+) -> Vec<BatchMismatch> {
+    let mut mismatches = Vec::new();
+    let tolerance = recorded.tolerance;
+    for (scenario, replayed_scenario) in recorded.scenarios.iter().zip(&replayed.scenarios) {
+        for (index, (step, replayed_step)) in scenario
+            .steps
+            .iter()
+            .zip(&replayed_scenario.steps)
+            .enumerate()
+        {
+            let (Some(expected), Some(actual)) = (&step.expect, &replayed_step.expect) else {
+                continue;
+            };
+            let location = (scenario.id.as_str(), index);
+            push_tick_label_mismatches(
+                &mut mismatches,
+                location,
+                "time_ticks",
+                &expected.time_ticks,
+                &actual.time_ticks,
+                tolerance,
+            );
+            push_tick_label_mismatches(
+                &mut mismatches,
+                location,
+                "price_ticks",
+                &expected.price_ticks,
+                &actual.price_ticks,
+                tolerance,
+            );
+            for (field, expected, actual) in [
+                (
+                    "crosshair_time_label",
+                    &expected.crosshair_time_label,
+                    &actual.crosshair_time_label,
+                ),
+                (
+                    "crosshair_price_label",
+                    &expected.crosshair_price_label,
+                    &actual.crosshair_price_label,
+                ),
+            ] {
+                if expected != actual {
+                    mismatches.push(BatchMismatch {
+                        scenario_id: scenario.id.clone(),
+                        step_index: index,
+                        field: field.to_owned(),
+                        expected: format!("{expected:?}"),
+                        actual: format!("{actual:?}"),
+                    });
+                }
+            }
         }
     }
+    mismatches
+}
+
+fn read_json<T: DeserializeOwned>(path: &Path) -> Result<T, String> {
+    let raw = fs::read_to_string(path)
+        .map_err(|err| format!("failed to read `{}`: {err}", path.display()))?;
+    serde_json::from_str(&raw).map_err(|err| format!("invalid json: {err}"))
 }
 
-fn write_json<T: Serialize>(path: &PathBuf, value: &T) -> Result<(), String> {
+fn write_json<T: Serialize>(path: &Path, value: &T) -> Result<(), String> {
     let payload = serde_json::to_string_pretty(value)
         .map_err(|err| format!("failed to serialize json: {err}"))?;
     fs::write(path, payload).map_err(|err| format!("failed to write `{}`: {err}", path.display()))
@@ -678,56 +1053,55 @@ fn write_json<T: Serialize>(path: &PathBuf, value: &T) -> Result<(), String> {
 
 fn parse_args() -> Result<CliArgs, String> {
     let mut args = std::env::args().skip(1);
-    let command = match args.next().as_deref() {
-        Some("export-time") => CommandKind::ExportTime,
-        Some("import-time") => CommandKind::ImportTime,
-        Some("export-price") => CommandKind::ExportPrice,
-        Some("import-price") => CommandKind::ImportPrice,
-        Some("export-interaction") => CommandKind::ExportInteraction,
-        Some("import-interaction") => CommandKind::ImportInteraction,
-        Some("import-lwc-interaction") => CommandKind::ImportLwcInteraction,
-        Some("import-lwc-visual") => CommandKind::ImportLwcVisual,
-        _ => {
-            return Err(
-                "usage: differential_trace_tool <export-time|import-time|export-price|import-price|export-interaction|import-interaction|import-lwc-interaction|import-lwc-visual> --input <path> --output <path>"
-                    .to_owned(),
-            );
-        }
-    };
+    let command = args
+        .next()
+        .as_deref()
+        .and_then(CommandKind::from_name)
+        .ok_or_else(|| USAGE.to_owned())?;
 
     let mut input = None::<PathBuf>;
     let mut output = None::<PathBuf>;
+    let mut input_dir = None::<PathBuf>;
+    let mut output_dir = None::<PathBuf>;
+    let mut summary = None::<PathBuf>;
 
     while let Some(flag) = args.next() {
-        match flag.as_str() {
-            "--input" => {
-                let value = args
-                    .next()
-                    .ok_or_else(|| "missing value for --input".to_owned())?;
-                input = Some(PathBuf::from(value));
-            }
-            "--output" => {
-                let value = args
-                    .next()
-                    .ok_or_else(|| "missing value for --output".to_owned())?;
-                output = Some(PathBuf::from(value));
-            }
-            "--help" | "-h" => {
-                return Err(
-                    "usage: differential_trace_tool <export-time|import-time|export-price|import-price|export-interaction|import-interaction|import-lwc-interaction|import-lwc-visual> --input <path> --output <path>".to_owned(),
-                )
-            }
+        let slot = match flag.as_str() {
+            "--input" => &mut input,
+            "--output" => &mut output,
+            "--input-dir" => &mut input_dir,
+            "--output-dir" => &mut output_dir,
+            "--summary" => &mut summary,
+            "--help" | "-h" => return Err(USAGE.to_owned()),
             _ => return Err(format!("unknown argument `{flag}`")),
-        }
+        };
+        let value = args
+            .next()
+            .ok_or_else(|| format!("missing value for {flag}"))?;
+        *slot = Some(PathBuf::from(value));
     }
 
-    let input = input.ok_or_else(|| "missing --input".to_owned())?;
-    let output = output.ok_or_else(|| "missing --output".to_owned())?;
-    Ok(CliArgs {
-        command,
-        input,
-        output,
-    })
+    let target = match (input, output, input_dir, output_dir) {
+        (Some(input), Some(output), None, None) if summary.is_none() => {
+            CliTarget::File { input, output }
+        }
+        (None, None, Some(input_dir), Some(output_dir)) => CliTarget::Directory {
+            input_dir,
+            output_dir,
+            summary,
+        },
+        (None, None, Some(_), None) => return Err("missing --output-dir".to_owned()),
+        (None, None, None, Some(_)) => return Err("missing --input-dir".to_owned()),
+        (None, Some(_), None, None) => return Err("missing --input".to_owned()),
+        (Some(_), None, None, None) => return Err("missing --output".to_owned()),
+        (None, None, None, None) => return Err("missing --input".to_owned()),
+        _ => {
+            return Err(
+                "--input/--output and --input-dir/--output-dir/--summary are exclusive".to_owned(),
+            );
+        }
+    };
+    Ok(CliArgs { command, target })
 }
 
 fn build_engine(
@@ -922,7 +1296,9 @@ fn export_price_trace(trace: &mut PriceTraceFile) -> Result<(), String> {
                     });
                 }
                 PriceAction::AppendPoint { time, value } => {
-                    engine.append_point(DataPoint::new(time, value));
+                    engine
+                        .append_point(DataPoint::new(time, value))
+                        .map_err(|err| format!("append_point failed: {err}"))?;
                 }
             }
 
@@ -988,7 +1364,73 @@ fn import_price_capture(capture: PriceCaptureFile) -> PriceTraceFile {
     }
 }
 
-fn export_interaction_trace(trace: &mut InteractionTraceFile) -> Result<(), String> {
+// Label kinds are told apart by color, so every kind gets a color no other
+// primitive uses; text and geometry do not depend on it.
+const SENTINEL_TIME_LABEL_COLOR: Color = Color::rgb(0.0, 0.0, 0.01);
+const SENTINEL_PRICE_LABEL_COLOR: Color = Color::rgb(0.0, 0.01, 0.0);
+const SENTINEL_CROSSHAIR_TIME_LABEL_COLOR: Color = Color::rgb(0.01, 0.0, 0.0);
+const SENTINEL_CROSSHAIR_PRICE_LABEL_COLOR: Color = Color::rgb(0.01, 0.01, 0.0);
+
+fn apply_label_sentinel_style(engine: &mut ChartEngine<NullRenderer>) -> Result<(), String> {
+    let style = engine.render_style();
+    engine
+        .set_render_style(RenderStyle {
+            time_axis_label_color: SENTINEL_TIME_LABEL_COLOR,
+            major_time_label_color: SENTINEL_TIME_LABEL_COLOR,
+            axis_label_color: SENTINEL_PRICE_LABEL_COLOR,
+            crosshair_time_label_color: SENTINEL_CROSSHAIR_TIME_LABEL_COLOR,
+            crosshair_price_label_color: SENTINEL_CROSSHAIR_PRICE_LABEL_COLOR,
+            crosshair_time_label_box_text_color: Some(SENTINEL_CROSSHAIR_TIME_LABEL_COLOR),
+            crosshair_price_label_box_text_color: Some(SENTINEL_CROSSHAIR_PRICE_LABEL_COLOR),
+            crosshair_time_label_box_auto_text_contrast: Some(false),
+            crosshair_price_label_box_auto_text_contrast: Some(false),
+            ..style
+        })
+        .map_err(|err| format!("set_render_style failed: {err}"))?;
+    engine
+        .set_crosshair_axis_label_visibility_behavior(CrosshairAxisLabelVisibilityBehavior {
+            show_time_label: true,
+            show_price_label: true,
+            ..engine.crosshair_axis_label_visibility_behavior()
+        })
+        .map_err(|err| format!("set_crosshair_axis_label_visibility_behavior failed: {err}"))
+}
+
+fn observe_labels(engine: &ChartEngine<NullRenderer>) -> Result<LabelExpectation, String> {
+    let frame = engine
+        .build_render_frame()
+        .map_err(|err| format!("build_render_frame failed: {err}"))?;
+    let price_font_size_px = engine.render_style().price_axis_label_font_size_px;
+    let mut observed = LabelExpectation::default();
+    for text in &frame.texts {
+        if text.color == SENTINEL_TIME_LABEL_COLOR && text.h_align == TextHAlign::Center {
+            observed.time_ticks.push(TraceTickLabel {
+                position_px: text.x,
+                text: text.text.to_string(),
+            });
+        } else if text.color == SENTINEL_PRICE_LABEL_COLOR
+            && text.font_size_px == price_font_size_px
+        {
+            observed.price_ticks.push(TraceTickLabel {
+                position_px: text.y,
+                text: text.text.to_string(),
+            });
+        } else if text.color == SENTINEL_CROSSHAIR_TIME_LABEL_COLOR {
+            observed.crosshair_time_label = Some(text.text.to_string());
+        } else if text.color == SENTINEL_CROSSHAIR_PRICE_LABEL_COLOR {
+            observed.crosshair_price_label = Some(text.text.to_string());
+        }
+    }
+    observed
+        .time_ticks
+        .sort_by(|left, right| left.position_px.total_cmp(&right.position_px));
+    observed
+        .price_ticks
+        .sort_by(|left, right| left.position_px.total_cmp(&right.position_px));
+    Ok(observed)
+}
+
+fn export_label_trace(trace: &mut LabelTraceFile) -> Result<(), String> {
     for scenario in &mut trace.scenarios {
         let mut engine = build_engine(
             Viewport::new(trace.viewport.width, trace.viewport.height),
@@ -997,6 +1439,17 @@ fn export_interaction_trace(trace: &mut InteractionTraceFile) -> Result<(), Stri
             trace.price_range.min,
             trace.price_range.max,
         );
+        apply_label_sentinel_style(&mut engine)?;
+        if let Some(config) = scenario.time_axis_label_config {
+            engine
+                .set_time_axis_label_config(config)
+                .map_err(|err| format!("set_time_axis_label_config failed: {err}"))?;
+        }
+        if let Some(config) = scenario.price_axis_label_config {
+            engine
+                .set_price_axis_label_config(config)
+                .map_err(|err| format!("set_price_axis_label_config failed: {err}"))?;
+        }
 
         if !scenario.points.is_empty() {
             let points = scenario
@@ -1009,107 +1462,70 @@ fn export_interaction_trace(trace: &mut InteractionTraceFile) -> Result<(), Stri
 
         for step in &mut scenario.steps {
             match step.action {
-                InteractionAction::SetNavigation {
-                    right_offset_bars,
-                    bar_spacing_px,
-                } => {
+                LabelAction::SetTimeVisibleRange { start, end } => {
                     engine
-                        .set_time_scale_navigation_behavior(TimeScaleNavigationBehavior {
-                            right_offset_bars,
-                            bar_spacing_px,
-                        })
-                        .map_err(|err| format!("set_navigation failed: {err}"))?;
-                }
-                InteractionAction::WheelPan {
-                    wheel_delta_x,
-                    pan_step_ratio,
-                } => {
-                    let _ = engine
-                        .wheel_pan_time_visible(wheel_delta_x, pan_step_ratio)
-                        .map_err(|err| format!("wheel_pan failed: {err}"))?;
-                }
-                InteractionAction::TouchDragPan {
-                    delta_x_px,
-                    delta_y_px,
-                } => {
-                    let _ = engine
-                        .touch_drag_pan_time_visible(delta_x_px, delta_y_px)
-                        .map_err(|err| format!("touch_drag_pan failed: {err}"))?;
-                }
-                InteractionAction::WheelZoom {
-                    wheel_delta_y,
-                    anchor_px,
-                    zoom_step_ratio,
-                    min_span_absolute,
-                } => {
-                    let _ = engine
-                        .wheel_zoom_time_visible(
-                            wheel_delta_y,
-                            anchor_px,
-                            zoom_step_ratio,
-                            min_span_absolute,
-                        )
-                        .map_err(|err| format!("wheel_zoom failed: {err}"))?;
-                }
-                InteractionAction::PinchZoom {
-                    factor,
-                    anchor_px,
-                    min_span_absolute,
-                } => {
-                    let _ = engine
-                        .pinch_zoom_time_visible(factor, anchor_px, min_span_absolute)
-                        .map_err(|err| format!("pinch_zoom failed: {err}"))?;
+                        .set_time_visible_range(start, end)
+                        .map_err(|err| format!("set_time_visible_range failed: {err}"))?;
                 }
-                InteractionAction::StartKineticPan {
-                    velocity_time_per_sec,
-                } => {
+                LabelAction::SetPriceScaleMode { mode } => {
                     engine
-                        .start_kinetic_pan(velocity_time_per_sec)
-                        .map_err(|err| format!("start_kinetic_pan failed: {err}"))?;
-                }
-                InteractionAction::StepKineticPan { delta_seconds } => {
-                    let _ = engine
-                        .step_kinetic_pan(delta_seconds)
-                        .map_err(|err| format!("step_kinetic_pan failed: {err}"))?;
-                }
-                InteractionAction::SetCrosshairMode { mode } => {
-                    engine.set_crosshair_mode(mode.into());
-                }
-                InteractionAction::PointerMove { x, y } => {
-                    engine.pointer_move(x, y);
+                        .set_price_scale_mode(mode.into())
+                        .map_err(|err| format!("set_price_scale_mode failed: {err}"))?;
                 }
-                InteractionAction::PointerLeave => {
-                    engine.pointer_leave();
+                LabelAction::AutoscaleVisibleData => {
+                    engine
+                        .autoscale_price_from_visible_data()
+                        .map_err(|err| format!("autoscale_visible_data failed: {err}"))?;
                 }
+                LabelAction::SetCrosshairMode { mode } => engine.set_crosshair_mode(mode.into()),
+                LabelAction::PointerMove { x, y } => engine.pointer_move(x, y),
+                LabelAction::PointerLeave => engine.pointer_leave(),
             }
-
-            let (visible_start, visible_end) = engine.time_visible_range();
-            let kinetic_state = engine.kinetic_pan_state();
-            let crosshair = engine.crosshair_state();
-
-            step.expect = Some(InteractionExpectation {
-                visible_start: Some(visible_start),
-                visible_end: Some(visible_end),
-                visible_span: Some(visible_end - visible_start),
-                scroll_position_bars: engine.time_scroll_position_bars(),
-                kinetic_active: Some(kinetic_state.active),
-                kinetic_velocity_time_per_sec: Some(kinetic_state.velocity_time_per_sec),
-                crosshair_visible: Some(crosshair.visible),
-                crosshair_x: Some(crosshair.x),
-                crosshair_y: Some(crosshair.y),
-                crosshair_snapped_x: crosshair.snapped_x,
-                crosshair_snapped_y: crosshair.snapped_y,
-                crosshair_snapped_time: crosshair.snapped_time,
-                crosshair_snapped_price: crosshair.snapped_price,
-            });
+            step.expect = Some(observe_labels(&engine)?);
         }
     }
 
     Ok(())
 }
 
-fn import_interaction_capture(capture: InteractionCaptureFile) -> InteractionTraceFile {
-    InteractionTraceFile {
+fn import_label_capture(capture: LabelCaptureFile) -> LabelTraceFile {
+    LabelTraceFile {
+        trace_name: capture.trace_name,
+        source: capture.source,
+        source_notes: capture.source_notes,
+        viewport: capture.viewport,
+        time_range: capture.time_range,
+        price_range: capture.price_range,
+        tolerance: capture.tolerance,
+        scenarios: capture
+            .scenarios
+            .into_iter()
+            .map(|scenario| LabelScenario {
+                id: scenario.id,
+                points: scenario.points,
+                time_axis_label_config: scenario.time_axis_label_config,
+                price_axis_label_config: scenario.price_axis_label_config,
+                steps: scenario
+                    .steps
+                    .into_iter()
+                    .map(|step| LabelStep {
+                        action: step.action,
+                        expect: Some(step.observed),
+                    })
+                    .collect(),
+            })
+            .collect(),
+    }
+}
+
+fn export_interaction_trace(trace: &mut InteractionTrace) -> Result<(), String> {
+    trace
+        .record_expectations()
+        .map_err(|err| format!("interaction replay failed: {err}"))
+}
+
+fn import_interaction_capture(capture: InteractionCaptureFile) -> InteractionTrace {
+    InteractionTrace {
         trace_name: capture.trace_name,
         source: capture.source,
         source_notes: capture.source_notes,
@@ -1129,6 +1545,7 @@ fn import_interaction_capture(capture: InteractionCaptureFile) -> InteractionTra
                     .map(|step| InteractionStep {
                         action: step.action,
                         expect: Some(step.observed),
+                        elapsed_ms: None,
                     })
                     .collect(),
             })
@@ -1138,7 +1555,7 @@ fn import_interaction_capture(capture: InteractionCaptureFile) -> InteractionTra
 
 fn import_lightweight_interaction_capture(
     capture: LightweightInteractionCaptureFile,
-) -> Result<InteractionTraceFile, String> {
+) -> Result<InteractionTrace, String> {
     let scenarios = if !capture.scenarios.is_empty() {
         capture
             .scenarios
@@ -1159,7 +1576,7 @@ fn import_lightweight_interaction_capture(
         )?]
     };
 
-    Ok(InteractionTraceFile {
+    Ok(InteractionTrace {
         trace_name: capture
             .trace_name
             .unwrap_or_else(|| "lightweight-real-capture-interaction".to_owned()),
@@ -1178,14 +1595,18 @@ fn import_lightweight_interaction_capture(
 
 fn import_lightweight_interaction_scenario(
     id: String,
-    points: Vec<DifferentialPoint>,
+    points: Vec<TracePoint>,
     events: Vec<Value>,
 ) -> Result<InteractionScenario, String> {
     let mut steps = Vec::with_capacity(events.len());
     for event in events {
         let action = map_lightweight_event_to_action(&event)?;
         let expect = parse_optional_interaction_expectation(&event)?;
-        steps.push(InteractionStep { action, expect });
+        steps.push(InteractionStep {
+            action,
+            expect,
+            elapsed_ms: None,
+        });
     }
     Ok(InteractionScenario { id, points, steps })
 }
@@ -1346,9 +1767,9 @@ fn import_lightweight_visual_capture(
             fixtures.push(import_lightweight_visual_fixture(
                 fixture,
                 index,
-                viewport.clone(),
-                time_range.clone(),
-                price_range.clone(),
+                viewport,
+                time_range,
+                price_range,
                 tolerance.clone(),
             )?);
         }
@@ -1369,9 +1790,9 @@ fn import_lightweight_visual_capture(
 fn import_lightweight_visual_fixture(
     fixture: LightweightVisualCaptureFixture,
     fixture_index: usize,
-    default_viewport: Option<DifferentialViewport>,
-    default_time_range: Option<DifferentialTimeRange>,
-    default_price_range: Option<DifferentialPriceRange>,
+    default_viewport: Option<TraceViewport>,
+    default_time_range: Option<TraceTimeRange>,
+    default_price_range: Option<TracePriceRange>,
     default_tolerance: Option<VisualCorpusTolerance>,
 ) -> Result<VisualCorpusFixture, String> {
     let id = fixture
@@ -1601,15 +2022,15 @@ mod tests {
             trace_name: "t".to_owned(),
             source: "lightweight".to_owned(),
             source_notes: "note".to_owned(),
-            viewport: DifferentialViewport {
+            viewport: TraceViewport {
                 width: 1000,
                 height: 500,
             },
-            time_range: DifferentialTimeRange {
+            time_range: TraceTimeRange {
                 start: 0.0,
                 end: 100.0,
             },
-            price_range: DifferentialPriceRange { min: 0.0, max: 1.0 },
+            price_range: TracePriceRange { min: 0.0, max: 1.0 },
             tolerance: 1e-6,
             scenarios: vec![TimeCaptureScenario {
                 id: "s1".to_owned(),
@@ -1639,24 +2060,24 @@ mod tests {
             trace_name: "t".to_owned(),
             source: "lightweight".to_owned(),
             source_notes: "note".to_owned(),
-            viewport: DifferentialViewport {
+            viewport: TraceViewport {
                 width: 1000,
                 height: 500,
             },
-            time_range: DifferentialTimeRange {
+            time_range: TraceTimeRange {
                 start: 0.0,
                 end: 100.0,
             },
-            price_range: DifferentialPriceRange { min: 0.0, max: 1.0 },
+            price_range: TracePriceRange { min: 0.0, max: 1.0 },
             tolerance: 1e-6,
             scenarios: vec![TimeScenario {
                 id: "s1".to_owned(),
                 points: vec![
-                    DifferentialPoint {
+                    TracePoint {
                         time: 0.0,
                         value: 0.5,
                     },
-                    DifferentialPoint {
+                    TracePoint {
                         time: 10.0,
                         value: 0.6,
                     },
@@ -1679,21 +2100,245 @@ mod tests {
         assert!(expect.right_margin_px.is_some());
     }
 
+    fn label_trace(steps: Vec<LabelAction>) -> LabelTraceFile {
+        LabelTraceFile {
+            trace_name: "labels".to_owned(),
+            source: "lightweight".to_owned(),
+            source_notes: "note".to_owned(),
+            viewport: TraceViewport {
+                width: 800,
+                height: 400,
+            },
+            time_range: TraceTimeRange {
+                start: 0.0,
+                end: 100.0,
+            },
+            price_range: TracePriceRange {
+                min: 0.0,
+                max: 100.0,
+            },
+            tolerance: 1e-6,
+            scenarios: vec![LabelScenario {
+                id: "s1".to_owned(),
+                points: (0..=100)
+                    .map(|i| TracePoint {
+                        time: f64::from(i),
+                        value: 40.0 + f64::from(i % 9),
+                    })
+                    .collect(),
+                time_axis_label_config: None,
+                price_axis_label_config: None,
+                steps: steps
+                    .into_iter()
+                    .map(|action| LabelStep {
+                        action,
+                        expect: None,
+                    })
+                    .collect(),
+            }],
+        }
+    }
+
+    #[test]
+    fn export_label_trace_captures_ordered_tick_labels() {
+        let mut trace = label_trace(vec![
+            LabelAction::SetTimeVisibleRange {
+                start: 10.0,
+                end: 60.0,
+            },
+            LabelAction::AutoscaleVisibleData,
+        ]);
+        export_label_trace(&mut trace).expect("export label trace");
+
+        for step in &trace.scenarios[0].steps {
+            let expect = step.expect.as_ref().expect("expectation generated");
+            assert!(expect.time_ticks.len() >= 2);
+            assert!(expect.price_ticks.len() >= 2);
+            assert!(
+                expect
+                    .time_ticks
+                    .windows(2)
+                    .all(|pair| pair[0].position_px < pair[1].position_px)
+            );
+            assert!(expect.price_ticks.iter().all(|tick| !tick.text.is_empty()));
+            assert!(expect.crosshair_time_label.is_none());
+            assert!(expect.crosshair_price_label.is_none());
+        }
+    }
+
+    #[test]
+    fn export_label_trace_captures_crosshair_labels_until_pointer_leave() {
+        let mut trace = label_trace(vec![
+            LabelAction::SetCrosshairMode {
+                mode: TraceCrosshairMode::Normal,
+            },
+            LabelAction::PointerMove { x: 300.0, y: 150.0 },
+            LabelAction::PointerLeave,
+        ]);
+        export_label_trace(&mut trace).expect("export label trace");
+
+        let steps = &trace.scenarios[0].steps;
+        let moved = steps[1].expect.as_ref().expect("pointer move expectation");
+        assert!(moved.crosshair_time_label.is_some());
+        assert!(moved.crosshair_price_label.is_some());
+        let left = steps[2].expect.as_ref().expect("pointer leave expectation");
+        assert!(left.crosshair_time_label.is_none());
+        assert!(left.crosshair_price_label.is_none());
+        assert_eq!(left.time_ticks, moved.time_ticks);
+    }
+
+    #[test]
+    fn import_label_capture_maps_observed_to_expect() {
+        let capture = LabelCaptureFile {
+            trace_name: "labels".to_owned(),
+            source: "lightweight".to_owned(),
+            source_notes: "note".to_owned(),
+            viewport: TraceViewport {
+                width: 800,
+                height: 400,
+            },
+            time_range: TraceTimeRange {
+                start: 0.0,
+                end: 100.0,
+            },
+            price_range: TracePriceRange {
+                min: 0.0,
+                max: 100.0,
+            },
+            tolerance: 1e-6,
+            scenarios: vec![LabelCaptureScenario {
+                id: "s1".to_owned(),
+                points: vec![],
+                time_axis_label_config: None,
+                price_axis_label_config: None,
+                steps: vec![LabelCaptureStep {
+                    action: LabelAction::PointerMove { x: 10.0, y: 20.0 },
+                    observed: LabelExpectation {
+                        time_ticks: vec![TraceTickLabel {
+                            position_px: 120.0,
+                            text: "12:00".to_owned(),
+                        }],
+                        crosshair_price_label: Some("42.00".to_owned()),
+                        ..LabelExpectation::default()
+                    },
+                }],
+            }],
+        };
+
+        let imported = import_label_capture(capture);
+        let expect = imported.scenarios[0].steps[0]
+            .expect
+            .as_ref()
+            .expect("observed maps to expect");
+        assert_eq!(expect.time_ticks[0].text, "12:00");
+        assert_eq!(expect.crosshair_price_label.as_deref(), Some("42.00"));
+    }
+
+    fn batch_dirs(name: &str) -> (PathBuf, PathBuf) {
+        let root = std::env::temp_dir().join(format!(
+            "chart_rs_differential_batch_{name}_{}",
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&root);
+        let input_dir = root.join("in");
+        fs::create_dir_all(&input_dir).expect("input dir");
+        (input_dir, root.join("out"))
+    }
+
+    #[test]
+    fn batch_mode_reports_per_file_pass_and_fail() {
+        let (input_dir, output_dir) = batch_dirs("labels");
+        let mut recorded = label_trace(vec![LabelAction::AutoscaleVisibleData]);
+        export_label_trace(&mut recorded).expect("export label trace");
+        write_json(&input_dir.join("a_recorded.json"), &recorded).expect("write recorded");
+
+        let mut drifted = recorded.clone();
+        let expect = drifted.scenarios[0].steps[0]
+            .expect
+            .as_mut()
+            .expect("recorded expectation");
+        expect.time_ticks[0].position_px += 5.0;
+        expect.crosshair_price_label = Some("99.00".to_owned());
+        write_json(&input_dir.join("b_drifted.json"), &drifted).expect("write drifted");
+        fs::write(input_dir.join("c_broken.json"), "{").expect("write broken");
+        fs::write(input_dir.join("notes.txt"), "skipped").expect("write notes");
+
+        let summary =
+            run_batch(CommandKind::ExportLabels, &input_dir, &output_dir).expect("batch run");
+        assert_eq!(summary.command, "export-labels");
+        assert_eq!((summary.total, summary.passed, summary.failed), (3, 1, 2));
+
+        let files: Vec<&str> = summary
+            .files
+            .iter()
+            .map(|file| file.file.as_str())
+            .collect();
+        assert_eq!(
+            files,
+            ["a_recorded.json", "b_drifted.json", "c_broken.json"]
+        );
+        assert_eq!(summary.files[0].status, BatchFileStatus::Passed);
+        let drifted_fields: Vec<&str> = summary.files[1]
+            .mismatches
+            .iter()
+            .map(|mismatch| mismatch.field.as_str())
+            .collect();
+        assert_eq!(
+            drifted_fields,
+            ["time_ticks[0].position_px", "crosshair_price_label"]
+        );
+        assert!(summary.files[2].error.is_some());
+        assert!(output_dir.join("a_recorded.json").is_file());
+        assert!(output_dir.join("b_drifted.json").is_file());
+
+        let _ = fs::remove_dir_all(output_dir.parent().expect("batch root"));
+    }
+
+    #[test]
+    fn batch_mode_import_commands_pass_when_conversion_succeeds() {
+        let (input_dir, output_dir) = batch_dirs("import_time");
+        let capture = serde_json::json!({
+            "trace_name": "t",
+            "source": "lightweight",
+            "source_notes": "note",
+            "viewport": { "width": 800, "height": 400 },
+            "time_range": { "start": 0.0, "end": 100.0 },
+            "price_range": { "min": 0.0, "max": 1.0 },
+            "tolerance": 1e-6,
+            "scenarios": []
+        });
+        write_json(&input_dir.join("capture.json"), &capture).expect("write capture");
+
+        let summary =
+            run_batch(CommandKind::ImportTime, &input_dir, &output_dir).expect("batch run");
+        assert_eq!((summary.total, summary.passed, summary.failed), (1, 1, 0));
+
+        let _ = fs::remove_dir_all(output_dir.parent().expect("batch root"));
+    }
+
+    #[test]
+    fn command_names_round_trip() {
+        for command in CommandKind::ALL {
+            assert_eq!(CommandKind::from_name(command.name()), Some(command));
+        }
+        assert_eq!(CommandKind::from_name("export"), None);
+    }
+
     #[test]
     fn import_interaction_capture_maps_observed_to_expect() {
         let capture = InteractionCaptureFile {
             trace_name: "interaction".to_owned(),
             source: "lightweight".to_owned(),
             source_notes: "note".to_owned(),
-            viewport: DifferentialViewport {
+            viewport: TraceViewport {
                 width: 800,
                 height: 400,
             },
-            time_range: DifferentialTimeRange {
+            time_range: TraceTimeRange {
                 start: 0.0,
                 end: 100.0,
             },
-            price_range: DifferentialPriceRange {
+            price_range: TracePriceRange {
                 min: 0.0,
                 max: 100.0,
             },
@@ -1719,19 +2364,19 @@ mod tests {
 
     #[test]
     fn export_interaction_trace_populates_expectation_fields() {
-        let mut trace = InteractionTraceFile {
+        let mut trace = InteractionTrace {
             trace_name: "interaction".to_owned(),
             source: "lightweight".to_owned(),
             source_notes: "note".to_owned(),
-            viewport: DifferentialViewport {
+            viewport: TraceViewport {
                 width: 800,
                 height: 400,
             },
-            time_range: DifferentialTimeRange {
+            time_range: TraceTimeRange {
                 start: 0.0,
                 end: 100.0,
             },
-            price_range: DifferentialPriceRange {
+            price_range: TracePriceRange {
                 min: 0.0,
                 max: 100.0,
             },
@@ -1739,11 +2384,11 @@ mod tests {
             scenarios: vec![InteractionScenario {
                 id: "s1".to_owned(),
                 points: vec![
-                    DifferentialPoint {
+                    TracePoint {
                         time: 0.0,
                         value: 10.0,
                     },
-                    DifferentialPoint {
+                    TracePoint {
                         time: 10.0,
                         value: 12.0,
                     },
@@ -1751,6 +2396,7 @@ mod tests {
                 steps: vec![InteractionStep {
                     action: InteractionAction::PointerMove { x: 100.0, y: 120.0 },
                     expect: None,
+                    elapsed_ms: None,
                 }],
             }],
         };
@@ -1773,25 +2419,25 @@ mod tests {
         let capture = LightweightInteractionCaptureFile {
             trace_name: Some("raw-capture".to_owned()),
             source_notes: Some("captured from lwc".to_owned()),
-            viewport: DifferentialViewport {
+            viewport: TraceViewport {
                 width: 1000,
                 height: 500,
             },
-            time_range: DifferentialTimeRange {
+            time_range: TraceTimeRange {
                 start: 0.0,
                 end: 100.0,
             },
-            price_range: DifferentialPriceRange {
+            price_range: TracePriceRange {
                 min: 0.0,
                 max: 100.0,
             },
             tolerance: Some(1e-6),
             points: vec![
-                DifferentialPoint {
+                TracePoint {
                     time: 0.0,
                     value: 10.0,
                 },
-                DifferentialPoint {
+                TracePoint {
                     time: 10.0,
                     value: 20.0,
                 },
@@ -1872,15 +2518,15 @@ mod tests {
             fixtures: vec![LightweightVisualCaptureFixture {
                 id: Some("LWC Fixture 01".to_owned()),
                 description: Some("visual fixture".to_owned()),
-                viewport: Some(DifferentialViewport {
+                viewport: Some(TraceViewport {
                     width: 800,
                     height: 400,
                 }),
-                time_range: Some(DifferentialTimeRange {
+                time_range: Some(TraceTimeRange {
                     start: 0.0,
                     end: 100.0,
                 }),
-                price_range: Some(DifferentialPriceRange {
+                price_range: Some(TracePriceRange {
                     min: 10.0,
                     max: 90.0,
                 }),
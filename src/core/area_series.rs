@@ -1,3 +1,4 @@
+use crate::core::parallel_projection::project_ordered;
 use crate::core::{DataPoint, PriceScale, TimeScale, Viewport};
 use crate::error::ChartResult;
 use serde::{Deserialize, Serialize};
@@ -43,12 +44,11 @@ pub fn project_area_geometry(
         return Ok(AreaGeometry::empty());
     }
 
-    let mut line_points = Vec::with_capacity(points.len());
-    for point in points {
+    let line_points = project_ordered(points, |point| {
         let x = time_scale.time_to_pixel(point.x, viewport)?;
         let y = price_scale.price_to_pixel(point.y, viewport)?;
-        line_points.push(AreaVertex { x, y });
-    }
+        Ok(AreaVertex { x, y })
+    })?;
 
     let baseline_y = f64::from(viewport.height);
     let first_x = line_points[0].x;
@@ -1,3 +1,4 @@
+use crate::core::parallel_projection::project_ordered;
 use crate::core::{OhlcBar, PriceScale, TimeScale, Viewport};
 use crate::error::{ChartError, ChartResult};
 use serde::{Deserialize, Serialize};
@@ -32,15 +33,14 @@ pub fn project_bars(
     }
 
     let half = tick_width_px * 0.5;
-    let mut projected = Vec::with_capacity(bars.len());
-    for bar in bars {
+    project_ordered(bars, |bar| {
         let center_x = time_scale.time_to_pixel(bar.time, viewport)?;
         let open_y = price_scale.price_to_pixel(bar.open, viewport)?;
         let close_y = price_scale.price_to_pixel(bar.close, viewport)?;
         let high_y = price_scale.price_to_pixel(bar.high, viewport)?;
         let low_y = price_scale.price_to_pixel(bar.low, viewport)?;
 
-        projected.push(BarGeometry {
+        Ok(BarGeometry {
             center_x,
             high_y,
             low_y,
@@ -48,8 +48,6 @@ pub fn project_bars(
             close_y,
             open_x: center_x - half,
             close_x: center_x + half,
-        });
-    }
-
-    Ok(projected)
+        })
+    })
 }
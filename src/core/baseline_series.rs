@@ -1,3 +1,4 @@
+use crate::core::parallel_projection::project_ordered;
 use crate::core::{DataPoint, PriceScale, TimeScale, Viewport};
 use crate::error::ChartResult;
 use serde::{Deserialize, Serialize};
@@ -51,12 +52,11 @@ pub fn project_baseline_geometry(
 
     let baseline_y = price_scale.price_to_pixel(baseline_price, viewport)?;
 
-    let mut line_points = Vec::with_capacity(points.len());
-    for point in points {
+    let line_points = project_ordered(points, |point| {
         let x = time_scale.time_to_pixel(point.x, viewport)?;
         let y = price_scale.price_to_pixel(point.y, viewport)?;
-        line_points.push(BaselineVertex { x, y });
-    }
+        Ok(BaselineVertex { x, y })
+    })?;
 
     let first_x = line_points[0].x;
     let last_x = line_points[line_points.len() - 1].x;
@@ -2,9 +2,7 @@ use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
-#[cfg(feature = "parallel-projection")]
-use rayon::prelude::*;
-
+use crate::core::parallel_projection::project_ordered;
 use crate::core::primitives::{datetime_to_unix_seconds, decimal_to_f64};
 use crate::core::{PriceScale, TimeScale, Viewport};
 use crate::error::{ChartError, ChartResult};
@@ -113,36 +111,13 @@ pub fn project_candles(
         ));
     }
 
-    // For large series, optional parallel projection keeps API behavior stable
-    // while reducing wall-clock projection time.
-    #[cfg(feature = "parallel-projection")]
-    {
-        let projected: Vec<ChartResult<CandleGeometry>> = bars
-            .par_iter()
-            .map(|bar| {
-                project_single_candle(*bar, time_scale, price_scale, viewport, body_width_px)
-            })
-            .collect();
-        projected.into_iter().collect()
-    }
-
-    #[cfg(not(feature = "parallel-projection"))]
-    {
-        let mut out = Vec::with_capacity(bars.len());
-        for bar in bars {
-            out.push(project_single_candle(
-                *bar,
-                time_scale,
-                price_scale,
-                viewport,
-                body_width_px,
-            )?);
-        }
-        Ok(out)
-    }
+    project_ordered(bars, |bar| {
+        project_single_candle(*bar, time_scale, price_scale, viewport, body_width_px)
+    })
 }
 
-fn project_single_candle(
+/// Projects one candle; `project_candles` maps this over a slice.
+pub(crate) fn project_single_candle(
     bar: OhlcBar,
     time_scale: TimeScale,
     price_scale: PriceScale,
@@ -0,0 +1,160 @@
+use std::str::FromStr;
+
+use chrono::{Datelike, NaiveDate, NaiveTime};
+use serde::{Deserialize, Serialize};
+
+use crate::error::{ChartError, ChartResult};
+
+/// Time value accepted at the data boundary.
+///
+/// Chart internals operate on unix seconds as `f64`; `ChartTime` keeps the
+/// original representation so label formatting can respect it (e.g. business
+/// days never show intraday clock times).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum ChartTime {
+    UnixSeconds(f64),
+    UnixMillis(f64),
+    /// Calendar day without time of day, anchored at 00:00 UTC.
+    BusinessDay {
+        year: i32,
+        month: u32,
+        day: u32,
+    },
+}
+
+/// Representation family of a `ChartTime`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ChartTimeKind {
+    UnixSeconds,
+    UnixMillis,
+    BusinessDay,
+}
+
+impl ChartTime {
+    /// Builds a validated business-day time.
+    pub fn business_day(year: i32, month: u32, day: u32) -> ChartResult<Self> {
+        let time = Self::BusinessDay { year, month, day };
+        time.to_unix_seconds()?;
+        Ok(time)
+    }
+
+    #[must_use]
+    pub fn kind(self) -> ChartTimeKind {
+        match self {
+            Self::UnixSeconds(_) => ChartTimeKind::UnixSeconds,
+            Self::UnixMillis(_) => ChartTimeKind::UnixMillis,
+            Self::BusinessDay { .. } => ChartTimeKind::BusinessDay,
+        }
+    }
+
+    /// Normalizes into the unix-seconds scale used by chart internals.
+    pub fn to_unix_seconds(self) -> ChartResult<f64> {
+        let seconds = match self {
+            Self::UnixSeconds(seconds) => seconds,
+            Self::UnixMillis(millis) => millis / 1_000.0,
+            Self::BusinessDay { year, month, day } => {
+                let date = NaiveDate::from_ymd_opt(year, month, day).ok_or_else(|| {
+                    ChartError::InvalidData(format!(
+                        "invalid business day {year:04}-{month:02}-{day:02}"
+                    ))
+                })?;
+                date.and_time(NaiveTime::MIN).and_utc().timestamp() as f64
+            }
+        };
+        if !seconds.is_finite() {
+            return Err(ChartError::InvalidData(
+                "chart time must be finite".to_owned(),
+            ));
+        }
+        Ok(seconds)
+    }
+}
+
+impl FromStr for ChartTime {
+    type Err = ChartError;
+
+    /// Parses `yyyy-mm-dd` business days or numeric unix seconds.
+    fn from_str(value: &str) -> ChartResult<Self> {
+        let value = value.trim();
+        if let Ok(date) = NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+            return Ok(Self::BusinessDay {
+                year: date.year(),
+                month: date.month(),
+                day: date.day(),
+            });
+        }
+        value
+            .parse::<f64>()
+            .ok()
+            .filter(|seconds| seconds.is_finite())
+            .map(Self::UnixSeconds)
+            .ok_or_else(|| ChartError::InvalidData(format!("unrecognized chart time `{value}`")))
+    }
+}
+
+/// Resolves the shared representation of a batch of times.
+///
+/// Business days cannot be mixed with timestamps because their labels would
+/// disagree on whether a time of day exists; mixing seconds and milliseconds is
+/// fine and resolves to the first sample's kind.
+pub fn resolve_chart_time_kind(
+    times: impl IntoIterator<Item = ChartTime>,
+) -> ChartResult<Option<ChartTimeKind>> {
+    let mut resolved: Option<ChartTimeKind> = None;
+    for time in times {
+        let kind = time.kind();
+        match resolved {
+            None => resolved = Some(kind),
+            Some(existing)
+                if (existing == ChartTimeKind::BusinessDay)
+                    != (kind == ChartTimeKind::BusinessDay) =>
+            {
+                return Err(ChartError::InvalidData(
+                    "business-day times cannot be mixed with timestamps".to_owned(),
+                ));
+            }
+            Some(_) => {}
+        }
+    }
+    Ok(resolved)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ChartTime, ChartTimeKind, resolve_chart_time_kind};
+
+    #[test]
+    fn business_day_normalizes_to_utc_midnight() {
+        let time = ChartTime::business_day(2024, 1, 2).expect("valid day");
+        assert_eq!(time.to_unix_seconds().expect("seconds"), 1_704_153_600.0);
+        assert!(ChartTime::business_day(2024, 2, 30).is_err());
+    }
+
+    #[test]
+    fn strings_parse_as_business_days_or_seconds() {
+        assert_eq!(
+            "2024-01-02".parse::<ChartTime>().expect("date"),
+            ChartTime::BusinessDay {
+                year: 2024,
+                month: 1,
+                day: 2
+            }
+        );
+        assert_eq!(
+            "1704153600".parse::<ChartTime>().expect("seconds"),
+            ChartTime::UnixSeconds(1_704_153_600.0)
+        );
+        assert!("yesterday".parse::<ChartTime>().is_err());
+    }
+
+    #[test]
+    fn mixed_business_days_and_timestamps_are_rejected() {
+        let day = ChartTime::business_day(2024, 1, 2).expect("valid day");
+        assert!(resolve_chart_time_kind([day, ChartTime::UnixSeconds(0.0)]).is_err());
+        assert_eq!(
+            resolve_chart_time_kind([ChartTime::UnixMillis(0.0), ChartTime::UnixSeconds(1.0)])
+                .expect("kind"),
+            Some(ChartTimeKind::UnixMillis)
+        );
+    }
+}
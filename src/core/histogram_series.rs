@@ -1,3 +1,4 @@
+use crate::core::parallel_projection::project_ordered;
 use crate::core::{DataPoint, PriceScale, TimeScale, Viewport};
 use crate::error::{ChartError, ChartResult};
 use serde::{Deserialize, Serialize};
@@ -37,18 +38,15 @@ pub fn project_histogram_bars(
     let baseline_y = price_scale.price_to_pixel(baseline_price, viewport)?;
     let half_width = bar_width_px * 0.5;
 
-    let mut bars = Vec::with_capacity(points.len());
-    for point in points {
+    project_ordered(points, |point| {
         let x_center = time_scale.time_to_pixel(point.x, viewport)?;
         let y_value = price_scale.price_to_pixel(point.y, viewport)?;
-        bars.push(HistogramBar {
+        Ok(HistogramBar {
             x_center,
             x_left: x_center - half_width,
             x_right: x_center + half_width,
             y_top: y_value.min(baseline_y),
             y_bottom: y_value.max(baseline_y),
-        });
-    }
-
-    Ok(bars)
+        })
+    })
 }
@@ -1,3 +1,4 @@
+use crate::core::parallel_projection::project_ordered;
 use crate::core::{DataPoint, PriceScale, TimeScale, Viewport};
 use crate::error::ChartResult;
 use serde::{Deserialize, Serialize};
@@ -25,12 +26,11 @@ pub fn project_line_segments(
         return Ok(Vec::new());
     }
 
-    let mut mapped = Vec::with_capacity(points.len());
-    for point in points {
+    let mapped = project_ordered(points, |point| {
         let x = time_scale.time_to_pixel(point.x, viewport)?;
         let y = price_scale.price_to_pixel(point.y, viewport)?;
-        mapped.push((x, y));
-    }
+        Ok((x, y))
+    })?;
 
     let mut segments = Vec::with_capacity(mapped.len() - 1);
     for pair in mapped.windows(2) {
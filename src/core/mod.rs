@@ -2,9 +2,11 @@ pub mod area_series;
 pub mod bar_series;
 pub mod baseline_series;
 pub mod candlestick;
+pub mod chart_time;
 pub mod histogram_series;
 pub mod line_series;
 pub mod pane;
+pub mod parallel_projection;
 pub mod price_scale;
 pub mod primitives;
 pub mod scale;
@@ -16,11 +18,16 @@ pub use area_series::{AreaGeometry, AreaVertex, project_area_geometry};
 pub use bar_series::{BarGeometry, project_bars};
 pub use baseline_series::{BaselineGeometry, BaselineVertex, project_baseline_geometry};
 pub use candlestick::{CandleGeometry, OhlcBar, project_candles};
+pub use chart_time::{ChartTime, ChartTimeKind, resolve_chart_time_kind};
 pub use histogram_series::{HistogramBar, project_histogram_bars};
 pub use line_series::{LineSegment, project_line_segments};
 pub use pane::{PaneCollection, PaneDescriptor, PaneId, PaneLayoutRegion};
+pub use parallel_projection::PARALLEL_PROJECTION_MIN_LEN;
 pub use price_scale::{PriceCoordinateSpace, PriceScale, PriceScaleMode, PriceScaleTuning};
 pub use scale::LinearScale;
 pub use time_scale::{TimeIndexCoordinateSpace, TimeScale, TimeScaleTuning};
 pub use types::{DataPoint, Viewport};
-pub use windowing::{candles_in_time_window, points_in_time_window};
+pub use windowing::{
+    candles_in_sorted_time_window, candles_in_time_window, candles_time_window_range,
+    points_in_sorted_time_window, points_in_time_window, points_time_window_range,
+};
@@ -79,6 +79,28 @@ impl PaneCollection {
         self.panes.iter().any(|pane| pane.id == pane_id)
     }
 
+    /// Checks a collection that bypassed the mutators (e.g. deserialized):
+    /// exactly one main pane, unique ids below `next_id`, valid stretch factors.
+    pub fn validated(self) -> ChartResult<Self> {
+        if self.panes.iter().filter(|pane| pane.is_main).count() != 1 {
+            return Err(ChartError::InvalidData(
+                "pane collection must contain exactly one main pane".to_owned(),
+            ));
+        }
+        for (index, pane) in self.panes.iter().enumerate() {
+            validate_stretch_factor(pane.stretch_factor)?;
+            if pane.id.raw() >= self.next_id
+                || self.panes[..index].iter().any(|other| other.id == pane.id)
+            {
+                return Err(ChartError::InvalidData(format!(
+                    "pane id {} is duplicated or not yet allocated",
+                    pane.id.raw()
+                )));
+            }
+        }
+        Ok(self)
+    }
+
     pub fn create_pane(&mut self, stretch_factor: f64) -> ChartResult<PaneId> {
         validate_stretch_factor(stretch_factor)?;
         let pane_id = PaneId::new(self.next_id);
@@ -0,0 +1,42 @@
+#[cfg(feature = "parallel-projection")]
+use rayon::prelude::*;
+
+use crate::error::ChartResult;
+
+/// Input length from which projection fans out across the rayon pool.
+///
+/// Visible windows below this size project faster on the calling thread than
+/// the pool handoff costs. Without the `parallel-projection` feature every
+/// input is projected sequentially.
+pub const PARALLEL_PROJECTION_MIN_LEN: usize = 16_384;
+
+/// Samples per rayon task once projection runs in parallel.
+#[cfg(feature = "parallel-projection")]
+const PARALLEL_PROJECTION_CHUNK_LEN: usize = 4_096;
+
+/// Maps every item through `project`, keeping input order.
+///
+/// The parallel path projects fixed-size chunks and concatenates them in
+/// input order, and reports the error of the first failing item, so output
+/// and errors match the sequential path exactly.
+pub(crate) fn project_ordered<T, U, F>(items: &[T], project: F) -> ChartResult<Vec<U>>
+where
+    T: Sync,
+    U: Send,
+    F: Fn(&T) -> ChartResult<U> + Sync,
+{
+    #[cfg(feature = "parallel-projection")]
+    if items.len() >= PARALLEL_PROJECTION_MIN_LEN {
+        let chunks: Vec<ChartResult<Vec<U>>> = items
+            .par_chunks(PARALLEL_PROJECTION_CHUNK_LEN)
+            .map(|chunk| chunk.iter().map(&project).collect())
+            .collect();
+        let mut out = Vec::with_capacity(items.len());
+        for chunk in chunks {
+            out.extend(chunk?);
+        }
+        return Ok(out);
+    }
+
+    items.iter().map(project).collect()
+}
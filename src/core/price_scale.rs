@@ -190,9 +190,11 @@ impl PriceScale {
         base_value: Option<f64>,
     ) -> ChartResult<Self> {
         if !price_min.is_finite() || !price_max.is_finite() || price_min == price_max {
-            return Err(ChartError::InvalidData(
-                "scale domain must be finite and non-zero".to_owned(),
-            ));
+            return Err(ChartError::InvalidRange {
+                field: "price domain",
+                min: price_min,
+                max: price_max,
+            });
         }
 
         let resolved_base = resolve_mode_base(mode, base_value, price_min, price_max)?;
@@ -227,9 +229,9 @@ impl PriceScale {
         mode: PriceScaleMode,
     ) -> ChartResult<Self> {
         if points.is_empty() {
-            return Err(ChartError::InvalidData(
-                "price scale cannot be built from empty data".to_owned(),
-            ));
+            return Err(ChartError::EmptySeries {
+                context: "price scale from points",
+            });
         }
 
         let mut min = f64::INFINITY;
@@ -264,9 +266,9 @@ impl PriceScale {
         mode: PriceScaleMode,
     ) -> ChartResult<Self> {
         if bars.is_empty() {
-            return Err(ChartError::InvalidData(
-                "price scale cannot be built from empty bars".to_owned(),
-            ));
+            return Err(ChartError::EmptySeries {
+                context: "price scale from bars",
+            });
         }
 
         let mut min = f64::INFINITY;
@@ -327,6 +329,25 @@ impl PriceScale {
         Ok(self)
     }
 
+    /// Checks a scale that bypassed the constructors (e.g. deserialized) by
+    /// rebuilding it from its raw settings; any mismatch is rejected.
+    pub fn validated(self) -> ChartResult<Self> {
+        let rebuilt = Self::new_with_mode_and_base(
+            self.domain_start,
+            self.domain_end,
+            self.mode,
+            self.base_value,
+        )?
+        .with_margins(self.top_margin_ratio, self.bottom_margin_ratio)?
+        .with_inverted(self.inverted);
+        if rebuilt != self {
+            return Err(ChartError::InvalidData(
+                "price scale state is inconsistent with its domain and mode".to_owned(),
+            ));
+        }
+        Ok(self)
+    }
+
     /// Rebuilds this scale using the same raw domain and a different mapping mode.
     pub fn with_mode(self, mode: PriceScaleMode) -> ChartResult<Self> {
         self.with_mode_and_base(mode, self.base_value)
@@ -377,15 +398,57 @@ impl PriceScale {
                 Ok(ticks)
             }
             PriceScaleMode::Log => {
-                let mut ticks = log_ladder_ticks(self.domain_start, self.domain_end, tick_count)?;
+                let (mut ticks, _) =
+                    log_ladder_ticks(self.domain_start, self.domain_end, tick_count)?;
                 if ticks.len() > tick_count {
                     ticks = evenly_sample_ticks(ticks, tick_count);
                 }
+                if self.domain_start > self.domain_end {
+                    ticks.reverse();
+                }
                 Ok(ticks)
             }
         }
     }
 
+    /// Like [`Self::ticks`], but linear-like modes place ticks on multiples of
+    /// a nice 1/2/5 step in the transformed domain, so a pan keeps them on the
+    /// same values and only a span change reflows them. Log ladders already
+    /// sit on fixed values and are returned unchanged.
+    pub fn anchored_ticks(self, tick_count: usize) -> ChartResult<Vec<f64>> {
+        if self.mode == PriceScaleMode::Log || tick_count < 2 {
+            return self.ticks(tick_count);
+        }
+        let base_value = self.resolved_mode_base()?;
+        let (start, end) = self.linear.domain();
+        let mut transformed = nice_linear_ticks(start.min(end), start.max(end), tick_count);
+        if start > end {
+            transformed.reverse();
+        }
+        transformed
+            .into_iter()
+            .map(|value| from_scale_domain(value, self.mode, base_value))
+            .collect()
+    }
+
+    /// Log-mode minor ticks between the majors of `ticks(tick_count)`: the
+    /// remaining integer multiples of each decade, or the skipped decades when
+    /// majors stride over several. Empty in every other mode.
+    pub fn minor_ticks(self, tick_count: usize) -> ChartResult<Vec<f64>> {
+        if self.mode != PriceScaleMode::Log || tick_count < 2 {
+            return Ok(Vec::new());
+        }
+        let (majors, mut minors) =
+            log_ladder_ticks(self.domain_start, self.domain_end, tick_count)?;
+        if majors.len() > tick_count {
+            return Ok(Vec::new());
+        }
+        if self.domain_start > self.domain_end {
+            minors.reverse();
+        }
+        Ok(minors)
+    }
+
     /// Maps a raw price to pixel Y, preserving inverted-axis behavior.
     pub fn price_to_pixel(self, price: f64, viewport: Viewport) -> ChartResult<f64> {
         if !viewport.is_valid() {
@@ -620,9 +683,11 @@ fn resolve_required_base(base_value: Option<f64>) -> ChartResult<f64> {
 
 fn normalize_range(start: f64, end: f64, min_span: f64) -> ChartResult<(f64, f64)> {
     if !start.is_finite() || !end.is_finite() {
-        return Err(ChartError::InvalidData(
-            "scale range must be finite".to_owned(),
-        ));
+        return Err(ChartError::InvalidRange {
+            field: "price",
+            min: start,
+            max: end,
+        });
     }
 
     if start == end {
@@ -669,49 +734,109 @@ fn resolve_price_axis_margins_px(
     Ok((top_px, bottom_px, plot_height))
 }
 
-fn log_ladder_ticks(start: f64, end: f64, tick_count: usize) -> ChartResult<Vec<f64>> {
+/// Major and minor log-scale ticks, ascending.
+///
+/// Majors use the densest decade ladder (`1..9`, `1-2-5`, decades, then every
+/// k-th decade) that fits in `tick_count`; minors are the remaining integer
+/// multiples (or skipped decades) between them. Ranges narrower than a decade
+/// are almost linear on screen, so they fall back to nice linear steps.
+fn log_ladder_ticks(start: f64, end: f64, tick_count: usize) -> ChartResult<(Vec<f64>, Vec<f64>)> {
     if start <= 0.0 || end <= 0.0 {
         return Err(ChartError::InvalidData(
             "log price scale requires values > 0".to_owned(),
         ));
     }
 
-    let ascending = start <= end;
     let min = start.min(end);
     let max = start.max(end);
     let min_exp = min.log10().floor() as i32;
     let max_exp = max.log10().ceil() as i32;
+    let target = tick_count.max(2);
+
+    const DENSE: [f64; 9] = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0];
+    let dense = decade_ladder(min, max, min_exp, max_exp, 1, &DENSE);
+    if dense.len() < target.div_ceil(2) {
+        let positive = nice_linear_ticks(min, max, target)
+            .into_iter()
+            .filter(|value| *value > 0.0)
+            .collect();
+        return Ok((positive, Vec::new()));
+    }
+    if dense.len() <= target {
+        return Ok((dense, Vec::new()));
+    }
+    let one_two_five = decade_ladder(min, max, min_exp, max_exp, 1, &[1.0, 2.0, 5.0]);
+    if one_two_five.len() <= target {
+        return Ok((one_two_five.clone(), subtract_ticks(dense, &one_two_five)));
+    }
+
+    let decades = decade_ladder(min, max, min_exp, max_exp, 1, &[1.0]);
+    let mut stride = 1;
+    loop {
+        let majors = decade_ladder(min, max, min_exp, max_exp, stride, &[1.0]);
+        if majors.len() <= target || majors.len() <= 2 {
+            let minors = if stride == 1 {
+                subtract_ticks(dense, &majors)
+            } else {
+                subtract_ticks(decades, &majors)
+            };
+            return Ok((majors, minors));
+        }
+        stride += 1;
+    }
+}
 
+fn decade_ladder(
+    min: f64,
+    max: f64,
+    min_exp: i32,
+    max_exp: i32,
+    stride: i32,
+    multipliers: &[f64],
+) -> Vec<f64> {
     let mut ticks = Vec::new();
     for exp in min_exp..=max_exp {
+        if exp.rem_euclid(stride) != 0 {
+            continue;
+        }
         let decade = 10_f64.powi(exp);
-        for multiplier in [1.0, 2.0, 5.0] {
+        for multiplier in multipliers {
             let candidate = decade * multiplier;
-            if candidate >= min && candidate <= max {
+            if candidate >= min * (1.0 - 1e-12) && candidate <= max * (1.0 + 1e-12) {
                 ticks.push(candidate);
             }
         }
     }
+    ticks
+}
 
-    if !ticks.iter().any(|value| approx_equal(*value, min)) {
-        ticks.push(min);
-    }
-    if !ticks.iter().any(|value| approx_equal(*value, max)) {
-        ticks.push(max);
-    }
-
-    ticks.sort_by(|lhs, rhs| lhs.total_cmp(rhs));
-    ticks.dedup_by(|lhs, rhs| approx_equal(*lhs, *rhs));
+fn subtract_ticks(ticks: Vec<f64>, remove: &[f64]) -> Vec<f64> {
+    ticks
+        .into_iter()
+        .filter(|value| !remove.iter().any(|other| approx_equal(*value, *other)))
+        .collect()
+}
 
-    let mut sampled = if ticks.len() > tick_count {
-        evenly_sample_ticks(ticks, tick_count)
+fn nice_linear_ticks(min: f64, max: f64, tick_count: usize) -> Vec<f64> {
+    let raw_step = (max - min) / (tick_count.max(2) - 1) as f64;
+    if !raw_step.is_finite() || raw_step <= 0.0 {
+        return vec![min];
+    }
+    let magnitude = 10_f64.powf(raw_step.log10().floor());
+    let normalized = raw_step / magnitude;
+    let nice = if normalized <= 1.0 {
+        1.0
+    } else if normalized <= 2.0 {
+        2.0
+    } else if normalized <= 5.0 {
+        5.0
     } else {
-        ticks
+        10.0
     };
-    if !ascending {
-        sampled.reverse();
-    }
-    Ok(sampled)
+    let step = nice * magnitude;
+    let first = (min / step - 1e-9).ceil() as i64;
+    let last = (max / step + 1e-9).floor() as i64;
+    (first..=last).map(|index| index as f64 * step).collect()
 }
 
 fn evenly_sample_ticks(ticks: Vec<f64>, target: usize) -> Vec<f64> {
@@ -12,9 +12,11 @@ pub struct LinearScale {
 impl LinearScale {
     pub fn new(domain_start: f64, domain_end: f64) -> ChartResult<Self> {
         if !domain_start.is_finite() || !domain_end.is_finite() || domain_start == domain_end {
-            return Err(ChartError::InvalidData(
-                "scale domain must be finite and non-zero".to_owned(),
-            ));
+            return Err(ChartError::InvalidRange {
+                field: "scale domain",
+                min: domain_start,
+                max: domain_end,
+            });
         }
 
         Ok(Self {
@@ -268,12 +268,15 @@ impl TimeScaleTuning {
 ///
 /// `full_*` tracks the raw fitted data range.
 /// `visible_*` includes optional padding and user-driven range changes.
+/// `inverted` mirrors the pixel mapping so the newest time sits on the left.
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct TimeScale {
     full_start: f64,
     full_end: f64,
     visible_start: f64,
     visible_end: f64,
+    #[serde(default)]
+    inverted: bool,
 }
 
 impl TimeScale {
@@ -285,6 +288,7 @@ impl TimeScale {
             full_end: normalized.1,
             visible_start: normalized.0,
             visible_end: normalized.1,
+            inverted: false,
         })
     }
 
@@ -306,9 +310,9 @@ impl TimeScale {
         let tuning = tuning.validate()?;
 
         if points.is_empty() && bars.is_empty() {
-            return Err(ChartError::InvalidData(
-                "time scale cannot be built from empty data".to_owned(),
-            ));
+            return Err(ChartError::EmptySeries {
+                context: "time scale from data",
+            });
         }
 
         let mut min = f64::INFINITY;
@@ -344,6 +348,7 @@ impl TimeScale {
             full_end,
             visible_start,
             visible_end,
+            inverted: false,
         })
     }
 
@@ -362,6 +367,19 @@ impl TimeScale {
         (self.visible_start, self.visible_end)
     }
 
+    /// Returns whether the pixel mapping direction is inverted.
+    #[must_use]
+    pub fn is_inverted(self) -> bool {
+        self.inverted
+    }
+
+    /// Returns a copy with updated inverted-axis behavior.
+    #[must_use]
+    pub fn with_inverted(mut self, inverted: bool) -> Self {
+        self.inverted = inverted;
+        self
+    }
+
     /// Overrides the visible range without modifying the full fitted range.
     pub fn set_visible_range(&mut self, start: f64, end: f64) -> ChartResult<()> {
         let normalized = normalize_range(start, end, 1e-9)?;
@@ -370,6 +388,20 @@ impl TimeScale {
         Ok(())
     }
 
+    /// Checks a scale that bypassed the constructors (e.g. deserialized):
+    /// both ranges must be finite with `start < end`.
+    pub fn validated(self) -> ChartResult<Self> {
+        let is_valid = |start: f64, end: f64| start.is_finite() && end.is_finite() && start < end;
+        if !is_valid(self.full_start, self.full_end)
+            || !is_valid(self.visible_start, self.visible_end)
+        {
+            return Err(ChartError::InvalidData(
+                "time scale ranges must be finite with start < end".to_owned(),
+            ));
+        }
+        Ok(self)
+    }
+
     /// Applies optional visible-range constraints against full-range edges.
     ///
     /// - `fix_left_edge`: keeps visible start at or after full start
@@ -519,7 +551,7 @@ impl TimeScale {
         tuning: TimeScaleTuning,
     ) -> ChartResult<()> {
         let fitted = Self::from_mixed_data_tuned(points, bars, tuning)?;
-        *self = fitted;
+        *self = fitted.with_inverted(self.inverted);
         Ok(())
     }
 
@@ -529,7 +561,7 @@ impl TimeScale {
         tuning: TimeScaleTuning,
     ) -> ChartResult<()> {
         let fitted = Self::from_data_tuned(points, tuning)?;
-        *self = fitted;
+        *self = fitted.with_inverted(self.inverted);
         Ok(())
     }
 
@@ -622,11 +654,24 @@ impl TimeScale {
     }
 
     pub fn time_to_pixel(self, time: f64, viewport: Viewport) -> ChartResult<f64> {
-        self.visible_linear()?.domain_to_pixel(time, viewport)
+        let pixel = self.visible_linear()?.domain_to_pixel(time, viewport)?;
+        Ok(self.mirror_pixel(pixel, viewport))
     }
 
     pub fn pixel_to_time(self, pixel: f64, viewport: Viewport) -> ChartResult<f64> {
-        self.visible_linear()?.pixel_to_domain(pixel, viewport)
+        self.visible_linear()?
+            .pixel_to_domain(self.mirror_pixel(pixel, viewport), viewport)
+    }
+
+    /// Maps between screen X and the left-to-right pixel space the index and
+    /// pan math runs in; the mirror is its own inverse.
+    #[must_use]
+    pub fn mirror_pixel(self, pixel: f64, viewport: Viewport) -> f64 {
+        if self.inverted {
+            f64::from(viewport.width) - pixel
+        } else {
+            pixel
+        }
     }
 
     fn visible_linear(self) -> ChartResult<LinearScale> {
@@ -636,9 +681,11 @@ impl TimeScale {
 
 fn normalize_range(start: f64, end: f64, min_span: f64) -> ChartResult<(f64, f64)> {
     if !start.is_finite() || !end.is_finite() {
-        return Err(ChartError::InvalidData(
-            "scale range must be finite".to_owned(),
-        ));
+        return Err(ChartError::InvalidRange {
+            field: "time",
+            min: start,
+            max: end,
+        });
     }
 
     if start == end {
@@ -1,3 +1,5 @@
+use std::ops::Range;
+
 use crate::core::{DataPoint, OhlcBar};
 
 /// Returns points whose logical time falls inside an inclusive time window.
@@ -31,3 +33,47 @@ pub fn candles_in_time_window(candles: &[OhlcBar], start: f64, end: f64) -> Vec<
         .filter(|candle| candle.time >= min_t && candle.time <= max_t)
         .collect()
 }
+
+/// Index range of the time-sorted `points` inside an inclusive time window.
+///
+/// Binary-searches both edges, so `points` must be sorted by `x`.
+#[must_use]
+pub fn points_time_window_range(points: &[DataPoint], start: f64, end: f64) -> Range<usize> {
+    sorted_time_window_range(points, start, end, |point| point.x)
+}
+
+/// Index range of the time-sorted `candles` inside an inclusive time window.
+///
+/// Binary-searches both edges, so `candles` must be sorted by `time`.
+#[must_use]
+pub fn candles_time_window_range(candles: &[OhlcBar], start: f64, end: f64) -> Range<usize> {
+    sorted_time_window_range(candles, start, end, |candle| candle.time)
+}
+
+/// Borrowing counterpart of [`points_in_time_window`] for time-sorted points.
+#[must_use]
+pub fn points_in_sorted_time_window(points: &[DataPoint], start: f64, end: f64) -> &[DataPoint] {
+    &points[points_time_window_range(points, start, end)]
+}
+
+/// Borrowing counterpart of [`candles_in_time_window`] for time-sorted candles.
+#[must_use]
+pub fn candles_in_sorted_time_window(candles: &[OhlcBar], start: f64, end: f64) -> &[OhlcBar] {
+    &candles[candles_time_window_range(candles, start, end)]
+}
+
+fn sorted_time_window_range<T>(
+    samples: &[T],
+    start: f64,
+    end: f64,
+    time_of: impl Fn(&T) -> f64,
+) -> Range<usize> {
+    let (min_t, max_t) = if start <= end {
+        (start, end)
+    } else {
+        (end, start)
+    };
+    let first = samples.partition_point(|sample| time_of(sample) < min_t);
+    let last = first + samples[first..].partition_point(|sample| time_of(sample) <= max_t);
+    first..last
+}
@@ -0,0 +1,9 @@
+//! Data sources that feed chart engines.
+//!
+//! Everything here produces plain `core` samples (`DataPoint`, `OhlcBar`) and
+//! never touches engine internals, so sources stay usable from examples,
+//! benches, and tests alike.
+
+pub mod sim;
+#[cfg(feature = "stream")]
+pub mod stream;
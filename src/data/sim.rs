@@ -0,0 +1,417 @@
+//! Deterministic market-data simulator.
+//!
+//! `RandomWalk` produces seeded random-walk ticks with optional time gaps and
+//! trading sessions, `CandleAggregator` folds ticks into OHLC bars, and
+//! `SimReplay` drives both from a virtual clock (`advance(dt)`). The same seed
+//! and config always yield the same samples on every platform.
+
+use crate::core::{DataPoint, OhlcBar};
+use crate::error::{ChartError, ChartResult};
+
+const SECONDS_PER_DAY: f64 = 86_400.0;
+
+/// Daily session window in seconds from UTC midnight; ticks are only emitted
+/// inside it. Windows with `start > end` wrap over midnight.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SimSession {
+    pub start_seconds_of_day: f64,
+    pub end_seconds_of_day: f64,
+}
+
+impl SimSession {
+    #[must_use]
+    pub fn new(start_seconds_of_day: f64, end_seconds_of_day: f64) -> Self {
+        Self {
+            start_seconds_of_day,
+            end_seconds_of_day,
+        }
+    }
+
+    fn contains(self, time: f64) -> bool {
+        let second = time.rem_euclid(SECONDS_PER_DAY);
+        if self.start_seconds_of_day < self.end_seconds_of_day {
+            second >= self.start_seconds_of_day && second < self.end_seconds_of_day
+        } else {
+            second >= self.start_seconds_of_day || second < self.end_seconds_of_day
+        }
+    }
+
+    /// Earliest in-session time at or after `time`.
+    fn next_open(self, time: f64) -> f64 {
+        if self.contains(time) {
+            return time;
+        }
+        let day_start = time - time.rem_euclid(SECONDS_PER_DAY);
+        let open = day_start + self.start_seconds_of_day;
+        if open >= time {
+            open
+        } else {
+            open + SECONDS_PER_DAY
+        }
+    }
+}
+
+/// Configuration for `RandomWalk`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RandomWalkConfig {
+    pub seed: u64,
+    pub start_time: f64,
+    /// Time between consecutive ticks.
+    pub tick_interval: f64,
+    pub start_price: f64,
+    /// Standard deviation of the per-tick relative price change.
+    pub volatility: f64,
+    /// Mean per-tick relative price change.
+    pub drift: f64,
+    /// Probability that a tick is preceded by a gap of missing ticks.
+    pub gap_probability: f64,
+    /// Maximum number of ticks skipped by a single gap.
+    pub max_gap_ticks: u32,
+    pub session: Option<SimSession>,
+}
+
+impl Default for RandomWalkConfig {
+    fn default() -> Self {
+        Self {
+            seed: 0x5eed,
+            start_time: 1_700_000_000.0,
+            tick_interval: 1.0,
+            start_price: 100.0,
+            volatility: 0.001,
+            drift: 0.0,
+            gap_probability: 0.0,
+            max_gap_ticks: 0,
+            session: None,
+        }
+    }
+}
+
+impl RandomWalkConfig {
+    #[must_use]
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    #[must_use]
+    pub fn with_start(mut self, start_time: f64, start_price: f64) -> Self {
+        self.start_time = start_time;
+        self.start_price = start_price;
+        self
+    }
+
+    #[must_use]
+    pub fn with_tick_interval(mut self, tick_interval: f64) -> Self {
+        self.tick_interval = tick_interval;
+        self
+    }
+
+    #[must_use]
+    pub fn with_volatility(mut self, volatility: f64) -> Self {
+        self.volatility = volatility;
+        self
+    }
+
+    #[must_use]
+    pub fn with_drift(mut self, drift: f64) -> Self {
+        self.drift = drift;
+        self
+    }
+
+    #[must_use]
+    pub fn with_gaps(mut self, gap_probability: f64, max_gap_ticks: u32) -> Self {
+        self.gap_probability = gap_probability;
+        self.max_gap_ticks = max_gap_ticks;
+        self
+    }
+
+    #[must_use]
+    pub fn with_session(mut self, session: SimSession) -> Self {
+        self.session = Some(session);
+        self
+    }
+
+    fn validate(self) -> ChartResult<Self> {
+        if !self.start_time.is_finite() {
+            return Err(invalid("simulator start time must be finite"));
+        }
+        if !self.tick_interval.is_finite() || self.tick_interval <= 0.0 {
+            return Err(invalid("simulator tick interval must be finite and > 0"));
+        }
+        if !self.start_price.is_finite() || self.start_price <= 0.0 {
+            return Err(invalid("simulator start price must be finite and > 0"));
+        }
+        if !self.volatility.is_finite() || self.volatility < 0.0 {
+            return Err(invalid("simulator volatility must be finite and >= 0"));
+        }
+        if !self.drift.is_finite() {
+            return Err(invalid("simulator drift must be finite"));
+        }
+        if !(0.0..=1.0).contains(&self.gap_probability) {
+            return Err(invalid("simulator gap probability must be within [0, 1]"));
+        }
+        if let Some(session) = self.session {
+            let range = 0.0..SECONDS_PER_DAY;
+            if !range.contains(&session.start_seconds_of_day)
+                || !range.contains(&session.end_seconds_of_day)
+                || session.start_seconds_of_day == session.end_seconds_of_day
+            {
+                return Err(invalid(
+                    "simulator session bounds must be distinct seconds within a day",
+                ));
+            }
+        }
+        Ok(self)
+    }
+}
+
+/// Seeded random-walk tick generator.
+#[derive(Debug, Clone)]
+pub struct RandomWalk {
+    config: RandomWalkConfig,
+    rng: SplitMix64,
+    next_time: f64,
+    price: f64,
+}
+
+impl RandomWalk {
+    pub fn new(config: RandomWalkConfig) -> ChartResult<Self> {
+        let config = config.validate()?;
+        let next_time = match config.session {
+            Some(session) => session.next_open(config.start_time),
+            None => config.start_time,
+        };
+        Ok(Self {
+            config,
+            rng: SplitMix64::new(config.seed),
+            next_time,
+            price: config.start_price,
+        })
+    }
+
+    #[must_use]
+    pub fn config(&self) -> RandomWalkConfig {
+        self.config
+    }
+
+    /// Time of the tick `next_tick` will return.
+    #[must_use]
+    pub fn peek_time(&self) -> f64 {
+        self.next_time
+    }
+
+    pub fn next_tick(&mut self) -> DataPoint {
+        let step = self.config.drift + self.config.volatility * self.rng.next_standard_normal();
+        // Relative steps keep prices positive for any volatility.
+        self.price *= step.exp();
+        let tick = DataPoint::new(self.next_time, self.price);
+        self.advance_clock();
+        tick
+    }
+
+    pub fn ticks(&mut self, count: usize) -> Vec<DataPoint> {
+        (0..count).map(|_| self.next_tick()).collect()
+    }
+
+    fn advance_clock(&mut self) {
+        let mut steps = 1.0;
+        if self.config.max_gap_ticks > 0 && self.rng.next_unit() < self.config.gap_probability {
+            steps += f64::from(self.rng.next_below(self.config.max_gap_ticks) + 1);
+        }
+        self.next_time += steps * self.config.tick_interval;
+        if let Some(session) = self.config.session {
+            self.next_time = session.next_open(self.next_time);
+        }
+    }
+}
+
+/// Folds ticks into fixed-interval OHLC bars aligned to multiples of
+/// `bar_interval`.
+#[derive(Debug, Clone)]
+pub struct CandleAggregator {
+    bar_interval: f64,
+    forming: Option<OhlcBar>,
+}
+
+impl CandleAggregator {
+    pub fn new(bar_interval: f64) -> ChartResult<Self> {
+        if !bar_interval.is_finite() || bar_interval <= 0.0 {
+            return Err(invalid("candle interval must be finite and > 0"));
+        }
+        Ok(Self {
+            bar_interval,
+            forming: None,
+        })
+    }
+
+    /// Bar currently being built, if any.
+    #[must_use]
+    pub fn forming(&self) -> Option<OhlcBar> {
+        self.forming
+    }
+
+    /// Applies one tick and returns the updated forming bar.
+    ///
+    /// The returned bar either replaces the previous forming bar (same time)
+    /// or starts a new one, matching `ChartEngine::update_candle` semantics.
+    pub fn push(&mut self, tick: DataPoint) -> OhlcBar {
+        let bar_time = (tick.x / self.bar_interval).floor() * self.bar_interval;
+        let bar = match self.forming {
+            Some(bar) if bar.time == bar_time => OhlcBar {
+                high: bar.high.max(tick.y),
+                low: bar.low.min(tick.y),
+                close: tick.y,
+                ..bar
+            },
+            _ => OhlcBar {
+                time: bar_time,
+                open: tick.y,
+                high: tick.y,
+                low: tick.y,
+                close: tick.y,
+            },
+        };
+        self.forming = Some(bar);
+        bar
+    }
+}
+
+/// Generates `count` complete bars from a random walk.
+pub fn simulate_candles(
+    config: RandomWalkConfig,
+    bar_interval: f64,
+    count: usize,
+) -> ChartResult<Vec<OhlcBar>> {
+    let mut walk = RandomWalk::new(config)?;
+    let mut aggregator = CandleAggregator::new(bar_interval)?;
+    let mut bars: Vec<OhlcBar> = Vec::with_capacity(count);
+    while bars.len() < count {
+        let previous = aggregator.forming();
+        let bar = aggregator.push(walk.next_tick());
+        if let Some(previous) = previous.filter(|previous| previous.time != bar.time) {
+            bars.push(previous);
+        }
+    }
+    Ok(bars)
+}
+
+/// Samples produced by one `SimReplay::advance` call.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SimBatch {
+    pub ticks: Vec<DataPoint>,
+    /// Every bar touched by the ticks in order; consecutive entries with the
+    /// same time are successive updates of one forming bar.
+    pub candles: Vec<OhlcBar>,
+}
+
+impl SimBatch {
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.ticks.is_empty()
+    }
+}
+
+/// Replays a random walk against a virtual clock.
+#[derive(Debug, Clone)]
+pub struct SimReplay {
+    walk: RandomWalk,
+    aggregator: CandleAggregator,
+    clock: f64,
+}
+
+impl SimReplay {
+    pub fn new(config: RandomWalkConfig, bar_interval: f64) -> ChartResult<Self> {
+        Ok(Self {
+            walk: RandomWalk::new(config)?,
+            aggregator: CandleAggregator::new(bar_interval)?,
+            clock: config.start_time,
+        })
+    }
+
+    /// Current virtual time.
+    #[must_use]
+    pub fn clock(&self) -> f64 {
+        self.clock
+    }
+
+    /// Moves the clock forward by `dt` and returns every sample that became due.
+    pub fn advance(&mut self, dt: f64) -> ChartResult<SimBatch> {
+        if !dt.is_finite() || dt < 0.0 {
+            return Err(invalid("replay step must be finite and >= 0"));
+        }
+        self.clock += dt;
+        let mut batch = SimBatch::default();
+        while self.walk.peek_time() <= self.clock {
+            let tick = self.walk.next_tick();
+            batch.candles.push(self.aggregator.push(tick));
+            batch.ticks.push(tick);
+        }
+        Ok(batch)
+    }
+}
+
+fn invalid(message: &str) -> ChartError {
+    ChartError::InvalidData(message.to_owned())
+}
+
+/// SplitMix64: tiny, seedable, and identical across platforms.
+#[derive(Debug, Clone)]
+struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform sample in `[0, 1)`.
+    fn next_unit(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1_u64 << 53) as f64
+    }
+
+    fn next_below(&mut self, bound: u32) -> u32 {
+        (self.next_u64() % u64::from(bound)) as u32
+    }
+
+    /// Box-Muller transform; one normal sample per call keeps the stream simple.
+    fn next_standard_normal(&mut self) -> f64 {
+        let u1 = 1.0 - self.next_unit();
+        let u2 = self.next_unit();
+        (-2.0 * u1.ln()).sqrt() * (std::f64::consts::TAU * u2).cos()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SimSession, SplitMix64};
+
+    #[test]
+    fn split_mix_is_stable_for_a_seed() {
+        let mut rng = SplitMix64::new(0);
+        assert_eq!(rng.next_u64(), 0xe220_a839_7b1d_cdaf);
+        let unit = rng.next_unit();
+        assert!((0.0..1.0).contains(&unit));
+    }
+
+    #[test]
+    fn session_next_open_skips_to_following_day() {
+        let session = SimSession::new(9.0 * 3_600.0, 17.0 * 3_600.0);
+        assert_eq!(session.next_open(10.0 * 3_600.0), 10.0 * 3_600.0);
+        assert_eq!(session.next_open(8.0 * 3_600.0), 9.0 * 3_600.0);
+        assert_eq!(session.next_open(18.0 * 3_600.0), 33.0 * 3_600.0);
+
+        let overnight = SimSession::new(22.0 * 3_600.0, 2.0 * 3_600.0);
+        assert!(overnight.contains(23.0 * 3_600.0));
+        assert!(overnight.contains(25.0 * 3_600.0));
+        assert_eq!(overnight.next_open(3.0 * 3_600.0), 22.0 * 3_600.0);
+    }
+}
@@ -0,0 +1,498 @@
+//! Async live-feed adapter (feature `stream`).
+//!
+//! A `LiveFeed` produces ticks/candles from any transport (WebSocket, TCP,
+//! replay). `run_live_feed` drives it on a tokio task: it batches events,
+//! reconnects with exponential backoff (kept until a session proves
+//! healthy), and forwards everything through a
+//! thread-safe queue. The UI thread owns the `StreamReceiver` and calls
+//! `apply_pending` once per frame, so the engine itself never crosses threads.
+
+use std::future::Future;
+use std::sync::mpsc;
+use std::time::Duration;
+
+use serde_json::Value;
+use tokio::runtime::Handle;
+use tokio::task::JoinHandle;
+use tokio::time::Instant;
+
+use crate::api::{ChartEngine, EngineCommand};
+use crate::core::{DataPoint, OhlcBar};
+use crate::error::{ChartError, ChartResult};
+use crate::render::Renderer;
+
+/// One realtime sample.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FeedEvent {
+    /// Applied with `ChartEngine::update_point`.
+    Tick(DataPoint),
+    /// Applied with `ChartEngine::update_candle`.
+    Candle(OhlcBar),
+}
+
+impl From<FeedEvent> for EngineCommand {
+    fn from(event: FeedEvent) -> Self {
+        match event {
+            FeedEvent::Tick(point) => Self::UpdatePoint(point),
+            FeedEvent::Candle(candle) => Self::UpdateCandle(candle),
+        }
+    }
+}
+
+/// Source of realtime events.
+///
+/// `next_event` may be cancelled while a batch window closes, so it must be
+/// cancel-safe (no event may be lost when its future is dropped).
+pub trait LiveFeed: Send + 'static {
+    /// Opens the connection; called again after every disconnect.
+    fn connect(&mut self) -> impl Future<Output = ChartResult<()>> + Send;
+
+    /// Waits for the next event. `Ok(None)` ends the stream cleanly, `Err`
+    /// triggers a reconnect.
+    fn next_event(&mut self) -> impl Future<Output = ChartResult<Option<FeedEvent>>> + Send;
+}
+
+/// Batching and reconnect policy for `run_live_feed`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StreamConfig {
+    /// Flushes a batch once it holds this many events.
+    pub max_batch_len: usize,
+    /// Flushes a batch this long after its first event.
+    pub batch_window: Duration,
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+    /// How long a session must stay connected before the backoff resets.
+    /// Sessions dropping sooner count as failed attempts, so a feed that
+    /// connects and disconnects at once still backs off.
+    pub healthy_after: Duration,
+    /// Consecutive failed attempts (refused connects or short-lived
+    /// sessions) before giving up; `None` retries forever.
+    pub max_reconnect_attempts: Option<u32>,
+}
+
+impl Default for StreamConfig {
+    fn default() -> Self {
+        Self {
+            max_batch_len: 256,
+            batch_window: Duration::from_millis(16),
+            initial_backoff: Duration::from_millis(250),
+            max_backoff: Duration::from_secs(30),
+            healthy_after: Duration::from_secs(10),
+            max_reconnect_attempts: None,
+        }
+    }
+}
+
+impl StreamConfig {
+    #[must_use]
+    pub fn with_batching(mut self, max_batch_len: usize, batch_window: Duration) -> Self {
+        self.max_batch_len = max_batch_len;
+        self.batch_window = batch_window;
+        self
+    }
+
+    #[must_use]
+    pub fn with_backoff(mut self, initial_backoff: Duration, max_backoff: Duration) -> Self {
+        self.initial_backoff = initial_backoff;
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    #[must_use]
+    pub fn with_healthy_after(mut self, healthy_after: Duration) -> Self {
+        self.healthy_after = healthy_after;
+        self
+    }
+
+    #[must_use]
+    pub fn with_max_reconnect_attempts(mut self, max_reconnect_attempts: Option<u32>) -> Self {
+        self.max_reconnect_attempts = max_reconnect_attempts;
+        self
+    }
+
+    fn validate(self) -> ChartResult<Self> {
+        if self.max_batch_len == 0 {
+            return Err(ChartError::InvalidData(
+                "stream batch length must be > 0".to_owned(),
+            ));
+        }
+        if self.initial_backoff > self.max_backoff {
+            return Err(ChartError::InvalidData(
+                "stream initial backoff must be <= max backoff".to_owned(),
+            ));
+        }
+        Ok(self)
+    }
+
+    /// Delay before reconnect attempt `attempt` (1-based): doubles from
+    /// `initial_backoff` and saturates at `max_backoff`.
+    #[must_use]
+    pub fn backoff_delay(&self, attempt: u32) -> Duration {
+        let factor = 1_u32 << attempt.saturating_sub(1).min(16);
+        self.initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff)
+    }
+}
+
+/// Connection lifecycle reported alongside batches.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FeedStatus {
+    Connected,
+    Disconnected {
+        reason: String,
+    },
+    Reconnecting {
+        attempt: u32,
+        delay: Duration,
+    },
+    /// The feed ended cleanly.
+    Finished,
+    /// `max_reconnect_attempts` was exhausted.
+    GaveUp {
+        reason: String,
+    },
+}
+
+/// Item carried by the stream queue.
+#[derive(Debug, Clone, PartialEq)]
+pub enum StreamMessage {
+    Batch(Vec<FeedEvent>),
+    Status(FeedStatus),
+}
+
+/// Producer half of the stream queue; `Send`, owned by the feed task.
+#[derive(Debug, Clone)]
+pub struct StreamSender {
+    inner: mpsc::Sender<StreamMessage>,
+}
+
+impl StreamSender {
+    /// Returns `false` once the receiver is gone.
+    pub fn send(&self, message: StreamMessage) -> bool {
+        self.inner.send(message).is_ok()
+    }
+}
+
+/// Consumer half of the stream queue, drained on the UI thread.
+#[derive(Debug)]
+pub struct StreamReceiver {
+    inner: mpsc::Receiver<StreamMessage>,
+    status: Option<FeedStatus>,
+}
+
+/// Outcome of one `StreamReceiver::apply_pending` call.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StreamApplyReport {
+    pub batches: usize,
+    pub applied: usize,
+    /// Events rejected by the engine (e.g. out-of-order after a reconnect).
+    pub rejected: usize,
+    pub statuses: Vec<FeedStatus>,
+}
+
+impl StreamReceiver {
+    /// Latest lifecycle status seen by `apply_pending`.
+    #[must_use]
+    pub fn status(&self) -> Option<&FeedStatus> {
+        self.status.as_ref()
+    }
+
+    /// Applies every queued batch without blocking.
+    ///
+    /// Rejected events are counted instead of aborting the drain, so one stale
+    /// replayed sample never stalls the rest of the stream.
+    pub fn apply_pending<R: Renderer>(&mut self, engine: &mut ChartEngine<R>) -> StreamApplyReport {
+        let mut report = StreamApplyReport::default();
+        while let Ok(message) = self.inner.try_recv() {
+            match message {
+                StreamMessage::Batch(events) => {
+                    report.batches += 1;
+                    for event in events {
+                        match engine.apply_command(event.into()) {
+                            Ok(()) => report.applied += 1,
+                            Err(_) => report.rejected += 1,
+                        }
+                    }
+                }
+                StreamMessage::Status(status) => {
+                    self.status = Some(status.clone());
+                    report.statuses.push(status);
+                }
+            }
+        }
+        report
+    }
+}
+
+/// Creates a connected sender/receiver pair.
+#[must_use]
+pub fn stream_channel() -> (StreamSender, StreamReceiver) {
+    let (inner_sender, inner_receiver) = mpsc::channel();
+    (
+        StreamSender {
+            inner: inner_sender,
+        },
+        StreamReceiver {
+            inner: inner_receiver,
+            status: None,
+        },
+    )
+}
+
+/// Spawns `run_live_feed` on the current tokio runtime.
+///
+/// The task stops by itself when the feed finishes, gives up, or the returned
+/// receiver is dropped. Called outside a runtime this returns an error
+/// instead of panicking; use `spawn_live_feed_on` to pick a runtime from
+/// any thread.
+pub fn spawn_live_feed<F: LiveFeed>(
+    feed: F,
+    config: StreamConfig,
+) -> ChartResult<(JoinHandle<()>, StreamReceiver)> {
+    let handle = Handle::try_current().map_err(|err| {
+        ChartError::InvalidData(format!("spawn_live_feed needs a tokio runtime: {err}"))
+    })?;
+    spawn_live_feed_on(&handle, feed, config)
+}
+
+/// Spawns `run_live_feed` on the runtime behind `handle`.
+pub fn spawn_live_feed_on<F: LiveFeed>(
+    handle: &Handle,
+    feed: F,
+    config: StreamConfig,
+) -> ChartResult<(JoinHandle<()>, StreamReceiver)> {
+    let config = config.validate()?;
+    let (sender, receiver) = stream_channel();
+    Ok((handle.spawn(run_live_feed(feed, config, sender)), receiver))
+}
+
+/// Drives `feed` until it finishes, gives up, or `sender` is disconnected.
+pub async fn run_live_feed<F: LiveFeed>(mut feed: F, config: StreamConfig, sender: StreamSender) {
+    let config = match config.validate() {
+        Ok(config) => config,
+        Err(err) => {
+            sender.send(StreamMessage::Status(FeedStatus::GaveUp {
+                reason: err.to_string(),
+            }));
+            return;
+        }
+    };
+    let mut failed_attempts = 0_u32;
+    loop {
+        if let Err(err) = feed.connect().await {
+            failed_attempts += 1;
+            if !back_off(&config, &sender, failed_attempts, err.to_string()).await {
+                return;
+            }
+            continue;
+        }
+        let connected_at = Instant::now();
+        if !sender.send(StreamMessage::Status(FeedStatus::Connected)) {
+            return;
+        }
+
+        match pump_batches(&mut feed, &config, &sender).await {
+            PumpOutcome::Finished => {
+                sender.send(StreamMessage::Status(FeedStatus::Finished));
+                return;
+            }
+            PumpOutcome::ReceiverGone => return,
+            PumpOutcome::Disconnected(reason) => {
+                let status = FeedStatus::Disconnected {
+                    reason: reason.clone(),
+                };
+                if !sender.send(StreamMessage::Status(status)) {
+                    return;
+                }
+                // Only a session that stayed up resets the backoff; one that
+                // dropped right away is retried like a refused connect.
+                if connected_at.elapsed() >= config.healthy_after {
+                    failed_attempts = 0;
+                } else {
+                    failed_attempts += 1;
+                    if !back_off(&config, &sender, failed_attempts, reason).await {
+                        return;
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Reports and waits out failed attempt `attempt`; `false` when the feed
+/// gave up or the receiver is gone.
+async fn back_off(
+    config: &StreamConfig,
+    sender: &StreamSender,
+    attempt: u32,
+    reason: String,
+) -> bool {
+    if config
+        .max_reconnect_attempts
+        .is_some_and(|max| attempt > max)
+    {
+        sender.send(StreamMessage::Status(FeedStatus::GaveUp { reason }));
+        return false;
+    }
+    let delay = config.backoff_delay(attempt);
+    if !sender.send(StreamMessage::Status(FeedStatus::Reconnecting {
+        attempt,
+        delay,
+    })) {
+        return false;
+    }
+    tokio::time::sleep(delay).await;
+    true
+}
+
+enum PumpOutcome {
+    Finished,
+    ReceiverGone,
+    Disconnected(String),
+}
+
+async fn pump_batches<F: LiveFeed>(
+    feed: &mut F,
+    config: &StreamConfig,
+    sender: &StreamSender,
+) -> PumpOutcome {
+    let mut batch = Vec::new();
+    let mut deadline = Instant::now();
+    loop {
+        let next = if batch.is_empty() {
+            feed.next_event().await
+        } else {
+            match tokio::time::timeout_at(deadline, feed.next_event()).await {
+                Ok(next) => next,
+                Err(_elapsed) => {
+                    if !flush(&mut batch, sender) {
+                        return PumpOutcome::ReceiverGone;
+                    }
+                    continue;
+                }
+            }
+        };
+
+        match next {
+            Ok(Some(event)) => {
+                if batch.is_empty() {
+                    deadline = Instant::now() + config.batch_window;
+                }
+                batch.push(event);
+                if batch.len() >= config.max_batch_len && !flush(&mut batch, sender) {
+                    return PumpOutcome::ReceiverGone;
+                }
+            }
+            Ok(None) => {
+                return if flush(&mut batch, sender) {
+                    PumpOutcome::Finished
+                } else {
+                    PumpOutcome::ReceiverGone
+                };
+            }
+            Err(err) => {
+                return if flush(&mut batch, sender) {
+                    PumpOutcome::Disconnected(err.to_string())
+                } else {
+                    PumpOutcome::ReceiverGone
+                };
+            }
+        }
+    }
+}
+
+fn flush(batch: &mut Vec<FeedEvent>, sender: &StreamSender) -> bool {
+    if batch.is_empty() {
+        return true;
+    }
+    sender.send(StreamMessage::Batch(std::mem::take(batch)))
+}
+
+/// Parses one Binance WebSocket payload (`kline` or `trade`, raw or wrapped in
+/// a combined-stream envelope) into a feed event. Times are converted from
+/// milliseconds to seconds; other event types yield `Ok(None)`.
+pub fn parse_binance_stream_message(text: &str) -> ChartResult<Option<FeedEvent>> {
+    let root: Value = serde_json::from_str(text)
+        .map_err(|err| ChartError::InvalidData(format!("invalid binance payload: {err}")))?;
+    let payload = root.get("data").unwrap_or(&root);
+    match payload.get("e").and_then(Value::as_str) {
+        Some("kline") => {
+            let kline = payload
+                .get("k")
+                .ok_or_else(|| ChartError::InvalidData("binance kline without `k`".to_owned()))?;
+            let candle = OhlcBar::new(
+                binance_millis(kline, "t")? / 1_000.0,
+                binance_number(kline, "o")?,
+                binance_number(kline, "h")?,
+                binance_number(kline, "l")?,
+                binance_number(kline, "c")?,
+            )?;
+            Ok(Some(FeedEvent::Candle(candle)))
+        }
+        Some("trade" | "aggTrade") => Ok(Some(FeedEvent::Tick(DataPoint::new(
+            binance_millis(payload, "T")? / 1_000.0,
+            binance_number(payload, "p")?,
+        )))),
+        _ => Ok(None),
+    }
+}
+
+fn binance_millis(object: &Value, key: &str) -> ChartResult<f64> {
+    object
+        .get(key)
+        .and_then(Value::as_f64)
+        .ok_or_else(|| ChartError::InvalidData(format!("binance field `{key}` must be a number")))
+}
+
+fn binance_number(object: &Value, key: &str) -> ChartResult<f64> {
+    let value = object.get(key);
+    value
+        .and_then(Value::as_str)
+        .and_then(|text| text.parse::<f64>().ok())
+        .or_else(|| value.and_then(Value::as_f64))
+        .filter(|number| number.is_finite())
+        .ok_or_else(|| ChartError::InvalidData(format!("binance field `{key}` is not numeric")))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{FeedEvent, StreamConfig, parse_binance_stream_message};
+
+    #[test]
+    fn backoff_doubles_and_saturates() {
+        let config = StreamConfig::default()
+            .with_backoff(Duration::from_millis(100), Duration::from_millis(500));
+        let delays = (1..=5)
+            .map(|attempt| config.backoff_delay(attempt).as_millis())
+            .collect::<Vec<_>>();
+        assert_eq!(delays, vec![100, 200, 400, 500, 500]);
+    }
+
+    #[test]
+    fn binance_kline_and_trade_payloads_parse() {
+        let kline = r#"{"stream":"btcusdt@kline_1m","data":{"e":"kline","k":{"t":1704067200000,"o":"10.0","h":"12.5","l":"9.5","c":"11.0"}}}"#;
+        let Some(FeedEvent::Candle(candle)) = parse_binance_stream_message(kline).expect("kline")
+        else {
+            panic!("expected candle");
+        };
+        assert_eq!(candle.time, 1_704_067_200.0);
+        assert_eq!(candle.high, 12.5);
+
+        let trade = r#"{"e":"trade","T":1704067200500,"p":"42.25"}"#;
+        assert_eq!(
+            parse_binance_stream_message(trade).expect("trade"),
+            Some(FeedEvent::Tick(crate::core::DataPoint::new(
+                1_704_067_200.5,
+                42.25
+            )))
+        );
+        assert_eq!(
+            parse_binance_stream_message(r#"{"e":"depthUpdate"}"#).expect("other"),
+            None
+        );
+        assert!(parse_binance_stream_message("not json").is_err());
+    }
+}
@@ -2,11 +2,53 @@ use thiserror::Error;
 
 pub type ChartResult<T> = Result<T, ChartError>;
 
+/// Errors returned by chart-rs.
+///
+/// Structured variants keep the context hosts need for recovery logic;
+/// `InvalidData` remains the catch-all for validation failures that do not
+/// have a dedicated variant yet. New variants may be added in minor releases.
 #[derive(Debug, Error)]
+#[non_exhaustive]
 pub enum ChartError {
     #[error("invalid viewport size: width={width}, height={height}")]
     InvalidViewport { width: u32, height: u32 },
 
     #[error("invalid data: {0}")]
     InvalidData(String),
+
+    /// A range (`min`, `max` as given by the caller) is non-finite or empty.
+    #[error("invalid {field} range: min={min}, max={max}")]
+    InvalidRange {
+        field: &'static str,
+        min: f64,
+        max: f64,
+    },
+
+    /// An operation needs at least one sample but the input series is empty.
+    #[error("{context} requires a non-empty series")]
+    EmptySeries { context: &'static str },
+
+    /// A realtime update at series position `index` is older than the
+    /// sample before it.
+    #[error("time at series index {index} is older than the latest sample")]
+    NonMonotonicTime { index: usize },
+
+    /// A capacity setting (`field`) is outside its accepted range.
+    #[error("invalid {field} capacity: {capacity} (must be > 0)")]
+    InvalidCapacity {
+        field: &'static str,
+        capacity: usize,
+    },
+
+    /// The render backend could not create or use its drawing target.
+    #[error("renderer unavailable: {0}")]
+    RendererUnavailable(String),
+
+    /// A serialized contract carries a schema version this build cannot read.
+    #[error("unsupported {schema} schema version: found {found}, expected {expected}")]
+    SchemaVersionMismatch {
+        schema: &'static str,
+        expected: u32,
+        found: u32,
+    },
 }
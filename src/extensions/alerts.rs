@@ -0,0 +1,72 @@
+use serde::{Deserialize, Serialize};
+
+use crate::error::{ChartError, ChartResult};
+use crate::render::Color;
+
+/// Side from which the last price reached an alert level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AlertDirection {
+    /// The price rose to or through the level.
+    Up,
+    /// The price fell to or through the level.
+    Down,
+}
+
+impl AlertDirection {
+    /// Direction in which a move from `previous` to `current` crosses
+    /// `level`, or `None` when it does not.
+    ///
+    /// Touching the level counts as crossing; leaving it again does not, so
+    /// a price resting on the level triggers once.
+    #[must_use]
+    pub fn crossing(previous: f64, current: f64, level: f64) -> Option<Self> {
+        if previous < level && current >= level {
+            Some(Self::Up)
+        } else if previous > level && current <= level {
+            Some(Self::Down)
+        } else {
+            None
+        }
+    }
+}
+
+/// Horizontal price level that notifies plugins when the last price
+/// crosses it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PriceAlert {
+    pub id: String,
+    pub price: f64,
+    pub color: Color,
+}
+
+impl PriceAlert {
+    #[must_use]
+    pub fn new(id: impl Into<String>, price: f64) -> Self {
+        Self {
+            id: id.into(),
+            price,
+            color: Color::rgb(1.0, 0.596, 0.0),
+        }
+    }
+
+    #[must_use]
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+
+    pub fn validate(&self) -> ChartResult<()> {
+        if self.id.is_empty() {
+            return Err(ChartError::InvalidData(
+                "price alert id must not be empty".to_owned(),
+            ));
+        }
+        if !self.price.is_finite() {
+            return Err(ChartError::InvalidData(format!(
+                "price alert `{}` must have a finite price",
+                self.id
+            )));
+        }
+        self.color.validate()
+    }
+}
@@ -0,0 +1,65 @@
+use crate::core::{PriceScale, TimeScale, Viewport};
+use crate::error::ChartResult;
+use crate::extensions::HitRegion;
+use crate::render::{ClipRect, LinePrimitive, PathPrimitive, RectPrimitive, TextPrimitive};
+
+/// Scales and plot bounds handed to a custom series renderer.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CustomSeriesRenderContext {
+    pub time_scale: TimeScale,
+    /// Price scale of the pane the series is drawn in.
+    pub price_scale: PriceScale,
+    pub viewport: Viewport,
+    /// Visible time window as `(start, end)`.
+    pub visible_range: (f64, f64),
+    pub plot_clip: ClipRect,
+}
+
+impl CustomSeriesRenderContext {
+    pub fn time_to_pixel(&self, time: f64) -> ChartResult<f64> {
+        self.time_scale.time_to_pixel(time, self.viewport)
+    }
+
+    pub fn price_to_pixel(&self, price: f64) -> ChartResult<f64> {
+        self.price_scale.price_to_pixel(price, self.viewport)
+    }
+}
+
+/// Primitives a custom series emits for one frame.
+///
+/// The engine places them in the series layer of the series' pane and clips
+/// any primitive without its own clip rect to the plot.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CustomSeriesPrimitives {
+    pub lines: Vec<LinePrimitive>,
+    pub rects: Vec<RectPrimitive>,
+    pub paths: Vec<PathPrimitive>,
+    pub texts: Vec<TextPrimitive>,
+    /// Interactive areas of this frame; hover and click events on them are
+    /// broadcast to every plugin since custom series have no event hook.
+    pub hit_regions: Vec<HitRegion>,
+}
+
+/// User-defined series in the spirit of Lightweight Charts custom series.
+///
+/// The engine owns the rows, keeps them sorted by `row_time`, and hands the
+/// visible slice to `autoscale_range` and `render`; the implementation only
+/// decides what a row means and how it is drawn.
+pub trait CustomSeries {
+    type Row: Clone + 'static;
+
+    /// Logical time of `row`.
+    fn row_time(&self, row: &Self::Row) -> f64;
+
+    /// Price range the visible `rows` need on their pane's price scale, or
+    /// `None` when they should not influence autoscale.
+    fn autoscale_range(&self, rows: &[Self::Row]) -> Option<(f64, f64)>;
+
+    /// Projects the visible `rows` into frame primitives.
+    fn render(
+        &self,
+        rows: &[Self::Row],
+        ctx: &CustomSeriesRenderContext,
+        out: &mut CustomSeriesPrimitives,
+    ) -> ChartResult<()>;
+}
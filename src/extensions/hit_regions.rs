@@ -0,0 +1,114 @@
+use serde::{Deserialize, Serialize};
+
+/// Geometry of a hit region in content pixel coordinates.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum HitShape {
+    Rect {
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+    },
+    Circle {
+        center_x: f64,
+        center_y: f64,
+        radius: f64,
+    },
+    /// Line segment widened by `tolerance_px` on each side.
+    Segment {
+        x1: f64,
+        y1: f64,
+        x2: f64,
+        y2: f64,
+        tolerance_px: f64,
+    },
+}
+
+impl HitShape {
+    #[must_use]
+    pub fn contains(self, x: f64, y: f64) -> bool {
+        match self {
+            Self::Rect {
+                x: left,
+                y: top,
+                width,
+                height,
+            } => {
+                let (left, right) = (left.min(left + width), left.max(left + width));
+                let (top, bottom) = (top.min(top + height), top.max(top + height));
+                (left..=right).contains(&x) && (top..=bottom).contains(&y)
+            }
+            Self::Circle {
+                center_x,
+                center_y,
+                radius,
+            } => (x - center_x).hypot(y - center_y) <= radius,
+            Self::Segment {
+                x1,
+                y1,
+                x2,
+                y2,
+                tolerance_px,
+            } => {
+                let (dx, dy) = (x2 - x1, y2 - y1);
+                let length_sq = dx * dx + dy * dy;
+                let t = if length_sq > 0.0 {
+                    (((x - x1) * dx + (y - y1) * dy) / length_sq).clamp(0.0, 1.0)
+                } else {
+                    0.0
+                };
+                (x - (x1 + t * dx)).hypot(y - (y1 + t * dy)) <= tolerance_px
+            }
+        }
+    }
+}
+
+/// Interactive area contributed by a plugin or custom series.
+///
+/// `id` is chosen by the owner and comes back in
+/// `PluginEvent::PrimitiveHovered` / `PrimitiveClicked`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HitRegion {
+    pub id: String,
+    pub shape: HitShape,
+}
+
+impl HitRegion {
+    #[must_use]
+    pub fn new(id: impl Into<String>, shape: HitShape) -> Self {
+        Self {
+            id: id.into(),
+            shape,
+        }
+    }
+
+    #[must_use]
+    pub fn rect(id: impl Into<String>, x: f64, y: f64, width: f64, height: f64) -> Self {
+        Self::new(
+            id,
+            HitShape::Rect {
+                x,
+                y,
+                width,
+                height,
+            },
+        )
+    }
+
+    #[must_use]
+    pub fn circle(id: impl Into<String>, center_x: f64, center_y: f64, radius: f64) -> Self {
+        Self::new(
+            id,
+            HitShape::Circle {
+                center_x,
+                center_y,
+                radius,
+            },
+        )
+    }
+
+    #[must_use]
+    pub fn contains(&self, x: f64, y: f64) -> bool {
+        self.shape.contains(x, y)
+    }
+}
@@ -2,8 +2,13 @@
 //!
 //! Keep extensions feature-gated and avoid coupling them into core paths.
 
+pub mod alerts;
+pub mod custom_series;
+pub mod hit_regions;
 pub mod markers;
 pub mod plugins;
+pub mod profile;
+pub mod trading;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ExtensionStatus {
@@ -12,8 +17,21 @@ pub enum ExtensionStatus {
     Stable,
 }
 
+pub use alerts::{AlertDirection, PriceAlert};
+pub use custom_series::{CustomSeries, CustomSeriesPrimitives, CustomSeriesRenderContext};
+pub use hit_regions::{HitRegion, HitShape};
 pub use markers::{
     MarkerLabelGeometry, MarkerPlacementConfig, MarkerPosition, MarkerSide, PlacedMarker,
     SeriesMarker, place_markers_on_candles,
 };
-pub use plugins::{ChartPlugin, PluginContext, PluginEvent};
+pub use plugins::{
+    ChartPlugin, PluginContext, PluginEvent, VisibleRangeChange, VisibleRangeChangeCause,
+};
+pub use profile::{
+    ProfileAnchor, VolumeProfile, VolumeProfileBar, VolumeProfileBucket, VolumeProfileConfig,
+    VolumeSample, compute_volume_profile, project_volume_profile,
+};
+pub use trading::{
+    OpenPosition, TradeSide, TradingLine, TradingLineKind, TradingOverlayStyle, WorkingOrder,
+    trading_lines,
+};
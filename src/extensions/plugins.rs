@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::core::Viewport;
+use crate::extensions::{AlertDirection, HitRegion};
 use crate::interaction::{CrosshairState, InteractionMode};
 
 /// Read-only state snapshot passed to plugin hooks.
@@ -16,16 +17,97 @@ pub struct PluginContext {
 }
 
 /// Event stream exposed to plugins.
-#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum PluginEvent {
-    DataUpdated { points_len: usize },
-    CandlesUpdated { candles_len: usize },
-    PointerMoved { x: f64, y: f64 },
+    DataUpdated {
+        points_len: usize,
+    },
+    CandlesUpdated {
+        candles_len: usize,
+    },
+    PointerMoved {
+        x: f64,
+        y: f64,
+    },
     PointerLeft,
-    VisibleRangeChanged { start: f64, end: f64 },
+    VisibleRangeChanged {
+        start: f64,
+        end: f64,
+        previous_start: f64,
+        previous_end: f64,
+        cause: VisibleRangeChangeCause,
+    },
     PanStarted,
     PanEnded,
     Rendered,
+    /// The pointer entered hit region `id`.
+    PrimitiveHovered {
+        id: String,
+    },
+    /// The pointer left hit region `id`.
+    PrimitiveHoverEnded {
+        id: String,
+    },
+    PrimitiveClicked {
+        id: String,
+    },
+    /// A working order line was dragged to `price` (sent on release).
+    OrderPriceChanged {
+        id: String,
+        price: f64,
+    },
+    /// The last price crossed price alert `id` at level `price`.
+    AlertTriggered {
+        id: String,
+        price: f64,
+        direction: AlertDirection,
+    },
+    /// A rubber-band zoom selection started at content position `(x, y)`.
+    ZoomSelectionStarted {
+        x: f64,
+        y: f64,
+    },
+    /// A zoom selection was released and applied; `price_domain` is set
+    /// when the price domain was fitted too.
+    ZoomSelectionCompleted {
+        start: f64,
+        end: f64,
+        price_domain: Option<(f64, f64)>,
+    },
+    /// A zoom selection was cancelled or released below the minimum size.
+    ZoomSelectionCancelled,
+    /// The box data selection changed; `samples` counts the selected points
+    /// or candles and the ranges are `None` once the selection is cleared.
+    SelectionChanged {
+        samples: usize,
+        time_range: Option<(f64, f64)>,
+        price_range: Option<(f64, f64)>,
+    },
+}
+
+/// What moved the visible time range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub enum VisibleRangeChangeCause {
+    /// Programmatic calls, data updates and resizes.
+    #[default]
+    Api,
+    Wheel,
+    /// Pointer, touch, pinch and time-axis drags.
+    Drag,
+    Kinetic,
+    /// `animate_time_visible_range` transitions.
+    Animation,
+}
+
+/// Visible time range change delivered to host subscriptions.
+///
+/// With coalesced delivery `previous` is the range before the first change of
+/// the batch and `cause` is the cause of the last one.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct VisibleRangeChange {
+    pub previous: (f64, f64),
+    pub current: (f64, f64),
+    pub cause: VisibleRangeChangeCause,
 }
 
 /// Extension hook interface for bounded custom logic.
@@ -35,4 +117,11 @@ pub enum PluginEvent {
 pub trait ChartPlugin {
     fn id(&self) -> &str;
     fn on_event(&mut self, event: PluginEvent, context: PluginContext);
+
+    /// Hit regions, in content pixels, for primitives this plugin draws or
+    /// overlays. Hover and click events on them are routed back to this
+    /// plugin only; later regions take precedence where they overlap.
+    fn hit_regions(&self, _context: PluginContext) -> Vec<HitRegion> {
+        Vec::new()
+    }
 }
@@ -0,0 +1,287 @@
+use serde::{Deserialize, Serialize};
+
+use crate::core::{OhlcBar, PriceScale, Viewport};
+use crate::error::{ChartError, ChartResult};
+use crate::render::Color;
+
+/// Traded volume of the candle at `time`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct VolumeSample {
+    pub time: f64,
+    pub volume: f64,
+}
+
+impl VolumeSample {
+    #[must_use]
+    pub const fn new(time: f64, volume: f64) -> Self {
+        Self { time, volume }
+    }
+}
+
+/// Plot edge the profile bars grow from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum ProfileAnchor {
+    Left,
+    #[default]
+    Right,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct VolumeProfileConfig {
+    pub bucket_count: usize,
+    /// Share of the total volume the value area covers, in `(0, 1]`.
+    pub value_area_ratio: f64,
+    pub anchor: ProfileAnchor,
+    /// Length of the largest bucket as a share of the plot width.
+    pub max_width_ratio: f64,
+    /// Vertical gap left between adjacent bars.
+    pub bar_gap_px: f64,
+    pub value_area_color: Color,
+    pub outside_color: Color,
+    pub point_of_control_color: Color,
+}
+
+impl Default for VolumeProfileConfig {
+    fn default() -> Self {
+        Self {
+            bucket_count: 24,
+            value_area_ratio: 0.7,
+            anchor: ProfileAnchor::Right,
+            max_width_ratio: 0.25,
+            bar_gap_px: 1.0,
+            value_area_color: Color::rgba(0.16, 0.38, 1.0, 0.35),
+            outside_color: Color::rgba(0.47, 0.49, 0.53, 0.25),
+            point_of_control_color: Color::rgba(0.937, 0.325, 0.314, 0.5),
+        }
+    }
+}
+
+impl VolumeProfileConfig {
+    pub fn validate(self) -> ChartResult<Self> {
+        if self.bucket_count == 0 {
+            return Err(ChartError::InvalidData(
+                "volume profile `bucket_count` must be > 0".to_owned(),
+            ));
+        }
+        for (value, name) in [
+            (self.value_area_ratio, "value_area_ratio"),
+            (self.max_width_ratio, "max_width_ratio"),
+        ] {
+            if !value.is_finite() || value <= 0.0 || value > 1.0 {
+                return Err(ChartError::InvalidData(format!(
+                    "volume profile `{name}` must be in (0, 1]"
+                )));
+            }
+        }
+        if !self.bar_gap_px.is_finite() || self.bar_gap_px < 0.0 {
+            return Err(ChartError::InvalidData(
+                "volume profile `bar_gap_px` must be finite and >= 0".to_owned(),
+            ));
+        }
+        self.value_area_color.validate()?;
+        self.outside_color.validate()?;
+        self.point_of_control_color.validate()?;
+        Ok(self)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct VolumeProfileBucket {
+    pub price_low: f64,
+    pub price_high: f64,
+    pub volume: f64,
+    pub in_value_area: bool,
+}
+
+/// Volume-at-price histogram over a set of candles, buckets ordered by
+/// ascending price.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VolumeProfile {
+    pub buckets: Vec<VolumeProfileBucket>,
+    /// Index of the bucket with the most volume (the lowest one on ties).
+    pub point_of_control: usize,
+    pub value_area_low: f64,
+    pub value_area_high: f64,
+    pub total_volume: f64,
+}
+
+/// Pixel geometry of one profile bar.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct VolumeProfileBar {
+    pub bucket: usize,
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+/// Builds a volume-at-price profile across the candles' low..high range.
+///
+/// Each candle's volume is spread evenly over its own low..high span. With
+/// `volumes` empty every candle weighs 1, which yields a time-at-price
+/// (session) profile; otherwise candles without a sample at their exact
+/// time contribute nothing. Returns `None` when there is nothing to bucket.
+pub fn compute_volume_profile(
+    candles: &[OhlcBar],
+    volumes: &[VolumeSample],
+    config: VolumeProfileConfig,
+) -> ChartResult<Option<VolumeProfile>> {
+    let config = config.validate()?;
+    let mut volume_lookup: Vec<VolumeSample> = volumes
+        .iter()
+        .copied()
+        .filter(|sample| sample.time.is_finite())
+        .collect();
+    volume_lookup.sort_by(|a, b| a.time.total_cmp(&b.time));
+    let weight_of = |time: f64| {
+        if volumes.is_empty() {
+            return 1.0;
+        }
+        volume_lookup
+            .binary_search_by(|sample| sample.time.total_cmp(&time))
+            .ok()
+            .map_or(0.0, |index| volume_lookup[index].volume)
+    };
+
+    let (Some(range_low), Some(range_high)) = (
+        candles.iter().map(|bar| bar.low).reduce(f64::min),
+        candles.iter().map(|bar| bar.high).reduce(f64::max),
+    ) else {
+        return Ok(None);
+    };
+    let bucket_count = config.bucket_count;
+    // A flat range still gets one priced bucket so the profile stays drawable.
+    let span = (range_high - range_low).max(f64::EPSILON * range_high.abs().max(1.0));
+    let bucket_height = span / bucket_count as f64;
+    let bucket_of = |price: f64| {
+        (((price - range_low) / bucket_height).floor().max(0.0) as usize).min(bucket_count - 1)
+    };
+
+    let mut bucket_volumes = vec![0.0; bucket_count];
+    for bar in candles {
+        let weight = weight_of(bar.time);
+        if !weight.is_finite() || weight <= 0.0 {
+            continue;
+        }
+        let (first, last) = (bucket_of(bar.low), bucket_of(bar.high));
+        let bar_span = bar.high - bar.low;
+        if first == last || bar_span <= 0.0 {
+            bucket_volumes[first] += weight;
+            continue;
+        }
+        for (index, volume) in bucket_volumes
+            .iter_mut()
+            .enumerate()
+            .take(last + 1)
+            .skip(first)
+        {
+            let bucket_low = range_low + index as f64 * bucket_height;
+            let overlap = bar.high.min(bucket_low + bucket_height) - bar.low.max(bucket_low);
+            *volume += weight * overlap.max(0.0) / bar_span;
+        }
+    }
+
+    let total_volume: f64 = bucket_volumes.iter().sum();
+    if total_volume <= 0.0 {
+        return Ok(None);
+    }
+    let point_of_control = bucket_volumes
+        .iter()
+        .enumerate()
+        .fold(0, |best, (index, volume)| {
+            if *volume > bucket_volumes[best] {
+                index
+            } else {
+                best
+            }
+        });
+
+    // Grow the value area from the point of control toward the heavier
+    // neighbor until it holds the requested share of the volume.
+    let target = total_volume * config.value_area_ratio;
+    let (mut low, mut high) = (point_of_control, point_of_control);
+    let mut covered = bucket_volumes[point_of_control];
+    while covered < target && (low > 0 || high + 1 < bucket_count) {
+        let below = low.checked_sub(1).map(|index| bucket_volumes[index]);
+        let above = bucket_volumes.get(high + 1).copied();
+        match (below, above) {
+            (Some(below), Some(above)) if above >= below => {
+                high += 1;
+                covered += above;
+            }
+            (Some(below), _) => {
+                low -= 1;
+                covered += below;
+            }
+            (None, Some(above)) => {
+                high += 1;
+                covered += above;
+            }
+            (None, None) => break,
+        }
+    }
+
+    let buckets = bucket_volumes
+        .into_iter()
+        .enumerate()
+        .map(|(index, volume)| VolumeProfileBucket {
+            price_low: range_low + index as f64 * bucket_height,
+            price_high: range_low + (index + 1) as f64 * bucket_height,
+            volume,
+            in_value_area: (low..=high).contains(&index),
+        })
+        .collect::<Vec<_>>();
+    Ok(Some(VolumeProfile {
+        value_area_low: buckets[low].price_low,
+        value_area_high: buckets[high].price_high,
+        buckets,
+        point_of_control,
+        total_volume,
+    }))
+}
+
+/// Projects non-empty profile buckets into bars anchored to one plot edge.
+///
+/// Bar length scales with bucket volume so the point of control spans
+/// `max_width_ratio` of `plot_width`.
+pub fn project_volume_profile(
+    profile: &VolumeProfile,
+    price_scale: PriceScale,
+    viewport: Viewport,
+    plot_width: f64,
+    config: VolumeProfileConfig,
+) -> ChartResult<Vec<VolumeProfileBar>> {
+    let config = config.validate()?;
+    if !plot_width.is_finite() || plot_width <= 0.0 {
+        return Err(ChartError::InvalidData(
+            "volume profile plot width must be finite and > 0".to_owned(),
+        ));
+    }
+    let max_volume = profile.buckets[profile.point_of_control].volume;
+    let max_width = plot_width * config.max_width_ratio;
+
+    let mut bars = Vec::with_capacity(profile.buckets.len());
+    for (bucket, entry) in profile.buckets.iter().enumerate() {
+        if entry.volume <= 0.0 {
+            continue;
+        }
+        let y_low = price_scale.price_to_pixel(entry.price_low, viewport)?;
+        let y_high = price_scale.price_to_pixel(entry.price_high, viewport)?;
+        let (top, bottom) = (y_low.min(y_high), y_low.max(y_high));
+        let extent = bottom - top;
+        let gap = config.bar_gap_px.min(extent * 0.5);
+        let width = max_width * entry.volume / max_volume;
+        let x = match config.anchor {
+            ProfileAnchor::Left => 0.0,
+            ProfileAnchor::Right => plot_width - width,
+        };
+        bars.push(VolumeProfileBar {
+            bucket,
+            x,
+            y: top + gap * 0.5,
+            width,
+            height: extent - gap,
+        });
+    }
+    Ok(bars)
+}
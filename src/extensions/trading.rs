@@ -0,0 +1,301 @@
+use serde::{Deserialize, Serialize};
+
+use crate::error::{ChartError, ChartResult};
+use crate::render::Color;
+
+/// Direction of an order or position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TradeSide {
+    Buy,
+    Sell,
+}
+
+impl TradeSide {
+    /// `1.0` for buys (long), `-1.0` for sells (short).
+    #[must_use]
+    pub fn sign(self) -> f64 {
+        match self {
+            Self::Buy => 1.0,
+            Self::Sell => -1.0,
+        }
+    }
+}
+
+/// Resting order drawn as a horizontal line at its limit/stop price.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorkingOrder {
+    pub id: String,
+    pub side: TradeSide,
+    pub price: f64,
+    pub quantity: f64,
+    /// Whether the line can be dragged to a new price.
+    pub draggable: bool,
+}
+
+impl WorkingOrder {
+    #[must_use]
+    pub fn new(id: impl Into<String>, side: TradeSide, price: f64, quantity: f64) -> Self {
+        Self {
+            id: id.into(),
+            side,
+            price,
+            quantity,
+            draggable: true,
+        }
+    }
+
+    #[must_use]
+    pub fn with_draggable(mut self, draggable: bool) -> Self {
+        self.draggable = draggable;
+        self
+    }
+
+    pub fn validate(&self) -> ChartResult<()> {
+        if !self.price.is_finite() || !self.quantity.is_finite() || self.quantity <= 0.0 {
+            return Err(ChartError::InvalidData(format!(
+                "working order `{}` must have a finite price and quantity > 0",
+                self.id
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Open position with optional take-profit / stop-loss bracket.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OpenPosition {
+    pub id: String,
+    pub side: TradeSide,
+    pub entry_price: f64,
+    pub quantity: f64,
+    pub take_profit: Option<f64>,
+    pub stop_loss: Option<f64>,
+}
+
+impl OpenPosition {
+    #[must_use]
+    pub fn new(id: impl Into<String>, side: TradeSide, entry_price: f64, quantity: f64) -> Self {
+        Self {
+            id: id.into(),
+            side,
+            entry_price,
+            quantity,
+            take_profit: None,
+            stop_loss: None,
+        }
+    }
+
+    #[must_use]
+    pub fn with_take_profit(mut self, price: f64) -> Self {
+        self.take_profit = Some(price);
+        self
+    }
+
+    #[must_use]
+    pub fn with_stop_loss(mut self, price: f64) -> Self {
+        self.stop_loss = Some(price);
+        self
+    }
+
+    /// Profit (negative for a loss) if the position were closed at `price`.
+    #[must_use]
+    pub fn pnl_at(&self, price: f64) -> f64 {
+        (price - self.entry_price) * self.quantity * self.side.sign()
+    }
+
+    pub fn validate(&self) -> ChartResult<()> {
+        let prices_finite = self.entry_price.is_finite()
+            && self.take_profit.is_none_or(f64::is_finite)
+            && self.stop_loss.is_none_or(f64::is_finite);
+        if !prices_finite || !self.quantity.is_finite() || self.quantity <= 0.0 {
+            return Err(ChartError::InvalidData(format!(
+                "open position `{}` must have finite prices and quantity > 0",
+                self.id
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Colors and geometry of the trading overlay.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct TradingOverlayStyle {
+    pub buy_color: Color,
+    pub sell_color: Color,
+    pub take_profit_color: Color,
+    pub stop_loss_color: Color,
+    pub label_text_color: Color,
+    pub line_width: f64,
+    pub font_size_px: f64,
+    pub label_padding_px: f64,
+    /// Distance from an order line, in pixels, that still grabs it.
+    pub drag_hit_half_height_px: f64,
+}
+
+impl Default for TradingOverlayStyle {
+    fn default() -> Self {
+        Self {
+            buy_color: Color::rgb(0.16, 0.38, 1.0),
+            sell_color: Color::rgb(0.937, 0.325, 0.314),
+            take_profit_color: Color::rgb(0.149, 0.651, 0.604),
+            stop_loss_color: Color::rgb(0.937, 0.325, 0.314),
+            label_text_color: Color::rgb(1.0, 1.0, 1.0),
+            line_width: 1.0,
+            font_size_px: 11.0,
+            label_padding_px: 4.0,
+            drag_hit_half_height_px: 4.0,
+        }
+    }
+}
+
+impl TradingOverlayStyle {
+    pub fn validate(self) -> ChartResult<Self> {
+        for (value, name) in [
+            (self.line_width, "line_width"),
+            (self.font_size_px, "font_size_px"),
+        ] {
+            if !value.is_finite() || value <= 0.0 {
+                return Err(ChartError::InvalidData(format!(
+                    "trading overlay `{name}` must be finite and > 0"
+                )));
+            }
+        }
+        for (value, name) in [
+            (self.label_padding_px, "label_padding_px"),
+            (self.drag_hit_half_height_px, "drag_hit_half_height_px"),
+        ] {
+            if !value.is_finite() || value < 0.0 {
+                return Err(ChartError::InvalidData(format!(
+                    "trading overlay `{name}` must be finite and >= 0"
+                )));
+            }
+        }
+        for color in [
+            self.buy_color,
+            self.sell_color,
+            self.take_profit_color,
+            self.stop_loss_color,
+            self.label_text_color,
+        ] {
+            color.validate()?;
+        }
+        Ok(self)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TradingLineKind {
+    Order,
+    Position,
+    TakeProfit,
+    StopLoss,
+}
+
+/// One horizontal overlay line in price space.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TradingLine {
+    /// Id of the order or position the line belongs to.
+    pub owner_id: String,
+    pub kind: TradingLineKind,
+    pub price: f64,
+    pub color: Color,
+    pub label: String,
+    /// Bracket levels are dashed; orders and entries are solid.
+    pub dashed: bool,
+}
+
+/// Lines for every order and position, in draw order (orders first, each
+/// position followed by its bracket).
+///
+/// Position and bracket labels include the PnL at `mark_price` resp. at the
+/// bracket level; without a mark price positions show quantity only.
+#[must_use]
+pub fn trading_lines(
+    orders: &[WorkingOrder],
+    positions: &[OpenPosition],
+    mark_price: Option<f64>,
+    style: TradingOverlayStyle,
+) -> Vec<TradingLine> {
+    let side_color = |side: TradeSide| match side {
+        TradeSide::Buy => style.buy_color,
+        TradeSide::Sell => style.sell_color,
+    };
+    let mut lines = Vec::with_capacity(orders.len() + positions.len() * 3);
+    for order in orders {
+        let side = match order.side {
+            TradeSide::Buy => "BUY",
+            TradeSide::Sell => "SELL",
+        };
+        lines.push(TradingLine {
+            owner_id: order.id.clone(),
+            kind: TradingLineKind::Order,
+            price: order.price,
+            color: side_color(order.side),
+            label: format!("{side} {}", format_quantity(order.quantity)),
+            dashed: false,
+        });
+    }
+    for position in positions {
+        let side = match position.side {
+            TradeSide::Buy => "LONG",
+            TradeSide::Sell => "SHORT",
+        };
+        let quantity = format_quantity(position.quantity);
+        let label = match mark_price {
+            Some(mark) => format!("{side} {quantity} {}", format_pnl(position.pnl_at(mark))),
+            None => format!("{side} {quantity}"),
+        };
+        lines.push(TradingLine {
+            owner_id: position.id.clone(),
+            kind: TradingLineKind::Position,
+            price: position.entry_price,
+            color: side_color(position.side),
+            label,
+            dashed: false,
+        });
+        for (level, kind, name, color) in [
+            (
+                position.take_profit,
+                TradingLineKind::TakeProfit,
+                "TP",
+                style.take_profit_color,
+            ),
+            (
+                position.stop_loss,
+                TradingLineKind::StopLoss,
+                "SL",
+                style.stop_loss_color,
+            ),
+        ] {
+            let Some(price) = level else {
+                continue;
+            };
+            lines.push(TradingLine {
+                owner_id: position.id.clone(),
+                kind,
+                price,
+                color,
+                label: format!("{name} {}", format_pnl(position.pnl_at(price))),
+                dashed: true,
+            });
+        }
+    }
+    lines
+}
+
+fn format_quantity(quantity: f64) -> String {
+    if quantity.fract() == 0.0 {
+        format!("{quantity:.0}")
+    } else {
+        format!("{quantity}")
+    }
+}
+
+fn format_pnl(pnl: f64) -> String {
+    // `-0.00` reads as a loss; show flat results unsigned.
+    if pnl.abs() < 0.005 {
+        "0.00".to_owned()
+    } else {
+        format!("{pnl:+.2}")
+    }
+}
@@ -9,11 +9,33 @@ pub enum InteractionMode {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum CrosshairMode {
     /// Crosshair follows nearest data sample (current default behavior).
+    ///
+    /// Whitespace samples are skipped, but only up to
+    /// `api::CROSSHAIR_SNAP_MAX_WHITESPACE_SKIP` on each side of the pointer;
+    /// past a longer run the crosshair follows the raw pointer instead.
     Magnet,
     /// Crosshair follows raw pointer position without snapping.
     Normal,
     /// Crosshair remains hidden regardless of pointer movement.
     Hidden,
+    /// Time follows the pointer while the price snaps to the nearest
+    /// horizontal gridline, ignoring data samples.
+    PriceGrid,
+}
+
+/// Sample field targeted by magnet-mode crosshair snapping.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum CrosshairSnapTarget {
+    /// Candle close; point samples snap to their value.
+    #[default]
+    Close,
+    Open,
+    High,
+    Low,
+    /// Candle OHLC field vertically closest to the pointer.
+    NearestOhlc,
+    /// Point-series values only; candles are ignored.
+    SeriesValue,
 }
 
 /// Tuning for deterministic kinetic pan stepping.
@@ -50,6 +72,23 @@ impl Default for KineticPanState {
     }
 }
 
+/// Series a magnet crosshair snapped to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CrosshairSnapSeries {
+    Points,
+    Candles,
+}
+
+/// What a snapped crosshair is attached to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CrosshairSnapKind {
+    /// A data sample (`CrosshairMode::Magnet`).
+    Data,
+    /// A price-axis gridline (`CrosshairMode::PriceGrid`); only the price
+    /// coordinate is snapped.
+    PriceGridline,
+}
+
 /// Deterministic snap candidate used to drive crosshair visuals and labels.
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct CrosshairSnap {
@@ -57,6 +96,7 @@ pub struct CrosshairSnap {
     pub y: f64,
     pub time: f64,
     pub price: f64,
+    pub series: CrosshairSnapSeries,
 }
 
 /// Public crosshair state exposed to host applications.
@@ -69,6 +109,10 @@ pub struct CrosshairState {
     pub snapped_y: Option<f64>,
     pub snapped_time: Option<f64>,
     pub snapped_price: Option<f64>,
+    #[serde(default)]
+    pub snapped_series: Option<CrosshairSnapSeries>,
+    #[serde(default)]
+    pub snap_kind: Option<CrosshairSnapKind>,
 }
 
 impl Default for CrosshairState {
@@ -81,14 +125,21 @@ impl Default for CrosshairState {
             snapped_y: None,
             snapped_time: None,
             snapped_price: None,
+            snapped_series: None,
+            snap_kind: None,
         }
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+/// Interaction settings and crosshair state; serializable so a host can
+/// restore a session through `ChartEngine::set_interaction_state`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct InteractionState {
     mode: InteractionMode,
     crosshair_mode: CrosshairMode,
+    crosshair_snap_target: CrosshairSnapTarget,
+    crosshair_snap_radius_px: Option<f64>,
     kinetic_pan_config: KineticPanConfig,
     kinetic_pan: KineticPanState,
     cursor_x: f64,
@@ -101,6 +152,8 @@ impl Default for InteractionState {
         Self {
             mode: InteractionMode::Idle,
             crosshair_mode: CrosshairMode::Magnet,
+            crosshair_snap_target: CrosshairSnapTarget::default(),
+            crosshair_snap_radius_px: None,
             kinetic_pan_config: KineticPanConfig::default(),
             kinetic_pan: KineticPanState::default(),
             cursor_x: 0.0,
@@ -125,6 +178,25 @@ impl InteractionState {
         self.crosshair_mode = mode;
     }
 
+    #[must_use]
+    pub fn crosshair_snap_target(self) -> CrosshairSnapTarget {
+        self.crosshair_snap_target
+    }
+
+    pub fn set_crosshair_snap_target(&mut self, target: CrosshairSnapTarget) {
+        self.crosshair_snap_target = target;
+    }
+
+    /// Maximum horizontal pointer distance for magnet snapping (`None` = unlimited).
+    #[must_use]
+    pub fn crosshair_snap_radius_px(self) -> Option<f64> {
+        self.crosshair_snap_radius_px
+    }
+
+    pub fn set_crosshair_snap_radius_px(&mut self, radius_px: Option<f64>) {
+        self.crosshair_snap_radius_px = radius_px;
+    }
+
     #[must_use]
     pub fn kinetic_pan_config(self) -> KineticPanConfig {
         self.kinetic_pan_config
@@ -174,6 +246,22 @@ impl InteractionState {
         (self.cursor_x, self.cursor_y)
     }
 
+    /// Copy with the transient pointer position cleared, for persisting
+    /// sessions without the last hover location. The crosshair follows the
+    /// pointer, so it is hidden as well.
+    #[must_use]
+    pub fn without_pointer_position(mut self) -> Self {
+        self.cursor_x = 0.0;
+        self.cursor_y = 0.0;
+        self.crosshair = CrosshairState::default();
+        self
+    }
+
+    pub(crate) fn set_cursor(&mut self, x: f64, y: f64) {
+        self.cursor_x = x;
+        self.cursor_y = y;
+    }
+
     #[must_use]
     pub fn crosshair(self) -> CrosshairState {
         self.crosshair
@@ -193,6 +281,8 @@ impl InteractionState {
         self.crosshair.snapped_y = None;
         self.crosshair.snapped_time = None;
         self.crosshair.snapped_price = None;
+        self.crosshair.snapped_series = None;
+        self.crosshair.snap_kind = None;
     }
 
     pub fn set_crosshair_snap(&mut self, snap: Option<CrosshairSnap>) {
@@ -202,16 +292,29 @@ impl InteractionState {
                 self.crosshair.snapped_y = Some(snap.y);
                 self.crosshair.snapped_time = Some(snap.time);
                 self.crosshair.snapped_price = Some(snap.price);
+                self.crosshair.snapped_series = Some(snap.series);
+                self.crosshair.snap_kind = Some(CrosshairSnapKind::Data);
             }
             None => {
                 self.crosshair.snapped_x = None;
                 self.crosshair.snapped_y = None;
                 self.crosshair.snapped_time = None;
                 self.crosshair.snapped_price = None;
+                self.crosshair.snapped_series = None;
+                self.crosshair.snap_kind = None;
             }
         }
     }
 
+    /// Snaps only the crosshair price to a gridline at pixel row `y`; time
+    /// keeps following the pointer.
+    pub fn set_crosshair_gridline_snap(&mut self, y: f64, price: f64) {
+        self.set_crosshair_snap(None);
+        self.crosshair.snapped_y = Some(y);
+        self.crosshair.snapped_price = Some(price);
+        self.crosshair.snap_kind = Some(CrosshairSnapKind::PriceGridline);
+    }
+
     pub fn on_pan_start(&mut self) {
         self.mode = InteractionMode::Panning;
     }
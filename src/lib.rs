@@ -3,17 +3,26 @@
 //! This crate provides a Rust-idiomatic API and a strict architectural split
 //! intended for long-term parity work against Lightweight Charts v5.1.
 
+pub mod a11y;
 pub mod api;
 pub mod core;
+pub mod data;
 pub mod error;
 pub mod extensions;
 pub mod interaction;
 pub mod lwc;
 pub mod render;
 pub mod telemetry;
+pub mod testing;
 
+#[cfg(feature = "egui-adapter")]
+pub mod platform_egui;
 #[cfg(feature = "gtk4-adapter")]
 pub mod platform_gtk;
+#[cfg(feature = "wasm")]
+pub mod platform_web;
+#[cfg(feature = "winit-adapter")]
+pub mod platform_winit;
 
 pub use api::{ChartEngine, ChartEngineConfig};
 pub use error::{ChartError, ChartResult};
@@ -0,0 +1,197 @@
+use egui::{CursorIcon, Key, PointerButton, Pos2, Response, Sense, Ui, Vec2, Widget};
+
+use crate::api::{
+    ChartEngine, InteractionOptions, PointerCursorHint, PointerInput, PointerInputBridge,
+    PointerModifiers, ViewportResizePolicy,
+};
+use crate::core::Viewport;
+use crate::render::Renderer;
+
+mod painter;
+
+/// Scroll distance, in points, treated as one wheel notch. Matches egui's
+/// native `line_scroll_speed`.
+const POINTS_PER_SCROLL_NOTCH: f32 = 40.0;
+
+/// Per-widget state kept in egui memory between frames.
+#[derive(Debug, Clone, Default)]
+struct ChartWidgetState {
+    bridge: PointerInputBridge,
+    hovered: bool,
+}
+
+/// egui widget drawing a `ChartEngine` and forwarding pointer input to it.
+///
+/// The engine viewport follows the allocated rect in points, so frame
+/// coordinates map 1:1 onto egui's coordinate space. Gestures go through
+/// `PointerInputBridge`, whose drag state lives in egui memory under the
+/// widget id. Any `Renderer` works: the widget paints the built
+/// `RenderFrame` with egui shapes and never calls `ChartEngine::render`.
+///
+/// ```ignore
+/// ui.add(ChartWidget::new(&mut engine).with_desired_size(egui::vec2(640.0, 360.0)));
+/// ```
+pub struct ChartWidget<'a, R: Renderer> {
+    engine: &'a mut ChartEngine<R>,
+    interactions: InteractionOptions,
+    resize_policy: ViewportResizePolicy,
+    desired_size: Option<Vec2>,
+}
+
+impl<'a, R: Renderer> ChartWidget<'a, R> {
+    #[must_use]
+    pub fn new(engine: &'a mut ChartEngine<R>) -> Self {
+        Self {
+            engine,
+            interactions: InteractionOptions::default(),
+            resize_policy: ViewportResizePolicy::default(),
+            desired_size: None,
+        }
+    }
+
+    #[must_use]
+    pub fn with_interactions(mut self, interactions: InteractionOptions) -> Self {
+        self.interactions = interactions;
+        self
+    }
+
+    #[must_use]
+    pub fn with_resize_policy(mut self, policy: ViewportResizePolicy) -> Self {
+        self.resize_policy = policy;
+        self
+    }
+
+    /// Size to allocate; defaults to all available space.
+    #[must_use]
+    pub fn with_desired_size(mut self, size: Vec2) -> Self {
+        self.desired_size = Some(size);
+        self
+    }
+}
+
+impl<R: Renderer> Widget for ChartWidget<'_, R> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let size = self.desired_size.unwrap_or_else(|| ui.available_size());
+        let (rect, response) = ui.allocate_exact_size(size, Sense::click_and_drag());
+        let viewport = Viewport::new(
+            rect.width().round().max(0.0) as u32,
+            rect.height().round().max(0.0) as u32,
+        );
+        if !viewport.is_valid() {
+            return response;
+        }
+        if self.engine.viewport() != viewport {
+            // An invalid resize keeps the previous viewport; the frame is
+            // still painted so the widget never goes blank.
+            let _ = self.engine.resize_viewport(viewport, self.resize_policy);
+        }
+
+        let state_id = response.id.with("chart_widget_state");
+        let mut state = ui
+            .data(|data| data.get_temp::<ChartWidgetState>(state_id))
+            .unwrap_or_default();
+        if state.bridge.options() != self.interactions {
+            state.bridge = PointerInputBridge::new(self.interactions);
+        }
+
+        for input in collect_inputs(ui, &response, rect.min, &mut state.hovered) {
+            // Rejected gestures are dropped, as in the GTK adapter.
+            let _ = state.bridge.handle(self.engine, input);
+        }
+        // egui resets the cursor every frame, so the hint is re-applied
+        // rather than taken on change.
+        if state.bridge.cursor_hint() == PointerCursorHint::ResizeVertical {
+            ui.ctx().set_cursor_icon(CursorIcon::ResizeVertical);
+        }
+        ui.data_mut(|data| data.insert_temp(state_id, state));
+
+        if ui.is_rect_visible(rect) {
+            if let Ok(frame) = self.engine.build_render_frame() {
+                painter::paint_frame(&ui.painter_at(rect), &frame, rect.min);
+                self.engine.recycle_render_frame(frame);
+            }
+        }
+        response
+    }
+}
+
+/// Translates this frame's egui input into `PointerInput`, relative to
+/// `origin`. Presses are reported at the press origin so the first drag
+/// update carries the movement egui needed to recognize the drag.
+fn collect_inputs(
+    ui: &Ui,
+    response: &Response,
+    origin: Pos2,
+    was_hovered: &mut bool,
+) -> Vec<PointerInput> {
+    let (latest, press_origin, pointer_delta, scroll, zoom, modifiers, escape) =
+        ui.input(|input| {
+            (
+                input.pointer.latest_pos(),
+                input.pointer.press_origin(),
+                input.pointer.delta(),
+                input.raw_scroll_delta,
+                input.zoom_delta(),
+                input.modifiers,
+                input.key_pressed(Key::Escape),
+            )
+        });
+    let local = |pos: Pos2| {
+        let offset = pos - origin;
+        (f64::from(offset.x), f64::from(offset.y))
+    };
+
+    let mut inputs = Vec::new();
+    let active = response.hovered() || response.dragged();
+    if response.drag_started_by(PointerButton::Primary) {
+        // Modifiers only matter for presses, so refresh them right before.
+        inputs.push(PointerInput::ModifiersChanged {
+            modifiers: PointerModifiers {
+                alt: modifiers.alt,
+                shift: modifiers.shift,
+                control: modifiers.ctrl,
+            },
+        });
+        if let Some((x, y)) = press_origin.map(local) {
+            inputs.push(PointerInput::Pressed { x, y });
+        }
+    }
+    let moved = !*was_hovered || pointer_delta != Vec2::ZERO || !inputs.is_empty();
+    match latest.map(local) {
+        Some((x, y)) if active && moved => inputs.push(PointerInput::Moved { x, y }),
+        Some(_) if active => {}
+        _ if *was_hovered => inputs.push(PointerInput::Left),
+        _ => {}
+    }
+    *was_hovered = active;
+
+    if escape && active {
+        inputs.push(PointerInput::Cancelled);
+    }
+    if response.drag_stopped_by(PointerButton::Primary) {
+        inputs.push(PointerInput::Released);
+    }
+    if response.double_clicked() {
+        if let Some((x, y)) = latest.map(local) {
+            inputs.push(PointerInput::DoubleClicked { x, y });
+        }
+    }
+    if response.hovered() {
+        // egui deltas move the content; `PointerInput` follows the wheel.
+        if scroll != Vec2::ZERO {
+            inputs.push(PointerInput::Scrolled {
+                delta_x: f64::from(-scroll.x / POINTS_PER_SCROLL_NOTCH),
+                delta_y: f64::from(-scroll.y / POINTS_PER_SCROLL_NOTCH),
+            });
+        }
+        if zoom != 1.0 {
+            let anchor_x =
+                latest.map_or(f64::from(response.rect.width()) * 0.5, |pos| local(pos).0);
+            inputs.push(PointerInput::Pinched {
+                factor: f64::from(zoom),
+                anchor_x,
+            });
+        }
+    }
+    inputs
+}
@@ -0,0 +1,348 @@
+use egui::epaint::{CornerRadius, Mesh, RectShape, StrokeKind, TextShape};
+use egui::{Align2, Color32, FontId, Painter, Pos2, Rect, Shape, Stroke, Vec2, pos2, vec2};
+
+use crate::render::{
+    ClipRect, Color, GradientDirection, LinePrimitive, LineStrokeStyle, LinearGradient,
+    PathFillRule, PathPoint, PathPrimitive, RectPrimitive, RenderFrame, TextHAlign, TextPrimitive,
+    TextVAlign,
+};
+
+/// Paints `frame` with its origin at `origin`, in the same primitive order
+/// as the Cairo backend (lines, rects, paths, texts).
+pub(super) fn paint_frame(painter: &Painter, frame: &RenderFrame, origin: Pos2) {
+    let offset = origin.to_vec2();
+    for line in &frame.lines {
+        clipped(painter, line.clip_rect, offset).extend(line_shapes(*line, offset));
+    }
+    for rect in &frame.rects {
+        clipped(painter, rect.clip_rect, offset).add(rect_shape(*rect, offset));
+    }
+    for path in &frame.paths {
+        clipped(painter, path.clip_rect, offset).extend(path_shapes(path, offset));
+    }
+    for text in &frame.texts {
+        let painter = clipped(painter, text.clip_rect, offset);
+        let shape = text_shape(&painter, text, offset);
+        painter.add(shape);
+    }
+}
+
+fn clipped(painter: &Painter, clip_rect: Option<ClipRect>, offset: Vec2) -> Painter {
+    match clip_rect {
+        Some(clip) => painter.with_clip_rect(
+            Rect::from_min_size(
+                pos2(clip.x as f32, clip.y as f32),
+                vec2(clip.width.max(0.0) as f32, clip.height.max(0.0) as f32),
+            )
+            .translate(offset)
+            .intersect(painter.clip_rect()),
+        ),
+        None => painter.clone(),
+    }
+}
+
+pub(super) fn to_color32(color: Color) -> Color32 {
+    let channel = |value: f64| (value.clamp(0.0, 1.0) * 255.0).round() as u8;
+    Color32::from_rgba_unmultiplied(
+        channel(color.red),
+        channel(color.green),
+        channel(color.blue),
+        channel(color.alpha),
+    )
+}
+
+fn to_pos(x: f64, y: f64, offset: Vec2) -> Pos2 {
+    pos2(x as f32, y as f32) + offset
+}
+
+/// Dash patterns match the Cairo backend, relative to the stroke width.
+fn stroked_polyline(points: &[Pos2], stroke: Stroke, style: LineStrokeStyle) -> Vec<Shape> {
+    let width = stroke.width;
+    match style {
+        LineStrokeStyle::Solid => vec![Shape::line(points.to_vec(), stroke)],
+        LineStrokeStyle::Dashed => Shape::dashed_line(points, stroke, width * 6.0, width * 4.0),
+        LineStrokeStyle::LargeDashed => {
+            Shape::dashed_line(points, stroke, width * 8.0, width * 6.0)
+        }
+        LineStrokeStyle::Dotted => Shape::dashed_line(points, stroke, width, width * 2.0),
+    }
+}
+
+fn line_shapes(line: LinePrimitive, offset: Vec2) -> Vec<Shape> {
+    let points = [
+        to_pos(line.x1, line.y1, offset),
+        to_pos(line.x2, line.y2, offset),
+    ];
+    let stroke = Stroke::new(line.stroke_width as f32, to_color32(line.color));
+    stroked_polyline(&points, stroke, line.stroke_style)
+}
+
+fn rect_shape(rect: RectPrimitive, offset: Vec2) -> Shape {
+    let bounds = Rect::from_min_size(
+        to_pos(rect.x, rect.y, offset),
+        vec2(rect.width as f32, rect.height as f32),
+    );
+    let border = if rect.border_width > 0.0 {
+        Stroke::new(rect.border_width as f32, to_color32(rect.border_color))
+    } else {
+        Stroke::NONE
+    };
+    let Some(gradient) = rect.fill_gradient else {
+        let radius = rect
+            .corner_radius
+            .min(rect.width * 0.5)
+            .min(rect.height * 0.5)
+            .clamp(0.0, f64::from(u8::MAX));
+        return Shape::Rect(RectShape::new(
+            bounds,
+            CornerRadius::same(radius.round() as u8),
+            to_color32(rect.fill_color),
+            border,
+            StrokeKind::Middle,
+        ));
+    };
+
+    // egui rect shapes only take solid fills; gradients use a vertex-colored
+    // mesh and drop the corner radius.
+    let mut mesh = Mesh::default();
+    let corners = [
+        (bounds.left_top(), 0.0, 0.0),
+        (bounds.right_top(), 1.0, 0.0),
+        (bounds.right_bottom(), 1.0, 1.0),
+        (bounds.left_bottom(), 0.0, 1.0),
+    ];
+    for (pos, tx, ty) in corners {
+        mesh.colored_vertex(pos, gradient_color(gradient, tx, ty));
+    }
+    mesh.add_triangle(0, 1, 2);
+    mesh.add_triangle(0, 2, 3);
+    let mut shapes = vec![Shape::mesh(mesh)];
+    if border != Stroke::NONE {
+        shapes.push(Shape::Rect(RectShape::stroke(
+            bounds,
+            CornerRadius::ZERO,
+            border,
+            StrokeKind::Middle,
+        )));
+    }
+    Shape::Vec(shapes)
+}
+
+fn gradient_color(gradient: LinearGradient, tx: f64, ty: f64) -> Color32 {
+    to_color32(gradient.color_at(match gradient.direction {
+        GradientDirection::Vertical => ty,
+        GradientDirection::Horizontal => tx,
+    }))
+}
+
+fn path_shapes(path: &PathPrimitive, offset: Vec2) -> Vec<Shape> {
+    let mut shapes = Vec::new();
+    if let Some(fill_color) = path.fill_color {
+        let mesh = fill_mesh(path, fill_color, offset);
+        if !mesh.is_empty() {
+            shapes.push(Shape::mesh(mesh));
+        }
+    }
+    if path.is_stroked() {
+        let mut points: Vec<Pos2> = path
+            .points
+            .iter()
+            .map(|point| to_pos(point.x, point.y, offset))
+            .collect();
+        if path.closed {
+            if let Some(first) = points.first().copied() {
+                points.push(first);
+            }
+        }
+        let stroke = Stroke::new(path.stroke_width as f32, to_color32(path.stroke_color));
+        shapes.extend(stroked_polyline(&points, stroke, path.stroke_style));
+    }
+    shapes
+}
+
+/// Fills `path` with one-pixel scanline spans.
+///
+/// egui only tessellates convex polygons, while area and baseline fills are
+/// concave; scanlines keep both fill rules exact at the cost of vertical
+/// anti-aliasing.
+fn fill_mesh(path: &PathPrimitive, fill_color: Color, offset: Vec2) -> Mesh {
+    let mut mesh = Mesh::default();
+    let Some((x, y, width, height)) = path.bounds() else {
+        return mesh;
+    };
+    let gradient = path.fill_gradient;
+    let solid = to_color32(fill_color);
+    let color_at = |span_x: f64, span_y: f64| match gradient {
+        Some(gradient) => gradient_color(
+            gradient,
+            relative(span_x, x, width),
+            relative(span_y, y, height),
+        ),
+        None => solid,
+    };
+
+    for (row_top, spans) in scanline_spans(&path.points, path.fill_rule) {
+        let row_center = row_top + 0.5;
+        for (left, right) in spans {
+            let rect = Rect::from_min_max(
+                to_pos(left, row_top, offset),
+                to_pos(right, row_top + 1.0, offset),
+            );
+            if gradient.is_some_and(|gradient| gradient.direction == GradientDirection::Horizontal)
+            {
+                let base = mesh.vertices.len() as u32;
+                mesh.colored_vertex(rect.left_top(), color_at(left, row_center));
+                mesh.colored_vertex(rect.right_top(), color_at(right, row_center));
+                mesh.colored_vertex(rect.right_bottom(), color_at(right, row_center));
+                mesh.colored_vertex(rect.left_bottom(), color_at(left, row_center));
+                mesh.add_triangle(base, base + 1, base + 2);
+                mesh.add_triangle(base, base + 2, base + 3);
+            } else {
+                mesh.add_colored_rect(rect, color_at(left, row_center));
+            }
+        }
+    }
+    mesh
+}
+
+fn relative(value: f64, start: f64, span: f64) -> f64 {
+    if span > 0.0 {
+        (value - start) / span
+    } else {
+        0.0
+    }
+}
+
+/// Horizontal fill spans per one-pixel row (keyed by the row top), sampled
+/// at row centers. The polygon is implicitly closed.
+pub(super) fn scanline_spans(
+    points: &[PathPoint],
+    fill_rule: PathFillRule,
+) -> Vec<(f64, Vec<(f64, f64)>)> {
+    if points.len() < 3 {
+        return Vec::new();
+    }
+    let (min_y, max_y) = points.iter().fold((f64::MAX, f64::MIN), |(low, high), p| {
+        (low.min(p.y), high.max(p.y))
+    });
+    let mut rows = Vec::new();
+    let mut crossings: Vec<(f64, i32)> = Vec::new();
+    let mut row_top = min_y.floor();
+    while row_top < max_y {
+        let sample_y = row_top + 0.5;
+        crossings.clear();
+        for (index, start) in points.iter().enumerate() {
+            let end = points[(index + 1) % points.len()];
+            let (upper, lower, winding) = if start.y <= end.y {
+                (*start, end, 1)
+            } else {
+                (end, *start, -1)
+            };
+            if sample_y < upper.y || sample_y >= lower.y {
+                continue;
+            }
+            let t = (sample_y - upper.y) / (lower.y - upper.y);
+            crossings.push((upper.x + (lower.x - upper.x) * t, winding));
+        }
+        crossings.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        let mut spans = Vec::new();
+        let mut winding = 0;
+        for pair in crossings.windows(2) {
+            winding += pair[0].1;
+            let inside = match fill_rule {
+                PathFillRule::NonZero => winding != 0,
+                PathFillRule::EvenOdd => winding % 2 != 0,
+            };
+            if inside && pair[1].0 > pair[0].0 {
+                spans.push((pair[0].0, pair[1].0));
+            }
+        }
+        if !spans.is_empty() {
+            rows.push((row_top, spans));
+        }
+        row_top += 1.0;
+    }
+    rows
+}
+
+fn text_shape(painter: &Painter, text: &TextPrimitive, offset: Vec2) -> Shape {
+    let font = if text.font_family.to_ascii_lowercase().contains("mono") {
+        FontId::monospace(text.font_size_px as f32)
+    } else {
+        FontId::proportional(text.font_size_px as f32)
+    };
+    let color = to_color32(text.color);
+    let galley = painter.layout_no_wrap(text.text.to_string(), font, color);
+    let anchor = Align2([
+        match text.h_align {
+            TextHAlign::Left => egui::Align::Min,
+            TextHAlign::Center => egui::Align::Center,
+            TextHAlign::Right => egui::Align::Max,
+        },
+        match text.v_align {
+            TextVAlign::Top => egui::Align::Min,
+            TextVAlign::Middle => egui::Align::Center,
+            TextVAlign::Bottom => egui::Align::Max,
+        },
+    ]);
+    let pos = anchor
+        .anchor_size(to_pos(text.x, text.y, offset), galley.size())
+        .min;
+    let shape = TextShape::new(pos, galley, color);
+    if text.is_rotated() {
+        Shape::Text(shape.with_angle_and_anchor(text.rotation_radians as f32, anchor))
+    } else {
+        Shape::Text(shape)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{scanline_spans, to_color32};
+    use crate::render::{Color, PathFillRule, PathPoint};
+
+    #[test]
+    fn concave_polygon_spans_skip_the_notch() {
+        // A "U" shape: the middle of the upper rows is outside.
+        let points = [
+            PathPoint::new(0.0, 0.0),
+            PathPoint::new(2.0, 0.0),
+            PathPoint::new(2.0, 2.0),
+            PathPoint::new(4.0, 2.0),
+            PathPoint::new(4.0, 0.0),
+            PathPoint::new(6.0, 0.0),
+            PathPoint::new(6.0, 4.0),
+            PathPoint::new(0.0, 4.0),
+        ];
+        let rows = scanline_spans(&points, PathFillRule::NonZero);
+        assert_eq!(rows.len(), 4);
+        assert_eq!(rows[0], (0.0, vec![(0.0, 2.0), (4.0, 6.0)]));
+        assert_eq!(rows[3], (3.0, vec![(0.0, 6.0)]));
+    }
+
+    #[test]
+    fn even_odd_rule_leaves_overlaps_empty() {
+        // Two overlapping squares traced as one polygon.
+        let points = [
+            PathPoint::new(0.0, 0.0),
+            PathPoint::new(4.0, 0.0),
+            PathPoint::new(4.0, 2.0),
+            PathPoint::new(0.0, 2.0),
+            PathPoint::new(0.0, 0.0),
+            PathPoint::new(2.0, 0.0),
+            PathPoint::new(2.0, 2.0),
+            PathPoint::new(0.0, 2.0),
+        ];
+        let even_odd = scanline_spans(&points, PathFillRule::EvenOdd);
+        assert_eq!(even_odd[0].1, vec![(2.0, 4.0)]);
+        let non_zero = scanline_spans(&points, PathFillRule::NonZero);
+        assert_eq!(non_zero[0].1, vec![(0.0, 2.0), (2.0, 4.0)]);
+    }
+
+    #[test]
+    fn colors_convert_to_unmultiplied_bytes() {
+        let color = to_color32(Color::rgba(1.0, 0.5, 0.0, 1.0));
+        assert_eq!(color.to_array(), [255, 128, 0, 255]);
+    }
+}
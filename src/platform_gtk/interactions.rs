@@ -0,0 +1,261 @@
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+use gtk4 as gtk;
+use gtk4::prelude::*;
+
+use crate::api::{
+    ChartEngine, InteractionOptions, PointerCursorHint, PointerInput, PointerInputBridge,
+    PointerModifiers,
+};
+use crate::render::{CairoContextRenderer, Renderer};
+
+use super::GtkChartAdapter;
+
+/// Shared engine access for controller callbacks, which outlive any borrow
+/// of the adapter itself.
+struct InteractionTarget<R: Renderer + CairoContextRenderer + 'static> {
+    drawing_area: gtk::DrawingArea,
+    engine: Rc<RefCell<ChartEngine<R>>>,
+    bridge: Rc<RefCell<PointerInputBridge>>,
+    frame_request_pending: Rc<Cell<bool>>,
+    animation_tick_active: Rc<Cell<bool>>,
+}
+
+impl<R: Renderer + CairoContextRenderer + 'static> Clone for InteractionTarget<R> {
+    fn clone(&self) -> Self {
+        Self {
+            drawing_area: self.drawing_area.clone(),
+            engine: Rc::clone(&self.engine),
+            bridge: Rc::clone(&self.bridge),
+            frame_request_pending: Rc::clone(&self.frame_request_pending),
+            animation_tick_active: Rc::clone(&self.animation_tick_active),
+        }
+    }
+}
+
+impl<R: Renderer + CairoContextRenderer + 'static> InteractionTarget<R> {
+    /// Feeds `input` to the bridge and schedules a redraw when it
+    /// invalidated the chart. Rejected gestures are dropped: a callback has
+    /// nowhere to report them.
+    fn apply(&self, input: PointerInput) {
+        let Ok(mut engine) = self.engine.try_borrow_mut() else {
+            return;
+        };
+        let Ok(mut bridge) = self.bridge.try_borrow_mut() else {
+            return;
+        };
+        let _ = bridge.handle(&mut engine, input);
+        if let Some(hint) = bridge.take_cursor_hint_change() {
+            self.drawing_area.set_cursor_from_name(match hint {
+                PointerCursorHint::Default => None,
+                PointerCursorHint::ResizeVertical => Some("row-resize"),
+            });
+        }
+        drop(bridge);
+        drop(engine);
+        GtkChartAdapter::<R>::schedule_draw_request(
+            &self.drawing_area,
+            &self.engine,
+            &self.frame_request_pending,
+            false,
+        );
+        GtkChartAdapter::<R>::ensure_animation_tick(
+            &self.drawing_area,
+            &self.engine,
+            &self.animation_tick_active,
+        );
+    }
+}
+
+impl<R: Renderer + CairoContextRenderer + 'static> GtkChartAdapter<R> {
+    /// Installs GTK controllers for the standard chart gestures.
+    ///
+    /// Replaces controllers installed by a previous call. Engine-side
+    /// interaction behaviors (`InteractionInputBehavior`, navigation limits)
+    /// still apply on top of `options`. Events stay in drawing-area
+    /// coordinates; the engine resolves its `LayoutInsets`, so gestures in
+    /// the inset margins hide the crosshair and start no drag. Hovering or
+    /// dragging a pane separator switches the drawing-area cursor to
+    /// `row-resize`.
+    pub fn enable_default_interactions(&self, options: InteractionOptions) {
+        self.disable_default_interactions();
+        let target = InteractionTarget {
+            drawing_area: self.drawing_area.clone(),
+            engine: Rc::clone(&self.engine),
+            bridge: Rc::new(RefCell::new(PointerInputBridge::new(options))),
+            frame_request_pending: Rc::clone(&self.frame_request_pending),
+            animation_tick_active: Rc::clone(&self.animation_tick_active),
+        };
+
+        let mut controllers: Vec<gtk::EventController> = Vec::new();
+        // Motion also drives the pane-separator cursor hint.
+        if options.pointer_motion || options.pane_separator_drag {
+            controllers.push(motion_controller(&target).upcast());
+        }
+        controllers.push(scroll_controller(&target).upcast());
+        if options.drag_pan
+            || options.price_axis_drag
+            || options.time_axis_drag
+            || options.pane_separator_drag
+        {
+            controllers.push(drag_controller(&target).upcast());
+        }
+        if options.pinch_zoom {
+            controllers.push(pinch_controller(&target).upcast());
+        }
+        if options.double_click_reset {
+            controllers.push(double_click_controller(&target).upcast());
+        }
+
+        for controller in &controllers {
+            self.drawing_area.add_controller(controller.clone());
+        }
+        *self.interaction_controllers.borrow_mut() = controllers;
+    }
+
+    /// Removes controllers installed by `enable_default_interactions`.
+    pub fn disable_default_interactions(&self) {
+        for controller in self.interaction_controllers.borrow_mut().drain(..) {
+            self.drawing_area.remove_controller(&controller);
+        }
+    }
+
+    #[must_use]
+    pub fn default_interactions_enabled(&self) -> bool {
+        !self.interaction_controllers.borrow().is_empty()
+    }
+}
+
+fn motion_controller<R: Renderer + CairoContextRenderer + 'static>(
+    target: &InteractionTarget<R>,
+) -> gtk::EventControllerMotion {
+    let motion = gtk::EventControllerMotion::new();
+    {
+        let target = target.clone();
+        motion.connect_motion(move |_, x, y| target.apply(PointerInput::Moved { x, y }));
+    }
+    {
+        let target = target.clone();
+        motion.connect_leave(move |_| target.apply(PointerInput::Left));
+    }
+    motion
+}
+
+fn pointer_modifiers(state: gtk::gdk::ModifierType) -> PointerModifiers {
+    PointerModifiers {
+        alt: state.contains(gtk::gdk::ModifierType::ALT_MASK),
+        shift: state.contains(gtk::gdk::ModifierType::SHIFT_MASK),
+        control: state.contains(gtk::gdk::ModifierType::CONTROL_MASK),
+    }
+}
+
+fn scroll_controller<R: Renderer + CairoContextRenderer + 'static>(
+    target: &InteractionTarget<R>,
+) -> gtk::EventControllerScroll {
+    let scroll = gtk::EventControllerScroll::new(
+        gtk::EventControllerScrollFlags::VERTICAL | gtk::EventControllerScrollFlags::HORIZONTAL,
+    );
+    let target = target.clone();
+    scroll.connect_scroll(move |controller, dx, dy| {
+        let wheel_enabled = target.engine.try_borrow().is_ok_and(|engine| {
+            let wheel = engine.wheel_behavior_config();
+            wheel.zoom_axis.is_some() || wheel.pan_axis.is_some()
+        });
+        if !wheel_enabled {
+            // Let an enclosing scrolled window have the event.
+            return gtk::glib::Propagation::Proceed;
+        }
+        // Sampled per event so `WheelBehaviorConfig::zoom_requires_control`
+        // sees the current control key.
+        target.apply(PointerInput::ModifiersChanged {
+            modifiers: pointer_modifiers(controller.current_event_state()),
+        });
+        target.apply(PointerInput::Scrolled {
+            delta_x: dx,
+            delta_y: dy,
+        });
+        gtk::glib::Propagation::Stop
+    });
+    scroll
+}
+
+fn drag_controller<R: Renderer + CairoContextRenderer + 'static>(
+    target: &InteractionTarget<R>,
+) -> gtk::GestureDrag {
+    let drag = gtk::GestureDrag::new();
+    // GTK reports offsets from the drag start; the bridge takes positions.
+    let start = Rc::new(Cell::new((0.0, 0.0)));
+    {
+        let target = target.clone();
+        let start = Rc::clone(&start);
+        drag.connect_drag_begin(move |gesture, x, y| {
+            start.set((x, y));
+            // Modifiers only matter at press time (alert creation), so they
+            // are sampled here instead of tracking key events.
+            target.apply(PointerInput::ModifiersChanged {
+                modifiers: pointer_modifiers(gesture.current_event_state()),
+            });
+            target.apply(PointerInput::Pressed { x, y });
+        });
+    }
+    {
+        let target = target.clone();
+        let start = Rc::clone(&start);
+        drag.connect_drag_update(move |_, offset_x, offset_y| {
+            let (start_x, start_y) = start.get();
+            target.apply(PointerInput::Moved {
+                x: start_x + offset_x,
+                y: start_y + offset_y,
+            });
+        });
+    }
+    {
+        let target = target.clone();
+        drag.connect_drag_end(move |_, _, _| target.apply(PointerInput::Released));
+    }
+    drag
+}
+
+fn pinch_controller<R: Renderer + CairoContextRenderer + 'static>(
+    target: &InteractionTarget<R>,
+) -> gtk::GestureZoom {
+    let pinch = gtk::GestureZoom::new();
+    // GTK reports the scale relative to the gesture start; the bridge takes
+    // per-update factors.
+    let last_scale = Rc::new(Cell::new(1.0));
+    {
+        let last_scale = Rc::clone(&last_scale);
+        pinch.connect_begin(move |_, _| last_scale.set(1.0));
+    }
+    {
+        let target = target.clone();
+        pinch.connect_scale_changed(move |gesture, scale| {
+            let previous = last_scale.replace(scale);
+            if previous <= 0.0 || scale <= 0.0 {
+                return;
+            }
+            let anchor_x = gesture
+                .bounding_box_center()
+                .map_or_else(|| f64::from(target.drawing_area.width()) * 0.5, |(x, _)| x);
+            target.apply(PointerInput::Pinched {
+                factor: scale / previous,
+                anchor_x,
+            });
+        });
+    }
+    pinch
+}
+
+fn double_click_controller<R: Renderer + CairoContextRenderer + 'static>(
+    target: &InteractionTarget<R>,
+) -> gtk::GestureClick {
+    let click = gtk::GestureClick::new();
+    let target = target.clone();
+    click.connect_pressed(move |_, n_press, x, y| {
+        if n_press == 2 {
+            target.apply(PointerInput::DoubleClicked { x, y });
+        }
+    });
+    click
+}
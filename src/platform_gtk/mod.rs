@@ -1,22 +1,33 @@
 use std::cell::{Cell, RefCell};
 use std::rc::Rc;
+use std::time::Duration;
 
 use gtk4 as gtk;
-use gtk4::prelude::{DrawingAreaExtManual, WidgetExt};
+use gtk4::prelude::{
+    AccessibleExt, AccessibleExtManual, DrawingAreaExtManual, WidgetExt, WidgetExtManual,
+};
 
+use crate::a11y::describe_view;
 use crate::api::{
-    ChartEngine, CrosshairFormatterDiagnostics, TimeCoordinateIndexPolicy, TimeFilledLogicalSlot,
+    ChartEngine, CrosshairFormatterDiagnostics, SeriesLegend, TimeCoordinateIndexPolicy,
+    TimeFilledLogicalSlot,
 };
 use crate::core::Viewport;
 use crate::error::ChartResult;
 use crate::render::{CairoContextRenderer, Renderer};
 
+mod interactions;
+mod resize;
+pub use crate::api::InteractionOptions;
+pub use resize::ResizePolicy;
+
 /// Minimal GTK4 adapter that wires a `ChartEngine` into a `DrawingArea`.
 ///
 /// The adapter keeps GTK concerns local while delegating all chart
 /// state/transforms/render-command generation to the engine.
 type CrosshairDiagnosticsHook = Rc<dyn Fn(CrosshairFormatterDiagnostics)>;
 type SnapshotJsonHook = Rc<dyn Fn(String)>;
+type SeriesLegendHook = Rc<dyn Fn(&SeriesLegend)>;
 
 pub struct GtkChartAdapter<R: Renderer + CairoContextRenderer + 'static> {
     drawing_area: gtk::DrawingArea,
@@ -24,7 +35,14 @@ pub struct GtkChartAdapter<R: Renderer + CairoContextRenderer + 'static> {
     diagnostics_hook: Rc<RefCell<Option<CrosshairDiagnosticsHook>>>,
     snapshot_hook: Rc<RefCell<Option<SnapshotJsonHook>>>,
     snapshot_hook_body_width_px: Rc<RefCell<f64>>,
+    series_legend_hook: Rc<RefCell<Option<SeriesLegendHook>>>,
+    last_series_legend: Rc<RefCell<Option<SeriesLegend>>>,
     frame_request_pending: Rc<Cell<bool>>,
+    accessible_description_enabled: Rc<Cell<bool>>,
+    last_accessible_description: Rc<RefCell<String>>,
+    interaction_controllers: RefCell<Vec<gtk::EventController>>,
+    resize_policy: Rc<Cell<ResizePolicy>>,
+    animation_tick_active: Rc<Cell<bool>>,
 }
 
 impl<R: Renderer + CairoContextRenderer + 'static> GtkChartAdapter<R> {
@@ -54,6 +72,20 @@ impl<R: Renderer + CairoContextRenderer + 'static> GtkChartAdapter<R> {
         f(&mut engine)
     }
 
+    /// Pushes `description` to the widget unless it is already current.
+    fn sync_accessible_description(
+        drawing_area: &gtk::DrawingArea,
+        last_description: &RefCell<String>,
+        description: String,
+    ) {
+        let mut last_description = last_description.borrow_mut();
+        if *last_description == description {
+            return;
+        }
+        drawing_area.update_property(&[gtk::accessible::Property::Description(&description)]);
+        *last_description = description;
+    }
+
     fn schedule_draw_request(
         drawing_area: &gtk::DrawingArea,
         engine: &Rc<RefCell<ChartEngine<R>>>,
@@ -68,6 +100,33 @@ impl<R: Renderer + CairoContextRenderer + 'static> GtkChartAdapter<R> {
         let engine = Rc::clone(engine);
         let frame_request_pending = Rc::clone(frame_request_pending);
         gtk::glib::idle_add_local_once(move || {
+            let throttled = !force
+                && engine
+                    .try_borrow()
+                    .map(|engine| engine.redraw_throttle_behavior().max_fps.is_some())
+                    .unwrap_or(false);
+            if throttled {
+                // Capped redraws wait for a frame-clock tick the engine grants.
+                drawing_area.add_tick_callback(move |widget, clock| {
+                    let frame_time =
+                        Duration::from_micros(u64::try_from(clock.frame_time()).unwrap_or(0));
+                    let Ok(mut engine) = engine.try_borrow_mut() else {
+                        return gtk::glib::ControlFlow::Continue;
+                    };
+                    if !engine.needs_redraw() {
+                        frame_request_pending.set(false);
+                        return gtk::glib::ControlFlow::Break;
+                    }
+                    if !engine.redraw_due(frame_time) {
+                        return gtk::glib::ControlFlow::Continue;
+                    }
+                    frame_request_pending.set(false);
+                    widget.queue_draw();
+                    gtk::glib::ControlFlow::Break
+                });
+                return;
+            }
+
             frame_request_pending.set(false);
             let should_draw = if force {
                 true
@@ -83,6 +142,52 @@ impl<R: Renderer + CairoContextRenderer + 'static> GtkChartAdapter<R> {
         });
     }
 
+    /// Steps engine animations (kinetic pan, range animations) on frame-clock
+    /// ticks while any is active. The tick callback removes itself once the
+    /// engine is idle, so an idle chart keeps the frame clock asleep.
+    fn ensure_animation_tick(
+        drawing_area: &gtk::DrawingArea,
+        engine: &Rc<RefCell<ChartEngine<R>>>,
+        animation_tick_active: &Rc<Cell<bool>>,
+    ) {
+        let animating = engine
+            .try_borrow()
+            .map(|engine| engine.has_active_animation())
+            .unwrap_or(false);
+        if !animating || animation_tick_active.replace(true) {
+            return;
+        }
+
+        let engine = Rc::clone(engine);
+        let animation_tick_active = Rc::clone(animation_tick_active);
+        let last_frame_time = Cell::new(None::<i64>);
+        drawing_area.add_tick_callback(move |widget, clock| {
+            let Ok(mut engine) = engine.try_borrow_mut() else {
+                return gtk::glib::ControlFlow::Continue;
+            };
+            let frame_time = clock.frame_time();
+            if let Some(last) = last_frame_time.replace(Some(frame_time)) {
+                let delta_seconds = (frame_time - last) as f64 / 1_000_000.0;
+                if delta_seconds > 0.0 {
+                    match engine.step_animations(delta_seconds) {
+                        Ok(true) => widget.queue_draw(),
+                        Ok(false) => {}
+                        Err(_) => {
+                            engine.stop_kinetic_pan();
+                            engine.stop_time_range_animation();
+                        }
+                    }
+                }
+            }
+            if engine.has_active_animation() {
+                gtk::glib::ControlFlow::Continue
+            } else {
+                animation_tick_active.set(false);
+                gtk::glib::ControlFlow::Break
+            }
+        });
+    }
+
     #[must_use]
     pub fn new(engine: ChartEngine<R>) -> Self {
         let drawing_area = gtk::DrawingArea::new();
@@ -94,12 +199,24 @@ impl<R: Renderer + CairoContextRenderer + 'static> GtkChartAdapter<R> {
             Rc::new(RefCell::new(None));
         let snapshot_hook: Rc<RefCell<Option<SnapshotJsonHook>>> = Rc::new(RefCell::new(None));
         let snapshot_hook_body_width_px = Rc::new(RefCell::new(7.0));
+        let series_legend_hook: Rc<RefCell<Option<SeriesLegendHook>>> = Rc::new(RefCell::new(None));
+        let last_series_legend: Rc<RefCell<Option<SeriesLegend>>> = Rc::new(RefCell::new(None));
         let frame_request_pending = Rc::new(Cell::new(false));
         let engine_for_draw = Rc::clone(&engine);
         let diagnostics_hook_for_draw = Rc::clone(&diagnostics_hook);
         let snapshot_hook_for_draw = Rc::clone(&snapshot_hook);
         let snapshot_hook_body_width_px_for_draw = Rc::clone(&snapshot_hook_body_width_px);
+        let series_legend_hook_for_draw = Rc::clone(&series_legend_hook);
+        let last_series_legend_for_draw = Rc::clone(&last_series_legend);
         let frame_request_pending_for_draw = Rc::clone(&frame_request_pending);
+        let accessible_description_enabled = Rc::new(Cell::new(false));
+        let last_accessible_description = Rc::new(RefCell::new(String::new()));
+        let accessible_description_enabled_for_draw = Rc::clone(&accessible_description_enabled);
+        let last_accessible_description_for_draw = Rc::clone(&last_accessible_description);
+        let resize_policy = Rc::new(Cell::new(ResizePolicy::default()));
+        let resize_policy_for_draw = Rc::clone(&resize_policy);
+        let resize_autoscale_source: Rc<RefCell<Option<gtk::glib::SourceId>>> =
+            Rc::new(RefCell::new(None));
         drawing_area.set_draw_func(move |_widget, context, width, height| {
             if width <= 0 || height <= 0 {
                 return;
@@ -112,7 +229,15 @@ impl<R: Renderer + CairoContextRenderer + 'static> GtkChartAdapter<R> {
 
             let viewport = Viewport::new(width as u32, height as u32);
             if engine.viewport() != viewport {
-                let _ = engine.set_viewport(viewport);
+                Self::apply_resize(
+                    &mut engine,
+                    viewport,
+                    resize_policy_for_draw.get(),
+                    _widget,
+                    &engine_for_draw,
+                    &frame_request_pending_for_draw,
+                    &resize_autoscale_source,
+                );
             }
 
             let _ = engine.render_on_cairo_context(context);
@@ -128,6 +253,23 @@ impl<R: Renderer + CairoContextRenderer + 'static> GtkChartAdapter<R> {
                 }
             }
 
+            if let Some(hook) = series_legend_hook_for_draw.borrow().as_ref().cloned() {
+                let legend = engine.series_legend();
+                let mut last_legend = last_series_legend_for_draw.borrow_mut();
+                if last_legend.as_ref() != Some(&legend) {
+                    hook(&legend);
+                    *last_legend = Some(legend);
+                }
+            }
+
+            if accessible_description_enabled_for_draw.get() {
+                Self::sync_accessible_description(
+                    _widget,
+                    &last_accessible_description_for_draw,
+                    describe_view(&engine),
+                );
+            }
+
             if engine.has_pending_invalidation() {
                 Self::schedule_draw_request(
                     _widget,
@@ -138,6 +280,26 @@ impl<R: Renderer + CairoContextRenderer + 'static> GtkChartAdapter<R> {
             }
         });
 
+        // Time-scale mark tooltips come straight from engine hit-testing.
+        drawing_area.set_has_tooltip(true);
+        let engine_for_tooltip = Rc::clone(&engine);
+        drawing_area.connect_query_tooltip(move |_widget, x, y, _keyboard_mode, tooltip| {
+            let Ok(engine) = engine_for_tooltip.try_borrow() else {
+                return false;
+            };
+            let (x, y) = engine.widget_to_content(f64::from(x), f64::from(y));
+            match engine
+                .time_scale_mark_at(x, y)
+                .and_then(|mark| mark.tooltip.as_deref())
+            {
+                Some(text) => {
+                    tooltip.set_text(Some(text));
+                    true
+                }
+                None => false,
+            }
+        });
+
         Self::schedule_draw_request(&drawing_area, &engine, &frame_request_pending, false);
 
         Self {
@@ -146,7 +308,14 @@ impl<R: Renderer + CairoContextRenderer + 'static> GtkChartAdapter<R> {
             diagnostics_hook,
             snapshot_hook,
             snapshot_hook_body_width_px,
+            series_legend_hook,
+            last_series_legend,
             frame_request_pending,
+            accessible_description_enabled,
+            last_accessible_description,
+            interaction_controllers: RefCell::new(Vec::new()),
+            resize_policy,
+            animation_tick_active: Rc::new(Cell::new(false)),
         }
     }
 
@@ -195,6 +364,11 @@ impl<R: Renderer + CairoContextRenderer + 'static> GtkChartAdapter<R> {
     ) -> ChartResult<T> {
         let output = self.with_engine_mut(f, "update engine")?;
         self.queue_draw_if_invalidated();
+        Self::ensure_animation_tick(
+            &self.drawing_area,
+            &self.engine,
+            &self.animation_tick_active,
+        );
         Ok(output)
     }
 
@@ -203,6 +377,12 @@ impl<R: Renderer + CairoContextRenderer + 'static> GtkChartAdapter<R> {
         self.frame_request_pending.get()
     }
 
+    /// Whether the frame-clock animation tick is installed.
+    #[must_use]
+    pub fn animation_tick_active(&self) -> bool {
+        self.animation_tick_active.get()
+    }
+
     pub fn set_crosshair_diagnostics_hook<F>(&self, hook: F)
     where
         F: Fn(CrosshairFormatterDiagnostics) + 'static,
@@ -226,6 +406,52 @@ impl<R: Renderer + CairoContextRenderer + 'static> GtkChartAdapter<R> {
         *self.snapshot_hook.borrow_mut() = None;
     }
 
+    /// Calls `hook` with `ChartEngine::series_legend` after drawn frames
+    /// whose legend differs from the last one delivered, so a bound legend
+    /// widget follows crosshair moves, range changes, and data updates.
+    pub fn set_series_legend_hook<F>(&self, hook: F)
+    where
+        F: Fn(&SeriesLegend) + 'static,
+    {
+        *self.series_legend_hook.borrow_mut() = Some(Rc::new(hook));
+        *self.last_series_legend.borrow_mut() = None;
+        self.queue_draw_force();
+    }
+
+    pub fn clear_series_legend_hook(&self) {
+        *self.series_legend_hook.borrow_mut() = None;
+        *self.last_series_legend.borrow_mut() = None;
+    }
+
+    /// Keeps the drawing area's accessible description in sync with
+    /// `a11y::describe_view`.
+    ///
+    /// The text is refreshed after each drawn frame, so crosshair moves,
+    /// visible-range changes, and data updates are announced once they
+    /// reach the screen. Disabling resets the description.
+    pub fn set_accessible_description_enabled(&self, enabled: bool) -> ChartResult<()> {
+        self.accessible_description_enabled.set(enabled);
+        if enabled {
+            let description =
+                self.with_engine_ref(|engine| Ok(describe_view(engine)), "accessible description")?;
+            Self::sync_accessible_description(
+                &self.drawing_area,
+                &self.last_accessible_description,
+                description,
+            );
+        } else {
+            self.last_accessible_description.borrow_mut().clear();
+            self.drawing_area
+                .reset_property(gtk::AccessibleProperty::Description);
+        }
+        Ok(())
+    }
+
+    #[must_use]
+    pub fn accessible_description_enabled(&self) -> bool {
+        self.accessible_description_enabled.get()
+    }
+
     pub fn crosshair_formatter_diagnostics(&self) -> ChartResult<CrosshairFormatterDiagnostics> {
         self.with_engine_ref(
             |engine| Ok(engine.crosshair_formatter_diagnostics()),
@@ -303,8 +529,12 @@ impl<R: Renderer + CairoContextRenderer + 'static> GtkChartAdapter<R> {
 
 #[cfg(test)]
 mod tests {
-    use super::{GtkChartAdapter, gtk};
-    use crate::api::{ChartEngine, ChartEngineConfig, TimeCoordinateIndexPolicy};
+    use std::time::Duration;
+
+    use super::{GtkChartAdapter, ResizePolicy, gtk};
+    use crate::api::{
+        ChartEngine, ChartEngineConfig, TimeCoordinateIndexPolicy, ViewportResizePolicy,
+    };
     use crate::core::{DataPoint, Viewport};
     use crate::render::CairoRenderer;
 
@@ -423,4 +653,37 @@ mod tests {
         adapter.queue_draw_if_invalidated();
         assert!(!adapter.frame_request_pending());
     }
+
+    #[test]
+    fn gtk_adapter_accessible_description_tracks_engine_state() {
+        let Some(adapter) = build_adapter() else {
+            return;
+        };
+
+        adapter
+            .set_accessible_description_enabled(true)
+            .expect("enable description");
+        assert!(adapter.accessible_description_enabled());
+        let description = adapter.last_accessible_description.borrow().clone();
+        assert!(description.contains("Last price"));
+
+        adapter
+            .set_accessible_description_enabled(false)
+            .expect("disable description");
+        assert!(adapter.last_accessible_description.borrow().is_empty());
+    }
+
+    #[test]
+    fn gtk_adapter_resize_policy_is_configurable_per_chart() {
+        let Some(adapter) = build_adapter() else {
+            return;
+        };
+        assert_eq!(adapter.resize_policy(), ResizePolicy::default());
+
+        let policy = ResizePolicy::default()
+            .with_layout(ViewportResizePolicy::PreserveRightOffset)
+            .with_autoscale_debounce(Some(Duration::from_millis(150)));
+        adapter.set_resize_policy(policy);
+        assert_eq!(adapter.resize_policy(), policy);
+    }
 }
@@ -0,0 +1,93 @@
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use std::time::Duration;
+
+use gtk4 as gtk;
+
+use crate::api::{ChartEngine, ViewportResizePolicy};
+use crate::core::Viewport;
+use crate::error::ChartResult;
+use crate::render::{CairoContextRenderer, Renderer};
+
+use super::GtkChartAdapter;
+
+/// Per-chart behavior when the drawing area changes size.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ResizePolicy {
+    pub layout: ViewportResizePolicy,
+    /// When set, the price scale is re-autoscaled from the visible data
+    /// once no resize happened for this long.
+    pub autoscale_debounce: Option<Duration>,
+}
+
+impl ResizePolicy {
+    #[must_use]
+    pub fn with_layout(mut self, layout: ViewportResizePolicy) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    #[must_use]
+    pub fn with_autoscale_debounce(mut self, delay: Option<Duration>) -> Self {
+        self.autoscale_debounce = delay;
+        self
+    }
+}
+
+/// Autoscales from visible candles, or visible points when no candles are
+/// loaded.
+fn autoscale_from_visible<R: Renderer>(engine: &mut ChartEngine<R>) -> ChartResult<()> {
+    if engine.candles().is_empty() {
+        engine.autoscale_price_from_visible_data()
+    } else {
+        engine.autoscale_price_from_visible_candles()
+    }
+}
+
+impl<R: Renderer + CairoContextRenderer + 'static> GtkChartAdapter<R> {
+    pub fn set_resize_policy(&self, policy: ResizePolicy) {
+        self.resize_policy.set(policy);
+    }
+
+    #[must_use]
+    pub fn resize_policy(&self) -> ResizePolicy {
+        self.resize_policy.get()
+    }
+
+    /// Applies the resize policy from the draw callback, where `engine` is
+    /// already borrowed, and (re)starts the debounced autoscale timer.
+    pub(super) fn apply_resize(
+        engine: &mut ChartEngine<R>,
+        viewport: Viewport,
+        policy: ResizePolicy,
+        drawing_area: &gtk::DrawingArea,
+        engine_rc: &Rc<RefCell<ChartEngine<R>>>,
+        frame_request_pending: &Rc<Cell<bool>>,
+        autoscale_source: &Rc<RefCell<Option<gtk::glib::SourceId>>>,
+    ) {
+        if engine.resize_viewport(viewport, policy.layout).is_err() {
+            return;
+        }
+        let Some(delay) = policy.autoscale_debounce else {
+            return;
+        };
+
+        if let Some(pending) = autoscale_source.borrow_mut().take() {
+            pending.remove();
+        }
+        let drawing_area = drawing_area.clone();
+        let engine_rc = Rc::clone(engine_rc);
+        let frame_request_pending = Rc::clone(frame_request_pending);
+        let source_slot = Rc::clone(autoscale_source);
+        let source = gtk::glib::timeout_add_local_once(delay, move || {
+            // The source is finished once this runs; forget it so the next
+            // resize does not try to remove it.
+            source_slot.borrow_mut().take();
+            if let Ok(mut engine) = engine_rc.try_borrow_mut() {
+                let _ = autoscale_from_visible(&mut engine);
+            }
+            Self::schedule_draw_request(&drawing_area, &engine_rc, &frame_request_pending, false);
+        });
+        *autoscale_source.borrow_mut() = Some(source);
+    }
+}
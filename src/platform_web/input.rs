@@ -0,0 +1,171 @@
+use crate::api::{PointerInput, TouchGestureTracker};
+
+/// Pixel scroll distance treated as one wheel notch (Chromium reports
+/// 100 px per notch).
+const PIXELS_PER_SCROLL_NOTCH: f64 = 100.0;
+/// Lines per wheel notch in `DOM_DELTA_LINE` mode (Firefox).
+const LINES_PER_SCROLL_NOTCH: f64 = 3.0;
+/// Scale applied to ctrl+wheel deltas, which browsers emit for trackpad
+/// pinches.
+const PINCH_DELTA_SCALE: f64 = 0.01;
+
+/// `WheelEvent.deltaMode` values.
+pub(super) const DOM_DELTA_PIXEL: u32 = 0;
+pub(super) const DOM_DELTA_LINE: u32 = 1;
+
+/// Translates DOM pointer events into `PointerInput`.
+///
+/// Mouse and pen pointers map directly; touch pointers go through
+/// `TouchGestureTracker` so two fingers pinch. Coordinates are CSS pixels
+/// relative to the canvas, matching the engine viewport.
+#[derive(Debug, Default)]
+pub(super) struct WebInputTranslator {
+    touches: TouchGestureTracker,
+}
+
+impl WebInputTranslator {
+    pub(super) fn pointer_down(
+        &mut self,
+        pointer_id: i32,
+        is_touch: bool,
+        button: i16,
+        x: f64,
+        y: f64,
+    ) -> Vec<PointerInput> {
+        if is_touch {
+            return self.touches.touch_started(touch_id(pointer_id), x, y);
+        }
+        if button != 0 {
+            return Vec::new();
+        }
+        vec![PointerInput::Moved { x, y }, PointerInput::Pressed { x, y }]
+    }
+
+    pub(super) fn pointer_move(
+        &mut self,
+        pointer_id: i32,
+        is_touch: bool,
+        x: f64,
+        y: f64,
+    ) -> Vec<PointerInput> {
+        if is_touch {
+            return self.touches.touch_moved(touch_id(pointer_id), x, y);
+        }
+        vec![PointerInput::Moved { x, y }]
+    }
+
+    pub(super) fn pointer_up(
+        &mut self,
+        pointer_id: i32,
+        is_touch: bool,
+        button: i16,
+    ) -> Vec<PointerInput> {
+        if is_touch {
+            return self.touches.touch_ended(touch_id(pointer_id));
+        }
+        if button != 0 {
+            return Vec::new();
+        }
+        vec![PointerInput::Released]
+    }
+
+    /// `pointercancel` (the browser took over the gesture).
+    pub(super) fn pointer_cancel(&mut self, pointer_id: i32, is_touch: bool) -> Vec<PointerInput> {
+        if is_touch {
+            return self.touches.touch_ended(touch_id(pointer_id));
+        }
+        vec![PointerInput::Released, PointerInput::Left]
+    }
+
+    /// `pointerleave`; touches end through `pointerup`/`pointercancel`.
+    pub(super) fn pointer_leave(&mut self, is_touch: bool) -> Vec<PointerInput> {
+        if is_touch {
+            return Vec::new();
+        }
+        vec![PointerInput::Left]
+    }
+}
+
+fn touch_id(pointer_id: i32) -> u64 {
+    u64::from(pointer_id as u32)
+}
+
+/// DOM wheel deltas already follow wheel direction (positive `deltaY` =
+/// wheel down = zoom out), so only the unit changes. Ctrl+wheel is how
+/// browsers report trackpad pinches and becomes `Pinched`.
+pub(super) fn wheel_input(
+    delta_x: f64,
+    delta_y: f64,
+    delta_mode: u32,
+    ctrl_key: bool,
+    anchor_x: f64,
+) -> PointerInput {
+    if ctrl_key {
+        return PointerInput::Pinched {
+            factor: (-delta_y * PINCH_DELTA_SCALE).exp(),
+            anchor_x,
+        };
+    }
+    let notches_per_unit = match delta_mode {
+        DOM_DELTA_PIXEL => 1.0 / PIXELS_PER_SCROLL_NOTCH,
+        DOM_DELTA_LINE => 1.0 / LINES_PER_SCROLL_NOTCH,
+        // DOM_DELTA_PAGE: one page per notch.
+        _ => 1.0,
+    };
+    PointerInput::Scrolled {
+        delta_x: delta_x * notches_per_unit,
+        delta_y: delta_y * notches_per_unit,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DOM_DELTA_LINE, DOM_DELTA_PIXEL, WebInputTranslator, wheel_input};
+    use crate::api::PointerInput;
+
+    #[test]
+    fn wheel_units_become_notches() {
+        assert_eq!(
+            wheel_input(0.0, 100.0, DOM_DELTA_PIXEL, false, 0.0),
+            PointerInput::Scrolled {
+                delta_x: 0.0,
+                delta_y: 1.0
+            }
+        );
+        assert_eq!(
+            wheel_input(-3.0, 0.0, DOM_DELTA_LINE, false, 0.0),
+            PointerInput::Scrolled {
+                delta_x: -1.0,
+                delta_y: 0.0
+            }
+        );
+    }
+
+    #[test]
+    fn ctrl_wheel_becomes_pinch_at_anchor() {
+        let PointerInput::Pinched { factor, anchor_x } =
+            wheel_input(0.0, -10.0, DOM_DELTA_PIXEL, true, 120.0)
+        else {
+            panic!("expected pinch");
+        };
+        assert!(factor > 1.0);
+        assert_eq!(anchor_x, 120.0);
+    }
+
+    #[test]
+    fn secondary_mouse_buttons_are_ignored() {
+        let mut translator = WebInputTranslator::default();
+        assert!(translator.pointer_down(1, false, 2, 10.0, 20.0).is_empty());
+        assert_eq!(
+            translator.pointer_down(1, false, 0, 10.0, 20.0),
+            vec![
+                PointerInput::Moved { x: 10.0, y: 20.0 },
+                PointerInput::Pressed { x: 10.0, y: 20.0 }
+            ]
+        );
+        assert_eq!(
+            translator.pointer_up(1, false, 0),
+            vec![PointerInput::Released]
+        );
+    }
+}
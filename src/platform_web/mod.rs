@@ -0,0 +1,327 @@
+use std::cell::{Cell, RefCell};
+use std::rc::{Rc, Weak};
+use std::time::Duration;
+
+use wasm_bindgen::JsCast;
+use wasm_bindgen::closure::Closure;
+use web_sys::{
+    AddEventListenerOptions, CanvasRenderingContext2d, Event, HtmlCanvasElement, MouseEvent,
+    PointerEvent, WheelEvent,
+};
+
+use crate::api::{
+    ChartEngine, ChartEngineConfig, InteractionOptions, PointerCursorHint, PointerInput,
+    PointerInputBridge, ViewportResizePolicy,
+};
+use crate::core::Viewport;
+use crate::error::{ChartError, ChartResult};
+use crate::render::CanvasRenderer;
+
+mod input;
+
+use input::{WebInputTranslator, wheel_input};
+
+/// Setup for `WebChart`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct WebChartOptions {
+    pub interactions: InteractionOptions,
+    pub resize_policy: ViewportResizePolicy,
+}
+
+impl WebChartOptions {
+    #[must_use]
+    pub fn with_interactions(mut self, interactions: InteractionOptions) -> Self {
+        self.interactions = interactions;
+        self
+    }
+
+    #[must_use]
+    pub fn with_resize_policy(mut self, policy: ViewportResizePolicy) -> Self {
+        self.resize_policy = policy;
+        self
+    }
+}
+
+type EventCallback = Closure<dyn FnMut(Event)>;
+type FrameCallback = Closure<dyn FnMut(f64)>;
+
+/// Browser adapter that wires a `ChartEngine` into an HTML `<canvas>`.
+///
+/// Frames are drawn by `CanvasRenderer`; pointer, touch, wheel, and
+/// double-click events go through `PointerInputBridge`, the same path the
+/// GTK, winit, and egui adapters use. Redraws are coalesced onto the next
+/// `requestAnimationFrame`. Dropping the adapter removes its listeners.
+pub struct WebChart {
+    shared: Rc<WebChartShared>,
+    listeners: Vec<(&'static str, EventCallback)>,
+}
+
+struct WebChartShared {
+    canvas: HtmlCanvasElement,
+    engine: Rc<RefCell<ChartEngine<CanvasRenderer>>>,
+    bridge: RefCell<PointerInputBridge>,
+    translator: RefCell<WebInputTranslator>,
+    resize_policy: ViewportResizePolicy,
+    frame_callback: RefCell<Option<FrameCallback>>,
+    frame_request: Cell<Option<i32>>,
+}
+
+impl WebChart {
+    /// Creates the engine on `canvas` and installs its event listeners.
+    ///
+    /// The config viewport is in CSS pixels; call `resize` when the canvas
+    /// box or `devicePixelRatio` changes.
+    pub fn new(
+        canvas: HtmlCanvasElement,
+        config: ChartEngineConfig,
+        options: WebChartOptions,
+    ) -> ChartResult<Self> {
+        let context = canvas
+            .get_context("2d")
+            .map_err(|err| map_dom_error("failed to get canvas context", err))?
+            .and_then(|context| context.dyn_into::<CanvasRenderingContext2d>().ok())
+            .ok_or_else(|| {
+                ChartError::RendererUnavailable("canvas has no 2d context".to_owned())
+            })?;
+        let mut renderer = CanvasRenderer::new(context);
+        if let Some(window) = web_sys::window() {
+            renderer.set_pixel_ratio(window.device_pixel_ratio())?;
+        }
+        let engine = ChartEngine::new(renderer, config)?;
+
+        let shared = Rc::new(WebChartShared {
+            canvas,
+            engine: Rc::new(RefCell::new(engine)),
+            bridge: RefCell::new(PointerInputBridge::new(options.interactions)),
+            translator: RefCell::new(WebInputTranslator::default()),
+            resize_policy: options.resize_policy,
+            frame_callback: RefCell::new(None),
+            frame_request: Cell::new(None),
+        });
+        let weak = Rc::downgrade(&shared);
+        *shared.frame_callback.borrow_mut() = Some(FrameCallback::new(move |timestamp: f64| {
+            if let Some(shared) = weak.upgrade() {
+                shared.frame_request.set(None);
+                let mut engine = shared.engine.borrow_mut();
+                // `requestAnimationFrame` timestamps are milliseconds.
+                engine.frame_clock_tick(Duration::from_secs_f64(timestamp.max(0.0) / 1000.0));
+                // A failed frame leaves the previous canvas content.
+                let _ = engine.render();
+            }
+        }));
+
+        let mut chart = Self {
+            shared,
+            listeners: Vec::new(),
+        };
+        chart.install_listeners()?;
+        chart.queue_draw();
+        Ok(chart)
+    }
+
+    #[must_use]
+    pub fn canvas(&self) -> &HtmlCanvasElement {
+        &self.shared.canvas
+    }
+
+    #[must_use]
+    pub fn engine(&self) -> Rc<RefCell<ChartEngine<CanvasRenderer>>> {
+        Rc::clone(&self.shared.engine)
+    }
+
+    /// Schedules one render on the next animation frame. Multiple calls
+    /// before the frame are coalesced.
+    pub fn queue_draw(&self) {
+        self.shared.queue_draw();
+    }
+
+    /// Renders immediately, bypassing `requestAnimationFrame`.
+    pub fn render_now(&self) -> ChartResult<()> {
+        self.shared.engine.borrow_mut().render()
+    }
+
+    /// Applies a new CSS-pixel size and device-pixel ratio, then queues a
+    /// draw.
+    pub fn resize(&self, width: u32, height: u32, pixel_ratio: f64) -> ChartResult<()> {
+        {
+            let mut engine = self.shared.engine.borrow_mut();
+            let viewport = Viewport::new(width, height);
+            if engine.viewport() != viewport {
+                engine.resize_viewport(viewport, self.shared.resize_policy)?;
+            }
+            engine.renderer_mut().set_pixel_ratio(pixel_ratio)?;
+        }
+        self.queue_draw();
+        Ok(())
+    }
+
+    fn install_listeners(&mut self) -> ChartResult<()> {
+        self.listen("pointerdown", false, |shared, event| {
+            let Some(event) = event.dyn_ref::<PointerEvent>() else {
+                return Vec::new();
+            };
+            // Keep receiving moves while dragging outside the canvas.
+            let _ = shared.canvas.set_pointer_capture(event.pointer_id());
+            let (x, y) = offset(event);
+            shared.translator.borrow_mut().pointer_down(
+                event.pointer_id(),
+                is_touch(event),
+                event.button(),
+                x,
+                y,
+            )
+        })?;
+        self.listen("pointermove", false, |shared, event| {
+            let Some(event) = event.dyn_ref::<PointerEvent>() else {
+                return Vec::new();
+            };
+            let (x, y) = offset(event);
+            shared
+                .translator
+                .borrow_mut()
+                .pointer_move(event.pointer_id(), is_touch(event), x, y)
+        })?;
+        self.listen("pointerup", false, |shared, event| {
+            let Some(event) = event.dyn_ref::<PointerEvent>() else {
+                return Vec::new();
+            };
+            shared.translator.borrow_mut().pointer_up(
+                event.pointer_id(),
+                is_touch(event),
+                event.button(),
+            )
+        })?;
+        self.listen("pointercancel", false, |shared, event| {
+            let Some(event) = event.dyn_ref::<PointerEvent>() else {
+                return Vec::new();
+            };
+            shared
+                .translator
+                .borrow_mut()
+                .pointer_cancel(event.pointer_id(), is_touch(event))
+        })?;
+        self.listen("pointerleave", false, |shared, event| {
+            let Some(event) = event.dyn_ref::<PointerEvent>() else {
+                return Vec::new();
+            };
+            shared
+                .translator
+                .borrow_mut()
+                .pointer_leave(is_touch(event))
+        })?;
+        self.listen("dblclick", false, |_, event| {
+            let Some(event) = event.dyn_ref::<MouseEvent>() else {
+                return Vec::new();
+            };
+            let (x, y) = offset(event);
+            vec![PointerInput::DoubleClicked { x, y }]
+        })?;
+        // Non-passive so the page does not scroll or zoom under the chart.
+        self.listen("wheel", true, |_, event| {
+            let Some(event) = event.dyn_ref::<WheelEvent>() else {
+                return Vec::new();
+            };
+            event.prevent_default();
+            let (x, _) = offset(event);
+            vec![wheel_input(
+                event.delta_x(),
+                event.delta_y(),
+                event.delta_mode(),
+                event.ctrl_key(),
+                x,
+            )]
+        })
+    }
+
+    fn listen(
+        &mut self,
+        event_type: &'static str,
+        cancelable: bool,
+        translate: impl Fn(&WebChartShared, &Event) -> Vec<PointerInput> + 'static,
+    ) -> ChartResult<()> {
+        let weak: Weak<WebChartShared> = Rc::downgrade(&self.shared);
+        let callback = EventCallback::new(move |event: Event| {
+            let Some(shared) = weak.upgrade() else {
+                return;
+            };
+            let inputs = translate(&shared, &event);
+            if inputs.is_empty() {
+                return;
+            }
+            {
+                let mut engine = shared.engine.borrow_mut();
+                let mut bridge = shared.bridge.borrow_mut();
+                for input in inputs {
+                    // Rejected gestures are dropped, as in the GTK adapter.
+                    let _ = bridge.handle(&mut engine, input);
+                }
+                if let Some(hint) = bridge.take_cursor_hint_change() {
+                    let cursor = match hint {
+                        PointerCursorHint::Default => "",
+                        PointerCursorHint::ResizeVertical => "row-resize",
+                    };
+                    let _ = shared.canvas.style().set_property("cursor", cursor);
+                }
+            }
+            shared.queue_draw();
+        });
+
+        let options = AddEventListenerOptions::new();
+        options.set_passive(!cancelable);
+        self.shared
+            .canvas
+            .add_event_listener_with_callback_and_add_event_listener_options(
+                event_type,
+                callback.as_ref().unchecked_ref(),
+                &options,
+            )
+            .map_err(|err| map_dom_error("failed to add event listener", err))?;
+        self.listeners.push((event_type, callback));
+        Ok(())
+    }
+}
+
+impl WebChartShared {
+    fn queue_draw(&self) {
+        if self.frame_request.get().is_some() {
+            return;
+        }
+        let Some(window) = web_sys::window() else {
+            return;
+        };
+        let frame_callback = self.frame_callback.borrow();
+        let Some(callback) = frame_callback.as_ref() else {
+            return;
+        };
+        if let Ok(handle) = window.request_animation_frame(callback.as_ref().unchecked_ref()) {
+            self.frame_request.set(Some(handle));
+        }
+    }
+}
+
+impl Drop for WebChart {
+    fn drop(&mut self) {
+        for (event_type, callback) in self.listeners.drain(..) {
+            let _ = self
+                .shared
+                .canvas
+                .remove_event_listener_with_callback(event_type, callback.as_ref().unchecked_ref());
+        }
+        if let (Some(handle), Some(window)) = (self.shared.frame_request.take(), web_sys::window())
+        {
+            let _ = window.cancel_animation_frame(handle);
+        }
+    }
+}
+
+fn offset(event: &MouseEvent) -> (f64, f64) {
+    (f64::from(event.offset_x()), f64::from(event.offset_y()))
+}
+
+fn is_touch(event: &PointerEvent) -> bool {
+    event.pointer_type() == "touch"
+}
+
+fn map_dom_error(context: &str, err: wasm_bindgen::JsValue) -> ChartError {
+    ChartError::RendererUnavailable(format!("{context}: {err:?}"))
+}
@@ -0,0 +1,197 @@
+use std::time::{Duration, Instant};
+
+use winit::event::{ElementState, MouseButton, MouseScrollDelta, TouchPhase, WindowEvent};
+use winit::keyboard::{Key, NamedKey};
+
+use crate::api::{PointerInput, PointerModifiers, TouchGestureTracker};
+
+/// Pixel scroll distance treated as one wheel notch (touchpads and
+/// high-resolution wheels report pixel deltas).
+const PIXELS_PER_SCROLL_NOTCH: f64 = 40.0;
+/// Maximum delay between two presses of a double-click.
+const DOUBLE_CLICK_INTERVAL: Duration = Duration::from_millis(400);
+/// Maximum pointer travel between two presses of a double-click.
+const DOUBLE_CLICK_DISTANCE_PX: f64 = 4.0;
+
+/// Translates winit window events into `PointerInput`.
+///
+/// winit reports raw presses and touches, so double-click detection lives
+/// here and touches go through `TouchGestureTracker`. Coordinates stay in
+/// physical pixels, matching the viewport the adapter gives the engine.
+#[derive(Debug, Default)]
+pub(super) struct WinitInputTranslator {
+    cursor: Option<(f64, f64)>,
+    last_press: Option<(Instant, f64, f64)>,
+    touches: TouchGestureTracker,
+}
+
+impl WinitInputTranslator {
+    pub(super) fn translate(&mut self, event: &WindowEvent, now: Instant) -> Vec<PointerInput> {
+        match event {
+            WindowEvent::CursorMoved { position, .. } => self.cursor_moved(position.x, position.y),
+            WindowEvent::CursorLeft { .. } => {
+                self.cursor = None;
+                vec![PointerInput::Left]
+            }
+            WindowEvent::MouseInput {
+                state,
+                button: MouseButton::Left,
+                ..
+            } => self.primary_button(*state, now),
+            WindowEvent::MouseWheel { delta, .. } => vec![wheel_input(*delta)],
+            WindowEvent::PinchGesture { delta, .. } if delta.is_finite() => {
+                let anchor_x = self.cursor.map_or(0.0, |(x, _)| x);
+                vec![PointerInput::Pinched {
+                    factor: 1.0 + delta,
+                    anchor_x,
+                }]
+            }
+            WindowEvent::ModifiersChanged(modifiers) => {
+                let state = modifiers.state();
+                vec![PointerInput::ModifiersChanged {
+                    modifiers: PointerModifiers {
+                        alt: state.alt_key(),
+                        shift: state.shift_key(),
+                        control: state.control_key(),
+                    },
+                }]
+            }
+            WindowEvent::Touch(touch) => {
+                self.touch(touch.id, touch.phase, touch.location.x, touch.location.y)
+            }
+            WindowEvent::KeyboardInput { event, .. }
+                if event.state == ElementState::Pressed
+                    && event.logical_key == Key::Named(NamedKey::Escape) =>
+            {
+                vec![PointerInput::Cancelled]
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    fn cursor_moved(&mut self, x: f64, y: f64) -> Vec<PointerInput> {
+        self.cursor = Some((x, y));
+        vec![PointerInput::Moved { x, y }]
+    }
+
+    fn primary_button(&mut self, state: ElementState, now: Instant) -> Vec<PointerInput> {
+        let Some((x, y)) = self.cursor else {
+            return Vec::new();
+        };
+        if state == ElementState::Released {
+            return vec![PointerInput::Released];
+        }
+        let is_double = self.last_press.is_some_and(|(at, last_x, last_y)| {
+            now.duration_since(at) <= DOUBLE_CLICK_INTERVAL
+                && (x - last_x).hypot(y - last_y) <= DOUBLE_CLICK_DISTANCE_PX
+        });
+        if is_double {
+            self.last_press = None;
+            vec![PointerInput::DoubleClicked { x, y }]
+        } else {
+            self.last_press = Some((now, x, y));
+            vec![PointerInput::Pressed { x, y }]
+        }
+    }
+
+    fn touch(&mut self, id: u64, phase: TouchPhase, x: f64, y: f64) -> Vec<PointerInput> {
+        match phase {
+            TouchPhase::Started => self.touches.touch_started(id, x, y),
+            TouchPhase::Moved => self.touches.touch_moved(id, x, y),
+            TouchPhase::Ended | TouchPhase::Cancelled => self.touches.touch_ended(id),
+        }
+    }
+}
+
+/// winit scroll deltas move the content (positive = reveal content up and
+/// left), while `PointerInput::Scrolled` follows wheel direction.
+fn wheel_input(delta: MouseScrollDelta) -> PointerInput {
+    let (delta_x, delta_y) = match delta {
+        MouseScrollDelta::LineDelta(x, y) => (f64::from(x), f64::from(y)),
+        MouseScrollDelta::PixelDelta(position) => (
+            position.x / PIXELS_PER_SCROLL_NOTCH,
+            position.y / PIXELS_PER_SCROLL_NOTCH,
+        ),
+    };
+    PointerInput::Scrolled {
+        delta_x: -delta_x,
+        delta_y: -delta_y,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use winit::event::{ElementState, MouseScrollDelta, TouchPhase};
+
+    use super::{WinitInputTranslator, wheel_input};
+    use crate::api::PointerInput;
+
+    #[test]
+    fn second_quick_press_becomes_double_click() {
+        let mut translator = WinitInputTranslator::default();
+        let start = Instant::now();
+        translator.cursor_moved(50.0, 60.0);
+
+        assert_eq!(
+            translator.primary_button(ElementState::Pressed, start),
+            vec![PointerInput::Pressed { x: 50.0, y: 60.0 }]
+        );
+        assert_eq!(
+            translator.primary_button(ElementState::Released, start),
+            vec![PointerInput::Released]
+        );
+        assert_eq!(
+            translator.primary_button(ElementState::Pressed, start + Duration::from_millis(150)),
+            vec![PointerInput::DoubleClicked { x: 50.0, y: 60.0 }]
+        );
+        assert_eq!(
+            translator.primary_button(ElementState::Pressed, start + Duration::from_secs(2)),
+            vec![PointerInput::Pressed { x: 50.0, y: 60.0 }]
+        );
+    }
+
+    #[test]
+    fn wheel_deltas_follow_wheel_direction() {
+        assert_eq!(
+            wheel_input(MouseScrollDelta::LineDelta(0.0, 1.0)),
+            PointerInput::Scrolled {
+                delta_x: 0.0,
+                delta_y: -1.0
+            }
+        );
+    }
+
+    #[test]
+    fn two_finger_spread_pinches_around_midpoint() {
+        let mut translator = WinitInputTranslator::default();
+        assert_eq!(
+            translator.touch(1, TouchPhase::Started, 100.0, 50.0),
+            vec![
+                PointerInput::Moved { x: 100.0, y: 50.0 },
+                PointerInput::Pressed { x: 100.0, y: 50.0 }
+            ]
+        );
+        assert_eq!(
+            translator.touch(2, TouchPhase::Started, 200.0, 50.0),
+            vec![PointerInput::Released]
+        );
+        assert_eq!(
+            translator.touch(2, TouchPhase::Moved, 300.0, 50.0),
+            vec![PointerInput::Pinched {
+                factor: 2.0,
+                anchor_x: 200.0
+            }]
+        );
+        assert!(
+            translator
+                .touch(2, TouchPhase::Ended, 300.0, 50.0)
+                .is_empty()
+        );
+        assert_eq!(
+            translator.touch(1, TouchPhase::Ended, 100.0, 50.0),
+            vec![PointerInput::Released, PointerInput::Left]
+        );
+    }
+}
@@ -0,0 +1,239 @@
+use std::num::NonZeroU32;
+use std::rc::Rc;
+use std::time::Instant;
+
+use softbuffer::{Context, Surface};
+use winit::application::ApplicationHandler;
+use winit::dpi::LogicalSize;
+use winit::event::WindowEvent;
+use winit::event_loop::{ActiveEventLoop, EventLoop};
+use winit::window::{CursorIcon, Window, WindowId};
+
+use crate::api::{
+    ChartEngine, InteractionOptions, PointerCursorHint, PointerInputBridge, ViewportResizePolicy,
+};
+use crate::core::Viewport;
+use crate::error::{ChartError, ChartResult};
+use crate::render::TinySkiaRenderer;
+
+mod input;
+
+use input::WinitInputTranslator;
+
+/// Window setup for `WinitChartApp`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WinitChartOptions {
+    pub title: String,
+    /// Initial inner size in logical pixels.
+    pub width: u32,
+    pub height: u32,
+    pub interactions: InteractionOptions,
+    pub resize_policy: ViewportResizePolicy,
+}
+
+impl Default for WinitChartOptions {
+    fn default() -> Self {
+        Self {
+            title: "chart-rs".to_owned(),
+            width: 960,
+            height: 540,
+            interactions: InteractionOptions::default(),
+            resize_policy: ViewportResizePolicy::default(),
+        }
+    }
+}
+
+impl WinitChartOptions {
+    #[must_use]
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    #[must_use]
+    pub fn with_size(mut self, width: u32, height: u32) -> Self {
+        self.width = width;
+        self.height = height;
+        self
+    }
+
+    #[must_use]
+    pub fn with_interactions(mut self, interactions: InteractionOptions) -> Self {
+        self.interactions = interactions;
+        self
+    }
+
+    #[must_use]
+    pub fn with_resize_policy(mut self, policy: ViewportResizePolicy) -> Self {
+        self.resize_policy = policy;
+        self
+    }
+}
+
+type WindowSurface = Surface<Rc<Window>, Rc<Window>>;
+
+/// winit application that owns one chart window.
+///
+/// Frames are rendered by the engine's `TinySkiaRenderer` and presented
+/// through softbuffer; pointer, wheel, pinch, and touch events go through
+/// `PointerInputBridge`. The engine viewport follows the window's physical
+/// size using the configured `ViewportResizePolicy`.
+pub struct WinitChartApp {
+    engine: ChartEngine<TinySkiaRenderer>,
+    options: WinitChartOptions,
+    bridge: PointerInputBridge,
+    translator: WinitInputTranslator,
+    window: Option<Rc<Window>>,
+    surface: Option<WindowSurface>,
+    surface_size: (u32, u32),
+    error: Option<ChartError>,
+}
+
+impl WinitChartApp {
+    #[must_use]
+    pub fn new(engine: ChartEngine<TinySkiaRenderer>, options: WinitChartOptions) -> Self {
+        let bridge = PointerInputBridge::new(options.interactions);
+        Self {
+            engine,
+            options,
+            bridge,
+            translator: WinitInputTranslator::default(),
+            window: None,
+            surface: None,
+            surface_size: (0, 0),
+            error: None,
+        }
+    }
+
+    #[must_use]
+    pub fn engine(&self) -> &ChartEngine<TinySkiaRenderer> {
+        &self.engine
+    }
+
+    pub fn engine_mut(&mut self) -> &mut ChartEngine<TinySkiaRenderer> {
+        &mut self.engine
+    }
+
+    /// Runs the event loop until the window closes and hands the engine
+    /// back. Window, surface, and render failures end the loop and are
+    /// returned as `ChartError::RendererUnavailable` (or the engine error).
+    pub fn run(mut self) -> ChartResult<ChartEngine<TinySkiaRenderer>> {
+        let event_loop = EventLoop::new().map_err(|err| {
+            ChartError::RendererUnavailable(format!("failed to create winit event loop: {err}"))
+        })?;
+        event_loop.run_app(&mut self).map_err(|err| {
+            ChartError::RendererUnavailable(format!("winit event loop failed: {err}"))
+        })?;
+        match self.error.take() {
+            Some(err) => Err(err),
+            None => Ok(self.engine),
+        }
+    }
+
+    fn create_window(&mut self, event_loop: &ActiveEventLoop) -> ChartResult<()> {
+        let attributes = Window::default_attributes()
+            .with_title(self.options.title.clone())
+            .with_inner_size(LogicalSize::new(self.options.width, self.options.height));
+        let window = event_loop
+            .create_window(attributes)
+            .map(Rc::new)
+            .map_err(|err| {
+                ChartError::RendererUnavailable(format!("failed to create window: {err}"))
+            })?;
+        let context = Context::new(Rc::clone(&window)).map_err(map_softbuffer_error)?;
+        let surface = Surface::new(&context, Rc::clone(&window)).map_err(map_softbuffer_error)?;
+        self.window = Some(window);
+        self.surface = Some(surface);
+        self.surface_size = (0, 0);
+        Ok(())
+    }
+
+    fn redraw(&mut self) -> ChartResult<()> {
+        let (Some(window), Some(surface)) = (self.window.as_ref(), self.surface.as_mut()) else {
+            return Ok(());
+        };
+        let size = window.inner_size();
+        let (Some(width), Some(height)) =
+            (NonZeroU32::new(size.width), NonZeroU32::new(size.height))
+        else {
+            return Ok(());
+        };
+
+        let viewport = Viewport::new(size.width, size.height);
+        if self.engine.viewport() != viewport {
+            self.engine
+                .resize_viewport(viewport, self.options.resize_policy)?;
+        }
+        self.engine.render()?;
+
+        if self.surface_size != (size.width, size.height) {
+            surface
+                .resize(width, height)
+                .map_err(map_softbuffer_error)?;
+            self.surface_size = (size.width, size.height);
+        }
+        let mut buffer = surface.buffer_mut().map_err(map_softbuffer_error)?;
+        self.engine.renderer().write_xrgb8888(&mut buffer)?;
+        buffer.present().map_err(map_softbuffer_error)
+    }
+
+    fn fail(&mut self, event_loop: &ActiveEventLoop, err: ChartError) {
+        self.error = Some(err);
+        event_loop.exit();
+    }
+}
+
+impl ApplicationHandler for WinitChartApp {
+    fn resumed(&mut self, event_loop: &ActiveEventLoop) {
+        if self.window.is_some() {
+            return;
+        }
+        if let Err(err) = self.create_window(event_loop) {
+            self.fail(event_loop, err);
+        }
+    }
+
+    fn window_event(
+        &mut self,
+        event_loop: &ActiveEventLoop,
+        _window_id: WindowId,
+        event: WindowEvent,
+    ) {
+        match event {
+            WindowEvent::CloseRequested => event_loop.exit(),
+            WindowEvent::RedrawRequested => {
+                if let Err(err) = self.redraw() {
+                    self.fail(event_loop, err);
+                }
+            }
+            WindowEvent::Resized(_) => {
+                if let Some(window) = &self.window {
+                    window.request_redraw();
+                }
+            }
+            event => {
+                let inputs = self.translator.translate(&event, Instant::now());
+                if inputs.is_empty() {
+                    return;
+                }
+                for input in inputs {
+                    // Rejected gestures are dropped, as in the GTK adapter.
+                    let _ = self.bridge.handle(&mut self.engine, input);
+                }
+                if let Some(window) = &self.window {
+                    if let Some(hint) = self.bridge.take_cursor_hint_change() {
+                        window.set_cursor(match hint {
+                            PointerCursorHint::Default => CursorIcon::Default,
+                            PointerCursorHint::ResizeVertical => CursorIcon::RowResize,
+                        });
+                    }
+                    window.request_redraw();
+                }
+            }
+        }
+    }
+}
+
+fn map_softbuffer_error(err: softbuffer::SoftBufferError) -> ChartError {
+    ChartError::RendererUnavailable(format!("softbuffer: {err}"))
+}
@@ -1,14 +1,19 @@
-use cairo::{Context, Format, ImageSurface};
-use pango::FontDescription;
+use cairo::{Context, FillRule, Format, ImageSurface};
+use pango::{FontDescription, Style, Weight};
 use std::f64::consts::{FRAC_PI_2, PI};
 
 use crate::error::{ChartError, ChartResult};
-use crate::render::{Color, LineStrokeStyle, RenderFrame, Renderer, TextHAlign};
+use crate::render::{
+    ClipRect, Color, GradientDirection, LinePrimitive, LineStrokeStyle, LinearGradient,
+    PathFillRule, PathPrimitive, RectPrimitive, RenderFrame, Renderer, TextFontWeight, TextHAlign,
+    TextPrimitive, TextVAlign,
+};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct CairoRenderStats {
     pub lines_drawn: usize,
     pub rects_drawn: usize,
+    pub paths_drawn: usize,
     pub texts_drawn: usize,
 }
 
@@ -133,54 +138,22 @@ impl CairoRenderer {
         let mut stats = CairoRenderStats::default();
 
         for line in &frame.lines {
-            apply_color(context, line.color);
-            context.set_line_width(line.stroke_width);
-            apply_line_stroke_style(context, line.stroke_style, line.stroke_width);
-            context.move_to(line.x1, line.y1);
-            context.line_to(line.x2, line.y2);
-            context
-                .stroke()
-                .map_err(|err| map_backend_error("failed to stroke line", err))?;
+            with_primitive_clip(context, line.clip_rect, || draw_line(context, *line))?;
             stats.lines_drawn += 1;
         }
 
         for rect in &frame.rects {
-            append_rect_path(context, *rect);
-            apply_color(context, rect.fill_color);
-            if rect.border_width > 0.0 {
-                context
-                    .fill_preserve()
-                    .map_err(|err| map_backend_error("failed to fill rectangle", err))?;
-                apply_color(context, rect.border_color);
-                context.set_line_width(rect.border_width);
-                context
-                    .stroke()
-                    .map_err(|err| map_backend_error("failed to stroke rectangle border", err))?;
-            } else {
-                context
-                    .fill()
-                    .map_err(|err| map_backend_error("failed to fill rectangle", err))?;
-            }
+            with_primitive_clip(context, rect.clip_rect, || draw_rect(context, *rect))?;
             stats.rects_drawn += 1;
         }
 
+        for path in &frame.paths {
+            with_primitive_clip(context, path.clip_rect, || draw_path(context, path))?;
+            stats.paths_drawn += 1;
+        }
+
         for text in &frame.texts {
-            let layout = pangocairo::functions::create_layout(context);
-            let font_description =
-                FontDescription::from_string(&format!("Sans {}", text.font_size_px));
-            layout.set_font_description(Some(&font_description));
-            layout.set_text(&text.text);
-
-            let (text_width, _text_height) = layout.pixel_size();
-            let x = match text.h_align {
-                TextHAlign::Left => text.x,
-                TextHAlign::Center => text.x - f64::from(text_width) / 2.0,
-                TextHAlign::Right => text.x - f64::from(text_width),
-            };
-
-            apply_color(context, text.color);
-            context.move_to(x, text.y);
-            pangocairo::functions::show_layout(context, &layout);
+            with_primitive_clip(context, text.clip_rect, || draw_text(context, text))?;
             stats.texts_drawn += 1;
         }
 
@@ -227,6 +200,181 @@ fn apply_color(context: &Context, color: Color) {
     context.set_source_rgba(color.red, color.green, color.blue, color.alpha);
 }
 
+/// Runs `draw` inside the primitive clip rect when one is set.
+fn with_primitive_clip(
+    context: &Context,
+    clip_rect: Option<ClipRect>,
+    draw: impl FnOnce() -> ChartResult<()>,
+) -> ChartResult<()> {
+    let Some(clip_rect) = clip_rect else {
+        return draw();
+    };
+    if clip_rect.is_empty() {
+        return Ok(());
+    }
+    context
+        .save()
+        .map_err(|err| map_backend_error("failed to save context", err))?;
+    context.rectangle(clip_rect.x, clip_rect.y, clip_rect.width, clip_rect.height);
+    context.clip();
+    let result = draw();
+    context
+        .restore()
+        .map_err(|err| map_backend_error("failed to restore context", err))?;
+    result
+}
+
+fn draw_line(context: &Context, line: LinePrimitive) -> ChartResult<()> {
+    apply_color(context, line.color);
+    context.set_line_width(line.stroke_width);
+    apply_line_stroke_style(context, line.stroke_style, line.stroke_width);
+    context.move_to(line.x1, line.y1);
+    context.line_to(line.x2, line.y2);
+    context
+        .stroke()
+        .map_err(|err| map_backend_error("failed to stroke line", err))
+}
+
+fn draw_rect(context: &Context, rect: RectPrimitive) -> ChartResult<()> {
+    append_rect_path(context, rect);
+    match rect.fill_gradient {
+        Some(gradient) => {
+            apply_linear_gradient(context, gradient, (rect.x, rect.y, rect.width, rect.height))?
+        }
+        None => apply_color(context, rect.fill_color),
+    }
+    if rect.border_width > 0.0 {
+        context
+            .fill_preserve()
+            .map_err(|err| map_backend_error("failed to fill rectangle", err))?;
+        apply_color(context, rect.border_color);
+        context.set_line_width(rect.border_width);
+        context
+            .stroke()
+            .map_err(|err| map_backend_error("failed to stroke rectangle border", err))
+    } else {
+        context
+            .fill()
+            .map_err(|err| map_backend_error("failed to fill rectangle", err))
+    }
+}
+
+fn draw_path(context: &Context, path: &PathPrimitive) -> ChartResult<()> {
+    let Some((first, rest)) = path.points.split_first() else {
+        return Ok(());
+    };
+    context.new_path();
+    context.move_to(first.x, first.y);
+    for point in rest {
+        context.line_to(point.x, point.y);
+    }
+    if path.closed {
+        context.close_path();
+    }
+
+    if let Some(fill_color) = path.fill_color {
+        context.set_fill_rule(match path.fill_rule {
+            PathFillRule::NonZero => FillRule::Winding,
+            PathFillRule::EvenOdd => FillRule::EvenOdd,
+        });
+        match (path.fill_gradient, path.bounds()) {
+            (Some(gradient), Some(bounds)) => apply_linear_gradient(context, gradient, bounds)?,
+            _ => apply_color(context, fill_color),
+        }
+        context
+            .fill_preserve()
+            .map_err(|err| map_backend_error("failed to fill path", err))?;
+    }
+
+    if path.is_stroked() {
+        apply_color(context, path.stroke_color);
+        context.set_line_width(path.stroke_width);
+        apply_line_stroke_style(context, path.stroke_style, path.stroke_width);
+        context
+            .stroke()
+            .map_err(|err| map_backend_error("failed to stroke path", err))?;
+    } else {
+        context.new_path();
+    }
+    Ok(())
+}
+
+fn draw_text(context: &Context, text: &TextPrimitive) -> ChartResult<()> {
+    let layout = pangocairo::functions::create_layout(context);
+    layout.set_font_description(Some(&font_description_for(text)));
+    layout.set_text(&text.text);
+
+    let (text_width, text_height) = layout.pixel_size();
+    let offset_x = match text.h_align {
+        TextHAlign::Left => 0.0,
+        TextHAlign::Center => -f64::from(text_width) / 2.0,
+        TextHAlign::Right => -f64::from(text_width),
+    };
+    let offset_y = match text.v_align {
+        TextVAlign::Top => 0.0,
+        TextVAlign::Middle => -f64::from(text_height) / 2.0,
+        TextVAlign::Bottom => -f64::from(text_height),
+    };
+
+    apply_color(context, text.color);
+    if !text.is_rotated() {
+        context.move_to(text.x + offset_x, text.y + offset_y);
+        pangocairo::functions::show_layout(context, &layout);
+        return Ok(());
+    }
+
+    context
+        .save()
+        .map_err(|err| map_backend_error("failed to save context", err))?;
+    context.translate(text.x, text.y);
+    context.rotate(text.rotation_radians);
+    context.move_to(offset_x, offset_y);
+    pangocairo::functions::update_layout(context, &layout);
+    pangocairo::functions::show_layout(context, &layout);
+    context
+        .restore()
+        .map_err(|err| map_backend_error("failed to restore context", err))
+}
+
+fn font_description_for(text: &TextPrimitive) -> FontDescription {
+    let mut font_description = FontDescription::new();
+    font_description.set_family(&text.font_family);
+    // Matches the historical `"Sans <size>"` description, which Pango reads as points.
+    font_description.set_size((text.font_size_px * f64::from(pango::SCALE)).round() as i32);
+    font_description.set_weight(match text.font_weight {
+        TextFontWeight::Light => Weight::Light,
+        TextFontWeight::Normal => Weight::Normal,
+        TextFontWeight::Medium => Weight::Medium,
+        TextFontWeight::Bold => Weight::Bold,
+    });
+    font_description.set_style(if text.italic {
+        Style::Italic
+    } else {
+        Style::Normal
+    });
+    font_description
+}
+
+/// Installs a two-stop gradient source spanning `bounds` (`x, y, width, height`).
+fn apply_linear_gradient(
+    context: &Context,
+    gradient: LinearGradient,
+    bounds: (f64, f64, f64, f64),
+) -> ChartResult<()> {
+    let (x, y, width, height) = bounds;
+    let (x1, y1) = match gradient.direction {
+        GradientDirection::Vertical => (x, y + height),
+        GradientDirection::Horizontal => (x + width, y),
+    };
+    let pattern = cairo::LinearGradient::new(x, y, x1, y1);
+    for (offset, color) in [(0.0, gradient.start_color), (1.0, gradient.end_color)] {
+        pattern.add_color_stop_rgba(offset, color.red, color.green, color.blue, color.alpha);
+    }
+    context
+        .set_source(&pattern)
+        .map_err(|err| map_backend_error("failed to set gradient source", err))
+}
+
 fn apply_line_stroke_style(context: &Context, stroke_style: LineStrokeStyle, stroke_width: f64) {
     match stroke_style {
         LineStrokeStyle::Solid => context.set_dash(&[], 0.0),
@@ -238,7 +386,7 @@ fn apply_line_stroke_style(context: &Context, stroke_style: LineStrokeStyle, str
     }
 }
 
-fn append_rect_path(context: &Context, rect: crate::render::RectPrimitive) {
+fn append_rect_path(context: &Context, rect: RectPrimitive) {
     if rect.corner_radius <= 0.0 {
         context.rectangle(rect.x, rect.y, rect.width, rect.height);
         return;
@@ -262,5 +410,5 @@ fn append_rect_path(context: &Context, rect: crate::render::RectPrimitive) {
 }
 
 fn map_backend_error(prefix: &str, err: cairo::Error) -> ChartError {
-    ChartError::InvalidData(format!("{prefix}: {err}"))
+    ChartError::RendererUnavailable(format!("{prefix}: {err}"))
 }
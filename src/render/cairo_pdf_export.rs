@@ -0,0 +1,103 @@
+use std::path::Path;
+
+use cairo::{Context, PdfSurface};
+
+use crate::error::{ChartError, ChartResult};
+use crate::render::{CairoContextRenderer, CairoRenderer, RenderFrame};
+
+/// Page margin around the fitted frame, in points.
+const PDF_PAGE_MARGIN_PT: f64 = 36.0;
+
+/// PDF page dimensions in points (1/72 inch).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PdfPageSize {
+    pub width_pt: f64,
+    pub height_pt: f64,
+}
+
+impl PdfPageSize {
+    /// ISO A4 portrait.
+    pub const A4: Self = Self::new(595.276, 841.89);
+    /// US Letter portrait.
+    pub const LETTER: Self = Self::new(612.0, 792.0);
+
+    #[must_use]
+    pub const fn new(width_pt: f64, height_pt: f64) -> Self {
+        Self {
+            width_pt,
+            height_pt,
+        }
+    }
+
+    /// Same page with width and height swapped.
+    #[must_use]
+    pub const fn landscape(self) -> Self {
+        Self::new(self.height_pt, self.width_pt)
+    }
+
+    pub fn validate(self) -> ChartResult<()> {
+        let printable = |size: f64| size.is_finite() && size > 2.0 * PDF_PAGE_MARGIN_PT;
+        if !printable(self.width_pt) || !printable(self.height_pt) {
+            return Err(ChartError::InvalidData(format!(
+                "pdf page size must be finite and larger than the {PDF_PAGE_MARGIN_PT}pt margins"
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Writes `frame` (laid out for `frame_width` x `frame_height` pixels) as a
+/// single-page vector PDF, scaled to fit inside the page margins and
+/// centered. Text stays selectable glyph runs instead of rasterized pixels.
+pub fn write_frame_pdf(
+    frame: &RenderFrame,
+    frame_width: f64,
+    frame_height: f64,
+    path: impl AsRef<Path>,
+    page_size: PdfPageSize,
+) -> ChartResult<()> {
+    page_size.validate()?;
+    if !(frame_width.is_finite()
+        && frame_width > 0.0
+        && frame_height.is_finite()
+        && frame_height > 0.0)
+    {
+        return Err(ChartError::InvalidData(
+            "pdf frame size must be finite and > 0".to_owned(),
+        ));
+    }
+
+    let surface = PdfSurface::new(page_size.width_pt, page_size.height_pt, path)
+        .map_err(|err| map_backend_error("failed to create pdf surface", err))?;
+    let context = Context::new(&surface)
+        .map_err(|err| map_backend_error("failed to create cairo context", err))?;
+
+    let available_width = page_size.width_pt - 2.0 * PDF_PAGE_MARGIN_PT;
+    let available_height = page_size.height_pt - 2.0 * PDF_PAGE_MARGIN_PT;
+    let scale = (available_width / frame_width).min(available_height / frame_height);
+    context.translate(
+        (page_size.width_pt - frame_width * scale) * 0.5,
+        (page_size.height_pt - frame_height * scale) * 0.5,
+    );
+    context.scale(scale, scale);
+    // Keeps the renderer's background clear inside the chart area.
+    context.rectangle(0.0, 0.0, frame_width, frame_height);
+    context.clip();
+
+    // The image surface is never drawn; the renderer only paints on `context`.
+    let mut renderer = CairoRenderer::new(1, 1)?;
+    renderer.render_on_cairo_context(&context, frame)?;
+
+    context
+        .show_page()
+        .map_err(|err| map_backend_error("failed to emit pdf page", err))?;
+    drop(context);
+    surface.finish();
+    surface
+        .status()
+        .map_err(|err| map_backend_error("failed to write pdf", err))
+}
+
+fn map_backend_error(prefix: &str, err: cairo::Error) -> ChartError {
+    ChartError::RendererUnavailable(format!("{prefix}: {err}"))
+}
@@ -0,0 +1,399 @@
+use wasm_bindgen::JsValue;
+use web_sys::{CanvasRenderingContext2d, CanvasWindingRule};
+
+use crate::error::{ChartError, ChartResult};
+use crate::render::{
+    ClipRect, Color, DEFAULT_TEXT_FONT_FAMILY, GradientDirection, LinePrimitive, LineStrokeStyle,
+    LinearGradient, PathFillRule, PathPrimitive, RectPrimitive, RenderFrame, Renderer,
+    TextFontWeight, TextHAlign, TextPrimitive, TextVAlign,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CanvasRenderStats {
+    pub lines_drawn: usize,
+    pub rects_drawn: usize,
+    pub paths_drawn: usize,
+    pub texts_drawn: usize,
+}
+
+/// HTML5 Canvas2D renderer for browser (`wasm32`) builds.
+///
+/// Frame coordinates are CSS pixels. The canvas backing store is resized to
+/// `viewport * pixel_ratio` on every render, and a matching transform is
+/// installed so frames stay sharp on high-density displays.
+#[derive(Debug, Clone)]
+pub struct CanvasRenderer {
+    context: CanvasRenderingContext2d,
+    clear_color: Color,
+    pixel_ratio: f64,
+    last_stats: CanvasRenderStats,
+}
+
+impl CanvasRenderer {
+    #[must_use]
+    pub fn new(context: CanvasRenderingContext2d) -> Self {
+        Self {
+            context,
+            clear_color: Color::rgb(1.0, 1.0, 1.0),
+            pixel_ratio: 1.0,
+            last_stats: CanvasRenderStats::default(),
+        }
+    }
+
+    #[must_use]
+    pub fn backend_name(&self) -> &'static str {
+        "canvas2d"
+    }
+
+    #[must_use]
+    pub fn context(&self) -> &CanvasRenderingContext2d {
+        &self.context
+    }
+
+    #[must_use]
+    pub fn clear_color(&self) -> Color {
+        self.clear_color
+    }
+
+    pub fn set_clear_color(&mut self, color: Color) -> ChartResult<()> {
+        color.validate()?;
+        self.clear_color = color;
+        Ok(())
+    }
+
+    #[must_use]
+    pub fn pixel_ratio(&self) -> f64 {
+        self.pixel_ratio
+    }
+
+    /// Sets the device-pixel ratio applied on the next render, usually
+    /// `window.devicePixelRatio`.
+    pub fn set_pixel_ratio(&mut self, pixel_ratio: f64) -> ChartResult<()> {
+        if !pixel_ratio.is_finite() || pixel_ratio <= 0.0 {
+            return Err(ChartError::InvalidData(
+                "canvas pixel ratio must be finite and > 0".to_owned(),
+            ));
+        }
+        self.pixel_ratio = pixel_ratio;
+        Ok(())
+    }
+
+    #[must_use]
+    pub fn last_stats(&self) -> CanvasRenderStats {
+        self.last_stats
+    }
+
+    fn prepare_canvas(&self, frame: &RenderFrame) -> ChartResult<()> {
+        let width = (f64::from(frame.viewport.width) * self.pixel_ratio).round() as u32;
+        let height = (f64::from(frame.viewport.height) * self.pixel_ratio).round() as u32;
+        if let Some(canvas) = self.context.canvas() {
+            // Assigning the size clears the canvas even when unchanged.
+            if canvas.width() != width {
+                canvas.set_width(width);
+            }
+            if canvas.height() != height {
+                canvas.set_height(height);
+            }
+        }
+        self.context
+            .set_transform(self.pixel_ratio, 0.0, 0.0, self.pixel_ratio, 0.0, 0.0)
+            .map_err(|err| map_canvas_error("failed to set canvas transform", err))?;
+        self.context
+            .set_fill_style_str(&css_color(self.clear_color));
+        self.context.fill_rect(
+            0.0,
+            0.0,
+            f64::from(frame.viewport.width),
+            f64::from(frame.viewport.height),
+        );
+        Ok(())
+    }
+
+    /// Runs `draw` with `clip_rect` applied. Returns `false` without drawing
+    /// when the clip is empty.
+    fn with_clip(
+        &self,
+        clip_rect: Option<ClipRect>,
+        draw: impl FnOnce(&CanvasRenderingContext2d) -> ChartResult<()>,
+    ) -> ChartResult<bool> {
+        let Some(clip_rect) = clip_rect else {
+            draw(&self.context)?;
+            return Ok(true);
+        };
+        if clip_rect.is_empty() {
+            return Ok(false);
+        }
+        self.context.save();
+        self.context.begin_path();
+        self.context
+            .rect(clip_rect.x, clip_rect.y, clip_rect.width, clip_rect.height);
+        self.context.clip();
+        let result = draw(&self.context);
+        self.context.restore();
+        result.map(|()| true)
+    }
+}
+
+impl Renderer for CanvasRenderer {
+    fn render(&mut self, frame: &RenderFrame) -> ChartResult<()> {
+        frame.validate()?;
+        self.clear_color.validate()?;
+        self.prepare_canvas(frame)?;
+
+        let mut stats = CanvasRenderStats::default();
+        for line in &frame.lines {
+            if self.with_clip(line.clip_rect, |context| draw_line(context, *line))? {
+                stats.lines_drawn += 1;
+            }
+        }
+        for rect in &frame.rects {
+            if self.with_clip(rect.clip_rect, |context| draw_rect(context, *rect))? {
+                stats.rects_drawn += 1;
+            }
+        }
+        for path in &frame.paths {
+            if self.with_clip(path.clip_rect, |context| draw_path(context, path))? {
+                stats.paths_drawn += 1;
+            }
+        }
+        for text in &frame.texts {
+            if self.with_clip(text.clip_rect, |context| draw_text(context, text))? {
+                stats.texts_drawn += 1;
+            }
+        }
+
+        self.last_stats = stats;
+        Ok(())
+    }
+}
+
+fn map_canvas_error(context: &str, err: JsValue) -> ChartError {
+    ChartError::RendererUnavailable(format!("{context}: {err:?}"))
+}
+
+/// CSS `rgba()` color string for `color`.
+fn css_color(color: Color) -> String {
+    let channel = |value: f64| (value.clamp(0.0, 1.0) * 255.0).round() as u8;
+    format!(
+        "rgba({}, {}, {}, {})",
+        channel(color.red),
+        channel(color.green),
+        channel(color.blue),
+        color.alpha.clamp(0.0, 1.0)
+    )
+}
+
+/// CSS font shorthand for `text`. The default family maps to the generic
+/// `sans-serif`, and every family falls back to it.
+fn css_font(text: &TextPrimitive) -> String {
+    let style = if text.italic { "italic " } else { "" };
+    let weight = match text.font_weight {
+        TextFontWeight::Light => 300,
+        TextFontWeight::Normal => 400,
+        TextFontWeight::Medium => 500,
+        TextFontWeight::Bold => 700,
+    };
+    let family = if text.font_family.is_empty() || text.font_family == DEFAULT_TEXT_FONT_FAMILY {
+        "sans-serif".to_owned()
+    } else {
+        format!("\"{}\", sans-serif", text.font_family.replace('"', ""))
+    };
+    format!("{style}{weight} {}px {family}", text.font_size_px)
+}
+
+/// Dash segments matching the Cairo backend; empty means solid.
+fn dash_pattern(width: f64, style: LineStrokeStyle) -> Vec<f64> {
+    match style {
+        LineStrokeStyle::Solid => Vec::new(),
+        LineStrokeStyle::Dashed => vec![width * 6.0, width * 4.0],
+        LineStrokeStyle::LargeDashed => vec![width * 8.0, width * 6.0],
+        LineStrokeStyle::Dotted => vec![width, width * 2.0],
+    }
+}
+
+fn apply_stroke(
+    context: &CanvasRenderingContext2d,
+    color: Color,
+    width: f64,
+    style: LineStrokeStyle,
+) -> ChartResult<()> {
+    let dashes = js_sys::Array::new();
+    for segment in dash_pattern(width, style) {
+        dashes.push(&JsValue::from_f64(segment));
+    }
+    context
+        .set_line_dash(&dashes)
+        .map_err(|err| map_canvas_error("failed to set line dash", err))?;
+    context.set_line_width(width);
+    context.set_stroke_style_str(&css_color(color));
+    Ok(())
+}
+
+/// Installs a two-stop gradient fill spanning `bounds` (`x, y, width, height`).
+fn apply_linear_gradient(
+    context: &CanvasRenderingContext2d,
+    gradient: LinearGradient,
+    bounds: (f64, f64, f64, f64),
+) -> ChartResult<()> {
+    let (x, y, width, height) = bounds;
+    let (x1, y1) = match gradient.direction {
+        GradientDirection::Vertical => (x, y + height),
+        GradientDirection::Horizontal => (x + width, y),
+    };
+    let pattern = context.create_linear_gradient(x, y, x1, y1);
+    for (offset, color) in [(0.0, gradient.start_color), (1.0, gradient.end_color)] {
+        pattern
+            .add_color_stop(offset, &css_color(color))
+            .map_err(|err| map_canvas_error("failed to add gradient stop", err))?;
+    }
+    context.set_fill_style_canvas_gradient(&pattern);
+    Ok(())
+}
+
+fn draw_line(context: &CanvasRenderingContext2d, line: LinePrimitive) -> ChartResult<()> {
+    apply_stroke(context, line.color, line.stroke_width, line.stroke_style)?;
+    context.begin_path();
+    context.move_to(line.x1, line.y1);
+    context.line_to(line.x2, line.y2);
+    context.stroke();
+    Ok(())
+}
+
+fn draw_rect(context: &CanvasRenderingContext2d, rect: RectPrimitive) -> ChartResult<()> {
+    context.begin_path();
+    if rect.corner_radius > 0.0 {
+        let radius = rect
+            .corner_radius
+            .min(rect.width * 0.5)
+            .min(rect.height * 0.5);
+        context
+            .round_rect_with_f64(rect.x, rect.y, rect.width, rect.height, radius)
+            .map_err(|err| map_canvas_error("failed to build rounded rect", err))?;
+    } else {
+        context.rect(rect.x, rect.y, rect.width, rect.height);
+    }
+
+    match rect.fill_gradient {
+        Some(gradient) => {
+            apply_linear_gradient(context, gradient, (rect.x, rect.y, rect.width, rect.height))?;
+        }
+        None => context.set_fill_style_str(&css_color(rect.fill_color)),
+    }
+    context.fill();
+    if rect.border_width > 0.0 {
+        apply_stroke(
+            context,
+            rect.border_color,
+            rect.border_width,
+            LineStrokeStyle::Solid,
+        )?;
+        context.stroke();
+    }
+    Ok(())
+}
+
+fn draw_path(context: &CanvasRenderingContext2d, path: &PathPrimitive) -> ChartResult<()> {
+    let Some((first, rest)) = path.points.split_first() else {
+        return Ok(());
+    };
+    context.begin_path();
+    context.move_to(first.x, first.y);
+    for point in rest {
+        context.line_to(point.x, point.y);
+    }
+    if path.closed {
+        context.close_path();
+    }
+
+    if let Some(fill_color) = path.fill_color {
+        match (path.fill_gradient, path.bounds()) {
+            (Some(gradient), Some(bounds)) => apply_linear_gradient(context, gradient, bounds)?,
+            _ => context.set_fill_style_str(&css_color(fill_color)),
+        }
+        let winding_rule = match path.fill_rule {
+            PathFillRule::NonZero => CanvasWindingRule::Nonzero,
+            PathFillRule::EvenOdd => CanvasWindingRule::Evenodd,
+        };
+        context.fill_with_canvas_winding_rule(winding_rule);
+    }
+
+    if path.is_stroked() {
+        apply_stroke(
+            context,
+            path.stroke_color,
+            path.stroke_width,
+            path.stroke_style,
+        )?;
+        context.stroke();
+    }
+    Ok(())
+}
+
+fn draw_text(context: &CanvasRenderingContext2d, text: &TextPrimitive) -> ChartResult<()> {
+    context.set_font(&css_font(text));
+    context.set_text_align(match text.h_align {
+        TextHAlign::Left => "left",
+        TextHAlign::Center => "center",
+        TextHAlign::Right => "right",
+    });
+    context.set_text_baseline(match text.v_align {
+        TextVAlign::Top => "top",
+        TextVAlign::Middle => "middle",
+        TextVAlign::Bottom => "bottom",
+    });
+    context.set_fill_style_str(&css_color(text.color));
+
+    if !text.is_rotated() {
+        return context
+            .fill_text(&text.text, text.x, text.y)
+            .map_err(|err| map_canvas_error("failed to draw text", err));
+    }
+
+    context.save();
+    let result = context
+        .translate(text.x, text.y)
+        .and_then(|()| context.rotate(text.rotation_radians))
+        .and_then(|()| context.fill_text(&text.text, 0.0, 0.0))
+        .map_err(|err| map_canvas_error("failed to draw rotated text", err));
+    context.restore();
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{css_color, css_font, dash_pattern};
+    use crate::render::{Color, LineStrokeStyle, TextFontWeight, TextHAlign, TextPrimitive};
+
+    #[test]
+    fn colors_become_css_rgba() {
+        assert_eq!(
+            css_color(Color::rgba(1.0, 0.5, 0.0, 0.25)),
+            "rgba(255, 128, 0, 0.25)"
+        );
+    }
+
+    #[test]
+    fn fonts_map_weight_style_and_family() {
+        let mut text = TextPrimitive::new(
+            "42",
+            0.0,
+            0.0,
+            12.0,
+            Color::rgb(0.0, 0.0, 0.0),
+            TextHAlign::Left,
+        );
+        assert_eq!(css_font(&text), "400 12px sans-serif");
+
+        text.font_family = "Inter".into();
+        text.font_weight = TextFontWeight::Bold;
+        text.italic = true;
+        assert_eq!(css_font(&text), "italic 700 12px \"Inter\", sans-serif");
+    }
+
+    #[test]
+    fn dash_patterns_scale_with_stroke_width() {
+        assert!(dash_pattern(2.0, LineStrokeStyle::Solid).is_empty());
+        assert_eq!(dash_pattern(2.0, LineStrokeStyle::Dashed), vec![12.0, 8.0]);
+        assert_eq!(dash_pattern(1.0, LineStrokeStyle::Dotted), vec![1.0, 2.0]);
+    }
+}
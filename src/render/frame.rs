@@ -1,6 +1,6 @@
 use crate::core::Viewport;
 use crate::error::{ChartError, ChartResult};
-use crate::render::{LinePrimitive, RectPrimitive, TextPrimitive};
+use crate::render::{LinePrimitive, PathPrimitive, RectPrimitive, TextPrimitive};
 
 /// Backend-agnostic scene for one chart draw pass.
 #[derive(Debug, Clone, PartialEq)]
@@ -8,6 +8,7 @@ pub struct RenderFrame {
     pub viewport: Viewport,
     pub lines: Vec<LinePrimitive>,
     pub rects: Vec<RectPrimitive>,
+    pub paths: Vec<PathPrimitive>,
     pub texts: Vec<TextPrimitive>,
 }
 
@@ -18,6 +19,7 @@ impl RenderFrame {
             viewport,
             lines: Vec::new(),
             rects: Vec::new(),
+            paths: Vec::new(),
             texts: Vec::new(),
         }
     }
@@ -40,6 +42,37 @@ impl RenderFrame {
         self
     }
 
+    #[must_use]
+    pub fn with_path(mut self, path: PathPrimitive) -> Self {
+        self.paths.push(path);
+        self
+    }
+
+    /// Empties every primitive buffer but keeps its capacity, so a recycled
+    /// frame can be refilled without reallocating.
+    pub fn clear(&mut self) {
+        self.lines.clear();
+        self.rects.clear();
+        self.paths.clear();
+        self.texts.clear();
+    }
+
+    /// Shifts every primitive (and its clip rect) by `(dx, dy)` pixels.
+    pub fn translate(&mut self, dx: f64, dy: f64) {
+        for line in &mut self.lines {
+            line.translate(dx, dy);
+        }
+        for rect in &mut self.rects {
+            rect.translate(dx, dy);
+        }
+        for path in &mut self.paths {
+            path.translate(dx, dy);
+        }
+        for text in &mut self.texts {
+            text.translate(dx, dy);
+        }
+    }
+
     pub fn validate(&self) -> ChartResult<()> {
         if !self.viewport.is_valid() {
             return Err(ChartError::InvalidViewport {
@@ -54,6 +87,9 @@ impl RenderFrame {
         for rect in &self.rects {
             rect.validate()?;
         }
+        for path in &self.paths {
+            path.validate()?;
+        }
         for text in &self.texts {
             text.validate()?;
         }
@@ -63,6 +99,9 @@ impl RenderFrame {
 
     #[must_use]
     pub fn is_empty(&self) -> bool {
-        self.lines.is_empty() && self.rects.is_empty() && self.texts.is_empty()
+        self.lines.is_empty()
+            && self.rects.is_empty()
+            && self.paths.is_empty()
+            && self.texts.is_empty()
     }
 }
@@ -1,7 +1,8 @@
 use crate::core::{PaneId, Viewport};
 
 use super::{
-    CanvasLayerKind, LinePrimitive, PaneLayerStack, RectPrimitive, RenderFrame, TextPrimitive,
+    CanvasLayerKind, ClipRect, LinePrimitive, PaneLayerStack, PathPrimitive, RectPrimitive,
+    RenderFrame, TextPrimitive,
 };
 
 #[derive(Debug, Clone, PartialEq)]
@@ -9,9 +10,22 @@ pub struct LayerPrimitives {
     pub kind: CanvasLayerKind,
     pub lines: Vec<LinePrimitive>,
     pub rects: Vec<RectPrimitive>,
+    pub paths: Vec<PathPrimitive>,
     pub texts: Vec<TextPrimitive>,
 }
 
+impl LayerPrimitives {
+    fn empty(kind: CanvasLayerKind) -> Self {
+        Self {
+            kind,
+            lines: Vec::new(),
+            rects: Vec::new(),
+            paths: Vec::new(),
+            texts: Vec::new(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct PaneLayerFrame {
     pub pane_id: PaneId,
@@ -36,12 +50,7 @@ impl LayeredRenderFrame {
                 let layers = stack
                     .layers
                     .into_iter()
-                    .map(|kind| LayerPrimitives {
-                        kind,
-                        lines: Vec::new(),
-                        rects: Vec::new(),
-                        texts: Vec::new(),
-                    })
+                    .map(LayerPrimitives::empty)
                     .collect();
                 PaneLayerFrame {
                     pane_id: stack.pane_id,
@@ -54,6 +63,44 @@ impl LayeredRenderFrame {
         Self { viewport, panes }
     }
 
+    /// Rebuilds the pane/layer skeleton for `stacks` like `from_stacks`, but
+    /// refills the cleared primitive buffers of the current layers (matched
+    /// by layer kind first) instead of allocating new ones.
+    pub fn reset_from_stacks(&mut self, viewport: Viewport, stacks: Vec<PaneLayerStack>) {
+        let mut spare: Vec<LayerPrimitives> =
+            self.panes.drain(..).flat_map(|pane| pane.layers).collect();
+        let default_bottom = f64::from(viewport.height);
+        self.viewport = viewport;
+        for stack in stacks {
+            let layers = stack
+                .layers
+                .into_iter()
+                .map(|kind| {
+                    let reused = spare
+                        .iter()
+                        .position(|layer| layer.kind == kind)
+                        .or_else(|| spare.len().checked_sub(1))
+                        .map(|index| spare.swap_remove(index));
+                    let Some(mut layer) = reused else {
+                        return LayerPrimitives::empty(kind);
+                    };
+                    layer.kind = kind;
+                    layer.lines.clear();
+                    layer.rects.clear();
+                    layer.paths.clear();
+                    layer.texts.clear();
+                    layer
+                })
+                .collect();
+            self.panes.push(PaneLayerFrame {
+                pane_id: stack.pane_id,
+                plot_top: 0.0,
+                plot_bottom: default_bottom,
+                layers,
+            });
+        }
+    }
+
     #[must_use]
     pub fn with_pane_regions(mut self, regions: &[(PaneId, f64, f64)]) -> Self {
         for pane in &mut self.panes {
@@ -77,6 +124,12 @@ impl LayeredRenderFrame {
         }
     }
 
+    pub fn push_path(&mut self, pane_id: PaneId, kind: CanvasLayerKind, path: PathPrimitive) {
+        if let Some(layer) = self.layer_mut(pane_id, kind) {
+            layer.paths.push(path);
+        }
+    }
+
     pub fn push_text(&mut self, pane_id: PaneId, kind: CanvasLayerKind, text: TextPrimitive) {
         if let Some(layer) = self.layer_mut(pane_id, kind) {
             layer.texts.push(text);
@@ -90,6 +143,7 @@ impl LayeredRenderFrame {
             for layer in &pane.layers {
                 frame.lines.extend(layer.lines.iter().copied());
                 frame.rects.extend(layer.rects.iter().copied());
+                frame.paths.extend(layer.paths.iter().cloned());
                 frame.texts.extend(layer.texts.iter().cloned());
             }
         }
@@ -103,6 +157,7 @@ impl LayeredRenderFrame {
         for layer in &pane.layers {
             frame.lines.extend(layer.lines.iter().copied());
             frame.rects.extend(layer.rects.iter().copied());
+            frame.paths.extend(layer.paths.iter().cloned());
             frame.texts.extend(layer.texts.iter().cloned());
         }
         Some(frame)
@@ -122,6 +177,7 @@ impl LayeredRenderFrame {
             }
             frame.lines.extend(layer.lines.iter().copied());
             frame.rects.extend(layer.rects.iter().copied());
+            frame.paths.extend(layer.paths.iter().cloned());
             frame.texts.extend(layer.texts.iter().cloned());
         }
         Some(frame)
@@ -147,6 +203,17 @@ impl LayeredRenderFrame {
             return;
         }
 
+        let remap =
+            |value: f64| remap_scalar(value, source_plot_top, source_span, target_top, target_span);
+        let remap_clip = |clip: &mut Option<ClipRect>| {
+            if let Some(clip) = clip {
+                let top = remap(clip.y);
+                let bottom = remap(clip.y + clip.height);
+                clip.y = top.min(bottom);
+                clip.height = (bottom - top).abs();
+            }
+        };
+
         for layer in &mut pane.layers {
             if matches!(
                 layer.kind,
@@ -155,47 +222,48 @@ impl LayeredRenderFrame {
                 continue;
             }
             for line in &mut layer.lines {
-                line.y1 = remap_scalar(
-                    line.y1,
-                    source_plot_top,
-                    source_span,
-                    target_top,
-                    target_span,
-                );
-                line.y2 = remap_scalar(
-                    line.y2,
-                    source_plot_top,
-                    source_span,
-                    target_top,
-                    target_span,
-                );
+                line.y1 = remap(line.y1);
+                line.y2 = remap(line.y2);
+                remap_clip(&mut line.clip_rect);
             }
             for rect in &mut layer.rects {
-                let top = remap_scalar(
-                    rect.y,
-                    source_plot_top,
-                    source_span,
-                    target_top,
-                    target_span,
-                );
-                let bottom = remap_scalar(
-                    rect.y + rect.height,
-                    source_plot_top,
-                    source_span,
-                    target_top,
-                    target_span,
-                );
+                let top = remap(rect.y);
+                let bottom = remap(rect.y + rect.height);
                 rect.y = top.min(bottom);
                 rect.height = (bottom - top).abs();
+                remap_clip(&mut rect.clip_rect);
+            }
+            for path in &mut layer.paths {
+                for point in &mut path.points {
+                    point.y = remap(point.y);
+                }
+                remap_clip(&mut path.clip_rect);
             }
             for text in &mut layer.texts {
-                text.y = remap_scalar(
-                    text.y,
-                    source_plot_top,
-                    source_span,
-                    target_top,
-                    target_span,
-                );
+                text.y = remap(text.y);
+                remap_clip(&mut text.clip_rect);
+            }
+        }
+    }
+
+    /// Shifts every primitive and pane region by `(dx, dy)` pixels.
+    pub fn translate(&mut self, dx: f64, dy: f64) {
+        for pane in &mut self.panes {
+            pane.plot_top += dy;
+            pane.plot_bottom += dy;
+            for layer in &mut pane.layers {
+                for line in &mut layer.lines {
+                    line.translate(dx, dy);
+                }
+                for rect in &mut layer.rects {
+                    rect.translate(dx, dy);
+                }
+                for path in &mut layer.paths {
+                    path.translate(dx, dy);
+                }
+                for text in &mut layer.texts {
+                    text.translate(dx, dy);
+                }
             }
         }
     }
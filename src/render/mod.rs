@@ -3,15 +3,20 @@ mod layer_stack;
 mod layered_frame;
 mod null_renderer;
 mod primitives;
+mod text_metrics;
 
 pub use frame::RenderFrame;
 pub use layer_stack::{CanvasLayerKind, PaneLayerStack};
 pub use layered_frame::{LayerPrimitives, LayeredRenderFrame, PaneLayerFrame};
 pub use null_renderer::NullRenderer;
 pub use primitives::{
-    Color, LinePrimitive, LineStrokeStyle, RectPrimitive, TextHAlign, TextPrimitive,
+    ArrowDirection, ArrowPrimitive, ClipRect, Color, DEFAULT_TEXT_FONT_FAMILY, GradientDirection,
+    LinePrimitive, LineStrokeStyle, LinearGradient, PathFillRule, PathPoint, PathPrimitive,
+    RectPrimitive, TextFontWeight, TextHAlign, TextPrimitive, TextVAlign,
 };
 
+pub use text_metrics::{DEJAVU_SANS_METRICS, FontMetricsTable};
+
 use crate::error::ChartResult;
 
 /// Contract implemented by any rendering backend.
@@ -26,3 +31,19 @@ pub trait Renderer {
 mod cairo_backend;
 #[cfg(feature = "cairo-backend")]
 pub use cairo_backend::{CairoContextRenderer, CairoRenderStats, CairoRenderer};
+#[cfg(feature = "pdf-export")]
+mod cairo_pdf_export;
+#[cfg(feature = "pdf-export")]
+pub use cairo_pdf_export::{PdfPageSize, write_frame_pdf};
+
+#[cfg(feature = "wasm")]
+mod canvas_backend;
+#[cfg(feature = "wasm")]
+pub use canvas_backend::{CanvasRenderStats, CanvasRenderer};
+
+#[cfg(feature = "tiny-skia-backend")]
+mod tiny_skia_backend;
+#[cfg(feature = "tiny-skia-backend")]
+mod tiny_skia_text;
+#[cfg(feature = "tiny-skia-backend")]
+pub use tiny_skia_backend::{TinySkiaRenderStats, TinySkiaRenderer};
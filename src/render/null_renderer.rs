@@ -9,6 +9,7 @@ use crate::render::{RenderFrame, Renderer};
 pub struct NullRenderer {
     pub last_line_count: usize,
     pub last_rect_count: usize,
+    pub last_path_count: usize,
     pub last_text_count: usize,
 }
 
@@ -17,6 +18,7 @@ impl Renderer for NullRenderer {
         frame.validate()?;
         self.last_line_count = frame.lines.len();
         self.last_rect_count = frame.rects.len();
+        self.last_path_count = frame.paths.len();
         self.last_text_count = frame.texts.len();
         Ok(())
     }
@@ -1,5 +1,9 @@
+use std::borrow::Cow;
+use std::sync::Arc;
+
 use serde::{Deserialize, Serialize};
 
+use crate::core::{AreaGeometry, AreaVertex, BaselineGeometry, BaselineVertex};
 use crate::error::{ChartError, ChartResult};
 
 /// RGBA color in normalized 0..=1 channel values.
@@ -44,6 +48,141 @@ impl Color {
     }
 }
 
+/// Axis-aligned clip rectangle in pixel space.
+///
+/// Primitives carrying a clip rect are drawn only inside it, which keeps
+/// overflow policy explicit (plot-area clip for series/crosshair content,
+/// axis-panel clips for labels) instead of relying on ad-hoc clamping.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ClipRect {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+impl ClipRect {
+    #[must_use]
+    pub const fn new(x: f64, y: f64, width: f64, height: f64) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    #[must_use]
+    pub fn right(self) -> f64 {
+        self.x + self.width
+    }
+
+    #[must_use]
+    pub fn bottom(self) -> f64 {
+        self.y + self.height
+    }
+
+    #[must_use]
+    pub fn is_empty(self) -> bool {
+        self.width <= 0.0 || self.height <= 0.0
+    }
+
+    #[must_use]
+    pub fn contains(self, x: f64, y: f64) -> bool {
+        x >= self.x && x <= self.right() && y >= self.y && y <= self.bottom()
+    }
+
+    pub fn translate(&mut self, dx: f64, dy: f64) {
+        self.x += dx;
+        self.y += dy;
+    }
+
+    pub fn validate(self) -> ChartResult<()> {
+        if !self.x.is_finite() || !self.y.is_finite() {
+            return Err(ChartError::InvalidData(
+                "clip rect coordinates must be finite".to_owned(),
+            ));
+        }
+        if !self.width.is_finite()
+            || !self.height.is_finite()
+            || self.width < 0.0
+            || self.height < 0.0
+        {
+            return Err(ChartError::InvalidData(
+                "clip rect size must be finite and >= 0".to_owned(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+fn validate_clip_rect(clip_rect: Option<ClipRect>) -> ChartResult<()> {
+    clip_rect.map_or(Ok(()), ClipRect::validate)
+}
+
+/// Axis along which a `LinearGradient` interpolates inside a primitive's bounds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum GradientDirection {
+    /// `start_color` at the top edge, `end_color` at the bottom edge.
+    #[default]
+    Vertical,
+    /// `start_color` at the left edge, `end_color` at the right edge.
+    Horizontal,
+}
+
+/// Two-stop linear gradient fill resolved against the filled primitive bounds.
+///
+/// Stops are relative to the primitive, not to the viewport, so the same
+/// gradient renders identically wherever the primitive is placed.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct LinearGradient {
+    pub start_color: Color,
+    pub end_color: Color,
+    pub direction: GradientDirection,
+}
+
+impl LinearGradient {
+    #[must_use]
+    pub const fn vertical(top_color: Color, bottom_color: Color) -> Self {
+        Self {
+            start_color: top_color,
+            end_color: bottom_color,
+            direction: GradientDirection::Vertical,
+        }
+    }
+
+    #[must_use]
+    pub const fn horizontal(left_color: Color, right_color: Color) -> Self {
+        Self {
+            start_color: left_color,
+            end_color: right_color,
+            direction: GradientDirection::Horizontal,
+        }
+    }
+
+    /// Returns the interpolated color at normalized offset `t` (clamped to `0..=1`).
+    #[must_use]
+    pub fn color_at(self, t: f64) -> Color {
+        let t = if t.is_finite() {
+            t.clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        let lerp = |start: f64, end: f64| start + (end - start) * t;
+        Color::rgba(
+            lerp(self.start_color.red, self.end_color.red),
+            lerp(self.start_color.green, self.end_color.green),
+            lerp(self.start_color.blue, self.end_color.blue),
+            lerp(self.start_color.alpha, self.end_color.alpha),
+        )
+    }
+
+    pub fn validate(self) -> ChartResult<()> {
+        self.start_color.validate()?;
+        self.end_color.validate()
+    }
+}
+
 /// Stroke pattern for line primitives.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum LineStrokeStyle {
@@ -64,6 +203,7 @@ pub struct LinePrimitive {
     pub stroke_width: f64,
     pub color: Color,
     pub stroke_style: LineStrokeStyle,
+    pub clip_rect: Option<ClipRect>,
 }
 
 impl LinePrimitive {
@@ -77,6 +217,7 @@ impl LinePrimitive {
             stroke_width,
             color,
             stroke_style: LineStrokeStyle::Solid,
+            clip_rect: None,
         }
     }
 
@@ -86,6 +227,22 @@ impl LinePrimitive {
         self
     }
 
+    #[must_use]
+    pub fn with_clip_rect(mut self, clip_rect: ClipRect) -> Self {
+        self.clip_rect = Some(clip_rect);
+        self
+    }
+
+    pub fn translate(&mut self, dx: f64, dy: f64) {
+        self.x1 += dx;
+        self.y1 += dy;
+        self.x2 += dx;
+        self.y2 += dy;
+        if let Some(clip_rect) = &mut self.clip_rect {
+            clip_rect.translate(dx, dy);
+        }
+    }
+
     pub fn validate(self) -> ChartResult<()> {
         if !self.x1.is_finite()
             || !self.y1.is_finite()
@@ -101,6 +258,7 @@ impl LinePrimitive {
                 "line stroke width must be finite and > 0".to_owned(),
             ));
         }
+        validate_clip_rect(self.clip_rect)?;
         self.color.validate()
     }
 }
@@ -116,6 +274,9 @@ pub struct RectPrimitive {
     pub border_width: f64,
     pub border_color: Color,
     pub corner_radius: f64,
+    /// Optional gradient that replaces `fill_color` when present.
+    pub fill_gradient: Option<LinearGradient>,
+    pub clip_rect: Option<ClipRect>,
 }
 
 impl RectPrimitive {
@@ -130,6 +291,8 @@ impl RectPrimitive {
             border_width: 0.0,
             border_color: Color::rgba(0.0, 0.0, 0.0, 0.0),
             corner_radius: 0.0,
+            fill_gradient: None,
+            clip_rect: None,
         }
     }
 
@@ -146,6 +309,26 @@ impl RectPrimitive {
         self
     }
 
+    #[must_use]
+    pub fn with_fill_gradient(mut self, fill_gradient: LinearGradient) -> Self {
+        self.fill_gradient = Some(fill_gradient);
+        self
+    }
+
+    #[must_use]
+    pub fn with_clip_rect(mut self, clip_rect: ClipRect) -> Self {
+        self.clip_rect = Some(clip_rect);
+        self
+    }
+
+    pub fn translate(&mut self, dx: f64, dy: f64) {
+        self.x += dx;
+        self.y += dy;
+        if let Some(clip_rect) = &mut self.clip_rect {
+            clip_rect.translate(dx, dy);
+        }
+    }
+
     pub fn validate(self) -> ChartResult<()> {
         if !self.x.is_finite() || !self.y.is_finite() {
             return Err(ChartError::InvalidData(
@@ -177,6 +360,10 @@ impl RectPrimitive {
             ));
         }
         self.fill_color.validate()?;
+        if let Some(fill_gradient) = self.fill_gradient {
+            fill_gradient.validate()?;
+        }
+        validate_clip_rect(self.clip_rect)?;
         if self.border_width > 0.0 {
             self.border_color.validate()?;
         }
@@ -184,29 +371,407 @@ impl RectPrimitive {
     }
 }
 
+/// Vertex of a `PathPrimitive` in pixel space.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PathPoint {
+    pub x: f64,
+    pub y: f64,
+}
+
+impl PathPoint {
+    #[must_use]
+    pub const fn new(x: f64, y: f64) -> Self {
+        Self { x, y }
+    }
+}
+
+impl From<AreaVertex> for PathPoint {
+    fn from(vertex: AreaVertex) -> Self {
+        Self::new(vertex.x, vertex.y)
+    }
+}
+
+impl From<BaselineVertex> for PathPoint {
+    fn from(vertex: BaselineVertex) -> Self {
+        Self::new(vertex.x, vertex.y)
+    }
+}
+
+fn path_points<V: Copy + Into<PathPoint>>(vertices: &[V]) -> Vec<PathPoint> {
+    vertices.iter().copied().map(Into::into).collect()
+}
+
+/// Fill rule used to decide the interior of closed self-intersecting paths.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum PathFillRule {
+    #[default]
+    NonZero,
+    EvenOdd,
+}
+
+/// Draw command for one polyline or polygon in pixel space.
+///
+/// A path is stroked when `stroke_width > 0` and filled when `fill_color` is
+/// set; fills require `closed = true`. `fill_gradient`, when present, replaces
+/// `fill_color` and is resolved against the path bounding box.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PathPrimitive {
+    pub points: Vec<PathPoint>,
+    pub closed: bool,
+    pub fill_color: Option<Color>,
+    pub fill_gradient: Option<LinearGradient>,
+    pub fill_rule: PathFillRule,
+    pub stroke_width: f64,
+    pub stroke_color: Color,
+    pub stroke_style: LineStrokeStyle,
+    pub clip_rect: Option<ClipRect>,
+}
+
+impl PathPrimitive {
+    /// Builds an open stroked polyline.
+    #[must_use]
+    pub fn polyline(points: Vec<PathPoint>, stroke_width: f64, stroke_color: Color) -> Self {
+        Self {
+            points,
+            closed: false,
+            fill_color: None,
+            fill_gradient: None,
+            fill_rule: PathFillRule::NonZero,
+            stroke_width,
+            stroke_color,
+            stroke_style: LineStrokeStyle::Solid,
+            clip_rect: None,
+        }
+    }
+
+    /// Builds the `[fill, line]` paths of projected area geometry, in paint
+    /// order.
+    #[must_use]
+    pub fn from_area_geometry(
+        geometry: &AreaGeometry,
+        line_width: f64,
+        line_color: Color,
+        fill_color: Color,
+    ) -> [Self; 2] {
+        [
+            Self::filled_polygon(path_points(&geometry.fill_polygon), fill_color),
+            Self::polyline(path_points(&geometry.line_points), line_width, line_color),
+        ]
+    }
+
+    /// Builds the `[above fill, below fill, line]` paths of projected
+    /// baseline geometry, in paint order.
+    #[must_use]
+    pub fn from_baseline_geometry(
+        geometry: &BaselineGeometry,
+        line_width: f64,
+        line_color: Color,
+        above_fill_color: Color,
+        below_fill_color: Color,
+    ) -> [Self; 3] {
+        [
+            Self::filled_polygon(path_points(&geometry.above_fill_polygon), above_fill_color),
+            Self::filled_polygon(path_points(&geometry.below_fill_polygon), below_fill_color),
+            Self::polyline(path_points(&geometry.line_points), line_width, line_color),
+        ]
+    }
+
+    /// Builds a closed filled polygon without stroke.
+    #[must_use]
+    pub fn filled_polygon(points: Vec<PathPoint>, fill_color: Color) -> Self {
+        Self {
+            points,
+            closed: true,
+            fill_color: Some(fill_color),
+            fill_gradient: None,
+            fill_rule: PathFillRule::NonZero,
+            stroke_width: 0.0,
+            stroke_color: Color::rgba(0.0, 0.0, 0.0, 0.0),
+            stroke_style: LineStrokeStyle::Solid,
+            clip_rect: None,
+        }
+    }
+
+    #[must_use]
+    pub fn with_fill_gradient(mut self, fill_gradient: LinearGradient) -> Self {
+        self.fill_gradient = Some(fill_gradient);
+        self
+    }
+
+    #[must_use]
+    pub fn with_fill_rule(mut self, fill_rule: PathFillRule) -> Self {
+        self.fill_rule = fill_rule;
+        self
+    }
+
+    #[must_use]
+    pub fn with_stroke(mut self, stroke_width: f64, stroke_color: Color) -> Self {
+        self.stroke_width = stroke_width;
+        self.stroke_color = stroke_color;
+        self
+    }
+
+    #[must_use]
+    pub fn with_stroke_style(mut self, stroke_style: LineStrokeStyle) -> Self {
+        self.stroke_style = stroke_style;
+        self
+    }
+
+    #[must_use]
+    pub fn with_clip_rect(mut self, clip_rect: ClipRect) -> Self {
+        self.clip_rect = Some(clip_rect);
+        self
+    }
+
+    pub fn translate(&mut self, dx: f64, dy: f64) {
+        for point in &mut self.points {
+            point.x += dx;
+            point.y += dy;
+        }
+        if let Some(clip_rect) = &mut self.clip_rect {
+            clip_rect.translate(dx, dy);
+        }
+    }
+
+    #[must_use]
+    pub fn is_filled(&self) -> bool {
+        self.fill_color.is_some()
+    }
+
+    #[must_use]
+    pub fn is_stroked(&self) -> bool {
+        self.stroke_width > 0.0
+    }
+
+    /// Returns the axis-aligned bounds as `(x, y, width, height)`.
+    #[must_use]
+    pub fn bounds(&self) -> Option<(f64, f64, f64, f64)> {
+        let first = self.points.first()?;
+        let (mut min_x, mut min_y, mut max_x, mut max_y) = (first.x, first.y, first.x, first.y);
+        for point in &self.points[1..] {
+            min_x = min_x.min(point.x);
+            min_y = min_y.min(point.y);
+            max_x = max_x.max(point.x);
+            max_y = max_y.max(point.y);
+        }
+        Some((min_x, min_y, max_x - min_x, max_y - min_y))
+    }
+
+    pub fn validate(&self) -> ChartResult<()> {
+        if self.points.len() < 2 {
+            return Err(ChartError::InvalidData(
+                "path must contain at least 2 points".to_owned(),
+            ));
+        }
+        if self
+            .points
+            .iter()
+            .any(|point| !point.x.is_finite() || !point.y.is_finite())
+        {
+            return Err(ChartError::InvalidData(
+                "path coordinates must be finite".to_owned(),
+            ));
+        }
+        if !self.stroke_width.is_finite() || self.stroke_width < 0.0 {
+            return Err(ChartError::InvalidData(
+                "path stroke width must be finite and >= 0".to_owned(),
+            ));
+        }
+        if !self.is_filled() && !self.is_stroked() {
+            return Err(ChartError::InvalidData(
+                "path must be filled, stroked, or both".to_owned(),
+            ));
+        }
+        if let Some(fill_color) = self.fill_color {
+            if !self.closed || self.points.len() < 3 {
+                return Err(ChartError::InvalidData(
+                    "filled path must be closed and contain at least 3 points".to_owned(),
+                ));
+            }
+            fill_color.validate()?;
+        }
+        if let Some(fill_gradient) = self.fill_gradient {
+            if !self.is_filled() {
+                return Err(ChartError::InvalidData(
+                    "path fill gradient requires a fill color fallback".to_owned(),
+                ));
+            }
+            fill_gradient.validate()?;
+        }
+        if self.is_stroked() {
+            self.stroke_color.validate()?;
+        }
+        validate_clip_rect(self.clip_rect)
+    }
+}
+
+/// Direction an `ArrowPrimitive` points in screen space.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ArrowDirection {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+/// Draw command for a filled triangular arrow head in pixel space.
+///
+/// The tip sits at `(tip_x, tip_y)` and the base is `length_px` behind it.
+/// Arrows lower to a closed filled `PathPrimitive` through `to_path`, so
+/// backends draw them with their path support.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ArrowPrimitive {
+    pub tip_x: f64,
+    pub tip_y: f64,
+    pub direction: ArrowDirection,
+    pub length_px: f64,
+    pub width_px: f64,
+    pub color: Color,
+    pub clip_rect: Option<ClipRect>,
+}
+
+impl ArrowPrimitive {
+    #[must_use]
+    pub fn new(
+        tip_x: f64,
+        tip_y: f64,
+        direction: ArrowDirection,
+        length_px: f64,
+        width_px: f64,
+        color: Color,
+    ) -> Self {
+        Self {
+            tip_x,
+            tip_y,
+            direction,
+            length_px,
+            width_px,
+            color,
+            clip_rect: None,
+        }
+    }
+
+    #[must_use]
+    pub fn with_clip_rect(mut self, clip_rect: ClipRect) -> Self {
+        self.clip_rect = Some(clip_rect);
+        self
+    }
+
+    pub fn translate(&mut self, dx: f64, dy: f64) {
+        self.tip_x += dx;
+        self.tip_y += dy;
+        if let Some(clip_rect) = &mut self.clip_rect {
+            clip_rect.translate(dx, dy);
+        }
+    }
+
+    /// Tip followed by the two base corners.
+    #[must_use]
+    pub fn points(&self) -> [PathPoint; 3] {
+        let (dx, dy) = match self.direction {
+            ArrowDirection::Up => (0.0, -1.0),
+            ArrowDirection::Down => (0.0, 1.0),
+            ArrowDirection::Left => (-1.0, 0.0),
+            ArrowDirection::Right => (1.0, 0.0),
+        };
+        let base_x = self.tip_x - dx * self.length_px;
+        let base_y = self.tip_y - dy * self.length_px;
+        let half_width = self.width_px * 0.5;
+        [
+            PathPoint::new(self.tip_x, self.tip_y),
+            PathPoint::new(base_x - dy * half_width, base_y + dx * half_width),
+            PathPoint::new(base_x + dy * half_width, base_y - dx * half_width),
+        ]
+    }
+
+    #[must_use]
+    pub fn to_path(&self) -> PathPrimitive {
+        let path = PathPrimitive::filled_polygon(self.points().to_vec(), self.color);
+        match self.clip_rect {
+            Some(clip_rect) => path.with_clip_rect(clip_rect),
+            None => path,
+        }
+    }
+
+    pub fn validate(&self) -> ChartResult<()> {
+        if !self.tip_x.is_finite() || !self.tip_y.is_finite() {
+            return Err(ChartError::InvalidData(
+                "arrow tip must be finite".to_owned(),
+            ));
+        }
+        if !self.length_px.is_finite()
+            || self.length_px <= 0.0
+            || !self.width_px.is_finite()
+            || self.width_px <= 0.0
+        {
+            return Err(ChartError::InvalidData(
+                "arrow length and width must be finite and > 0".to_owned(),
+            ));
+        }
+        self.color.validate()?;
+        validate_clip_rect(self.clip_rect)
+    }
+}
+
 /// Horizontal text alignment relative to `TextPrimitive::x`.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum TextHAlign {
     Left,
     Center,
     Right,
 }
 
+/// Vertical text alignment relative to `TextPrimitive::y`.
+///
+/// `Top` keeps the historical behavior where `y` is the top edge of the
+/// laid-out text block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum TextVAlign {
+    #[default]
+    Top,
+    Middle,
+    Bottom,
+}
+
+/// Font weight applied to a text primitive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum TextFontWeight {
+    Light,
+    #[default]
+    Normal,
+    Medium,
+    Bold,
+}
+
+/// Default font family used when a primitive does not request one.
+pub const DEFAULT_TEXT_FONT_FAMILY: &str = "Sans";
+
 /// Draw command for one label in pixel space.
+///
+/// Rotation is expressed in radians, clockwise in screen space, and pivots
+/// around the anchor point `(x, y)` after alignment is resolved.
 #[derive(Debug, Clone, PartialEq)]
 pub struct TextPrimitive {
-    pub text: String,
+    /// Shared so cached axis labels reach the frame without a copy.
+    pub text: Arc<str>,
     pub x: f64,
     pub y: f64,
     pub font_size_px: f64,
     pub color: Color,
     pub h_align: TextHAlign,
+    pub v_align: TextVAlign,
+    /// Borrowed for the default family so most labels allocate nothing.
+    pub font_family: Cow<'static, str>,
+    pub font_weight: TextFontWeight,
+    pub italic: bool,
+    pub rotation_radians: f64,
+    pub clip_rect: Option<ClipRect>,
 }
 
 impl TextPrimitive {
     #[must_use]
     pub fn new(
-        text: impl Into<String>,
+        text: impl Into<Arc<str>>,
         x: f64,
         y: f64,
         font_size_px: f64,
@@ -220,9 +785,64 @@ impl TextPrimitive {
             font_size_px,
             color,
             h_align,
+            v_align: TextVAlign::Top,
+            font_family: Cow::Borrowed(DEFAULT_TEXT_FONT_FAMILY),
+            font_weight: TextFontWeight::Normal,
+            italic: false,
+            rotation_radians: 0.0,
+            clip_rect: None,
+        }
+    }
+
+    #[must_use]
+    pub fn with_v_align(mut self, v_align: TextVAlign) -> Self {
+        self.v_align = v_align;
+        self
+    }
+
+    #[must_use]
+    pub fn with_font_family(mut self, font_family: impl Into<Cow<'static, str>>) -> Self {
+        self.font_family = font_family.into();
+        self
+    }
+
+    #[must_use]
+    pub fn with_font_weight(mut self, font_weight: TextFontWeight) -> Self {
+        self.font_weight = font_weight;
+        self
+    }
+
+    #[must_use]
+    pub fn with_italic(mut self, italic: bool) -> Self {
+        self.italic = italic;
+        self
+    }
+
+    #[must_use]
+    pub fn with_rotation(mut self, rotation_radians: f64) -> Self {
+        self.rotation_radians = rotation_radians;
+        self
+    }
+
+    #[must_use]
+    pub fn with_clip_rect(mut self, clip_rect: ClipRect) -> Self {
+        self.clip_rect = Some(clip_rect);
+        self
+    }
+
+    pub fn translate(&mut self, dx: f64, dy: f64) {
+        self.x += dx;
+        self.y += dy;
+        if let Some(clip_rect) = &mut self.clip_rect {
+            clip_rect.translate(dx, dy);
         }
     }
 
+    #[must_use]
+    pub fn is_rotated(&self) -> bool {
+        self.rotation_radians != 0.0
+    }
+
     pub fn validate(&self) -> ChartResult<()> {
         if self.text.is_empty() {
             return Err(ChartError::InvalidData(
@@ -239,6 +859,17 @@ impl TextPrimitive {
                 "font size must be finite and > 0".to_owned(),
             ));
         }
+        if self.font_family.trim().is_empty() {
+            return Err(ChartError::InvalidData(
+                "text font family must not be empty".to_owned(),
+            ));
+        }
+        if !self.rotation_radians.is_finite() {
+            return Err(ChartError::InvalidData(
+                "text rotation must be finite".to_owned(),
+            ));
+        }
+        validate_clip_rect(self.clip_rect)?;
         self.color.validate()
     }
 }
@@ -0,0 +1,51 @@
+/// Horizontal advance widths of one font face, for measuring text without a
+/// text shaper.
+///
+/// Widths are per codepoint with no kerning or ligatures, which matches
+/// Pango's output for the digits, separators and Latin letters used in axis
+/// labels to well under a pixel at typical label sizes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FontMetricsTable {
+    pub family: &'static str,
+    pub units_per_em: u16,
+    /// Advances of the printable ASCII range `' '..='~'`.
+    pub ascii_advances: [u16; 95],
+    /// Advance used for every codepoint outside the table.
+    pub fallback_advance: u16,
+}
+
+/// DejaVu Sans (Bitstream Vera license), the face Pango resolves the
+/// default `Sans` family to on most Linux installs.
+pub const DEJAVU_SANS_METRICS: FontMetricsTable = FontMetricsTable {
+    family: "DejaVu Sans",
+    units_per_em: 2048,
+    ascii_advances: [
+        651, 821, 942, 1716, 1303, 1946, 1597, 563, 799, 799, 1024, 1716, 651, 739, 651, 690, 1303,
+        1303, 1303, 1303, 1303, 1303, 1303, 1303, 1303, 1303, 690, 690, 1716, 1716, 1716, 1087,
+        2048, 1401, 1405, 1430, 1577, 1294, 1178, 1587, 1540, 604, 604, 1343, 1141, 1767, 1532,
+        1612, 1235, 1612, 1423, 1300, 1251, 1499, 1401, 2025, 1403, 1251, 1403, 799, 690, 799,
+        1716, 1024, 1024, 1255, 1300, 1126, 1300, 1260, 721, 1300, 1298, 569, 569, 1186, 569, 1995,
+        1298, 1253, 1300, 1300, 842, 1067, 803, 1298, 1212, 1675, 1212, 1212, 1075, 1303, 690,
+        1303, 1716,
+    ],
+    fallback_advance: 1229,
+};
+
+impl FontMetricsTable {
+    /// Advance of `ch` as a fraction of the font size.
+    #[must_use]
+    pub fn advance_em(&self, ch: char) -> f64 {
+        let advance = u32::from(ch)
+            .checked_sub(u32::from(' '))
+            .and_then(|index| self.ascii_advances.get(index as usize))
+            .copied()
+            .unwrap_or(self.fallback_advance);
+        f64::from(advance) / f64::from(self.units_per_em)
+    }
+
+    /// Width of `text` set at `font_size_px`.
+    #[must_use]
+    pub fn text_width_px(&self, text: &str, font_size_px: f64) -> f64 {
+        text.chars().map(|ch| self.advance_em(ch)).sum::<f64>() * font_size_px
+    }
+}
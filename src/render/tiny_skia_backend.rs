@@ -0,0 +1,465 @@
+use tiny_skia::{
+    FillRule, GradientStop, Mask, Paint, Path, PathBuilder, Pixmap, Point, Rect, SpreadMode,
+    Stroke, StrokeDash, Transform,
+};
+
+use crate::error::{ChartError, ChartResult};
+use crate::render::{
+    ClipRect, Color, GradientDirection, LinePrimitive, LineStrokeStyle, LinearGradient,
+    PathFillRule, PathPrimitive, RectPrimitive, RenderFrame, Renderer, TextFontWeight,
+};
+
+use super::tiny_skia_text::{TextFonts, draw_text};
+
+/// Cubic Bezier control-point ratio approximating a quarter circle.
+const QUARTER_ARC_KAPPA: f32 = 0.552_284_8;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TinySkiaRenderStats {
+    pub lines_drawn: usize,
+    pub rects_drawn: usize,
+    pub paths_drawn: usize,
+    pub texts_drawn: usize,
+    /// Text primitives dropped because no font was loaded or found.
+    pub texts_without_font: usize,
+}
+
+/// Pure-Rust software renderer backed by a `tiny_skia::Pixmap`.
+///
+/// The pixmap follows the frame viewport, so resizing the engine viewport is
+/// enough to resize the output. Text is rasterized with ab_glyph from the
+/// loaded font (`set_font_data`); without one, the first text primitive
+/// probes a few well-known system font paths. Text is skipped, and counted
+/// in `texts_without_font`, when no font is available, so hosts that cannot
+/// rely on system fonts (or want output that matches across machines)
+/// should load one; `has_font` reports whether text will be drawn.
+#[derive(Debug, Clone)]
+pub struct TinySkiaRenderer {
+    pixmap: Pixmap,
+    clear_color: Color,
+    fonts: TextFonts,
+    last_stats: TinySkiaRenderStats,
+}
+
+impl TinySkiaRenderer {
+    pub fn new(width: u32, height: u32) -> ChartResult<Self> {
+        Ok(Self {
+            pixmap: new_pixmap(width, height)?,
+            clear_color: Color::rgb(1.0, 1.0, 1.0),
+            fonts: TextFonts::default(),
+            last_stats: TinySkiaRenderStats::default(),
+        })
+    }
+
+    #[must_use]
+    pub fn backend_name(&self) -> &'static str {
+        "tiny-skia"
+    }
+
+    #[must_use]
+    pub fn pixmap(&self) -> &Pixmap {
+        &self.pixmap
+    }
+
+    #[must_use]
+    pub fn clear_color(&self) -> Color {
+        self.clear_color
+    }
+
+    pub fn set_clear_color(&mut self, color: Color) -> ChartResult<()> {
+        color.validate()?;
+        self.clear_color = color;
+        Ok(())
+    }
+
+    /// Uses the TrueType/OpenType font in `data` for text and disables the
+    /// system font lookup.
+    pub fn set_font_data(&mut self, data: Vec<u8>) -> ChartResult<()> {
+        self.fonts.set_regular(data)
+    }
+
+    /// Face for `TextFontWeight::Bold`; other weights use the regular face.
+    pub fn set_bold_font_data(&mut self, data: Vec<u8>) -> ChartResult<()> {
+        self.fonts.set_bold(data)
+    }
+
+    /// Whether a font is available for text, running the system font lookup
+    /// first when no font was loaded.
+    pub fn has_font(&mut self) -> bool {
+        self.fonts.resolve(TextFontWeight::Normal).is_some()
+    }
+
+    #[must_use]
+    pub fn last_stats(&self) -> TinySkiaRenderStats {
+        self.last_stats
+    }
+
+    /// Encodes the last frame as PNG.
+    pub fn encode_png(&self) -> ChartResult<Vec<u8>> {
+        self.pixmap
+            .encode_png()
+            .map_err(|err| ChartError::InvalidData(format!("failed to encode png: {err}")))
+    }
+
+    pub fn write_png(&self, path: impl AsRef<std::path::Path>) -> ChartResult<()> {
+        let path = path.as_ref();
+        std::fs::write(path, self.encode_png()?).map_err(|err| {
+            ChartError::InvalidData(format!("failed to write `{}`: {err}", path.display()))
+        })
+    }
+
+    /// Copies the last frame into `out` as `0x00RRGGBB` words (the layout
+    /// expected by softbuffer), composited over black.
+    pub fn write_xrgb8888(&self, out: &mut [u32]) -> ChartResult<()> {
+        let pixels = self.pixmap.pixels();
+        if out.len() != pixels.len() {
+            return Err(ChartError::InvalidData(format!(
+                "pixel buffer holds {} pixels, expected {}",
+                out.len(),
+                pixels.len()
+            )));
+        }
+        for (word, pixel) in out.iter_mut().zip(pixels) {
+            *word = (u32::from(pixel.red()) << 16)
+                | (u32::from(pixel.green()) << 8)
+                | u32::from(pixel.blue());
+        }
+        Ok(())
+    }
+}
+
+impl Renderer for TinySkiaRenderer {
+    fn render(&mut self, frame: &RenderFrame) -> ChartResult<()> {
+        frame.validate()?;
+        self.clear_color.validate()?;
+
+        let (width, height) = (frame.viewport.width, frame.viewport.height);
+        if self.pixmap.width() != width || self.pixmap.height() != height {
+            self.pixmap = new_pixmap(width, height)?;
+        }
+        self.pixmap.fill(to_skia_color(self.clear_color));
+
+        let mut clips = ClipMaskCache::default();
+        let mut stats = TinySkiaRenderStats::default();
+
+        for line in &frame.lines {
+            let Some(mask) = clips.resolve(line.clip_rect, width, height)? else {
+                continue;
+            };
+            draw_line(&mut self.pixmap, *line, mask);
+            stats.lines_drawn += 1;
+        }
+
+        for rect in &frame.rects {
+            let Some(mask) = clips.resolve(rect.clip_rect, width, height)? else {
+                continue;
+            };
+            draw_rect(&mut self.pixmap, *rect, mask);
+            stats.rects_drawn += 1;
+        }
+
+        for path in &frame.paths {
+            let Some(mask) = clips.resolve(path.clip_rect, width, height)? else {
+                continue;
+            };
+            draw_path(&mut self.pixmap, path, mask);
+            stats.paths_drawn += 1;
+        }
+
+        for text in &frame.texts {
+            let Some(mask) = clips.resolve(text.clip_rect, width, height)? else {
+                continue;
+            };
+            let Some(font) = self.fonts.resolve(text.font_weight) else {
+                stats.texts_without_font += 1;
+                continue;
+            };
+            if draw_text(&mut self.pixmap, text, font, mask) {
+                stats.texts_drawn += 1;
+            }
+        }
+
+        self.last_stats = stats;
+        Ok(())
+    }
+}
+
+/// Reuses the mask of the previous primitive: consecutive primitives mostly
+/// share the plot or axis-panel clip.
+#[derive(Default)]
+struct ClipMaskCache {
+    last: Option<(ClipRect, Mask)>,
+}
+
+impl ClipMaskCache {
+    /// `Ok(None)` means the primitive is clipped away entirely; `Some(None)`
+    /// means it is unclipped.
+    fn resolve(
+        &mut self,
+        clip_rect: Option<ClipRect>,
+        width: u32,
+        height: u32,
+    ) -> ChartResult<Option<Option<&Mask>>> {
+        let Some(clip_rect) = clip_rect else {
+            return Ok(Some(None));
+        };
+        if clip_rect.is_empty() {
+            return Ok(None);
+        }
+        let cached = matches!(&self.last, Some((last, _)) if *last == clip_rect);
+        if !cached {
+            let Some(bounds) =
+                to_skia_rect(clip_rect.x, clip_rect.y, clip_rect.width, clip_rect.height)
+            else {
+                return Ok(None);
+            };
+            let mut mask = Mask::new(width, height).ok_or_else(|| {
+                ChartError::RendererUnavailable("failed to allocate tiny-skia clip mask".to_owned())
+            })?;
+            mask.fill_path(
+                &PathBuilder::from_rect(bounds),
+                FillRule::Winding,
+                false,
+                Transform::identity(),
+            );
+            self.last = Some((clip_rect, mask));
+        }
+        Ok(Some(self.last.as_ref().map(|(_, mask)| mask)))
+    }
+}
+
+fn new_pixmap(width: u32, height: u32) -> ChartResult<Pixmap> {
+    if width == 0 || height == 0 {
+        return Err(ChartError::InvalidData(
+            "tiny-skia pixmap size must be > 0".to_owned(),
+        ));
+    }
+    Pixmap::new(width, height).ok_or_else(|| {
+        ChartError::RendererUnavailable(format!(
+            "failed to allocate {width}x{height} tiny-skia pixmap"
+        ))
+    })
+}
+
+fn to_skia_color(color: Color) -> tiny_skia::Color {
+    tiny_skia::Color::from_rgba(
+        color.red.clamp(0.0, 1.0) as f32,
+        color.green.clamp(0.0, 1.0) as f32,
+        color.blue.clamp(0.0, 1.0) as f32,
+        color.alpha.clamp(0.0, 1.0) as f32,
+    )
+    .unwrap_or(tiny_skia::Color::TRANSPARENT)
+}
+
+fn to_skia_rect(x: f64, y: f64, width: f64, height: f64) -> Option<Rect> {
+    Rect::from_xywh(x as f32, y as f32, width as f32, height as f32)
+}
+
+fn solid_paint(color: Color) -> Paint<'static> {
+    let mut paint = Paint::default();
+    paint.set_color(to_skia_color(color));
+    paint.anti_alias = true;
+    paint
+}
+
+/// Paint with a two-stop gradient spanning `bounds` (`x, y, width, height`).
+fn gradient_paint(gradient: LinearGradient, bounds: (f64, f64, f64, f64)) -> Paint<'static> {
+    let (x, y, width, height) = bounds;
+    let end = match gradient.direction {
+        GradientDirection::Vertical => Point::from_xy(x as f32, (y + height) as f32),
+        GradientDirection::Horizontal => Point::from_xy((x + width) as f32, y as f32),
+    };
+    let shader = tiny_skia::LinearGradient::new(
+        Point::from_xy(x as f32, y as f32),
+        end,
+        vec![
+            GradientStop::new(0.0, to_skia_color(gradient.start_color)),
+            GradientStop::new(1.0, to_skia_color(gradient.end_color)),
+        ],
+        SpreadMode::Pad,
+        Transform::identity(),
+    );
+    match shader {
+        Some(shader) => Paint {
+            shader,
+            anti_alias: true,
+            ..Paint::default()
+        },
+        // Degenerate bounds collapse to the start color, as a zero-length
+        // Cairo gradient does.
+        None => solid_paint(gradient.start_color),
+    }
+}
+
+fn stroke_for(width: f64, style: LineStrokeStyle) -> Stroke {
+    let width = width as f32;
+    let dash = match style {
+        LineStrokeStyle::Solid => None,
+        LineStrokeStyle::Dashed => StrokeDash::new(vec![width * 6.0, width * 4.0], 0.0),
+        LineStrokeStyle::LargeDashed => StrokeDash::new(vec![width * 8.0, width * 6.0], 0.0),
+        LineStrokeStyle::Dotted => StrokeDash::new(vec![width, width * 2.0], 0.0),
+    };
+    Stroke {
+        width,
+        dash,
+        ..Stroke::default()
+    }
+}
+
+fn draw_line(pixmap: &mut Pixmap, line: LinePrimitive, mask: Option<&Mask>) {
+    let mut builder = PathBuilder::new();
+    builder.move_to(line.x1 as f32, line.y1 as f32);
+    builder.line_to(line.x2 as f32, line.y2 as f32);
+    let Some(path) = builder.finish() else {
+        return;
+    };
+    pixmap.stroke_path(
+        &path,
+        &solid_paint(line.color),
+        &stroke_for(line.stroke_width, line.stroke_style),
+        Transform::identity(),
+        mask,
+    );
+}
+
+fn draw_rect(pixmap: &mut Pixmap, rect: RectPrimitive, mask: Option<&Mask>) {
+    let Some(path) = rect_path(rect) else {
+        return;
+    };
+    let fill = match rect.fill_gradient {
+        Some(gradient) => gradient_paint(gradient, (rect.x, rect.y, rect.width, rect.height)),
+        None => solid_paint(rect.fill_color),
+    };
+    pixmap.fill_path(&path, &fill, FillRule::Winding, Transform::identity(), mask);
+    if rect.border_width > 0.0 {
+        pixmap.stroke_path(
+            &path,
+            &solid_paint(rect.border_color),
+            &stroke_for(rect.border_width, LineStrokeStyle::Solid),
+            Transform::identity(),
+            mask,
+        );
+    }
+}
+
+fn rect_path(rect: RectPrimitive) -> Option<Path> {
+    let bounds = to_skia_rect(rect.x, rect.y, rect.width, rect.height)?;
+    if rect.corner_radius <= 0.0 {
+        return Some(PathBuilder::from_rect(bounds));
+    }
+
+    let radius = rect
+        .corner_radius
+        .min(rect.width * 0.5)
+        .min(rect.height * 0.5) as f32;
+    let handle = radius * (1.0 - QUARTER_ARC_KAPPA);
+    let (left, top, right, bottom) = (bounds.left(), bounds.top(), bounds.right(), bounds.bottom());
+
+    let mut builder = PathBuilder::new();
+    builder.move_to(left + radius, top);
+    builder.line_to(right - radius, top);
+    builder.cubic_to(
+        right - handle,
+        top,
+        right,
+        top + handle,
+        right,
+        top + radius,
+    );
+    builder.line_to(right, bottom - radius);
+    builder.cubic_to(
+        right,
+        bottom - handle,
+        right - handle,
+        bottom,
+        right - radius,
+        bottom,
+    );
+    builder.line_to(left + radius, bottom);
+    builder.cubic_to(
+        left + handle,
+        bottom,
+        left,
+        bottom - handle,
+        left,
+        bottom - radius,
+    );
+    builder.line_to(left, top + radius);
+    builder.cubic_to(left, top + handle, left + handle, top, left + radius, top);
+    builder.close();
+    builder.finish()
+}
+
+fn draw_path(pixmap: &mut Pixmap, path: &PathPrimitive, mask: Option<&Mask>) {
+    let Some((first, rest)) = path.points.split_first() else {
+        return;
+    };
+    let mut builder = PathBuilder::new();
+    builder.move_to(first.x as f32, first.y as f32);
+    for point in rest {
+        builder.line_to(point.x as f32, point.y as f32);
+    }
+    if path.closed {
+        builder.close();
+    }
+    let Some(skia_path) = builder.finish() else {
+        return;
+    };
+
+    if let Some(fill_color) = path.fill_color {
+        let fill_rule = match path.fill_rule {
+            PathFillRule::NonZero => FillRule::Winding,
+            PathFillRule::EvenOdd => FillRule::EvenOdd,
+        };
+        let paint = match (path.fill_gradient, path.bounds()) {
+            (Some(gradient), Some(bounds)) => gradient_paint(gradient, bounds),
+            _ => solid_paint(fill_color),
+        };
+        pixmap.fill_path(&skia_path, &paint, fill_rule, Transform::identity(), mask);
+    }
+
+    if path.is_stroked() {
+        pixmap.stroke_path(
+            &skia_path,
+            &solid_paint(path.stroke_color),
+            &stroke_for(path.stroke_width, path.stroke_style),
+            Transform::identity(),
+            mask,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TinySkiaRenderer;
+    use crate::core::Viewport;
+    use crate::render::{ClipRect, Color, RectPrimitive, RenderFrame, Renderer};
+
+    #[test]
+    fn pixmap_follows_frame_viewport() {
+        let mut renderer = TinySkiaRenderer::new(10, 10).expect("renderer");
+        renderer
+            .render(&RenderFrame::new(Viewport::new(32, 16)))
+            .expect("render");
+        assert_eq!(
+            (renderer.pixmap().width(), renderer.pixmap().height()),
+            (32, 16)
+        );
+    }
+
+    #[test]
+    fn clipped_rect_only_fills_inside_the_clip() {
+        let mut renderer = TinySkiaRenderer::new(20, 20).expect("renderer");
+        let rect = RectPrimitive::new(0.0, 0.0, 20.0, 20.0, Color::rgb(1.0, 0.0, 0.0))
+            .with_clip_rect(ClipRect::new(0.0, 0.0, 10.0, 20.0));
+        renderer
+            .render(&RenderFrame::new(Viewport::new(20, 20)).with_rect(rect))
+            .expect("render");
+
+        let mut pixels = vec![0; 400];
+        renderer.write_xrgb8888(&mut pixels).expect("copy");
+        assert_eq!(pixels[20 * 5 + 2], 0x00ff_0000);
+        assert_eq!(pixels[20 * 5 + 15], 0x00ff_ffff);
+        assert_eq!(renderer.last_stats().rects_drawn, 1);
+    }
+}
@@ -0,0 +1,240 @@
+use ab_glyph::{Font, FontArc, Glyph, PxScale, ScaleFont, point};
+use tiny_skia::{FilterQuality, Mask, Pixmap, PixmapPaint, PremultipliedColorU8, Transform};
+
+use crate::error::{ChartError, ChartResult};
+use crate::render::{Color, TextFontWeight, TextHAlign, TextPrimitive, TextVAlign};
+
+/// Regular/bold font files probed when no font was loaded explicitly.
+const SYSTEM_FONT_CANDIDATES: &[(&str, &str)] = &[
+    (
+        "/usr/share/fonts/truetype/dejavu/DejaVuSans.ttf",
+        "/usr/share/fonts/truetype/dejavu/DejaVuSans-Bold.ttf",
+    ),
+    (
+        "/usr/share/fonts/TTF/DejaVuSans.ttf",
+        "/usr/share/fonts/TTF/DejaVuSans-Bold.ttf",
+    ),
+    (
+        "/usr/share/fonts/dejavu/DejaVuSans.ttf",
+        "/usr/share/fonts/dejavu/DejaVuSans-Bold.ttf",
+    ),
+    (
+        "/usr/share/fonts/truetype/liberation/LiberationSans-Regular.ttf",
+        "/usr/share/fonts/truetype/liberation/LiberationSans-Bold.ttf",
+    ),
+    ("/Library/Fonts/Arial.ttf", "/Library/Fonts/Arial Bold.ttf"),
+    (
+        "/System/Library/Fonts/Supplemental/Arial.ttf",
+        "/System/Library/Fonts/Supplemental/Arial Bold.ttf",
+    ),
+    (
+        "C:\\Windows\\Fonts\\arial.ttf",
+        "C:\\Windows\\Fonts\\arialbd.ttf",
+    ),
+];
+
+/// Horizontal shear used for synthetic italics.
+const ITALIC_SKEW: f32 = -0.2;
+
+/// Faces used for text primitives. Bold text falls back to the regular face
+/// when no bold face is loaded.
+#[derive(Debug, Clone, Default)]
+pub(super) struct TextFonts {
+    regular: Option<FontArc>,
+    bold: Option<FontArc>,
+    system_lookup_done: bool,
+}
+
+impl TextFonts {
+    pub(super) fn set_regular(&mut self, data: Vec<u8>) -> ChartResult<()> {
+        self.regular = Some(parse_font(data)?);
+        self.system_lookup_done = true;
+        Ok(())
+    }
+
+    pub(super) fn set_bold(&mut self, data: Vec<u8>) -> ChartResult<()> {
+        self.bold = Some(parse_font(data)?);
+        Ok(())
+    }
+
+    /// Face for `weight`, probing `SYSTEM_FONT_CANDIDATES` once when no font
+    /// was loaded.
+    pub(super) fn resolve(&mut self, weight: TextFontWeight) -> Option<&FontArc> {
+        if !self.system_lookup_done {
+            self.system_lookup_done = true;
+            self.load_system_fonts();
+        }
+        match (weight, &self.bold) {
+            (TextFontWeight::Bold, Some(bold)) => Some(bold),
+            _ => self.regular.as_ref(),
+        }
+    }
+
+    fn load_system_fonts(&mut self) {
+        for (regular, bold) in SYSTEM_FONT_CANDIDATES {
+            let Some(font) = read_font_file(regular) else {
+                continue;
+            };
+            self.regular = Some(font);
+            if self.bold.is_none() {
+                self.bold = read_font_file(bold);
+            }
+            return;
+        }
+    }
+}
+
+fn read_font_file(path: &str) -> Option<FontArc> {
+    parse_font(std::fs::read(path).ok()?).ok()
+}
+
+fn parse_font(data: Vec<u8>) -> ChartResult<FontArc> {
+    FontArc::try_from_vec(data)
+        .map_err(|err| ChartError::InvalidData(format!("invalid font data: {err}")))
+}
+
+/// Draws `text` onto `pixmap`. Returns `false` when nothing was drawn.
+///
+/// The string is laid out on one line and rasterized into a coverage pixmap
+/// in line-box space (top-left at the origin, baseline at the ascent), which
+/// is then composited with alignment, rotation, and synthetic italics
+/// applied as one transform. Alignment uses the line box like the Cairo
+/// backend's Pango layout extents.
+pub(super) fn draw_text(
+    pixmap: &mut Pixmap,
+    text: &TextPrimitive,
+    font: &FontArc,
+    mask: Option<&Mask>,
+) -> bool {
+    if text.text.is_empty() || text.font_size_px <= 0.0 {
+        return false;
+    }
+    let scale = PxScale::from(text.font_size_px as f32);
+    let scaled = font.as_scaled(scale);
+    let ascent = scaled.ascent();
+
+    let mut glyphs: Vec<Glyph> = Vec::with_capacity(text.text.len());
+    let mut caret = 0.0_f32;
+    let mut previous = None;
+    for ch in text.text.chars() {
+        let id = scaled.glyph_id(ch);
+        if let Some(previous) = previous {
+            caret += scaled.kern(previous, id);
+        }
+        glyphs.push(id.with_scale_and_position(scale, point(caret, ascent)));
+        caret += scaled.h_advance(id);
+        previous = Some(id);
+    }
+    let line_width = caret;
+    let line_height = ascent - scaled.descent();
+
+    let outlined: Vec<_> = glyphs
+        .into_iter()
+        .filter_map(|glyph| font.outline_glyph(glyph))
+        .collect();
+    let Some(bounds) = outlined
+        .iter()
+        .map(|glyph| glyph.px_bounds())
+        .reduce(|a, b| ab_glyph::Rect {
+            min: point(a.min.x.min(b.min.x), a.min.y.min(b.min.y)),
+            max: point(a.max.x.max(b.max.x), a.max.y.max(b.max.y)),
+        })
+    else {
+        // Whitespace only.
+        return false;
+    };
+
+    let (origin_x, origin_y) = (bounds.min.x.floor(), bounds.min.y.floor());
+    let width = (bounds.max.x.ceil() - origin_x).max(1.0) as u32;
+    let height = (bounds.max.y.ceil() - origin_y).max(1.0) as u32;
+    let mut coverage = vec![0.0_f32; (width * height) as usize];
+    for glyph in &outlined {
+        let glyph_bounds = glyph.px_bounds();
+        let left = (glyph_bounds.min.x - origin_x) as i64;
+        let top = (glyph_bounds.min.y - origin_y) as i64;
+        glyph.draw(|x, y, value| {
+            let (px, py) = (left + i64::from(x), top + i64::from(y));
+            if px < 0 || py < 0 || px >= i64::from(width) || py >= i64::from(height) {
+                return;
+            }
+            let cell = &mut coverage[(py as u32 * width + px as u32) as usize];
+            *cell = (*cell + value).min(1.0);
+        });
+    }
+    let Some(glyph_pixmap) = coverage_pixmap(&coverage, width, height, text.color) else {
+        return false;
+    };
+
+    let offset_x = match text.h_align {
+        TextHAlign::Left => 0.0,
+        TextHAlign::Center => -line_width / 2.0,
+        TextHAlign::Right => -line_width,
+    };
+    let offset_y = match text.v_align {
+        TextVAlign::Top => 0.0,
+        TextVAlign::Middle => -line_height / 2.0,
+        TextVAlign::Bottom => -line_height,
+    };
+
+    let (transform, quality) = if !text.is_rotated() && !text.italic {
+        // Snap to whole pixels so upright labels stay crisp.
+        let x = (text.x as f32 + offset_x + origin_x).round();
+        let y = (text.y as f32 + offset_y + origin_y).round();
+        (Transform::from_translate(x, y), FilterQuality::Nearest)
+    } else {
+        let mut transform = Transform::from_translate(text.x as f32, text.y as f32)
+            .pre_rotate(text.rotation_radians.to_degrees() as f32)
+            .pre_translate(offset_x, offset_y);
+        if text.italic {
+            // Shear around the baseline so glyphs lean without drifting.
+            transform = transform
+                .pre_translate(0.0, ascent)
+                .pre_concat(Transform::from_row(1.0, 0.0, ITALIC_SKEW, 1.0, 0.0, 0.0))
+                .pre_translate(0.0, -ascent);
+        }
+        (
+            transform.pre_translate(origin_x, origin_y),
+            FilterQuality::Bilinear,
+        )
+    };
+    pixmap.draw_pixmap(
+        0,
+        0,
+        glyph_pixmap.as_ref(),
+        &PixmapPaint {
+            quality,
+            ..PixmapPaint::default()
+        },
+        transform,
+        mask,
+    );
+    true
+}
+
+/// Premultiplied pixmap of `color` modulated by `coverage`.
+fn coverage_pixmap(coverage: &[f32], width: u32, height: u32, color: Color) -> Option<Pixmap> {
+    let mut pixmap = Pixmap::new(width, height)?;
+    let channel = |value: f64| value.clamp(0.0, 1.0) as f32;
+    let (red, green, blue, alpha) = (
+        channel(color.red),
+        channel(color.green),
+        channel(color.blue),
+        channel(color.alpha),
+    );
+    for (pixel, value) in pixmap.pixels_mut().iter_mut().zip(coverage) {
+        let a = value * alpha;
+        if a <= 0.0 {
+            continue;
+        }
+        let to_u8 = |channel: f32| (channel * 255.0).round() as u8;
+        if let Some(premultiplied) = PremultipliedColorU8::from_rgba(
+            to_u8(red * a),
+            to_u8(green * a),
+            to_u8(blue * a),
+            to_u8(a),
+        ) {
+            *pixel = premultiplied;
+        }
+    }
+    Some(pixmap)
+}
@@ -3,6 +3,36 @@
 //! This module keeps tracing setup explicit and opt-in.
 //! Consumers can either call `init_default_tracing` or wire their own
 //! `tracing` subscriber and filters.
+//!
+//! Frame builds are instrumented with the spans named below: one `DEBUG`
+//! frame span with `TRACE` phase spans nested inside it, so a
+//! `tracing-subscriber` filter or a `tracy` layer shows where frame time goes.
+
+/// One frame build, including frame-budget rebuilds. Records `lines`,
+/// `texts`, `rects` and `reduction_level` of the emitted frame.
+pub const FRAME_SPAN: &str = "chart_rs.frame";
+/// Axis layout, pane regions and background primitives.
+pub const FRAME_LAYOUT_SPAN: &str = "chart_rs.frame.layout";
+/// All series layers; parent of the windowing and projection spans.
+pub const FRAME_SERIES_SPAN: &str = "chart_rs.frame.series";
+/// Visible-window lookup and level-of-detail reduction of one series.
+/// Records `series` and the windowed `samples`.
+pub const FRAME_WINDOWING_SPAN: &str = "chart_rs.frame.windowing";
+/// Pixel projection of one series. Records `series` and projected `samples`.
+pub const FRAME_PROJECTION_SPAN: &str = "chart_rs.frame.projection";
+/// Time and price axes; parent of the ticks and labels spans.
+pub const FRAME_AXIS_SPAN: &str = "chart_rs.frame.axis";
+/// Tick generation of one axis. Records `axis` and the selected `ticks`.
+pub const FRAME_TICKS_SPAN: &str = "chart_rs.frame.ticks";
+/// Label formatting and placement of one axis. Records `axis` and `labels`.
+pub const FRAME_LABELS_SPAN: &str = "chart_rs.frame.labels";
+/// Crosshair lines and axis labels.
+pub const FRAME_CROSSHAIR_SPAN: &str = "chart_rs.frame.crosshair";
+/// Time-scale marks, bar countdown, data gaps, OHLC info line and debug
+/// overlay.
+pub const FRAME_OVERLAYS_SPAN: &str = "chart_rs.frame.overlays";
+/// `RenderFrame::validate` of the finished frame.
+pub const FRAME_VALIDATION_SPAN: &str = "chart_rs.frame.validation";
 
 /// Initializes a default `tracing` subscriber when the `telemetry` feature is enabled.
 ///
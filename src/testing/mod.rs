@@ -0,0 +1,11 @@
+//! Test support that downstream applications can use in their own suites.
+//!
+//! These helpers only go through public APIs (engine methods, core scales),
+//! so anything they verify holds for host applications as well.
+
+pub mod scale_invariants;
+#[cfg(feature = "testing-proptest")]
+pub mod scale_props;
+pub mod trace;
+#[cfg(feature = "visual-diff")]
+pub mod visual;
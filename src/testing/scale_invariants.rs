@@ -0,0 +1,193 @@
+//! Scale-math invariants usable against built-in or custom scales.
+//!
+//! Each check returns `ChartError::InvalidData` describing the first
+//! violation, so the checks can run inside proptest, a fuzzer, or a plain
+//! unit test. With the `testing-proptest` feature, `scale_props` drives them
+//! with generated inputs.
+
+use crate::core::{PriceScale, TimeScale, Viewport};
+use crate::error::{ChartError, ChartResult};
+
+/// A value <-> pixel projection whose math should be invertible.
+pub trait ProjectedScale {
+    fn value_to_pixel(&self, value: f64, viewport: Viewport) -> ChartResult<f64>;
+    fn pixel_to_value(&self, pixel: f64, viewport: Viewport) -> ChartResult<f64>;
+
+    /// Interval of values the projection is expected to handle exactly;
+    /// checks only sample inside it.
+    fn value_range(&self) -> (f64, f64);
+
+    /// `1.0` when pixels grow with the value, `-1.0` when they shrink.
+    fn pixel_direction(&self) -> f64;
+}
+
+/// A projected scale with a pannable/zoomable visible window.
+pub trait NavigableScale: ProjectedScale + Clone {
+    /// Shifts the visible window by `delta` value units.
+    fn pan_by(&mut self, delta: f64) -> ChartResult<()>;
+
+    /// Zooms around `anchor`; `factor > 1.0` zooms in.
+    fn zoom_by(&mut self, factor: f64, anchor: f64) -> ChartResult<()>;
+}
+
+impl ProjectedScale for TimeScale {
+    fn value_to_pixel(&self, value: f64, viewport: Viewport) -> ChartResult<f64> {
+        self.time_to_pixel(value, viewport)
+    }
+
+    fn pixel_to_value(&self, pixel: f64, viewport: Viewport) -> ChartResult<f64> {
+        self.pixel_to_time(pixel, viewport)
+    }
+
+    fn value_range(&self) -> (f64, f64) {
+        self.visible_range()
+    }
+
+    fn pixel_direction(&self) -> f64 {
+        1.0
+    }
+}
+
+impl NavigableScale for TimeScale {
+    fn pan_by(&mut self, delta: f64) -> ChartResult<()> {
+        self.pan_visible_by_delta(delta)
+    }
+
+    fn zoom_by(&mut self, factor: f64, anchor: f64) -> ChartResult<()> {
+        self.zoom_visible_by_factor(factor, anchor, f64::MIN_POSITIVE)
+    }
+}
+
+impl ProjectedScale for PriceScale {
+    fn value_to_pixel(&self, value: f64, viewport: Viewport) -> ChartResult<f64> {
+        self.price_to_pixel(value, viewport)
+    }
+
+    fn pixel_to_value(&self, pixel: f64, viewport: Viewport) -> ChartResult<f64> {
+        self.pixel_to_price(pixel, viewport)
+    }
+
+    fn value_range(&self) -> (f64, f64) {
+        let (start, end) = self.domain();
+        (start.min(end), start.max(end))
+    }
+
+    fn pixel_direction(&self) -> f64 {
+        if self.is_inverted() { 1.0 } else { -1.0 }
+    }
+}
+
+/// Largest magnitude involved in `scale`'s value range, floored at `1.0`.
+///
+/// Multiply by a relative tolerance to get an absolute one that stays
+/// meaningful for both tiny and huge domains.
+#[must_use]
+pub fn value_magnitude<S: ProjectedScale + ?Sized>(scale: &S) -> f64 {
+    let (start, end) = scale.value_range();
+    start.abs().max(end.abs()).max(end - start).max(1.0)
+}
+
+/// `pixel_to_value(value_to_pixel(value)) == value` within `tolerance`.
+pub fn check_round_trip<S: ProjectedScale + ?Sized>(
+    scale: &S,
+    viewport: Viewport,
+    value: f64,
+    tolerance: f64,
+) -> ChartResult<()> {
+    let pixel = scale.value_to_pixel(value, viewport)?;
+    let recovered = scale.pixel_to_value(pixel, viewport)?;
+    if !pixel.is_finite() || (recovered - value).abs() > tolerance {
+        return Err(ChartError::InvalidData(format!(
+            "round trip drifted: value={value}, pixel={pixel}, recovered={recovered}, tolerance={tolerance}"
+        )));
+    }
+    Ok(())
+}
+
+/// Distinct values project to pixels ordered by `pixel_direction`.
+///
+/// Values closer than `tolerance` are skipped, since their pixels may
+/// legitimately coincide after rounding.
+pub fn check_monotonic<S: ProjectedScale + ?Sized>(
+    scale: &S,
+    viewport: Viewport,
+    a: f64,
+    b: f64,
+    tolerance: f64,
+) -> ChartResult<()> {
+    if (a - b).abs() <= tolerance {
+        return Ok(());
+    }
+    let (low, high) = if a < b { (a, b) } else { (b, a) };
+    let low_px = scale.value_to_pixel(low, viewport)?;
+    let high_px = scale.value_to_pixel(high, viewport)?;
+    if (high_px - low_px) * scale.pixel_direction() <= 0.0 {
+        return Err(ChartError::InvalidData(format!(
+            "projection not monotonic: {low}->{low_px}, {high}->{high_px}, direction={}",
+            scale.pixel_direction()
+        )));
+    }
+    Ok(())
+}
+
+/// Zooming by `factor` and then `1 / factor` around the same anchor restores
+/// the visible window within `tolerance`.
+pub fn check_zoom_inverse<S: NavigableScale>(
+    scale: &S,
+    factor: f64,
+    anchor: f64,
+    tolerance: f64,
+) -> ChartResult<()> {
+    let mut zoomed = scale.clone();
+    zoomed.zoom_by(factor, anchor)?;
+    zoomed.zoom_by(1.0 / factor, anchor)?;
+    check_same_range(scale.value_range(), zoomed.value_range(), tolerance, || {
+        format!("zoom {factor} then {} around {anchor}", 1.0 / factor)
+    })
+}
+
+/// Applying `deltas` one by one equals a single pan by their sum and leaves
+/// the span unchanged within `tolerance`.
+pub fn check_pan_accumulation<S: NavigableScale>(
+    scale: &S,
+    deltas: &[f64],
+    tolerance: f64,
+) -> ChartResult<()> {
+    let mut stepped = scale.clone();
+    for delta in deltas {
+        stepped.pan_by(*delta)?;
+    }
+    let mut direct = scale.clone();
+    direct.pan_by(deltas.iter().sum())?;
+    check_same_range(
+        direct.value_range(),
+        stepped.value_range(),
+        tolerance,
+        || format!("{} accumulated pans", deltas.len()),
+    )?;
+
+    let (start, end) = scale.value_range();
+    let (stepped_start, stepped_end) = stepped.value_range();
+    let span_drift = ((stepped_end - stepped_start) - (end - start)).abs();
+    if span_drift > tolerance {
+        return Err(ChartError::InvalidData(format!(
+            "pan changed the visible span by {span_drift} (tolerance={tolerance})"
+        )));
+    }
+    Ok(())
+}
+
+fn check_same_range(
+    expected: (f64, f64),
+    actual: (f64, f64),
+    tolerance: f64,
+    context: impl FnOnce() -> String,
+) -> ChartResult<()> {
+    if (expected.0 - actual.0).abs() > tolerance || (expected.1 - actual.1).abs() > tolerance {
+        return Err(ChartError::InvalidData(format!(
+            "{}: expected range {expected:?}, got {actual:?} (tolerance={tolerance})",
+            context()
+        )));
+    }
+    Ok(())
+}
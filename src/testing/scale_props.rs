@@ -0,0 +1,119 @@
+//! Proptest drivers for `scale_invariants` (feature `testing-proptest`).
+//!
+//! `check_projection_properties` and `check_navigation_properties` accept any
+//! strategy producing a scale, so downstream crates can point them at their
+//! own `ProjectedScale` / `NavigableScale` implementations.
+
+use std::fmt::Debug;
+
+use proptest::prelude::*;
+use proptest::test_runner::{TestCaseError, TestRunner};
+
+pub use proptest::test_runner::Config as ProptestConfig;
+
+use crate::core::{PriceScale, PriceScaleMode, TimeScale, Viewport};
+use crate::error::{ChartError, ChartResult};
+
+use super::scale_invariants::{
+    NavigableScale, ProjectedScale, check_monotonic, check_pan_accumulation, check_round_trip,
+    check_zoom_inverse, value_magnitude,
+};
+
+/// Relative tolerance (scaled by `value_magnitude`) used by the drivers.
+pub const DEFAULT_RELATIVE_TOLERANCE: f64 = 1e-9;
+
+pub fn viewport_strategy() -> impl Strategy<Value = Viewport> {
+    (64u32..=4096, 64u32..=2048).prop_map(|(width, height)| Viewport::new(width, height))
+}
+
+/// Time scales spanning a millisecond to a few years anywhere in +/- 1e9 s.
+pub fn time_scale_strategy() -> impl Strategy<Value = TimeScale> {
+    (-1.0e9f64..1.0e9, 1.0e-3f64..1.0e8).prop_map(|(start, span)| {
+        TimeScale::new(start, start + span).expect("generated time range is valid")
+    })
+}
+
+/// Price scales across every mode, optionally inverted and with margins.
+pub fn price_scale_strategy() -> impl Strategy<Value = PriceScale> {
+    let linear = (-1.0e6f64..1.0e6, 1.0e-3f64..1.0e6)
+        .prop_map(|(min, span)| (min, min + span, PriceScaleMode::Linear));
+    let positive = (
+        1.0e-3f64..1.0e5,
+        1.001f64..1.0e3,
+        prop_oneof![
+            Just(PriceScaleMode::Log),
+            Just(PriceScaleMode::Percentage),
+            Just(PriceScaleMode::IndexedTo100),
+        ],
+    )
+        .prop_map(|(min, ratio, mode)| (min, min * ratio, mode));
+    (
+        prop_oneof![linear, positive],
+        any::<bool>(),
+        0.0f64..0.2,
+        0.0f64..0.2,
+    )
+        .prop_map(|((min, max, mode), inverted, top, bottom)| {
+            PriceScale::new_with_mode(min, max, mode)
+                .and_then(|scale| scale.with_margins(top, bottom))
+                .expect("generated price range is valid")
+                .with_inverted(inverted)
+        })
+}
+
+/// Round-trip accuracy and monotonicity for scales from `strategy`.
+pub fn check_projection_properties<S, G>(strategy: G, config: ProptestConfig) -> ChartResult<()>
+where
+    S: ProjectedScale + Debug,
+    G: Strategy<Value = S>,
+{
+    let mut runner = TestRunner::new(config);
+    runner
+        .run(
+            &(strategy, viewport_strategy(), 0.0f64..=1.0, 0.0f64..=1.0),
+            |(scale, viewport, a, b)| {
+                let (start, end) = scale.value_range();
+                let tolerance = DEFAULT_RELATIVE_TOLERANCE * value_magnitude(&scale);
+                let first = start + a * (end - start);
+                let second = start + b * (end - start);
+                check_round_trip(&scale, viewport, first, tolerance).map_err(fail)?;
+                check_monotonic(&scale, viewport, first, second, tolerance).map_err(fail)
+            },
+        )
+        .map_err(|err| ChartError::InvalidData(format!("scale projection property failed: {err}")))
+}
+
+/// Zoom/inverse-zoom stability and pan accumulation for scales from
+/// `strategy`. Zoom factors stay within `[0.1, 10]` and pans within one
+/// visible span per step.
+pub fn check_navigation_properties<S, G>(strategy: G, config: ProptestConfig) -> ChartResult<()>
+where
+    S: NavigableScale + Debug,
+    G: Strategy<Value = S>,
+{
+    let mut runner = TestRunner::new(config);
+    runner
+        .run(
+            &(
+                strategy,
+                0.1f64..10.0,
+                0.0f64..=1.0,
+                prop::collection::vec(-1.0f64..1.0, 1..32),
+            ),
+            |(scale, factor, anchor_ratio, pan_ratios)| {
+                let (start, end) = scale.value_range();
+                let span = end - start;
+                let anchor = start + anchor_ratio * span;
+                let deltas: Vec<f64> = pan_ratios.iter().map(|ratio| ratio * span).collect();
+                let travelled: f64 = deltas.iter().map(|delta| delta.abs()).sum();
+                let tolerance = DEFAULT_RELATIVE_TOLERANCE * value_magnitude(&scale).max(travelled);
+                check_zoom_inverse(&scale, factor, anchor, tolerance).map_err(fail)?;
+                check_pan_accumulation(&scale, &deltas, tolerance).map_err(fail)
+            },
+        )
+        .map_err(|err| ChartError::InvalidData(format!("scale navigation property failed: {err}")))
+}
+
+fn fail(err: ChartError) -> TestCaseError {
+    TestCaseError::fail(err.to_string())
+}
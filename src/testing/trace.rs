@@ -0,0 +1,11 @@
+//! Interaction traces for test suites.
+//!
+//! The schema and executor live in `api` (interaction recording produces
+//! traces); this module re-exports them under their test-support names.
+
+pub use crate::api::{
+    InteractionAction, InteractionExpectation, InteractionScenario, InteractionStep,
+    InteractionTrace, ReplayReport, TraceCrosshairMode, TraceMismatch, TracePoint, TracePriceRange,
+    TraceTimeRange, TraceViewport, apply_interaction_action as apply_action,
+    observe_interaction_state as observe, replay_interaction_scenario as replay,
+};
@@ -0,0 +1,171 @@
+//! Golden-image comparison for PNG snapshots.
+//!
+//! `compare_png` decodes both images to 8-bit RGBA and measures per-channel
+//! differences the same way the Lightweight visual corpus does: the largest
+//! absolute channel difference and the mean over every channel byte. The
+//! report also carries a heatmap PNG for failure artifacts.
+
+use std::io::Cursor;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{ChartError, ChartResult};
+
+/// Smallest red intensity of a differing heatmap pixel, so one-step channel
+/// differences stay visible next to black matching pixels.
+const HEATMAP_MIN_INTENSITY: u8 = 64;
+
+/// Per-image limits; same shape as the visual corpus `tolerance` entries.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct VisualTolerance {
+    pub max_channel_abs_diff: u8,
+    pub mean_channel_abs_diff: f64,
+}
+
+impl VisualTolerance {
+    /// Tolerance that only accepts byte-identical pixels.
+    #[must_use]
+    pub const fn exact() -> Self {
+        Self {
+            max_channel_abs_diff: 0,
+            mean_channel_abs_diff: 0.0,
+        }
+    }
+}
+
+/// Outcome of one baseline/candidate comparison.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct VisualDiffReport {
+    pub width: u32,
+    pub height: u32,
+    pub max_channel_abs_diff: u8,
+    pub mean_channel_abs_diff: f64,
+    /// Pixels with at least one differing RGBA channel.
+    pub differing_pixels: usize,
+    pub tolerance: VisualTolerance,
+    pub passed: bool,
+    /// Opaque PNG of the image size: black where pixels match, red scaled
+    /// by the largest channel difference where they do not.
+    #[serde(skip)]
+    pub heatmap_png: Vec<u8>,
+}
+
+struct RgbaImage {
+    width: u32,
+    height: u32,
+    pixels: Vec<u8>,
+}
+
+/// Compares two encoded PNG images.
+///
+/// Any color type and bit depth is accepted; both images are normalized to
+/// 8-bit RGBA first. Fails with `ChartError::InvalidData` when either image
+/// cannot be decoded or the sizes differ.
+pub fn compare_png(
+    baseline: &[u8],
+    candidate: &[u8],
+    tolerance: VisualTolerance,
+) -> ChartResult<VisualDiffReport> {
+    let baseline = decode_rgba(baseline, "baseline")?;
+    let candidate = decode_rgba(candidate, "candidate")?;
+    if (baseline.width, baseline.height) != (candidate.width, candidate.height) {
+        return Err(ChartError::InvalidData(format!(
+            "visual diff size mismatch: baseline {}x{}, candidate {}x{}",
+            baseline.width, baseline.height, candidate.width, candidate.height
+        )));
+    }
+
+    let mut max_diff = 0u8;
+    let mut sum_diff = 0u64;
+    let mut differing_pixels = 0usize;
+    let mut heatmap = Vec::with_capacity(baseline.pixels.len());
+    for (expected, actual) in baseline
+        .pixels
+        .chunks_exact(4)
+        .zip(candidate.pixels.chunks_exact(4))
+    {
+        let mut pixel_diff = 0u8;
+        for (expected, actual) in expected.iter().zip(actual) {
+            let diff = expected.abs_diff(*actual);
+            pixel_diff = pixel_diff.max(diff);
+            sum_diff += u64::from(diff);
+        }
+        max_diff = max_diff.max(pixel_diff);
+        let intensity = if pixel_diff == 0 {
+            0
+        } else {
+            differing_pixels += 1;
+            pixel_diff.max(HEATMAP_MIN_INTENSITY)
+        };
+        heatmap.extend_from_slice(&[intensity, 0, 0, u8::MAX]);
+    }
+
+    let mean_diff = if baseline.pixels.is_empty() {
+        0.0
+    } else {
+        sum_diff as f64 / baseline.pixels.len() as f64
+    };
+    Ok(VisualDiffReport {
+        width: baseline.width,
+        height: baseline.height,
+        max_channel_abs_diff: max_diff,
+        mean_channel_abs_diff: mean_diff,
+        differing_pixels,
+        tolerance,
+        passed: max_diff <= tolerance.max_channel_abs_diff
+            && mean_diff <= tolerance.mean_channel_abs_diff,
+        heatmap_png: encode_rgba(baseline.width, baseline.height, &heatmap)?,
+    })
+}
+
+fn decode_rgba(bytes: &[u8], role: &str) -> ChartResult<RgbaImage> {
+    let map_err = |err: png::DecodingError| {
+        ChartError::InvalidData(format!("failed to decode {role} png: {err}"))
+    };
+    let mut decoder = png::Decoder::new(Cursor::new(bytes));
+    decoder.set_transformations(png::Transformations::normalize_to_color8());
+    let mut reader = decoder.read_info().map_err(map_err)?;
+    let mut buffer = vec![0; reader.output_buffer_size()];
+    let info = reader.next_frame(&mut buffer).map_err(map_err)?;
+    buffer.truncate(info.buffer_size());
+
+    let pixels = match info.color_type {
+        png::ColorType::Rgba => buffer,
+        png::ColorType::Rgb => buffer
+            .chunks_exact(3)
+            .flat_map(|rgb| [rgb[0], rgb[1], rgb[2], u8::MAX])
+            .collect(),
+        png::ColorType::GrayscaleAlpha => buffer
+            .chunks_exact(2)
+            .flat_map(|ga| [ga[0], ga[0], ga[0], ga[1]])
+            .collect(),
+        png::ColorType::Grayscale => buffer
+            .iter()
+            .flat_map(|&gray| [gray, gray, gray, u8::MAX])
+            .collect(),
+        png::ColorType::Indexed => {
+            return Err(ChartError::InvalidData(format!(
+                "failed to decode {role} png: palette was not expanded"
+            )));
+        }
+    };
+    Ok(RgbaImage {
+        width: info.width,
+        height: info.height,
+        pixels,
+    })
+}
+
+fn encode_rgba(width: u32, height: u32, pixels: &[u8]) -> ChartResult<Vec<u8>> {
+    let map_err = |err: png::EncodingError| {
+        ChartError::InvalidData(format!("failed to encode visual diff heatmap: {err}"))
+    };
+    let mut bytes = Vec::new();
+    let mut encoder = png::Encoder::new(&mut bytes, width, height);
+    encoder.set_color(png::ColorType::Rgba);
+    encoder.set_depth(png::BitDepth::Eight);
+    let mut writer = encoder.write_header().map_err(map_err)?;
+    writer.write_image_data(pixels).map_err(map_err)?;
+    writer.finish().map_err(map_err)?;
+    Ok(bytes)
+}
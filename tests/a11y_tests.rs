@@ -0,0 +1,101 @@
+use chart_rs::a11y::{HoveredSample, describe_view, summarize_view};
+use chart_rs::api::{ChartEngine, ChartEngineConfig};
+use chart_rs::core::{DataPoint, OhlcBar, Viewport};
+use chart_rs::interaction::CrosshairMode;
+use chart_rs::render::NullRenderer;
+use std::sync::Arc;
+
+fn engine() -> ChartEngine<NullRenderer> {
+    let config =
+        ChartEngineConfig::new(Viewport::new(800, 400), 0.0, 100.0).with_price_domain(0.0, 50.0);
+    ChartEngine::new(NullRenderer::default(), config).expect("engine")
+}
+
+fn candles() -> Vec<OhlcBar> {
+    (0..10)
+        .map(|i| {
+            let open = 20.0 + f64::from(i);
+            OhlcBar::new(
+                f64::from(i) * 10.0,
+                open,
+                open + 3.0,
+                open - 2.0,
+                open + 1.0,
+            )
+            .expect("bar")
+        })
+        .collect()
+}
+
+#[test]
+fn empty_chart_reports_range_and_no_data() {
+    let engine = engine();
+    let summary = summarize_view(&engine);
+    assert_eq!(summary.last_price, None);
+    assert_eq!(summary.hovered, None);
+    assert!(describe_view(&engine).ends_with("No data."));
+}
+
+#[test]
+fn candle_summary_covers_visible_window() {
+    let mut engine = engine();
+    engine.set_candles(candles());
+    engine.set_time_visible_range(20.0, 50.0).expect("range");
+
+    let summary = summarize_view(&engine);
+    assert_eq!(summary.last_price, Some(30.0));
+    // First visible open 22 -> last visible close 26.
+    let change = summary.change_percent.expect("change");
+    assert!((change - (4.0 / 22.0 * 100.0)).abs() <= 1e-9);
+    assert_eq!(summary.visible_low, Some(20.0));
+    assert_eq!(summary.visible_high, Some(28.0));
+}
+
+#[test]
+fn hovered_candle_is_described_with_ohlc() {
+    let mut engine = engine();
+    engine.set_candles(candles());
+    engine.set_crosshair_mode(CrosshairMode::Normal);
+    let x = engine.map_x_to_pixel(41.0).expect("pixel");
+    engine.pointer_move(x, 100.0);
+
+    let summary = summarize_view(&engine);
+    let Some(HoveredSample::Candle(bar)) = summary.hovered else {
+        panic!("expected hovered candle, got {:?}", summary.hovered);
+    };
+    assert_eq!(bar.time, 40.0);
+
+    engine.set_price_label_formatter(Arc::new(|price| format!("${price:.1}")));
+    let text = describe_view(&engine);
+    assert!(text.contains("Last price $30.0."), "{text}");
+    assert!(
+        text.contains("open $24.0, high $27.0, low $22.0, close $25.0."),
+        "{text}"
+    );
+    assert!(text.contains("over the visible range"), "{text}");
+
+    engine.pointer_leave();
+    assert_eq!(summarize_view(&engine).hovered, None);
+}
+
+#[test]
+fn point_series_reports_hovered_value() {
+    let mut engine = engine();
+    engine.set_data(vec![
+        DataPoint::new(0.0, 10.0),
+        DataPoint::new(50.0, 15.0),
+        DataPoint::new(100.0, 12.0),
+    ]);
+    engine.set_crosshair_mode(CrosshairMode::Normal);
+    let x = engine.map_x_to_pixel(55.0).expect("pixel");
+    engine.pointer_move(x, 100.0);
+
+    let summary = summarize_view(&engine);
+    assert_eq!(
+        summary.hovered,
+        Some(HoveredSample::Point(DataPoint::new(50.0, 15.0)))
+    );
+    assert_eq!(summary.visible_low, Some(10.0));
+    assert_eq!(summary.visible_high, Some(15.0));
+    assert!(describe_view(&engine).contains(": value "));
+}
@@ -0,0 +1,86 @@
+use chart_rs::api::{
+    ChartEngine, ChartEngineConfig, PriceAxisLabelConfig, TimeAxisLabelConfig, TimeAxisLabelPolicy,
+};
+use chart_rs::core::Viewport;
+use chart_rs::render::{CanvasLayerKind, NullRenderer};
+
+const START: f64 = 1_700_000_123.0;
+
+fn engine(anchor: bool, price_domain: (f64, f64)) -> ChartEngine<NullRenderer> {
+    let config = ChartEngineConfig::new(Viewport::new(900, 500), START, START + 86_400.0)
+        .with_price_domain(price_domain.0, price_domain.1);
+    let mut engine = ChartEngine::new(NullRenderer::default(), config).expect("engine");
+    engine
+        .set_time_axis_label_config(TimeAxisLabelConfig {
+            policy: TimeAxisLabelPolicy::UtcAdaptive,
+            anchor_ticks_to_nice_steps: anchor,
+            ..TimeAxisLabelConfig::default()
+        })
+        .expect("time config");
+    engine
+        .set_price_axis_label_config(PriceAxisLabelConfig {
+            anchor_ticks_to_nice_steps: anchor,
+            ..engine.price_axis_label_config()
+        })
+        .expect("price config");
+    engine
+}
+
+fn grid_values(engine: &ChartEngine<NullRenderer>, horizontal: bool) -> Vec<f64> {
+    let layered = engine.build_layered_render_frame().expect("layered");
+    layered.panes[0]
+        .layers
+        .iter()
+        .filter(|layer| layer.kind == CanvasLayerKind::Grid)
+        .flat_map(|layer| layer.lines.iter())
+        .filter(|line| (line.y1 == line.y2) == horizontal)
+        .map(|line| {
+            if horizontal {
+                engine.map_pixel_to_price(line.y1).expect("price")
+            } else {
+                engine.map_pixel_to_x(line.x1).expect("time")
+            }
+        })
+        .collect()
+}
+
+fn is_multiple(value: f64, step: f64) -> bool {
+    let ratio = value / step;
+    (ratio - ratio.round()).abs() <= 1e-6
+}
+
+#[test]
+fn anchored_time_gridlines_stay_on_the_same_times_across_pan() {
+    let mut engine = engine(true, (0.0, 50.0));
+    let before = grid_values(&engine, false);
+    engine.pan_time_visible_by(1_234.0).expect("pan");
+    let after = grid_values(&engine, false);
+
+    assert!(before.len() >= 3);
+    let step = before[1] - before[0];
+    assert!(step > 0.0 && is_multiple(step, 60.0), "{before:?}");
+    for time in before.iter().chain(&after) {
+        assert!(is_multiple(*time, step), "{time} not on {step}s grid");
+    }
+    assert!(before.iter().any(|time| after.contains(time)));
+}
+
+#[test]
+fn anchored_price_gridlines_land_on_nice_multiples_for_any_offset() {
+    for domain in [(0.0, 50.0), (3.3, 53.3), (17.9, 67.9)] {
+        let prices = grid_values(&engine(true, domain), true);
+        assert!(prices.len() >= 3);
+        for price in prices {
+            assert!(is_multiple(price, 5.0), "{price} in {domain:?}");
+        }
+    }
+}
+
+#[test]
+fn unanchored_ticks_keep_dividing_the_visible_range() {
+    let prices = grid_values(&engine(false, (3.3, 53.3)), true);
+    assert!(
+        prices.iter().any(|price| !is_multiple(*price, 5.0)),
+        "{prices:?}"
+    );
+}
@@ -0,0 +1,149 @@
+#![cfg(feature = "apng-export")]
+
+use chart_rs::api::{AnimatedPngOptions, AnimatedPngRecorder, ChartEngine, ChartEngineConfig};
+use chart_rs::core::{OhlcBar, Viewport};
+use chart_rs::render::NullRenderer;
+use chart_rs::testing::trace::InteractionTrace;
+
+fn replay_engine(viewport: Viewport) -> ChartEngine<NullRenderer> {
+    let config = ChartEngineConfig::new(viewport, 0.0, 100.0).with_price_domain(0.0, 50.0);
+    let mut engine = ChartEngine::new(NullRenderer::default(), config).expect("engine");
+    engine.set_candles(
+        (0..10)
+            .map(|i| {
+                let close = 20.0 + f64::from(i);
+                OhlcBar::new(f64::from(i) * 10.0, close, close + 1.0, close - 1.0, close)
+                    .expect("bar")
+            })
+            .collect(),
+    );
+    engine
+}
+
+/// `(width, height, num_frames, num_plays)` from the APNG header.
+fn apng_header(bytes: &[u8]) -> (u32, u32, u32, u32) {
+    let reader = png::Decoder::new(bytes).read_info().expect("decode");
+    let info = reader.info();
+    let control = info.animation_control.expect("animation control");
+    (
+        info.width,
+        info.height,
+        control.num_frames,
+        control.num_plays,
+    )
+}
+
+#[test]
+fn replay_session_is_captured_frame_by_frame() {
+    let mut engine = replay_engine(Viewport::new(160, 90));
+    engine.start_replay(50.0).expect("replay");
+    let mut recorder = AnimatedPngRecorder::new(AnimatedPngOptions {
+        frame_delay_ms: 40,
+        loop_count: 2,
+        ..AnimatedPngOptions::default()
+    })
+    .expect("recorder");
+
+    // The cursor frame, then 80 and the partial step to 90.
+    assert_eq!(
+        recorder
+            .capture_replay(&mut engine, 3, 10)
+            .expect("capture"),
+        3
+    );
+    assert_eq!(engine.replay_cursor(), Some(90.0));
+    assert_eq!(
+        apng_header(&recorder.encode().expect("encode")),
+        (160, 90, 3, 2)
+    );
+}
+
+#[test]
+fn trace_scenario_captures_seed_and_every_step() {
+    let trace = InteractionTrace::from_json_str(
+        r#"{
+            "trace_name": "pan",
+            "source": "test",
+            "source_notes": "",
+            "viewport": { "width": 120, "height": 80 },
+            "time_range": { "start": 0.0, "end": 100.0 },
+            "price_range": { "min": 0.0, "max": 10.0 },
+            "tolerance": 0.0,
+            "scenarios": [{
+                "id": "pan",
+                "points": [{ "time": 0.0, "value": 1.0 }, { "time": 100.0, "value": 9.0 }],
+                "steps": [
+                    { "action": { "type": "pan_by_pixels", "delta_px": 10.0 } },
+                    { "action": { "type": "pointer_move", "x": 40.0, "y": 30.0 } }
+                ]
+            }]
+        }"#,
+    )
+    .expect("trace");
+    let mut recorder = AnimatedPngRecorder::new(AnimatedPngOptions::default()).expect("recorder");
+    assert_eq!(
+        recorder.capture_trace_scenario(&trace, 0).expect("capture"),
+        3
+    );
+    assert!(recorder.capture_trace_scenario(&trace, 1).is_err());
+    assert_eq!(
+        apng_header(&recorder.encode().expect("encode")),
+        (120, 80, 3, 0)
+    );
+}
+
+#[test]
+fn empty_recordings_and_size_changes_are_rejected() {
+    let mut recorder = AnimatedPngRecorder::new(AnimatedPngOptions::default()).expect("recorder");
+    assert!(recorder.encode().is_err());
+
+    recorder
+        .capture(&replay_engine(Viewport::new(160, 90)))
+        .expect("capture");
+    assert!(
+        recorder
+            .capture(&replay_engine(Viewport::new(200, 90)))
+            .is_err()
+    );
+    assert_eq!(recorder.frame_count(), 1);
+
+    let mut engine = replay_engine(Viewport::new(160, 90));
+    assert!(recorder.capture_replay(&mut engine, 1, 5).is_err());
+    engine.start_replay(50.0).expect("replay");
+    assert!(recorder.capture_replay(&mut engine, 0, 5).is_err());
+}
+
+#[test]
+fn frame_buffer_is_capped_and_streamed_to_the_caller() {
+    let options = AnimatedPngOptions {
+        max_frames: 2,
+        ..AnimatedPngOptions::default()
+    };
+    let mut recorder = AnimatedPngRecorder::new(options).expect("recorder");
+    let mut engine = replay_engine(Viewport::new(160, 90));
+    engine.start_replay(20.0).expect("replay");
+
+    // Plenty of hidden bars, but only two frames fit.
+    assert_eq!(
+        recorder
+            .capture_replay(&mut engine, 1, 10)
+            .expect("capture"),
+        2
+    );
+    assert_eq!(recorder.remaining_capacity(), 0);
+    assert!(recorder.capture(&engine).is_err());
+    assert_eq!(recorder.frame_count(), 2);
+
+    let mut streamed = Vec::new();
+    recorder.write_to(&mut streamed).expect("write");
+    assert_eq!(streamed, recorder.encode().expect("encode"));
+    assert_eq!(apng_header(&streamed), (160, 90, 2, 0));
+
+    assert!(
+        AnimatedPngRecorder::new(AnimatedPngOptions {
+            max_frames: 0,
+            ..AnimatedPngOptions::default()
+        })
+        .is_err()
+    );
+}
@@ -0,0 +1,94 @@
+use chart_rs::api::{ChartEngine, ChartEngineConfig};
+use chart_rs::core::{DataPoint, Viewport};
+use chart_rs::render::NullRenderer;
+
+fn engine() -> ChartEngine<NullRenderer> {
+    let config = ChartEngineConfig::new(Viewport::new(800, 400), 0.0, 100.0);
+    let mut engine = ChartEngine::new(NullRenderer::default(), config).expect("engine");
+    engine.set_data(
+        (0..=1_000)
+            .map(|i| DataPoint::new(f64::from(i), f64::from(i % 13)))
+            .collect(),
+    );
+    engine.set_time_visible_range(0.0, 100.0).expect("range");
+    engine
+}
+
+#[test]
+fn range_animation_eases_to_target_and_goes_idle() {
+    let mut engine = engine();
+    assert!(!engine.has_active_animation());
+    engine
+        .animate_time_visible_range(200.0, 400.0, 0.5)
+        .expect("animate");
+    assert!(engine.has_active_animation());
+    assert_eq!(engine.time_visible_range(), (0.0, 100.0));
+
+    assert!(engine.step_animations(0.25).expect("step"));
+    let (start, end) = engine.time_visible_range();
+    // Ease-out: more than half of the way at the halfway time.
+    assert!(start > 100.0 && start < 200.0, "{start}");
+    assert!(end > 250.0 && end < 400.0, "{end}");
+
+    assert!(engine.step_animations(0.5).expect("step"));
+    assert_eq!(engine.time_visible_range(), (200.0, 400.0));
+    assert!(!engine.has_active_animation());
+    assert!(!engine.step_animations(0.016).expect("step"));
+}
+
+#[test]
+fn step_animations_drives_kinetic_pan_until_it_decays() {
+    let mut engine = engine();
+    engine.start_kinetic_pan(200.0).expect("kinetic");
+    assert!(engine.has_active_animation());
+
+    let mut steps = 0;
+    while engine.has_active_animation() {
+        assert!(engine.step_animations(1.0 / 60.0).expect("step"));
+        steps += 1;
+        assert!(steps < 10_000, "kinetic pan never settled");
+    }
+    assert!(engine.time_visible_range().0 > 0.0);
+}
+
+#[test]
+fn animations_replace_each_other_and_yield_to_drag_pan() {
+    let mut engine = engine();
+    engine.start_kinetic_pan(200.0).expect("kinetic");
+    engine
+        .animate_time_visible_range(200.0, 300.0, 1.0)
+        .expect("animate");
+    assert!(!engine.kinetic_pan_state().active);
+    assert!(engine.time_range_animation_active());
+
+    engine.start_kinetic_pan(-50.0).expect("kinetic");
+    assert!(!engine.time_range_animation_active());
+    engine.stop_kinetic_pan();
+
+    engine
+        .animate_time_visible_range(200.0, 300.0, 1.0)
+        .expect("animate");
+    engine.pan_start();
+    assert!(!engine.has_active_animation());
+
+    engine
+        .animate_time_visible_range(500.0, 600.0, 0.0)
+        .expect("animate");
+    assert_eq!(engine.time_visible_range(), (500.0, 600.0));
+    assert!(!engine.has_active_animation());
+}
+
+#[test]
+fn invalid_animation_input_is_rejected() {
+    let mut engine = engine();
+    assert!(engine.animate_time_visible_range(10.0, 5.0, 1.0).is_err());
+    assert!(engine.animate_time_visible_range(0.0, 5.0, -1.0).is_err());
+    assert!(
+        engine
+            .animate_time_visible_range(0.0, f64::NAN, 1.0)
+            .is_err()
+    );
+    assert!(!engine.has_active_animation());
+    assert!(engine.step_animations(0.0).is_err());
+    assert!(engine.step_animations(f64::INFINITY).is_err());
+}
@@ -11,7 +11,9 @@ fn engine_smoke_flow() {
     let mut engine = ChartEngine::new(renderer, config).expect("engine init");
 
     engine.set_data(vec![DataPoint::new(1.0, 10.0), DataPoint::new(2.0, 20.0)]);
-    engine.append_point(DataPoint::new(3.0, 30.0));
+    engine
+        .append_point(DataPoint::new(3.0, 30.0))
+        .expect("append");
     engine
         .autoscale_price_from_data()
         .expect("autoscale should succeed");
@@ -0,0 +1,126 @@
+use chart_rs::api::{AxisBackgroundShadow, ChartEngine, ChartEngineConfig, RenderStyle};
+use chart_rs::core::{DataPoint, Viewport};
+use chart_rs::render::{CanvasLayerKind, Color, NullRenderer};
+
+const PRICE_STRIP: Color = Color::rgb(0.1, 0.12, 0.14);
+const TIME_STRIP: Color = Color::rgb(0.14, 0.12, 0.1);
+const SHADOW: Color = Color::rgba(0.0, 0.0, 0.0, 0.5);
+
+fn engine(style: impl FnOnce(RenderStyle) -> RenderStyle) -> ChartEngine<NullRenderer> {
+    let config =
+        ChartEngineConfig::new(Viewport::new(800, 400), 0.0, 100.0).with_price_domain(0.0, 50.0);
+    let mut engine = ChartEngine::new(NullRenderer::default(), config).expect("engine");
+    engine.set_data(vec![DataPoint::new(10.0, 20.0), DataPoint::new(90.0, 30.0)]);
+    engine
+        .set_render_style(style(engine.render_style()))
+        .expect("style");
+    engine
+}
+
+fn filled(style: RenderStyle) -> RenderStyle {
+    RenderStyle {
+        price_axis_background_color: Some(PRICE_STRIP),
+        time_axis_background_color: Some(TIME_STRIP),
+        axis_background_shadow: Some(AxisBackgroundShadow {
+            color: SHADOW,
+            width_px: 2.0,
+        }),
+        ..style
+    }
+}
+
+#[test]
+fn strips_fill_the_axis_panels_beneath_axis_content() {
+    let engine = engine(filled);
+    let plot_right = 800.0 - engine.resolved_price_axis_width_px();
+    let layered = engine.build_layered_render_frame().expect("layered");
+    let axis = layered.panes[0]
+        .layers
+        .iter()
+        .find(|layer| layer.kind == CanvasLayerKind::Axis)
+        .expect("axis layer");
+
+    let price = axis.rects[0];
+    assert_eq!(price.fill_color, PRICE_STRIP);
+    assert_eq!((price.x, price.y, price.height), (plot_right, 0.0, 400.0));
+    assert!((price.x + price.width - 800.0).abs() < 1e-9);
+    let time = axis.rects[1];
+    assert_eq!(time.fill_color, TIME_STRIP);
+    assert_eq!((time.x, time.width), (0.0, plot_right));
+    assert!((time.y + time.height - 400.0).abs() < 1e-9);
+
+    let shadows: Vec<_> = axis
+        .lines
+        .iter()
+        .filter(|line| line.color == SHADOW)
+        .collect();
+    assert_eq!(shadows.len(), 2);
+    assert_eq!(shadows[0].x1, plot_right + 1.0);
+    assert_eq!(shadows[1].y1, time.y + 1.0);
+    // Shadows come before the axis borders and tick marks.
+    assert!(axis.lines[..2].iter().all(|line| line.color == SHADOW));
+    assert!(!axis.texts.is_empty());
+}
+
+#[test]
+fn shadows_need_a_filled_strip() {
+    let engine = engine(|style| RenderStyle {
+        price_axis_background_color: Some(PRICE_STRIP),
+        axis_background_shadow: Some(AxisBackgroundShadow {
+            color: SHADOW,
+            width_px: 1.0,
+        }),
+        ..style
+    });
+    let frame = engine.build_render_frame().expect("frame");
+    assert_eq!(
+        frame
+            .rects
+            .iter()
+            .filter(|rect| rect.fill_color == TIME_STRIP)
+            .count(),
+        0
+    );
+    assert_eq!(
+        frame
+            .lines
+            .iter()
+            .filter(|line| line.color == SHADOW)
+            .count(),
+        1
+    );
+
+    let plain = self::engine(|style| style);
+    let frame = plain.build_render_frame().expect("frame");
+    assert!(
+        frame
+            .rects
+            .iter()
+            .all(|rect| rect.fill_color != PRICE_STRIP)
+    );
+}
+
+#[test]
+fn invalid_shadow_width_is_rejected_and_old_styles_deserialize() {
+    let mut engine = engine(|style| style);
+    let style = RenderStyle {
+        axis_background_shadow: Some(AxisBackgroundShadow {
+            color: SHADOW,
+            width_px: 0.0,
+        }),
+        ..engine.render_style()
+    };
+    assert!(engine.set_render_style(style).is_err());
+
+    let mut json = serde_json::to_value(filled(RenderStyle::default())).expect("json");
+    let object = json.as_object_mut().expect("object");
+    for key in [
+        "price_axis_background_color",
+        "time_axis_background_color",
+        "axis_background_shadow",
+    ] {
+        object.remove(key);
+    }
+    let style: RenderStyle = serde_json::from_value(json).expect("style");
+    assert_eq!(style, RenderStyle::default());
+}
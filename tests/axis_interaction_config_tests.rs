@@ -0,0 +1,185 @@
+use chart_rs::api::{
+    AxisInteractionConfig, AxisInteractionOptions, ChartAxis, ChartEngine, ChartEngineConfig,
+    PointerInput, PointerInputBridge,
+};
+use chart_rs::core::{DataPoint, Viewport};
+use chart_rs::render::NullRenderer;
+
+fn engine_with(config: AxisInteractionConfig) -> ChartEngine<NullRenderer> {
+    let config = ChartEngineConfig::new(Viewport::new(800, 400), 0.0, 100.0)
+        .with_price_domain(0.0, 50.0)
+        .with_axis_interaction_config(config);
+    let mut engine = ChartEngine::new(NullRenderer::default(), config).expect("engine");
+    engine.set_data(
+        (0..=100)
+            .map(|i| DataPoint::new(f64::from(i), 20.0 + f64::from(i % 5)))
+            .collect(),
+    );
+    engine
+}
+
+fn engine() -> ChartEngine<NullRenderer> {
+    engine_with(AxisInteractionConfig::default())
+}
+
+fn assert_domain_eq(left: (f64, f64), right: (f64, f64)) {
+    assert!((left.0 - right.0).abs() <= 1e-9, "{left:?} != {right:?}");
+    assert!((left.1 - right.1).abs() <= 1e-9, "{left:?} != {right:?}");
+}
+
+#[test]
+fn axis_drag_uses_configured_sensitivity_and_min_span() {
+    let config = AxisInteractionConfig {
+        price: AxisInteractionOptions {
+            drag_sensitivity: 0.4,
+            ..AxisInteractionConfig::default().price
+        },
+        time: AxisInteractionOptions {
+            drag_sensitivity: 0.3,
+            min_span: 5.0,
+            ..AxisInteractionConfig::default().time
+        },
+    };
+    let mut configured = engine_with(config);
+    let mut explicit = engine();
+
+    let factor = configured
+        .handle_axis_drag(ChartAxis::Price, 60.0, 100.0)
+        .expect("drag");
+    let expected = explicit
+        .axis_drag_scale_price(60.0, 100.0, 0.4, 1e-7)
+        .expect("drag");
+    assert_eq!(factor, expected);
+    assert_domain_eq(configured.price_domain(), explicit.price_domain());
+
+    configured
+        .handle_axis_drag(ChartAxis::Time, 2400.0, 300.0)
+        .expect("drag");
+    let (start, end) = configured.time_visible_range();
+    assert!((end - start - 5.0).abs() <= 1e-9);
+}
+
+#[test]
+fn inverted_and_disabled_axes() {
+    let defaults = AxisInteractionConfig::default();
+    let mut inverted = engine_with(AxisInteractionConfig {
+        price: AxisInteractionOptions {
+            invert_direction: true,
+            ..defaults.price
+        },
+        ..defaults
+    });
+    let mut plain = engine();
+    inverted
+        .handle_axis_drag(ChartAxis::Price, 60.0, 100.0)
+        .expect("drag");
+    plain
+        .handle_axis_drag(ChartAxis::Price, -60.0, 100.0)
+        .expect("drag");
+    assert_domain_eq(inverted.price_domain(), plain.price_domain());
+
+    let mut disabled = engine_with(AxisInteractionConfig {
+        time: AxisInteractionOptions {
+            drag_enabled: false,
+            wheel_enabled: false,
+            ..defaults.time
+        },
+        ..defaults
+    });
+    let before = disabled.time_visible_range();
+    assert_eq!(
+        disabled
+            .handle_axis_drag(ChartAxis::Time, 120.0, 300.0)
+            .expect("drag"),
+        1.0
+    );
+    assert_eq!(
+        disabled
+            .handle_axis_wheel(ChartAxis::Time, 120.0, 300.0)
+            .expect("wheel"),
+        1.0
+    );
+    assert_eq!(disabled.time_visible_range(), before);
+
+    // The pointer bridge goes through the same config.
+    let mut bridge = PointerInputBridge::default();
+    for input in [
+        PointerInput::Pressed { x: 300.0, y: 390.0 },
+        PointerInput::Moved { x: 360.0, y: 390.0 },
+        PointerInput::Released,
+    ] {
+        bridge.handle(&mut disabled, input).expect("input");
+    }
+    assert_eq!(disabled.time_visible_range(), before);
+}
+
+#[test]
+fn axis_wheel_zooms_with_wheel_sensitivity() {
+    let mut configured = engine();
+    let mut explicit = engine();
+    configured
+        .handle_axis_wheel(ChartAxis::Time, -120.0, 200.0)
+        .expect("wheel");
+    explicit
+        .wheel_zoom_time_visible(-120.0, 200.0, 0.12, 1.0)
+        .expect("wheel");
+    assert_domain_eq(
+        configured.time_visible_range(),
+        explicit.time_visible_range(),
+    );
+
+    let (start, end) = configured.price_domain();
+    configured
+        .handle_axis_wheel(ChartAxis::Price, 120.0, 150.0)
+        .expect("wheel");
+    let (zoomed_start, zoomed_end) = configured.price_domain();
+    assert!(zoomed_end - zoomed_start > end - start);
+}
+
+#[test]
+fn config_is_validated_and_persisted_in_scene() {
+    let mut engine = engine();
+    let defaults = AxisInteractionConfig::default();
+    for options in [
+        AxisInteractionOptions {
+            drag_sensitivity: 0.0,
+            ..defaults.price
+        },
+        AxisInteractionOptions {
+            wheel_sensitivity: f64::INFINITY,
+            ..defaults.price
+        },
+        AxisInteractionOptions {
+            min_span: -1.0,
+            ..defaults.price
+        },
+    ] {
+        assert!(
+            engine
+                .set_axis_interaction_config(AxisInteractionConfig {
+                    price: options,
+                    ..defaults
+                })
+                .is_err()
+        );
+    }
+    assert_eq!(engine.axis_interaction_config(), defaults);
+
+    let config = AxisInteractionConfig {
+        time: AxisInteractionOptions {
+            invert_direction: true,
+            wheel_sensitivity: 0.2,
+            ..defaults.time
+        },
+        ..defaults
+    };
+    engine.set_axis_interaction_config(config).expect("config");
+    let json = engine.export_scene_json_pretty().expect("export");
+    let mut restored = ChartEngine::new(
+        NullRenderer::default(),
+        ChartEngineConfig::new(Viewport::new(800, 400), 0.0, 1.0),
+    )
+    .expect("engine");
+    restored.import_scene_json_str(&json).expect("import");
+    assert_eq!(restored.axis_interaction_config(), config);
+}
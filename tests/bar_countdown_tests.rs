@@ -0,0 +1,126 @@
+use chart_rs::api::{
+    BarCountdownBehavior, ChartEngine, ChartEngineConfig, InvalidationLevel, TimeAxisLabelConfig,
+    TimeAxisTimeUnit,
+};
+use chart_rs::core::{OhlcBar, Viewport};
+use chart_rs::render::{CanvasLayerKind, NullRenderer};
+
+const MINUTE: f64 = 60.0;
+/// Open time of the latest one-minute candle.
+const LATEST: f64 = 10.0 * MINUTE;
+
+fn engine() -> ChartEngine<NullRenderer> {
+    let config = ChartEngineConfig::new(Viewport::new(800, 400), 0.0, LATEST + MINUTE)
+        .with_price_domain(0.0, 50.0)
+        .with_bar_countdown_behavior(BarCountdownBehavior {
+            visible: true,
+            ..BarCountdownBehavior::default()
+        });
+    let mut engine = ChartEngine::new(NullRenderer::default(), config).expect("engine");
+    engine.set_candles(
+        (0..=10)
+            .map(|i| OhlcBar::new(f64::from(i) * MINUTE, 20.0, 21.0, 19.0, 20.5).expect("bar"))
+            .collect(),
+    );
+    engine
+}
+
+fn axis_texts(engine: &ChartEngine<NullRenderer>) -> Vec<String> {
+    let layered = engine.build_layered_render_frame().expect("layered");
+    layered.panes[0]
+        .layers
+        .iter()
+        .filter(|layer| layer.kind == CanvasLayerKind::Axis)
+        .flat_map(|layer| &layer.texts)
+        .map(|text| text.text.to_string())
+        .collect()
+}
+
+#[test]
+fn countdown_measures_time_left_in_the_latest_bar() {
+    let mut engine = engine();
+    assert_eq!(engine.bar_close_countdown(), None);
+    let before = axis_texts(&engine);
+
+    engine.bar_clock_tick(LATEST + 18.0).expect("tick");
+    assert_eq!(engine.bar_close_countdown(), Some(42.0));
+    assert_eq!(engine.bar_countdown_text().as_deref(), Some("00:42"));
+    let texts = axis_texts(&engine);
+    assert_eq!(texts.len(), before.len() + 1);
+    assert!(texts.contains(&"00:42".to_owned()));
+
+    // A clock past the close holds at zero until the next bar arrives.
+    engine.bar_clock_tick(LATEST + 75.0).expect("tick");
+    assert_eq!(engine.bar_countdown_text().as_deref(), Some("00:00"));
+}
+
+#[test]
+fn configured_interval_and_millisecond_time_units() {
+    let mut engine = engine();
+    engine
+        .set_bar_countdown_behavior(BarCountdownBehavior {
+            visible: true,
+            bar_interval: Some(60.0 * MINUTE),
+            ..BarCountdownBehavior::default()
+        })
+        .expect("behavior");
+    engine.bar_clock_tick(LATEST + 5.0).expect("tick");
+    assert_eq!(engine.bar_countdown_text().as_deref(), Some("59:55"));
+
+    engine
+        .set_time_axis_label_config(TimeAxisLabelConfig {
+            time_unit: TimeAxisTimeUnit::Milliseconds,
+            ..engine.time_axis_label_config()
+        })
+        .expect("config");
+    engine
+        .set_bar_countdown_behavior(BarCountdownBehavior {
+            visible: true,
+            bar_interval: Some(90_000.0),
+            ..BarCountdownBehavior::default()
+        })
+        .expect("behavior");
+    engine.bar_clock_tick(LATEST).expect("tick");
+    assert_eq!(engine.bar_countdown_text().as_deref(), Some("01:30"));
+}
+
+#[test]
+fn clock_ticks_only_invalidate_when_the_label_changes() {
+    let mut engine = engine();
+    engine.bar_clock_tick(LATEST + 18.0).expect("tick");
+    engine.clear_pending_invalidation();
+
+    engine.bar_clock_tick(LATEST + 18.4).expect("tick");
+    assert_eq!(engine.pending_invalidation_level(), InvalidationLevel::None);
+    engine.bar_clock_tick(LATEST + 19.0).expect("tick");
+    assert!(engine.has_pending_invalidation());
+
+    assert!(engine.bar_clock_tick(f64::NAN).is_err());
+    assert_eq!(engine.bar_clock_time(), Some(LATEST + 19.0));
+}
+
+#[test]
+fn invalid_behavior_is_rejected_and_scene_round_trips() {
+    let mut engine = engine();
+    assert!(
+        engine
+            .set_bar_countdown_behavior(BarCountdownBehavior {
+                bar_interval: Some(0.0),
+                ..BarCountdownBehavior::default()
+            })
+            .is_err()
+    );
+    let behavior = BarCountdownBehavior {
+        visible: true,
+        bar_interval: Some(MINUTE),
+        padding_px: 5.0,
+        ..BarCountdownBehavior::default()
+    };
+    engine
+        .set_bar_countdown_behavior(behavior)
+        .expect("behavior");
+    let json = engine.export_scene_json_pretty().expect("json");
+    let mut restored = self::engine();
+    restored.import_scene_json_str(&json).expect("import");
+    assert_eq!(restored.bar_countdown_behavior(), behavior);
+}
@@ -0,0 +1,89 @@
+use chart_rs::ChartError;
+use chart_rs::api::{CandlestickDetailTiers, ChartEngine, ChartEngineConfig, RenderStyle};
+use chart_rs::core::{OhlcBar, Viewport};
+use chart_rs::render::{CanvasLayerKind, NullRenderer};
+
+/// Engine with 400 one-unit candles, showing `visible_bars` of them.
+fn engine(tiers: Option<CandlestickDetailTiers>, visible_bars: f64) -> ChartEngine<NullRenderer> {
+    let config =
+        ChartEngineConfig::new(Viewport::new(800, 400), 0.0, 400.0).with_price_domain(0.0, 50.0);
+    let mut engine = ChartEngine::new(NullRenderer::default(), config).expect("engine");
+    let candles = (0..400)
+        .map(|index| {
+            let open = 20.0 + f64::from(index % 7);
+            OhlcBar::new(f64::from(index), open, open + 4.0, open - 4.0, open + 2.0).expect("bar")
+        })
+        .collect();
+    engine.set_candles(candles);
+    engine
+        .set_render_style(RenderStyle {
+            candlestick_detail_tiers: tiers,
+            ..engine.render_style()
+        })
+        .expect("style");
+    engine
+        .set_time_visible_range(400.0 - visible_bars, 400.0)
+        .expect("range");
+    engine
+}
+
+/// `(wick lines, bodies, bordered bodies)` of the series layer.
+fn series_counts(engine: &ChartEngine<NullRenderer>) -> (usize, usize, usize) {
+    let layered = engine.build_layered_render_frame().expect("layered");
+    let series = layered.panes[0]
+        .layers
+        .iter()
+        .find(|layer| layer.kind == CanvasLayerKind::Series)
+        .expect("series layer");
+    let bordered = series
+        .rects
+        .iter()
+        .filter(|rect| rect.border_width > 0.0)
+        .count();
+    (series.lines.len(), series.rects.len(), bordered)
+}
+
+#[test]
+fn dense_candles_draw_as_filled_high_low_rects() {
+    let tiered = engine(Some(CandlestickDetailTiers::default()), 380.0);
+    let (wicks, bodies, bordered) = series_counts(&tiered);
+    assert_eq!((wicks, bordered), (0, 0));
+    assert!(bodies > 300);
+
+    let untiered = engine(None, 380.0);
+    let (wicks, _, _) = series_counts(&untiered);
+    assert!(wicks > 300);
+}
+
+#[test]
+fn medium_spacing_keeps_wicks_but_drops_borders() {
+    let engine = engine(Some(CandlestickDetailTiers::default()), 160.0);
+    let (wicks, bodies, bordered) = series_counts(&engine);
+    assert!(wicks > 100);
+    assert_eq!(wicks, bodies);
+    assert_eq!(bordered, 0);
+}
+
+#[test]
+fn wide_spacing_keeps_full_detail() {
+    let engine = engine(Some(CandlestickDetailTiers::default()), 40.0);
+    let (wicks, bodies, bordered) = series_counts(&engine);
+    assert!(wicks > 30);
+    assert_eq!(bordered, bodies);
+}
+
+#[test]
+fn tier_thresholds_are_validated() {
+    let mut engine = engine(None, 40.0);
+    let inverted = RenderStyle {
+        candlestick_detail_tiers: Some(CandlestickDetailTiers {
+            filled_rect_below_px: 8.0,
+            no_border_below_px: 4.0,
+        }),
+        ..engine.render_style()
+    };
+    assert!(matches!(
+        engine.set_render_style(inverted),
+        Err(ChartError::InvalidData(_))
+    ));
+}
@@ -62,7 +62,7 @@ fn candlestick_render_uses_dedicated_candle_style_fields() {
         candlestick_border_width_px: 1.5,
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set style");
+    engine.set_render_style(style.clone()).expect("set style");
 
     let layered = engine
         .build_layered_render_frame()
@@ -134,7 +134,7 @@ fn candlestick_hollow_up_mode_makes_bull_body_transparent() {
         candlestick_border_down_color: Color::rgb(0.58, 0.12, 0.17),
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set style");
+    engine.set_render_style(style.clone()).expect("set style");
 
     let layered = engine
         .build_layered_render_frame()
@@ -335,7 +335,7 @@ fn candlestick_border_only_body_path_is_used_for_tiny_bar_width() {
         candlestick_border_down_color: Color::rgb(0.50, 0.13, 0.11),
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set style");
+    engine.set_render_style(style.clone()).expect("set style");
 
     let layered = engine
         .build_layered_render_frame()
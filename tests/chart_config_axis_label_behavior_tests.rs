@@ -1,7 +1,7 @@
 use chart_rs::api::{
     AxisLabelLocale, ChartEngine, ChartEngineConfig, PriceAxisDisplayMode, PriceAxisLabelConfig,
-    PriceAxisLabelPolicy, TimeAxisLabelConfig, TimeAxisLabelPolicy, TimeAxisSessionConfig,
-    TimeAxisTimeZone,
+    PriceAxisLabelPolicy, PriceAxisSecondaryLabel, TimeAxisLabelConfig, TimeAxisLabelPolicy,
+    TimeAxisSessionConfig, TimeAxisTimeUnit, TimeAxisTimeZone,
 };
 use chart_rs::core::Viewport;
 use chart_rs::render::NullRenderer;
@@ -37,6 +37,8 @@ fn chart_engine_config_applies_axis_label_configs_on_init() {
             end_hour: 16,
             end_minute: 0,
         }),
+        time_unit: TimeAxisTimeUnit::Seconds,
+        anchor_ticks_to_nice_steps: false,
     };
     let price_config = PriceAxisLabelConfig {
         locale: AxisLabelLocale::EsEs,
@@ -47,6 +49,9 @@ fn chart_engine_config_applies_axis_label_configs_on_init() {
         display_mode: PriceAxisDisplayMode::Percentage {
             base_price: Some(100.0),
         },
+        secondary_label: PriceAxisSecondaryLabel::Hidden,
+        align_ticks_to_min_move: false,
+        anchor_ticks_to_nice_steps: false,
     };
 
     let config = ChartEngineConfig::new(Viewport::new(1000, 500), 0.0, 100.0)
@@ -74,6 +79,8 @@ fn chart_engine_config_rejects_invalid_time_axis_label_config() {
             end_hour: 9,
             end_minute: 0,
         }),
+        time_unit: TimeAxisTimeUnit::Seconds,
+        anchor_ticks_to_nice_steps: false,
     };
 
     let config = ChartEngineConfig::new(Viewport::new(1000, 500), 0.0, 100.0)
@@ -96,6 +103,9 @@ fn chart_engine_config_rejects_invalid_price_axis_label_config() {
             trim_trailing_zeros: true,
         },
         display_mode: PriceAxisDisplayMode::Normal,
+        secondary_label: PriceAxisSecondaryLabel::Hidden,
+        align_ticks_to_min_move: false,
+        anchor_ticks_to_nice_steps: false,
     };
 
     let config = ChartEngineConfig::new(Viewport::new(1000, 500), 0.0, 100.0)
@@ -1,4 +1,7 @@
-use chart_rs::api::{ChartEngine, ChartEngineConfig, LastPriceBehavior, LastPriceSourceMode};
+use chart_rs::api::{
+    ChartEngine, ChartEngineConfig, LastPriceBehavior, LastPriceOffscreenPolicy,
+    LastPriceSourceMode,
+};
 use chart_rs::core::Viewport;
 use chart_rs::render::NullRenderer;
 
@@ -17,6 +20,7 @@ fn chart_engine_config_applies_last_price_behavior_on_init() {
         show_label: true,
         use_trend_color: true,
         source_mode: LastPriceSourceMode::LatestVisible,
+        offscreen_policy: LastPriceOffscreenPolicy::Draw,
     };
     let config = ChartEngineConfig::new(Viewport::new(1000, 500), 0.0, 100.0)
         .with_price_domain(0.0, 1.0)
@@ -34,6 +38,7 @@ fn last_price_behavior_takes_precedence_over_last_price_source_mode() {
         show_label: true,
         use_trend_color: false,
         source_mode: LastPriceSourceMode::LatestData,
+        offscreen_policy: LastPriceOffscreenPolicy::Draw,
     };
     let config = ChartEngineConfig::new(Viewport::new(1000, 500), 0.0, 100.0)
         .with_price_domain(0.0, 1.0)
@@ -85,6 +90,7 @@ fn chart_engine_config_json_parses_last_price_behavior() {
             show_label: false,
             use_trend_color: true,
             source_mode: LastPriceSourceMode::LatestVisible,
+            offscreen_policy: LastPriceOffscreenPolicy::Draw,
         })
     );
 }
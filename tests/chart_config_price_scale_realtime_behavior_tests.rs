@@ -28,7 +28,9 @@ fn chart_engine_config_applies_price_scale_realtime_behavior() {
     let after_set = engine.price_domain();
     assert!((after_set.0 - before.0).abs() > 1e-9 || (after_set.1 - before.1).abs() > 1e-9);
 
-    engine.append_point(DataPoint::new(2.0, 200.0));
+    engine
+        .append_point(DataPoint::new(2.0, 200.0))
+        .expect("append");
     let after_update = engine.price_domain();
     assert!(after_update.1 > after_set.1);
 }
@@ -57,6 +57,7 @@ fn chart_engine_config_applies_zoom_limit_policy() {
         .with_time_scale_zoom_limit_behavior(TimeScaleZoomLimitBehavior {
             min_bar_spacing_px: 20.0,
             max_bar_spacing_px: None,
+            max_visible_span_ratio: None,
         });
     let mut engine = build_engine(config);
     engine.set_data(seed_points());
@@ -0,0 +1,159 @@
+use chart_rs::api::{ChartEngine, ChartEngineConfig, CrosshairMode, StagedCandles};
+use chart_rs::core::{ChartTime, ChartTimeKind, DataPoint, OhlcBar, Viewport};
+use chart_rs::render::{NullRenderer, RenderFrame};
+
+const DAY: f64 = 86_400.0;
+const JAN_1_2024: f64 = 1_704_067_200.0;
+
+fn build_engine() -> ChartEngine<NullRenderer> {
+    let renderer = NullRenderer::default();
+    let config = ChartEngineConfig::new(Viewport::new(900, 400), JAN_1_2024, JAN_1_2024 + DAY)
+        .with_price_domain(0.0, 100.0);
+    ChartEngine::new(renderer, config).expect("engine init")
+}
+
+fn business_day_bars() -> Vec<(ChartTime, [f64; 4])> {
+    (1..=5)
+        .map(|day| {
+            (
+                ChartTime::business_day(2024, 1, day).expect("day"),
+                [10.0, 12.0, 9.0, 11.0],
+            )
+        })
+        .collect()
+}
+
+fn time_axis_texts(engine: &ChartEngine<NullRenderer>, frame: &RenderFrame) -> Vec<String> {
+    let plot_bottom =
+        f64::from(engine.viewport().height) - engine.render_style().time_axis_height_px;
+    frame
+        .texts
+        .iter()
+        .filter(|text| text.y >= plot_bottom)
+        .map(|text| text.text.to_string())
+        .collect()
+}
+
+fn is_date_only(text: &str) -> bool {
+    text.len() == 10 && text.as_bytes()[4] == b'-' && text.as_bytes()[7] == b'-'
+}
+
+#[test]
+fn business_days_normalize_to_utc_midnight() {
+    let mut engine = build_engine();
+    engine
+        .set_timed_candles(business_day_bars())
+        .expect("timed candles");
+
+    let times = engine
+        .candles()
+        .iter()
+        .map(|candle| candle.time)
+        .collect::<Vec<_>>();
+    let expected = (0..5)
+        .map(|day| JAN_1_2024 + f64::from(day) * DAY)
+        .collect::<Vec<_>>();
+    assert_eq!(times, expected);
+    assert_eq!(engine.chart_time_kind(), Some(ChartTimeKind::BusinessDay));
+}
+
+#[test]
+fn business_day_labels_never_show_clock_times() {
+    let mut engine = build_engine();
+    engine
+        .set_timed_candles(business_day_bars())
+        .expect("timed candles");
+    engine
+        .set_time_visible_range(JAN_1_2024, JAN_1_2024 + DAY)
+        .expect("range");
+    engine.set_crosshair_mode(CrosshairMode::Normal);
+    engine.pointer_move(300.0, 100.0);
+
+    let frame = engine.build_render_frame().expect("frame");
+    let texts = time_axis_texts(&engine, &frame);
+    assert!(!texts.is_empty());
+    assert!(texts.iter().all(|text| is_date_only(text)), "{texts:?}");
+}
+
+#[test]
+fn timestamp_data_keeps_intraday_labels() {
+    let mut engine = build_engine();
+    engine
+        .set_timed_data(
+            (0..24)
+                .map(|hour| {
+                    (
+                        ChartTime::UnixMillis((JAN_1_2024 + f64::from(hour) * 3_600.0) * 1_000.0),
+                        50.0,
+                    )
+                })
+                .collect(),
+        )
+        .expect("timed data");
+    assert_eq!(engine.chart_time_kind(), Some(ChartTimeKind::UnixMillis));
+    assert_eq!(engine.points()[1].x, JAN_1_2024 + 3_600.0);
+
+    let frame = engine.build_render_frame().expect("frame");
+    let texts = time_axis_texts(&engine, &frame);
+    assert!(texts.iter().any(|text| !is_date_only(text)), "{texts:?}");
+}
+
+#[test]
+fn invalid_or_mixed_times_are_rejected() {
+    let mut engine = build_engine();
+    assert!(
+        engine
+            .set_timed_data(vec![
+                (ChartTime::business_day(2024, 1, 2).expect("day"), 1.0),
+                (ChartTime::UnixSeconds(JAN_1_2024), 2.0),
+            ])
+            .is_err()
+    );
+    assert!(
+        engine
+            .set_timed_data(vec![(
+                ChartTime::BusinessDay {
+                    year: 2024,
+                    month: 13,
+                    day: 1
+                },
+                1.0
+            )])
+            .is_err()
+    );
+    assert!(engine.points().is_empty());
+    assert_eq!(engine.chart_time_kind(), None);
+}
+
+#[test]
+fn raw_data_replacement_resets_the_time_kind() {
+    let mut engine = build_engine();
+    engine
+        .set_timed_candles(business_day_bars())
+        .expect("timed candles");
+    engine.set_candles(vec![
+        OhlcBar::new(JAN_1_2024, 1.0, 2.0, 0.5, 1.5).expect("bar"),
+        OhlcBar::new(JAN_1_2024 + 3_600.0, 1.5, 2.5, 1.0, 2.0).expect("bar"),
+    ]);
+    assert_eq!(engine.chart_time_kind(), None);
+
+    engine
+        .set_timed_data(vec![(
+            ChartTime::business_day(2024, 1, 2).expect("day"),
+            1.0,
+        )])
+        .expect("timed data");
+    engine.set_data(vec![DataPoint::new(JAN_1_2024, 1.0)]);
+    assert_eq!(engine.chart_time_kind(), None);
+
+    engine
+        .set_timed_candles(business_day_bars())
+        .expect("timed candles");
+    assert_eq!(engine.chart_time_kind(), Some(ChartTimeKind::BusinessDay));
+    engine.stage_prepared_candles(StagedCandles::prepare(vec![
+        OhlcBar::new(JAN_1_2024, 1.0, 2.0, 0.5, 1.5).expect("bar"),
+        OhlcBar::new(JAN_1_2024 + 3_600.0, 1.5, 2.5, 1.0, 2.0).expect("bar"),
+    ]));
+    assert!(engine.commit_staged());
+    assert_eq!(engine.chart_time_kind(), None);
+}
@@ -0,0 +1,188 @@
+use chart_rs::ChartError;
+use chart_rs::api::{
+    ChartEngine, ChartEngineConfig, CompareSeries, PriceAxisDisplayMode, PriceAxisLabelConfig,
+};
+use chart_rs::core::{DataPoint, OhlcBar, Viewport};
+use chart_rs::render::{CanvasLayerKind, Color, NullRenderer};
+
+const COMPARE_COLOR: Color = Color::rgb(0.9, 0.5, 0.1);
+
+fn engine() -> ChartEngine<NullRenderer> {
+    let config =
+        ChartEngineConfig::new(Viewport::new(1000, 500), 0.0, 9.0).with_price_domain(90.0, 130.0);
+    let mut engine = ChartEngine::new(NullRenderer::default(), config).expect("engine");
+    engine.set_candles(
+        (0..10)
+            .map(|i| {
+                let close = 100.0 + f64::from(i);
+                OhlcBar::new(f64::from(i), close, close + 1.0, close - 1.0, close).expect("bar")
+            })
+            .collect(),
+    );
+    engine
+        .add_compare_series(CompareSeries::new(
+            "peer",
+            (0..10)
+                .map(|i| DataPoint::new(f64::from(i), 50.0 + 5.0 * f64::from(i)))
+                .collect(),
+            COMPARE_COLOR,
+        ))
+        .expect("compare series");
+    engine
+}
+
+#[test]
+fn compare_series_rebase_to_first_visible_primary_value() {
+    let mut engine = engine();
+    assert_eq!(engine.rebased_compare_points("peer"), None);
+    assert_eq!(
+        engine.resolve_price_axis_display_mode(),
+        PriceAxisDisplayMode::Normal
+    );
+
+    engine.set_compare_mode_enabled(true);
+    assert_eq!(engine.compare_primary_base_price(), Some(100.0));
+    assert_eq!(engine.compare_series_base_price("peer"), Some(50.0));
+    let rebased = engine.rebased_compare_points("peer").expect("rebased");
+    assert_eq!(rebased.len(), 10);
+    assert_eq!(rebased[0], DataPoint::new(0.0, 100.0));
+    // 95 is +90% over 50, so it lands at +90% over 100.
+    assert!((rebased[9].y - 190.0).abs() <= 1e-9);
+    assert_eq!(engine.rebased_compare_points("missing"), None);
+}
+
+#[test]
+fn rebase_follows_visible_range() {
+    let mut engine = engine();
+    engine.set_compare_mode_enabled(true);
+    engine.set_time_visible_range(4.0, 9.0).expect("range");
+
+    assert_eq!(engine.compare_primary_base_price(), Some(104.0));
+    assert_eq!(engine.compare_series_base_price("peer"), Some(70.0));
+    let rebased = engine.rebased_compare_points("peer").expect("rebased");
+    assert_eq!(rebased.first().map(|point| point.x), Some(4.0));
+    assert_eq!(rebased[0].y, 104.0);
+    assert!((rebased.last().expect("last").y - 104.0 * 95.0 / 70.0).abs() <= 1e-9);
+}
+
+#[test]
+fn compare_mode_switches_axis_to_percentage_from_visible_base() {
+    let mut engine = engine();
+    engine
+        .set_price_axis_label_config(PriceAxisLabelConfig {
+            display_mode: PriceAxisDisplayMode::Percentage {
+                base_price: Some(1.0),
+            },
+            ..PriceAxisLabelConfig::default()
+        })
+        .expect("config");
+    engine.set_compare_mode_enabled(true);
+    assert_eq!(
+        engine.resolve_price_axis_display_mode(),
+        PriceAxisDisplayMode::Percentage { base_price: None }
+    );
+
+    let frame = engine.build_render_frame().expect("frame");
+    let percent_labels: Vec<_> = frame
+        .texts
+        .iter()
+        .filter(|text| text.text.ends_with('%'))
+        .collect();
+    assert!(!percent_labels.is_empty());
+    // Labels are relative to the first visible close, not the configured base.
+    assert!(
+        percent_labels
+            .iter()
+            .any(|text| text.text.trim_start_matches(['+', '-']).starts_with('0'))
+    );
+
+    engine
+        .set_price_axis_label_config(PriceAxisLabelConfig {
+            display_mode: PriceAxisDisplayMode::IndexedTo100 {
+                base_price: Some(1.0),
+            },
+            ..PriceAxisLabelConfig::default()
+        })
+        .expect("config");
+    assert_eq!(
+        engine.resolve_price_axis_display_mode(),
+        PriceAxisDisplayMode::IndexedTo100 { base_price: None }
+    );
+}
+
+#[test]
+fn compare_lines_render_and_widen_visible_autoscale() {
+    let mut engine = engine();
+    let series_lines = |engine: &ChartEngine<NullRenderer>| {
+        let layered = engine.build_layered_render_frame().expect("layered");
+        layered.panes[0]
+            .layers
+            .iter()
+            .find(|layer| layer.kind == CanvasLayerKind::Series)
+            .expect("series layer")
+            .lines
+            .iter()
+            .filter(|line| line.color == COMPARE_COLOR)
+            .count()
+    };
+    assert_eq!(series_lines(&engine), 0);
+
+    engine.set_compare_mode_enabled(true);
+    assert_eq!(series_lines(&engine), 9);
+    // The pane scale widens to the rebased values, so no line leaves the plot.
+    let plot_bottom = 500.0 - engine.render_style().time_axis_height_px;
+    let frame = engine.build_render_frame().expect("frame");
+    assert!(
+        frame
+            .lines
+            .iter()
+            .filter(|line| line.color == COMPARE_COLOR)
+            .all(|line| (0.0..=plot_bottom).contains(&line.y1)
+                && (0.0..=plot_bottom).contains(&line.y2))
+    );
+
+    engine
+        .autoscale_price_from_visible_candles()
+        .expect("autoscale");
+    let (_, max) = engine.price_domain();
+    assert!(max >= 190.0);
+    engine.set_compare_mode_enabled(false);
+    engine
+        .autoscale_price_from_visible_candles()
+        .expect("autoscale");
+    let (_, max) = engine.price_domain();
+    assert!(max < 190.0);
+}
+
+#[test]
+fn compare_series_are_validated_and_persist_in_scene() {
+    let mut engine = engine();
+    let err = engine
+        .add_compare_series(CompareSeries::new("peer", Vec::new(), COMPARE_COLOR))
+        .expect_err("duplicate id");
+    assert!(matches!(err, ChartError::InvalidData(_)));
+    assert!(
+        engine
+            .add_compare_series(CompareSeries::new("", Vec::new(), COMPARE_COLOR))
+            .is_err()
+    );
+    engine.set_compare_mode_enabled(true);
+
+    let json = engine.export_scene_json_pretty().expect("export");
+    let mut restored = ChartEngine::new(
+        NullRenderer::default(),
+        ChartEngineConfig::new(Viewport::new(1000, 500), 0.0, 1.0),
+    )
+    .expect("engine");
+    restored.import_scene_json_str(&json).expect("import");
+    assert!(restored.compare_mode_enabled());
+    assert_eq!(restored.compare_series(), engine.compare_series());
+    assert_eq!(
+        restored.rebased_compare_points("peer"),
+        engine.rebased_compare_points("peer")
+    );
+
+    assert!(restored.remove_compare_series("peer"));
+    assert!(!restored.remove_compare_series("peer"));
+    assert!(restored.compare_series().is_empty());
+}
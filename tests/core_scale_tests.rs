@@ -205,3 +205,51 @@ fn price_scale_log_mode_ticks_preserve_domain_direction() {
     assert!(ticks.windows(2).all(|pair| pair[0] > pair[1]));
     assert!(ticks.iter().all(|value| is_log_125_ladder(*value)));
 }
+
+#[test]
+fn price_scale_log_mode_ticks_stride_decades_on_wide_ranges() {
+    let scale = PriceScale::new_with_mode(0.001, 1e6, PriceScaleMode::Log).expect("log scale");
+    let ticks = scale.ticks(5).expect("ticks");
+
+    assert!(ticks.len() <= 5);
+    assert!(ticks.len() >= 2);
+    assert!(ticks.iter().all(|value| {
+        let exponent = value.log10();
+        (exponent - exponent.round()).abs() <= 1e-9
+    }));
+
+    let minors = scale.minor_ticks(5).expect("minor ticks");
+    assert!(!minors.is_empty());
+    assert!(minors.iter().all(|value| !ticks.contains(value)));
+}
+
+#[test]
+fn price_scale_log_mode_narrow_range_uses_nice_linear_steps() {
+    let scale = PriceScale::new_with_mode(123.0, 178.0, PriceScaleMode::Log).expect("log scale");
+    let ticks = scale.ticks(8).expect("ticks");
+
+    assert!(ticks.len() >= 4);
+    assert!(ticks.len() <= 8);
+    assert!(
+        ticks
+            .iter()
+            .all(|value| (value / 10.0).fract().abs() <= 1e-9)
+    );
+    assert!(scale.minor_ticks(8).expect("minor ticks").is_empty());
+}
+
+#[test]
+fn price_scale_log_mode_minor_ticks_fill_the_125_ladder() {
+    let scale = PriceScale::new_with_mode(1.0, 1_000.0, PriceScaleMode::Log).expect("log scale");
+    let ticks = scale.ticks(10).expect("ticks");
+    let minors = scale.minor_ticks(10).expect("minor ticks");
+
+    assert_eq!(ticks.len() + minors.len(), 28);
+    for expected in [3.0, 4.0, 9.0, 30.0, 800.0] {
+        assert!(minors.iter().any(|value| (value - expected).abs() <= 1e-9));
+    }
+    assert!(minors.iter().all(|value| !is_log_125_ladder(*value)));
+
+    let linear = PriceScale::new(1.0, 1_000.0).expect("linear scale");
+    assert!(linear.minor_ticks(10).expect("minor ticks").is_empty());
+}
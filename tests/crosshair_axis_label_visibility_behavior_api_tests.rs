@@ -53,7 +53,7 @@ fn crosshair_axis_label_visibility_behavior_is_applied_to_rendered_labels() {
         crosshair_price_label_color: Color::rgb(0.19, 0.41, 0.89),
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set style");
+    engine.set_render_style(style.clone()).expect("set style");
     engine
         .set_crosshair_axis_label_visibility_behavior(CrosshairAxisLabelVisibilityBehavior {
             show_time_label: false,
@@ -0,0 +1,131 @@
+use chart_rs::api::{
+    ChartEngine, ChartEngineConfig, CrosshairLabelTemplate, CrosshairLabelTemplatePart,
+    CrosshairMode, RenderStyle,
+};
+use chart_rs::core::{DataPoint, Viewport};
+use chart_rs::render::{NullRenderer, TextHAlign};
+
+/// 2024-01-02 09:30:00 UTC.
+const OPEN: f64 = 1_704_187_800.0;
+const HOUR: f64 = 3_600.0;
+
+fn engine() -> ChartEngine<NullRenderer> {
+    let config = ChartEngineConfig::new(Viewport::new(900, 500), OPEN, OPEN + 4.0 * HOUR)
+        .with_price_domain(0.0, 50.0);
+    let mut engine = ChartEngine::new(NullRenderer::default(), config).expect("engine");
+    engine.set_crosshair_mode(CrosshairMode::Magnet);
+    engine
+        .set_render_style(RenderStyle {
+            show_time_axis_labels: false,
+            show_last_price_label: false,
+            ..engine.render_style()
+        })
+        .expect("style");
+    engine.set_data(vec![
+        DataPoint::new(OPEN, 20.0),
+        DataPoint::new(OPEN + 2.0 * HOUR, 25.0),
+        DataPoint::new(OPEN + 4.0 * HOUR, 30.0),
+    ]);
+    engine
+}
+
+/// Crosshair (time, price) label texts with the pointer on the middle point.
+fn crosshair_texts(engine: &mut ChartEngine<NullRenderer>) -> (String, String) {
+    let plot_right = 900.0 - engine.render_style().price_axis_width_px;
+    engine.pointer_move(plot_right * 0.5, 200.0);
+    let frame = engine.build_render_frame().expect("frame");
+    let time = frame
+        .texts
+        .iter()
+        .find(|text| text.h_align == TextHAlign::Center)
+        .expect("time label");
+    let price = frame
+        .texts
+        .iter()
+        .rfind(|text| text.x > plot_right)
+        .expect("price label");
+    (time.text.to_string(), price.text.to_string())
+}
+
+#[test]
+fn parsed_templates_match_the_builder() {
+    let parsed =
+        CrosshairLabelTemplate::parse("{price} • {time:%H:%M} {{{change}}}").expect("parse");
+    let built = CrosshairLabelTemplate::new()
+        .price()
+        .text(" • ")
+        .time_with_pattern("%H:%M")
+        .text(" {")
+        .change()
+        .text("}");
+    assert_eq!(parsed, built);
+    assert_eq!(
+        CrosshairLabelTemplate::parse("{value:btc}")
+            .expect("parse")
+            .parts(),
+        [CrosshairLabelTemplatePart::SeriesValue(Some(
+            "btc".to_owned()
+        ))]
+    );
+
+    for invalid in ["{volume}", "{price", "price}", "{time:%Q}", "{change:1}"] {
+        assert!(CrosshairLabelTemplate::parse(invalid).is_err(), "{invalid}");
+    }
+}
+
+#[test]
+fn templates_combine_price_time_change_and_value() {
+    let mut engine = engine();
+    let (plain_time, plain_price) = crosshair_texts(&mut engine);
+
+    engine
+        .set_crosshair_price_label_template(Some(
+            CrosshairLabelTemplate::parse("{price} ({change}) @ {time:%H:%M}").expect("parse"),
+        ))
+        .expect("template");
+    engine
+        .set_crosshair_time_label_template(Some(
+            CrosshairLabelTemplate::parse("{time} · {value}").expect("parse"),
+        ))
+        .expect("template");
+    let (time, price) = crosshair_texts(&mut engine);
+    assert_eq!(price, format!("{plain_price} (+25.00%) @ 11:30"));
+    assert_eq!(time, format!("{plain_time} · {plain_price}"));
+
+    engine
+        .set_crosshair_price_label_template(None)
+        .expect("template");
+    engine
+        .set_crosshair_time_label_template(None)
+        .expect("template");
+    assert_eq!(crosshair_texts(&mut engine), (plain_time, plain_price));
+}
+
+#[test]
+fn template_parts_reuse_the_crosshair_formatter_cache() {
+    let mut engine = engine();
+    engine.set_crosshair_price_label_formatter(std::sync::Arc::new(|price| format!("P{price}")));
+    engine
+        .set_crosshair_price_label_template(Some(CrosshairLabelTemplate::new().price().text("!")))
+        .expect("template");
+    let (_, price) = crosshair_texts(&mut engine);
+    assert_eq!(price, "P25!");
+
+    let misses = engine.crosshair_price_label_cache_stats().misses;
+    crosshair_texts(&mut engine);
+    let stats = engine.crosshair_price_label_cache_stats();
+    assert_eq!(stats.misses, misses);
+    assert!(stats.hits > 0);
+}
+
+#[test]
+fn builder_templates_with_bad_patterns_are_rejected() {
+    let mut engine = engine();
+    let template = CrosshairLabelTemplate::new().time_with_pattern("%Q");
+    assert!(
+        engine
+            .set_crosshair_time_label_template(Some(template))
+            .is_err()
+    );
+    assert!(engine.crosshair_time_label_template().is_none());
+}
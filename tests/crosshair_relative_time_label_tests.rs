@@ -0,0 +1,125 @@
+use chart_rs::api::{
+    ChartEngine, ChartEngineConfig, CrosshairMode, CrosshairTimeLabelMode, RelativeTimeAnchor,
+    RenderStyle,
+};
+use chart_rs::core::{DataPoint, Viewport};
+use chart_rs::render::{NullRenderer, TextHAlign};
+
+const LATEST: f64 = 100_000.0;
+/// 3h 24m before the latest point.
+const EARLIER: f64 = LATEST - 12_240.0;
+
+fn engine(mode: CrosshairTimeLabelMode) -> ChartEngine<NullRenderer> {
+    let config =
+        ChartEngineConfig::new(Viewport::new(900, 500), 0.0, LATEST).with_price_domain(0.0, 50.0);
+    let mut engine = ChartEngine::new(NullRenderer::default(), config).expect("engine");
+    engine.set_crosshair_mode(CrosshairMode::Magnet);
+    engine
+        .set_render_style(RenderStyle {
+            show_time_axis_labels: false,
+            crosshair_time_label_mode: mode,
+            ..engine.render_style()
+        })
+        .expect("style");
+    engine.set_data(vec![
+        DataPoint::new(0.0, 10.0),
+        DataPoint::new(EARLIER, 20.0),
+        DataPoint::new(LATEST, 30.0),
+    ]);
+    engine
+}
+
+fn crosshair_time_text(engine: &mut ChartEngine<NullRenderer>, time: f64) -> String {
+    let plot_right = 900.0 - engine.render_style().price_axis_width_px;
+    engine.pointer_move(time / LATEST * plot_right, 200.0);
+    let frame = engine.build_render_frame().expect("frame");
+    frame
+        .texts
+        .iter()
+        .find(|text| text.h_align == TextHAlign::Center)
+        .expect("crosshair time label")
+        .text
+        .to_string()
+}
+
+#[test]
+fn relative_mode_shows_offset_from_latest_bar() {
+    let mut engine = engine(CrosshairTimeLabelMode::Relative(
+        RelativeTimeAnchor::LatestBar,
+    ));
+    assert_eq!(crosshair_time_text(&mut engine, EARLIER), "3h 24m ago");
+    assert_eq!(crosshair_time_text(&mut engine, 0.0), "1d 3h ago");
+}
+
+#[test]
+fn combined_mode_appends_offset_to_timestamp() {
+    let mut absolute = engine(CrosshairTimeLabelMode::Absolute);
+    let timestamp = crosshair_time_text(&mut absolute, EARLIER);
+
+    let mut combined = engine(CrosshairTimeLabelMode::AbsoluteWithRelative(
+        RelativeTimeAnchor::LatestBar,
+    ));
+    assert_eq!(
+        crosshair_time_text(&mut combined, EARLIER),
+        format!("{timestamp} (3h 24m ago)")
+    );
+}
+
+#[test]
+fn now_anchor_measures_from_the_host_clock() {
+    let mut absolute = engine(CrosshairTimeLabelMode::Absolute);
+    let mut engine = engine(CrosshairTimeLabelMode::Relative(RelativeTimeAnchor::Now));
+    // Without a clock tick there is no anchor.
+    assert_eq!(
+        crosshair_time_text(&mut engine, EARLIER),
+        crosshair_time_text(&mut absolute, EARLIER)
+    );
+
+    engine.bar_clock_tick(LATEST + 300.0).expect("tick");
+    assert_eq!(crosshair_time_text(&mut engine, EARLIER), "3h 29m ago");
+    assert_eq!(crosshair_time_text(&mut engine, EARLIER), "3h 29m ago");
+}
+
+#[test]
+fn clock_ticks_repaint_a_visible_now_anchored_crosshair() {
+    let mut latest_bar = engine(CrosshairTimeLabelMode::Relative(
+        RelativeTimeAnchor::LatestBar,
+    ));
+    let mut engine = engine(CrosshairTimeLabelMode::Relative(RelativeTimeAnchor::Now));
+    engine.bar_clock_tick(LATEST + 300.0).expect("tick");
+    crosshair_time_text(&mut engine, EARLIER);
+    engine.render().expect("render");
+
+    engine.bar_clock_tick(LATEST + 300.0).expect("same tick");
+    assert!(!engine.has_pending_invalidation());
+    engine.bar_clock_tick(LATEST + 600.0).expect("tick");
+    assert!(engine.has_pending_invalidation());
+    assert_eq!(crosshair_time_text(&mut engine, EARLIER), "3h 34m ago");
+
+    engine.pointer_leave();
+    engine.render().expect("render");
+    engine.bar_clock_tick(LATEST + 900.0).expect("tick");
+    assert!(!engine.has_pending_invalidation());
+
+    // Other anchors do not depend on the clock.
+    crosshair_time_text(&mut latest_bar, EARLIER);
+    latest_bar.render().expect("render");
+    latest_bar.bar_clock_tick(LATEST).expect("tick");
+    assert!(!latest_bar.has_pending_invalidation());
+}
+
+#[test]
+fn relative_mode_without_data_falls_back_to_timestamp() {
+    let mut relative = engine(CrosshairTimeLabelMode::Relative(
+        RelativeTimeAnchor::LatestBar,
+    ));
+    relative.set_data(Vec::new());
+    relative.set_crosshair_mode(CrosshairMode::Normal);
+    let mut absolute = engine(CrosshairTimeLabelMode::Absolute);
+    absolute.set_data(Vec::new());
+    absolute.set_crosshair_mode(CrosshairMode::Normal);
+    assert_eq!(
+        crosshair_time_text(&mut relative, EARLIER),
+        crosshair_time_text(&mut absolute, EARLIER)
+    );
+}
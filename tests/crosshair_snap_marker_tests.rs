@@ -0,0 +1,117 @@
+use chart_rs::ChartError;
+use chart_rs::api::{
+    ChartEngine, ChartEngineConfig, CrosshairMode, CrosshairSnapMarkerStyle, RenderStyle,
+};
+use chart_rs::core::{DataPoint, Viewport};
+use chart_rs::render::{CanvasLayerKind, Color, NullRenderer, RectPrimitive};
+
+const FILL: Color = Color::rgb(0.9, 0.2, 0.1);
+const BORDER: Color = Color::rgb(1.0, 1.0, 1.0);
+
+fn marker(pulse: bool) -> CrosshairSnapMarkerStyle {
+    CrosshairSnapMarkerStyle {
+        radius_px: 5.0,
+        fill_color: FILL,
+        border_color: BORDER,
+        border_width_px: 2.0,
+        pulse,
+    }
+}
+
+fn engine(mode: CrosshairMode, pulse: bool) -> ChartEngine<NullRenderer> {
+    let config =
+        ChartEngineConfig::new(Viewport::new(800, 400), 0.0, 100.0).with_price_domain(0.0, 50.0);
+    let mut engine = ChartEngine::new(NullRenderer::default(), config).expect("engine");
+    engine.set_data(vec![DataPoint::new(10.0, 20.0), DataPoint::new(90.0, 30.0)]);
+    engine
+        .set_render_style(RenderStyle {
+            crosshair_snap_marker: Some(marker(pulse)),
+            ..engine.render_style()
+        })
+        .expect("style");
+    engine.set_crosshair_mode(mode);
+    let plot_right = 800.0 - engine.resolved_price_axis_width_px();
+    engine.pointer_move(plot_right * 0.1, 120.0);
+    engine
+}
+
+fn crosshair_circles(engine: &ChartEngine<NullRenderer>) -> Vec<RectPrimitive> {
+    let layered = engine.build_layered_render_frame().expect("layered");
+    layered.panes[0]
+        .layers
+        .iter()
+        .find(|layer| layer.kind == CanvasLayerKind::Crosshair)
+        .expect("crosshair layer")
+        .rects
+        .iter()
+        .filter(|rect| rect.corner_radius > 0.0 && rect.width == rect.corner_radius * 2.0)
+        .copied()
+        .collect()
+}
+
+#[test]
+fn marker_is_drawn_at_the_snapped_point() {
+    let engine = engine(CrosshairMode::Magnet, false);
+    let crosshair = engine.crosshair_state();
+    let (x, y) = (
+        crosshair.snapped_x.expect("snap x"),
+        crosshair.snapped_y.expect("snap y"),
+    );
+    let circles = crosshair_circles(&engine);
+    assert_eq!(circles.len(), 1);
+    let circle = circles[0];
+    assert_eq!(circle.fill_color, FILL);
+    assert_eq!((circle.border_width, circle.border_color), (2.0, BORDER));
+    assert!((circle.x + 5.0 - x).abs() < 1e-9);
+    assert!((circle.y + 5.0 - y).abs() < 1e-9);
+    assert!(circle.clip_rect.is_some());
+}
+
+#[test]
+fn marker_needs_a_magnet_snap() {
+    let engine = engine(CrosshairMode::Normal, false);
+    assert!(crosshair_circles(&engine).is_empty());
+}
+
+#[test]
+fn realtime_updates_pulse_the_marker_until_the_animation_ends() {
+    let mut engine = engine(CrosshairMode::Magnet, true);
+    assert!(!engine.has_active_animation());
+
+    engine
+        .append_point(DataPoint::new(95.0, 31.0))
+        .expect("append");
+    assert!(engine.crosshair_snap_marker_pulse_active());
+    assert!(engine.has_active_animation());
+    engine.step_animations(0.2).expect("step");
+    let circles = crosshair_circles(&engine);
+    assert_eq!(circles.len(), 2);
+    let (halo, marker) = (circles[0], circles[1]);
+    assert!(halo.width > marker.width);
+    assert!(halo.fill_color.alpha < FILL.alpha);
+
+    engine.step_animations(1.0).expect("step");
+    assert!(!engine.has_active_animation());
+    assert_eq!(crosshair_circles(&engine).len(), 1);
+}
+
+#[test]
+fn pulse_is_opt_in_and_marker_geometry_is_validated() {
+    let mut engine = engine(CrosshairMode::Magnet, false);
+    engine
+        .append_point(DataPoint::new(95.0, 31.0))
+        .expect("append");
+    assert!(!engine.crosshair_snap_marker_pulse_active());
+
+    let invalid = RenderStyle {
+        crosshair_snap_marker: Some(CrosshairSnapMarkerStyle {
+            radius_px: 0.0,
+            ..marker(false)
+        }),
+        ..engine.render_style()
+    };
+    assert!(matches!(
+        engine.set_render_style(invalid),
+        Err(ChartError::InvalidData(_))
+    ));
+}
@@ -1,6 +1,7 @@
-use chart_rs::api::{ChartEngine, ChartEngineConfig};
+use chart_rs::ChartError;
+use chart_rs::api::{CROSSHAIR_SNAP_MAX_WHITESPACE_SKIP, ChartEngine, ChartEngineConfig};
 use chart_rs::core::{DataPoint, OhlcBar, Viewport};
-use chart_rs::interaction::CrosshairMode;
+use chart_rs::interaction::{CrosshairMode, CrosshairSnapKind, CrosshairSnapTarget};
 use chart_rs::render::NullRenderer;
 
 #[test]
@@ -182,3 +183,286 @@ fn magnet_crosshair_sparse_gap_tie_uses_upper_filled_index() {
     assert!((snapped_time - 8.0).abs() <= 1e-9);
     assert!((snapped_price - 80.0).abs() <= 1e-9);
 }
+
+fn candle_engine() -> ChartEngine<NullRenderer> {
+    let renderer = NullRenderer::default();
+    let config =
+        ChartEngineConfig::new(Viewport::new(1000, 500), 0.0, 10.0).with_price_domain(0.0, 100.0);
+    let mut engine = ChartEngine::new(renderer, config).expect("engine init");
+    engine.set_candles(vec![
+        OhlcBar::new(2.0, 40.0, 90.0, 10.0, 60.0).expect("bar"),
+        OhlcBar::new(6.0, 50.0, 70.0, 30.0, 45.0).expect("bar"),
+    ]);
+    engine
+}
+
+#[test]
+fn magnet_snap_target_selects_candle_field() {
+    let mut engine = candle_engine();
+    let pointer_x = engine.map_x_to_pixel(2.2).expect("x map");
+
+    for (target, expected) in [
+        (CrosshairSnapTarget::Close, 60.0),
+        (CrosshairSnapTarget::Open, 40.0),
+        (CrosshairSnapTarget::High, 90.0),
+        (CrosshairSnapTarget::Low, 10.0),
+    ] {
+        engine.set_crosshair_snap_target(target);
+        engine.pointer_move(pointer_x, 10.0);
+        let price = engine.crosshair_state().snapped_price.expect("snapped");
+        assert!((price - expected).abs() <= 1e-9, "{target:?}");
+    }
+}
+
+#[test]
+fn nearest_ohlc_snap_target_follows_pointer_height() {
+    let mut engine = candle_engine();
+    engine.set_crosshair_snap_target(CrosshairSnapTarget::NearestOhlc);
+    let pointer_x = engine.map_x_to_pixel(2.0).expect("x map");
+
+    let near_high_y = engine.map_price_to_pixel(85.0).expect("price map");
+    engine.pointer_move(pointer_x, near_high_y);
+    assert_eq!(engine.crosshair_state().snapped_price, Some(90.0));
+
+    let near_low_y = engine.map_price_to_pixel(15.0).expect("price map");
+    engine.pointer_move(pointer_x, near_low_y);
+    assert_eq!(engine.crosshair_state().snapped_price, Some(10.0));
+}
+
+#[test]
+fn series_value_snap_target_ignores_candles() {
+    let mut engine = candle_engine();
+    engine.set_data(vec![DataPoint::new(8.0, 25.0)]);
+    engine.set_crosshair_snap_target(CrosshairSnapTarget::SeriesValue);
+
+    let pointer_x = engine.map_x_to_pixel(2.0).expect("x map");
+    engine.pointer_move(pointer_x, 100.0);
+    let crosshair = engine.crosshair_state();
+    assert_eq!(crosshair.snapped_time, Some(8.0));
+    assert_eq!(crosshair.snapped_price, Some(25.0));
+}
+
+#[test]
+fn changing_snap_target_resnaps_visible_crosshair() {
+    let mut engine = candle_engine();
+    let pointer_x = engine.map_x_to_pixel(6.0).expect("x map");
+    engine.pointer_move(pointer_x, 100.0);
+    assert_eq!(engine.crosshair_state().snapped_price, Some(45.0));
+
+    engine.set_crosshair_snap_target(CrosshairSnapTarget::High);
+    assert_eq!(engine.crosshair_state().snapped_price, Some(70.0));
+}
+
+#[test]
+fn magnet_snap_ignores_samples_outside_visible_window() {
+    let renderer = NullRenderer::default();
+    let config =
+        ChartEngineConfig::new(Viewport::new(1000, 500), 0.0, 10.0).with_price_domain(0.0, 100.0);
+    let mut engine = ChartEngine::new(renderer, config).expect("engine init");
+    engine.set_data(vec![DataPoint::new(-5.0, 20.0), DataPoint::new(20.0, 80.0)]);
+
+    let pointer_x = engine.map_x_to_pixel(1.0).expect("x map");
+    engine.pointer_move(pointer_x, 100.0);
+    let crosshair = engine.crosshair_state();
+    assert!(crosshair.visible);
+    assert!(crosshair.snapped_time.is_none());
+}
+
+#[test]
+fn magnet_snap_radius_limits_snapping_to_nearby_samples() {
+    let renderer = NullRenderer::default();
+    let config = ChartEngineConfig::new(Viewport::new(1000, 500), 0.0, 10.0)
+        .with_price_domain(0.0, 100.0)
+        .with_crosshair_snap_radius_px(Some(20.0));
+    let mut engine = ChartEngine::new(renderer, config).expect("engine init");
+    engine.set_data(vec![DataPoint::new(2.0, 20.0), DataPoint::new(8.0, 80.0)]);
+    assert_eq!(engine.crosshair_snap_radius_px(), Some(20.0));
+
+    let sample_x = engine.map_x_to_pixel(2.0).expect("x map");
+    engine.pointer_move(sample_x + 10.0, 100.0);
+    assert_eq!(engine.crosshair_state().snapped_time, Some(2.0));
+
+    engine.pointer_move(sample_x + 60.0, 100.0);
+    let crosshair = engine.crosshair_state();
+    assert!(crosshair.visible);
+    assert!(crosshair.snapped_x.is_none());
+    assert!((crosshair.x - (sample_x + 60.0)).abs() <= 1e-9);
+
+    engine
+        .set_crosshair_snap_radius_px(None)
+        .expect("clear radius");
+    assert_eq!(engine.crosshair_state().snapped_time, Some(2.0));
+}
+
+#[test]
+fn invalid_magnet_snap_radius_is_rejected() {
+    let renderer = NullRenderer::default();
+    let config = ChartEngineConfig::new(Viewport::new(1000, 500), 0.0, 10.0);
+    let mut engine = ChartEngine::new(renderer, config).expect("engine init");
+    assert!(engine.set_crosshair_snap_radius_px(Some(-1.0)).is_err());
+    assert!(engine.set_crosshair_snap_radius_px(Some(f64::NAN)).is_err());
+    assert_eq!(engine.crosshair_snap_radius_px(), None);
+}
+
+#[test]
+fn magnet_snap_reaches_samples_just_past_the_visible_edge() {
+    let renderer = NullRenderer::default();
+    let config =
+        ChartEngineConfig::new(Viewport::new(1000, 500), 0.0, 10.0).with_price_domain(0.0, 100.0);
+    let mut engine = ChartEngine::new(renderer, config).expect("engine init");
+    engine.set_data(vec![DataPoint::new(5.0, 50.0), DataPoint::new(10.1, 80.0)]);
+
+    let pointer_x = engine.map_x_to_pixel(9.9).expect("x map");
+    engine.pointer_move(pointer_x, 100.0);
+    assert_eq!(engine.crosshair_state().snapped_time, Some(10.1));
+}
+
+#[test]
+fn magnet_snap_skips_whitespace_samples() {
+    let renderer = NullRenderer::default();
+    let config =
+        ChartEngineConfig::new(Viewport::new(1000, 500), 0.0, 10.0).with_price_domain(0.0, 100.0);
+    let mut engine = ChartEngine::new(renderer, config).expect("engine init");
+    engine.set_data(vec![DataPoint::new(2.0, 20.0)]);
+    engine
+        .append_point(DataPoint::new(5.0, f64::NAN))
+        .expect("append");
+    engine
+        .append_point(DataPoint::new(8.0, 80.0))
+        .expect("append");
+
+    let pointer_x = engine.map_x_to_pixel(5.2).expect("x map");
+    engine.pointer_move(pointer_x, 100.0);
+    let crosshair = engine.crosshair_state();
+    assert_eq!(crosshair.snapped_time, Some(8.0));
+    assert_eq!(crosshair.snapped_price, Some(80.0));
+
+    engine.pointer_move(engine.map_x_to_pixel(4.9).expect("x map"), 100.0);
+    assert_eq!(engine.crosshair_state().snapped_time, Some(2.0));
+}
+
+#[test]
+fn out_of_order_appends_are_rejected_so_every_sample_stays_snappable() {
+    let renderer = NullRenderer::default();
+    let config =
+        ChartEngineConfig::new(Viewport::new(1000, 500), 0.0, 100.0).with_price_domain(0.0, 100.0);
+    let mut engine = ChartEngine::new(renderer, config).expect("engine init");
+    engine.set_data(vec![
+        DataPoint::new(10.0, 10.0),
+        DataPoint::new(50.0, 50.0),
+        DataPoint::new(90.0, 90.0),
+    ]);
+
+    assert!(matches!(
+        engine.append_point(DataPoint::new(20.0, 20.0)),
+        Err(ChartError::NonMonotonicTime { index: 3 })
+    ));
+    assert_eq!(engine.points().len(), 3);
+    engine
+        .append_point(DataPoint::new(95.0, 95.0))
+        .expect("append");
+
+    for (time, expected) in [(21.0, 10.0), (93.0, 95.0), (88.0, 90.0)] {
+        engine.pointer_move(engine.map_x_to_pixel(time).expect("x map"), 100.0);
+        assert_eq!(engine.crosshair_state().snapped_time, Some(expected));
+    }
+}
+
+#[test]
+fn magnet_snap_gives_up_past_the_whitespace_skip_limit() {
+    let renderer = NullRenderer::default();
+    let config =
+        ChartEngineConfig::new(Viewport::new(1000, 500), 0.0, 200.0).with_price_domain(0.0, 100.0);
+    let mut engine = ChartEngine::new(renderer, config).expect("engine init");
+    let gap = CROSSHAIR_SNAP_MAX_WHITESPACE_SKIP;
+    engine.set_data(vec![DataPoint::new(0.0, 20.0)]);
+    for i in 1..=gap + 1 {
+        engine
+            .append_point(DataPoint::new(i as f64, f64::NAN))
+            .expect("append");
+    }
+
+    // A run of exactly the limit is still crossed to the sample behind it.
+    engine.pointer_move(engine.map_x_to_pixel(gap as f64).expect("x map"), 100.0);
+    assert_eq!(engine.crosshair_state().snapped_time, Some(0.0));
+
+    // Behind a longer run the crosshair stays on the raw pointer.
+    engine.pointer_move(
+        engine.map_x_to_pixel(gap as f64 + 1.5).expect("x map"),
+        100.0,
+    );
+    let crosshair = engine.crosshair_state();
+    assert!(crosshair.visible);
+    assert_eq!(crosshair.snapped_time, None);
+}
+
+fn horizontal_gridline_prices(engine: &ChartEngine<NullRenderer>) -> Vec<f64> {
+    let grid_color = engine.render_style().grid.horz_lines.color;
+    engine
+        .build_render_frame()
+        .expect("frame")
+        .lines
+        .iter()
+        .filter(|line| line.color == grid_color && line.y1 == line.y2)
+        .map(|line| engine.map_pixel_to_price(line.y1).expect("price"))
+        .collect()
+}
+
+#[test]
+fn price_grid_mode_snaps_price_to_nearest_gridline_only() {
+    let renderer = NullRenderer::default();
+    let config =
+        ChartEngineConfig::new(Viewport::new(1000, 500), 0.0, 10.0).with_price_domain(0.0, 100.0);
+    let mut engine = ChartEngine::new(renderer, config).expect("engine init");
+    engine.set_data(vec![DataPoint::new(2.0, 23.0), DataPoint::new(8.0, 81.0)]);
+    engine.set_crosshair_mode(CrosshairMode::PriceGrid);
+
+    let gridlines = horizontal_gridline_prices(&engine);
+    assert!(gridlines.len() >= 3);
+    let pointer_y = engine.map_price_to_pixel(47.3).expect("y");
+    let pointer_x = engine.map_x_to_pixel(2.1).expect("x");
+    engine.pointer_move(pointer_x, pointer_y);
+
+    let crosshair = engine.crosshair_state();
+    assert_eq!(crosshair.snap_kind, Some(CrosshairSnapKind::PriceGridline));
+    assert_eq!(crosshair.snapped_x, None);
+    assert_eq!(crosshair.snapped_time, None);
+    assert_eq!(crosshair.snapped_series, None);
+    let snapped_price = crosshair.snapped_price.expect("snapped price");
+    let nearest = gridlines
+        .iter()
+        .copied()
+        .min_by(|left, right| (left - 47.3).abs().total_cmp(&(right - 47.3).abs()))
+        .expect("gridline");
+    assert!((snapped_price - nearest).abs() <= 1e-6);
+    let expected_y = engine.map_price_to_pixel(snapped_price).expect("y");
+    assert!((crosshair.snapped_y.expect("snapped y") - expected_y).abs() <= 1e-9);
+}
+
+#[test]
+fn snap_kind_reports_data_snaps_and_clears_on_leave() {
+    let renderer = NullRenderer::default();
+    let config =
+        ChartEngineConfig::new(Viewport::new(1000, 500), 0.0, 10.0).with_price_domain(0.0, 100.0);
+    let mut engine = ChartEngine::new(renderer, config).expect("engine init");
+    engine.set_data(vec![DataPoint::new(2.0, 20.0), DataPoint::new(8.0, 80.0)]);
+
+    engine.pointer_move(engine.map_x_to_pixel(2.1).expect("x"), 200.0);
+    assert_eq!(
+        engine.crosshair_state().snap_kind,
+        Some(CrosshairSnapKind::Data)
+    );
+
+    engine.set_crosshair_mode(CrosshairMode::Normal);
+    engine.pointer_move(300.0, 200.0);
+    assert_eq!(engine.crosshair_state().snap_kind, None);
+
+    engine.set_crosshair_mode(CrosshairMode::PriceGrid);
+    engine.pointer_move(300.0, 200.0);
+    assert_eq!(
+        engine.crosshair_state().snap_kind,
+        Some(CrosshairSnapKind::PriceGridline)
+    );
+    engine.pointer_leave();
+    assert_eq!(engine.crosshair_state().snap_kind, None);
+}
@@ -0,0 +1,254 @@
+use chart_rs::ChartError;
+use chart_rs::api::{ChartEngine, ChartEngineConfig};
+use chart_rs::core::{DataPoint, Viewport};
+use chart_rs::error::ChartResult;
+use chart_rs::extensions::{CustomSeries, CustomSeriesPrimitives, CustomSeriesRenderContext};
+use chart_rs::render::{CanvasLayerKind, Color, LinePrimitive, NullRenderer, RectPrimitive};
+
+const STEM_COLOR: Color = Color::rgb(0.2, 0.6, 0.3);
+
+#[derive(Debug, Clone, Copy)]
+struct Lollipop {
+    time: f64,
+    value: f64,
+}
+
+/// Stem from zero up to the value, capped with a square head.
+struct LollipopSeries {
+    head_px: f64,
+}
+
+impl CustomSeries for LollipopSeries {
+    type Row = Lollipop;
+
+    fn row_time(&self, row: &Lollipop) -> f64 {
+        row.time
+    }
+
+    fn autoscale_range(&self, rows: &[Lollipop]) -> Option<(f64, f64)> {
+        let high = rows.iter().map(|row| row.value).reduce(f64::max)?;
+        let low = rows.iter().map(|row| row.value).reduce(f64::min)?;
+        Some((low.min(0.0), high.max(0.0)))
+    }
+
+    fn render(
+        &self,
+        rows: &[Lollipop],
+        ctx: &CustomSeriesRenderContext,
+        out: &mut CustomSeriesPrimitives,
+    ) -> ChartResult<()> {
+        let zero_y = ctx.price_to_pixel(0.0)?;
+        for row in rows {
+            let x = ctx.time_to_pixel(row.time)?;
+            let y = ctx.price_to_pixel(row.value)?;
+            out.lines
+                .push(LinePrimitive::new(x, zero_y, x, y, 1.0, STEM_COLOR));
+            let half = self.head_px * 0.5;
+            out.rects.push(RectPrimitive::new(
+                x - half,
+                y - half,
+                self.head_px,
+                self.head_px,
+                STEM_COLOR,
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Different row type, used to check typed row updates.
+struct MarkerSeries;
+
+impl CustomSeries for MarkerSeries {
+    type Row = f64;
+
+    fn row_time(&self, row: &f64) -> f64 {
+        *row
+    }
+
+    fn autoscale_range(&self, _rows: &[f64]) -> Option<(f64, f64)> {
+        None
+    }
+
+    fn render(
+        &self,
+        _rows: &[f64],
+        _ctx: &CustomSeriesRenderContext,
+        _out: &mut CustomSeriesPrimitives,
+    ) -> ChartResult<()> {
+        Ok(())
+    }
+}
+
+fn rows(values: &[f64]) -> Vec<Lollipop> {
+    values
+        .iter()
+        .enumerate()
+        .map(|(index, value)| Lollipop {
+            time: index as f64,
+            value: *value,
+        })
+        .collect()
+}
+
+fn engine() -> ChartEngine<NullRenderer> {
+    let config =
+        ChartEngineConfig::new(Viewport::new(1000, 500), 0.0, 9.0).with_price_domain(0.0, 10.0);
+    ChartEngine::new(NullRenderer::default(), config).expect("engine")
+}
+
+#[test]
+fn custom_series_renders_visible_rows_into_its_pane_series_layer() {
+    let mut engine = engine();
+    let pane = engine.create_pane(1.0).expect("pane");
+    // Rows arrive newest first; the engine sorts them by time.
+    let mut unsorted = rows(&[4.0, -2.0, 6.0, 1.0, 3.0]);
+    unsorted.reverse();
+    engine
+        .add_custom_series("lollipops", pane, LollipopSeries { head_px: 6.0 }, unsorted)
+        .expect("add");
+    engine.set_time_visible_range(1.0, 3.0).expect("range");
+
+    let layered = engine.build_layered_render_frame().expect("layered");
+    let pane_frame = layered
+        .panes
+        .iter()
+        .find(|frame| frame.pane_id == pane)
+        .expect("pane frame");
+    let series = pane_frame
+        .layers
+        .iter()
+        .find(|layer| layer.kind == CanvasLayerKind::Series)
+        .expect("series layer");
+    assert_eq!(series.rects.len(), 3);
+    assert_eq!(series.lines.len(), 3);
+    assert!(series.rects.iter().all(|rect| rect.clip_rect.is_some()));
+
+    let main_series_rects = layered.panes[0]
+        .layers
+        .iter()
+        .find(|layer| layer.kind == CanvasLayerKind::Series)
+        .expect("main series layer")
+        .rects
+        .len();
+    assert_eq!(main_series_rects, 0);
+}
+
+#[test]
+fn autoscale_range_feeds_pane_and_main_price_scales() {
+    let mut engine = engine();
+    engine.set_data((0..5).map(|i| DataPoint::new(f64::from(i), 1.0)).collect());
+    engine
+        .add_custom_series(
+            "lollipops",
+            engine.main_pane_id(),
+            LollipopSeries { head_px: 4.0 },
+            rows(&[2.0, 40.0, -5.0, 8.0, 1.0]),
+        )
+        .expect("add");
+
+    assert_eq!(
+        engine.custom_series_autoscale_range("lollipops"),
+        Some((-5.0, 40.0))
+    );
+    engine.set_time_visible_range(3.0, 4.0).expect("range");
+    assert_eq!(
+        engine.custom_series_autoscale_range("lollipops"),
+        Some((0.0, 8.0))
+    );
+
+    engine.set_time_visible_range(0.0, 4.0).expect("range");
+    engine
+        .autoscale_price_from_visible_data()
+        .expect("autoscale");
+    let (min, max) = engine.price_domain();
+    assert!(min <= -5.0 && max >= 40.0);
+
+    // Heads stay inside the plot because the pane scale covers the range.
+    let plot_bottom = 500.0 - engine.render_style().time_axis_height_px;
+    let frame = engine.build_render_frame().expect("frame");
+    let heads: Vec<_> = frame
+        .rects
+        .iter()
+        .filter(|rect| rect.fill_color == STEM_COLOR)
+        .collect();
+    assert_eq!(heads.len(), 5);
+    assert!(heads.iter().all(|rect| {
+        let center = rect.y + rect.height * 0.5;
+        (0.0..=plot_bottom).contains(&center)
+    }));
+}
+
+#[test]
+fn rows_are_updated_through_the_registered_type() {
+    let mut engine = engine();
+    let main = engine.main_pane_id();
+    engine
+        .add_custom_series(
+            "lollipops",
+            main,
+            LollipopSeries { head_px: 4.0 },
+            rows(&[1.0]),
+        )
+        .expect("add");
+    engine
+        .add_custom_series("markers", main, MarkerSeries, vec![1.0, 2.0])
+        .expect("add");
+    assert_eq!(engine.custom_series_ids(), ["lollipops", "markers"]);
+    assert_eq!(engine.custom_series_autoscale_range("markers"), None);
+
+    engine
+        .set_custom_series_rows::<LollipopSeries>("lollipops", rows(&[3.0, 9.0]))
+        .expect("rows");
+    assert_eq!(
+        engine.custom_series_autoscale_range("lollipops"),
+        Some((0.0, 9.0))
+    );
+
+    let err = engine
+        .set_custom_series_rows::<MarkerSeries>("lollipops", vec![1.0])
+        .expect_err("type mismatch");
+    assert!(matches!(err, ChartError::InvalidData(_)));
+    assert!(
+        engine
+            .set_custom_series_rows::<MarkerSeries>("missing", Vec::new())
+            .is_err()
+    );
+
+    assert!(engine.remove_custom_series("markers"));
+    assert!(!engine.remove_custom_series("markers"));
+    assert!(!engine.has_custom_series("markers"));
+}
+
+#[test]
+fn invalid_registrations_are_rejected() {
+    let mut engine = engine();
+    let main = engine.main_pane_id();
+    engine
+        .add_custom_series(
+            "lollipops",
+            main,
+            LollipopSeries { head_px: 4.0 },
+            Vec::new(),
+        )
+        .expect("add");
+
+    for (id, pane, times) in [
+        ("lollipops", main, vec![0.0]),
+        ("", main, vec![0.0]),
+        ("nan", main, vec![f64::NAN]),
+    ] {
+        let err = engine
+            .add_custom_series(id, pane, MarkerSeries, times)
+            .expect_err("invalid registration");
+        assert!(matches!(err, ChartError::InvalidData(_)));
+    }
+
+    let pane = engine.create_pane(1.0).expect("pane");
+    engine.remove_pane(pane).expect("remove");
+    assert!(
+        engine
+            .add_custom_series("orphan", pane, MarkerSeries, Vec::new())
+            .is_err()
+    );
+}
@@ -0,0 +1,80 @@
+use chart_rs::api::{ChartEngine, ChartEngineConfig, CompareSeries, DataExportFormat, SeriesRef};
+use chart_rs::core::{DataPoint, OhlcBar, Viewport};
+use chart_rs::render::{Color, NullRenderer};
+
+fn engine() -> ChartEngine<NullRenderer> {
+    let config =
+        ChartEngineConfig::new(Viewport::new(800, 400), 0.0, 100.0).with_price_domain(0.0, 50.0);
+    let mut engine = ChartEngine::new(NullRenderer::default(), config).expect("engine");
+    engine.set_candles(
+        (0..10)
+            .map(|i| {
+                let time = f64::from(i) * 10.0;
+                OhlcBar::new(time, 20.0, 40.5, 19.0, 21.0 + f64::from(i)).expect("bar")
+            })
+            .collect(),
+    );
+    engine.set_data(
+        (0..10)
+            .map(|i| DataPoint::new(f64::from(i) * 10.0, 1.5))
+            .collect(),
+    );
+    engine.set_time_visible_range(25.0, 45.0).expect("range");
+    engine
+}
+
+#[test]
+fn csv_contains_only_visible_samples() {
+    let engine = engine();
+    assert_eq!(
+        engine
+            .export_visible(SeriesRef::Candles, DataExportFormat::Csv)
+            .expect("csv"),
+        "time,open,high,low,close\n30,20,40.5,19,24\n40,20,40.5,19,25\n"
+    );
+    assert_eq!(
+        engine
+            .export_visible(SeriesRef::Points, DataExportFormat::Csv)
+            .expect("csv"),
+        "time,value\n30,1.5\n40,1.5\n"
+    );
+}
+
+#[test]
+fn json_rows_match_csv_columns() {
+    let mut engine = engine();
+    let json = engine
+        .export_visible(SeriesRef::Candles, DataExportFormat::Json)
+        .expect("json");
+    let rows: Vec<OhlcBar> = serde_json::from_str(&json).expect("rows");
+    assert_eq!(rows, engine.visible_candles());
+
+    engine
+        .add_compare_series(CompareSeries::new(
+            "cmp",
+            vec![DataPoint::new(0.0, 5.0), DataPoint::new(35.0, 6.0)],
+            Color::rgb(0.0, 0.0, 1.0),
+        ))
+        .expect("compare");
+    assert_eq!(
+        engine
+            .export_visible(SeriesRef::Compare("cmp"), DataExportFormat::Json)
+            .expect("json"),
+        r#"[{"time":35.0,"value":6.0}]"#
+    );
+}
+
+#[test]
+fn unknown_and_custom_series_are_rejected() {
+    let engine = engine();
+    assert!(
+        engine
+            .export_visible(SeriesRef::Compare("missing"), DataExportFormat::Csv)
+            .is_err()
+    );
+    assert!(
+        engine
+            .export_visible(SeriesRef::Custom("custom"), DataExportFormat::Json)
+            .is_err()
+    );
+}
@@ -0,0 +1,198 @@
+use chart_rs::api::{
+    ChartEngine, ChartEngineConfig, DataGap, DataGapBehavior, TimeAxisLabelConfig,
+    TimeAxisSessionConfig,
+};
+use chart_rs::core::{DataPoint, OhlcBar, Viewport};
+use chart_rs::render::{CanvasLayerKind, NullRenderer};
+
+const HOUR: f64 = 3_600.0;
+const DAY: f64 = 86_400.0;
+/// 1970-01-05, a Monday.
+const MONDAY: f64 = 4.0 * DAY;
+
+fn engine() -> ChartEngine<NullRenderer> {
+    let config =
+        ChartEngineConfig::new(Viewport::new(800, 400), 0.0, 100.0).with_price_domain(0.0, 50.0);
+    ChartEngine::new(NullRenderer::default(), config).expect("engine")
+}
+
+fn candles(times: impl IntoIterator<Item = f64>) -> Vec<OhlcBar> {
+    times
+        .into_iter()
+        .map(|time| OhlcBar::new(time, 20.0, 21.0, 19.0, 20.5).expect("bar"))
+        .collect()
+}
+
+#[test]
+fn missing_bars_are_found_with_inferred_interval() {
+    let mut engine = engine();
+    let times = (0..20)
+        .filter(|i| !(5..8).contains(i))
+        .map(|i| f64::from(i) * 60.0);
+    engine.set_candles(candles(times));
+    assert_eq!(
+        engine.detected_gaps(),
+        vec![DataGap {
+            start: 240.0,
+            end: 480.0,
+            missing_bars: 3,
+        }]
+    );
+
+    // Points are used when there are no candles.
+    let mut engine = self::engine();
+    engine.set_data(vec![
+        DataPoint::new(0.0, 1.0),
+        DataPoint::new(1.0, 1.0),
+        DataPoint::new(2.0, 1.0),
+        DataPoint::new(5.0, 1.0),
+    ]);
+    assert_eq!(engine.detected_gaps().len(), 1);
+    assert_eq!(engine.detected_gaps()[0].missing_bars, 2);
+}
+
+#[test]
+fn closed_session_hours_are_not_gaps() {
+    let mut engine = engine();
+    engine
+        .set_time_axis_label_config(TimeAxisLabelConfig {
+            session: Some(TimeAxisSessionConfig {
+                start_hour: 9,
+                start_minute: 0,
+                end_hour: 17,
+                end_minute: 0,
+            }),
+            ..TimeAxisLabelConfig::default()
+        })
+        .expect("config");
+    // Hourly bars 09:00-17:00 on two days; the second day misses 12:00.
+    let times = (0..2).flat_map(|day| {
+        (9..=17)
+            .filter(move |hour| !(day == 1 && *hour == 12))
+            .map(move |hour| MONDAY + f64::from(day) * DAY + f64::from(hour) * HOUR)
+    });
+    engine.set_candles(candles(times));
+    engine
+        .set_data_gap_behavior(DataGapBehavior {
+            expected_interval: Some(HOUR),
+            ..DataGapBehavior::default()
+        })
+        .expect("behavior");
+
+    assert_eq!(
+        engine.detected_gaps(),
+        vec![DataGap {
+            start: MONDAY + DAY + 11.0 * HOUR,
+            end: MONDAY + DAY + 13.0 * HOUR,
+            missing_bars: 1,
+        }]
+    );
+}
+
+#[test]
+fn weekends_are_skipped_only_when_enabled() {
+    let mut engine = engine();
+    // Daily bars Monday-Friday for two weeks.
+    let times = (0..14)
+        .filter(|day| day % 7 < 5)
+        .map(|day| MONDAY + f64::from(day) * DAY);
+    engine.set_candles(candles(times));
+    assert_eq!(
+        engine.detected_gaps(),
+        vec![DataGap {
+            start: MONDAY + 4.0 * DAY,
+            end: MONDAY + 7.0 * DAY,
+            missing_bars: 2,
+        }]
+    );
+
+    engine
+        .set_data_gap_behavior(DataGapBehavior {
+            skip_weekends: true,
+            ..DataGapBehavior::default()
+        })
+        .expect("behavior");
+    assert!(engine.detected_gaps().is_empty());
+}
+
+#[test]
+fn markers_render_on_the_time_axis_when_enabled() {
+    let mut engine = engine();
+    engine.set_candles(candles([0.0, 10.0, 20.0, 50.0, 60.0]));
+    let marker_color = DataGapBehavior::default().marker_color;
+    let marker_count = |engine: &ChartEngine<NullRenderer>| {
+        let layered = engine.build_layered_render_frame().expect("layered");
+        layered.panes[0]
+            .layers
+            .iter()
+            .filter(|layer| layer.kind == CanvasLayerKind::Axis)
+            .flat_map(|layer| &layer.rects)
+            .filter(|rect| rect.fill_color == marker_color)
+            .count()
+    };
+    assert_eq!(marker_count(&engine), 0);
+
+    engine
+        .set_data_gap_behavior(DataGapBehavior {
+            show_markers: true,
+            ..DataGapBehavior::default()
+        })
+        .expect("behavior");
+    assert_eq!(marker_count(&engine), 1);
+
+    let layered = engine.build_layered_render_frame().expect("layered");
+    let marker = layered.panes[0]
+        .layers
+        .iter()
+        .flat_map(|layer| &layer.rects)
+        .find(|rect| rect.fill_color == marker_color)
+        .copied()
+        .expect("marker");
+    assert!((marker.x - engine.map_x_to_pixel(20.0).expect("x")).abs() < 1e-9);
+    assert!((marker.x + marker.width - engine.map_x_to_pixel(50.0).expect("x")).abs() < 1e-9);
+
+    // Filling the gap clears the cached detection.
+    engine.set_candles(candles([0.0, 10.0, 20.0, 30.0, 40.0, 50.0, 60.0]));
+    assert!(engine.detected_gaps().is_empty());
+    assert_eq!(marker_count(&engine), 0);
+}
+
+#[test]
+fn behavior_validates_and_persists_in_scenes() {
+    let mut engine = engine();
+    for invalid in [
+        DataGapBehavior {
+            expected_interval: Some(0.0),
+            ..DataGapBehavior::default()
+        },
+        DataGapBehavior {
+            tolerance: 0.5,
+            ..DataGapBehavior::default()
+        },
+        DataGapBehavior {
+            marker_height_px: f64::NAN,
+            ..DataGapBehavior::default()
+        },
+    ] {
+        assert!(engine.set_data_gap_behavior(invalid).is_err());
+    }
+
+    let behavior = DataGapBehavior {
+        expected_interval: Some(60.0),
+        show_markers: true,
+        ..DataGapBehavior::default()
+    };
+    engine.set_data_gap_behavior(behavior).expect("behavior");
+    let json = engine.export_scene_json_pretty().expect("json");
+    let mut restored = self::engine();
+    restored.import_scene_json_str(&json).expect("import");
+    assert_eq!(restored.data_gap_behavior(), behavior);
+
+    let configured = ChartEngine::new(
+        NullRenderer::default(),
+        ChartEngineConfig::new(Viewport::new(800, 400), 0.0, 100.0)
+            .with_data_gap_behavior(behavior),
+    )
+    .expect("engine");
+    assert_eq!(configured.data_gap_behavior(), behavior);
+}
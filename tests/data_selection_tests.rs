@@ -0,0 +1,273 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use chart_rs::api::{
+    ChartEngine, ChartEngineConfig, DataSelectionSeries, InteractionOptions, PointerInput,
+    PointerInputBridge, PointerModifiers,
+};
+use chart_rs::core::{DataPoint, OhlcBar, Viewport};
+use chart_rs::extensions::{ChartPlugin, PluginContext, PluginEvent};
+use chart_rs::render::NullRenderer;
+
+struct SelectionRecorder {
+    events: Rc<RefCell<Vec<PluginEvent>>>,
+}
+
+impl ChartPlugin for SelectionRecorder {
+    fn id(&self) -> &str {
+        "selection-recorder"
+    }
+
+    fn on_event(&mut self, event: PluginEvent, _context: PluginContext) {
+        if matches!(event, PluginEvent::SelectionChanged { .. }) {
+            self.events.borrow_mut().push(event);
+        }
+    }
+}
+
+type Events = Rc<RefCell<Vec<PluginEvent>>>;
+
+fn build_engine() -> (ChartEngine<NullRenderer>, Events) {
+    let config =
+        ChartEngineConfig::new(Viewport::new(800, 400), 0.0, 100.0).with_price_domain(0.0, 100.0);
+    let mut engine = ChartEngine::new(NullRenderer::default(), config).expect("engine init");
+    engine.set_data(
+        (0..=100)
+            .map(|step| DataPoint::new(f64::from(step), f64::from(step)))
+            .collect(),
+    );
+    engine.set_candles(
+        (0..=100)
+            .map(|step| {
+                let mid = f64::from(step);
+                OhlcBar::new(mid, mid, mid + 2.0, mid - 2.0, mid).expect("candle")
+            })
+            .collect(),
+    );
+    engine
+        .set_time_visible_range(0.0, 100.0)
+        .expect("visible range");
+    let events = Rc::new(RefCell::new(Vec::new()));
+    engine
+        .register_plugin(Box::new(SelectionRecorder {
+            events: events.clone(),
+        }))
+        .expect("register plugin");
+    (engine, events)
+}
+
+/// Drags a box from `(t0, p0)` to `(t1, p1)` in data coordinates.
+fn select(
+    engine: &mut ChartEngine<NullRenderer>,
+    series: DataSelectionSeries,
+    (t0, p0): (f64, f64),
+    (t1, p1): (f64, f64),
+) {
+    let x0 = engine.map_x_to_pixel(t0).expect("x0");
+    let x1 = engine.map_x_to_pixel(t1).expect("x1");
+    let y0 = engine.map_price_to_pixel(p0).expect("y0");
+    let y1 = engine.map_price_to_pixel(p1).expect("y1");
+    engine.begin_data_selection(series, x0, y0);
+    engine.update_data_selection(x1, y1);
+}
+
+#[test]
+fn point_selection_keeps_samples_inside_the_box() {
+    let (mut engine, events) = build_engine();
+    let domain = engine.price_domain();
+    let (low, high) = (domain.0 + (domain.1 - domain.0) * 0.2, domain.1);
+
+    select(
+        &mut engine,
+        DataSelectionSeries::Points,
+        (10.0, high),
+        (60.0, low),
+    );
+    assert!(engine.data_selection_box().is_some());
+    let selection = engine
+        .end_data_selection()
+        .expect("release")
+        .expect("selection")
+        .clone();
+
+    assert_eq!(selection.series, DataSelectionSeries::Points);
+    assert!(selection.candles.is_empty());
+    assert!(!selection.points.is_empty());
+    for point in &selection.points {
+        assert!((10.0 - 1e-6..=60.0 + 1e-6).contains(&point.x));
+        assert!(
+            (selection.price_range.0..=selection.price_range.1).contains(&point.y),
+            "{point:?} outside {:?}",
+            selection.price_range
+        );
+    }
+    assert_eq!(engine.current_selection(), Some(&selection));
+    assert_eq!(engine.data_selection_box(), None);
+    assert_eq!(
+        *events.borrow(),
+        vec![PluginEvent::SelectionChanged {
+            samples: selection.len(),
+            time_range: Some(selection.time_range),
+            price_range: Some(selection.price_range),
+        }]
+    );
+}
+
+#[test]
+fn candle_selection_includes_bars_overlapping_the_price_range() {
+    let (mut engine, _) = build_engine();
+
+    select(
+        &mut engine,
+        DataSelectionSeries::Candles,
+        (20.0, 51.0),
+        (80.0, 49.0),
+    );
+    let selection = engine
+        .end_data_selection()
+        .expect("release")
+        .expect("selection")
+        .clone();
+
+    let (low, high) = selection.price_range;
+    assert!(selection.points.is_empty());
+    assert!(!selection.candles.is_empty());
+    assert!(
+        selection
+            .candles
+            .iter()
+            .all(|bar| bar.low <= high && bar.high >= low && (20.0..=80.0).contains(&bar.time))
+    );
+    let times: Vec<f64> = selection.candles.iter().map(|bar| bar.time).collect();
+    assert!(times.contains(&50.0));
+    assert!(!times.contains(&20.0));
+}
+
+#[test]
+fn tiny_box_clears_the_previous_selection() {
+    let (mut engine, events) = build_engine();
+    select(
+        &mut engine,
+        DataSelectionSeries::Points,
+        (10.0, 90.0),
+        (50.0, 10.0),
+    );
+    engine.end_data_selection().expect("release");
+
+    engine.begin_data_selection(DataSelectionSeries::Points, 100.0, 100.0);
+    engine.update_data_selection(101.0, 101.0);
+    assert!(engine.end_data_selection().expect("release").is_none());
+
+    assert!(engine.current_selection().is_none());
+    assert_eq!(
+        events.borrow().last(),
+        Some(&PluginEvent::SelectionChanged {
+            samples: 0,
+            time_range: None,
+            price_range: None,
+        })
+    );
+}
+
+#[test]
+fn cancel_keeps_the_current_selection() {
+    let (mut engine, events) = build_engine();
+    select(
+        &mut engine,
+        DataSelectionSeries::Points,
+        (10.0, 90.0),
+        (50.0, 10.0),
+    );
+    engine.end_data_selection().expect("release");
+    let kept = engine.current_selection().cloned();
+
+    select(
+        &mut engine,
+        DataSelectionSeries::Candles,
+        (60.0, 90.0),
+        (90.0, 10.0),
+    );
+    engine.cancel_data_selection();
+
+    assert_eq!(engine.current_selection().cloned(), kept);
+    assert_eq!(events.borrow().len(), 1);
+
+    engine.clear_selection();
+    engine.clear_selection();
+    assert!(engine.current_selection().is_none());
+    assert_eq!(events.borrow().len(), 2);
+}
+
+#[test]
+fn current_selection_is_drawn_until_cleared() {
+    let (mut engine, _) = build_engine();
+    let rects_before = engine.build_render_frame().expect("frame").rects.len();
+
+    select(
+        &mut engine,
+        DataSelectionSeries::Points,
+        (10.0, 90.0),
+        (50.0, 10.0),
+    );
+    assert_eq!(
+        engine.build_render_frame().expect("frame").rects.len(),
+        rects_before + 1
+    );
+    engine.end_data_selection().expect("release");
+    assert_eq!(
+        engine.build_render_frame().expect("frame").rects.len(),
+        rects_before + 1
+    );
+
+    engine.clear_selection();
+    assert_eq!(
+        engine.build_render_frame().expect("frame").rects.len(),
+        rects_before
+    );
+}
+
+#[test]
+fn bridge_routes_modifier_drag_to_data_selection() {
+    let (mut engine, events) = build_engine();
+    let mut bridge = PointerInputBridge::new(
+        InteractionOptions::default()
+            .with_data_selection(Some(PointerModifiers::CONTROL), DataSelectionSeries::Points),
+    );
+    let range_before = engine.time_visible_range();
+
+    for input in [
+        PointerInput::ModifiersChanged {
+            modifiers: PointerModifiers::CONTROL,
+        },
+        PointerInput::Pressed { x: 100.0, y: 60.0 },
+        PointerInput::Moved { x: 400.0, y: 250.0 },
+        PointerInput::Released,
+    ] {
+        bridge.handle(&mut engine, input).expect("input");
+    }
+
+    assert_eq!(engine.time_visible_range(), range_before);
+    let selection = engine.current_selection().expect("selection");
+    assert_eq!(selection.series, DataSelectionSeries::Points);
+    assert_eq!(events.borrow().len(), 1);
+}
+
+#[test]
+fn data_selection_is_disabled_by_default() {
+    let (mut engine, events) = build_engine();
+    let mut bridge = PointerInputBridge::default();
+
+    for input in [
+        PointerInput::ModifiersChanged {
+            modifiers: PointerModifiers::CONTROL,
+        },
+        PointerInput::Pressed { x: 100.0, y: 60.0 },
+        PointerInput::Moved { x: 400.0, y: 250.0 },
+        PointerInput::Released,
+    ] {
+        bridge.handle(&mut engine, input).expect("input");
+    }
+
+    assert!(engine.current_selection().is_none());
+    assert!(events.borrow().is_empty());
+}
@@ -0,0 +1,106 @@
+use chart_rs::api::{ChartEngine, ChartEngineConfig};
+use chart_rs::core::Viewport;
+use chart_rs::data::sim::{
+    CandleAggregator, RandomWalk, RandomWalkConfig, SimReplay, SimSession, simulate_candles,
+};
+use chart_rs::render::NullRenderer;
+
+const T0: f64 = 1_704_067_200.0; // 2024-01-01T00:00:00Z
+
+fn config() -> RandomWalkConfig {
+    RandomWalkConfig::default()
+        .with_seed(42)
+        .with_start(T0, 100.0)
+        .with_volatility(0.01)
+}
+
+#[test]
+fn same_seed_produces_identical_streams() {
+    let a = RandomWalk::new(config()).expect("walk").ticks(500);
+    let b = RandomWalk::new(config()).expect("walk").ticks(500);
+    let c = RandomWalk::new(config().with_seed(7))
+        .expect("walk")
+        .ticks(500);
+
+    assert_eq!(a, b);
+    assert_ne!(a, c);
+    assert!(a.iter().all(|tick| tick.y.is_finite() && tick.y > 0.0));
+    assert!(a.windows(2).all(|pair| pair[1].x - pair[0].x == 1.0));
+}
+
+#[test]
+fn gaps_skip_whole_tick_intervals() {
+    let ticks = RandomWalk::new(config().with_tick_interval(5.0).with_gaps(0.2, 4))
+        .expect("walk")
+        .ticks(1_000);
+    let deltas = ticks
+        .windows(2)
+        .map(|pair| pair[1].x - pair[0].x)
+        .collect::<Vec<_>>();
+
+    assert!(deltas.iter().any(|delta| *delta > 5.0));
+    assert!(
+        deltas
+            .iter()
+            .all(|delta| *delta >= 5.0 && *delta <= 25.0 && delta % 5.0 == 0.0)
+    );
+}
+
+#[test]
+fn sessions_only_emit_in_session_ticks() {
+    let session = SimSession::new(9.5 * 3_600.0, 16.0 * 3_600.0);
+    let ticks = RandomWalk::new(config().with_tick_interval(600.0).with_session(session))
+        .expect("walk")
+        .ticks(200);
+
+    assert_eq!(ticks[0].x, T0 + 9.5 * 3_600.0);
+    assert!(ticks.iter().all(|tick| {
+        let second = (tick.x - T0).rem_euclid(86_400.0);
+        (9.5 * 3_600.0..16.0 * 3_600.0).contains(&second)
+    }));
+    assert!(ticks.last().expect("tick").x > T0 + 86_400.0);
+}
+
+#[test]
+fn simulated_candles_are_valid_and_aligned() {
+    let bars = simulate_candles(config().with_gaps(0.1, 30), 60.0, 50).expect("candles");
+
+    assert_eq!(bars.len(), 50);
+    assert!(bars.windows(2).all(|pair| pair[0].time < pair[1].time));
+    assert!(bars.iter().all(|bar| {
+        bar.time % 60.0 == 0.0
+            && bar.low <= bar.open.min(bar.close)
+            && bar.high >= bar.open.max(bar.close)
+    }));
+}
+
+#[test]
+fn replay_advances_against_virtual_clock_and_feeds_engine() {
+    let mut replay = SimReplay::new(config(), 60.0).expect("replay");
+    let renderer = NullRenderer::default();
+    let engine_config = ChartEngineConfig::new(Viewport::new(800, 400), T0, T0 + 600.0)
+        .with_price_domain(0.0, 200.0);
+    let mut engine = ChartEngine::new(renderer, engine_config).expect("engine");
+
+    let first = replay.advance(0.0).expect("advance");
+    assert_eq!(first.ticks.len(), 1);
+
+    let mut total = first.ticks.len();
+    for candle in first.candles {
+        engine.update_candle(candle).expect("update");
+    }
+    for _ in 0..10 {
+        let batch = replay.advance(30.5).expect("advance");
+        total += batch.ticks.len();
+        for candle in batch.candles {
+            engine.update_candle(candle).expect("update");
+        }
+    }
+
+    assert_eq!(replay.clock(), T0 + 305.0);
+    assert_eq!(total, 306);
+    assert_eq!(engine.candles().len(), 6);
+    assert!(replay.advance(-1.0).is_err());
+    assert!(CandleAggregator::new(0.0).is_err());
+    assert!(RandomWalk::new(config().with_volatility(f64::NAN)).is_err());
+}
@@ -0,0 +1,288 @@
+#![cfg(feature = "stream")]
+
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use chart_rs::ChartError;
+use chart_rs::api::{ChartEngine, ChartEngineConfig};
+use chart_rs::core::{DataPoint, OhlcBar, Viewport};
+use chart_rs::data::stream::{
+    FeedEvent, FeedStatus, LiveFeed, StreamConfig, run_live_feed, spawn_live_feed,
+    spawn_live_feed_on, stream_channel,
+};
+use chart_rs::error::ChartResult;
+use chart_rs::render::NullRenderer;
+
+const T0: f64 = 1_704_067_200.0;
+
+enum Step {
+    Event(FeedEvent),
+    Drop,
+    Pause(Duration),
+}
+
+/// Replays a fixed script; `connect_failures` connects fail before each session.
+struct ScriptedFeed {
+    connect_failures: VecDeque<u32>,
+    pending_failures: u32,
+    steps: VecDeque<Step>,
+}
+
+impl ScriptedFeed {
+    fn new(connect_failures: Vec<u32>, steps: Vec<Step>) -> Self {
+        let mut connect_failures = VecDeque::from(connect_failures);
+        let pending_failures = connect_failures.pop_front().unwrap_or(0);
+        Self {
+            connect_failures,
+            pending_failures,
+            steps: steps.into(),
+        }
+    }
+}
+
+impl LiveFeed for ScriptedFeed {
+    async fn connect(&mut self) -> ChartResult<()> {
+        if self.pending_failures > 0 {
+            self.pending_failures -= 1;
+            return Err(ChartError::InvalidData("connect refused".to_owned()));
+        }
+        self.pending_failures = self.connect_failures.pop_front().unwrap_or(0);
+        Ok(())
+    }
+
+    async fn next_event(&mut self) -> ChartResult<Option<FeedEvent>> {
+        loop {
+            match self.steps.pop_front() {
+                None => return Ok(None),
+                Some(Step::Event(event)) => return Ok(Some(event)),
+                Some(Step::Drop) => {
+                    return Err(ChartError::InvalidData("socket closed".to_owned()));
+                }
+                Some(Step::Pause(duration)) => tokio::time::sleep(duration).await,
+            }
+        }
+    }
+}
+
+fn runtime() -> tokio::runtime::Runtime {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_time()
+        .build()
+        .expect("runtime")
+}
+
+fn candle(offset: f64, close: f64) -> Step {
+    Step::Event(FeedEvent::Candle(
+        OhlcBar::new(T0 + offset, close, close, close, close).expect("candle"),
+    ))
+}
+
+fn fast_config() -> StreamConfig {
+    StreamConfig::default()
+        .with_batching(3, Duration::from_millis(5))
+        .with_backoff(Duration::from_millis(1), Duration::from_millis(4))
+}
+
+fn build_engine() -> ChartEngine<NullRenderer> {
+    let config = ChartEngineConfig::new(Viewport::new(800, 400), T0, T0 + 600.0)
+        .with_price_domain(0.0, 100.0);
+    ChartEngine::new(NullRenderer::default(), config).expect("engine")
+}
+
+#[test]
+fn events_are_batched_by_size_and_window() {
+    let (sender, mut receiver) = stream_channel();
+    let feed = ScriptedFeed::new(
+        vec![],
+        vec![
+            candle(0.0, 1.0),
+            candle(0.0, 2.0),
+            candle(60.0, 3.0),
+            candle(60.0, 4.0),
+            Step::Pause(Duration::from_millis(40)),
+            candle(120.0, 5.0),
+        ],
+    );
+    runtime().block_on(run_live_feed(feed, fast_config(), sender));
+
+    let mut engine = build_engine();
+    let report = receiver.apply_pending(&mut engine);
+    assert_eq!(report.batches, 3);
+    assert_eq!(report.applied, 5);
+    assert_eq!(report.rejected, 0);
+    assert_eq!(
+        report.statuses,
+        vec![FeedStatus::Connected, FeedStatus::Finished]
+    );
+    let closes = engine
+        .candles()
+        .iter()
+        .map(|bar| bar.close)
+        .collect::<Vec<_>>();
+    assert_eq!(closes, vec![2.0, 4.0, 5.0]);
+}
+
+#[test]
+fn disconnects_reconnect_with_backoff_and_skip_stale_replays() {
+    let feed = ScriptedFeed::new(
+        vec![0, 2],
+        vec![
+            candle(0.0, 1.0),
+            candle(60.0, 2.0),
+            Step::Drop,
+            candle(0.0, 1.5),
+            candle(60.0, 2.5),
+            Step::Event(FeedEvent::Tick(DataPoint::new(T0 + 61.0, 2.6))),
+        ],
+    );
+    let (sender, mut receiver) = stream_channel();
+    runtime().block_on(run_live_feed(feed, fast_config(), sender));
+
+    let mut engine = build_engine();
+    let report = receiver.apply_pending(&mut engine);
+    assert_eq!(
+        report.statuses,
+        vec![
+            FeedStatus::Connected,
+            FeedStatus::Disconnected {
+                reason: "invalid data: socket closed".to_owned()
+            },
+            // The session dropped before `healthy_after`, so it counts as
+            // the first failed attempt.
+            FeedStatus::Reconnecting {
+                attempt: 1,
+                delay: Duration::from_millis(1)
+            },
+            FeedStatus::Reconnecting {
+                attempt: 2,
+                delay: Duration::from_millis(2)
+            },
+            FeedStatus::Reconnecting {
+                attempt: 3,
+                delay: Duration::from_millis(4)
+            },
+            FeedStatus::Connected,
+            FeedStatus::Finished,
+        ]
+    );
+    assert_eq!(report.applied, 4);
+    assert_eq!(report.rejected, 1);
+    assert_eq!(engine.candles().last().expect("candle").close, 2.5);
+    assert_eq!(engine.points().len(), 1);
+    assert_eq!(receiver.status(), Some(&FeedStatus::Finished));
+}
+
+#[test]
+fn sessions_dropping_at_once_keep_backing_off() {
+    let feed = ScriptedFeed::new(vec![], vec![Step::Drop, Step::Drop, Step::Drop]);
+    let (sender, mut receiver) = stream_channel();
+    runtime().block_on(run_live_feed(
+        feed,
+        fast_config().with_max_reconnect_attempts(Some(2)),
+        sender,
+    ));
+
+    let statuses = receiver.apply_pending(&mut build_engine()).statuses;
+    let attempts: Vec<_> = statuses
+        .iter()
+        .filter_map(|status| match status {
+            FeedStatus::Reconnecting { attempt, delay } => Some((*attempt, *delay)),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(
+        attempts,
+        vec![(1, Duration::from_millis(1)), (2, Duration::from_millis(2))]
+    );
+    assert!(matches!(statuses.last(), Some(FeedStatus::GaveUp { .. })));
+}
+
+#[test]
+fn healthy_sessions_reset_the_backoff() {
+    let feed = ScriptedFeed::new(
+        vec![],
+        vec![
+            Step::Pause(Duration::from_millis(20)),
+            Step::Drop,
+            Step::Drop,
+        ],
+    );
+    let (sender, mut receiver) = stream_channel();
+    runtime().block_on(run_live_feed(
+        feed,
+        fast_config().with_healthy_after(Duration::from_millis(10)),
+        sender,
+    ));
+
+    let dropped = FeedStatus::Disconnected {
+        reason: "invalid data: socket closed".to_owned(),
+    };
+    assert_eq!(
+        receiver.apply_pending(&mut build_engine()).statuses,
+        vec![
+            FeedStatus::Connected,
+            dropped.clone(),
+            // Reconnects at once after the healthy session...
+            FeedStatus::Connected,
+            dropped,
+            // ...but backs off after the short one.
+            FeedStatus::Reconnecting {
+                attempt: 1,
+                delay: Duration::from_millis(1)
+            },
+            FeedStatus::Connected,
+            FeedStatus::Finished,
+        ]
+    );
+}
+
+#[test]
+fn exhausted_reconnects_give_up() {
+    let feed = ScriptedFeed::new(vec![10], vec![candle(0.0, 1.0)]);
+    let (sender, mut receiver) = stream_channel();
+    runtime().block_on(run_live_feed(
+        feed,
+        fast_config().with_max_reconnect_attempts(Some(2)),
+        sender,
+    ));
+
+    let report = receiver.apply_pending(&mut build_engine());
+    assert_eq!(report.applied, 0);
+    assert!(matches!(
+        report.statuses.last(),
+        Some(FeedStatus::GaveUp { .. })
+    ));
+    assert_eq!(report.statuses.len(), 3);
+}
+
+#[test]
+fn spawned_task_stops_when_receiver_is_dropped() {
+    let runtime = runtime();
+    let steps = (0..10_000)
+        .map(|index| candle(f64::from(index) * 60.0, 1.0))
+        .collect();
+    let feed = ScriptedFeed::new(vec![], steps);
+    let (handle, receiver) = runtime
+        .block_on(async { spawn_live_feed(feed, fast_config()) })
+        .expect("spawn");
+    drop(receiver);
+    runtime.block_on(handle).expect("task joins");
+
+    // Outside a runtime spawning fails instead of panicking.
+    assert!(spawn_live_feed(ScriptedFeed::new(vec![], vec![]), fast_config()).is_err());
+    let (handle, _receiver) = spawn_live_feed_on(
+        runtime.handle(),
+        ScriptedFeed::new(vec![], vec![]),
+        fast_config(),
+    )
+    .expect("spawn on handle");
+    runtime.block_on(handle).expect("task joins");
+
+    assert!(
+        spawn_live_feed(
+            ScriptedFeed::new(vec![], vec![]),
+            StreamConfig::default().with_batching(0, Duration::ZERO)
+        )
+        .is_err()
+    );
+}
@@ -0,0 +1,162 @@
+use std::time::Duration;
+
+use chart_rs::api::{ChartEngine, ChartEngineConfig, DebugOverlayBehavior, DebugOverlayCorner};
+use chart_rs::core::{DataPoint, OhlcBar, Viewport};
+use chart_rs::interaction::InteractionMode;
+use chart_rs::render::{CanvasLayerKind, NullRenderer, RectPrimitive, TextPrimitive};
+
+fn engine() -> ChartEngine<NullRenderer> {
+    let config = ChartEngineConfig::new(Viewport::new(1000, 500), 0.0, 99.0);
+    let mut engine = ChartEngine::new(NullRenderer::default(), config).expect("engine");
+    engine.set_data(
+        (0..100)
+            .map(|i| DataPoint::new(f64::from(i), 10.0 + f64::from(i % 7)))
+            .collect(),
+    );
+    engine.set_candles(
+        (0..50)
+            .map(|i| {
+                let time = f64::from(i * 2);
+                OhlcBar::new(time, 10.0, 12.0, 9.0, 11.0).expect("bar")
+            })
+            .collect(),
+    );
+    engine
+}
+
+fn overlay_layer(engine: &ChartEngine<NullRenderer>) -> (Vec<RectPrimitive>, Vec<TextPrimitive>) {
+    let background = engine.debug_overlay_behavior().background_color;
+    let layered = engine.build_layered_render_frame().expect("layered");
+    let layer = layered.panes[0]
+        .layers
+        .iter()
+        .find(|layer| layer.kind == CanvasLayerKind::Crosshair)
+        .expect("crosshair layer");
+    let rects = layer
+        .rects
+        .iter()
+        .filter(|rect| rect.fill_color == background)
+        .copied()
+        .collect();
+    (rects, layer.texts.clone())
+}
+
+#[test]
+fn overlay_is_opt_in_and_toggles_at_runtime() {
+    let mut engine = engine();
+    assert!(!engine.debug_overlay_behavior().enabled);
+    assert!(overlay_layer(&engine).0.is_empty());
+
+    engine.set_debug_overlay_enabled(true);
+    let (rects, texts) = overlay_layer(&engine);
+    assert_eq!(rects.len(), 1);
+    // Frame and cache rows change with every build; the rest is stable.
+    for line in ["fps --", "points 100/100", "candles 50/50", "mode idle"] {
+        assert!(texts.iter().any(|text| &*text.text == line), "{line}");
+    }
+
+    // Default corner is top-right inside the plot.
+    let behavior = engine.debug_overlay_behavior();
+    let plot_right = 1000.0 - engine.resolved_price_axis_width_px();
+    let panel = rects[0];
+    assert!((panel.x + panel.width - (plot_right - behavior.padding_px)).abs() <= 1e-9);
+    assert_eq!(panel.y, behavior.padding_px);
+
+    engine.set_debug_overlay_enabled(false);
+    assert!(overlay_layer(&engine).0.is_empty());
+}
+
+#[test]
+fn stats_report_visible_counts_caches_mode_and_fps() {
+    let mut engine = engine();
+    engine.set_time_visible_range(10.0, 29.0).expect("range");
+    let stats = engine.debug_overlay_stats();
+    assert_eq!((stats.visible_points, stats.total_points), (20, 100));
+    assert_eq!((stats.visible_candles, stats.total_candles), (10, 50));
+    assert_eq!(stats.fps, None);
+    assert_eq!(stats.last_frame, None);
+
+    // Renders are only timed while the overlay is on, and only on the host
+    // frame clock.
+    engine.frame_clock_tick(Duration::from_millis(0));
+    engine.render().expect("render");
+    engine.frame_clock_tick(Duration::from_millis(20));
+    engine.render().expect("render");
+    assert_eq!(engine.debug_overlay_stats().fps, None);
+
+    engine.set_debug_overlay_enabled(true);
+    engine.render().expect("render");
+    assert_eq!(engine.debug_overlay_stats().fps, None);
+    for millis in [40, 60] {
+        engine.frame_clock_tick(Duration::from_millis(millis));
+        engine.render().expect("render");
+    }
+    engine.set_debug_overlay_enabled(false);
+    let stats = engine.debug_overlay_stats();
+    assert!(stats.fps.is_some_and(|fps| (fps - 50.0).abs() <= 1e-9));
+    assert_eq!(stats.last_frame, engine.last_frame_stats());
+    assert!(
+        stats
+            .time_label_cache_hit_rate
+            .is_some_and(|rate| (0.0..=1.0).contains(&rate))
+    );
+    assert!(stats.price_label_cache_hit_rate.is_some());
+    assert!(
+        stats
+            .lines()
+            .iter()
+            .any(|line| line.starts_with("points 20/100"))
+    );
+
+    engine.pan_start();
+    assert_eq!(
+        engine.debug_overlay_stats().interaction_mode,
+        InteractionMode::Panning
+    );
+    assert!(
+        engine
+            .debug_overlay_stats()
+            .lines()
+            .contains(&"mode panning".to_owned())
+    );
+}
+
+#[test]
+fn bottom_left_corner_sits_above_the_time_axis() {
+    let behavior = DebugOverlayBehavior {
+        enabled: true,
+        corner: DebugOverlayCorner::BottomLeft,
+        ..DebugOverlayBehavior::default()
+    };
+    let config = ChartEngineConfig::new(Viewport::new(1000, 500), 0.0, 99.0)
+        .with_debug_overlay_behavior(behavior);
+    let engine = ChartEngine::new(NullRenderer::default(), config).expect("engine");
+    assert_eq!(engine.debug_overlay_behavior(), behavior);
+
+    let (rects, _) = overlay_layer(&engine);
+    let panel = rects[0];
+    let plot_bottom = 500.0 - engine.render_style().time_axis_height_px;
+    assert_eq!(panel.x, behavior.padding_px);
+    assert!((panel.y + panel.height - (plot_bottom - behavior.padding_px)).abs() <= 1e-9);
+}
+
+#[test]
+fn invalid_behavior_is_rejected() {
+    let mut engine = engine();
+    for behavior in [
+        DebugOverlayBehavior {
+            font_size_px: 0.0,
+            ..DebugOverlayBehavior::default()
+        },
+        DebugOverlayBehavior {
+            padding_px: f64::NAN,
+            ..DebugOverlayBehavior::default()
+        },
+    ] {
+        assert!(engine.set_debug_overlay_behavior(behavior).is_err());
+    }
+    assert_eq!(
+        engine.debug_overlay_behavior(),
+        DebugOverlayBehavior::default()
+    );
+}
@@ -0,0 +1,124 @@
+#![cfg(feature = "egui-adapter")]
+
+use chart_rs::api::{ChartEngine, ChartEngineConfig};
+use chart_rs::core::{DataPoint, Viewport};
+use chart_rs::platform_egui::ChartWidget;
+use chart_rs::render::NullRenderer;
+use egui::{
+    Event, FullOutput, Modifiers, MouseWheelUnit, PointerButton, Pos2, RawInput, Rect, pos2, vec2,
+};
+
+fn engine() -> ChartEngine<NullRenderer> {
+    let config =
+        ChartEngineConfig::new(Viewport::new(200, 100), 0.0, 100.0).with_price_domain(0.0, 50.0);
+    let mut engine = ChartEngine::new(NullRenderer::default(), config).expect("engine");
+    engine.set_data(
+        (0..=100)
+            .map(|i| DataPoint::new(f64::from(i), 20.0 + f64::from(i % 5)))
+            .collect(),
+    );
+    engine
+}
+
+fn run_frame(
+    ctx: &egui::Context,
+    engine: &mut ChartEngine<NullRenderer>,
+    events: Vec<Event>,
+) -> FullOutput {
+    let input = RawInput {
+        screen_rect: Some(Rect::from_min_size(Pos2::ZERO, vec2(800.0, 400.0))),
+        events,
+        ..RawInput::default()
+    };
+    ctx.run(input, |ctx| {
+        egui::CentralPanel::default()
+            .frame(egui::Frame::NONE)
+            .show(ctx, |ui| {
+                ui.add(ChartWidget::new(engine));
+            });
+    })
+}
+
+fn press(pos: Pos2, pressed: bool) -> Event {
+    Event::PointerButton {
+        pos,
+        button: PointerButton::Primary,
+        pressed,
+        modifiers: Modifiers::NONE,
+    }
+}
+
+#[test]
+fn widget_sizes_engine_viewport_and_paints_frame() {
+    let ctx = egui::Context::default();
+    let mut engine = engine();
+
+    let output = run_frame(&ctx, &mut engine, Vec::new());
+
+    assert_eq!(engine.viewport(), Viewport::new(800, 400));
+    assert!(!output.shapes.is_empty());
+}
+
+#[test]
+fn hover_drives_crosshair_until_pointer_leaves() {
+    let ctx = egui::Context::default();
+    let mut engine = engine();
+    run_frame(&ctx, &mut engine, Vec::new());
+
+    run_frame(
+        &ctx,
+        &mut engine,
+        vec![Event::PointerMoved(pos2(200.0, 150.0))],
+    );
+    assert!(engine.crosshair_state().visible);
+
+    run_frame(&ctx, &mut engine, vec![Event::PointerGone]);
+    assert!(!engine.crosshair_state().visible);
+}
+
+#[test]
+fn wheel_over_widget_zooms_time_scale() {
+    let ctx = egui::Context::default();
+    let mut engine = engine();
+    run_frame(
+        &ctx,
+        &mut engine,
+        vec![Event::PointerMoved(pos2(300.0, 150.0))],
+    );
+    let (start, end) = engine.time_visible_range();
+
+    run_frame(
+        &ctx,
+        &mut engine,
+        vec![Event::MouseWheel {
+            unit: MouseWheelUnit::Line,
+            delta: vec2(0.0, 1.0),
+            modifiers: Modifiers::NONE,
+        }],
+    );
+
+    let (zoomed_start, zoomed_end) = engine.time_visible_range();
+    assert!(zoomed_end - zoomed_start < end - start);
+}
+
+#[test]
+fn primary_drag_pans_time_scale() {
+    let ctx = egui::Context::default();
+    let mut engine = engine();
+    run_frame(
+        &ctx,
+        &mut engine,
+        vec![Event::PointerMoved(pos2(300.0, 150.0))],
+    );
+    let (start, end) = engine.time_visible_range();
+
+    run_frame(&ctx, &mut engine, vec![press(pos2(300.0, 150.0), true)]);
+    for x in [320.0, 360.0, 400.0] {
+        run_frame(&ctx, &mut engine, vec![Event::PointerMoved(pos2(x, 150.0))]);
+    }
+    run_frame(&ctx, &mut engine, vec![press(pos2(400.0, 150.0), false)]);
+
+    let (panned_start, panned_end) = engine.time_visible_range();
+    assert!(panned_start < start);
+    assert!(((panned_end - panned_start) - (end - start)).abs() < 1e-9);
+}
@@ -0,0 +1,154 @@
+use chart_rs::api::{
+    ChartEngine, ChartEngineConfig, ElasticOverscrollBehavior, TimeScaleEdgeBehavior,
+    TimeScaleNavigationBehavior,
+};
+use chart_rs::core::Viewport;
+use chart_rs::render::NullRenderer;
+
+fn build_engine(elastic: ElasticOverscrollBehavior) -> ChartEngine<NullRenderer> {
+    let renderer = NullRenderer::default();
+    let config = ChartEngineConfig::new(Viewport::new(1000, 500), 0.0, 100.0)
+        .with_price_domain(0.0, 1.0)
+        .with_time_scale_edge_behavior(TimeScaleEdgeBehavior {
+            fix_left_edge: true,
+            fix_right_edge: false,
+        })
+        .with_elastic_overscroll_behavior(elastic);
+    let mut engine = ChartEngine::new(renderer, config).expect("engine init");
+    engine
+        .set_time_scale_navigation_behavior(TimeScaleNavigationBehavior {
+            right_offset_bars: 0.0,
+            bar_spacing_px: None,
+        })
+        .expect("disable default spacing navigation");
+    engine
+}
+
+fn elastic() -> ElasticOverscrollBehavior {
+    ElasticOverscrollBehavior {
+        enabled: true,
+        ..ElasticOverscrollBehavior::default()
+    }
+}
+
+#[test]
+fn drag_past_a_fixed_edge_stretches_with_growing_resistance() {
+    let mut engine = build_engine(elastic());
+
+    engine.pan_start();
+    engine.pan_time_visible_by_pixels(200.0).expect("pan");
+    let (first_start, first_end) = engine.time_visible_range();
+    assert!(engine.elastic_overscroll_active());
+    // 0.1 time units per pixel: the pull shows, but damped below the drag
+    // and the 80px cap.
+    assert!(first_start < 0.0 && first_start > -8.0, "{first_start}");
+    assert!((first_end - first_start - 100.0).abs() <= 1e-9);
+
+    engine.pan_time_visible_by_pixels(200.0).expect("pan");
+    let (second_start, _) = engine.time_visible_range();
+    assert!(second_start < first_start && second_start > -8.0);
+    assert!(first_start - second_start < -first_start);
+
+    engine.pan_time_visible_by_pixels(-400.0).expect("pan back");
+    let (start, _) = engine.time_visible_range();
+    assert!(start.abs() <= 1e-9, "{start}");
+    assert!(!engine.elastic_overscroll_active());
+}
+
+#[test]
+fn releasing_the_drag_springs_back_to_the_edge_through_step_animations() {
+    let mut engine = build_engine(elastic());
+
+    engine.pan_start();
+    engine.pan_time_visible_by_pixels(300.0).expect("pan");
+    let (pulled_start, _) = engine.time_visible_range();
+    engine.pan_end();
+
+    assert!(!engine.elastic_overscroll_active());
+    assert!(engine.has_active_animation());
+    engine.start_kinetic_pan(50.0).expect("kinetic");
+    assert!(!engine.kinetic_pan_state().active);
+
+    assert!(engine.step_animations(0.1).expect("step"));
+    let (mid_start, _) = engine.time_visible_range();
+    assert!(mid_start > pulled_start && mid_start < 0.0, "{mid_start}");
+
+    engine.step_animations(0.3).expect("step");
+    let (start, end) = engine.time_visible_range();
+    assert!(start.abs() <= 1e-9 && (end - 100.0).abs() <= 1e-9);
+    assert!(!engine.has_active_animation());
+}
+
+#[test]
+fn pressing_during_spring_back_settles_the_range_at_the_edge() {
+    let mut engine = build_engine(elastic());
+
+    engine.pan_start();
+    engine.pan_time_visible_by_pixels(300.0).expect("pan");
+    engine.pan_end();
+    engine.step_animations(0.05).expect("step");
+    assert!(engine.time_visible_range().0 < 0.0);
+
+    engine.pan_start();
+    engine.pan_end();
+
+    let (start, end) = engine.time_visible_range();
+    assert!(start.abs() <= 1e-9 && (end - 100.0).abs() <= 1e-9);
+    assert!(!engine.elastic_overscroll_active());
+    assert!(!engine.has_active_animation());
+}
+
+#[test]
+fn zero_spring_back_duration_snaps_to_the_edge_on_release() {
+    let mut engine = build_engine(ElasticOverscrollBehavior {
+        spring_back_seconds: 0.0,
+        ..elastic()
+    });
+
+    engine.pan_start();
+    engine.pan_time_visible_by_pixels(300.0).expect("pan");
+    engine.pan_end();
+
+    let (start, _) = engine.time_visible_range();
+    assert!(start.abs() <= 1e-9);
+    assert!(!engine.has_active_animation());
+}
+
+#[test]
+fn disabled_overscroll_and_non_drag_pans_keep_the_hard_clamp() {
+    let mut engine = build_engine(ElasticOverscrollBehavior::default());
+    engine.pan_start();
+    engine.pan_time_visible_by_pixels(200.0).expect("pan");
+    assert_eq!(engine.time_visible_range().0, 0.0);
+    engine.pan_end();
+
+    let mut engine = build_engine(elastic());
+    engine.pan_time_visible_by_pixels(200.0).expect("pan");
+    assert_eq!(engine.time_visible_range().0, 0.0);
+    assert!(!engine.elastic_overscroll_active());
+}
+
+#[test]
+fn invalid_overscroll_behavior_is_rejected() {
+    let mut engine = build_engine(ElasticOverscrollBehavior::default());
+    for behavior in [
+        ElasticOverscrollBehavior {
+            max_overscroll_px: 0.0,
+            ..elastic()
+        },
+        ElasticOverscrollBehavior {
+            max_overscroll_px: f64::NAN,
+            ..elastic()
+        },
+        ElasticOverscrollBehavior {
+            spring_back_seconds: -1.0,
+            ..elastic()
+        },
+    ] {
+        assert!(engine.set_elastic_overscroll_behavior(behavior).is_err());
+    }
+    assert_eq!(
+        engine.elastic_overscroll_behavior(),
+        ElasticOverscrollBehavior::default()
+    );
+}
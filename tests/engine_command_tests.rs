@@ -0,0 +1,98 @@
+use std::thread;
+
+use chart_rs::api::{
+    ChartEngine, ChartEngineConfig, EngineCommand, EngineHandle, RenderStyle,
+    engine_command_channel,
+};
+use chart_rs::core::{DataPoint, OhlcBar, Viewport};
+use chart_rs::render::{Color, NullRenderer};
+
+const T0: f64 = 1_704_067_200.0;
+
+fn build_engine() -> ChartEngine<NullRenderer> {
+    let config = ChartEngineConfig::new(Viewport::new(800, 400), T0, T0 + 600.0)
+        .with_price_domain(0.0, 100.0);
+    ChartEngine::new(NullRenderer::default(), config).expect("engine")
+}
+
+fn bar(time: f64, close: f64) -> OhlcBar {
+    OhlcBar::new(time, close, close + 1.0, close - 1.0, close).expect("bar")
+}
+
+#[test]
+fn handle_is_send_and_sync() {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<EngineHandle>();
+    assert_send_sync::<EngineCommand>();
+}
+
+#[test]
+fn background_threads_feed_engine_through_queue() {
+    let (handle, mut queue) = engine_command_channel();
+    let mut engine = build_engine();
+
+    let producer = {
+        let handle = handle.clone();
+        thread::spawn(move || {
+            handle
+                .set_candles(
+                    (0..5)
+                        .map(|i| bar(T0 + f64::from(i) * 60.0, 10.0))
+                        .collect(),
+                )
+                .expect("send");
+            handle.append_candle(bar(T0 + 300.0, 11.0)).expect("send");
+            handle.update_candle(bar(T0 + 300.0, 12.0)).expect("send");
+            handle
+                .set_visible_range(T0 + 60.0, T0 + 240.0)
+                .expect("send");
+        })
+    };
+    producer.join().expect("producer");
+    handle
+        .send(EngineCommand::SetData(vec![DataPoint::new(T0, 5.0)]))
+        .expect("send");
+
+    assert!(engine.candles().is_empty());
+    let report = queue.apply_pending(&mut engine);
+    assert_eq!(report.applied, 5);
+    assert!(report.errors.is_empty());
+    assert_eq!(engine.candles().len(), 6);
+    assert_eq!(engine.candles()[5].close, 12.0);
+    assert_eq!(engine.points().len(), 1);
+    assert_eq!(engine.time_visible_range(), (T0 + 60.0, T0 + 240.0));
+
+    let report = queue.apply_pending(&mut engine);
+    assert_eq!(report.applied, 0);
+}
+
+#[test]
+fn rejected_commands_do_not_stop_the_drain() {
+    let (handle, mut queue) = engine_command_channel();
+    let mut engine = build_engine();
+    let style = RenderStyle {
+        background_color: Some(Color::rgb(0.1, 0.2, 0.3)),
+        ..engine.render_style()
+    };
+
+    handle.update_candle(bar(T0 + 60.0, 10.0)).expect("send");
+    handle.update_candle(bar(T0, 9.0)).expect("send");
+    handle.set_visible_range(f64::NAN, T0).expect("send");
+    handle.set_style(style.clone()).expect("send");
+
+    let report = queue.apply_pending(&mut engine);
+    assert_eq!(report.applied, 2);
+    assert_eq!(report.errors.len(), 2);
+    assert_eq!(engine.candles().len(), 1);
+    assert_eq!(
+        engine.render_style().background_color,
+        style.background_color
+    );
+}
+
+#[test]
+fn send_fails_after_queue_is_dropped() {
+    let (handle, queue) = engine_command_channel();
+    drop(queue);
+    assert!(handle.send(EngineCommand::ResetVisibleRange).is_err());
+}
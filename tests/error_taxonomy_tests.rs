@@ -0,0 +1,121 @@
+use chart_rs::ChartError;
+use chart_rs::api::{ChartEngine, ChartEngineConfig, ChartSceneV2, StyledOhlcBar};
+use chart_rs::core::{DataPoint, OhlcBar, PriceScale, TimeScale, Viewport};
+use chart_rs::render::NullRenderer;
+
+fn engine() -> ChartEngine<NullRenderer> {
+    let config = ChartEngineConfig::new(Viewport::new(800, 400), 0.0, 100.0);
+    ChartEngine::new(NullRenderer::default(), config).expect("engine")
+}
+
+#[test]
+fn non_finite_ranges_report_field_and_bounds() {
+    let err = TimeScale::new(f64::NAN, 10.0).expect_err("nan start");
+    match err {
+        ChartError::InvalidRange { field, max, .. } => {
+            assert_eq!(field, "time");
+            assert_eq!(max, 10.0);
+        }
+        other => panic!("unexpected error: {other:?}"),
+    }
+
+    let err = PriceScale::new(5.0, 5.0).expect_err("zero span");
+    assert!(matches!(
+        err,
+        ChartError::InvalidRange { field: "price domain", min, max } if min == 5.0 && max == 5.0
+    ));
+}
+
+#[test]
+fn empty_inputs_report_empty_series() {
+    assert!(matches!(
+        PriceScale::from_data(&[]),
+        Err(ChartError::EmptySeries { .. })
+    ));
+    assert!(matches!(
+        PriceScale::from_ohlc(&[]),
+        Err(ChartError::EmptySeries { .. })
+    ));
+    assert!(matches!(
+        TimeScale::from_data(&[]),
+        Err(ChartError::EmptySeries { .. })
+    ));
+}
+
+#[test]
+fn out_of_order_updates_report_series_index() {
+    let mut engine = engine();
+    engine.set_data(vec![DataPoint::new(10.0, 1.0), DataPoint::new(20.0, 2.0)]);
+    let err = engine
+        .update_point(DataPoint::new(15.0, 3.0))
+        .expect_err("older point");
+    assert!(matches!(err, ChartError::NonMonotonicTime { index: 2 }));
+
+    engine.set_candles(vec![OhlcBar::new(10.0, 1.0, 2.0, 0.5, 1.5).expect("bar")]);
+    let err = engine
+        .update_candle(OhlcBar::new(5.0, 1.0, 2.0, 0.5, 1.5).expect("bar"))
+        .expect_err("older candle");
+    assert!(matches!(err, ChartError::NonMonotonicTime { index: 1 }));
+}
+
+#[test]
+fn out_of_order_appends_report_series_index() {
+    let mut engine = engine();
+    engine.set_data(vec![DataPoint::new(10.0, 1.0), DataPoint::new(20.0, 2.0)]);
+    let err = engine
+        .append_point(DataPoint::new(15.0, 3.0))
+        .expect_err("older point");
+    assert!(matches!(err, ChartError::NonMonotonicTime { index: 2 }));
+
+    engine.set_candles(vec![OhlcBar::new(10.0, 1.0, 2.0, 0.5, 1.5).expect("bar")]);
+    let older = OhlcBar::new(5.0, 1.0, 2.0, 0.5, 1.5).expect("bar");
+    let err = engine.append_candle(older).expect_err("older candle");
+    assert!(matches!(err, ChartError::NonMonotonicTime { index: 1 }));
+    let err = engine
+        .append_styled_candle(StyledOhlcBar::new(older))
+        .expect_err("older styled candle");
+    assert!(matches!(err, ChartError::NonMonotonicTime { index: 1 }));
+    assert_eq!(engine.candles().len(), 1);
+}
+
+#[test]
+fn unknown_schema_versions_report_expected_and_found() {
+    let mut scene = engine().export_scene();
+    scene.schema_version = 7;
+    let json = scene.to_json_pretty().expect("serialize");
+    match ChartSceneV2::from_json_str(&json).expect_err("schema 7") {
+        ChartError::SchemaVersionMismatch {
+            schema,
+            expected,
+            found,
+        } => {
+            assert_eq!(schema, "scene");
+            assert_eq!(expected, 2);
+            assert_eq!(found, 7);
+        }
+        other => panic!("unexpected error: {other:?}"),
+    }
+    assert!(matches!(
+        engine().import_scene(scene),
+        Err(ChartError::SchemaVersionMismatch { found: 7, .. })
+    ));
+}
+
+#[test]
+fn typed_variants_keep_readable_messages() {
+    let err = ChartError::SchemaVersionMismatch {
+        schema: "scene",
+        expected: 2,
+        found: 3,
+    };
+    assert_eq!(
+        err.to_string(),
+        "unsupported scene schema version: found 3, expected 2"
+    );
+    let err = ChartError::InvalidRange {
+        field: "time",
+        min: 1.0,
+        max: f64::INFINITY,
+    };
+    assert_eq!(err.to_string(), "invalid time range: min=1, max=inf");
+}
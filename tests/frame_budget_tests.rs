@@ -0,0 +1,170 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use chart_rs::ChartError;
+use chart_rs::api::{
+    ChartEngine, ChartEngineConfig, ChartWarning, FrameBudget, FramePrimitiveStats,
+};
+use chart_rs::core::{OhlcBar, Viewport};
+use chart_rs::render::NullRenderer;
+
+fn engine_with_candles(count: usize) -> ChartEngine<NullRenderer> {
+    let config = ChartEngineConfig::new(Viewport::new(800, 400), 0.0, count as f64)
+        .with_price_domain(0.0, 200.0);
+    let mut engine = ChartEngine::new(NullRenderer::default(), config).expect("engine");
+    let candles = (0..count)
+        .map(|index| {
+            let base = 100.0 + (index as f64 * 0.37).sin() * 40.0;
+            OhlcBar::new(index as f64, base, base + 5.0, base - 5.0, base + 1.0).expect("bar")
+        })
+        .collect();
+    engine.set_candles(candles);
+    engine
+}
+
+fn collect_warnings(engine: &mut ChartEngine<NullRenderer>) -> Rc<RefCell<Vec<ChartWarning>>> {
+    let warnings = Rc::new(RefCell::new(Vec::new()));
+    let sink = Rc::clone(&warnings);
+    engine.set_warning_sink(move |warning| sink.borrow_mut().push(*warning));
+    warnings
+}
+
+fn budget_warnings(warnings: &[ChartWarning]) -> Vec<ChartWarning> {
+    warnings
+        .iter()
+        .copied()
+        .filter(|warning| matches!(warning, ChartWarning::FrameBudgetExceeded { .. }))
+        .collect()
+}
+
+#[test]
+fn last_frame_stats_match_built_frame_without_budget() {
+    let mut engine = engine_with_candles(50);
+    let warnings = collect_warnings(&mut engine);
+    assert_eq!(engine.last_frame_stats(), None);
+
+    let frame = engine.build_render_frame().expect("frame");
+    assert_eq!(
+        engine.last_frame_stats(),
+        Some(FramePrimitiveStats::from_frame(&frame))
+    );
+    assert_eq!(frame.rects.len(), 50);
+    assert!(budget_warnings(&warnings.borrow()).is_empty());
+}
+
+#[test]
+fn rect_budget_merges_candles_and_reports_reduction() {
+    let mut engine = engine_with_candles(4000);
+    let warnings = collect_warnings(&mut engine);
+    engine
+        .set_frame_budget(Some(FrameBudget::default().with_max_rects(600)))
+        .expect("budget");
+
+    let frame = engine.build_render_frame().expect("frame");
+    assert!(frame.rects.len() <= 600);
+
+    let reported = budget_warnings(&warnings.borrow());
+    let [
+        ChartWarning::FrameBudgetExceeded {
+            requested,
+            rendered,
+            reduction_level,
+        },
+    ] = reported.as_slice()
+    else {
+        panic!("expected one budget warning, got {reported:?}");
+    };
+    assert!(requested.rects >= 4000);
+    assert_eq!(*rendered, FramePrimitiveStats::from_frame(&frame));
+    assert_eq!(*reduction_level, 3);
+    assert_eq!(engine.last_frame_stats(), Some(*rendered));
+}
+
+#[test]
+fn text_budget_thins_axis_ticks() {
+    let mut engine = engine_with_candles(50);
+    let unbounded = engine.build_render_frame().expect("frame").texts.len();
+
+    let max_texts = unbounded / 2;
+    engine
+        .set_frame_budget(Some(FrameBudget::default().with_max_texts(max_texts)))
+        .expect("budget");
+    let frame = engine.build_render_frame().expect("frame");
+    assert!(frame.texts.len() <= max_texts);
+    assert_eq!(frame.rects.len(), 50);
+}
+
+#[test]
+fn unreachable_budget_stops_at_strongest_reduction() {
+    let mut engine = engine_with_candles(200);
+    let warnings = collect_warnings(&mut engine);
+    engine
+        .set_frame_budget(Some(FrameBudget::default().with_max_lines(1)))
+        .expect("budget");
+
+    let frame = engine.build_render_frame().expect("frame");
+    assert!(frame.lines.len() > 1);
+    assert!(matches!(
+        budget_warnings(&warnings.borrow()).as_slice(),
+        [ChartWarning::FrameBudgetExceeded {
+            reduction_level: 6,
+            ..
+        }]
+    ));
+}
+
+#[test]
+fn frame_budget_round_trips_through_config_and_rejects_zero_caps() {
+    let budget = FrameBudget::default()
+        .with_max_lines(500)
+        .with_max_texts(40);
+    let config =
+        ChartEngineConfig::new(Viewport::new(800, 400), 0.0, 10.0).with_frame_budget(budget);
+    let restored =
+        ChartEngineConfig::from_json_str(&config.to_json_pretty().expect("json")).expect("parse");
+    assert_eq!(restored.frame_budget, Some(budget));
+
+    let mut engine = ChartEngine::new(NullRenderer::default(), restored).expect("engine");
+    assert_eq!(engine.frame_budget(), Some(budget));
+
+    let err = engine
+        .set_frame_budget(Some(FrameBudget::default().with_max_texts(0)))
+        .expect_err("zero cap must fail");
+    assert!(matches!(err, ChartError::InvalidData(_)));
+    engine.set_frame_budget(None).expect("clear budget");
+    assert_eq!(engine.frame_budget(), None);
+}
+
+#[test]
+fn sustained_over_budget_frames_reuse_the_reduction_and_warn_once() {
+    let mut engine = engine_with_candles(4000);
+    let warnings = collect_warnings(&mut engine);
+    engine
+        .set_frame_budget(Some(FrameBudget::default().with_max_rects(600)))
+        .expect("budget");
+
+    let first = engine.build_render_frame().expect("frame");
+    for _ in 0..5 {
+        let frame = engine.build_render_frame().expect("frame");
+        assert_eq!(frame.rects.len(), first.rects.len());
+    }
+    assert_eq!(budget_warnings(&warnings.borrow()).len(), 1);
+}
+
+#[test]
+fn remembered_reduction_relaxes_once_the_load_drops() {
+    let mut engine = engine_with_candles(4000);
+    engine
+        .set_frame_budget(Some(FrameBudget::default().with_max_rects(600)))
+        .expect("budget");
+    engine.build_render_frame().expect("frame");
+
+    engine.set_time_visible_range(0.0, 100.0).expect("range");
+    let mut rects = Vec::new();
+    for _ in 0..4 {
+        rects.push(engine.build_render_frame().expect("frame").rects.len());
+    }
+    assert!(rects.windows(2).all(|pair| pair[0] <= pair[1]), "{rects:?}");
+    assert!(*rects.last().expect("rects") >= 100);
+    assert!(rects.iter().all(|&count| count <= 600));
+}
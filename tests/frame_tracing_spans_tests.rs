@@ -0,0 +1,177 @@
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+use chart_rs::api::{ChartEngine, ChartEngineConfig};
+use chart_rs::core::{DataPoint, Viewport};
+use chart_rs::render::NullRenderer;
+use chart_rs::telemetry::{
+    FRAME_AXIS_SPAN, FRAME_CROSSHAIR_SPAN, FRAME_LABELS_SPAN, FRAME_LAYOUT_SPAN,
+    FRAME_OVERLAYS_SPAN, FRAME_PROJECTION_SPAN, FRAME_SERIES_SPAN, FRAME_SPAN, FRAME_TICKS_SPAN,
+    FRAME_VALIDATION_SPAN, FRAME_WINDOWING_SPAN,
+};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Metadata, Subscriber};
+
+#[derive(Debug, Clone)]
+struct RecordedSpan {
+    name: &'static str,
+    parent: Option<u64>,
+    fields: HashMap<String, String>,
+}
+
+#[derive(Default)]
+struct SpanRecorderState {
+    spans: Vec<RecordedSpan>,
+    stack: Vec<u64>,
+}
+
+#[derive(Default, Clone)]
+struct SpanRecorder {
+    state: Arc<Mutex<SpanRecorderState>>,
+    next_id: Arc<AtomicU64>,
+}
+
+struct FieldVisitor<'a>(&'a mut HashMap<String, String>);
+
+impl Visit for FieldVisitor<'_> {
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        self.0.insert(
+            field.name().to_owned(),
+            format!("{value:?}").replace('"', ""),
+        );
+    }
+}
+
+impl Subscriber for SpanRecorder {
+    fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, attrs: &Attributes<'_>) -> Id {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed) + 1;
+        let mut state = self.state.lock().expect("recorder");
+        let parent = attrs
+            .parent()
+            .map(Id::into_u64)
+            .or_else(|| state.stack.last().copied());
+        let mut fields = HashMap::new();
+        attrs.record(&mut FieldVisitor(&mut fields));
+        state.spans.push(RecordedSpan {
+            name: attrs.metadata().name(),
+            parent,
+            fields,
+        });
+        Id::from_u64(id)
+    }
+
+    fn record(&self, span: &Id, values: &Record<'_>) {
+        let mut state = self.state.lock().expect("recorder");
+        let index = span.into_u64() as usize - 1;
+        values.record(&mut FieldVisitor(&mut state.spans[index].fields));
+    }
+
+    fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+    fn event(&self, _event: &Event<'_>) {}
+
+    fn enter(&self, span: &Id) {
+        self.state
+            .lock()
+            .expect("recorder")
+            .stack
+            .push(span.into_u64());
+    }
+
+    fn exit(&self, _span: &Id) {
+        self.state.lock().expect("recorder").stack.pop();
+    }
+}
+
+fn record_frame_build() -> (Vec<RecordedSpan>, chart_rs::render::RenderFrame) {
+    let config =
+        ChartEngineConfig::new(Viewport::new(800, 400), 0.0, 100.0).with_price_domain(0.0, 100.0);
+    let mut engine = ChartEngine::new(NullRenderer::default(), config).expect("engine");
+    engine.set_data(
+        (0..=100)
+            .map(|i| DataPoint::new(f64::from(i), 50.0 + f64::from(i % 7)))
+            .collect(),
+    );
+    let recorder = SpanRecorder::default();
+    let frame = tracing::subscriber::with_default(recorder.clone(), || {
+        engine.build_render_frame().expect("frame")
+    });
+    let spans = recorder.state.lock().expect("recorder").spans.clone();
+    (spans, frame)
+}
+
+fn field(span: &RecordedSpan, name: &str) -> String {
+    span.fields.get(name).cloned().unwrap_or_default()
+}
+
+#[test]
+fn frame_span_wraps_ordered_phase_spans() {
+    let (spans, frame) = record_frame_build();
+    let frame_index = spans
+        .iter()
+        .position(|span| span.name == FRAME_SPAN)
+        .expect("frame span");
+    let frame_id = frame_index as u64 + 1;
+    let phases: Vec<&str> = spans
+        .iter()
+        .filter(|span| span.parent == Some(frame_id))
+        .map(|span| span.name)
+        .collect();
+    assert_eq!(
+        phases,
+        [
+            FRAME_LAYOUT_SPAN,
+            FRAME_SERIES_SPAN,
+            FRAME_AXIS_SPAN,
+            FRAME_OVERLAYS_SPAN,
+            FRAME_CROSSHAIR_SPAN,
+            FRAME_OVERLAYS_SPAN,
+            FRAME_VALIDATION_SPAN,
+        ]
+    );
+
+    let frame_span = &spans[frame_index];
+    assert_eq!(field(frame_span, "lines"), frame.lines.len().to_string());
+    assert_eq!(field(frame_span, "texts"), frame.texts.len().to_string());
+    assert_eq!(field(frame_span, "rects"), frame.rects.len().to_string());
+    assert_eq!(field(frame_span, "reduction_level"), "0");
+}
+
+#[test]
+fn series_and_axis_phases_record_counters() {
+    let (spans, frame) = record_frame_build();
+    let windowing = spans
+        .iter()
+        .find(|span| span.name == FRAME_WINDOWING_SPAN && field(span, "series") == "line")
+        .expect("line windowing span");
+    assert_eq!(field(windowing, "samples"), "101");
+    let projection = spans
+        .iter()
+        .find(|span| span.name == FRAME_PROJECTION_SPAN && field(span, "series") == "line")
+        .expect("line projection span");
+    assert_eq!(field(projection, "samples"), "101");
+
+    let mut axis_labels = 0;
+    for axis in ["time", "price"] {
+        let ticks = spans
+            .iter()
+            .find(|span| span.name == FRAME_TICKS_SPAN && field(span, "axis") == axis)
+            .expect("ticks span");
+        assert!(field(ticks, "ticks").parse::<usize>().expect("ticks") > 0);
+        let labels = spans
+            .iter()
+            .find(|span| span.name == FRAME_LABELS_SPAN && field(span, "axis") == axis)
+            .expect("labels span");
+        let count = field(labels, "labels").parse::<usize>().expect("labels");
+        assert!(count > 0, "{axis}");
+        axis_labels += count;
+    }
+    assert!(axis_labels <= frame.texts.len());
+}
@@ -0,0 +1,147 @@
+use chart_rs::api::{
+    ChartEngine, ChartEngineConfig, HighLowMarkerBehavior, RenderStyle, VisibleHighLow,
+};
+use chart_rs::core::{OhlcBar, Viewport};
+use chart_rs::render::{CanvasLayerKind, NullRenderer};
+
+fn bar(time: f64, high: f64, low: f64) -> OhlcBar {
+    OhlcBar::new(time, low + 1.0, high, low, high - 1.0).expect("bar")
+}
+
+fn engine() -> ChartEngine<NullRenderer> {
+    let config = ChartEngineConfig::new(Viewport::new(800, 400), 0.0, 100.0)
+        .with_price_domain(0.0, 100.0)
+        .with_high_low_marker_behavior(HighLowMarkerBehavior {
+            visible: true,
+            ..HighLowMarkerBehavior::default()
+        });
+    let mut engine = ChartEngine::new(NullRenderer::default(), config).expect("engine");
+    engine
+        .set_render_style(RenderStyle {
+            show_last_price_label: false,
+            ..engine.render_style()
+        })
+        .expect("style");
+    engine.set_candles(vec![
+        bar(10.0, 40.0, 30.0),
+        bar(30.0, 70.0, 35.0),
+        bar(50.0, 45.0, 20.0),
+        bar(70.0, 70.0, 25.0),
+        bar(90.0, 90.0, 40.0),
+    ]);
+    engine
+}
+
+fn overlay_texts(engine: &ChartEngine<NullRenderer>) -> Vec<String> {
+    let layered = engine.build_layered_render_frame().expect("layered");
+    layered.panes[0]
+        .layers
+        .iter()
+        .filter(|layer| layer.kind == CanvasLayerKind::Overlay)
+        .flat_map(|layer| &layer.texts)
+        .map(|text| text.text.to_string())
+        .collect()
+}
+
+#[test]
+fn extremes_follow_the_visible_range_and_data_updates() {
+    let mut engine = engine();
+    assert_eq!(
+        engine.visible_high_low(),
+        Some(VisibleHighLow {
+            high: 90.0,
+            high_time: 90.0,
+            low: 20.0,
+            low_time: 50.0,
+        })
+    );
+
+    engine.set_time_visible_range(0.0, 80.0).expect("range");
+    let extremes = engine.visible_high_low().expect("extremes");
+    // Ties keep the earliest bar.
+    assert_eq!((extremes.high, extremes.high_time), (70.0, 30.0));
+
+    engine.set_time_visible_range(0.0, 100.0).expect("range");
+    engine.update_candle(bar(90.0, 60.0, 10.0)).expect("update");
+    let extremes = engine.visible_high_low().expect("extremes");
+    assert_eq!((extremes.high, extremes.high_time), (70.0, 30.0));
+    assert_eq!((extremes.low, extremes.low_time), (10.0, 90.0));
+}
+
+#[test]
+fn labels_show_price_and_time_in_the_plot() {
+    let engine = engine();
+    let texts = overlay_texts(&engine);
+    assert_eq!(texts.len(), 2);
+    assert!(texts[0].starts_with("90.00 (") && texts[0].ends_with(')'));
+    assert!(texts[1].starts_with("20.00 ("));
+
+    let mut hidden = self::engine();
+    hidden
+        .set_high_low_marker_behavior(HighLowMarkerBehavior::default())
+        .expect("behavior");
+    assert!(overlay_texts(&hidden).is_empty());
+}
+
+#[test]
+fn axis_labels_join_the_last_value_labels_and_push_ticks_aside() {
+    let engine = engine();
+    let plot_right = 800.0 - engine.resolved_price_axis_width_px();
+    let frame = engine.build_render_frame().expect("frame");
+    let axis_texts: Vec<_> = frame
+        .texts
+        .iter()
+        .filter(|text| text.x > plot_right)
+        .collect();
+    let high_py = engine.map_price_to_pixel(90.0).expect("py");
+    assert!(axis_texts.iter().any(|text| &*text.text == "90.00"));
+    assert!(axis_texts.iter().any(|text| &*text.text == "20.00"));
+    assert!(
+        axis_texts
+            .iter()
+            .filter(|text| &*text.text != "90.00" && &*text.text != "20.00")
+            .all(|text| (text.y - high_py).abs() > 6.0)
+    );
+
+    let mut without_axis = self::engine();
+    without_axis
+        .set_high_low_marker_behavior(HighLowMarkerBehavior {
+            visible: true,
+            show_axis_labels: false,
+            ..HighLowMarkerBehavior::default()
+        })
+        .expect("behavior");
+    let frame = without_axis.build_render_frame().expect("frame");
+    assert!(
+        frame
+            .texts
+            .iter()
+            .filter(|text| text.x > plot_right)
+            .all(|text| &*text.text != "20.00")
+    );
+}
+
+#[test]
+fn invalid_behavior_is_rejected_and_scene_round_trips() {
+    let mut engine = engine();
+    assert!(
+        engine
+            .set_high_low_marker_behavior(HighLowMarkerBehavior {
+                font_size_px: 0.0,
+                ..HighLowMarkerBehavior::default()
+            })
+            .is_err()
+    );
+    let behavior = HighLowMarkerBehavior {
+        visible: true,
+        offset_px: 8.0,
+        ..HighLowMarkerBehavior::default()
+    };
+    engine
+        .set_high_low_marker_behavior(behavior)
+        .expect("behavior");
+    let json = engine.export_scene_json_pretty().expect("json");
+    let mut restored = self::engine();
+    restored.import_scene_json_str(&json).expect("import");
+    assert_eq!(restored.high_low_marker_behavior(), behavior);
+}
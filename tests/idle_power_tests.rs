@@ -0,0 +1,142 @@
+use std::time::Duration;
+
+use chart_rs::api::{
+    ChartEngine, ChartEngineConfig, EngineCommand, IdlePowerBehavior, PointerInput,
+    PointerInputBridge, engine_command_channel,
+};
+use chart_rs::core::{DataPoint, Viewport};
+use chart_rs::render::NullRenderer;
+
+fn idle_after(seconds: f64) -> IdlePowerBehavior {
+    IdlePowerBehavior {
+        idle_after_seconds: Some(seconds),
+        ..IdlePowerBehavior::default()
+    }
+}
+
+fn engine_with(behavior: IdlePowerBehavior) -> ChartEngine<NullRenderer> {
+    let config = ChartEngineConfig::new(Viewport::new(800, 400), 0.0, 100.0)
+        .with_idle_power_behavior(behavior);
+    let mut engine = ChartEngine::new(NullRenderer::default(), config).expect("engine");
+    engine.set_data(
+        (0..=100)
+            .map(|i| DataPoint::new(f64::from(i), f64::from(i % 9)))
+            .collect(),
+    );
+    engine.render().expect("render");
+    engine
+}
+
+fn secs(seconds: f64) -> Duration {
+    Duration::from_secs_f64(seconds)
+}
+
+#[test]
+fn goes_idle_after_quiet_period_and_resumes_on_interaction() {
+    let mut engine = engine_with(idle_after(5.0));
+    engine.power_tick(secs(0.0));
+    engine.power_tick(secs(4.9));
+    assert!(!engine.is_power_idle());
+
+    // Activity restarts the quiet period.
+    engine.pointer_move(100.0, 100.0);
+    engine.power_tick(secs(5.0));
+    engine.power_tick(secs(9.9));
+    assert!(!engine.is_power_idle());
+    engine.power_tick(secs(10.0));
+    assert!(engine.is_power_idle());
+
+    let mut bridge = PointerInputBridge::default();
+    bridge
+        .handle(&mut engine, PointerInput::Moved { x: 50.0, y: 50.0 })
+        .expect("input");
+    assert!(!engine.is_power_idle());
+}
+
+#[test]
+fn idle_batches_queued_data_commands_once_per_interval() {
+    let mut engine = engine_with(idle_after(1.0));
+    let (handle, mut queue) = engine_command_channel();
+    engine.power_tick(secs(0.0));
+
+    // Streaming updates count as activity while awake.
+    handle
+        .send(EngineCommand::AppendPoint(DataPoint::new(101.0, 1.0)))
+        .expect("send");
+    assert_eq!(queue.apply_pending(&mut engine).applied, 1);
+    engine.power_tick(secs(0.5));
+    engine.power_tick(secs(1.4));
+    assert!(!engine.is_power_idle());
+    engine.power_tick(secs(1.5));
+    assert!(engine.is_power_idle());
+
+    for i in 102..105 {
+        handle
+            .send(EngineCommand::AppendPoint(DataPoint::new(
+                f64::from(i),
+                1.0,
+            )))
+            .expect("send");
+    }
+    let report = queue.apply_pending(&mut engine);
+    assert_eq!((report.applied, report.deferred), (0, 3));
+    assert_eq!(engine.deferred_command_count(), 3);
+    assert_eq!(engine.points().len(), 102);
+
+    assert_eq!(engine.power_tick(secs(2.0)).applied, 0);
+    assert_eq!(engine.power_tick(secs(2.5)).applied, 3);
+    assert_eq!(engine.points().len(), 105);
+    // Batched data does not wake the engine.
+    assert!(engine.is_power_idle());
+
+    handle
+        .send(EngineCommand::AppendPoint(DataPoint::new(105.0, 1.0)))
+        .expect("send");
+    assert_eq!(queue.apply_pending(&mut engine).deferred, 1);
+    engine.pan_start();
+    assert!(!engine.is_power_idle());
+    assert_eq!(engine.deferred_command_count(), 0);
+    assert_eq!(engine.points().len(), 106);
+}
+
+#[test]
+fn idle_caps_redraws_to_the_batch_interval() {
+    let mut engine = engine_with(IdlePowerBehavior {
+        idle_after_seconds: Some(1.0),
+        batch_interval_seconds: 2.0,
+    });
+    engine.power_tick(secs(0.0));
+    engine.power_tick(secs(1.0));
+    assert!(engine.is_power_idle());
+
+    engine.set_time_visible_range(10.0, 60.0).expect("range");
+    assert!(engine.redraw_due(secs(1.0)));
+    engine.render().expect("render");
+    engine.set_time_visible_range(20.0, 70.0).expect("range");
+    assert!(!engine.redraw_due(secs(1.5)));
+    assert!(engine.redraw_due(secs(3.0)));
+}
+
+#[test]
+fn disabling_resumes_and_invalid_behavior_is_rejected() {
+    let mut engine = engine_with(idle_after(1.0));
+    engine.power_tick(secs(0.0));
+    engine.power_tick(secs(2.0));
+    assert!(engine.is_power_idle());
+    engine
+        .set_idle_power_behavior(IdlePowerBehavior::default())
+        .expect("behavior");
+    assert!(!engine.is_power_idle());
+
+    for behavior in [
+        idle_after(0.0),
+        idle_after(f64::NAN),
+        IdlePowerBehavior {
+            batch_interval_seconds: 0.0,
+            ..IdlePowerBehavior::default()
+        },
+    ] {
+        assert!(engine.set_idle_power_behavior(behavior).is_err());
+    }
+    assert_eq!(engine.idle_power_behavior(), IdlePowerBehavior::default());
+}
@@ -0,0 +1,187 @@
+use std::time::Duration;
+
+use chart_rs::api::{ChartEngine, ChartEngineConfig, InteractionRecordingOptions};
+use chart_rs::core::{DataPoint, Viewport};
+use chart_rs::interaction::CrosshairMode;
+use chart_rs::render::NullRenderer;
+use chart_rs::testing::trace::{InteractionAction, InteractionTrace, replay};
+
+fn engine_with_data() -> ChartEngine<NullRenderer> {
+    let config =
+        ChartEngineConfig::new(Viewport::new(900, 500), 0.0, 200.0).with_price_domain(0.0, 100.0);
+    let mut engine = ChartEngine::new(NullRenderer::default(), config).expect("engine");
+    engine.set_data(
+        (0..=40)
+            .map(|i| DataPoint::new(f64::from(i) * 5.0, 40.0 + f64::from(i % 7)))
+            .collect(),
+    );
+    engine
+}
+
+fn perform_session(engine: &mut ChartEngine<NullRenderer>) {
+    engine.set_crosshair_mode(CrosshairMode::Normal);
+    engine.pointer_move(200.0, 120.0);
+    engine
+        .wheel_zoom_time_visible(-240.0, 450.0, 0.2, 1e-6)
+        .expect("wheel zoom");
+    engine.pan_start();
+    engine.pan_time_visible_by_pixels(35.0).expect("pan");
+    engine.pan_end();
+    engine
+        .axis_drag_scale_time(60.0, 300.0, 0.2, 1e-6)
+        .expect("time axis drag");
+    engine
+        .axis_drag_pan_price(25.0, 200.0)
+        .expect("price axis pan");
+    engine
+        .axis_drag_scale_price(-120.0, 200.0, 0.2, 1e-6)
+        .expect("price axis scale");
+    engine.frame_clock_tick(Duration::from_millis(1_250));
+    engine.pointer_move(410.0, 260.0);
+    engine.start_kinetic_pan(12.0).expect("kinetic start");
+    engine.step_kinetic_pan(0.016).expect("kinetic step");
+    engine.pointer_leave();
+}
+
+#[test]
+fn interactions_are_not_recorded_unless_enabled() {
+    let mut engine = engine_with_data();
+    perform_session(&mut engine);
+    assert!(!engine.is_recording_interactions());
+    assert!(engine.stop_interaction_recording().is_none());
+}
+
+#[test]
+fn recording_captures_gestures_with_timestamps_in_order() {
+    let mut engine = engine_with_data();
+    engine.frame_clock_tick(Duration::from_millis(1_000));
+    engine.start_interaction_recording(InteractionRecordingOptions::default());
+    perform_session(&mut engine);
+    let trace = engine.stop_interaction_recording().expect("recorded trace");
+    assert!(!engine.is_recording_interactions());
+
+    let steps = &trace.scenarios[0].steps;
+    assert_eq!(trace.scenarios[0].points.len(), 41);
+    assert!(matches!(
+        steps[1].action,
+        InteractionAction::SetTimeVisibleRange { .. }
+    ));
+    let actions: Vec<_> = steps.iter().skip(2).map(|step| step.action).collect();
+    assert_eq!(actions.len(), 13);
+    assert!(
+        matches!(actions[1], InteractionAction::PointerMove { x, y } if x == 200.0 && y == 120.0)
+    );
+    assert!(matches!(actions[2], InteractionAction::WheelZoom { .. }));
+    assert_eq!(actions[3], InteractionAction::PanStart);
+    assert_eq!(
+        actions[4],
+        InteractionAction::PanByPixels { delta_px: 35.0 }
+    );
+    assert!(matches!(
+        actions[6],
+        InteractionAction::AxisDragScaleTime { .. }
+    ));
+    assert!(matches!(
+        actions[7],
+        InteractionAction::AxisDragPanPrice { .. }
+    ));
+    assert!(matches!(
+        actions[8],
+        InteractionAction::AxisDragScalePrice { .. }
+    ));
+    assert_eq!(actions[12], InteractionAction::PointerLeave);
+
+    let stamps: Vec<f64> = steps
+        .iter()
+        .map(|step| step.elapsed_ms.expect("timestamp"))
+        .collect();
+    assert!(stamps.windows(2).all(|pair| pair[0] <= pair[1]));
+    // Stamps follow the host frame clock, not the system clock.
+    assert_eq!(stamps[0], 0.0);
+    assert_eq!(stamps.last().copied(), Some(250.0));
+    assert!(steps.iter().all(|step| step.expect.is_some()));
+}
+
+#[test]
+fn steps_are_unstamped_until_the_frame_clock_runs() {
+    let mut engine = engine_with_data();
+    engine.start_interaction_recording(InteractionRecordingOptions::default());
+    engine.pointer_move(200.0, 120.0);
+    engine.frame_clock_tick(Duration::from_millis(500));
+    engine.pointer_move(210.0, 120.0);
+    engine.frame_clock_tick(Duration::from_millis(530));
+    engine.pointer_leave();
+
+    let trace = engine.stop_interaction_recording().expect("recorded trace");
+    let stamps: Vec<_> = trace.scenarios[0]
+        .steps
+        .iter()
+        .map(|step| step.elapsed_ms)
+        .collect();
+    assert_eq!(stamps, [None, None, None, Some(0.0), Some(30.0)]);
+}
+
+#[test]
+fn rejected_calls_are_not_recorded() {
+    let mut engine = engine_with_data();
+    engine.start_interaction_recording(
+        InteractionRecordingOptions::default().with_record_expectations(false),
+    );
+    assert!(
+        engine
+            .pinch_zoom_time_visible(f64::NAN, 10.0, 1e-6)
+            .is_err()
+    );
+    engine.pointer_move(10.0, 10.0);
+
+    let trace = engine.recorded_interaction_trace().expect("recording");
+    let steps = &trace.scenarios[0].steps;
+    assert_eq!(steps.len(), 3);
+    assert!(matches!(
+        steps[2].action,
+        InteractionAction::PointerMove { .. }
+    ));
+    assert!(steps.iter().all(|step| step.expect.is_none()));
+}
+
+#[test]
+fn recorded_trace_replays_from_json() {
+    let mut engine = engine_with_data();
+    engine.start_interaction_recording(
+        InteractionRecordingOptions::default().with_trace_name("bug-report"),
+    );
+    perform_session(&mut engine);
+    let json = engine
+        .stop_interaction_recording()
+        .expect("recorded trace")
+        .to_json_pretty()
+        .expect("serialize");
+
+    let trace = InteractionTrace::from_json_str(&json).expect("parse");
+    assert_eq!(trace.trace_name, "bug-report");
+    let report = trace.replay().expect("replay");
+    assert!(report.is_match(), "{:#?}", report.mismatches);
+}
+
+#[test]
+fn recorded_trace_replays_on_scene_restored_engine() {
+    let mut engine = engine_with_data();
+    engine
+        .wheel_zoom_time_visible(-120.0, 100.0, 0.2, 1e-6)
+        .expect("pre-recording zoom");
+    let scene = engine.export_scene();
+    engine.start_interaction_recording(InteractionRecordingOptions::default());
+    perform_session(&mut engine);
+    let trace = engine.stop_interaction_recording().expect("recorded trace");
+
+    let mut restored = ChartEngine::new(
+        NullRenderer::default(),
+        ChartEngineConfig::new(Viewport::new(10, 10), 0.0, 1.0),
+    )
+    .expect("engine");
+    restored.import_scene(scene).expect("import scene");
+    let report = replay(&mut restored, &trace.scenarios[0], trace.tolerance).expect("replay");
+    assert!(report.is_match(), "{:#?}", report.mismatches);
+    assert_eq!(restored.time_visible_range(), engine.time_visible_range());
+    assert_eq!(restored.price_domain(), engine.price_domain());
+}
@@ -0,0 +1,118 @@
+use chart_rs::api::{ChartEngine, ChartEngineConfig};
+use chart_rs::core::{DataPoint, Viewport};
+use chart_rs::interaction::{
+    CrosshairMode, CrosshairState, InteractionMode, InteractionState, KineticPanConfig,
+    KineticPanState,
+};
+use chart_rs::render::NullRenderer;
+
+fn engine() -> ChartEngine<NullRenderer> {
+    let config =
+        ChartEngineConfig::new(Viewport::new(1000, 500), 0.0, 10.0).with_price_domain(0.0, 100.0);
+    let mut engine = ChartEngine::new(NullRenderer::default(), config).expect("engine init");
+    engine.set_data(vec![DataPoint::new(2.0, 20.0), DataPoint::new(8.0, 80.0)]);
+    engine
+}
+
+fn customized_engine() -> ChartEngine<NullRenderer> {
+    let mut engine = engine();
+    engine.set_crosshair_mode(CrosshairMode::Normal);
+    engine
+        .set_kinetic_pan_config(KineticPanConfig {
+            decay_per_second: 0.5,
+            stop_velocity_abs: 0.25,
+        })
+        .expect("kinetic config");
+    engine.pointer_move(420.0, 180.0);
+    engine.set_crosshair_time(Some(8.0)).expect("pin crosshair");
+    engine
+}
+
+#[test]
+fn interaction_state_json_round_trip_restores_session_settings() {
+    let saved = customized_engine();
+    let json = serde_json::to_string(&saved.interaction_state()).expect("serialize");
+
+    let mut restored = engine();
+    let state: InteractionState = serde_json::from_str(&json).expect("deserialize");
+    restored
+        .set_interaction_state(state, true)
+        .expect("restore state");
+
+    assert_eq!(restored.interaction_state(), saved.interaction_state());
+    assert_eq!(restored.crosshair_mode(), CrosshairMode::Normal);
+    assert_eq!(restored.kinetic_pan_config(), saved.kinetic_pan_config());
+    assert_eq!(restored.crosshair_state(), saved.crosshair_state());
+    assert_eq!(restored.crosshair_time(), Some(8.0));
+}
+
+#[test]
+fn interaction_state_restore_can_keep_current_pointer_position() {
+    let saved = customized_engine();
+    let mut restored = engine();
+    restored.pointer_move(100.0, 50.0);
+    restored.pointer_leave();
+
+    restored
+        .set_interaction_state(saved.interaction_state(), false)
+        .expect("restore state");
+
+    assert_eq!(restored.interaction_state().cursor(), (100.0, 50.0));
+    assert!(!restored.crosshair_state().visible);
+    assert_eq!(restored.crosshair_time(), None);
+    assert_eq!(restored.crosshair_mode(), CrosshairMode::Normal);
+}
+
+#[test]
+fn interaction_state_restore_does_not_resume_pan_or_kinetic_coast() {
+    let mut saved = customized_engine();
+    saved.pan_start();
+    saved.start_kinetic_pan(4.0).expect("kinetic pan");
+    assert_eq!(saved.interaction_mode(), InteractionMode::Panning);
+
+    let mut restored = engine();
+    restored
+        .set_interaction_state(saved.interaction_state(), true)
+        .expect("restore state");
+
+    assert_eq!(restored.interaction_mode(), InteractionMode::Idle);
+    assert_eq!(restored.kinetic_pan_state(), KineticPanState::default());
+    assert!(!restored.step_kinetic_pan(0.5).expect("step"));
+    assert_eq!(restored.kinetic_pan_config(), saved.kinetic_pan_config());
+}
+
+#[test]
+fn interaction_state_without_pointer_position_hides_the_crosshair() {
+    let saved = customized_engine().interaction_state();
+    let stripped = saved.without_pointer_position();
+
+    assert_eq!(stripped.cursor(), (0.0, 0.0));
+    assert!(saved.crosshair().visible);
+    assert_eq!(stripped.crosshair(), CrosshairState::default());
+    assert_eq!(stripped.crosshair_mode(), saved.crosshair_mode());
+    assert_eq!(stripped.kinetic_pan_config(), saved.kinetic_pan_config());
+}
+
+#[test]
+fn interaction_state_deserializes_missing_fields_as_defaults() {
+    let state: InteractionState =
+        serde_json::from_str(r#"{"crosshair_mode":"Hidden"}"#).expect("deserialize");
+
+    assert_eq!(state.crosshair_mode(), CrosshairMode::Hidden);
+    assert_eq!(
+        state.kinetic_pan_config(),
+        InteractionState::default().kinetic_pan_config()
+    );
+}
+
+#[test]
+fn interaction_state_restore_rejects_invalid_kinetic_config() {
+    let json = serde_json::to_string(&engine().interaction_state())
+        .expect("serialize")
+        .replace(r#""stop_velocity_abs":"#, r#""stop_velocity_abs":-"#);
+    let state: InteractionState = serde_json::from_str(&json).expect("deserialize");
+
+    let mut engine = engine();
+    assert!(engine.set_interaction_state(state, true).is_err());
+    assert_eq!(engine.interaction_state(), InteractionState::default());
+}
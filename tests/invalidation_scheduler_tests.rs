@@ -1,7 +1,7 @@
 use chart_rs::api::{
-    ChartEngine, ChartEngineConfig, InvalidationLevel, InvalidationTopic, RenderStyle,
-    TimeScaleNavigationBehavior, TimeScaleResizeAnchor, TimeScaleResizeBehavior,
-    TimeScaleZoomLimitBehavior,
+    ChartEngine, ChartEngineConfig, GridLineOptions, GridOptions, InvalidationLevel,
+    InvalidationTopic, RenderStyle, TimeScaleNavigationBehavior, TimeScaleResizeAnchor,
+    TimeScaleResizeBehavior, TimeScaleZoomLimitBehavior,
 };
 use chart_rs::core::{DataPoint, TimeScaleTuning, Viewport};
 use chart_rs::lwc::model::TimeScaleInvalidationType;
@@ -45,7 +45,9 @@ fn pointer_move_sets_cursor_and_data_update_escalates_to_full() {
     );
     assert!(engine.has_pending_invalidation_topic(InvalidationTopic::Cursor));
 
-    engine.append_point(DataPoint::new(1.0, 2.0));
+    engine
+        .append_point(DataPoint::new(1.0, 2.0))
+        .expect("append");
     assert_eq!(engine.pending_invalidation_level(), InvalidationLevel::Full);
     assert!(engine.has_pending_invalidation_topic(InvalidationTopic::Series));
     assert!(!engine.has_pending_invalidation_topic(InvalidationTopic::TimeScale));
@@ -158,7 +160,13 @@ fn set_render_style_non_layout_change_triggers_light_invalidation() {
     engine.clear_pending_invalidation();
 
     let changed_style = RenderStyle {
-        grid_line_color: Color::rgb(0.13, 0.2, 0.27),
+        grid: GridOptions {
+            vert_lines: GridLineOptions {
+                color: Color::rgb(0.13, 0.2, 0.27),
+                ..engine.render_style().grid.vert_lines
+            },
+            ..engine.render_style().grid
+        },
         ..engine.render_style()
     };
     engine
@@ -526,6 +534,7 @@ fn zoom_limit_behavior_registers_lwc_bar_spacing_and_right_offset_intent() {
         .set_time_scale_zoom_limit_behavior(TimeScaleZoomLimitBehavior {
             min_bar_spacing_px: 0.5,
             max_bar_spacing_px: Some(2.0),
+            max_visible_span_ratio: None,
         })
         .expect("zoom limit behavior should be valid");
 
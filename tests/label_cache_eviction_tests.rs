@@ -0,0 +1,80 @@
+use chart_rs::ChartError;
+use chart_rs::api::{ChartEngine, ChartEngineConfig, DEFAULT_LABEL_CACHE_CAPACITY, SeriesRef};
+use chart_rs::core::Viewport;
+use chart_rs::render::NullRenderer;
+
+fn engine() -> ChartEngine<NullRenderer> {
+    let config =
+        ChartEngineConfig::new(Viewport::new(800, 400), 0.0, 100.0).with_price_domain(0.0, 100.0);
+    ChartEngine::new(NullRenderer::default(), config).expect("engine")
+}
+
+fn format(engine: &ChartEngine<NullRenderer>, value: u32) -> String {
+    engine.format_series_value(SeriesRef::Points, f64::from(value))
+}
+
+#[test]
+fn full_cache_evicts_one_least_recently_used_label_per_insert() {
+    let mut engine = engine();
+    engine.set_label_cache_capacity(4).expect("capacity");
+    for value in 0..4 {
+        format(&engine, value);
+    }
+    // Touch 0 so 1 becomes the least recently used label.
+    format(&engine, 0);
+    format(&engine, 4);
+
+    let stats = engine.price_label_cache_stats();
+    assert_eq!(stats.size, 4);
+    assert_eq!(stats.capacity, 4);
+    assert_eq!(stats.evictions, 1);
+
+    let misses = stats.misses;
+    format(&engine, 0);
+    assert_eq!(engine.price_label_cache_stats().misses, misses);
+    format(&engine, 1);
+    assert_eq!(engine.price_label_cache_stats().misses, misses + 1);
+}
+
+#[test]
+fn cache_size_never_collapses_under_churn() {
+    let mut engine = engine();
+    engine.set_label_cache_capacity(8).expect("capacity");
+    for value in 0..100 {
+        format(&engine, value);
+        let stats = engine.price_label_cache_stats();
+        assert_eq!(stats.size, (value as usize + 1).min(8));
+    }
+    assert_eq!(engine.price_label_cache_stats().evictions, 92);
+}
+
+#[test]
+fn shrinking_capacity_keeps_most_recent_labels() {
+    let mut engine = engine();
+    assert_eq!(engine.label_cache_capacity(), DEFAULT_LABEL_CACHE_CAPACITY);
+    for value in 0..6 {
+        format(&engine, value);
+    }
+    engine.set_label_cache_capacity(2).expect("capacity");
+    let stats = engine.price_label_cache_stats();
+    assert_eq!((stats.size, stats.evictions), (2, 4));
+    assert_eq!(engine.time_label_cache_stats().capacity, 2);
+    assert_eq!(engine.crosshair_price_label_cache_stats().capacity, 2);
+
+    format(&engine, 5);
+    format(&engine, 4);
+    assert_eq!(engine.price_label_cache_stats().misses, stats.misses);
+}
+
+#[test]
+fn zero_capacity_is_rejected() {
+    let mut engine = engine();
+    assert!(matches!(
+        engine.set_label_cache_capacity(0),
+        Err(ChartError::InvalidCapacity {
+            field: "label cache",
+            capacity: 0
+        })
+    ));
+    assert_eq!(engine.label_cache_capacity(), DEFAULT_LABEL_CACHE_CAPACITY);
+}
@@ -0,0 +1,102 @@
+use chart_rs::api::{ChartEngine, ChartEngineConfig, LabelTextMetrics, RenderStyle};
+use chart_rs::core::{OhlcBar, Viewport};
+use chart_rs::extensions::PriceAlert;
+use chart_rs::render::{CanvasLayerKind, DEJAVU_SANS_METRICS, NullRenderer};
+
+#[test]
+fn table_sums_per_codepoint_advances() {
+    let digit = DEJAVU_SANS_METRICS.advance_em('7');
+    assert!((digit - 1303.0 / 2048.0).abs() < 1e-12);
+    assert!((DEJAVU_SANS_METRICS.advance_em(' ') - 651.0 / 2048.0).abs() < 1e-12);
+    assert!((DEJAVU_SANS_METRICS.advance_em('~') - 1716.0 / 2048.0).abs() < 1e-12);
+    // Codepoints outside printable ASCII use the fallback advance.
+    assert!((DEJAVU_SANS_METRICS.advance_em('€') - 1229.0 / 2048.0).abs() < 1e-12);
+
+    let width = DEJAVU_SANS_METRICS.text_width_px("1,234.5", 12.0);
+    let expected = (5.0 * 1303.0 + 2.0 * 651.0) / 2048.0 * 12.0;
+    assert!((width - expected).abs() < 1e-9);
+    assert_eq!(DEJAVU_SANS_METRICS.text_width_px("", 12.0), 0.0);
+}
+
+#[test]
+fn estimated_metrics_keep_the_character_class_estimate() {
+    // Headless layout defaults to the embedded table.
+    assert_eq!(LabelTextMetrics::default(), LabelTextMetrics::DejaVuSans);
+    let estimated = LabelTextMetrics::Estimated;
+    assert!((estimated.text_width_px("100.5", 10.0) - 28.2).abs() < 1e-9);
+    // Short labels are never narrower than one font size.
+    assert_eq!(estimated.text_width_px(".", 10.0), 10.0);
+    assert_eq!(
+        LabelTextMetrics::DejaVuSans.text_width_px("100.5", 10.0),
+        DEJAVU_SANS_METRICS.text_width_px("100.5", 10.0)
+    );
+}
+
+#[test]
+fn render_style_selects_metrics_for_label_layout() {
+    let config =
+        ChartEngineConfig::new(Viewport::new(800, 400), 0.0, 100.0).with_price_domain(0.0, 50.0);
+    let mut engine = ChartEngine::new(NullRenderer::default(), config).expect("engine");
+    engine.set_candles(vec![
+        OhlcBar::new(10.0, 20.0, 21.0, 19.0, 20.0).expect("bar"),
+        OhlcBar::new(20.0, 20.0, 21.0, 19.0, 20.5).expect("bar"),
+    ]);
+    engine
+        .add_price_alert(PriceAlert::new("a", 20.25))
+        .expect("alert");
+    let alert_label_width = |engine: &ChartEngine<NullRenderer>| {
+        let color = engine.price_alerts()[0].color;
+        let layered = engine.build_layered_render_frame().expect("layered");
+        layered.panes[0]
+            .layers
+            .iter()
+            .filter(|layer| layer.kind == CanvasLayerKind::Overlay)
+            .flat_map(|layer| &layer.rects)
+            .find(|rect| rect.fill_color == color)
+            .expect("alert label")
+            .width
+    };
+    let text = {
+        let layered = engine.build_layered_render_frame().expect("layered");
+        layered.panes[0]
+            .layers
+            .iter()
+            .filter(|layer| layer.kind == CanvasLayerKind::Overlay)
+            .flat_map(|layer| &layer.texts)
+            .next()
+            .expect("alert text")
+            .text
+            .to_string()
+    };
+    assert_eq!(
+        engine.render_style().label_text_metrics,
+        LabelTextMetrics::DejaVuSans
+    );
+    let table = alert_label_width(&engine);
+
+    engine
+        .set_render_style(RenderStyle {
+            label_text_metrics: LabelTextMetrics::Estimated,
+            ..engine.render_style()
+        })
+        .expect("style");
+    let estimated = alert_label_width(&engine);
+    let padding = table - DEJAVU_SANS_METRICS.text_width_px(&text, 11.0);
+    let expected = LabelTextMetrics::Estimated.text_width_px(&text, 11.0) + padding;
+    assert!((estimated - expected).abs() < 1e-9);
+    assert_ne!(table, estimated);
+}
+
+#[test]
+fn styles_without_the_field_deserialize_with_default_metrics() {
+    let mut json = serde_json::to_value(RenderStyle {
+        label_text_metrics: LabelTextMetrics::Estimated,
+        ..RenderStyle::default()
+    })
+    .expect("json");
+    json.as_object_mut()
+        .expect("object")
+        .remove("label_text_metrics");
+    let style: RenderStyle = serde_json::from_value(json).expect("style");
+    assert_eq!(style, RenderStyle::default());
+}
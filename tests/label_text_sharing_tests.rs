@@ -0,0 +1,76 @@
+use std::sync::Arc;
+
+use chart_rs::api::{ChartEngine, ChartEngineConfig};
+use chart_rs::core::{DataPoint, Viewport};
+use chart_rs::render::{NullRenderer, RenderFrame, TextHAlign, TextPrimitive};
+
+fn engine() -> ChartEngine<NullRenderer> {
+    let config =
+        ChartEngineConfig::new(Viewport::new(800, 400), 0.0, 100.0).with_price_domain(10.0, 90.0);
+    let mut engine = ChartEngine::new(NullRenderer::default(), config).expect("engine");
+    engine.set_data(
+        (0..100)
+            .map(|i| DataPoint::new(f64::from(i), 50.0))
+            .collect(),
+    );
+    engine
+}
+
+fn axis_texts(frame: &RenderFrame, h_align: TextHAlign) -> Vec<&TextPrimitive> {
+    frame
+        .texts
+        .iter()
+        .filter(|text| text.h_align == h_align)
+        .collect()
+}
+
+fn shares_allocations(first: &[&TextPrimitive], second: &[&TextPrimitive]) -> bool {
+    first.len() == second.len()
+        && first
+            .iter()
+            .zip(second)
+            .all(|(left, right)| Arc::ptr_eq(&left.text, &right.text))
+}
+
+#[test]
+fn repeated_price_axis_labels_share_text_across_frames() {
+    let engine = engine();
+    let first = engine.build_render_frame().expect("first");
+    let second = engine.build_render_frame().expect("second");
+    let first_labels = axis_texts(&first, TextHAlign::Right);
+    assert!(!first_labels.is_empty());
+    assert!(shares_allocations(
+        &first_labels,
+        &axis_texts(&second, TextHAlign::Right)
+    ));
+}
+
+#[test]
+fn repeated_time_axis_labels_share_text_across_frames() {
+    let engine = engine();
+    let first = engine.build_render_frame().expect("first");
+    let second = engine.build_render_frame().expect("second");
+    let first_labels = axis_texts(&first, TextHAlign::Center);
+    assert!(!first_labels.is_empty());
+    assert!(shares_allocations(
+        &first_labels,
+        &axis_texts(&second, TextHAlign::Center)
+    ));
+}
+
+#[test]
+fn clearing_the_cache_allocates_fresh_label_text() {
+    let engine = engine();
+    let first = engine.build_render_frame().expect("first");
+    engine.clear_price_label_cache();
+    let second = engine.build_render_frame().expect("second");
+    let first_labels = axis_texts(&first, TextHAlign::Right);
+    let second_labels = axis_texts(&second, TextHAlign::Right);
+    assert!(!shares_allocations(&first_labels, &second_labels));
+    assert!(
+        first_labels
+            .iter()
+            .zip(&second_labels)
+            .all(|(left, right)| left.text == right.text)
+    );
+}
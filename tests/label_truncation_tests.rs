@@ -0,0 +1,125 @@
+use chart_rs::api::{
+    ChartEngine, ChartEngineConfig, LabelOverflowPolicy, LabelTextMetrics,
+    LastPriceLabelBoxWidthMode, PriceAxisWidthMode, RenderStyle,
+};
+use chart_rs::core::{DataPoint, Viewport};
+use chart_rs::render::NullRenderer;
+
+const METRICS: LabelTextMetrics = LabelTextMetrics::DejaVuSans;
+
+fn width(text: &str) -> f64 {
+    METRICS.text_width_px(text, 10.0)
+}
+
+fn fit(policy: LabelOverflowPolicy, text: &str, max_width_px: f64) -> Option<String> {
+    policy.fit_text(text, max_width_px, 10.0, METRICS)
+}
+
+#[test]
+fn text_that_fits_is_kept_under_every_policy() {
+    for policy in [
+        LabelOverflowPolicy::Overflow,
+        LabelOverflowPolicy::TruncateEnd,
+        LabelOverflowPolicy::TruncateMiddle,
+        LabelOverflowPolicy::Hide,
+    ] {
+        assert_eq!(fit(policy, "12.5", width("12.5")).as_deref(), Some("12.5"));
+    }
+    assert_eq!(
+        fit(LabelOverflowPolicy::Overflow, "123456.78", 1.0).as_deref(),
+        Some("123456.78")
+    );
+    assert_eq!(fit(LabelOverflowPolicy::Hide, "123456.78", 20.0), None);
+}
+
+#[test]
+fn truncation_keeps_the_longest_prefix_or_both_ends() {
+    let max = width("1234…");
+    assert_eq!(
+        fit(LabelOverflowPolicy::TruncateEnd, "123456.78", max).as_deref(),
+        Some("1234…")
+    );
+    assert_eq!(
+        fit(LabelOverflowPolicy::TruncateMiddle, "123456.78", max).as_deref(),
+        Some("12…78")
+    );
+    // Nothing is drawn when not even the ellipsis fits.
+    assert_eq!(
+        fit(
+            LabelOverflowPolicy::TruncateEnd,
+            "123456.78",
+            width("…") - 0.1
+        ),
+        None
+    );
+}
+
+#[test]
+fn truncation_never_splits_grapheme_clusters() {
+    // `e` + combining acute accent and a flag are one grapheme each.
+    let text = "ae\u{301}\u{1F1E9}\u{1F1EA}z";
+    let max = width("ae\u{301}…");
+    let truncated = fit(LabelOverflowPolicy::TruncateEnd, text, max).expect("label");
+    assert_eq!(truncated, "ae\u{301}…");
+
+    let tighter = fit(LabelOverflowPolicy::TruncateEnd, text, max - 0.1).expect("label");
+    assert_eq!(tighter, "a…");
+}
+
+#[test]
+fn capped_price_axis_truncates_tick_and_last_price_labels() {
+    let config =
+        ChartEngineConfig::new(Viewport::new(800, 400), 0.0, 100.0).with_price_domain(0.0, 1.0);
+    let mut engine = ChartEngine::new(NullRenderer::default(), config).expect("engine");
+    let style = RenderStyle {
+        price_axis_width_mode: PriceAxisWidthMode::Auto {
+            min_width_px: 30.0,
+            max_width_px: 40.0,
+            hysteresis_px: 0.0,
+        },
+        show_last_price_label_box: true,
+        last_price_label_box_width_mode: LastPriceLabelBoxWidthMode::FitText,
+        label_overflow_policy: LabelOverflowPolicy::TruncateEnd,
+        ..engine.render_style()
+    };
+    engine.set_render_style(style.clone()).expect("style");
+    engine.set_data(vec![
+        DataPoint::new(10.0, 1_234_567.0),
+        DataPoint::new(90.0, 1_234_587.0),
+    ]);
+    engine.autoscale_price_from_data().expect("autoscale");
+
+    let frame = engine.build_render_frame().expect("frame");
+    let plot_right = 800.0 - engine.resolved_price_axis_width_px();
+    let axis_texts = frame
+        .texts
+        .iter()
+        .filter(|text| text.x > plot_right)
+        .collect::<Vec<_>>();
+    assert!(!axis_texts.is_empty());
+    assert!(axis_texts.iter().all(|text| text.text.ends_with('…')));
+
+    engine
+        .set_render_style(RenderStyle {
+            label_overflow_policy: LabelOverflowPolicy::Hide,
+            ..style
+        })
+        .expect("style");
+    let frame = engine.build_render_frame().expect("frame");
+    assert!(frame.texts.iter().all(|text| text.x <= plot_right));
+    assert!(frame.rects.iter().all(|rect| rect.x < plot_right));
+}
+
+#[test]
+fn styles_without_the_field_deserialize_with_overflowing_labels() {
+    let mut json = serde_json::to_value(RenderStyle {
+        label_overflow_policy: LabelOverflowPolicy::TruncateMiddle,
+        ..RenderStyle::default()
+    })
+    .expect("json");
+    json.as_object_mut()
+        .expect("object")
+        .remove("label_overflow_policy");
+    let style: RenderStyle = serde_json::from_value(json).expect("style");
+    assert_eq!(style, RenderStyle::default());
+}
@@ -1,4 +1,7 @@
-use chart_rs::api::{ChartEngine, ChartEngineConfig, LastPriceBehavior, LastPriceSourceMode};
+use chart_rs::api::{
+    ChartEngine, ChartEngineConfig, LastPriceBehavior, LastPriceOffscreenPolicy,
+    LastPriceSourceMode,
+};
 use chart_rs::core::{DataPoint, Viewport};
 use chart_rs::render::NullRenderer;
 
@@ -24,6 +27,7 @@ fn set_last_price_behavior_updates_render_style_contract() {
         show_label: true,
         use_trend_color: true,
         source_mode: LastPriceSourceMode::LatestVisible,
+        offscreen_policy: LastPriceOffscreenPolicy::Draw,
     };
     engine
         .set_last_price_behavior(behavior)
@@ -58,6 +62,7 @@ fn last_price_behavior_can_hide_line_and_label_in_render_frame() {
             show_label: false,
             use_trend_color: false,
             source_mode: LastPriceSourceMode::LatestData,
+            offscreen_policy: LastPriceOffscreenPolicy::Draw,
         })
         .expect("set behavior");
 
@@ -0,0 +1,116 @@
+use chart_rs::api::{ChartEngine, ChartEngineConfig, LastPriceOffscreenPolicy, RenderStyle};
+use chart_rs::core::{DataPoint, Viewport};
+use chart_rs::render::{NullRenderer, RenderFrame};
+
+const LAST_PRICE: f64 = 45.0;
+
+/// Latest point far right of the visible range; visible-range autoscale
+/// leaves `LAST_PRICE` above the price domain.
+fn engine(policy: LastPriceOffscreenPolicy) -> ChartEngine<NullRenderer> {
+    engine_with_last_price(policy, LAST_PRICE)
+}
+
+fn engine_with_last_price(
+    policy: LastPriceOffscreenPolicy,
+    last_price: f64,
+) -> ChartEngine<NullRenderer> {
+    let config =
+        ChartEngineConfig::new(Viewport::new(800, 400), 0.0, 100.0).with_price_domain(0.0, 20.0);
+    let mut engine = ChartEngine::new(NullRenderer::default(), config).expect("engine");
+    engine
+        .set_render_style(RenderStyle {
+            last_price_offscreen_policy: policy,
+            ..engine.render_style()
+        })
+        .expect("style");
+    engine.set_data(vec![
+        DataPoint::new(10.0, 5.0),
+        DataPoint::new(20.0, 15.0),
+        DataPoint::new(100.0, last_price),
+    ]);
+    engine.set_time_visible_range(0.0, 50.0).expect("range");
+    engine
+}
+
+fn has_last_price_label(frame: &RenderFrame) -> bool {
+    frame.texts.iter().any(|text| &*text.text == "45.00")
+}
+
+fn edge_arrows(engine: &ChartEngine<NullRenderer>, frame: &RenderFrame) -> usize {
+    let color = engine.render_style().last_price_line_color;
+    frame
+        .paths
+        .iter()
+        .filter(|path| path.closed && path.fill_color == Some(color))
+        .count()
+}
+
+#[test]
+fn default_policy_pins_the_marker_without_an_arrow() {
+    let engine = engine(LastPriceOffscreenPolicy::Draw);
+    let frame = engine.build_render_frame().expect("frame");
+    assert!(has_last_price_label(&frame));
+    assert_eq!(edge_arrows(&engine, &frame), 0);
+}
+
+#[test]
+fn hide_policy_drops_the_marker_while_its_bar_is_off_screen() {
+    let drawn = engine(LastPriceOffscreenPolicy::Draw)
+        .build_render_frame()
+        .expect("frame");
+    let mut engine = engine(LastPriceOffscreenPolicy::Hide);
+    let frame = engine.build_render_frame().expect("frame");
+    assert!(!has_last_price_label(&frame));
+    assert_eq!(frame.lines.len(), drawn.lines.len() - 1);
+
+    engine.set_time_visible_range(50.0, 110.0).expect("range");
+    let frame = engine.build_render_frame().expect("frame");
+    assert!(has_last_price_label(&frame));
+}
+
+#[test]
+fn edge_arrow_points_towards_the_off_domain_price() {
+    let engine = engine(LastPriceOffscreenPolicy::EdgeArrow);
+    let frame = engine.build_render_frame().expect("frame");
+    assert!(has_last_price_label(&frame));
+    assert_eq!(edge_arrows(&engine, &frame), 1);
+    let color = engine.render_style().last_price_line_color;
+    let arrow = frame
+        .paths
+        .iter()
+        .find(|path| path.fill_color == Some(color))
+        .expect("arrow");
+    // Tip first, pointing up towards the top edge.
+    assert!(arrow.points[0].y < arrow.points[1].y);
+    assert!(arrow.points[0].y < 10.0);
+
+    let engine = engine_with_last_price(LastPriceOffscreenPolicy::EdgeArrow, -30.0);
+    let frame = engine.build_render_frame().expect("frame");
+    let arrow = frame
+        .paths
+        .iter()
+        .find(|path| path.fill_color == Some(color))
+        .expect("arrow");
+    assert!(arrow.points[0].y > arrow.points[1].y);
+
+    let engine = engine_with_last_price(LastPriceOffscreenPolicy::EdgeArrow, 10.0);
+    let frame = engine.build_render_frame().expect("frame");
+    assert_eq!(edge_arrows(&engine, &frame), 0);
+}
+
+#[test]
+fn extend_domain_keeps_the_last_price_in_visible_autoscale() {
+    let mut plain = engine(LastPriceOffscreenPolicy::Draw);
+    plain
+        .autoscale_price_from_visible_data()
+        .expect("autoscale");
+    assert!(plain.price_domain().1 < LAST_PRICE);
+
+    let mut extended = engine(LastPriceOffscreenPolicy::ExtendDomain);
+    extended
+        .autoscale_price_from_visible_data()
+        .expect("autoscale");
+    assert!(extended.price_domain().1 >= LAST_PRICE);
+    let frame = extended.build_render_frame().expect("frame");
+    assert_eq!(edge_arrows(&extended, &frame), 0);
+}
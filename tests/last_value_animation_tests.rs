@@ -0,0 +1,125 @@
+use chart_rs::ChartError;
+use chart_rs::api::{ChartEngine, ChartEngineConfig, LastValueAnimationBehavior};
+use chart_rs::core::{DataPoint, OhlcBar, Viewport};
+use chart_rs::render::{CanvasLayerKind, LinePrimitive, NullRenderer, RectPrimitive};
+
+const ANIMATED: LastValueAnimationBehavior = LastValueAnimationBehavior {
+    enabled: true,
+    duration_seconds: 0.5,
+};
+
+fn engine(behavior: LastValueAnimationBehavior) -> ChartEngine<NullRenderer> {
+    let config = ChartEngineConfig::new(Viewport::new(800, 400), 0.0, 100.0)
+        .with_price_domain(0.0, 50.0)
+        .with_last_value_animation_behavior(behavior);
+    ChartEngine::new(NullRenderer::default(), config).expect("engine")
+}
+
+fn series_lines(engine: &ChartEngine<NullRenderer>) -> Vec<LinePrimitive> {
+    let layered = engine.build_layered_render_frame().expect("layered");
+    layered.panes[0]
+        .layers
+        .iter()
+        .find(|layer| layer.kind == CanvasLayerKind::Series)
+        .expect("series layer")
+        .lines
+        .clone()
+}
+
+fn series_rects(engine: &ChartEngine<NullRenderer>) -> Vec<RectPrimitive> {
+    engine.build_render_frame().expect("frame").rects
+}
+
+#[test]
+fn updated_point_moves_from_the_previous_value() {
+    let mut animated = engine(ANIMATED);
+    let mut immediate = engine(LastValueAnimationBehavior::default());
+    for engine in [&mut animated, &mut immediate] {
+        engine.set_data(vec![DataPoint::new(10.0, 20.0), DataPoint::new(50.0, 20.0)]);
+        engine
+            .update_point(DataPoint::new(50.0, 40.0))
+            .expect("update");
+    }
+    assert!(animated.last_value_animation_active());
+    assert!(animated.has_active_animation());
+    let last_segment =
+        |engine: &ChartEngine<NullRenderer>| *series_lines(engine).last().expect("segment");
+    let start = last_segment(&animated);
+    assert_eq!(start.y1, start.y2);
+    let target = last_segment(&immediate).y2;
+
+    animated.step_animations(0.2).expect("step");
+    let midway = last_segment(&animated).y2;
+    assert!(midway < start.y2 && midway > target);
+
+    animated.step_animations(0.5).expect("step");
+    assert!(!animated.last_value_animation_active());
+    assert_eq!(series_lines(&animated), series_lines(&immediate));
+}
+
+#[test]
+fn candle_close_eases_towards_the_new_close() {
+    let bars = vec![
+        OhlcBar::new(10.0, 20.0, 25.0, 15.0, 22.0).expect("bar"),
+        OhlcBar::new(20.0, 22.0, 26.0, 21.0, 24.0).expect("bar"),
+    ];
+    let update = OhlcBar::new(20.0, 22.0, 35.0, 21.0, 34.0).expect("bar");
+    let mut animated = engine(ANIMATED);
+    let mut immediate = engine(LastValueAnimationBehavior::default());
+    for engine in [&mut animated, &mut immediate] {
+        engine.set_candles(bars.clone());
+        engine.update_candle(update).expect("update");
+    }
+
+    animated.step_animations(0.1).expect("step");
+    assert_ne!(series_rects(&animated), series_rects(&immediate));
+    animated.step_animations(1.0).expect("step");
+    assert_eq!(series_rects(&animated), series_rects(&immediate));
+}
+
+#[test]
+fn appended_point_starts_from_the_previous_last_value() {
+    let mut engine = engine(ANIMATED);
+    engine.set_data(vec![DataPoint::new(10.0, 20.0)]);
+    engine
+        .append_point(DataPoint::new(50.0, 40.0))
+        .expect("append");
+    let segment = *series_lines(&engine).last().expect("segment");
+    assert_eq!(segment.y1, segment.y2);
+
+    // Replacing the data drops the transition from drawing.
+    engine.set_data(vec![DataPoint::new(10.0, 20.0), DataPoint::new(50.0, 40.0)]);
+    let segment = *series_lines(&engine).last().expect("segment");
+    assert!(segment.y2 < segment.y1);
+}
+
+#[test]
+fn animation_is_opt_in_and_validated() {
+    let mut engine = engine(LastValueAnimationBehavior::default());
+    engine.set_data(vec![DataPoint::new(10.0, 20.0)]);
+    engine
+        .update_point(DataPoint::new(10.0, 30.0))
+        .expect("update");
+    assert!(!engine.last_value_animation_active());
+
+    engine
+        .set_last_value_animation_behavior(ANIMATED)
+        .expect("behavior");
+    engine
+        .update_point(DataPoint::new(10.0, 35.0))
+        .expect("update");
+    assert!(engine.last_value_animation_active());
+    engine
+        .set_last_value_animation_behavior(LastValueAnimationBehavior::default())
+        .expect("behavior");
+    assert!(!engine.last_value_animation_active());
+
+    let invalid = LastValueAnimationBehavior {
+        duration_seconds: 0.0,
+        ..ANIMATED
+    };
+    assert!(matches!(
+        engine.set_last_value_animation_behavior(invalid),
+        Err(ChartError::InvalidData(_))
+    ));
+}
@@ -0,0 +1,146 @@
+use chart_rs::ChartError;
+use chart_rs::api::{
+    ChartEngine, ChartEngineConfig, LayoutInsets, PointerInput, PointerInputBridge, PointerRegion,
+};
+use chart_rs::core::{DataPoint, OhlcBar, Viewport};
+use chart_rs::render::NullRenderer;
+
+const INSETS: LayoutInsets = LayoutInsets::new(12, 20, 28, 10);
+
+fn engine(viewport: Viewport, insets: LayoutInsets) -> ChartEngine<NullRenderer> {
+    let config = ChartEngineConfig::new(viewport, 0.0, 60.0)
+        .with_price_domain(0.0, 50.0)
+        .with_layout_insets(insets);
+    let mut engine = ChartEngine::new(NullRenderer::default(), config).expect("engine");
+    engine.set_data(
+        (0..=60)
+            .map(|i| DataPoint::new(f64::from(i), 20.0 + f64::from(i % 7)))
+            .collect(),
+    );
+    engine.set_candles(
+        (0..=60)
+            .map(|i| {
+                let base = 20.0 + f64::from(i % 9);
+                OhlcBar::new(f64::from(i), base, base + 3.0, base - 2.0, base + 1.0).expect("bar")
+            })
+            .collect(),
+    );
+    engine
+}
+
+#[test]
+fn insets_split_widget_and_content_viewports() {
+    let mut engine = engine(Viewport::new(800, 400), INSETS);
+    assert_eq!(engine.viewport(), Viewport::new(800, 400));
+    assert_eq!(engine.content_viewport(), Viewport::new(760, 370));
+    assert_eq!(engine.widget_to_content(12.0, 20.0), (0.0, 0.0));
+
+    engine
+        .set_viewport(Viewport::new(640, 300))
+        .expect("resize");
+    assert_eq!(engine.content_viewport(), Viewport::new(600, 270));
+
+    engine
+        .set_layout_insets(LayoutInsets::default())
+        .expect("clear insets");
+    assert_eq!(engine.viewport(), Viewport::new(640, 300));
+    assert_eq!(engine.content_viewport(), Viewport::new(640, 300));
+}
+
+#[test]
+fn insets_leaving_no_content_area_are_rejected() {
+    let mut engine = engine(Viewport::new(800, 400), LayoutInsets::default());
+    let err = engine
+        .set_layout_insets(LayoutInsets::new(400, 0, 400, 0))
+        .expect_err("no content width");
+    assert!(matches!(err, ChartError::InvalidData(_)));
+    assert_eq!(engine.layout_insets(), LayoutInsets::default());
+
+    engine.set_layout_insets(INSETS).expect("insets");
+    assert!(engine.set_viewport(Viewport::new(40, 400)).is_err());
+    assert_eq!(engine.viewport(), Viewport::new(800, 400));
+
+    let config = ChartEngineConfig::new(Viewport::new(20, 20), 0.0, 1.0).with_layout_insets(INSETS);
+    assert!(ChartEngine::new(NullRenderer::default(), config).is_err());
+}
+
+#[test]
+fn layout_insets_round_trip_through_config_json() {
+    let config =
+        ChartEngineConfig::new(Viewport::new(800, 400), 0.0, 10.0).with_layout_insets(INSETS);
+    let restored =
+        ChartEngineConfig::from_json_str(&config.to_json_pretty().expect("json")).expect("parse");
+    assert_eq!(restored.layout_insets, INSETS);
+
+    let legacy = r#"{"viewport":{"width":800,"height":400},"time_start":0.0,"time_end":10.0,"price_min":0.0,"price_max":1.0}"#;
+    let legacy = ChartEngineConfig::from_json_str(legacy).expect("legacy parse");
+    assert_eq!(legacy.layout_insets, LayoutInsets::default());
+}
+
+#[test]
+fn inset_frame_is_content_frame_shifted_into_widget_viewport() {
+    let plain = engine(Viewport::new(760, 370), LayoutInsets::default());
+    let inset = engine(Viewport::new(800, 400), INSETS);
+
+    let mut expected = plain.build_render_frame().expect("plain frame");
+    expected.translate(12.0, 20.0);
+    expected.viewport = Viewport::new(800, 400);
+    assert_eq!(inset.build_render_frame().expect("inset frame"), expected);
+
+    let mut expected_layered = plain.build_layered_render_frame().expect("plain layered");
+    expected_layered.translate(12.0, 20.0);
+    expected_layered.viewport = Viewport::new(800, 400);
+    let layered = inset.build_layered_render_frame().expect("inset layered");
+    assert_eq!(layered, expected_layered);
+    assert_eq!(layered.panes[0].plot_top, 20.0);
+}
+
+#[test]
+fn pointer_regions_account_for_insets() {
+    let engine = engine(Viewport::new(800, 400), INSETS);
+    let style = engine.render_style();
+    let plot_right = 12.0 + 760.0 - style.price_axis_width_px;
+    let plot_bottom = 20.0 + 370.0 - style.time_axis_height_px;
+
+    assert_eq!(engine.pointer_region(5.0, 100.0), PointerRegion::Outside);
+    assert_eq!(engine.pointer_region(100.0, 10.0), PointerRegion::Outside);
+    assert_eq!(engine.pointer_region(790.0, 100.0), PointerRegion::Outside);
+    assert_eq!(engine.pointer_region(100.0, 395.0), PointerRegion::Outside);
+    assert_eq!(engine.pointer_region(20.0, 30.0), PointerRegion::Plot);
+    assert_eq!(
+        engine.pointer_region(plot_right + 1.0, 100.0),
+        PointerRegion::PriceAxis
+    );
+    assert_eq!(
+        engine.pointer_region(100.0, plot_bottom + 1.0),
+        PointerRegion::TimeAxis
+    );
+}
+
+#[test]
+fn bridge_maps_widget_positions_into_content_and_ignores_margins() {
+    let mut engine = engine(Viewport::new(800, 400), INSETS);
+    let mut bridge = PointerInputBridge::default();
+
+    bridge
+        .handle(&mut engine, PointerInput::Moved { x: 112.0, y: 120.0 })
+        .expect("move");
+    let crosshair = engine.crosshair_state();
+    assert!(crosshair.visible);
+    assert_eq!((crosshair.x, crosshair.y), (100.0, 100.0));
+
+    bridge
+        .handle(&mut engine, PointerInput::Moved { x: 5.0, y: 120.0 })
+        .expect("move into margin");
+    assert!(!engine.crosshair_state().visible);
+
+    let range = engine.time_visible_range();
+    bridge
+        .handle(&mut engine, PointerInput::Pressed { x: 5.0, y: 120.0 })
+        .expect("press in margin");
+    assert!(!bridge.is_dragging());
+    bridge
+        .handle(&mut engine, PointerInput::Moved { x: 60.0, y: 120.0 })
+        .expect("move");
+    assert_eq!(engine.time_visible_range(), range);
+}
@@ -221,7 +221,9 @@ fn lightweight_v51_price_scale_differential_trace_transformed_autoscale() {
                     });
                 }
                 DifferentialAction::AppendPoint { time, value } => {
-                    engine.append_point(DataPoint::new(time, value));
+                    engine
+                        .append_point(DataPoint::new(time, value))
+                        .expect("append");
                 }
             }
 
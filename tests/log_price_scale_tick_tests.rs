@@ -0,0 +1,70 @@
+use chart_rs::api::{
+    ChartEngine, ChartEngineConfig, GridLineOptions, GridOptions, PriceAxisLabelConfig,
+    PriceAxisLabelPolicy, RenderStyle,
+};
+use chart_rs::core::{PriceScaleMode, Viewport};
+use chart_rs::render::{CanvasLayerKind, Color, NullRenderer};
+
+const MINOR_COLOR: Color = Color::rgb(0.55, 0.15, 0.65);
+
+fn engine(mode: PriceScaleMode, show_minor: bool) -> ChartEngine<NullRenderer> {
+    let config = ChartEngineConfig::new(Viewport::new(800, 600), 0.0, 100.0)
+        .with_price_domain(0.01, 100.0)
+        .with_price_scale_mode(mode);
+    let mut engine = ChartEngine::new(NullRenderer::default(), config).expect("engine");
+    engine
+        .set_render_style(RenderStyle {
+            grid: GridOptions {
+                minor_horz_lines: GridLineOptions::new(MINOR_COLOR, 1.0).with_visible(show_minor),
+                ..engine.render_style().grid
+            },
+            ..engine.render_style()
+        })
+        .expect("style");
+    engine
+        .set_price_axis_label_config(PriceAxisLabelConfig {
+            policy: PriceAxisLabelPolicy::Adaptive,
+            ..engine.price_axis_label_config()
+        })
+        .expect("label config");
+    engine
+}
+
+fn minor_grid_prices(engine: &ChartEngine<NullRenderer>) -> Vec<f64> {
+    let layered = engine.build_layered_render_frame().expect("layered");
+    layered.panes[0]
+        .layers
+        .iter()
+        .filter(|layer| layer.kind == CanvasLayerKind::Grid)
+        .flat_map(|layer| layer.lines.iter())
+        .filter(|line| line.color == MINOR_COLOR)
+        .map(|line| engine.map_pixel_to_price(line.y1).expect("price"))
+        .collect()
+}
+
+#[test]
+fn log_scale_draws_minor_grid_lines_between_ladder_ticks() {
+    let engine = engine(PriceScaleMode::Log, true);
+    let prices = minor_grid_prices(&engine);
+    assert!(!prices.is_empty());
+    for price in prices {
+        let mantissa = price / 10_f64.powf(price.log10().floor());
+        assert!((mantissa - mantissa.round()).abs() <= 1e-6, "{price}");
+    }
+}
+
+#[test]
+fn minor_grid_lines_are_opt_in_and_log_only() {
+    assert!(minor_grid_prices(&engine(PriceScaleMode::Log, false)).is_empty());
+    assert!(minor_grid_prices(&engine(PriceScaleMode::Linear, true)).is_empty());
+}
+
+#[test]
+fn adaptive_log_labels_use_local_precision() {
+    let engine = engine(PriceScaleMode::Log, false);
+    let frame = engine.build_render_frame().expect("frame");
+    let labels: Vec<&str> = frame.texts.iter().map(|text| text.text.as_ref()).collect();
+    assert!(labels.contains(&"100"), "{labels:?}");
+    assert!(labels.contains(&"0.01"), "{labels:?}");
+    assert!(!labels.contains(&"100.00"), "{labels:?}");
+}
@@ -0,0 +1,76 @@
+use chart_rs::api::{ChartEngine, ChartEngineConfig, GridLineOptions, GridOptions, RenderStyle};
+use chart_rs::core::Viewport;
+use chart_rs::render::{Color, LinePrimitive, NullRenderer};
+
+const MAJOR_COLOR: Color = Color::rgb(0.20, 0.40, 0.60);
+const MINOR_COLOR: Color = Color::rgb(0.55, 0.15, 0.65);
+
+fn engine(subdivisions: u32) -> ChartEngine<NullRenderer> {
+    let config =
+        ChartEngineConfig::new(Viewport::new(900, 600), 0.0, 100.0).with_price_domain(0.0, 50.0);
+    let mut engine = ChartEngine::new(NullRenderer::default(), config).expect("engine");
+    let major = GridLineOptions::new(MAJOR_COLOR, 1.0);
+    let minor = GridLineOptions::new(MINOR_COLOR, 0.5);
+    engine
+        .set_render_style(RenderStyle {
+            grid: GridOptions {
+                vert_lines: major,
+                major_vert_lines: major,
+                horz_lines: major,
+                minor_horz_lines: minor,
+                minor_horz_subdivisions: subdivisions,
+                minor_vert_lines: minor,
+                minor_vert_subdivisions: subdivisions,
+            },
+            ..engine.render_style()
+        })
+        .expect("style");
+    engine
+}
+
+fn grid_lines(engine: &ChartEngine<NullRenderer>, color: Color, horizontal: bool) -> Vec<f64> {
+    let mut positions: Vec<f64> = engine
+        .build_render_frame()
+        .expect("frame")
+        .lines
+        .iter()
+        .filter(|line| line.color == color && (line.y1 == line.y2) == horizontal)
+        .map(|line: &LinePrimitive| if horizontal { line.y1 } else { line.x1 })
+        .collect();
+    positions.sort_by(f64::total_cmp);
+    positions
+}
+
+#[test]
+fn minor_lines_split_every_major_gap() {
+    let engine = engine(2);
+    for horizontal in [true, false] {
+        let majors = grid_lines(&engine, MAJOR_COLOR, horizontal);
+        let minors = grid_lines(&engine, MINOR_COLOR, horizontal);
+        assert!(majors.len() >= 2, "horizontal={horizontal}");
+        for pair in majors.windows(2) {
+            let midpoint = (pair[0] + pair[1]) * 0.5;
+            assert!(
+                minors.iter().any(|minor| (minor - midpoint).abs() <= 1e-9),
+                "horizontal={horizontal}: no minor line at {midpoint}"
+            );
+        }
+        assert!(
+            minors
+                .iter()
+                .all(|minor| majors.iter().all(|major| (minor - major).abs() >= 1.0))
+        );
+    }
+}
+
+#[test]
+fn minor_lines_leave_major_tick_density_unchanged() {
+    for horizontal in [true, false] {
+        let without = grid_lines(&engine(0), MAJOR_COLOR, horizontal);
+        let with = grid_lines(&engine(4), MAJOR_COLOR, horizontal);
+        assert_eq!(without, with);
+        assert!(grid_lines(&engine(0), MINOR_COLOR, horizontal).is_empty());
+        assert!(grid_lines(&engine(1), MINOR_COLOR, horizontal).is_empty());
+        assert!(!grid_lines(&engine(4), MINOR_COLOR, horizontal).is_empty());
+    }
+}
@@ -0,0 +1,130 @@
+use chart_rs::api::{ChartEngine, ChartEngineConfig, NavigationHistoryBehavior};
+use chart_rs::core::{DataPoint, PriceScaleMode, TimeScaleTuning, Viewport};
+use chart_rs::render::NullRenderer;
+
+fn engine(config: ChartEngineConfig) -> ChartEngine<NullRenderer> {
+    let mut engine = ChartEngine::new(NullRenderer::default(), config).expect("engine");
+    engine.set_data(
+        (0..=100)
+            .map(|index| DataPoint::new(f64::from(index), 20.0 + f64::from(index % 7)))
+            .collect(),
+    );
+    engine
+        .set_time_visible_range(20.0, 80.0)
+        .expect("visible range");
+    engine.clear_view_history();
+    engine
+}
+
+fn config() -> ChartEngineConfig {
+    ChartEngineConfig::new(Viewport::new(800, 400), 0.0, 100.0).with_price_domain(10.0, 50.0)
+}
+
+fn drag(engine: &mut ChartEngine<NullRenderer>, delta_px: f64) {
+    engine.pan_start();
+    engine.pan_time_visible_by_pixels(delta_px).expect("pan");
+    engine.pan_end();
+}
+
+#[test]
+fn undo_and_redo_walk_drag_checkpoints() {
+    let mut engine = engine(config());
+    assert!(!engine.can_undo_view());
+
+    drag(&mut engine, 80.0);
+    let after_first = engine.time_visible_range();
+    drag(&mut engine, 80.0);
+    let after_second = engine.time_visible_range();
+
+    assert!(engine.undo_view().expect("undo"));
+    assert_eq!(engine.time_visible_range(), after_first);
+    assert!(engine.undo_view().expect("undo"));
+    assert_eq!(engine.time_visible_range(), (20.0, 80.0));
+    assert!(!engine.undo_view().expect("undo"));
+
+    assert!(engine.can_redo_view());
+    assert!(engine.redo_view().expect("redo"));
+    assert!(engine.redo_view().expect("redo"));
+    assert_eq!(engine.time_visible_range(), after_second);
+    assert!(!engine.redo_view().expect("redo"));
+}
+
+#[test]
+fn navigation_after_undo_discards_redo_steps() {
+    let mut engine = engine(config());
+    drag(&mut engine, 80.0);
+    engine.undo_view().expect("undo");
+    drag(&mut engine, -40.0);
+
+    assert!(!engine.can_redo_view());
+    assert!(!engine.redo_view().expect("redo"));
+}
+
+#[test]
+fn zoom_burst_and_kinetic_fling_are_single_steps() {
+    let mut engine = engine(config());
+    for _ in 0..4 {
+        engine
+            .wheel_zoom_time_visible(-120.0, 400.0, 0.1, 1e-6)
+            .expect("wheel");
+    }
+    assert!(engine.undo_view().expect("undo"));
+    assert_eq!(engine.time_visible_range(), (20.0, 80.0));
+
+    drag(&mut engine, 40.0);
+    engine.start_kinetic_pan(20.0).expect("kinetic");
+    while engine.kinetic_pan_state().active {
+        engine.step_kinetic_pan(0.05).expect("step");
+    }
+    assert!(engine.undo_view().expect("undo"));
+    assert_eq!(engine.time_visible_range(), (20.0, 80.0));
+}
+
+#[test]
+fn undo_restores_price_domain_and_scale_mode() {
+    let mut engine = engine(config());
+    let before = engine.navigation_view_state();
+
+    engine
+        .set_price_scale_mode(PriceScaleMode::Log)
+        .expect("log mode");
+    engine
+        .fit_time_to_data(TimeScaleTuning::default())
+        .expect("fit");
+    assert_ne!(engine.time_visible_range(), before.time_visible_range);
+
+    assert!(engine.undo_view().expect("undo"));
+    assert_eq!(engine.price_scale_mode(), PriceScaleMode::Log);
+    assert_eq!(engine.time_visible_range(), before.time_visible_range);
+    assert!(engine.undo_view().expect("undo"));
+    assert_eq!(engine.navigation_view_state(), before);
+}
+
+#[test]
+fn uncheckpointed_changes_are_undone_and_redoable() {
+    let mut engine = engine(config());
+    engine.set_time_visible_range(40.0, 60.0).expect("range");
+    assert!(engine.can_undo_view());
+
+    assert!(engine.undo_view().expect("undo"));
+    assert_eq!(engine.time_visible_range(), (20.0, 80.0));
+    assert!(engine.redo_view().expect("redo"));
+    assert_eq!(engine.time_visible_range(), (40.0, 60.0));
+}
+
+#[test]
+fn history_is_bounded_and_can_be_disabled() {
+    let mut engine = engine(
+        config().with_navigation_history_behavior(NavigationHistoryBehavior { max_entries: 2 }),
+    );
+    for _ in 0..5 {
+        drag(&mut engine, 20.0);
+    }
+    assert!(engine.undo_view().expect("undo"));
+    assert!(engine.undo_view().expect("undo"));
+    assert!(!engine.undo_view().expect("undo"));
+
+    engine.set_navigation_history_behavior(NavigationHistoryBehavior { max_entries: 0 });
+    drag(&mut engine, 20.0);
+    assert!(!engine.undo_view().expect("undo"));
+}
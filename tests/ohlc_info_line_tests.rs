@@ -0,0 +1,137 @@
+use chart_rs::ChartError;
+use chart_rs::api::{
+    ChartEngine, ChartEngineConfig, OhlcInfoDirection, OhlcInfoLineBehavior, OhlcInfoLineMode,
+};
+use chart_rs::core::{OhlcBar, Viewport};
+use chart_rs::render::{CanvasLayerKind, NullRenderer};
+
+fn engine(mode: OhlcInfoLineMode) -> ChartEngine<NullRenderer> {
+    let config = ChartEngineConfig::new(Viewport::new(1000, 500), 0.0, 4.0)
+        .with_price_domain(0.0, 200.0)
+        .with_ohlc_info_line_behavior(OhlcInfoLineBehavior {
+            mode,
+            ..OhlcInfoLineBehavior::default()
+        });
+    let mut engine = ChartEngine::new(NullRenderer::default(), config).expect("engine");
+    engine.set_candles(vec![
+        OhlcBar::new(0.0, 100.0, 105.0, 95.0, 100.0).expect("bar"),
+        OhlcBar::new(1.0, 100.0, 112.0, 99.0, 110.0).expect("bar"),
+        OhlcBar::new(2.0, 110.0, 111.0, 98.0, 99.0).expect("bar"),
+    ]);
+    engine
+}
+
+#[test]
+fn info_line_reads_latest_bar_without_crosshair() {
+    let engine = engine(OhlcInfoLineMode::DataOnly);
+    let line = engine.ohlc_info_line().expect("info line");
+    let style = engine.render_style();
+
+    assert!(!line.hovered);
+    assert_eq!(line.bar.time, 2.0);
+    assert_eq!(line.change, -11.0);
+    assert_eq!(line.change_percent, Some(-10.0));
+
+    let labels: Vec<_> = line.fields.iter().map(|field| field.label).collect();
+    assert_eq!(labels, ["O", "H", "L", "C", "", ""]);
+    assert!(line.fields.iter().all(|field| {
+        field.direction == OhlcInfoDirection::Down && field.color == style.candlestick_down_color
+    }));
+    assert_eq!(line.fields[5].value, "(-10.00%)");
+}
+
+#[test]
+fn info_line_follows_hovered_bar() {
+    let mut engine = engine(OhlcInfoLineMode::DataOnly);
+    let x = engine.map_x_to_pixel(1.0).expect("pixel");
+    engine.pointer_move(x, 100.0);
+
+    let line = engine.ohlc_info_line().expect("info line");
+    assert!(line.hovered);
+    assert_eq!(line.bar.time, 1.0);
+    assert_eq!(line.change, 10.0);
+    assert_eq!(line.fields[0].direction, OhlcInfoDirection::Up);
+    assert!(line.fields[4].value.starts_with('+'));
+    assert_eq!(line.fields[5].value, "(+10.00%)");
+
+    let x = engine.map_x_to_pixel(0.0).expect("pixel");
+    engine.pointer_move(x, 100.0);
+    let line = engine.ohlc_info_line().expect("info line");
+    assert_eq!(line.change, 0.0);
+    assert!(
+        line.fields
+            .iter()
+            .all(|field| field.direction == OhlcInfoDirection::Neutral)
+    );
+}
+
+#[test]
+fn hidden_and_data_only_modes_render_nothing() {
+    for mode in [OhlcInfoLineMode::Hidden, OhlcInfoLineMode::DataOnly] {
+        let engine = engine(mode);
+        let baseline = engine.build_render_frame().expect("frame");
+        assert!(!baseline.texts.iter().any(|text| &*text.text == "O"));
+    }
+    assert!(engine(OhlcInfoLineMode::Hidden).ohlc_info_line().is_none());
+}
+
+#[test]
+fn rendered_mode_draws_fields_in_crosshair_layer() {
+    let engine = engine(OhlcInfoLineMode::Rendered);
+    let behavior = engine.ohlc_info_line_behavior();
+    let line = engine.ohlc_info_line().expect("info line");
+
+    let frame = engine.build_render_frame().expect("frame");
+    let label = frame
+        .texts
+        .iter()
+        .find(|text| &*text.text == "O")
+        .expect("O label");
+    assert_eq!(
+        (label.x, label.y),
+        (behavior.padding_px, behavior.padding_px)
+    );
+    let values: Vec<_> = frame
+        .texts
+        .iter()
+        .filter(|text| text.y == behavior.padding_px)
+        .map(|text| text.text.as_ref())
+        .collect();
+    for field in &line.fields {
+        assert!(values.contains(&field.value.as_str()));
+    }
+
+    let layered = engine.build_layered_render_frame().expect("layered");
+    let crosshair_texts = layered.panes[0]
+        .layers
+        .iter()
+        .find(|layer| layer.kind == CanvasLayerKind::Crosshair)
+        .expect("crosshair layer")
+        .texts
+        .len();
+    assert_eq!(crosshair_texts, line.fields.len() + 4);
+}
+
+#[test]
+fn invalid_behavior_is_rejected_and_scene_keeps_behavior() {
+    let mut engine = engine(OhlcInfoLineMode::Rendered);
+    let err = engine
+        .set_ohlc_info_line_behavior(OhlcInfoLineBehavior {
+            font_size_px: 0.0,
+            ..OhlcInfoLineBehavior::default()
+        })
+        .expect_err("zero font size");
+    assert!(matches!(err, ChartError::InvalidData(_)));
+
+    let json = engine.export_scene_json_pretty().expect("export");
+    let mut restored = ChartEngine::new(
+        NullRenderer::default(),
+        ChartEngineConfig::new(Viewport::new(1000, 500), 0.0, 4.0),
+    )
+    .expect("engine");
+    restored.import_scene_json_str(&json).expect("import");
+    assert_eq!(
+        restored.ohlc_info_line_behavior(),
+        engine.ohlc_info_line_behavior()
+    );
+}
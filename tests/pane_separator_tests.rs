@@ -0,0 +1,189 @@
+use chart_rs::ChartError;
+use chart_rs::api::{
+    ChartEngine, ChartEngineConfig, InteractionOptions, PaneSeparatorBehavior, PointerCursorHint,
+    PointerInput, PointerInputBridge, PointerRegion,
+};
+use chart_rs::core::Viewport;
+use chart_rs::render::NullRenderer;
+
+fn engine_with_panes(extra_panes: usize) -> ChartEngine<NullRenderer> {
+    let config =
+        ChartEngineConfig::new(Viewport::new(800, 400), 0.0, 100.0).with_price_domain(0.0, 50.0);
+    let mut engine = ChartEngine::new(NullRenderer::default(), config).expect("engine");
+    for _ in 0..extra_panes {
+        engine.create_pane(1.0).expect("pane");
+    }
+    engine
+}
+
+fn pane_heights(engine: &ChartEngine<NullRenderer>) -> Vec<f64> {
+    engine
+        .pane_plot_regions_for_current_viewport()
+        .iter()
+        .map(|region| region.height())
+        .collect()
+}
+
+#[test]
+fn separators_sit_between_adjacent_panes() {
+    let engine = engine_with_panes(2);
+    let regions = engine.pane_plot_regions_for_current_viewport();
+    let separators = engine.pane_separators();
+
+    assert_eq!(separators.len(), 2);
+    for (index, separator) in separators.iter().enumerate() {
+        assert_eq!(separator.index, index);
+        assert_eq!(separator.upper_pane_id, regions[index].pane_id);
+        assert_eq!(separator.lower_pane_id, regions[index + 1].pane_id);
+        assert_eq!(separator.y, regions[index].plot_bottom);
+    }
+    assert!(engine_with_panes(0).pane_separators().is_empty());
+}
+
+#[test]
+fn pointer_region_reports_separator_hit_zone() {
+    let mut engine = engine_with_panes(1);
+    let y = engine.pane_separators()[0].y;
+
+    assert_eq!(
+        engine.pointer_region(100.0, y + 3.0),
+        PointerRegion::PaneSeparator { index: 0 }
+    );
+    assert_eq!(
+        engine.pointer_region(790.0, y - 3.0),
+        PointerRegion::PaneSeparator { index: 0 }
+    );
+    assert_eq!(engine.pointer_region(100.0, y + 10.0), PointerRegion::Plot);
+
+    engine
+        .set_pane_separator_behavior(PaneSeparatorBehavior {
+            draggable: false,
+            ..PaneSeparatorBehavior::default()
+        })
+        .expect("behavior");
+    assert_eq!(engine.pointer_region(100.0, y), PointerRegion::Plot);
+    assert_eq!(engine.pane_separator_at(100.0, y), None);
+}
+
+#[test]
+fn separator_drag_trades_height_between_neighbors_and_clamps() {
+    let mut engine = engine_with_panes(2);
+    let before = pane_heights(&engine);
+    let total: f64 = before.iter().sum();
+
+    assert!(engine.drag_pane_separator(0, 40.0).expect("drag"));
+    let after = pane_heights(&engine);
+    assert!((after[0] - (before[0] + 40.0)).abs() <= 1e-6);
+    assert!((after[1] - (before[1] - 40.0)).abs() <= 1e-6);
+    assert!((after[2] - before[2]).abs() <= 1e-6);
+
+    engine.drag_pane_separator(0, 1_000.0).expect("drag");
+    let clamped = pane_heights(&engine);
+    assert!((clamped[1] - 30.0).abs() <= 1e-6);
+    assert!((clamped.iter().sum::<f64>() - total).abs() <= 1e-6);
+
+    assert!(!engine.drag_pane_separator(0, 10.0).expect("pinned at min"));
+    assert!(!engine.drag_pane_separator(2, 10.0).expect("unknown index"));
+    let stretch_sum: f64 = engine.panes().iter().map(|pane| pane.stretch_factor).sum();
+    assert!((stretch_sum - 1.0).abs() <= 1e-9);
+}
+
+#[test]
+fn bridge_drags_separator_and_reports_cursor_hints() {
+    let mut engine = engine_with_panes(1);
+    let mut bridge = PointerInputBridge::default();
+    let y = engine.pane_separators()[0].y;
+    let before = pane_heights(&engine);
+
+    bridge
+        .handle(&mut engine, PointerInput::Moved { x: 100.0, y })
+        .expect("hover");
+    assert_eq!(bridge.cursor_hint(), PointerCursorHint::ResizeVertical);
+    assert_eq!(
+        bridge.take_cursor_hint_change(),
+        Some(PointerCursorHint::ResizeVertical)
+    );
+    assert_eq!(bridge.take_cursor_hint_change(), None);
+
+    let range = engine.time_visible_range();
+    bridge
+        .handle(&mut engine, PointerInput::Pressed { x: 100.0, y })
+        .expect("press");
+    bridge
+        .handle(
+            &mut engine,
+            PointerInput::Moved {
+                x: 140.0,
+                y: y - 25.0,
+            },
+        )
+        .expect("drag");
+    let after = pane_heights(&engine);
+    assert!((after[0] - (before[0] - 25.0)).abs() <= 1e-6);
+    assert_eq!(engine.time_visible_range(), range);
+    assert_eq!(bridge.take_cursor_hint_change(), None);
+
+    bridge
+        .handle(&mut engine, PointerInput::Released)
+        .expect("release");
+    bridge
+        .handle(&mut engine, PointerInput::Left)
+        .expect("leave");
+    assert_eq!(
+        bridge.take_cursor_hint_change(),
+        Some(PointerCursorHint::Default)
+    );
+
+    let mut disabled =
+        PointerInputBridge::new(InteractionOptions::default().with_pane_separator_drag(false));
+    let y = engine.pane_separators()[0].y;
+    disabled
+        .handle(&mut engine, PointerInput::Moved { x: 100.0, y })
+        .expect("hover");
+    assert_eq!(disabled.cursor_hint(), PointerCursorHint::Default);
+    disabled
+        .handle(&mut engine, PointerInput::Pressed { x: 100.0, y })
+        .expect("press");
+    assert!(!disabled.is_dragging());
+}
+
+#[test]
+fn pane_ratios_and_separator_behavior_persist_in_scene() {
+    let mut engine = engine_with_panes(1);
+    let behavior = PaneSeparatorBehavior {
+        draggable: true,
+        hit_zone_half_height_px: 6.0,
+        min_pane_height_px: 50.0,
+    };
+    engine
+        .set_pane_separator_behavior(behavior)
+        .expect("behavior");
+    engine.drag_pane_separator(0, 60.0).expect("drag");
+    let heights = pane_heights(&engine);
+
+    let json = engine.export_scene_json_pretty().expect("export");
+    let mut restored = engine_with_panes(0);
+    restored.import_scene_json_str(&json).expect("import");
+    assert_eq!(restored.pane_separator_behavior(), behavior);
+    assert_eq!(restored.panes(), engine.panes());
+    assert_eq!(pane_heights(&restored), heights);
+}
+
+#[test]
+fn invalid_separator_behavior_is_rejected() {
+    let mut engine = engine_with_panes(1);
+    let err = engine
+        .set_pane_separator_behavior(PaneSeparatorBehavior {
+            min_pane_height_px: -1.0,
+            ..PaneSeparatorBehavior::default()
+        })
+        .expect_err("negative min height");
+    assert!(matches!(err, ChartError::InvalidData(_)));
+
+    let config = ChartEngineConfig::new(Viewport::new(800, 400), 0.0, 1.0)
+        .with_pane_separator_behavior(PaneSeparatorBehavior {
+            hit_zone_half_height_px: f64::NAN,
+            ..PaneSeparatorBehavior::default()
+        });
+    assert!(ChartEngine::new(NullRenderer::default(), config).is_err());
+}
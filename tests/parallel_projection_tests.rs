@@ -0,0 +1,95 @@
+use chart_rs::ChartError;
+use chart_rs::core::{
+    DataPoint, OhlcBar, PARALLEL_PROJECTION_MIN_LEN, PriceScale, PriceScaleMode, TimeScale,
+    Viewport, project_bars, project_candles, project_histogram_bars, project_line_segments,
+};
+
+const LEN: usize = PARALLEL_PROJECTION_MIN_LEN * 3 + 17;
+
+fn scales() -> (TimeScale, PriceScale, Viewport) {
+    (
+        TimeScale::new(0.0, LEN as f64).expect("time scale"),
+        PriceScale::new(0.0, 200.0).expect("price scale"),
+        Viewport::new(1920, 1080),
+    )
+}
+
+fn points() -> Vec<DataPoint> {
+    (0..LEN)
+        .map(|i| DataPoint::new(i as f64, 100.0 + (i as f64 * 0.01).sin() * 50.0))
+        .collect()
+}
+
+fn bars() -> Vec<OhlcBar> {
+    points()
+        .into_iter()
+        .map(|point| {
+            OhlcBar::new(
+                point.x,
+                point.y,
+                point.y + 2.0,
+                point.y - 2.0,
+                point.y + 1.0,
+            )
+            .expect("bar")
+        })
+        .collect()
+}
+
+#[test]
+fn large_candle_projection_matches_small_window_projection() {
+    let (time_scale, price_scale, viewport) = scales();
+    let bars = bars();
+    let whole = project_candles(&bars, time_scale, price_scale, viewport, 5.0).expect("whole");
+    let windowed: Vec<_> = bars
+        .chunks(PARALLEL_PROJECTION_MIN_LEN / 2)
+        .flat_map(|chunk| {
+            project_candles(chunk, time_scale, price_scale, viewport, 5.0).expect("chunk")
+        })
+        .collect();
+    assert_eq!(whole, windowed);
+}
+
+#[test]
+fn large_point_projections_keep_input_order() {
+    let (time_scale, price_scale, viewport) = scales();
+    let points = points();
+    let segments =
+        project_line_segments(&points, time_scale, price_scale, viewport).expect("segments");
+    assert_eq!(segments.len(), LEN - 1);
+    assert!(segments.windows(2).all(|pair| pair[0].x2 == pair[1].x1));
+    assert!(segments.iter().all(|segment| segment.x1 < segment.x2));
+
+    let histogram = project_histogram_bars(&points, time_scale, price_scale, viewport, 3.0, 0.0)
+        .expect("histogram");
+    assert!(
+        histogram
+            .windows(2)
+            .all(|pair| pair[0].x_center < pair[1].x_center)
+    );
+}
+
+#[test]
+fn large_projection_is_deterministic() {
+    let (time_scale, price_scale, viewport) = scales();
+    let bars = bars();
+    let first = project_bars(&bars, time_scale, price_scale, viewport, 4.0).expect("first");
+    let second = project_bars(&bars, time_scale, price_scale, viewport, 4.0).expect("second");
+    assert_eq!(first, second);
+}
+
+#[test]
+fn large_projection_reports_the_first_failing_sample() {
+    let (time_scale, _, viewport) = scales();
+    let price_scale =
+        PriceScale::new_with_mode(1.0, 200.0, PriceScaleMode::Log).expect("log price scale");
+    let mut points = points();
+    points[PARALLEL_PROJECTION_MIN_LEN + 5].y = -1.0;
+    points[LEN - 10].y = f64::NAN;
+    let err = project_line_segments(&points, time_scale, price_scale, viewport)
+        .expect_err("invalid samples");
+    assert!(
+        matches!(err, ChartError::InvalidData(ref message) if message.contains("log price scale")),
+        "{err:?}"
+    );
+}
@@ -42,6 +42,15 @@ fn event_kind(event: &PluginEvent) -> &'static str {
         PluginEvent::PanStarted => "pan_start",
         PluginEvent::PanEnded => "pan_end",
         PluginEvent::Rendered => "rendered",
+        PluginEvent::PrimitiveHovered { .. } => "primitive_hovered",
+        PluginEvent::PrimitiveHoverEnded { .. } => "primitive_hover_ended",
+        PluginEvent::PrimitiveClicked { .. } => "primitive_clicked",
+        PluginEvent::OrderPriceChanged { .. } => "order_price_changed",
+        PluginEvent::AlertTriggered { .. } => "alert_triggered",
+        PluginEvent::ZoomSelectionStarted { .. } => "zoom_selection_started",
+        PluginEvent::ZoomSelectionCompleted { .. } => "zoom_selection_completed",
+        PluginEvent::ZoomSelectionCancelled => "zoom_selection_cancelled",
+        PluginEvent::SelectionChanged { .. } => "selection_changed",
     }
 }
 
@@ -58,7 +67,9 @@ fn plugin_receives_deterministic_event_sequence() {
         .expect("register plugin");
 
     engine.set_data(vec![DataPoint::new(10.0, 10.0)]);
-    engine.append_point(DataPoint::new(20.0, 20.0));
+    engine
+        .append_point(DataPoint::new(20.0, 20.0))
+        .expect("append");
     engine.set_candles(vec![
         OhlcBar::new(10.0, 9.0, 12.0, 8.0, 11.0).expect("valid candle"),
     ]);
@@ -125,7 +136,9 @@ fn unregister_plugin_stops_dispatch() {
     assert_eq!(engine.plugin_count(), 0);
     assert!(!engine.has_plugin("to-remove"));
 
-    engine.append_point(DataPoint::new(2.0, 2.0));
+    engine
+        .append_point(DataPoint::new(2.0, 2.0))
+        .expect("append");
     assert_eq!(events.borrow().len(), 1);
 }
 
@@ -147,10 +160,10 @@ fn visible_range_event_contains_new_range() {
     let last = events
         .borrow()
         .last()
-        .copied()
+        .cloned()
         .expect("range event expected");
     match last {
-        PluginEvent::VisibleRangeChanged { start, end } => {
+        PluginEvent::VisibleRangeChanged { start, end, .. } => {
             assert!((start - 15.0).abs() <= 1e-9);
             assert!((end - 40.0).abs() <= 1e-9);
         }
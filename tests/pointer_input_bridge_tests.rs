@@ -0,0 +1,267 @@
+use chart_rs::api::{
+    ChartEngine, ChartEngineConfig, InteractionOptions, PointerInput, PointerInputBridge,
+    PointerModifiers, PointerRegion, TouchGestureTracker, WheelBehaviorConfig,
+};
+use chart_rs::core::{DataPoint, Viewport};
+use chart_rs::render::NullRenderer;
+
+fn engine() -> ChartEngine<NullRenderer> {
+    let config =
+        ChartEngineConfig::new(Viewport::new(800, 400), 0.0, 100.0).with_price_domain(0.0, 50.0);
+    let mut engine = ChartEngine::new(NullRenderer::default(), config).expect("engine");
+    engine.set_data(
+        (0..=100)
+            .map(|i| DataPoint::new(f64::from(i), 20.0 + f64::from(i % 5)))
+            .collect(),
+    );
+    engine
+}
+
+fn plot_bounds(engine: &ChartEngine<NullRenderer>) -> (f64, f64) {
+    let style = engine.render_style();
+    (
+        800.0 - style.price_axis_width_px,
+        400.0 - style.time_axis_height_px,
+    )
+}
+
+#[test]
+fn pointer_region_follows_axis_panels() {
+    let engine = engine();
+    let (plot_right, plot_bottom) = plot_bounds(&engine);
+
+    assert_eq!(engine.pointer_region(10.0, 10.0), PointerRegion::Plot);
+    assert_eq!(
+        engine.pointer_region(plot_right + 1.0, 10.0),
+        PointerRegion::PriceAxis
+    );
+    assert_eq!(
+        engine.pointer_region(10.0, plot_bottom + 1.0),
+        PointerRegion::TimeAxis
+    );
+}
+
+#[test]
+fn motion_and_leave_drive_the_crosshair() {
+    let mut engine = engine();
+    let mut bridge = PointerInputBridge::default();
+
+    bridge
+        .handle(&mut engine, PointerInput::Moved { x: 120.0, y: 80.0 })
+        .expect("move");
+    assert!(engine.crosshair_state().visible);
+    bridge
+        .handle(&mut engine, PointerInput::Left)
+        .expect("leave");
+    assert!(!engine.crosshair_state().visible);
+}
+
+#[test]
+fn plot_drag_pans_and_releases() {
+    let mut engine = engine();
+    let mut bridge = PointerInputBridge::default();
+    let before = engine.time_visible_range();
+
+    bridge
+        .handle(&mut engine, PointerInput::Pressed { x: 200.0, y: 100.0 })
+        .expect("press");
+    assert!(bridge.is_dragging());
+    bridge
+        .handle(&mut engine, PointerInput::Moved { x: 260.0, y: 100.0 })
+        .expect("drag");
+    bridge
+        .handle(&mut engine, PointerInput::Released)
+        .expect("release");
+
+    let after = engine.time_visible_range();
+    assert!(!bridge.is_dragging());
+    assert!(after.0 < before.0);
+    assert!(((after.1 - after.0) - (before.1 - before.0)).abs() < 1e-9);
+}
+
+#[test]
+fn disabled_gestures_leave_the_engine_untouched() {
+    let mut engine = engine();
+    engine
+        .set_wheel_behavior_config(WheelBehaviorConfig {
+            zoom_axis: None,
+            ..WheelBehaviorConfig::default()
+        })
+        .expect("wheel config");
+    let mut bridge = PointerInputBridge::new(
+        InteractionOptions::default()
+            .with_drag_pan(false)
+            .with_pinch_zoom(false),
+    );
+    let before = engine.time_visible_range();
+
+    bridge
+        .handle(&mut engine, PointerInput::Pressed { x: 200.0, y: 100.0 })
+        .expect("press");
+    assert!(!bridge.is_dragging());
+    bridge
+        .handle(&mut engine, PointerInput::Moved { x: 260.0, y: 100.0 })
+        .expect("move");
+    bridge
+        .handle(
+            &mut engine,
+            PointerInput::Scrolled {
+                delta_x: 0.0,
+                delta_y: -1.0,
+            },
+        )
+        .expect("scroll");
+    bridge
+        .handle(
+            &mut engine,
+            PointerInput::Pinched {
+                factor: 2.0,
+                anchor_x: 300.0,
+            },
+        )
+        .expect("pinch");
+
+    assert_eq!(engine.time_visible_range(), before);
+}
+
+#[test]
+fn scroll_zoom_anchors_on_the_tracked_pointer() {
+    let mut engine = engine();
+    let mut bridge = PointerInputBridge::default();
+    let anchor_px = 100.0;
+    bridge
+        .handle(
+            &mut engine,
+            PointerInput::Moved {
+                x: anchor_px,
+                y: 50.0,
+            },
+        )
+        .expect("move");
+    let anchor_time = engine.map_pixel_to_x(anchor_px).expect("anchor time");
+
+    bridge
+        .handle(
+            &mut engine,
+            PointerInput::Scrolled {
+                delta_x: 0.0,
+                delta_y: -1.0,
+            },
+        )
+        .expect("scroll");
+
+    let (start, end) = engine.time_visible_range();
+    assert!(end - start < 100.0);
+    let anchor_after = engine.map_pixel_to_x(anchor_px).expect("anchor time");
+    assert!((anchor_after - anchor_time).abs() < 1e-6);
+}
+
+#[test]
+fn scroll_follows_the_engine_wheel_behavior_and_held_modifiers() {
+    let mut engine = engine();
+    engine
+        .set_wheel_behavior_config(WheelBehaviorConfig {
+            zoom_requires_control: true,
+            ..WheelBehaviorConfig::default()
+        })
+        .expect("wheel config");
+    let mut bridge = PointerInputBridge::default();
+    let scroll_up = PointerInput::Scrolled {
+        delta_x: 0.0,
+        delta_y: -1.0,
+    };
+
+    bridge.handle(&mut engine, scroll_up).expect("scroll");
+    assert_eq!(engine.time_visible_range(), (0.0, 100.0));
+
+    bridge
+        .handle(
+            &mut engine,
+            PointerInput::ModifiersChanged {
+                modifiers: PointerModifiers {
+                    control: true,
+                    ..PointerModifiers::NONE
+                },
+            },
+        )
+        .expect("modifiers");
+    bridge.handle(&mut engine, scroll_up).expect("scroll");
+    let (start, end) = engine.time_visible_range();
+    assert!(end - start < 100.0);
+}
+
+#[test]
+fn double_click_on_price_axis_resets_price_scale() {
+    let mut engine = engine();
+    let mut bridge = PointerInputBridge::default();
+    let (plot_right, _) = plot_bounds(&engine);
+    let initial = engine.price_domain();
+
+    bridge
+        .handle(
+            &mut engine,
+            PointerInput::Pressed {
+                x: plot_right + 10.0,
+                y: 100.0,
+            },
+        )
+        .expect("press");
+    bridge
+        .handle(
+            &mut engine,
+            PointerInput::Moved {
+                x: plot_right + 10.0,
+                y: 160.0,
+            },
+        )
+        .expect("axis drag");
+    bridge
+        .handle(&mut engine, PointerInput::Released)
+        .expect("release");
+    let dragged = engine.price_domain();
+    assert_ne!(dragged, initial);
+
+    bridge
+        .handle(
+            &mut engine,
+            PointerInput::DoubleClicked {
+                x: plot_right + 10.0,
+                y: 100.0,
+            },
+        )
+        .expect("double click");
+    assert_ne!(engine.price_domain(), dragged);
+}
+
+#[test]
+fn touch_tracker_drags_with_one_finger_and_pinches_with_two() {
+    let mut tracker = TouchGestureTracker::new();
+    assert_eq!(
+        tracker.touch_started(7, 100.0, 50.0),
+        vec![
+            PointerInput::Moved { x: 100.0, y: 50.0 },
+            PointerInput::Pressed { x: 100.0, y: 50.0 }
+        ]
+    );
+    assert_eq!(
+        tracker.touch_moved(7, 120.0, 50.0),
+        vec![PointerInput::Moved { x: 120.0, y: 50.0 }]
+    );
+    assert_eq!(
+        tracker.touch_started(8, 220.0, 50.0),
+        vec![PointerInput::Released]
+    );
+    assert_eq!(
+        tracker.touch_moved(8, 170.0, 50.0),
+        vec![PointerInput::Pinched {
+            factor: 0.5,
+            anchor_x: 145.0
+        }]
+    );
+    assert!(tracker.touch_ended(8).is_empty());
+    assert_eq!(tracker.active_touches(), 1);
+    assert_eq!(
+        tracker.touch_ended(7),
+        vec![PointerInput::Released, PointerInput::Left]
+    );
+}
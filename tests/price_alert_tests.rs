@@ -0,0 +1,250 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use chart_rs::api::{
+    ChartEngine, ChartEngineConfig, InteractionOptions, PointerInput, PointerInputBridge,
+    PointerModifiers, SeriesPixelSpace, SeriesRef,
+};
+use chart_rs::core::{OhlcBar, Viewport};
+use chart_rs::extensions::{AlertDirection, ChartPlugin, PluginContext, PluginEvent, PriceAlert};
+use chart_rs::render::{CanvasLayerKind, LineStrokeStyle, NullRenderer};
+
+type EventLog = Rc<RefCell<Vec<PluginEvent>>>;
+
+struct AlertListener {
+    events: EventLog,
+}
+
+impl ChartPlugin for AlertListener {
+    fn id(&self) -> &str {
+        "alerts"
+    }
+
+    fn on_event(&mut self, event: PluginEvent, _context: PluginContext) {
+        if matches!(event, PluginEvent::AlertTriggered { .. }) {
+            self.events.borrow_mut().push(event);
+        }
+    }
+}
+
+fn bar(time: f64, close: f64) -> OhlcBar {
+    OhlcBar::new(time, close, close + 1.0, close - 1.0, close).expect("bar")
+}
+
+fn engine_with_listener(events: &EventLog) -> ChartEngine<NullRenderer> {
+    let config =
+        ChartEngineConfig::new(Viewport::new(800, 400), 0.0, 100.0).with_price_domain(0.0, 50.0);
+    let mut engine = ChartEngine::new(NullRenderer::default(), config).expect("engine");
+    engine
+        .register_plugin(Box::new(AlertListener {
+            events: Rc::clone(events),
+        }))
+        .expect("plugin");
+    engine.set_candles((0..5).map(|i| bar(f64::from(i) * 10.0, 20.0)).collect());
+    engine
+}
+
+fn triggered(events: &EventLog) -> Vec<(String, f64, AlertDirection)> {
+    events
+        .borrow()
+        .iter()
+        .filter_map(|event| match event {
+            PluginEvent::AlertTriggered {
+                id,
+                price,
+                direction,
+            } => Some((id.clone(), *price, *direction)),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn crossing_requires_reaching_the_level_from_the_other_side() {
+    assert_eq!(
+        AlertDirection::crossing(19.0, 21.0, 20.0),
+        Some(AlertDirection::Up)
+    );
+    assert_eq!(
+        AlertDirection::crossing(19.0, 20.0, 20.0),
+        Some(AlertDirection::Up)
+    );
+    assert_eq!(
+        AlertDirection::crossing(21.0, 19.0, 20.0),
+        Some(AlertDirection::Down)
+    );
+    assert_eq!(AlertDirection::crossing(20.0, 21.0, 20.0), None);
+    assert_eq!(AlertDirection::crossing(18.0, 19.0, 20.0), None);
+}
+
+#[test]
+fn last_price_crossings_emit_alert_events() {
+    let events = EventLog::default();
+    let mut engine = engine_with_listener(&events);
+    engine
+        .set_price_alerts(vec![
+            PriceAlert::new("above", 25.0),
+            PriceAlert::new("below", 15.0),
+        ])
+        .expect("alerts");
+
+    engine.append_candle(bar(50.0, 24.0)).expect("append");
+    assert!(triggered(&events).is_empty());
+    engine.append_candle(bar(60.0, 26.0)).expect("append");
+    engine.update_candle(bar(60.0, 14.0)).expect("update");
+    assert_eq!(
+        triggered(&events),
+        vec![
+            ("above".to_owned(), 25.0, AlertDirection::Up),
+            ("above".to_owned(), 25.0, AlertDirection::Down),
+            ("below".to_owned(), 15.0, AlertDirection::Down),
+        ]
+    );
+
+    assert!(engine.remove_price_alert("below"));
+    assert!(!engine.remove_price_alert("below"));
+    engine.append_candle(bar(70.0, 16.0)).expect("append");
+    assert_eq!(triggered(&events).len(), 3);
+}
+
+#[test]
+fn alt_press_on_plot_or_price_axis_creates_alert_without_panning() {
+    let events = EventLog::default();
+    let mut engine = engine_with_listener(&events);
+    let range = engine.time_visible_range();
+    let y = engine
+        .series_price_to_pixel(SeriesRef::Candles, 20.5, SeriesPixelSpace::Content)
+        .expect("y");
+    let axis_x = f64::from(engine.viewport().width) - 5.0;
+
+    let mut bridge = PointerInputBridge::new(InteractionOptions::default());
+    for input in [
+        PointerInput::ModifiersChanged {
+            modifiers: PointerModifiers::ALT,
+        },
+        PointerInput::Pressed { x: 200.0, y },
+        PointerInput::Moved { x: 260.0, y },
+        PointerInput::Released,
+        PointerInput::Pressed { x: axis_x, y },
+        PointerInput::Released,
+    ] {
+        bridge.handle(&mut engine, input).expect("input");
+    }
+    let ids: Vec<_> = engine
+        .price_alerts()
+        .iter()
+        .map(|alert| alert.id.as_str())
+        .collect();
+    assert_eq!(ids, vec!["alert-1", "alert-2"]);
+    assert!((engine.price_alerts()[0].price - 20.5).abs() < 1e-9);
+    assert_eq!(engine.time_visible_range(), range);
+
+    // Without the modifier a press pans as usual.
+    bridge
+        .handle(
+            &mut engine,
+            PointerInput::ModifiersChanged {
+                modifiers: PointerModifiers::NONE,
+            },
+        )
+        .expect("modifiers");
+    for input in [
+        PointerInput::Pressed { x: 200.0, y },
+        PointerInput::Moved { x: 260.0, y },
+        PointerInput::Released,
+    ] {
+        bridge.handle(&mut engine, input).expect("input");
+    }
+    assert_eq!(engine.price_alerts().len(), 2);
+    assert_ne!(engine.time_visible_range(), range);
+
+    let mut disabled =
+        PointerInputBridge::new(InteractionOptions::default().with_alert_creation(None));
+    for input in [
+        PointerInput::ModifiersChanged {
+            modifiers: PointerModifiers::ALT,
+        },
+        PointerInput::Pressed { x: 200.0, y },
+        PointerInput::Released,
+    ] {
+        disabled.handle(&mut engine, input).expect("input");
+    }
+    assert_eq!(engine.price_alerts().len(), 2);
+}
+
+#[test]
+fn alerts_render_as_dashed_overlay_lines_with_price_labels() {
+    let events = EventLog::default();
+    let mut engine = engine_with_listener(&events);
+    engine
+        .add_price_alert(PriceAlert::new("a", 20.5))
+        .expect("alert");
+
+    let layered = engine.build_layered_render_frame().expect("layered");
+    let overlay = layered.panes[0]
+        .layers
+        .iter()
+        .find(|layer| layer.kind == CanvasLayerKind::Overlay)
+        .expect("overlay layer");
+    let color = engine.price_alerts()[0].color;
+    let lines: Vec<_> = overlay
+        .lines
+        .iter()
+        .filter(|line| line.color == color)
+        .collect();
+    assert_eq!(lines.len(), 1);
+    assert_eq!(lines[0].stroke_style, LineStrokeStyle::Dashed);
+    let y = engine
+        .series_price_to_pixel(SeriesRef::Candles, 20.5, SeriesPixelSpace::Content)
+        .expect("y");
+    assert!((lines[0].y1 - y).abs() < 1e-9);
+    assert_eq!(overlay.texts.len(), 1);
+    assert!(overlay.texts[0].text.starts_with("20.5"));
+}
+
+#[test]
+fn alerts_persist_through_scenes_without_triggering() {
+    let events = EventLog::default();
+    let mut source = engine_with_listener(&events);
+    source
+        .add_price_alert(PriceAlert::new("a", 30.0))
+        .expect("alert");
+    let json = source.export_scene_json_pretty().expect("json");
+
+    let mut target = engine_with_listener(&events);
+    target
+        .add_price_alert(PriceAlert::new("stale", 22.0))
+        .expect("alert");
+    target.append_candle(bar(50.0, 21.0)).expect("append");
+    target.import_scene_json_str(&json).expect("import");
+    assert_eq!(target.price_alerts(), source.price_alerts());
+    assert!(triggered(&events).is_empty());
+
+    target.append_candle(bar(50.0, 31.0)).expect("append");
+    assert_eq!(
+        triggered(&events),
+        vec![("a".to_owned(), 30.0, AlertDirection::Up)]
+    );
+}
+
+#[test]
+fn invalid_and_duplicate_alerts_are_rejected() {
+    let events = EventLog::default();
+    let mut engine = engine_with_listener(&events);
+    assert!(
+        engine
+            .add_price_alert(PriceAlert::new("a", f64::NAN))
+            .is_err()
+    );
+    assert!(engine.add_price_alert(PriceAlert::new("", 10.0)).is_err());
+    engine
+        .add_price_alert(PriceAlert::new("a", 10.0))
+        .expect("alert");
+    assert!(engine.add_price_alert(PriceAlert::new("a", 12.0)).is_err());
+    assert!(
+        engine
+            .set_price_alerts(vec![PriceAlert::new("b", 1.0), PriceAlert::new("b", 2.0)])
+            .is_err()
+    );
+    assert_eq!(engine.price_alerts().len(), 1);
+}
@@ -0,0 +1,102 @@
+use chart_rs::ChartError;
+use chart_rs::api::{
+    ChartEngine, ChartEngineConfig, PointerRegion, PriceAxisWidthMode, RenderStyle,
+};
+use chart_rs::core::{DataPoint, Viewport};
+use chart_rs::render::NullRenderer;
+
+const AUTO: PriceAxisWidthMode = PriceAxisWidthMode::Auto {
+    min_width_px: 30.0,
+    max_width_px: 200.0,
+    hysteresis_px: 20.0,
+};
+
+fn engine(mode: PriceAxisWidthMode) -> ChartEngine<NullRenderer> {
+    let config = ChartEngineConfig::new(Viewport::new(800, 400), 0.0, 100.0);
+    let mut engine = ChartEngine::new(NullRenderer::default(), config).expect("engine");
+    engine
+        .set_render_style(RenderStyle {
+            price_axis_width_mode: mode,
+            ..engine.render_style()
+        })
+        .expect("style");
+    engine
+}
+
+fn resolve_width_for(engine: &mut ChartEngine<NullRenderer>, low: f64, high: f64) -> f64 {
+    engine.set_data(vec![DataPoint::new(10.0, low), DataPoint::new(90.0, high)]);
+    engine.autoscale_price_from_data().expect("autoscale");
+    engine.build_render_frame().expect("frame");
+    engine.resolved_price_axis_width_px()
+}
+
+#[test]
+fn auto_width_follows_widest_label() {
+    let mut short = engine(AUTO);
+    let short_width = resolve_width_for(&mut short, 0.0, 10.0);
+    let mut long = engine(AUTO);
+    let long_width = resolve_width_for(&mut long, 100_000.0, 100_010.0);
+
+    assert!(short_width < RenderStyle::default().price_axis_width_px);
+    assert!(long_width > short_width);
+    assert!((30.0..=200.0).contains(&short_width));
+}
+
+#[test]
+fn auto_width_shrinks_only_past_hysteresis() {
+    let mut engine = engine(AUTO);
+    let wide = resolve_width_for(&mut engine, 100_000.0, 100_010.0);
+    let medium = resolve_width_for(&mut engine, 1_000.0, 1_010.0);
+    assert_eq!(medium, wide);
+
+    let narrow = resolve_width_for(&mut engine, 0.0, 10.0);
+    assert!(narrow < wide - 20.0);
+    assert_eq!(resolve_width_for(&mut engine, 100_000.0, 100_010.0), wide);
+}
+
+#[test]
+fn auto_width_is_clamped_to_configured_bounds() {
+    let mut engine = engine(PriceAxisWidthMode::Auto {
+        min_width_px: 90.0,
+        max_width_px: 100.0,
+        hysteresis_px: 0.0,
+    });
+    assert_eq!(resolve_width_for(&mut engine, 0.0, 10.0), 90.0);
+    assert_eq!(resolve_width_for(&mut engine, 1.0e12, 1.0e12 + 10.0), 100.0);
+}
+
+#[test]
+fn fixed_mode_keeps_styled_width_for_short_labels() {
+    let mut engine = engine(PriceAxisWidthMode::Fixed);
+    assert_eq!(engine.resolved_price_axis_width_px(), 72.0);
+    assert_eq!(resolve_width_for(&mut engine, 0.0, 10.0), 72.0);
+}
+
+#[test]
+fn pointer_region_uses_resolved_auto_width() {
+    let mut engine = engine(AUTO);
+    let width = resolve_width_for(&mut engine, 0.0, 10.0);
+    let x = 800.0 - width - 2.0;
+    assert!(800.0 - 72.0 < x);
+    assert_eq!(engine.pointer_region(x, 100.0), PointerRegion::Plot);
+    assert_eq!(
+        engine.pointer_region(800.0 - width + 1.0, 100.0),
+        PointerRegion::PriceAxis
+    );
+}
+
+#[test]
+fn invalid_auto_width_bounds_are_rejected() {
+    let mut engine = engine(PriceAxisWidthMode::Fixed);
+    let err = engine
+        .set_render_style(RenderStyle {
+            price_axis_width_mode: PriceAxisWidthMode::Auto {
+                min_width_px: 80.0,
+                max_width_px: 40.0,
+                hysteresis_px: 4.0,
+            },
+            ..engine.render_style()
+        })
+        .expect_err("max below min must fail");
+    assert!(matches!(err, ChartError::InvalidData(_)));
+}
@@ -3,7 +3,7 @@ use std::sync::Arc;
 use chart_rs::ChartError;
 use chart_rs::api::{
     AxisLabelLocale, ChartEngine, ChartEngineConfig, PriceAxisDisplayMode, PriceAxisLabelConfig,
-    PriceAxisLabelPolicy,
+    PriceAxisLabelPolicy, PriceAxisSecondaryLabel, ScientificNotation,
 };
 use chart_rs::core::{DataPoint, PriceScaleMode, Viewport};
 use chart_rs::render::{NullRenderer, TextHAlign};
@@ -13,7 +13,7 @@ fn price_labels(frame: &chart_rs::render::RenderFrame) -> Vec<&str> {
         .texts
         .iter()
         .filter(|label| label.h_align == TextHAlign::Right)
-        .map(|label| label.text.as_str())
+        .map(|label| label.text.as_ref())
         .collect()
 }
 
@@ -104,6 +104,9 @@ fn build_engine_for_fallback_cache_scenario(
             locale,
             policy: PriceAxisLabelPolicy::FixedDecimals { precision: 2 },
             display_mode,
+            secondary_label: PriceAxisSecondaryLabel::Hidden,
+            align_ticks_to_min_move: false,
+            anchor_ticks_to_nice_steps: false,
         })
         .expect("set fallback mode");
     engine
@@ -298,6 +301,88 @@ fn invalid_price_axis_min_move_is_rejected() {
     assert!(matches!(err, ChartError::InvalidData(_)));
 }
 
+#[test]
+fn compact_policy_abbreviates_large_prices() {
+    let renderer = NullRenderer::default();
+    let config = ChartEngineConfig::new(Viewport::new(820, 420), 0.0, 100.0)
+        .with_price_domain(12.0e9, 13.0e9);
+    let mut engine = ChartEngine::new(renderer, config).expect("engine init");
+
+    engine
+        .set_price_axis_label_config(PriceAxisLabelConfig {
+            policy: PriceAxisLabelPolicy::Compact {
+                significant_digits: 3,
+            },
+            ..PriceAxisLabelConfig::default()
+        })
+        .expect("set compact policy");
+
+    let frame = engine.build_render_frame().expect("build frame");
+    let labels = price_labels(&frame);
+    assert!(labels.len() > 2);
+    assert!(labels.iter().all(|label| {
+        let value = label
+            .strip_suffix('B')
+            .and_then(|number| number.parse::<f64>().ok())
+            .expect("compact label");
+        (12.0..=13.0).contains(&value) && label.len() <= 5
+    }));
+}
+
+#[test]
+fn scientific_policy_labels_tiny_prices_without_cache_collisions() {
+    let renderer = NullRenderer::default();
+    let config = ChartEngineConfig::new(Viewport::new(820, 420), 0.0, 100.0)
+        .with_price_domain(0.000_000_001_2, 0.000_000_001_3);
+    let mut engine = ChartEngine::new(renderer, config).expect("engine init");
+
+    engine
+        .set_price_axis_label_config(PriceAxisLabelConfig {
+            locale: AxisLabelLocale::EsEs,
+            policy: PriceAxisLabelPolicy::Scientific {
+                significant_digits: 4,
+                notation: ScientificNotation::SubscriptZeros,
+            },
+            ..PriceAxisLabelConfig::default()
+        })
+        .expect("set scientific policy");
+
+    let frame = engine.build_render_frame().expect("build frame");
+    let labels = price_labels(&frame);
+    assert!(labels.len() > 2);
+    assert!(labels.iter().all(|label| label.starts_with("0,0₈1")));
+    // Ticks closer than a nano still get their own cache entries.
+    let mut distinct = labels.clone();
+    distinct.dedup();
+    assert_eq!(distinct.len(), labels.len());
+}
+
+#[test]
+fn invalid_price_axis_significant_digits_are_rejected() {
+    let renderer = NullRenderer::default();
+    let config =
+        ChartEngineConfig::new(Viewport::new(820, 420), 0.0, 100.0).with_price_domain(0.0, 10.0);
+    let mut engine = ChartEngine::new(renderer, config).expect("engine init");
+
+    for policy in [
+        PriceAxisLabelPolicy::Compact {
+            significant_digits: 0,
+        },
+        PriceAxisLabelPolicy::Scientific {
+            significant_digits: 16,
+            notation: ScientificNotation::Exponent,
+        },
+    ] {
+        let err = engine
+            .set_price_axis_label_config(PriceAxisLabelConfig {
+                policy,
+                ..PriceAxisLabelConfig::default()
+            })
+            .expect_err("significant digits should fail");
+        assert!(matches!(err, ChartError::InvalidData(_)));
+    }
+}
+
 #[test]
 fn percentage_display_mode_uses_percent_suffix() {
     let renderer = NullRenderer::default();
@@ -312,6 +397,9 @@ fn percentage_display_mode_uses_percent_suffix() {
             display_mode: PriceAxisDisplayMode::Percentage {
                 base_price: Some(100.0),
             },
+            secondary_label: PriceAxisSecondaryLabel::Hidden,
+            align_ticks_to_min_move: false,
+            anchor_ticks_to_nice_steps: false,
         })
         .expect("set percentage mode");
 
@@ -335,6 +423,9 @@ fn indexed_to_100_display_mode_applies_base_transform() {
             display_mode: PriceAxisDisplayMode::IndexedTo100 {
                 base_price: Some(50.0),
             },
+            secondary_label: PriceAxisSecondaryLabel::Hidden,
+            align_ticks_to_min_move: false,
+            anchor_ticks_to_nice_steps: false,
         })
         .expect("set indexed mode");
 
@@ -359,6 +450,9 @@ fn invalid_price_axis_display_base_falls_back_to_one() {
                 locale: AxisLabelLocale::EnUs,
                 policy: PriceAxisLabelPolicy::Adaptive,
                 display_mode: mode,
+                secondary_label: PriceAxisSecondaryLabel::Hidden,
+                align_ticks_to_min_move: false,
+                anchor_ticks_to_nice_steps: false,
             })
             .expect("set display mode");
         let frame = engine.build_render_frame().expect("build frame");
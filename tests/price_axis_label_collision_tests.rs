@@ -0,0 +1,134 @@
+use chart_rs::api::{
+    ChartEngine, ChartEngineConfig, CrosshairMode, PriceAxisLabelCollisionPriority, RenderStyle,
+    SeriesLastValueStyle,
+};
+use chart_rs::core::{DataPoint, Viewport};
+use chart_rs::render::{Color, NullRenderer, RenderFrame};
+
+const LAST_PRICE_BOX: Color = Color::rgb(0.1, 0.6, 0.3);
+const CROSSHAIR_BOX: Color = Color::rgb(0.2, 0.2, 0.7);
+const SERIES_BOX: Color = Color::rgb(0.9, 0.4, 0.1);
+
+fn build_engine(priority: PriceAxisLabelCollisionPriority) -> ChartEngine<NullRenderer> {
+    let renderer = NullRenderer::default();
+    let config =
+        ChartEngineConfig::new(Viewport::new(900, 500), 0.0, 100.0).with_price_domain(0.0, 100.0);
+    let mut engine = ChartEngine::new(renderer, config).expect("engine init");
+    engine.set_data(vec![DataPoint::new(10.0, 20.0), DataPoint::new(50.0, 40.0)]);
+    engine.set_crosshair_mode(CrosshairMode::Normal);
+    engine
+        .set_render_style(RenderStyle {
+            show_last_price_label: true,
+            show_last_price_label_box: true,
+            last_price_label_box_use_marker_color: false,
+            last_price_label_box_color: LAST_PRICE_BOX,
+            crosshair_price_label_box_color: Some(CROSSHAIR_BOX),
+            price_axis_label_collision_priority: priority,
+            ..engine.render_style()
+        })
+        .expect("set style");
+    engine
+}
+
+fn box_count(frame: &RenderFrame, color: Color) -> usize {
+    frame
+        .rects
+        .iter()
+        .filter(|rect| rect.fill_color == color)
+        .count()
+}
+
+fn hover_price(engine: &mut ChartEngine<NullRenderer>, price: f64) {
+    let y = engine.map_price_to_pixel(price).expect("price map");
+    engine.pointer_move(200.0, y);
+}
+
+#[test]
+fn crosshair_label_hides_overlapping_last_price_label_by_default() {
+    assert_eq!(
+        RenderStyle::default().price_axis_label_collision_priority,
+        PriceAxisLabelCollisionPriority::PreferCrosshair
+    );
+    let mut engine = build_engine(PriceAxisLabelCollisionPriority::PreferCrosshair);
+    hover_price(&mut engine, 40.0);
+    let frame = engine.build_render_frame().expect("frame");
+
+    assert_eq!(box_count(&frame, CROSSHAIR_BOX), 1);
+    assert_eq!(box_count(&frame, LAST_PRICE_BOX), 0);
+}
+
+#[test]
+fn last_value_priority_hides_overlapping_crosshair_label() {
+    let mut engine = build_engine(PriceAxisLabelCollisionPriority::PreferLastValue);
+    hover_price(&mut engine, 40.0);
+    let frame = engine.build_render_frame().expect("frame");
+
+    assert_eq!(box_count(&frame, CROSSHAIR_BOX), 0);
+    assert_eq!(box_count(&frame, LAST_PRICE_BOX), 1);
+}
+
+#[test]
+fn distant_labels_are_all_kept() {
+    for priority in [
+        PriceAxisLabelCollisionPriority::PreferCrosshair,
+        PriceAxisLabelCollisionPriority::PreferLastValue,
+    ] {
+        let mut engine = build_engine(priority);
+        hover_price(&mut engine, 80.0);
+        let frame = engine.build_render_frame().expect("frame");
+
+        assert_eq!(box_count(&frame, CROSSHAIR_BOX), 1);
+        assert_eq!(box_count(&frame, LAST_PRICE_BOX), 1);
+    }
+}
+
+#[test]
+fn series_last_value_labels_take_part_in_collisions() {
+    let mut engine = build_engine(PriceAxisLabelCollisionPriority::PreferCrosshair);
+    engine
+        .set_render_style(RenderStyle {
+            show_last_price_label: false,
+            point_series_last_value: Some(SeriesLastValueStyle::new(SERIES_BOX)),
+            last_price_label_box_use_marker_color: true,
+            ..engine.render_style()
+        })
+        .expect("set style");
+    let frame = engine.build_render_frame().expect("frame");
+    assert_eq!(box_count(&frame, SERIES_BOX), 1);
+
+    hover_price(&mut engine, 40.0);
+    let frame = engine.build_render_frame().expect("frame");
+    assert_eq!(box_count(&frame, CROSSHAIR_BOX), 1);
+    assert_eq!(box_count(&frame, SERIES_BOX), 0);
+}
+
+#[test]
+fn remaining_labels_never_overlap_the_crosshair_box() {
+    let mut engine = build_engine(PriceAxisLabelCollisionPriority::PreferCrosshair);
+    let plot_bottom =
+        f64::from(engine.viewport().height) - engine.render_style().time_axis_height_px;
+    let mut y = 0.0;
+    while y < plot_bottom {
+        engine.pointer_move(200.0, y);
+        let frame = engine.build_render_frame().expect("frame");
+        let crosshair = frame
+            .rects
+            .iter()
+            .find(|rect| rect.fill_color == CROSSHAIR_BOX)
+            .copied();
+        if let (Some(crosshair), Some(last_price)) = (
+            crosshair,
+            frame
+                .rects
+                .iter()
+                .find(|rect| rect.fill_color == LAST_PRICE_BOX),
+        ) {
+            assert!(
+                crosshair.y + crosshair.height <= last_price.y
+                    || last_price.y + last_price.height <= crosshair.y,
+                "boxes overlap at pointer y={y}"
+            );
+        }
+        y += 3.0;
+    }
+}
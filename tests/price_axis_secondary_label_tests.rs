@@ -0,0 +1,130 @@
+use chart_rs::api::{
+    ChartEngine, ChartEngineConfig, PriceAxisLabelConfig, PriceAxisSecondaryLabel,
+    PriceAxisWidthMode, RenderStyle,
+};
+use chart_rs::core::{DataPoint, Viewport};
+use chart_rs::render::{NullRenderer, RenderFrame, TextHAlign};
+
+fn engine() -> ChartEngine<NullRenderer> {
+    let config =
+        ChartEngineConfig::new(Viewport::new(800, 400), 0.0, 100.0).with_price_domain(0.0, 50.0);
+    let mut engine = ChartEngine::new(NullRenderer::default(), config).expect("engine");
+    engine
+        .set_render_style(RenderStyle {
+            show_last_price_label: false,
+            ..engine.render_style()
+        })
+        .expect("style");
+    engine.set_data(vec![DataPoint::new(10.0, 20.0), DataPoint::new(90.0, 25.0)]);
+    engine
+}
+
+fn with_secondary(
+    engine: &mut ChartEngine<NullRenderer>,
+    secondary_label: PriceAxisSecondaryLabel,
+) {
+    engine
+        .set_price_axis_label_config(PriceAxisLabelConfig {
+            secondary_label,
+            ..engine.price_axis_label_config()
+        })
+        .expect("config");
+}
+
+/// Pairs every primary tick label with the secondary label drawn after it.
+fn tick_pairs(labels: &[(f64, String)]) -> Vec<(f64, String)> {
+    labels
+        .chunks(2)
+        .map(|pair| (pair[0].1.parse().expect("price"), pair[1].1.clone()))
+        .collect()
+}
+
+fn expected_percent(price: f64, base_price: f64) -> String {
+    let text = format!("{:+.2}%", (price - base_price) / base_price * 100.0);
+    if text == "+0.00%" || text == "-0.00%" {
+        "0.00%".to_owned()
+    } else {
+        text
+    }
+}
+
+/// Font sizes and texts of right-aligned axis labels, in draw order.
+fn axis_labels(frame: &RenderFrame) -> Vec<(f64, String)> {
+    frame
+        .texts
+        .iter()
+        .filter(|text| text.h_align == TextHAlign::Right)
+        .map(|text| (text.font_size_px, text.text.to_string()))
+        .collect()
+}
+
+#[test]
+fn each_tick_gains_a_smaller_percent_label_from_the_last_price() {
+    let mut engine = engine();
+    let plain = axis_labels(&engine.build_render_frame().expect("frame"));
+    with_secondary(&mut engine, PriceAxisSecondaryLabel::PercentFromLastPrice);
+    let labels = axis_labels(&engine.build_render_frame().expect("frame"));
+    assert_eq!(labels.len(), plain.len() * 2);
+
+    let primary_size = plain[0].0;
+    let secondary = labels
+        .iter()
+        .filter(|(size, _)| *size < primary_size)
+        .map(|(_, text)| text.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(secondary.len(), plain.len());
+    assert!(secondary.iter().all(|text| text.ends_with('%')));
+    // A 25.00 tick sits on the last price; 50.00 is twice it.
+    for (price, expected) in [
+        ("25.00", "0.00%"),
+        ("50.00", "+100.00%"),
+        ("0.00", "-100.00%"),
+    ] {
+        if let Some(index) = labels.iter().position(|(_, text)| text == price) {
+            assert_eq!(labels[index + 1].1, expected);
+        }
+    }
+}
+
+#[test]
+fn fixed_base_is_validated_and_used() {
+    let mut engine = engine();
+    assert!(
+        engine
+            .set_price_axis_label_config(PriceAxisLabelConfig {
+                secondary_label: PriceAxisSecondaryLabel::PercentFromBase { base_price: 0.0 },
+                ..engine.price_axis_label_config()
+            })
+            .is_err()
+    );
+    with_secondary(
+        &mut engine,
+        PriceAxisSecondaryLabel::PercentFromBase { base_price: 100.0 },
+    );
+    let labels = axis_labels(&engine.build_render_frame().expect("frame"));
+    assert!(!labels.is_empty());
+    for (price, secondary) in tick_pairs(&labels) {
+        assert_eq!(secondary, expected_percent(price, 100.0));
+    }
+}
+
+#[test]
+fn secondary_labels_count_towards_auto_price_axis_width() {
+    let mut engine = engine();
+    engine
+        .set_render_style(RenderStyle {
+            price_axis_width_mode: PriceAxisWidthMode::Auto {
+                min_width_px: 20.0,
+                max_width_px: 300.0,
+                hysteresis_px: 0.0,
+            },
+            ..engine.render_style()
+        })
+        .expect("style");
+    engine.build_render_frame().expect("frame");
+    let plain_width = engine.resolved_price_axis_width_px();
+
+    with_secondary(&mut engine, PriceAxisSecondaryLabel::PercentFromLastPrice);
+    engine.build_render_frame().expect("frame");
+    assert!(engine.resolved_price_axis_width_px() > plain_width);
+}
@@ -0,0 +1,137 @@
+use chart_rs::api::{
+    ChartEngine, ChartEngineConfig, PriceAxisDisplayMode, PriceAxisLabelConfig, PriceAxisWidthMode,
+    RenderStyle,
+};
+use chart_rs::core::{DataPoint, Viewport};
+use chart_rs::render::{NullRenderer, RenderFrame};
+
+fn engine() -> ChartEngine<NullRenderer> {
+    let config =
+        ChartEngineConfig::new(Viewport::new(800, 400), 0.0, 100.0).with_price_domain(0.0, 50.0);
+    let mut engine = ChartEngine::new(NullRenderer::default(), config).expect("engine");
+    engine.set_data(vec![DataPoint::new(10.0, 20.0), DataPoint::new(90.0, 30.0)]);
+    engine
+}
+
+fn with_affixes(engine: &mut ChartEngine<NullRenderer>, prefix: &str, suffix: &str) {
+    engine
+        .set_render_style(RenderStyle {
+            show_last_price_label: true,
+            price_label_prefix: prefix.into(),
+            price_label_suffix: suffix.into(),
+            ..engine.render_style()
+        })
+        .expect("style");
+}
+
+/// Texts right of the plot: price-axis ticks, last-price and crosshair labels.
+fn axis_texts(engine: &ChartEngine<NullRenderer>, frame: &RenderFrame) -> Vec<String> {
+    let plot_right = 800.0 - engine.resolved_price_axis_width_px();
+    frame
+        .texts
+        .iter()
+        .filter(|text| text.x > plot_right)
+        .map(|text| text.text.to_string())
+        .collect()
+}
+
+#[test]
+fn affixes_wrap_axis_last_price_and_crosshair_labels() {
+    let mut engine = engine();
+    with_affixes(&mut engine, "$", " BTC");
+    let before = axis_texts(&engine, &engine.build_render_frame().expect("frame"));
+    engine.pointer_move(200.0, 150.0);
+    let frame = engine.build_render_frame().expect("frame");
+    let texts = axis_texts(&engine, &frame);
+
+    assert!(texts.len() > before.len());
+    assert!(texts.contains(&"$30.00 BTC".to_owned()));
+    assert!(
+        texts
+            .iter()
+            .all(|text| text.starts_with('$') && text.ends_with(" BTC"))
+    );
+}
+
+#[test]
+fn affixes_follow_the_percentage_suffix_and_replace_cached_labels() {
+    let mut engine = engine();
+    engine
+        .set_price_axis_label_config(PriceAxisLabelConfig {
+            display_mode: PriceAxisDisplayMode::Percentage {
+                base_price: Some(25.0),
+            },
+            ..PriceAxisLabelConfig::default()
+        })
+        .expect("config");
+    let plain = axis_texts(&engine, &engine.build_render_frame().expect("frame"));
+    assert!(plain.iter().all(|text| text.ends_with('%')));
+
+    with_affixes(&mut engine, "", " pts");
+    let decorated = axis_texts(&engine, &engine.build_render_frame().expect("frame"));
+    assert!(decorated.iter().all(|text| text.ends_with("% pts")));
+
+    with_affixes(&mut engine, "", "");
+    assert_eq!(
+        axis_texts(&engine, &engine.build_render_frame().expect("frame")),
+        plain
+    );
+}
+
+#[test]
+fn affixes_count_towards_auto_price_axis_width() {
+    let mut engine = engine();
+    engine
+        .set_render_style(RenderStyle {
+            price_axis_width_mode: PriceAxisWidthMode::Auto {
+                min_width_px: 20.0,
+                max_width_px: 300.0,
+                hysteresis_px: 0.0,
+            },
+            ..engine.render_style()
+        })
+        .expect("style");
+    engine.build_render_frame().expect("frame");
+    let plain_width = engine.resolved_price_axis_width_px();
+
+    with_affixes(&mut engine, "€ ", " EUR");
+    engine.build_render_frame().expect("frame");
+    assert!(engine.resolved_price_axis_width_px() > plain_width + 20.0);
+}
+
+#[test]
+fn styles_without_affix_fields_deserialize_without_affixes() {
+    let mut json = serde_json::to_value(RenderStyle {
+        price_label_prefix: "$".into(),
+        price_label_suffix: " USD".into(),
+        ..RenderStyle::default()
+    })
+    .expect("json");
+    let object = json.as_object_mut().expect("object");
+    assert_eq!(object["price_label_prefix"], "$");
+    object.remove("price_label_prefix");
+    object.remove("price_label_suffix");
+    let style: RenderStyle = serde_json::from_value(json).expect("style");
+    assert_eq!(style, RenderStyle::default());
+}
+
+#[test]
+fn deserialized_affixes_render_and_are_released_with_the_style() {
+    let mut value = serde_json::to_value(RenderStyle {
+        show_last_price_label: true,
+        ..RenderStyle::default()
+    })
+    .expect("json");
+    value["price_label_suffix"] = " XBT".into();
+    let style: RenderStyle = serde_json::from_value(value).expect("style");
+
+    let mut engine = engine();
+    engine.set_render_style(style).expect("style");
+    let texts = axis_texts(&engine, &engine.build_render_frame().expect("frame"));
+    assert!(texts.contains(&"30.00 XBT".to_owned()));
+
+    let suffix = engine.render_style().price_label_suffix;
+    assert_eq!(std::sync::Arc::strong_count(&suffix), 2);
+    with_affixes(&mut engine, "", "");
+    assert_eq!(std::sync::Arc::strong_count(&suffix), 1);
+}
@@ -42,7 +42,9 @@ fn append_point_does_not_autoscale_when_disabled() {
         .expect("initial autoscale from points");
     let before = engine.price_domain();
 
-    engine.append_point(DataPoint::new(2.0, 300.0));
+    engine
+        .append_point(DataPoint::new(2.0, 300.0))
+        .expect("append");
     let after = engine.price_domain();
     assert!((after.0 - before.0).abs() <= 1e-12);
     assert!((after.1 - before.1).abs() <= 1e-12);
@@ -62,7 +64,9 @@ fn append_point_autoscales_when_enabled() {
         autoscale_on_data_update: true,
         autoscale_on_time_range_change: false,
     });
-    engine.append_point(DataPoint::new(2.0, 300.0));
+    engine
+        .append_point(DataPoint::new(2.0, 300.0))
+        .expect("append");
     let after = engine.price_domain();
     assert!(after.1 > before.1);
 }
@@ -103,7 +107,9 @@ fn candles_take_priority_for_realtime_autoscale_source() {
         autoscale_on_data_update: true,
         autoscale_on_time_range_change: false,
     });
-    engine.append_point(DataPoint::new(2.0, 1_000.0));
+    engine
+        .append_point(DataPoint::new(2.0, 1_000.0))
+        .expect("append");
     let after = engine.price_domain();
 
     assert!((after.0 - before.0).abs() <= 1e-12);
@@ -124,7 +130,9 @@ fn append_candle_autoscales_when_enabled() {
         autoscale_on_data_update: true,
         autoscale_on_time_range_change: false,
     });
-    engine.append_candle(OhlcBar::new(1.0, 60.0, 90.0, 58.0, 88.0).expect("candle"));
+    engine
+        .append_candle(OhlcBar::new(1.0, 60.0, 90.0, 58.0, 88.0).expect("candle"))
+        .expect("append");
     let after = engine.price_domain();
     assert!(after.1 > before.1);
 }
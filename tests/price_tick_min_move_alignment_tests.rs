@@ -0,0 +1,76 @@
+use chart_rs::api::{
+    ChartEngine, ChartEngineConfig, CrosshairMode, PriceAxisLabelConfig, PriceAxisLabelPolicy,
+};
+use chart_rs::core::Viewport;
+use chart_rs::render::{CanvasLayerKind, NullRenderer};
+
+const MIN_MOVE: f64 = 0.25;
+
+fn engine(policy: PriceAxisLabelPolicy, align: bool) -> ChartEngine<NullRenderer> {
+    let config = ChartEngineConfig::new(Viewport::new(800, 400), 0.0, 100.0)
+        .with_price_domain(4001.1, 4004.9);
+    let mut engine = ChartEngine::new(NullRenderer::default(), config).expect("engine");
+    engine
+        .set_price_axis_label_config(PriceAxisLabelConfig {
+            policy,
+            align_ticks_to_min_move: align,
+            ..engine.price_axis_label_config()
+        })
+        .expect("config");
+    engine
+}
+
+fn min_move_policy() -> PriceAxisLabelPolicy {
+    PriceAxisLabelPolicy::MinMove {
+        min_move: MIN_MOVE,
+        trim_trailing_zeros: false,
+    }
+}
+
+fn horizontal_line_prices(engine: &ChartEngine<NullRenderer>, kind: CanvasLayerKind) -> Vec<f64> {
+    let layered = engine.build_layered_render_frame().expect("layered");
+    layered.panes[0]
+        .layers
+        .iter()
+        .filter(|layer| layer.kind == kind)
+        .flat_map(|layer| layer.lines.iter())
+        .filter(|line| line.y1 == line.y2)
+        .map(|line| engine.map_pixel_to_price(line.y1).expect("price"))
+        .collect()
+}
+
+fn on_grid(price: f64) -> bool {
+    ((price / MIN_MOVE).round() * MIN_MOVE - price).abs() < 1e-6
+}
+
+#[test]
+fn aligned_ticks_land_on_min_move_multiples() {
+    let engine = engine(min_move_policy(), true);
+    let prices = horizontal_line_prices(&engine, CanvasLayerKind::Grid);
+    assert!(prices.len() >= 3);
+    assert!(prices.iter().all(|&price| on_grid(price)), "{prices:?}");
+}
+
+#[test]
+fn ticks_stay_evenly_divided_without_the_option() {
+    let engine = engine(min_move_policy(), false);
+    let prices = horizontal_line_prices(&engine, CanvasLayerKind::Grid);
+    assert!(prices.iter().any(|&price| !on_grid(price)), "{prices:?}");
+}
+
+#[test]
+fn option_needs_a_min_move_policy() {
+    let engine = engine(PriceAxisLabelPolicy::FixedDecimals { precision: 2 }, true);
+    let prices = horizontal_line_prices(&engine, CanvasLayerKind::Grid);
+    assert!(prices.iter().any(|&price| !on_grid(price)), "{prices:?}");
+}
+
+#[test]
+fn free_crosshair_price_snaps_to_the_tick_grid() {
+    let mut engine = engine(min_move_policy(), true);
+    engine.set_crosshair_mode(CrosshairMode::Normal);
+    engine.pointer_move(200.0, 137.0);
+    let prices = horizontal_line_prices(&engine, CanvasLayerKind::Crosshair);
+    assert_eq!(prices.len(), 1);
+    assert!(on_grid(prices[0]), "{prices:?}");
+}
@@ -0,0 +1,252 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use chart_rs::api::{
+    ChartEngine, ChartEngineConfig, InteractionOptions, PointerInput, PointerInputBridge,
+    PrimitiveHit, PrimitiveOwner,
+};
+use chart_rs::core::Viewport;
+use chart_rs::error::ChartResult;
+use chart_rs::extensions::{
+    ChartPlugin, CustomSeries, CustomSeriesPrimitives, CustomSeriesRenderContext, HitRegion,
+    HitShape, PluginContext, PluginEvent,
+};
+use chart_rs::render::NullRenderer;
+
+type EventLog = Rc<RefCell<Vec<(String, PluginEvent)>>>;
+
+/// Overlay plugin owning one fixed hit region per entry.
+struct OverlayPlugin {
+    id: String,
+    regions: Vec<HitRegion>,
+    events: EventLog,
+}
+
+impl ChartPlugin for OverlayPlugin {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn on_event(&mut self, event: PluginEvent, _context: PluginContext) {
+        if matches!(
+            event,
+            PluginEvent::PrimitiveHovered { .. }
+                | PluginEvent::PrimitiveHoverEnded { .. }
+                | PluginEvent::PrimitiveClicked { .. }
+        ) {
+            self.events.borrow_mut().push((self.id.clone(), event));
+        }
+    }
+
+    fn hit_regions(&self, _context: PluginContext) -> Vec<HitRegion> {
+        self.regions.clone()
+    }
+}
+
+/// Custom series reporting a dot region per row.
+struct DotSeries;
+
+impl CustomSeries for DotSeries {
+    type Row = (f64, f64);
+
+    fn row_time(&self, row: &(f64, f64)) -> f64 {
+        row.0
+    }
+
+    fn autoscale_range(&self, _rows: &[(f64, f64)]) -> Option<(f64, f64)> {
+        None
+    }
+
+    fn render(
+        &self,
+        rows: &[(f64, f64)],
+        ctx: &CustomSeriesRenderContext,
+        out: &mut CustomSeriesPrimitives,
+    ) -> ChartResult<()> {
+        for (time, price) in rows {
+            out.hit_regions.push(HitRegion::circle(
+                format!("dot-{time}"),
+                ctx.time_to_pixel(*time)?,
+                ctx.price_to_pixel(*price)?,
+                5.0,
+            ));
+        }
+        Ok(())
+    }
+}
+
+fn engine_with_plugins(events: &EventLog) -> ChartEngine<NullRenderer> {
+    let config =
+        ChartEngineConfig::new(Viewport::new(800, 500), 0.0, 100.0).with_price_domain(0.0, 100.0);
+    let mut engine = ChartEngine::new(NullRenderer::default(), config).expect("engine");
+    engine
+        .register_plugin(Box::new(OverlayPlugin {
+            id: "zones".to_owned(),
+            regions: vec![HitRegion::rect("zone-a", 100.0, 100.0, 50.0, 50.0)],
+            events: events.clone(),
+        }))
+        .expect("register");
+    engine
+        .register_plugin(Box::new(OverlayPlugin {
+            id: "trendlines".to_owned(),
+            regions: vec![HitRegion::new(
+                "line-1",
+                HitShape::Segment {
+                    x1: 300.0,
+                    y1: 100.0,
+                    x2: 400.0,
+                    y2: 200.0,
+                    tolerance_px: 4.0,
+                },
+            )],
+            events: events.clone(),
+        }))
+        .expect("register");
+    engine
+}
+
+fn plugin_hit(plugin: &str, id: &str) -> PrimitiveHit {
+    PrimitiveHit {
+        owner: PrimitiveOwner::Plugin(plugin.to_owned()),
+        id: id.to_owned(),
+    }
+}
+
+#[test]
+fn hit_shapes_cover_their_geometry() {
+    let rect = HitShape::Rect {
+        x: 10.0,
+        y: 10.0,
+        width: -5.0,
+        height: 5.0,
+    };
+    assert!(rect.contains(7.0, 12.0));
+    assert!(!rect.contains(11.0, 12.0));
+
+    let segment = HitShape::Segment {
+        x1: 0.0,
+        y1: 0.0,
+        x2: 10.0,
+        y2: 0.0,
+        tolerance_px: 2.0,
+    };
+    assert!(segment.contains(5.0, 1.5));
+    assert!(!segment.contains(5.0, 2.5));
+    assert!(!segment.contains(13.0, 0.0));
+    assert!(HitRegion::circle("c", 0.0, 0.0, 3.0).contains(2.0, 2.0));
+}
+
+#[test]
+fn hover_transitions_are_routed_to_the_owning_plugin() {
+    let events = EventLog::default();
+    let mut engine = engine_with_plugins(&events);
+
+    engine.pointer_move(120.0, 120.0);
+    assert_eq!(
+        engine.hovered_primitive(),
+        Some(&plugin_hit("zones", "zone-a"))
+    );
+    engine.pointer_move(125.0, 125.0);
+    engine.pointer_move(350.0, 151.0);
+    engine.pointer_leave();
+    assert_eq!(engine.hovered_primitive(), None);
+
+    let id = |id: &str| id.to_owned();
+    assert_eq!(
+        *events.borrow(),
+        vec![
+            (
+                id("zones"),
+                PluginEvent::PrimitiveHovered { id: id("zone-a") }
+            ),
+            (
+                id("zones"),
+                PluginEvent::PrimitiveHoverEnded { id: id("zone-a") }
+            ),
+            (
+                id("trendlines"),
+                PluginEvent::PrimitiveHovered { id: id("line-1") }
+            ),
+            (
+                id("trendlines"),
+                PluginEvent::PrimitiveHoverEnded { id: id("line-1") }
+            ),
+        ]
+    );
+}
+
+#[test]
+fn clicks_reach_owner_through_engine_and_bridge() {
+    let events = EventLog::default();
+    let mut engine = engine_with_plugins(&events);
+    assert_eq!(engine.pointer_click(10.0, 10.0), None);
+    assert_eq!(
+        engine.pointer_click(110.0, 140.0),
+        Some(plugin_hit("zones", "zone-a"))
+    );
+
+    let mut bridge = PointerInputBridge::new(InteractionOptions::default());
+    for input in [
+        PointerInput::Pressed { x: 350.0, y: 150.0 },
+        PointerInput::Moved { x: 351.0, y: 151.0 },
+        PointerInput::Released,
+        // A drag is not a click.
+        PointerInput::Pressed { x: 120.0, y: 120.0 },
+        PointerInput::Moved { x: 160.0, y: 120.0 },
+        PointerInput::Released,
+    ] {
+        bridge.handle(&mut engine, input).expect("input");
+    }
+
+    let clicks: Vec<_> = events
+        .borrow()
+        .iter()
+        .filter_map(|(plugin, event)| match event {
+            PluginEvent::PrimitiveClicked { id } => Some((plugin.clone(), id.clone())),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(
+        clicks,
+        vec![
+            ("zones".to_owned(), "zone-a".to_owned()),
+            ("trendlines".to_owned(), "line-1".to_owned()),
+        ]
+    );
+}
+
+#[test]
+fn custom_series_regions_hit_after_render_and_broadcast() {
+    let events = EventLog::default();
+    let mut engine = engine_with_plugins(&events);
+    let main_pane = engine.main_pane_id();
+    engine
+        .add_custom_series("dots", main_pane, DotSeries, vec![(80.0, 50.0)])
+        .expect("custom series");
+    let x = engine.map_x_to_pixel(80.0).expect("x");
+    assert_eq!(engine.primitive_at(x, 250.0), None);
+
+    engine.build_render_frame().expect("frame");
+    let dot_y = (0..500)
+        .map(f64::from)
+        .find(|y| engine.primitive_at(x, *y).is_some())
+        .expect("dot region");
+    let hit = engine.pointer_click(x, dot_y + 1.0).expect("hit");
+    assert_eq!(
+        hit,
+        PrimitiveHit {
+            owner: PrimitiveOwner::CustomSeries("dots".to_owned()),
+            id: "dot-80".to_owned(),
+        }
+    );
+    let receivers: Vec<_> = events
+        .borrow()
+        .iter()
+        .map(|(plugin, _)| plugin.clone())
+        .collect();
+    assert_eq!(receivers, ["zones", "trendlines"]);
+
+    assert!(engine.remove_custom_series("dots"));
+    engine.build_render_frame().expect("frame");
+    assert_eq!(engine.primitive_at(x, dot_y + 1.0), None);
+}
@@ -135,6 +135,7 @@ proptest! {
             .set_time_scale_zoom_limit_behavior(TimeScaleZoomLimitBehavior {
                 min_bar_spacing_px: min_spacing,
                 max_bar_spacing_px: max_spacing,
+                max_visible_span_ratio: None,
             })
             .expect("set time zoom limits");
         engine
@@ -1,6 +1,6 @@
 use chart_rs::api::{
     AxisLabelLocale, ChartEngine, ChartEngineConfig, PriceAxisDisplayMode, PriceAxisLabelConfig,
-    PriceAxisLabelPolicy,
+    PriceAxisLabelPolicy, PriceAxisSecondaryLabel,
 };
 use chart_rs::core::Viewport;
 use chart_rs::render::{NullRenderer, TextHAlign};
@@ -22,6 +22,9 @@ fn build_labels_without_data(
             locale,
             policy: PriceAxisLabelPolicy::FixedDecimals { precision: 2 },
             display_mode,
+            secondary_label: PriceAxisSecondaryLabel::Hidden,
+            align_ticks_to_min_move: false,
+            anchor_ticks_to_nice_steps: false,
         })
         .expect("set price axis config");
 
@@ -30,7 +33,7 @@ fn build_labels_without_data(
         .texts
         .iter()
         .filter(|label| label.h_align == TextHAlign::Right)
-        .map(|label| label.text.clone())
+        .map(|label| label.text.to_string())
         .collect()
 }
 
@@ -1,6 +1,6 @@
 use chart_rs::api::{
     AxisLabelLocale, ChartEngine, ChartEngineConfig, PriceAxisDisplayMode, PriceAxisLabelConfig,
-    PriceAxisLabelPolicy,
+    PriceAxisLabelPolicy, PriceAxisSecondaryLabel,
 };
 use chart_rs::core::{DataPoint, Viewport};
 use chart_rs::render::{NullRenderer, TextHAlign};
@@ -50,6 +50,9 @@ fn build_labels(
             locale,
             policy: PriceAxisLabelPolicy::FixedDecimals { precision: 2 },
             display_mode,
+            secondary_label: PriceAxisSecondaryLabel::Hidden,
+            align_ticks_to_min_move: false,
+            anchor_ticks_to_nice_steps: false,
         })
         .expect("set price axis config");
 
@@ -58,7 +61,7 @@ fn build_labels(
         .texts
         .iter()
         .filter(|label| label.h_align == TextHAlign::Right)
-        .map(|label| label.text.clone())
+        .map(|label| label.text.to_string())
         .collect()
 }
 
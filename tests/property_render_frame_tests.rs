@@ -91,7 +91,7 @@ proptest! {
             show_crosshair_vertical_line: show_vertical,
             ..engine.render_style()
         };
-        engine.set_render_style(style).expect("set style");
+        engine.set_render_style(style.clone()).expect("set style");
         engine.pointer_move(x, y);
 
         let first = engine.build_render_frame().expect("first frame");
@@ -284,15 +284,15 @@ proptest! {
             show_crosshair_price_label_box: false,
             crosshair_time_label_color: Color::rgb(0.88, 0.22, 0.19),
             crosshair_price_label_color: Color::rgb(0.19, 0.43, 0.88),
-            crosshair_label_prefix: "S:",
-            crosshair_label_suffix: ":S",
-            crosshair_time_label_prefix: if override_time { Some("T:") } else { None },
-            crosshair_time_label_suffix: if override_time { Some(":T") } else { None },
-            crosshair_price_label_prefix: if override_price { Some("P:") } else { None },
-            crosshair_price_label_suffix: if override_price { Some(":P") } else { None },
+            crosshair_label_prefix: "S:".into(),
+            crosshair_label_suffix: ":S".into(),
+            crosshair_time_label_prefix: if override_time { Some("T:".into()) } else { None },
+            crosshair_time_label_suffix: if override_time { Some(":T".into()) } else { None },
+            crosshair_price_label_prefix: if override_price { Some("P:".into()) } else { None },
+            crosshair_price_label_suffix: if override_price { Some(":P".into()) } else { None },
             ..engine.render_style()
         };
-        engine.set_render_style(style).expect("set style");
+        engine.set_render_style(style.clone()).expect("set style");
         engine.pointer_move(640.0, 360.0);
 
         let first = engine.build_render_frame().expect("first frame");
@@ -305,14 +305,14 @@ proptest! {
             .find(|text| text.color == style.crosshair_time_label_color)
             .expect("crosshair time text")
             .text
-            .as_str();
+            .as_ref();
         let price_text = first
             .texts
             .iter()
             .find(|text| text.color == style.crosshair_price_label_color)
             .expect("crosshair price text")
             .text
-            .as_str();
+            .as_ref();
 
         if override_time {
             prop_assert!(time_text.starts_with("T:") && time_text.ends_with(":T"));
@@ -370,7 +370,7 @@ proptest! {
             },
             ..engine.render_style()
         };
-        engine.set_render_style(style).expect("set style");
+        engine.set_render_style(style.clone()).expect("set style");
         engine.pointer_move(640.0, 360.0);
 
         let first = engine.build_render_frame().expect("first frame");
@@ -383,7 +383,7 @@ proptest! {
             .find(|text| text.color == style.crosshair_time_label_color)
             .expect("crosshair time text")
             .text
-            .as_str();
+            .as_ref();
         let price_text = first
             .texts
             .iter()
@@ -492,7 +492,7 @@ proptest! {
             show_crosshair_price_label: show_price_label,
             ..engine.render_style()
         };
-        engine.set_render_style(style).expect("set style");
+        engine.set_render_style(style.clone()).expect("set style");
         engine.pointer_move(x, y);
 
         let first = engine.build_render_frame().expect("first frame");
@@ -536,7 +536,7 @@ proptest! {
             show_crosshair_price_label_box: show_price_box,
             ..engine.render_style()
         };
-        engine.set_render_style(style).expect("set style");
+        engine.set_render_style(style.clone()).expect("set style");
         engine.pointer_move(x, y);
 
         let first = engine.build_render_frame().expect("first frame");
@@ -574,7 +574,7 @@ proptest! {
             show_crosshair_price_label_box: true,
             ..engine.render_style()
         };
-        engine.set_render_style(style).expect("set style");
+        engine.set_render_style(style.clone()).expect("set style");
         engine.pointer_move(400.0, 250.0);
 
         let frame = engine.build_render_frame().expect("frame");
@@ -617,7 +617,7 @@ proptest! {
             show_crosshair_price_label_box: true,
             ..engine.render_style()
         };
-        engine.set_render_style(style).expect("set style");
+        engine.set_render_style(style.clone()).expect("set style");
         engine.pointer_move(400.0, 250.0);
 
         let first = engine.build_render_frame().expect("first frame");
@@ -1026,7 +1026,7 @@ proptest! {
             show_crosshair_price_label_box: true,
             ..engine.render_style()
         };
-        engine.set_render_style(style).expect("set style");
+        engine.set_render_style(style.clone()).expect("set style");
         engine.pointer_move(x, y);
 
         let first = engine.build_render_frame().expect("first frame");
@@ -1141,7 +1141,7 @@ proptest! {
             show_crosshair_price_label_box_border: show_price_border,
             ..engine.render_style()
         };
-        engine.set_render_style(style).expect("set style");
+        engine.set_render_style(style.clone()).expect("set style");
         engine.pointer_move(400.0, 250.0);
 
         let first = engine.build_render_frame().expect("first frame");
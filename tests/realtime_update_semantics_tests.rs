@@ -54,7 +54,7 @@ fn update_point_rejects_out_of_order_time() {
     let err = engine
         .update_point(DataPoint::new(19.0, 3.0))
         .expect_err("older time must fail");
-    assert!(matches!(err, ChartError::InvalidData(_)));
+    assert!(matches!(err, ChartError::NonMonotonicTime { index: 2 }));
 }
 
 #[test]
@@ -108,5 +108,5 @@ fn update_candle_supports_replace_and_order_validation() {
     let err = engine
         .update_candle(c19)
         .expect_err("older candle time must fail");
-    assert!(matches!(err, ChartError::InvalidData(_)));
+    assert!(matches!(err, ChartError::NonMonotonicTime { .. }));
 }
@@ -0,0 +1,111 @@
+use std::time::Duration;
+
+use chart_rs::api::{
+    ChartEngine, ChartEngineConfig, InvalidationTopic, InvalidationTopics, RedrawThrottleBehavior,
+};
+use chart_rs::core::{DataPoint, Viewport};
+use chart_rs::render::NullRenderer;
+
+fn engine_with(behavior: RedrawThrottleBehavior) -> ChartEngine<NullRenderer> {
+    let config = ChartEngineConfig::new(Viewport::new(800, 400), 0.0, 100.0)
+        .with_redraw_throttle_behavior(behavior);
+    let mut engine = ChartEngine::new(NullRenderer::default(), config).expect("engine");
+    engine.set_data(
+        (0..=100)
+            .map(|i| DataPoint::new(f64::from(i), f64::from(i % 9)))
+            .collect(),
+    );
+    engine.render().expect("render");
+    engine
+}
+
+fn ms(millis: u64) -> Duration {
+    Duration::from_millis(millis)
+}
+
+#[test]
+fn pointer_moves_mark_cursor_only_redraws() {
+    let mut engine = engine_with(RedrawThrottleBehavior::default());
+    assert!(!engine.needs_redraw());
+    assert!(engine.redraw_reasons().is_none());
+
+    engine.pointer_move(200.0, 100.0);
+    engine.pointer_move(210.0, 110.0);
+    assert!(engine.needs_redraw());
+    assert_eq!(
+        engine.redraw_reasons(),
+        InvalidationTopics::from_topic(InvalidationTopic::Cursor)
+    );
+
+    engine.render().expect("render");
+    assert!(!engine.needs_redraw());
+}
+
+#[test]
+fn unthrottled_ticks_redraw_whenever_dirty() {
+    let mut engine = engine_with(RedrawThrottleBehavior::default());
+    assert!(!engine.redraw_due(ms(0)));
+
+    engine.pointer_move(200.0, 100.0);
+    assert!(engine.redraw_due(ms(1)));
+    engine.render().expect("render");
+    engine.pointer_move(220.0, 100.0);
+    assert!(engine.redraw_due(ms(2)));
+}
+
+#[test]
+fn max_fps_coalesces_redraws_between_frame_intervals() {
+    let mut engine = engine_with(RedrawThrottleBehavior {
+        max_fps: Some(30.0),
+    });
+    engine.pointer_move(200.0, 100.0);
+    assert!(engine.redraw_due(ms(1_000)));
+    engine.render().expect("render");
+
+    let mut granted = Vec::new();
+    for tick in 1..=8 {
+        let frame_time = ms(1_000 + tick * 1_000 / 60);
+        engine.pointer_move(200.0 + tick as f64, 100.0);
+        if engine.redraw_due(frame_time) {
+            granted.push(tick);
+            engine.render().expect("render");
+        }
+    }
+    // A 60 Hz clock under a 30 fps cap draws every other tick.
+    assert_eq!(granted, [2, 4, 6, 8]);
+
+    // Pending state survives denied ticks, and a clock reset is never held back.
+    engine.pointer_move(300.0, 100.0);
+    assert!(!engine.redraw_due(ms(1_140)));
+    assert!(engine.needs_redraw());
+    assert!(engine.redraw_due(ms(5)));
+}
+
+#[test]
+fn throttle_behavior_is_validated() {
+    let mut engine = engine_with(RedrawThrottleBehavior::default());
+    for max_fps in [0.0, -30.0, f64::NAN, f64::INFINITY] {
+        assert!(
+            engine
+                .set_redraw_throttle_behavior(RedrawThrottleBehavior {
+                    max_fps: Some(max_fps)
+                })
+                .is_err()
+        );
+    }
+    assert_eq!(
+        engine.redraw_throttle_behavior(),
+        RedrawThrottleBehavior::default()
+    );
+
+    let mut capped = engine_with(RedrawThrottleBehavior {
+        max_fps: Some(60.0),
+    });
+    assert_eq!(capped.redraw_throttle_behavior().max_fps, Some(60.0));
+    // Tick jitter around the refresh interval does not drop frames.
+    capped.pointer_move(200.0, 100.0);
+    assert!(capped.redraw_due(ms(1_000)));
+    capped.render().expect("render");
+    capped.pointer_move(210.0, 100.0);
+    assert!(capped.redraw_due(ms(1_016)));
+}
@@ -1,6 +1,6 @@
 use chart_rs::api::{
     ChartEngine, ChartEngineConfig, CrosshairMode, RenderStyle, TimeAxisLabelConfig,
-    TimeAxisLabelPolicy, TimeAxisSessionConfig, TimeAxisTimeZone,
+    TimeAxisLabelPolicy, TimeAxisSessionConfig, TimeAxisTimeUnit, TimeAxisTimeZone,
 };
 use chart_rs::core::{DataPoint, Viewport};
 use chart_rs::render::{Color, NullRenderer, TextHAlign};
@@ -174,7 +174,9 @@ fn crosshair_label_boxes_respect_section_bounds_even_with_allow_overflow() {
         crosshair_price_label_box_padding_y_px: 18.0,
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set render style");
+    engine
+        .set_render_style(style.clone())
+        .expect("set render style");
 
     let frame = engine.build_render_frame().expect("build frame");
     let viewport_height = f64::from(engine.viewport().height);
@@ -209,7 +211,9 @@ fn oversized_axis_dimensions_preserve_minimum_plot_area() {
         time_axis_height_px: 100.0,
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set render style");
+    engine
+        .set_render_style(style.clone())
+        .expect("set render style");
 
     let frame = engine.build_render_frame().expect("build frame");
     let viewport_width = f64::from(engine.viewport().width);
@@ -277,7 +281,9 @@ fn tiny_viewport_clamps_axis_sections_without_negative_plot_space() {
         time_axis_height_px: 200.0,
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set render style");
+    engine
+        .set_render_style(style.clone())
+        .expect("set render style");
 
     let frame = engine.build_render_frame().expect("build frame");
     let viewport_width = f64::from(engine.viewport().width);
@@ -326,7 +332,9 @@ fn adaptive_price_axis_width_expands_for_large_price_labels() {
         last_price_label_font_size_px: 18.0,
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set render style");
+    engine
+        .set_render_style(style.clone())
+        .expect("set render style");
 
     let frame = engine.build_render_frame().expect("build frame");
     let viewport_width = f64::from(engine.viewport().width);
@@ -370,7 +378,9 @@ fn adaptive_time_axis_height_expands_for_large_time_axis_typography() {
         major_time_tick_mark_length_px: 11.0,
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set render style");
+    engine
+        .set_render_style(style.clone())
+        .expect("set render style");
 
     let frame = engine.build_render_frame().expect("build frame");
     let viewport_width = f64::from(engine.viewport().width);
@@ -624,6 +634,8 @@ fn major_time_labels_are_retained_and_collision_safe_under_mixed_zoom_density()
                 end_hour: 16,
                 end_minute: 0,
             }),
+            time_unit: TimeAxisTimeUnit::Seconds,
+            anchor_ticks_to_nice_steps: false,
         })
         .expect("time-axis config");
     engine
@@ -0,0 +1,84 @@
+use chart_rs::api::{BackgroundBandMode, ChartEngine, ChartEngineConfig, RenderStyle};
+use chart_rs::core::{DataPoint, Viewport};
+use chart_rs::render::{CanvasLayerKind, Color, NullRenderer};
+
+const DAY: f64 = 86_400.0;
+
+fn build_engine(style: RenderStyle) -> ChartEngine<NullRenderer> {
+    let renderer = NullRenderer::default();
+    let config = ChartEngineConfig::new(Viewport::new(900, 500), 0.0, 6.0 * DAY)
+        .with_price_domain(0.0, 50.0);
+    let mut engine = ChartEngine::new(renderer, config).expect("engine init");
+    engine.set_data(vec![
+        DataPoint::new(DAY, 10.0),
+        DataPoint::new(3.0 * DAY, 25.0),
+    ]);
+    engine.set_render_style(style).expect("set style");
+    engine
+}
+
+#[test]
+fn default_style_emits_no_background_primitives() {
+    let engine = build_engine(RenderStyle::default());
+    let layered = engine.build_layered_render_frame().expect("layered frame");
+    let background = layered.panes[0]
+        .layers
+        .iter()
+        .find(|layer| layer.kind == CanvasLayerKind::Background)
+        .expect("background layer");
+    assert!(background.rects.is_empty());
+}
+
+#[test]
+fn background_fills_are_emitted_first_in_background_layer() {
+    let chart_bg = Color::rgb(0.05, 0.05, 0.08);
+    let plot_bg = Color::rgb(0.1, 0.1, 0.12);
+    let engine = build_engine(RenderStyle {
+        background_color: Some(chart_bg),
+        plot_background_color: Some(plot_bg),
+        ..RenderStyle::default()
+    });
+
+    let frame = engine.build_render_frame().expect("frame");
+    assert_eq!(frame.rects[0].fill_color, chart_bg);
+    assert_eq!(
+        (frame.rects[0].width, frame.rects[0].height),
+        (900.0, 500.0)
+    );
+    assert_eq!(frame.rects[1].fill_color, plot_bg);
+    assert!(frame.rects[1].width < 900.0);
+    assert!(frame.rects[1].height < 500.0);
+
+    let layered = engine.build_layered_render_frame().expect("layered frame");
+    let background = layered.panes[0]
+        .layers
+        .iter()
+        .find(|layer| layer.kind == CanvasLayerKind::Background)
+        .expect("background layer");
+    assert_eq!(background.rects.len(), 2);
+}
+
+#[test]
+fn alternating_day_bands_cover_odd_days_within_plot() {
+    let band = Color::rgba(0.5, 0.5, 0.5, 0.1);
+    let engine = build_engine(RenderStyle {
+        background_band_mode: BackgroundBandMode::AlternatingDays,
+        background_band_color: band,
+        ..RenderStyle::default()
+    });
+
+    let frame = engine.build_render_frame().expect("frame");
+    let bands = frame
+        .rects
+        .iter()
+        .filter(|rect| rect.fill_color == band)
+        .collect::<Vec<_>>();
+    // Days 1, 3 and 5 of the six-day window.
+    assert_eq!(bands.len(), 3);
+    let plot = bands[0].clip_rect.expect("bands are clipped to plot");
+    for rect in &bands {
+        assert!(rect.x >= plot.x && rect.x + rect.width <= plot.right() + 1e-9);
+        assert_eq!(rect.height, plot.height);
+    }
+    assert!((bands[0].width - bands[1].width).abs() < 1e-6);
+}
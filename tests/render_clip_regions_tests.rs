@@ -0,0 +1,158 @@
+use chart_rs::api::{ChartEngine, ChartEngineConfig, CrosshairMode, RenderStyle};
+use chart_rs::core::{DataPoint, OhlcBar, Viewport};
+use chart_rs::render::{CanvasLayerKind, ClipRect, NullRenderer, RenderFrame, TextHAlign};
+
+fn build_engine() -> ChartEngine<NullRenderer> {
+    let renderer = NullRenderer::default();
+    let config =
+        ChartEngineConfig::new(Viewport::new(900, 500), 0.0, 100.0).with_price_domain(0.0, 50.0);
+    let mut engine = ChartEngine::new(renderer, config).expect("engine init");
+    engine.set_data(vec![
+        DataPoint::new(10.0, 10.0),
+        DataPoint::new(20.0, 25.0),
+        DataPoint::new(40.0, 15.0),
+    ]);
+    engine
+}
+
+fn plot_clip(frame: &RenderFrame, engine: &ChartEngine<NullRenderer>) -> ClipRect {
+    frame
+        .lines
+        .iter()
+        .find(|line| line.color == engine.render_style().series_line_color)
+        .and_then(|line| line.clip_rect)
+        .expect("series line must carry the plot clip")
+}
+
+#[test]
+fn series_primitives_are_clipped_to_plot_area() {
+    let mut engine = build_engine();
+    engine.set_candles(vec![
+        OhlcBar::new(10.0, 10.0, 20.0, 5.0, 15.0).expect("bar"),
+        OhlcBar::new(20.0, 15.0, 30.0, 12.0, 28.0).expect("bar"),
+    ]);
+    let frame = engine.build_render_frame().expect("build frame");
+    let plot = plot_clip(&frame, &engine);
+
+    assert_eq!((plot.x, plot.y), (0.0, 0.0));
+    assert!(plot.width > 0.0 && plot.height > 0.0);
+    assert!(!frame.rects.is_empty());
+    assert!(
+        frame
+            .rects
+            .iter()
+            .all(|rect| rect.clip_rect == Some(plot) || rect.x >= plot.right())
+    );
+}
+
+#[test]
+fn axis_labels_are_clipped_to_their_axis_panels() {
+    let engine = build_engine();
+    let frame = engine.build_render_frame().expect("build frame");
+    let plot = plot_clip(&frame, &engine);
+
+    let price_labels = frame
+        .texts
+        .iter()
+        .filter(|label| label.h_align == TextHAlign::Right)
+        .collect::<Vec<_>>();
+    let time_labels = frame
+        .texts
+        .iter()
+        .filter(|label| label.h_align == TextHAlign::Center)
+        .collect::<Vec<_>>();
+    assert!(!price_labels.is_empty() && !time_labels.is_empty());
+
+    for label in price_labels {
+        let clip = label.clip_rect.expect("price label clip");
+        assert_eq!(clip.x, plot.right());
+        assert_eq!(clip.height, plot.height);
+    }
+    for label in time_labels {
+        let clip = label.clip_rect.expect("time label clip");
+        assert_eq!(clip.y, plot.bottom());
+        assert_eq!(clip.width, plot.width);
+    }
+}
+
+#[test]
+fn crosshair_guides_and_label_boxes_use_plot_and_axis_clips() {
+    let mut engine = build_engine();
+    engine.set_crosshair_mode(CrosshairMode::Normal);
+    engine.pointer_move(333.0, 177.0);
+    let frame = engine.build_render_frame().expect("build frame");
+    let plot = plot_clip(&frame, &engine);
+    let style = engine.render_style();
+
+    let guides = frame
+        .lines
+        .iter()
+        .filter(|line| line.color == style.crosshair_line_color)
+        .collect::<Vec<_>>();
+    assert_eq!(guides.len(), 2);
+    assert!(guides.iter().all(|line| line.clip_rect == Some(plot)));
+
+    let box_clips = frame
+        .rects
+        .iter()
+        .filter_map(|rect| rect.clip_rect)
+        .collect::<Vec<_>>();
+    assert!(box_clips.iter().any(|clip| clip.x == plot.right()));
+    assert!(box_clips.iter().any(|clip| clip.y == plot.bottom()));
+}
+
+#[test]
+fn layered_plot_clips_follow_pane_remap() {
+    let engine = build_engine();
+    let layered = engine.build_layered_render_frame().expect("layered frame");
+    let main = layered.panes.first().expect("main pane");
+    let series = main
+        .layers
+        .iter()
+        .find(|layer| layer.kind == CanvasLayerKind::Series)
+        .expect("series layer");
+    let clip = series.lines[0].clip_rect.expect("series clip");
+    assert_eq!(clip.y, main.plot_top);
+    assert!((clip.bottom() - main.plot_bottom).abs() <= 1e-9);
+}
+
+#[test]
+fn crosshair_guides_can_extend_through_axis_panels() {
+    let mut engine = build_engine();
+    engine.set_crosshair_mode(CrosshairMode::Normal);
+    engine
+        .set_render_style(RenderStyle {
+            crosshair_lines_extend_into_axis_panels: true,
+            ..engine.render_style()
+        })
+        .expect("set style");
+    engine.pointer_move(333.0, 177.0);
+    let frame = engine.build_render_frame().expect("build frame");
+    let plot = plot_clip(&frame, &engine);
+    let style = engine.render_style();
+
+    let guides = frame
+        .lines
+        .iter()
+        .filter(|line| line.color == style.crosshair_line_color)
+        .collect::<Vec<_>>();
+    let vertical = guides
+        .iter()
+        .find(|line| line.x1 == line.x2)
+        .expect("vertical guide");
+    let horizontal = guides
+        .iter()
+        .find(|line| line.y1 == line.y2)
+        .expect("horizontal guide");
+
+    assert_eq!(vertical.y2, 500.0);
+    assert_eq!(
+        vertical.clip_rect,
+        Some(ClipRect::new(0.0, 0.0, plot.right(), 500.0))
+    );
+    assert_eq!(horizontal.x2, 900.0);
+    assert_eq!(
+        horizontal.clip_rect,
+        Some(ClipRect::new(0.0, 0.0, 900.0, plot.bottom()))
+    );
+}
@@ -0,0 +1,107 @@
+use chart_rs::ChartError;
+use chart_rs::api::{
+    ChartEngine, ChartEngineConfig, RENDER_FRAME_JSON_SCHEMA_V1, RenderFrameJsonContractV1,
+};
+use chart_rs::core::{DataPoint, OhlcBar, Viewport};
+use chart_rs::render::NullRenderer;
+
+fn engine() -> ChartEngine<NullRenderer> {
+    let config =
+        ChartEngineConfig::new(Viewport::new(800, 400), 0.0, 100.0).with_price_domain(0.0, 100.0);
+    let mut engine = ChartEngine::new(NullRenderer::default(), config).expect("engine");
+    engine.set_data(
+        (0..=100)
+            .map(|i| DataPoint::new(f64::from(i), 50.0 + f64::from(i % 7) / 3.0))
+            .collect(),
+    );
+    engine.set_candles(
+        (0..20)
+            .map(|i| {
+                let base = 40.0 + f64::from(i) / 7.0;
+                OhlcBar::new(f64::from(i) * 5.0, base, base + 3.0, base - 3.0, base + 1.0)
+                    .expect("bar")
+            })
+            .collect(),
+    );
+    engine
+}
+
+fn is_quantized(value: f64, scale: f64) -> bool {
+    ((value * scale).round() - value * scale).abs() < 1e-6
+}
+
+#[test]
+fn contract_mirrors_frame_primitives_in_draw_order() {
+    let engine = engine();
+    let frame = engine.build_render_frame().expect("frame");
+    let contract = engine.render_frame_contract_v1(6.0).expect("contract");
+
+    assert_eq!(contract.schema_version, RENDER_FRAME_JSON_SCHEMA_V1);
+    assert_eq!(contract.viewport, frame.viewport);
+    assert_eq!(contract.lines.len(), frame.lines.len());
+    assert_eq!(contract.rects.len(), frame.rects.len());
+    assert_eq!(contract.paths.len(), frame.paths.len());
+    assert_eq!(contract.texts.len(), frame.texts.len());
+    assert_eq!(contract.candle_geometry.len(), 20);
+    for (exported, text) in contract.texts.iter().zip(&frame.texts) {
+        assert_eq!(exported.text, *text.text);
+        assert!((exported.x - text.x).abs() <= 0.0005);
+    }
+}
+
+#[test]
+fn contract_floats_are_quantized() {
+    let contract = engine().render_frame_contract_v1(6.0).expect("contract");
+    for line in &contract.lines {
+        for value in [line.x1, line.y1, line.x2, line.y2, line.stroke_width] {
+            assert!(is_quantized(value, 1_000.0), "{value}");
+        }
+        assert!(is_quantized(line.color.red, 1_000_000.0));
+    }
+    for path in &contract.paths {
+        for point in &path.points {
+            assert!(is_quantized(point.x, 1_000.0) && is_quantized(point.y, 1_000.0));
+        }
+    }
+    for candle in &contract.candle_geometry {
+        assert!(is_quantized(candle.body_top, 1_000.0));
+    }
+}
+
+#[test]
+fn contract_json_is_deterministic_and_round_trips() {
+    let engine = engine();
+    let first = engine.render_frame_json_contract_v1(6.0).expect("first");
+    let second = engine.render_frame_json_contract_v1(6.0).expect("second");
+    assert_eq!(first, second);
+    assert!(
+        ![",", "}", "]"]
+            .iter()
+            .any(|end| first.contains(&format!("-0.0{end}")))
+    );
+
+    let parsed = RenderFrameJsonContractV1::from_json_str(&first).expect("parse");
+    assert_eq!(
+        parsed,
+        engine.render_frame_contract_v1(6.0).expect("contract")
+    );
+
+    let pretty = engine
+        .render_frame_json_contract_v1_pretty(6.0)
+        .expect("pretty");
+    assert_eq!(
+        RenderFrameJsonContractV1::from_json_str(&pretty).expect("parse pretty"),
+        parsed
+    );
+}
+
+#[test]
+fn contract_rejects_unknown_schema_version() {
+    let mut contract = engine().render_frame_contract_v1(6.0).expect("contract");
+    contract.schema_version = 9;
+    let json = contract.to_json().expect("json");
+    assert!(matches!(
+        RenderFrameJsonContractV1::from_json_str(&json),
+        Err(ChartError::SchemaVersionMismatch { found: 9, .. })
+    ));
+}
@@ -0,0 +1,85 @@
+use chart_rs::api::{ChartEngine, ChartEngineConfig, CrosshairMode};
+use chart_rs::core::{DataPoint, OhlcBar, PaneId, Viewport};
+use chart_rs::render::{LayeredRenderFrame, NullRenderer, PaneLayerStack};
+
+fn build_engine() -> ChartEngine<NullRenderer> {
+    let config =
+        ChartEngineConfig::new(Viewport::new(900, 480), 0.0, 100.0).with_price_domain(0.0, 50.0);
+    let mut engine = ChartEngine::new(NullRenderer::default(), config).expect("engine init");
+    engine.set_data(
+        (0..100)
+            .map(|index| DataPoint::new(f64::from(index), 20.0 + f64::from(index % 9)))
+            .collect(),
+    );
+    engine.set_candles(
+        (0..100)
+            .map(|index| {
+                let time = f64::from(index);
+                OhlcBar::new(time, 20.0, 30.0, 15.0, 25.0).expect("candle")
+            })
+            .collect(),
+    );
+    engine.set_crosshair_mode(CrosshairMode::Normal);
+    engine.pointer_move(200.0, 120.0);
+    engine
+}
+
+#[test]
+fn recycled_frame_buffers_are_refilled_with_identical_content() {
+    let engine = build_engine();
+    let fresh = build_engine().build_render_frame().expect("fresh");
+
+    let first = engine.build_render_frame().expect("first");
+    let lines_ptr = first.lines.as_ptr();
+    let texts_ptr = first.texts.as_ptr();
+    engine.recycle_render_frame(first);
+
+    let second = engine.build_render_frame().expect("second");
+    assert_eq!(second.lines.as_ptr(), lines_ptr);
+    assert_eq!(second.texts.as_ptr(), texts_ptr);
+    assert_eq!(second, fresh);
+}
+
+#[test]
+fn render_recycles_its_frame_and_layered_builds_stay_exact() {
+    let mut engine = build_engine();
+    let fresh = build_engine()
+        .build_layered_render_frame()
+        .expect("fresh layered");
+
+    engine.render().expect("render");
+    let layered = engine.build_layered_render_frame().expect("layered");
+    assert_eq!(layered, fresh);
+    engine.recycle_layered_render_frame(layered);
+
+    engine.pointer_move(600.0, 300.0);
+    let moved = engine.build_layered_render_frame().expect("moved");
+    let expected = {
+        let mut other = build_engine();
+        other.pointer_move(600.0, 300.0);
+        other.build_layered_render_frame().expect("expected")
+    };
+    assert_eq!(moved, expected);
+}
+
+#[test]
+fn reset_from_stacks_clears_primitives_and_follows_the_new_layout() {
+    let viewport = Viewport::new(400, 300);
+    let main = PaneLayerStack::canonical_for_pane(PaneId::new(0));
+    let mut layered = LayeredRenderFrame::from_stacks(viewport, vec![main.clone()]);
+    let series_ptr = {
+        let layer = &mut layered.panes[0].layers[0];
+        layer.lines.reserve(64);
+        layer.lines.as_ptr()
+    };
+
+    let resized = Viewport::new(200, 100);
+    let extra = PaneLayerStack::canonical_for_pane(PaneId::new(1));
+    layered.reset_from_stacks(resized, vec![main.clone(), extra.clone()]);
+
+    assert_eq!(
+        layered,
+        LayeredRenderFrame::from_stacks(resized, vec![main, extra])
+    );
+    assert_eq!(layered.panes[0].layers[0].lines.as_ptr(), series_ptr);
+}
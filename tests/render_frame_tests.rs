@@ -2,8 +2,9 @@ use chart_rs::api::{
     AxisLabelLocale, ChartEngine, ChartEngineConfig, CrosshairLabelBoxHorizontalAnchor,
     CrosshairLabelBoxOverflowPolicy, CrosshairLabelBoxVerticalAnchor,
     CrosshairLabelBoxVisibilityPriority, CrosshairLabelBoxWidthMode, CrosshairLabelBoxZOrderPolicy,
-    CrosshairLabelSourceMode, CrosshairMode, LastPriceLabelBoxWidthMode, LastPriceSourceMode,
-    RenderStyle, TimeAxisLabelConfig, TimeAxisLabelPolicy, TimeAxisSessionConfig, TimeAxisTimeZone,
+    CrosshairLabelSourceMode, CrosshairMode, GridLineOptions, GridOptions,
+    LastPriceLabelBoxWidthMode, LastPriceSourceMode, RenderStyle, TimeAxisLabelConfig,
+    TimeAxisLabelPolicy, TimeAxisSessionConfig, TimeAxisTimeUnit, TimeAxisTimeZone,
 };
 use chart_rs::core::{DataPoint, Viewport};
 use chart_rs::render::{Color, LineStrokeStyle, NullRenderer, TextHAlign};
@@ -88,7 +89,7 @@ fn time_axis_labels_use_configured_typography_offset_and_tick_length() {
         show_time_axis_tick_marks: true,
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set style");
+    engine.set_render_style(style.clone()).expect("set style");
 
     let frame = engine.build_render_frame().expect("build frame");
     let viewport_height = f64::from(engine.viewport().height);
@@ -156,7 +157,7 @@ fn time_axis_labels_can_be_hidden() {
         show_time_axis_labels: false,
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set style");
+    engine.set_render_style(style.clone()).expect("set style");
 
     let frame = engine.build_render_frame().expect("build frame");
     let viewport_height = f64::from(engine.viewport().height);
@@ -169,7 +170,7 @@ fn time_axis_labels_can_be_hidden() {
             .any(|text| text.h_align == TextHAlign::Center)
     );
     assert!(frame.lines.iter().any(|line| {
-        line.color == style.grid_line_color
+        line.color == style.grid.vert_lines.color
             && (line.x1 - line.x2).abs() <= 1e-9
             && (line.y1 - 0.0).abs() <= 1e-9
             && (line.y2 - plot_bottom).abs() <= 1e-9
@@ -193,7 +194,7 @@ fn time_axis_labels_use_dedicated_color() {
         axis_label_color: Color::rgb(0.12, 0.18, 0.26),
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set style");
+    engine.set_render_style(style.clone()).expect("set style");
 
     let frame = engine.build_render_frame().expect("build frame");
     assert!(frame.texts.iter().any(|text| {
@@ -233,6 +234,8 @@ fn major_time_axis_labels_can_be_hidden() {
                 end_hour: 16,
                 end_minute: 0,
             }),
+            time_unit: TimeAxisTimeUnit::Seconds,
+            anchor_ticks_to_nice_steps: false,
         })
         .expect("set session/time-axis config");
 
@@ -257,7 +260,7 @@ fn major_time_axis_labels_can_be_hidden() {
     assert!(
         !center_labels
             .iter()
-            .any(|text| text.text == "2024-01-02 09:30")
+            .any(|text| &*text.text == "2024-01-02 09:30")
     );
 }
 
@@ -288,6 +291,8 @@ fn major_time_axis_labels_use_dedicated_color() {
                 end_hour: 16,
                 end_minute: 0,
             }),
+            time_unit: TimeAxisTimeUnit::Seconds,
+            anchor_ticks_to_nice_steps: false,
         })
         .expect("set session/time-axis config");
 
@@ -296,17 +301,17 @@ fn major_time_axis_labels_use_dedicated_color() {
         major_time_label_color: Color::rgb(0.89, 0.31, 0.18),
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set style");
+    engine.set_render_style(style.clone()).expect("set style");
 
     let frame = engine.build_render_frame().expect("build frame");
     assert!(frame.texts.iter().any(|text| {
         text.h_align == TextHAlign::Center
-            && text.text == "2024-01-02 09:30"
+            && &*text.text == "2024-01-02 09:30"
             && text.color == style.major_time_label_color
     }));
     assert!(frame.texts.iter().any(|text| {
         text.h_align == TextHAlign::Center
-            && text.text != "2024-01-02 09:30"
+            && &*text.text != "2024-01-02 09:30"
             && text.color == style.time_axis_label_color
     }));
 }
@@ -338,6 +343,8 @@ fn major_time_axis_labels_use_dedicated_offset() {
                 end_hour: 16,
                 end_minute: 0,
             }),
+            time_unit: TimeAxisTimeUnit::Seconds,
+            anchor_ticks_to_nice_steps: false,
         })
         .expect("set session/time-axis config");
 
@@ -347,7 +354,7 @@ fn major_time_axis_labels_use_dedicated_offset() {
         major_time_label_font_size_px: 14.0,
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set style");
+    engine.set_render_style(style.clone()).expect("set style");
 
     let frame = engine.build_render_frame().expect("build frame");
     let viewport_height = f64::from(engine.viewport().height);
@@ -355,12 +362,12 @@ fn major_time_axis_labels_use_dedicated_offset() {
     let major_label = frame
         .texts
         .iter()
-        .find(|text| text.h_align == TextHAlign::Center && text.text == "2024-01-02 09:30")
+        .find(|text| text.h_align == TextHAlign::Center && &*text.text == "2024-01-02 09:30")
         .expect("major session-boundary label");
     let regular_label = frame
         .texts
         .iter()
-        .find(|text| text.h_align == TextHAlign::Center && text.text != "2024-01-02 09:30")
+        .find(|text| text.h_align == TextHAlign::Center && &*text.text != "2024-01-02 09:30")
         .expect("regular time-axis label");
 
     let major_offset_from_border = major_label.y - plot_bottom;
@@ -395,6 +402,8 @@ fn major_time_axis_tick_marks_use_dedicated_style() {
                 end_hour: 16,
                 end_minute: 0,
             }),
+            time_unit: TimeAxisTimeUnit::Seconds,
+            anchor_ticks_to_nice_steps: false,
         })
         .expect("set session/time-axis config");
 
@@ -409,7 +418,7 @@ fn major_time_axis_tick_marks_use_dedicated_style() {
         show_major_time_tick_marks: true,
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set style");
+    engine.set_render_style(style.clone()).expect("set style");
 
     let frame = engine.build_render_frame().expect("build frame");
     let viewport_height = f64::from(engine.viewport().height);
@@ -461,6 +470,8 @@ fn major_time_axis_tick_marks_can_be_hidden() {
                 end_hour: 16,
                 end_minute: 0,
             }),
+            time_unit: TimeAxisTimeUnit::Seconds,
+            anchor_ticks_to_nice_steps: false,
         })
         .expect("set session/time-axis config");
 
@@ -473,7 +484,7 @@ fn major_time_axis_tick_marks_can_be_hidden() {
         major_time_tick_mark_width: 2.75,
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set style");
+    engine.set_render_style(style.clone()).expect("set style");
 
     let frame = engine.build_render_frame().expect("build frame");
     let viewport_height = f64::from(engine.viewport().height);
@@ -521,33 +532,40 @@ fn major_time_axis_grid_lines_can_be_hidden() {
                 end_hour: 16,
                 end_minute: 0,
             }),
+            time_unit: TimeAxisTimeUnit::Seconds,
+            anchor_ticks_to_nice_steps: false,
         })
         .expect("set session/time-axis config");
 
     let style = RenderStyle {
-        show_major_time_grid_lines: false,
-        major_grid_line_color: Color::rgb(0.87, 0.28, 0.20),
-        major_grid_line_width: 2.5,
+        grid: GridOptions {
+            major_vert_lines: GridLineOptions {
+                visible: false,
+                color: Color::rgb(0.87, 0.28, 0.20),
+                width: 2.5,
+                ..engine.render_style().grid.major_vert_lines
+            },
+            ..engine.render_style().grid
+        },
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set style");
+    engine.set_render_style(style.clone()).expect("set style");
 
     let frame = engine.build_render_frame().expect("build frame");
     let viewport_height = f64::from(engine.viewport().height);
     let plot_bottom = (viewport_height - style.time_axis_height_px).clamp(0.0, viewport_height);
 
     assert!(!frame.lines.iter().any(|line| {
-        line.color == style.major_grid_line_color
-            && line.stroke_width == style.major_grid_line_width
+        line.color == style.grid.major_vert_lines.color
+            && line.stroke_width == style.grid.major_vert_lines.width
             && (line.x1 - line.x2).abs() <= 1e-9
             && (line.y1 - 0.0).abs() <= 1e-9
             && (line.y2 - plot_bottom).abs() <= 1e-9
     }));
     assert!(
-        frame
-            .texts
-            .iter()
-            .any(|text| { text.h_align == TextHAlign::Center && text.text == "2024-01-02 09:30" })
+        frame.texts.iter().any(|text| {
+            text.h_align == TextHAlign::Center && &*text.text == "2024-01-02 09:30"
+        })
     );
 }
 
@@ -569,7 +587,7 @@ fn time_axis_tick_marks_can_be_hidden() {
         show_time_axis_tick_marks: false,
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set style");
+    engine.set_render_style(style.clone()).expect("set style");
 
     let frame = engine.build_render_frame().expect("build frame");
     let viewport_height = f64::from(engine.viewport().height);
@@ -583,7 +601,7 @@ fn time_axis_tick_marks_can_be_hidden() {
             && line.y2 > line.y1
     }));
     assert!(frame.lines.iter().any(|line| {
-        line.color == style.grid_line_color
+        line.color == style.grid.vert_lines.color
             && (line.x1 - line.x2).abs() <= 1e-9
             && (line.y1 - 0.0).abs() <= 1e-9
             && (line.y2 - plot_bottom).abs() <= 1e-9
@@ -610,7 +628,7 @@ fn time_axis_tick_marks_use_dedicated_style() {
         show_time_axis_tick_marks: true,
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set style");
+    engine.set_render_style(style.clone()).expect("set style");
 
     let frame = engine.build_render_frame().expect("build frame");
     let viewport_height = f64::from(engine.viewport().height);
@@ -650,7 +668,7 @@ fn time_axis_border_can_be_hidden() {
         show_price_axis_border: true,
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set style");
+    engine.set_render_style(style.clone()).expect("set style");
 
     let frame = engine.build_render_frame().expect("build frame");
     let viewport_height = f64::from(engine.viewport().height);
@@ -691,7 +709,7 @@ fn price_axis_border_can_be_hidden() {
         show_price_axis_border: false,
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set style");
+    engine.set_render_style(style.clone()).expect("set style");
 
     let frame = engine.build_render_frame().expect("build frame");
     let viewport_height = f64::from(engine.viewport().height);
@@ -741,7 +759,7 @@ fn last_price_marker_uses_latest_sample_value() {
     assert!(frame.texts.iter().any(|text| {
         text.color == style.last_price_label_color
             && text.h_align == TextHAlign::Right
-            && text.text == "15.00"
+            && &*text.text == "15.00"
     }));
 }
 
@@ -763,7 +781,7 @@ fn last_price_label_uses_configured_vertical_offset() {
         show_last_price_label_box: false,
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set style");
+    engine.set_render_style(style.clone()).expect("set style");
 
     let frame = engine.build_render_frame().expect("build frame");
     let marker_y = engine.map_price_to_pixel(15.0).expect("map").clamp(
@@ -774,7 +792,7 @@ fn last_price_label_uses_configured_vertical_offset() {
 
     assert!(frame.texts.iter().any(|text| {
         text.h_align == TextHAlign::Right
-            && text.text == "15.00"
+            && &*text.text == "15.00"
             && text.color == style.last_price_label_color
             && (text.y - expected_text_y).abs() <= 1e-9
     }));
@@ -796,7 +814,7 @@ fn last_price_marker_can_be_disabled() {
         ..engine.render_style()
     };
     engine
-        .set_render_style(custom_style)
+        .set_render_style(custom_style.clone())
         .expect("set render style");
 
     let frame = engine.build_render_frame().expect("build frame");
@@ -828,7 +846,7 @@ fn price_axis_insets_apply_to_labels_and_tick_marks() {
         last_price_label_color: Color::rgb(0.0, 1.0, 0.0),
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set style");
+    engine.set_render_style(style.clone()).expect("set style");
 
     let frame = engine.build_render_frame().expect("build frame");
     let viewport_width = f64::from(engine.viewport().width);
@@ -845,7 +863,7 @@ fn price_axis_insets_apply_to_labels_and_tick_marks() {
     assert!(frame.texts.iter().any(|text| {
         text.h_align == TextHAlign::Right
             && text.color == style.last_price_label_color
-            && text.text == "20.00"
+            && &*text.text == "20.00"
             && (text.x - expected_label_x).abs() <= 1e-9
     }));
     assert!(frame.lines.iter().any(|line| {
@@ -870,7 +888,7 @@ fn price_axis_tick_marks_can_be_hidden() {
         price_axis_tick_mark_color: Color::rgb(0.91, 0.23, 0.21),
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set style");
+    engine.set_render_style(style.clone()).expect("set style");
 
     let frame = engine.build_render_frame().expect("build frame");
     let viewport_width = f64::from(engine.viewport().width);
@@ -893,21 +911,27 @@ fn price_axis_grid_lines_can_be_hidden() {
     engine.set_data(vec![DataPoint::new(1.0, 10.0), DataPoint::new(2.0, 20.0)]);
 
     let style = RenderStyle {
-        show_price_axis_grid_lines: false,
-        price_axis_grid_line_color: Color::rgb(0.91, 0.23, 0.21),
-        price_axis_grid_line_width: 2.0,
+        grid: GridOptions {
+            horz_lines: GridLineOptions {
+                visible: false,
+                color: Color::rgb(0.91, 0.23, 0.21),
+                width: 2.0,
+                ..engine.render_style().grid.horz_lines
+            },
+            ..engine.render_style().grid
+        },
         last_price_line_color: Color::rgb(0.11, 0.74, 0.31),
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set style");
+    engine.set_render_style(style.clone()).expect("set style");
 
     let frame = engine.build_render_frame().expect("build frame");
     let viewport_width = f64::from(engine.viewport().width);
     let plot_right = (viewport_width - style.price_axis_width_px).clamp(0.0, viewport_width);
 
     assert!(!frame.lines.iter().any(|line| {
-        line.color == style.price_axis_grid_line_color
-            && line.stroke_width == style.price_axis_grid_line_width
+        line.color == style.grid.horz_lines.color
+            && line.stroke_width == style.grid.horz_lines.width
             && (line.y1 - line.y2).abs() <= 1e-9
             && (line.x1 - 0.0).abs() <= 1e-9
             && (line.x2 - plot_right).abs() <= 1e-9
@@ -928,7 +952,7 @@ fn price_axis_labels_can_be_hidden() {
         last_price_label_color: Color::rgb(0.12, 0.72, 0.31),
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set style");
+    engine.set_render_style(style.clone()).expect("set style");
 
     let frame = engine.build_render_frame().expect("build frame");
 
@@ -940,7 +964,7 @@ fn price_axis_labels_can_be_hidden() {
     assert!(frame.texts.iter().any(|text| {
         text.h_align == TextHAlign::Right
             && text.color == style.last_price_label_color
-            && text.text == "20.00"
+            && &*text.text == "20.00"
     }));
 }
 
@@ -959,7 +983,7 @@ fn last_price_label_padding_right_is_independent_from_axis_label_padding() {
         last_price_label_color: Color::rgb(0.0, 1.0, 0.0),
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set style");
+    engine.set_render_style(style.clone()).expect("set style");
 
     let frame = engine.build_render_frame().expect("build frame");
     let viewport_width = f64::from(engine.viewport().width);
@@ -974,7 +998,7 @@ fn last_price_label_padding_right_is_independent_from_axis_label_padding() {
     assert!(frame.texts.iter().any(|text| {
         text.h_align == TextHAlign::Right
             && text.color == style.last_price_label_color
-            && text.text == "20.00"
+            && &*text.text == "20.00"
             && (text.x - expected_last_price_label_x).abs() <= 1e-9
     }));
 }
@@ -993,7 +1017,7 @@ fn price_axis_labels_use_configured_font_size_and_offset() {
         show_last_price_label: false,
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set style");
+    engine.set_render_style(style.clone()).expect("set style");
 
     let frame = engine.build_render_frame().expect("build frame");
     let axis_labels: Vec<_> = frame
@@ -1009,14 +1033,14 @@ fn price_axis_labels_use_configured_font_size_and_offset() {
     );
     assert!(axis_labels.iter().any(|text| {
         frame.lines.iter().any(|line| {
-            line.color == style.grid_line_color
+            line.color == style.grid.vert_lines.color
                 && (line.y1 - line.y2).abs() <= 1e-9
                 && ((line.y1 - text.y) - style.price_axis_label_offset_y_px).abs() <= 1e-9
         })
     }));
     assert!(axis_labels.iter().all(|text| {
         frame.lines.iter().any(|line| {
-            line.color == style.grid_line_color && (line.y1 - line.y2).abs() <= 1e-9 && {
+            line.color == style.grid.vert_lines.color && (line.y1 - line.y2).abs() <= 1e-9 && {
                 let delta_y = line.y1 - text.y;
                 delta_y >= -1e-9 && delta_y <= style.price_axis_label_offset_y_px + 1e-9
             }
@@ -1046,7 +1070,7 @@ fn last_price_label_exclusion_filters_overlapping_axis_labels() {
         ..engine.render_style()
     };
     engine
-        .set_render_style(no_exclusion_style)
+        .set_render_style(no_exclusion_style.clone())
         .expect("set style no exclusion");
     let frame_no_exclusion = engine.build_render_frame().expect("frame no exclusion");
     let axis_labels_no_exclusion = frame_no_exclusion
@@ -1062,7 +1086,7 @@ fn last_price_label_exclusion_filters_overlapping_axis_labels() {
         ..no_exclusion_style
     };
     engine
-        .set_render_style(strong_exclusion_style)
+        .set_render_style(strong_exclusion_style.clone())
         .expect("set style strong exclusion");
     let frame_strong_exclusion = engine.build_render_frame().expect("frame strong exclusion");
     let axis_labels_strong_exclusion = frame_strong_exclusion
@@ -1098,7 +1122,7 @@ fn last_price_trend_color_uses_up_color() {
         ..engine.render_style()
     };
     engine
-        .set_render_style(trend_style)
+        .set_render_style(trend_style.clone())
         .expect("set trend style");
 
     let frame = engine.build_render_frame().expect("build frame");
@@ -1115,7 +1139,7 @@ fn last_price_trend_color_uses_up_color() {
     }));
     assert!(frame.texts.iter().any(|text| {
         text.h_align == TextHAlign::Right
-            && text.text == "15.00"
+            && &*text.text == "15.00"
             && text.color == trend_style.last_price_up_color
     }));
 }
@@ -1137,7 +1161,7 @@ fn last_price_trend_color_uses_down_color() {
         ..engine.render_style()
     };
     engine
-        .set_render_style(trend_style)
+        .set_render_style(trend_style.clone())
         .expect("set trend style");
 
     let frame = engine.build_render_frame().expect("build frame");
@@ -1154,7 +1178,7 @@ fn last_price_trend_color_uses_down_color() {
     }));
     assert!(frame.texts.iter().any(|text| {
         text.h_align == TextHAlign::Right
-            && text.text == "12.00"
+            && &*text.text == "12.00"
             && text.color == trend_style.last_price_down_color
     }));
 }
@@ -1176,7 +1200,7 @@ fn last_price_trend_color_uses_neutral_without_previous_sample() {
         ..engine.render_style()
     };
     engine
-        .set_render_style(trend_style)
+        .set_render_style(trend_style.clone())
         .expect("set trend style");
 
     let frame = engine.build_render_frame().expect("build frame");
@@ -1193,7 +1217,7 @@ fn last_price_trend_color_uses_neutral_without_previous_sample() {
     }));
     assert!(frame.texts.iter().any(|text| {
         text.h_align == TextHAlign::Right
-            && text.text == "14.00"
+            && &*text.text == "14.00"
             && text.color == trend_style.last_price_neutral_color
     }));
 }
@@ -1220,7 +1244,7 @@ fn last_price_source_mode_latest_visible_uses_latest_visible_sample() {
         last_price_source_mode: LastPriceSourceMode::LatestVisible,
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set style");
+    engine.set_render_style(style.clone()).expect("set style");
 
     let frame = engine.build_render_frame().expect("build frame");
     let expected_y = engine.map_price_to_pixel(20.0).expect("map").clamp(
@@ -1235,7 +1259,7 @@ fn last_price_source_mode_latest_visible_uses_latest_visible_sample() {
     }));
     assert!(frame.texts.iter().any(|text| {
         text.h_align == TextHAlign::Right
-            && text.text == "20.00"
+            && &*text.text == "20.00"
             && text.color == style.last_price_label_color
     }));
 }
@@ -1262,7 +1286,7 @@ fn last_price_source_mode_latest_visible_hides_marker_for_empty_visible_window()
         last_price_source_mode: LastPriceSourceMode::LatestVisible,
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set style");
+    engine.set_render_style(style.clone()).expect("set style");
 
     let frame = engine.build_render_frame().expect("build frame");
     assert!(!frame.lines.iter().any(|line| {
@@ -1293,7 +1317,7 @@ fn last_price_label_box_draws_axis_rect_and_uses_box_text_color() {
         last_price_label_box_text_color: Color::rgb(1.0, 1.0, 1.0),
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set style");
+    engine.set_render_style(style.clone()).expect("set style");
     let frame = engine.build_render_frame().expect("build frame");
     let plot_right = (f64::from(engine.viewport().width) - style.price_axis_width_px)
         .clamp(0.0, f64::from(engine.viewport().width));
@@ -1309,7 +1333,7 @@ fn last_price_label_box_draws_axis_rect_and_uses_box_text_color() {
     }));
     assert!(frame.texts.iter().any(|text| {
         text.h_align == TextHAlign::Right
-            && text.text == "20.00"
+            && &*text.text == "20.00"
             && text.color == style.last_price_label_box_text_color
     }));
 }
@@ -1331,7 +1355,7 @@ fn last_price_label_box_can_use_custom_fill_color() {
         last_price_label_box_auto_text_contrast: false,
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set style");
+    engine.set_render_style(style.clone()).expect("set style");
     let frame = engine.build_render_frame().expect("build frame");
 
     assert!(
@@ -1342,7 +1366,7 @@ fn last_price_label_box_can_use_custom_fill_color() {
     );
     assert!(frame.texts.iter().any(|text| {
         text.h_align == TextHAlign::Right
-            && text.text == "20.00"
+            && &*text.text == "20.00"
             && text.color == style.last_price_label_box_text_color
     }));
 }
@@ -1368,7 +1392,7 @@ fn last_price_label_box_auto_text_contrast_switches_to_dark_text_on_bright_fill(
 
     assert!(frame.texts.iter().any(|text| {
         text.h_align == TextHAlign::Right
-            && text.text == "20.00"
+            && &*text.text == "20.00"
             && text.color == Color::rgb(0.06, 0.08, 0.11)
     }));
 }
@@ -1415,7 +1439,7 @@ fn last_price_label_box_fit_text_respects_min_width_and_padding() {
         last_price_label_box_color: Color::rgb(0.12, 0.12, 0.12),
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set style");
+    engine.set_render_style(style.clone()).expect("set style");
     let frame = engine.build_render_frame().expect("build frame");
 
     let expected_box_width = 80.0;
@@ -1428,7 +1452,7 @@ fn last_price_label_box_fit_text_respects_min_width_and_padding() {
     }));
     assert!(frame.texts.iter().any(|text| {
         text.h_align == TextHAlign::Right
-            && text.text == "20.00"
+            && &*text.text == "20.00"
             && (text.x - expected_text_x).abs() <= 1e-9
     }));
 }
@@ -1447,7 +1471,7 @@ fn crosshair_lines_follow_pointer_in_normal_mode() {
         crosshair_vertical_line_width: None,
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set style");
+    engine.set_render_style(style.clone()).expect("set style");
     engine.pointer_move(333.0, 177.0);
 
     let frame = engine.build_render_frame().expect("build frame");
@@ -1494,7 +1518,7 @@ fn crosshair_lines_use_snapped_coordinates_in_magnet_mode() {
         crosshair_vertical_line_width: None,
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set style");
+    engine.set_render_style(style.clone()).expect("set style");
     engine.pointer_move(171.0, 300.0);
 
     let crosshair = engine.crosshair_state();
@@ -1540,7 +1564,7 @@ fn crosshair_line_visibility_toggles_are_independent() {
         show_crosshair_vertical_line: false,
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set style");
+    engine.set_render_style(style.clone()).expect("set style");
     engine.pointer_move(260.0, 210.0);
     let frame = engine.build_render_frame().expect("build frame");
 
@@ -1571,7 +1595,7 @@ fn crosshair_line_shared_visibility_toggle_disables_both_axes() {
         show_crosshair_vertical_line: true,
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set style");
+    engine.set_render_style(style.clone()).expect("set style");
     engine.pointer_move(260.0, 210.0);
     let frame = engine.build_render_frame().expect("build frame");
 
@@ -1594,7 +1618,7 @@ fn crosshair_line_style_is_independent_per_axis() {
         crosshair_vertical_line_style: Some(LineStrokeStyle::Dashed),
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set style");
+    engine.set_render_style(style.clone()).expect("set style");
     engine.pointer_move(260.0, 210.0);
     let frame = engine.build_render_frame().expect("build frame");
     let viewport_width = f64::from(engine.viewport().width);
@@ -1642,7 +1666,7 @@ fn crosshair_line_style_shared_policy_applies_without_per_axis_overrides() {
         crosshair_vertical_line_style: None,
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set style");
+    engine.set_render_style(style.clone()).expect("set style");
     engine.pointer_move(260.0, 210.0);
     let frame = engine.build_render_frame().expect("build frame");
     let viewport_width = f64::from(engine.viewport().width);
@@ -1689,7 +1713,7 @@ fn crosshair_line_width_is_independent_per_axis() {
         crosshair_vertical_line_width: Some(2.0),
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set style");
+    engine.set_render_style(style.clone()).expect("set style");
     engine.pointer_move(260.0, 210.0);
     let frame = engine.build_render_frame().expect("build frame");
     let viewport_width = f64::from(engine.viewport().width);
@@ -1737,7 +1761,7 @@ fn crosshair_line_width_shared_policy_applies_without_per_axis_overrides() {
         crosshair_vertical_line_width: None,
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set style");
+    engine.set_render_style(style.clone()).expect("set style");
     engine.pointer_move(260.0, 210.0);
     let frame = engine.build_render_frame().expect("build frame");
     let viewport_width = f64::from(engine.viewport().width);
@@ -1784,7 +1808,7 @@ fn crosshair_line_color_is_independent_per_axis() {
         crosshair_vertical_line_color: Some(Color::rgb(0.19, 0.40, 0.84)),
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set style");
+    engine.set_render_style(style.clone()).expect("set style");
     engine.pointer_move(260.0, 210.0);
     let frame = engine.build_render_frame().expect("build frame");
     let viewport_width = f64::from(engine.viewport().width);
@@ -1842,7 +1866,7 @@ fn crosshair_line_color_shared_policy_applies_without_per_axis_overrides() {
         crosshair_vertical_line_color: None,
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set style");
+    engine.set_render_style(style.clone()).expect("set style");
     engine.pointer_move(260.0, 210.0);
     let frame = engine.build_render_frame().expect("build frame");
     let viewport_width = f64::from(engine.viewport().width);
@@ -1900,7 +1924,7 @@ fn crosshair_axis_labels_follow_pointer_in_normal_mode() {
         crosshair_price_label_font_size_px: 13.0,
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set style");
+    engine.set_render_style(style.clone()).expect("set style");
     engine.pointer_move(333.0, 177.0);
     let frame = engine.build_render_frame().expect("build frame");
 
@@ -1922,14 +1946,14 @@ fn crosshair_axis_labels_follow_pointer_in_normal_mode() {
     assert!(frame.texts.iter().any(|text| {
         text.color == style.crosshair_time_label_color
             && text.h_align == TextHAlign::Center
-            && text.text == expected_time_text
+            && *text.text == expected_time_text
             && (text.x - expected_x).abs() <= 1e-9
             && (text.font_size_px - style.crosshair_time_label_font_size_px).abs() <= 1e-9
     }));
     assert!(frame.texts.iter().any(|text| {
         text.color == style.crosshair_price_label_color
             && text.h_align == TextHAlign::Right
-            && text.text == expected_price_text
+            && *text.text == expected_price_text
             && (text.y - (expected_y - style.crosshair_price_label_offset_y_px).max(0.0)).abs()
                 <= 1e-9
             && (text.font_size_px - style.crosshair_price_label_font_size_px).abs() <= 1e-9
@@ -1958,7 +1982,7 @@ fn crosshair_time_label_formatter_override_is_applied_per_axis() {
         show_crosshair_price_label_box: false,
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set style");
+    engine.set_render_style(style.clone()).expect("set style");
     engine.set_crosshair_time_label_formatter(Arc::new(|value| format!("XTIME:{value:.1}")));
     engine.pointer_move(333.0, 177.0);
 
@@ -1994,7 +2018,7 @@ fn crosshair_price_label_formatter_override_is_applied_per_axis() {
         show_crosshair_price_label_box: false,
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set style");
+    engine.set_render_style(style.clone()).expect("set style");
     engine.set_crosshair_price_label_formatter(Arc::new(|_| "XP".to_owned()));
     engine.pointer_move(333.0, 177.0);
 
@@ -2004,7 +2028,7 @@ fn crosshair_price_label_formatter_override_is_applied_per_axis() {
         .iter()
         .find(|text| text.color == style.crosshair_price_label_color)
         .expect("crosshair price label");
-    assert_eq!(crosshair_price_text.text, "XP%");
+    assert_eq!(&*crosshair_price_text.text, "XP%");
 }
 
 #[test]
@@ -2021,7 +2045,7 @@ fn clearing_crosshair_formatter_overrides_falls_back_to_default_policy() {
         show_crosshair_price_label_box: false,
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set style");
+    engine.set_render_style(style.clone()).expect("set style");
     engine.set_crosshair_time_label_formatter(Arc::new(|_| "XTIME".to_owned()));
     engine.clear_crosshair_time_label_formatter();
     engine.pointer_move(333.0, 177.0);
@@ -2032,7 +2056,7 @@ fn clearing_crosshair_formatter_overrides_falls_back_to_default_policy() {
         .iter()
         .find(|text| text.color == style.crosshair_time_label_color)
         .expect("crosshair time label");
-    assert_ne!(crosshair_time_text.text, "XTIME");
+    assert_ne!(&*crosshair_time_text.text, "XTIME");
 }
 
 #[test]
@@ -2049,11 +2073,11 @@ fn crosshair_axis_label_text_transform_supports_shared_fallback() {
         show_crosshair_price_label_box: false,
         crosshair_time_label_color: Color::rgb(0.88, 0.26, 0.18),
         crosshair_price_label_color: Color::rgb(0.19, 0.43, 0.88),
-        crosshair_label_prefix: "[[",
-        crosshair_label_suffix: "]]",
+        crosshair_label_prefix: "[[".into(),
+        crosshair_label_suffix: "]]".into(),
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set style");
+    engine.set_render_style(style.clone()).expect("set style");
     engine.pointer_move(333.0, 177.0);
 
     let frame = engine.build_render_frame().expect("build frame");
@@ -2086,15 +2110,15 @@ fn crosshair_axis_label_text_transform_supports_per_axis_overrides() {
         show_crosshair_price_label_box: false,
         crosshair_time_label_color: Color::rgb(0.88, 0.26, 0.18),
         crosshair_price_label_color: Color::rgb(0.19, 0.43, 0.88),
-        crosshair_label_prefix: "S:",
-        crosshair_label_suffix: ":S",
-        crosshair_time_label_prefix: Some("T:"),
-        crosshair_time_label_suffix: Some(":T"),
-        crosshair_price_label_prefix: Some("P:"),
-        crosshair_price_label_suffix: Some(":P"),
+        crosshair_label_prefix: "S:".into(),
+        crosshair_label_suffix: ":S".into(),
+        crosshair_time_label_prefix: Some("T:".into()),
+        crosshair_time_label_suffix: Some(":T".into()),
+        crosshair_price_label_prefix: Some("P:".into()),
+        crosshair_price_label_suffix: Some(":P".into()),
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set style");
+    engine.set_render_style(style.clone()).expect("set style");
     engine.pointer_move(333.0, 177.0);
 
     let frame = engine.build_render_frame().expect("build frame");
@@ -2136,7 +2160,7 @@ fn crosshair_axis_label_numeric_precision_supports_shared_fallback() {
         crosshair_label_numeric_precision: Some(1),
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set style");
+    engine.set_render_style(style.clone()).expect("set style");
     engine.pointer_move(333.0, 177.0);
 
     let frame = engine.build_render_frame().expect("build frame");
@@ -2187,7 +2211,7 @@ fn crosshair_axis_label_numeric_precision_supports_per_axis_overrides() {
         crosshair_price_label_numeric_precision: Some(4),
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set style");
+    engine.set_render_style(style.clone()).expect("set style");
     engine.pointer_move(333.0, 177.0);
 
     let frame = engine.build_render_frame().expect("build frame");
@@ -2228,7 +2252,7 @@ fn crosshair_time_formatter_context_includes_span_and_source_mode() {
         crosshair_time_label_color: Color::rgb(0.89, 0.24, 0.20),
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set style");
+    engine.set_render_style(style.clone()).expect("set style");
     engine.set_crosshair_time_label_formatter_with_context(Arc::new(|value, context| {
         let source = match context.source_mode {
             CrosshairLabelSourceMode::SnappedData => "snapped",
@@ -2268,7 +2292,7 @@ fn crosshair_price_formatter_context_includes_span_and_source_mode() {
         crosshair_price_label_color: Color::rgb(0.20, 0.43, 0.88),
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set style");
+    engine.set_render_style(style.clone()).expect("set style");
     engine.set_crosshair_price_label_formatter_with_context(Arc::new(|value, context| {
         let source = match context.source_mode {
             CrosshairLabelSourceMode::SnappedData => "snapped",
@@ -2303,7 +2327,7 @@ fn crosshair_context_formatter_has_priority_over_legacy_formatter() {
         crosshair_time_label_color: Color::rgb(0.89, 0.24, 0.20),
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set style");
+    engine.set_render_style(style.clone()).expect("set style");
     engine.set_crosshair_time_label_formatter(Arc::new(|_| "LEGACY".to_owned()));
     engine.set_crosshair_time_label_formatter_with_context(Arc::new(|_, _| "CTX".to_owned()));
     engine.pointer_move(333.0, 177.0);
@@ -2314,7 +2338,7 @@ fn crosshair_context_formatter_has_priority_over_legacy_formatter() {
         .iter()
         .find(|label| label.color == style.crosshair_time_label_color)
         .expect("time label");
-    assert_eq!(text.text, "CTX");
+    assert_eq!(&*text.text, "CTX");
 }
 
 #[test]
@@ -2528,7 +2552,7 @@ fn crosshair_axis_labels_use_dedicated_vertical_offsets() {
         crosshair_price_label_offset_y_px: 13.0,
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set style");
+    engine.set_render_style(style.clone()).expect("set style");
     engine.pointer_move(333.0, 177.0);
     let frame = engine.build_render_frame().expect("build frame");
 
@@ -2571,7 +2595,7 @@ fn crosshair_axis_labels_use_dedicated_horizontal_insets() {
         crosshair_price_label_padding_right_px: 14.0,
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set style");
+    engine.set_render_style(style.clone()).expect("set style");
     engine.pointer_move(2.0, 177.0);
     let frame = engine.build_render_frame().expect("build frame");
 
@@ -2609,7 +2633,7 @@ fn crosshair_axis_labels_use_dedicated_font_sizes() {
         crosshair_price_label_font_size_px: 10.0,
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set style");
+    engine.set_render_style(style.clone()).expect("set style");
     engine.pointer_move(333.0, 177.0);
     let frame = engine.build_render_frame().expect("build frame");
 
@@ -2650,7 +2674,7 @@ fn crosshair_axis_labels_use_snapped_values_in_magnet_mode() {
         show_crosshair_price_label_box: false,
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set style");
+    engine.set_render_style(style.clone()).expect("set style");
     engine.pointer_move(171.0, 300.0);
 
     let crosshair = engine.crosshair_state();
@@ -2665,12 +2689,12 @@ fn crosshair_axis_labels_use_snapped_values_in_magnet_mode() {
     assert!(frame.texts.iter().any(|text| {
         text.color == style.crosshair_time_label_color
             && text.h_align == TextHAlign::Center
-            && text.text == format!("{snapped_time:.2}")
+            && *text.text == format!("{snapped_time:.2}")
     }));
     assert!(frame.texts.iter().any(|text| {
         text.color == style.crosshair_price_label_color
             && text.h_align == TextHAlign::Right
-            && text.text == format!("{snapped_price:.2}")
+            && *text.text == format!("{snapped_price:.2}")
     }));
 }
 
@@ -2690,7 +2714,7 @@ fn crosshair_axis_label_visibility_toggles_are_independent() {
         show_crosshair_price_label: true,
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set style");
+    engine.set_render_style(style.clone()).expect("set style");
     engine.pointer_move(260.0, 210.0);
     let frame = engine.build_render_frame().expect("build frame");
 
@@ -2721,7 +2745,7 @@ fn crosshair_axis_label_boxes_render_on_time_and_price_axes() {
         show_crosshair_price_label_box: true,
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set style");
+    engine.set_render_style(style.clone()).expect("set style");
     engine.pointer_move(260.0, 210.0);
 
     let frame = engine.build_render_frame().expect("build frame");
@@ -2749,7 +2773,7 @@ fn crosshair_axis_label_box_visibility_toggles_are_independent() {
         show_crosshair_price_label_box: true,
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set style");
+    engine.set_render_style(style.clone()).expect("set style");
     engine.pointer_move(260.0, 210.0);
 
     let frame = engine.build_render_frame().expect("build frame");
@@ -2780,7 +2804,7 @@ fn crosshair_axis_label_boxes_apply_border_and_corner_radius() {
         show_crosshair_price_label_box: true,
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set style");
+    engine.set_render_style(style.clone()).expect("set style");
     engine.pointer_move(260.0, 210.0);
 
     let frame = engine.build_render_frame().expect("build frame");
@@ -2846,7 +2870,7 @@ fn crosshair_axis_label_box_manual_text_color_is_used_when_auto_contrast_disable
         show_crosshair_price_label_box: true,
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set style");
+    engine.set_render_style(style.clone()).expect("set style");
     engine.pointer_move(260.0, 210.0);
     let frame = engine.build_render_frame().expect("build frame");
 
@@ -2877,7 +2901,7 @@ fn crosshair_axis_label_box_text_policy_is_independent_per_axis() {
         show_crosshair_price_label_box: true,
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set style");
+    engine.set_render_style(style.clone()).expect("set style");
     engine.pointer_move(260.0, 210.0);
     let frame = engine.build_render_frame().expect("build frame");
 
@@ -2918,7 +2942,7 @@ fn crosshair_axis_label_box_text_alignment_is_independent_per_axis() {
         show_crosshair_price_label_box: true,
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set style");
+    engine.set_render_style(style.clone()).expect("set style");
     engine.pointer_move(260.0, 210.0);
     let frame = engine.build_render_frame().expect("build frame");
 
@@ -2955,7 +2979,7 @@ fn crosshair_axis_label_box_horizontal_anchor_is_independent_per_axis() {
         show_crosshair_price_label_box: true,
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set style");
+    engine.set_render_style(style.clone()).expect("set style");
     engine.pointer_move(260.0, 210.0);
     let frame = engine.build_render_frame().expect("build frame");
 
@@ -3002,7 +3026,7 @@ fn crosshair_axis_label_box_overflow_policy_is_independent_per_axis() {
         show_crosshair_price_label_box: true,
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set style");
+    engine.set_render_style(style.clone()).expect("set style");
     engine.pointer_move(260.0, 490.0);
     let frame = engine.build_render_frame().expect("build frame");
 
@@ -3053,7 +3077,7 @@ fn crosshair_axis_label_box_visibility_priority_resolves_overlap_deterministical
         show_crosshair_price_label_box: true,
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set style");
+    engine.set_render_style(style.clone()).expect("set style");
     engine.pointer_move(880.0, 490.0);
     let frame = engine.build_render_frame().expect("build frame");
 
@@ -3092,7 +3116,7 @@ fn crosshair_axis_label_box_clip_margin_is_independent_per_axis() {
         show_crosshair_price_label_box: true,
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set style");
+    engine.set_render_style(style.clone()).expect("set style");
     engine.pointer_move(2.0, 498.0);
     let frame = engine.build_render_frame().expect("build frame");
 
@@ -3134,7 +3158,7 @@ fn crosshair_axis_label_box_stabilization_step_is_independent_per_axis() {
         crosshair_price_label_box_stabilization_step_px: 3.0,
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set style");
+    engine.set_render_style(style.clone()).expect("set style");
     engine.pointer_move(263.3, 217.4);
     let frame = engine.build_render_frame().expect("build frame");
 
@@ -3181,7 +3205,7 @@ fn crosshair_axis_label_box_z_order_is_deterministic_per_axis() {
         show_crosshair_price_label_box: true,
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set style");
+    engine.set_render_style(style.clone()).expect("set style");
     engine.pointer_move(260.0, 210.0);
     let frame = engine.build_render_frame().expect("build frame");
 
@@ -3244,7 +3268,7 @@ fn crosshair_axis_label_box_vertical_anchor_is_independent_per_axis() {
         show_crosshair_price_label_box: true,
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set style");
+    engine.set_render_style(style.clone()).expect("set style");
     engine.pointer_move(260.0, 210.0);
     let frame = engine.build_render_frame().expect("build frame");
 
@@ -3321,7 +3345,7 @@ fn crosshair_axis_label_box_fill_color_is_independent_per_axis() {
         show_crosshair_price_label_box: true,
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set style");
+    engine.set_render_style(style.clone()).expect("set style");
     engine.pointer_move(260.0, 210.0);
     let frame = engine.build_render_frame().expect("build frame");
 
@@ -3357,7 +3381,7 @@ fn crosshair_axis_label_box_width_mode_full_axis_expands_boxes() {
         show_crosshair_price_label_box: true,
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set style");
+    engine.set_render_style(style.clone()).expect("set style");
     engine.pointer_move(260.0, 210.0);
 
     let frame = engine.build_render_frame().expect("build frame");
@@ -3401,7 +3425,7 @@ fn crosshair_axis_label_box_width_mode_is_independent_per_axis() {
         show_crosshair_price_label_box: true,
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set style");
+    engine.set_render_style(style.clone()).expect("set style");
     engine.pointer_move(260.0, 210.0);
 
     let frame = engine.build_render_frame().expect("build frame");
@@ -3446,7 +3470,7 @@ fn crosshair_axis_label_box_min_width_is_independent_per_axis() {
         show_crosshair_price_label_box: true,
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set style");
+    engine.set_render_style(style.clone()).expect("set style");
     engine.pointer_move(260.0, 210.0);
 
     let frame = engine.build_render_frame().expect("build frame");
@@ -3490,7 +3514,7 @@ fn crosshair_axis_label_box_border_visibility_toggles_are_independent() {
         show_crosshair_price_label_box_border: true,
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set style");
+    engine.set_render_style(style.clone()).expect("set style");
     engine.pointer_move(260.0, 210.0);
     let frame = engine.build_render_frame().expect("build frame");
 
@@ -3533,7 +3557,7 @@ fn crosshair_axis_label_box_border_style_is_independent_per_axis() {
         crosshair_price_label_box_border_width_px: 1.0,
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set style");
+    engine.set_render_style(style.clone()).expect("set style");
     engine.pointer_move(260.0, 210.0);
     let frame = engine.build_render_frame().expect("build frame");
 
@@ -3585,7 +3609,7 @@ fn crosshair_axis_label_box_corner_radius_is_independent_per_axis() {
         show_crosshair_price_label_box: true,
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set style");
+    engine.set_render_style(style.clone()).expect("set style");
     engine.pointer_move(260.0, 210.0);
     let frame = engine.build_render_frame().expect("build frame");
 
@@ -3630,7 +3654,7 @@ fn crosshair_axis_label_box_padding_is_independent_per_axis() {
         show_crosshair_price_label_box: true,
         ..engine.render_style()
     };
-    engine.set_render_style(style).expect("set style");
+    engine.set_render_style(style.clone()).expect("set style");
     engine.pointer_move(260.0, 210.0);
     let frame = engine.build_render_frame().expect("build frame");
 
@@ -0,0 +1,50 @@
+#![cfg(feature = "pdf-export")]
+
+use chart_rs::ChartError;
+use chart_rs::api::{ChartEngine, ChartEngineConfig};
+use chart_rs::core::{DataPoint, Viewport};
+use chart_rs::render::{NullRenderer, PdfPageSize};
+
+fn engine() -> ChartEngine<NullRenderer> {
+    let config =
+        ChartEngineConfig::new(Viewport::new(900, 500), 0.0, 100.0).with_price_domain(0.0, 50.0);
+    let mut engine = ChartEngine::new(NullRenderer::default(), config).expect("engine init");
+    engine.set_data(vec![
+        DataPoint::new(10.0, 10.0),
+        DataPoint::new(20.0, 20.0),
+        DataPoint::new(40.0, 15.0),
+    ]);
+    engine
+}
+
+#[test]
+fn current_frame_is_written_as_pdf_document() {
+    let path = std::env::temp_dir().join(format!("chart-rs-export-{}.pdf", std::process::id()));
+    engine()
+        .render_to_pdf(&path, PdfPageSize::A4.landscape())
+        .expect("pdf export");
+
+    let bytes = std::fs::read(&path).expect("read pdf");
+    std::fs::remove_file(&path).expect("cleanup");
+    assert!(bytes.starts_with(b"%PDF-"));
+    assert!(bytes.len() > 1_000);
+}
+
+#[test]
+fn page_sizes_must_leave_room_inside_the_margins() {
+    let path = std::env::temp_dir().join("chart-rs-export-invalid.pdf");
+    for page_size in [
+        PdfPageSize::new(50.0, 800.0),
+        PdfPageSize::new(f64::NAN, 800.0),
+    ] {
+        let err = engine()
+            .render_to_pdf(&path, page_size)
+            .expect_err("invalid page size");
+        assert!(matches!(err, ChartError::InvalidData(_)));
+    }
+    assert!(!path.exists());
+    assert_eq!(
+        PdfPageSize::LETTER.landscape(),
+        PdfPageSize::new(792.0, 612.0)
+    );
+}
@@ -0,0 +1,287 @@
+use chart_rs::ChartError;
+use chart_rs::core::{
+    DataPoint, PriceScale, TimeScale, Viewport, project_area_geometry, project_baseline_geometry,
+};
+use chart_rs::render::{
+    ArrowDirection, ArrowPrimitive, CanvasLayerKind, ClipRect, Color, DEFAULT_TEXT_FONT_FAMILY,
+    GradientDirection, LayeredRenderFrame, LinearGradient, PaneLayerStack, PathFillRule, PathPoint,
+    PathPrimitive, RectPrimitive, RenderFrame, TextFontWeight, TextHAlign, TextPrimitive,
+    TextVAlign,
+};
+
+fn label() -> TextPrimitive {
+    TextPrimitive::new(
+        "Price",
+        10.0,
+        20.0,
+        12.0,
+        Color::rgb(0.1, 0.1, 0.1),
+        TextHAlign::Left,
+    )
+}
+
+#[test]
+fn text_primitive_defaults_preserve_legacy_typography() {
+    let text = label();
+    assert_eq!(text.font_family, DEFAULT_TEXT_FONT_FAMILY);
+    assert_eq!(text.font_weight, TextFontWeight::Normal);
+    assert_eq!(text.v_align, TextVAlign::Top);
+    assert!(!text.italic);
+    assert!(!text.is_rotated());
+    text.validate().expect("default text is valid");
+}
+
+#[test]
+fn text_primitive_builders_apply_rich_typography() {
+    let text = label()
+        .with_font_family("Monospace")
+        .with_font_weight(TextFontWeight::Bold)
+        .with_italic(true)
+        .with_v_align(TextVAlign::Middle)
+        .with_rotation(-std::f64::consts::FRAC_PI_2);
+
+    assert_eq!(text.font_family, "Monospace");
+    assert_eq!(text.font_weight, TextFontWeight::Bold);
+    assert!(text.italic);
+    assert_eq!(text.v_align, TextVAlign::Middle);
+    assert!(text.is_rotated());
+    text.validate().expect("rotated title is valid");
+}
+
+#[test]
+fn text_primitive_rejects_blank_font_family() {
+    let err = label()
+        .with_font_family("  ")
+        .validate()
+        .expect_err("blank family must fail");
+    assert!(matches!(err, ChartError::InvalidData(_)));
+}
+
+#[test]
+fn render_frame_rejects_non_finite_text_rotation() {
+    let frame =
+        RenderFrame::new(Viewport::new(200, 100)).with_text(label().with_rotation(f64::NAN));
+    let err = frame.validate().expect_err("nan rotation must fail");
+    assert!(matches!(err, ChartError::InvalidData(_)));
+}
+
+#[test]
+fn linear_gradient_interpolates_between_stops_with_clamping() {
+    let gradient = LinearGradient::vertical(
+        Color::rgba(0.0, 0.2, 1.0, 0.4),
+        Color::rgba(1.0, 0.2, 0.0, 0.0),
+    );
+    assert_eq!(gradient.direction, GradientDirection::Vertical);
+    assert_eq!(gradient.color_at(-1.0), gradient.start_color);
+    assert_eq!(gradient.color_at(2.0), gradient.end_color);
+
+    let mid = gradient.color_at(0.5);
+    assert!((mid.red - 0.5).abs() <= 1e-12);
+    assert!((mid.green - 0.2).abs() <= 1e-12);
+    assert!((mid.alpha - 0.2).abs() <= 1e-12);
+}
+
+#[test]
+fn rect_gradient_fill_is_validated_by_frame_contract() {
+    let valid =
+        RectPrimitive::new(0.0, 0.0, 50.0, 20.0, Color::rgb(1.0, 1.0, 1.0)).with_fill_gradient(
+            LinearGradient::horizontal(Color::rgb(0.0, 0.0, 0.0), Color::rgb(1.0, 1.0, 1.0)),
+        );
+    RenderFrame::new(Viewport::new(200, 100))
+        .with_rect(valid)
+        .validate()
+        .expect("valid gradient rect");
+
+    let invalid = valid.with_fill_gradient(LinearGradient::vertical(
+        Color::rgba(0.0, 0.0, 0.0, 1.5),
+        Color::rgb(1.0, 1.0, 1.0),
+    ));
+    let err = RenderFrame::new(Viewport::new(200, 100))
+        .with_rect(invalid)
+        .validate()
+        .expect_err("out-of-range gradient stop must fail");
+    assert!(matches!(err, ChartError::InvalidData(_)));
+}
+
+#[test]
+fn linear_gradient_serialization_is_deterministic() {
+    let gradient =
+        LinearGradient::vertical(Color::rgb(0.1, 0.2, 0.3), Color::rgba(0.1, 0.2, 0.3, 0.0));
+    let first = serde_json::to_string(&gradient).expect("serialize");
+    let second = serde_json::to_string(&gradient).expect("serialize");
+    assert_eq!(first, second);
+
+    let decoded: LinearGradient = serde_json::from_str(&first).expect("deserialize");
+    assert_eq!(decoded, gradient);
+}
+
+#[test]
+fn area_geometry_fill_polygon_converts_into_valid_closed_path() {
+    let viewport = Viewport::new(400, 200);
+    let geometry = project_area_geometry(
+        &[
+            DataPoint::new(0.0, 10.0),
+            DataPoint::new(5.0, 30.0),
+            DataPoint::new(10.0, 20.0),
+        ],
+        TimeScale::new(0.0, 10.0).expect("time scale"),
+        PriceScale::new(0.0, 40.0).expect("price scale"),
+        viewport,
+    )
+    .expect("area geometry");
+
+    let [fill, line] = PathPrimitive::from_area_geometry(
+        &geometry,
+        2.0,
+        Color::rgb(0.1, 0.4, 0.9),
+        Color::rgba(0.1, 0.4, 0.9, 0.3),
+    );
+    let fill = fill.with_fill_gradient(LinearGradient::vertical(
+        Color::rgba(0.1, 0.4, 0.9, 0.4),
+        Color::rgba(0.1, 0.4, 0.9, 0.0),
+    ));
+    assert_eq!(fill.points.len(), geometry.fill_polygon.len());
+    assert_eq!(
+        line.points[1],
+        PathPoint::new(geometry.line_points[1].x, geometry.line_points[1].y)
+    );
+
+    assert!(fill.closed && fill.is_filled() && !fill.is_stroked());
+    assert!(!line.closed && line.is_stroked() && !line.is_filled());
+    let (_, _, _, height) = fill.bounds().expect("bounds");
+    assert!(height > 0.0);
+
+    let frame = RenderFrame::new(viewport).with_path(fill).with_path(line);
+    frame.validate().expect("area paths are valid");
+    assert!(!frame.is_empty());
+}
+
+#[test]
+fn baseline_geometry_polygons_convert_into_paths() {
+    let viewport = Viewport::new(400, 200);
+    let geometry = project_baseline_geometry(
+        &[
+            DataPoint::new(0.0, 10.0),
+            DataPoint::new(5.0, 30.0),
+            DataPoint::new(10.0, 5.0),
+        ],
+        TimeScale::new(0.0, 10.0).expect("time scale"),
+        PriceScale::new(0.0, 40.0).expect("price scale"),
+        viewport,
+        20.0,
+    )
+    .expect("baseline geometry");
+
+    let above = Color::rgba(0.2, 0.7, 0.3, 0.25);
+    let below = Color::rgba(0.9, 0.3, 0.3, 0.25);
+    let [above_fill, below_fill, line] = PathPrimitive::from_baseline_geometry(
+        &geometry,
+        2.0,
+        Color::rgb(0.1, 0.4, 0.9),
+        above,
+        below,
+    );
+    assert_eq!(above_fill.fill_color, Some(above));
+    assert_eq!(below_fill.fill_color, Some(below));
+    assert_eq!(line.points.len(), geometry.line_points.len());
+    for path in [above_fill, below_fill] {
+        path.with_fill_rule(PathFillRule::EvenOdd)
+            .validate()
+            .expect("baseline path is valid");
+    }
+    line.validate().expect("baseline line is valid");
+}
+
+#[test]
+fn path_validation_rejects_open_fills_and_invisible_paths() {
+    let points = vec![PathPoint::new(0.0, 0.0), PathPoint::new(10.0, 10.0)];
+    let mut open_fill = PathPrimitive::filled_polygon(points.clone(), Color::rgb(0.0, 0.0, 0.0));
+    open_fill.closed = false;
+    assert!(matches!(
+        open_fill.validate(),
+        Err(ChartError::InvalidData(_))
+    ));
+
+    let invisible = PathPrimitive::polyline(points, 0.0, Color::rgb(0.0, 0.0, 0.0));
+    assert!(matches!(
+        invisible.validate(),
+        Err(ChartError::InvalidData(_))
+    ));
+
+    let non_finite = PathPrimitive::polyline(
+        vec![PathPoint::new(0.0, f64::INFINITY), PathPoint::new(1.0, 1.0)],
+        1.0,
+        Color::rgb(0.0, 0.0, 0.0),
+    );
+    assert!(matches!(
+        non_finite.validate(),
+        Err(ChartError::InvalidData(_))
+    ));
+}
+
+#[test]
+fn arrow_primitive_lowers_to_a_closed_triangle() {
+    let color = Color::rgb(0.2, 0.4, 0.6);
+    let up = ArrowPrimitive::new(50.0, 10.0, ArrowDirection::Up, 6.0, 10.0, color);
+    assert_eq!(
+        up.points(),
+        [
+            PathPoint::new(50.0, 10.0),
+            PathPoint::new(55.0, 16.0),
+            PathPoint::new(45.0, 16.0),
+        ]
+    );
+    let right = ArrowPrimitive::new(50.0, 10.0, ArrowDirection::Right, 6.0, 10.0, color);
+    assert_eq!(right.points()[1], PathPoint::new(44.0, 15.0));
+    assert_eq!(right.points()[2], PathPoint::new(44.0, 5.0));
+
+    let clip = ClipRect::new(0.0, 0.0, 100.0, 100.0);
+    let path = up.with_clip_rect(clip).to_path();
+    assert!(path.closed);
+    assert_eq!(path.fill_color, Some(color));
+    assert_eq!(path.clip_rect, Some(clip));
+    assert!(path.validate().is_ok());
+
+    let flat = ArrowPrimitive::new(0.0, 0.0, ArrowDirection::Down, 0.0, 10.0, color);
+    assert!(matches!(flat.validate(), Err(ChartError::InvalidData(_))));
+}
+
+#[test]
+fn layered_frame_routes_and_remaps_paths_in_plot_layers() {
+    let pane_id = chart_rs::core::PaneId::new(0);
+    let mut layered = LayeredRenderFrame::from_stacks(
+        Viewport::new(100, 100),
+        vec![PaneLayerStack::canonical_for_pane(pane_id)],
+    )
+    .with_pane_regions(&[(pane_id, 50.0, 100.0)]);
+    layered.push_path(
+        pane_id,
+        CanvasLayerKind::Series,
+        PathPrimitive::polyline(
+            vec![PathPoint::new(0.0, 0.0), PathPoint::new(10.0, 100.0)],
+            1.0,
+            Color::rgb(0.0, 0.0, 0.0),
+        ),
+    );
+    layered.remap_plot_layers_to_pane_region(pane_id, 0.0, 100.0);
+
+    let flattened = layered.flatten();
+    assert_eq!(flattened.paths.len(), 1);
+    assert_eq!(flattened.paths[0].points[0].y, 50.0);
+    assert_eq!(flattened.paths[0].points[1].y, 100.0);
+}
+
+#[test]
+fn clip_rect_validation_is_part_of_primitive_contract() {
+    let clip = ClipRect::new(10.0, 20.0, 100.0, 50.0);
+    assert!(clip.contains(10.0, 70.0));
+    assert!(!clip.contains(111.0, 30.0));
+    assert!(ClipRect::new(0.0, 0.0, 0.0, 10.0).is_empty());
+
+    let frame = RenderFrame::new(Viewport::new(200, 100)).with_text(label().with_clip_rect(clip));
+    frame.validate().expect("valid clip");
+
+    let frame = RenderFrame::new(Viewport::new(200, 100))
+        .with_text(label().with_clip_rect(ClipRect::new(0.0, 0.0, -1.0, 10.0)));
+    assert!(matches!(frame.validate(), Err(ChartError::InvalidData(_))));
+}
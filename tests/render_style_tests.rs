@@ -1,8 +1,11 @@
 use chart_rs::ChartError;
 use chart_rs::api::{
-    AxisLabelLocale, ChartEngine, ChartEngineConfig, CrosshairLabelBoxWidthMode,
-    LastPriceLabelBoxWidthMode, LastPriceSourceMode, RenderStyle, TimeAxisLabelConfig,
-    TimeAxisLabelPolicy, TimeAxisSessionConfig, TimeAxisTimeZone,
+    AxisBackgroundShadow, AxisLabelLocale, BackgroundBandMode, ChartEngine, ChartEngineConfig,
+    CrosshairLabelBoxWidthMode, CrosshairSnapMarkerStyle, CrosshairTimeLabelMode, GridLineOptions,
+    GridOptions, LabelOverflowPolicy, LabelTextMetrics, LastPriceLabelBoxWidthMode,
+    LastPriceOffscreenPolicy, LastPriceSourceMode, RelativeTimeAnchor, RenderStyle,
+    TimeAxisLabelConfig, TimeAxisLabelPolicy, TimeAxisSessionConfig, TimeAxisTimeUnit,
+    TimeAxisTimeZone,
 };
 use chart_rs::core::Viewport;
 use chart_rs::render::{Color, LineStrokeStyle, NullRenderer, TextHAlign};
@@ -20,7 +23,7 @@ fn default_render_style_produces_grid_and_axis_lines() {
     let grid_lines = frame
         .lines
         .iter()
-        .filter(|line| line.color == style.grid_line_color)
+        .filter(|line| line.color == style.grid.vert_lines.color)
         .count();
     let axis_lines = frame
         .lines
@@ -41,9 +44,16 @@ fn custom_render_style_is_applied_to_frame() {
 
     let custom_style = RenderStyle {
         series_line_color: Color::rgb(0.9, 0.2, 0.2),
-        grid_line_color: Color::rgb(0.1, 0.7, 0.4),
-        price_axis_grid_line_color: Color::rgb(0.12, 0.55, 0.81),
-        major_grid_line_color: Color::rgb(0.8, 0.4, 0.1),
+        grid: GridOptions {
+            vert_lines: GridLineOptions::new(Color::rgb(0.1, 0.7, 0.4), 2.0),
+            major_vert_lines: GridLineOptions::new(Color::rgb(0.8, 0.4, 0.1), 3.0),
+            horz_lines: GridLineOptions::new(Color::rgb(0.12, 0.55, 0.81), 1.75)
+                .with_style(LineStrokeStyle::Dotted),
+            minor_horz_lines: GridLineOptions::new(Color::rgb(0.9, 0.91, 0.93), 0.5),
+            minor_horz_subdivisions: 2,
+            minor_vert_lines: GridLineOptions::new(Color::rgb(0.91, 0.92, 0.94), 0.5),
+            minor_vert_subdivisions: 4,
+        },
         axis_border_color: Color::rgb(0.2, 0.2, 0.2),
         price_axis_tick_mark_color: Color::rgb(0.7, 0.2, 0.5),
         time_axis_tick_mark_color: Color::rgb(0.2, 0.6, 0.85),
@@ -56,12 +66,12 @@ fn custom_render_style_is_applied_to_frame() {
         crosshair_vertical_line_color: Some(Color::rgb(0.20, 0.42, 0.88)),
         crosshair_time_label_color: Color::rgb(0.90, 0.28, 0.17),
         crosshair_price_label_color: Color::rgb(0.19, 0.42, 0.88),
-        crosshair_label_prefix: "S:",
-        crosshair_label_suffix: ":Z",
-        crosshair_time_label_prefix: Some("T:"),
-        crosshair_time_label_suffix: Some(":TT"),
-        crosshair_price_label_prefix: Some("P:"),
-        crosshair_price_label_suffix: Some(":PP"),
+        crosshair_label_prefix: "S:".into(),
+        crosshair_label_suffix: ":Z".into(),
+        crosshair_time_label_prefix: Some("T:".into()),
+        crosshair_time_label_suffix: Some(":TT".into()),
+        crosshair_price_label_prefix: Some("P:".into()),
+        crosshair_price_label_suffix: Some(":PP".into()),
         crosshair_label_numeric_precision: Some(2),
         crosshair_time_label_numeric_precision: Some(3),
         crosshair_price_label_numeric_precision: Some(4),
@@ -92,9 +102,6 @@ fn custom_render_style_is_applied_to_frame() {
         candlestick_border_up_color: Color::rgb(0.06, 0.45, 0.39),
         candlestick_border_down_color: Color::rgb(0.58, 0.12, 0.10),
         candlestick_body_mode: chart_rs::api::CandlestickBodyMode::Solid,
-        grid_line_width: 2.0,
-        price_axis_grid_line_width: 1.75,
-        major_grid_line_width: 3.0,
         axis_line_width: 1.5,
         price_axis_tick_mark_width: 1.25,
         time_axis_tick_mark_width: 2.25,
@@ -106,9 +113,11 @@ fn custom_render_style_is_applied_to_frame() {
         candlestick_border_width_px: 1.4,
         show_candlestick_wicks: true,
         show_candlestick_borders: true,
+        candlestick_detail_tiers: Some(chart_rs::api::CandlestickDetailTiers::default()),
         crosshair_line_style: LineStrokeStyle::Dashed,
         crosshair_horizontal_line_style: Some(LineStrokeStyle::Dotted),
         crosshair_vertical_line_style: Some(LineStrokeStyle::Solid),
+        crosshair_snap_marker: Some(CrosshairSnapMarkerStyle::default()),
         crosshair_time_label_font_size_px: 12.0,
         crosshair_price_label_font_size_px: 12.0,
         crosshair_axis_label_font_size_px: 12.0,
@@ -181,6 +190,7 @@ fn custom_render_style_is_applied_to_frame() {
         major_time_label_font_size_px: 13.0,
         time_axis_label_font_size_px: 11.5,
         time_axis_label_offset_y_px: 5.0,
+        time_axis_label_rotation_degrees: 0.0,
         crosshair_time_label_offset_y_px: 6.0,
         major_time_label_offset_y_px: 7.0,
         time_axis_tick_mark_length_px: 7.0,
@@ -192,20 +202,21 @@ fn custom_render_style_is_applied_to_frame() {
         last_price_label_offset_y_px: 8.0,
         last_price_label_padding_right_px: 7.0,
         price_axis_width_px: 84.0,
+        price_axis_width_mode: chart_rs::api::PriceAxisWidthMode::Fixed,
         time_axis_height_px: 28.0,
         show_price_axis_tick_marks: true,
-        show_price_axis_grid_lines: true,
         show_price_axis_labels: true,
         show_price_axis_border: true,
         show_time_axis_labels: true,
         show_time_axis_border: true,
         show_major_time_labels: true,
-        show_major_time_grid_lines: true,
+        show_time_axis_sticky_date_label: true,
         show_time_axis_tick_marks: true,
         show_major_time_tick_marks: true,
         show_crosshair_horizontal_line: true,
         show_crosshair_vertical_line: true,
         show_crosshair_lines: true,
+        crosshair_lines_extend_into_axis_panels: false,
         show_crosshair_time_label: true,
         show_crosshair_price_label: true,
         show_crosshair_time_label_box: true,
@@ -220,6 +231,7 @@ fn custom_render_style_is_applied_to_frame() {
         show_last_price_label: true,
         last_price_use_trend_color: true,
         last_price_source_mode: LastPriceSourceMode::LatestData,
+        last_price_offscreen_policy: LastPriceOffscreenPolicy::Draw,
         show_last_price_label_box: true,
         last_price_label_box_use_marker_color: false,
         last_price_label_box_color: Color::rgb(0.1, 0.1, 0.1),
@@ -233,9 +245,28 @@ fn custom_render_style_is_applied_to_frame() {
         last_price_label_box_border_color: Color::rgb(0.85, 0.85, 0.85),
         last_price_label_box_corner_radius_px: 4.0,
         last_price_label_exclusion_px: 24.0,
+        point_series_last_value: None,
+        candle_series_last_value: None,
+        price_axis_label_collision_priority:
+            chart_rs::api::PriceAxisLabelCollisionPriority::PreferLastValue,
+        background_color: Some(Color::rgb(0.04, 0.05, 0.07)),
+        plot_background_color: Some(Color::rgb(0.07, 0.08, 0.1)),
+        background_band_mode: BackgroundBandMode::Weekends,
+        background_band_color: Color::rgba(0.5, 0.5, 0.6, 0.12),
+        price_axis_background_color: Some(Color::rgb(0.1, 0.11, 0.13)),
+        time_axis_background_color: Some(Color::rgb(0.1, 0.11, 0.13)),
+        axis_background_shadow: Some(AxisBackgroundShadow {
+            color: Color::rgba(0.0, 0.0, 0.0, 0.4),
+            width_px: 2.0,
+        }),
+        label_text_metrics: LabelTextMetrics::Estimated,
+        label_overflow_policy: LabelOverflowPolicy::TruncateEnd,
+        price_label_prefix: "$".into(),
+        price_label_suffix: " USD".into(),
+        crosshair_time_label_mode: CrosshairTimeLabelMode::Relative(RelativeTimeAnchor::Now),
     };
     engine
-        .set_render_style(custom_style)
+        .set_render_style(custom_style.clone())
         .expect("set render style");
     engine
         .set_time_axis_label_config(TimeAxisLabelConfig {
@@ -249,10 +280,9 @@ fn custom_render_style_is_applied_to_frame() {
 
     let frame = engine.build_render_frame().expect("frame");
     assert!(
-        frame
-            .lines
-            .iter()
-            .any(|line| line.color == custom_style.grid_line_color && line.stroke_width == 2.0)
+        frame.lines.iter().any(
+            |line| line.color == custom_style.grid.vert_lines.color && line.stroke_width == 2.0
+        )
     );
     assert!(
         frame
@@ -260,14 +290,13 @@ fn custom_render_style_is_applied_to_frame() {
             .iter()
             .any(|line| line.color == custom_style.axis_border_color && line.stroke_width == 1.5)
     );
-    assert!(
-        frame.lines.iter().any(
-            |line| line.color == custom_style.major_grid_line_color && line.stroke_width == 3.0
-        )
-    );
+    assert!(frame.lines.iter().any(
+        |line| line.color == custom_style.grid.major_vert_lines.color && line.stroke_width == 3.0
+    ));
     assert!(frame.lines.iter().any(|line| {
-        line.color == custom_style.price_axis_grid_line_color
-            && line.stroke_width == custom_style.price_axis_grid_line_width
+        line.color == custom_style.grid.horz_lines.color
+            && line.stroke_width == custom_style.grid.horz_lines.width
+            && line.stroke_style == LineStrokeStyle::Dotted
     }));
     assert!(frame.lines.iter().any(|line| {
         line.color == custom_style.price_axis_tick_mark_color
@@ -310,7 +339,7 @@ fn invalid_render_style_is_rejected() {
     let mut engine = ChartEngine::new(renderer, config).expect("engine init");
 
     let mut style = engine.render_style();
-    style.grid_line_width = 0.0;
+    style.grid.vert_lines.width = 0.0;
 
     let err = engine
         .set_render_style(style)
@@ -470,7 +499,7 @@ fn invalid_price_axis_grid_line_color_is_rejected() {
     let mut engine = ChartEngine::new(renderer, config).expect("engine init");
 
     let mut style = engine.render_style();
-    style.price_axis_grid_line_color = Color::rgb(1.2, 0.2, 0.2);
+    style.grid.horz_lines.color = Color::rgb(1.2, 0.2, 0.2);
 
     let err = engine
         .set_render_style(style)
@@ -486,7 +515,7 @@ fn invalid_price_axis_grid_line_width_is_rejected() {
     let mut engine = ChartEngine::new(renderer, config).expect("engine init");
 
     let mut style = engine.render_style();
-    style.price_axis_grid_line_width = 0.0;
+    style.grid.horz_lines.width = 0.0;
 
     let err = engine
         .set_render_style(style)
@@ -1638,8 +1667,14 @@ fn session_boundary_uses_major_tick_styling() {
     let mut engine = ChartEngine::new(renderer, config).expect("engine init");
 
     let custom_style = RenderStyle {
-        major_grid_line_color: Color::rgb(0.75, 0.35, 0.12),
-        major_grid_line_width: 2.5,
+        grid: GridOptions {
+            major_vert_lines: GridLineOptions {
+                color: Color::rgb(0.75, 0.35, 0.12),
+                width: 2.5,
+                ..engine.render_style().grid.major_vert_lines
+            },
+            ..engine.render_style().grid
+        },
         major_time_tick_mark_color: Color::rgb(0.89, 0.31, 0.19),
         major_time_tick_mark_width: 2.25,
         major_time_tick_mark_length_px: 8.5,
@@ -1652,7 +1687,7 @@ fn session_boundary_uses_major_tick_styling() {
         ..engine.render_style()
     };
     engine
-        .set_render_style(custom_style)
+        .set_render_style(custom_style.clone())
         .expect("set custom render style");
     engine
         .set_time_axis_label_config(TimeAxisLabelConfig {
@@ -1667,17 +1702,16 @@ fn session_boundary_uses_major_tick_styling() {
                 end_hour: 16,
                 end_minute: 0,
             }),
+            time_unit: TimeAxisTimeUnit::Seconds,
+            anchor_ticks_to_nice_steps: false,
         })
         .expect("set session/time-axis config");
 
     let frame = engine.build_render_frame().expect("frame");
-    assert!(
-        frame
-            .lines
-            .iter()
-            .any(|line| line.color == custom_style.major_grid_line_color
-                && line.stroke_width == custom_style.major_grid_line_width)
-    );
+    assert!(frame.lines.iter().any(
+        |line| line.color == custom_style.grid.major_vert_lines.color
+            && line.stroke_width == custom_style.grid.major_vert_lines.width
+    ));
     let major_tick_candidates: Vec<(f64, f64)> = frame
         .lines
         .iter()
@@ -1705,7 +1739,7 @@ fn session_boundary_uses_major_tick_styling() {
         "missing major session tick at expected y; plot_bottom={plot_bottom}, expected_end={expected_major_tick_end_y}, candidates={major_tick_candidates:?}"
     );
     assert!(frame.texts.iter().any(|text| {
-        text.text == "2024-01-02 09:30"
+        &*text.text == "2024-01-02 09:30"
             && text.font_size_px == custom_style.major_time_label_font_size_px
             && (text.y
                 - (plot_bottom + custom_style.major_time_label_offset_y_px)
@@ -1716,7 +1750,7 @@ fn session_boundary_uses_major_tick_styling() {
     }));
     assert!(frame.texts.iter().any(|text| {
         text.h_align == chart_rs::render::TextHAlign::Center
-            && text.text != "2024-01-02 09:30"
+            && &*text.text != "2024-01-02 09:30"
             && (text.y
                 - (plot_bottom + custom_style.time_axis_label_offset_y_px)
                     .min((viewport_height - text.font_size_px).max(0.0)))
@@ -1753,6 +1787,8 @@ fn major_time_labels_visibility_toggle_is_applied() {
                 end_hour: 16,
                 end_minute: 0,
             }),
+            time_unit: TimeAxisTimeUnit::Seconds,
+            anchor_ticks_to_nice_steps: false,
         })
         .expect("set session/time-axis config");
 
@@ -1770,7 +1806,7 @@ fn major_time_labels_visibility_toggle_is_applied() {
     assert!(
         !center_labels
             .iter()
-            .any(|text| text.text == "2024-01-02 09:30")
+            .any(|text| &*text.text == "2024-01-02 09:30")
     );
 }
 
@@ -1782,13 +1818,19 @@ fn major_time_grid_lines_visibility_toggle_is_applied() {
     let mut engine = ChartEngine::new(renderer, config).expect("engine init");
 
     let custom_style = RenderStyle {
-        show_major_time_grid_lines: false,
-        major_grid_line_color: Color::rgb(0.86, 0.31, 0.22),
-        major_grid_line_width: 2.5,
+        grid: GridOptions {
+            major_vert_lines: GridLineOptions {
+                visible: false,
+                color: Color::rgb(0.86, 0.31, 0.22),
+                width: 2.5,
+                ..engine.render_style().grid.major_vert_lines
+            },
+            ..engine.render_style().grid
+        },
         ..engine.render_style()
     };
     engine
-        .set_render_style(custom_style)
+        .set_render_style(custom_style.clone())
         .expect("set custom render style");
     engine
         .set_time_axis_label_config(TimeAxisLabelConfig {
@@ -1803,6 +1845,8 @@ fn major_time_grid_lines_visibility_toggle_is_applied() {
                 end_hour: 16,
                 end_minute: 0,
             }),
+            time_unit: TimeAxisTimeUnit::Seconds,
+            anchor_ticks_to_nice_steps: false,
         })
         .expect("set session/time-axis config");
 
@@ -1811,15 +1855,15 @@ fn major_time_grid_lines_visibility_toggle_is_applied() {
     let plot_bottom =
         (viewport_height - custom_style.time_axis_height_px).clamp(0.0, viewport_height);
     assert!(!frame.lines.iter().any(|line| {
-        line.color == custom_style.major_grid_line_color
-            && line.stroke_width == custom_style.major_grid_line_width
+        line.color == custom_style.grid.major_vert_lines.color
+            && line.stroke_width == custom_style.grid.major_vert_lines.width
             && (line.x1 - line.x2).abs() <= 1e-9
             && (line.y1 - 0.0).abs() <= 1e-9
             && (line.y2 - plot_bottom).abs() <= 1e-9
     }));
     assert!(frame.lines.iter().any(|line| {
-        line.color == custom_style.grid_line_color
-            && line.stroke_width == custom_style.grid_line_width
+        line.color == custom_style.grid.vert_lines.color
+            && line.stroke_width == custom_style.grid.vert_lines.width
             && (line.x1 - line.x2).abs() <= 1e-9
             && (line.y1 - 0.0).abs() <= 1e-9
             && (line.y2 - plot_bottom).abs() <= 1e-9
@@ -1841,7 +1885,7 @@ fn major_time_tick_marks_visibility_toggle_is_applied() {
         ..engine.render_style()
     };
     engine
-        .set_render_style(custom_style)
+        .set_render_style(custom_style.clone())
         .expect("set custom render style");
     engine
         .set_time_axis_label_config(TimeAxisLabelConfig {
@@ -1856,6 +1900,8 @@ fn major_time_tick_marks_visibility_toggle_is_applied() {
                 end_hour: 16,
                 end_minute: 0,
             }),
+            time_unit: TimeAxisTimeUnit::Seconds,
+            anchor_ticks_to_nice_steps: false,
         })
         .expect("set session/time-axis config");
 
@@ -1894,7 +1940,7 @@ fn time_axis_border_visibility_toggle_is_applied() {
         ..engine.render_style()
     };
     engine
-        .set_render_style(custom_style)
+        .set_render_style(custom_style.clone())
         .expect("set custom render style");
 
     let frame = engine.build_render_frame().expect("frame");
@@ -1933,7 +1979,7 @@ fn price_axis_border_visibility_toggle_is_applied() {
         ..engine.render_style()
     };
     engine
-        .set_render_style(custom_style)
+        .set_render_style(custom_style.clone())
         .expect("set custom render style");
 
     let frame = engine.build_render_frame().expect("frame");
@@ -1995,7 +2041,7 @@ fn time_axis_tick_marks_visibility_toggle_is_applied() {
         ..engine.render_style()
     };
     engine
-        .set_render_style(custom_style)
+        .set_render_style(custom_style.clone())
         .expect("set custom render style");
 
     let frame = engine.build_render_frame().expect("frame");
@@ -2010,3 +2056,64 @@ fn time_axis_tick_marks_visibility_toggle_is_applied() {
             && line.y2 > line.y1
     }));
 }
+
+#[test]
+fn grid_directions_toggle_and_style_independently() {
+    let renderer = NullRenderer::default();
+    let config =
+        ChartEngineConfig::new(Viewport::new(900, 420), 0.0, 100.0).with_price_domain(0.0, 50.0);
+    let mut engine = ChartEngine::new(renderer, config).expect("engine init");
+    let defaults = GridOptions::default();
+    let vert_color = Color::rgb(0.3, 0.6, 0.2);
+    let horz_color = Color::rgb(0.7, 0.2, 0.6);
+
+    engine
+        .set_render_style(RenderStyle {
+            grid: GridOptions {
+                vert_lines: GridLineOptions::new(vert_color, 1.0)
+                    .with_style(LineStrokeStyle::Dashed),
+                horz_lines: GridLineOptions::new(horz_color, 1.0).with_visible(false),
+                ..defaults
+            },
+            ..engine.render_style()
+        })
+        .expect("set grid style");
+    let frame = engine.build_render_frame().expect("frame");
+    assert!(
+        frame
+            .lines
+            .iter()
+            .any(|line| line.color == vert_color && line.stroke_style == LineStrokeStyle::Dashed)
+    );
+    assert!(!frame.lines.iter().any(|line| line.color == horz_color));
+
+    engine
+        .set_render_style(RenderStyle {
+            grid: GridOptions {
+                vert_lines: GridLineOptions::new(vert_color, 1.0).with_visible(false),
+                major_vert_lines: GridLineOptions::new(vert_color, 2.0),
+                horz_lines: GridLineOptions::new(horz_color, 1.0),
+                minor_horz_lines: defaults.minor_horz_lines,
+                ..defaults
+            },
+            ..engine.render_style()
+        })
+        .expect("set grid style");
+    let frame = engine.build_render_frame().expect("frame");
+    assert!(!frame.lines.iter().any(|line| line.color == vert_color));
+    assert!(frame.lines.iter().any(|line| line.color == horz_color));
+}
+
+#[test]
+fn invalid_grid_line_width_is_rejected() {
+    let renderer = NullRenderer::default();
+    let config = ChartEngineConfig::new(Viewport::new(900, 420), 0.0, 100.0);
+    let mut engine = ChartEngine::new(renderer, config).expect("engine init");
+    let mut style = engine.render_style();
+    style.grid.horz_lines.width = f64::NAN;
+
+    let err = engine
+        .set_render_style(style)
+        .expect_err("nan grid width must fail");
+    assert!(matches!(err, ChartError::InvalidData(_)));
+}
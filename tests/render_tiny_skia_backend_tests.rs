@@ -0,0 +1,165 @@
+#![cfg(feature = "tiny-skia-backend")]
+
+use chart_rs::ChartError;
+use chart_rs::api::{ChartEngine, ChartEngineConfig};
+use chart_rs::core::{DataPoint, Viewport};
+use chart_rs::render::{
+    Color, RenderFrame, Renderer, TextHAlign, TextPrimitive, TextVAlign, TinySkiaRenderer,
+};
+
+/// Bundled so text assertions never depend on the host's fonts.
+const TEST_FONT: &[u8] = include_bytes!("fixtures/fonts/DejaVuSans.ttf");
+
+fn renderer_with_font(width: u32, height: u32) -> TinySkiaRenderer {
+    let mut renderer = TinySkiaRenderer::new(width, height).expect("renderer");
+    renderer.set_font_data(TEST_FONT.to_vec()).expect("font");
+    assert!(renderer.has_font());
+    renderer
+}
+
+fn ink_columns(renderer: &TinySkiaRenderer) -> Option<(u32, u32)> {
+    let pixmap = renderer.pixmap();
+    let columns: Vec<u32> = (0..pixmap.width())
+        .filter(|&x| {
+            (0..pixmap.height()).any(|y| pixmap.pixel(x, y).is_some_and(|pixel| pixel.red() < 128))
+        })
+        .collect();
+    Some((*columns.first()?, *columns.last()?))
+}
+
+fn engine(width: u32, height: u32) -> ChartEngine<TinySkiaRenderer> {
+    let renderer = renderer_with_font(width, height);
+    let config = ChartEngineConfig::new(Viewport::new(width, height), 0.0, 100.0)
+        .with_price_domain(0.0, 50.0);
+    let mut engine = ChartEngine::new(renderer, config).expect("engine init");
+    engine.set_data(vec![
+        DataPoint::new(10.0, 10.0),
+        DataPoint::new(20.0, 20.0),
+        DataPoint::new(40.0, 15.0),
+    ]);
+    engine
+}
+
+#[test]
+fn tiny_skia_renderer_rejects_invalid_pixmap_size() {
+    let err = TinySkiaRenderer::new(0, 480).expect_err("invalid width must fail");
+    assert!(matches!(err, ChartError::InvalidData(_)));
+}
+
+#[test]
+fn tiny_skia_renderer_draws_frame_geometry() {
+    let mut engine = engine(900, 500);
+    let frame = engine.build_render_frame().expect("build frame");
+
+    engine.render().expect("render");
+    let renderer = engine.into_renderer();
+    let stats = renderer.last_stats();
+
+    assert_eq!(stats.lines_drawn, frame.lines.len());
+    assert_eq!(stats.rects_drawn, frame.rects.len());
+    assert_eq!(stats.paths_drawn, frame.paths.len());
+    assert!(
+        renderer
+            .pixmap()
+            .pixels()
+            .iter()
+            .any(|pixel| pixel.red() < 200)
+    );
+}
+
+#[test]
+fn tiny_skia_renderer_follows_viewport_and_exports_xrgb() {
+    let mut engine = engine(300, 200);
+    engine
+        .set_viewport(Viewport::new(320, 180))
+        .expect("viewport");
+    engine.render().expect("render");
+
+    let renderer = engine.into_renderer();
+    assert_eq!(
+        (renderer.pixmap().width(), renderer.pixmap().height()),
+        (320, 180)
+    );
+    assert!(renderer.write_xrgb8888(&mut [0; 10]).is_err());
+
+    let mut pixels = vec![0; 320 * 180];
+    renderer.write_xrgb8888(&mut pixels).expect("copy");
+    let pixel = renderer.pixmap().pixel(5, 7).expect("pixel");
+    let expected =
+        (u32::from(pixel.red()) << 16) | (u32::from(pixel.green()) << 8) | u32::from(pixel.blue());
+    assert_eq!(pixels[7 * 320 + 5], expected);
+}
+
+#[test]
+fn tiny_skia_renderer_rejects_invalid_font_data() {
+    let mut renderer = TinySkiaRenderer::new(10, 10).expect("renderer");
+    let err = renderer
+        .set_font_data(vec![0, 1, 2, 3])
+        .expect_err("garbage font must fail");
+    assert!(matches!(err, ChartError::InvalidData(_)));
+}
+
+#[test]
+fn tiny_skia_renderer_rasterizes_aligned_text() {
+    let mut renderer = renderer_with_font(200, 40);
+    let text = |h_align| {
+        TextPrimitive::new(
+            "100.25",
+            100.0,
+            20.0,
+            14.0,
+            Color::rgb(0.0, 0.0, 0.0),
+            h_align,
+        )
+        .with_v_align(TextVAlign::Middle)
+    };
+
+    renderer
+        .render(&RenderFrame::new(Viewport::new(200, 40)).with_text(text(TextHAlign::Right)))
+        .expect("render");
+    assert_eq!(renderer.last_stats().texts_drawn, 1);
+    let (_, right_edge) = ink_columns(&renderer).expect("right-aligned ink");
+    assert!(right_edge <= 100);
+
+    renderer
+        .render(&RenderFrame::new(Viewport::new(200, 40)).with_text(text(TextHAlign::Left)))
+        .expect("render");
+    let (left_edge, _) = ink_columns(&renderer).expect("left-aligned ink");
+    assert!(left_edge >= 100);
+}
+
+#[test]
+fn tiny_skia_renderer_draws_every_engine_text_with_a_font() {
+    let renderer = renderer_with_font(600, 400);
+    let config =
+        ChartEngineConfig::new(Viewport::new(600, 400), 0.0, 100.0).with_price_domain(0.0, 50.0);
+    let mut engine = ChartEngine::new(renderer, config).expect("engine init");
+    engine.set_data(vec![DataPoint::new(10.0, 10.0), DataPoint::new(60.0, 30.0)]);
+    let frame = engine.build_render_frame().expect("build frame");
+    assert!(!frame.texts.is_empty());
+
+    engine.render().expect("render");
+    let stats = engine.renderer().last_stats();
+    assert_eq!(stats.texts_drawn, frame.texts.len());
+    assert_eq!(stats.texts_without_font, 0);
+}
+
+#[test]
+fn tiny_skia_png_baselines_are_deterministic() {
+    let mut first = engine(240, 160);
+    first.render().expect("render");
+    let png = first.renderer().encode_png().expect("encode");
+    assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+
+    let mut second = engine(240, 160);
+    second.render().expect("render");
+    assert_eq!(second.renderer().encode_png().expect("encode"), png);
+
+    let decoded = tiny_skia::Pixmap::decode_png(&png).expect("decode");
+    assert_eq!(decoded.data(), first.renderer().pixmap().data());
+
+    let path = std::env::temp_dir().join("chart_rs_tiny_skia_baseline.png");
+    first.renderer().write_png(&path).expect("write");
+    assert_eq!(std::fs::read(&path).expect("read back"), png);
+    let _ = std::fs::remove_file(path);
+}
@@ -0,0 +1,142 @@
+use chart_rs::api::{ChartEngine, ChartEngineConfig, StyledOhlcBar};
+use chart_rs::core::{DataPoint, OhlcBar, Viewport};
+use chart_rs::render::NullRenderer;
+
+fn bar(time: f64, close: f64) -> OhlcBar {
+    OhlcBar::new(time, close, close + 1.0, close - 1.0, close).expect("bar")
+}
+
+/// Ten candles at `0, 10, .., 90` closing at `20 + index`.
+fn engine() -> ChartEngine<NullRenderer> {
+    let config =
+        ChartEngineConfig::new(Viewport::new(800, 400), 0.0, 100.0).with_price_domain(0.0, 50.0);
+    let mut engine = ChartEngine::new(NullRenderer::default(), config).expect("engine");
+    engine.set_candles(
+        (0..10)
+            .map(|i| bar(f64::from(i) * 10.0, 20.0 + f64::from(i)))
+            .collect(),
+    );
+    engine
+}
+
+fn candle_times(engine: &ChartEngine<NullRenderer>) -> Vec<f64> {
+    engine.candles().iter().map(|bar| bar.time).collect()
+}
+
+#[test]
+fn cursor_hides_newer_bars_from_model_and_crosshair() {
+    let mut engine = engine();
+    engine.start_replay(45.0).expect("replay");
+    assert_eq!(engine.replay_cursor(), Some(45.0));
+    assert_eq!(candle_times(&engine), vec![0.0, 10.0, 20.0, 30.0, 40.0]);
+    assert_eq!(engine.replay_remaining_bars(), 5);
+
+    let x = engine.map_x_to_pixel(80.0).expect("x");
+    engine.pointer_move(x, 100.0);
+    let snapped = engine.crosshair_state().snapped_time;
+    assert!(snapped.is_none_or(|time| time <= 45.0), "{snapped:?}");
+
+    // Restarting earlier moves the cursor back; stopping restores all bars.
+    engine.start_replay(15.0).expect("replay");
+    assert_eq!(candle_times(&engine), vec![0.0, 10.0]);
+    engine.stop_replay();
+    assert_eq!(engine.replay_cursor(), None);
+    assert_eq!(engine.candles().len(), 10);
+    assert_eq!(engine.candles()[9], bar(90.0, 29.0));
+}
+
+#[test]
+fn stepping_reveals_one_time_per_bar_across_series() {
+    let mut engine = engine();
+    engine.set_data(vec![
+        DataPoint::new(40.0, 1.0),
+        DataPoint::new(50.0, 2.0),
+        DataPoint::new(55.0, 3.0),
+    ]);
+    engine.start_replay(40.0).expect("replay");
+    assert_eq!(engine.points().len(), 1);
+    // 50 (candle and point), 55 (point only), 60 .. 90 (candles).
+    assert_eq!(engine.replay_remaining_bars(), 6);
+
+    assert_eq!(engine.replay_step_forward(1).expect("step"), 1);
+    assert_eq!(engine.replay_cursor(), Some(50.0));
+    assert_eq!(engine.points().len(), 2);
+    assert_eq!(engine.candles().len(), 6);
+
+    assert_eq!(engine.replay_step_forward(2).expect("step"), 2);
+    assert_eq!(engine.replay_cursor(), Some(60.0));
+    assert_eq!(engine.points().len(), 3);
+    assert_eq!(engine.candles().len(), 7);
+
+    assert_eq!(engine.replay_step_forward(10).expect("step"), 3);
+    assert_eq!(engine.replay_cursor(), Some(90.0));
+    assert_eq!(engine.candles().len(), 10);
+    assert_eq!(engine.replay_step_forward(1).expect("step"), 0);
+
+    engine.stop_replay();
+    assert_eq!(engine.replay_step_forward(1).expect("step"), 0);
+}
+
+#[test]
+fn playback_advances_with_animation_steps_and_pauses_at_the_end() {
+    let mut engine = engine();
+    engine.start_replay(50.0).expect("replay");
+    assert!(!engine.has_active_animation());
+    engine.replay_play(4.0).expect("play");
+    assert!(engine.replay_playing());
+    assert!(engine.has_active_animation());
+
+    let mut revealed_steps = 0;
+    for _ in 0..5 {
+        if engine.step_animations(0.1).expect("step") {
+            revealed_steps += 1;
+        }
+    }
+    // 0.5 s at 4 bars/s.
+    assert_eq!(revealed_steps, 2);
+    assert_eq!(engine.replay_cursor(), Some(70.0));
+
+    engine.replay_pause();
+    engine.step_animations(1.0).expect("step");
+    assert_eq!(engine.replay_cursor(), Some(70.0));
+
+    engine.replay_play(4.0).expect("play");
+    engine.step_animations(1.0).expect("step");
+    assert_eq!(engine.replay_cursor(), Some(90.0));
+    assert!(!engine.replay_playing());
+    assert!(!engine.has_active_animation());
+}
+
+#[test]
+fn data_written_during_replay_stays_masked() {
+    let mut engine = engine();
+    engine.start_replay(45.0).expect("replay");
+
+    engine.append_candle(bar(100.0, 40.0)).expect("append");
+    engine.update_candle(bar(100.0, 41.0)).expect("update");
+    engine
+        .append_styled_candle(StyledOhlcBar::new(bar(110.0, 42.0)))
+        .expect("append");
+    assert!(engine.update_candle(bar(105.0, 41.0)).is_err());
+    assert_eq!(engine.candles().len(), 5);
+    assert_eq!(engine.replay_remaining_bars(), 7);
+
+    // Replacing the series re-applies the cursor.
+    engine.set_candles((0..20).map(|i| bar(f64::from(i) * 5.0, 20.0)).collect());
+    assert_eq!(engine.candles().len(), 10);
+    assert_eq!(engine.replay_remaining_bars(), 10);
+
+    engine.stop_replay();
+    assert_eq!(engine.candles().len(), 20);
+}
+
+#[test]
+fn invalid_replay_arguments_are_rejected() {
+    let mut engine = engine();
+    assert!(engine.start_replay(f64::NAN).is_err());
+    assert!(engine.replay_play(1.0).is_err());
+    engine.start_replay(10.0).expect("replay");
+    assert!(engine.replay_play(0.0).is_err());
+    assert!(engine.replay_play(f64::INFINITY).is_err());
+    assert!(!engine.replay_playing());
+}
@@ -0,0 +1,225 @@
+use chart_rs::api::{
+    CHART_SCENE_JSON_SCHEMA_V2, CandlestickBarStyleOverride, ChartEngine, ChartEngineConfig,
+    ChartSceneV2, CrosshairMode, LayoutInsets, PriceScaleMarginBehavior, RenderStyle,
+    StyledOhlcBar, TimeAxisLabelConfig, TimeAxisLabelPolicy,
+};
+use chart_rs::core::{DataPoint, OhlcBar, PriceScaleMode, Viewport};
+use chart_rs::render::{Color, NullRenderer};
+use std::sync::Arc;
+
+const T0: f64 = 1_704_067_200.0;
+
+fn build_engine(viewport: Viewport, start: f64, end: f64) -> ChartEngine<NullRenderer> {
+    let config = ChartEngineConfig::new(viewport, start, end).with_price_domain(1.0, 200.0);
+    ChartEngine::new(NullRenderer::default(), config).expect("engine")
+}
+
+fn rich_engine() -> ChartEngine<NullRenderer> {
+    let mut engine = build_engine(Viewport::new(960, 540), T0, T0 + 3_600.0);
+    let candles = (0..40)
+        .map(|i| {
+            let close = 50.0 + f64::from(i) * 0.1 + 0.2;
+            let bar = OhlcBar::new(
+                T0 + f64::from(i) * 60.0,
+                close - 0.3,
+                close + 1.0 / 3.0,
+                close - 1.0,
+                close,
+            )
+            .expect("bar");
+            let style_override = (i % 5 == 0).then_some(CandlestickBarStyleOverride {
+                color: Some(Color::rgb(0.9, 0.1, 0.1)),
+                wick_color: None,
+                border_color: None,
+            });
+            StyledOhlcBar {
+                ohlc: bar,
+                style_override,
+            }
+        })
+        .collect();
+    engine.set_styled_candles(candles).expect("candles");
+    engine.set_data(vec![
+        DataPoint::new(T0, 0.1 + 0.2),
+        DataPoint::new(T0 + 600.0, 1e-300 + 48.0),
+    ]);
+    let volume_pane = engine.create_pane(0.3).expect("pane");
+    engine.set_points_pane(volume_pane).expect("points pane");
+    engine.set_series_metadata("symbol", "BTCUSDT");
+
+    let mut style = engine.render_style();
+    style.crosshair_label_prefix = "@ ".into();
+    style.crosshair_price_label_suffix = Some(" USD".into());
+    style.show_last_price_line = true;
+    engine.set_render_style(style).expect("style");
+    engine
+        .set_time_axis_label_config(TimeAxisLabelConfig {
+            policy: TimeAxisLabelPolicy::UtcDateTime { show_seconds: true },
+            ..TimeAxisLabelConfig::default()
+        })
+        .expect("time labels");
+    engine.set_crosshair_mode(CrosshairMode::Normal);
+    engine
+        .set_price_scale_margin_behavior(PriceScaleMarginBehavior {
+            top_margin_ratio: 0.15,
+            bottom_margin_ratio: 0.05,
+        })
+        .expect("margins");
+    engine
+        .set_price_scale_mode(PriceScaleMode::Percentage)
+        .expect("percentage mode");
+    engine
+        .set_time_visible_range(T0 + 123.456, T0 + 1_987.654_321)
+        .expect("range");
+    engine
+}
+
+#[test]
+fn scene_round_trip_reproduces_engine_exactly() {
+    let source = rich_engine();
+    let json = source.export_scene_json_pretty().expect("export");
+
+    let mut target = build_engine(Viewport::new(300, 200), 0.0, 10.0);
+    target.import_scene_json_str(&json).expect("import");
+
+    assert_eq!(target.export_scene(), source.export_scene());
+    assert_eq!(target.export_scene_json_pretty().expect("export"), json);
+    assert_eq!(
+        target.build_render_frame().expect("target frame"),
+        source.build_render_frame().expect("source frame")
+    );
+    assert_eq!(&*target.render_style().crosshair_label_prefix, "@ ");
+    assert_eq!(target.points()[0].y.to_bits(), (0.1_f64 + 0.2).to_bits());
+    assert_eq!(target.panes().len(), 2);
+    assert_eq!(
+        target.series_metadata().get("symbol").map(String::as_str),
+        Some("BTCUSDT")
+    );
+}
+
+#[test]
+fn scene_round_trip_keeps_layout_insets() {
+    let mut source = rich_engine();
+    source
+        .set_layout_insets(LayoutInsets::new(12, 8, 24, 16))
+        .expect("insets");
+    let json = source.export_scene_json_pretty().expect("export");
+
+    let mut target = build_engine(Viewport::new(300, 200), 0.0, 10.0);
+    target.import_scene_json_str(&json).expect("import");
+
+    assert_eq!(target.layout_insets(), LayoutInsets::new(12, 8, 24, 16));
+    assert_eq!(target.viewport(), source.viewport());
+    assert_eq!(target.content_viewport(), source.content_viewport());
+    assert_eq!(
+        target.build_render_frame().expect("target frame"),
+        source.build_render_frame().expect("source frame")
+    );
+
+    let mut payload: serde_json::Value =
+        serde_json::to_value(source.export_scene()).expect("value");
+    let behaviors = payload["behaviors"].as_object_mut().expect("behaviors");
+    assert!(behaviors.remove("layout_insets").is_some());
+    target
+        .import_scene_json_str(&payload.to_string())
+        .expect("older v2 scene imports");
+    assert_eq!(target.layout_insets(), LayoutInsets::default());
+    assert_eq!(target.content_viewport(), source.viewport());
+}
+
+#[test]
+fn repeated_scene_imports_keep_crosshair_affixes_owned_by_the_style() {
+    let json = rich_engine().export_scene_json_pretty().expect("export");
+    let mut target = build_engine(Viewport::new(300, 200), 0.0, 10.0);
+    for _ in 0..3 {
+        target.import_scene_json_str(&json).expect("import");
+    }
+
+    let style = target.render_style();
+    assert_eq!(&*style.crosshair_label_prefix, "@ ");
+    assert_eq!(style.crosshair_price_label_suffix.as_deref(), Some(" USD"));
+    // Only the engine and this clone hold the deserialized affix, so replacing
+    // the style releases it instead of leaking one copy per import.
+    assert_eq!(Arc::strong_count(&style.crosshair_label_prefix), 2);
+}
+
+#[test]
+fn v2_scenes_without_later_style_fields_still_import() {
+    let source = rich_engine();
+    let mut payload: serde_json::Value =
+        serde_json::to_value(source.export_scene()).expect("value");
+    let style = payload["render_style"].as_object_mut().expect("style");
+    for field in [
+        "time_axis_label_rotation_degrees",
+        "price_axis_width_mode",
+        "candlestick_detail_tiers",
+        "crosshair_snap_marker",
+        "last_price_offscreen_policy",
+        "price_axis_background_color",
+        "time_axis_background_color",
+        "axis_background_shadow",
+        "label_text_metrics",
+        "label_overflow_policy",
+        "price_label_prefix",
+        "price_label_suffix",
+        "crosshair_time_label_mode",
+    ] {
+        assert!(style.remove(field).is_some(), "{field} is exported");
+    }
+    let grid = style["grid"].as_object_mut().expect("grid");
+    for field in [
+        "minor_horz_subdivisions",
+        "minor_vert_lines",
+        "minor_vert_subdivisions",
+    ] {
+        assert!(grid.remove(field).is_some(), "{field} is exported");
+    }
+
+    let mut target = build_engine(Viewport::new(300, 200), 0.0, 10.0);
+    target
+        .import_scene_json_str(&payload.to_string())
+        .expect("older v2 scene imports");
+    let style = target.render_style();
+    let defaults = RenderStyle::default();
+    assert_eq!(style.time_axis_label_rotation_degrees, 0.0);
+    assert_eq!(style.price_axis_width_mode, defaults.price_axis_width_mode);
+    assert_eq!(style.grid.minor_vert_lines, defaults.grid.minor_vert_lines);
+    assert_eq!(style.grid.minor_vert_subdivisions, 0);
+    assert_eq!(
+        &*style.crosshair_label_prefix,
+        &*source.render_style().crosshair_label_prefix
+    );
+}
+
+#[test]
+fn scene_json_carries_schema_version() {
+    let scene = rich_engine().export_scene();
+    assert_eq!(scene.schema_version, CHART_SCENE_JSON_SCHEMA_V2);
+
+    let mut payload: serde_json::Value =
+        serde_json::from_str(&scene.to_json_pretty().expect("json")).expect("value");
+    payload["schema_version"] = serde_json::json!(1);
+    assert!(ChartSceneV2::from_json_str(&payload.to_string()).is_err());
+}
+
+#[test]
+fn invalid_scenes_are_rejected_without_side_effects() {
+    let mut target = build_engine(Viewport::new(640, 480), T0, T0 + 60.0);
+    let before = target.export_scene();
+
+    let mut unknown_pane = rich_engine().export_scene();
+    unknown_pane.candles_pane_id = chart_rs::core::PaneId::new(99);
+    assert!(target.import_scene(unknown_pane).is_err());
+
+    let mut payload: serde_json::Value =
+        serde_json::to_value(rich_engine().export_scene()).expect("value");
+    payload["price_scale"]["domain_start"] = serde_json::json!(-5.0);
+    assert!(target.import_scene_json_str(&payload.to_string()).is_err());
+
+    let mut payload: serde_json::Value =
+        serde_json::to_value(rich_engine().export_scene()).expect("value");
+    payload["time_scale"]["visible_end"] = serde_json::json!(0.0);
+    assert!(target.import_scene_json_str(&payload.to_string()).is_err());
+
+    assert_eq!(target.export_scene(), before);
+}
@@ -0,0 +1,163 @@
+use chart_rs::api::{
+    ChartEngine, ChartEngineConfig, CompareSeries, LayoutInsets, SeriesPixelSpace, SeriesRef,
+};
+use chart_rs::core::{DataPoint, PaneId, Viewport};
+use chart_rs::render::{CanvasLayerKind, Color, NullRenderer};
+
+fn engine() -> ChartEngine<NullRenderer> {
+    let config = ChartEngineConfig::new(Viewport::new(800, 600), 0.0, 10.0);
+    let mut engine = ChartEngine::new(NullRenderer::default(), config).expect("engine");
+    engine.set_data(
+        (0..=10)
+            .map(|i| DataPoint::new(f64::from(i), 100.0 + f64::from(i * 3)))
+            .collect(),
+    );
+    engine
+}
+
+/// `y1` of the first series-layer line drawn into `pane_id`.
+fn first_series_line_y(engine: &ChartEngine<NullRenderer>, pane_id: PaneId) -> f64 {
+    let layered = engine.build_layered_render_frame().expect("layered");
+    let pane = layered
+        .panes
+        .iter()
+        .find(|pane| pane.pane_id == pane_id)
+        .expect("pane");
+    pane.layers
+        .iter()
+        .find(|layer| layer.kind == CanvasLayerKind::Series)
+        .and_then(|layer| layer.lines.first())
+        .expect("series line")
+        .y1
+}
+
+fn assert_close(left: f64, right: f64) {
+    assert!((left - right).abs() <= 1e-6, "{left} != {right}");
+}
+
+#[test]
+fn points_conversion_matches_rendered_line_in_main_pane() {
+    let engine = engine();
+    let y = engine
+        .series_price_to_pixel(SeriesRef::Points, 100.0, SeriesPixelSpace::Content)
+        .expect("to pixel");
+    assert_close(y, first_series_line_y(&engine, engine.main_pane_id()));
+    assert_close(
+        engine
+            .series_price_to_pixel(SeriesRef::Points, 100.0, SeriesPixelSpace::Pane)
+            .expect("to pixel"),
+        y,
+    );
+    let price = engine
+        .series_pixel_to_price(SeriesRef::Points, y, SeriesPixelSpace::Content)
+        .expect("to price");
+    assert_close(price, 100.0);
+}
+
+#[test]
+fn pane_and_widget_spaces_follow_the_series_pane() {
+    let mut engine = engine();
+    let pane = engine.create_pane(1.0).expect("pane");
+    engine.set_points_pane(pane).expect("points pane");
+    engine
+        .set_layout_insets(LayoutInsets::new(0, 20, 0, 0))
+        .expect("insets");
+    assert_eq!(engine.series_pane_id(SeriesRef::Points), Some(pane));
+
+    let region = engine
+        .pane_plot_regions_for_current_viewport()
+        .into_iter()
+        .find(|region| region.pane_id == pane)
+        .expect("region");
+    assert!(region.plot_top > 0.0);
+
+    let to_pixel = |space| {
+        engine
+            .series_price_to_pixel(SeriesRef::Points, 100.0, space)
+            .expect("to pixel")
+    };
+    let content = to_pixel(SeriesPixelSpace::Content);
+    assert!(content >= region.plot_top && content <= region.plot_bottom);
+    assert_close(to_pixel(SeriesPixelSpace::Pane), content - region.plot_top);
+    assert_close(to_pixel(SeriesPixelSpace::Widget), content + 20.0);
+    // Built frames are in widget coordinates.
+    assert_close(
+        to_pixel(SeriesPixelSpace::Widget),
+        first_series_line_y(&engine, pane),
+    );
+
+    for space in [
+        SeriesPixelSpace::Content,
+        SeriesPixelSpace::Widget,
+        SeriesPixelSpace::Pane,
+    ] {
+        let price = engine
+            .series_pixel_to_price(SeriesRef::Points, to_pixel(space), space)
+            .expect("to price");
+        assert_close(price, 100.0);
+    }
+}
+
+#[test]
+fn compare_series_values_are_rebased_onto_the_primary_scale() {
+    let mut engine = engine();
+    engine
+        .add_compare_series(CompareSeries::new(
+            "peer",
+            (0..=10)
+                .map(|i| DataPoint::new(f64::from(i), 50.0 + f64::from(i)))
+                .collect(),
+            Color::rgb(0.9, 0.3, 0.1),
+        ))
+        .expect("compare");
+    let peer = SeriesRef::Compare("peer");
+    assert!(
+        engine
+            .series_price_to_pixel(peer, 50.0, SeriesPixelSpace::Content)
+            .is_err()
+    );
+
+    engine.set_compare_mode_enabled(true);
+    // Both series share their first visible value on screen.
+    let peer_base = engine
+        .series_price_to_pixel(peer, 50.0, SeriesPixelSpace::Content)
+        .expect("to pixel");
+    let primary_base = engine
+        .series_price_to_pixel(SeriesRef::Points, 100.0, SeriesPixelSpace::Content)
+        .expect("to pixel");
+    assert_close(peer_base, primary_base);
+
+    let y = engine
+        .series_price_to_pixel(peer, 55.0, SeriesPixelSpace::Content)
+        .expect("to pixel");
+    assert_close(
+        engine
+            .series_pixel_to_price(SeriesRef::Points, y, SeriesPixelSpace::Content)
+            .expect("to price"),
+        110.0,
+    );
+    assert_close(
+        engine
+            .series_pixel_to_price(peer, y, SeriesPixelSpace::Content)
+            .expect("to price"),
+        55.0,
+    );
+}
+
+#[test]
+fn unknown_series_are_rejected() {
+    let engine = engine();
+    assert_eq!(engine.series_pane_id(SeriesRef::Custom("missing")), None);
+    for series in [SeriesRef::Compare("missing"), SeriesRef::Custom("missing")] {
+        assert!(
+            engine
+                .series_price_to_pixel(series, 100.0, SeriesPixelSpace::Content)
+                .is_err()
+        );
+        assert!(
+            engine
+                .series_pixel_to_price(series, 100.0, SeriesPixelSpace::Content)
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,137 @@
+use chart_rs::api::{ChartEngine, ChartEngineConfig, RenderStyle, SeriesLastValueStyle};
+use chart_rs::core::{DataPoint, OhlcBar, Viewport};
+use chart_rs::render::{Color, NullRenderer, RenderFrame, TextHAlign};
+
+const POINT_COLOR: Color = Color::rgb(0.9, 0.4, 0.1);
+const CANDLE_COLOR: Color = Color::rgb(0.3, 0.2, 0.8);
+
+fn build_engine() -> ChartEngine<NullRenderer> {
+    let renderer = NullRenderer::default();
+    let config =
+        ChartEngineConfig::new(Viewport::new(900, 500), 0.0, 100.0).with_price_domain(0.0, 100.0);
+    let mut engine = ChartEngine::new(renderer, config).expect("engine init");
+    engine.set_data(vec![DataPoint::new(10.0, 20.0), DataPoint::new(50.0, 30.0)]);
+    engine.set_candles(vec![
+        OhlcBar::new(20.0, 60.0, 75.0, 55.0, 70.0).expect("bar"),
+        OhlcBar::new(40.0, 70.0, 72.0, 58.0, 62.0).expect("bar"),
+    ]);
+    engine
+}
+
+fn plot_right(engine: &ChartEngine<NullRenderer>) -> f64 {
+    f64::from(engine.viewport().width) - engine.render_style().price_axis_width_px
+}
+
+fn price_y(engine: &ChartEngine<NullRenderer>, price: f64) -> f64 {
+    let plot_bottom =
+        f64::from(engine.viewport().height) - engine.render_style().time_axis_height_px;
+    engine
+        .map_price_to_pixel(price)
+        .expect("price map")
+        .clamp(0.0, plot_bottom)
+}
+
+fn last_value_lines(frame: &RenderFrame, color: Color) -> Vec<f64> {
+    frame
+        .lines
+        .iter()
+        .filter(|line| line.color == color && line.y1 == line.y2)
+        .map(|line| line.y1)
+        .collect()
+}
+
+#[test]
+fn per_series_last_values_are_opt_in() {
+    let engine = build_engine();
+    let frame = engine.build_render_frame().expect("frame");
+    assert!(last_value_lines(&frame, POINT_COLOR).is_empty());
+    assert!(last_value_lines(&frame, CANDLE_COLOR).is_empty());
+}
+
+#[test]
+fn each_series_gets_its_own_line_and_label() {
+    let mut engine = build_engine();
+    engine
+        .set_render_style(RenderStyle {
+            show_last_price_line: false,
+            show_last_price_label: false,
+            point_series_last_value: Some(SeriesLastValueStyle::new(POINT_COLOR)),
+            candle_series_last_value: Some(SeriesLastValueStyle::new(CANDLE_COLOR)),
+            ..engine.render_style()
+        })
+        .expect("set style");
+    let frame = engine.build_render_frame().expect("frame");
+
+    let point_y = price_y(&engine, 30.0);
+    let candle_y = price_y(&engine, 62.0);
+    assert_eq!(last_value_lines(&frame, POINT_COLOR), vec![point_y]);
+    assert_eq!(last_value_lines(&frame, CANDLE_COLOR), vec![candle_y]);
+
+    let labels = frame
+        .texts
+        .iter()
+        .filter(|text| text.color == POINT_COLOR || text.color == CANDLE_COLOR)
+        .filter(|text| text.h_align == TextHAlign::Right && text.x > plot_right(&engine))
+        .count();
+    assert_eq!(labels, 2);
+}
+
+#[test]
+fn trend_mode_uses_last_price_direction_colors_per_series() {
+    let mut engine = build_engine();
+    let style = RenderStyle {
+        point_series_last_value: Some(SeriesLastValueStyle {
+            use_trend_color: true,
+            ..SeriesLastValueStyle::new(POINT_COLOR)
+        }),
+        candle_series_last_value: Some(SeriesLastValueStyle {
+            use_trend_color: true,
+            show_label: false,
+            ..SeriesLastValueStyle::new(CANDLE_COLOR)
+        }),
+        show_last_price_line: false,
+        ..engine.render_style()
+    };
+    engine.set_render_style(style.clone()).expect("set style");
+    let frame = engine.build_render_frame().expect("frame");
+
+    // Points rise 20 -> 30, candle closes fall 70 -> 62.
+    let point_y = price_y(&engine, 30.0);
+    let candle_y = price_y(&engine, 62.0);
+    assert!(last_value_lines(&frame, style.last_price_up_color).contains(&point_y));
+    assert!(last_value_lines(&frame, style.last_price_down_color).contains(&candle_y));
+}
+
+#[test]
+fn colliding_last_value_labels_are_stacked() {
+    let renderer = NullRenderer::default();
+    let config =
+        ChartEngineConfig::new(Viewport::new(900, 500), 0.0, 100.0).with_price_domain(0.0, 100.0);
+    let mut engine = ChartEngine::new(renderer, config).expect("engine init");
+    engine.set_data(vec![DataPoint::new(50.0, 50.0)]);
+    engine.set_candles(vec![
+        OhlcBar::new(40.0, 50.0, 52.0, 49.0, 50.5).expect("bar"),
+    ]);
+    engine
+        .set_render_style(RenderStyle {
+            show_last_price_label_box: true,
+            point_series_last_value: Some(SeriesLastValueStyle::new(POINT_COLOR)),
+            candle_series_last_value: Some(SeriesLastValueStyle::new(CANDLE_COLOR)),
+            ..engine.render_style()
+        })
+        .expect("set style");
+    let frame = engine.build_render_frame().expect("frame");
+
+    let mut boxes = frame
+        .rects
+        .iter()
+        .filter(|rect| rect.x >= plot_right(&engine))
+        .map(|rect| (rect.y, rect.y + rect.height))
+        .collect::<Vec<_>>();
+    boxes.sort_by(|left, right| left.0.total_cmp(&right.0));
+    // Global last-price label plus one per series.
+    assert_eq!(boxes.len(), 3);
+    for pair in boxes.windows(2) {
+        assert!(pair[0].1 <= pair[1].0 + 1e-9, "{boxes:?}");
+    }
+}
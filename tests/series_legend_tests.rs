@@ -0,0 +1,109 @@
+use chart_rs::api::{ChartEngine, ChartEngineConfig, LEGEND_CANDLES_NAME_KEY, LegendSeriesKind};
+use chart_rs::core::{DataPoint, OhlcBar, Viewport};
+use chart_rs::render::NullRenderer;
+
+fn engine() -> ChartEngine<NullRenderer> {
+    let config =
+        ChartEngineConfig::new(Viewport::new(1000, 500), 0.0, 10.0).with_price_domain(0.0, 50.0);
+    let mut engine = ChartEngine::new(NullRenderer::default(), config).expect("engine");
+    engine.set_data(
+        (0..=10)
+            .map(|i| DataPoint::new(f64::from(i), 10.0 + f64::from(i)))
+            .collect(),
+    );
+    engine.set_candles(
+        (0..=10)
+            .map(|i| {
+                let open = 20.0 + f64::from(i);
+                OhlcBar::new(f64::from(i), open, open + 3.0, open - 1.0, open + 2.0).expect("bar")
+            })
+            .collect(),
+    );
+    engine
+}
+
+#[test]
+fn legend_lists_loaded_series_with_last_values_and_window_change() {
+    let engine = engine();
+    let style = engine.render_style();
+    let legend = engine.series_legend();
+
+    assert_eq!(legend.crosshair_time, None);
+    assert_eq!(legend.entries.len(), 2);
+
+    let points = &legend.entries[0];
+    assert_eq!(points.kind, LegendSeriesKind::Points);
+    assert_eq!(points.name, "Line");
+    assert_eq!(points.color, style.series_line_color);
+    assert_eq!(points.last_value, Some(20.0));
+    assert_eq!(points.crosshair_value, None);
+    assert_eq!(points.change, Some(10.0));
+    assert_eq!(points.change_percent, Some(100.0));
+
+    let candles = &legend.entries[1];
+    assert_eq!(candles.kind, LegendSeriesKind::Candles);
+    assert_eq!(candles.name, "Candles");
+    assert_eq!(candles.color, style.candlestick_up_color);
+    assert_eq!(candles.last_value, Some(32.0));
+    assert_eq!(candles.change, Some(12.0));
+    assert_eq!(candles.change_percent, Some(60.0));
+}
+
+#[test]
+fn legend_follows_crosshair_and_visible_range() {
+    let mut engine = engine();
+    let x = engine.map_x_to_pixel(4.0).expect("pixel");
+    engine.pointer_move(x, 100.0);
+
+    let legend = engine.series_legend();
+    assert_eq!(legend.crosshair_time, Some(4.0));
+    assert_eq!(legend.entries[0].crosshair_value, Some(14.0));
+    assert_eq!(legend.entries[1].crosshair_value, Some(26.0));
+
+    engine.set_time_visible_range(5.0, 8.0).expect("range");
+    let legend = engine.series_legend();
+    assert_eq!(legend.entries[0].change, Some(3.0));
+    assert_eq!(legend.entries[1].change, Some(5.0));
+    assert_eq!(legend.entries[0].last_value, Some(20.0));
+
+    engine.pointer_leave();
+    let legend = engine.series_legend();
+    assert_eq!(legend.crosshair_time, None);
+    assert!(
+        legend
+            .entries
+            .iter()
+            .all(|entry| entry.crosshair_value.is_none())
+    );
+}
+
+#[test]
+fn legend_uses_metadata_names_and_skips_empty_series() {
+    let config = ChartEngineConfig::new(Viewport::new(800, 400), 0.0, 10.0);
+    let mut engine = ChartEngine::new(NullRenderer::default(), config).expect("engine");
+    assert!(engine.series_legend().entries.is_empty());
+
+    engine.set_series_metadata(LEGEND_CANDLES_NAME_KEY, "BTCUSD");
+    engine.set_candles(vec![
+        OhlcBar::new(1.0, 10.0, 12.0, 8.0, 11.0).expect("bar"),
+        OhlcBar::new(2.0, 11.0, 11.5, 7.0, 9.0).expect("bar"),
+    ]);
+    let legend = engine.series_legend();
+    assert_eq!(legend.entries.len(), 1);
+    assert_eq!(legend.entries[0].name, "BTCUSD");
+    assert_eq!(
+        legend.entries[0].color,
+        engine.render_style().candlestick_down_color
+    );
+}
+
+#[test]
+fn zero_base_change_has_no_percent() {
+    let config = ChartEngineConfig::new(Viewport::new(800, 400), 0.0, 10.0);
+    let mut engine = ChartEngine::new(NullRenderer::default(), config).expect("engine");
+    engine.set_data(vec![DataPoint::new(1.0, 0.0), DataPoint::new(2.0, 5.0)]);
+
+    let entry = &engine.series_legend().entries[0];
+    assert_eq!(entry.change, Some(5.0));
+    assert_eq!(entry.change_percent, None);
+}
@@ -0,0 +1,195 @@
+use chart_rs::api::{
+    ChartEngine, ChartEngineConfig, CompareSeries, CrosshairMode, CrosshairSnapSeries,
+    PriceAxisLabelPolicy, RenderStyle, SeriesLastValueStyle, SeriesRef,
+};
+use chart_rs::core::{DataPoint, OhlcBar, Viewport};
+use chart_rs::render::{Color, NullRenderer};
+
+const FOUR_DECIMALS: PriceAxisLabelPolicy = PriceAxisLabelPolicy::FixedDecimals { precision: 4 };
+const WHOLE: PriceAxisLabelPolicy = PriceAxisLabelPolicy::FixedDecimals { precision: 0 };
+
+fn engine() -> ChartEngine<NullRenderer> {
+    let config =
+        ChartEngineConfig::new(Viewport::new(800, 400), 0.0, 100.0).with_price_domain(0.0, 50.0);
+    let mut engine = ChartEngine::new(NullRenderer::default(), config).expect("engine");
+    engine
+        .set_render_style(RenderStyle {
+            show_last_price_label: false,
+            point_series_last_value: Some(SeriesLastValueStyle::new(Color::rgb(0.2, 0.4, 0.9))),
+            ..engine.render_style()
+        })
+        .expect("style");
+    engine.set_data(vec![
+        DataPoint::new(10.0, 12.25),
+        DataPoint::new(90.0, 31.75),
+    ]);
+    engine.set_candles(vec![
+        OhlcBar::new(20.0, 20.0, 22.0, 19.0, 21.5).expect("bar"),
+        OhlcBar::new(80.0, 21.5, 24.0, 21.0, 23.125).expect("bar"),
+    ]);
+    engine
+}
+
+fn axis_texts(engine: &ChartEngine<NullRenderer>) -> Vec<String> {
+    let plot_right = 800.0 - engine.resolved_price_axis_width_px();
+    engine
+        .build_render_frame()
+        .expect("frame")
+        .texts
+        .into_iter()
+        .filter(|text| text.x > plot_right)
+        .map(|text| text.text.to_string())
+        .collect()
+}
+
+fn decimals(text: &str) -> usize {
+    text.split_once('.')
+        .map_or(0, |(_, fraction)| fraction.len())
+}
+
+#[test]
+fn axis_follows_the_primary_series_format() {
+    let mut engine = engine();
+    assert!(axis_texts(&engine).iter().all(|text| decimals(text) == 2));
+
+    engine
+        .set_series_price_format(SeriesRef::Points, Some(WHOLE))
+        .expect("format");
+    // Candles are the primary series, so the ticks keep the axis format
+    // while the point series' last-value label drops its decimals.
+    let texts = axis_texts(&engine);
+    assert!(texts.contains(&"32".to_owned()));
+    assert!(
+        texts
+            .iter()
+            .filter(|text| *text != "32")
+            .all(|text| decimals(text) == 2)
+    );
+
+    engine
+        .set_series_price_format(SeriesRef::Candles, Some(FOUR_DECIMALS))
+        .expect("format");
+    let texts = axis_texts(&engine);
+    assert!(texts.contains(&"32".to_owned()));
+    assert!(
+        texts
+            .iter()
+            .filter(|text| *text != "32")
+            .all(|text| decimals(text) == 4)
+    );
+}
+
+#[test]
+fn legend_and_snapped_crosshair_use_the_series_format() {
+    let mut engine = engine();
+    engine
+        .set_series_price_format(SeriesRef::Points, Some(WHOLE))
+        .expect("format");
+    engine
+        .set_series_price_format(SeriesRef::Candles, Some(FOUR_DECIMALS))
+        .expect("format");
+    engine.set_crosshair_mode(CrosshairMode::Magnet);
+    let plot_right = 800.0 - engine.resolved_price_axis_width_px();
+    engine.pointer_move(plot_right * 0.1, 200.0);
+    assert_eq!(
+        engine.crosshair_state().snapped_series,
+        Some(CrosshairSnapSeries::Points)
+    );
+    let texts = axis_texts(&engine);
+    assert!(texts.contains(&"12".to_owned()));
+
+    let legend = engine.series_legend();
+    let texts: Vec<_> = legend
+        .entries
+        .iter()
+        .map(|entry| {
+            (
+                entry.last_value_text.clone(),
+                entry.crosshair_value_text.clone(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        texts,
+        [
+            (Some("32".to_owned()), Some("12".to_owned())),
+            (Some("23.1250".to_owned()), Some("21.5000".to_owned())),
+        ]
+    );
+}
+
+#[test]
+fn compare_series_carry_their_own_format() {
+    let mut engine = engine();
+    engine
+        .add_compare_series(
+            CompareSeries::new(
+                "yield",
+                vec![DataPoint::new(10.0, 4.1234)],
+                Color::rgb(0.9, 0.5, 0.1),
+            )
+            .with_price_format(PriceAxisLabelPolicy::FixedDecimals { precision: 3 }),
+        )
+        .expect("compare");
+    assert_eq!(
+        engine.format_series_value(SeriesRef::Compare("yield"), 4.1234),
+        "4.123"
+    );
+    assert_eq!(
+        engine.format_series_value(SeriesRef::Points, 4.1234),
+        "4.12"
+    );
+
+    assert!(
+        engine
+            .set_series_price_format(SeriesRef::Compare("missing"), Some(WHOLE))
+            .is_err()
+    );
+    assert!(
+        engine
+            .set_series_price_format(SeriesRef::Custom("bands"), Some(WHOLE))
+            .is_err()
+    );
+    assert!(
+        engine
+            .set_series_price_format(
+                SeriesRef::Points,
+                Some(PriceAxisLabelPolicy::MinMove {
+                    min_move: 0.0,
+                    trim_trailing_zeros: false,
+                }),
+            )
+            .is_err()
+    );
+}
+
+#[test]
+fn series_formats_round_trip_through_scenes() {
+    let mut engine = engine();
+    engine
+        .set_series_price_format(SeriesRef::Candles, Some(FOUR_DECIMALS))
+        .expect("format");
+    engine
+        .add_compare_series(
+            CompareSeries::new(
+                "btc",
+                vec![DataPoint::new(10.0, 1.0)],
+                Color::rgb(1.0, 0.6, 0.0),
+            )
+            .with_price_format(WHOLE),
+        )
+        .expect("compare");
+    let json = engine.export_scene_json_pretty().expect("json");
+
+    let mut restored = self::engine();
+    restored.import_scene_json_str(&json).expect("import");
+    assert_eq!(
+        restored.series_price_format(SeriesRef::Candles),
+        Some(FOUR_DECIMALS)
+    );
+    assert_eq!(restored.series_price_format(SeriesRef::Points), None);
+    assert_eq!(
+        restored.series_price_format(SeriesRef::Compare("btc")),
+        Some(WHOLE)
+    );
+}
@@ -0,0 +1,191 @@
+use chart_rs::api::{
+    ChartEngine, ChartEngineConfig, CompareSeries, SeriesRef, SeriesValueInterpolation,
+};
+use chart_rs::core::{DataPoint, OhlcBar, Viewport};
+use chart_rs::error::ChartResult;
+use chart_rs::extensions::{CustomSeries, CustomSeriesPrimitives, CustomSeriesRenderContext};
+use chart_rs::render::{Color, NullRenderer};
+
+/// Custom series of `(time, low, high)` bands.
+struct BandSeries;
+
+impl CustomSeries for BandSeries {
+    type Row = (f64, f64, f64);
+
+    fn row_time(&self, row: &(f64, f64, f64)) -> f64 {
+        row.0
+    }
+
+    fn autoscale_range(&self, rows: &[(f64, f64, f64)]) -> Option<(f64, f64)> {
+        rows.iter()
+            .map(|row| (row.1, row.2))
+            .reduce(|(low, high), (row_low, row_high)| (low.min(row_low), high.max(row_high)))
+    }
+
+    fn render(
+        &self,
+        _rows: &[(f64, f64, f64)],
+        _ctx: &CustomSeriesRenderContext,
+        _out: &mut CustomSeriesPrimitives,
+    ) -> ChartResult<()> {
+        Ok(())
+    }
+}
+
+fn engine() -> ChartEngine<NullRenderer> {
+    let config = ChartEngineConfig::new(Viewport::new(800, 400), 0.0, 100.0);
+    let mut engine = ChartEngine::new(NullRenderer::default(), config).expect("engine");
+    engine.set_data(vec![
+        DataPoint::new(0.0, 10.0),
+        DataPoint::new(10.0, 30.0),
+        DataPoint::new(20.0, 5.0),
+        DataPoint::new(30.0, 25.0),
+    ]);
+    engine.set_candles(vec![
+        OhlcBar::new(0.0, 10.0, 15.0, 8.0, 12.0).expect("bar"),
+        OhlcBar::new(10.0, 12.0, 20.0, 11.0, 18.0).expect("bar"),
+        OhlcBar::new(20.0, 18.0, 19.0, 2.0, 4.0).expect("bar"),
+    ]);
+    engine
+}
+
+#[test]
+fn price_range_covers_samples_inside_the_window() {
+    let mut engine = engine();
+    assert_eq!(
+        engine.series_price_range(SeriesRef::Points, (5.0, 30.0)),
+        Some((5.0, 30.0))
+    );
+    // Reversed windows are normalized and bounds are inclusive.
+    assert_eq!(
+        engine.series_price_range(SeriesRef::Points, (20.0, 0.0)),
+        Some((5.0, 30.0))
+    );
+    assert_eq!(
+        engine.series_price_range(SeriesRef::Candles, (0.0, 10.0)),
+        Some((8.0, 20.0))
+    );
+    assert_eq!(
+        engine.series_price_range(SeriesRef::Points, (11.0, 19.0)),
+        None
+    );
+    assert_eq!(
+        engine.series_price_range(SeriesRef::Points, (f64::NAN, 10.0)),
+        None
+    );
+
+    engine
+        .add_compare_series(CompareSeries::new(
+            "peer",
+            vec![DataPoint::new(0.0, 100.0), DataPoint::new(10.0, 90.0)],
+            Color::rgb(0.2, 0.4, 0.8),
+        ))
+        .expect("compare");
+    let main_pane = engine.main_pane_id();
+    engine
+        .add_custom_series(
+            "bands",
+            main_pane,
+            BandSeries,
+            vec![(0.0, 1.0, 3.0), (50.0, -4.0, 2.0)],
+        )
+        .expect("custom");
+    assert_eq!(
+        engine.series_price_range(SeriesRef::Compare("peer"), (0.0, 100.0)),
+        Some((90.0, 100.0))
+    );
+    assert_eq!(
+        engine.series_price_range(SeriesRef::Custom("bands"), (0.0, 10.0)),
+        Some((1.0, 3.0))
+    );
+    assert_eq!(
+        engine.series_price_range(SeriesRef::Custom("bands"), (0.0, 60.0)),
+        Some((-4.0, 3.0))
+    );
+    assert_eq!(
+        engine.series_price_range(SeriesRef::Compare("missing"), (0.0, 100.0)),
+        None
+    );
+}
+
+#[test]
+fn value_at_follows_interpolation_mode() {
+    let engine = engine();
+    let value = |time, mode| engine.series_value_at(SeriesRef::Points, time, mode);
+
+    assert_eq!(value(10.0, SeriesValueInterpolation::Exact), Some(30.0));
+    assert_eq!(value(12.0, SeriesValueInterpolation::Exact), None);
+    assert_eq!(value(12.0, SeriesValueInterpolation::Previous), Some(30.0));
+    assert_eq!(value(-1.0, SeriesValueInterpolation::Previous), None);
+    assert_eq!(value(99.0, SeriesValueInterpolation::Previous), Some(25.0));
+    assert_eq!(value(16.0, SeriesValueInterpolation::Nearest), Some(5.0));
+    assert_eq!(value(15.0, SeriesValueInterpolation::Nearest), Some(30.0));
+    assert_eq!(value(15.0, SeriesValueInterpolation::Linear), Some(17.5));
+    assert_eq!(value(20.0, SeriesValueInterpolation::Linear), Some(5.0));
+    assert_eq!(value(31.0, SeriesValueInterpolation::Linear), None);
+    assert_eq!(value(f64::NAN, SeriesValueInterpolation::Nearest), None);
+}
+
+#[test]
+fn queries_see_appended_samples() {
+    let mut engine = engine();
+    // Older samples are rejected, keeping the binary-searched window valid.
+    assert!(engine.append_point(DataPoint::new(15.0, 99.0)).is_err());
+    engine
+        .append_point(DataPoint::new(40.0, 50.0))
+        .expect("append");
+    engine
+        .append_candle(OhlcBar::new(30.0, 4.0, 40.0, 3.0, 35.0).expect("bar"))
+        .expect("append");
+    assert!(
+        engine
+            .append_candle(OhlcBar::new(25.0, 4.0, 90.0, 1.0, 80.0).expect("bar"))
+            .is_err()
+    );
+
+    assert_eq!(
+        engine.series_price_range(SeriesRef::Points, (12.0, 40.0)),
+        Some((5.0, 50.0))
+    );
+    assert_eq!(
+        engine.series_price_range(SeriesRef::Candles, (15.0, 35.0)),
+        Some((2.0, 40.0))
+    );
+    let value = |time, mode| engine.series_value_at(SeriesRef::Points, time, mode);
+    assert_eq!(value(15.0, SeriesValueInterpolation::Exact), None);
+    assert_eq!(value(35.0, SeriesValueInterpolation::Linear), Some(37.5));
+    assert_eq!(
+        engine.series_value_at(SeriesRef::Candles, 30.0, SeriesValueInterpolation::Exact),
+        Some(35.0)
+    );
+}
+
+#[test]
+fn value_at_resolves_candle_closes_and_skips_custom_series() {
+    let mut engine = engine();
+    assert_eq!(
+        engine.series_value_at(SeriesRef::Candles, 15.0, SeriesValueInterpolation::Linear),
+        Some(11.0)
+    );
+    assert_eq!(
+        engine.series_value_at(
+            SeriesRef::Candles,
+            25.0,
+            SeriesValueInterpolation::default()
+        ),
+        Some(4.0)
+    );
+
+    let main_pane = engine.main_pane_id();
+    engine
+        .add_custom_series("bands", main_pane, BandSeries, vec![(0.0, 1.0, 3.0)])
+        .expect("custom");
+    assert_eq!(
+        engine.series_value_at(
+            SeriesRef::Custom("bands"),
+            0.0,
+            SeriesValueInterpolation::Exact
+        ),
+        None
+    );
+}
@@ -0,0 +1,135 @@
+use std::cell::Cell;
+use std::thread;
+use std::time::Duration;
+
+use chart_rs::api::{ChartEngine, ChartEngineConfig, StagedCandles, engine_command_channel};
+use chart_rs::core::{OhlcBar, Viewport};
+use chart_rs::render::NullRenderer;
+
+const T0: f64 = 1_704_067_200.0;
+
+fn build_engine() -> ChartEngine<NullRenderer> {
+    let config = ChartEngineConfig::new(Viewport::new(800, 400), T0, T0 + 6_000.0)
+        .with_price_domain(0.0, 100.0);
+    ChartEngine::new(NullRenderer::default(), config).expect("engine")
+}
+
+fn unsorted_bars(count: u32) -> Vec<OhlcBar> {
+    (0..count)
+        .rev()
+        .map(|i| {
+            let close = 10.0 + f64::from(i % 7);
+            OhlcBar::new(
+                T0 + f64::from(i) * 60.0,
+                close,
+                close + 1.0,
+                close - 1.0,
+                close,
+            )
+            .expect("bar")
+        })
+        .collect()
+}
+
+#[test]
+fn staging_leaves_visible_series_untouched_until_commit() {
+    let mut engine = build_engine();
+    engine.set_candles(unsorted_bars(3));
+
+    engine.stage_candles(unsorted_bars(100));
+    assert!(engine.has_staged_candles());
+    assert_eq!(engine.candles().len(), 3);
+
+    assert!(engine.commit_staged());
+    assert!(!engine.has_staged_candles());
+    assert_eq!(engine.candles().len(), 100);
+    assert!(
+        engine
+            .candles()
+            .windows(2)
+            .all(|pair| pair[0].time < pair[1].time)
+    );
+    assert!(!engine.commit_staged());
+}
+
+#[test]
+fn committed_series_matches_set_candles() {
+    let mut bars = unsorted_bars(50);
+    bars.push(bars[0]);
+    let mut broken = bars[1];
+    broken.high = f64::NAN;
+    bars.push(broken);
+
+    let mut direct = build_engine();
+    direct.set_candles(bars.clone());
+
+    let staged = StagedCandles::prepare(bars);
+    assert_eq!(staged.original_len(), 52);
+    assert_eq!(staged.len(), 50);
+
+    let mut committed = build_engine();
+    committed.stage_prepared_candles(staged);
+    committed.commit_staged();
+    assert_eq!(committed.candles(), direct.candles());
+    assert_eq!(committed.price_domain(), direct.price_domain());
+}
+
+#[test]
+fn worker_prepared_batches_commit_through_engine_handle() {
+    let (handle, mut queue) = engine_command_channel();
+    let worker = thread::spawn(move || {
+        handle
+            .commit_staged_candles(StagedCandles::prepare(unsorted_bars(10_000)))
+            .expect("send");
+    });
+    worker.join().expect("worker");
+
+    let mut engine = build_engine();
+    let report = queue.apply_pending(&mut engine);
+    assert_eq!(report.applied, 1);
+    assert_eq!(engine.candles().len(), 10_000);
+    assert_eq!(engine.ingestion_stats().last_committed_len, 10_000);
+}
+
+#[test]
+fn ingestion_stats_track_stage_durations_and_discards() {
+    let mut engine = build_engine();
+    engine.stage_candles(unsorted_bars(10));
+    engine.stage_candles(unsorted_bars(20));
+    engine.commit_staged();
+    engine.stage_candles(unsorted_bars(5));
+    assert!(engine.discard_staged());
+    assert!(!engine.discard_staged());
+
+    let stats = engine.ingestion_stats();
+    assert_eq!(stats.staged_batches, 3);
+    assert_eq!(stats.committed_batches, 1);
+    assert_eq!(stats.discarded_batches, 2);
+    assert_eq!(stats.last_committed_len, 20);
+    assert!(stats.max_stage_duration >= stats.last_stage_duration);
+    assert!(stats.total_stage_duration >= stats.max_stage_duration);
+    assert_eq!(engine.candles().len(), 20);
+}
+
+#[test]
+fn stage_durations_come_from_the_host_clock() {
+    let untimed = StagedCandles::prepare(unsorted_bars(10));
+    assert_eq!(untimed.stage_duration(), Duration::ZERO);
+
+    let ticks = Cell::new(0_u64);
+    let clock = || {
+        ticks.set(ticks.get() + 1);
+        Duration::from_millis(ticks.get() * 3)
+    };
+    let timed = StagedCandles::prepare_timed(unsorted_bars(10), clock);
+    assert_eq!(timed.stage_duration(), Duration::from_millis(3));
+    assert_eq!(timed.len(), 10);
+
+    let mut engine = build_engine();
+    engine.stage_prepared_candles(timed);
+    engine.stage_prepared_candles(untimed);
+    let stats = engine.ingestion_stats();
+    assert_eq!(stats.last_stage_duration, Duration::ZERO);
+    assert_eq!(stats.max_stage_duration, Duration::from_millis(3));
+    assert_eq!(stats.total_stage_duration, Duration::from_millis(3));
+}
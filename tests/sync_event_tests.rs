@@ -0,0 +1,80 @@
+use chart_rs::api::{
+    ChartEngine, ChartEngineConfig, CrosshairSyncEvent, RangeSyncEvent, SYNC_EVENT_JSON_SCHEMA_V1,
+    SyncEvent,
+};
+use chart_rs::core::{DataPoint, Viewport};
+use chart_rs::error::ChartError;
+use chart_rs::render::NullRenderer;
+
+fn engine() -> ChartEngine<NullRenderer> {
+    let config = ChartEngineConfig::new(Viewport::new(800, 400), 0.0, 100.0);
+    let mut engine = ChartEngine::new(NullRenderer::default(), config).expect("engine");
+    engine.set_data(
+        (0..=200)
+            .map(|i| DataPoint::new(f64::from(i), f64::from(i % 7)))
+            .collect(),
+    );
+    engine.set_time_visible_range(0.0, 100.0).expect("range");
+    engine
+}
+
+#[test]
+fn events_round_trip_as_compact_versioned_json() {
+    let range: SyncEvent = RangeSyncEvent::new(10.0, 60.0).into();
+    let json = range.to_json().expect("json");
+    assert_eq!(json, r#"{"k":"range","v":1,"s":10.0,"e":60.0}"#);
+    assert_eq!(SyncEvent::from_json_str(&json).expect("parse"), range);
+
+    let hidden: SyncEvent = CrosshairSyncEvent::new(None).into();
+    let json = hidden.to_json().expect("json");
+    assert_eq!(json, r#"{"k":"crosshair","v":1,"t":null}"#);
+    assert_eq!(SyncEvent::from_json_str(&json).expect("parse"), hidden);
+    assert_eq!(hidden.schema_version(), SYNC_EVENT_JSON_SCHEMA_V1);
+
+    assert!(matches!(
+        SyncEvent::from_json_str(r#"{"k":"range","v":2,"s":1.0,"e":2.0}"#),
+        Err(ChartError::SchemaVersionMismatch { found: 2, .. })
+    ));
+    assert!(SyncEvent::from_json_str(r#"{"k":"zoom","v":1}"#).is_err());
+}
+
+#[test]
+fn remote_events_mirror_range_and_crosshair() {
+    let mut local = engine();
+    let mut remote = engine();
+    local.set_time_visible_range(50.0, 120.0).expect("range");
+    let x = local.map_x_to_pixel(80.0).expect("x");
+    local.pointer_move(x, 120.0);
+
+    for event in [
+        SyncEvent::from(local.range_sync_event()),
+        SyncEvent::from(local.crosshair_sync_event()),
+    ] {
+        let wire = event.to_json().expect("json");
+        remote
+            .apply_sync_event(SyncEvent::from_json_str(&wire).expect("parse"))
+            .expect("apply");
+    }
+    assert_eq!(remote.time_visible_range(), (50.0, 120.0));
+    assert_eq!(remote.crosshair_time(), Some(80.0));
+
+    // Remote control wins over local motion.
+    remote.start_kinetic_pan(40.0).expect("kinetic");
+    remote
+        .apply_sync_event(RangeSyncEvent::new(20.0, 30.0).into())
+        .expect("apply");
+    assert!(!remote.has_active_animation());
+    assert_eq!(remote.time_visible_range(), (20.0, 30.0));
+
+    remote
+        .apply_sync_event(CrosshairSyncEvent::new(None).into())
+        .expect("apply");
+    assert_eq!(remote.crosshair_time(), None);
+
+    let stale = CrosshairSyncEvent {
+        schema_version: 0,
+        time: Some(10.0),
+    };
+    assert!(remote.apply_sync_event(stale.into()).is_err());
+    assert_eq!(remote.crosshair_time(), None);
+}
@@ -0,0 +1,155 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use chart_rs::api::{ChartEngine, ChartEngineConfig, SyncGroup, SyncMemberOptions};
+use chart_rs::core::{DataPoint, Viewport};
+use chart_rs::interaction::CrosshairMode;
+use chart_rs::render::NullRenderer;
+
+type SharedEngine = Rc<RefCell<ChartEngine<NullRenderer>>>;
+
+fn shared_engine(offset: f64) -> SharedEngine {
+    let config = ChartEngineConfig::new(Viewport::new(800, 400), 0.0, 100.0);
+    let mut engine = ChartEngine::new(NullRenderer::default(), config).expect("engine");
+    engine.set_data(
+        (0..=500)
+            .map(|i| DataPoint::new(f64::from(i), offset + f64::from(i % 11)))
+            .collect(),
+    );
+    engine.set_time_visible_range(0.0, 100.0).expect("range");
+    Rc::new(RefCell::new(engine))
+}
+
+fn range_only() -> SyncMemberOptions {
+    SyncMemberOptions {
+        sync_crosshair: false,
+        ..SyncMemberOptions::default()
+    }
+}
+
+#[test]
+fn range_changes_propagate_from_whichever_chart_moved() {
+    let (a, b, c) = (
+        shared_engine(10.0),
+        shared_engine(50.0),
+        shared_engine(90.0),
+    );
+    c.borrow_mut()
+        .set_time_visible_range(300.0, 350.0)
+        .expect("range");
+    let mut group = SyncGroup::new();
+    let id_a = group
+        .add(a.clone(), SyncMemberOptions::default())
+        .expect("add");
+    let id_b = group
+        .add(b.clone(), SyncMemberOptions::default())
+        .expect("add");
+    // Joining adopts the group's range.
+    let id_c = group.add(c.clone(), range_only()).expect("add");
+    assert_eq!(c.borrow().time_visible_range(), (0.0, 100.0));
+    assert!(group.synchronize().expect("sync").is_empty());
+
+    a.borrow_mut()
+        .set_time_visible_range(40.0, 90.0)
+        .expect("range");
+    assert_eq!(group.synchronize().expect("sync"), vec![id_b, id_c]);
+    assert_eq!(b.borrow().time_visible_range(), (40.0, 90.0));
+    assert_eq!(c.borrow().time_visible_range(), (40.0, 90.0));
+
+    c.borrow_mut()
+        .set_time_visible_range(120.0, 200.0)
+        .expect("range");
+    assert_eq!(group.synchronize().expect("sync"), vec![id_a, id_b]);
+    assert_eq!(a.borrow().time_visible_range(), (120.0, 200.0));
+
+    // Opting out of range sync isolates the chart both ways.
+    group
+        .set_member_options(
+            id_b,
+            SyncMemberOptions {
+                sync_range: false,
+                ..SyncMemberOptions::default()
+            },
+        )
+        .expect("options");
+    b.borrow_mut()
+        .set_time_visible_range(0.0, 10.0)
+        .expect("range");
+    a.borrow_mut()
+        .set_time_visible_range(200.0, 260.0)
+        .expect("range");
+    group.synchronize().expect("sync");
+    assert_eq!(b.borrow().time_visible_range(), (0.0, 10.0));
+    assert_eq!(c.borrow().time_visible_range(), (200.0, 260.0));
+
+    assert!(group.remove(id_c));
+    assert!(!group.remove(id_c));
+    assert_eq!(group.len(), 2);
+}
+
+#[test]
+fn followers_track_kinetic_pan_and_animations_frame_by_frame() {
+    let (a, b) = (shared_engine(10.0), shared_engine(50.0));
+    let mut group = SyncGroup::new();
+    group.add(a.clone(), range_only()).expect("add");
+    group.add(b.clone(), range_only()).expect("add");
+
+    // A follower's own motion yields to the driving chart.
+    b.borrow_mut()
+        .animate_time_visible_range(300.0, 400.0, 1.0)
+        .expect("animate");
+    a.borrow_mut().start_kinetic_pan(120.0).expect("kinetic");
+    for _ in 0..10 {
+        a.borrow_mut().step_animations(1.0 / 60.0).expect("step");
+        b.borrow_mut().step_animations(1.0 / 60.0).expect("step");
+        group.synchronize().expect("sync");
+        assert!(!b.borrow().has_active_animation());
+        assert_eq!(
+            b.borrow().time_visible_range(),
+            a.borrow().time_visible_range()
+        );
+    }
+    assert!(a.borrow().time_visible_range().0 > 0.0);
+
+    a.borrow_mut().stop_kinetic_pan();
+    a.borrow_mut()
+        .animate_time_visible_range(200.0, 260.0, 0.5)
+        .expect("animate");
+    while a.borrow().has_active_animation() {
+        a.borrow_mut().step_animations(0.1).expect("step");
+        group.synchronize().expect("sync");
+        assert_eq!(
+            b.borrow().time_visible_range(),
+            a.borrow().time_visible_range()
+        );
+    }
+    assert_eq!(b.borrow().time_visible_range(), (200.0, 260.0));
+}
+
+#[test]
+fn crosshair_time_is_mirrored_and_hidden_with_the_source() {
+    let (a, b) = (shared_engine(10.0), shared_engine(50.0));
+    b.borrow_mut().set_crosshair_mode(CrosshairMode::Normal);
+    let mut group = SyncGroup::new();
+    let id_a = group
+        .add(a.clone(), SyncMemberOptions::default())
+        .expect("add");
+    let id_b = group
+        .add(b.clone(), SyncMemberOptions::default())
+        .expect("add");
+
+    let x = a.borrow().map_x_to_pixel(42.0).expect("x");
+    a.borrow_mut().pointer_move(x, 100.0);
+    assert_eq!(a.borrow().crosshair_time(), Some(42.0));
+    assert_eq!(group.synchronize().expect("sync"), vec![id_b]);
+    let time = b.borrow().crosshair_time().expect("crosshair time");
+    assert!((time - 42.0).abs() <= 1e-9, "{time}");
+    assert!(b.borrow().needs_redraw());
+
+    b.borrow_mut().pointer_leave();
+    assert_eq!(group.synchronize().expect("sync"), vec![id_a]);
+    assert!(!a.borrow().crosshair_state().visible);
+    assert_eq!(a.borrow().crosshair_time(), None);
+
+    assert!(b.borrow_mut().set_crosshair_time(Some(f64::NAN)).is_err());
+}
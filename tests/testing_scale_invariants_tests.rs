@@ -0,0 +1,99 @@
+use chart_rs::core::{PriceScale, PriceScaleMode, TimeScale, Viewport};
+use chart_rs::error::ChartResult;
+use chart_rs::testing::scale_invariants::{
+    NavigableScale, ProjectedScale, check_monotonic, check_pan_accumulation, check_round_trip,
+    check_zoom_inverse, value_magnitude,
+};
+
+/// Custom scale whose inverse is deliberately off by a fixed bias.
+#[derive(Debug, Clone, Copy)]
+struct BiasedScale {
+    start: f64,
+    end: f64,
+    bias: f64,
+}
+
+impl ProjectedScale for BiasedScale {
+    fn value_to_pixel(&self, value: f64, viewport: Viewport) -> ChartResult<f64> {
+        Ok((value - self.start) / (self.end - self.start) * f64::from(viewport.width))
+    }
+
+    fn pixel_to_value(&self, pixel: f64, viewport: Viewport) -> ChartResult<f64> {
+        Ok(self.start + pixel / f64::from(viewport.width) * (self.end - self.start) + self.bias)
+    }
+
+    fn value_range(&self) -> (f64, f64) {
+        (self.start, self.end)
+    }
+
+    fn pixel_direction(&self) -> f64 {
+        1.0
+    }
+}
+
+impl NavigableScale for BiasedScale {
+    fn pan_by(&mut self, delta: f64) -> ChartResult<()> {
+        self.start += delta;
+        self.end += delta + self.bias;
+        Ok(())
+    }
+
+    fn zoom_by(&mut self, factor: f64, anchor: f64) -> ChartResult<()> {
+        self.start = anchor - (anchor - self.start) / factor;
+        self.end = anchor + (self.end - anchor) / factor + self.bias;
+        Ok(())
+    }
+}
+
+#[test]
+fn built_in_scales_satisfy_projection_invariants() {
+    let viewport = Viewport::new(1280, 720);
+    let time = TimeScale::new(1_700_000_000.0, 1_700_086_400.0).expect("time scale");
+    let tolerance = 1e-9 * value_magnitude(&time);
+    check_round_trip(&time, viewport, 1_700_012_345.5, tolerance).expect("time round trip");
+    check_monotonic(&time, viewport, 1_700_000_100.0, 1_700_000_200.0, tolerance)
+        .expect("time monotonic");
+
+    for mode in [
+        PriceScaleMode::Linear,
+        PriceScaleMode::Log,
+        PriceScaleMode::Percentage,
+        PriceScaleMode::IndexedTo100,
+    ] {
+        for inverted in [false, true] {
+            let price = PriceScale::new_with_mode(12.0, 480.0, mode)
+                .expect("price scale")
+                .with_inverted(inverted);
+            let tolerance = 1e-9 * value_magnitude(&price);
+            check_round_trip(&price, viewport, 97.25, tolerance).expect("price round trip");
+            check_monotonic(&price, viewport, 40.0, 41.0, tolerance).expect("price monotonic");
+        }
+    }
+}
+
+#[test]
+fn time_scale_satisfies_navigation_invariants() {
+    let time = TimeScale::new(-500.0, 1500.0).expect("time scale");
+    let tolerance = 1e-9 * value_magnitude(&time);
+    check_zoom_inverse(&time, 3.7, 200.0, tolerance).expect("zoom inverse");
+    check_zoom_inverse(&time, 0.25, -500.0, tolerance).expect("zoom-out inverse");
+    check_pan_accumulation(&time, &[10.0, -3.5, 250.0, -0.125, 77.0], tolerance)
+        .expect("pan accumulation");
+}
+
+#[test]
+fn violations_in_custom_scales_are_reported() {
+    let viewport = Viewport::new(800, 600);
+    let scale = BiasedScale {
+        start: 0.0,
+        end: 100.0,
+        bias: 0.5,
+    };
+
+    let round_trip = check_round_trip(&scale, viewport, 50.0, 1e-6).expect_err("biased inverse");
+    assert!(round_trip.to_string().contains("round trip drifted"));
+    assert!(check_monotonic(&scale, viewport, 10.0, 20.0, 1e-6).is_ok());
+    assert!(check_zoom_inverse(&scale, 2.0, 50.0, 1e-6).is_err());
+    let pan = check_pan_accumulation(&scale, &[1.0, 2.0, 3.0], 1e-6).expect_err("span drifts");
+    assert!(pan.to_string().contains("accumulated pans"));
+}
@@ -0,0 +1,29 @@
+#![cfg(feature = "testing-proptest")]
+
+use chart_rs::testing::scale_props::{
+    ProptestConfig, check_navigation_properties, check_projection_properties, price_scale_strategy,
+    time_scale_strategy,
+};
+
+fn config() -> ProptestConfig {
+    ProptestConfig {
+        cases: 128,
+        failure_persistence: None,
+        ..ProptestConfig::default()
+    }
+}
+
+#[test]
+fn time_scale_projection_properties_hold() {
+    check_projection_properties(time_scale_strategy(), config()).expect("time projection");
+}
+
+#[test]
+fn price_scale_projection_properties_hold() {
+    check_projection_properties(price_scale_strategy(), config()).expect("price projection");
+}
+
+#[test]
+fn time_scale_navigation_properties_hold() {
+    check_navigation_properties(time_scale_strategy(), config()).expect("time navigation");
+}
@@ -0,0 +1,137 @@
+use chart_rs::api::{ChartEngine, ChartEngineConfig};
+use chart_rs::core::Viewport;
+use chart_rs::render::NullRenderer;
+use chart_rs::testing::trace::{
+    InteractionAction, InteractionScenario, InteractionStep, InteractionTrace, TracePoint,
+    TracePriceRange, TraceTimeRange, TraceViewport, replay,
+};
+
+fn load_fixture() -> InteractionTrace {
+    let path = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/fixtures/lightweight_differential/interaction_zoom_pan_kinetic_crosshair_trace.json"
+    );
+    let raw = std::fs::read_to_string(path).expect("trace fixture exists");
+    InteractionTrace::from_json_str(&raw).expect("trace fixture parses")
+}
+
+fn action_only_trace() -> InteractionTrace {
+    InteractionTrace {
+        trace_name: "session".to_owned(),
+        source: "host-app".to_owned(),
+        source_notes: "recorded".to_owned(),
+        viewport: TraceViewport {
+            width: 800,
+            height: 400,
+        },
+        time_range: TraceTimeRange {
+            start: 0.0,
+            end: 100.0,
+        },
+        price_range: TracePriceRange {
+            min: 0.0,
+            max: 50.0,
+        },
+        tolerance: 1e-9,
+        scenarios: vec![InteractionScenario {
+            id: "zoom-then-hover".to_owned(),
+            points: (0..=10)
+                .map(|i| TracePoint {
+                    time: f64::from(i) * 10.0,
+                    value: 10.0 + f64::from(i),
+                })
+                .collect(),
+            steps: [
+                InteractionAction::WheelZoom {
+                    wheel_delta_y: -120.0,
+                    anchor_px: 400.0,
+                    zoom_step_ratio: 0.2,
+                    min_span_absolute: 1e-6,
+                },
+                InteractionAction::WheelPan {
+                    wheel_delta_x: 120.0,
+                    pan_step_ratio: 0.1,
+                },
+                InteractionAction::PointerMove { x: 300.0, y: 150.0 },
+            ]
+            .into_iter()
+            .map(|action| InteractionStep {
+                action,
+                expect: None,
+                elapsed_ms: None,
+            })
+            .collect(),
+        }],
+    }
+}
+
+#[test]
+fn lightweight_fixture_replays_without_mismatches() {
+    let trace = load_fixture();
+    let report = trace.replay().expect("replay");
+    assert_eq!(report.scenarios, trace.scenarios.len());
+    assert!(report.steps > 0);
+    assert!(report.is_match(), "{:#?}", report.mismatches);
+}
+
+#[test]
+fn replay_reports_field_level_mismatches() {
+    let mut trace = load_fixture();
+    let step = &mut trace.scenarios[0].steps[1];
+    let expect = step.expect.as_mut().expect("fixture step has expectation");
+    expect.visible_start = expect.visible_start.map(|value| value + 1.0);
+
+    let report = trace.replay().expect("replay");
+    assert_eq!(report.mismatches.len(), 1);
+    let mismatch = &report.mismatches[0];
+    assert_eq!(mismatch.scenario_id, trace.scenarios[0].id);
+    assert_eq!(mismatch.step_index, 1);
+    assert_eq!(mismatch.field, "visible_start");
+}
+
+#[test]
+fn recorded_expectations_survive_json_round_trip_and_replay() {
+    let mut trace = action_only_trace();
+    trace.record_expectations().expect("record");
+    assert!(
+        trace.scenarios[0]
+            .steps
+            .iter()
+            .all(|step| step.expect.is_some())
+    );
+
+    let json = trace.to_json_pretty().expect("serialize");
+    let restored = InteractionTrace::from_json_str(&json).expect("parse");
+    assert_eq!(restored, trace);
+    assert!(restored.replay().expect("replay").is_match());
+}
+
+#[test]
+fn replay_runs_on_host_engine() {
+    let mut trace = action_only_trace();
+    trace.record_expectations().expect("record");
+
+    let config =
+        ChartEngineConfig::new(Viewport::new(800, 400), 0.0, 100.0).with_price_domain(0.0, 50.0);
+    let mut engine = ChartEngine::new(NullRenderer::default(), config).expect("engine");
+    let report = replay(&mut engine, &trace.scenarios[0], trace.tolerance).expect("replay");
+    assert_eq!(report.steps, 3);
+    assert!(report.is_match(), "{:#?}", report.mismatches);
+    assert!(engine.crosshair_state().visible);
+}
+
+#[test]
+fn invalid_action_aborts_replay_with_context() {
+    let mut trace = action_only_trace();
+    trace.scenarios[0].steps.push(InteractionStep {
+        action: InteractionAction::PinchZoom {
+            factor: f64::NAN,
+            anchor_px: 0.0,
+            min_span_absolute: 1e-6,
+        },
+        expect: None,
+        elapsed_ms: None,
+    });
+    let err = trace.replay().expect_err("nan pinch factor must fail");
+    assert!(err.to_string().contains("zoom-then-hover"));
+}
@@ -3,7 +3,7 @@ use std::sync::Arc;
 use chart_rs::ChartError;
 use chart_rs::api::{
     AxisLabelLocale, ChartEngine, ChartEngineConfig, TimeAxisLabelConfig, TimeAxisLabelPolicy,
-    TimeAxisSessionConfig, TimeAxisTimeZone,
+    TimeAxisSessionConfig, TimeAxisTimeUnit, TimeAxisTimeZone,
 };
 use chart_rs::core::Viewport;
 use chart_rs::render::{NullRenderer, TextHAlign};
@@ -28,7 +28,7 @@ fn time_axis_decimal_locale_es_uses_comma_separator() {
         .texts
         .iter()
         .filter(|label| label.h_align == TextHAlign::Center)
-        .map(|label| label.text.as_str())
+        .map(|label| label.text.as_ref())
         .collect();
 
     assert!(!time_labels.is_empty());
@@ -57,7 +57,7 @@ fn time_axis_datetime_policy_formats_utc_labels() {
         .texts
         .iter()
         .filter(|label| label.h_align == TextHAlign::Center)
-        .map(|label| label.text.as_str())
+        .map(|label| label.text.as_ref())
         .collect();
 
     assert!(!time_labels.is_empty());
@@ -81,7 +81,7 @@ fn custom_time_label_formatter_overrides_policy() {
         .texts
         .iter()
         .filter(|label| label.h_align == TextHAlign::Center)
-        .map(|label| label.text.as_str())
+        .map(|label| label.text.as_ref())
         .collect();
 
     assert!(!time_labels.is_empty());
@@ -112,7 +112,7 @@ fn utc_adaptive_policy_changes_label_detail_with_zoom() {
         .texts
         .iter()
         .filter(|label| label.h_align == TextHAlign::Center)
-        .map(|label| label.text.as_str())
+        .map(|label| label.text.as_ref())
         .collect();
     assert!(!wide_time_labels.is_empty());
     assert!(wide_time_labels.iter().all(|text| !text.contains(':')));
@@ -125,7 +125,7 @@ fn utc_adaptive_policy_changes_label_detail_with_zoom() {
         .texts
         .iter()
         .filter(|label| label.h_align == TextHAlign::Center)
-        .map(|label| label.text.as_str())
+        .map(|label| label.text.as_ref())
         .collect();
     assert!(!narrow_time_labels.is_empty());
     assert!(
@@ -148,6 +148,8 @@ fn utc_adaptive_policy_keeps_mixed_date_context_and_time_only_ticks_near_day_bou
             policy: TimeAxisLabelPolicy::UtcAdaptive,
             timezone: TimeAxisTimeZone::Utc,
             session: None,
+            time_unit: TimeAxisTimeUnit::Seconds,
+            anchor_ticks_to_nice_steps: false,
         })
         .expect("set adaptive policy");
 
@@ -156,7 +158,7 @@ fn utc_adaptive_policy_keeps_mixed_date_context_and_time_only_ticks_near_day_bou
         .texts
         .iter()
         .filter(|label| label.h_align == TextHAlign::Center)
-        .map(|label| label.text.as_str())
+        .map(|label| label.text.as_ref())
         .collect();
 
     assert!(!time_labels.is_empty());
@@ -230,6 +232,8 @@ fn utc_datetime_timezone_offset_aligns_label_output() {
             },
             timezone: TimeAxisTimeZone::FixedOffsetMinutes { minutes: -300 },
             session: None,
+            time_unit: TimeAxisTimeUnit::Seconds,
+            anchor_ticks_to_nice_steps: false,
         })
         .expect("set timezone policy");
 
@@ -238,7 +242,7 @@ fn utc_datetime_timezone_offset_aligns_label_output() {
         .texts
         .iter()
         .filter(|label| label.h_align == TextHAlign::Center)
-        .map(|label| label.text.as_str())
+        .map(|label| label.text.as_ref())
         .collect();
 
     assert!(!time_labels.is_empty());
@@ -269,6 +273,8 @@ fn session_boundary_keeps_date_context_while_intraday_labels_collapse_to_time()
                 end_hour: 16,
                 end_minute: 0,
             }),
+            time_unit: TimeAxisTimeUnit::Seconds,
+            anchor_ticks_to_nice_steps: false,
         })
         .expect("set session policy");
 
@@ -277,7 +283,7 @@ fn session_boundary_keeps_date_context_while_intraday_labels_collapse_to_time()
         .texts
         .iter()
         .filter(|label| label.h_align == TextHAlign::Center)
-        .map(|label| label.text.as_str())
+        .map(|label| label.text.as_ref())
         .collect();
 
     assert!(!time_labels.is_empty());
@@ -308,6 +314,8 @@ fn invalid_time_axis_timezone_offset_is_rejected() {
             },
             timezone: TimeAxisTimeZone::FixedOffsetMinutes { minutes: 960 },
             session: None,
+            time_unit: TimeAxisTimeUnit::Seconds,
+            anchor_ticks_to_nice_steps: false,
         })
         .expect_err("timezone should fail");
     assert!(matches!(err, ChartError::InvalidData(_)));
@@ -331,6 +339,8 @@ fn invalid_time_axis_session_is_rejected() {
                 end_hour: 9,
                 end_minute: 30,
             }),
+            time_unit: TimeAxisTimeUnit::Seconds,
+            anchor_ticks_to_nice_steps: false,
         })
         .expect_err("session should fail");
     assert!(matches!(err, ChartError::InvalidData(_)));
@@ -0,0 +1,82 @@
+use std::sync::Arc;
+
+use chart_rs::ChartError;
+use chart_rs::api::{ChartEngine, ChartEngineConfig, RenderStyle};
+use chart_rs::core::Viewport;
+use chart_rs::render::{NullRenderer, TextHAlign, TextPrimitive};
+
+fn crowded_engine(rotation_degrees: f64) -> ChartEngine<NullRenderer> {
+    let config =
+        ChartEngineConfig::new(Viewport::new(800, 300), 0.0, 100.0).with_price_domain(0.0, 10.0);
+    let mut engine = ChartEngine::new(NullRenderer::default(), config).expect("engine init");
+    engine.set_time_label_formatter(Arc::new(|value| format!("session #{value:.0} close")));
+    let style = RenderStyle {
+        time_axis_label_rotation_degrees: rotation_degrees,
+        time_axis_height_px: 64.0,
+        ..engine.render_style()
+    };
+    engine.set_render_style(style).expect("set style");
+    engine
+}
+
+fn time_labels(engine: &ChartEngine<NullRenderer>) -> Vec<TextPrimitive> {
+    engine
+        .build_render_frame()
+        .expect("build frame")
+        .texts
+        .into_iter()
+        .filter(|label| label.text.starts_with("session"))
+        .collect()
+}
+
+#[test]
+fn crowded_time_labels_rotate_instead_of_dropping() {
+    let horizontal = time_labels(&crowded_engine(0.0));
+    let rotated = time_labels(&crowded_engine(45.0));
+    assert!(horizontal.iter().all(|label| !label.is_rotated()));
+    assert!(rotated.len() > horizontal.len());
+    for label in &rotated {
+        assert!((label.rotation_radians + 45f64.to_radians()).abs() <= 1e-12);
+        assert_eq!(label.h_align, TextHAlign::Right);
+        assert!(label.x >= 0.0 && label.x <= 800.0);
+    }
+}
+
+#[test]
+fn uncrowded_time_labels_stay_horizontal_with_rotation_configured() {
+    let config =
+        ChartEngineConfig::new(Viewport::new(640, 300), 10.0, 20.0).with_price_domain(0.0, 10.0);
+    let mut engine = ChartEngine::new(NullRenderer::default(), config).expect("engine init");
+    engine.set_time_label_formatter(Arc::new(|value| format!("t={value:.0}")));
+    let style = RenderStyle {
+        time_axis_label_rotation_degrees: 45.0,
+        ..engine.render_style()
+    };
+    engine.set_render_style(style).expect("set style");
+
+    let frame = engine.build_render_frame().expect("build frame");
+    let labels: Vec<&TextPrimitive> = frame
+        .texts
+        .iter()
+        .filter(|label| label.text.starts_with("t="))
+        .collect();
+    assert!(!labels.is_empty());
+    assert!(labels.iter().all(|label| !label.is_rotated()));
+}
+
+#[test]
+fn time_label_rotation_outside_zero_to_ninety_is_rejected() {
+    let config =
+        ChartEngineConfig::new(Viewport::new(640, 300), 0.0, 100.0).with_price_domain(0.0, 10.0);
+    let mut engine = ChartEngine::new(NullRenderer::default(), config).expect("engine init");
+    for degrees in [-10.0, 120.0, f64::NAN] {
+        let style = RenderStyle {
+            time_axis_label_rotation_degrees: degrees,
+            ..engine.render_style()
+        };
+        let err = engine
+            .set_render_style(style)
+            .expect_err("invalid rotation");
+        assert!(matches!(err, ChartError::InvalidData(_)));
+    }
+}
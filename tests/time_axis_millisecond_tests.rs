@@ -0,0 +1,108 @@
+use chart_rs::api::{
+    ChartEngine, ChartEngineConfig, TimeAxisLabelConfig, TimeAxisLabelPolicy, TimeAxisTimeUnit,
+};
+use chart_rs::core::{ChartTime, Viewport};
+use chart_rs::render::{NullRenderer, RenderFrame, TextHAlign};
+
+const T0_MILLIS: f64 = 1_704_067_200_000.0; // 2024-01-01T00:00:00Z
+
+fn millisecond_config() -> TimeAxisLabelConfig {
+    TimeAxisLabelConfig {
+        policy: TimeAxisLabelPolicy::UtcAdaptive,
+        time_unit: TimeAxisTimeUnit::Milliseconds,
+        ..TimeAxisLabelConfig::default()
+    }
+}
+
+fn build_engine(start: f64, end: f64) -> ChartEngine<NullRenderer> {
+    let renderer = NullRenderer::default();
+    let config = ChartEngineConfig::new(Viewport::new(1000, 340), start, end)
+        .with_price_domain(0.0, 10.0)
+        .with_time_axis_label_config(millisecond_config());
+    ChartEngine::new(renderer, config).expect("engine init")
+}
+
+fn time_labels(frame: &RenderFrame) -> Vec<String> {
+    frame
+        .texts
+        .iter()
+        .filter(|label| label.h_align == TextHAlign::Center)
+        .map(|label| label.text.to_string())
+        .collect()
+}
+
+#[test]
+fn sub_second_spans_use_millisecond_labels() {
+    let mut engine = build_engine(T0_MILLIS + 1_000.0, T0_MILLIS + 2_000.0);
+    let frame = engine.build_render_frame().expect("frame");
+    let labels = time_labels(&frame);
+
+    assert!(labels.len() >= 2, "{labels:?}");
+    assert!(
+        labels
+            .iter()
+            .all(|label| label.starts_with("00:00:01.") && label.len() == 12),
+        "{labels:?}"
+    );
+    let mut unique = labels.clone();
+    unique.dedup();
+    assert_eq!(unique.len(), labels.len());
+
+    engine
+        .set_time_visible_range(T0_MILLIS, T0_MILLIS + 3_600_000.0)
+        .expect("range");
+    let frame = engine.build_render_frame().expect("frame");
+    assert!(
+        time_labels(&frame).iter().all(|label| !label.contains('.')),
+        "{:?}",
+        time_labels(&frame)
+    );
+}
+
+#[test]
+fn crosshair_time_label_keeps_milliseconds() {
+    let mut engine = build_engine(T0_MILLIS, T0_MILLIS + 5_000.0);
+    engine.pointer_move(500.0, 100.0);
+    let frame = engine.build_render_frame().expect("frame");
+
+    assert!(
+        frame
+            .texts
+            .iter()
+            .any(|label| &*label.text == "2024-01-01 00:00:02.500"),
+        "{:?}",
+        time_labels(&frame)
+    );
+}
+
+#[test]
+fn seconds_unit_keeps_whole_second_labels() {
+    let renderer = NullRenderer::default();
+    let config = ChartEngineConfig::new(Viewport::new(1000, 340), 1_704_067_201.0, 1_704_067_202.0)
+        .with_price_domain(0.0, 10.0);
+    let engine = ChartEngine::new(renderer, config).expect("engine init");
+    let frame = engine.build_render_frame().expect("frame");
+
+    assert!(time_labels(&frame).iter().all(|label| !label.contains('.')));
+}
+
+#[test]
+fn chart_times_are_normalized_into_the_configured_unit() {
+    let mut engine = build_engine(T0_MILLIS, T0_MILLIS + 86_400_000.0);
+    engine
+        .set_timed_data(vec![
+            (ChartTime::UnixMillis(T0_MILLIS + 1.0), 1.0),
+            (ChartTime::UnixSeconds(1_704_067_201.5), 2.0),
+        ])
+        .expect("timed data");
+    assert_eq!(engine.points()[0].x, T0_MILLIS + 1.0);
+    assert_eq!(engine.points()[1].x, T0_MILLIS + 1_500.0);
+
+    engine
+        .set_timed_candles(vec![(
+            ChartTime::business_day(2024, 1, 2).expect("day"),
+            [1.0, 2.0, 0.5, 1.5],
+        )])
+        .expect("timed candles");
+    assert_eq!(engine.candles()[0].time, T0_MILLIS + 86_400_000.0);
+}
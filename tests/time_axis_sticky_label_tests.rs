@@ -0,0 +1,141 @@
+use chart_rs::api::{
+    ChartEngine, ChartEngineConfig, RenderStyle, TimeAxisLabelConfig, TimeAxisLabelPolicy,
+};
+use chart_rs::core::{OhlcBar, Viewport};
+use chart_rs::render::{NullRenderer, RenderFrame, TextHAlign, TextPrimitive};
+
+const DAY_START: f64 = 1_704_067_200.0; // 2024-01-01T00:00:00Z
+const HOUR: f64 = 3_600.0;
+
+fn build_engine() -> ChartEngine<NullRenderer> {
+    let renderer = NullRenderer::default();
+    let config =
+        ChartEngineConfig::new(Viewport::new(900, 400), DAY_START, DAY_START + 72.0 * HOUR)
+            .with_price_domain(0.0, 100.0);
+    let mut engine = ChartEngine::new(renderer, config).expect("engine init");
+    let candles = (0..72)
+        .map(|hour| {
+            OhlcBar::new(DAY_START + f64::from(hour) * HOUR, 50.0, 60.0, 40.0, 55.0).expect("bar")
+        })
+        .collect();
+    engine.set_candles(candles);
+    engine
+        .set_render_style(RenderStyle {
+            show_time_axis_sticky_date_label: true,
+            ..engine.render_style()
+        })
+        .expect("set style");
+    engine
+}
+
+fn sticky_label(frame: &RenderFrame) -> Option<&TextPrimitive> {
+    frame
+        .texts
+        .iter()
+        .find(|text| text.h_align == TextHAlign::Left && text.x == 4.0)
+}
+
+#[test]
+fn sticky_date_label_is_opt_in() {
+    assert!(!RenderStyle::default().show_time_axis_sticky_date_label);
+    let mut engine = build_engine();
+    engine
+        .set_render_style(RenderStyle {
+            show_time_axis_sticky_date_label: false,
+            ..engine.render_style()
+        })
+        .expect("set style");
+    let frame = engine.build_render_frame().expect("frame");
+    assert!(sticky_label(&frame).is_none());
+}
+
+#[test]
+fn sticky_date_label_tracks_left_most_visible_bar_while_panning() {
+    let mut engine = build_engine();
+    engine
+        .set_time_visible_range(DAY_START + 20.5 * HOUR, DAY_START + 26.0 * HOUR)
+        .expect("range");
+    let frame = engine.build_render_frame().expect("frame");
+    assert_eq!(
+        sticky_label(&frame).map(|label| label.text.as_ref()),
+        Some("2024-01-01")
+    );
+
+    engine
+        .set_time_visible_range(DAY_START + 24.5 * HOUR, DAY_START + 30.0 * HOUR)
+        .expect("range");
+    let frame = engine.build_render_frame().expect("frame");
+    assert_eq!(
+        sticky_label(&frame).map(|label| label.text.as_ref()),
+        Some("2024-01-02")
+    );
+}
+
+#[test]
+fn sticky_date_label_follows_appended_bars() {
+    let mut engine = build_engine();
+    // An older bar is rejected, so the binary-searched left edge cannot land
+    // on a bar out of time order.
+    let older = OhlcBar::new(DAY_START + 47.5 * HOUR, 50.0, 60.0, 40.0, 55.0).expect("bar");
+    assert!(engine.append_candle(older).is_err());
+    for hour in 72..80 {
+        let bar =
+            OhlcBar::new(DAY_START + f64::from(hour) * HOUR, 50.0, 60.0, 40.0, 55.0).expect("bar");
+        engine.append_candle(bar).expect("append");
+    }
+
+    engine
+        .set_time_visible_range(DAY_START + 47.25 * HOUR, DAY_START + 52.0 * HOUR)
+        .expect("range");
+    let frame = engine.build_render_frame().expect("frame");
+    assert_eq!(
+        sticky_label(&frame).map(|label| label.text.as_ref()),
+        Some("2024-01-03")
+    );
+
+    engine
+        .set_time_visible_range(DAY_START + 71.5 * HOUR, DAY_START + 78.0 * HOUR)
+        .expect("range");
+    let frame = engine.build_render_frame().expect("frame");
+    assert_eq!(
+        sticky_label(&frame).map(|label| label.text.as_ref()),
+        Some("2024-01-04")
+    );
+}
+
+#[test]
+fn regular_labels_do_not_overlap_sticky_label() {
+    let mut engine = build_engine();
+    engine
+        .set_time_visible_range(DAY_START + 2.0 * HOUR, DAY_START + 8.0 * HOUR)
+        .expect("range");
+    let frame = engine.build_render_frame().expect("frame");
+    let plot_bottom =
+        f64::from(engine.viewport().height) - engine.render_style().time_axis_height_px;
+    let sticky = sticky_label(&frame).expect("sticky label");
+    let sticky_right = sticky.x + sticky.text.len() as f64 * sticky.font_size_px * 0.62;
+
+    let axis_labels = frame
+        .texts
+        .iter()
+        .filter(|text| text.h_align == TextHAlign::Center && text.y >= plot_bottom)
+        .collect::<Vec<_>>();
+    assert!(!axis_labels.is_empty());
+    for label in axis_labels {
+        let half_width = label.text.len() as f64 * label.font_size_px * 0.31;
+        assert!(label.x - half_width >= sticky_right);
+    }
+}
+
+#[test]
+fn logical_time_axis_has_no_sticky_date_label() {
+    let mut engine = build_engine();
+    engine
+        .set_time_axis_label_config(TimeAxisLabelConfig {
+            policy: TimeAxisLabelPolicy::LogicalDecimal { precision: 1 },
+            ..TimeAxisLabelConfig::default()
+        })
+        .expect("label config");
+    let frame = engine.build_render_frame().expect("frame");
+    assert!(sticky_label(&frame).is_none());
+}
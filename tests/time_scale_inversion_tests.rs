@@ -0,0 +1,155 @@
+use chart_rs::api::{
+    ChartEngine, ChartEngineConfig, CrosshairMode, TimeAxisLabelConfig, TimeAxisLabelPolicy,
+    TimeCoordinateIndexPolicy,
+};
+use chart_rs::core::{DataPoint, OhlcBar, Viewport};
+use chart_rs::render::{CanvasLayerKind, NullRenderer};
+
+fn engine(inverted: bool) -> ChartEngine<NullRenderer> {
+    let config = ChartEngineConfig::new(Viewport::new(800, 400), 0.0, 100.0)
+        .with_price_domain(0.0, 50.0)
+        .with_time_scale_inverted(inverted);
+    let mut engine = ChartEngine::new(NullRenderer::default(), config).expect("engine");
+    engine.set_data(
+        (0..=100)
+            .map(|index| DataPoint::new(f64::from(index), 20.0 + f64::from(index % 9)))
+            .collect(),
+    );
+    engine
+        .set_time_visible_range(20.0, 80.0)
+        .expect("visible range");
+    engine
+}
+
+#[test]
+fn inverted_time_scale_puts_the_newest_time_on_the_left() {
+    let engine = engine(true);
+    assert!(engine.time_scale_inverted());
+    assert!(engine.map_x_to_pixel(80.0).expect("end").abs() <= 1e-9);
+    assert!((engine.map_x_to_pixel(20.0).expect("start") - 800.0).abs() <= 1e-9);
+
+    let time = engine.map_pixel_to_x(250.0).expect("time");
+    assert!((engine.map_x_to_pixel(time).expect("px") - 250.0).abs() <= 1e-9);
+}
+
+#[test]
+fn time_axis_labels_decrease_left_to_right() {
+    let mut engine = engine(true);
+    engine
+        .set_time_axis_label_config(TimeAxisLabelConfig {
+            policy: TimeAxisLabelPolicy::LogicalDecimal { precision: 0 },
+            ..engine.time_axis_label_config()
+        })
+        .expect("label config");
+    let frame = engine.build_render_frame().expect("frame");
+    let plot_bottom = 400.0 - engine.render_style().time_axis_height_px;
+    let mut labels: Vec<(f64, f64)> = frame
+        .texts
+        .iter()
+        .filter(|text| text.y >= plot_bottom)
+        .filter_map(|text| Some((text.x, text.text.parse::<f64>().ok()?)))
+        .collect();
+    labels.sort_by(|left, right| left.0.total_cmp(&right.0));
+    assert!(labels.len() >= 3, "{labels:?}");
+    assert!(
+        labels.windows(2).all(|pair| pair[1].1 < pair[0].1),
+        "{labels:?}"
+    );
+}
+
+#[test]
+fn inverted_candles_run_right_to_left() {
+    let mut engine = engine(true);
+    engine.set_candles(
+        (0..=100)
+            .map(|index| {
+                let time = f64::from(index);
+                OhlcBar::new(time, 20.0, 24.0, 18.0, 22.0).expect("bar")
+            })
+            .collect(),
+    );
+    let layered = engine.build_layered_render_frame().expect("layered");
+    let series = layered.panes[0]
+        .layers
+        .iter()
+        .find(|layer| layer.kind == CanvasLayerKind::Series)
+        .expect("series layer");
+    let xs: Vec<f64> = series.rects.iter().map(|rect| rect.x).collect();
+    assert!(xs.len() > 10);
+    assert!(xs.windows(2).all(|pair| pair[1] < pair[0]), "{xs:?}");
+}
+
+#[test]
+fn pixel_pan_follows_the_mirrored_direction() {
+    let mut plain = engine(false);
+    let mut inverted = engine(true);
+    plain.pan_time_visible_by_pixels(80.0).expect("pan");
+    inverted.pan_time_visible_by_pixels(80.0).expect("pan");
+
+    let plain_shift = plain.time_visible_range().0 - 20.0;
+    let inverted_shift = inverted.time_visible_range().0 - 20.0;
+    assert!(plain_shift < 0.0);
+    assert!((inverted_shift + plain_shift).abs() <= 1e-6);
+}
+
+#[test]
+fn zoom_keeps_the_time_under_the_anchor() {
+    let mut engine = engine(true);
+    let anchor_px = 200.0;
+    let before = engine.map_pixel_to_x(anchor_px).expect("before");
+    engine
+        .zoom_time_visible_around_pixel(1.5, anchor_px, 1e-6)
+        .expect("zoom");
+    let after = engine.map_pixel_to_x(anchor_px).expect("after");
+    assert!((before - after).abs() <= 1e-6, "{before} vs {after}");
+}
+
+#[test]
+fn logical_index_mapping_is_mirrored() {
+    let plain = engine(false);
+    let inverted = engine(true);
+    let plain_px = plain
+        .map_logical_index_to_pixel(50.0)
+        .expect("map")
+        .expect("space");
+    let inverted_px = inverted
+        .map_logical_index_to_pixel(50.0)
+        .expect("map")
+        .expect("space");
+    assert!((plain_px + inverted_px - 800.0).abs() <= 1e-9);
+
+    let index = inverted
+        .map_pixel_to_logical_index(inverted_px, TimeCoordinateIndexPolicy::AllowWhitespace)
+        .expect("map")
+        .expect("space");
+    let plain_index = plain
+        .map_pixel_to_logical_index(plain_px, TimeCoordinateIndexPolicy::AllowWhitespace)
+        .expect("map")
+        .expect("space");
+    assert!((index - plain_index).abs() <= 1e-6);
+}
+
+#[test]
+fn magnet_crosshair_snaps_to_the_point_under_the_pointer() {
+    let mut engine = engine(true);
+    engine.set_crosshair_mode(CrosshairMode::Magnet);
+    let x = engine.map_x_to_pixel(70.0).expect("px");
+    engine.pointer_move(x + 1.0, 200.0);
+    let crosshair = engine.crosshair_state();
+    assert_eq!(crosshair.snapped_time, Some(70.0));
+    assert!((crosshair.snapped_x.expect("snapped x") - x).abs() <= 1e-9);
+}
+
+#[test]
+fn inversion_survives_scene_round_trip_and_refits() {
+    let mut engine = engine(false);
+    engine.set_time_scale_inverted(true);
+    let scene = engine.export_scene();
+
+    let mut restored = self::engine(false);
+    restored.import_scene(scene).expect("import");
+    assert!(restored.time_scale_inverted());
+
+    restored.fit_time_to_data(Default::default()).expect("fit");
+    assert!(restored.time_scale_inverted());
+}
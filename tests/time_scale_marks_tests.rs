@@ -0,0 +1,148 @@
+use chart_rs::ChartError;
+use chart_rs::api::{ChartEngine, ChartEngineConfig, TimeScaleMark, TimeScaleMarkBehavior};
+use chart_rs::core::Viewport;
+use chart_rs::render::{CanvasLayerKind, NullRenderer, RectPrimitive};
+
+fn engine() -> ChartEngine<NullRenderer> {
+    let config = ChartEngineConfig::new(Viewport::new(1000, 500), 0.0, 100.0);
+    let mut engine = ChartEngine::new(NullRenderer::default(), config).expect("engine");
+    engine
+        .set_time_scale_marks(vec![
+            TimeScaleMark::new("dividend", 50.0, "D").with_tooltip("Dividend 0.24"),
+            TimeScaleMark::new("earnings", 20.0, "E").with_tooltip("Q3 earnings"),
+            TimeScaleMark::new("offscreen", 500.0, "S"),
+        ])
+        .expect("marks");
+    engine
+}
+
+fn badges(engine: &ChartEngine<NullRenderer>) -> Vec<RectPrimitive> {
+    let radius = engine.time_scale_mark_behavior().size_px * 0.5;
+    let layered = engine.build_layered_render_frame().expect("layered");
+    layered.panes[0]
+        .layers
+        .iter()
+        .find(|layer| layer.kind == CanvasLayerKind::Axis)
+        .expect("axis layer")
+        .rects
+        .iter()
+        .filter(|rect| rect.corner_radius == radius)
+        .copied()
+        .collect()
+}
+
+#[test]
+fn marks_are_sorted_and_drawn_as_badges_in_time_axis_panel() {
+    let engine = engine();
+    let ids: Vec<_> = engine
+        .time_scale_marks()
+        .iter()
+        .map(|mark| mark.id.as_str())
+        .collect();
+    assert_eq!(ids, ["earnings", "dividend", "offscreen"]);
+
+    let behavior = engine.time_scale_mark_behavior();
+    let badges = badges(&engine);
+    assert_eq!(badges.len(), 2);
+    let expected_x = engine.map_x_to_pixel(20.0).expect("pixel") - behavior.size_px * 0.5;
+    assert!((badges[0].x - expected_x).abs() <= 1e-9);
+    assert_eq!(
+        badges[0].y + badges[0].height,
+        500.0 - behavior.bottom_padding_px
+    );
+    let plot_bottom = 500.0 - engine.render_style().time_axis_height_px;
+    assert!(badges.iter().all(|badge| {
+        let clip = badge.clip_rect.expect("clip");
+        clip.y == plot_bottom && badge.y >= plot_bottom
+    }));
+
+    let frame = engine.build_render_frame().expect("frame");
+    assert!(frame.texts.iter().any(|text| &*text.text == "E"));
+    assert!(!frame.texts.iter().any(|text| &*text.text == "S"));
+}
+
+#[test]
+fn hit_testing_resolves_tooltips() {
+    let engine = engine();
+    let behavior = engine.time_scale_mark_behavior();
+    let y = 500.0 - behavior.bottom_padding_px - behavior.size_px * 0.5;
+    let x = engine.map_x_to_pixel(50.0).expect("pixel");
+
+    let mark = engine.time_scale_mark_at(x + 3.0, y - 3.0).expect("hit");
+    assert_eq!(mark.id, "dividend");
+    assert_eq!(mark.tooltip.as_deref(), Some("Dividend 0.24"));
+    assert!(engine.time_scale_mark_at(x, y - behavior.size_px).is_none());
+    assert!(engine.time_scale_mark_at(x + behavior.size_px, y).is_none());
+}
+
+#[test]
+fn overlapping_marks_hit_the_last_drawn() {
+    let mut engine = engine();
+    engine
+        .add_time_scale_mark(TimeScaleMark::new("split", 50.0, "S").with_tooltip("2:1 split"))
+        .expect("add");
+    let behavior = engine.time_scale_mark_behavior();
+    let y = 500.0 - behavior.bottom_padding_px - behavior.size_px * 0.5;
+    let x = engine.map_x_to_pixel(50.0).expect("pixel");
+    assert_eq!(engine.time_scale_mark_at(x, y).expect("hit").id, "split");
+
+    assert!(engine.remove_time_scale_mark("split"));
+    assert!(!engine.remove_time_scale_mark("split"));
+    assert_eq!(engine.time_scale_mark_at(x, y).expect("hit").id, "dividend");
+}
+
+#[test]
+fn hidden_marks_neither_draw_nor_hit() {
+    let mut engine = engine();
+    engine
+        .set_time_scale_mark_behavior(TimeScaleMarkBehavior {
+            visible: false,
+            ..TimeScaleMarkBehavior::default()
+        })
+        .expect("behavior");
+    assert!(badges(&engine).is_empty());
+    let x = engine.map_x_to_pixel(50.0).expect("pixel");
+    assert!(engine.time_scale_mark_at(x, 491.0).is_none());
+}
+
+#[test]
+fn invalid_input_is_rejected_and_scene_keeps_marks() {
+    let mut engine = engine();
+    let err = engine
+        .add_time_scale_mark(TimeScaleMark::new("bad", f64::NAN, "B"))
+        .expect_err("nan time");
+    assert!(matches!(err, ChartError::InvalidData(_)));
+    assert!(
+        engine
+            .set_time_scale_mark_behavior(TimeScaleMarkBehavior {
+                size_px: 0.0,
+                ..TimeScaleMarkBehavior::default()
+            })
+            .is_err()
+    );
+    let behavior = TimeScaleMarkBehavior {
+        size_px: 18.0,
+        ..TimeScaleMarkBehavior::default()
+    };
+    engine
+        .set_time_scale_mark_behavior(behavior)
+        .expect("behavior");
+
+    let json = engine.export_scene_json_pretty().expect("export");
+    let mut restored = ChartEngine::new(
+        NullRenderer::default(),
+        ChartEngineConfig::new(Viewport::new(1000, 500), 0.0, 1.0),
+    )
+    .expect("engine");
+    restored.import_scene_json_str(&json).expect("import");
+    assert_eq!(restored.time_scale_marks(), engine.time_scale_marks());
+    assert_eq!(restored.time_scale_mark_behavior(), behavior);
+
+    let configured = ChartEngine::new(
+        NullRenderer::default(),
+        ChartEngineConfig::new(Viewport::new(1000, 500), 0.0, 1.0)
+            .with_time_scale_mark_behavior(behavior),
+    )
+    .expect("engine");
+    assert_eq!(configured.time_scale_mark_behavior(), behavior);
+}
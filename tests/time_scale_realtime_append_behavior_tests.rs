@@ -39,7 +39,9 @@ fn default_realtime_append_behavior_contract() {
 fn default_behavior_tracks_right_edge_when_at_tail() {
     let mut engine = build_engine();
 
-    engine.append_point(DataPoint::new(101.0, 1.0));
+    engine
+        .append_point(DataPoint::new(101.0, 1.0))
+        .expect("append");
 
     let (full_start, full_end) = engine.time_full_range();
     let (visible_start, visible_end) = engine.time_visible_range();
@@ -58,7 +60,9 @@ fn append_does_not_shift_when_viewport_is_away_from_tail_beyond_tolerance() {
         .set_time_visible_range(0.0, 90.0)
         .expect("set visible range");
 
-    engine.append_point(DataPoint::new(110.0, 1.0));
+    engine
+        .append_point(DataPoint::new(110.0, 1.0))
+        .expect("append");
 
     let (_, full_end) = engine.time_full_range();
     let (visible_start, visible_end) = engine.time_visible_range();
@@ -75,7 +79,9 @@ fn append_shifts_continuously_when_within_right_edge_tolerance() {
         .set_time_visible_range(0.0, 93.0)
         .expect("set visible range");
 
-    engine.append_point(DataPoint::new(110.0, 1.0));
+    engine
+        .append_point(DataPoint::new(110.0, 1.0))
+        .expect("append");
 
     let (visible_start, visible_end) = engine.time_visible_range();
     assert!((visible_start - 10.0).abs() <= 1e-9);
@@ -92,7 +98,9 @@ fn disable_preserve_right_edge_keeps_visible_range_fixed() {
         })
         .expect("set realtime append behavior");
 
-    engine.append_point(DataPoint::new(110.0, 1.0));
+    engine
+        .append_point(DataPoint::new(110.0, 1.0))
+        .expect("append");
 
     let (visible_start, visible_end) = engine.time_visible_range();
     assert!((visible_start - 0.0).abs() <= 1e-9);
@@ -111,7 +119,9 @@ fn append_composes_with_right_offset_navigation() {
         .expect("set navigation behavior");
 
     let (_, end_before) = engine.time_visible_range();
-    engine.append_point(DataPoint::new(110.0, 1.0));
+    engine
+        .append_point(DataPoint::new(110.0, 1.0))
+        .expect("append");
     let (_, end_after) = engine.time_visible_range();
 
     assert!((end_before - 120.0).abs() <= 1e-9);
@@ -129,7 +139,9 @@ fn append_composes_with_navigation_spacing_policy() {
         })
         .expect("set navigation behavior");
 
-    engine.append_point(DataPoint::new(110.0, 1.0));
+    engine
+        .append_point(DataPoint::new(110.0, 1.0))
+        .expect("append");
 
     let (_, full_end) = engine.time_full_range();
     let (start_after, end_after) = engine.time_visible_range();
@@ -143,7 +155,7 @@ fn append_candle_uses_same_realtime_policy() {
     let mut engine = build_engine();
     let candle = OhlcBar::new(110.0, 1.0, 2.0, 0.5, 1.5).expect("valid candle");
 
-    engine.append_candle(candle);
+    engine.append_candle(candle).expect("append");
 
     let (_, full_end) = engine.time_full_range();
     let (visible_start, visible_end) = engine.time_visible_range();
@@ -170,7 +182,9 @@ fn realtime_append_with_right_edge_tracking_registers_apply_right_offset_invalid
     engine.set_data(seed_points());
     engine.clear_pending_invalidation();
 
-    engine.append_point(DataPoint::new(110.0, 1.0));
+    engine
+        .append_point(DataPoint::new(110.0, 1.0))
+        .expect("append");
 
     let pending = engine
         .lwc_pending_invalidation()
@@ -198,7 +212,9 @@ fn realtime_append_without_right_edge_tracking_does_not_emit_time_scale_invalida
     engine.set_data(seed_points());
     engine.clear_pending_invalidation();
 
-    engine.append_point(DataPoint::new(110.0, 1.0));
+    engine
+        .append_point(DataPoint::new(110.0, 1.0))
+        .expect("append");
 
     let pending = engine
         .lwc_pending_invalidation()
@@ -1,6 +1,7 @@
 use chart_rs::ChartError;
 use chart_rs::api::{
-    ChartEngine, ChartEngineConfig, TimeScaleNavigationBehavior, TimeScaleZoomLimitBehavior,
+    ChartAxis, ChartEngine, ChartEngineConfig, TimeScaleEdgeBehavior, TimeScaleNavigationBehavior,
+    TimeScaleZoomLimitBehavior,
 };
 use chart_rs::core::{DataPoint, TimeScaleTuning, Viewport};
 use chart_rs::render::NullRenderer;
@@ -49,6 +50,7 @@ fn invalid_zoom_limit_behavior_is_rejected() {
         .set_time_scale_zoom_limit_behavior(TimeScaleZoomLimitBehavior {
             min_bar_spacing_px: 0.0,
             max_bar_spacing_px: None,
+            max_visible_span_ratio: None,
         })
         .expect_err("zero min spacing must fail");
     assert!(matches!(err, ChartError::InvalidData(_)));
@@ -57,6 +59,7 @@ fn invalid_zoom_limit_behavior_is_rejected() {
         .set_time_scale_zoom_limit_behavior(TimeScaleZoomLimitBehavior {
             min_bar_spacing_px: 5.0,
             max_bar_spacing_px: Some(4.0),
+            max_visible_span_ratio: None,
         })
         .expect_err("max spacing below min must fail");
     assert!(matches!(err, ChartError::InvalidData(_)));
@@ -69,6 +72,7 @@ fn min_spacing_clamps_zoom_out_span() {
         .set_time_scale_zoom_limit_behavior(TimeScaleZoomLimitBehavior {
             min_bar_spacing_px: 20.0,
             max_bar_spacing_px: None,
+            max_visible_span_ratio: None,
         })
         .expect("set zoom limits");
 
@@ -87,6 +91,7 @@ fn max_spacing_clamps_zoom_in_span() {
         .set_time_scale_zoom_limit_behavior(TimeScaleZoomLimitBehavior {
             min_bar_spacing_px: 0.5,
             max_bar_spacing_px: Some(40.0),
+            max_visible_span_ratio: None,
         })
         .expect("set zoom limits");
 
@@ -106,6 +111,7 @@ fn navigation_spacing_below_min_is_clamped_with_right_edge_preserved() {
         .set_time_scale_zoom_limit_behavior(TimeScaleZoomLimitBehavior {
             min_bar_spacing_px: 20.0,
             max_bar_spacing_px: None,
+            max_visible_span_ratio: None,
         })
         .expect("set zoom limits");
     engine
@@ -131,6 +137,7 @@ fn zoom_limit_clamp_preserves_right_offset_pixels_priority() {
         .set_time_scale_zoom_limit_behavior(TimeScaleZoomLimitBehavior {
             min_bar_spacing_px: 20.0,
             max_bar_spacing_px: None,
+            max_visible_span_ratio: None,
         })
         .expect("set zoom limits");
 
@@ -141,3 +148,131 @@ fn zoom_limit_clamp_preserves_right_offset_pixels_priority() {
     assert!((span - 50.0).abs() <= 1e-9);
     assert!(((end - full_end) - expected_offset).abs() <= 1e-9);
 }
+
+fn prepare_span_limited_engine(ratio: f64) -> ChartEngine<NullRenderer> {
+    let mut engine = prepare_fitted_engine();
+    engine
+        .set_time_scale_zoom_limit_behavior(TimeScaleZoomLimitBehavior {
+            max_visible_span_ratio: Some(ratio),
+            ..TimeScaleZoomLimitBehavior::default()
+        })
+        .expect("set zoom limits");
+    engine
+}
+
+fn full_span(engine: &ChartEngine<NullRenderer>) -> f64 {
+    let (full_start, full_end) = engine.time_full_range();
+    full_end - full_start
+}
+
+fn visible_span(engine: &ChartEngine<NullRenderer>) -> f64 {
+    let (start, end) = engine.time_visible_range();
+    end - start
+}
+
+#[test]
+fn max_visible_span_ratio_caps_every_zoom_out_path() {
+    let mut engine = prepare_span_limited_engine(0.5);
+    let cap = full_span(&engine) * 0.5;
+    assert!(visible_span(&engine) <= cap + 1e-9);
+
+    engine
+        .set_time_visible_range(-1000.0, 1000.0)
+        .expect("set visible range");
+    assert!((visible_span(&engine) - cap).abs() <= 1e-9);
+
+    engine.set_time_visible_range(100.0, 110.0).expect("range");
+    for _ in 0..20 {
+        engine
+            .wheel_zoom_time_visible(240.0, 500.0, 0.2, 1.0)
+            .expect("wheel zoom");
+    }
+    assert!((visible_span(&engine) - cap).abs() <= 1e-9);
+
+    engine.set_time_visible_range(100.0, 110.0).expect("range");
+    for _ in 0..20 {
+        engine
+            .pinch_zoom_time_visible(0.5, 500.0, 1.0)
+            .expect("pinch zoom");
+    }
+    assert!((visible_span(&engine) - cap).abs() <= 1e-9);
+
+    engine.set_time_visible_range(100.0, 110.0).expect("range");
+    for _ in 0..20 {
+        engine
+            .handle_axis_drag(ChartAxis::Time, -400.0, 500.0)
+            .expect("axis drag");
+    }
+    assert!((visible_span(&engine) - cap).abs() <= 1e-9);
+}
+
+#[test]
+fn max_visible_span_ratio_never_undercuts_max_bar_spacing() {
+    let mut engine = prepare_fitted_engine();
+    engine
+        .set_time_scale_zoom_limit_behavior(TimeScaleZoomLimitBehavior {
+            min_bar_spacing_px: 0.5,
+            max_bar_spacing_px: Some(40.0),
+            max_visible_span_ratio: Some(0.01),
+        })
+        .expect("set zoom limits");
+    engine
+        .set_time_visible_range(0.0, 1000.0)
+        .expect("set visible range");
+    assert!((visible_span(&engine) - 25.0).abs() <= 1e-9);
+}
+
+#[test]
+fn invalid_max_visible_span_ratio_is_rejected() {
+    let mut engine = prepare_fitted_engine();
+    for ratio in [0.0, -0.5, f64::NAN, f64::INFINITY] {
+        let err = engine
+            .set_time_scale_zoom_limit_behavior(TimeScaleZoomLimitBehavior {
+                max_visible_span_ratio: Some(ratio),
+                ..TimeScaleZoomLimitBehavior::default()
+            })
+            .expect_err("invalid ratio must fail");
+        assert!(matches!(err, ChartError::InvalidData(_)));
+    }
+    assert_eq!(
+        engine.time_scale_zoom_limit_behavior(),
+        TimeScaleZoomLimitBehavior::default()
+    );
+}
+
+#[test]
+fn locked_to_data_bounds_keeps_pans_and_zooms_inside_full_range() {
+    let config = ChartEngineConfig::new(Viewport::new(1000, 500), 0.0, 100.0)
+        .with_price_domain(0.0, 1.0)
+        .with_time_scale_edge_behavior(TimeScaleEdgeBehavior::locked_to_data_bounds())
+        .with_time_scale_zoom_limit_behavior(TimeScaleZoomLimitBehavior {
+            max_visible_span_ratio: Some(1.0),
+            ..TimeScaleZoomLimitBehavior::default()
+        });
+    let mut engine = ChartEngine::new(NullRenderer::default(), config).expect("engine init");
+    engine.set_data(seed_points());
+    engine
+        .fit_time_to_data(TimeScaleTuning::default())
+        .expect("fit time");
+    assert!(engine.time_scale_edge_behavior().is_locked_to_data_bounds());
+
+    let (full_start, full_end) = engine.time_full_range();
+    engine.set_time_visible_range(100.0, 150.0).expect("range");
+    engine.pan_time_visible_by(-1000.0).expect("pan left");
+    let (start, end) = engine.time_visible_range();
+    assert!((start - full_start).abs() <= 1e-9);
+    assert!((end - start - 50.0).abs() <= 1e-9);
+
+    engine.pan_time_visible_by(1000.0).expect("pan right");
+    let (start, end) = engine.time_visible_range();
+    assert!((end - full_end).abs() <= 1e-9);
+    assert!((end - start - 50.0).abs() <= 1e-9);
+
+    for _ in 0..20 {
+        engine
+            .wheel_zoom_time_visible(240.0, 500.0, 0.2, 1.0)
+            .expect("wheel zoom");
+    }
+    let (start, end) = engine.time_visible_range();
+    assert!(start >= full_start - 1e-9 && end <= full_end + 1e-9);
+}
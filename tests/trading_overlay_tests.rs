@@ -0,0 +1,257 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use chart_rs::api::{
+    ChartEngine, ChartEngineConfig, InteractionOptions, PointerCursorHint, PointerInput,
+    PointerInputBridge, SeriesPixelSpace, SeriesRef,
+};
+use chart_rs::core::{OhlcBar, Viewport};
+use chart_rs::extensions::{
+    ChartPlugin, OpenPosition, PluginContext, PluginEvent, TradeSide, TradingLineKind,
+    TradingOverlayStyle, WorkingOrder, trading_lines,
+};
+use chart_rs::render::{CanvasLayerKind, LineStrokeStyle, NullRenderer};
+
+type EventLog = Rc<RefCell<Vec<PluginEvent>>>;
+
+struct OrderListener {
+    events: EventLog,
+}
+
+impl ChartPlugin for OrderListener {
+    fn id(&self) -> &str {
+        "orders"
+    }
+
+    fn on_event(&mut self, event: PluginEvent, _context: PluginContext) {
+        if matches!(event, PluginEvent::OrderPriceChanged { .. }) {
+            self.events.borrow_mut().push(event);
+        }
+    }
+}
+
+fn engine() -> ChartEngine<NullRenderer> {
+    let config =
+        ChartEngineConfig::new(Viewport::new(800, 400), 0.0, 100.0).with_price_domain(0.0, 50.0);
+    let mut engine = ChartEngine::new(NullRenderer::default(), config).expect("engine");
+    engine.set_candles(
+        (0..10)
+            .map(|i| {
+                let open = 20.0 + f64::from(i);
+                OhlcBar::new(
+                    f64::from(i) * 10.0,
+                    open,
+                    open + 3.0,
+                    open - 2.0,
+                    open + 1.0,
+                )
+                .expect("bar")
+            })
+            .collect(),
+    );
+    engine
+}
+
+fn price_y(engine: &ChartEngine<NullRenderer>, price: f64) -> f64 {
+    engine
+        .series_price_to_pixel(SeriesRef::Candles, price, SeriesPixelSpace::Content)
+        .expect("y")
+}
+
+#[test]
+fn lines_carry_side_colors_quantities_and_pnl() {
+    let style = TradingOverlayStyle::default();
+    let orders = [WorkingOrder::new("o1", TradeSide::Sell, 35.0, 1.5)];
+    let positions = [OpenPosition::new("p1", TradeSide::Buy, 25.0, 2.0)
+        .with_take_profit(37.5)
+        .with_stop_loss(20.0)];
+
+    let lines = trading_lines(&orders, &positions, Some(30.0), style);
+    let summary: Vec<_> = lines
+        .iter()
+        .map(|line| (line.kind, line.label.as_str(), line.dashed))
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            (TradingLineKind::Order, "SELL 1.5", false),
+            (TradingLineKind::Position, "LONG 2 +10.00", false),
+            (TradingLineKind::TakeProfit, "TP +25.00", true),
+            (TradingLineKind::StopLoss, "SL -10.00", true),
+        ]
+    );
+    assert_eq!(lines[0].color, style.sell_color);
+    assert_eq!(lines[1].color, style.buy_color);
+    assert_eq!(lines[2].color, style.take_profit_color);
+
+    let short = OpenPosition::new("p2", TradeSide::Sell, 25.0, 1.0);
+    assert_eq!(short.pnl_at(20.0), 5.0);
+    let lines = trading_lines(&[], &[short], None, style);
+    assert_eq!(lines[0].label, "SHORT 1");
+}
+
+#[test]
+fn overlay_renders_lines_and_labels_in_overlay_layer() {
+    let mut engine = engine();
+    engine
+        .set_working_orders(vec![WorkingOrder::new("o1", TradeSide::Buy, 22.0, 3.0)])
+        .expect("orders");
+    engine
+        .set_open_positions(vec![
+            OpenPosition::new("p1", TradeSide::Buy, 25.0, 2.0).with_stop_loss(21.0),
+        ])
+        .expect("positions");
+
+    let layered = engine.build_layered_render_frame().expect("layered");
+    let overlay = layered.panes[0]
+        .layers
+        .iter()
+        .find(|layer| layer.kind == CanvasLayerKind::Overlay)
+        .expect("overlay layer");
+    let texts: Vec<_> = overlay
+        .texts
+        .iter()
+        .map(|text| text.text.as_ref())
+        .collect();
+    // Mark price is the last close (30.0).
+    assert_eq!(texts, vec!["BUY 3", "LONG 2 +10.00", "SL -8.00"]);
+    assert_eq!(overlay.rects.len(), 3);
+
+    let order_line = overlay.lines[0];
+    assert!((order_line.y1 - price_y(&engine, 22.0)).abs() < 1e-9);
+    assert_eq!(order_line.y1, order_line.y2);
+    assert_eq!(order_line.stroke_style, LineStrokeStyle::Solid);
+    assert_eq!(overlay.lines[2].stroke_style, LineStrokeStyle::Dashed);
+}
+
+#[test]
+fn dragging_an_order_moves_its_price_and_emits_event_on_release() {
+    let events = EventLog::default();
+    let mut engine = engine();
+    engine
+        .register_plugin(Box::new(OrderListener {
+            events: Rc::clone(&events),
+        }))
+        .expect("plugin");
+    engine
+        .set_working_orders(vec![
+            WorkingOrder::new("o1", TradeSide::Buy, 22.0, 1.0),
+            WorkingOrder::new("locked", TradeSide::Sell, 40.0, 1.0).with_draggable(false),
+        ])
+        .expect("orders");
+
+    let y = price_y(&engine, 22.0);
+    assert_eq!(
+        engine
+            .working_order_at(100.0, y + 3.0)
+            .map(|o| o.id.as_str()),
+        Some("o1")
+    );
+    assert!(engine.working_order_at(100.0, y + 10.0).is_none());
+    assert!(!engine.begin_order_drag(100.0, price_y(&engine, 40.0)));
+
+    assert!(engine.begin_order_drag(100.0, y));
+    assert_eq!(engine.dragged_order_id(), Some("o1"));
+    engine.drag_order_to(price_y(&engine, 24.0)).expect("drag");
+    assert!((engine.working_orders()[0].price - 24.0).abs() < 1e-9);
+    assert!(events.borrow().is_empty());
+
+    let price = engine.end_order_drag().expect("moved");
+    assert!((price - 24.0).abs() < 1e-9);
+    assert_eq!(engine.dragged_order_id(), None);
+    assert!(matches!(
+        events.borrow().as_slice(),
+        [PluginEvent::OrderPriceChanged { id, price }] if id == "o1" && (price - 24.0).abs() < 1e-9
+    ));
+
+    // Releasing without moving does not notify.
+    let y = price_y(&engine, 24.0);
+    assert!(engine.begin_order_drag(100.0, y));
+    assert_eq!(engine.end_order_drag(), None);
+    assert_eq!(events.borrow().len(), 1);
+}
+
+#[test]
+fn cancel_restores_the_original_price() {
+    let mut engine = engine();
+    engine
+        .set_working_orders(vec![WorkingOrder::new("o1", TradeSide::Buy, 22.0, 1.0)])
+        .expect("orders");
+    assert!(engine.begin_order_drag(100.0, price_y(&engine, 22.0)));
+    engine.drag_order_to(price_y(&engine, 30.0)).expect("drag");
+    engine.cancel_order_drag();
+    assert_eq!(engine.working_orders()[0].price, 22.0);
+    assert_eq!(engine.dragged_order_id(), None);
+}
+
+#[test]
+fn pointer_bridge_drags_order_lines_instead_of_panning() {
+    let mut engine = engine();
+    engine
+        .set_working_orders(vec![WorkingOrder::new("o1", TradeSide::Buy, 22.0, 1.0)])
+        .expect("orders");
+    let range = engine.time_visible_range();
+    let y = price_y(&engine, 22.0);
+    let target = price_y(&engine, 26.0);
+
+    let mut bridge = PointerInputBridge::new(InteractionOptions::default());
+    bridge
+        .handle(&mut engine, PointerInput::Moved { x: 200.0, y })
+        .expect("hover");
+    assert_eq!(bridge.cursor_hint(), PointerCursorHint::ResizeVertical);
+    for input in [
+        PointerInput::Pressed { x: 200.0, y },
+        PointerInput::Moved {
+            x: 260.0,
+            y: target,
+        },
+        PointerInput::Released,
+    ] {
+        bridge.handle(&mut engine, input).expect("input");
+    }
+    assert!((engine.working_orders()[0].price - 26.0).abs() < 1e-9);
+    assert_eq!(engine.time_visible_range(), range);
+
+    let mut bridge = PointerInputBridge::new(InteractionOptions::default().with_order_drag(false));
+    for input in [
+        PointerInput::Pressed {
+            x: 200.0,
+            y: target,
+        },
+        PointerInput::Moved { x: 260.0, y },
+        PointerInput::Released,
+    ] {
+        bridge.handle(&mut engine, input).expect("input");
+    }
+    assert!((engine.working_orders()[0].price - 26.0).abs() < 1e-9);
+    assert_ne!(engine.time_visible_range(), range);
+}
+
+#[test]
+fn invalid_orders_positions_and_style_are_rejected() {
+    let mut engine = engine();
+    assert!(
+        engine
+            .set_working_orders(vec![WorkingOrder::new("o1", TradeSide::Buy, f64::NAN, 1.0)])
+            .is_err()
+    );
+    assert!(
+        engine
+            .set_working_orders(vec![WorkingOrder::new("o1", TradeSide::Buy, 20.0, 0.0)])
+            .is_err()
+    );
+    assert!(
+        engine
+            .set_open_positions(vec![
+                OpenPosition::new("p1", TradeSide::Sell, 20.0, 1.0).with_stop_loss(f64::INFINITY),
+            ])
+            .is_err()
+    );
+    let style = TradingOverlayStyle {
+        line_width: 0.0,
+        ..TradingOverlayStyle::default()
+    };
+    assert!(engine.set_trading_overlay_style(style).is_err());
+    assert!(engine.working_orders().is_empty());
+    assert!(engine.open_positions().is_empty());
+}
@@ -0,0 +1,124 @@
+use chart_rs::ChartError;
+use chart_rs::api::{ChartEngine, ChartEngineConfig, ViewBookmark};
+use chart_rs::core::{DataPoint, PriceScaleMode, Viewport};
+use chart_rs::render::NullRenderer;
+
+fn engine() -> ChartEngine<NullRenderer> {
+    let config =
+        ChartEngineConfig::new(Viewport::new(800, 400), 0.0, 100.0).with_price_domain(10.0, 50.0);
+    let mut engine = ChartEngine::new(NullRenderer::default(), config).expect("engine");
+    engine.set_data(
+        (0..=100)
+            .map(|index| DataPoint::new(f64::from(index), 20.0 + f64::from(index % 7)))
+            .collect(),
+    );
+    engine
+}
+
+#[test]
+fn restore_view_returns_to_the_saved_region_and_crosshair() {
+    let mut engine = engine();
+    engine.set_time_visible_range(30.0, 45.0).expect("range");
+    engine
+        .set_price_scale_mode(PriceScaleMode::Log)
+        .expect("log mode");
+    engine.set_crosshair_time(Some(40.0)).expect("crosshair");
+    engine.save_view("earnings-week").expect("save");
+    let saved = engine.navigation_view_state();
+
+    engine.set_time_visible_range(0.0, 100.0).expect("range");
+    engine
+        .set_price_scale_mode(PriceScaleMode::Linear)
+        .expect("linear mode");
+    engine.set_crosshair_time(None).expect("crosshair");
+
+    assert!(engine.restore_view("earnings-week").expect("restore"));
+    assert_eq!(engine.navigation_view_state(), saved);
+    assert_eq!(engine.crosshair_time(), Some(40.0));
+    assert!(!engine.restore_view("missing").expect("restore"));
+}
+
+#[test]
+fn restore_view_is_one_undo_step() {
+    let mut engine = engine();
+    engine.set_time_visible_range(30.0, 45.0).expect("range");
+    engine.save_view("zoomed").expect("save");
+    engine.set_time_visible_range(10.0, 90.0).expect("range");
+    engine.checkpoint_view();
+
+    engine.restore_view("zoomed").expect("restore");
+    assert!(engine.undo_view().expect("undo"));
+    assert_eq!(engine.time_visible_range(), (10.0, 90.0));
+}
+
+#[test]
+fn saving_an_existing_name_overwrites_in_place() {
+    let mut engine = engine();
+    engine.save_view("a").expect("save");
+    engine.save_view("b").expect("save");
+    engine.set_time_visible_range(50.0, 60.0).expect("range");
+    engine.save_view("a").expect("save");
+
+    let names: Vec<_> = engine
+        .view_bookmarks()
+        .iter()
+        .map(|bookmark| bookmark.name.as_str())
+        .collect();
+    assert_eq!(names, ["a", "b"]);
+    assert_eq!(
+        engine
+            .view_bookmark("a")
+            .expect("a")
+            .view
+            .time_visible_range,
+        (50.0, 60.0)
+    );
+    assert!(engine.remove_view_bookmark("a"));
+    assert!(!engine.remove_view_bookmark("a"));
+}
+
+#[test]
+fn bookmarks_round_trip_through_the_scene_contract() {
+    let mut engine = engine();
+    engine.set_time_visible_range(30.0, 45.0).expect("range");
+    engine.set_crosshair_time(Some(33.0)).expect("crosshair");
+    engine.save_view("study").expect("save");
+
+    let json = engine.export_scene_json_pretty().expect("export");
+    let mut restored = ChartEngine::new(
+        NullRenderer::default(),
+        ChartEngineConfig::new(Viewport::new(800, 400), 0.0, 10.0),
+    )
+    .expect("engine");
+    restored.import_scene_json_str(&json).expect("import");
+
+    assert_eq!(restored.view_bookmarks(), engine.view_bookmarks());
+    assert!(restored.restore_view("study").expect("restore"));
+    assert_eq!(restored.time_visible_range(), (30.0, 45.0));
+}
+
+#[test]
+fn invalid_bookmarks_are_rejected() {
+    let mut engine = engine();
+    assert!(matches!(
+        engine.save_view("  "),
+        Err(ChartError::InvalidData(_))
+    ));
+
+    let bookmark = ViewBookmark {
+        name: "dup".to_owned(),
+        view: engine.navigation_view_state(),
+        crosshair_time: None,
+    };
+    assert!(matches!(
+        engine.set_view_bookmarks(vec![bookmark.clone(), bookmark.clone()]),
+        Err(ChartError::InvalidData(_))
+    ));
+
+    let mut reversed = bookmark;
+    reversed.view.time_visible_range = (60.0, 40.0);
+    assert!(matches!(
+        engine.set_view_bookmarks(vec![reversed]),
+        Err(ChartError::InvalidData(_))
+    ));
+}
@@ -0,0 +1,97 @@
+use chart_rs::api::{ChartEngine, ChartEngineConfig, ViewportResizePolicy};
+use chart_rs::core::{DataPoint, Viewport};
+use chart_rs::render::NullRenderer;
+
+fn engine() -> ChartEngine<NullRenderer> {
+    let config =
+        ChartEngineConfig::new(Viewport::new(800, 400), 0.0, 1000.0).with_price_domain(0.0, 10.0);
+    let mut engine = ChartEngine::new(NullRenderer::default(), config).expect("engine");
+    engine.set_data(
+        (0..=100)
+            .map(|i| DataPoint::new(f64::from(i) * 10.0, f64::from(i % 10)))
+            .collect(),
+    );
+    engine
+        .set_time_visible_range(200.0, 600.0)
+        .expect("visible range");
+    engine
+}
+
+fn assert_range(actual: (f64, f64), expected: (f64, f64)) {
+    assert!(
+        (actual.0 - expected.0).abs() <= 1e-9 && (actual.1 - expected.1).abs() <= 1e-9,
+        "expected {expected:?}, got {actual:?}"
+    );
+}
+
+#[test]
+fn preserve_visible_span_keeps_range() {
+    let mut engine = engine();
+    engine
+        .resize_viewport(
+            Viewport::new(1200, 400),
+            ViewportResizePolicy::PreserveVisibleSpan,
+        )
+        .expect("resize");
+    assert_eq!(engine.viewport(), Viewport::new(1200, 400));
+    assert_range(engine.time_visible_range(), (200.0, 600.0));
+}
+
+#[test]
+fn preserve_bar_spacing_keeps_left_edge_and_time_per_pixel() {
+    let mut engine = engine();
+    engine
+        .resize_viewport(
+            Viewport::new(1200, 400),
+            ViewportResizePolicy::PreserveBarSpacing,
+        )
+        .expect("resize");
+    assert_range(engine.time_visible_range(), (200.0, 800.0));
+
+    engine
+        .resize_viewport(
+            Viewport::new(600, 400),
+            ViewportResizePolicy::PreserveBarSpacing,
+        )
+        .expect("shrink");
+    assert_range(engine.time_visible_range(), (200.0, 500.0));
+}
+
+#[test]
+fn preserve_right_offset_keeps_right_edge() {
+    let mut engine = engine();
+    engine
+        .resize_viewport(
+            Viewport::new(400, 400),
+            ViewportResizePolicy::PreserveRightOffset,
+        )
+        .expect("resize");
+    assert_range(engine.time_visible_range(), (400.0, 600.0));
+}
+
+#[test]
+fn height_only_changes_keep_range() {
+    let mut engine = engine();
+    engine
+        .resize_viewport(
+            Viewport::new(800, 700),
+            ViewportResizePolicy::PreserveBarSpacing,
+        )
+        .expect("resize");
+    assert_range(engine.time_visible_range(), (200.0, 600.0));
+}
+
+#[test]
+fn invalid_viewport_is_rejected_without_side_effects() {
+    let mut engine = engine();
+    assert!(
+        engine
+            .resize_viewport(
+                Viewport::new(0, 400),
+                ViewportResizePolicy::PreserveRightOffset
+            )
+            .is_err()
+    );
+    assert_eq!(engine.viewport(), Viewport::new(800, 400));
+    assert_range(engine.time_visible_range(), (200.0, 600.0));
+}
@@ -1,6 +1,9 @@
 use chart_rs::ChartError;
 use chart_rs::api::{ChartEngine, ChartEngineConfig};
-use chart_rs::core::{DataPoint, OhlcBar, Viewport};
+use chart_rs::core::{
+    DataPoint, OhlcBar, Viewport, candles_in_sorted_time_window, candles_in_time_window,
+    points_in_sorted_time_window, points_in_time_window, points_time_window_range,
+};
 use chart_rs::render::NullRenderer;
 
 #[test]
@@ -75,3 +78,130 @@ fn visible_window_overscan_rejects_invalid_ratio() {
         .expect_err("negative overscan must fail");
     assert!(matches!(err, ChartError::InvalidData(_)));
 }
+
+#[test]
+fn visible_slices_and_ranges_match_owned_accessors() {
+    let renderer = NullRenderer::default();
+    let config =
+        ChartEngineConfig::new(Viewport::new(1200, 600), 0.0, 100.0).with_price_domain(0.0, 200.0);
+    let mut engine = ChartEngine::new(renderer, config).expect("engine init");
+
+    engine.set_data(vec![
+        DataPoint::new(50.0, 50.0),
+        DataPoint::new(5.0, 10.0),
+        DataPoint::new(15.0, 15.0),
+        DataPoint::new(60.0, 60.0),
+        DataPoint::new(90.0, 90.0),
+    ]);
+    engine.set_candles(vec![
+        OhlcBar::new(10.0, 9.0, 12.0, 8.0, 11.0).expect("valid candle"),
+        OhlcBar::new(25.0, 20.0, 26.0, 19.0, 24.0).expect("valid candle"),
+        OhlcBar::new(80.0, 70.0, 82.0, 68.0, 79.0).expect("valid candle"),
+    ]);
+    engine
+        .set_time_visible_range(15.0, 60.0)
+        .expect("set visible range");
+
+    assert_eq!(engine.visible_points_slice(), engine.visible_points());
+    assert_eq!(engine.visible_points_range(), 1..4);
+    assert_eq!(
+        &engine.points()[engine.visible_points_range()],
+        engine.visible_points_slice()
+    );
+    assert_eq!(engine.visible_candles_slice(), engine.visible_candles());
+    assert_eq!(engine.visible_candles_range(), 1..2);
+    assert_eq!(
+        engine
+            .visible_points_with_overscan_slice(0.5)
+            .expect("overscan"),
+        engine.visible_points_with_overscan(0.5).expect("overscan")
+    );
+
+    engine
+        .set_time_visible_range(91.0, 99.0)
+        .expect("set visible range");
+    assert!(engine.visible_points_slice().is_empty());
+    assert!(engine.visible_candles_range().is_empty());
+}
+
+#[test]
+fn visible_windows_over_appended_data_match_the_filtering_window() {
+    let renderer = NullRenderer::default();
+    let config =
+        ChartEngineConfig::new(Viewport::new(1200, 600), 0.0, 100.0).with_price_domain(0.0, 200.0);
+    let mut engine = ChartEngine::new(renderer, config).expect("engine init");
+    engine.set_data(vec![
+        DataPoint::new(10.0, 10.0),
+        DataPoint::new(50.0, 50.0),
+        DataPoint::new(90.0, 90.0),
+    ]);
+    engine.set_candles(vec![
+        OhlcBar::new(10.0, 9.0, 12.0, 8.0, 11.0).expect("valid candle"),
+    ]);
+
+    // Older samples are rejected instead of breaking the time order the
+    // slice-based windows rely on.
+    assert!(matches!(
+        engine.append_point(DataPoint::new(20.0, 20.0)),
+        Err(ChartError::NonMonotonicTime { .. })
+    ));
+    for x in [95.0, 95.0, 120.0] {
+        engine.append_point(DataPoint::new(x, x)).expect("append");
+    }
+    for time in [30.0, 60.0] {
+        let candle = OhlcBar::new(time, 20.0, 26.0, 19.0, 24.0).expect("valid candle");
+        engine.append_candle(candle).expect("append");
+    }
+
+    for (start, end) in [(15.0, 30.0), (40.0, 100.0), (90.0, 130.0), (25.0, 65.0)] {
+        engine
+            .set_time_visible_range(start, end)
+            .expect("set visible range");
+        assert_eq!(
+            engine.visible_points(),
+            points_in_time_window(engine.points(), start, end)
+        );
+        assert_eq!(
+            engine.visible_candles(),
+            candles_in_time_window(engine.candles(), start, end)
+        );
+    }
+    // The last window holds the original 50 plus both appended candles.
+    assert_eq!(engine.visible_points(), vec![DataPoint::new(50.0, 50.0)]);
+    assert_eq!(engine.visible_candles().len(), 2);
+    engine
+        .set_time_visible_range(90.0, 130.0)
+        .expect("set visible range");
+    assert_eq!(engine.visible_points().len(), 4);
+}
+
+#[test]
+fn sorted_time_window_matches_filtering_window() {
+    let points: Vec<DataPoint> = (0..20)
+        .map(|index| DataPoint::new(f64::from(index) * 0.5, f64::from(index)))
+        .collect();
+    for (start, end) in [
+        (2.0, 4.0),
+        (4.0, 2.0),
+        (-1.0, 0.0),
+        (9.6, 20.0),
+        (2.25, 2.4),
+    ] {
+        assert_eq!(
+            points_in_sorted_time_window(&points, start, end),
+            points_in_time_window(&points, start, end).as_slice()
+        );
+    }
+    assert_eq!(points_time_window_range(&points, 2.0, 4.0), 4..9);
+
+    let candles: Vec<OhlcBar> = (0..5)
+        .map(|index| {
+            let time = f64::from(index);
+            OhlcBar::new(time, 1.0, 2.0, 0.5, 1.5).expect("candle")
+        })
+        .collect();
+    assert_eq!(
+        candles_in_sorted_time_window(&candles, 1.0, 3.0),
+        candles_in_time_window(&candles, 1.0, 3.0).as_slice()
+    );
+}
@@ -0,0 +1,171 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use chart_rs::ChartError;
+use chart_rs::api::{
+    ChartEngine, ChartEngineConfig, VisibleRangeChangeBehavior, VisibleRangeChangeDelivery,
+};
+use chart_rs::core::{DataPoint, Viewport};
+use chart_rs::extensions::{
+    ChartPlugin, PluginContext, PluginEvent, VisibleRangeChange, VisibleRangeChangeCause,
+};
+use chart_rs::render::NullRenderer;
+
+type Changes = Rc<RefCell<Vec<VisibleRangeChange>>>;
+
+struct RangePlugin {
+    events: Rc<RefCell<Vec<PluginEvent>>>,
+}
+
+impl ChartPlugin for RangePlugin {
+    fn id(&self) -> &str {
+        "range"
+    }
+
+    fn on_event(&mut self, event: PluginEvent, _context: PluginContext) {
+        if matches!(event, PluginEvent::VisibleRangeChanged { .. }) {
+            self.events.borrow_mut().push(event);
+        }
+    }
+}
+
+fn engine(delivery: VisibleRangeChangeDelivery) -> (ChartEngine<NullRenderer>, Changes) {
+    let config = ChartEngineConfig::new(Viewport::new(800, 400), 0.0, 100.0)
+        .with_price_domain(0.0, 50.0)
+        .with_visible_range_change_behavior(VisibleRangeChangeBehavior { delivery });
+    let mut engine = ChartEngine::new(NullRenderer::default(), config).expect("engine");
+    engine.set_data(
+        (0..=100)
+            .map(|index| DataPoint::new(f64::from(index), 20.0 + f64::from(index % 7)))
+            .collect(),
+    );
+    engine
+        .set_time_visible_range(20.0, 80.0)
+        .expect("visible range");
+    engine.flush_visible_range_change();
+    let changes = Changes::default();
+    let sink = Rc::clone(&changes);
+    engine.subscribe_visible_time_range_change(move |change| sink.borrow_mut().push(*change));
+    (engine, changes)
+}
+
+#[test]
+fn immediate_delivery_reports_previous_range_and_api_cause() {
+    let (mut engine, changes) = engine(VisibleRangeChangeDelivery::Immediate);
+    let events = Rc::new(RefCell::new(Vec::new()));
+    engine
+        .register_plugin(Box::new(RangePlugin {
+            events: Rc::clone(&events),
+        }))
+        .expect("register");
+    engine.set_time_visible_range(30.0, 70.0).expect("range");
+
+    assert_eq!(
+        *changes.borrow(),
+        vec![VisibleRangeChange {
+            previous: (20.0, 80.0),
+            current: (30.0, 70.0),
+            cause: VisibleRangeChangeCause::Api,
+        }]
+    );
+    assert_eq!(
+        events.borrow().last(),
+        Some(&PluginEvent::VisibleRangeChanged {
+            start: 30.0,
+            end: 70.0,
+            previous_start: 20.0,
+            previous_end: 80.0,
+            cause: VisibleRangeChangeCause::Api,
+        })
+    );
+}
+
+#[test]
+fn interaction_entry_points_tag_their_cause() {
+    let (mut engine, changes) = engine(VisibleRangeChangeDelivery::Immediate);
+    let last_cause = |changes: &Changes| changes.borrow().last().expect("change").cause;
+
+    engine.pan_time_visible_by_pixels(40.0).expect("drag");
+    assert_eq!(last_cause(&changes), VisibleRangeChangeCause::Drag);
+
+    engine
+        .wheel_zoom_time_visible(-120.0, 300.0, 0.2, 1e-6)
+        .expect("wheel");
+    assert_eq!(last_cause(&changes), VisibleRangeChangeCause::Wheel);
+
+    engine.start_kinetic_pan(10.0).expect("kinetic");
+    engine.step_kinetic_pan(0.016).expect("kinetic step");
+    assert_eq!(last_cause(&changes), VisibleRangeChangeCause::Kinetic);
+    engine.stop_kinetic_pan();
+
+    engine
+        .animate_time_visible_range(10.0, 60.0, 0.5)
+        .expect("animate");
+    engine.step_animations(0.1).expect("step");
+    assert_eq!(last_cause(&changes), VisibleRangeChangeCause::Animation);
+
+    engine.set_time_visible_range(0.0, 50.0).expect("range");
+    assert_eq!(last_cause(&changes), VisibleRangeChangeCause::Api);
+}
+
+#[test]
+fn debounced_delivery_coalesces_a_burst() {
+    let (mut engine, changes) =
+        engine(VisibleRangeChangeDelivery::Debounced { quiet_seconds: 0.2 });
+    for _ in 0..5 {
+        engine.pan_time_visible_by_pixels(10.0).expect("drag");
+    }
+    let final_range = engine.time_visible_range();
+    assert!(changes.borrow().is_empty());
+    assert!(engine.visible_range_change_pending());
+    assert!(engine.has_active_animation());
+
+    engine.step_animations(0.1).expect("step");
+    assert!(changes.borrow().is_empty());
+    engine.step_animations(0.15).expect("step");
+
+    assert_eq!(
+        *changes.borrow(),
+        vec![VisibleRangeChange {
+            previous: (20.0, 80.0),
+            current: final_range,
+            cause: VisibleRangeChangeCause::Drag,
+        }]
+    );
+    assert!(!engine.visible_range_change_pending());
+}
+
+#[test]
+fn per_frame_delivery_flushes_on_render() {
+    let (mut engine, changes) = engine(VisibleRangeChangeDelivery::PerFrame);
+    engine.set_time_visible_range(25.0, 75.0).expect("range");
+    engine.set_time_visible_range(30.0, 70.0).expect("range");
+    assert!(changes.borrow().is_empty());
+
+    engine.render().expect("render");
+    assert_eq!(changes.borrow().len(), 1);
+    assert_eq!(changes.borrow()[0].previous, (20.0, 80.0));
+    assert_eq!(changes.borrow()[0].current, (30.0, 70.0));
+
+    engine.render().expect("render");
+    assert_eq!(changes.borrow().len(), 1);
+}
+
+#[test]
+fn unsubscribe_and_validation() {
+    let (mut engine, changes) = engine(VisibleRangeChangeDelivery::Immediate);
+    let id = engine.subscribe_visible_time_range_change(|_| {});
+    assert!(engine.unsubscribe_visible_time_range_change(id));
+    assert!(!engine.unsubscribe_visible_time_range_change(id));
+    assert!(engine.unsubscribe_visible_time_range_change(1));
+    engine.set_time_visible_range(30.0, 70.0).expect("range");
+    assert!(changes.borrow().is_empty());
+
+    let invalid = VisibleRangeChangeBehavior {
+        delivery: VisibleRangeChangeDelivery::Debounced { quiet_seconds: 0.0 },
+    };
+    assert!(matches!(
+        engine.set_visible_range_change_behavior(invalid),
+        Err(ChartError::InvalidData(_))
+    ));
+}
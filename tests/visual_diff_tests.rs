@@ -0,0 +1,131 @@
+#![cfg(feature = "visual-diff")]
+
+use chart_rs::ChartError;
+use chart_rs::testing::visual::{VisualTolerance, compare_png};
+
+fn encode(width: u32, height: u32, color: png::ColorType, pixels: &[u8]) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    let mut encoder = png::Encoder::new(&mut bytes, width, height);
+    encoder.set_color(color);
+    encoder.set_depth(png::BitDepth::Eight);
+    let mut writer = encoder.write_header().expect("header");
+    writer.write_image_data(pixels).expect("pixels");
+    writer.finish().expect("finish");
+    bytes
+}
+
+fn solid_rgba(width: u32, height: u32, rgba: [u8; 4]) -> Vec<u8> {
+    rgba.repeat((width * height) as usize)
+}
+
+/// `(width, height, rgba pixels)` of an encoded heatmap.
+fn decode(bytes: &[u8]) -> (u32, u32, Vec<u8>) {
+    let mut reader = png::Decoder::new(bytes).read_info().expect("decode");
+    let mut buffer = vec![0; reader.output_buffer_size()];
+    let info = reader.next_frame(&mut buffer).expect("frame");
+    (info.width, info.height, buffer)
+}
+
+#[test]
+fn identical_images_pass_exact_tolerance() {
+    let image = encode(
+        4,
+        3,
+        png::ColorType::Rgba,
+        &solid_rgba(4, 3, [10, 20, 30, 255]),
+    );
+    let report = compare_png(&image, &image, VisualTolerance::exact()).expect("compare");
+    assert!(report.passed);
+    assert_eq!((report.width, report.height), (4, 3));
+    assert_eq!(report.max_channel_abs_diff, 0);
+    assert_eq!(report.mean_channel_abs_diff, 0.0);
+    assert_eq!(report.differing_pixels, 0);
+}
+
+#[test]
+fn channel_differences_are_measured_and_mapped_in_the_heatmap() {
+    let baseline_pixels = solid_rgba(2, 2, [100, 100, 100, 255]);
+    let mut candidate_pixels = baseline_pixels.clone();
+    // Second pixel: green channel off by 8.
+    candidate_pixels[5] = 108;
+    let baseline = encode(2, 2, png::ColorType::Rgba, &baseline_pixels);
+    let candidate = encode(2, 2, png::ColorType::Rgba, &candidate_pixels);
+
+    let tolerance = VisualTolerance {
+        max_channel_abs_diff: 8,
+        mean_channel_abs_diff: 1.0,
+    };
+    let report = compare_png(&baseline, &candidate, tolerance).expect("compare");
+    assert!(report.passed);
+    assert_eq!(report.max_channel_abs_diff, 8);
+    assert_eq!(report.mean_channel_abs_diff, 0.5);
+    assert_eq!(report.differing_pixels, 1);
+
+    let (width, height, heatmap) = decode(&report.heatmap_png);
+    assert_eq!((width, height), (2, 2));
+    assert_eq!(&heatmap[0..4], &[0, 0, 0, 255]);
+    assert_eq!(&heatmap[4..8], &[64, 0, 0, 255]);
+
+    let strict = compare_png(&baseline, &candidate, VisualTolerance::exact()).expect("compare");
+    assert!(!strict.passed);
+}
+
+#[test]
+fn mean_tolerance_fails_widespread_small_drift() {
+    let baseline = encode(
+        3,
+        3,
+        png::ColorType::Rgba,
+        &solid_rgba(3, 3, [50, 50, 50, 255]),
+    );
+    let candidate = encode(
+        3,
+        3,
+        png::ColorType::Rgba,
+        &solid_rgba(3, 3, [54, 54, 54, 255]),
+    );
+    let report = compare_png(
+        &baseline,
+        &candidate,
+        VisualTolerance {
+            max_channel_abs_diff: 4,
+            mean_channel_abs_diff: 1.0,
+        },
+    )
+    .expect("compare");
+    assert_eq!(report.mean_channel_abs_diff, 3.0);
+    assert_eq!(report.differing_pixels, 9);
+    assert!(!report.passed);
+}
+
+#[test]
+fn rgb_and_rgba_encodings_of_the_same_pixels_match() {
+    let rgb = encode(2, 1, png::ColorType::Rgb, &[1, 2, 3, 4, 5, 6]);
+    let rgba = encode(2, 1, png::ColorType::Rgba, &[1, 2, 3, 255, 4, 5, 6, 255]);
+    let report = compare_png(&rgb, &rgba, VisualTolerance::exact()).expect("compare");
+    assert!(report.passed);
+}
+
+#[test]
+fn size_mismatch_and_invalid_png_are_rejected() {
+    let small = encode(
+        2,
+        2,
+        png::ColorType::Rgba,
+        &solid_rgba(2, 2, [0, 0, 0, 255]),
+    );
+    let large = encode(
+        3,
+        2,
+        png::ColorType::Rgba,
+        &solid_rgba(3, 2, [0, 0, 0, 255]),
+    );
+    assert!(matches!(
+        compare_png(&small, &large, VisualTolerance::exact()),
+        Err(ChartError::InvalidData(message)) if message.contains("size mismatch")
+    ));
+    assert!(matches!(
+        compare_png(b"not a png", &small, VisualTolerance::exact()),
+        Err(ChartError::InvalidData(message)) if message.contains("baseline")
+    ));
+}
@@ -274,7 +274,7 @@ fn run_fixture(fixture: &AxisSectionSizingFixture) -> chart_rs::ChartResult<Layo
 
     let mut style = engine.render_style();
     apply_style_overrides(&mut style, &input.render_style_overrides);
-    engine.set_render_style(style)?;
+    engine.set_render_style(style.clone())?;
 
     let frame = engine.build_render_frame()?;
     Ok(compute_layout_signature(&frame, style, input.viewport))
@@ -403,7 +403,7 @@ fn compute_layout_signature(
                 .total_cmp(&right.x)
                 .then_with(|| left.y.total_cmp(&right.y))
         })
-        .map(|text| text.text.clone());
+        .map(|text| text.text.to_string());
     let top_price_label_text = frame
         .texts
         .iter()
@@ -413,7 +413,7 @@ fn compute_layout_signature(
                 .total_cmp(&right.y)
                 .then_with(|| left.x.total_cmp(&right.x))
         })
-        .map(|text| text.text.clone());
+        .map(|text| text.text.to_string());
     let major_time_tick_end =
         (plot_bottom + style.major_time_tick_mark_length_px).min(viewport_height);
     let major_time_tick_mark_count = frame
@@ -0,0 +1,195 @@
+use chart_rs::ChartError;
+use chart_rs::api::{ChartEngine, ChartEngineConfig};
+use chart_rs::core::{OhlcBar, Viewport};
+use chart_rs::extensions::{
+    ProfileAnchor, VolumeProfileConfig, VolumeSample, compute_volume_profile,
+};
+use chart_rs::render::{CanvasLayerKind, NullRenderer};
+
+fn bar(time: f64, low: f64, high: f64) -> OhlcBar {
+    OhlcBar::new(time, low, high, low, high).expect("bar")
+}
+
+fn config(bucket_count: usize) -> VolumeProfileConfig {
+    VolumeProfileConfig {
+        bucket_count,
+        ..VolumeProfileConfig::default()
+    }
+}
+
+#[test]
+fn volume_is_spread_over_each_candle_range() {
+    let candles = [bar(0.0, 0.0, 10.0), bar(1.0, 5.0, 10.0)];
+    let volumes = [VolumeSample::new(0.0, 100.0), VolumeSample::new(1.0, 50.0)];
+    let profile = compute_volume_profile(&candles, &volumes, config(2))
+        .expect("profile")
+        .expect("non-empty");
+
+    assert_eq!(profile.buckets.len(), 2);
+    assert_eq!(profile.buckets[0].price_low, 0.0);
+    assert_eq!(profile.buckets[1].price_high, 10.0);
+    assert_eq!(profile.buckets[0].volume, 50.0);
+    assert_eq!(profile.buckets[1].volume, 100.0);
+    assert_eq!(profile.total_volume, 150.0);
+    assert_eq!(profile.point_of_control, 1);
+}
+
+#[test]
+fn value_area_grows_from_point_of_control_toward_heavier_side() {
+    let candles: Vec<_> = (0..5)
+        .map(|i| bar(f64::from(i), f64::from(i), f64::from(i + 1)))
+        .collect();
+    let volumes: Vec<_> = [10.0, 20.0, 40.0, 25.0, 5.0]
+        .into_iter()
+        .enumerate()
+        .map(|(i, volume)| VolumeSample::new(i as f64, volume))
+        .collect();
+    let profile = compute_volume_profile(
+        &candles,
+        &volumes,
+        VolumeProfileConfig {
+            value_area_ratio: 0.8,
+            ..config(5)
+        },
+    )
+    .expect("profile")
+    .expect("non-empty");
+
+    assert_eq!(profile.point_of_control, 2);
+    let in_area: Vec<_> = profile
+        .buckets
+        .iter()
+        .map(|bucket| bucket.in_value_area)
+        .collect();
+    assert_eq!(in_area, [false, true, true, true, false]);
+    assert_eq!(
+        (profile.value_area_low, profile.value_area_high),
+        (1.0, 4.0)
+    );
+}
+
+#[test]
+fn missing_volumes_fall_back_to_bar_counts() {
+    let candles = [bar(0.0, 0.0, 1.0), bar(1.0, 0.0, 1.0), bar(2.0, 3.0, 4.0)];
+    let session = compute_volume_profile(&candles, &[], config(4))
+        .expect("profile")
+        .expect("non-empty");
+    assert_eq!(session.total_volume, 3.0);
+    assert_eq!(session.point_of_control, 0);
+
+    let partial = compute_volume_profile(&candles, &[VolumeSample::new(2.0, 7.0)], config(4))
+        .expect("profile")
+        .expect("non-empty");
+    assert_eq!(partial.total_volume, 7.0);
+    assert_eq!(partial.point_of_control, 3);
+
+    assert!(
+        compute_volume_profile(&[], &[], config(4))
+            .expect("profile")
+            .is_none()
+    );
+    let err = compute_volume_profile(&candles, &[], config(0)).expect_err("zero buckets");
+    assert!(matches!(err, ChartError::InvalidData(_)));
+}
+
+fn engine() -> ChartEngine<NullRenderer> {
+    let config =
+        ChartEngineConfig::new(Viewport::new(1000, 500), 0.0, 9.0).with_price_domain(0.0, 20.0);
+    let mut engine = ChartEngine::new(NullRenderer::default(), config).expect("engine");
+    engine.set_candles(
+        (0..10)
+            .map(|i| bar(f64::from(i), f64::from(i), f64::from(i) + 4.0))
+            .collect(),
+    );
+    engine
+        .set_volume_samples(
+            (0..10)
+                .map(|i| VolumeSample::new(f64::from(i), f64::from(10 - i)))
+                .collect(),
+        )
+        .expect("volumes");
+    engine
+}
+
+#[test]
+fn engine_profiles_visible_candles_and_draws_overlay_bars() {
+    let mut engine = engine();
+    assert_eq!(engine.volume_profile().expect("profile"), None);
+
+    let overlay_config = VolumeProfileConfig {
+        anchor: ProfileAnchor::Left,
+        ..config(8)
+    };
+    engine
+        .set_volume_profile_config(Some(overlay_config))
+        .expect("config");
+    let profile = engine.volume_profile().expect("profile").expect("profile");
+    assert_eq!(profile.total_volume, 55.0);
+    assert_eq!(profile.buckets[7].price_high, 13.0);
+
+    let layered = engine.build_layered_render_frame().expect("layered");
+    let overlay = layered.panes[0]
+        .layers
+        .iter()
+        .find(|layer| layer.kind == CanvasLayerKind::Overlay)
+        .expect("overlay layer");
+    let drawn = profile
+        .buckets
+        .iter()
+        .filter(|bucket| bucket.volume > 0.0)
+        .count();
+    assert_eq!(overlay.rects.len(), drawn);
+    assert!(overlay.rects.iter().all(|rect| rect.x == 0.0));
+    let widest = overlay
+        .rects
+        .iter()
+        .map(|rect| rect.width)
+        .fold(0.0, f64::max);
+    let plot_right = 1000.0 - engine.render_style().price_axis_width_px;
+    assert!((widest - plot_right * overlay_config.max_width_ratio).abs() <= 1e-6);
+    let poc = overlay
+        .rects
+        .iter()
+        .find(|rect| rect.width == widest)
+        .expect("point of control");
+    assert_eq!(poc.fill_color, overlay_config.point_of_control_color);
+
+    engine.set_time_visible_range(5.0, 9.0).expect("range");
+    let zoomed = engine.volume_profile().expect("profile").expect("profile");
+    assert_eq!(zoomed.total_volume, 15.0);
+    assert_eq!(zoomed.buckets[0].price_low, 5.0);
+}
+
+#[test]
+fn volume_samples_are_validated_and_persist_in_scene() {
+    let mut engine = engine();
+    let err = engine
+        .set_volume_samples(vec![VolumeSample::new(0.0, -1.0)])
+        .expect_err("negative volume");
+    assert!(matches!(err, ChartError::InvalidData(_)));
+    assert!(
+        engine
+            .set_volume_profile_config(Some(VolumeProfileConfig {
+                value_area_ratio: 1.5,
+                ..VolumeProfileConfig::default()
+            }))
+            .is_err()
+    );
+
+    engine
+        .set_volume_profile_config(Some(config(6)))
+        .expect("config");
+    let json = engine.export_scene_json_pretty().expect("export");
+    let mut restored = ChartEngine::new(
+        NullRenderer::default(),
+        ChartEngineConfig::new(Viewport::new(1000, 500), 0.0, 1.0),
+    )
+    .expect("engine");
+    restored.import_scene_json_str(&json).expect("import");
+    assert_eq!(restored.volume_profile_config(), Some(config(6)));
+    assert_eq!(restored.volume_samples(), engine.volume_samples());
+    assert_eq!(
+        restored.volume_profile().expect("profile"),
+        engine.volume_profile().expect("profile")
+    );
+}
@@ -0,0 +1,140 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use chart_rs::api::{
+    AutoscaleSource, ChartEngine, ChartEngineConfig, ChartWarning, RenderStyle, WarningAxis,
+    WarningLabel,
+};
+use chart_rs::core::{DataPoint, Viewport};
+use chart_rs::interaction::CrosshairMode;
+use chart_rs::render::NullRenderer;
+
+fn engine(viewport: Viewport) -> ChartEngine<NullRenderer> {
+    let config = ChartEngineConfig::new(viewport, 0.0, 100.0).with_price_domain(0.0, 50.0);
+    ChartEngine::new(NullRenderer::default(), config).expect("engine")
+}
+
+fn collect_warnings(engine: &mut ChartEngine<NullRenderer>) -> Rc<RefCell<Vec<ChartWarning>>> {
+    let warnings = Rc::new(RefCell::new(Vec::new()));
+    let sink = Rc::clone(&warnings);
+    engine.set_warning_sink(move |warning| sink.borrow_mut().push(*warning));
+    warnings
+}
+
+#[test]
+fn autoscale_on_empty_data_reports_skipped_source() {
+    let mut engine = engine(Viewport::new(800, 400));
+    let warnings = collect_warnings(&mut engine);
+    assert!(engine.has_warning_sink());
+
+    engine
+        .autoscale_price_from_data()
+        .expect("autoscale points");
+    engine
+        .autoscale_price_from_visible_candles()
+        .expect("autoscale visible candles");
+
+    assert_eq!(
+        *warnings.borrow(),
+        vec![
+            ChartWarning::AutoscaleSkippedEmptyData {
+                source: AutoscaleSource::Points
+            },
+            ChartWarning::AutoscaleSkippedEmptyData {
+                source: AutoscaleSource::VisibleCandles
+            },
+        ]
+    );
+    assert_eq!(engine.price_domain(), (0.0, 50.0));
+}
+
+#[test]
+fn cleared_sink_stops_reporting() {
+    let mut engine = engine(Viewport::new(800, 400));
+    let warnings = collect_warnings(&mut engine);
+    engine.clear_warning_sink();
+    assert!(!engine.has_warning_sink());
+
+    engine.autoscale_price_from_candles().expect("autoscale");
+    assert!(warnings.borrow().is_empty());
+}
+
+#[test]
+fn oversized_crosshair_label_box_reports_clipping() {
+    let mut engine = engine(Viewport::new(900, 500));
+    engine.set_crosshair_mode(CrosshairMode::Normal);
+    let style = RenderStyle {
+        crosshair_price_label_box_min_width_px: 400.0,
+        show_crosshair_price_label_box: true,
+        ..engine.render_style()
+    };
+    engine.set_render_style(style).expect("style");
+    engine.pointer_move(300.0, 200.0);
+    let warnings = collect_warnings(&mut engine);
+    engine.build_render_frame().expect("frame");
+
+    let clipped = warnings
+        .borrow()
+        .iter()
+        .find_map(|warning| match *warning {
+            ChartWarning::LabelBoxClipped {
+                label: WarningLabel::CrosshairPrice,
+                requested_width_px,
+                width_px,
+            } => Some((requested_width_px, width_px)),
+            _ => None,
+        })
+        .expect("price label box clipped");
+    assert_eq!(clipped.0, 400.0);
+    assert!(clipped.1 < 400.0);
+}
+
+#[test]
+fn narrow_axis_reports_dropped_ticks() {
+    let mut engine = engine(Viewport::new(900, 400));
+    engine.set_data(
+        (0..=100)
+            .map(|i| DataPoint::new(f64::from(i), f64::from(i % 13)))
+            .collect(),
+    );
+    let warnings = collect_warnings(&mut engine);
+    engine.build_render_frame().expect("frame");
+    assert!(
+        !warnings
+            .borrow()
+            .iter()
+            .any(|warning| matches!(warning, ChartWarning::TicksDropped { .. }))
+    );
+
+    engine
+        .set_viewport(Viewport::new(90, 400))
+        .expect("viewport");
+    engine.build_render_frame().expect("frame");
+    let dropped = warnings
+        .borrow()
+        .iter()
+        .find_map(|warning| match *warning {
+            ChartWarning::TicksDropped {
+                axis: WarningAxis::Time,
+                candidates,
+                kept,
+            } => Some((candidates, kept)),
+            _ => None,
+        })
+        .expect("time ticks dropped");
+    assert!(dropped.0 >= 2);
+    assert!(dropped.1 < 2);
+}
+
+#[test]
+fn warnings_have_readable_messages() {
+    let warning = ChartWarning::TicksDropped {
+        axis: WarningAxis::Time,
+        candidates: 9,
+        kept: 4,
+    };
+    assert_eq!(
+        warning.to_string(),
+        "Time axis kept 4 of 9 ticks after spacing"
+    );
+}
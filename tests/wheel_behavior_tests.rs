@@ -0,0 +1,154 @@
+use chart_rs::api::{
+    ChartEngine, ChartEngineConfig, PointerModifiers, TimeScaleNavigationBehavior, WheelAxis,
+    WheelBehaviorConfig,
+};
+use chart_rs::core::Viewport;
+use chart_rs::render::NullRenderer;
+
+fn build_engine(config: WheelBehaviorConfig) -> ChartEngine<NullRenderer> {
+    let engine_config = ChartEngineConfig::new(Viewport::new(1000, 500), 0.0, 100.0)
+        .with_price_domain(0.0, 1.0)
+        .with_wheel_behavior_config(config);
+    let mut engine = ChartEngine::new(NullRenderer::default(), engine_config).expect("engine init");
+    engine
+        .set_time_scale_navigation_behavior(TimeScaleNavigationBehavior {
+            right_offset_bars: 0.0,
+            bar_spacing_px: None,
+        })
+        .expect("disable default spacing navigation");
+    engine
+}
+
+fn span(engine: &ChartEngine<NullRenderer>) -> f64 {
+    let (start, end) = engine.time_visible_range();
+    end - start
+}
+
+const CONTROL: PointerModifiers = PointerModifiers {
+    alt: false,
+    shift: false,
+    control: true,
+};
+
+#[test]
+fn default_mapping_zooms_vertically_and_pans_horizontally() {
+    let mut engine = build_engine(WheelBehaviorConfig::default());
+    assert_eq!(
+        engine.wheel_behavior_config(),
+        WheelBehaviorConfig::default()
+    );
+
+    engine
+        .handle_wheel(0.0, -1.0, PointerModifiers::NONE, Some((250.0, 100.0)))
+        .expect("zoom");
+    let zoomed = engine.time_visible_range();
+    assert!(zoomed.1 - zoomed.0 < 100.0);
+
+    engine
+        .handle_wheel(1.0, 0.0, PointerModifiers::NONE, None)
+        .expect("pan");
+    let panned = engine.time_visible_range();
+    assert!(panned.0 > zoomed.0);
+    assert!(((panned.1 - panned.0) - (zoomed.1 - zoomed.0)).abs() <= 1e-9);
+}
+
+#[test]
+fn zoom_anchors_on_pointer_position() {
+    let mut engine = build_engine(WheelBehaviorConfig::default());
+    let anchor_time = engine.map_pixel_to_x(250.0).expect("anchor time");
+
+    engine
+        .handle_wheel(0.0, -2.0, PointerModifiers::NONE, Some((250.0, 40.0)))
+        .expect("zoom");
+
+    let anchor_after = engine.map_pixel_to_x(250.0).expect("anchor time");
+    assert!((anchor_after - anchor_time).abs() <= 1e-6);
+}
+
+#[test]
+fn control_modifier_requirement_turns_plain_vertical_wheel_into_pan() {
+    let mut engine = build_engine(WheelBehaviorConfig {
+        pan_axis: Some(WheelAxis::Vertical),
+        zoom_requires_control: true,
+        ..WheelBehaviorConfig::default()
+    });
+
+    engine
+        .handle_wheel(0.0, 1.0, PointerModifiers::NONE, None)
+        .expect("pan");
+    let (start, _) = engine.time_visible_range();
+    assert!(start > 0.0);
+    assert!((span(&engine) - 100.0).abs() <= 1e-9);
+
+    engine.handle_wheel(0.0, -1.0, CONTROL, None).expect("zoom");
+    assert!(span(&engine) < 100.0);
+}
+
+#[test]
+fn inverted_direction_flips_zoom_and_pan() {
+    let mut natural = build_engine(WheelBehaviorConfig::default());
+    let mut inverted = build_engine(WheelBehaviorConfig {
+        invert_direction: true,
+        ..WheelBehaviorConfig::default()
+    });
+
+    natural
+        .handle_wheel(1.0, -1.0, PointerModifiers::NONE, None)
+        .expect("natural");
+    inverted
+        .handle_wheel(1.0, -1.0, PointerModifiers::NONE, None)
+        .expect("inverted");
+
+    assert!(span(&natural) < 100.0);
+    assert!(span(&inverted) > 100.0);
+}
+
+#[test]
+fn sensitivities_scale_each_wheel_axis() {
+    let mut base = build_engine(WheelBehaviorConfig::default());
+    let mut scaled = build_engine(WheelBehaviorConfig {
+        horizontal_sensitivity: 2.0,
+        ..WheelBehaviorConfig::default()
+    });
+
+    base.handle_wheel(1.0, 0.0, PointerModifiers::NONE, None)
+        .expect("pan");
+    scaled
+        .handle_wheel(1.0, 0.0, PointerModifiers::NONE, None)
+        .expect("pan");
+
+    let base_shift = base.time_visible_range().0;
+    let scaled_shift = scaled.time_visible_range().0;
+    assert!((scaled_shift - 2.0 * base_shift).abs() <= 1e-6);
+}
+
+#[test]
+fn disabled_axes_leave_the_visible_range_untouched() {
+    let mut engine = build_engine(WheelBehaviorConfig {
+        zoom_axis: None,
+        pan_axis: None,
+        ..WheelBehaviorConfig::default()
+    });
+
+    engine
+        .handle_wheel(3.0, -3.0, CONTROL, Some((100.0, 100.0)))
+        .expect("no-op");
+
+    assert_eq!(engine.time_visible_range(), (0.0, 100.0));
+}
+
+#[test]
+fn invalid_wheel_behavior_config_is_rejected() {
+    let mut engine = build_engine(WheelBehaviorConfig::default());
+
+    let err = engine.set_wheel_behavior_config(WheelBehaviorConfig {
+        zoom_step_ratio: f64::NAN,
+        ..WheelBehaviorConfig::default()
+    });
+
+    assert!(err.is_err());
+    assert_eq!(
+        engine.wheel_behavior_config(),
+        WheelBehaviorConfig::default()
+    );
+}
@@ -0,0 +1,282 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use chart_rs::api::{
+    ChartEngine, ChartEngineConfig, InteractionOptions, InvalidationTopic, PointerInput,
+    PointerInputBridge, PointerModifiers, ZoomSelectionBehavior,
+};
+use chart_rs::core::{DataPoint, Viewport};
+use chart_rs::extensions::{ChartPlugin, PluginContext, PluginEvent};
+use chart_rs::render::NullRenderer;
+
+struct RecordingPlugin {
+    events: Rc<RefCell<Vec<PluginEvent>>>,
+}
+
+impl ChartPlugin for RecordingPlugin {
+    fn id(&self) -> &str {
+        "zoom-selection-recorder"
+    }
+
+    fn on_event(&mut self, event: PluginEvent, _context: PluginContext) {
+        if matches!(
+            event,
+            PluginEvent::ZoomSelectionStarted { .. }
+                | PluginEvent::ZoomSelectionCompleted { .. }
+                | PluginEvent::ZoomSelectionCancelled
+        ) {
+            self.events.borrow_mut().push(event);
+        }
+    }
+}
+
+type Events = Rc<RefCell<Vec<PluginEvent>>>;
+
+fn build_engine() -> (ChartEngine<NullRenderer>, Events) {
+    let config =
+        ChartEngineConfig::new(Viewport::new(800, 400), 0.0, 100.0).with_price_domain(0.0, 100.0);
+    let mut engine = ChartEngine::new(NullRenderer::default(), config).expect("engine init");
+    engine.set_data(
+        (0..=100)
+            .map(|step| DataPoint::new(f64::from(step), f64::from(step)))
+            .collect(),
+    );
+    engine
+        .set_time_visible_range(0.0, 100.0)
+        .expect("visible range");
+    let events = Rc::new(RefCell::new(Vec::new()));
+    engine
+        .register_plugin(Box::new(RecordingPlugin {
+            events: events.clone(),
+        }))
+        .expect("register plugin");
+    (engine, events)
+}
+
+fn assert_close(actual: f64, expected: f64) {
+    assert!(
+        (actual - expected).abs() <= 1e-6,
+        "expected {expected}, got {actual}"
+    );
+}
+
+#[test]
+fn selection_sets_visible_time_range_and_reports_lifecycle() {
+    let (mut engine, events) = build_engine();
+    let left = engine.map_x_to_pixel(20.0).expect("left");
+    let right = engine.map_x_to_pixel(40.0).expect("right");
+
+    engine.begin_zoom_selection(right, 50.0);
+    engine.update_zoom_selection(left, 120.0);
+    let selection = engine.zoom_selection().expect("active selection");
+    assert_close(selection.rect().0, left);
+    assert_close(selection.rect().2, right - left);
+
+    assert!(engine.end_zoom_selection().expect("apply selection"));
+
+    let (start, end) = engine.time_visible_range();
+    assert_close(start, 20.0);
+    assert_close(end, 40.0);
+    assert_eq!(engine.zoom_selection(), None);
+    assert_eq!(
+        *events.borrow(),
+        vec![
+            PluginEvent::ZoomSelectionStarted { x: right, y: 50.0 },
+            PluginEvent::ZoomSelectionCompleted {
+                start,
+                end,
+                price_domain: None,
+            },
+        ]
+    );
+}
+
+#[test]
+fn price_selection_also_fits_price_domain() {
+    let (mut engine, events) = build_engine();
+    engine
+        .set_zoom_selection_behavior(ZoomSelectionBehavior {
+            zoom_price: true,
+            ..ZoomSelectionBehavior::default()
+        })
+        .expect("behavior");
+    let top_price = engine.map_pixel_to_price(80.0).expect("top price");
+    let bottom_price = engine.map_pixel_to_price(200.0).expect("bottom price");
+
+    engine.begin_zoom_selection(100.0, 200.0);
+    engine.update_zoom_selection(300.0, 80.0);
+    assert!(engine.end_zoom_selection().expect("apply selection"));
+
+    let (price_min, price_max) = engine.price_domain();
+    assert_close(price_min, bottom_price);
+    assert_close(price_max, top_price);
+    assert!(matches!(
+        events.borrow().last(),
+        Some(PluginEvent::ZoomSelectionCompleted {
+            price_domain: Some(_),
+            ..
+        })
+    ));
+}
+
+#[test]
+fn undersized_selection_is_cancelled_on_release() {
+    let (mut engine, events) = build_engine();
+
+    engine.begin_zoom_selection(100.0, 100.0);
+    engine.update_zoom_selection(102.0, 180.0);
+    assert!(!engine.end_zoom_selection().expect("release"));
+
+    assert_eq!(engine.time_visible_range(), (0.0, 100.0));
+    assert_eq!(
+        events.borrow().last(),
+        Some(&PluginEvent::ZoomSelectionCancelled)
+    );
+}
+
+#[test]
+fn cancel_drops_selection_without_zooming() {
+    let (mut engine, events) = build_engine();
+
+    engine.begin_zoom_selection(100.0, 100.0);
+    engine.update_zoom_selection(300.0, 180.0);
+    engine.cancel_zoom_selection();
+
+    assert_eq!(engine.zoom_selection(), None);
+    assert!(!engine.end_zoom_selection().expect("nothing to apply"));
+    assert_eq!(engine.time_visible_range(), (0.0, 100.0));
+    assert_eq!(events.borrow().len(), 2);
+    assert_eq!(events.borrow()[1], PluginEvent::ZoomSelectionCancelled);
+}
+
+#[test]
+fn selection_start_and_drop_schedule_a_cursor_repaint() {
+    let (mut engine, _) = build_engine();
+    engine.clear_pending_invalidation();
+
+    engine.begin_zoom_selection(100.0, 100.0);
+    assert!(engine.has_pending_invalidation_topic(InvalidationTopic::Cursor));
+
+    engine.update_zoom_selection(300.0, 180.0);
+    engine.clear_pending_invalidation();
+    engine.cancel_zoom_selection();
+    assert!(engine.has_pending_invalidation_topic(InvalidationTopic::Cursor));
+
+    engine.begin_zoom_selection(100.0, 100.0);
+    engine.update_zoom_selection(102.0, 180.0);
+    engine.clear_pending_invalidation();
+    assert!(!engine.end_zoom_selection().expect("release"));
+    assert!(engine.has_pending_invalidation_topic(InvalidationTopic::Cursor));
+}
+
+#[test]
+fn active_selection_draws_a_plot_height_band() {
+    let (mut engine, _) = build_engine();
+    let rects_before = engine.build_render_frame().expect("frame").rects.len();
+
+    engine.begin_zoom_selection(100.0, 100.0);
+    engine.update_zoom_selection(300.0, 150.0);
+    let frame = engine.build_render_frame().expect("frame");
+
+    assert_eq!(frame.rects.len(), rects_before + 1);
+    let band = frame
+        .rects
+        .iter()
+        .find(|rect| rect.fill_color == ZoomSelectionBehavior::default().fill_color)
+        .expect("selection band");
+    assert_close(band.x, 100.0);
+    assert_close(band.width, 200.0);
+    assert_close(band.y, 0.0);
+}
+
+#[test]
+fn shift_drag_through_bridge_zooms_instead_of_panning() {
+    let (mut engine, events) = build_engine();
+    let mut bridge = PointerInputBridge::default();
+    let left = engine.map_x_to_pixel(30.0).expect("left");
+    let right = engine.map_x_to_pixel(60.0).expect("right");
+
+    for input in [
+        PointerInput::ModifiersChanged {
+            modifiers: PointerModifiers::SHIFT,
+        },
+        PointerInput::Moved { x: left, y: 100.0 },
+        PointerInput::Pressed { x: left, y: 100.0 },
+        PointerInput::Moved { x: right, y: 150.0 },
+        PointerInput::Released,
+    ] {
+        bridge.handle(&mut engine, input).expect("input");
+    }
+
+    let (start, end) = engine.time_visible_range();
+    assert_close(start, 30.0);
+    assert_close(end, 60.0);
+    assert!(!bridge.is_dragging());
+    assert_eq!(events.borrow().len(), 2);
+}
+
+#[test]
+fn cancelled_input_aborts_bridge_selection() {
+    let (mut engine, events) = build_engine();
+    let mut bridge = PointerInputBridge::default();
+
+    for input in [
+        PointerInput::ModifiersChanged {
+            modifiers: PointerModifiers::SHIFT,
+        },
+        PointerInput::Pressed { x: 100.0, y: 100.0 },
+        PointerInput::Moved { x: 300.0, y: 150.0 },
+        PointerInput::Cancelled,
+        PointerInput::Released,
+    ] {
+        bridge.handle(&mut engine, input).expect("input");
+    }
+
+    assert_eq!(engine.time_visible_range(), (0.0, 100.0));
+    assert_eq!(engine.zoom_selection(), None);
+    assert_eq!(
+        events.borrow().last(),
+        Some(&PluginEvent::ZoomSelectionCancelled)
+    );
+}
+
+#[test]
+fn disabled_zoom_selection_keeps_shift_drag_panning() {
+    let (mut engine, events) = build_engine();
+    let mut bridge =
+        PointerInputBridge::new(InteractionOptions::default().with_zoom_selection(None));
+
+    for input in [
+        PointerInput::ModifiersChanged {
+            modifiers: PointerModifiers::SHIFT,
+        },
+        PointerInput::Pressed { x: 300.0, y: 100.0 },
+        PointerInput::Moved { x: 200.0, y: 100.0 },
+        PointerInput::Released,
+    ] {
+        bridge.handle(&mut engine, input).expect("input");
+    }
+
+    let (start, end) = engine.time_visible_range();
+    assert!(start > 0.0);
+    assert_close(end - start, 100.0);
+    assert!(events.borrow().is_empty());
+}
+
+#[test]
+fn invalid_zoom_selection_behavior_is_rejected() {
+    let (mut engine, _) = build_engine();
+
+    assert!(
+        engine
+            .set_zoom_selection_behavior(ZoomSelectionBehavior {
+                min_size_px: f64::NAN,
+                ..ZoomSelectionBehavior::default()
+            })
+            .is_err()
+    );
+    assert_eq!(
+        engine.zoom_selection_behavior(),
+        ZoomSelectionBehavior::default()
+    );
+}